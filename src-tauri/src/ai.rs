@@ -9,8 +9,12 @@ use std::sync::Arc;
 use tauri::{AppHandle, State};
 use tokio::sync::RwLock;
 use uuid::Uuid;
+use crate::core::events::{publish_domain_event, DomainEvent};
 use crate::security::keystore::Keystore;
 
+/// Score movement (in points) that counts as a risk change even without a level change.
+const RISK_CHANGE_THRESHOLD: f64 = 10.0;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct RiskFeatures {
@@ -49,6 +53,17 @@ pub struct RiskScore {
     pub timestamp: String,
 }
 
+/// A first score, a level change or a large score move are published as risk changes.
+fn risk_score_changed(previous: Option<&RiskScore>, current: &RiskScore) -> bool {
+    match previous {
+        None => true,
+        Some(previous) => {
+            previous.risk_level != current.risk_level
+                || (previous.score - current.score).abs() >= RISK_CHANGE_THRESHOLD
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct RiskFactor {
@@ -352,6 +367,8 @@ impl RiskAnalyzer {
             timestamp: Utc::now().to_rfc3339(),
         };
         
+        let previous = self.get_latest_risk_score(token_address).await?;
+
         // Store in database
         let factors_json = serde_json::to_string(&factors).unwrap_or_default();
         sqlx::query(
@@ -367,6 +384,19 @@ impl RiskAnalyzer {
         .bind(&risk_score.timestamp)
         .execute(&self.pool)
         .await?;
+
+        if risk_score_changed(previous.as_ref(), &risk_score) {
+            publish_domain_event(
+                "risk_analyzer",
+                DomainEvent::RiskScoreChanged {
+                    token_address: risk_score.token_address.clone(),
+                    previous_score: previous.as_ref().map(|p| p.score),
+                    score: risk_score.score,
+                    previous_level: previous.map(|p| p.risk_level),
+                    risk_level: risk_score.risk_level.clone(),
+                },
+            );
+        }
         
         Ok(risk_score)
     }
@@ -407,6 +437,37 @@ impl RiskAnalyzer {
         })
     }
     
+    /// Scores between two RFC 3339 timestamps, oldest first.
+    pub async fn get_risk_series(
+        &self,
+        token_address: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<RiskHistoryPoint>, sqlx::Error> {
+        let rows = sqlx::query(
+            r#"
+            SELECT score, risk_level, timestamp
+            FROM risk_scores
+            WHERE token_address = ? AND timestamp >= ? AND timestamp <= ?
+            ORDER BY timestamp ASC
+            "#,
+        )
+        .bind(token_address)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| RiskHistoryPoint {
+                timestamp: row.get("timestamp"),
+                score: row.get("score"),
+                risk_level: row.get("risk_level"),
+            })
+            .collect())
+    }
+
     pub async fn get_latest_risk_score(
         &self,
         token_address: &str,
@@ -2090,4 +2151,20 @@ mod tests {
         assert_eq!(stats.requests_count, deserialized.requests_count);
         assert_eq!(stats.tokens_used, deserialized.tokens_used);
     }
+
+    #[test]
+    fn test_risk_change_detection() {
+        let score = |score: f64, level: &str| RiskScore {
+            token_address: "mint".to_string(),
+            score,
+            risk_level: level.to_string(),
+            contributing_factors: vec![],
+            timestamp: Utc::now().to_rfc3339(),
+        };
+
+        assert!(risk_score_changed(None, &score(20.0, "Low")));
+        assert!(!risk_score_changed(Some(&score(20.0, "Low")), &score(25.0, "Low")));
+        assert!(risk_score_changed(Some(&score(28.0, "Low")), &score(31.0, "Medium")));
+        assert!(risk_score_changed(Some(&score(62.0, "High")), &score(75.0, "High")));
+    }
 }
@@ -1,12 +1,23 @@
-use chrono::Utc;
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{Pool, Row, Sqlite, SqlitePool};
 use std::path::PathBuf;
+use std::sync::Arc;
 use tauri::{AppHandle, State};
+use tokio::sync::RwLock;
 
-use super::price_alerts::{AlertError, CompoundCondition, NotificationChannel};
+use super::price_alerts::{
+    AlertConditionType, AlertError, CompoundCondition, NotificationChannel, PriceAlert,
+};
 
 const ALERT_HISTORY_DB_FILE: &str = "alert_history.db";
+/// Moves smaller than this (in percent) count as neutral outcomes.
+const NEUTRAL_RETURN_PERCENT: f64 = 1.0;
+/// How late an outcome checkpoint may still be sampled before it is skipped.
+const CHECKPOINT_GRACE_MINUTES: i64 = 30;
+/// Minimum evaluated triggers before an alert can be flagged for pruning.
+const PRUNE_MIN_EVALUATED: usize = 5;
+const PRUNE_MAX_HIT_RATE: f64 = 0.4;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -26,12 +37,160 @@ pub struct AlertHistoryEntry {
     pub price_after_1h: Option<f64>,
     pub price_after_4h: Option<f64>,
     pub price_after_24h: Option<f64>,
-    pub outcome_type: Option<String>, // "profit", "loss", "neutral", "pending"
+    pub outcome_type: Option<String>, // "profit", "loss", "neutral", "pending", "unknown"
     pub created_at: String,
+    pub price_24h_ago: Option<f64>,
+    pub volume_24h: Option<f64>,
+    pub change_24h: Option<f64>,
+    pub muted: bool,
+}
+
+/// Market snapshot captured when an alert fires.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertMarketContext {
+    pub price_24h_ago: Option<f64>,
+    pub volume_24h: Option<f64>,
+}
+
+impl AlertMarketContext {
+    pub fn change_24h(&self, price: f64) -> Option<f64> {
+        self.price_24h_ago
+            .filter(|previous| *previous > 0.0)
+            .map(|previous| (price - previous) / previous * 100.0)
+    }
+}
+
+/// Which side a trader acting on the alert would take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertTradeBias {
+    Long,
+    Short,
+}
+
+/// Derives the trade bias from the alert's first condition: breakouts above a
+/// level, upward moves and volume spikes are read as longs, breakdowns as shorts.
+pub fn alert_trade_bias(compound: &CompoundCondition) -> AlertTradeBias {
+    match compound.conditions.first() {
+        Some(condition) => match condition.condition_type {
+            AlertConditionType::Below => AlertTradeBias::Short,
+            AlertConditionType::PercentChange if condition.value < 0.0 => AlertTradeBias::Short,
+            _ => AlertTradeBias::Long,
+        },
+        None => AlertTradeBias::Long,
+    }
+}
+
+/// Return in percent from acting on the alert at `entry` and exiting at `exit`.
+pub fn alert_trade_return(bias: AlertTradeBias, entry: f64, exit: f64) -> Option<f64> {
+    if entry <= 0.0 {
+        return None;
+    }
+    let change = (exit - entry) / entry * 100.0;
+    Some(match bias {
+        AlertTradeBias::Long => change,
+        AlertTradeBias::Short => -change,
+    })
+}
+
+pub fn classify_alert_outcome(trade_return: f64) -> &'static str {
+    if trade_return >= NEUTRAL_RETURN_PERCENT {
+        "profit"
+    } else if trade_return <= -NEUTRAL_RETURN_PERCENT {
+        "loss"
+    } else {
+        "neutral"
+    }
+}
+
+/// Per-alert usefulness based on the outcomes of its past triggers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertOutcomeSummary {
+    pub trigger_count: usize,
+    pub muted_count: usize,
+    pub evaluated_count: usize,
+    pub profit_count: usize,
+    pub loss_count: usize,
+    pub neutral_count: usize,
+    pub hit_rate: Option<f64>,
+    pub avg_return_1h: Option<f64>,
+    pub avg_return_24h: Option<f64>,
+    pub prune_suggested: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct AlertTriggerHistory {
+    pub alert_id: String,
+    pub bias: AlertTradeBias,
+    pub entries: Vec<AlertHistoryEntry>,
+    pub summary: AlertOutcomeSummary,
+}
+
+pub fn summarize_alert_outcomes(entries: &[AlertHistoryEntry]) -> AlertOutcomeSummary {
+    let mut summary = AlertOutcomeSummary {
+        trigger_count: entries.len(),
+        muted_count: entries.iter().filter(|entry| entry.muted).count(),
+        ..Default::default()
+    };
+
+    let mut returns_1h = Vec::new();
+    let mut returns_24h = Vec::new();
+    for entry in entries {
+        let bias = alert_trade_bias(&entry.compound_condition);
+        if let Some(ret) = entry
+            .price_after_1h
+            .and_then(|exit| alert_trade_return(bias, entry.triggered_price, exit))
+        {
+            returns_1h.push(ret);
+        }
+        if let Some(ret) = entry
+            .price_after_24h
+            .and_then(|exit| alert_trade_return(bias, entry.triggered_price, exit))
+        {
+            returns_24h.push(ret);
+        }
+        match entry.outcome_type.as_deref() {
+            Some("profit") => summary.profit_count += 1,
+            Some("loss") => summary.loss_count += 1,
+            Some("neutral") => summary.neutral_count += 1,
+            _ => continue,
+        }
+        summary.evaluated_count += 1;
+    }
+
+    let average = |values: &[f64]| {
+        (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+    };
+    summary.avg_return_1h = average(&returns_1h);
+    summary.avg_return_24h = average(&returns_24h);
+    if summary.evaluated_count > 0 {
+        let hit_rate = summary.profit_count as f64 / summary.evaluated_count as f64;
+        summary.hit_rate = Some(hit_rate);
+        summary.prune_suggested = summary.evaluated_count >= PRUNE_MIN_EVALUATED
+            && hit_rate < PRUNE_MAX_HIT_RATE
+            && summary.avg_return_24h.map_or(true, |ret| ret <= 0.0);
+    }
+    summary
+}
+
+/// Whether the `hours` checkpoint should be sampled `elapsed` after the
+/// trigger; `None` once its grace window has passed without a sample.
+fn checkpoint_due(elapsed: Duration, hours: i64) -> Option<bool> {
+    let mark = Duration::hours(hours);
+    if elapsed < mark {
+        Some(false)
+    } else if elapsed <= mark + Duration::minutes(CHECKPOINT_GRACE_MINUTES) {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AlertHistoryFilter {
     pub symbol: Option<String>,
     pub alert_name: Option<String>,
@@ -71,6 +230,8 @@ pub struct AlertHistoryManager {
     pool: Pool<Sqlite>,
 }
 
+pub type SharedAlertHistoryManager = Arc<RwLock<AlertHistoryManager>>;
+
 impl AlertHistoryManager {
     pub async fn new(app: &AppHandle) -> Result<Self, AlertError> {
         let db_path = alert_history_db_path(app)?;
@@ -102,7 +263,11 @@ impl AlertHistoryManager {
                 price_after_4h REAL,
                 price_after_24h REAL,
                 outcome_type TEXT,
-                created_at TEXT NOT NULL
+                created_at TEXT NOT NULL,
+                price_24h_ago REAL,
+                volume_24h REAL,
+                change_24h REAL,
+                muted INTEGER NOT NULL DEFAULT 0
             )
             "#,
         )
@@ -124,22 +289,22 @@ impl AlertHistoryManager {
         Ok(())
     }
 
+    /// Records a trigger, including muted ones, with the market context at
+    /// trigger time. Outcomes are filled in later by `update_pending_outcomes`.
     pub async fn record_triggered_alert(
         &self,
-        alert_id: &str,
-        alert_name: &str,
-        symbol: &str,
-        mint: &str,
-        compound_condition: &CompoundCondition,
+        alert: &PriceAlert,
         triggered_price: f64,
         conditions_met: &str,
-        notification_channels: &[NotificationChannel],
+        context: &AlertMarketContext,
+        muted: bool,
     ) -> Result<AlertHistoryEntry, AlertError> {
         let id = uuid::Uuid::new_v4().to_string();
         let now = Utc::now().to_rfc3339();
+        let change_24h = context.change_24h(triggered_price);
 
-        let compound_condition_json = serde_json::to_string(compound_condition)?;
-        let channels_json = serde_json::to_string(notification_channels)?;
+        let compound_condition_json = serde_json::to_string(&alert.compound_condition)?;
+        let channels_json = serde_json::to_string(&alert.notification_channels)?;
 
         sqlx::query(
             r#"
@@ -147,16 +312,17 @@ impl AlertHistoryManager {
                 id, alert_id, alert_name, symbol, mint, compound_condition,
                 triggered_price, conditions_met, notification_channels,
                 triggered_at, bookmarked, outcome_notes, price_after_1h,
-                price_after_4h, price_after_24h, outcome_type, created_at
+                price_after_4h, price_after_24h, outcome_type, created_at,
+                price_24h_ago, volume_24h, change_24h, muted
             )
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)
             "#,
         )
         .bind(&id)
-        .bind(alert_id)
-        .bind(alert_name)
-        .bind(symbol)
-        .bind(mint)
+        .bind(&alert.id)
+        .bind(&alert.name)
+        .bind(&alert.symbol)
+        .bind(&alert.mint)
         .bind(&compound_condition_json)
         .bind(triggered_price)
         .bind(conditions_met)
@@ -169,19 +335,23 @@ impl AlertHistoryManager {
         .bind::<Option<f64>>(None) // price_after_24h
         .bind::<Option<String>>(Some("pending".to_string())) // outcome_type
         .bind(&now)
+        .bind(context.price_24h_ago)
+        .bind(context.volume_24h)
+        .bind(change_24h)
+        .bind(if muted { 1 } else { 0 })
         .execute(&self.pool)
         .await?;
 
         Ok(AlertHistoryEntry {
             id,
-            alert_id: alert_id.to_string(),
-            alert_name: alert_name.to_string(),
-            symbol: symbol.to_string(),
-            mint: mint.to_string(),
-            compound_condition: compound_condition.clone(),
+            alert_id: alert.id.clone(),
+            alert_name: alert.name.clone(),
+            symbol: alert.symbol.clone(),
+            mint: alert.mint.clone(),
+            compound_condition: alert.compound_condition.clone(),
             triggered_price,
             conditions_met: conditions_met.to_string(),
-            notification_channels: notification_channels.to_vec(),
+            notification_channels: alert.notification_channels.clone(),
             triggered_at: now.clone(),
             bookmarked: false,
             outcome_notes: None,
@@ -190,9 +360,105 @@ impl AlertHistoryManager {
             price_after_24h: None,
             outcome_type: Some("pending".to_string()),
             created_at: now,
+            price_24h_ago: context.price_24h_ago,
+            volume_24h: context.volume_24h,
+            change_24h,
+            muted,
         })
     }
 
+    /// Samples 1h/4h/24h prices for pending triggers and settles their outcome
+    /// once the 24h checkpoint is reached or missed.
+    pub async fn update_pending_outcomes(
+        &self,
+        lookup: impl Fn(&str) -> Option<f64>,
+        now: DateTime<Utc>,
+    ) -> Result<usize, AlertError> {
+        let pending = self
+            .list_history(AlertHistoryFilter {
+                outcome_type: Some("pending".to_string()),
+                ..Default::default()
+            })
+            .await?;
+
+        let mut settled = 0;
+        for mut entry in pending {
+            let Ok(triggered_at) = DateTime::parse_from_rfc3339(&entry.triggered_at) else {
+                continue;
+            };
+            let elapsed = now - triggered_at.with_timezone(&Utc);
+            let price = lookup(&entry.symbol);
+
+            for (hours, slot) in [
+                (1, &mut entry.price_after_1h),
+                (4, &mut entry.price_after_4h),
+                (24, &mut entry.price_after_24h),
+            ] {
+                if slot.is_none() && checkpoint_due(elapsed, hours) == Some(true) {
+                    *slot = price;
+                }
+            }
+
+            if entry.price_after_24h.is_some() || checkpoint_due(elapsed, 24).is_none() {
+                let bias = alert_trade_bias(&entry.compound_condition);
+                let exit = entry
+                    .price_after_24h
+                    .or(entry.price_after_4h)
+                    .or(entry.price_after_1h);
+                entry.outcome_type = Some(
+                    exit.and_then(|exit| alert_trade_return(bias, entry.triggered_price, exit))
+                        .map(classify_alert_outcome)
+                        .unwrap_or("unknown")
+                        .to_string(),
+                );
+                settled += 1;
+            }
+
+            self.update_history_entry(
+                &entry.id,
+                UpdateAlertHistoryRequest {
+                    bookmarked: None,
+                    outcome_notes: None,
+                    price_after_1h: entry.price_after_1h,
+                    price_after_4h: entry.price_after_4h,
+                    price_after_24h: entry.price_after_24h,
+                    outcome_type: entry.outcome_type.clone(),
+                },
+            )
+            .await?;
+        }
+
+        Ok(settled)
+    }
+
+    pub async fn get_alert_trigger_history(
+        &self,
+        alert_id: &str,
+        limit: Option<i32>,
+    ) -> Result<Vec<AlertHistoryEntry>, AlertError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, alert_id, alert_name, symbol, mint, compound_condition,
+                   triggered_price, conditions_met, notification_channels,
+                   triggered_at, bookmarked, outcome_notes, price_after_1h,
+                   price_after_4h, price_after_24h, outcome_type, created_at,
+                   price_24h_ago, volume_24h, change_24h, muted
+            FROM alert_history
+            WHERE alert_id = ?1
+            ORDER BY triggered_at DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(alert_id)
+        .bind(limit.unwrap_or(-1))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_history_entry(row))
+            .collect()
+    }
+
     pub async fn list_history(
         &self,
         filter: AlertHistoryFilter,
@@ -202,7 +468,8 @@ impl AlertHistoryManager {
             SELECT id, alert_id, alert_name, symbol, mint, compound_condition,
                    triggered_price, conditions_met, notification_channels,
                    triggered_at, bookmarked, outcome_notes, price_after_1h,
-                   price_after_4h, price_after_24h, outcome_type, created_at
+                   price_after_4h, price_after_24h, outcome_type, created_at,
+                   price_24h_ago, volume_24h, change_24h, muted
             FROM alert_history
             WHERE 1=1
             "#,
@@ -270,7 +537,8 @@ impl AlertHistoryManager {
             SELECT id, alert_id, alert_name, symbol, mint, compound_condition,
                    triggered_price, conditions_met, notification_channels,
                    triggered_at, bookmarked, outcome_notes, price_after_1h,
-                   price_after_4h, price_after_24h, outcome_type, created_at
+                   price_after_4h, price_after_24h, outcome_type, created_at,
+                   price_24h_ago, volume_24h, change_24h, muted
             FROM alert_history
             WHERE id = ?1
             "#,
@@ -411,6 +679,7 @@ impl AlertHistoryManager {
             serde_json::from_str(&channels_json)?;
 
         let bookmarked_int: i32 = row.try_get("bookmarked")?;
+        let muted_int: i32 = row.try_get("muted")?;
 
         Ok(AlertHistoryEntry {
             id: row.try_get("id")?,
@@ -430,6 +699,10 @@ impl AlertHistoryManager {
             price_after_24h: row.try_get("price_after_24h")?,
             outcome_type: row.try_get("outcome_type")?,
             created_at: row.try_get("created_at")?,
+            price_24h_ago: row.try_get("price_24h_ago")?,
+            volume_24h: row.try_get("volume_24h")?,
+            change_24h: row.try_get("change_24h")?,
+            muted: muted_int == 1,
         })
     }
 }
@@ -447,7 +720,7 @@ fn alert_history_db_path(app: &AppHandle) -> Result<PathBuf, AlertError> {
 // Tauri commands
 #[tauri::command]
 pub async fn alert_history_list(
-    manager: State<'_, crate::alerts::SharedAlertHistoryManager>,
+    manager: State<'_, SharedAlertHistoryManager>,
     filter: AlertHistoryFilter,
 ) -> Result<Vec<AlertHistoryEntry>, String> {
     let mgr = manager.read().await;
@@ -456,7 +729,7 @@ pub async fn alert_history_list(
 
 #[tauri::command]
 pub async fn alert_history_get(
-    manager: State<'_, crate::alerts::SharedAlertHistoryManager>,
+    manager: State<'_, SharedAlertHistoryManager>,
     id: String,
 ) -> Result<AlertHistoryEntry, String> {
     let mgr = manager.read().await;
@@ -465,7 +738,7 @@ pub async fn alert_history_get(
 
 #[tauri::command]
 pub async fn alert_history_update(
-    manager: State<'_, crate::alerts::SharedAlertHistoryManager>,
+    manager: State<'_, SharedAlertHistoryManager>,
     id: String,
     req: UpdateAlertHistoryRequest,
 ) -> Result<AlertHistoryEntry, String> {
@@ -477,7 +750,7 @@ pub async fn alert_history_update(
 
 #[tauri::command]
 pub async fn alert_history_delete(
-    manager: State<'_, crate::alerts::SharedAlertHistoryManager>,
+    manager: State<'_, SharedAlertHistoryManager>,
     id: String,
 ) -> Result<(), String> {
     let mgr = manager.read().await;
@@ -488,7 +761,7 @@ pub async fn alert_history_delete(
 
 #[tauri::command]
 pub async fn alert_history_stats(
-    manager: State<'_, crate::alerts::SharedAlertHistoryManager>,
+    manager: State<'_, SharedAlertHistoryManager>,
 ) -> Result<AlertHistoryStats, String> {
     let mgr = manager.read().await;
     mgr.get_statistics().await.map_err(|e| e.to_string())
@@ -496,9 +769,117 @@ pub async fn alert_history_stats(
 
 #[tauri::command]
 pub async fn alert_history_export_csv(
-    manager: State<'_, crate::alerts::SharedAlertHistoryManager>,
+    manager: State<'_, SharedAlertHistoryManager>,
     filter: AlertHistoryFilter,
 ) -> Result<String, String> {
     let mgr = manager.read().await;
     mgr.export_to_csv(filter).await.map_err(|e| e.to_string())
 }
+
+/// Trigger log for one alert with its outcome summary, used to spot alerts
+/// that would not have been profitable to act on.
+#[tauri::command]
+pub async fn get_alert_history(
+    manager: State<'_, SharedAlertHistoryManager>,
+    alert_id: String,
+    limit: Option<i32>,
+) -> Result<AlertTriggerHistory, String> {
+    let mgr = manager.read().await;
+    let entries = mgr
+        .get_alert_trigger_history(&alert_id, limit)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let bias = entries
+        .first()
+        .map(|entry| alert_trade_bias(&entry.compound_condition))
+        .unwrap_or(AlertTradeBias::Long);
+    let summary = summarize_alert_outcomes(&entries);
+
+    Ok(AlertTriggerHistory {
+        alert_id,
+        bias,
+        entries,
+        summary,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerts::price_alerts::{AlertCondition, LogicalOperator};
+
+    fn condition(condition_type: AlertConditionType, value: f64) -> CompoundCondition {
+        CompoundCondition {
+            conditions: vec![AlertCondition {
+                condition_type,
+                value,
+                timeframe_minutes: None,
+            }],
+            operator: LogicalOperator::And,
+        }
+    }
+
+    fn entry(
+        condition_type: AlertConditionType,
+        outcome: &str,
+        after_24h: f64,
+    ) -> AlertHistoryEntry {
+        AlertHistoryEntry {
+            id: "h1".into(),
+            alert_id: "a1".into(),
+            alert_name: "SOL".into(),
+            symbol: "SOL".into(),
+            mint: String::new(),
+            compound_condition: condition(condition_type, 100.0),
+            triggered_price: 100.0,
+            conditions_met: String::new(),
+            notification_channels: vec![NotificationChannel::InApp],
+            triggered_at: String::new(),
+            bookmarked: false,
+            outcome_notes: None,
+            price_after_1h: None,
+            price_after_4h: None,
+            price_after_24h: Some(after_24h),
+            outcome_type: Some(outcome.to_string()),
+            created_at: String::new(),
+            price_24h_ago: None,
+            volume_24h: None,
+            change_24h: None,
+            muted: false,
+        }
+    }
+
+    #[test]
+    fn below_alerts_profit_from_further_drops() {
+        let bias = alert_trade_bias(&condition(AlertConditionType::Below, 90.0));
+        assert_eq!(bias, AlertTradeBias::Short);
+        let ret = alert_trade_return(bias, 90.0, 81.0).unwrap();
+        assert!((ret - 10.0).abs() < 1e-9);
+        assert_eq!(classify_alert_outcome(ret), "profit");
+        assert_eq!(classify_alert_outcome(0.5), "neutral");
+    }
+
+    #[test]
+    fn checkpoints_are_skipped_after_grace_window() {
+        assert_eq!(checkpoint_due(Duration::minutes(59), 1), Some(false));
+        assert_eq!(checkpoint_due(Duration::minutes(70), 1), Some(true));
+        assert_eq!(checkpoint_due(Duration::hours(3), 1), None);
+    }
+
+    #[test]
+    fn losing_alerts_are_flagged_for_pruning() {
+        let mut entries: Vec<_> = (0..4)
+            .map(|_| entry(AlertConditionType::Above, "loss", 95.0))
+            .collect();
+        entries.push(entry(AlertConditionType::Above, "profit", 104.0));
+        entries.push(entry(AlertConditionType::Above, "pending", 100.0));
+
+        let summary = summarize_alert_outcomes(&entries);
+        assert_eq!(summary.trigger_count, 6);
+        assert_eq!(summary.evaluated_count, 5);
+        assert_eq!(summary.hit_rate, Some(0.2));
+        assert!(summary.avg_return_24h.unwrap() < 0.0);
+        assert!(summary.prune_suggested);
+    }
+}
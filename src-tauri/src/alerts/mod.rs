@@ -1,5 +1,13 @@
 pub mod price_alerts;
+pub mod preview;
 pub mod logic;
+pub mod mute;
+pub mod rearm;
+pub mod alert_history;
 
 pub use price_alerts::*;
+pub use preview::*;
 pub use logic::*;
+pub use mute::*;
+pub use rearm::*;
+pub use alert_history::*;
@@ -0,0 +1,447 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveTime, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Row};
+use tauri::{Manager, State};
+
+use super::price_alerts::{AlertError, AlertManager, AlertTriggerEvent, SharedAlertManager};
+
+const GLOBAL_SCOPE: &str = "global";
+const MUTE_SUMMARY_EVENT: &str = "alert_mute_summary";
+
+/// Recurring window, e.g. a sleep schedule. A window whose end is not after
+/// its start runs past midnight into the next day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklyMuteWindow {
+    /// Days the window starts on.
+    pub days: Vec<Weekday>,
+    /// Local "HH:MM".
+    pub start_time: String,
+    pub end_time: String,
+}
+
+/// One-off range, e.g. a meeting or travel.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OneOffMuteWindow {
+    pub label: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// Mute windows for one alert, or for every alert when `alert_id` is `None`.
+/// Unlike channel quiet hours this silences the alert itself; triggers are
+/// still recorded.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertMuteSchedule {
+    pub alert_id: Option<String>,
+    pub enabled: bool,
+    #[serde(default)]
+    pub weekly: Vec<WeeklyMuteWindow>,
+    #[serde(default)]
+    pub one_off: Vec<OneOffMuteWindow>,
+    /// Offset of the user's local time used for weekly windows.
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+    /// Emit a summary of muted triggers once the window ends.
+    #[serde(default)]
+    pub summarize_on_end: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct MutedTrigger {
+    pub id: i64,
+    pub scope: String,
+    pub alert_id: String,
+    pub alert_name: String,
+    pub symbol: String,
+    pub current_price: f64,
+    pub conditions_met: String,
+    pub triggered_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MuteSummary {
+    pub alert_id: Option<String>,
+    pub triggers: Vec<MutedTrigger>,
+    pub window_ended_at: String,
+}
+
+fn parse_time(value: &str) -> Result<NaiveTime, AlertError> {
+    NaiveTime::parse_from_str(value, "%H:%M")
+        .map_err(|_| AlertError::Internal(format!("Invalid time '{}', expected HH:MM", value)))
+}
+
+impl WeeklyMuteWindow {
+    fn contains(&self, local: DateTime<FixedOffset>) -> bool {
+        let (Ok(start), Ok(end)) = (parse_time(&self.start_time), parse_time(&self.end_time))
+        else {
+            return false;
+        };
+        let time = local.time();
+        let today = local.weekday();
+        if start < end {
+            return self.days.contains(&today) && time >= start && time < end;
+        }
+        (self.days.contains(&today) && time >= start)
+            || (self.days.contains(&today.pred()) && time < end)
+    }
+}
+
+impl AlertMuteSchedule {
+    pub fn scope(&self) -> &str {
+        self.alert_id.as_deref().unwrap_or(GLOBAL_SCOPE)
+    }
+
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if self
+            .one_off
+            .iter()
+            .any(|window| window.starts_at <= now && now < window.ends_at)
+        {
+            return true;
+        }
+        let Some(offset) = FixedOffset::east_opt(self.utc_offset_minutes * 60) else {
+            return false;
+        };
+        let local = now.with_timezone(&offset);
+        self.weekly.iter().any(|window| window.contains(local))
+    }
+
+    fn validate(&self) -> Result<(), AlertError> {
+        for window in &self.weekly {
+            parse_time(&window.start_time)?;
+            parse_time(&window.end_time)?;
+            if window.days.is_empty() {
+                return Err(AlertError::Internal(
+                    "Weekly mute window needs at least one day".into(),
+                ));
+            }
+        }
+        if self.one_off.iter().any(|w| w.ends_at <= w.starts_at) {
+            return Err(AlertError::Internal(
+                "Mute window must end after it starts".into(),
+            ));
+        }
+        if FixedOffset::east_opt(self.utc_offset_minutes * 60).is_none() {
+            return Err(AlertError::Internal("Invalid UTC offset".into()));
+        }
+        Ok(())
+    }
+}
+
+/// The schedule muting `alert_id` right now; a per-alert schedule takes
+/// precedence over the global one.
+pub fn active_mute<'a>(
+    schedules: &'a [AlertMuteSchedule],
+    alert_id: &str,
+    now: DateTime<Utc>,
+) -> Option<&'a AlertMuteSchedule> {
+    schedules
+        .iter()
+        .filter(|s| s.alert_id.as_deref() == Some(alert_id))
+        .chain(schedules.iter().filter(|s| s.alert_id.is_none()))
+        .find(|s| s.is_active(now))
+}
+
+impl AlertManager {
+    pub(super) async fn initialize_mute_tables(&self) -> Result<(), AlertError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_mute_schedules (
+                scope TEXT PRIMARY KEY,
+                schedule TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_muted_triggers (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                scope TEXT NOT NULL,
+                alert_id TEXT NOT NULL,
+                alert_name TEXT NOT NULL,
+                symbol TEXT NOT NULL,
+                current_price REAL NOT NULL,
+                conditions_met TEXT NOT NULL,
+                triggered_at TEXT NOT NULL,
+                pending_summary INTEGER NOT NULL DEFAULT 0
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_muted_triggers_scope ON alert_muted_triggers(scope, pending_summary)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_mute_schedules(&self) -> Result<Vec<AlertMuteSchedule>, AlertError> {
+        let rows = sqlx::query("SELECT schedule FROM alert_mute_schedules")
+            .fetch_all(&self.pool)
+            .await?;
+        rows.into_iter()
+            .map(|row| {
+                Ok(serde_json::from_str(
+                    &row.try_get::<String, _>("schedule")?,
+                )?)
+            })
+            .collect()
+    }
+
+    pub async fn set_mute_schedule(
+        &self,
+        mut schedule: AlertMuteSchedule,
+    ) -> Result<AlertMuteSchedule, AlertError> {
+        schedule.validate()?;
+        if let Some(alert_id) = &schedule.alert_id {
+            self.get_alert(alert_id).await?;
+        }
+        let now = Utc::now();
+        schedule.one_off.retain(|window| window.ends_at > now);
+
+        sqlx::query(
+            r#"
+            INSERT INTO alert_mute_schedules (scope, schedule, updated_at)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(scope) DO UPDATE SET schedule = excluded.schedule,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(schedule.scope())
+        .bind(serde_json::to_string(&schedule)?)
+        .bind(now.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        if !schedule.summarize_on_end {
+            sqlx::query("UPDATE alert_muted_triggers SET pending_summary = 0 WHERE scope = ?1")
+                .bind(schedule.scope())
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(schedule)
+    }
+
+    pub(super) async fn record_muted_trigger(
+        &self,
+        schedule: &AlertMuteSchedule,
+        event: &AlertTriggerEvent,
+    ) -> Result<(), AlertError> {
+        sqlx::query(
+            r#"
+            INSERT INTO alert_muted_triggers (
+                scope, alert_id, alert_name, symbol, current_price, conditions_met,
+                triggered_at, pending_summary
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#,
+        )
+        .bind(schedule.scope())
+        .bind(&event.alert_id)
+        .bind(&event.alert_name)
+        .bind(&event.symbol)
+        .bind(event.current_price)
+        .bind(&event.conditions_met)
+        .bind(&event.triggered_at)
+        .bind(schedule.summarize_on_end)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_muted_triggers(
+        &self,
+        alert_id: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<MutedTrigger>, AlertError> {
+        let query = match alert_id {
+            Some(alert_id) => sqlx::query_as::<_, MutedTrigger>(
+                r#"
+                SELECT id, scope, alert_id, alert_name, symbol, current_price, conditions_met,
+                       triggered_at
+                FROM alert_muted_triggers WHERE alert_id = ?1
+                ORDER BY id DESC LIMIT ?2
+                "#,
+            )
+            .bind(alert_id.to_string()),
+            None => sqlx::query_as::<_, MutedTrigger>(
+                r#"
+                SELECT id, scope, alert_id, alert_name, symbol, current_price, conditions_met,
+                       triggered_at
+                FROM alert_muted_triggers
+                ORDER BY id DESC LIMIT ?1
+                "#,
+            ),
+        };
+        Ok(query.bind(limit).fetch_all(&self.pool).await?)
+    }
+
+    /// Emits a summary for each schedule whose window has ended with muted
+    /// triggers still waiting to be reported.
+    pub(super) async fn flush_mute_summaries(
+        &self,
+        schedules: &[AlertMuteSchedule],
+        now: DateTime<Utc>,
+    ) -> Result<(), AlertError> {
+        for schedule in schedules
+            .iter()
+            .filter(|s| s.summarize_on_end && !s.is_active(now))
+        {
+            let triggers = sqlx::query_as::<_, MutedTrigger>(
+                r#"
+                SELECT id, scope, alert_id, alert_name, symbol, current_price, conditions_met,
+                       triggered_at
+                FROM alert_muted_triggers WHERE scope = ?1 AND pending_summary = 1
+                ORDER BY id ASC
+                "#,
+            )
+            .bind(schedule.scope())
+            .fetch_all(&self.pool)
+            .await?;
+            let Some(last) = triggers.last().map(|t| t.id) else {
+                continue;
+            };
+
+            sqlx::query(
+                "UPDATE alert_muted_triggers SET pending_summary = 0 WHERE scope = ?1 AND id <= ?2",
+            )
+            .bind(schedule.scope())
+            .bind(last)
+            .execute(&self.pool)
+            .await?;
+
+            let summary = MuteSummary {
+                alert_id: schedule.alert_id.clone(),
+                triggers,
+                window_ended_at: now.to_rfc3339(),
+            };
+            if let Err(err) = self.app_handle.emit_all(MUTE_SUMMARY_EVENT, summary) {
+                tracing::warn!(error = %err, "failed to emit alert mute summary");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[tauri::command]
+pub async fn alert_set_mute_schedule(
+    manager: State<'_, SharedAlertManager>,
+    schedule: AlertMuteSchedule,
+) -> Result<AlertMuteSchedule, String> {
+    let mgr = manager.read().await;
+    mgr.set_mute_schedule(schedule)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn alert_get_mute_schedules(
+    manager: State<'_, SharedAlertManager>,
+) -> Result<Vec<AlertMuteSchedule>, String> {
+    let mgr = manager.read().await;
+    mgr.list_mute_schedules().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn alert_list_muted_triggers(
+    manager: State<'_, SharedAlertManager>,
+    alert_id: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<MutedTrigger>, String> {
+    let mgr = manager.read().await;
+    mgr.list_muted_triggers(alert_id.as_deref(), limit.unwrap_or(100))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sleep_schedule(alert_id: Option<&str>) -> AlertMuteSchedule {
+        AlertMuteSchedule {
+            alert_id: alert_id.map(str::to_string),
+            enabled: true,
+            weekly: vec![WeeklyMuteWindow {
+                days: vec![Weekday::Mon, Weekday::Tue],
+                start_time: "23:00".to_string(),
+                end_time: "07:00".to_string(),
+            }],
+            one_off: Vec::new(),
+            utc_offset_minutes: 120,
+            summarize_on_end: true,
+        }
+    }
+
+    // 2024-01-01 is a Monday
+    fn utc(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, day, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn overnight_window_runs_into_the_next_day_in_local_time() {
+        let schedule = sleep_schedule(None);
+        // Monday 23:30 local
+        assert!(schedule.is_active(utc(1, 21, 30)));
+        // Tuesday 06:59 local, started Monday
+        assert!(schedule.is_active(utc(2, 4, 59)));
+        // Tuesday 07:00 local
+        assert!(!schedule.is_active(utc(2, 5, 0)));
+        // Thursday 01:00 local, Wednesday is not scheduled
+        assert!(!schedule.is_active(utc(3, 23, 0)));
+    }
+
+    #[test]
+    fn one_off_ranges_and_disabled_schedules() {
+        let mut schedule = sleep_schedule(None);
+        schedule.weekly.clear();
+        schedule.one_off.push(OneOffMuteWindow {
+            label: Some("Conference".to_string()),
+            starts_at: utc(5, 9, 0),
+            ends_at: utc(5, 17, 0),
+        });
+        assert!(schedule.is_active(utc(5, 12, 0)));
+        assert!(!schedule.is_active(utc(5, 17, 0)));
+
+        schedule.enabled = false;
+        assert!(!schedule.is_active(utc(5, 12, 0)));
+        assert!(schedule.validate().is_ok());
+
+        schedule.one_off[0].ends_at = utc(5, 8, 0);
+        assert!(schedule.validate().is_err());
+    }
+
+    #[test]
+    fn alert_schedule_takes_precedence_over_global() {
+        let global = sleep_schedule(None);
+        let alert = sleep_schedule(Some("alert-1"));
+        let schedules = vec![global, alert];
+        let now = utc(1, 21, 30);
+
+        let muting = active_mute(&schedules, "alert-1", now).unwrap();
+        assert_eq!(muting.scope(), "alert-1");
+        assert_eq!(
+            active_mute(&schedules, "alert-2", now).unwrap().scope(),
+            "global"
+        );
+        assert!(active_mute(&schedules, "alert-2", utc(2, 12, 0)).is_none());
+    }
+}
@@ -0,0 +1,428 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Manager, State};
+
+use super::price_alerts::{
+    alert_webhook_event, AlertConditionType, AlertTriggerEvent, NotificationChannel, PriceAlert,
+    SharedAlertManager,
+};
+use crate::notifications::{
+    alert_push_content, ChatServiceType, RenderedNotification, SharedNotificationRouter,
+};
+use crate::webhooks::SharedWebhookManager;
+
+const PREVIEW_EVENT: &str = "alert_preview";
+const LOCAL_TARGET: &str = "local";
+
+/// Market data chosen so that the alert's conditions would fire.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampleMarketData {
+    pub current_price: f64,
+    pub price_24h_ago: Option<f64>,
+    pub volume_24h: Option<f64>,
+}
+
+impl SampleMarketData {
+    pub fn for_alert(alert: &PriceAlert) -> Self {
+        let conditions = &alert.compound_condition.conditions;
+
+        let current_price = conditions
+            .iter()
+            .find_map(|condition| match condition.condition_type {
+                AlertConditionType::Above => Some(condition.value * 1.05),
+                AlertConditionType::Below => Some(condition.value * 0.95),
+                _ => None,
+            })
+            .filter(|price| *price > 0.0)
+            .unwrap_or(1.0);
+
+        let price_24h_ago = conditions
+            .iter()
+            .find(|condition| condition.condition_type == AlertConditionType::PercentChange)
+            .map(|condition| current_price / (1.0 + (condition.value + 1.0) / 100.0));
+
+        let volume_24h = conditions
+            .iter()
+            .find(|condition| condition.condition_type == AlertConditionType::VolumeSpike)
+            .map(|condition| condition.value * 1.5);
+
+        Self {
+            current_price,
+            price_24h_ago,
+            volume_24h,
+        }
+    }
+}
+
+/// One destination the previewed alert would be sent to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewTarget {
+    pub target_id: String,
+    pub target_name: String,
+    /// Whether a real trigger would reach this target.
+    pub receives_live: bool,
+    pub sandbox: bool,
+    pub payload: Value,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreview {
+    pub alert_id: String,
+    pub channel: NotificationChannel,
+    pub sample: AlertTriggerEvent,
+    pub targets: Vec<PreviewTarget>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewDeliveryResult {
+    pub success: bool,
+    pub message: String,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+fn chat_service(channel: &NotificationChannel) -> Option<ChatServiceType> {
+    match channel {
+        NotificationChannel::Telegram => Some(ChatServiceType::Telegram),
+        NotificationChannel::Slack => Some(ChatServiceType::Slack),
+        NotificationChannel::Discord => Some(ChatServiceType::Discord),
+        _ => None,
+    }
+}
+
+fn chat_target(rendered: RenderedNotification) -> PreviewTarget {
+    PreviewTarget {
+        receives_live: rendered.enabled && !rendered.sandbox,
+        target_id: rendered.target_id,
+        target_name: rendered.target_name,
+        sandbox: rendered.sandbox,
+        payload: rendered.payload,
+        error: None,
+    }
+}
+
+async fn sample_trigger(
+    manager: &SharedAlertManager,
+    alert_id: &str,
+) -> Result<(PriceAlert, AlertTriggerEvent), String> {
+    let manager = manager.read().await;
+    let alert = manager
+        .get_alert(alert_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let sample = SampleMarketData::for_alert(&alert);
+    let (_, _, conditions_met) = manager.evaluate_conditions(
+        &alert.compound_condition,
+        sample.current_price,
+        sample.price_24h_ago,
+        sample.volume_24h,
+    );
+
+    let event = AlertTriggerEvent {
+        alert_id: alert.id.clone(),
+        alert_name: alert.name.clone(),
+        symbol: alert.symbol.clone(),
+        current_price: sample.current_price,
+        conditions_met,
+        triggered_at: Utc::now().to_rfc3339(),
+    };
+    Ok((alert, event))
+}
+
+async fn webhook_targets(
+    app: &AppHandle,
+    alert: &PriceAlert,
+    event: &AlertTriggerEvent,
+) -> Result<Vec<PreviewTarget>, String> {
+    let Some(manager) = app.try_state::<SharedWebhookManager>() else {
+        return Ok(Vec::new());
+    };
+    let manager = manager.read().await;
+    let webhook_event = alert_webhook_event(alert, event);
+    let variables = webhook_event.variables();
+
+    let webhooks = manager.list_webhooks().await.map_err(|e| e.to_string())?;
+    Ok(webhooks
+        .into_iter()
+        .map(|config| {
+            let (payload, error) = match manager.preview_payload(&config, &variables) {
+                Ok(body) => (
+                    serde_json::from_str(&body).unwrap_or(Value::String(body)),
+                    None,
+                ),
+                Err(e) => (Value::Null, Some(e.to_string())),
+            };
+            PreviewTarget {
+                receives_live: config.enabled && config.subscription.matches(&webhook_event),
+                sandbox: config.subscription.event_types.is_empty(),
+                target_id: config.id,
+                target_name: config.name,
+                payload,
+                error,
+            }
+        })
+        .collect())
+}
+
+async fn push_targets(
+    app: &AppHandle,
+    alert: &PriceAlert,
+    event: &AlertTriggerEvent,
+) -> Result<Vec<PreviewTarget>, String> {
+    let Some(router) = app.try_state::<SharedNotificationRouter>() else {
+        return Ok(Vec::new());
+    };
+    let router = router.read().await;
+    let (title, message) = alert_push_content(
+        &event.alert_name,
+        &event.symbol,
+        event.current_price,
+        &event.conditions_met,
+    );
+    let selected = alert.notification_channels.contains(&NotificationChannel::Push);
+
+    let subscriptions = router
+        .push_bridge()
+        .list_subscriptions()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(subscriptions
+        .into_iter()
+        .map(|subscription| PreviewTarget {
+            receives_live: selected && subscription.enabled,
+            sandbox: false,
+            target_id: subscription.id,
+            target_name: subscription.name,
+            payload: serde_json::json!({ "title": title, "message": message }),
+            error: None,
+        })
+        .collect())
+}
+
+/// Renders what each target of a channel would receive if the alert fired now.
+#[tauri::command]
+pub async fn preview_notification(
+    app: AppHandle,
+    manager: State<'_, SharedAlertManager>,
+    alert_id: String,
+    channel: NotificationChannel,
+) -> Result<NotificationPreview, String> {
+    let (alert, sample) = sample_trigger(&manager, &alert_id).await?;
+
+    let targets = match &channel {
+        NotificationChannel::InApp | NotificationChannel::System => vec![PreviewTarget {
+            target_id: LOCAL_TARGET.to_string(),
+            target_name: "This device".to_string(),
+            receives_live: alert.notification_channels.contains(&channel),
+            sandbox: false,
+            payload: serde_json::to_value(&sample).map_err(|e| e.to_string())?,
+            error: None,
+        }],
+        NotificationChannel::Webhook => webhook_targets(&app, &alert, &sample).await?,
+        NotificationChannel::Email => {
+            return Err("Price alerts are not delivered by email".to_string());
+        }
+        NotificationChannel::Push => push_targets(&app, &alert, &sample).await?,
+        chat => {
+            let service = chat_service(chat).expect("chat channel");
+            let router = app
+                .try_state::<SharedNotificationRouter>()
+                .ok_or_else(|| "Chat integrations are unavailable".to_string())?;
+            let router = router.read().await;
+            router
+                .render_alert(&service, &sample)
+                .await
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .map(chat_target)
+                .collect()
+        }
+    };
+
+    Ok(NotificationPreview {
+        alert_id: alert.id,
+        channel,
+        sample,
+        targets,
+    })
+}
+
+/// Delivers the previewed notification to a sandbox target. Chat sandboxes are
+/// channels flagged as such; webhook sandboxes are webhooks with no event
+/// subscription, so neither ever receives live alerts.
+#[tauri::command]
+pub async fn send_notification_preview(
+    app: AppHandle,
+    manager: State<'_, SharedAlertManager>,
+    alert_id: String,
+    channel: NotificationChannel,
+    target_id: String,
+) -> Result<PreviewDeliveryResult, String> {
+    let (alert, sample) = sample_trigger(&manager, &alert_id).await?;
+
+    match &channel {
+        NotificationChannel::InApp | NotificationChannel::System => {
+            app.emit_all(PREVIEW_EVENT, &sample)
+                .map_err(|e| format!("Failed to emit preview: {}", e))?;
+            Ok(PreviewDeliveryResult {
+                success: true,
+                message: "Preview shown on this device".to_string(),
+                latency_ms: None,
+                error: None,
+            })
+        }
+        NotificationChannel::Webhook => {
+            let manager = app
+                .try_state::<SharedWebhookManager>()
+                .ok_or_else(|| "Webhooks are unavailable".to_string())?;
+            let manager = manager.read().await;
+            let config = manager
+                .get_webhook(&target_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            if !config.subscription.event_types.is_empty() {
+                return Err(format!(
+                    "{} receives live events and cannot be used as a sandbox",
+                    config.name
+                ));
+            }
+
+            let variables = alert_webhook_event(&alert, &sample).variables();
+            Ok(match manager.test_webhook(&target_id, variables).await {
+                Ok(result) => PreviewDeliveryResult {
+                    success: result.success,
+                    message: result.message,
+                    latency_ms: result.latency_ms.map(|ms| ms as u64),
+                    error: None,
+                },
+                Err(e) => PreviewDeliveryResult {
+                    success: false,
+                    message: "Failed to deliver preview".to_string(),
+                    latency_ms: None,
+                    error: Some(e.to_string()),
+                },
+            })
+        }
+        NotificationChannel::Email => Err("Price alerts are not delivered by email".to_string()),
+        // Phones have no sandbox; this is the same test push Settings sends
+        NotificationChannel::Push => {
+            let router = app
+                .try_state::<SharedNotificationRouter>()
+                .ok_or_else(|| "Push notifications are unavailable".to_string())?;
+            let router = router.read().await;
+            let (_, message) = alert_push_content(
+                &sample.alert_name,
+                &sample.symbol,
+                sample.current_price,
+                &sample.conditions_met,
+            );
+            let result = router
+                .test_push(&target_id, &message)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(PreviewDeliveryResult {
+                success: result.success,
+                message: result.message,
+                latency_ms: result.delivery_time,
+                error: result.error,
+            })
+        }
+        chat => {
+            let service = chat_service(chat).expect("chat channel");
+            let router = app
+                .try_state::<SharedNotificationRouter>()
+                .ok_or_else(|| "Chat integrations are unavailable".to_string())?;
+            let router = router.read().await;
+            let result = router
+                .send_alert_to_sandbox(&service, &target_id, &sample)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(PreviewDeliveryResult {
+                success: result.success,
+                message: result.message,
+                latency_ms: result.delivery_time,
+                error: result.error,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerts::price_alerts::{
+        AlertCondition, AlertState, CompoundCondition, LogicalOperator,
+    };
+
+    fn alert(conditions: Vec<(AlertConditionType, f64)>) -> PriceAlert {
+        PriceAlert {
+            id: "a1".into(),
+            name: "SOL breakout".into(),
+            symbol: "SOL".into(),
+            mint: "So11111111111111111111111111111111111111112".into(),
+            watchlist_id: None,
+            compound_condition: CompoundCondition {
+                conditions: conditions
+                    .into_iter()
+                    .map(|(condition_type, value)| AlertCondition {
+                        condition_type,
+                        value,
+                        timeframe_minutes: None,
+                    })
+                    .collect(),
+                operator: LogicalOperator::And,
+            },
+            notification_channels: vec![NotificationChannel::InApp],
+            cooldown_minutes: 5,
+            state: AlertState::Active,
+            last_triggered_at: None,
+            cooldown_until: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+            version: 1,
+            rearm_policy: Default::default(),
+        }
+    }
+
+    #[test]
+    fn sample_crosses_price_thresholds() {
+        let above = SampleMarketData::for_alert(&alert(vec![(AlertConditionType::Above, 200.0)]));
+        assert!(above.current_price > 200.0);
+
+        let below = SampleMarketData::for_alert(&alert(vec![(AlertConditionType::Below, 100.0)]));
+        assert!(below.current_price < 100.0);
+        assert_eq!(below.price_24h_ago, None);
+    }
+
+    #[test]
+    fn sample_exceeds_change_and_volume_thresholds() {
+        let sample = SampleMarketData::for_alert(&alert(vec![
+            (AlertConditionType::PercentChange, 10.0),
+            (AlertConditionType::VolumeSpike, 1_000_000.0),
+        ]));
+
+        let previous = sample.price_24h_ago.unwrap();
+        let change = (sample.current_price - previous) / previous * 100.0;
+        assert!(change >= 10.0);
+        assert!(sample.volume_24h.unwrap() >= 1_000_000.0);
+    }
+
+    #[test]
+    fn sandbox_chat_targets_never_receive_live_alerts() {
+        let target = chat_target(RenderedNotification {
+            target_id: "c1".into(),
+            target_name: "Preview channel".into(),
+            enabled: true,
+            sandbox: true,
+            payload: Value::Null,
+        });
+        assert!(!target.receives_live);
+        assert!(chat_service(&NotificationChannel::Email).is_none());
+    }
+}
@@ -7,6 +7,23 @@ use std::sync::Arc;
 use tauri::{AppHandle, Manager, State};
 use tokio::sync::RwLock;
 
+use super::alert_history::{AlertMarketContext, SharedAlertHistoryManager};
+use super::mute::{active_mute, AlertMuteSchedule};
+use super::rearm::{rearm_ready, AlertRearmPolicy, AlertTriggerMode};
+use crate::config::entity_versions::{
+    bump_entity_version, entity_version, initialize_entity_versions, VersionBump,
+    VersionConflict, VersionedEditError,
+};
+use crate::config::recycle_bin::{
+    discard_recycled, recycle_deleted, undo_unavailable, DeletedItem, RecycledEntityKind,
+};
+use crate::config::version_history::{record_config_change, ConfigEntity, LOCAL_ACTOR};
+use crate::core::events::{publish_domain_event, DomainEvent};
+use crate::data::event_store::{record_app_event, Event as AuditEvent};
+use crate::idempotency::run_idempotent;
+use crate::notifications::integration::queue_alert_notifications;
+use crate::webhooks::{queue_event, EventSeverity, WebhookEvent, WebhookEventType};
+
 const ALERTS_DB_FILE: &str = "price_alerts.db";
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -118,6 +135,8 @@ pub enum NotificationChannel {
     Telegram,
     Slack,
     Discord,
+    /// Encrypted push to registered phones, for alerts away from the desktop.
+    Push,
 }
 
 impl NotificationChannel {
@@ -130,6 +149,7 @@ impl NotificationChannel {
             NotificationChannel::Telegram => "telegram",
             NotificationChannel::Slack => "slack",
             NotificationChannel::Discord => "discord",
+            NotificationChannel::Push => "push",
         }
     }
 
@@ -142,6 +162,7 @@ impl NotificationChannel {
             "telegram" => Some(NotificationChannel::Telegram),
             "slack" => Some(NotificationChannel::Slack),
             "discord" => Some(NotificationChannel::Discord),
+            "push" => Some(NotificationChannel::Push),
             _ => None,
         }
     }
@@ -163,6 +184,11 @@ pub struct PriceAlert {
     pub cooldown_until: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    /// Incremented on every edit; pass it back to reject stale updates.
+    #[serde(default)]
+    pub version: i64,
+    #[serde(default)]
+    pub rearm_policy: AlertRearmPolicy,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -175,6 +201,8 @@ pub struct CreateAlertRequest {
     pub compound_condition: CompoundCondition,
     pub notification_channels: Vec<NotificationChannel>,
     pub cooldown_minutes: i32,
+    #[serde(default)]
+    pub rearm_policy: AlertRearmPolicy,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -185,6 +213,11 @@ pub struct UpdateAlertRequest {
     pub notification_channels: Option<Vec<NotificationChannel>>,
     pub cooldown_minutes: Option<i32>,
     pub state: Option<AlertState>,
+    #[serde(default)]
+    pub rearm_policy: Option<AlertRearmPolicy>,
+    /// Version the edit was based on; omit to overwrite unconditionally.
+    #[serde(default)]
+    pub expected_version: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -220,14 +253,25 @@ pub enum AlertError {
     NotFound(String),
     #[error("alert in cooldown until: {0}")]
     InCooldown(String),
+    #[error(transparent)]
+    Conflict(#[from] VersionConflict),
     #[error("internal error: {0}")]
     Internal(String),
 }
 
+impl From<AlertError> for VersionedEditError {
+    fn from(error: AlertError) -> Self {
+        match error {
+            AlertError::Conflict(conflict) => VersionedEditError::Conflict(conflict),
+            other => VersionedEditError::Message(other.to_string()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct AlertManager {
-    pool: Pool<Sqlite>,
-    app_handle: AppHandle,
+    pub(super) pool: Pool<Sqlite>,
+    pub(super) app_handle: AppHandle,
 }
 
 pub type SharedAlertManager = Arc<RwLock<AlertManager>>;
@@ -279,10 +323,15 @@ impl AlertManager {
         .execute(&self.pool)
         .await?;
 
+        self.initialize_mute_tables().await?;
+        self.initialize_rearm_tables().await?;
+        initialize_entity_versions(&self.pool).await?;
+
         Ok(())
     }
 
     pub async fn create_alert(&self, req: CreateAlertRequest) -> Result<PriceAlert, AlertError> {
+        req.rearm_policy.validate()?;
         let id = uuid::Uuid::new_v4().to_string();
         let now = Utc::now().to_rfc3339();
         
@@ -314,6 +363,7 @@ impl AlertManager {
         .bind(&now)
         .execute(&self.pool)
         .await?;
+        self.save_rearm_policy(&id, &req.rearm_policy).await?;
 
         Ok(PriceAlert {
             id,
@@ -329,6 +379,8 @@ impl AlertManager {
             cooldown_until: None,
             created_at: now.clone(),
             updated_at: now,
+            version: 1,
+            rearm_policy: req.rearm_policy,
         })
     }
 
@@ -347,7 +399,10 @@ impl AlertManager {
 
         let mut alerts = Vec::new();
         for row in rows {
-            alerts.push(self.row_to_alert(row)?);
+            let mut alert = self.row_to_alert(row)?;
+            alert.version = entity_version(&self.pool, &alert.id).await?;
+            self.load_rearm_policy(&mut alert).await?;
+            alerts.push(alert);
         }
 
         Ok(alerts)
@@ -368,7 +423,10 @@ impl AlertManager {
         .await?
         .ok_or_else(|| AlertError::NotFound(id.to_string()))?;
 
-        self.row_to_alert(row)
+        let mut alert = self.row_to_alert(row)?;
+        alert.version = entity_version(&self.pool, id).await?;
+        self.load_rearm_policy(&mut alert).await?;
+        Ok(alert)
     }
 
     pub async fn update_alert(
@@ -378,6 +436,24 @@ impl AlertManager {
     ) -> Result<PriceAlert, AlertError> {
         let mut alert = self.get_alert(id).await?;
         let now = Utc::now().to_rfc3339();
+        if let Some(policy) = &req.rearm_policy {
+            policy.validate()?;
+        }
+
+        alert.version = match bump_entity_version(&self.pool, id, req.expected_version).await? {
+            VersionBump::Bumped(version) => version,
+            VersionBump::Stale { latest } => {
+                let current = self.get_alert(id).await?;
+                return Err(VersionConflict::new(
+                    "alert",
+                    id,
+                    req.expected_version.unwrap_or_default(),
+                    latest,
+                    Some(&current),
+                )
+                .into());
+            }
+        };
 
         if let Some(name) = req.name {
             alert.name = name;
@@ -394,6 +470,10 @@ impl AlertManager {
         if let Some(state) = req.state {
             alert.state = state;
         }
+        if let Some(rearm_policy) = req.rearm_policy {
+            self.save_rearm_policy(id, &rearm_policy).await?;
+            alert.rearm_policy = rearm_policy;
+        }
 
         alert.updated_at = now.clone();
 
@@ -430,10 +510,83 @@ impl AlertManager {
         if result.rows_affected() == 0 {
             return Err(AlertError::NotFound(id.to_string()));
         }
+        self.delete_rearm_policy(id).await?;
 
         Ok(())
     }
 
+    /// Deletes an alert on the user's behalf. The alert goes to the recycle bin
+    /// first and is kept if that fails; undo and rollback use `delete_alert`.
+    pub async fn recycle_alert(&self, id: &str) -> Result<(PriceAlert, DeletedItem), AlertError> {
+        let alert = self.get_alert(id).await?;
+        let recycled =
+            recycle_deleted(&self.app_handle, RecycledEntityKind::Alert, id, &alert.name, &alert)
+                .await
+                .map_err(|e| AlertError::Internal(undo_unavailable(e)))?;
+        if let Err(err) = self.delete_alert(id).await {
+            discard_recycled(&self.app_handle, &recycled.id).await;
+            return Err(err);
+        }
+        Ok((alert, recycled))
+    }
+
+    /// Reverses `recycle_alert` and drops the recycled copy.
+    pub async fn unrecycle_alert(
+        &self,
+        alert: PriceAlert,
+        recycled_id: &str,
+    ) -> Result<PriceAlert, AlertError> {
+        let restored = self.restore_alert(alert).await?;
+        discard_recycled(&self.app_handle, recycled_id).await;
+        Ok(restored)
+    }
+
+    /// Writes a previously saved alert definition back, recreating it if it was deleted.
+    /// Trigger bookkeeping on a live alert is kept as is.
+    pub async fn restore_alert(&self, alert: PriceAlert) -> Result<PriceAlert, AlertError> {
+        let now = Utc::now().to_rfc3339();
+        let compound_condition_json = serde_json::to_string(&alert.compound_condition)?;
+        let channels_json = serde_json::to_string(&alert.notification_channels)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO price_alerts (
+                id, name, symbol, mint, watchlist_id, compound_condition,
+                notification_channels, cooldown_minutes, state,
+                last_triggered_at, cooldown_until, created_at, updated_at
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, NULL, NULL, ?10, ?11)
+            ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                symbol = excluded.symbol,
+                mint = excluded.mint,
+                watchlist_id = excluded.watchlist_id,
+                compound_condition = excluded.compound_condition,
+                notification_channels = excluded.notification_channels,
+                cooldown_minutes = excluded.cooldown_minutes,
+                state = excluded.state,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&alert.id)
+        .bind(&alert.name)
+        .bind(&alert.symbol)
+        .bind(&alert.mint)
+        .bind(&alert.watchlist_id)
+        .bind(&compound_condition_json)
+        .bind(&channels_json)
+        .bind(alert.cooldown_minutes)
+        .bind(alert.state.as_str())
+        .bind(&alert.created_at)
+        .bind(&now)
+        .execute(&self.pool)
+        .await?;
+        self.save_rearm_policy(&alert.id, &alert.rearm_policy).await?;
+        bump_entity_version(&self.pool, &alert.id, None).await?;
+
+        self.get_alert(&alert.id).await
+    }
+
     pub async fn test_alert(
         &self,
         id: &str,
@@ -473,18 +626,24 @@ impl AlertManager {
                    notification_channels, cooldown_minutes, state,
                    last_triggered_at, cooldown_until, created_at, updated_at
             FROM price_alerts
-            WHERE symbol = ?1 AND state = ?2
+            WHERE symbol = ?1 AND state IN (?2, ?3)
             "#,
         )
         .bind(symbol)
         .bind(AlertState::Active.as_str())
+        .bind(AlertState::Cooldown.as_str())
         .fetch_all(&self.pool)
         .await?;
 
+        let mute_schedules = self.list_mute_schedules().await?;
+        self.flush_mute_summaries(&mute_schedules, now).await?;
+
         let mut triggered_alerts = Vec::new();
 
         for row in rows {
-            let alert = self.row_to_alert(row)?;
+            let mut alert = self.row_to_alert(row)?;
+            let (rearm_policy, trigger_price) = self.rearm_state(&alert.id).await?;
+            alert.rearm_policy = rearm_policy;
 
             if let Some(cooldown_until_str) = &alert.cooldown_until {
                 if let Ok(cooldown_until) = DateTime::parse_from_rfc3339(cooldown_until_str) {
@@ -501,8 +660,28 @@ impl AlertManager {
                 volume_24h,
             );
 
+            // Cooled-down alerts re-arm here once the price has left the hysteresis band
+            if alert.state == AlertState::Cooldown {
+                if !rearm_ready(
+                    &alert.rearm_policy,
+                    &alert.compound_condition,
+                    trigger_price,
+                    current_price,
+                    would_trigger,
+                ) {
+                    continue;
+                }
+                self.rearm_alert(&alert.id).await?;
+            }
+
             if would_trigger {
-                self.trigger_alert(&alert, current_price, &message).await?;
+                let mute = active_mute(&mute_schedules, &alert.id, now);
+                let context = AlertMarketContext {
+                    price_24h_ago,
+                    volume_24h,
+                };
+                self.trigger_alert(&alert, current_price, &message, mute, &context)
+                    .await?;
                 triggered_alerts.push(alert.id.clone());
             }
         }
@@ -515,9 +694,18 @@ impl AlertManager {
         alert: &PriceAlert,
         current_price: f64,
         message: &str,
+        mute: Option<&AlertMuteSchedule>,
+        context: &AlertMarketContext,
     ) -> Result<(), AlertError> {
         let now = Utc::now();
-        let cooldown_until = now + Duration::minutes(alert.cooldown_minutes as i64);
+        // One-shot alerts stay triggered until edited; recurring ones cool down
+        let (state, cooldown_until) = match alert.rearm_policy.mode {
+            AlertTriggerMode::OneShot => (AlertState::Triggered, None),
+            AlertTriggerMode::Recurring => (
+                AlertState::Cooldown,
+                Some((now + Duration::minutes(alert.cooldown_minutes as i64)).to_rfc3339()),
+            ),
+        };
 
         sqlx::query(
             r#"
@@ -526,13 +714,26 @@ impl AlertManager {
             WHERE id = ?5
             "#,
         )
-        .bind(AlertState::Cooldown.as_str())
+        .bind(state.as_str())
         .bind(now.to_rfc3339())
-        .bind(cooldown_until.to_rfc3339())
+        .bind(cooldown_until)
         .bind(now.to_rfc3339())
         .bind(&alert.id)
         .execute(&self.pool)
         .await?;
+        self.record_trigger_price(alert, current_price).await?;
+        record_app_event(
+            &self.app_handle,
+            &format!("alert_{}", alert.id),
+            AuditEvent::AlertTriggered {
+                alert_id: alert.id.clone(),
+                alert_name: alert.name.clone(),
+                symbol: alert.symbol.clone(),
+                price: current_price,
+                timestamp: now,
+            },
+        )
+        .await;
 
         let event = AlertTriggerEvent {
             alert_id: alert.id.clone(),
@@ -543,34 +744,98 @@ impl AlertManager {
             triggered_at: now.to_rfc3339(),
         };
 
+        if let Some(history) = self.app_handle.try_state::<SharedAlertHistoryManager>() {
+            let recorded = history
+                .read()
+                .await
+                .record_triggered_alert(alert, current_price, message, context, mute.is_some())
+                .await;
+            if let Err(err) = recorded {
+                tracing::warn!(
+                    alert_id = %alert.id,
+                    error = %err,
+                    "failed to record alert history"
+                );
+            }
+        }
+
+        // Published before the mute check: a muted trigger still happened
+        publish_domain_event(
+            "price_alerts",
+            DomainEvent::AlertTriggered {
+                alert_id: alert.id.clone(),
+                alert_name: alert.name.clone(),
+                mint: alert.mint.clone(),
+                symbol: alert.symbol.clone(),
+                price: current_price,
+                triggered_at: event.triggered_at.clone(),
+            },
+        );
+
+        // Muted triggers still start the cooldown so the window isn't flooded
+        if let Some(schedule) = mute {
+            return self.record_muted_trigger(schedule, &event).await;
+        }
+
+        let webhook_event = alert_webhook_event(alert, &event);
+
         self.app_handle
-            .emit_all("alert_triggered", event)
+            .emit_all("alert_triggered", event.clone())
             .map_err(|e| AlertError::Internal(format!("Failed to emit event: {}", e)))?;
 
+        // Keyed by trigger time so a redelivered trigger reaches subscribers once
+        let dedupe_key = format!("alert:{}:{}", alert.id, event.triggered_at);
+        queue_event(&self.app_handle, webhook_event, Some(&dedupe_key)).await;
+        queue_alert_notifications(&self.app_handle, &event, &alert.notification_channels).await;
+
         Ok(())
     }
 
+    /// Re-arms expired cooldowns. Alerts with hysteresis or a reset condition are
+    /// left for `check_and_trigger_alerts`, which sees the price.
     pub async fn reset_cooldowns(&self) -> Result<usize, AlertError> {
         let now = Utc::now().to_rfc3339();
         
-        let result = sqlx::query(
+        let expired = sqlx::query(
+            r#"
+            SELECT id FROM price_alerts
+            WHERE state = ?1 AND (cooldown_until IS NULL OR cooldown_until <= ?2)
+            "#,
+        )
+        .bind(AlertState::Cooldown.as_str())
+        .bind(&now)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut reset = 0;
+        for row in expired {
+            let id: String = row.try_get("id")?;
+            if self.rearm_state(&id).await?.0.rearms_on_cooldown() {
+                self.rearm_alert(&id).await?;
+                reset += 1;
+            }
+        }
+
+        Ok(reset)
+    }
+
+    async fn rearm_alert(&self, id: &str) -> Result<(), AlertError> {
+        sqlx::query(
             r#"
             UPDATE price_alerts
             SET state = ?1, cooldown_until = NULL, updated_at = ?2
-            WHERE state = ?3 AND (cooldown_until IS NULL OR cooldown_until <= ?4)
+            WHERE id = ?3
             "#,
         )
         .bind(AlertState::Active.as_str())
-        .bind(&now)
-        .bind(AlertState::Cooldown.as_str())
-        .bind(&now)
+        .bind(Utc::now().to_rfc3339())
+        .bind(id)
         .execute(&self.pool)
         .await?;
-
-        Ok(result.rows_affected() as usize)
+        Ok(())
     }
 
-    fn evaluate_conditions(
+    pub(super) fn evaluate_conditions(
         &self,
         compound: &CompoundCondition,
         current_price: f64,
@@ -670,10 +935,23 @@ impl AlertManager {
             cooldown_until: row.try_get("cooldown_until")?,
             created_at: row.try_get("created_at")?,
             updated_at: row.try_get("updated_at")?,
+            version: 1,
+            rearm_policy: AlertRearmPolicy::default(),
         })
     }
 }
 
+pub(super) fn alert_webhook_event(alert: &PriceAlert, event: &AlertTriggerEvent) -> WebhookEvent {
+    WebhookEvent::new(
+        WebhookEventType::PriceAlert,
+        EventSeverity::Warning,
+        format!("{} triggered", alert.name),
+        event.conditions_met.clone(),
+    )
+    .with_token(alert.mint.clone())
+    .with_data(serde_json::to_value(event).unwrap_or_default())
+}
+
 fn alerts_db_path(app: &AppHandle) -> Result<PathBuf, AlertError> {
     let app_data_dir = app
         .path_resolver()
@@ -687,11 +965,19 @@ fn alerts_db_path(app: &AppHandle) -> Result<PathBuf, AlertError> {
 // Tauri commands
 #[tauri::command]
 pub async fn alert_create(
+    app_handle: AppHandle,
     manager: State<'_, SharedAlertManager>,
     req: CreateAlertRequest,
+    idempotency_key: Option<String>,
 ) -> Result<PriceAlert, String> {
     let mgr = manager.read().await;
-    mgr.create_alert(req).await.map_err(|e| e.to_string())
+    run_idempotent("alert_create", idempotency_key.as_deref(), &req, || async {
+        let alert = mgr.create_alert(req.clone()).await.map_err(|e| e.to_string())?;
+        record_config_change(&app_handle, ConfigEntity::alert(&alert.id), Some(&alert), LOCAL_ACTOR)
+            .await;
+        Ok(alert)
+    })
+    .await
 }
 
 #[tauri::command]
@@ -713,21 +999,26 @@ pub async fn alert_get(
 
 #[tauri::command]
 pub async fn alert_update(
+    app_handle: AppHandle,
     manager: State<'_, SharedAlertManager>,
     id: String,
     req: UpdateAlertRequest,
-) -> Result<PriceAlert, String> {
+) -> Result<PriceAlert, VersionedEditError> {
     let mgr = manager.read().await;
-    mgr.update_alert(&id, req).await.map_err(|e| e.to_string())
+    let alert = mgr.update_alert(&id, req).await?;
+    record_config_change(&app_handle, ConfigEntity::alert(&alert.id), Some(&alert), LOCAL_ACTOR).await;
+    Ok(alert)
 }
 
 #[tauri::command]
 pub async fn alert_delete(
+    app_handle: AppHandle,
     manager: State<'_, SharedAlertManager>,
     id: String,
 ) -> Result<(), String> {
-    let mgr = manager.read().await;
-    mgr.delete_alert(&id).await.map_err(|e| e.to_string())
+    manager.read().await.recycle_alert(&id).await.map_err(|e| e.to_string())?;
+    record_config_change::<PriceAlert>(&app_handle, ConfigEntity::alert(&id), None, LOCAL_ACTOR).await;
+    Ok(())
 }
 
 #[tauri::command]
@@ -0,0 +1,292 @@
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+
+use super::price_alerts::{
+    AlertConditionType, AlertError, AlertManager, CompoundCondition, LogicalOperator, PriceAlert,
+};
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertTriggerMode {
+    /// Fires once and stays in the triggered state until edited.
+    OneShot,
+    #[default]
+    Recurring,
+}
+
+/// When a recurring alert may fire again after its cooldown. With the defaults
+/// it re-arms as soon as the cooldown ends.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRearmPolicy {
+    #[serde(default)]
+    pub mode: AlertTriggerMode,
+    /// Distance in percent the price must move back past a threshold, or away from
+    /// the trigger price for non-threshold conditions, before re-arming.
+    #[serde(default)]
+    pub hysteresis_percent: f64,
+    /// The conditions must also be seen false after the cooldown.
+    #[serde(default)]
+    pub require_reset: bool,
+}
+
+impl AlertRearmPolicy {
+    pub(super) fn validate(&self) -> Result<(), AlertError> {
+        if !(0.0..=100.0).contains(&self.hysteresis_percent) {
+            return Err(AlertError::Internal(
+                "Hysteresis must be between 0 and 100 percent".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether the cooldown alone is enough, so re-arming needs no market data.
+    pub fn rearms_on_cooldown(&self) -> bool {
+        self.hysteresis_percent <= 0.0 && !self.require_reset
+    }
+}
+
+/// Whether the price has cleared a single condition by the hysteresis band.
+fn condition_cleared(
+    condition_type: &AlertConditionType,
+    value: f64,
+    band: f64,
+    current_price: f64,
+    trigger_price: Option<f64>,
+) -> bool {
+    match condition_type {
+        AlertConditionType::Above => current_price <= value * (1.0 - band),
+        AlertConditionType::Below => current_price >= value * (1.0 + band),
+        AlertConditionType::PercentChange | AlertConditionType::VolumeSpike => {
+            match trigger_price {
+                Some(trigger) if trigger > 0.0 => {
+                    ((current_price - trigger) / trigger).abs() >= band
+                }
+                _ => true,
+            }
+        }
+    }
+}
+
+/// Decides whether an alert whose cooldown has ended may be armed again.
+/// `conditions_met` is the plain evaluation at `current_price`.
+pub fn rearm_ready(
+    policy: &AlertRearmPolicy,
+    compound: &CompoundCondition,
+    trigger_price: Option<f64>,
+    current_price: f64,
+    conditions_met: bool,
+) -> bool {
+    if policy.mode == AlertTriggerMode::OneShot {
+        return false;
+    }
+    if policy.require_reset && conditions_met {
+        return false;
+    }
+    if policy.hysteresis_percent <= 0.0 || compound.conditions.is_empty() {
+        return true;
+    }
+
+    let band = policy.hysteresis_percent / 100.0;
+    let mut cleared = compound.conditions.iter().map(|condition| {
+        condition_cleared(
+            &condition.condition_type,
+            condition.value,
+            band,
+            current_price,
+            trigger_price,
+        )
+    });
+    // An AND alert is off once any condition clears, an OR alert only once all do
+    match compound.operator {
+        LogicalOperator::And => cleared.any(|c| c),
+        LogicalOperator::Or => cleared.all(|c| c),
+    }
+}
+
+impl AlertManager {
+    pub(super) async fn initialize_rearm_tables(&self) -> Result<(), AlertError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_rearm_policies (
+                alert_id TEXT PRIMARY KEY NOT NULL,
+                policy_json TEXT NOT NULL,
+                trigger_price REAL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Policy and last trigger price; alerts without a row use the defaults.
+    pub(super) async fn rearm_state(
+        &self,
+        alert_id: &str,
+    ) -> Result<(AlertRearmPolicy, Option<f64>), AlertError> {
+        let row = sqlx::query(
+            "SELECT policy_json, trigger_price FROM alert_rearm_policies WHERE alert_id = ?1",
+        )
+        .bind(alert_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => {
+                let policy_json: String = row.try_get("policy_json")?;
+                Ok((
+                    serde_json::from_str(&policy_json)?,
+                    row.try_get("trigger_price")?,
+                ))
+            }
+            None => Ok((AlertRearmPolicy::default(), None)),
+        }
+    }
+
+    pub(super) async fn load_rearm_policy(&self, alert: &mut PriceAlert) -> Result<(), AlertError> {
+        alert.rearm_policy = self.rearm_state(&alert.id).await?.0;
+        Ok(())
+    }
+
+    pub(super) async fn save_rearm_policy(
+        &self,
+        alert_id: &str,
+        policy: &AlertRearmPolicy,
+    ) -> Result<(), AlertError> {
+        policy.validate()?;
+        sqlx::query(
+            r#"
+            INSERT INTO alert_rearm_policies (alert_id, policy_json)
+            VALUES (?1, ?2)
+            ON CONFLICT(alert_id) DO UPDATE SET policy_json = excluded.policy_json
+            "#,
+        )
+        .bind(alert_id)
+        .bind(serde_json::to_string(policy)?)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(super) async fn record_trigger_price(
+        &self,
+        alert: &PriceAlert,
+        price: f64,
+    ) -> Result<(), AlertError> {
+        sqlx::query(
+            r#"
+            INSERT INTO alert_rearm_policies (alert_id, policy_json, trigger_price)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(alert_id) DO UPDATE SET trigger_price = excluded.trigger_price
+            "#,
+        )
+        .bind(&alert.id)
+        .bind(serde_json::to_string(&alert.rearm_policy)?)
+        .bind(price)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub(super) async fn delete_rearm_policy(&self, alert_id: &str) -> Result<(), AlertError> {
+        sqlx::query("DELETE FROM alert_rearm_policies WHERE alert_id = ?1")
+            .bind(alert_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerts::price_alerts::AlertCondition;
+
+    fn compound(
+        conditions: Vec<(AlertConditionType, f64)>,
+        operator: LogicalOperator,
+    ) -> CompoundCondition {
+        CompoundCondition {
+            conditions: conditions
+                .into_iter()
+                .map(|(condition_type, value)| AlertCondition {
+                    condition_type,
+                    value,
+                    timeframe_minutes: None,
+                })
+                .collect(),
+            operator,
+        }
+    }
+
+    fn hysteresis(percent: f64) -> AlertRearmPolicy {
+        AlertRearmPolicy {
+            hysteresis_percent: percent,
+            ..AlertRearmPolicy::default()
+        }
+    }
+
+    #[test]
+    fn threshold_alerts_rearm_only_outside_the_band() {
+        let above = compound(
+            vec![(AlertConditionType::Above, 100.0)],
+            LogicalOperator::And,
+        );
+        let policy = hysteresis(2.0);
+
+        assert!(!rearm_ready(&policy, &above, Some(101.0), 99.0, false));
+        assert!(rearm_ready(&policy, &above, Some(101.0), 97.5, false));
+
+        let below = compound(
+            vec![(AlertConditionType::Below, 50.0)],
+            LogicalOperator::And,
+        );
+        assert!(!rearm_ready(&policy, &below, Some(49.0), 50.5, false));
+        assert!(rearm_ready(&policy, &below, Some(49.0), 51.5, false));
+    }
+
+    #[test]
+    fn reset_condition_and_one_shot_block_rearming() {
+        let above = compound(
+            vec![(AlertConditionType::Above, 100.0)],
+            LogicalOperator::And,
+        );
+        let reset = AlertRearmPolicy {
+            require_reset: true,
+            ..AlertRearmPolicy::default()
+        };
+        assert!(!rearm_ready(&reset, &above, Some(101.0), 102.0, true));
+        assert!(rearm_ready(&reset, &above, Some(101.0), 99.0, false));
+        assert!(!reset.rearms_on_cooldown());
+
+        let one_shot = AlertRearmPolicy {
+            mode: AlertTriggerMode::OneShot,
+            ..AlertRearmPolicy::default()
+        };
+        assert!(!rearm_ready(&one_shot, &above, Some(101.0), 50.0, false));
+        assert!(AlertRearmPolicy::default().rearms_on_cooldown());
+    }
+
+    #[test]
+    fn or_alerts_need_every_condition_cleared() {
+        let band = compound(
+            vec![
+                (AlertConditionType::Above, 110.0),
+                (AlertConditionType::Below, 90.0),
+            ],
+            LogicalOperator::Or,
+        );
+        let policy = hysteresis(5.0);
+
+        assert!(!rearm_ready(&policy, &band, Some(111.0), 106.0, false));
+        assert!(rearm_ready(&policy, &band, Some(111.0), 100.0, false));
+
+        let spike = compound(
+            vec![(AlertConditionType::PercentChange, 10.0)],
+            LogicalOperator::And,
+        );
+        assert!(!rearm_ready(&policy, &spike, Some(100.0), 103.0, false));
+        assert!(rearm_ready(&policy, &spike, Some(100.0), 94.0, false));
+    }
+}
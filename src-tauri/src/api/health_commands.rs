@@ -1,23 +1,43 @@
 use super::health_monitor::{ApiHealthDashboard, ApiHealthMetrics, SharedApiHealthMonitor};
+use crate::api_config::ApiConfigManager;
+use crate::security::keystore::Keystore;
 use tauri::State;
 
+fn mark_failover(metrics: &mut ApiHealthMetrics, keystore: &Keystore, config_manager: &ApiConfigManager) {
+    metrics.failover_active = config_manager
+        .failover_status(keystore)
+        .iter()
+        .any(|status| status.service == metrics.service_name && status.failover.is_some());
+}
+
 #[tauri::command]
 pub async fn get_api_health_dashboard(
     monitor: State<'_, SharedApiHealthMonitor>,
+    keystore: State<'_, Keystore>,
+    config_manager: State<'_, ApiConfigManager>,
 ) -> Result<ApiHealthDashboard, String> {
     let mon = monitor.read().await;
-    mon.get_dashboard().await.map_err(|e| e.to_string())
+    let mut dashboard = mon.get_dashboard().await.map_err(|e| e.to_string())?;
+    for metrics in dashboard.services.values_mut() {
+        mark_failover(metrics, &keystore, &config_manager);
+    }
+    Ok(dashboard)
 }
 
 #[tauri::command]
 pub async fn get_service_health_metrics(
     monitor: State<'_, SharedApiHealthMonitor>,
+    keystore: State<'_, Keystore>,
+    config_manager: State<'_, ApiConfigManager>,
     service_name: String,
 ) -> Result<ApiHealthMetrics, String> {
     let mon = monitor.read().await;
-    mon.get_metrics(&service_name)
+    let mut metrics = mon
+        .get_metrics(&service_name)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    mark_failover(&mut metrics, &keystore, &config_manager);
+    Ok(metrics)
 }
 
 #[tauri::command]
@@ -8,7 +8,11 @@ use std::time::Duration;
 use thiserror::Error;
 use tracing::{debug, instrument, warn};
 
+use crate::idempotency::run_idempotent_within;
+
 const JUPITER_BASE_URL: &str = "https://quote-api.jup.ag/v6";
+/// Roughly how long a freshly built swap transaction's blockhash stays valid.
+const SWAP_IDEMPOTENCY_WINDOW_SECS: i64 = 60;
 
 #[derive(Debug, Error)]
 pub enum JupiterError {
@@ -24,6 +28,27 @@ pub enum JupiterError {
     MissingQuote,
 }
 
+/// Error codes Jupiter answers with when it has no way to fill the order.
+const NO_ROUTE_ERROR_CODES: &[&str] = &[
+    "COULD_NOT_FIND_ANY_ROUTE",
+    "NO_ROUTES_FOUND",
+    "TOKEN_NOT_TRADABLE",
+];
+
+impl JupiterError {
+    /// Whether Jupiter answered that no route exists, as opposed to the
+    /// request itself failing.
+    pub fn is_no_route(&self) -> bool {
+        match self {
+            JupiterError::Http { status, body } => {
+                status.is_client_error()
+                    && NO_ROUTE_ERROR_CODES.iter().any(|code| body.contains(code))
+            }
+            _ => false,
+        }
+    }
+}
+
 impl From<JupiterError> for String {
     fn from(value: JupiterError) -> Self {
         value.to_string()
@@ -143,6 +168,16 @@ pub struct QuoteResponse {
     pub route_plan: Vec<RoutePlanStep>,
     #[serde(default)]
     pub prioritization_fee_lamports: Option<String>,
+    #[serde(default)]
+    pub platform_fee: Option<PlatformFee>,
+}
+
+/// Fee taken by the integrator, in base units of the fee mint.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlatformFee {
+    pub amount: String,
+    pub fee_bps: u16,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -307,21 +342,46 @@ pub async fn jupiter_quote(input: QuoteCommandInput) -> Result<QuoteResult, Stri
 }
 
 #[tauri::command]
-#[instrument(skip(input), fields(user = %input.user_public_key))]
-pub async fn jupiter_swap(input: SwapCommandInput) -> Result<SwapResult, String> {
+#[instrument(skip(input, idempotency_key), fields(user = %input.user_public_key))]
+pub async fn jupiter_swap(
+    input: SwapCommandInput,
+    idempotency_key: Option<String>,
+) -> Result<SwapResult, String> {
+    // The unsigned transaction embeds a recent blockhash, so a replay is only
+    // useful while that blockhash can still land.
+    run_idempotent_within(
+        "jupiter_swap",
+        idempotency_key.as_deref(),
+        &input,
+        chrono::Duration::seconds(SWAP_IDEMPOTENCY_WINDOW_SECS),
+        || execute_jupiter_swap(&input),
+    )
+    .await
+}
+
+async fn execute_jupiter_swap(input: &SwapCommandInput) -> Result<SwapResult, String> {
     if input.quote.route_plan.is_empty() {
         return Err(JupiterError::MissingQuote.into());
     }
 
     let client = JupiterClient::default();
     let response = client
-        .execute_swap(&input, input.simulate.unwrap_or(false))
+        .execute_swap(input, input.simulate.unwrap_or(false))
         .await?;
     let swap_transaction = response
         .swap_transaction
         .ok_or_else(|| JupiterError::InvalidResponse("missing transaction".into()))?;
 
+    let swap_transaction =
+        match crate::wallet::compute_budget::tune_swap_transaction(&swap_transaction).await {
+            Some(tuned) => tuned,
+            None => swap_transaction,
+        };
     let transaction = decode_versioned_transaction(&swap_transaction)?;
+    if !input.simulate.unwrap_or(false) {
+        crate::wallet::fee_disclosure::record_swap_fees(input, &parse_route_plan(&input.quote))
+            .await;
+    }
     let simulation = response.simulation_logs.map(|logs| SwapSimulationResult {
         logs,
         compute_units_consumed: response.compute_units_consumed,
@@ -395,7 +455,10 @@ fn decode_versioned_transaction(encoded: &str) -> Result<EncodedTransaction, Str
 }
 
 impl JupiterClient {
-    async fn quote(&self, input: &QuoteCommandInput) -> Result<QuoteResponse, JupiterError> {
+    pub(crate) async fn quote(
+        &self,
+        input: &QuoteCommandInput,
+    ) -> Result<QuoteResponse, JupiterError> {
         let amount = input.amount.to_string();
         let swap_mode = input.swap_mode.unwrap_or_default();
         let params = QuoteQueryParams {
@@ -521,6 +584,7 @@ mod tests {
                 percent: 100.0,
             }],
             prioritization_fee_lamports: Some("2000".into()),
+            platform_fee: None,
         }
     }
 
@@ -604,6 +668,23 @@ mod tests {
         assert_eq!(result.compute_units_consumed, Some(50000));
     }
 
+    #[test]
+    fn no_route_is_told_apart_from_failed_requests() {
+        let no_route = JupiterError::Http {
+            status: StatusCode::BAD_REQUEST,
+            body: r#"{"error":"Could not find any route","errorCode":"COULD_NOT_FIND_ANY_ROUTE"}"#
+                .into(),
+        };
+        assert!(no_route.is_no_route());
+
+        let rate_limited = JupiterError::Http {
+            status: StatusCode::TOO_MANY_REQUESTS,
+            body: "Too many requests".into(),
+        };
+        assert!(!rate_limited.is_no_route());
+        assert!(!JupiterError::Network("timed out".into()).is_no_route());
+    }
+
     fn dummy_versioned_tx() -> solana_sdk::transaction::VersionedTransaction {
         use solana_sdk::{
             instruction::CompiledInstruction,
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{debug, instrument};
 
+const DEFAULT_RPC_ENDPOINT: &str = "https://api.mainnet-beta.solana.com";
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MEVProtectionConfig {
@@ -49,16 +52,90 @@ pub struct MEVProtectionResult {
 #[tauri::command]
 #[instrument]
 pub async fn get_network_congestion() -> Result<CongestionData, String> {
-    // In a real implementation, this would query the Solana RPC for recent priority fees
-    // For now, we'll return mock data that simulates congestion analysis
-
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map_err(|e| format!("Time error: {}", e))?
         .as_secs();
 
-    // Simulate varying congestion based on time
-    // In production, this would analyze actual recent transactions
+    let congestion = if crate::demo::is_demo_mode() {
+        simulated_congestion(timestamp)
+    } else {
+        congestion_from_fees(recent_prioritization_fees().await?, timestamp)
+    };
+
+    debug!(
+        "Network congestion: level={}, avg_fee={}, median_fee={}",
+        congestion.level, congestion.average_fee, congestion.median_fee
+    );
+    Ok(congestion)
+}
+
+/// Priority fees (micro-lamports per compute unit) paid in recent slots.
+async fn recent_prioritization_fees() -> Result<Vec<u64>, String> {
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getRecentPrioritizationFees",
+        "params": [],
+    });
+
+    let data: Value = reqwest::Client::new()
+        .post(DEFAULT_RPC_ENDPOINT)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| format!("RPC request failed: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+    if let Some(error) = data.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+
+    Ok(data["result"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry["prioritizationFee"].as_u64())
+        .collect())
+}
+
+fn congestion_from_fees(mut fees: Vec<u64>, timestamp: u64) -> CongestionData {
+    fees.sort_unstable();
+    let percentile = |p: usize| {
+        if fees.is_empty() {
+            0
+        } else {
+            fees[((fees.len() - 1) * p) / 100]
+        }
+    };
+    let median_fee = percentile(50);
+    let average_fee = if fees.is_empty() {
+        0
+    } else {
+        fees.iter().sum::<u64>() / fees.len() as u64
+    };
+    let level = if median_fee >= 10_000 {
+        "high"
+    } else if median_fee >= 3_000 {
+        "medium"
+    } else {
+        "low"
+    };
+
+    CongestionData {
+        level: level.to_string(),
+        average_fee,
+        median_fee,
+        percentile_75: percentile(75),
+        percentile_95: percentile(95),
+        timestamp,
+    }
+}
+
+/// Demo mode only: congestion follows the hour of day.
+fn simulated_congestion(timestamp: u64) -> CongestionData {
     let hour = (timestamp / 3600) % 24;
     let (level, avg_fee, median_fee) = if hour >= 14 && hour <= 18 {
         // Peak hours - high congestion
@@ -71,37 +148,33 @@ pub async fn get_network_congestion() -> Result<CongestionData, String> {
         ("low".to_string(), 2000u64, 1500u64)
     };
 
-    let percentile_75 = median_fee + (median_fee / 4);
-    let percentile_95 = median_fee * 2;
-
-    debug!(
-        "Network congestion: level={}, avg_fee={}, median_fee={}",
-        level, avg_fee, median_fee
-    );
-
-    Ok(CongestionData {
+    CongestionData {
         level,
         average_fee: avg_fee,
         median_fee,
-        percentile_75,
-        percentile_95,
+        percentile_75: median_fee + (median_fee / 4),
+        percentile_95: median_fee * 2,
         timestamp,
-    })
+    }
 }
 
 /// Get priority fee estimates for different presets
 #[tauri::command]
 #[instrument]
 pub async fn get_priority_fee_estimates() -> Result<Vec<PriorityFeeEstimate>, String> {
-    let congestion = get_network_congestion().await?;
+    let estimates = fee_estimates(&get_network_congestion().await?);
+    debug!("Priority fee estimates: {:?}", estimates);
+    Ok(estimates)
+}
 
+fn fee_estimates(congestion: &CongestionData) -> Vec<PriorityFeeEstimate> {
     let multiplier = match congestion.level.as_str() {
         "high" => 2.0,
         "low" => 0.75,
         _ => 1.0,
     };
 
-    let estimates = vec![
+    vec![
         PriorityFeeEstimate {
             preset: "slow".to_string(),
             micro_lamports: (1000.0 * multiplier) as u64,
@@ -117,10 +190,7 @@ pub async fn get_priority_fee_estimates() -> Result<Vec<PriorityFeeEstimate>, St
             micro_lamports: (10000.0 * multiplier) as u64,
             estimated_confirmation_time: "5-10s".to_string(),
         },
-    ];
-
-    debug!("Priority fee estimates: {:?}", estimates);
-    Ok(estimates)
+    ]
 }
 
 /// Submit transaction with MEV protection
@@ -213,23 +283,30 @@ pub async fn validate_trade_thresholds(
 mod tests {
     use super::*;
 
-    #[tokio::test]
-    async fn test_get_network_congestion() {
-        let result = get_network_congestion().await;
-        assert!(result.is_ok());
-
-        let congestion = result.unwrap();
+    #[test]
+    fn test_simulated_congestion() {
+        let congestion = simulated_congestion(1_700_000_000);
         assert!(["low", "medium", "high"].contains(&congestion.level.as_str()));
         assert!(congestion.average_fee > 0);
         assert!(congestion.median_fee > 0);
     }
 
-    #[tokio::test]
-    async fn test_get_priority_fee_estimates() {
-        let result = get_priority_fee_estimates().await;
-        assert!(result.is_ok());
+    #[test]
+    fn test_congestion_from_recent_fees() {
+        let congestion = congestion_from_fees(vec![0, 12_000, 4_000, 5_000, 100], 1);
+        assert_eq!(congestion.level, "medium");
+        assert_eq!(congestion.median_fee, 4_000);
+        assert_eq!(congestion.average_fee, 4_220);
+        assert_eq!(congestion.percentile_95, 5_000);
+
+        let idle = congestion_from_fees(Vec::new(), 1);
+        assert_eq!(idle.level, "low");
+        assert_eq!(idle.median_fee, 0);
+    }
 
-        let estimates = result.unwrap();
+    #[test]
+    fn test_get_priority_fee_estimates() {
+        let estimates = fee_estimates(&simulated_congestion(1_700_000_000));
         assert_eq!(estimates.len(), 3);
         assert_eq!(estimates[0].preset, "slow");
         assert_eq!(estimates[1].preset, "normal");
@@ -1,10 +1,10 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Manager, State};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -63,6 +63,8 @@ pub enum AlertType {
     LimitExceeded,
     HighLatency,
     HighErrorRate,
+    ProjectedOverage,
+    BudgetExceeded,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,21 +78,182 @@ pub struct FairUseLimit {
     pub reset_at: DateTime<Utc>,
 }
 
+/// Monthly budget for one service. Either limit may be set; the tighter one applies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageBudget {
+    pub service: String,
+    pub monthly_request_limit: Option<u64>,
+    pub monthly_cost_limit: Option<f64>,
+    /// Refuse every request once the budget is used up.
+    #[serde(default)]
+    pub hard_cap: bool,
+    /// Hold back background requests once usage passes `throttle_at_percent`.
+    #[serde(default)]
+    pub throttle_background: bool,
+    #[serde(default = "default_throttle_percent")]
+    pub throttle_at_percent: f64,
+}
+
+fn default_throttle_percent() -> f64 {
+    90.0
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RequestPriority {
+    Interactive,
+    Background,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "decision", content = "reason")]
+pub enum BudgetDecision {
+    Allow,
+    Throttle(String),
+    Block(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetStatus {
+    NoBudget,
+    OnTrack,
+    ProjectedOverage,
+    NearCap,
+    Exceeded,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageForecast {
+    pub service: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub requests_to_date: u64,
+    pub cost_to_date: f64,
+    pub projected_requests: u64,
+    pub projected_cost: f64,
+    pub budget: Option<UsageBudget>,
+    /// Share of the budget used so far, against whichever limit is tighter.
+    pub budget_used_percent: Option<f64>,
+    pub projected_budget_percent: Option<f64>,
+    pub status: BudgetStatus,
+    pub throttling_background: bool,
+}
+
 pub struct ApiUsageTracker {
     usage_log: Arc<Mutex<Vec<ApiUsageRecord>>>,
     fair_use_limits: Arc<Mutex<HashMap<String, FairUseLimit>>>,
+    budgets: Arc<Mutex<HashMap<String, UsageBudget>>>,
     data_path: PathBuf,
 }
 
+/// Example rates per call, used for cost estimates and cost budgets.
+fn cost_per_call(service: &str) -> f64 {
+    match service {
+        "helius" => 0.01 / 1000.0,
+        "birdeye" => 0.02 / 1000.0,
+        "jupiter" => 0.005 / 1000.0,
+        "solana_rpc" => 0.001 / 1000.0,
+        _ => 0.0,
+    }
+}
+
+fn month_bounds(now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start = Utc
+        .with_ymd_and_hms(now.year(), now.month(), 1, 0, 0, 0)
+        .single()
+        .unwrap_or(now);
+    let (next_year, next_month) = if now.month() == 12 {
+        (now.year() + 1, 1)
+    } else {
+        (now.year(), now.month() + 1)
+    };
+    let end = Utc
+        .with_ymd_and_hms(next_year, next_month, 1, 0, 0, 0)
+        .single()
+        .unwrap_or(now);
+    (start, end)
+}
+
+/// Straight-line projection of month-to-date usage over the whole period. The first
+/// hour is treated as a full hour so a handful of early calls doesn't explode the rate.
+pub fn project_usage(
+    to_date: u64,
+    period_start: DateTime<Utc>,
+    period_end: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> u64 {
+    let elapsed = (now - period_start).num_seconds().max(3600) as f64;
+    let total = (period_end - period_start).num_seconds().max(1) as f64;
+    if elapsed >= total {
+        return to_date;
+    }
+    (to_date as f64 * total / elapsed).round() as u64
+}
+
+/// Percentage of the budget the given usage represents, against the tighter limit.
+fn budget_percent(budget: &UsageBudget, requests: u64, cost: f64) -> Option<f64> {
+    let by_requests = budget
+        .monthly_request_limit
+        .filter(|limit| *limit > 0)
+        .map(|limit| requests as f64 / limit as f64 * 100.0);
+    let by_cost = budget
+        .monthly_cost_limit
+        .filter(|limit| *limit > 0.0)
+        .map(|limit| cost / limit * 100.0);
+
+    match (by_requests, by_cost) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn budget_status(budget: &UsageBudget, used: Option<f64>, projected: Option<f64>) -> BudgetStatus {
+    match (used, projected) {
+        (None, _) => BudgetStatus::NoBudget,
+        (Some(used), _) if used >= 100.0 => BudgetStatus::Exceeded,
+        (Some(used), _) if used >= budget.throttle_at_percent => BudgetStatus::NearCap,
+        (_, Some(projected)) if projected > 100.0 => BudgetStatus::ProjectedOverage,
+        _ => BudgetStatus::OnTrack,
+    }
+}
+
+pub fn evaluate_budget(
+    budget: &UsageBudget,
+    used_percent: f64,
+    priority: RequestPriority,
+) -> BudgetDecision {
+    if budget.hard_cap && used_percent >= 100.0 {
+        return BudgetDecision::Block(format!(
+            "{} monthly budget exhausted ({:.0}%)",
+            budget.service, used_percent
+        ));
+    }
+    if budget.throttle_background
+        && priority == RequestPriority::Background
+        && used_percent >= budget.throttle_at_percent
+    {
+        return BudgetDecision::Throttle(format!(
+            "{} at {:.0}% of monthly budget; background requests paused",
+            budget.service, used_percent
+        ));
+    }
+    BudgetDecision::Allow
+}
+
 impl ApiUsageTracker {
     pub fn new(data_path: PathBuf) -> Result<Self, String> {
         let tracker = Self {
             usage_log: Arc::new(Mutex::new(Vec::new())),
             fair_use_limits: Arc::new(Mutex::new(HashMap::new())),
+            budgets: Arc::new(Mutex::new(HashMap::new())),
             data_path,
         };
         
         tracker.load_usage_data()?;
+        tracker.load_budgets()?;
         tracker.initialize_fair_use_limits()?;
         
         Ok(tracker)
@@ -127,6 +290,34 @@ impl ApiUsageTracker {
         Ok(())
     }
     
+    fn budgets_path(&self) -> PathBuf {
+        self.data_path.with_file_name("api_budgets.json")
+    }
+
+    fn load_budgets(&self) -> Result<(), String> {
+        let path = self.budgets_path();
+        if path.exists() {
+            let data = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read budgets: {}", e))?;
+            let budgets: HashMap<String, UsageBudget> = serde_json::from_str(&data)
+                .map_err(|e| format!("Failed to parse budgets: {}", e))?;
+
+            if let Ok(mut current) = self.budgets.lock() {
+                *current = budgets;
+            }
+        }
+        Ok(())
+    }
+
+    fn save_budgets(&self) -> Result<(), String> {
+        let budgets = self.budgets.lock()
+            .map_err(|_| "Failed to lock budgets".to_string())?;
+        let data = serde_json::to_string_pretty(&*budgets)
+            .map_err(|e| format!("Failed to serialize budgets: {}", e))?;
+        fs::write(self.budgets_path(), data)
+            .map_err(|e| format!("Failed to write budgets: {}", e))
+    }
+
     fn initialize_fair_use_limits(&self) -> Result<(), String> {
         let mut limits = self.fair_use_limits.lock()
             .map_err(|_| "Failed to lock fair use limits".to_string())?;
@@ -158,8 +349,8 @@ impl ApiUsageTracker {
         if let Ok(mut log) = self.usage_log.lock() {
             log.push(record.clone());
             
-            // Keep only last 30 days
-            let cutoff = Utc::now() - chrono::Duration::days(30);
+            // Keep a little over a month so month-to-date budgets stay complete
+            let cutoff = Utc::now() - chrono::Duration::days(35);
             log.retain(|r| r.timestamp > cutoff);
         }
         
@@ -228,14 +419,7 @@ impl ApiUsageTracker {
                 stats.average_latency_ms = total_latency as f64 / service_records.len() as f64;
             }
             
-            // Estimate costs (example rates per 1000 calls)
-            stats.estimated_cost = match service.as_str() {
-                "helius" => stats.total_calls as f64 * 0.01 / 1000.0,
-                "birdeye" => stats.total_calls as f64 * 0.02 / 1000.0,
-                "jupiter" => stats.total_calls as f64 * 0.005 / 1000.0,
-                "solana_rpc" => stats.total_calls as f64 * 0.001 / 1000.0,
-                _ => 0.0,
-            };
+            stats.estimated_cost = stats.total_calls as f64 * cost_per_call(service);
             
             // Build endpoint breakdown
             let mut endpoint_map: HashMap<String, (u64, u64, u64)> = HashMap::new();
@@ -259,8 +443,11 @@ impl ApiUsageTracker {
             endpoint_breakdown.insert(service.clone(), endpoints);
         }
         
+        drop(log);
+
         // Generate alerts
-        let alerts = self.generate_alerts(&services)?;
+        let mut alerts = self.generate_alerts(&services)?;
+        alerts.extend(self.budget_alerts()?);
         
         Ok(ApiUsageAnalytics {
             services,
@@ -328,6 +515,180 @@ impl ApiUsageTracker {
             .map_err(|_| "Failed to lock fair use limits".to_string())?;
         Ok(limits.values().cloned().collect())
     }
+
+    pub fn list_budgets(&self) -> Result<Vec<UsageBudget>, String> {
+        let budgets = self.budgets.lock()
+            .map_err(|_| "Failed to lock budgets".to_string())?;
+        Ok(budgets.values().cloned().collect())
+    }
+
+    pub fn set_budget(&self, budget: UsageBudget) -> Result<UsageBudget, String> {
+        if budget.monthly_request_limit.is_none() && budget.monthly_cost_limit.is_none() {
+            return Err("Budget needs a request or cost limit".to_string());
+        }
+        if !(0.0..=100.0).contains(&budget.throttle_at_percent) {
+            return Err("Throttle threshold must be between 0 and 100".to_string());
+        }
+
+        self.budgets.lock()
+            .map_err(|_| "Failed to lock budgets".to_string())?
+            .insert(budget.service.clone(), budget.clone());
+        self.save_budgets()?;
+        Ok(budget)
+    }
+
+    pub fn remove_budget(&self, service: &str) -> Result<(), String> {
+        self.budgets.lock()
+            .map_err(|_| "Failed to lock budgets".to_string())?
+            .remove(service);
+        self.save_budgets()
+    }
+
+    fn month_to_date(&self, service: &str, period_start: DateTime<Utc>) -> Result<u64, String> {
+        let log = self.usage_log.lock()
+            .map_err(|_| "Failed to lock usage log".to_string())?;
+        Ok(log
+            .iter()
+            .filter(|r| r.service == service && r.timestamp >= period_start)
+            .count() as u64)
+    }
+
+    pub fn get_forecast(&self, service: &str) -> Result<UsageForecast, String> {
+        let now = Utc::now();
+        let (period_start, period_end) = month_bounds(now);
+        let rate = cost_per_call(service);
+
+        let requests_to_date = self.month_to_date(service, period_start)?;
+        let projected_requests = project_usage(requests_to_date, period_start, period_end, now);
+        let cost_to_date = requests_to_date as f64 * rate;
+        let projected_cost = projected_requests as f64 * rate;
+
+        let budget = self.budgets.lock()
+            .map_err(|_| "Failed to lock budgets".to_string())?
+            .get(service)
+            .cloned();
+
+        let budget_used_percent = budget
+            .as_ref()
+            .and_then(|b| budget_percent(b, requests_to_date, cost_to_date));
+        let projected_budget_percent = budget
+            .as_ref()
+            .and_then(|b| budget_percent(b, projected_requests, projected_cost));
+        let status = budget
+            .as_ref()
+            .map(|b| budget_status(b, budget_used_percent, projected_budget_percent))
+            .unwrap_or(BudgetStatus::NoBudget);
+        let throttling_background = budget
+            .as_ref()
+            .zip(budget_used_percent)
+            .map(|(b, used)| b.throttle_background && used >= b.throttle_at_percent)
+            .unwrap_or(false);
+
+        Ok(UsageForecast {
+            service: service.to_string(),
+            period_start,
+            period_end,
+            requests_to_date,
+            cost_to_date,
+            projected_requests,
+            projected_cost,
+            budget,
+            budget_used_percent,
+            projected_budget_percent,
+            status,
+            throttling_background,
+        })
+    }
+
+    /// Whether a request to `service` should go out now. Callers making background
+    /// requests (polling, prefetch) should pass `RequestPriority::Background`.
+    pub fn check_budget(&self, service: &str, priority: RequestPriority) -> Result<BudgetDecision, String> {
+        let forecast = self.get_forecast(service)?;
+        Ok(match (forecast.budget, forecast.budget_used_percent) {
+            (Some(budget), Some(used)) => evaluate_budget(&budget, used, priority),
+            _ => BudgetDecision::Allow,
+        })
+    }
+
+    fn budget_alerts(&self) -> Result<Vec<UsageAlert>, String> {
+        let mut alerts = Vec::new();
+        for budget in self.list_budgets()? {
+            let forecast = self.get_forecast(&budget.service)?;
+            let alert = match forecast.status {
+                BudgetStatus::Exceeded => Some((
+                    AlertType::BudgetExceeded,
+                    format!(
+                        "Monthly budget used up ({:.0}%)",
+                        forecast.budget_used_percent.unwrap_or(100.0)
+                    ),
+                )),
+                BudgetStatus::NearCap | BudgetStatus::ProjectedOverage => forecast
+                    .projected_budget_percent
+                    .filter(|projected| *projected > 100.0)
+                    .map(|projected| {
+                        (
+                            AlertType::ProjectedOverage,
+                            format!(
+                                "Projected to reach {:.0}% of monthly budget ({} requests, ${:.2})",
+                                projected, forecast.projected_requests, forecast.projected_cost
+                            ),
+                        )
+                    }),
+                _ => None,
+            };
+
+            if let Some((alert_type, message)) = alert {
+                alerts.push(UsageAlert {
+                    service: budget.service.clone(),
+                    alert_type,
+                    message,
+                    timestamp: Utc::now(),
+                });
+            }
+        }
+        Ok(alerts)
+    }
+}
+
+/// Budget gate for requests the backend makes on its own. `Err` carries the reason
+/// the request should be skipped; nothing is gated while the tracker isn't loaded.
+pub fn ensure_within_budget(
+    app: &AppHandle,
+    service: &str,
+    priority: RequestPriority,
+) -> Result<(), String> {
+    let Some(tracker) = app.try_state::<Arc<Mutex<ApiUsageTracker>>>() else {
+        return Ok(());
+    };
+    let decision = tracker
+        .lock()
+        .map_err(|_| "Failed to lock usage tracker".to_string())?
+        .check_budget(service, priority)?;
+    match decision {
+        BudgetDecision::Allow => Ok(()),
+        BudgetDecision::Throttle(reason) | BudgetDecision::Block(reason) => Err(reason),
+    }
+}
+
+/// Counts a request the backend made towards its service's usage and budget.
+pub fn record_backend_usage(app: &AppHandle, service: &str, status_code: u16, latency_ms: u64) {
+    let Some(tracker) = app.try_state::<Arc<Mutex<ApiUsageTracker>>>() else {
+        return;
+    };
+    let record = ApiUsageRecord {
+        service: service.to_string(),
+        endpoint: "backend".to_string(),
+        timestamp: Utc::now(),
+        status_code,
+        latency_ms,
+    };
+    let result = match tracker.lock() {
+        Ok(tracker) => tracker.record_usage(record),
+        Err(_) => Err("Failed to lock usage tracker".to_string()),
+    };
+    if let Err(err) = result {
+        tracing::warn!(error = %err, service = %service, "failed to record API usage");
+    }
 }
 
 fn calculate_next_reset() -> DateTime<Utc> {
@@ -378,6 +739,76 @@ pub async fn get_fair_use_status(
     tracker.get_fair_use_limits()
 }
 
+#[tauri::command]
+pub async fn get_usage_forecast(
+    service: Option<String>,
+    tracker: State<'_, Arc<Mutex<ApiUsageTracker>>>,
+) -> Result<Vec<UsageForecast>, String> {
+    let tracker = tracker.lock()
+        .map_err(|_| "Failed to lock usage tracker".to_string())?;
+
+    let services = match service {
+        Some(service) => vec![service],
+        None => {
+            let mut services: Vec<String> = tracker
+                .get_fair_use_limits()?
+                .into_iter()
+                .map(|limit| limit.service)
+                .chain(tracker.list_budgets()?.into_iter().map(|budget| budget.service))
+                .collect();
+            services.sort();
+            services.dedup();
+            services
+        }
+    };
+
+    services.iter().map(|service| tracker.get_forecast(service)).collect()
+}
+
+#[tauri::command]
+pub async fn list_api_budgets(
+    tracker: State<'_, Arc<Mutex<ApiUsageTracker>>>,
+) -> Result<Vec<UsageBudget>, String> {
+    let tracker = tracker.lock()
+        .map_err(|_| "Failed to lock usage tracker".to_string())?;
+
+    tracker.list_budgets()
+}
+
+#[tauri::command]
+pub async fn set_api_budget(
+    budget: UsageBudget,
+    tracker: State<'_, Arc<Mutex<ApiUsageTracker>>>,
+) -> Result<UsageBudget, String> {
+    let tracker = tracker.lock()
+        .map_err(|_| "Failed to lock usage tracker".to_string())?;
+
+    tracker.set_budget(budget)
+}
+
+#[tauri::command]
+pub async fn remove_api_budget(
+    service: String,
+    tracker: State<'_, Arc<Mutex<ApiUsageTracker>>>,
+) -> Result<(), String> {
+    let tracker = tracker.lock()
+        .map_err(|_| "Failed to lock usage tracker".to_string())?;
+
+    tracker.remove_budget(&service)
+}
+
+#[tauri::command]
+pub async fn check_api_budget(
+    service: String,
+    priority: Option<RequestPriority>,
+    tracker: State<'_, Arc<Mutex<ApiUsageTracker>>>,
+) -> Result<BudgetDecision, String> {
+    let tracker = tracker.lock()
+        .map_err(|_| "Failed to lock usage tracker".to_string())?;
+
+    tracker.check_budget(&service, priority.unwrap_or(RequestPriority::Interactive))
+}
+
 pub fn initialize_usage_tracker(app: &AppHandle) -> Result<Arc<Mutex<ApiUsageTracker>>, String> {
     let mut data_path = app
         .path_resolver()
@@ -389,3 +820,71 @@ pub fn initialize_usage_tracker(app: &AppHandle) -> Result<Arc<Mutex<ApiUsageTra
     let tracker = ApiUsageTracker::new(data_path)?;
     Ok(Arc::new(Mutex::new(tracker)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn budget(limit: u64) -> UsageBudget {
+        UsageBudget {
+            service: "helius".to_string(),
+            monthly_request_limit: Some(limit),
+            monthly_cost_limit: None,
+            hard_cap: true,
+            throttle_background: true,
+            throttle_at_percent: 90.0,
+        }
+    }
+
+    #[test]
+    fn projects_month_to_date_linearly() {
+        let start = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+
+        let day_ten = start + Duration::days(10);
+        assert_eq!(project_usage(1000, start, end, day_ten), 3000);
+        assert_eq!(project_usage(1000, start, end, end + Duration::days(1)), 1000);
+        // Early in the month the rate is taken over at least an hour.
+        assert_eq!(project_usage(1, start, end, start + Duration::seconds(5)), 720);
+    }
+
+    #[test]
+    fn month_bounds_roll_over_december() {
+        let (start, end) = month_bounds(Utc.with_ymd_and_hms(2024, 12, 15, 8, 0, 0).unwrap());
+        assert_eq!(start, Utc.with_ymd_and_hms(2024, 12, 1, 0, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn tighter_limit_wins_and_drives_status() {
+        let mut b = budget(1000);
+        b.monthly_cost_limit = Some(0.001);
+        // 50 requests = 5% of request limit, but 0.0005 of 0.001 = 50% of cost limit.
+        let used = budget_percent(&b, 50, 50.0 * cost_per_call("helius")).unwrap();
+        assert!((used - 50.0).abs() < 1e-6);
+
+        assert_eq!(budget_status(&b, Some(50.0), Some(150.0)), BudgetStatus::ProjectedOverage);
+        assert_eq!(budget_status(&b, Some(95.0), Some(150.0)), BudgetStatus::NearCap);
+        assert_eq!(budget_status(&b, Some(100.0), Some(150.0)), BudgetStatus::Exceeded);
+        assert_eq!(budget_status(&b, Some(20.0), Some(60.0)), BudgetStatus::OnTrack);
+    }
+
+    #[test]
+    fn throttles_background_before_blocking_everything() {
+        let b = budget(1000);
+        assert_eq!(evaluate_budget(&b, 50.0, RequestPriority::Background), BudgetDecision::Allow);
+        assert!(matches!(
+            evaluate_budget(&b, 92.0, RequestPriority::Background),
+            BudgetDecision::Throttle(_)
+        ));
+        assert_eq!(evaluate_budget(&b, 92.0, RequestPriority::Interactive), BudgetDecision::Allow);
+        assert!(matches!(
+            evaluate_budget(&b, 100.0, RequestPriority::Interactive),
+            BudgetDecision::Block(_)
+        ));
+
+        let soft = UsageBudget { hard_cap: false, ..budget(1000) };
+        assert_eq!(evaluate_budget(&soft, 120.0, RequestPriority::Interactive), BudgetDecision::Allow);
+    }
+}
@@ -2,8 +2,12 @@ use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use tauri::State;
+use std::time::Instant;
+use tauri::{AppHandle, Manager, State};
 
+use crate::api::health_monitor::{HealthCheckRecord, SharedApiHealthMonitor};
+use crate::api_analytics::record_backend_usage;
+use crate::notifications::router::SharedNotificationRouter;
 use crate::security::keystore::{Keystore, KeystoreError};
 
 const KEY_HELIUS_API: &str = "api_key_helius";
@@ -30,6 +34,11 @@ const ROTATION_INTERVAL_DAYS: i64 = 90;
 const ROTATION_REMINDER_THRESHOLD_DAYS: i64 = 15;
 const ROTATION_HISTORY_LIMIT: usize = 50;
 
+pub(crate) const API_SERVICES: &[&str] = &["helius", "birdeye", "jupiter", "solana_rpc"];
+const SECONDARY_KEY_SUFFIX: &str = "_secondary";
+const FAILOVER_EVENT: &str = "api_key_failover";
+const FAILOVER_CHECK_INTERVAL_SECS: u64 = 30;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiKeyConfig {
@@ -55,6 +64,155 @@ pub struct ApiKeyMetadata {
     pub rotation_due_at: Option<DateTime<Utc>>,
     #[serde(default)]
     pub reminder_sent_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub failover_policy: FailoverPolicy,
+}
+
+/// Which key a request was made with.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum KeySource {
+    Primary,
+    Secondary,
+    Default,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FailoverTarget {
+    /// Fall back to the bundled developer key.
+    Default,
+    /// Fall back to the user's secondary key, or the developer key if none is saved.
+    Secondary,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailoverPolicy {
+    pub enabled: bool,
+    pub target: FailoverTarget,
+    /// Consecutive failed or rate-limited requests before switching away from the user key.
+    pub failure_threshold: u32,
+    /// Consecutive successful probes of the user key before switching back.
+    pub recovery_successes: u32,
+    pub probe_interval_secs: i64,
+}
+
+impl Default for FailoverPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            target: FailoverTarget::Secondary,
+            failure_threshold: 3,
+            recovery_successes: 2,
+            probe_interval_secs: 120,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveFailover {
+    pub source: KeySource,
+    pub since: DateTime<Utc>,
+    pub reason: String,
+    pub recovery_successes: u32,
+    pub next_probe_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct FailoverState {
+    consecutive_failures: u32,
+    active: Option<ActiveFailover>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedApiKey {
+    pub service: String,
+    pub key: String,
+    pub source: KeySource,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestOutcome {
+    pub success: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
+impl RequestOutcome {
+    /// Outcome of an HTTP request that began at `started`. Only transport errors and
+    /// non-2xx statuses count as failures; API errors inside a 200 body don't.
+    pub fn from_response(
+        started: Instant,
+        response: &Result<reqwest::Response, reqwest::Error>,
+    ) -> Self {
+        let latency_ms = started.elapsed().as_millis() as u64;
+        match response {
+            Ok(response) => {
+                let status = response.status();
+                Self {
+                    success: status.is_success(),
+                    status_code: Some(status.as_u16()),
+                    latency_ms,
+                    error: (!status.is_success()).then(|| format!("HTTP {}", status)),
+                }
+            }
+            Err(err) => Self {
+                success: false,
+                status_code: err.status().map(|s| s.as_u16()),
+                latency_ms,
+                error: Some(err.to_string()),
+            },
+        }
+    }
+
+    /// Outcome of a call whose HTTP details are hidden behind a `Result`; any
+    /// error counts as a failed request.
+    pub fn from_result<T>(started: Instant, result: &Result<T, String>) -> Self {
+        Self {
+            success: result.is_ok(),
+            status_code: None,
+            latency_ms: started.elapsed().as_millis() as u64,
+            error: result.as_ref().err().cloned(),
+        }
+    }
+
+    fn rate_limited(&self) -> bool {
+        self.status_code == Some(429)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FailoverEventKind {
+    Activated,
+    Recovered,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailoverEvent {
+    pub service: String,
+    pub kind: FailoverEventKind,
+    pub from: KeySource,
+    pub to: KeySource,
+    pub reason: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailoverStatus {
+    pub service: String,
+    pub policy: FailoverPolicy,
+    pub active_source: KeySource,
+    pub consecutive_failures: u32,
+    pub failover: Option<ActiveFailover>,
+    pub has_secondary_key: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,6 +253,7 @@ pub struct ApiStatus {
 pub struct ServiceStatus {
     pub configured: bool,
     pub using_default: bool,
+    pub active_key: KeySource,
     pub connection_status: ConnectionStatus,
     pub rate_limit_info: Option<RateLimitInfo>,
     pub last_tested: Option<DateTime<Utc>>,
@@ -128,6 +287,7 @@ pub struct ConnectionTestResult {
 
 pub struct ApiConfigManager {
     metadata: Arc<Mutex<HashMap<String, ApiKeyMetadata>>>,
+    failover: Arc<Mutex<HashMap<String, FailoverState>>>,
 }
 
 fn default_metadata(service: &str, use_default: bool) -> ApiKeyMetadata {
@@ -147,13 +307,111 @@ fn default_metadata(service: &str, use_default: bool) -> ApiKeyMetadata {
         rotation_history: Vec::new(),
         rotation_due_at: Some(now + Duration::days(ROTATION_INTERVAL_DAYS)),
         reminder_sent_at: None,
+        failover_policy: FailoverPolicy::default(),
     }
 }
 
+fn primary_key_id(service: &str) -> Option<&'static str> {
+    match service {
+        "helius" => Some(KEY_HELIUS_API),
+        "birdeye" => Some(KEY_BIRDEYE_API),
+        "jupiter" => Some(KEY_JUPITER_API),
+        "solana_rpc" => Some(KEY_SOLANA_RPC),
+        _ => None,
+    }
+}
+
+fn secondary_key_id(service: &str) -> Option<String> {
+    primary_key_id(service).map(|id| format!("{}{}", id, SECONDARY_KEY_SUFFIX))
+}
+
+fn read_key(keystore: &Keystore, key_id: &str) -> Option<String> {
+    keystore
+        .retrieve_secret(key_id)
+        .ok()
+        .and_then(|secret| String::from_utf8(secret.to_vec()).ok())
+}
+
+/// Advances the failover state for one request made with the user's primary key.
+/// Returns the key source to switch to once the failure threshold is reached.
+fn apply_outcome(
+    state: &mut FailoverState,
+    policy: &FailoverPolicy,
+    outcome: &RequestOutcome,
+    has_secondary: bool,
+    now: DateTime<Utc>,
+) -> Option<ActiveFailover> {
+    if outcome.success {
+        state.consecutive_failures = 0;
+        return None;
+    }
+
+    state.consecutive_failures += 1;
+    if !policy.enabled || state.active.is_some() || state.consecutive_failures < policy.failure_threshold {
+        return None;
+    }
+
+    let source = match policy.target {
+        FailoverTarget::Secondary if has_secondary => KeySource::Secondary,
+        _ => KeySource::Default,
+    };
+    let reason = if outcome.rate_limited() {
+        format!("rate limited {} times in a row", state.consecutive_failures)
+    } else {
+        format!(
+            "{} consecutive errors{}",
+            state.consecutive_failures,
+            outcome
+                .error
+                .as_ref()
+                .map(|e| format!(", last: {}", e))
+                .unwrap_or_default()
+        )
+    };
+
+    let failover = ActiveFailover {
+        source,
+        since: now,
+        reason,
+        recovery_successes: 0,
+        next_probe_at: now + Duration::seconds(policy.probe_interval_secs),
+    };
+    state.active = Some(failover.clone());
+    Some(failover)
+}
+
+/// Records a probe of the primary key while failed over. Returns true once enough
+/// probes have succeeded in a row to switch back.
+fn apply_probe(
+    state: &mut FailoverState,
+    policy: &FailoverPolicy,
+    success: bool,
+    now: DateTime<Utc>,
+) -> bool {
+    let Some(active) = state.active.as_mut() else {
+        return false;
+    };
+
+    active.next_probe_at = now + Duration::seconds(policy.probe_interval_secs);
+    if !success {
+        active.recovery_successes = 0;
+        return false;
+    }
+
+    active.recovery_successes += 1;
+    if active.recovery_successes >= policy.recovery_successes.max(1) {
+        state.active = None;
+        state.consecutive_failures = 0;
+        return true;
+    }
+    false
+}
+
 impl ApiConfigManager {
     pub fn new() -> Self {
         Self {
             metadata: Arc::new(Mutex::new(HashMap::new())),
+            failover: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -195,6 +453,152 @@ impl ApiConfigManager {
         self.get_metadata(service)
             .unwrap_or_else(|| default_metadata(service, use_default))
     }
+
+    pub fn failover_policy(&self, service: &str) -> FailoverPolicy {
+        self.get_metadata(service)
+            .map(|m| m.failover_policy)
+            .unwrap_or_default()
+    }
+
+    fn active_failover(&self, service: &str) -> Option<ActiveFailover> {
+        self.failover
+            .lock()
+            .ok()?
+            .get(service)
+            .and_then(|state| state.active.clone())
+    }
+
+    /// Picks the key a request should use right now: the user's key unless it has
+    /// failed over, otherwise the secondary or developer key.
+    pub fn resolve_key(&self, service: &str, keystore: &Keystore) -> Result<ResolvedApiKey, String> {
+        let primary_id = primary_key_id(service).ok_or_else(|| "Unknown service".to_string())?;
+        let use_default = self.get_metadata(service).map(|m| m.use_default).unwrap_or(true);
+
+        let resolved = |key: String, source: KeySource| ResolvedApiKey {
+            service: service.to_string(),
+            key,
+            source,
+        };
+        let default = || resolved(get_default_key(service), KeySource::Default);
+
+        if use_default {
+            return Ok(default());
+        }
+
+        match self.active_failover(service).map(|f| f.source) {
+            Some(KeySource::Secondary) => Ok(secondary_key_id(service)
+                .and_then(|id| read_key(keystore, &id))
+                .map(|key| resolved(key, KeySource::Secondary))
+                .unwrap_or_else(default)),
+            Some(KeySource::Default) => Ok(default()),
+            _ => Ok(read_key(keystore, primary_id)
+                .map(|key| resolved(key, KeySource::Primary))
+                .unwrap_or_else(default)),
+        }
+    }
+
+    /// Feeds a request result into the failover tracker. Only outcomes for the user's
+    /// primary key count towards failing over.
+    pub fn record_outcome(
+        &self,
+        service: &str,
+        source: KeySource,
+        outcome: &RequestOutcome,
+        keystore: &Keystore,
+    ) -> Option<FailoverEvent> {
+        if source != KeySource::Primary {
+            return None;
+        }
+
+        let policy = self.failover_policy(service);
+        let has_secondary = secondary_key_id(service)
+            .map(|id| keystore.retrieve_secret(&id).is_ok())
+            .unwrap_or(false);
+
+        let mut failover = self.failover.lock().ok()?;
+        let state = failover.entry(service.to_string()).or_default();
+        let activated = apply_outcome(state, &policy, outcome, has_secondary, Utc::now())?;
+
+        Some(FailoverEvent {
+            service: service.to_string(),
+            kind: FailoverEventKind::Activated,
+            from: KeySource::Primary,
+            to: activated.source,
+            reason: activated.reason,
+            timestamp: activated.since,
+        })
+    }
+
+    pub fn due_recovery_probes(&self, now: DateTime<Utc>) -> Vec<String> {
+        self.failover
+            .lock()
+            .map(|failover| {
+                failover
+                    .iter()
+                    .filter(|(_, state)| {
+                        state
+                            .active
+                            .as_ref()
+                            .map(|active| active.next_probe_at <= now)
+                            .unwrap_or(false)
+                    })
+                    .map(|(service, _)| service.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn record_probe(&self, service: &str, success: bool) -> Option<FailoverEvent> {
+        let policy = self.failover_policy(service);
+        let mut failover = self.failover.lock().ok()?;
+        let state = failover.get_mut(service)?;
+        let from = state.active.as_ref()?.source;
+
+        apply_probe(state, &policy, success, Utc::now()).then(|| FailoverEvent {
+            service: service.to_string(),
+            kind: FailoverEventKind::Recovered,
+            from,
+            to: KeySource::Primary,
+            reason: format!("{} successful probes of the user key", policy.recovery_successes.max(1)),
+            timestamp: Utc::now(),
+        })
+    }
+
+    /// Drops any failover so the next request goes back to the configured key.
+    pub fn reset_failover(&self, service: &str) {
+        if let Ok(mut failover) = self.failover.lock() {
+            failover.remove(service);
+        }
+    }
+
+    pub fn failover_status(&self, keystore: &Keystore) -> Vec<FailoverStatus> {
+        API_SERVICES
+            .iter()
+            .map(|service| {
+                let state = self
+                    .failover
+                    .lock()
+                    .ok()
+                    .and_then(|f| f.get(*service).cloned())
+                    .unwrap_or_default();
+                let active_source = self
+                    .resolve_key(service, keystore)
+                    .map(|r| r.source)
+                    .unwrap_or(KeySource::Default);
+
+                FailoverStatus {
+                    service: service.to_string(),
+                    policy: self.failover_policy(service),
+                    active_source,
+                    consecutive_failures: state.consecutive_failures,
+                    failover: state.active,
+                    has_secondary_key: secondary_key_id(service)
+                        .map(|id| keystore.retrieve_secret(&id).is_ok())
+                        .unwrap_or(false),
+                }
+            })
+            .collect()
+    }
 }
 
 impl Default for ApiConfigManager {
@@ -254,6 +658,7 @@ pub async fn save_api_key(
     config_manager
         .update_metadata(&service, metadata, &keystore)
         .map_err(|e| format!("Failed to update metadata: {}", e))?;
+    config_manager.reset_failover(&service);
 
     Ok(format!("API key for {} saved successfully", service))
 }
@@ -289,6 +694,7 @@ pub async fn remove_api_key(
     config_manager
         .update_metadata(&service, metadata, &keystore)
         .map_err(|e| format!("Failed to update metadata: {}", e))?;
+    config_manager.reset_failover(&service);
 
     Ok(format!("API key for {} removed", service))
 }
@@ -345,13 +751,7 @@ pub async fn test_api_connection(
     };
 
     // Test the connection based on service
-    let result = match service.as_str() {
-        "helius" => test_helius_connection(&api_key).await,
-        "birdeye" => test_birdeye_connection(&api_key).await,
-        "jupiter" => test_jupiter_connection(&api_key).await,
-        "solana_rpc" => test_rpc_connection(&api_key).await,
-        _ => return Err("Unknown service".to_string()),
-    };
+    let result = probe_service(&service, &api_key).await;
 
     let latency = start.elapsed().as_millis() as u64;
 
@@ -471,9 +871,15 @@ fn get_service_status(
         .map(|m| m.rotation_history.clone())
         .unwrap_or_default();
 
+    let active_key = config_manager
+        .resolve_key(service, keystore)
+        .map(|resolved| resolved.source)
+        .unwrap_or(KeySource::Default);
+
     Ok(ServiceStatus {
         configured,
         using_default,
+        active_key,
         connection_status,
         rate_limit_info,
         last_tested,
@@ -497,6 +903,29 @@ fn get_default_key(service: &str) -> String {
     }
 }
 
+/// Probes a service with the key requests would use right now, without touching its
+/// stored connection metadata.
+pub(crate) async fn ping_service(
+    service: &str,
+    keystore: &Keystore,
+    config_manager: &ApiConfigManager,
+) -> Result<u16, String> {
+    let resolved = config_manager.resolve_key(service, keystore)?;
+    probe_service(service, &resolved.key)
+        .await
+        .map(|(status_code, _)| status_code)
+}
+
+async fn probe_service(service: &str, api_key: &str) -> Result<(u16, Option<RateLimitInfo>), String> {
+    match service {
+        "helius" => test_helius_connection(api_key).await,
+        "birdeye" => test_birdeye_connection(api_key).await,
+        "jupiter" => test_jupiter_connection(api_key).await,
+        "solana_rpc" => test_rpc_connection(api_key).await,
+        _ => Err("Unknown service".to_string()),
+    }
+}
+
 async fn test_helius_connection(api_key: &str) -> Result<(u16, Option<RateLimitInfo>), String> {
     let client = reqwest::Client::new();
     let url = format!("https://api.helius.xyz/v0/addresses/HeM8ZhRrPA8QUcLt7ycTGy8AyD1q2CqfRvEdBZ99jqZv/balances?api-key={}", api_key);
@@ -740,6 +1169,191 @@ pub async fn import_api_keys(
     Ok("API keys imported successfully".to_string())
 }
 
+async fn notify_failover(app: &AppHandle, event: &FailoverEvent) {
+    if let Err(err) = app.emit_all(FAILOVER_EVENT, event) {
+        tracing::warn!(error = %err, "failed to emit API failover event");
+    }
+
+    let Some(router) = app.try_state::<SharedNotificationRouter>() else {
+        return;
+    };
+    let (title, message) = match event.kind {
+        FailoverEventKind::Activated => (
+            "API key failover",
+            format!(
+                "{} switched from your key to the {:?} key: {}",
+                event.service, event.to, event.reason
+            ),
+        ),
+        FailoverEventKind::Recovered => (
+            "API key restored",
+            format!("{} is using your key again after {}", event.service, event.reason),
+        ),
+    };
+    if let Err(err) = router
+        .read()
+        .await
+        .send_broadcast_notification("api_key_failover", title, &message)
+        .await
+    {
+        tracing::warn!(error = %err, "failed to deliver API failover notification");
+    }
+}
+
+/// Key a background request should use, or `None` while key config isn't loaded.
+pub fn resolve_app_key(app: &AppHandle, service: &str) -> Option<ResolvedApiKey> {
+    let manager = app.try_state::<ApiConfigManager>()?;
+    let keystore = app.try_state::<Keystore>()?;
+    manager.resolve_key(service, &keystore).ok()
+}
+
+/// Entry point for code making API calls: records the result with the health monitor
+/// and fails over (or notifies about it) when the user's key keeps failing.
+pub async fn report_api_outcome(
+    app: &AppHandle,
+    service: &str,
+    source: KeySource,
+    outcome: RequestOutcome,
+) {
+    record_backend_usage(
+        app,
+        service,
+        outcome.status_code.unwrap_or_default(),
+        outcome.latency_ms,
+    );
+    if let Some(monitor) = app.try_state::<SharedApiHealthMonitor>() {
+        let record = HealthCheckRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            service_name: service.to_string(),
+            timestamp: Utc::now(),
+            success: outcome.success,
+            latency_ms: outcome.latency_ms as u128,
+            status_code: outcome.status_code,
+            error: outcome.error.clone(),
+        };
+        if let Err(err) = monitor.read().await.record_check(record).await {
+            tracing::warn!(error = %err, service = %service, "failed to record API health check");
+        }
+    }
+
+    let event = match (app.try_state::<ApiConfigManager>(), app.try_state::<Keystore>()) {
+        (Some(manager), Some(keystore)) => {
+            manager.record_outcome(service, source, &outcome, &keystore)
+        }
+        _ => None,
+    };
+    if let Some(event) = event {
+        notify_failover(app, &event).await;
+    }
+}
+
+/// Probes the user's key for services that have failed over and switches back once
+/// it has recovered.
+pub fn start_failover_monitor(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(FAILOVER_CHECK_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+
+            let due = app.state::<ApiConfigManager>().due_recovery_probes(Utc::now());
+            for service in due {
+                let Some(key_id) = primary_key_id(&service) else {
+                    continue;
+                };
+                let Some(api_key) = read_key(&app.state::<Keystore>(), key_id) else {
+                    // User key is gone; nothing to recover to.
+                    app.state::<ApiConfigManager>().reset_failover(&service);
+                    continue;
+                };
+
+                let success = probe_service(&service, &api_key).await.is_ok();
+                let event = app.state::<ApiConfigManager>().record_probe(&service, success);
+                if let Some(event) = event {
+                    notify_failover(&app, &event).await;
+                }
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_api_failover_status(
+    keystore: State<'_, Keystore>,
+    config_manager: State<'_, ApiConfigManager>,
+) -> Result<Vec<FailoverStatus>, String> {
+    Ok(config_manager.failover_status(&keystore))
+}
+
+#[tauri::command]
+pub async fn set_api_failover_policy(
+    service: String,
+    policy: FailoverPolicy,
+    keystore: State<'_, Keystore>,
+    config_manager: State<'_, ApiConfigManager>,
+) -> Result<FailoverPolicy, String> {
+    primary_key_id(&service).ok_or_else(|| "Unknown service".to_string())?;
+    if policy.failure_threshold == 0 {
+        return Err("Failure threshold must be at least 1".to_string());
+    }
+    if policy.probe_interval_secs < FAILOVER_CHECK_INTERVAL_SECS as i64 {
+        return Err(format!(
+            "Probe interval must be at least {} seconds",
+            FAILOVER_CHECK_INTERVAL_SECS
+        ));
+    }
+
+    let mut metadata = config_manager.get_or_create_metadata(&service, true);
+    metadata.failover_policy = policy.clone();
+    config_manager
+        .update_metadata(&service, metadata, &keystore)
+        .map_err(|e| format!("Failed to update metadata: {}", e))?;
+
+    if !policy.enabled {
+        config_manager.reset_failover(&service);
+    }
+    Ok(policy)
+}
+
+#[tauri::command]
+pub async fn save_secondary_api_key(
+    service: String,
+    api_key: String,
+    keystore: State<'_, Keystore>,
+) -> Result<String, String> {
+    let key_id = secondary_key_id(&service).ok_or_else(|| "Unknown service".to_string())?;
+    keystore
+        .store_secret(&key_id, api_key.as_bytes())
+        .map_err(|e| format!("Failed to store API key: {}", e))?;
+
+    Ok(format!("Secondary API key for {} saved successfully", service))
+}
+
+#[tauri::command]
+pub async fn remove_secondary_api_key(
+    service: String,
+    keystore: State<'_, Keystore>,
+) -> Result<String, String> {
+    let key_id = secondary_key_id(&service).ok_or_else(|| "Unknown service".to_string())?;
+    keystore
+        .remove_secret(&key_id)
+        .map_err(|e| format!("Failed to remove API key: {}", e))?;
+
+    Ok(format!("Secondary API key for {} removed", service))
+}
+
+#[tauri::command]
+pub async fn report_api_request_outcome(
+    service: String,
+    source: KeySource,
+    outcome: RequestOutcome,
+    app_handle: AppHandle,
+) -> Result<(), String> {
+    primary_key_id(&service).ok_or_else(|| "Unknown service".to_string())?;
+    report_api_outcome(&app_handle, &service, source, outcome).await;
+    Ok(())
+}
+
 pub fn register_api_config_manager(app: &tauri::App) -> Result<(), Box<dyn std::error::Error>> {
     let config_manager = ApiConfigManager::new();
     
@@ -751,3 +1365,86 @@ pub fn register_api_config_manager(app: &tauri::App) -> Result<(), Box<dyn std::
     app.manage(config_manager);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure(status_code: Option<u16>) -> RequestOutcome {
+        RequestOutcome {
+            success: false,
+            status_code,
+            latency_ms: 120,
+            error: Some("boom".to_string()),
+        }
+    }
+
+    fn success() -> RequestOutcome {
+        RequestOutcome {
+            success: true,
+            status_code: Some(200),
+            latency_ms: 80,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn fails_over_after_consecutive_failures() {
+        let policy = FailoverPolicy::default();
+        let mut state = FailoverState::default();
+        let now = Utc::now();
+
+        assert!(apply_outcome(&mut state, &policy, &failure(Some(429)), true, now).is_none());
+        assert!(apply_outcome(&mut state, &policy, &success(), true, now).is_none());
+        assert_eq!(state.consecutive_failures, 0);
+
+        for _ in 0..2 {
+            assert!(apply_outcome(&mut state, &policy, &failure(Some(429)), true, now).is_none());
+        }
+        let failover = apply_outcome(&mut state, &policy, &failure(Some(429)), true, now).unwrap();
+        assert_eq!(failover.source, KeySource::Secondary);
+        assert!(failover.reason.contains("rate limited"));
+
+        // Already failed over; further failures don't re-trigger.
+        assert!(apply_outcome(&mut state, &policy, &failure(None), true, now).is_none());
+    }
+
+    #[test]
+    fn falls_back_to_default_without_secondary_or_when_disabled() {
+        let policy = FailoverPolicy {
+            failure_threshold: 1,
+            ..FailoverPolicy::default()
+        };
+        let mut state = FailoverState::default();
+        let failover = apply_outcome(&mut state, &policy, &failure(Some(500)), false, Utc::now()).unwrap();
+        assert_eq!(failover.source, KeySource::Default);
+
+        let disabled = FailoverPolicy {
+            enabled: false,
+            failure_threshold: 1,
+            ..FailoverPolicy::default()
+        };
+        let mut state = FailoverState::default();
+        assert!(apply_outcome(&mut state, &disabled, &failure(Some(500)), true, Utc::now()).is_none());
+    }
+
+    #[test]
+    fn switches_back_after_recovery_probes() {
+        let policy = FailoverPolicy {
+            failure_threshold: 1,
+            recovery_successes: 2,
+            ..FailoverPolicy::default()
+        };
+        let mut state = FailoverState::default();
+        let now = Utc::now();
+        apply_outcome(&mut state, &policy, &failure(None), true, now).unwrap();
+
+        assert!(!apply_probe(&mut state, &policy, true, now));
+        assert!(!apply_probe(&mut state, &policy, false, now));
+        assert_eq!(state.active.as_ref().unwrap().recovery_successes, 0);
+        assert!(!apply_probe(&mut state, &policy, true, now));
+        assert!(apply_probe(&mut state, &policy, true, now));
+        assert!(state.active.is_none());
+        assert_eq!(state.consecutive_failures, 0);
+    }
+}
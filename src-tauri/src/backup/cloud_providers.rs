@@ -4,6 +4,8 @@ use std::fs;
 use std::path::PathBuf;
 use tauri::AppHandle;
 
+use crate::security::activity_log::ActivityChainHead;
+
 const STORAGE_DIR: &str = "cloud_backups";
 const MAX_VERSIONS: usize = 20;
 
@@ -55,6 +57,8 @@ pub struct BackupMetadata {
     pub created_at: DateTime<Utc>,
     pub version: u32,
     pub checksum: String,
+    #[serde(default)]
+    pub activity_log_head: Option<ActivityChainHead>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -15,6 +15,7 @@ use tauri::{AppHandle, Manager, State};
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use crate::security::activity_log::ActivityLogger;
 use crate::security::keystore::{Keystore, KeystoreError};
 
 use super::cloud_providers::{BackupMetadata, CloudProvider, CloudProviderConfig, CloudProviderError, CloudProviderManager};
@@ -163,6 +164,12 @@ impl BackupService {
         // Serialize encrypted backup
         let backup_data = serde_json::to_vec(&encrypted)?;
 
+        // Record the activity log chain head so the log can be cross-checked later
+        let activity_log_head = self
+            .app_handle
+            .try_state::<ActivityLogger>()
+            .and_then(|logger| logger.chain_head().ok());
+
         // Create metadata
         let filename = format!("backup_{}.enc", Utc::now().format("%Y%m%d_%H%M%S"));
         let metadata = BackupMetadata {
@@ -171,6 +178,7 @@ impl BackupService {
             created_at: encrypted.created_at,
             version: encrypted.version,
             checksum: encrypted.checksum.clone(),
+            activity_log_head,
         };
 
         // Upload to cloud
@@ -0,0 +1,507 @@
+use crate::alerts::{CreateAlertRequest, PriceAlert, SharedAlertManager, UpdateAlertRequest};
+use crate::config::version_history::{record_config_change, ConfigEntity, LOCAL_ACTOR};
+use crate::portfolio::{ReorderItem, SharedWatchlistManager, Watchlist};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, State};
+
+const MAX_BATCH_SIZE: usize = 200;
+
+/// Commands callable through `batch_invoke`. Deleting a watchlist is left out on
+/// purpose: it cannot be undone with its original id, so it can't join a rollback.
+const BATCH_WHITELIST: &[&str] = &[
+    "watchlist_list",
+    "watchlist_get",
+    "watchlist_create",
+    "watchlist_update",
+    "watchlist_add_item",
+    "watchlist_remove_item",
+    "watchlist_reorder_items",
+    "alert_list",
+    "alert_get",
+    "alert_create",
+    "alert_update",
+    "alert_delete",
+];
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCommand {
+    pub command: String,
+    /// Same arguments the standalone command takes. Any string of the form
+    /// `$<index>.<path>` is replaced by that field of an earlier result, e.g.
+    /// `"$0.id"` for the id of a watchlist created by the first command.
+    #[serde(default)]
+    pub args: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchItemResult {
+    pub index: usize,
+    pub command: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub rolled_back: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResponse {
+    pub success: bool,
+    pub atomic: bool,
+    pub rolled_back: bool,
+    pub results: Vec<BatchItemResult>,
+    pub rollback_errors: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IdArgs {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WatchlistCreateArgs {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WatchlistUpdateArgs {
+    id: String,
+    name: String,
+    #[serde(default)]
+    expected_version: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WatchlistItemArgs {
+    watchlist_id: String,
+    #[serde(default)]
+    symbol: String,
+    mint: String,
+    #[serde(default)]
+    expected_version: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WatchlistReorderArgs {
+    watchlist_id: String,
+    items: Vec<ReorderItem>,
+    #[serde(default)]
+    expected_version: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertCreateArgs {
+    req: CreateAlertRequest,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertUpdateArgs {
+    id: String,
+    req: UpdateAlertRequest,
+}
+
+/// Compensating write that reverses one executed command.
+enum Undo {
+    DeleteWatchlist(String),
+    RestoreWatchlist(Watchlist),
+    DeleteAlert(String),
+    RestoreAlert(PriceAlert),
+    /// Restores an alert deleted in the batch and drops its recycle bin copy.
+    UndeleteAlert(PriceAlert, String),
+}
+
+/// Config history entries are only written once the batch is known to stick.
+enum ConfigChange {
+    Alert(String, Option<PriceAlert>),
+}
+
+struct Executed {
+    result: Value,
+    undo: Option<Undo>,
+    change: Option<ConfigChange>,
+}
+
+fn parse_args<T: serde::de::DeserializeOwned>(args: Value) -> Result<T, String> {
+    serde_json::from_value(args).map_err(|e| format!("invalid arguments: {e}"))
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|e| e.to_string())
+}
+
+fn parse_reference(raw: &str) -> Option<(usize, Vec<&str>)> {
+    let rest = raw.strip_prefix('$')?;
+    let mut parts = rest.split('.');
+    let index = parts.next()?.parse().ok()?;
+    let path: Vec<&str> = parts.filter(|p| !p.is_empty()).collect();
+    // A bare `$5` is far more likely a literal than a reference.
+    (!path.is_empty()).then_some((index, path))
+}
+
+/// Substitutes `$<index>.<path>` strings with values from earlier results.
+pub fn resolve_references(args: &Value, results: &[Option<Value>]) -> Result<Value, String> {
+    match args {
+        Value::String(raw) => {
+            let Some((index, path)) = parse_reference(raw) else {
+                return Ok(args.clone());
+            };
+            let mut current = results
+                .get(index)
+                .and_then(|r| r.as_ref())
+                .ok_or_else(|| format!("reference {raw} points at a command without a result"))?;
+            for segment in path {
+                current = match current {
+                    Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+                    Value::Object(map) => map.get(segment),
+                    _ => None,
+                }
+                .ok_or_else(|| format!("reference {raw} does not resolve"))?;
+            }
+            Ok(current.clone())
+        }
+        Value::Array(items) => items
+            .iter()
+            .map(|item| resolve_references(item, results))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| resolve_references(value, results).map(|v| (key.clone(), v)))
+            .collect::<Result<serde_json::Map<_, _>, _>>()
+            .map(Value::Object),
+        _ => Ok(args.clone()),
+    }
+}
+
+async fn execute(
+    command: &str,
+    args: Value,
+    watchlists: &SharedWatchlistManager,
+    alerts: &SharedAlertManager,
+) -> Result<Executed, String> {
+    let read_only = |result: Value| Executed {
+        result,
+        undo: None,
+        change: None,
+    };
+
+    match command {
+        "watchlist_list" => {
+            let lists = watchlists.read().await.list_watchlists().await.map_err(|e| e.to_string())?;
+            Ok(read_only(to_value(&lists)?))
+        }
+        "watchlist_get" => {
+            let args: IdArgs = parse_args(args)?;
+            let list = watchlists.read().await.get_watchlist(&args.id).await.map_err(|e| e.to_string())?;
+            Ok(read_only(to_value(&list)?))
+        }
+        "watchlist_create" => {
+            let args: WatchlistCreateArgs = parse_args(args)?;
+            let list = watchlists.read().await.create_watchlist(args.name).await.map_err(|e| e.to_string())?;
+            Ok(Executed {
+                result: to_value(&list)?,
+                undo: Some(Undo::DeleteWatchlist(list.id)),
+                change: None,
+            })
+        }
+        "watchlist_update" => {
+            let args: WatchlistUpdateArgs = parse_args(args)?;
+            let mgr = watchlists.read().await;
+            let before = mgr.get_watchlist(&args.id).await.map_err(|e| e.to_string())?;
+            let list = mgr
+                .update_watchlist(&args.id, args.name, args.expected_version)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(Executed {
+                result: to_value(&list)?,
+                undo: Some(Undo::RestoreWatchlist(before)),
+                change: None,
+            })
+        }
+        "watchlist_add_item" | "watchlist_remove_item" | "watchlist_reorder_items" => {
+            let mgr = watchlists.read().await;
+            let (before, list) = match command {
+                "watchlist_add_item" => {
+                    let args: WatchlistItemArgs = parse_args(args)?;
+                    let before = mgr.get_watchlist(&args.watchlist_id).await.map_err(|e| e.to_string())?;
+                    let list = mgr
+                        .add_item(&args.watchlist_id, args.symbol, args.mint, args.expected_version)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    (before, list)
+                }
+                "watchlist_remove_item" => {
+                    let args: WatchlistItemArgs = parse_args(args)?;
+                    let before = mgr.get_watchlist(&args.watchlist_id).await.map_err(|e| e.to_string())?;
+                    let list = mgr
+                        .remove_item(&args.watchlist_id, &args.mint, args.expected_version)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    (before, list)
+                }
+                _ => {
+                    let args: WatchlistReorderArgs = parse_args(args)?;
+                    let before = mgr.get_watchlist(&args.watchlist_id).await.map_err(|e| e.to_string())?;
+                    let list = mgr
+                        .reorder_items(&args.watchlist_id, args.items, args.expected_version)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    (before, list)
+                }
+            };
+            Ok(Executed {
+                result: to_value(&list)?,
+                undo: Some(Undo::RestoreWatchlist(before)),
+                change: None,
+            })
+        }
+        "alert_list" => {
+            let list = alerts.read().await.list_alerts().await.map_err(|e| e.to_string())?;
+            Ok(read_only(to_value(&list)?))
+        }
+        "alert_get" => {
+            let args: IdArgs = parse_args(args)?;
+            let alert = alerts.read().await.get_alert(&args.id).await.map_err(|e| e.to_string())?;
+            Ok(read_only(to_value(&alert)?))
+        }
+        "alert_create" => {
+            let args: AlertCreateArgs = parse_args(args)?;
+            let alert = alerts.read().await.create_alert(args.req).await.map_err(|e| e.to_string())?;
+            Ok(Executed {
+                result: to_value(&alert)?,
+                undo: Some(Undo::DeleteAlert(alert.id.clone())),
+                change: Some(ConfigChange::Alert(alert.id.clone(), Some(alert))),
+            })
+        }
+        "alert_update" => {
+            let args: AlertUpdateArgs = parse_args(args)?;
+            let mgr = alerts.read().await;
+            let before = mgr.get_alert(&args.id).await.map_err(|e| e.to_string())?;
+            let alert = mgr.update_alert(&args.id, args.req).await.map_err(|e| e.to_string())?;
+            Ok(Executed {
+                result: to_value(&alert)?,
+                undo: Some(Undo::RestoreAlert(before)),
+                change: Some(ConfigChange::Alert(alert.id.clone(), Some(alert))),
+            })
+        }
+        "alert_delete" => {
+            let args: IdArgs = parse_args(args)?;
+            let (before, recycled) =
+                alerts.read().await.recycle_alert(&args.id).await.map_err(|e| e.to_string())?;
+            Ok(Executed {
+                result: Value::Null,
+                undo: Some(Undo::UndeleteAlert(before, recycled.id)),
+                change: Some(ConfigChange::Alert(args.id, None)),
+            })
+        }
+        other => Err(format!("command {other} is not allowed in a batch")),
+    }
+}
+
+async fn undo(
+    step: Undo,
+    watchlists: &SharedWatchlistManager,
+    alerts: &SharedAlertManager,
+) -> Result<(), String> {
+    match step {
+        Undo::DeleteWatchlist(id) => watchlists
+            .read()
+            .await
+            .delete_watchlist(&id)
+            .await
+            .map_err(|e| e.to_string()),
+        Undo::RestoreWatchlist(before) => {
+            let mgr = watchlists.read().await;
+            let current = mgr.get_watchlist(&before.id).await.map_err(|e| e.to_string())?;
+
+            if current.name != before.name {
+                mgr.update_watchlist(&before.id, before.name.clone(), None)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            for item in current.items.iter().filter(|c| !before.items.iter().any(|b| b.mint == c.mint)) {
+                mgr.remove_item(&before.id, &item.mint, None).await.map_err(|e| e.to_string())?;
+            }
+            for item in before.items.iter().filter(|b| !current.items.iter().any(|c| c.mint == b.mint)) {
+                mgr.add_item(&before.id, item.symbol.clone(), item.mint.clone(), None)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+
+            let positions = before
+                .items
+                .iter()
+                .map(|item| ReorderItem {
+                    symbol: item.symbol.clone(),
+                    mint: item.mint.clone(),
+                    position: item.position,
+                })
+                .collect();
+            mgr.reorder_items(&before.id, positions, None).await.map_err(|e| e.to_string())?;
+            Ok(())
+        }
+        Undo::DeleteAlert(id) => alerts.read().await.delete_alert(&id).await.map_err(|e| e.to_string()),
+        Undo::RestoreAlert(before) => alerts
+            .read()
+            .await
+            .restore_alert(before)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string()),
+        Undo::UndeleteAlert(before, recycled_id) => alerts
+            .read()
+            .await
+            .unrecycle_alert(before, &recycled_id)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string()),
+    }
+}
+
+/// Runs a list of whitelisted commands in one round trip. With `atomic` (the
+/// default) the batch stops at the first failure and every write already made is
+/// reversed, newest first; otherwise each command runs independently.
+#[tauri::command]
+pub async fn batch_invoke(
+    app_handle: AppHandle,
+    watchlists: State<'_, SharedWatchlistManager>,
+    alerts: State<'_, SharedAlertManager>,
+    commands: Vec<BatchCommand>,
+    atomic: Option<bool>,
+) -> Result<BatchResponse, String> {
+    let atomic = atomic.unwrap_or(true);
+
+    if commands.len() > MAX_BATCH_SIZE {
+        return Err(format!("batch exceeds {MAX_BATCH_SIZE} commands"));
+    }
+    if let Some(cmd) = commands.iter().find(|c| !BATCH_WHITELIST.contains(&c.command.as_str())) {
+        return Err(format!("command {} is not allowed in a batch", cmd.command));
+    }
+
+    let mut results: Vec<BatchItemResult> = Vec::with_capacity(commands.len());
+    let mut outputs: Vec<Option<Value>> = Vec::with_capacity(commands.len());
+    let mut undo_log: Vec<(usize, Undo)> = Vec::new();
+    let mut changes: Vec<ConfigChange> = Vec::new();
+    let mut failed = false;
+
+    for (index, cmd) in commands.iter().enumerate() {
+        let outcome = match resolve_references(&cmd.args, &outputs) {
+            Ok(args) => execute(&cmd.command, args, watchlists.inner(), alerts.inner()).await,
+            Err(err) => Err(err),
+        };
+
+        match outcome {
+            Ok(executed) => {
+                if let Some(step) = executed.undo {
+                    undo_log.push((index, step));
+                }
+                changes.extend(executed.change);
+                outputs.push(Some(executed.result.clone()));
+                results.push(BatchItemResult {
+                    index,
+                    command: cmd.command.clone(),
+                    ok: true,
+                    result: Some(executed.result),
+                    error: None,
+                    rolled_back: false,
+                });
+            }
+            Err(err) => {
+                failed = true;
+                outputs.push(None);
+                results.push(BatchItemResult {
+                    index,
+                    command: cmd.command.clone(),
+                    ok: false,
+                    result: None,
+                    error: Some(err),
+                    rolled_back: false,
+                });
+                if atomic {
+                    break;
+                }
+            }
+        }
+    }
+
+    let rolled_back = atomic && failed;
+    let mut rollback_errors = Vec::new();
+    if rolled_back {
+        for (index, step) in undo_log.into_iter().rev() {
+            match undo(step, watchlists.inner(), alerts.inner()).await {
+                Ok(()) => results[index].rolled_back = true,
+                Err(err) => rollback_errors.push(format!("command {index}: {err}")),
+            }
+        }
+    } else {
+        for change in changes {
+            match change {
+                ConfigChange::Alert(id, after) => {
+                    record_config_change(&app_handle, ConfigEntity::alert(&id), after.as_ref(), LOCAL_ACTOR)
+                        .await;
+                }
+            }
+        }
+    }
+
+    if !rollback_errors.is_empty() {
+        tracing::warn!(errors = ?rollback_errors, "batch rollback incomplete");
+    }
+
+    Ok(BatchResponse {
+        success: !failed,
+        atomic,
+        rolled_back,
+        results,
+        rollback_errors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn resolves_nested_references() {
+        let results = vec![Some(json!({ "id": "wl-1", "items": [{ "mint": "m1" }] })), None];
+        let args = json!({
+            "watchlistId": "$0.id",
+            "req": { "mint": "$0.items.0.mint", "name": "literal" },
+            "count": 3
+        });
+
+        let resolved = resolve_references(&args, &results).unwrap();
+        assert_eq!(resolved["watchlistId"], json!("wl-1"));
+        assert_eq!(resolved["req"]["mint"], json!("m1"));
+        assert_eq!(resolved["req"]["name"], json!("literal"));
+        assert_eq!(resolved["count"], json!(3));
+    }
+
+    #[test]
+    fn rejects_references_to_failed_or_missing_results() {
+        let results = vec![Some(json!({ "id": "wl-1" })), None];
+        assert!(resolve_references(&json!("$1.id"), &results).is_err());
+        assert!(resolve_references(&json!("$5.id"), &results).is_err());
+        assert!(resolve_references(&json!("$0.missing"), &results).is_err());
+    }
+
+    #[test]
+    fn plain_dollar_strings_are_left_alone() {
+        let results = vec![];
+        assert_eq!(resolve_references(&json!("$SOL"), &results).unwrap(), json!("$SOL"));
+        assert_eq!(resolve_references(&json!("$5"), &results).unwrap(), json!("$5"));
+    }
+}
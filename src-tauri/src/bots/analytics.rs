@@ -0,0 +1,443 @@
+use crate::bots::dca_bot::{dca_database, DcaConfig};
+use crate::core::price_engine::get_price_engine;
+use crate::trading::copy_trading::{copy_trade_database, CopyTradeConfig};
+use crate::wallet::fee_tracker::FeePeriod;
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const STABLE_QUOTES: [&str; 3] = ["USDC", "USDT", "USD"];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BotKind {
+    Dca,
+    CopyTrade,
+}
+
+#[derive(Debug, Clone)]
+pub struct BotInfo {
+    pub id: String,
+    pub name: String,
+    pub kind: BotKind,
+    /// Asset the bot spends
+    pub benchmark: String,
+}
+
+impl From<&DcaConfig> for BotInfo {
+    fn from(config: &DcaConfig) -> Self {
+        Self {
+            id: config.id.clone(),
+            name: config.name.clone(),
+            kind: BotKind::Dca,
+            benchmark: config.input_symbol.clone(),
+        }
+    }
+}
+
+impl From<&CopyTradeConfig> for BotInfo {
+    fn from(config: &CopyTradeConfig) -> Self {
+        // Copied amounts are recorded in USD
+        Self {
+            id: config.id.clone(),
+            name: config.name.clone(),
+            kind: BotKind::CopyTrade,
+            benchmark: "USD".to_string(),
+        }
+    }
+}
+
+/// One execution normalized across bot types. Amounts are in the bot's quote asset.
+#[derive(Debug, Clone)]
+pub struct BotFill {
+    pub executed_at: DateTime<Utc>,
+    pub quote_spent: f64,
+    pub base_received: f64,
+    /// Realized or reported PnL when the bot tracks it per trade.
+    pub pnl: Option<f64>,
+    pub slippage_bps: Option<f64>,
+    pub fee: f64,
+    pub success: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BotAnalytics {
+    pub bot_id: String,
+    pub name: String,
+    pub kind: BotKind,
+    pub period: FeePeriod,
+    /// Asset the bot spends; returns are measured against simply holding it.
+    pub benchmark: String,
+    pub execution_count: usize,
+    pub successful_count: usize,
+    pub invested: f64,
+    pub current_value: f64,
+    pub net_profit: f64,
+    pub benchmark_adjusted_return_pct: f64,
+    /// Annualized money-weighted return; `None` when it can't be solved.
+    pub irr_pct: Option<f64>,
+    pub hit_rate_pct: f64,
+    /// `None` when the bot doesn't record execution slippage.
+    pub avg_slippage_bps: Option<f64>,
+    pub fees_paid: f64,
+    pub fee_drag_pct: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotStatementLine {
+    pub bot_id: String,
+    pub name: String,
+    pub kind: BotKind,
+    pub invested: f64,
+    pub pnl: f64,
+    pub fees: f64,
+}
+
+fn month_key(at: DateTime<Utc>) -> String {
+    format!("{:04}-{:02}", at.year(), at.month())
+}
+
+/// Annualized internal rate of return for dated cash flows (negative = money in).
+pub fn xirr(flows: &[(DateTime<Utc>, f64)]) -> Option<f64> {
+    let start = flows.iter().map(|(at, _)| *at).min()?;
+    if !flows.iter().any(|(_, v)| *v < 0.0) || !flows.iter().any(|(_, v)| *v > 0.0) {
+        return None;
+    }
+
+    let npv = |rate: f64| -> f64 {
+        flows
+            .iter()
+            .map(|(at, value)| {
+                let years = (*at - start).num_seconds() as f64 / (365.25 * 86_400.0);
+                value / (1.0 + rate).powf(years)
+            })
+            .sum()
+    };
+
+    // Bisection is slow but can't diverge on the odd flow shapes bots produce
+    let (mut low, mut high) = (-0.9999, 1_000.0);
+    let (mut npv_low, npv_high) = (npv(low), npv(high));
+    if npv_low.signum() == npv_high.signum() {
+        return None;
+    }
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        let npv_mid = npv(mid);
+        if npv_mid.abs() < 1e-9 {
+            return Some(mid);
+        }
+        if npv_mid.signum() == npv_low.signum() {
+            low = mid;
+            npv_low = npv_mid;
+        } else {
+            high = mid;
+        }
+    }
+    Some((low + high) / 2.0)
+}
+
+/// `mark_price` is the current base price in quote units; fills without per-trade PnL are
+/// valued at it.
+pub fn compute_bot_analytics(
+    bot: &BotInfo,
+    period: FeePeriod,
+    fills: &[BotFill],
+    mark_price: f64,
+    now: DateTime<Utc>,
+) -> BotAnalytics {
+    let successful: Vec<&BotFill> = fills.iter().filter(|f| f.success && f.quote_spent > 0.0).collect();
+
+    let invested: f64 = successful.iter().map(|f| f.quote_spent).sum();
+    let current_value: f64 = successful
+        .iter()
+        .map(|f| match f.pnl {
+            Some(pnl) => f.quote_spent + pnl,
+            None => f.base_received * mark_price,
+        })
+        .sum();
+    let fees_paid: f64 = successful.iter().map(|f| f.fee).sum();
+    let net_profit = current_value - invested - fees_paid;
+
+    let winners = successful
+        .iter()
+        .filter(|f| match f.pnl {
+            Some(pnl) => pnl > 0.0,
+            None => f.base_received * mark_price > f.quote_spent,
+        })
+        .count();
+
+    let slippages: Vec<f64> = successful.iter().filter_map(|f| f.slippage_bps).collect();
+
+    let mut flows: Vec<(DateTime<Utc>, f64)> = successful
+        .iter()
+        .map(|f| (f.executed_at, -(f.quote_spent + f.fee)))
+        .collect();
+    flows.push((now, current_value));
+
+    let pct = |value: f64| if invested > 0.0 { value / invested * 100.0 } else { 0.0 };
+
+    BotAnalytics {
+        bot_id: bot.id.clone(),
+        name: bot.name.clone(),
+        kind: bot.kind,
+        period,
+        benchmark: bot.benchmark.clone(),
+        execution_count: fills.len(),
+        successful_count: successful.len(),
+        invested,
+        current_value,
+        net_profit,
+        // Flows are denominated in the quote asset, so holding it is the zero line
+        benchmark_adjusted_return_pct: pct(net_profit),
+        irr_pct: xirr(&flows).map(|r| r * 100.0),
+        hit_rate_pct: if successful.is_empty() {
+            0.0
+        } else {
+            winners as f64 / successful.len() as f64 * 100.0
+        },
+        avg_slippage_bps: if slippages.is_empty() {
+            None
+        } else {
+            Some(slippages.iter().sum::<f64>() / slippages.len() as f64)
+        },
+        fees_paid,
+        fee_drag_pct: pct(fees_paid),
+    }
+}
+
+fn usd_price(symbol: &str) -> Option<f64> {
+    if STABLE_QUOTES.contains(&symbol.to_uppercase().as_str()) {
+        return Some(1.0);
+    }
+    get_price_engine()
+        .get_cached_price(symbol)
+        .map(|p| p.price)
+        .filter(|p| *p > 0.0)
+}
+
+async fn dca_fills(config: &DcaConfig, since: DateTime<Utc>) -> Result<(Vec<BotFill>, f64), String> {
+    let db = dca_database()?;
+    let db = db.read().await;
+    let executions = db.get_executions(&config.id).await.map_err(|e| e.to_string())?;
+    let costs = db.fill_costs(&config.id).await.map_err(|e| e.to_string())?;
+
+    let mut fills: Vec<BotFill> = executions
+        .iter()
+        .filter(|e| e.executed_at >= since && e.status != "skipped")
+        .map(|e| {
+            let cost = costs.get(&e.id);
+            BotFill {
+                executed_at: e.executed_at,
+                quote_spent: e.input_amount,
+                base_received: e.output_amount,
+                pnl: None,
+                slippage_bps: cost.map(|(slippage, _)| *slippage),
+                fee: cost.map(|(_, fee)| *fee).unwrap_or(0.0),
+                success: e.status == "success",
+            }
+        })
+        .collect();
+    fills.sort_by_key(|f| f.executed_at);
+
+    // Live prices when both legs are quoted, otherwise the latest fill price
+    let last_fill_price = executions
+        .iter()
+        .find(|e| e.status == "success" && e.price > 0.0)
+        .map(|e| e.price);
+    let mark = match (usd_price(&config.output_symbol), usd_price(&config.input_symbol)) {
+        (Some(base), Some(quote)) => base / quote,
+        _ => last_fill_price.map(|p| 1.0 / p).unwrap_or(0.0),
+    };
+
+    Ok((fills, mark))
+}
+
+async fn copy_trade_fills(config: &CopyTradeConfig, since: DateTime<Utc>) -> Result<Vec<BotFill>, String> {
+    let db = copy_trade_database()?;
+    let executions = db
+        .read()
+        .await
+        .get_executions(&config.id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut fills: Vec<BotFill> = executions
+        .iter()
+        .filter(|e| e.executed_at >= since && e.status != "skipped")
+        .map(|e| BotFill {
+            executed_at: e.executed_at,
+            quote_spent: e.copied_amount,
+            base_received: 0.0,
+            pnl: Some(e.pnl),
+            slippage_bps: None,
+            fee: 0.0,
+            success: e.status == "success",
+        })
+        .collect();
+    fills.sort_by_key(|f| f.executed_at);
+    Ok(fills)
+}
+
+async fn find_dca(bot_id: &str) -> Option<DcaConfig> {
+    let db = dca_database().ok()?;
+    let db = db.read().await;
+    db.get_config(bot_id).await.ok().flatten()
+}
+
+async fn find_copy_trade(bot_id: &str) -> Option<CopyTradeConfig> {
+    let db = copy_trade_database().ok()?;
+    let db = db.read().await;
+    db.get_config(bot_id).await.ok().flatten()
+}
+
+pub async fn bot_analytics(bot_id: &str, period: FeePeriod) -> Result<BotAnalytics, String> {
+    let now = Utc::now();
+    let since = period.start(now);
+
+    if let Some(config) = find_dca(bot_id).await {
+        let (fills, mark) = dca_fills(&config, since).await?;
+        return Ok(compute_bot_analytics(&BotInfo::from(&config), period, &fills, mark, now));
+    }
+
+    if let Some(config) = find_copy_trade(bot_id).await {
+        let fills = copy_trade_fills(&config, since).await?;
+        return Ok(compute_bot_analytics(&BotInfo::from(&config), period, &fills, 0.0, now));
+    }
+
+    Err(format!("Bot not found: {bot_id}"))
+}
+
+/// Per-month bot activity for a wallet's statement. Bots that aren't initialized are skipped.
+pub async fn bot_statement_lines(
+    wallet_address: &str,
+    since: DateTime<Utc>,
+) -> BTreeMap<String, Vec<BotStatementLine>> {
+    let mut months: BTreeMap<String, Vec<BotStatementLine>> = BTreeMap::new();
+    let mut add = |bot: &BotInfo, fill: &BotFill| {
+        let lines = months.entry(month_key(fill.executed_at)).or_default();
+        let line = match lines.iter().position(|l| l.bot_id == bot.id) {
+            Some(index) => &mut lines[index],
+            None => {
+                lines.push(BotStatementLine {
+                    bot_id: bot.id.clone(),
+                    name: bot.name.clone(),
+                    kind: bot.kind,
+                    invested: 0.0,
+                    pnl: 0.0,
+                    fees: 0.0,
+                });
+                lines.last_mut().expect("line was just pushed")
+            }
+        };
+        line.invested += fill.quote_spent;
+        line.pnl += fill.pnl.unwrap_or(0.0);
+        line.fees += fill.fee;
+    };
+
+    if let Ok(db) = dca_database() {
+        let configs = db.read().await.list_configs(wallet_address).await.unwrap_or_default();
+        for config in configs {
+            if let Ok((fills, _)) = dca_fills(&config, since).await {
+                let bot = BotInfo::from(&config);
+                for fill in fills.iter().filter(|f| f.success) {
+                    add(&bot, fill);
+                }
+            }
+        }
+    }
+
+    if let Ok(db) = copy_trade_database() {
+        let configs = db.read().await.list_configs(wallet_address).await.unwrap_or_default();
+        for config in configs {
+            if let Ok(fills) = copy_trade_fills(&config, since).await {
+                let bot = BotInfo::from(&config);
+                for fill in fills.iter().filter(|f| f.success) {
+                    add(&bot, fill);
+                }
+            }
+        }
+    }
+
+    months
+}
+
+#[tauri::command]
+pub async fn get_bot_analytics(bot_id: String, period: FeePeriod) -> Result<BotAnalytics, String> {
+    bot_analytics(&bot_id, period).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn fill(days_ago: i64, spent: f64, base: f64, now: DateTime<Utc>) -> BotFill {
+        BotFill {
+            executed_at: now - Duration::days(days_ago),
+            quote_spent: spent,
+            base_received: base,
+            pnl: None,
+            slippage_bps: Some(20.0),
+            fee: spent * 0.001,
+            success: true,
+        }
+    }
+
+    #[test]
+    fn xirr_matches_simple_annual_return() {
+        let start = Utc::now() - Duration::days(365);
+        let rate = xirr(&[(start, -100.0), (start + Duration::days(365), 110.0)]).unwrap();
+        assert!((rate - 0.0999).abs() < 0.002);
+        assert!(xirr(&[(start, -100.0)]).is_none());
+    }
+
+    #[test]
+    fn dca_metrics_use_mark_price() {
+        let now = Utc::now();
+        let fills = vec![fill(60, 100.0, 1.0, now), fill(30, 100.0, 0.5, now)];
+
+        let bot = BotInfo {
+            id: "dca".to_string(),
+            name: "Weekly SOL".to_string(),
+            kind: BotKind::Dca,
+            benchmark: "USDC".to_string(),
+        };
+        let analytics = compute_bot_analytics(&bot, FeePeriod::All, &fills, 150.0, now);
+
+        assert_eq!(analytics.invested, 200.0);
+        assert_eq!(analytics.current_value, 225.0);
+        assert!((analytics.net_profit - 24.8).abs() < 1e-9);
+        assert_eq!(analytics.hit_rate_pct, 50.0);
+        assert_eq!(analytics.avg_slippage_bps, Some(20.0));
+        assert!((analytics.fee_drag_pct - 0.1).abs() < 1e-9);
+        assert!(analytics.irr_pct.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn copy_trades_use_reported_pnl() {
+        let now = Utc::now();
+        let mut win = fill(10, 100.0, 0.0, now);
+        win.pnl = Some(30.0);
+        win.slippage_bps = None;
+        let mut loss = fill(5, 100.0, 0.0, now);
+        loss.pnl = Some(-10.0);
+        loss.slippage_bps = None;
+        let mut failed = fill(1, 100.0, 0.0, now);
+        failed.success = false;
+
+        let bot = BotInfo {
+            id: "copy".to_string(),
+            name: "Follow whale".to_string(),
+            kind: BotKind::CopyTrade,
+            benchmark: "USD".to_string(),
+        };
+        let analytics = compute_bot_analytics(&bot, FeePeriod::Month, &[win, loss, failed], 0.0, now);
+
+        assert_eq!(analytics.execution_count, 3);
+        assert_eq!(analytics.successful_count, 2);
+        assert_eq!(analytics.current_value, 220.0);
+        assert_eq!(analytics.hit_rate_pct, 50.0);
+        assert!(analytics.avg_slippage_bps.is_none());
+    }
+}
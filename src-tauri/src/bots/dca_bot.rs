@@ -1,6 +1,16 @@
 use crate::api::jupiter::{
     jupiter_quote, PriorityFeeConfig, QuoteCommandInput, QuoteResult, SwapMode,
 };
+use crate::config::entity_versions::{
+    bump_entity_version, entity_version, initialize_entity_versions, VersionBump,
+    VersionConflict, VersionedEditError,
+};
+use crate::config::version_history::{record_config_change, ConfigEntity, LOCAL_ACTOR};
+use crate::data::event_store::{record_app_event, Event as AuditEvent};
+use crate::idempotency::run_idempotent;
+use crate::trading::execution_presets::{
+    record_execution_preset, resolve_execution, ExecutionPresetSource,
+};
 use chrono::{DateTime, NaiveDateTime, Utc};
 use cron::Schedule;
 use serde::{Deserialize, Serialize};
@@ -44,6 +54,10 @@ pub struct DcaConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[sqlx(try_from = "Option<String>")]
     pub next_execution: Option<DateTime<Utc>>,
+    /// Incremented on every edit; kept in `entity_versions`, not `dca_configs`.
+    #[serde(default)]
+    #[sqlx(default)]
+    pub version: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -61,7 +75,7 @@ pub struct DcaExecution {
     pub tx_signature: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateDcaRequest {
     pub name: String,
     pub wallet_address: String,
@@ -179,6 +193,20 @@ impl DcaDatabase {
         .execute(&self.pool)
         .await?;
 
+        // Execution costs live beside dca_executions so older databases keep working
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS dca_fill_costs (
+                execution_id TEXT PRIMARY KEY,
+                slippage_bps REAL NOT NULL,
+                fee_amount REAL NOT NULL,
+                FOREIGN KEY (execution_id) REFERENCES dca_executions(id) ON DELETE CASCADE
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
         sqlx::query(
             r#"
             CREATE INDEX IF NOT EXISTS idx_dca_configs_active ON dca_configs(is_active);
@@ -191,6 +219,8 @@ impl DcaDatabase {
         .execute(&self.pool)
         .await?;
 
+        initialize_entity_versions(&self.pool).await?;
+
         Ok(())
     }
 
@@ -241,19 +271,35 @@ impl DcaDatabase {
     }
 
     pub async fn get_config(&self, id: &str) -> Result<Option<DcaConfig>, sqlx::Error> {
-        sqlx::query_as::<_, DcaConfig>("SELECT * FROM dca_configs WHERE id = ?1")
+        let mut config = sqlx::query_as::<_, DcaConfig>("SELECT * FROM dca_configs WHERE id = ?1")
             .bind(id)
             .fetch_optional(&self.pool)
-            .await
+            .await?;
+        if let Some(config) = config.as_mut() {
+            config.version = entity_version(&self.pool, id).await?;
+        }
+        Ok(config)
     }
 
     pub async fn list_configs(&self, wallet_address: &str) -> Result<Vec<DcaConfig>, sqlx::Error> {
-        sqlx::query_as::<_, DcaConfig>(
+        let mut configs = sqlx::query_as::<_, DcaConfig>(
             "SELECT * FROM dca_configs WHERE wallet_address = ?1 ORDER BY created_at DESC",
         )
         .bind(wallet_address)
         .fetch_all(&self.pool)
-        .await
+        .await?;
+        for config in configs.iter_mut() {
+            config.version = entity_version(&self.pool, &config.id).await?;
+        }
+        Ok(configs)
+    }
+
+    pub async fn bump_config_version(
+        &self,
+        id: &str,
+        expected_version: Option<i64>,
+    ) -> Result<VersionBump, sqlx::Error> {
+        bump_entity_version(&self.pool, id, expected_version).await
     }
 
     pub async fn get_active_configs(&self) -> Result<Vec<DcaConfig>, sqlx::Error> {
@@ -325,6 +371,48 @@ impl DcaDatabase {
         Ok(())
     }
 
+    /// Rewrites the user-editable fields of a config, inserting it again if it was
+    /// deleted. Spend and execution window of a live config are left untouched.
+    pub async fn restore_config(&self, config: &DcaConfig) -> Result<(), sqlx::Error> {
+        if self.get_config(&config.id).await?.is_none() {
+            return self.create_config(config).await;
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE dca_configs SET
+                name = ?1, wallet_address = ?2, input_mint = ?3, output_mint = ?4,
+                input_symbol = ?5, output_symbol = ?6, input_decimals = ?7, output_decimals = ?8,
+                amount_per_execution = ?9, total_budget = ?10, schedule_cron = ?11,
+                slippage_bps = ?12, priority_fee_micro_lamports = ?13, max_price_impact_pct = ?14,
+                daily_spend_cap = ?15, is_active = ?16, updated_at = ?17
+            WHERE id = ?18
+            "#,
+        )
+        .bind(&config.name)
+        .bind(&config.wallet_address)
+        .bind(&config.input_mint)
+        .bind(&config.output_mint)
+        .bind(&config.input_symbol)
+        .bind(&config.output_symbol)
+        .bind(config.input_decimals)
+        .bind(config.output_decimals)
+        .bind(config.amount_per_execution)
+        .bind(config.total_budget)
+        .bind(&config.schedule_cron)
+        .bind(config.slippage_bps)
+        .bind(config.priority_fee_micro_lamports)
+        .bind(config.max_price_impact_pct)
+        .bind(config.daily_spend_cap)
+        .bind(if config.is_active { 1 } else { 0 })
+        .bind(Utc::now().to_rfc3339())
+        .bind(&config.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn delete_config(&self, id: &str) -> Result<(), sqlx::Error> {
         sqlx::query("DELETE FROM dca_configs WHERE id = ?1")
             .bind(id)
@@ -359,6 +447,47 @@ impl DcaDatabase {
         Ok(())
     }
 
+    pub async fn record_fill_cost(
+        &self,
+        execution_id: &str,
+        slippage_bps: f64,
+        fee_amount: f64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO dca_fill_costs (execution_id, slippage_bps, fee_amount) VALUES (?1, ?2, ?3)",
+        )
+        .bind(execution_id)
+        .bind(slippage_bps)
+        .bind(fee_amount)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Slippage (bps) and fee (input units) per execution id.
+    pub async fn fill_costs(&self, dca_id: &str) -> Result<HashMap<String, (f64, f64)>, sqlx::Error> {
+        let rows = sqlx::query(
+            r#"
+            SELECT c.execution_id, c.slippage_bps, c.fee_amount
+            FROM dca_fill_costs c
+            JOIN dca_executions e ON e.id = c.execution_id
+            WHERE e.dca_config_id = ?1
+            "#,
+        )
+        .bind(dca_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok((
+                    row.try_get("execution_id")?,
+                    (row.try_get("slippage_bps")?, row.try_get("fee_amount")?),
+                ))
+            })
+            .collect()
+    }
+
     pub async fn get_executions(&self, dca_id: &str) -> Result<Vec<DcaExecution>, sqlx::Error> {
         sqlx::query_as::<_, DcaExecution>(
             "SELECT * FROM dca_executions WHERE dca_config_id = ?1 ORDER BY executed_at DESC",
@@ -481,6 +610,7 @@ impl DcaManager {
             updated_at: Utc::now(),
             last_execution: None,
             next_execution: Some(next_execution),
+            version: 1,
         };
 
         self.db
@@ -517,7 +647,43 @@ impl DcaManager {
             .map_err(|e| format!("Failed to list DCA configs: {e}"))
     }
 
-    pub async fn pause_dca(&self, id: &str) -> Result<(), String> {
+    /// Rejects the edit when `expected_version` is set and the config changed since.
+    async fn bump_version(
+        &self,
+        id: &str,
+        expected_version: Option<i64>,
+    ) -> Result<i64, VersionedEditError> {
+        let bump = self
+            .db
+            .read()
+            .await
+            .bump_config_version(id, expected_version)
+            .await
+            .map_err(|e| format!("Failed to update DCA version: {e}"))?;
+
+        match bump {
+            VersionBump::Bumped(version) => Ok(version),
+            VersionBump::Stale { latest } => {
+                let current = self.get_dca(id).await?;
+                Err(VersionConflict::new(
+                    "dca_bot",
+                    id,
+                    expected_version.unwrap_or_default(),
+                    latest,
+                    Some(&current),
+                )
+                .into())
+            }
+        }
+    }
+
+    pub async fn pause_dca(
+        &self,
+        id: &str,
+        expected_version: Option<i64>,
+    ) -> Result<(), VersionedEditError> {
+        self.get_dca(id).await?;
+        self.bump_version(id, expected_version).await?;
         self.db
             .write()
             .await
@@ -529,7 +695,11 @@ impl DcaManager {
         Ok(())
     }
 
-    pub async fn resume_dca(&self, id: &str) -> Result<DcaConfig, String> {
+    pub async fn resume_dca(
+        &self,
+        id: &str,
+        expected_version: Option<i64>,
+    ) -> Result<DcaConfig, VersionedEditError> {
         let mut config = self.get_dca(id).await?;
         let schedule = Schedule::from_str(&config.schedule_cron)
             .map_err(|e| format!("Invalid stored cron expression: {e}"))?;
@@ -538,6 +708,7 @@ impl DcaManager {
             .upcoming(Utc)
             .next()
             .ok_or_else(|| "Unable to determine next execution time".to_string())?;
+        config.version = self.bump_version(id, expected_version).await?;
 
         self.db
             .write()
@@ -577,6 +748,41 @@ impl DcaManager {
         Ok(())
     }
 
+    pub async fn restore_dca(&self, mut config: DcaConfig) -> Result<DcaConfig, String> {
+        let schedule = Schedule::from_str(&config.schedule_cron)
+            .map_err(|e| format!("Invalid cron expression: {e}"))?;
+
+        let existing = self.get_dca(&config.id).await.ok();
+        if existing.is_none() {
+            config.next_execution = schedule.upcoming(Utc).next();
+        }
+
+        self.db
+            .write()
+            .await
+            .restore_config(&config)
+            .await
+            .map_err(|e| format!("Failed to restore DCA config: {e}"))?;
+
+        if config.is_active {
+            self.schedules
+                .write()
+                .await
+                .insert(config.id.clone(), schedule);
+        } else {
+            self.schedules.write().await.remove(&config.id);
+        }
+
+        self.db
+            .read()
+            .await
+            .bump_config_version(&config.id, None)
+            .await
+            .map_err(|e| format!("Failed to update DCA version: {e}"))?;
+
+        self.get_dca(&config.id).await
+    }
+
     pub async fn executions(&self, id: &str) -> Result<Vec<DcaExecution>, String> {
         self.db
             .read()
@@ -642,6 +848,11 @@ impl DcaManager {
     }
 
     pub async fn check_and_execute(&self) -> Result<(), String> {
+        // Due runs stay due and go out once the macro event window has passed.
+        if crate::market::automation_blackout(&self.app_handle).await.is_some() {
+            return Ok(());
+        }
+
         let due_configs = self
             .db
             .read()
@@ -709,19 +920,27 @@ impl DcaManager {
         }
 
         let amount_in_units = to_base_units(config.amount_per_execution, config.input_decimals)?;
+        let execution = resolve_execution(
+            &self.app_handle,
+            &[config.output_mint.as_str(), config.input_mint.as_str()],
+            config.slippage_bps.clamp(0, u16::MAX as i32) as u16,
+            config.priority_fee_micro_lamports.max(0) as u64,
+            None,
+        )
+        .await;
 
         let quote_input = QuoteCommandInput {
             input_mint: config.input_mint.clone(),
             output_mint: config.output_mint.clone(),
             amount: amount_in_units,
-            slippage_bps: Some(config.slippage_bps as u16),
+            slippage_bps: Some(execution.params.slippage_bps),
             swap_mode: Some(SwapMode::ExactIn),
             platform_fee_bps: None,
             only_direct_routes: None,
             referral_account: None,
             as_legacy_transaction: None,
             priority_fee_config: Some(PriorityFeeConfig {
-                compute_unit_price_micro_lamports: Some(config.priority_fee_micro_lamports as u64),
+                compute_unit_price_micro_lamports: Some(execution.params.priority_fee_micro_lamports),
                 auto_multiplier: None,
             }),
         };
@@ -759,16 +978,50 @@ impl DcaManager {
 
         let execution_time = Utc::now();
 
-        self.log_execution(
-            config,
-            input_amount,
-            output_amount,
-            price,
-            "success",
-            None,
-            Some(format!("simulated_{}", Uuid::new_v4())),
+        let execution_id = self
+            .log_execution(
+                config,
+                input_amount,
+                output_amount,
+                price,
+                "success",
+                None,
+                Some(format!("simulated_{}", Uuid::new_v4())),
+            )
+            .await?;
+        record_execution_preset(
+            &self.app_handle,
+            &execution_id,
+            ExecutionPresetSource::Dca,
+            &execution,
         )
-        .await?;
+        .await;
+        record_app_event(
+            &self.app_handle,
+            &format!("dca_{}", config.id),
+            AuditEvent::BotTradeExecuted {
+                bot_type: "dca".to_string(),
+                bot_id: config.id.clone(),
+                execution_id: execution_id.clone(),
+                input_mint: config.input_mint.clone(),
+                output_mint: config.output_mint.clone(),
+                input_amount,
+                output_amount,
+                timestamp: execution_time,
+            },
+        )
+        .await;
+
+        let fee_amount = input_amount * quote_result.route.total_fee_bps as f64 / 10_000.0;
+        if let Err(err) = self
+            .db
+            .write()
+            .await
+            .record_fill_cost(&execution_id, price_impact_pct * 100.0, fee_amount)
+            .await
+        {
+            eprintln!("Failed to record DCA fill cost: {err}");
+        }
 
         let new_spent = config.spent_amount + input_amount;
         self.db
@@ -800,7 +1053,7 @@ impl DcaManager {
         status: &str,
         error_message: Option<String>,
         tx_signature: Option<String>,
-    ) -> Result<(), String> {
+    ) -> Result<String, String> {
         let execution = DcaExecution {
             id: Uuid::new_v4().to_string(),
             dca_config_id: config.id.clone(),
@@ -819,7 +1072,9 @@ impl DcaManager {
             .await
             .record_execution(&execution)
             .await
-            .map_err(|e| format!("Failed to persist execution log: {e}"))
+            .map_err(|e| format!("Failed to persist execution log: {e}"))?;
+
+        Ok(execution.id)
     }
 
     async fn schedule_next(
@@ -970,15 +1225,33 @@ fn require_state<'a>() -> Result<&'a DcaState, String> {
         .ok_or_else(|| "DCA module not initialized".to_string())
 }
 
+pub fn dca_manager() -> Result<SharedDcaManager, String> {
+    require_state().map(|state| state.manager.clone())
+}
+
+pub fn dca_database() -> Result<SharedDcaDatabase, String> {
+    require_state().map(|state| state.db.clone())
+}
+
 #[tauri::command]
 pub async fn dca_init(handle: AppHandle) -> Result<(), String> {
     init_dca(&handle).await
 }
 
 #[tauri::command]
-pub async fn dca_create(request: CreateDcaRequest) -> Result<DcaConfig, String> {
+pub async fn dca_create(
+    handle: AppHandle,
+    request: CreateDcaRequest,
+    idempotency_key: Option<String>,
+) -> Result<DcaConfig, String> {
     let state = require_state()?;
-    state.manager.create_dca(request).await
+    run_idempotent("dca_create", idempotency_key.as_deref(), &request, || async {
+        let config = state.manager.create_dca(request.clone()).await?;
+        record_config_change(&handle, ConfigEntity::dca_bot(&config.id), Some(&config), LOCAL_ACTOR)
+            .await;
+        Ok(config)
+    })
+    .await
 }
 
 #[tauri::command]
@@ -994,21 +1267,36 @@ pub async fn dca_get(id: String) -> Result<DcaConfig, String> {
 }
 
 #[tauri::command]
-pub async fn dca_pause(id: String) -> Result<(), String> {
+pub async fn dca_pause(
+    handle: AppHandle,
+    id: String,
+    expected_version: Option<i64>,
+) -> Result<(), VersionedEditError> {
     let state = require_state()?;
-    state.manager.pause_dca(&id).await
+    state.manager.pause_dca(&id, expected_version).await?;
+    let config = state.manager.get_dca(&id).await?;
+    record_config_change(&handle, ConfigEntity::dca_bot(&id), Some(&config), LOCAL_ACTOR).await;
+    Ok(())
 }
 
 #[tauri::command]
-pub async fn dca_resume(id: String) -> Result<DcaConfig, String> {
+pub async fn dca_resume(
+    handle: AppHandle,
+    id: String,
+    expected_version: Option<i64>,
+) -> Result<DcaConfig, VersionedEditError> {
     let state = require_state()?;
-    state.manager.resume_dca(&id).await
+    let config = state.manager.resume_dca(&id, expected_version).await?;
+    record_config_change(&handle, ConfigEntity::dca_bot(&id), Some(&config), LOCAL_ACTOR).await;
+    Ok(config)
 }
 
 #[tauri::command]
-pub async fn dca_delete(id: String) -> Result<(), String> {
+pub async fn dca_delete(handle: AppHandle, id: String) -> Result<(), String> {
     let state = require_state()?;
-    state.manager.delete_dca(&id).await
+    state.manager.delete_dca(&id).await?;
+    record_config_change::<DcaConfig>(&handle, ConfigEntity::dca_bot(&id), None, LOCAL_ACTOR).await;
+    Ok(())
 }
 
 #[tauri::command]
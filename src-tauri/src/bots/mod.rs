@@ -1,3 +1,5 @@
+pub mod analytics;
 pub mod dca_bot;
 
+pub use analytics::*;
 pub use dca_bot::*;
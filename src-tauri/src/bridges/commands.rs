@@ -16,7 +16,7 @@ pub async fn bridge_get_quote(
     if let Some(prov_str) = provider {
         let prov = BridgeProvider::from_str(&prov_str)
             .ok_or_else(|| format!("Invalid bridge provider: {}", prov_str))?;
-        let adapter = get_bridge_adapter(&prov);
+        let adapter = get_bridge_adapter(&prov)?;
         let quote = adapter.quote(&request).await?;
         quotes.push(quote);
     } else {
@@ -47,7 +47,7 @@ pub async fn bridge_create_transaction(
     request: BridgeTransactionRequest,
     bridge_manager: State<'_, SharedBridgeManager>,
 ) -> Result<BridgeTransaction, String> {
-    let adapter = get_bridge_adapter(&request.provider);
+    let adapter = get_bridge_adapter(&request.provider)?;
     let mut transaction = adapter.prepare_transaction(&request).await?;
     
     let mut manager = bridge_manager.write().await;
@@ -134,7 +134,7 @@ pub async fn bridge_poll_status(
     let prov = BridgeProvider::from_str(&provider)
         .ok_or_else(|| format!("Invalid bridge provider: {}", provider))?;
     
-    let adapter = get_bridge_adapter(&prov);
+    let adapter = get_bridge_adapter(&prov)?;
     let status = adapter.poll_status(&transaction_id).await?;
     
     let mut manager = bridge_manager.write().await;
@@ -143,10 +143,11 @@ pub async fn bridge_poll_status(
     Ok(status)
 }
 
-fn get_bridge_adapter(provider: &BridgeProvider) -> SharedBridgeAdapter {
+fn get_bridge_adapter(provider: &BridgeProvider) -> Result<SharedBridgeAdapter, String> {
     match provider {
-        BridgeProvider::Wormhole => Arc::new(WormholeAdapter::new()),
-        BridgeProvider::AllBridge => Arc::new(AllBridgeAdapter::new()),
-        BridgeProvider::Synapse => Arc::new(SynapseAdapter::new()),
+        BridgeProvider::Wormhole => Ok(Arc::new(WormholeAdapter::new())),
+        BridgeProvider::AllBridge => Ok(Arc::new(AllBridgeAdapter::new())),
+        BridgeProvider::Synapse => Ok(Arc::new(SynapseAdapter::new())),
+        BridgeProvider::DeBridge => Err("deBridge transfers are tracked but cannot be initiated here".to_string()),
     }
 }
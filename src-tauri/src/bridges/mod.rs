@@ -3,12 +3,14 @@ pub mod wormhole;
 pub mod allbridge;
 pub mod synapse;
 pub mod commands;
+pub mod tracker;
 
 pub use types::*;
 pub use wormhole::*;
 pub use allbridge::*;
 pub use synapse::*;
 pub use commands::*;
+pub use tracker::*;
 
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -23,6 +25,7 @@ pub enum BridgeProvider {
     Wormhole,
     AllBridge,
     Synapse,
+    DeBridge,
 }
 
 impl BridgeProvider {
@@ -31,6 +34,7 @@ impl BridgeProvider {
             BridgeProvider::Wormhole => "wormhole",
             BridgeProvider::AllBridge => "allbridge",
             BridgeProvider::Synapse => "synapse",
+            BridgeProvider::DeBridge => "debridge",
         }
     }
 
@@ -39,6 +43,7 @@ impl BridgeProvider {
             "wormhole" => Some(BridgeProvider::Wormhole),
             "allbridge" => Some(BridgeProvider::AllBridge),
             "synapse" => Some(BridgeProvider::Synapse),
+            "debridge" | "dln" => Some(BridgeProvider::DeBridge),
             _ => None,
         }
     }
@@ -77,7 +82,7 @@ pub struct BridgeTransactionRequest {
     pub sender_address: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum BridgeTransactionStatus {
     Pending,
@@ -88,6 +93,27 @@ pub enum BridgeTransactionStatus {
     Failed,
 }
 
+impl BridgeTransactionStatus {
+    fn stage(&self) -> u8 {
+        match self {
+            BridgeTransactionStatus::Pending => 0,
+            BridgeTransactionStatus::Submitted => 1,
+            BridgeTransactionStatus::Confirmed => 2,
+            BridgeTransactionStatus::Bridging => 3,
+            BridgeTransactionStatus::Completed | BridgeTransactionStatus::Failed => 4,
+        }
+    }
+
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, BridgeTransactionStatus::Completed | BridgeTransactionStatus::Failed)
+    }
+
+    /// Bridges only move forward; a transfer can fail at any point before it completes.
+    pub fn can_transition_to(&self, next: &BridgeTransactionStatus) -> bool {
+        !self.is_terminal() && next.stage() > self.stage()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BridgeTransaction {
     pub id: String,
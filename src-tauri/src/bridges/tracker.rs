@@ -0,0 +1,949 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use super::{BridgeProvider, BridgeTransactionStatus};
+use crate::chains::{ChainId, SharedChainManager};
+use crate::notifications::router::SharedNotificationRouter;
+use crate::portfolio::{LotCarryOver, SharedPortfolioData, SharedTaxLotsState};
+use crate::wallet::multi_wallet::MultiWalletManager;
+
+const BRIDGE_TRACKER_DB_FILE: &str = "bridge_tracker.db";
+const POLL_INTERVAL_SECS: u64 = 120;
+const BRIDGE_STATUS_EVENT: &str = "bridge_status_changed";
+
+const WORMHOLESCAN_OPERATIONS_URL: &str =
+    "https://api.wormholescan.io/api/v1/operations?address={address}&page=0&pageSize=50&sortOrder=DESC";
+const DEBRIDGE_ORDERS_URL: &str = "https://stats-api.dl.mn/api/Orders/filteredList";
+
+static NULL_VALUE: Value = Value::Null;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BridgeTrackerError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("bridge not found: {0}")]
+    NotFound(String),
+    #[error("invalid status change: {0}")]
+    InvalidTransition(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+/// A bridge transfer involving one of the tracked addresses, as reported by the bridge's indexer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BridgeObservation {
+    pub provider: BridgeProvider,
+    pub external_id: String,
+    pub from_chain: Option<ChainId>,
+    pub to_chain: Option<ChainId>,
+    pub sender: Option<String>,
+    pub recipient: Option<String>,
+    pub token_symbol: String,
+    pub source_token: String,
+    pub destination_token: String,
+    pub amount_sent: f64,
+    pub amount_received: Option<f64>,
+    pub usd_value: f64,
+    pub source_tx_hash: Option<String>,
+    pub destination_tx_hash: Option<String>,
+    pub status: BridgeTransactionStatus,
+    pub started_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackedBridge {
+    pub id: String,
+    pub provider: BridgeProvider,
+    pub wallet: String,
+    pub from_chain: Option<ChainId>,
+    pub to_chain: Option<ChainId>,
+    pub recipient: Option<String>,
+    pub token_symbol: String,
+    pub source_token: String,
+    pub destination_token: String,
+    pub amount_sent: f64,
+    pub amount_received: Option<f64>,
+    pub usd_value: f64,
+    pub source_tx_hash: Option<String>,
+    pub destination_tx_hash: Option<String>,
+    pub status: BridgeTransactionStatus,
+    /// Whether the destination transaction was confirmed against the destination chain's RPC.
+    pub destination_verified: bool,
+    /// Whether tax lots have been carried over to the destination asset.
+    pub basis_carried: bool,
+    pub note: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeTrackerSettings {
+    pub providers: Vec<BridgeProvider>,
+    /// Addresses on other chains (e.g. EVM receive addresses) to watch alongside the wallets.
+    pub additional_addresses: Vec<String>,
+    pub carry_cost_basis: bool,
+    pub verify_destination: bool,
+}
+
+impl Default for BridgeTrackerSettings {
+    fn default() -> Self {
+        Self {
+            providers: vec![BridgeProvider::Wormhole, BridgeProvider::DeBridge],
+            additional_addresses: Vec::new(),
+            carry_cost_basis: true,
+            verify_destination: true,
+        }
+    }
+}
+
+fn wormhole_chain(id: u64) -> Option<ChainId> {
+    match id {
+        1 => Some(ChainId::Solana),
+        2 => Some(ChainId::Ethereum),
+        5 => Some(ChainId::Polygon),
+        23 => Some(ChainId::Arbitrum),
+        30 => Some(ChainId::Base),
+        _ => None,
+    }
+}
+
+fn debridge_chain(id: u64) -> Option<ChainId> {
+    match id {
+        7565164 => Some(ChainId::Solana),
+        1 => Some(ChainId::Ethereum),
+        137 => Some(ChainId::Polygon),
+        42161 => Some(ChainId::Arbitrum),
+        8453 => Some(ChainId::Base),
+        _ => None,
+    }
+}
+
+/// Reads a field that may be a plain value or one of deBridge's `{ stringValue }` wrappers.
+fn text_at(value: &Value, path: &[&str]) -> Option<String> {
+    let mut current = value;
+    for key in path {
+        current = current.get(*key)?;
+    }
+    match current {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Object(_) => ["stringValue", "bigIntegerValue"]
+            .iter()
+            .find_map(|k| text_at(current, &[*k])),
+        _ => None,
+    }
+}
+
+fn number_at(value: &Value, path: &[&str]) -> Option<f64> {
+    text_at(value, path).and_then(|s| s.parse().ok())
+}
+
+fn timestamp_at(value: &Value, path: &[&str]) -> Option<DateTime<Utc>> {
+    let raw = text_at(value, path)?;
+    if let Ok(secs) = raw.parse::<i64>() {
+        return Utc.timestamp_opt(secs, 0).single();
+    }
+    DateTime::parse_from_rfc3339(&raw).ok().map(|dt| dt.with_timezone(&Utc))
+}
+
+pub fn parse_wormhole_operations(body: &Value) -> Vec<BridgeObservation> {
+    let Some(operations) = body.get("operations").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+
+    operations
+        .iter()
+        .filter_map(|op| {
+            let external_id = text_at(op, &["id"])?;
+            let props = op.pointer("/content/standarizedProperties").unwrap_or(&NULL_VALUE);
+            let destination_tx_hash = text_at(op, &["targetChain", "transaction", "txHash"]);
+
+            let status = if destination_tx_hash.is_some()
+                || text_at(op, &["targetChain", "status"]).as_deref() == Some("completed")
+            {
+                BridgeTransactionStatus::Completed
+            } else if op.get("vaa").is_some_and(|v| !v.is_null()) {
+                BridgeTransactionStatus::Bridging
+            } else if text_at(op, &["sourceChain", "status"]).as_deref() == Some("confirmed") {
+                BridgeTransactionStatus::Confirmed
+            } else {
+                BridgeTransactionStatus::Submitted
+            };
+
+            let amount = number_at(op, &["data", "tokenAmount"]).unwrap_or(0.0);
+            let token = text_at(props, &["tokenAddress"]).unwrap_or_default();
+
+            Some(BridgeObservation {
+                provider: BridgeProvider::Wormhole,
+                external_id,
+                from_chain: number_at(op, &["sourceChain", "chainId"]).and_then(|c| wormhole_chain(c as u64)),
+                to_chain: number_at(props, &["toChain"]).and_then(|c| wormhole_chain(c as u64)),
+                sender: text_at(op, &["sourceChain", "from"]),
+                recipient: text_at(props, &["toAddress"]).or_else(|| text_at(op, &["targetChain", "to"])),
+                token_symbol: text_at(op, &["data", "symbol"]).unwrap_or_else(|| "UNKNOWN".to_string()),
+                source_token: token.clone(),
+                // Wormhole delivers a wrapped representation of the same token
+                destination_token: token,
+                amount_sent: amount,
+                amount_received: (status == BridgeTransactionStatus::Completed).then_some(amount),
+                usd_value: number_at(op, &["data", "usdAmount"]).unwrap_or(0.0),
+                source_tx_hash: text_at(op, &["sourceChain", "transaction", "txHash"]),
+                destination_tx_hash,
+                status,
+                started_at: timestamp_at(op, &["sourceChain", "timestamp"]).unwrap_or_else(Utc::now),
+            })
+        })
+        .collect()
+}
+
+fn debridge_amount(offer: &Value) -> Option<f64> {
+    let raw = number_at(offer, &["finalAmount"]).or_else(|| number_at(offer, &["amount"]))?;
+    let decimals = number_at(offer, &["metadata", "decimals"]).unwrap_or(0.0);
+    Some(raw / 10f64.powi(decimals as i32))
+}
+
+pub fn parse_debridge_orders(body: &Value) -> Vec<BridgeObservation> {
+    let Some(orders) = body.get("orders").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+
+    orders
+        .iter()
+        .filter_map(|order| {
+            let external_id = text_at(order, &["orderId"])?;
+            let give = order.get("giveOfferWithMetadata").unwrap_or(&NULL_VALUE);
+            let take = order.get("takeOfferWithMetadata").unwrap_or(&NULL_VALUE);
+
+            let status = match text_at(order, &["state"]).unwrap_or_default().as_str() {
+                "Fulfilled" | "SentUnlock" | "ClaimedUnlock" => BridgeTransactionStatus::Completed,
+                "OrderCancelled" | "SentOrderCancel" | "ClaimedOrderCancel" => BridgeTransactionStatus::Failed,
+                // The order exists on the source chain and waits for a taker on the destination
+                _ => BridgeTransactionStatus::Bridging,
+            };
+
+            Some(BridgeObservation {
+                provider: BridgeProvider::DeBridge,
+                external_id,
+                from_chain: number_at(give, &["chainId"]).and_then(|c| debridge_chain(c as u64)),
+                to_chain: number_at(take, &["chainId"]).and_then(|c| debridge_chain(c as u64)),
+                sender: text_at(order, &["makerSrc"]),
+                recipient: text_at(order, &["receiverDst"]),
+                token_symbol: text_at(give, &["metadata", "symbol"]).unwrap_or_else(|| "UNKNOWN".to_string()),
+                source_token: text_at(give, &["tokenAddress"]).unwrap_or_default(),
+                destination_token: text_at(take, &["tokenAddress"]).unwrap_or_default(),
+                amount_sent: debridge_amount(give).unwrap_or(0.0),
+                amount_received: debridge_amount(take).filter(|_| status == BridgeTransactionStatus::Completed),
+                usd_value: number_at(order, &["usdAmount"]).unwrap_or(0.0),
+                source_tx_hash: text_at(order, &["createEventTransactionHash"]),
+                destination_tx_hash: text_at(order, &["fulfilledDstEventMetadata", "transactionHash"]),
+                status,
+                started_at: timestamp_at(order, &["creationTimestamp"]).unwrap_or_else(Utc::now),
+            })
+        })
+        .collect()
+}
+
+/// Applies an observation to a tracked bridge. Returns true when the status advanced.
+pub fn apply_observation(bridge: &mut TrackedBridge, observation: &BridgeObservation) -> bool {
+    if observation.destination_tx_hash.is_some() {
+        bridge.destination_tx_hash = observation.destination_tx_hash.clone();
+    }
+    if observation.amount_received.is_some() {
+        bridge.amount_received = observation.amount_received;
+    }
+    if observation.usd_value > 0.0 {
+        bridge.usd_value = observation.usd_value;
+    }
+
+    if !bridge.status.can_transition_to(&observation.status) {
+        return false;
+    }
+    bridge.status = observation.status.clone();
+    bridge.updated_at = Utc::now();
+    if bridge.status.is_terminal() {
+        bridge.completed_at = Some(bridge.updated_at);
+    }
+    true
+}
+
+fn status_str(status: &BridgeTransactionStatus) -> String {
+    serde_json::to_value(status)
+        .ok()
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default()
+}
+
+fn parse_time(raw: &str) -> Result<DateTime<Utc>, BridgeTrackerError> {
+    DateTime::parse_from_rfc3339(raw)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| BridgeTrackerError::Internal(e.to_string()))
+}
+
+pub struct BridgeTracker {
+    pool: Pool<Sqlite>,
+    http: reqwest::Client,
+}
+
+pub type SharedBridgeTracker = Arc<RwLock<BridgeTracker>>;
+
+impl BridgeTracker {
+    pub async fn new(app: &AppHandle) -> Result<Self, BridgeTrackerError> {
+        let db_path = bridge_tracker_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let tracker = Self {
+            pool,
+            http: reqwest::Client::new(),
+        };
+        tracker.initialize().await?;
+        Ok(tracker)
+    }
+
+    async fn initialize(&self) -> Result<(), BridgeTrackerError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS tracked_bridges (
+                id TEXT PRIMARY KEY,
+                provider TEXT NOT NULL,
+                wallet TEXT NOT NULL,
+                data TEXT NOT NULL,
+                status TEXT NOT NULL,
+                started_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_tracked_bridges_status ON tracked_bridges(status)")
+            .execute(&self.pool)
+            .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS bridge_tracker_settings (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                settings TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn settings(&self) -> Result<BridgeTrackerSettings, BridgeTrackerError> {
+        let row = sqlx::query("SELECT settings FROM bridge_tracker_settings WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match row {
+            Some(row) => {
+                let settings: String = row.try_get("settings")?;
+                Ok(serde_json::from_str(&settings)?)
+            }
+            None => Ok(BridgeTrackerSettings::default()),
+        }
+    }
+
+    pub async fn set_settings(&self, settings: &BridgeTrackerSettings) -> Result<(), BridgeTrackerError> {
+        sqlx::query(
+            r#"
+            INSERT INTO bridge_tracker_settings (id, settings) VALUES (1, ?)
+            ON CONFLICT(id) DO UPDATE SET settings = excluded.settings
+            "#,
+        )
+        .bind(serde_json::to_string(settings)?)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    fn row_to_bridge(row: &SqliteRow) -> Result<TrackedBridge, BridgeTrackerError> {
+        let data: String = row.try_get("data")?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    pub async fn list(&self, pending_only: bool) -> Result<Vec<TrackedBridge>, BridgeTrackerError> {
+        let query = if pending_only {
+            "SELECT data FROM tracked_bridges WHERE status NOT IN ('completed', 'failed') ORDER BY started_at DESC"
+        } else {
+            "SELECT data FROM tracked_bridges ORDER BY started_at DESC"
+        };
+        let rows = sqlx::query(query).fetch_all(&self.pool).await?;
+        rows.iter().map(Self::row_to_bridge).collect()
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Option<TrackedBridge>, BridgeTrackerError> {
+        let row = sqlx::query("SELECT data FROM tracked_bridges WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        row.as_ref().map(Self::row_to_bridge).transpose()
+    }
+
+    pub async fn save(&self, bridge: &TrackedBridge) -> Result<(), BridgeTrackerError> {
+        sqlx::query(
+            r#"
+            INSERT INTO tracked_bridges (id, provider, wallet, data, status, started_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                data = excluded.data,
+                status = excluded.status,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&bridge.id)
+        .bind(bridge.provider.as_str())
+        .bind(&bridge.wallet)
+        .bind(serde_json::to_string(bridge)?)
+        .bind(status_str(&bridge.status))
+        .bind(bridge.started_at.to_rfc3339())
+        .bind(bridge.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn fetch_wormhole(&self, address: &str) -> Result<Vec<BridgeObservation>, BridgeTrackerError> {
+        let url = WORMHOLESCAN_OPERATIONS_URL.replace("{address}", address);
+        let body: Value = self.http.get(&url).send().await?.error_for_status()?.json().await?;
+        Ok(parse_wormhole_operations(&body))
+    }
+
+    async fn fetch_debridge(&self, address: &str) -> Result<Vec<BridgeObservation>, BridgeTrackerError> {
+        let body: Value = self
+            .http
+            .post(DEBRIDGE_ORDERS_URL)
+            .json(&json!({ "creator": address, "skip": 0, "take": 50 }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(parse_debridge_orders(&body))
+    }
+
+    pub async fn observe(
+        &self,
+        provider: &BridgeProvider,
+        address: &str,
+    ) -> Result<Vec<BridgeObservation>, BridgeTrackerError> {
+        match provider {
+            BridgeProvider::Wormhole => self.fetch_wormhole(address).await,
+            BridgeProvider::DeBridge => self.fetch_debridge(address).await,
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Checks that the destination transaction landed successfully on the destination chain.
+    /// `None` means the chain could not be queried.
+    pub async fn verify_destination(&self, chain: &ChainId, rpc_url: &str, tx_hash: &str) -> Option<bool> {
+        let payload = match chain {
+            ChainId::Solana => json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "getSignatureStatuses",
+                "params": [[tx_hash], { "searchTransactionHistory": true }]
+            }),
+            _ => json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "eth_getTransactionReceipt",
+                "params": [tx_hash]
+            }),
+        };
+
+        let response: Value = self
+            .http
+            .post(rpc_url)
+            .json(&payload)
+            .send()
+            .await
+            .ok()?
+            .json()
+            .await
+            .ok()?;
+
+        match chain {
+            ChainId::Solana => {
+                let status = response.pointer("/result/value/0")?;
+                Some(!status.is_null() && status.get("err").map_or(true, Value::is_null))
+            }
+            _ => {
+                let receipt = response.get("result")?;
+                Some(receipt.get("status").and_then(|s| s.as_str()) == Some("0x1"))
+            }
+        }
+    }
+}
+
+fn bridge_tracker_db_path(app: &AppHandle) -> Result<PathBuf, BridgeTrackerError> {
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| BridgeTrackerError::Internal("Unable to resolve app data directory".to_string()))?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(BRIDGE_TRACKER_DB_FILE))
+}
+
+fn tracked_addresses(app: &AppHandle, settings: &BridgeTrackerSettings) -> Vec<String> {
+    let mut addresses: Vec<String> = Vec::new();
+    if let Some(wallets) = app.try_state::<MultiWalletManager>() {
+        match wallets.list_wallets() {
+            Ok(list) => addresses.extend(list.into_iter().map(|w| w.public_key)),
+            Err(err) => tracing::warn!(error = %err, "failed to list wallets for bridge tracking"),
+        }
+    }
+    addresses.extend(settings.additional_addresses.iter().cloned());
+
+    let mut seen = HashSet::new();
+    addresses.retain(|a| seen.insert(a.to_lowercase()));
+    addresses
+}
+
+async fn rpc_url_for(app: &AppHandle, chain: &ChainId) -> Option<String> {
+    let chains = app.try_state::<SharedChainManager>()?;
+    let guard = chains.read().await;
+    guard.get_chain_config(chain).map(|c| c.rpc_url.clone())
+}
+
+/// Carries the source lots over to the destination asset once a bridge completes.
+fn carry_cost_basis(app: &AppHandle, bridge: &mut TrackedBridge) {
+    let Some(lots) = app.try_state::<SharedTaxLotsState>() else {
+        return;
+    };
+    let Some(amount_received) = bridge.amount_received.filter(|a| *a > 0.0) else {
+        bridge.note = Some("Received amount unknown; cost basis not carried over".to_string());
+        return;
+    };
+
+    let transfer = LotCarryOver {
+        reference: format!("bridge-{}", bridge.id),
+        source_symbol: bridge.token_symbol.clone(),
+        source_mint: bridge.source_token.clone(),
+        destination_symbol: bridge.token_symbol.clone(),
+        destination_mint: bridge.destination_token.clone(),
+        amount_sent: bridge.amount_sent,
+        amount_received,
+        fee_usd: 0.0,
+    };
+
+    let result = match lots.lock() {
+        Ok(mut guard) => guard.carry_over(&transfer),
+        Err(_) => Err("Tax lots unavailable".to_string()),
+    };
+
+    match result {
+        Ok(result) => {
+            bridge.basis_carried = true;
+            if result.uncovered_amount > f64::EPSILON {
+                bridge.note = Some(format!(
+                    "{:.6} {} had no matching tax lot; add its cost basis manually",
+                    result.uncovered_amount, bridge.token_symbol
+                ));
+            }
+        }
+        Err(err) => bridge.note = Some(err),
+    }
+}
+
+async fn notify_completion(app: &AppHandle, bridge: &TrackedBridge) {
+    let Some(router) = app.try_state::<SharedNotificationRouter>() else {
+        return;
+    };
+
+    let (title, verb) = match bridge.status {
+        BridgeTransactionStatus::Failed => ("Bridge transfer failed", "failed"),
+        _ => ("Bridge transfer completed", "arrived"),
+    };
+    let route = match (&bridge.from_chain, &bridge.to_chain) {
+        (Some(from), Some(to)) => format!(" from {} to {}", from.as_str(), to.as_str()),
+        _ => String::new(),
+    };
+    let message = format!(
+        "{:.4} {}{} via {} {}.",
+        bridge.amount_sent,
+        bridge.token_symbol,
+        route,
+        bridge.provider.as_str(),
+        verb
+    );
+
+    if let Err(err) = router
+        .read()
+        .await
+        .send_broadcast_notification(&bridge.id, title, &message)
+        .await
+    {
+        tracing::warn!(error = %err, "failed to deliver bridge notification");
+    }
+}
+
+/// Polls every enabled bridge indexer for the tracked addresses and advances pending bridges.
+pub async fn sync_bridges(app: &AppHandle, tracker: &SharedBridgeTracker) -> Result<Vec<TrackedBridge>, String> {
+    let tracker = tracker.read().await;
+    let settings = tracker.settings().await.map_err(|e| e.to_string())?;
+    let mut changed = Vec::new();
+
+    for address in tracked_addresses(app, &settings) {
+        for provider in &settings.providers {
+            let observations = match tracker.observe(provider, &address).await {
+                Ok(observations) => observations,
+                Err(err) => {
+                    tracing::warn!(error = %err, provider = provider.as_str(), "bridge lookup failed");
+                    continue;
+                }
+            };
+
+            for observation in observations {
+                let id = format!("{}:{}", provider.as_str(), observation.external_id);
+                let existing = tracker.get(&id).await.map_err(|e| e.to_string())?;
+                let is_new = existing.is_none();
+                let mut bridge = existing.unwrap_or_else(|| TrackedBridge {
+                    id: id.clone(),
+                    provider: observation.provider.clone(),
+                    wallet: address.clone(),
+                    from_chain: observation.from_chain.clone(),
+                    to_chain: observation.to_chain.clone(),
+                    recipient: observation.recipient.clone(),
+                    token_symbol: observation.token_symbol.clone(),
+                    source_token: observation.source_token.clone(),
+                    destination_token: observation.destination_token.clone(),
+                    amount_sent: observation.amount_sent,
+                    amount_received: None,
+                    usd_value: observation.usd_value,
+                    source_tx_hash: observation.source_tx_hash.clone(),
+                    destination_tx_hash: None,
+                    status: BridgeTransactionStatus::Pending,
+                    destination_verified: false,
+                    basis_carried: false,
+                    note: None,
+                    started_at: observation.started_at,
+                    updated_at: Utc::now(),
+                    completed_at: None,
+                });
+                if bridge.status.is_terminal() {
+                    continue;
+                }
+
+                let mut target = observation.clone();
+                if target.status == BridgeTransactionStatus::Completed && settings.verify_destination {
+                    let check = match (&bridge.to_chain, &target.destination_tx_hash) {
+                        (Some(chain), Some(hash)) => match rpc_url_for(app, chain).await {
+                            Some(rpc) => tracker.verify_destination(chain, &rpc, hash).await,
+                            None => None,
+                        },
+                        _ => None,
+                    };
+                    match check {
+                        Some(true) => bridge.destination_verified = true,
+                        // Not visible on the destination chain yet; check again next cycle
+                        Some(false) => target.status = BridgeTransactionStatus::Bridging,
+                        None => {}
+                    }
+                }
+
+                let advanced = apply_observation(&mut bridge, &target);
+                if bridge.status == BridgeTransactionStatus::Completed
+                    && settings.carry_cost_basis
+                    && !bridge.basis_carried
+                {
+                    carry_cost_basis(app, &mut bridge);
+                }
+
+                if advanced || is_new {
+                    tracker.save(&bridge).await.map_err(|e| e.to_string())?;
+                    if bridge.status.is_terminal() {
+                        notify_completion(app, &bridge).await;
+                    }
+                    changed.push(bridge);
+                }
+            }
+        }
+    }
+
+    drop(tracker);
+    for bridge in &changed {
+        if let Err(err) = app.emit_all(BRIDGE_STATUS_EVENT, bridge) {
+            tracing::warn!(error = %err, "failed to emit bridge status");
+        }
+    }
+
+    Ok(changed)
+}
+
+async fn publish_in_transit(app: &AppHandle, tracker: &SharedBridgeTracker) {
+    let pending = match tracker.read().await.list(true).await {
+        Ok(pending) => pending,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to load pending bridges");
+            return;
+        }
+    };
+    let in_transit: f64 = pending.iter().map(|b| b.usd_value).sum();
+
+    if let Some(data) = app.try_state::<SharedPortfolioData>() {
+        if let Ok(mut guard) = data.lock() {
+            guard.set_bridge_in_transit(in_transit);
+        }
+    }
+}
+
+pub fn start_bridge_tracker(app: AppHandle, tracker: SharedBridgeTracker) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(POLL_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            if let Err(err) = sync_bridges(&app, &tracker).await {
+                tracing::warn!(error = %err, "bridge tracking cycle failed");
+            }
+            publish_in_transit(&app, &tracker).await;
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn list_tracked_bridges(
+    pending_only: Option<bool>,
+    tracker: State<'_, SharedBridgeTracker>,
+) -> Result<Vec<TrackedBridge>, String> {
+    tracker
+        .read()
+        .await
+        .list(pending_only.unwrap_or(false))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn refresh_tracked_bridges(
+    app_handle: AppHandle,
+    tracker: State<'_, SharedBridgeTracker>,
+) -> Result<Vec<TrackedBridge>, String> {
+    let changed = sync_bridges(&app_handle, tracker.inner()).await?;
+    publish_in_transit(&app_handle, tracker.inner()).await;
+    Ok(changed)
+}
+
+/// Manually resolves a bridge the indexers lost track of, e.g. a refund claimed by hand.
+#[tauri::command]
+pub async fn resolve_tracked_bridge(
+    id: String,
+    status: BridgeTransactionStatus,
+    amount_received: Option<f64>,
+    app_handle: AppHandle,
+    tracker: State<'_, SharedBridgeTracker>,
+) -> Result<TrackedBridge, String> {
+    let bridge = {
+        let guard = tracker.read().await;
+        let mut bridge = guard
+            .get(&id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| BridgeTrackerError::NotFound(id.clone()).to_string())?;
+
+        if !bridge.status.can_transition_to(&status) {
+            return Err(BridgeTrackerError::InvalidTransition(format!(
+                "{} -> {}",
+                status_str(&bridge.status),
+                status_str(&status)
+            ))
+            .to_string());
+        }
+
+        let observation = BridgeObservation {
+            provider: bridge.provider.clone(),
+            external_id: bridge.id.clone(),
+            from_chain: bridge.from_chain.clone(),
+            to_chain: bridge.to_chain.clone(),
+            sender: None,
+            recipient: bridge.recipient.clone(),
+            token_symbol: bridge.token_symbol.clone(),
+            source_token: bridge.source_token.clone(),
+            destination_token: bridge.destination_token.clone(),
+            amount_sent: bridge.amount_sent,
+            amount_received: amount_received.or(bridge.amount_received),
+            usd_value: bridge.usd_value,
+            source_tx_hash: bridge.source_tx_hash.clone(),
+            destination_tx_hash: None,
+            status,
+            started_at: bridge.started_at,
+        };
+        apply_observation(&mut bridge, &observation);
+
+        let settings = guard.settings().await.map_err(|e| e.to_string())?;
+        if bridge.status == BridgeTransactionStatus::Completed && settings.carry_cost_basis && !bridge.basis_carried {
+            carry_cost_basis(&app_handle, &mut bridge);
+        }
+        guard.save(&bridge).await.map_err(|e| e.to_string())?;
+        bridge
+    };
+
+    publish_in_transit(&app_handle, tracker.inner()).await;
+    Ok(bridge)
+}
+
+#[tauri::command]
+pub async fn get_bridge_tracker_settings(
+    tracker: State<'_, SharedBridgeTracker>,
+) -> Result<BridgeTrackerSettings, String> {
+    tracker.read().await.settings().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_bridge_tracker_settings(
+    settings: BridgeTrackerSettings,
+    tracker: State<'_, SharedBridgeTracker>,
+) -> Result<BridgeTrackerSettings, String> {
+    tracker
+        .read()
+        .await
+        .set_settings(&settings)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracked(observation: &BridgeObservation) -> TrackedBridge {
+        TrackedBridge {
+            id: "wormhole:1".to_string(),
+            provider: observation.provider.clone(),
+            wallet: "wallet".to_string(),
+            from_chain: observation.from_chain.clone(),
+            to_chain: observation.to_chain.clone(),
+            recipient: None,
+            token_symbol: observation.token_symbol.clone(),
+            source_token: observation.source_token.clone(),
+            destination_token: observation.destination_token.clone(),
+            amount_sent: observation.amount_sent,
+            amount_received: None,
+            usd_value: 0.0,
+            source_tx_hash: None,
+            destination_tx_hash: None,
+            status: BridgeTransactionStatus::Pending,
+            destination_verified: false,
+            basis_carried: false,
+            note: None,
+            started_at: Utc::now(),
+            updated_at: Utc::now(),
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn parses_wormhole_operation_states() {
+        let body = json!({
+            "operations": [
+                {
+                    "id": "1/abc/10",
+                    "vaa": { "raw": "AQ==" },
+                    "content": { "standarizedProperties": { "toChain": 2, "tokenAddress": "So111", "toAddress": "0xabc" } },
+                    "sourceChain": { "chainId": 1, "status": "confirmed", "timestamp": "2024-05-01T10:00:00Z",
+                                     "transaction": { "txHash": "sig1" }, "from": "wallet" },
+                    "data": { "symbol": "SOL", "tokenAmount": "12.5", "usdAmount": "2000" }
+                },
+                {
+                    "id": "1/abc/11",
+                    "content": { "standarizedProperties": { "toChain": 30 } },
+                    "sourceChain": { "chainId": 1, "status": "confirmed", "transaction": { "txHash": "sig2" } },
+                    "targetChain": { "chainId": 30, "status": "completed", "transaction": { "txHash": "0xdef" } },
+                    "data": { "symbol": "USDC", "tokenAmount": "100" }
+                }
+            ]
+        });
+
+        let observations = parse_wormhole_operations(&body);
+        assert_eq!(observations.len(), 2);
+        assert_eq!(observations[0].status, BridgeTransactionStatus::Bridging);
+        assert_eq!(observations[0].to_chain, Some(ChainId::Ethereum));
+        assert_eq!(observations[0].amount_sent, 12.5);
+        assert_eq!(observations[0].amount_received, None);
+        assert_eq!(observations[1].status, BridgeTransactionStatus::Completed);
+        assert_eq!(observations[1].to_chain, Some(ChainId::Base));
+        assert_eq!(observations[1].amount_received, Some(100.0));
+    }
+
+    #[test]
+    fn parses_debridge_orders_with_wrapped_values() {
+        let body = json!({
+            "orders": [{
+                "orderId": { "stringValue": "0xorder" },
+                "state": "Fulfilled",
+                "creationTimestamp": 1714557600,
+                "giveOfferWithMetadata": {
+                    "chainId": { "bigIntegerValue": 7565164 },
+                    "tokenAddress": { "stringValue": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" },
+                    "amount": { "stringValue": "250000000" },
+                    "metadata": { "decimals": 6, "symbol": "USDC" }
+                },
+                "takeOfferWithMetadata": {
+                    "chainId": { "bigIntegerValue": 42161 },
+                    "tokenAddress": { "stringValue": "0xaf88" },
+                    "amount": { "stringValue": "249100000" },
+                    "metadata": { "decimals": 6, "symbol": "USDC" }
+                },
+                "fulfilledDstEventMetadata": { "transactionHash": { "stringValue": "0xfill" } }
+            }]
+        });
+
+        let observations = parse_debridge_orders(&body);
+        assert_eq!(observations.len(), 1);
+        let order = &observations[0];
+        assert_eq!(order.from_chain, Some(ChainId::Solana));
+        assert_eq!(order.to_chain, Some(ChainId::Arbitrum));
+        assert_eq!(order.amount_sent, 250.0);
+        assert_eq!(order.amount_received, Some(249.1));
+        assert_eq!(order.destination_tx_hash.as_deref(), Some("0xfill"));
+        assert_eq!(order.status, BridgeTransactionStatus::Completed);
+    }
+
+    #[test]
+    fn state_machine_only_moves_forward() {
+        let mut observation = parse_wormhole_operations(&json!({
+            "operations": [{ "id": "1", "vaa": {}, "data": { "symbol": "SOL", "tokenAmount": "1" } }]
+        }))
+        .remove(0);
+        let mut bridge = tracked(&observation);
+
+        assert!(apply_observation(&mut bridge, &observation));
+        assert_eq!(bridge.status, BridgeTransactionStatus::Bridging);
+
+        observation.status = BridgeTransactionStatus::Submitted;
+        assert!(!apply_observation(&mut bridge, &observation));
+        assert_eq!(bridge.status, BridgeTransactionStatus::Bridging);
+
+        observation.status = BridgeTransactionStatus::Completed;
+        assert!(apply_observation(&mut bridge, &observation));
+        assert!(bridge.completed_at.is_some());
+
+        observation.status = BridgeTransactionStatus::Failed;
+        assert!(!apply_observation(&mut bridge, &observation));
+        assert_eq!(bridge.status, BridgeTransactionStatus::Completed);
+    }
+}
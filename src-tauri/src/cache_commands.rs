@@ -28,32 +28,37 @@ pub async fn warm_cache(
     keys: Vec<String>,
 ) -> Result<WarmProgress, String> {
     let manager = cache_manager.read().await;
-    
-    // Define a simple fetcher that returns mock data for now
-    // In a real implementation, this would fetch actual data
-    let result = manager.warm_cache(keys, |key| async move {
-        // Determine cache type based on key prefix
-        let cache_type = if key.starts_with("token_price_") {
-            CacheType::TokenPrice
-        } else if key.starts_with("token_info_") {
-            CacheType::TokenInfo
-        } else if key.starts_with("market_") {
-            CacheType::MarketData
-        } else if key.starts_with("top_") {
-            CacheType::TopCoins
-        } else if key.starts_with("trending_") {
-            CacheType::TrendingCoins
-        } else {
-            CacheType::UserData
-        };
-
-        // Return mock data for now
-        Ok((json!({ "key": key, "timestamp": chrono::Utc::now().timestamp() }), cache_type))
-    }).await?;
+    let result = manager
+        .warm_cache(keys, |key| async move { price_warm_entry(&key, None) })
+        .await?;
 
     Ok(result)
 }
 
+/// Cache entry for a `token_price_<token>` key, taken from the price engine.
+/// Keys the engine has no price for are skipped rather than filled with
+/// placeholder values. `symbol` overrides the lookup for address-keyed entries.
+pub(crate) fn price_warm_entry(
+    key: &str,
+    symbol: Option<&str>,
+) -> Result<(serde_json::Value, CacheType), String> {
+    let token = key
+        .strip_prefix("token_price_")
+        .ok_or_else(|| format!("no data source to warm {key}"))?;
+    let price = crate::core::price_engine::get_price_engine()
+        .get_cached_price(symbol.unwrap_or(token))
+        .ok_or_else(|| format!("no price available for {token}"))?;
+
+    Ok((
+        json!({
+            "price": price.price,
+            "change24h": price.change_24h,
+            "volume": price.volume,
+        }),
+        CacheType::TokenPrice,
+    ))
+}
+
 #[tauri::command]
 pub async fn get_cache_item(
     cache_manager: State<'_, SharedCacheManager>,
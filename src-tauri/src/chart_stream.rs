@@ -1,8 +1,10 @@
 use crate::core::price_engine::{get_price_engine, PriceUpdate};
 use crate::core::WebSocketManager;
+use crate::websocket::watchdog::{detect_stall, CHART_STALL_TIMEOUT};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Manager, State};
 use tokio::sync::RwLock;
 use tokio::time::Duration;
@@ -65,8 +67,11 @@ pub async fn subscribe_chart_prices(
     // Start emission task for this symbol if not already running
     let app_handle_clone = app_handle.clone();
     let symbol_clone = symbol.clone();
+    let supervisor = ws_manager.inner().clone();
     
     tokio::spawn(async move {
+        // Reset on every resubscribe so a dead feed is retried once per window
+        let mut watching_since = Instant::now();
         loop {
             let interval_ms = {
                 let subs = CHART_SUBS.read().await;
@@ -94,7 +99,25 @@ pub async fn subscribe_chart_prices(
             
             // Get latest price from engine
             let engine = get_price_engine();
-            if let Some(cached_price) = engine.get_cached_price(&symbol_clone) {
+            let cached = engine.get_cached_price(&symbol_clone);
+
+            // A price that stops moving freezes the chart; resubscribe the symbol
+            let now = Instant::now();
+            let now_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+            let last_update = cached.as_ref().and_then(|price| {
+                now.checked_sub(Duration::from_millis(now_ms.saturating_sub(price.timestamp)))
+            });
+            if let Some(silent_for) =
+                detect_stall(last_update, watching_since, now, CHART_STALL_TIMEOUT)
+            {
+                supervisor.recover_chart_stall(&symbol_clone, silent_for).await;
+                watching_since = now;
+            }
+
+            if let Some(cached_price) = cached {
                 let update = ChartPriceUpdate {
                     symbol: symbol_clone.clone(),
                     price: cached_price.price,
@@ -0,0 +1,288 @@
+use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+const DATA_SOURCES_FILE: &str = "data_sources.json";
+const RECORDINGS_DIR: &str = "data_source_recordings";
+const DATA_SOURCES_EVENT: &str = "data_sources_changed";
+
+/// Modules whose upstream data can be swapped for fixtures during development.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataModule {
+    Market,
+    Social,
+    Scanner,
+    PriceEngine,
+}
+
+impl DataModule {
+    pub const ALL: [DataModule; 4] = [
+        DataModule::Market,
+        DataModule::Social,
+        DataModule::Scanner,
+        DataModule::PriceEngine,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataModule::Market => "market",
+            DataModule::Social => "social",
+            DataModule::Scanner => "scanner",
+            DataModule::PriceEngine => "price_engine",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataSourceMode {
+    #[default]
+    Live,
+    /// Deterministic demo fixtures.
+    Mock,
+    /// Payloads previously captured from live providers.
+    Recorded,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSourceConfig {
+    #[serde(default)]
+    pub modes: HashMap<DataModule, DataSourceMode>,
+    /// Save successful live payloads so they can be served in recorded mode.
+    #[serde(default)]
+    pub capture_recordings: bool,
+}
+
+impl DataSourceConfig {
+    pub fn configured_mode(&self, module: DataModule) -> DataSourceMode {
+        self.modes.get(&module).copied().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSourceStatus {
+    pub config: DataSourceConfig,
+    pub effective: HashMap<DataModule, DataSourceMode>,
+    /// Release builds ignore the matrix and always use live providers.
+    pub locked_to_live: bool,
+}
+
+/// Which provider serves a single fetch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataProvider {
+    Live { capture: Option<PathBuf> },
+    Mock,
+    Recorded(PathBuf),
+}
+
+lazy_static::lazy_static! {
+    static ref DATA_SOURCES: RwLock<DataSourceConfig> = RwLock::new(DataSourceConfig::default());
+    static ref RECORDINGS_ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
+}
+
+fn matrix_unlocked() -> bool {
+    cfg!(debug_assertions)
+}
+
+/// Mode a module actually runs in. Demo mode always means fixtures; outside of
+/// it only development builds honour the configured matrix.
+pub fn effective_mode(module: DataModule) -> DataSourceMode {
+    resolve_mode(
+        DATA_SOURCES.read().configured_mode(module),
+        crate::demo::is_demo_mode(),
+        matrix_unlocked(),
+    )
+}
+
+pub fn resolve_mode(configured: DataSourceMode, demo_mode: bool, unlocked: bool) -> DataSourceMode {
+    if demo_mode {
+        DataSourceMode::Mock
+    } else if unlocked {
+        configured
+    } else {
+        DataSourceMode::Live
+    }
+}
+
+fn sanitize_key(key: &str) -> String {
+    key.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+fn recording_path(module: DataModule, key: &str) -> PathBuf {
+    let root = RECORDINGS_ROOT
+        .read()
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(RECORDINGS_DIR));
+    root.join(module.as_str()).join(format!("{}.json", sanitize_key(key)))
+}
+
+/// Provider factory: picks live, mock or recorded for one fetch of `key`.
+pub fn provider_for(module: DataModule, key: &str) -> DataProvider {
+    match effective_mode(module) {
+        DataSourceMode::Mock => DataProvider::Mock,
+        DataSourceMode::Recorded => DataProvider::Recorded(recording_path(module, key)),
+        DataSourceMode::Live => {
+            let capture = (matrix_unlocked() && DATA_SOURCES.read().capture_recordings)
+                .then(|| recording_path(module, key));
+            DataProvider::Live { capture }
+        }
+    }
+}
+
+pub fn read_recording<T: DeserializeOwned>(path: &Path) -> Result<T, String> {
+    let data = fs::read_to_string(path)
+        .map_err(|e| format!("No recording at {}: {e}", path.display()))?;
+    serde_json::from_str(&data).map_err(|e| format!("Invalid recording {}: {e}", path.display()))
+}
+
+pub fn write_recording<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+/// Fetches `key` for `module` from whichever provider the matrix selects. Live
+/// failures are returned as errors rather than quietly replaced with fixtures.
+pub async fn fetch_from_source<T, Fut>(
+    module: DataModule,
+    key: &str,
+    live: impl FnOnce() -> Fut,
+    mock: impl FnOnce() -> Result<T, String>,
+) -> Result<T, String>
+where
+    T: Serialize + DeserializeOwned,
+    Fut: Future<Output = Result<T, String>>,
+{
+    match provider_for(module, key) {
+        DataProvider::Mock => mock(),
+        DataProvider::Recorded(path) => read_recording(&path),
+        DataProvider::Live { capture } => {
+            let value = live().await.map_err(|err| {
+                tracing::warn!(module = module.as_str(), key, error = %err, "live data source failed");
+                err
+            })?;
+            if let Some(path) = capture {
+                if let Err(err) = write_recording(&path, &value) {
+                    tracing::warn!(module = module.as_str(), key, error = %err, "failed to capture recording");
+                }
+            }
+            Ok(value)
+        }
+    }
+}
+
+fn config_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut path = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+    if !path.exists() {
+        fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+    }
+    path.push(DATA_SOURCES_FILE);
+    Ok(path)
+}
+
+/// Restores the persisted matrix; every module is live unless configured otherwise.
+pub fn load_data_sources(app: &AppHandle) -> Result<(), String> {
+    let path = config_path(app)?;
+    let config = if path.exists() {
+        let data = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        serde_json::from_str::<DataSourceConfig>(&data).map_err(|e| e.to_string())?
+    } else {
+        DataSourceConfig::default()
+    };
+
+    *RECORDINGS_ROOT.write() = path.parent().map(|dir| dir.join(RECORDINGS_DIR));
+    *DATA_SOURCES.write() = config;
+    Ok(())
+}
+
+fn save_data_sources(app: &AppHandle, config: &DataSourceConfig) -> Result<(), String> {
+    let path = config_path(app)?;
+    let json = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+fn current_status() -> DataSourceStatus {
+    DataSourceStatus {
+        config: DATA_SOURCES.read().clone(),
+        effective: DataModule::ALL
+            .iter()
+            .map(|module| (*module, effective_mode(*module)))
+            .collect(),
+        locked_to_live: !matrix_unlocked(),
+    }
+}
+
+fn apply(app: &AppHandle, update: impl FnOnce(&mut DataSourceConfig)) -> Result<DataSourceStatus, String> {
+    let mut config = DATA_SOURCES.read().clone();
+    update(&mut config);
+    save_data_sources(app, &config)?;
+    *DATA_SOURCES.write() = config;
+
+    let status = current_status();
+    if let Err(err) = app.emit_all(DATA_SOURCES_EVENT, &status) {
+        tracing::warn!(error = %err, "failed to emit data source change");
+    }
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn get_data_sources() -> Result<DataSourceStatus, String> {
+    Ok(current_status())
+}
+
+#[tauri::command]
+pub async fn set_data_source_mode(
+    module: DataModule,
+    mode: DataSourceMode,
+    app_handle: AppHandle,
+) -> Result<DataSourceStatus, String> {
+    apply(&app_handle, |config| {
+        config.modes.insert(module, mode);
+    })
+}
+
+#[tauri::command]
+pub async fn set_data_source_capture(
+    enabled: bool,
+    app_handle: AppHandle,
+) -> Result<DataSourceStatus, String> {
+    apply(&app_handle, |config| config.capture_recordings = enabled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_builds_always_resolve_live() {
+        assert_eq!(resolve_mode(DataSourceMode::Mock, false, false), DataSourceMode::Live);
+        assert_eq!(resolve_mode(DataSourceMode::Recorded, false, false), DataSourceMode::Live);
+        assert_eq!(resolve_mode(DataSourceMode::Recorded, false, true), DataSourceMode::Recorded);
+    }
+
+    #[test]
+    fn demo_mode_forces_fixtures() {
+        assert_eq!(resolve_mode(DataSourceMode::Live, true, false), DataSourceMode::Mock);
+        assert_eq!(resolve_mode(DataSourceMode::Recorded, true, true), DataSourceMode::Mock);
+    }
+
+    #[test]
+    fn recording_keys_are_file_safe() {
+        assert_eq!(sanitize_key("top_coins:100/0"), "top_coins_100_0");
+    }
+}
@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{Pool, Sqlite};
+
+/// Returned when an edit was based on an outdated copy of the entity. Carries the
+/// latest stored version so the frontend can merge and retry.
+#[derive(Debug, Clone, Serialize, Deserialize, thiserror::Error)]
+#[serde(rename_all = "camelCase")]
+#[error("{entity_kind} {entity_id} was changed elsewhere (expected version {expected_version}, latest is {latest_version})")]
+pub struct VersionConflict {
+    pub entity_kind: String,
+    pub entity_id: String,
+    pub expected_version: i64,
+    pub latest_version: i64,
+    pub latest: Option<Value>,
+}
+
+impl VersionConflict {
+    pub fn new<T: Serialize>(
+        entity_kind: &str,
+        entity_id: &str,
+        expected_version: i64,
+        latest_version: i64,
+        latest: Option<&T>,
+    ) -> Self {
+        Self {
+            entity_kind: entity_kind.to_string(),
+            entity_id: entity_id.to_string(),
+            expected_version,
+            latest_version,
+            latest: latest.and_then(|value| serde_json::to_value(value).ok()),
+        }
+    }
+}
+
+/// Error type for versioned edit commands. Conflicts serialize as a
+/// `VersionConflict` object; everything else stays a plain string as before.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum VersionedEditError {
+    Conflict(VersionConflict),
+    Message(String),
+}
+
+impl From<VersionConflict> for VersionedEditError {
+    fn from(conflict: VersionConflict) -> Self {
+        VersionedEditError::Conflict(conflict)
+    }
+}
+
+impl From<String> for VersionedEditError {
+    fn from(message: String) -> Self {
+        VersionedEditError::Message(message)
+    }
+}
+
+impl std::fmt::Display for VersionedEditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionedEditError::Conflict(conflict) => write!(f, "{conflict}"),
+            VersionedEditError::Message(message) => f.write_str(message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionBump {
+    Bumped(i64),
+    Stale { latest: i64 },
+}
+
+/// Version `0` (or none) means the caller doesn't track versions, so the edit
+/// is applied unconditionally.
+pub fn expected_version(expected: Option<i64>) -> Option<i64> {
+    expected.filter(|version| *version > 0)
+}
+
+/// Versions live beside the entity tables so existing databases keep working;
+/// entities without a row are at version 1.
+pub async fn initialize_entity_versions(pool: &Pool<Sqlite>) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS entity_versions (
+            entity_id TEXT PRIMARY KEY,
+            version INTEGER NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn entity_version(pool: &Pool<Sqlite>, entity_id: &str) -> Result<i64, sqlx::Error> {
+    let version: Option<i64> =
+        sqlx::query_scalar("SELECT version FROM entity_versions WHERE entity_id = ?1")
+            .bind(entity_id)
+            .fetch_optional(pool)
+            .await?;
+    Ok(version.unwrap_or(1))
+}
+
+/// Increments the version if it still matches `expected`. The compare and the
+/// increment are a single UPDATE, so two concurrent edits can't both win.
+pub async fn bump_entity_version(
+    pool: &Pool<Sqlite>,
+    entity_id: &str,
+    expected: Option<i64>,
+) -> Result<VersionBump, sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO entity_versions (entity_id, version) VALUES (?1, 1) ON CONFLICT(entity_id) DO NOTHING",
+    )
+    .bind(entity_id)
+    .execute(pool)
+    .await?;
+
+    let result = sqlx::query(
+        r#"
+        UPDATE entity_versions
+        SET version = version + 1
+        WHERE entity_id = ?1 AND (?2 IS NULL OR version = ?2)
+        "#,
+    )
+    .bind(entity_id)
+    .bind(expected_version(expected))
+    .execute(pool)
+    .await?;
+
+    let latest = entity_version(pool, entity_id).await?;
+    if result.rows_affected() == 0 {
+        return Ok(VersionBump::Stale { latest });
+    }
+    Ok(VersionBump::Bumped(latest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unversioned_edits_skip_the_check() {
+        assert_eq!(expected_version(None), None);
+        assert_eq!(expected_version(Some(0)), None);
+        assert_eq!(expected_version(Some(3)), Some(3));
+    }
+
+    #[test]
+    fn conflicts_serialize_with_latest_version() {
+        let latest = serde_json::json!({ "id": "w1", "name": "Majors" });
+        let error: VersionedEditError =
+            VersionConflict::new("watchlist", "w1", 2, 4, Some(&latest)).into();
+
+        let value = serde_json::to_value(&error).unwrap();
+        assert_eq!(value["latestVersion"], 4);
+        assert_eq!(value["expectedVersion"], 2);
+        assert_eq!(value["latest"]["name"], "Majors");
+    }
+
+    #[test]
+    fn other_errors_stay_plain_strings() {
+        let error = VersionedEditError::from("watchlist not found: w1".to_string());
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            serde_json::json!("watchlist not found: w1")
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::ops::Deref;
+use std::path::PathBuf;
+use tauri::command::{CommandArg, CommandItem};
+use tauri::{AppHandle, InvokeError, Manager, Runtime, State};
+
+const FEATURE_FLAGS_FILE: &str = "feature_flags.json";
+const FEATURE_FLAGS_EVENT: &str = "feature_flags_changed";
+
+/// Subsystems that can be switched off. A disabled module opens no database
+/// and starts no background work; its pages are hidden in the app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureModule {
+    P2p,
+    Launchpad,
+    Social,
+}
+
+impl FeatureModule {
+    pub const ALL: [FeatureModule; 3] = [
+        FeatureModule::P2p,
+        FeatureModule::Launchpad,
+        FeatureModule::Social,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FeatureModule::P2p => "P2P Marketplace",
+            FeatureModule::Launchpad => "Launchpad",
+            FeatureModule::Social => "Social Intelligence",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            FeatureModule::P2p => "Peer-to-peer offers, escrow and disputes",
+            FeatureModule::Launchpad => "Token creation, vesting and airdrops",
+            FeatureModule::Social => "Reddit and Twitter mentions, sentiment and whale feeds",
+        }
+    }
+
+    /// Built-in default. Flags are never fetched remotely; the only way to
+    /// change one is a local override.
+    pub fn enabled_by_default(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureFlags {
+    /// Only modules that differ from their default are stored.
+    #[serde(default)]
+    pub overrides: BTreeMap<FeatureModule, bool>,
+}
+
+impl FeatureFlags {
+    pub fn is_enabled(&self, module: FeatureModule) -> bool {
+        self.overrides
+            .get(&module)
+            .copied()
+            .unwrap_or_else(|| module.enabled_by_default())
+    }
+
+    pub fn set(&mut self, module: FeatureModule, enabled: bool) {
+        if enabled == module.enabled_by_default() {
+            self.overrides.remove(&module);
+        } else {
+            self.overrides.insert(module, enabled);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureFlagStatus {
+    pub module: FeatureModule,
+    pub label: String,
+    pub description: String,
+    pub enabled: bool,
+    pub overridden: bool,
+    /// Whether the module was started in this session.
+    pub running: bool,
+    /// A started module can't be torn down, so switching it off only takes
+    /// full effect after a restart.
+    pub restart_required: bool,
+}
+
+fn status(flags: &FeatureFlags, running: &BTreeSet<FeatureModule>) -> Vec<FeatureFlagStatus> {
+    FeatureModule::ALL
+        .iter()
+        .map(|&module| {
+            let enabled = flags.is_enabled(module);
+            let running = running.contains(&module);
+            FeatureFlagStatus {
+                module,
+                label: module.label().to_string(),
+                description: module.description().to_string(),
+                enabled,
+                overridden: flags.overrides.contains_key(&module),
+                running,
+                restart_required: running && !enabled,
+            }
+        })
+        .collect()
+}
+
+lazy_static::lazy_static! {
+    static ref FEATURE_FLAGS: RwLock<FeatureFlags> = RwLock::new(FeatureFlags::default());
+    static ref RUNNING_MODULES: RwLock<BTreeSet<FeatureModule>> = RwLock::new(BTreeSet::new());
+}
+
+pub fn is_feature_enabled(module: FeatureModule) -> bool {
+    FEATURE_FLAGS.read().is_enabled(module)
+}
+
+fn feature_flags_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(FEATURE_FLAGS_FILE))
+}
+
+/// Loads local overrides. Must run before any switchable module starts.
+pub fn load_feature_flags(app: &AppHandle) -> Result<(), String> {
+    let path = feature_flags_path(app)?;
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let data = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let flags: FeatureFlags = serde_json::from_str(&data).map_err(|e| e.to_string())?;
+    *FEATURE_FLAGS.write() = flags;
+    Ok(())
+}
+
+/// Starts `module` if it is enabled and not yet running. Returns whether it
+/// was started by this call.
+pub async fn start_feature_module(app: &AppHandle, module: FeatureModule) -> Result<bool, String> {
+    // Claim the module before starting it, so concurrent callers can't both start it
+    if !is_feature_enabled(module) || !RUNNING_MODULES.write().insert(module) {
+        return Ok(false);
+    }
+
+    let started = match module {
+        FeatureModule::P2p => crate::p2p::start_p2p_module(app).await,
+        FeatureModule::Launchpad => {
+            crate::launchpad::start_launchpad_module(app);
+            Ok(())
+        }
+        FeatureModule::Social => crate::social::start_social_module(app).await,
+    };
+    if let Err(err) = started {
+        RUNNING_MODULES.write().remove(&module);
+        return Err(err);
+    }
+    Ok(true)
+}
+
+/// State that only exists while its module runs.
+pub trait ModuleOwned: Send + Sync + 'static {
+    const MODULE: FeatureModule;
+}
+
+/// Command argument for module-owned state. Unlike `State`, which panics when
+/// the value was never managed, it rejects the call while the module is off.
+pub struct ModuleState<'r, T: ModuleOwned>(State<'r, T>);
+
+impl<'r, T: ModuleOwned> ModuleState<'r, T> {
+    pub fn inner(&self) -> &'r T {
+        self.0.inner()
+    }
+}
+
+impl<T: ModuleOwned> Deref for ModuleState<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.0.inner()
+    }
+}
+
+fn module_disabled_message(module: FeatureModule) -> String {
+    format!(
+        "{} is disabled. Enable it in Settings to use this feature.",
+        module.label()
+    )
+}
+
+impl<'r, 'de: 'r, T: ModuleOwned, R: Runtime> CommandArg<'de, R> for ModuleState<'r, T> {
+    fn from_command(command: CommandItem<'de, R>) -> Result<Self, InvokeError> {
+        command
+            .message
+            .state_ref()
+            .try_get::<T>()
+            .map(ModuleState)
+            .ok_or_else(|| InvokeError::from(module_disabled_message(T::MODULE)))
+    }
+}
+
+fn current_status() -> Vec<FeatureFlagStatus> {
+    status(&FEATURE_FLAGS.read(), &RUNNING_MODULES.read())
+}
+
+#[tauri::command]
+pub async fn get_feature_flags() -> Result<Vec<FeatureFlagStatus>, String> {
+    Ok(current_status())
+}
+
+/// Saves the flag and starts the module right away when it is switched on.
+/// Switching a running module off hides it now and stops it at next launch.
+#[tauri::command]
+pub async fn set_feature_flag(
+    module: FeatureModule,
+    enabled: bool,
+    app_handle: AppHandle,
+) -> Result<Vec<FeatureFlagStatus>, String> {
+    let mut flags = FEATURE_FLAGS.read().clone();
+    flags.set(module, enabled);
+    let json = serde_json::to_string_pretty(&flags).map_err(|e| e.to_string())?;
+    fs::write(feature_flags_path(&app_handle)?, json).map_err(|e| e.to_string())?;
+    *FEATURE_FLAGS.write() = flags;
+
+    if enabled {
+        start_feature_module(&app_handle, module).await?;
+    }
+
+    let statuses = current_status();
+    if let Err(err) = app_handle.emit_all(FEATURE_FLAGS_EVENT, &statuses) {
+        tracing::warn!(error = %err, "failed to emit feature flag change");
+    }
+    Ok(statuses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modules_default_to_enabled() {
+        let flags = FeatureFlags::default();
+        assert!(FeatureModule::ALL.iter().all(|&m| flags.is_enabled(m)));
+    }
+
+    #[test]
+    fn only_non_default_values_are_stored() {
+        let mut flags = FeatureFlags::default();
+        flags.set(FeatureModule::Social, false);
+        assert!(!flags.is_enabled(FeatureModule::Social));
+        assert_eq!(
+            serde_json::to_string(&flags).unwrap(),
+            r#"{"overrides":{"social":false}}"#
+        );
+
+        flags.set(FeatureModule::Social, true);
+        assert!(flags.overrides.is_empty());
+    }
+
+    #[test]
+    fn disabling_a_running_module_needs_restart() {
+        let mut flags = FeatureFlags::default();
+        flags.set(FeatureModule::P2p, false);
+        flags.set(FeatureModule::Launchpad, false);
+        let running = BTreeSet::from([FeatureModule::P2p, FeatureModule::Social]);
+
+        let statuses = status(&flags, &running);
+        let p2p = &statuses[0];
+        assert!(p2p.restart_required && p2p.overridden && !p2p.enabled);
+        let launchpad = &statuses[1];
+        assert!(!launchpad.running && !launchpad.restart_required);
+        let social = &statuses[2];
+        assert!(social.running && social.enabled && !social.restart_required);
+        assert_eq!(
+            module_disabled_message(FeatureModule::P2p),
+            "P2P Marketplace is disabled. Enable it in Settings to use this feature."
+        );
+    }
+}
@@ -0,0 +1,694 @@
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use reqwest::header::HeaderMap;
+use reqwest::{RequestBuilder, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use sqlx::{sqlite::SqlitePool, Pool, Row, Sqlite};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+use tauri::AppHandle;
+use tokio::sync::OnceCell;
+use url::Url;
+
+const HTTP_RECORDER_FILE: &str = "http_recorder.json";
+const HTTP_RECORDINGS_DB: &str = "http_recordings.db";
+const REDACTED: &str = "[REDACTED]";
+const MAX_RECORDED_BODY_BYTES: usize = 2 * 1024 * 1024;
+const DEFAULT_RETENTION_DAYS: u32 = 7;
+
+/// Header, query and JSON field names ending in one of these (ignoring case and
+/// separators) are treated as secrets, e.g. `X-API-KEY`, `access_token`, `clientSecret`.
+const SENSITIVE_SUFFIXES: [&str; 10] = [
+    "key",
+    "token",
+    "secret",
+    "password",
+    "auth",
+    "authorization",
+    "cookie",
+    "session",
+    "sessionid",
+    "credentials",
+];
+
+/// On-chain addresses that happen to end in "key" but are public by design.
+const PUBLIC_NAMES: [&str; 2] = ["pubkey", "publickey"];
+
+/// Headers describing the wire encoding of the original body; meaningless on replay.
+const TRANSPORT_HEADERS: [&str; 3] = ["content-length", "content-encoding", "transfer-encoding"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum HttpRecorderError {
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("no recorded response for {method} {url}")]
+    NotRecorded { method: String, url: String },
+    #[error("recording store error: {0}")]
+    Store(#[from] sqlx::Error),
+    #[error("recording store unavailable")]
+    StoreUnavailable,
+    #[error("invalid recording: {0}")]
+    InvalidRecording(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpRecorderMode {
+    #[default]
+    Off,
+    /// Pass requests through and keep a redacted copy of each exchange.
+    Record,
+    /// Serve the latest matching recording instead of touching the network.
+    Replay,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRecorderConfig {
+    #[serde(default)]
+    pub mode: HttpRecorderMode,
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u32,
+}
+
+fn default_retention_days() -> u32 {
+    DEFAULT_RETENTION_DAYS
+}
+
+impl Default for HttpRecorderConfig {
+    fn default() -> Self {
+        Self {
+            mode: HttpRecorderMode::Off,
+            retention_days: DEFAULT_RETENTION_DAYS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRecorderStatus {
+    pub config: HttpRecorderConfig,
+    pub effective_mode: HttpRecorderMode,
+    /// Release builds can record for bug reports but never replay.
+    pub replay_locked: bool,
+    pub store_ready: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRecordingSummary {
+    pub id: i64,
+    pub service: String,
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    pub latency_ms: u64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRecording {
+    #[serde(flatten)]
+    pub summary: HttpRecordingSummary,
+    pub fingerprint: String,
+    pub request_headers: BTreeMap<String, String>,
+    pub request_body: Option<String>,
+    pub response_headers: BTreeMap<String, String>,
+    pub response_body: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRecordingFilter {
+    pub service: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub limit: Option<u32>,
+}
+
+lazy_static::lazy_static! {
+    static ref HTTP_RECORDER: RwLock<HttpRecorderConfig> = RwLock::new(HttpRecorderConfig::default());
+    static ref CONFIG_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+}
+
+static RECORDING_STORE: OnceCell<HttpRecordingStore> = OnceCell::const_new();
+
+fn replay_unlocked() -> bool {
+    cfg!(debug_assertions)
+}
+
+pub fn effective_mode() -> HttpRecorderMode {
+    resolve_mode(HTTP_RECORDER.read().mode, replay_unlocked())
+}
+
+pub fn resolve_mode(configured: HttpRecorderMode, replay_unlocked: bool) -> HttpRecorderMode {
+    match configured {
+        HttpRecorderMode::Replay if !replay_unlocked => HttpRecorderMode::Off,
+        mode => mode,
+    }
+}
+
+fn is_sensitive(name: &str) -> bool {
+    let name: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    !PUBLIC_NAMES.contains(&name.as_str())
+        && SENSITIVE_SUFFIXES
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+}
+
+/// Strips credentials from the userinfo and masks secret-looking query values.
+pub fn redact_url(url: &Url) -> String {
+    let mut redacted = url.clone();
+    if !redacted.username().is_empty() || redacted.password().is_some() {
+        let _ = redacted.set_username(REDACTED);
+        let _ = redacted.set_password(None);
+    }
+
+    if redacted.query().is_some() {
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(name, value)| {
+                let value = if is_sensitive(&name) {
+                    REDACTED.to_string()
+                } else {
+                    value.into_owned()
+                };
+                (name.into_owned(), value)
+            })
+            .collect();
+        redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    redacted.to_string()
+}
+
+pub fn redact_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_sensitive(name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+pub fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (name, field) in map.iter_mut() {
+                if is_sensitive(name) {
+                    *field = Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// JSON bodies are redacted field by field; anything else is stored as text.
+pub fn redact_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<Value>(body) {
+        Ok(mut json) => {
+            redact_json(&mut json);
+            json.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+/// Identity of a request after redaction, so replays match without the secrets.
+pub fn fingerprint(method: &str, url: &str, body: Option<&str>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(url.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(body.unwrap_or_default().as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+struct NewRecording<'a> {
+    service: &'a str,
+    method: &'a str,
+    url: &'a str,
+    fingerprint: &'a str,
+    request_headers: BTreeMap<String, String>,
+    request_body: Option<String>,
+    status: u16,
+    response_headers: BTreeMap<String, String>,
+    response_body: String,
+    latency_ms: u64,
+}
+
+pub struct HttpRecordingStore {
+    pool: Pool<Sqlite>,
+}
+
+impl HttpRecordingStore {
+    async fn new(db_path: PathBuf) -> Result<Self, HttpRecorderError> {
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS http_recordings (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                service TEXT NOT NULL,
+                method TEXT NOT NULL,
+                url TEXT NOT NULL,
+                fingerprint TEXT NOT NULL,
+                request_headers TEXT NOT NULL,
+                request_body TEXT,
+                status INTEGER NOT NULL,
+                response_headers TEXT NOT NULL,
+                response_body TEXT NOT NULL,
+                latency_ms INTEGER NOT NULL,
+                recorded_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_http_recordings_fingerprint ON http_recordings(fingerprint, recorded_at)",
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_http_recordings_service ON http_recordings(service, recorded_at)",
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(Self { pool })
+    }
+
+    async fn insert(&self, recording: NewRecording<'_>) -> Result<(), HttpRecorderError> {
+        let request_headers = serde_json::to_string(&recording.request_headers)
+            .map_err(|e| HttpRecorderError::InvalidRecording(e.to_string()))?;
+        let response_headers = serde_json::to_string(&recording.response_headers)
+            .map_err(|e| HttpRecorderError::InvalidRecording(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO http_recordings (
+                service, method, url, fingerprint, request_headers, request_body,
+                status, response_headers, response_body, latency_ms, recorded_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(recording.service)
+        .bind(recording.method)
+        .bind(recording.url)
+        .bind(recording.fingerprint)
+        .bind(request_headers)
+        .bind(recording.request_body)
+        .bind(recording.status as i64)
+        .bind(response_headers)
+        .bind(recording.response_body)
+        .bind(recording.latency_ms as i64)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn latest(&self, fingerprint: &str) -> Result<Option<HttpRecording>, HttpRecorderError> {
+        let row = sqlx::query(
+            "SELECT * FROM http_recordings WHERE fingerprint = ? ORDER BY recorded_at DESC, id DESC LIMIT 1",
+        )
+        .bind(fingerprint)
+        .fetch_optional(&self.pool)
+        .await?;
+        row.map(|row| row_to_recording(&row)).transpose()
+    }
+
+    async fn get(&self, id: i64) -> Result<Option<HttpRecording>, HttpRecorderError> {
+        let row = sqlx::query("SELECT * FROM http_recordings WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+        row.map(|row| row_to_recording(&row)).transpose()
+    }
+
+    async fn list(
+        &self,
+        filter: &HttpRecordingFilter,
+    ) -> Result<Vec<HttpRecordingSummary>, HttpRecorderError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM http_recordings
+            WHERE (?1 IS NULL OR service = ?1)
+              AND (?2 IS NULL OR recorded_at >= ?2)
+              AND (?3 IS NULL OR recorded_at <= ?3)
+            ORDER BY recorded_at DESC, id DESC
+            LIMIT ?4
+            "#,
+        )
+        .bind(filter.service.as_deref())
+        .bind(filter.since.map(|t| t.to_rfc3339()))
+        .bind(filter.until.map(|t| t.to_rfc3339()))
+        .bind(filter.limit.unwrap_or(200) as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| row_to_recording(row).map(|recording| recording.summary))
+            .collect()
+    }
+
+    async fn delete(&self, service: Option<&str>) -> Result<u64, HttpRecorderError> {
+        let result = sqlx::query("DELETE FROM http_recordings WHERE (?1 IS NULL OR service = ?1)")
+            .bind(service)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn prune(&self, retention_days: u32) -> Result<u64, HttpRecorderError> {
+        let cutoff = Utc::now() - Duration::days(retention_days as i64);
+        let result = sqlx::query("DELETE FROM http_recordings WHERE recorded_at < ?")
+            .bind(cutoff.to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+fn row_to_recording(row: &sqlx::sqlite::SqliteRow) -> Result<HttpRecording, HttpRecorderError> {
+    let parse_headers = |column: &str| -> Result<BTreeMap<String, String>, HttpRecorderError> {
+        serde_json::from_str(&row.try_get::<String, _>(column)?)
+            .map_err(|e| HttpRecorderError::InvalidRecording(e.to_string()))
+    };
+    let recorded_at: String = row.try_get("recorded_at")?;
+
+    Ok(HttpRecording {
+        summary: HttpRecordingSummary {
+            id: row.try_get("id")?,
+            service: row.try_get("service")?,
+            method: row.try_get("method")?,
+            url: row.try_get("url")?,
+            status: row.try_get::<i64, _>("status")? as u16,
+            latency_ms: row.try_get::<i64, _>("latency_ms")? as u64,
+            recorded_at: DateTime::parse_from_rfc3339(&recorded_at)
+                .map_err(|e| HttpRecorderError::InvalidRecording(e.to_string()))?
+                .with_timezone(&Utc),
+        },
+        fingerprint: row.try_get("fingerprint")?,
+        request_headers: parse_headers("request_headers")?,
+        request_body: row.try_get("request_body")?,
+        response_headers: parse_headers("response_headers")?,
+        response_body: row.try_get("response_body")?,
+    })
+}
+
+fn rebuild_response(
+    status: u16,
+    headers: &BTreeMap<String, String>,
+    body: Vec<u8>,
+) -> Result<Response, HttpRecorderError> {
+    let mut builder = http::Response::builder().status(status);
+    for (name, value) in headers {
+        if !TRANSPORT_HEADERS.contains(&name.as_str()) {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+    }
+    let response = builder
+        .body(body)
+        .map_err(|e| HttpRecorderError::InvalidRecording(e.to_string()))?;
+    Ok(Response::from(response))
+}
+
+/// Sends `request` through the recorder. With the recorder off this is a plain
+/// `send()`; recording never changes what the caller receives.
+pub async fn send(service: &str, request: RequestBuilder) -> Result<Response, HttpRecorderError> {
+    let mode = effective_mode();
+    if mode == HttpRecorderMode::Off {
+        return Ok(request.send().await?);
+    }
+
+    let (client, request) = request.build_split();
+    let request = request?;
+    let method = request.method().to_string();
+    let url = redact_url(request.url());
+    let request_body = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .map(redact_body);
+    let fingerprint = fingerprint(&method, &url, request_body.as_deref());
+
+    if mode == HttpRecorderMode::Replay {
+        let store = RECORDING_STORE
+            .get()
+            .ok_or(HttpRecorderError::StoreUnavailable)?;
+        let recording = store
+            .latest(&fingerprint)
+            .await?
+            .ok_or(HttpRecorderError::NotRecorded { method, url })?;
+        return rebuild_response(
+            recording.summary.status,
+            &recording.response_headers,
+            recording.response_body.into_bytes(),
+        );
+    }
+
+    let request_headers = redact_headers(request.headers());
+    let started = Instant::now();
+    let response = client.execute(request).await?;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let status = response.status().as_u16();
+    let response_headers = redact_headers(response.headers());
+    let raw_headers: BTreeMap<String, String> = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.as_str().to_string(),
+                String::from_utf8_lossy(value.as_bytes()).into_owned(),
+            )
+        })
+        .collect();
+    let body = response.bytes().await?.to_vec();
+
+    match RECORDING_STORE.get() {
+        Some(store) if body.len() <= MAX_RECORDED_BODY_BYTES => {
+            let recording = NewRecording {
+                service,
+                method: &method,
+                url: &url,
+                fingerprint: &fingerprint,
+                request_headers,
+                request_body,
+                status,
+                response_headers,
+                response_body: redact_body(&body),
+                latency_ms,
+            };
+            if let Err(err) = store.insert(recording).await {
+                tracing::warn!(service, error = %err, "failed to record http exchange");
+            }
+        }
+        Some(_) => {
+            tracing::debug!(service, url = %url, size = body.len(), "response too large to record");
+        }
+        None => {
+            tracing::warn!(
+                service,
+                "http recorder enabled but the recording store is unavailable"
+            );
+        }
+    }
+
+    rebuild_response(status, &raw_headers, body)
+}
+
+fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let path = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+    if !path.exists() {
+        fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+    }
+    Ok(path)
+}
+
+/// Restores the recorder settings, opens the store and drops expired recordings.
+pub async fn init_http_recorder(app: &AppHandle) -> Result<(), String> {
+    let dir = app_data_dir(app)?;
+    let config_path = dir.join(HTTP_RECORDER_FILE);
+    let config = if config_path.exists() {
+        let data = fs::read_to_string(&config_path).map_err(|e| e.to_string())?;
+        serde_json::from_str::<HttpRecorderConfig>(&data).map_err(|e| e.to_string())?
+    } else {
+        HttpRecorderConfig::default()
+    };
+    *CONFIG_PATH.write() = Some(config_path);
+    *HTTP_RECORDER.write() = config.clone();
+
+    let store = RECORDING_STORE
+        .get_or_try_init(|| HttpRecordingStore::new(dir.join(HTTP_RECORDINGS_DB)))
+        .await
+        .map_err(|e| e.to_string())?;
+    let pruned = store
+        .prune(config.retention_days)
+        .await
+        .map_err(|e| e.to_string())?;
+    if pruned > 0 {
+        tracing::info!(pruned, "pruned expired http recordings");
+    }
+    Ok(())
+}
+
+fn require_store<'a>() -> Result<&'a HttpRecordingStore, String> {
+    RECORDING_STORE
+        .get()
+        .ok_or_else(|| "HTTP recorder not initialized".to_string())
+}
+
+fn apply(update: impl FnOnce(&mut HttpRecorderConfig)) -> Result<HttpRecorderStatus, String> {
+    let mut config = HTTP_RECORDER.read().clone();
+    update(&mut config);
+
+    let path = CONFIG_PATH
+        .read()
+        .clone()
+        .ok_or_else(|| "HTTP recorder not initialized".to_string())?;
+    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())?;
+
+    *HTTP_RECORDER.write() = config;
+    Ok(current_status())
+}
+
+fn current_status() -> HttpRecorderStatus {
+    HttpRecorderStatus {
+        config: HTTP_RECORDER.read().clone(),
+        effective_mode: effective_mode(),
+        replay_locked: !replay_unlocked(),
+        store_ready: RECORDING_STORE.get().is_some(),
+    }
+}
+
+#[tauri::command]
+pub async fn get_http_recorder_status() -> Result<HttpRecorderStatus, String> {
+    Ok(current_status())
+}
+
+#[tauri::command]
+pub async fn set_http_recorder_mode(mode: HttpRecorderMode) -> Result<HttpRecorderStatus, String> {
+    apply(|config| config.mode = mode)
+}
+
+#[tauri::command]
+pub async fn set_http_recorder_retention(days: u32) -> Result<HttpRecorderStatus, String> {
+    if days == 0 {
+        return Err("Retention must be at least one day".into());
+    }
+    let status = apply(|config| config.retention_days = days)?;
+    require_store()?
+        .prune(days)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn list_http_recordings(
+    filter: Option<HttpRecordingFilter>,
+) -> Result<Vec<HttpRecordingSummary>, String> {
+    require_store()?
+        .list(&filter.unwrap_or_default())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_http_recording(id: i64) -> Result<Option<HttpRecording>, String> {
+    require_store()?.get(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn clear_http_recordings(service: Option<String>) -> Result<u64, String> {
+    require_store()?
+        .delete(service.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secrets_are_redacted_from_urls() {
+        let url = Url::parse(
+            "https://user:pw@public-api.birdeye.so/defi/price?address=So111&api-key=abc123",
+        )
+        .unwrap();
+        let redacted = redact_url(&url);
+        assert!(!redacted.contains("abc123"));
+        assert!(!redacted.contains("pw@"));
+        assert!(redacted.contains("address=So111"));
+    }
+
+    #[test]
+    fn secrets_are_redacted_from_headers_and_json() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-API-KEY", "abc123".parse().unwrap());
+        headers.insert("Authorization", "Bearer xyz".parse().unwrap());
+        headers.insert("Accept", "application/json".parse().unwrap());
+        let redacted = redact_headers(&headers);
+        assert_eq!(redacted["x-api-key"], REDACTED);
+        assert_eq!(redacted["authorization"], REDACTED);
+        assert_eq!(redacted["accept"], "application/json");
+
+        let body = redact_body(br#"{"query":"sol","auth":{"token":"t"},"items":[{"apiKey":"k"}]}"#);
+        assert!(!body.contains("\"t\"") && !body.contains("\"k\""));
+        assert!(body.contains("\"sol\""));
+
+        let body = redact_body(br#"{"author_id":"1","tokens":[{"pubkey":"So111"}]}"#);
+        assert!(body.contains("\"1\"") && body.contains("So111"));
+    }
+
+    #[test]
+    fn fingerprints_ignore_secret_values_and_replay_is_dev_only() {
+        let a = Url::parse("https://api.example.com/v1?q=1&token=first").unwrap();
+        let b = Url::parse("https://api.example.com/v1?q=1&token=second").unwrap();
+        assert_eq!(
+            fingerprint("GET", &redact_url(&a), None),
+            fingerprint("GET", &redact_url(&b), None)
+        );
+
+        assert_eq!(
+            resolve_mode(HttpRecorderMode::Replay, false),
+            HttpRecorderMode::Off
+        );
+        assert_eq!(
+            resolve_mode(HttpRecorderMode::Record, false),
+            HttpRecorderMode::Record
+        );
+        assert_eq!(
+            resolve_mode(HttpRecorderMode::Replay, true),
+            HttpRecorderMode::Replay
+        );
+    }
+}
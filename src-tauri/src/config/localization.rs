@@ -0,0 +1,739 @@
+use chrono::{DateTime, Datelike, FixedOffset, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+const LOCALIZATION_FILE: &str = "localization.json";
+const LOCALIZATION_EVENT: &str = "localization_changed";
+pub const DEFAULT_LOCALE: &str = "en-US";
+const FALLBACK_LANGUAGE: &str = "en";
+
+/// How one locale writes numbers, USD amounts and dates. Amounts across the
+/// app are in USD, so only the placement of the `$` changes, never the value.
+#[derive(Debug)]
+pub struct LocaleRules {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub language: &'static str,
+    decimal: &'static str,
+    group: &'static str,
+    /// `{n}` is the formatted absolute amount, `{sign}` a leading minus.
+    currency: &'static str,
+    percent: &'static str,
+    numeric_date: &'static str,
+}
+
+static LOCALES: &[LocaleRules] = &[
+    LocaleRules {
+        code: "en-US",
+        name: "English (United States)",
+        language: "en",
+        decimal: ".",
+        group: ",",
+        currency: "{sign}${n}",
+        percent: "{n}%",
+        numeric_date: "%m/%d/%Y",
+    },
+    LocaleRules {
+        code: "en-GB",
+        name: "English (United Kingdom)",
+        language: "en",
+        decimal: ".",
+        group: ",",
+        currency: "{sign}${n}",
+        percent: "{n}%",
+        numeric_date: "%d/%m/%Y",
+    },
+    LocaleRules {
+        code: "de-DE",
+        name: "Deutsch (Deutschland)",
+        language: "de",
+        decimal: ",",
+        group: ".",
+        currency: "{sign}{n} $",
+        percent: "{n} %",
+        numeric_date: "%d.%m.%Y",
+    },
+    LocaleRules {
+        code: "fr-FR",
+        name: "Français (France)",
+        language: "fr",
+        decimal: ",",
+        group: "\u{202f}",
+        currency: "{sign}{n} $",
+        percent: "{n} %",
+        numeric_date: "%d/%m/%Y",
+    },
+    LocaleRules {
+        code: "es-ES",
+        name: "Español (España)",
+        language: "es",
+        decimal: ",",
+        group: ".",
+        currency: "{sign}{n} $",
+        percent: "{n} %",
+        numeric_date: "%d/%m/%Y",
+    },
+    LocaleRules {
+        code: "pt-BR",
+        name: "Português (Brasil)",
+        language: "pt",
+        decimal: ",",
+        group: ".",
+        currency: "{sign}US$ {n}",
+        percent: "{n}%",
+        numeric_date: "%d/%m/%Y",
+    },
+];
+
+type Catalog = &'static [(&'static str, &'static str)];
+
+/// Message templates per language. English is the fallback for any key a
+/// language lacks, and defines which keys and placeholders exist.
+static CATALOGS: &[(&str, Catalog)] = &[
+    (
+        "en",
+        &[
+            ("alert.title", "Price Alert Triggered"),
+            ("alert.triggered", "Alert {name} has been triggered"),
+            ("alert.field.alert", "Alert"),
+            ("alert.field.symbol", "Symbol"),
+            ("alert.field.price", "Price"),
+            ("alert.field.condition", "Condition"),
+            ("alert.field.triggered_at", "Triggered at"),
+            ("report.title", "Weekly summary"),
+            ("report.subject", "Weekly summary: {period}"),
+            ("report.portfolio", "Portfolio"),
+            ("report.portfolio.value", "Value {value}"),
+            ("report.portfolio.value_change", "Value {value} ({change} / {percent})"),
+            ("report.portfolio.realized.one", "Realized P&L {pnl} across {count} closed trade"),
+            ("report.portfolio.realized.other", "Realized P&L {pnl} across {count} closed trades"),
+            ("report.alerts", "Alerts fired ({count})"),
+            ("report.alerts.empty", "No alerts fired this week."),
+            ("report.bot_trades", "Bot trades ({count})"),
+            ("report.bot_trades.empty", "No bot trades this week."),
+            ("report.unlocks", "Upcoming unlocks"),
+            ("report.unlocks.row", "{date} · {symbol} unlocks {amount} tokens"),
+            ("report.unlocks.held", "held"),
+            (
+                "report.unlocks.empty",
+                "No unlocks for held or watchlisted tokens in the next two weeks.",
+            ),
+            ("report.more", "…and {count} more"),
+            ("date.months", "Jan,Feb,Mar,Apr,May,Jun,Jul,Aug,Sep,Oct,Nov,Dec"),
+            ("date.weekdays", "Mon,Tue,Wed,Thu,Fri,Sat,Sun"),
+            ("date.short", "{month} {day}"),
+            ("date.medium", "{month} {day}, {year}"),
+        ],
+    ),
+    (
+        "de",
+        &[
+            ("alert.title", "Preisalarm ausgelöst"),
+            ("alert.triggered", "Alarm {name} wurde ausgelöst"),
+            ("alert.field.alert", "Alarm"),
+            ("alert.field.symbol", "Symbol"),
+            ("alert.field.price", "Preis"),
+            ("alert.field.condition", "Bedingung"),
+            ("alert.field.triggered_at", "Ausgelöst am"),
+            ("report.title", "Wochenübersicht"),
+            ("report.subject", "Wochenübersicht: {period}"),
+            ("report.portfolio", "Portfolio"),
+            ("report.portfolio.value", "Wert {value}"),
+            ("report.portfolio.value_change", "Wert {value} ({change} / {percent})"),
+            (
+                "report.portfolio.realized.one",
+                "Realisierter Gewinn/Verlust {pnl} aus {count} geschlossenem Trade",
+            ),
+            (
+                "report.portfolio.realized.other",
+                "Realisierter Gewinn/Verlust {pnl} aus {count} geschlossenen Trades",
+            ),
+            ("report.alerts", "Ausgelöste Alarme ({count})"),
+            ("report.alerts.empty", "Diese Woche wurden keine Alarme ausgelöst."),
+            ("report.bot_trades", "Bot-Trades ({count})"),
+            ("report.bot_trades.empty", "Diese Woche gab es keine Bot-Trades."),
+            ("report.unlocks", "Anstehende Token-Freigaben"),
+            ("report.unlocks.row", "{date} · {symbol} gibt {amount} Token frei"),
+            ("report.unlocks.held", "gehalten"),
+            (
+                "report.unlocks.empty",
+                "Keine Freigaben für gehaltene oder beobachtete Token in den nächsten zwei Wochen.",
+            ),
+            ("report.more", "…und {count} weitere"),
+            ("date.months", "Jan.,Feb.,März,Apr.,Mai,Juni,Juli,Aug.,Sept.,Okt.,Nov.,Dez."),
+            ("date.weekdays", "Mo.,Di.,Mi.,Do.,Fr.,Sa.,So."),
+            ("date.short", "{day}. {month}"),
+            ("date.medium", "{day}. {month} {year}"),
+        ],
+    ),
+    (
+        "fr",
+        &[
+            ("alert.title", "Alerte de prix déclenchée"),
+            ("alert.triggered", "L'alerte {name} a été déclenchée"),
+            ("alert.field.alert", "Alerte"),
+            ("alert.field.symbol", "Symbole"),
+            ("alert.field.price", "Prix"),
+            ("alert.field.condition", "Condition"),
+            ("alert.field.triggered_at", "Déclenchée le"),
+            ("report.title", "Résumé hebdomadaire"),
+            ("report.subject", "Résumé hebdomadaire : {period}"),
+            ("report.portfolio", "Portefeuille"),
+            ("report.portfolio.value", "Valeur {value}"),
+            ("report.portfolio.value_change", "Valeur {value} ({change} / {percent})"),
+            ("report.portfolio.realized.one", "P&L réalisé {pnl} sur {count} trade clôturé"),
+            ("report.portfolio.realized.other", "P&L réalisé {pnl} sur {count} trades clôturés"),
+            ("report.alerts", "Alertes déclenchées ({count})"),
+            ("report.alerts.empty", "Aucune alerte déclenchée cette semaine."),
+            ("report.bot_trades", "Trades des bots ({count})"),
+            ("report.bot_trades.empty", "Aucun trade de bot cette semaine."),
+            ("report.unlocks", "Déblocages à venir"),
+            ("report.unlocks.row", "{date} · {symbol} débloque {amount} jetons"),
+            ("report.unlocks.held", "détenu"),
+            (
+                "report.unlocks.empty",
+                "Aucun déblocage pour les jetons détenus ou suivis dans les deux prochaines semaines.",
+            ),
+            ("report.more", "…et {count} de plus"),
+            (
+                "date.months",
+                "janv.,févr.,mars,avr.,mai,juin,juil.,août,sept.,oct.,nov.,déc.",
+            ),
+            ("date.weekdays", "lun.,mar.,mer.,jeu.,ven.,sam.,dim."),
+            ("date.short", "{day} {month}"),
+            ("date.medium", "{day} {month} {year}"),
+        ],
+    ),
+    (
+        "es",
+        &[
+            ("alert.title", "Alerta de precio activada"),
+            ("alert.triggered", "La alerta {name} se ha activado"),
+            ("alert.field.alert", "Alerta"),
+            ("alert.field.symbol", "Símbolo"),
+            ("alert.field.price", "Precio"),
+            ("alert.field.condition", "Condición"),
+            ("alert.field.triggered_at", "Activada el"),
+            ("report.title", "Resumen semanal"),
+            ("report.subject", "Resumen semanal: {period}"),
+            ("report.portfolio", "Cartera"),
+            ("report.portfolio.value", "Valor {value}"),
+            ("report.portfolio.value_change", "Valor {value} ({change} / {percent})"),
+            ("report.portfolio.realized.one", "P&L realizado {pnl} en {count} operación cerrada"),
+            (
+                "report.portfolio.realized.other",
+                "P&L realizado {pnl} en {count} operaciones cerradas",
+            ),
+            ("report.alerts", "Alertas activadas ({count})"),
+            ("report.alerts.empty", "No se activaron alertas esta semana."),
+            ("report.bot_trades", "Operaciones de bots ({count})"),
+            ("report.bot_trades.empty", "No hubo operaciones de bots esta semana."),
+            ("report.unlocks", "Próximos desbloqueos"),
+            ("report.unlocks.row", "{date} · {symbol} desbloquea {amount} tokens"),
+            ("report.unlocks.held", "en cartera"),
+            (
+                "report.unlocks.empty",
+                "No hay desbloqueos de tokens en cartera o en seguimiento en las próximas dos semanas.",
+            ),
+            ("report.more", "…y {count} más"),
+            ("date.months", "ene,feb,mar,abr,may,jun,jul,ago,sept,oct,nov,dic"),
+            ("date.weekdays", "lun,mar,mié,jue,vie,sáb,dom"),
+            ("date.short", "{day} {month}"),
+            ("date.medium", "{day} {month} {year}"),
+        ],
+    ),
+    (
+        "pt",
+        &[
+            ("alert.title", "Alerta de preço disparado"),
+            ("alert.triggered", "O alerta {name} foi disparado"),
+            ("alert.field.alert", "Alerta"),
+            ("alert.field.symbol", "Símbolo"),
+            ("alert.field.price", "Preço"),
+            ("alert.field.condition", "Condição"),
+            ("alert.field.triggered_at", "Disparado em"),
+            ("report.title", "Resumo semanal"),
+            ("report.subject", "Resumo semanal: {period}"),
+            ("report.portfolio", "Carteira"),
+            ("report.portfolio.value", "Valor {value}"),
+            ("report.portfolio.value_change", "Valor {value} ({change} / {percent})"),
+            ("report.portfolio.realized.one", "P&L realizado {pnl} em {count} operação fechada"),
+            (
+                "report.portfolio.realized.other",
+                "P&L realizado {pnl} em {count} operações fechadas",
+            ),
+            ("report.alerts", "Alertas disparados ({count})"),
+            ("report.alerts.empty", "Nenhum alerta disparado nesta semana."),
+            ("report.bot_trades", "Operações de bots ({count})"),
+            ("report.bot_trades.empty", "Nenhuma operação de bots nesta semana."),
+            ("report.unlocks", "Próximos desbloqueios"),
+            ("report.unlocks.row", "{date} · {symbol} desbloqueia {amount} tokens"),
+            ("report.unlocks.held", "em carteira"),
+            (
+                "report.unlocks.empty",
+                "Nenhum desbloqueio de tokens em carteira ou monitorados nas próximas duas semanas.",
+            ),
+            ("report.more", "…e mais {count}"),
+            ("date.months", "jan,fev,mar,abr,mai,jun,jul,ago,set,out,nov,dez"),
+            ("date.weekdays", "seg,ter,qua,qui,sex,sáb,dom"),
+            ("date.short", "{day} de {month}"),
+            ("date.medium", "{day} de {month} de {year}"),
+        ],
+    ),
+];
+
+fn locale_rules(code: &str) -> Option<&'static LocaleRules> {
+    LOCALES
+        .iter()
+        .find(|rules| rules.code.eq_ignore_ascii_case(code))
+}
+
+fn catalog_template(language: &str, key: &str) -> Option<&'static str> {
+    CATALOGS
+        .iter()
+        .find(|(lang, _)| *lang == language)
+        .and_then(|(_, catalog)| catalog.iter().find(|(k, _)| *k == key))
+        .map(|(_, template)| *template)
+}
+
+fn placeholders(template: &str) -> Vec<&str> {
+    template
+        .split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizationSettings {
+    pub locale: String,
+    /// Offset used for timestamps in notifications.
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+    /// Replaces built-in templates by key, whatever the locale.
+    #[serde(default)]
+    pub template_overrides: BTreeMap<String, String>,
+}
+
+impl Default for LocalizationSettings {
+    fn default() -> Self {
+        Self {
+            locale: DEFAULT_LOCALE.to_string(),
+            utc_offset_minutes: 0,
+            template_overrides: BTreeMap::new(),
+        }
+    }
+}
+
+impl LocalizationSettings {
+    /// Overrides must name a known template and use only its placeholders, so
+    /// a typo can't leave `{value}` unfilled in a sent statement.
+    pub fn validate(&self) -> Result<(), String> {
+        if locale_rules(&self.locale).is_none() {
+            return Err(format!("Unsupported locale: {}", self.locale));
+        }
+        if self.utc_offset_minutes.abs() > 14 * 60 {
+            return Err("UTC offset must be within ±14 hours".to_string());
+        }
+        for (key, template) in &self.template_overrides {
+            let base = catalog_template(FALLBACK_LANGUAGE, key)
+                .ok_or_else(|| format!("Unknown message template: {key}"))?;
+            let allowed = placeholders(base);
+            if let Some(unknown) = placeholders(template)
+                .into_iter()
+                .find(|p| !allowed.contains(p))
+            {
+                return Err(format!("Template {key} has no placeholder {{{unknown}}}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats numbers, amounts, dates and messages for one locale. Generators
+/// take a snapshot with [`current_localizer`] so a settings change never
+/// mixes two locales in one document.
+#[derive(Debug, Clone)]
+pub struct Localizer {
+    rules: &'static LocaleRules,
+    offset: FixedOffset,
+    overrides: BTreeMap<String, String>,
+}
+
+impl Default for Localizer {
+    fn default() -> Self {
+        Self::new(&LocalizationSettings::default())
+    }
+}
+
+impl Localizer {
+    pub fn new(settings: &LocalizationSettings) -> Self {
+        Self {
+            rules: locale_rules(&settings.locale).unwrap_or(&LOCALES[0]),
+            offset: FixedOffset::east_opt(settings.utc_offset_minutes * 60)
+                .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap()),
+            overrides: settings.template_overrides.clone(),
+        }
+    }
+
+    pub fn locale(&self) -> &'static str {
+        self.rules.code
+    }
+
+    /// Message for `key` with `{placeholder}`s filled from `args`. Falls back
+    /// from the user's override to the locale's language, then English, then
+    /// the key itself.
+    pub fn text(&self, key: &str, args: &[(&str, &str)]) -> String {
+        let template = self
+            .overrides
+            .get(key)
+            .map(String::as_str)
+            .or_else(|| catalog_template(self.rules.language, key))
+            .or_else(|| catalog_template(FALLBACK_LANGUAGE, key))
+            .unwrap_or(key);
+
+        args.iter()
+            .fold(template.to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{name}}}"), value)
+            })
+    }
+
+    /// Picks `{key}.one` or `{key}.other` by `count`; `{count}` is filled in.
+    pub fn plural(&self, key: &str, count: u64, args: &[(&str, &str)]) -> String {
+        let form = if count == 1 { "one" } else { "other" };
+        let count = self.number(count as f64, 0);
+        let mut args = args.to_vec();
+        args.push(("count", &count));
+        self.text(&format!("{key}.{form}"), &args)
+    }
+
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        let formatted = format!("{:.*}", decimals, value.abs());
+        let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+        let mut grouped = String::new();
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push_str(self.rules.group);
+            }
+            grouped.push(digit);
+        }
+        if !fraction.is_empty() {
+            grouped.push_str(self.rules.decimal);
+            grouped.push_str(fraction);
+        }
+
+        // Values that round to zero don't keep a minus sign
+        let is_zero = formatted.chars().all(|c| c == '0' || c == '.');
+        if value < 0.0 && !is_zero {
+            format!("-{grouped}")
+        } else {
+            grouped
+        }
+    }
+
+    /// A USD amount, e.g. `$1,234.50` or `1.234,50 $`.
+    pub fn currency(&self, value: f64, decimals: usize) -> String {
+        let amount = self.number(value, decimals);
+        let (sign, amount) = match amount.strip_prefix('-') {
+            Some(abs) => ("-", abs.to_string()),
+            None => ("", amount),
+        };
+        self.rules
+            .currency
+            .replace("{sign}", sign)
+            .replace("{n}", &amount)
+    }
+
+    /// Like [`currency`](Self::currency) with an explicit `+` on gains.
+    pub fn signed_currency(&self, value: f64, decimals: usize) -> String {
+        let formatted = self.currency(value, decimals);
+        if value > 0.0 && self.number(value, decimals) != self.number(0.0, decimals) {
+            format!("+{formatted}")
+        } else {
+            formatted
+        }
+    }
+
+    /// A signed percentage, e.g. `+4.20%` or `+4,20 %`.
+    pub fn percent(&self, value: f64, decimals: usize) -> String {
+        let number = self.number(value, decimals);
+        let number = if value > 0.0 && number != self.number(0.0, decimals) {
+            format!("+{number}")
+        } else {
+            number
+        };
+        self.rules.percent.replace("{n}", &number)
+    }
+
+    /// Converts to the offset configured for notifications.
+    pub fn local(&self, time: DateTime<Utc>) -> DateTime<FixedOffset> {
+        time.with_timezone(&self.offset)
+    }
+
+    fn name_list(&self, key: &str, index: usize) -> String {
+        self.text(key, &[])
+            .split(',')
+            .nth(index)
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    fn month_name(&self, time: &DateTime<FixedOffset>) -> String {
+        self.name_list("date.months", time.month0() as usize)
+    }
+
+    fn weekday_name(&self, time: &DateTime<FixedOffset>) -> String {
+        self.name_list(
+            "date.weekdays",
+            time.weekday().num_days_from_monday() as usize,
+        )
+    }
+
+    /// Day and month, e.g. `May 6` or `6. Mai`.
+    pub fn short_date(&self, time: &DateTime<FixedOffset>) -> String {
+        let (month, day) = (self.month_name(time), time.day().to_string());
+        self.text("date.short", &[("month", &month), ("day", &day)])
+    }
+
+    /// Day, month and year, e.g. `May 6, 2024` or `6. Mai 2024`.
+    pub fn medium_date(&self, time: &DateTime<FixedOffset>) -> String {
+        let (month, day, year) = (
+            self.month_name(time),
+            time.day().to_string(),
+            time.year().to_string(),
+        );
+        self.text(
+            "date.medium",
+            &[("month", &month), ("day", &day), ("year", &year)],
+        )
+    }
+
+    /// Weekday and 24-hour time, e.g. `Tue 14:05`.
+    pub fn weekday_time(&self, time: &DateTime<FixedOffset>) -> String {
+        format!("{} {}", self.weekday_name(time), time.format("%H:%M"))
+    }
+
+    /// Weekday and short date, e.g. `Tue May 7`.
+    pub fn weekday_date(&self, time: &DateTime<FixedOffset>) -> String {
+        format!("{} {}", self.weekday_name(time), self.short_date(time))
+    }
+
+    /// Numeric date and time with the zone, e.g. `05/07/2024 14:05 UTC`.
+    pub fn date_time(&self, time: DateTime<Utc>) -> String {
+        let local = self.local(time);
+        let seconds = self.offset.local_minus_utc();
+        let zone = if seconds == 0 {
+            "UTC".to_string()
+        } else {
+            let sign = if seconds < 0 { '-' } else { '+' };
+            let minutes = seconds.abs() / 60;
+            format!("UTC{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+        };
+        format!(
+            "{} {zone}",
+            local.format(&format!("{} %H:%M", self.rules.numeric_date))
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupportedLocale {
+    pub code: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizationPreview {
+    pub number: String,
+    pub currency: String,
+    pub percent: String,
+    pub date: String,
+    pub date_time: String,
+    pub message: String,
+}
+
+lazy_static::lazy_static! {
+    static ref LOCALIZATION: RwLock<LocalizationSettings> =
+        RwLock::new(LocalizationSettings::default());
+}
+
+/// The localizer for the current settings; generators call this once per
+/// document or message.
+pub fn current_localizer() -> Localizer {
+    Localizer::new(&LOCALIZATION.read())
+}
+
+fn localization_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(LOCALIZATION_FILE))
+}
+
+/// Loads saved settings at startup. A locale that is no longer supported
+/// falls back to the default rather than failing startup.
+pub fn load_localization(app: &AppHandle) -> Result<(), String> {
+    let path = localization_path(app)?;
+    let mut settings = if path.exists() {
+        let data = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        serde_json::from_str::<LocalizationSettings>(&data).map_err(|e| e.to_string())?
+    } else {
+        LocalizationSettings::default()
+    };
+    if settings.validate().is_err() {
+        settings.locale = DEFAULT_LOCALE.to_string();
+        settings.template_overrides.clear();
+    }
+
+    *LOCALIZATION.write() = settings;
+    Ok(())
+}
+
+fn preview(settings: &LocalizationSettings) -> LocalizationPreview {
+    let localizer = Localizer::new(settings);
+    let now = Utc::now();
+    LocalizationPreview {
+        number: localizer.number(1_234_567.891, 2),
+        currency: localizer.signed_currency(-1_234.5, 2),
+        percent: localizer.percent(4.2, 2),
+        date: localizer.medium_date(&localizer.local(now)),
+        date_time: localizer.date_time(now),
+        message: localizer.text("alert.triggered", &[("name", "SOL > 150")]),
+    }
+}
+
+#[tauri::command]
+pub async fn get_localization_settings() -> Result<LocalizationSettings, String> {
+    Ok(LOCALIZATION.read().clone())
+}
+
+#[tauri::command]
+pub async fn update_localization_settings(
+    settings: LocalizationSettings,
+    app_handle: AppHandle,
+) -> Result<LocalizationSettings, String> {
+    settings.validate()?;
+    let json = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+    fs::write(localization_path(&app_handle)?, json).map_err(|e| e.to_string())?;
+    *LOCALIZATION.write() = settings.clone();
+
+    if let Err(err) = app_handle.emit_all(LOCALIZATION_EVENT, &settings) {
+        tracing::warn!(error = %err, "failed to emit localization change");
+    }
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn list_supported_locales() -> Result<Vec<SupportedLocale>, String> {
+    Ok(LOCALES
+        .iter()
+        .map(|rules| SupportedLocale {
+            code: rules.code.to_string(),
+            name: rules.name.to_string(),
+        })
+        .collect())
+}
+
+/// Formats sample values with unsaved settings so the settings page can show
+/// the result before applying it.
+#[tauri::command]
+pub async fn preview_localization(
+    settings: LocalizationSettings,
+) -> Result<LocalizationPreview, String> {
+    settings.validate()?;
+    Ok(preview(&settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn localizer(locale: &str) -> Localizer {
+        Localizer::new(&LocalizationSettings {
+            locale: locale.to_string(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn formats_numbers_and_amounts_per_locale() {
+        let en = localizer("en-US");
+        assert_eq!(en.number(1_234_567.891, 2), "1,234,567.89");
+        assert_eq!(en.currency(-1_234.5, 2), "-$1,234.50");
+        assert_eq!(en.signed_currency(12.0, 2), "+$12.00");
+        assert_eq!(en.percent(4.2, 2), "+4.20%");
+        assert_eq!(en.number(-0.001, 2), "0.00");
+
+        let de = localizer("de-DE");
+        assert_eq!(de.number(1_234_567.891, 2), "1.234.567,89");
+        assert_eq!(de.currency(-1_234.5, 2), "-1.234,50 $");
+        assert_eq!(de.percent(-4.2, 1), "-4,2 %");
+
+        assert_eq!(localizer("pt-BR").currency(999.0, 0), "US$ 999");
+        assert_eq!(localizer("fr-FR").number(1_000.0, 0), "1\u{202f}000");
+    }
+
+    #[test]
+    fn dates_use_localized_names_and_order() {
+        let time = Utc.with_ymd_and_hms(2024, 5, 7, 14, 5, 0).unwrap();
+        let en = localizer("en-US");
+        let local = en.local(time);
+        assert_eq!(en.medium_date(&local), "May 7, 2024");
+        assert_eq!(en.weekday_time(&local), "Tue 14:05");
+        assert_eq!(en.date_time(time), "05/07/2024 14:05 UTC");
+
+        let de = Localizer::new(&LocalizationSettings {
+            locale: "de-DE".to_string(),
+            utc_offset_minutes: 120,
+            ..Default::default()
+        });
+        assert_eq!(de.medium_date(&de.local(time)), "7. Mai 2024");
+        assert_eq!(de.weekday_date(&de.local(time)), "Di. 7. Mai");
+        assert_eq!(de.date_time(time), "07.05.2024 16:05 UTC+02:00");
+    }
+
+    #[test]
+    fn templates_fall_back_and_overrides_are_checked() {
+        let mut settings = LocalizationSettings {
+            locale: "es-ES".to_string(),
+            ..Default::default()
+        };
+        let es = Localizer::new(&settings);
+        assert_eq!(es.text("report.title", &[]), "Resumen semanal");
+        assert_eq!(
+            es.plural("report.portfolio.realized", 1, &[("pnl", "+5 $")]),
+            "P&L realizado +5 $ en 1 operación cerrada"
+        );
+        assert_eq!(es.text("unknown.key", &[]), "unknown.key");
+
+        settings
+            .template_overrides
+            .insert("report.title".to_string(), "Mi resumen".to_string());
+        assert!(settings.validate().is_ok());
+        assert_eq!(
+            Localizer::new(&settings).text("report.title", &[]),
+            "Mi resumen"
+        );
+
+        settings.template_overrides.insert(
+            "report.subject".to_string(),
+            "Resumen {periodo}".to_string(),
+        );
+        assert!(settings.validate().is_err());
+
+        settings.template_overrides.clear();
+        settings.locale = "xx-XX".to_string();
+        assert!(settings.validate().is_err());
+    }
+}
@@ -1,7 +1,25 @@
 pub mod settings_schema;
 pub mod settings_manager;
 pub mod commands;
+pub mod version_history;
+pub mod recycle_bin;
+pub mod entity_versions;
+pub mod data_sources;
+pub mod http_recorder;
+pub mod setup_bundle;
+pub mod token_registry;
+pub mod localization;
+pub mod feature_flags;
 
 pub use settings_schema::*;
 pub use settings_manager::*;
 pub use commands::*;
+pub use version_history::*;
+pub use recycle_bin::*;
+pub use entity_versions::*;
+pub use data_sources::*;
+pub use http_recorder::*;
+pub use setup_bundle::*;
+pub use token_registry::*;
+pub use localization::*;
+pub use feature_flags::*;
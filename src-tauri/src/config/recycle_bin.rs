@@ -0,0 +1,467 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use super::version_history::{record_config_change, ConfigEntity, LOCAL_ACTOR};
+use crate::alerts::{PriceAlert, SharedAlertManager};
+use crate::portfolio::{SharedWatchlistManager, Watchlist};
+use crate::security::keystore::Keystore;
+use crate::wallet::multi_wallet::{MultiWalletManager, WalletInfo};
+use crate::webhooks::{SharedWebhookManager, WebhookConfig};
+
+const RECYCLE_BIN_DB_FILE: &str = "recycle_bin.db";
+/// How long deleted entities stay restorable.
+const RECYCLE_TTL_DAYS: i64 = 7;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecycleBinError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("deleted item not found: {0}")]
+    NotFound(String),
+    #[error("nothing to undo")]
+    Empty,
+    #[error("restore failed: {0}")]
+    Restore(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecycledEntityKind {
+    Watchlist,
+    Alert,
+    Wallet,
+    Webhook,
+}
+
+impl RecycledEntityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecycledEntityKind::Watchlist => "watchlist",
+            RecycledEntityKind::Alert => "alert",
+            RecycledEntityKind::Wallet => "wallet",
+            RecycledEntityKind::Webhook => "webhook",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "watchlist" => Some(RecycledEntityKind::Watchlist),
+            "alert" => Some(RecycledEntityKind::Alert),
+            "wallet" => Some(RecycledEntityKind::Wallet),
+            "webhook" => Some(RecycledEntityKind::Webhook),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletedItem {
+    pub id: String,
+    pub kind: RecycledEntityKind,
+    pub entity_id: String,
+    pub label: String,
+    pub snapshot: Value,
+    pub deleted_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoredItem {
+    pub kind: RecycledEntityKind,
+    pub entity_id: String,
+    pub label: String,
+    pub restored: Value,
+}
+
+pub fn recycle_expiry(deleted_at: DateTime<Utc>) -> DateTime<Utc> {
+    deleted_at + Duration::days(RECYCLE_TTL_DAYS)
+}
+
+#[derive(Clone)]
+pub struct RecycleBin {
+    pool: Pool<Sqlite>,
+}
+
+pub type SharedRecycleBin = Arc<RwLock<RecycleBin>>;
+
+impl RecycleBin {
+    pub async fn new(app: &AppHandle) -> Result<Self, RecycleBinError> {
+        let db_path = recycle_bin_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let bin = Self { pool };
+        bin.initialize().await?;
+        Ok(bin)
+    }
+
+    async fn initialize(&self) -> Result<(), RecycleBinError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS deleted_items (
+                id TEXT PRIMARY KEY,
+                kind TEXT NOT NULL,
+                entity_id TEXT NOT NULL,
+                label TEXT NOT NULL,
+                snapshot TEXT NOT NULL,
+                deleted_at TEXT NOT NULL,
+                expires_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_deleted_items_deleted_at ON deleted_items(deleted_at)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn put(
+        &self,
+        kind: RecycledEntityKind,
+        entity_id: &str,
+        label: &str,
+        snapshot: Value,
+    ) -> Result<DeletedItem, RecycleBinError> {
+        let deleted_at = Utc::now();
+        let item = DeletedItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind,
+            entity_id: entity_id.to_string(),
+            label: label.to_string(),
+            snapshot,
+            deleted_at,
+            expires_at: recycle_expiry(deleted_at),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO deleted_items (id, kind, entity_id, label, snapshot, deleted_at, expires_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )
+        .bind(&item.id)
+        .bind(kind.as_str())
+        .bind(&item.entity_id)
+        .bind(&item.label)
+        .bind(serde_json::to_string(&item.snapshot)?)
+        .bind(item.deleted_at.to_rfc3339())
+        .bind(item.expires_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(item)
+    }
+
+    pub async fn purge_expired(&self) -> Result<u64, RecycleBinError> {
+        let result = sqlx::query("DELETE FROM deleted_items WHERE expires_at <= ?1")
+            .bind(Utc::now().to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Restorable items, newest first.
+    pub async fn list(
+        &self,
+        kind: Option<RecycledEntityKind>,
+    ) -> Result<Vec<DeletedItem>, RecycleBinError> {
+        self.purge_expired().await?;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, kind, entity_id, label, snapshot, deleted_at, expires_at
+            FROM deleted_items
+            WHERE ?1 IS NULL OR kind = ?1
+            ORDER BY deleted_at DESC
+            "#,
+        )
+        .bind(kind.map(|k| k.as_str()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_item).collect()
+    }
+
+    pub async fn get(&self, id: &str) -> Result<DeletedItem, RecycleBinError> {
+        self.purge_expired().await?;
+
+        let row = sqlx::query(
+            r#"
+            SELECT id, kind, entity_id, label, snapshot, deleted_at, expires_at
+            FROM deleted_items
+            WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| RecycleBinError::NotFound(id.to_string()))?;
+
+        row_to_item(&row)
+    }
+
+    pub async fn remove(&self, id: &str) -> Result<(), RecycleBinError> {
+        sqlx::query("DELETE FROM deleted_items WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+fn row_to_item(row: &SqliteRow) -> Result<DeletedItem, RecycleBinError> {
+    let kind: String = row.try_get("kind")?;
+    let snapshot: String = row.try_get("snapshot")?;
+    let deleted_at: String = row.try_get("deleted_at")?;
+    let expires_at: String = row.try_get("expires_at")?;
+    let parse_time = |value: &str| {
+        DateTime::parse_from_rfc3339(value)
+            .map(|t| t.with_timezone(&Utc))
+            .map_err(|e| RecycleBinError::Internal(e.to_string()))
+    };
+
+    Ok(DeletedItem {
+        id: row.try_get("id")?,
+        kind: RecycledEntityKind::from_str(&kind)
+            .ok_or_else(|| RecycleBinError::Internal(format!("unknown kind {kind}")))?,
+        entity_id: row.try_get("entity_id")?,
+        label: row.try_get("label")?,
+        snapshot: serde_json::from_str(&snapshot)?,
+        deleted_at: parse_time(&deleted_at)?,
+        expires_at: parse_time(&expires_at)?,
+    })
+}
+
+fn recycle_bin_db_path(app: &AppHandle) -> Result<PathBuf, RecycleBinError> {
+    let app_data_dir = app.path_resolver().app_data_dir().ok_or_else(|| {
+        RecycleBinError::Internal("Unable to resolve app data directory".to_string())
+    })?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(RECYCLE_BIN_DB_FILE))
+}
+
+/// Opt-in hook for destructive commands: call with the entity just before deleting
+/// it, and abort the delete on error so nothing is removed without an undo. If the
+/// delete then fails, drop the copy again with `discard_recycled`.
+pub async fn recycle_deleted<T: Serialize>(
+    app: &AppHandle,
+    kind: RecycledEntityKind,
+    entity_id: &str,
+    label: &str,
+    entity: &T,
+) -> Result<DeletedItem, RecycleBinError> {
+    let bin = app
+        .try_state::<SharedRecycleBin>()
+        .ok_or_else(|| RecycleBinError::Internal("recycle bin unavailable".to_string()))?;
+    let snapshot = serde_json::to_value(entity)?;
+    let item = bin.read().await.put(kind, entity_id, label, snapshot).await?;
+    Ok(item)
+}
+
+/// Removes a recycled copy whose delete failed or was rolled back, so undo
+/// can't restore something that was never gone.
+pub async fn discard_recycled(app: &AppHandle, item_id: &str) {
+    let Some(bin) = app.try_state::<SharedRecycleBin>() else {
+        return;
+    };
+    if let Err(err) = bin.read().await.remove(item_id).await {
+        tracing::warn!(error = %err, item_id, "failed to discard recycled item");
+    }
+}
+
+/// Message for a delete refused because its undo couldn't be recorded.
+pub fn undo_unavailable(err: RecycleBinError) -> String {
+    format!("not deleted: undo is unavailable ({err})")
+}
+
+/// Writes the snapshot back through the owning module, keeping the original id.
+async fn apply_restore(app: &AppHandle, item: &DeletedItem) -> Result<Value, RecycleBinError> {
+    let restore_err = |e: String| RecycleBinError::Restore(e);
+    let snapshot = item.snapshot.clone();
+
+    match item.kind {
+        RecycledEntityKind::Watchlist => {
+            let manager = app
+                .try_state::<SharedWatchlistManager>()
+                .ok_or_else(|| restore_err("watchlist manager unavailable".to_string()))?;
+            let watchlist: Watchlist = serde_json::from_value(snapshot)?;
+            let restored = manager
+                .read()
+                .await
+                .restore_watchlist(watchlist)
+                .await
+                .map_err(|e| restore_err(e.to_string()))?;
+            Ok(serde_json::to_value(restored)?)
+        }
+        RecycledEntityKind::Alert => {
+            let manager = app
+                .try_state::<SharedAlertManager>()
+                .ok_or_else(|| restore_err("alert manager unavailable".to_string()))?;
+            let alert: PriceAlert = serde_json::from_value(snapshot)?;
+            let restored = manager
+                .read()
+                .await
+                .restore_alert(alert)
+                .await
+                .map_err(|e| restore_err(e.to_string()))?;
+            record_config_change(
+                app,
+                ConfigEntity::alert(&restored.id),
+                Some(&restored),
+                LOCAL_ACTOR,
+            )
+            .await;
+            Ok(serde_json::to_value(restored)?)
+        }
+        RecycledEntityKind::Wallet => {
+            let manager = app
+                .try_state::<MultiWalletManager>()
+                .ok_or_else(|| restore_err("wallet manager unavailable".to_string()))?;
+            let keystore = app
+                .try_state::<Keystore>()
+                .ok_or_else(|| restore_err("keystore unavailable".to_string()))?;
+            let wallet: WalletInfo = serde_json::from_value(snapshot)?;
+            let restored = manager
+                .restore_wallet(wallet, &keystore)
+                .map_err(|e| restore_err(e.to_string()))?;
+            Ok(serde_json::to_value(restored)?)
+        }
+        RecycledEntityKind::Webhook => {
+            let manager = app
+                .try_state::<SharedWebhookManager>()
+                .ok_or_else(|| restore_err("webhook manager unavailable".to_string()))?;
+            let config: WebhookConfig = serde_json::from_value(snapshot)?;
+            let restored = manager
+                .read()
+                .await
+                .restore_webhook(config)
+                .await
+                .map_err(|e| restore_err(e.to_string()))?;
+            Ok(serde_json::to_value(restored)?)
+        }
+    }
+}
+
+async fn restore_deleted(
+    app: &AppHandle,
+    bin: &RecycleBin,
+    item: DeletedItem,
+) -> Result<RestoredItem, RecycleBinError> {
+    let restored = apply_restore(app, &item).await?;
+    bin.remove(&item.id).await?;
+
+    Ok(RestoredItem {
+        kind: item.kind,
+        entity_id: item.entity_id,
+        label: item.label,
+        restored,
+    })
+}
+
+#[tauri::command]
+pub async fn list_deleted_items(
+    kind: Option<RecycledEntityKind>,
+    bin: State<'_, SharedRecycleBin>,
+) -> Result<Vec<DeletedItem>, String> {
+    let bin = bin.read().await;
+    bin.list(kind).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn restore_item(
+    id: String,
+    app_handle: AppHandle,
+    bin: State<'_, SharedRecycleBin>,
+) -> Result<RestoredItem, String> {
+    let bin = bin.read().await;
+    let item = bin.get(&id).await.map_err(|e| e.to_string())?;
+    restore_deleted(&app_handle, &bin, item)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Restores the most recently deleted entity.
+#[tauri::command]
+pub async fn undo_last_operation(
+    app_handle: AppHandle,
+    bin: State<'_, SharedRecycleBin>,
+) -> Result<RestoredItem, String> {
+    let bin = bin.read().await;
+    let item = bin
+        .list(None)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .next()
+        .ok_or_else(|| RecycleBinError::Empty.to_string())?;
+    restore_deleted(&app_handle, &bin, item)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds_round_trip() {
+        for kind in [
+            RecycledEntityKind::Watchlist,
+            RecycledEntityKind::Alert,
+            RecycledEntityKind::Wallet,
+            RecycledEntityKind::Webhook,
+        ] {
+            assert_eq!(RecycledEntityKind::from_str(kind.as_str()), Some(kind));
+        }
+        assert_eq!(RecycledEntityKind::from_str("bot"), None);
+    }
+
+    #[test]
+    fn items_expire_after_ttl() {
+        let deleted_at = Utc::now();
+        assert_eq!(
+            recycle_expiry(deleted_at) - deleted_at,
+            Duration::days(RECYCLE_TTL_DAYS)
+        );
+    }
+
+    #[test]
+    fn deleted_item_serializes_camel_case() {
+        let deleted_at = Utc::now();
+        let item = DeletedItem {
+            id: "r1".into(),
+            kind: RecycledEntityKind::Webhook,
+            entity_id: "w1".into(),
+            label: "Discord relay".into(),
+            snapshot: serde_json::json!({ "id": "w1" }),
+            deleted_at,
+            expires_at: recycle_expiry(deleted_at),
+        };
+
+        let value = serde_json::to_value(&item).unwrap();
+        assert_eq!(value["kind"], "webhook");
+        assert_eq!(value["entityId"], "w1");
+        assert!(value.get("expiresAt").is_some());
+    }
+}
@@ -0,0 +1,1028 @@
+//! YAML export/import of alert definitions and bot configs.
+//!
+//! Definitions are keyed by section and name (`alerts/<name>`), never by local id,
+//! so a bundle can be kept in git or handed to another user. Bot definitions carry
+//! no wallet; the importing wallet is supplied at import time. Imports only create
+//! and update, they never delete anything that is missing from the bundle.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Manager};
+
+use crate::alerts::price_alerts::{
+    AlertState, CompoundCondition, CreateAlertRequest, NotificationChannel, PriceAlert,
+    SharedAlertManager, UpdateAlertRequest,
+};
+use crate::alerts::rearm::AlertRearmPolicy;
+use crate::bots::dca_bot::{dca_manager, preview_next_execution, CreateDcaRequest, DcaConfig};
+use crate::config::version_history::{
+    diff_configs, record_config_change, ConfigEntity, LOCAL_ACTOR,
+};
+use crate::trading::copy_trading::{copy_trade_manager, CopyTradeConfig, CreateCopyTradeRequest};
+
+pub const SETUP_SCHEMA_VERSION: u32 = 1;
+
+/// Alert fields that identify what is being watched; changing them on an existing
+/// alert is reported as a conflict instead of being applied.
+const ALERT_IMMUTABLE_FIELDS: &[&str] = &["symbol", "mint"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupSection {
+    Alerts,
+    DcaBots,
+    CopyTrading,
+}
+
+impl SetupSection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SetupSection::Alerts => "alerts",
+            SetupSection::DcaBots => "dca_bots",
+            SetupSection::CopyTrading => "copy_trading",
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AlertDefinition {
+    pub name: String,
+    pub symbol: String,
+    pub mint: String,
+    pub compound_condition: CompoundCondition,
+    pub notification_channels: Vec<NotificationChannel>,
+    pub cooldown_minutes: i32,
+    #[serde(default)]
+    pub rearm_policy: AlertRearmPolicy,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+impl From<&PriceAlert> for AlertDefinition {
+    fn from(alert: &PriceAlert) -> Self {
+        Self {
+            name: alert.name.clone(),
+            symbol: alert.symbol.clone(),
+            mint: alert.mint.clone(),
+            compound_condition: alert.compound_condition.clone(),
+            notification_channels: alert.notification_channels.clone(),
+            cooldown_minutes: alert.cooldown_minutes,
+            rearm_policy: alert.rearm_policy.clone(),
+            enabled: alert.state != AlertState::Disabled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DcaDefinition {
+    pub name: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub input_symbol: String,
+    pub output_symbol: String,
+    pub input_decimals: i32,
+    pub output_decimals: i32,
+    pub amount_per_execution: f64,
+    pub total_budget: f64,
+    pub schedule_cron: String,
+    pub slippage_bps: i32,
+    pub priority_fee_micro_lamports: i32,
+    pub max_price_impact_pct: f64,
+    #[serde(default)]
+    pub daily_spend_cap: Option<f64>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+impl From<&DcaConfig> for DcaDefinition {
+    fn from(config: &DcaConfig) -> Self {
+        Self {
+            name: config.name.clone(),
+            input_mint: config.input_mint.clone(),
+            output_mint: config.output_mint.clone(),
+            input_symbol: config.input_symbol.clone(),
+            output_symbol: config.output_symbol.clone(),
+            input_decimals: config.input_decimals,
+            output_decimals: config.output_decimals,
+            amount_per_execution: config.amount_per_execution,
+            total_budget: config.total_budget,
+            schedule_cron: config.schedule_cron.clone(),
+            slippage_bps: config.slippage_bps,
+            priority_fee_micro_lamports: config.priority_fee_micro_lamports,
+            max_price_impact_pct: config.max_price_impact_pct,
+            daily_spend_cap: config.daily_spend_cap,
+            enabled: config.is_active,
+        }
+    }
+}
+
+impl DcaDefinition {
+    fn to_request(&self, wallet_address: &str) -> CreateDcaRequest {
+        CreateDcaRequest {
+            name: self.name.clone(),
+            wallet_address: wallet_address.to_string(),
+            input_mint: self.input_mint.clone(),
+            output_mint: self.output_mint.clone(),
+            input_symbol: self.input_symbol.clone(),
+            output_symbol: self.output_symbol.clone(),
+            input_decimals: self.input_decimals,
+            output_decimals: self.output_decimals,
+            amount_per_execution: self.amount_per_execution,
+            total_budget: self.total_budget,
+            schedule_cron: self.schedule_cron.clone(),
+            slippage_bps: self.slippage_bps,
+            priority_fee_micro_lamports: self.priority_fee_micro_lamports,
+            max_price_impact_pct: self.max_price_impact_pct,
+            daily_spend_cap: self.daily_spend_cap,
+        }
+    }
+
+    fn apply_to(&self, config: &mut DcaConfig) {
+        config.name = self.name.clone();
+        config.input_mint = self.input_mint.clone();
+        config.output_mint = self.output_mint.clone();
+        config.input_symbol = self.input_symbol.clone();
+        config.output_symbol = self.output_symbol.clone();
+        config.input_decimals = self.input_decimals;
+        config.output_decimals = self.output_decimals;
+        config.amount_per_execution = self.amount_per_execution;
+        config.total_budget = self.total_budget;
+        config.schedule_cron = self.schedule_cron.clone();
+        config.slippage_bps = self.slippage_bps;
+        config.priority_fee_micro_lamports = self.priority_fee_micro_lamports;
+        config.max_price_impact_pct = self.max_price_impact_pct;
+        config.daily_spend_cap = self.daily_spend_cap;
+        config.is_active = self.enabled;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CopyTradeDefinition {
+    pub name: String,
+    pub source_wallet: String,
+    pub allocation_percentage: f64,
+    pub multiplier: f64,
+    #[serde(default)]
+    pub min_trade_amount: Option<f64>,
+    #[serde(default)]
+    pub max_trade_amount: Option<f64>,
+    pub delay_seconds: i32,
+    #[serde(default)]
+    pub token_whitelist: Option<Vec<String>>,
+    #[serde(default)]
+    pub token_blacklist: Option<Vec<String>>,
+    #[serde(default)]
+    pub stop_loss_percentage: Option<f64>,
+    #[serde(default)]
+    pub take_profit_percentage: Option<f64>,
+    #[serde(default)]
+    pub max_daily_trades: Option<i32>,
+    #[serde(default)]
+    pub max_total_loss: Option<f64>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn split_token_list(list: &Option<String>) -> Option<Vec<String>> {
+    list.as_ref().map(|list| {
+        list.split(',')
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(str::to_string)
+            .collect()
+    })
+}
+
+impl From<&CopyTradeConfig> for CopyTradeDefinition {
+    fn from(config: &CopyTradeConfig) -> Self {
+        Self {
+            name: config.name.clone(),
+            source_wallet: config.source_wallet.clone(),
+            allocation_percentage: config.allocation_percentage,
+            multiplier: config.multiplier,
+            min_trade_amount: config.min_trade_amount,
+            max_trade_amount: config.max_trade_amount,
+            delay_seconds: config.delay_seconds,
+            token_whitelist: split_token_list(&config.token_whitelist),
+            token_blacklist: split_token_list(&config.token_blacklist),
+            stop_loss_percentage: config.stop_loss_percentage,
+            take_profit_percentage: config.take_profit_percentage,
+            max_daily_trades: config.max_daily_trades,
+            max_total_loss: config.max_total_loss,
+            enabled: config.is_active,
+        }
+    }
+}
+
+impl CopyTradeDefinition {
+    fn to_request(&self, wallet_address: &str) -> CreateCopyTradeRequest {
+        CreateCopyTradeRequest {
+            name: self.name.clone(),
+            wallet_address: wallet_address.to_string(),
+            source_wallet: self.source_wallet.clone(),
+            allocation_percentage: self.allocation_percentage,
+            multiplier: self.multiplier,
+            min_trade_amount: self.min_trade_amount,
+            max_trade_amount: self.max_trade_amount,
+            delay_seconds: self.delay_seconds,
+            token_whitelist: self.token_whitelist.clone(),
+            token_blacklist: self.token_blacklist.clone(),
+            stop_loss_percentage: self.stop_loss_percentage,
+            take_profit_percentage: self.take_profit_percentage,
+            max_daily_trades: self.max_daily_trades,
+            max_total_loss: self.max_total_loss,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SetupBundle {
+    pub schema_version: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alerts: Vec<AlertDefinition>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dca_bots: Vec<DcaDefinition>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub copy_trading: Vec<CopyTradeDefinition>,
+}
+
+impl Default for SetupBundle {
+    fn default() -> Self {
+        Self {
+            schema_version: SETUP_SCHEMA_VERSION,
+            alerts: Vec::new(),
+            dca_bots: Vec::new(),
+            copy_trading: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SetupValidationIssue {
+    pub path: String,
+    pub message: String,
+}
+
+impl SetupValidationIssue {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Parses a bundle and checks it against the schema. All problems are reported
+/// at once so a hand-edited file can be fixed in one pass.
+pub fn parse_setup_bundle(yaml: &str) -> Result<SetupBundle, Vec<SetupValidationIssue>> {
+    let bundle: SetupBundle = serde_yaml::from_str(yaml).map_err(|e| {
+        let path = e
+            .location()
+            .map(|loc| format!("line {}, column {}", loc.line(), loc.column()))
+            .unwrap_or_default();
+        vec![SetupValidationIssue::new(path, e.to_string())]
+    })?;
+
+    let issues = validate_setup_bundle(&bundle);
+    if issues.is_empty() {
+        Ok(bundle)
+    } else {
+        Err(issues)
+    }
+}
+
+fn check_names<'a>(
+    section: SetupSection,
+    names: impl Iterator<Item = &'a str>,
+    issues: &mut Vec<SetupValidationIssue>,
+) {
+    let mut seen = HashSet::new();
+    for (index, name) in names.enumerate() {
+        let path = format!("{}[{}].name", section.as_str(), index);
+        if name.trim().is_empty() {
+            issues.push(SetupValidationIssue::new(path, "name must not be empty"));
+        } else if !seen.insert(name) {
+            issues.push(SetupValidationIssue::new(
+                path,
+                format!("duplicate name '{name}'"),
+            ));
+        }
+    }
+}
+
+pub fn validate_setup_bundle(bundle: &SetupBundle) -> Vec<SetupValidationIssue> {
+    let mut issues = Vec::new();
+
+    if bundle.schema_version != SETUP_SCHEMA_VERSION {
+        issues.push(SetupValidationIssue::new(
+            "schema_version",
+            format!(
+                "unsupported schema version {} (expected {})",
+                bundle.schema_version, SETUP_SCHEMA_VERSION
+            ),
+        ));
+    }
+
+    check_names(
+        SetupSection::Alerts,
+        bundle.alerts.iter().map(|alert| alert.name.as_str()),
+        &mut issues,
+    );
+    for (index, alert) in bundle.alerts.iter().enumerate() {
+        let path = |field: &str| format!("alerts[{index}].{field}");
+        if alert.mint.trim().is_empty() {
+            issues.push(SetupValidationIssue::new(
+                path("mint"),
+                "mint must not be empty",
+            ));
+        }
+        if alert.compound_condition.conditions.is_empty() {
+            issues.push(SetupValidationIssue::new(
+                path("compound_condition"),
+                "at least one condition is required",
+            ));
+        }
+        if alert.cooldown_minutes < 0 {
+            issues.push(SetupValidationIssue::new(
+                path("cooldown_minutes"),
+                "cooldown must be non-negative",
+            ));
+        }
+        if !(0.0..=100.0).contains(&alert.rearm_policy.hysteresis_percent) {
+            issues.push(SetupValidationIssue::new(
+                path("rearm_policy.hysteresisPercent"),
+                "hysteresis must be between 0 and 100 percent",
+            ));
+        }
+    }
+
+    check_names(
+        SetupSection::DcaBots,
+        bundle.dca_bots.iter().map(|bot| bot.name.as_str()),
+        &mut issues,
+    );
+    for (index, bot) in bundle.dca_bots.iter().enumerate() {
+        let path = |field: &str| format!("dca_bots[{index}].{field}");
+        if bot.amount_per_execution <= 0.0 {
+            issues.push(SetupValidationIssue::new(
+                path("amount_per_execution"),
+                "amount per execution must be greater than zero",
+            ));
+        }
+        if bot.total_budget < bot.amount_per_execution {
+            issues.push(SetupValidationIssue::new(
+                path("total_budget"),
+                "total budget must be at least the amount per execution",
+            ));
+        }
+        if bot.slippage_bps < 0 {
+            issues.push(SetupValidationIssue::new(
+                path("slippage_bps"),
+                "slippage must be non-negative",
+            ));
+        }
+        if bot.max_price_impact_pct <= 0.0 {
+            issues.push(SetupValidationIssue::new(
+                path("max_price_impact_pct"),
+                "max price impact must be greater than zero",
+            ));
+        }
+        if let Err(e) = preview_next_execution(&bot.schedule_cron, Utc::now()) {
+            issues.push(SetupValidationIssue::new(path("schedule_cron"), e));
+        }
+    }
+
+    check_names(
+        SetupSection::CopyTrading,
+        bundle.copy_trading.iter().map(|copy| copy.name.as_str()),
+        &mut issues,
+    );
+    for (index, copy) in bundle.copy_trading.iter().enumerate() {
+        let path = |field: &str| format!("copy_trading[{index}].{field}");
+        if copy.source_wallet.trim().is_empty() {
+            issues.push(SetupValidationIssue::new(
+                path("source_wallet"),
+                "source wallet must not be empty",
+            ));
+        }
+        if !(0.0..=100.0).contains(&copy.allocation_percentage) {
+            issues.push(SetupValidationIssue::new(
+                path("allocation_percentage"),
+                "allocation must be between 0 and 100",
+            ));
+        }
+        if copy.multiplier <= 0.0 {
+            issues.push(SetupValidationIssue::new(
+                path("multiplier"),
+                "multiplier must be greater than zero",
+            ));
+        }
+    }
+
+    issues
+}
+
+pub fn setup_bundle_to_yaml(bundle: &SetupBundle) -> Result<String, String> {
+    serde_yaml::to_string(bundle).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SetupChangeKind {
+    Create,
+    Update,
+    Unchanged,
+    Conflict,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetupChange {
+    /// `<section>/<name>`; pass it back to `setup_apply_import` to select the change.
+    pub key: String,
+    pub section: SetupSection,
+    pub name: String,
+    pub kind: SetupChangeKind,
+    pub changed_fields: Vec<String>,
+    pub reason: Option<String>,
+}
+
+fn setup_key(section: SetupSection, name: &str) -> String {
+    format!("{}/{}", section.as_str(), name)
+}
+
+fn diff_section<T: Serialize>(
+    section: SetupSection,
+    current: &[T],
+    incoming: &[T],
+    name: impl Fn(&T) -> &str,
+    immutable_fields: &[&str],
+) -> Vec<SetupChange> {
+    // The first entry wins when the current state has duplicate names.
+    let mut existing: HashMap<&str, Value> = HashMap::new();
+    for item in current {
+        existing
+            .entry(name(item))
+            .or_insert_with(|| serde_json::to_value(item).unwrap_or(Value::Null));
+    }
+
+    incoming
+        .iter()
+        .map(|definition| {
+            let name = name(definition);
+            let incoming = serde_json::to_value(definition).unwrap_or(Value::Null);
+            let (kind, changed_fields) = match existing.get(name) {
+                None => (SetupChangeKind::Create, Vec::new()),
+                Some(existing) => {
+                    let fields: Vec<String> = diff_configs(Some(existing), Some(&incoming))
+                        .into_iter()
+                        .map(|change| change.path)
+                        .collect();
+                    if fields.is_empty() {
+                        (SetupChangeKind::Unchanged, fields)
+                    } else if fields
+                        .iter()
+                        .any(|f| immutable_fields.contains(&f.as_str()))
+                    {
+                        (SetupChangeKind::Conflict, fields)
+                    } else {
+                        (SetupChangeKind::Update, fields)
+                    }
+                }
+            };
+            let reason = (kind == SetupChangeKind::Conflict).then(|| {
+                format!(
+                    "{} cannot change on an existing entry; rename or delete it first",
+                    immutable_fields.join("/")
+                )
+            });
+            SetupChange {
+                key: setup_key(section, name),
+                section,
+                name: name.to_string(),
+                kind,
+                changed_fields,
+                reason,
+            }
+        })
+        .collect()
+}
+
+/// Dry-run diff of a bundle against what is configured now.
+pub fn diff_setup_bundle(current: &SetupBundle, incoming: &SetupBundle) -> Vec<SetupChange> {
+    let mut changes = diff_section(
+        SetupSection::Alerts,
+        &current.alerts,
+        &incoming.alerts,
+        |alert| alert.name.as_str(),
+        ALERT_IMMUTABLE_FIELDS,
+    );
+    changes.extend(diff_section(
+        SetupSection::DcaBots,
+        &current.dca_bots,
+        &incoming.dca_bots,
+        |bot| bot.name.as_str(),
+        &[],
+    ));
+    changes.extend(diff_section(
+        SetupSection::CopyTrading,
+        &current.copy_trading,
+        &incoming.copy_trading,
+        |copy| copy.name.as_str(),
+        &[],
+    ));
+    changes
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetupImportPreview {
+    pub issues: Vec<SetupValidationIssue>,
+    pub changes: Vec<SetupChange>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SetupImportReport {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub skipped: Vec<SetupChange>,
+    pub failed: Vec<SetupValidationIssue>,
+}
+
+async fn current_alerts(app: &AppHandle) -> Result<Vec<PriceAlert>, String> {
+    let manager = app
+        .try_state::<SharedAlertManager>()
+        .ok_or_else(|| "Alert manager not initialized".to_string())?;
+    let alerts = manager.read().await.list_alerts().await;
+    alerts.map_err(|e| e.to_string())
+}
+
+async fn current_setup(
+    app: &AppHandle,
+    wallet_address: Option<&str>,
+    sections: &[SetupSection],
+) -> Result<SetupBundle, String> {
+    let mut bundle = SetupBundle::default();
+
+    if sections.contains(&SetupSection::Alerts) {
+        bundle.alerts = current_alerts(app).await?.iter().map(Into::into).collect();
+    }
+    if let Some(wallet_address) = wallet_address {
+        if sections.contains(&SetupSection::DcaBots) {
+            let configs = dca_manager()?.list_dcas(wallet_address).await?;
+            bundle.dca_bots = configs.iter().map(Into::into).collect();
+        }
+        if sections.contains(&SetupSection::CopyTrading) {
+            let configs = copy_trade_manager()?
+                .list_copy_trades(wallet_address)
+                .await?;
+            bundle.copy_trading = configs.iter().map(Into::into).collect();
+        }
+    }
+
+    Ok(bundle)
+}
+
+fn bundle_sections(bundle: &SetupBundle) -> Vec<SetupSection> {
+    let mut sections = Vec::new();
+    if !bundle.alerts.is_empty() {
+        sections.push(SetupSection::Alerts);
+    }
+    if !bundle.dca_bots.is_empty() {
+        sections.push(SetupSection::DcaBots);
+    }
+    if !bundle.copy_trading.is_empty() {
+        sections.push(SetupSection::CopyTrading);
+    }
+    sections
+}
+
+fn require_wallet(bundle: &SetupBundle, wallet_address: Option<&str>) -> Result<(), String> {
+    let has_bots = !bundle.dca_bots.is_empty() || !bundle.copy_trading.is_empty();
+    if has_bots && wallet_address.is_none() {
+        return Err("A wallet address is required to import bot configs".into());
+    }
+    Ok(())
+}
+
+async fn apply_alert(
+    app: &AppHandle,
+    existing: Option<&PriceAlert>,
+    definition: &AlertDefinition,
+) -> Result<(), String> {
+    let manager = app
+        .try_state::<SharedAlertManager>()
+        .ok_or_else(|| "Alert manager not initialized".to_string())?;
+    let manager = manager.read().await;
+
+    let alert = match existing {
+        None => {
+            let alert = manager
+                .create_alert(CreateAlertRequest {
+                    name: definition.name.clone(),
+                    symbol: definition.symbol.clone(),
+                    mint: definition.mint.clone(),
+                    watchlist_id: None,
+                    compound_condition: definition.compound_condition.clone(),
+                    notification_channels: definition.notification_channels.clone(),
+                    cooldown_minutes: definition.cooldown_minutes,
+                    rearm_policy: definition.rearm_policy.clone(),
+                })
+                .await
+                .map_err(|e| e.to_string())?;
+            if definition.enabled {
+                alert
+            } else {
+                let disable = UpdateAlertRequest {
+                    name: None,
+                    compound_condition: None,
+                    notification_channels: None,
+                    cooldown_minutes: None,
+                    state: Some(AlertState::Disabled),
+                    rearm_policy: None,
+                    expected_version: None,
+                };
+                manager
+                    .update_alert(&alert.id, disable)
+                    .await
+                    .map_err(|e| e.to_string())?
+            }
+        }
+        Some(existing) => {
+            let was_enabled = existing.state != AlertState::Disabled;
+            let state = match (was_enabled, definition.enabled) {
+                (true, false) => Some(AlertState::Disabled),
+                (false, true) => Some(AlertState::Active),
+                _ => None,
+            };
+            manager
+                .update_alert(
+                    &existing.id,
+                    UpdateAlertRequest {
+                        name: Some(definition.name.clone()),
+                        compound_condition: Some(definition.compound_condition.clone()),
+                        notification_channels: Some(definition.notification_channels.clone()),
+                        cooldown_minutes: Some(definition.cooldown_minutes),
+                        state,
+                        rearm_policy: Some(definition.rearm_policy.clone()),
+                        expected_version: Some(existing.version),
+                    },
+                )
+                .await
+                .map_err(|e| e.to_string())?
+        }
+    };
+
+    record_config_change(
+        app,
+        ConfigEntity::alert(&alert.id),
+        Some(&alert),
+        LOCAL_ACTOR,
+    )
+    .await;
+    Ok(())
+}
+
+async fn apply_dca(
+    app: &AppHandle,
+    existing: Option<&DcaConfig>,
+    definition: &DcaDefinition,
+    wallet_address: &str,
+) -> Result<(), String> {
+    let manager = dca_manager()?;
+    let config = match existing {
+        None => {
+            let config = manager
+                .create_dca(definition.to_request(wallet_address))
+                .await?;
+            if !definition.enabled {
+                manager
+                    .pause_dca(&config.id, None)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            manager.get_dca(&config.id).await?
+        }
+        Some(existing) => {
+            let mut config = existing.clone();
+            definition.apply_to(&mut config);
+            manager.restore_dca(config).await?
+        }
+    };
+
+    record_config_change(
+        app,
+        ConfigEntity::dca_bot(&config.id),
+        Some(&config),
+        LOCAL_ACTOR,
+    )
+    .await;
+    Ok(())
+}
+
+async fn apply_copy_trade(
+    app: &AppHandle,
+    existing: Option<&CopyTradeConfig>,
+    definition: &CopyTradeDefinition,
+    wallet_address: &str,
+) -> Result<(), String> {
+    let manager = copy_trade_manager()?;
+    let mut request = definition.to_request(wallet_address);
+    request.source_wallet =
+        crate::wallet::sns::resolve_address_input(app, &request.source_wallet).await?;
+
+    match existing {
+        None => {
+            let config = manager.create_copy_trade(request).await?;
+            if !definition.enabled {
+                manager.pause_copy_trade(&config.id).await?;
+            }
+        }
+        Some(existing) => {
+            manager
+                .update_copy_trade(&existing.id, request, definition.enabled)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn setup_export_yaml(
+    app_handle: AppHandle,
+    wallet_address: Option<String>,
+    sections: Option<Vec<SetupSection>>,
+) -> Result<String, String> {
+    let sections = sections.unwrap_or_else(|| {
+        vec![
+            SetupSection::Alerts,
+            SetupSection::DcaBots,
+            SetupSection::CopyTrading,
+        ]
+    });
+    let bundle = current_setup(&app_handle, wallet_address.as_deref(), &sections).await?;
+    setup_bundle_to_yaml(&bundle)
+}
+
+#[tauri::command]
+pub async fn setup_preview_import(
+    app_handle: AppHandle,
+    yaml: String,
+    wallet_address: Option<String>,
+) -> Result<SetupImportPreview, String> {
+    let bundle = match parse_setup_bundle(&yaml) {
+        Ok(bundle) => bundle,
+        Err(issues) => {
+            return Ok(SetupImportPreview {
+                issues,
+                changes: Vec::new(),
+            })
+        }
+    };
+    require_wallet(&bundle, wallet_address.as_deref())?;
+
+    let current = current_setup(
+        &app_handle,
+        wallet_address.as_deref(),
+        &bundle_sections(&bundle),
+    )
+    .await?;
+    Ok(SetupImportPreview {
+        issues: Vec::new(),
+        changes: diff_setup_bundle(&current, &bundle),
+    })
+}
+
+/// Applies the creates and updates from a bundle. `selected` limits the import to
+/// the given change keys; without it every applicable change is applied.
+#[tauri::command]
+pub async fn setup_apply_import(
+    app_handle: AppHandle,
+    yaml: String,
+    wallet_address: Option<String>,
+    selected: Option<Vec<String>>,
+) -> Result<SetupImportReport, String> {
+    let bundle = parse_setup_bundle(&yaml).map_err(|issues| {
+        issues
+            .iter()
+            .map(|issue| format!("{}: {}", issue.path, issue.message))
+            .collect::<Vec<_>>()
+            .join("; ")
+    })?;
+    require_wallet(&bundle, wallet_address.as_deref())?;
+    let wallet = wallet_address.as_deref().unwrap_or_default();
+
+    let sections = bundle_sections(&bundle);
+    let current = current_setup(&app_handle, wallet_address.as_deref(), &sections).await?;
+    let changes = diff_setup_bundle(&current, &bundle);
+
+    let alerts = if sections.contains(&SetupSection::Alerts) {
+        current_alerts(&app_handle).await?
+    } else {
+        Vec::new()
+    };
+    let dca_configs = if sections.contains(&SetupSection::DcaBots) {
+        dca_manager()?.list_dcas(wallet).await?
+    } else {
+        Vec::new()
+    };
+    let copy_configs = if sections.contains(&SetupSection::CopyTrading) {
+        copy_trade_manager()?.list_copy_trades(wallet).await?
+    } else {
+        Vec::new()
+    };
+
+    let mut report = SetupImportReport::default();
+    for change in changes {
+        let is_selected = selected
+            .as_ref()
+            .map_or(true, |selected| selected.contains(&change.key));
+        let applicable = matches!(
+            change.kind,
+            SetupChangeKind::Create | SetupChangeKind::Update
+        );
+        if !is_selected || !applicable {
+            report.skipped.push(change);
+            continue;
+        }
+
+        let result = match change.section {
+            SetupSection::Alerts => {
+                let existing = alerts.iter().find(|alert| alert.name == change.name);
+                match bundle.alerts.iter().find(|alert| alert.name == change.name) {
+                    Some(definition) => apply_alert(&app_handle, existing, definition).await,
+                    None => continue,
+                }
+            }
+            SetupSection::DcaBots => {
+                let existing = dca_configs.iter().find(|config| config.name == change.name);
+                match bundle.dca_bots.iter().find(|bot| bot.name == change.name) {
+                    Some(definition) => apply_dca(&app_handle, existing, definition, wallet).await,
+                    None => continue,
+                }
+            }
+            SetupSection::CopyTrading => {
+                let existing = copy_configs
+                    .iter()
+                    .find(|config| config.name == change.name);
+                match bundle
+                    .copy_trading
+                    .iter()
+                    .find(|copy| copy.name == change.name)
+                {
+                    Some(definition) => {
+                        apply_copy_trade(&app_handle, existing, definition, wallet).await
+                    }
+                    None => continue,
+                }
+            }
+        };
+
+        match result {
+            Ok(()) if change.kind == SetupChangeKind::Create => report.created.push(change.key),
+            Ok(()) => report.updated.push(change.key),
+            Err(message) => report
+                .failed
+                .push(SetupValidationIssue::new(change.key, message)),
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerts::price_alerts::{AlertCondition, AlertConditionType, LogicalOperator};
+
+    const BUNDLE: &str = r#"
+schema_version: 1
+alerts:
+  - name: SOL breakout
+    symbol: SOL
+    mint: So11111111111111111111111111111111111111112
+    compound_condition:
+      conditions:
+        - conditionType: above
+          value: 250.0
+          timeframeMinutes: null
+      operator: and
+    notification_channels: [inapp]
+    cooldown_minutes: 30
+dca_bots:
+  - name: Weekly SOL
+    input_mint: EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v
+    output_mint: So11111111111111111111111111111111111111112
+    input_symbol: USDC
+    output_symbol: SOL
+    input_decimals: 6
+    output_decimals: 9
+    amount_per_execution: 50.0
+    total_budget: 1000.0
+    schedule_cron: "0 0 9 * * MON *"
+    slippage_bps: 50
+    priority_fee_micro_lamports: 1000
+    max_price_impact_pct: 1.0
+"#;
+
+    fn alert(name: &str, value: f64) -> AlertDefinition {
+        AlertDefinition {
+            name: name.to_string(),
+            symbol: "SOL".to_string(),
+            mint: "So11111111111111111111111111111111111111112".to_string(),
+            compound_condition: CompoundCondition {
+                conditions: vec![AlertCondition {
+                    condition_type: AlertConditionType::Above,
+                    value,
+                    timeframe_minutes: None,
+                }],
+                operator: LogicalOperator::And,
+            },
+            notification_channels: vec![NotificationChannel::InApp],
+            cooldown_minutes: 30,
+            rearm_policy: AlertRearmPolicy::default(),
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn bundles_round_trip_through_yaml() {
+        let bundle = parse_setup_bundle(BUNDLE).unwrap();
+        assert_eq!(bundle.alerts.len(), 1);
+        assert!(bundle.dca_bots[0].enabled);
+
+        let reparsed = parse_setup_bundle(&setup_bundle_to_yaml(&bundle).unwrap()).unwrap();
+        assert!(diff_setup_bundle(&bundle, &reparsed)
+            .iter()
+            .all(|change| change.kind == SetupChangeKind::Unchanged));
+    }
+
+    #[test]
+    fn validation_reports_every_issue_with_its_path() {
+        let invalid = BUNDLE
+            .replace("schema_version: 1", "schema_version: 9")
+            .replace("\"0 0 9 * * MON *\"", "not-a-cron")
+            .replace("total_budget: 1000.0", "total_budget: 10.0");
+
+        let paths: Vec<String> = parse_setup_bundle(&invalid)
+            .unwrap_err()
+            .into_iter()
+            .map(|issue| issue.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "schema_version",
+                "dca_bots[0].total_budget",
+                "dca_bots[0].schedule_cron",
+            ]
+        );
+        assert!(parse_setup_bundle("schema_version: 1\nunknown: true").is_err());
+    }
+
+    #[test]
+    fn dry_run_classifies_creates_updates_and_conflicts() {
+        let mut moved = alert("Moved", 100.0);
+        moved.mint = "other-mint".to_string();
+        let current = SetupBundle {
+            alerts: vec![
+                alert("Same", 100.0),
+                alert("Edited", 100.0),
+                alert("Moved", 100.0),
+            ],
+            ..SetupBundle::default()
+        };
+        let incoming = SetupBundle {
+            alerts: vec![
+                alert("Same", 100.0),
+                alert("Edited", 120.0),
+                moved,
+                alert("New", 80.0),
+            ],
+            ..SetupBundle::default()
+        };
+
+        let kinds: Vec<(String, SetupChangeKind)> = diff_setup_bundle(&current, &incoming)
+            .into_iter()
+            .map(|change| (change.key, change.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("alerts/Same".to_string(), SetupChangeKind::Unchanged),
+                ("alerts/Edited".to_string(), SetupChangeKind::Update),
+                ("alerts/Moved".to_string(), SetupChangeKind::Conflict),
+                ("alerts/New".to_string(), SetupChangeKind::Create),
+            ]
+        );
+    }
+}
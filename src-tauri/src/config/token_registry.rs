@@ -0,0 +1,397 @@
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tauri::{AppHandle, Manager};
+
+const TOKEN_REGISTRY_FILE: &str = "token_registry.json";
+const TOKEN_REGISTRY_EVENT: &str = "token_registry_changed";
+const MAX_SYMBOL_LEN: usize = 16;
+const MAX_NAME_LEN: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenEntryKind {
+    /// A token no provider knows; every field comes from the registry.
+    Custom,
+    /// Corrects provider metadata field by field.
+    Override,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenRegistryEntry {
+    pub mint: String,
+    pub kind: TokenEntryKind,
+    #[serde(default)]
+    pub symbol: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    /// Local image file or URL.
+    #[serde(default)]
+    pub logo_path: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterTokenRequest {
+    pub mint: String,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+    #[serde(default)]
+    pub logo_path: Option<String>,
+}
+
+/// Fields left empty keep the provider's value.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenOverrideRequest {
+    pub mint: String,
+    #[serde(default)]
+    pub symbol: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    #[serde(default)]
+    pub logo_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenRegistry {
+    #[serde(default)]
+    pub entries: HashMap<String, TokenRegistryEntry>,
+}
+
+impl TokenRegistry {
+    pub fn get(&self, mint: &str) -> Option<&TokenRegistryEntry> {
+        self.entries.get(mint)
+    }
+
+    pub fn register(
+        &mut self,
+        request: RegisterTokenRequest,
+    ) -> Result<TokenRegistryEntry, String> {
+        let entry = TokenRegistryEntry {
+            mint: validate_mint(&request.mint)?,
+            kind: TokenEntryKind::Custom,
+            symbol: Some(validate_label("Symbol", &request.symbol, MAX_SYMBOL_LEN)?),
+            name: Some(validate_label("Name", &request.name, MAX_NAME_LEN)?),
+            decimals: Some(request.decimals),
+            logo_path: normalize_logo(request.logo_path)?,
+            updated_at: Utc::now(),
+        };
+        self.entries.insert(entry.mint.clone(), entry.clone());
+        Ok(entry)
+    }
+
+    /// Overrides on a custom token edit it in place; it stays custom.
+    pub fn set_override(
+        &mut self,
+        request: TokenOverrideRequest,
+    ) -> Result<TokenRegistryEntry, String> {
+        let mint = validate_mint(&request.mint)?;
+        let symbol = request
+            .symbol
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| validate_label("Symbol", &s, MAX_SYMBOL_LEN))
+            .transpose()?;
+        let name = request
+            .name
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| validate_label("Name", &s, MAX_NAME_LEN))
+            .transpose()?;
+        let logo_path = normalize_logo(request.logo_path)?;
+
+        let entry = match self.entries.get(&mint) {
+            Some(existing) if existing.kind == TokenEntryKind::Custom => TokenRegistryEntry {
+                symbol: symbol.or_else(|| existing.symbol.clone()),
+                name: name.or_else(|| existing.name.clone()),
+                decimals: request.decimals.or(existing.decimals),
+                logo_path: logo_path.or_else(|| existing.logo_path.clone()),
+                updated_at: Utc::now(),
+                ..existing.clone()
+            },
+            _ => {
+                if symbol.is_none()
+                    && name.is_none()
+                    && request.decimals.is_none()
+                    && logo_path.is_none()
+                {
+                    return Err("An override needs at least one field".to_string());
+                }
+                TokenRegistryEntry {
+                    mint: mint.clone(),
+                    kind: TokenEntryKind::Override,
+                    symbol,
+                    name,
+                    decimals: request.decimals,
+                    logo_path,
+                    updated_at: Utc::now(),
+                }
+            }
+        };
+        self.entries.insert(mint, entry.clone());
+        Ok(entry)
+    }
+
+    pub fn remove(&mut self, mint: &str) -> bool {
+        self.entries.remove(mint.trim()).is_some()
+    }
+}
+
+impl TokenRegistryEntry {
+    /// Replaces provider values with whatever the registry sets.
+    pub fn apply(
+        &self,
+        symbol: &mut String,
+        name: Option<&mut String>,
+        decimals: Option<&mut u8>,
+        logo_uri: Option<&mut Option<String>>,
+    ) {
+        if let Some(value) = &self.symbol {
+            *symbol = value.clone();
+        }
+        if let (Some(target), Some(value)) = (name, &self.name) {
+            *target = value.clone();
+        }
+        if let (Some(target), Some(value)) = (decimals, self.decimals) {
+            *target = value;
+        }
+        if let (Some(target), Some(value)) = (logo_uri, &self.logo_path) {
+            *target = Some(value.clone());
+        }
+    }
+}
+
+fn validate_mint(mint: &str) -> Result<String, String> {
+    let mint = mint.trim();
+    Pubkey::from_str(mint).map_err(|_| format!("Invalid mint address: {mint}"))?;
+    Ok(mint.to_string())
+}
+
+fn validate_label(field: &str, value: &str, max_len: usize) -> Result<String, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(format!("{field} is required"));
+    }
+    if value.chars().count() > max_len {
+        return Err(format!("{field} must be at most {max_len} characters"));
+    }
+    Ok(value.to_string())
+}
+
+fn normalize_logo(logo_path: Option<String>) -> Result<Option<String>, String> {
+    let Some(logo) = logo_path
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+    else {
+        return Ok(None);
+    };
+    let is_url = ["https://", "http://", "data:image/"]
+        .iter()
+        .any(|prefix| logo.starts_with(prefix));
+    if !is_url && !Path::new(&logo).is_file() {
+        return Err(format!("Logo file not found: {logo}"));
+    }
+    Ok(Some(logo))
+}
+
+lazy_static::lazy_static! {
+    static ref TOKEN_REGISTRY: RwLock<TokenRegistry> = RwLock::new(TokenRegistry::default());
+}
+
+/// Local metadata for a mint, consulted before any provider lookup.
+pub fn registered_token(mint: &str) -> Option<TokenRegistryEntry> {
+    TOKEN_REGISTRY.read().get(mint).cloned()
+}
+
+/// Applies the registry entry for `mint`, if any, over provider metadata.
+pub fn apply_token_overrides(
+    mint: &str,
+    symbol: &mut String,
+    name: Option<&mut String>,
+    decimals: Option<&mut u8>,
+    logo_uri: Option<&mut Option<String>>,
+) {
+    if let Some(entry) = TOKEN_REGISTRY.read().get(mint) {
+        entry.apply(symbol, name, decimals, logo_uri);
+    }
+}
+
+/// Tokens registered by hand, for searches that providers can't answer.
+pub fn custom_tokens() -> Vec<TokenRegistryEntry> {
+    TOKEN_REGISTRY
+        .read()
+        .entries
+        .values()
+        .filter(|entry| entry.kind == TokenEntryKind::Custom)
+        .cloned()
+        .collect()
+}
+
+fn registry_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut path = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+    if !path.exists() {
+        fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+    }
+    path.push(TOKEN_REGISTRY_FILE);
+    Ok(path)
+}
+
+pub fn load_token_registry(app: &AppHandle) -> Result<(), String> {
+    let path = registry_path(app)?;
+    let registry = if path.exists() {
+        let data = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        serde_json::from_str::<TokenRegistry>(&data).map_err(|e| e.to_string())?
+    } else {
+        TokenRegistry::default()
+    };
+
+    *TOKEN_REGISTRY.write() = registry;
+    Ok(())
+}
+
+fn apply<T>(
+    app: &AppHandle,
+    update: impl FnOnce(&mut TokenRegistry) -> Result<T, String>,
+) -> Result<T, String> {
+    let mut registry = TOKEN_REGISTRY.read().clone();
+    let result = update(&mut registry)?;
+    let json = serde_json::to_string_pretty(&registry).map_err(|e| e.to_string())?;
+    fs::write(registry_path(app)?, json).map_err(|e| e.to_string())?;
+    *TOKEN_REGISTRY.write() = registry;
+
+    if let Err(err) = app.emit_all(TOKEN_REGISTRY_EVENT, list_entries()) {
+        tracing::warn!(error = %err, "failed to emit token registry change");
+    }
+    Ok(result)
+}
+
+fn list_entries() -> Vec<TokenRegistryEntry> {
+    let mut entries: Vec<_> = TOKEN_REGISTRY.read().entries.values().cloned().collect();
+    entries.sort_by(|a, b| a.symbol.cmp(&b.symbol).then_with(|| a.mint.cmp(&b.mint)));
+    entries
+}
+
+#[tauri::command]
+pub async fn list_token_registry() -> Result<Vec<TokenRegistryEntry>, String> {
+    Ok(list_entries())
+}
+
+#[tauri::command]
+pub async fn register_custom_token(
+    request: RegisterTokenRequest,
+    app_handle: AppHandle,
+) -> Result<TokenRegistryEntry, String> {
+    apply(&app_handle, |registry| registry.register(request))
+}
+
+#[tauri::command]
+pub async fn set_token_metadata_override(
+    request: TokenOverrideRequest,
+    app_handle: AppHandle,
+) -> Result<TokenRegistryEntry, String> {
+    apply(&app_handle, |registry| registry.set_override(request))
+}
+
+#[tauri::command]
+pub async fn remove_token_registry_entry(
+    mint: String,
+    app_handle: AppHandle,
+) -> Result<bool, String> {
+    apply(&app_handle, |registry| Ok(registry.remove(&mint)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    #[test]
+    fn overrides_replace_only_the_fields_they_set() {
+        let mut registry = TokenRegistry::default();
+        let entry = registry
+            .set_override(TokenOverrideRequest {
+                mint: MINT.to_string(),
+                symbol: Some("USDC".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let mut symbol = "UNKNOWN".to_string();
+        let mut name = "Unknown Token".to_string();
+        let mut decimals = 9;
+        entry.apply(&mut symbol, Some(&mut name), Some(&mut decimals), None);
+        assert_eq!(symbol, "USDC");
+        assert_eq!(name, "Unknown Token");
+        assert_eq!(decimals, 9);
+    }
+
+    #[test]
+    fn custom_tokens_stay_custom_when_overridden() {
+        let mut registry = TokenRegistry::default();
+        registry
+            .register(RegisterTokenRequest {
+                mint: MINT.to_string(),
+                symbol: "OBS".to_string(),
+                name: "Obscure".to_string(),
+                decimals: 6,
+                logo_path: None,
+            })
+            .unwrap();
+        let entry = registry
+            .set_override(TokenOverrideRequest {
+                mint: MINT.to_string(),
+                name: Some("Obscure Token".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(entry.kind, TokenEntryKind::Custom);
+        assert_eq!(entry.symbol.as_deref(), Some("OBS"));
+        assert_eq!(entry.name.as_deref(), Some("Obscure Token"));
+        assert_eq!(entry.decimals, Some(6));
+    }
+
+    #[test]
+    fn rejects_invalid_entries() {
+        let mut registry = TokenRegistry::default();
+        let custom = |mint: &str, symbol: &str| RegisterTokenRequest {
+            mint: mint.to_string(),
+            symbol: symbol.to_string(),
+            name: "Token".to_string(),
+            decimals: 9,
+            logo_path: None,
+        };
+        assert!(registry.register(custom("not-a-mint", "TKN")).is_err());
+        assert!(registry.register(custom(MINT, "  ")).is_err());
+        assert!(registry
+            .set_override(TokenOverrideRequest {
+                mint: MINT.to_string(),
+                ..Default::default()
+            })
+            .is_err());
+        assert!(registry
+            .set_override(TokenOverrideRequest {
+                mint: MINT.to_string(),
+                logo_path: Some("/nonexistent/logo.png".to_string()),
+                ..Default::default()
+            })
+            .is_err());
+        assert!(registry.entries.is_empty());
+    }
+}
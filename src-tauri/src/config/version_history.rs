@@ -0,0 +1,568 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use crate::alerts::{PriceAlert, SharedAlertManager};
+use crate::bots::DcaConfig;
+use crate::portfolio::{RebalanceProfile, SharedRebalancerState};
+
+const HISTORY_DB_FILE: &str = "config_history.db";
+const MAX_VERSIONS_PER_ENTITY: i64 = 100;
+
+pub const LOCAL_ACTOR: &str = "local_user";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigHistoryError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("version {version} not found for {entity}")]
+    VersionNotFound { entity: String, version: i64 },
+    #[error("rollback failed: {0}")]
+    Rollback(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigEntityKind {
+    Alert,
+    DcaBot,
+    RebalanceProfile,
+}
+
+impl ConfigEntityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfigEntityKind::Alert => "alert",
+            ConfigEntityKind::DcaBot => "dca_bot",
+            ConfigEntityKind::RebalanceProfile => "rebalance_profile",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "alert" => Some(ConfigEntityKind::Alert),
+            "dca_bot" => Some(ConfigEntityKind::DcaBot),
+            "rebalance_profile" => Some(ConfigEntityKind::RebalanceProfile),
+            _ => None,
+        }
+    }
+
+    /// Runtime bookkeeping that changes without the user touching the config; it is
+    /// left out of diffs and never overwritten by a rollback.
+    fn volatile_fields(&self) -> &'static [&'static str] {
+        match self {
+            ConfigEntityKind::Alert => &["lastTriggeredAt", "cooldownUntil", "createdAt", "updatedAt"],
+            ConfigEntityKind::DcaBot => &[
+                "spent_amount",
+                "created_at",
+                "updated_at",
+                "last_execution",
+                "next_execution",
+            ],
+            ConfigEntityKind::RebalanceProfile => &[],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigEntity {
+    pub kind: ConfigEntityKind,
+    pub id: String,
+}
+
+impl ConfigEntity {
+    pub fn alert(id: &str) -> Self {
+        Self { kind: ConfigEntityKind::Alert, id: id.to_string() }
+    }
+
+    pub fn dca_bot(id: &str) -> Self {
+        Self { kind: ConfigEntityKind::DcaBot, id: id.to_string() }
+    }
+
+    pub fn rebalance_profile(id: &str) -> Self {
+        Self { kind: ConfigEntityKind::RebalanceProfile, id: id.to_string() }
+    }
+}
+
+impl std::fmt::Display for ConfigEntity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.kind.as_str(), self.id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigChangeKind {
+    Created,
+    Updated,
+    Deleted,
+    RolledBack,
+}
+
+impl ConfigChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfigChangeKind::Created => "created",
+            ConfigChangeKind::Updated => "updated",
+            ConfigChangeKind::Deleted => "deleted",
+            ConfigChangeKind::RolledBack => "rolled_back",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "created" => Some(ConfigChangeKind::Created),
+            "updated" => Some(ConfigChangeKind::Updated),
+            "deleted" => Some(ConfigChangeKind::Deleted),
+            "rolled_back" => Some(ConfigChangeKind::RolledBack),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigFieldChange {
+    /// Dotted path to the field, e.g. `compoundCondition.logic`.
+    pub path: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigVersion {
+    pub entity: ConfigEntity,
+    pub version: i64,
+    pub change_kind: ConfigChangeKind,
+    pub actor: String,
+    pub changed_at: DateTime<Utc>,
+    pub diff: Vec<ConfigFieldChange>,
+    /// Full config as of this version; `None` once the entity was deleted.
+    pub snapshot: Option<Value>,
+    pub rolled_back_from: Option<i64>,
+}
+
+fn strip_volatile(kind: ConfigEntityKind, value: &Value) -> Value {
+    let mut value = value.clone();
+    if let Value::Object(map) = &mut value {
+        for field in kind.volatile_fields() {
+            map.remove(*field);
+        }
+    }
+    value
+}
+
+fn diff_into(path: &str, before: Option<&Value>, after: Option<&Value>, out: &mut Vec<ConfigFieldChange>) {
+    match (before, after) {
+        (Some(Value::Object(a)), Some(Value::Object(b))) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                diff_into(&child, a.get(key), b.get(key), out);
+            }
+        }
+        (a, b) if a == b => {}
+        (a, b) => out.push(ConfigFieldChange {
+            path: path.to_string(),
+            before: a.cloned(),
+            after: b.cloned(),
+        }),
+    }
+}
+
+/// Field-level diff between two config snapshots. Objects are walked recursively;
+/// arrays and scalars are compared as a whole.
+pub fn diff_configs(before: Option<&Value>, after: Option<&Value>) -> Vec<ConfigFieldChange> {
+    let empty = Value::Object(Map::new());
+    let mut changes = Vec::new();
+    diff_into(
+        "",
+        Some(before.unwrap_or(&empty)),
+        Some(after.unwrap_or(&empty)),
+        &mut changes,
+    );
+    changes
+}
+
+#[derive(Clone)]
+pub struct ConfigHistoryStore {
+    pool: Pool<Sqlite>,
+}
+
+pub type SharedConfigHistory = Arc<RwLock<ConfigHistoryStore>>;
+
+impl ConfigHistoryStore {
+    pub async fn new(app: &AppHandle) -> Result<Self, ConfigHistoryError> {
+        let db_path = history_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let store = Self { pool };
+        store.initialize().await?;
+        Ok(store)
+    }
+
+    async fn initialize(&self) -> Result<(), ConfigHistoryError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS config_versions (
+                entity_kind TEXT NOT NULL,
+                entity_id TEXT NOT NULL,
+                version INTEGER NOT NULL,
+                change_kind TEXT NOT NULL,
+                actor TEXT NOT NULL,
+                changed_at TEXT NOT NULL,
+                diff TEXT NOT NULL,
+                snapshot TEXT,
+                rolled_back_from INTEGER,
+                PRIMARY KEY (entity_kind, entity_id, version)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records a new version if the config actually changed since the last one.
+    /// `after` is `None` when the entity was deleted.
+    pub async fn record(
+        &self,
+        entity: &ConfigEntity,
+        after: Option<Value>,
+        actor: &str,
+        rolled_back_from: Option<i64>,
+    ) -> Result<Option<ConfigVersion>, ConfigHistoryError> {
+        let latest = self.latest(entity).await?;
+        let before = latest.as_ref().and_then(|v| v.snapshot.clone());
+
+        let diff = diff_configs(
+            before.as_ref().map(|v| strip_volatile(entity.kind, v)).as_ref(),
+            after.as_ref().map(|v| strip_volatile(entity.kind, v)).as_ref(),
+        );
+        if diff.is_empty() && rolled_back_from.is_none() {
+            return Ok(None);
+        }
+
+        let change_kind = match (&before, &after, rolled_back_from) {
+            (_, _, Some(_)) => ConfigChangeKind::RolledBack,
+            (_, None, _) => ConfigChangeKind::Deleted,
+            (None, Some(_), _) => ConfigChangeKind::Created,
+            (Some(_), Some(_), _) => ConfigChangeKind::Updated,
+        };
+
+        let version = ConfigVersion {
+            entity: entity.clone(),
+            version: latest.map(|v| v.version).unwrap_or(0) + 1,
+            change_kind,
+            actor: actor.to_string(),
+            changed_at: Utc::now(),
+            diff,
+            snapshot: after,
+            rolled_back_from,
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO config_versions (
+                entity_kind, entity_id, version, change_kind, actor,
+                changed_at, diff, snapshot, rolled_back_from
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "#,
+        )
+        .bind(entity.kind.as_str())
+        .bind(&entity.id)
+        .bind(version.version)
+        .bind(version.change_kind.as_str())
+        .bind(&version.actor)
+        .bind(version.changed_at.to_rfc3339())
+        .bind(serde_json::to_string(&version.diff)?)
+        .bind(version.snapshot.as_ref().map(serde_json::to_string).transpose()?)
+        .bind(version.rolled_back_from)
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "DELETE FROM config_versions WHERE entity_kind = ?1 AND entity_id = ?2 AND version <= ?3",
+        )
+        .bind(entity.kind.as_str())
+        .bind(&entity.id)
+        .bind(version.version - MAX_VERSIONS_PER_ENTITY)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(Some(version))
+    }
+
+    pub async fn history(&self, entity: &ConfigEntity) -> Result<Vec<ConfigVersion>, ConfigHistoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM config_versions
+            WHERE entity_kind = ?1 AND entity_id = ?2
+            ORDER BY version DESC
+            "#,
+        )
+        .bind(entity.kind.as_str())
+        .bind(&entity.id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_version).collect()
+    }
+
+    pub async fn latest(&self, entity: &ConfigEntity) -> Result<Option<ConfigVersion>, ConfigHistoryError> {
+        let row = sqlx::query(
+            r#"
+            SELECT * FROM config_versions
+            WHERE entity_kind = ?1 AND entity_id = ?2
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(entity.kind.as_str())
+        .bind(&entity.id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_version).transpose()
+    }
+
+    pub async fn get_version(
+        &self,
+        entity: &ConfigEntity,
+        version: i64,
+    ) -> Result<ConfigVersion, ConfigHistoryError> {
+        let row = sqlx::query(
+            "SELECT * FROM config_versions WHERE entity_kind = ?1 AND entity_id = ?2 AND version = ?3",
+        )
+        .bind(entity.kind.as_str())
+        .bind(&entity.id)
+        .bind(version)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| ConfigHistoryError::VersionNotFound {
+            entity: entity.to_string(),
+            version,
+        })?;
+
+        row_to_version(&row)
+    }
+}
+
+fn row_to_version(row: &SqliteRow) -> Result<ConfigVersion, ConfigHistoryError> {
+    let kind: String = row.try_get("entity_kind")?;
+    let change_kind: String = row.try_get("change_kind")?;
+    let changed_at: String = row.try_get("changed_at")?;
+    let diff: String = row.try_get("diff")?;
+    let snapshot: Option<String> = row.try_get("snapshot")?;
+
+    Ok(ConfigVersion {
+        entity: ConfigEntity {
+            kind: ConfigEntityKind::from_str(&kind)
+                .ok_or_else(|| ConfigHistoryError::Internal(format!("Invalid entity kind: {}", kind)))?,
+            id: row.try_get("entity_id")?,
+        },
+        version: row.try_get("version")?,
+        change_kind: ConfigChangeKind::from_str(&change_kind).ok_or_else(|| {
+            ConfigHistoryError::Internal(format!("Invalid change kind: {}", change_kind))
+        })?,
+        actor: row.try_get("actor")?,
+        changed_at: DateTime::parse_from_rfc3339(&changed_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| ConfigHistoryError::Internal(e.to_string()))?,
+        diff: serde_json::from_str(&diff)?,
+        snapshot: snapshot.as_deref().map(serde_json::from_str).transpose()?,
+        rolled_back_from: row.try_get("rolled_back_from")?,
+    })
+}
+
+fn history_db_path(app: &AppHandle) -> Result<PathBuf, ConfigHistoryError> {
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| ConfigHistoryError::Internal("Unable to resolve app data directory".to_string()))?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(HISTORY_DB_FILE))
+}
+
+/// Best-effort hook for managers: history failures are logged, never surfaced, so a
+/// broken history database can't block saving an alert or bot.
+pub async fn record_config_change<T: Serialize>(
+    app: &AppHandle,
+    entity: ConfigEntity,
+    after: Option<&T>,
+    actor: &str,
+) {
+    let Some(history) = app.try_state::<SharedConfigHistory>() else {
+        return;
+    };
+
+    let result = match after.map(serde_json::to_value).transpose() {
+        Ok(snapshot) => history.read().await.record(&entity, snapshot, actor, None).await,
+        Err(err) => Err(err.into()),
+    };
+    if let Err(err) = result {
+        tracing::warn!(error = %err, entity = %entity, "failed to record config version");
+    }
+}
+
+/// Puts the entity back to the given version, keeping runtime fields (spend, cooldowns,
+/// schedule position) from the live config where one exists.
+async fn apply_rollback(
+    app: &AppHandle,
+    entity: &ConfigEntity,
+    snapshot: Option<&Value>,
+) -> Result<Option<Value>, ConfigHistoryError> {
+    let rollback_err = |e: String| ConfigHistoryError::Rollback(e);
+
+    match entity.kind {
+        ConfigEntityKind::Alert => {
+            let manager = app
+                .try_state::<SharedAlertManager>()
+                .ok_or_else(|| rollback_err("alert manager unavailable".to_string()))?;
+            let manager = manager.read().await;
+            match snapshot {
+                Some(value) => {
+                    let alert: PriceAlert = serde_json::from_value(value.clone())?;
+                    let restored = manager
+                        .restore_alert(alert)
+                        .await
+                        .map_err(|e| rollback_err(e.to_string()))?;
+                    Ok(Some(serde_json::to_value(restored)?))
+                }
+                None => {
+                    manager
+                        .delete_alert(&entity.id)
+                        .await
+                        .map_err(|e| rollback_err(e.to_string()))?;
+                    Ok(None)
+                }
+            }
+        }
+        ConfigEntityKind::DcaBot => {
+            let manager = crate::bots::dca_manager().map_err(rollback_err)?;
+            match snapshot {
+                Some(value) => {
+                    let config: DcaConfig = serde_json::from_value(value.clone())?;
+                    let restored = manager.restore_dca(config).await.map_err(rollback_err)?;
+                    Ok(Some(serde_json::to_value(restored)?))
+                }
+                None => {
+                    manager.delete_dca(&entity.id).await.map_err(rollback_err)?;
+                    Ok(None)
+                }
+            }
+        }
+        ConfigEntityKind::RebalanceProfile => {
+            let state = app
+                .try_state::<SharedRebalancerState>()
+                .ok_or_else(|| rollback_err("rebalancer unavailable".to_string()))?;
+            let mut guard = state
+                .lock()
+                .map_err(|_| rollback_err("Rebalancer unavailable".to_string()))?;
+            match snapshot {
+                Some(value) => {
+                    let profile: RebalanceProfile = serde_json::from_value(value.clone())?;
+                    Ok(Some(serde_json::to_value(guard.restore_profile(profile))?))
+                }
+                None => {
+                    guard.remove_profile(&entity.id);
+                    Ok(None)
+                }
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_config_history(
+    entity: ConfigEntity,
+    history: State<'_, SharedConfigHistory>,
+) -> Result<Vec<ConfigVersion>, String> {
+    let history = history.read().await;
+    history.history(&entity).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn rollback_config(
+    entity: ConfigEntity,
+    version: i64,
+    app_handle: AppHandle,
+    history: State<'_, SharedConfigHistory>,
+) -> Result<ConfigVersion, String> {
+    let history = history.read().await;
+    let target = history
+        .get_version(&entity, version)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let restored = apply_rollback(&app_handle, &entity, target.snapshot.as_ref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    history
+        .record(&entity, restored, LOCAL_ACTOR, Some(version))
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "rollback was not recorded".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn diff_reports_nested_changes_only() {
+        let before = json!({"name": "SOL breakout", "cooldownMinutes": 30, "condition": {"logic": "and", "threshold": 150}});
+        let after = json!({"name": "SOL breakout", "cooldownMinutes": 60, "condition": {"logic": "and", "threshold": 160}});
+
+        let diff = diff_configs(Some(&before), Some(&after));
+        let paths: Vec<&str> = diff.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["condition.threshold", "cooldownMinutes"]);
+        assert_eq!(diff[1].before, Some(json!(30)));
+        assert_eq!(diff[1].after, Some(json!(60)));
+    }
+
+    #[test]
+    fn diff_handles_creation_and_deletion() {
+        let config = json!({"name": "Daily JUP", "is_active": true});
+
+        let created = diff_configs(None, Some(&config));
+        assert_eq!(created.len(), 2);
+        assert!(created.iter().all(|c| c.before.is_none()));
+
+        let deleted = diff_configs(Some(&config), None);
+        assert!(deleted.iter().all(|c| c.after.is_none()));
+    }
+
+    #[test]
+    fn runtime_fields_are_ignored() {
+        let before = json!({"name": "Daily JUP", "spent_amount": 10.0, "next_execution": "a"});
+        let after = json!({"name": "Daily JUP", "spent_amount": 20.0, "next_execution": "b"});
+
+        let diff = diff_configs(
+            Some(&strip_volatile(ConfigEntityKind::DcaBot, &before)),
+            Some(&strip_volatile(ConfigEntityKind::DcaBot, &after)),
+        );
+        assert!(diff.is_empty());
+    }
+}
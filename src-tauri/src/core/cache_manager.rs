@@ -774,6 +774,45 @@ impl CacheManager {
         }
     }
 
+    /// Drops least recently accessed entries from memory until the cache fits
+    /// in `target_bytes`. Disk copies are kept, so a later `get` rehydrates
+    /// them instead of refetching. Returns the number of entries dropped.
+    pub async fn shrink_memory_to(&self, target_bytes: usize) -> usize {
+        let mut cache = self.cache.write().await;
+        let mut stats = self.stats.write().await;
+        if stats.total_size_bytes <= target_bytes {
+            return 0;
+        }
+
+        let mut by_access: Vec<(String, u64)> = cache
+            .iter()
+            .map(|(key, entry)| (key.clone(), entry.last_accessed_ms))
+            .collect();
+        by_access.sort_by_key(|(_, last_accessed_ms)| *last_accessed_ms);
+
+        let mut removed = 0usize;
+        for (key, _) in by_access {
+            if stats.total_size_bytes <= target_bytes {
+                break;
+            }
+            let Some(entry) = cache.remove(&key) else {
+                continue;
+            };
+            removed += 1;
+            stats.total_evictions = stats.total_evictions.saturating_add(1);
+            stats.total_size_bytes = stats.total_size_bytes.saturating_sub(entry.size_bytes);
+
+            let type_key = format!("{:?}", entry.cache_type);
+            if let Some(type_stats) = stats.per_type_stats.get_mut(&type_key) {
+                type_stats.entries = type_stats.entries.saturating_sub(1);
+                type_stats.size_bytes = type_stats.size_bytes.saturating_sub(entry.size_bytes);
+            }
+        }
+        stats.total_entries = cache.len();
+
+        removed
+    }
+
     pub async fn purge_keys_with_prefix(&self, prefix: &str) -> usize {
         let mut removed = 0usize;
         let mut cache = self.cache.write().await;
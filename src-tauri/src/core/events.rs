@@ -0,0 +1,302 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::warn;
+
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+const RECENT_EVENT_CAPACITY: usize = 500;
+const DEFAULT_INSPECTION_LIMIT: usize = 100;
+
+/// Domain events shared between subsystems. Publishers don't know who listens,
+/// so adding a reaction (e.g. journal on fill) never touches the publisher.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DomainEvent {
+    #[serde(rename_all = "camelCase")]
+    OrderFilled {
+        order_id: String,
+        wallet_address: String,
+        side: String,
+        input_mint: String,
+        output_mint: String,
+        amount: f64,
+        fill_price: f64,
+        tx_signature: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    CoinDetected {
+        mint: String,
+        symbol: String,
+        liquidity: f64,
+        safety_score: f64,
+    },
+    #[serde(rename_all = "camelCase")]
+    RiskScoreChanged {
+        token_address: String,
+        previous_score: Option<f64>,
+        score: f64,
+        previous_level: Option<String>,
+        risk_level: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    WhaleActivity {
+        wallet_address: String,
+        wallet_label: Option<String>,
+        action: String,
+        token: Option<String>,
+        amount_usd: Option<f64>,
+        tx_signature: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    AlertTriggered {
+        alert_id: String,
+        alert_name: String,
+        mint: String,
+        symbol: String,
+        price: f64,
+        triggered_at: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum DomainEventKind {
+    OrderFilled,
+    CoinDetected,
+    RiskScoreChanged,
+    WhaleActivity,
+    AlertTriggered,
+}
+
+impl DomainEvent {
+    pub fn kind(&self) -> DomainEventKind {
+        match self {
+            DomainEvent::OrderFilled { .. } => DomainEventKind::OrderFilled,
+            DomainEvent::CoinDetected { .. } => DomainEventKind::CoinDetected,
+            DomainEvent::RiskScoreChanged { .. } => DomainEventKind::RiskScoreChanged,
+            DomainEvent::WhaleActivity { .. } => DomainEventKind::WhaleActivity,
+            DomainEvent::AlertTriggered { .. } => DomainEventKind::AlertTriggered,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainEventEnvelope {
+    pub sequence: u64,
+    pub source: String,
+    pub published_at: DateTime<Utc>,
+    pub event: DomainEvent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainEventBusStats {
+    pub published_total: u64,
+    pub subscriber_count: usize,
+    pub published_by_kind: HashMap<DomainEventKind, u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainEventInspection {
+    pub stats: DomainEventBusStats,
+    pub events: Vec<DomainEventEnvelope>,
+}
+
+/// Receives only the kinds it was created for. Lagging subscribers skip the
+/// events they missed instead of failing.
+pub struct DomainEventSubscription {
+    receiver: broadcast::Receiver<DomainEventEnvelope>,
+    kinds: Vec<DomainEventKind>,
+}
+
+impl DomainEventSubscription {
+    pub async fn recv(&mut self) -> Option<DomainEventEnvelope> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(envelope) if self.accepts(&envelope) => return Some(envelope),
+                Ok(_) => continue,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("domain event subscriber lagged, skipped {} events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    fn accepts(&self, envelope: &DomainEventEnvelope) -> bool {
+        self.kinds.is_empty() || self.kinds.contains(&envelope.event.kind())
+    }
+}
+
+pub struct DomainEventBus {
+    sender: broadcast::Sender<DomainEventEnvelope>,
+    sequence: AtomicU64,
+    recent: Mutex<VecDeque<DomainEventEnvelope>>,
+    counts: Mutex<HashMap<DomainEventKind, u64>>,
+}
+
+impl Default for DomainEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DomainEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self {
+            sender,
+            sequence: AtomicU64::new(0),
+            recent: Mutex::new(VecDeque::with_capacity(RECENT_EVENT_CAPACITY)),
+            counts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Publishing never blocks and succeeds without subscribers; the event is
+    /// still kept for inspection.
+    pub fn publish(&self, source: &str, event: DomainEvent) -> DomainEventEnvelope {
+        let envelope = DomainEventEnvelope {
+            sequence: self.sequence.fetch_add(1, Ordering::Relaxed) + 1,
+            source: source.to_string(),
+            published_at: Utc::now(),
+            event,
+        };
+
+        *self.counts.lock().entry(envelope.event.kind()).or_insert(0) += 1;
+        {
+            let mut recent = self.recent.lock();
+            if recent.len() == RECENT_EVENT_CAPACITY {
+                recent.pop_front();
+            }
+            recent.push_back(envelope.clone());
+        }
+
+        let _ = self.sender.send(envelope.clone());
+        envelope
+    }
+
+    /// Subscribes to the given kinds; an empty list receives everything.
+    pub fn subscribe(&self, kinds: &[DomainEventKind]) -> DomainEventSubscription {
+        DomainEventSubscription {
+            receiver: self.sender.subscribe(),
+            kinds: kinds.to_vec(),
+        }
+    }
+
+    /// Most recent events first, optionally narrowed to one kind.
+    pub fn recent(&self, kind: Option<DomainEventKind>, limit: usize) -> Vec<DomainEventEnvelope> {
+        self.recent
+            .lock()
+            .iter()
+            .rev()
+            .filter(|envelope| kind.map_or(true, |kind| envelope.event.kind() == kind))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    pub fn stats(&self) -> DomainEventBusStats {
+        DomainEventBusStats {
+            published_total: self.sequence.load(Ordering::Relaxed),
+            subscriber_count: self.sender.receiver_count(),
+            published_by_kind: self.counts.lock().clone(),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref DOMAIN_EVENT_BUS: Arc<DomainEventBus> = Arc::new(DomainEventBus::new());
+}
+
+pub fn get_domain_event_bus() -> Arc<DomainEventBus> {
+    Arc::clone(&DOMAIN_EVENT_BUS)
+}
+
+pub fn publish_domain_event(source: &str, event: DomainEvent) {
+    DOMAIN_EVENT_BUS.publish(source, event);
+}
+
+#[tauri::command]
+pub fn inspect_domain_events(
+    kind: Option<DomainEventKind>,
+    limit: Option<usize>,
+) -> Result<DomainEventInspection, String> {
+    let bus = get_domain_event_bus();
+    Ok(DomainEventInspection {
+        stats: bus.stats(),
+        events: bus.recent(kind, limit.unwrap_or(DEFAULT_INSPECTION_LIMIT)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(mint: &str) -> DomainEvent {
+        DomainEvent::CoinDetected {
+            mint: mint.to_string(),
+            symbol: "NEW".to_string(),
+            liquidity: 25_000.0,
+            safety_score: 82.0,
+        }
+    }
+
+    fn risk_change() -> DomainEvent {
+        DomainEvent::RiskScoreChanged {
+            token_address: "mint".to_string(),
+            previous_score: Some(40.0),
+            score: 72.0,
+            previous_level: Some("Medium".to_string()),
+            risk_level: "High".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn subscribers_only_receive_requested_kinds() {
+        let bus = DomainEventBus::new();
+        let mut subscription = bus.subscribe(&[DomainEventKind::RiskScoreChanged]);
+
+        bus.publish("scanner", coin("a"));
+        bus.publish("risk", risk_change());
+
+        let received = subscription.recv().await.unwrap();
+        assert_eq!(received.event.kind(), DomainEventKind::RiskScoreChanged);
+        assert_eq!(received.sequence, 2);
+        assert_eq!(received.source, "risk");
+    }
+
+    #[test]
+    fn inspection_returns_newest_first_and_counts_kinds() {
+        let bus = DomainEventBus::new();
+        bus.publish("scanner", coin("a"));
+        bus.publish("risk", risk_change());
+        bus.publish("scanner", coin("b"));
+
+        let coins = bus.recent(Some(DomainEventKind::CoinDetected), 10);
+        assert_eq!(coins.len(), 2);
+        assert_eq!(coins[0].event, coin("b"));
+
+        let stats = bus.stats();
+        assert_eq!(stats.published_total, 3);
+        assert_eq!(stats.published_by_kind[&DomainEventKind::CoinDetected], 2);
+    }
+
+    #[test]
+    fn recent_events_are_bounded() {
+        let bus = DomainEventBus::new();
+        for i in 0..RECENT_EVENT_CAPACITY + 5 {
+            bus.publish("scanner", coin(&i.to_string()));
+        }
+
+        let events = bus.recent(None, usize::MAX);
+        assert_eq!(events.len(), RECENT_EVENT_CAPACITY);
+        assert_eq!(events.last().unwrap().sequence, 6);
+    }
+}
@@ -0,0 +1,692 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{FromRow, Pool, Sqlite, SqlitePool};
+use tauri::{AppHandle, Manager};
+use tokio::sync::{Notify, OnceCell, RwLock};
+use uuid::Uuid;
+
+use crate::notifications::router::SharedNotificationRouter;
+use crate::webhooks::{SharedWebhookManager, WebhookEvent};
+
+const DELIVERY_QUEUE_DB_FILE: &str = "delivery_queue.db";
+/// Polling fallback; enqueues wake the worker immediately.
+const WORKER_INTERVAL: StdDuration = StdDuration::from_secs(5);
+const CLAIM_BATCH: i64 = 20;
+/// A claimed message whose worker died is handed out again after this long.
+const LEASE_SECS: i64 = 120;
+const DEFAULT_MAX_ATTEMPTS: i64 = 8;
+const BASE_BACKOFF_SECS: i64 = 5;
+const MAX_BACKOFF_SECS: i64 = 15 * 60;
+/// Delivered rows, and with them their dedupe keys, are kept this long.
+const DELIVERED_RETENTION_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueTopic {
+    /// Consumed by the notification router (chat channels).
+    Notification,
+    /// Consumed by the webhook manager.
+    Webhook,
+}
+
+impl QueueTopic {
+    fn as_str(&self) -> &'static str {
+        match self {
+            QueueTopic::Notification => "notification",
+            QueueTopic::Webhook => "webhook",
+        }
+    }
+}
+
+impl FromStr for QueueTopic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "notification" => Ok(QueueTopic::Notification),
+            "webhook" => Ok(QueueTopic::Webhook),
+            _ => Err(format!("Invalid queue topic: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueuedMessageStatus {
+    Pending,
+    Processing,
+    Delivered,
+    /// Gave up after the maximum attempts; can be retried by hand.
+    Dead,
+}
+
+impl QueuedMessageStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            QueuedMessageStatus::Pending => "pending",
+            QueuedMessageStatus::Processing => "processing",
+            QueuedMessageStatus::Delivered => "delivered",
+            QueuedMessageStatus::Dead => "dead",
+        }
+    }
+}
+
+/// Payload of a `Notification` message.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NotificationJob {
+    Broadcast {
+        reference_id: String,
+        title: String,
+        message: String,
+    },
+    Alert {
+        alert_id: String,
+        alert_name: String,
+        symbol: String,
+        current_price: f64,
+        condition: String,
+        /// Jobs queued before push delivery existed only went to chat.
+        #[serde(default = "chat_by_default")]
+        chat: bool,
+        #[serde(default)]
+        push: bool,
+    },
+}
+
+fn chat_by_default() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedMessage {
+    pub id: String,
+    pub topic: String,
+    /// Producers set this so a replayed detection or alert is delivered once.
+    pub dedupe_key: Option<String>,
+    pub payload: String,
+    pub status: String,
+    pub attempts: i64,
+    pub max_attempts: i64,
+    pub next_attempt_at: i64,
+    pub locked_until: Option<i64>,
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub delivered_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryQueueStats {
+    pub pending: i64,
+    pub processing: i64,
+    pub delivered: i64,
+    pub dead: i64,
+    /// Age in seconds of the oldest message still waiting.
+    pub oldest_pending_secs: Option<i64>,
+}
+
+/// Exponential backoff capped at `MAX_BACKOFF_SECS`.
+pub fn queue_backoff_secs(attempts: i64) -> i64 {
+    let exponent = attempts.clamp(1, 16) as u32 - 1;
+    (BASE_BACKOFF_SECS * 2_i64.pow(exponent)).min(MAX_BACKOFF_SECS)
+}
+
+pub struct DeliveryQueue {
+    pool: Pool<Sqlite>,
+}
+
+pub type SharedDeliveryQueue = Arc<RwLock<DeliveryQueue>>;
+
+static DELIVERY_QUEUE: OnceCell<SharedDeliveryQueue> = OnceCell::const_new();
+static DELIVERY_QUEUE_WAKE: Notify = Notify::const_new();
+
+impl DeliveryQueue {
+    pub async fn open(db_url: &str) -> Result<Self, String> {
+        let pool = SqlitePool::connect(db_url)
+            .await
+            .map_err(|e| format!("Failed to open delivery queue database: {e}"))?;
+
+        let queue = Self { pool };
+        queue
+            .initialize()
+            .await
+            .map_err(|e| format!("Failed to initialize delivery queue: {e}"))?;
+        Ok(queue)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS queued_messages (
+                id TEXT PRIMARY KEY,
+                topic TEXT NOT NULL,
+                dedupe_key TEXT,
+                payload TEXT NOT NULL,
+                status TEXT NOT NULL,
+                attempts INTEGER NOT NULL,
+                max_attempts INTEGER NOT NULL,
+                next_attempt_at INTEGER NOT NULL,
+                locked_until INTEGER,
+                last_error TEXT,
+                created_at INTEGER NOT NULL,
+                delivered_at INTEGER,
+                UNIQUE (topic, dedupe_key)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_queued_messages_due ON queued_messages(status, next_attempt_at)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Stores a message; returns false when the dedupe key was already queued.
+    pub async fn enqueue(
+        &self,
+        topic: QueueTopic,
+        dedupe_key: Option<&str>,
+        payload: &Value,
+    ) -> Result<bool, String> {
+        let now = Utc::now().timestamp();
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO queued_messages (
+                id, topic, dedupe_key, payload, status, attempts, max_attempts,
+                next_attempt_at, created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, 0, ?6, ?7, ?7)
+            "#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(topic.as_str())
+        .bind(dedupe_key)
+        .bind(payload.to_string())
+        .bind(QueuedMessageStatus::Pending.as_str())
+        .bind(DEFAULT_MAX_ATTEMPTS)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to enqueue message: {e}"))?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Leases due messages to the caller. Messages stuck in processing past
+    /// their lease (the app crashed mid-delivery) are claimed again.
+    pub async fn claim_due(&self, now: i64) -> Result<Vec<QueuedMessage>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        let due = sqlx::query_as::<_, QueuedMessage>(
+            r#"
+            SELECT * FROM queued_messages
+            WHERE (status = 'pending' AND next_attempt_at <= ?1)
+               OR (status = 'processing' AND locked_until <= ?1)
+            ORDER BY next_attempt_at ASC
+            LIMIT ?2
+            "#,
+        )
+        .bind(now)
+        .bind(CLAIM_BATCH)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for message in &due {
+            sqlx::query(
+                r#"
+                UPDATE queued_messages
+                SET status = 'processing', locked_until = ?2, attempts = attempts + 1
+                WHERE id = ?1
+                "#,
+            )
+            .bind(&message.id)
+            .bind(now + LEASE_SECS)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(due
+            .into_iter()
+            .map(|mut message| {
+                message.attempts += 1;
+                message.status = QueuedMessageStatus::Processing.as_str().to_string();
+                message
+            })
+            .collect())
+    }
+
+    pub async fn mark_delivered(&self, id: &str, now: i64) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE queued_messages
+            SET status = 'delivered', delivered_at = ?2, locked_until = NULL, last_error = NULL
+            WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Schedules a retry with backoff, or dead-letters the message once it
+    /// has used all its attempts.
+    pub async fn mark_failed(
+        &self,
+        message: &QueuedMessage,
+        error: &str,
+        now: i64,
+    ) -> Result<QueuedMessageStatus, sqlx::Error> {
+        let status = if message.attempts >= message.max_attempts {
+            QueuedMessageStatus::Dead
+        } else {
+            QueuedMessageStatus::Pending
+        };
+        sqlx::query(
+            r#"
+            UPDATE queued_messages
+            SET status = ?2, next_attempt_at = ?3, locked_until = NULL, last_error = ?4
+            WHERE id = ?1
+            "#,
+        )
+        .bind(&message.id)
+        .bind(status.as_str())
+        .bind(now + queue_backoff_secs(message.attempts))
+        .bind(error)
+        .execute(&self.pool)
+        .await?;
+        Ok(status)
+    }
+
+    pub async fn retry_dead(&self, id: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE queued_messages
+            SET status = 'pending', attempts = 0, next_attempt_at = ?2, last_error = NULL
+            WHERE id = ?1 AND status = 'dead'
+            "#,
+        )
+        .bind(id)
+        .bind(Utc::now().timestamp())
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn list(
+        &self,
+        status: QueuedMessageStatus,
+        limit: i64,
+    ) -> Result<Vec<QueuedMessage>, sqlx::Error> {
+        sqlx::query_as::<_, QueuedMessage>(
+            "SELECT * FROM queued_messages WHERE status = ?1 ORDER BY created_at DESC LIMIT ?2",
+        )
+        .bind(status.as_str())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn stats(&self, now: i64) -> Result<DeliveryQueueStats, sqlx::Error> {
+        let counts: Vec<(String, i64)> =
+            sqlx::query_as("SELECT status, COUNT(*) FROM queued_messages GROUP BY status")
+                .fetch_all(&self.pool)
+                .await?;
+        let oldest: Option<i64> = sqlx::query_scalar(
+            "SELECT MIN(created_at) FROM queued_messages WHERE status IN ('pending', 'processing')",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let mut stats = DeliveryQueueStats {
+            oldest_pending_secs: oldest.map(|created| now - created),
+            ..Default::default()
+        };
+        for (status, count) in counts {
+            match status.as_str() {
+                "pending" => stats.pending = count,
+                "processing" => stats.processing = count,
+                "delivered" => stats.delivered = count,
+                "dead" => stats.dead = count,
+                _ => {}
+            }
+        }
+        Ok(stats)
+    }
+
+    pub async fn prune_delivered(&self, now: i64) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "DELETE FROM queued_messages WHERE status = 'delivered' AND delivered_at < ?1",
+        )
+        .bind(now - Duration::days(DELIVERED_RETENTION_DAYS).num_seconds())
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+async fn deliver(app: &AppHandle, message: &QueuedMessage) -> Result<(), String> {
+    let topic = QueueTopic::from_str(&message.topic)?;
+    match topic {
+        QueueTopic::Notification => {
+            let job: NotificationJob = serde_json::from_str(&message.payload)
+                .map_err(|e| format!("Invalid notification payload: {e}"))?;
+            let router = app
+                .try_state::<SharedNotificationRouter>()
+                .ok_or_else(|| "Notification router not ready".to_string())?;
+            let router = router.read().await;
+            let result = match &job {
+                NotificationJob::Broadcast {
+                    reference_id,
+                    title,
+                    message,
+                } => {
+                    router
+                        .send_broadcast_notification(reference_id, title, message)
+                        .await
+                }
+                NotificationJob::Alert {
+                    alert_id,
+                    alert_name,
+                    symbol,
+                    current_price,
+                    condition,
+                    chat,
+                    push,
+                } => {
+                    // Chat goes first so a retry after a chat failure doesn't push twice
+                    let mut result = Ok(());
+                    if *chat {
+                        result = router
+                            .send_alert_notification(
+                                alert_id,
+                                alert_name,
+                                symbol,
+                                *current_price,
+                                condition,
+                            )
+                            .await;
+                    }
+                    if *push && result.is_ok() {
+                        result = router
+                            .send_alert_push(
+                                alert_id,
+                                alert_name,
+                                symbol,
+                                *current_price,
+                                condition,
+                            )
+                            .await;
+                    }
+                    result
+                }
+            };
+            result.map_err(|e| e.to_string())
+        }
+        QueueTopic::Webhook => {
+            let event: WebhookEvent = serde_json::from_str(&message.payload)
+                .map_err(|e| format!("Invalid webhook payload: {e}"))?;
+            let manager = app
+                .try_state::<SharedWebhookManager>()
+                .ok_or_else(|| "Webhook manager not ready".to_string())?;
+            let manager = manager.read().await;
+            manager
+                .dispatch_event(&event)
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// Delivers everything that is due. Each message is marked delivered only
+/// after its consumer succeeds, so a crash means redelivery rather than loss.
+pub async fn run_delivery_queue_tick(
+    app: &AppHandle,
+    queue: &SharedDeliveryQueue,
+) -> Result<usize, String> {
+    let now = Utc::now().timestamp();
+    let due = queue
+        .read()
+        .await
+        .claim_due(now)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut delivered = 0;
+    for message in &due {
+        let result = deliver(app, message).await;
+        let queue = queue.read().await;
+        let now = Utc::now().timestamp();
+        match result {
+            Ok(()) => {
+                queue
+                    .mark_delivered(&message.id, now)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                delivered += 1;
+            }
+            Err(err) => {
+                let status = queue
+                    .mark_failed(message, &err, now)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                tracing::warn!(
+                    id = %message.id,
+                    topic = %message.topic,
+                    attempts = message.attempts,
+                    dead = status == QueuedMessageStatus::Dead,
+                    error = %err,
+                    "queued message delivery failed"
+                );
+            }
+        }
+    }
+
+    if let Err(err) = queue.read().await.prune_delivered(now).await {
+        tracing::warn!(error = %err, "failed to prune delivered queue messages");
+    }
+    Ok(delivered)
+}
+
+pub async fn init_delivery_queue(app: &AppHandle) -> Result<(), String> {
+    if DELIVERY_QUEUE.get().is_some() {
+        return Ok(());
+    }
+    let mut db_path: PathBuf = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+    std::fs::create_dir_all(&db_path)
+        .map_err(|e| format!("Failed to create data directory: {e}"))?;
+    db_path.push(DELIVERY_QUEUE_DB_FILE);
+
+    let queue = DeliveryQueue::open(&format!("sqlite:{}?mode=rwc", db_path.display())).await?;
+    let queue = Arc::new(RwLock::new(queue));
+    DELIVERY_QUEUE
+        .set(queue.clone())
+        .map_err(|_| "Delivery queue already initialized".to_string())?;
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if let Err(err) = run_delivery_queue_tick(&app, &queue).await {
+                tracing::warn!(error = %err, "delivery queue tick failed");
+            }
+            let _ = tokio::time::timeout(WORKER_INTERVAL, DELIVERY_QUEUE_WAKE.notified()).await;
+        }
+    });
+    Ok(())
+}
+
+/// Persists a message for the delivery worker. Returns `Err` with the payload
+/// untouched when the queue is unavailable, so callers can fall back to a
+/// direct call.
+pub async fn enqueue_message(
+    topic: QueueTopic,
+    dedupe_key: Option<&str>,
+    payload: Value,
+) -> Result<bool, Value> {
+    let Some(queue) = DELIVERY_QUEUE.get() else {
+        return Err(payload);
+    };
+    match queue
+        .read()
+        .await
+        .enqueue(topic, dedupe_key, &payload)
+        .await
+    {
+        Ok(queued) => {
+            DELIVERY_QUEUE_WAKE.notify_one();
+            Ok(queued)
+        }
+        Err(err) => {
+            tracing::warn!(error = %err, topic = topic.as_str(), "failed to enqueue message");
+            Err(payload)
+        }
+    }
+}
+
+fn require_queue() -> Result<&'static SharedDeliveryQueue, String> {
+    DELIVERY_QUEUE
+        .get()
+        .ok_or_else(|| "Delivery queue not initialized".to_string())
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn get_delivery_queue_stats() -> Result<DeliveryQueueStats, String> {
+    let queue = require_queue()?.read().await;
+    queue
+        .stats(Utc::now().timestamp())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_dead_letter_messages(limit: Option<i64>) -> Result<Vec<QueuedMessage>, String> {
+    let queue = require_queue()?.read().await;
+    queue
+        .list(QueuedMessageStatus::Dead, limit.unwrap_or(100))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn retry_dead_letter_message(id: String) -> Result<bool, String> {
+    let retried = {
+        let queue = require_queue()?.read().await;
+        queue.retry_dead(&id).await.map_err(|e| e.to_string())?
+    };
+    if retried {
+        DELIVERY_QUEUE_WAKE.notify_one();
+    }
+    Ok(retried)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    async fn memory_queue() -> DeliveryQueue {
+        let url = format!("sqlite:file:{}?mode=memory&cache=shared", Uuid::new_v4());
+        DeliveryQueue::open(&url).await.unwrap()
+    }
+
+    #[test]
+    fn backoff_grows_and_caps() {
+        assert_eq!(queue_backoff_secs(1), 5);
+        assert_eq!(queue_backoff_secs(2), 10);
+        assert_eq!(queue_backoff_secs(4), 40);
+        assert_eq!(queue_backoff_secs(20), MAX_BACKOFF_SECS);
+    }
+
+    #[tokio::test]
+    async fn dedupe_keys_are_delivered_once() {
+        let queue = memory_queue().await;
+        let payload = json!({ "kind": "broadcast" });
+        assert!(queue
+            .enqueue(QueueTopic::Notification, Some("coin:abc"), &payload)
+            .await
+            .unwrap());
+        assert!(!queue
+            .enqueue(QueueTopic::Notification, Some("coin:abc"), &payload)
+            .await
+            .unwrap());
+        // The same key on another topic is a different message
+        assert!(queue
+            .enqueue(QueueTopic::Webhook, Some("coin:abc"), &payload)
+            .await
+            .unwrap());
+        // Messages without a key are never deduplicated
+        assert!(queue
+            .enqueue(QueueTopic::Webhook, None, &payload)
+            .await
+            .unwrap());
+        assert!(queue
+            .enqueue(QueueTopic::Webhook, None, &payload)
+            .await
+            .unwrap());
+
+        let claimed = queue.claim_due(Utc::now().timestamp()).await.unwrap();
+        assert_eq!(claimed.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn failed_and_abandoned_messages_are_redelivered() {
+        let queue = memory_queue().await;
+        queue
+            .enqueue(QueueTopic::Webhook, Some("alert:1"), &json!({}))
+            .await
+            .unwrap();
+        let now = Utc::now().timestamp();
+
+        // A claim that is never settled (crash) comes back once the lease ends
+        let first = queue.claim_due(now).await.unwrap();
+        assert_eq!(first.len(), 1);
+        assert!(queue.claim_due(now).await.unwrap().is_empty());
+        let reclaimed = queue.claim_due(now + LEASE_SECS).await.unwrap();
+        assert_eq!(reclaimed[0].attempts, 2);
+
+        // A failure waits out the backoff, and the last attempt dead-letters
+        let mut message = reclaimed[0].clone();
+        let status = queue.mark_failed(&message, "timeout", now).await.unwrap();
+        assert_eq!(status, QueuedMessageStatus::Pending);
+        assert!(queue.claim_due(now).await.unwrap().is_empty());
+        assert_eq!(queue.claim_due(now + 60).await.unwrap().len(), 1);
+
+        message.attempts = message.max_attempts;
+        let status = queue.mark_failed(&message, "timeout", now).await.unwrap();
+        assert_eq!(status, QueuedMessageStatus::Dead);
+        assert!(queue.retry_dead(&message.id).await.unwrap());
+        queue.mark_delivered(&message.id, now).await.unwrap();
+        assert_eq!(queue.stats(now).await.unwrap().delivered, 1);
+    }
+
+    #[test]
+    fn alert_jobs_queued_before_push_still_go_to_chat() {
+        let job: NotificationJob = serde_json::from_value(json!({
+            "kind": "alert",
+            "alert_id": "a1",
+            "alert_name": "SOL breakout",
+            "symbol": "SOL",
+            "current_price": 150.0,
+            "condition": "price above 140",
+        }))
+        .unwrap();
+        assert!(matches!(job, NotificationJob::Alert { chat: true, push: false, .. }));
+    }
+}
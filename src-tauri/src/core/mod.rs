@@ -1,7 +1,11 @@
 pub mod cache_manager;
 pub mod websocket_manager;
 pub mod price_engine;
+pub mod events;
+pub mod message_queue;
 
 pub use cache_manager::*;
 pub use websocket_manager::*;
 pub use price_engine::*;
+pub use events::*;
+pub use message_queue::*;
@@ -12,6 +12,8 @@ use serde::{Deserialize, Serialize};
 use sysinfo::{CpuRefreshKind, RefreshKind, System};
 use tracing::{info, trace, warn};
 
+use crate::config::data_sources::{provider_for, read_recording, DataModule, DataProvider};
+
 const LATENCY_WINDOW: usize = 10_000;
 const MEMORY_POOL_CAPACITY: usize = 512;
 const MEMORY_POOL_BUFFER_SIZE: usize = 1024;
@@ -307,13 +309,34 @@ impl PriceEngine {
     }
 
     pub fn get_price(&self, symbol: &str) -> Option<f64> {
-        let prices = self.prices.read();
-        prices.get(symbol).map(|entry| entry.price)
+        self.get_cached_price(symbol).map(|entry| entry.price)
     }
 
+    /// Reads from the live cache, or from fixtures / recordings when the price
+    /// engine's data source says so.
     pub fn get_cached_price(&self, symbol: &str) -> Option<CachedPrice> {
-        let prices = self.prices.read();
-        prices.get(symbol).cloned()
+        match provider_for(DataModule::PriceEngine, &format!("price_{symbol}")) {
+            DataProvider::Live { .. } => self.prices.read().get(symbol).cloned(),
+            DataProvider::Mock => crate::demo::find_demo_token(symbol).map(|token| {
+                let snapshot = crate::demo::demo_snapshot(token);
+                CachedPrice {
+                    price: snapshot.price,
+                    volume: snapshot.volume_24h,
+                    change_24h: snapshot.price_change_24h,
+                    timestamp: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_millis() as u64,
+                }
+            }),
+            DataProvider::Recorded(path) => match read_recording(&path) {
+                Ok(price) => Some(price),
+                Err(err) => {
+                    warn!("recorded price unavailable: {err}");
+                    None
+                }
+            },
+        }
     }
 
     pub async fn run_performance_test(&self, num_updates: usize) -> PerformanceMetrics {
@@ -3,8 +3,13 @@ use crate::websocket::birdeye::BirdeyeStream;
 use crate::websocket::helius::HeliusStream;
 use crate::websocket::reconnect::ExponentialBackoff;
 use crate::websocket::types::*;
+use crate::websocket::watchdog::{
+    detect_stall, heartbeat_timeout, ProviderIncidentStats, StalledStream, StreamIncident,
+    StreamWatchdog, STREAM_STALL_EVENT,
+};
 use rand::Rng;
 use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Manager};
@@ -12,8 +17,7 @@ use tokio::sync::{broadcast, mpsc, Mutex, RwLock};
 use tokio::time::{interval_at, Instant as TokioInstant};
 use tokio_tungstenite::tungstenite::Message;
 
-const PING_INTERVAL: Duration = Duration::from_secs(30);
-const STALE_THRESHOLD: Duration = Duration::from_secs(60);
+const WATCHDOG_INTERVAL: Duration = Duration::from_secs(10);
 const QUEUE_CAPACITY: usize = 1000;
 const POLL_INTERVAL: Duration = Duration::from_secs(5);
 const MAX_SYMBOL_BATCH: usize = 100;
@@ -32,12 +36,16 @@ pub struct StreamConnection {
     pub statistics: Arc<RwLock<StreamStatisticsInternal>>,
     pub event_tx: broadcast::Sender<StreamEvent>,
     pub command_tx: Arc<Mutex<Option<mpsc::UnboundedSender<StreamCommand>>>>,
+    /// Bumped when the watchdog abandons a socket, so the abandoned read loop
+    /// can't schedule a second reconnect when it finally errors out.
+    pub generation: Arc<AtomicU64>,
 }
 
 
 #[derive(Clone)]
 pub struct WebSocketManager {
     connections: Arc<RwLock<HashMap<StreamProvider, StreamConnection>>>,
+    watchdog: Arc<StreamWatchdog>,
     app_handle: AppHandle,
 }
 
@@ -105,6 +113,7 @@ impl WebSocketManager {
     pub fn new(app_handle: AppHandle) -> Self {
         let manager = Self {
             connections: Arc::new(RwLock::new(HashMap::new())),
+            watchdog: Arc::new(StreamWatchdog::new()),
             app_handle,
         };
 
@@ -136,6 +145,7 @@ impl WebSocketManager {
             statistics: Arc::new(RwLock::new(StreamStatisticsInternal::default())),
             event_tx: tx,
             command_tx: Arc::new(Mutex::new(None)),
+            generation: Arc::new(AtomicU64::new(0)),
         };
 
         self.connections
@@ -150,7 +160,7 @@ impl WebSocketManager {
 
         let manager = self.clone();
         tauri::async_runtime::spawn(async move {
-            manager.run_heartbeat(provider).await;
+            manager.run_watchdog(provider).await;
         });
     }
 
@@ -163,19 +173,24 @@ impl WebSocketManager {
             self.transition_state(&connection, ConnectionStateInternal::Connecting)
                 .await;
             self.emit_status(&connection).await;
+            let generation = connection.generation.load(Ordering::SeqCst);
 
-            match provider {
+            let result = match provider {
                 StreamProvider::Birdeye => {
-                    let stream = BirdeyeStream::new(connection.clone(), self.app_handle.clone());
-                    if let Err(err) = stream.start().await {
-                        self.handle_connection_error(&connection, err).await;
-                    }
+                    BirdeyeStream::new(connection.clone(), self.app_handle.clone())
+                        .start()
+                        .await
                 }
                 StreamProvider::Helius => {
-                    let stream = HeliusStream::new(connection.clone(), self.app_handle.clone());
-                    if let Err(err) = stream.start().await {
-                        self.handle_connection_error(&connection, err).await;
-                    }
+                    HeliusStream::new(connection.clone(), self.app_handle.clone())
+                        .start()
+                        .await
+                }
+            };
+
+            if let Err(err) = result {
+                if connection.generation.load(Ordering::SeqCst) == generation {
+                    self.handle_connection_error(&connection, err).await;
                 }
             }
         }
@@ -235,33 +250,94 @@ impl WebSocketManager {
         }
     }
 
-    async fn run_heartbeat(&self, provider: StreamProvider) {
+    /// Supervises a provider for its whole lifetime. Only connected sockets
+    /// are checked; reconnects in progress are left to the backoff.
+    async fn run_watchdog(&self, provider: StreamProvider) {
         if let Some(connection) = self.get_connection(&provider).await {
-            let mut ticker = interval_at(TokioInstant::now() + PING_INTERVAL, PING_INTERVAL);
+            let timeout = heartbeat_timeout(&provider);
+            let mut ticker = interval_at(TokioInstant::now() + WATCHDOG_INTERVAL, WATCHDOG_INTERVAL);
+            let mut connected_at: Option<Instant> = None;
             loop {
                 ticker.tick().await;
 
                 let state = connection.state.read().await.clone();
-                if matches!(
-                    state,
-                    ConnectionStateInternal::Disconnected | ConnectionStateInternal::Failed
-                ) {
-                    break;
+                if !matches!(state, ConnectionStateInternal::Connected) {
+                    connected_at = None;
+                    continue;
                 }
+                let since = *connected_at.get_or_insert_with(Instant::now);
 
-                let last_message = connection.last_message.read().await.clone();
-                if let Some(last) = last_message {
-                    if last.elapsed() > STALE_THRESHOLD {
-                        self.force_reconnect(&connection, "heartbeat stale").await;
-                    }
-                } else {
-                    self.force_reconnect(&connection, "no message received")
-                        .await;
+                let last_message = *connection.last_message.read().await;
+                let stalled = detect_stall(last_message, since, Instant::now(), timeout);
+                if let Some(silent_for) = stalled {
+                    connected_at = None;
+                    self.recover_stall(&connection, silent_for).await;
                 }
             }
         }
     }
 
+    /// Drops a silent socket and reconnects. The stream resubscribes every
+    /// stored topic once it is connected again.
+    async fn recover_stall(&self, connection: &StreamConnection, silent_for: Duration) {
+        connection.generation.fetch_add(1, Ordering::SeqCst);
+        if let Some(tx) = connection.command_tx.lock().await.take() {
+            let _ = tx.send(StreamCommand::Close);
+        }
+
+        let subscriptions = connection.subscriptions.read().await.clone();
+        let resubscribed = match connection.provider {
+            StreamProvider::Birdeye => subscriptions.prices,
+            StreamProvider::Helius => subscriptions.wallets,
+        };
+        let incident = StreamIncident {
+            provider: connection.provider.clone(),
+            stream: StalledStream::Socket,
+            detected_at: chrono::Utc::now(),
+            silent_for_ms: silent_for.as_millis() as u64,
+            resubscribed,
+        };
+        tracing::warn!(
+            provider = connection.provider.id(),
+            silent_for_ms = incident.silent_for_ms,
+            "stream stalled, reconnecting"
+        );
+        self.watchdog.record(incident.clone());
+        let _ = self.app_handle.emit_all(STREAM_STALL_EVENT, &incident);
+
+        connection.statistics.write().await.reconnect_count += 1;
+        let reason = format!("no message for {}s", silent_for.as_secs());
+        self.force_reconnect(connection, &reason).await;
+    }
+
+    /// Called by chart streams whose price stopped moving while the socket
+    /// stayed up: the symbol is subscribed again without reconnecting.
+    pub async fn recover_chart_stall(&self, symbol: &str, silent_for: Duration) {
+        let incident = StreamIncident {
+            provider: StreamProvider::Birdeye,
+            stream: StalledStream::Chart,
+            detected_at: chrono::Utc::now(),
+            silent_for_ms: silent_for.as_millis() as u64,
+            resubscribed: vec![symbol.to_string()],
+        };
+        self.watchdog.record(incident.clone());
+        let _ = self.app_handle.emit_all(STREAM_STALL_EVENT, &incident);
+
+        if let Some(connection) = self.get_connection(&StreamProvider::Birdeye).await {
+            if let Some(ref tx) = *connection.command_tx.lock().await {
+                let _ = tx.send(StreamCommand::SubscribePrices(vec![symbol.to_string()]));
+            }
+        }
+    }
+
+    pub fn incident_stats(&self) -> Vec<ProviderIncidentStats> {
+        self.watchdog.stats()
+    }
+
+    pub fn reset_incidents(&self) {
+        self.watchdog.reset();
+    }
+
     pub async fn force_reconnect(&self, connection: &StreamConnection, reason: &str) {
         {
             let mut state = connection.state.write().await;
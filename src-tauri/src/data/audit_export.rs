@@ -0,0 +1,263 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use tauri::State;
+
+use super::event_store::{EventFilter, EventRecord};
+use crate::security::keystore::{Keystore, KeystoreError};
+
+const AUDIT_SIGNING_KEY: &str = "audit.export_signing_key";
+pub const AUDIT_EXPORT_FORMAT: &str = "eclipse-audit-trail/jsonl-v1";
+
+/// One line of the JSON Lines file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLine {
+    pub id: String,
+    pub event_type: String,
+    pub aggregate_id: String,
+    pub sequence: i64,
+    pub timestamp: String,
+    pub data: Value,
+}
+
+impl From<&EventRecord> for AuditLine {
+    fn from(record: &EventRecord) -> Self {
+        Self {
+            id: record.id.clone(),
+            event_type: record.event_type.clone(),
+            aggregate_id: record.aggregate_id.clone(),
+            sequence: record.sequence,
+            timestamp: record.timestamp.clone(),
+            data: serde_json::from_str(&record.event_data).unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditExportFilter {
+    pub aggregate_id: Option<String>,
+    pub event_type: Option<String>,
+    pub from_time: Option<DateTime<Utc>>,
+    pub to_time: Option<DateTime<Utc>>,
+}
+
+/// Describes what an export contains so a verifier can tell it is complete.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditExportManifest {
+    pub format: String,
+    pub generated_at: DateTime<Utc>,
+    pub record_count: usize,
+    pub event_type_counts: BTreeMap<String, usize>,
+    pub first_timestamp: Option<String>,
+    pub last_timestamp: Option<String>,
+    pub filter: AuditExportFilter,
+    /// Hex SHA-256 of the JSON Lines file exactly as exported.
+    pub records_sha256: String,
+    /// Base58 Ed25519 key, the same encoding as Solana addresses.
+    pub public_key: String,
+}
+
+/// The three files of a signed export. `signature` is the base58 Ed25519
+/// signature over the bytes of `manifest`, which is kept as text so a verifier
+/// checks exactly what was signed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedAuditExport {
+    pub records: String,
+    pub manifest: String,
+    pub signature: String,
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+pub fn audit_lines(records: &[EventRecord]) -> Result<String, String> {
+    let mut jsonl = String::new();
+    for record in records {
+        let line = serde_json::to_string(&AuditLine::from(record)).map_err(|e| e.to_string())?;
+        jsonl.push_str(&line);
+        jsonl.push('\n');
+    }
+    Ok(jsonl)
+}
+
+pub fn sign_audit_export(
+    records: &[EventRecord],
+    filter: &EventFilter,
+    keypair: &Keypair,
+) -> Result<SignedAuditExport, String> {
+    let jsonl = audit_lines(records)?;
+
+    let mut event_type_counts = BTreeMap::new();
+    for record in records {
+        *event_type_counts
+            .entry(record.event_type.clone())
+            .or_insert(0) += 1;
+    }
+
+    let manifest = AuditExportManifest {
+        format: AUDIT_EXPORT_FORMAT.to_string(),
+        generated_at: Utc::now(),
+        record_count: records.len(),
+        event_type_counts,
+        first_timestamp: records.first().map(|r| r.timestamp.clone()),
+        last_timestamp: records.last().map(|r| r.timestamp.clone()),
+        filter: AuditExportFilter {
+            aggregate_id: filter.aggregate_id.clone(),
+            event_type: filter.event_type.clone(),
+            from_time: filter.from_time,
+            to_time: filter.to_time,
+        },
+        records_sha256: sha256_hex(jsonl.as_bytes()),
+        public_key: keypair.pubkey().to_string(),
+    };
+    let manifest = serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())?;
+    let signature = keypair.sign_message(manifest.as_bytes());
+
+    Ok(SignedAuditExport {
+        records: jsonl,
+        manifest,
+        signature: signature.to_string(),
+    })
+}
+
+/// Checks the signature, then that the records are the ones the manifest
+/// describes. Returns the manifest so the caller can compare the public key
+/// against one it already trusts.
+pub fn verify_audit_export(export: &SignedAuditExport) -> Result<AuditExportManifest, String> {
+    let manifest: AuditExportManifest =
+        serde_json::from_str(&export.manifest).map_err(|e| format!("Invalid manifest: {e}"))?;
+    if manifest.format != AUDIT_EXPORT_FORMAT {
+        return Err(format!("Unsupported export format: {}", manifest.format));
+    }
+
+    let public_key = Pubkey::from_str(&manifest.public_key)
+        .map_err(|_| "Invalid public key in manifest".to_string())?;
+    let signature =
+        Signature::from_str(&export.signature).map_err(|_| "Invalid signature".to_string())?;
+    if !signature.verify(public_key.as_ref(), export.manifest.as_bytes()) {
+        return Err("Signature does not match the manifest".to_string());
+    }
+
+    if sha256_hex(export.records.as_bytes()) != manifest.records_sha256 {
+        return Err("Records were modified after signing".to_string());
+    }
+    let lines = export.records.lines().filter(|l| !l.is_empty()).count();
+    if lines != manifest.record_count {
+        return Err(format!(
+            "Manifest lists {} records but the export has {lines}",
+            manifest.record_count
+        ));
+    }
+
+    Ok(manifest)
+}
+
+/// Loads the local export signing key, creating it on first use.
+pub fn audit_signing_keypair(keystore: &Keystore) -> Result<Keypair, String> {
+    match keystore.retrieve_secret(AUDIT_SIGNING_KEY) {
+        Ok(raw) => Keypair::from_bytes(&raw).map_err(|e| format!("Corrupt audit signing key: {e}")),
+        Err(KeystoreError::NotFound) => {
+            let keypair = Keypair::new();
+            keystore
+                .store_secret(AUDIT_SIGNING_KEY, &keypair.to_bytes())
+                .map_err(|e| e.to_string())?;
+            Ok(keypair)
+        }
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// The key auditors pin; publish it out of band so exports can't be re-signed.
+#[tauri::command]
+pub async fn get_audit_export_public_key(keystore: State<'_, Keystore>) -> Result<String, String> {
+    Ok(audit_signing_keypair(&keystore)?.pubkey().to_string())
+}
+
+#[tauri::command]
+pub async fn verify_audit_export_command(
+    export: SignedAuditExport,
+) -> Result<AuditExportManifest, String> {
+    verify_audit_export(&export)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(sequence: i64, event_type: &str) -> EventRecord {
+        EventRecord {
+            id: format!("evt-{sequence}"),
+            event_type: event_type.to_string(),
+            event_data: format!(r#"{{"type":"{event_type}"}}"#),
+            aggregate_id: "order-1".to_string(),
+            sequence,
+            timestamp: format!("2024-01-01T00:00:0{sequence}Z"),
+        }
+    }
+
+    fn filter() -> EventFilter {
+        EventFilter {
+            aggregate_id: None,
+            event_type: None,
+            from_time: None,
+            to_time: None,
+            limit: None,
+            offset: None,
+            include_archived: false,
+        }
+    }
+
+    #[test]
+    fn signed_export_verifies_and_counts_records() {
+        let keypair = Keypair::new();
+        let records = vec![
+            record(1, "order_placed"),
+            record(2, "order_filled"),
+            record(3, "order_placed"),
+        ];
+        let export = sign_audit_export(&records, &filter(), &keypair).unwrap();
+
+        assert_eq!(export.records.lines().count(), 3);
+        let manifest = verify_audit_export(&export).unwrap();
+        assert_eq!(manifest.record_count, 3);
+        assert_eq!(manifest.event_type_counts["order_placed"], 2);
+        assert_eq!(manifest.public_key, keypair.pubkey().to_string());
+    }
+
+    #[test]
+    fn dropped_or_edited_records_fail_verification() {
+        let keypair = Keypair::new();
+        let records = vec![record(1, "order_placed"), record(2, "order_filled")];
+        let export = sign_audit_export(&records, &filter(), &keypair).unwrap();
+
+        let mut truncated = export.clone();
+        truncated.records = truncated.records.lines().next().unwrap().to_string() + "\n";
+        assert!(verify_audit_export(&truncated).is_err());
+
+        let mut edited = export.clone();
+        edited.records = edited.records.replace("order_filled", "order_cancelled");
+        assert!(verify_audit_export(&edited).is_err());
+    }
+
+    #[test]
+    fn rewritten_manifest_fails_signature_check() {
+        let keypair = Keypair::new();
+        let export = sign_audit_export(&[record(1, "order_placed")], &filter(), &keypair).unwrap();
+
+        let mut forged = export.clone();
+        forged.manifest = forged
+            .manifest
+            .replace("\"recordCount\": 1", "\"recordCount\": 0");
+        assert_ne!(forged.manifest, export.manifest);
+        assert!(verify_audit_export(&forged).is_err());
+    }
+}
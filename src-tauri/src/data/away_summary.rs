@@ -0,0 +1,752 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite, SqlitePool};
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+
+use super::event_store::{record_app_event, Event, EventFilter, EventRecord, SharedEventStore};
+use crate::core::events::{get_domain_event_bus, DomainEvent, DomainEventKind};
+use crate::core::price_engine::get_price_engine;
+use crate::notifications::router::SharedNotificationRouter;
+use crate::portfolio::watchlists::SharedWatchlistManager;
+
+const AWAY_SUMMARY_DB_FILE: &str = "session_activity.db";
+const HEARTBEAT_INTERVAL_SECS: u64 = 300;
+const DEFAULT_LOOKBACK_HOURS: i64 = 24;
+const MAX_EVENTS_PER_TYPE: i64 = 1_000;
+const MAX_TIMELINE_ENTRIES: usize = 200;
+
+/// Event store types that make up the summary.
+const SUMMARY_EVENT_TYPES: [&str; 5] = [
+    "alert_triggered",
+    "order_filled",
+    "bot_trade_executed",
+    "watchlist_price_moved",
+    "risk_score_changed",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AwaySummarySettings {
+    /// Send a notification digest when the app starts after an absence.
+    pub digest_enabled: bool,
+    /// Shorter gaps between sessions don't count as being away.
+    pub min_away_minutes: i64,
+    /// Watchlist moves smaller than this are not recorded.
+    pub watchlist_move_percent: f64,
+}
+
+impl Default for AwaySummarySettings {
+    fn default() -> Self {
+        Self {
+            digest_enabled: true,
+            min_away_minutes: 60,
+            watchlist_move_percent: 10.0,
+        }
+    }
+}
+
+impl AwaySummarySettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.min_away_minutes < 1 {
+            return Err("Minimum away time must be at least one minute".into());
+        }
+        if !(0.5..=100.0).contains(&self.watchlist_move_percent) {
+            return Err("Watchlist move threshold must be between 0.5 and 100 percent".into());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AwayCategory {
+    AlertFired,
+    OrderFilled,
+    BotTrade,
+    WatchlistMove,
+    RiskChange,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AwayTimelineEntry {
+    pub timestamp: DateTime<Utc>,
+    pub category: AwayCategory,
+    pub title: String,
+    pub detail: String,
+    /// Alert, order, execution or token the entry is about.
+    pub reference: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AwaySummary {
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    pub alerts_fired: usize,
+    pub orders_filled: usize,
+    pub bot_trades: usize,
+    pub watchlist_moves: usize,
+    pub risk_changes: usize,
+    /// Oldest first, capped; the counts above cover everything.
+    pub timeline: Vec<AwayTimelineEntry>,
+    pub truncated: bool,
+}
+
+impl AwaySummary {
+    pub fn is_empty(&self) -> bool {
+        self.alerts_fired
+            + self.orders_filled
+            + self.bot_trades
+            + self.watchlist_moves
+            + self.risk_changes
+            == 0
+    }
+
+    /// One line for the notification digest.
+    pub fn headline(&self) -> String {
+        let parts: Vec<String> = [
+            (self.alerts_fired, "alert", "alerts", "fired"),
+            (self.orders_filled, "order", "orders", "filled"),
+            (self.bot_trades, "bot trade", "bot trades", "executed"),
+            (
+                self.watchlist_moves,
+                "large watchlist move",
+                "large watchlist moves",
+                "",
+            ),
+            (
+                self.risk_changes,
+                "risk score change",
+                "risk score changes",
+                "",
+            ),
+        ]
+        .into_iter()
+        .filter(|(count, ..)| *count > 0)
+        .map(|(count, one, many, verb)| {
+            let noun = if count == 1 { one } else { many };
+            format!("{count} {noun} {verb}").trim_end().to_string()
+        })
+        .collect();
+
+        if parts.is_empty() {
+            "Nothing happened while you were away".to_string()
+        } else {
+            format!("While you were away: {}", parts.join(", "))
+        }
+    }
+}
+
+fn timeline_entry(record: &EventRecord) -> Option<AwayTimelineEntry> {
+    let event: Event = serde_json::from_str(&record.event_data).ok()?;
+    let (timestamp, category, title, detail, reference) = match event {
+        Event::AlertTriggered {
+            alert_id,
+            alert_name,
+            symbol,
+            price,
+            timestamp,
+        } => (
+            timestamp,
+            AwayCategory::AlertFired,
+            format!("Alert '{alert_name}' fired"),
+            format!("{symbol} at {price}"),
+            alert_id,
+        ),
+        Event::OrderFilled {
+            order_id,
+            fill_price,
+            filled_quantity,
+            timestamp,
+        } => (
+            timestamp,
+            AwayCategory::OrderFilled,
+            "Order filled".to_string(),
+            format!("{filled_quantity} units at {fill_price}"),
+            order_id,
+        ),
+        Event::BotTradeExecuted {
+            bot_type,
+            bot_id,
+            input_mint,
+            output_mint,
+            input_amount,
+            output_amount,
+            timestamp,
+            ..
+        } => (
+            timestamp,
+            AwayCategory::BotTrade,
+            format!("{} bot traded", bot_type.replace('_', " ")),
+            format!("{input_amount} {input_mint} -> {output_amount} {output_mint}"),
+            bot_id,
+        ),
+        Event::WatchlistPriceMoved {
+            symbol,
+            mint,
+            from_price,
+            to_price,
+            change_percent,
+            timestamp,
+        } => (
+            timestamp,
+            AwayCategory::WatchlistMove,
+            format!("{symbol} moved {change_percent:+.1}%"),
+            format!("{from_price} -> {to_price}"),
+            mint,
+        ),
+        Event::RiskScoreChanged {
+            token_address,
+            previous_score,
+            score,
+            risk_level,
+            timestamp,
+        } => (
+            timestamp,
+            AwayCategory::RiskChange,
+            format!("Risk level now {risk_level}"),
+            match previous_score {
+                Some(previous) => format!("Score {previous:.0} -> {score:.0}"),
+                None => format!("Score {score:.0}"),
+            },
+            token_address,
+        ),
+        _ => return None,
+    };
+
+    Some(AwayTimelineEntry {
+        timestamp,
+        category,
+        title,
+        detail,
+        reference,
+    })
+}
+
+/// Assembles the summary from event store records; events outside the window
+/// and types that aren't part of the summary are ignored.
+pub fn build_away_summary(
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    records: &[EventRecord],
+) -> AwaySummary {
+    let mut entries: Vec<AwayTimelineEntry> = records
+        .iter()
+        .filter_map(timeline_entry)
+        .filter(|entry| entry.timestamp >= since && entry.timestamp <= until)
+        .collect();
+    entries.sort_by_key(|entry| entry.timestamp);
+
+    let count = |category: AwayCategory| entries.iter().filter(|e| e.category == category).count();
+    let mut summary = AwaySummary {
+        since,
+        until,
+        alerts_fired: count(AwayCategory::AlertFired),
+        orders_filled: count(AwayCategory::OrderFilled),
+        bot_trades: count(AwayCategory::BotTrade),
+        watchlist_moves: count(AwayCategory::WatchlistMove),
+        risk_changes: count(AwayCategory::RiskChange),
+        timeline: Vec::new(),
+        truncated: entries.len() > MAX_TIMELINE_ENTRIES,
+    };
+    // Keep the most recent entries when there are too many to show
+    let skip = entries.len().saturating_sub(MAX_TIMELINE_ENTRIES);
+    summary.timeline = entries.into_iter().skip(skip).collect();
+    summary
+}
+
+/// Percent move from the marked price when it reaches the threshold.
+pub fn watchlist_move(marked_price: f64, price: f64, threshold_percent: f64) -> Option<f64> {
+    if marked_price <= 0.0 || price <= 0.0 {
+        return None;
+    }
+    let change = (price - marked_price) / marked_price * 100.0;
+    (change.abs() >= threshold_percent).then_some(change)
+}
+
+/// Tracks when the app was last active and the watchlist price marks that
+/// moves are measured from.
+pub struct SessionActivity {
+    pool: Pool<Sqlite>,
+}
+
+pub type SharedSessionActivity = Arc<SessionActivity>;
+
+impl SessionActivity {
+    async fn open(url: &str) -> Result<Self, sqlx::Error> {
+        let pool = SqlitePool::connect(url).await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS session_activity (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                last_active_at TEXT NOT NULL,
+                previous_active_at TEXT
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS away_summary_settings (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                config_json TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS watchlist_price_marks (
+                mint TEXT PRIMARY KEY NOT NULL,
+                symbol TEXT NOT NULL,
+                price REAL NOT NULL,
+                marked_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(Self { pool })
+    }
+
+    /// Starts a new session and returns when the previous one was last active.
+    async fn start_session(&self) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+        let now = Utc::now().to_rfc3339();
+        sqlx::query(
+            r#"
+            INSERT INTO session_activity (id, last_active_at, previous_active_at)
+            VALUES (1, ?1, NULL)
+            ON CONFLICT(id) DO UPDATE SET
+                previous_active_at = session_activity.last_active_at,
+                last_active_at = excluded.last_active_at
+            "#,
+        )
+        .bind(&now)
+        .execute(&self.pool)
+        .await?;
+        self.previous_session_end().await
+    }
+
+    async fn heartbeat(&self) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE session_activity SET last_active_at = ?1 WHERE id = 1")
+            .bind(Utc::now().to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn previous_session_end(&self) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+        let value: Option<Option<String>> =
+            sqlx::query_scalar("SELECT previous_active_at FROM session_activity WHERE id = 1")
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(value
+            .flatten()
+            .and_then(|raw| DateTime::parse_from_rfc3339(&raw).ok())
+            .map(|time| time.with_timezone(&Utc)))
+    }
+
+    pub async fn settings(&self) -> Result<AwaySummarySettings, String> {
+        let row = sqlx::query("SELECT config_json FROM away_summary_settings WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        match row {
+            Some(row) => {
+                let json: String = row.try_get("config_json").map_err(|e| e.to_string())?;
+                serde_json::from_str(&json).map_err(|e| e.to_string())
+            }
+            None => Ok(AwaySummarySettings::default()),
+        }
+    }
+
+    async fn save_settings(&self, settings: &AwaySummarySettings) -> Result<(), String> {
+        settings.validate()?;
+        let json = serde_json::to_string(settings).map_err(|e| e.to_string())?;
+        sqlx::query(
+            r#"
+            INSERT INTO away_summary_settings (id, config_json) VALUES (1, ?1)
+            ON CONFLICT(id) DO UPDATE SET config_json = excluded.config_json
+            "#,
+        )
+        .bind(json)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn price_marks(&self) -> Result<HashMap<String, f64>, sqlx::Error> {
+        let rows =
+            sqlx::query_as::<_, (String, f64)>("SELECT mint, price FROM watchlist_price_marks")
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn set_price_mark(
+        &self,
+        mint: &str,
+        symbol: &str,
+        price: f64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO watchlist_price_marks (mint, symbol, price, marked_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(mint) DO UPDATE SET
+                symbol = excluded.symbol,
+                price = excluded.price,
+                marked_at = excluded.marked_at
+            "#,
+        )
+        .bind(mint)
+        .bind(symbol)
+        .bind(price)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+/// Compares streamed watchlist prices with their marks and records moves past
+/// the threshold, re-marking the price each time one is recorded.
+async fn track_watchlist_moves(app: &AppHandle, activity: &SessionActivity, threshold: f64) {
+    let Some(manager) = app.try_state::<SharedWatchlistManager>() else {
+        return;
+    };
+    let watchlists = match manager.read().await.list_watchlists().await {
+        Ok(watchlists) => watchlists,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to load watchlists for move tracking");
+            return;
+        }
+    };
+    let marks = match activity.price_marks().await {
+        Ok(marks) => marks,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to load watchlist price marks");
+            return;
+        }
+    };
+
+    let engine = get_price_engine();
+    let mut seen = Vec::new();
+    for item in watchlists.into_iter().flat_map(|w| w.items) {
+        if item.mint.is_empty() || seen.contains(&item.mint) {
+            continue;
+        }
+        seen.push(item.mint.clone());
+        let Some(price) = engine.get_price(&item.symbol) else {
+            continue;
+        };
+
+        let marked = marks.get(&item.mint).copied();
+        if let Some(marked_price) = marked {
+            let Some(change_percent) = watchlist_move(marked_price, price, threshold) else {
+                continue;
+            };
+            record_app_event(
+                app,
+                &format!("watchlist_{}", item.mint),
+                Event::WatchlistPriceMoved {
+                    symbol: item.symbol.clone(),
+                    mint: item.mint.clone(),
+                    from_price: marked_price,
+                    to_price: price,
+                    change_percent,
+                    timestamp: Utc::now(),
+                },
+            )
+            .await;
+        }
+        if let Err(err) = activity
+            .set_price_mark(&item.mint, &item.symbol, price)
+            .await
+        {
+            tracing::warn!(error = %err, mint = %item.mint, "failed to mark watchlist price");
+        }
+    }
+}
+
+/// Copies risk score changes from the domain bus into the event store so they
+/// show up in later summaries.
+fn spawn_risk_change_recorder(app: AppHandle) {
+    let mut subscription = get_domain_event_bus().subscribe(&[DomainEventKind::RiskScoreChanged]);
+    tauri::async_runtime::spawn(async move {
+        while let Some(envelope) = subscription.recv().await {
+            if let DomainEvent::RiskScoreChanged {
+                token_address,
+                previous_score,
+                score,
+                risk_level,
+                ..
+            } = envelope.event
+            {
+                record_app_event(
+                    &app,
+                    &format!("risk_{token_address}"),
+                    Event::RiskScoreChanged {
+                        token_address,
+                        previous_score,
+                        score,
+                        risk_level,
+                        timestamp: envelope.published_at,
+                    },
+                )
+                .await;
+            }
+        }
+    });
+}
+
+/// Reads the summary events between `since` and `until` from the event store.
+pub async fn load_away_summary(
+    app: &AppHandle,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Result<AwaySummary, String> {
+    let store = app
+        .try_state::<SharedEventStore>()
+        .ok_or_else(|| "Event store unavailable".to_string())?;
+    let store = store.read().await;
+
+    let mut records = Vec::new();
+    for event_type in SUMMARY_EVENT_TYPES {
+        let filter = EventFilter {
+            aggregate_id: None,
+            event_type: Some(event_type.to_string()),
+            from_time: Some(since),
+            to_time: Some(until),
+            limit: Some(MAX_EVENTS_PER_TYPE),
+            offset: None,
+            include_archived: false,
+        };
+        records.extend(store.get_events(filter).await.map_err(|e| e.to_string())?);
+    }
+    Ok(build_away_summary(since, until, &records))
+}
+
+async fn deliver_away_digest(app: &AppHandle, summary: &AwaySummary) {
+    let _ = app.emit_all("away_summary", summary);
+
+    let Some(router) = app.try_state::<SharedNotificationRouter>() else {
+        return;
+    };
+    let reference = format!("away_{}", summary.since.timestamp());
+    if let Err(err) = router
+        .read()
+        .await
+        .send_broadcast_notification(&reference, "While you were away", &summary.headline())
+        .await
+    {
+        tracing::warn!(error = %err, "failed to deliver away summary digest");
+    }
+}
+
+/// Starts the session and, after a long enough absence, sends the digest of
+/// what happened since the previous one.
+async fn begin_session(app: &AppHandle, activity: &SessionActivity) {
+    let previous = match activity.start_session().await {
+        Ok(previous) => previous,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to start activity session");
+            return;
+        }
+    };
+    let settings = activity.settings().await.unwrap_or_default();
+    let Some(since) = previous else {
+        return;
+    };
+    let now = Utc::now();
+    if !settings.digest_enabled || now - since < Duration::minutes(settings.min_away_minutes) {
+        return;
+    }
+
+    match load_away_summary(app, since, now).await {
+        Ok(summary) if !summary.is_empty() => deliver_away_digest(app, &summary).await,
+        Ok(_) => {}
+        Err(err) => tracing::warn!(error = %err, "failed to build away summary"),
+    }
+}
+
+pub fn register_away_summary(app: &tauri::App) {
+    let Some(dir) = app.path_resolver().app_data_dir() else {
+        tracing::warn!("app data directory unavailable; away summaries disabled");
+        return;
+    };
+    if let Err(err) = fs::create_dir_all(&dir) {
+        tracing::warn!(error = %err, "failed to create app data directory");
+    }
+    let url = format!(
+        "sqlite:{}?mode=rwc",
+        dir.join(AWAY_SUMMARY_DB_FILE).display()
+    );
+
+    let activity = match tauri::async_runtime::block_on(SessionActivity::open(&url)) {
+        Ok(activity) => Arc::new(activity),
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to open session activity");
+            return;
+        }
+    };
+    app.manage::<SharedSessionActivity>(activity.clone());
+
+    let handle = app.handle();
+    spawn_risk_change_recorder(handle.clone());
+    tauri::async_runtime::spawn(async move {
+        begin_session(&handle, &activity).await;
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(HEARTBEAT_INTERVAL_SECS)).await;
+            if let Err(err) = activity.heartbeat().await {
+                tracing::warn!(error = %err, "failed to record session heartbeat");
+            }
+            let threshold = activity
+                .settings()
+                .await
+                .map(|settings| settings.watchlist_move_percent)
+                .unwrap_or(AwaySummarySettings::default().watchlist_move_percent);
+            track_watchlist_moves(&handle, &activity, threshold).await;
+        }
+    });
+}
+
+/// Summary since `since`, defaulting to the end of the previous session.
+#[tauri::command]
+pub async fn get_away_summary(
+    since: Option<DateTime<Utc>>,
+    activity: State<'_, SharedSessionActivity>,
+    app: AppHandle,
+) -> Result<AwaySummary, String> {
+    let now = Utc::now();
+    let since = match since {
+        Some(since) => since,
+        None => activity
+            .previous_session_end()
+            .await
+            .map_err(|e| e.to_string())?
+            .unwrap_or_else(|| now - Duration::hours(DEFAULT_LOOKBACK_HOURS)),
+    };
+    if since > now {
+        return Err("Summary start must not be in the future".into());
+    }
+    load_away_summary(&app, since, now).await
+}
+
+#[tauri::command]
+pub async fn get_away_summary_settings(
+    activity: State<'_, SharedSessionActivity>,
+) -> Result<AwaySummarySettings, String> {
+    activity.settings().await
+}
+
+#[tauri::command]
+pub async fn update_away_summary_settings(
+    settings: AwaySummarySettings,
+    activity: State<'_, SharedSessionActivity>,
+) -> Result<AwaySummarySettings, String> {
+    activity.save_settings(&settings).await?;
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(event_type: &str, event: Event) -> EventRecord {
+        EventRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            event_type: event_type.to_string(),
+            event_data: serde_json::to_string(&event).unwrap(),
+            aggregate_id: "test".to_string(),
+            sequence: 1,
+            timestamp: Utc::now().to_rfc3339(),
+        }
+    }
+
+    fn alert(at: DateTime<Utc>) -> EventRecord {
+        record(
+            "alert_triggered",
+            Event::AlertTriggered {
+                alert_id: "a1".to_string(),
+                alert_name: "SOL breakout".to_string(),
+                symbol: "SOL".to_string(),
+                price: 210.0,
+                timestamp: at,
+            },
+        )
+    }
+
+    #[test]
+    fn summary_counts_categories_in_time_order() {
+        let since = Utc::now() - Duration::hours(6);
+        let until = Utc::now();
+        let records = vec![
+            alert(since + Duration::hours(3)),
+            record(
+                "order_filled",
+                Event::OrderFilled {
+                    order_id: "o1".to_string(),
+                    fill_price: 1.5,
+                    filled_quantity: 10.0,
+                    timestamp: since + Duration::hours(1),
+                },
+            ),
+            record(
+                "setting_changed",
+                Event::SettingChanged {
+                    key: "theme".to_string(),
+                    old_value: "dark".to_string(),
+                    new_value: "light".to_string(),
+                    timestamp: since + Duration::hours(2),
+                },
+            ),
+            alert(since - Duration::hours(1)),
+        ];
+
+        let summary = build_away_summary(since, until, &records);
+        assert_eq!(summary.alerts_fired, 1);
+        assert_eq!(summary.orders_filled, 1);
+        assert_eq!(summary.timeline.len(), 2);
+        assert_eq!(summary.timeline[0].category, AwayCategory::OrderFilled);
+        assert_eq!(summary.timeline[1].reference, "a1");
+        assert_eq!(
+            summary.headline(),
+            "While you were away: 1 alert fired, 1 order filled"
+        );
+    }
+
+    #[test]
+    fn timeline_keeps_most_recent_entries_when_truncated() {
+        let since = Utc::now() - Duration::days(2);
+        let records: Vec<EventRecord> = (0..MAX_TIMELINE_ENTRIES + 10)
+            .map(|i| alert(since + Duration::minutes(i as i64)))
+            .collect();
+
+        let summary = build_away_summary(since, Utc::now(), &records);
+        assert!(summary.truncated);
+        assert_eq!(summary.alerts_fired, MAX_TIMELINE_ENTRIES + 10);
+        assert_eq!(summary.timeline.len(), MAX_TIMELINE_ENTRIES);
+        assert_eq!(summary.timeline[0].timestamp, since + Duration::minutes(10));
+    }
+
+    #[test]
+    fn watchlist_moves_need_the_threshold() {
+        assert_eq!(watchlist_move(100.0, 105.0, 10.0), None);
+        assert!(watchlist_move(100.0, 112.0, 10.0).is_some_and(|c| (c - 12.0).abs() < 1e-9));
+        assert!(watchlist_move(100.0, 85.0, 10.0).is_some_and(|c| c < 0.0));
+        assert_eq!(watchlist_move(0.0, 85.0, 10.0), None);
+        assert!(build_away_summary(Utc::now(), Utc::now(), &[]).is_empty());
+    }
+}
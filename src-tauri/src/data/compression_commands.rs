@@ -1,4 +1,8 @@
-use crate::data::database::{CompressionConfig, CompressionStats, SharedCompressionManager};
+use crate::data::database::{
+    CompressionConfig, CompressionStats, EventPartitionPolicyReport, SharedCompressionManager,
+};
+use crate::data::event_partitions::EventPartitionPolicy;
+use crate::data::event_store::SharedEventStore;
 use tauri::State;
 
 #[tauri::command]
@@ -51,6 +55,42 @@ pub async fn get_compression_config(
     Ok(manager.get_config().await)
 }
 
+#[tauri::command]
+pub async fn get_event_partition_policy(
+    compression_manager: State<'_, SharedCompressionManager>,
+) -> Result<EventPartitionPolicy, String> {
+    let manager = compression_manager.read().await;
+    manager
+        .get_event_partition_policy()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_event_partition_policy(
+    compression_manager: State<'_, SharedCompressionManager>,
+    policy: EventPartitionPolicy,
+) -> Result<(), String> {
+    let manager = compression_manager.read().await;
+    manager
+        .update_event_partition_policy(&policy)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn apply_event_partition_policy(
+    compression_manager: State<'_, SharedCompressionManager>,
+    event_store: State<'_, SharedEventStore>,
+) -> Result<EventPartitionPolicyReport, String> {
+    let partitions = event_store.read().await.partitions();
+    let manager = compression_manager.read().await;
+    manager
+        .apply_event_partition_policy(&partitions)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn decompress_data(
     compression_manager: State<'_, SharedCompressionManager>,
@@ -83,6 +123,17 @@ pub async fn get_database_size(
         total_size += metadata.len();
     }
     data_dir.pop();
+
+    // Monthly event partitions, active and compressed
+    data_dir.push("event_partitions");
+    if let Ok(entries) = fs::read_dir(&data_dir) {
+        for entry in entries.flatten() {
+            if let Ok(metadata) = entry.metadata() {
+                total_size += metadata.len();
+            }
+        }
+    }
+    data_dir.pop();
     
     // Get multisig.db size
     data_dir.push("multisig.db");
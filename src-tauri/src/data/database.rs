@@ -7,6 +7,11 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use zstd;
 
+use super::event_partitions::{
+    plan_partition_actions, EventPartitionAction, EventPartitionKey, EventPartitionPolicy,
+    EventPartitions,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompressionConfig {
     pub enabled: bool,
@@ -36,6 +41,13 @@ pub struct CompressionStats {
     pub last_compression_run: Option<String>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventPartitionPolicyReport {
+    pub compressed: Vec<String>,
+    pub deleted: Vec<String>,
+    pub space_saved_bytes: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 struct CompressedRecord {
     id: String,
@@ -136,6 +148,19 @@ impl CompressionManager {
         .execute(&self.pool)
         .await?;
 
+        // Create event_partition_policy table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS event_partition_policy (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                policy_json TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
         // Initialize config if not exists
         sqlx::query(
             r#"
@@ -477,6 +502,96 @@ impl CompressionManager {
         Ok(stats)
     }
 
+    pub async fn get_event_partition_policy(&self) -> Result<EventPartitionPolicy, sqlx::Error> {
+        let row = sqlx::query_as::<_, (String,)>(
+            "SELECT policy_json FROM event_partition_policy WHERE id = 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row
+            .and_then(|(json,)| serde_json::from_str(&json).ok())
+            .unwrap_or_default())
+    }
+
+    pub async fn update_event_partition_policy(
+        &self,
+        policy: &EventPartitionPolicy,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(delete_after) = policy.delete_after_months {
+            if policy.compress_after_months > 0 && delete_after <= policy.compress_after_months {
+                return Err("delete_after_months must be greater than compress_after_months".into());
+            }
+        }
+        for name in &policy.pinned_partitions {
+            if EventPartitionKey::parse(name).is_none() {
+                return Err(format!("invalid partition name: {}", name).into());
+            }
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO event_partition_policy (id, policy_json, updated_at)
+            VALUES (1, ?1, ?2)
+            ON CONFLICT(id) DO UPDATE SET policy_json = excluded.policy_json, updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(serde_json::to_string(policy)?)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Compresses or deletes whole event partitions according to the stored policy.
+    pub async fn apply_event_partition_policy(
+        &self,
+        partitions: &EventPartitions,
+    ) -> Result<EventPartitionPolicyReport, Box<dyn std::error::Error>> {
+        let config = self.config.read().await.clone();
+        if !config.enabled {
+            return Ok(EventPartitionPolicyReport::default());
+        }
+
+        let start_time = std::time::Instant::now();
+        let policy = self.get_event_partition_policy().await?;
+        let current = EventPartitionKey::for_time(&Utc::now());
+        let mut report = EventPartitionPolicyReport::default();
+
+        for (key, action) in plan_partition_actions(&partitions.keys(), &policy, &current) {
+            match action {
+                EventPartitionAction::Compress => {
+                    report.space_saved_bytes +=
+                        partitions.compress(&key, config.compression_level).await?;
+                    report.compressed.push(key.name());
+                }
+                EventPartitionAction::Delete => {
+                    partitions.delete(&key).await?;
+                    report.deleted.push(key.name());
+                }
+            }
+        }
+
+        if !report.compressed.is_empty() {
+            sqlx::query(
+                r#"
+                INSERT INTO compression_log (id, records_compressed, space_saved_bytes, compression_time_ms, timestamp)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                "#,
+            )
+            .bind(uuid::Uuid::new_v4().to_string())
+            .bind(report.compressed.len() as i64)
+            .bind(report.space_saved_bytes)
+            .bind(start_time.elapsed().as_millis() as i64)
+            .bind(Utc::now().to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(report)
+    }
+
     pub async fn cleanup_cache(&self) {
         let mut cache = self.decompression_cache.write().await;
         let now = Utc::now();
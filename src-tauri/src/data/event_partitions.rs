@@ -0,0 +1,426 @@
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite, SqlitePool};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::sync::RwLock;
+
+const PARTITION_FILE_PREFIX: &str = "events-";
+const PARTITION_FILE_EXT: &str = ".db";
+const ARCHIVE_FILE_EXT: &str = ".db.zst";
+/// Read-only copies of archived partitions live here while a query uses them.
+const SCRATCH_DIR: &str = "scratch";
+
+/// Calendar month an event partition covers, e.g. `2024-05`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EventPartitionKey {
+    pub year: i32,
+    pub month: u32,
+}
+
+impl EventPartitionKey {
+    pub fn for_time(time: &DateTime<Utc>) -> Self {
+        Self {
+            year: time.year(),
+            month: time.month(),
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        let (year, month) = name.split_once('-')?;
+        let key = Self {
+            year: year.parse().ok()?,
+            month: month.parse().ok()?,
+        };
+        (1..=12).contains(&key.month).then_some(key)
+    }
+
+    pub fn name(&self) -> String {
+        format!("{:04}-{:02}", self.year, self.month)
+    }
+
+    pub fn start(&self) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(self.year, self.month, 1, 0, 0, 0)
+            .single()
+            .unwrap_or_default()
+    }
+
+    pub fn end(&self) -> DateTime<Utc> {
+        self.next().start()
+    }
+
+    fn next(&self) -> Self {
+        if self.month == 12 {
+            Self {
+                year: self.year + 1,
+                month: 1,
+            }
+        } else {
+            Self {
+                year: self.year,
+                month: self.month + 1,
+            }
+        }
+    }
+
+    /// Whole months between this partition and `current`; 0 for the current month.
+    pub fn age_in_months(&self, current: &Self) -> i64 {
+        (current.year as i64 * 12 + current.month as i64)
+            - (self.year as i64 * 12 + self.month as i64)
+    }
+
+    /// Partition pruning: whether any event in this month can fall inside the range.
+    pub fn overlaps(&self, from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> bool {
+        from.map_or(true, |from| self.end() > from) && to.map_or(true, |to| self.start() <= to)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventPartitionState {
+    Active,
+    Compressed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventPartitionInfo {
+    pub partition: String,
+    pub state: EventPartitionState,
+    pub size_bytes: u64,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// Age-based policy for partition files, applied by the `CompressionManager`.
+/// The current month is never touched and pinned partitions are always kept as is.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EventPartitionPolicy {
+    pub compress_after_months: u32,
+    pub delete_after_months: Option<u32>,
+    #[serde(default)]
+    pub pinned_partitions: Vec<String>,
+}
+
+impl Default for EventPartitionPolicy {
+    fn default() -> Self {
+        Self {
+            compress_after_months: 2,
+            delete_after_months: None,
+            pinned_partitions: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventPartitionAction {
+    Compress,
+    Delete,
+}
+
+pub fn plan_partition_actions(
+    partitions: &[(EventPartitionKey, EventPartitionState)],
+    policy: &EventPartitionPolicy,
+    current: &EventPartitionKey,
+) -> Vec<(EventPartitionKey, EventPartitionAction)> {
+    partitions
+        .iter()
+        .filter(|(key, _)| !policy.pinned_partitions.contains(&key.name()))
+        .filter_map(|(key, state)| {
+            let age = key.age_in_months(current);
+            if age <= 0 {
+                return None;
+            }
+            if policy
+                .delete_after_months
+                .is_some_and(|months| age >= months as i64)
+            {
+                return Some((*key, EventPartitionAction::Delete));
+            }
+            let compress = policy.compress_after_months > 0
+                && age >= policy.compress_after_months as i64
+                && *state == EventPartitionState::Active;
+            compress.then_some((*key, EventPartitionAction::Compress))
+        })
+        .collect()
+}
+
+/// Monthly partition files under one directory. Compressed partitions stay
+/// compressed: readers that ask for them get a read-only scratch copy, and
+/// only a write unpacks one for good.
+pub struct EventPartitions {
+    dir: PathBuf,
+    pools: RwLock<HashMap<EventPartitionKey, Pool<Sqlite>>>,
+    archived: RwLock<HashMap<EventPartitionKey, Pool<Sqlite>>>,
+}
+
+impl EventPartitions {
+    pub fn new(dir: PathBuf) -> Result<Self, sqlx::Error> {
+        // Scratch copies from an earlier run are stale
+        let scratch = dir.join(SCRATCH_DIR);
+        if scratch.exists() {
+            std::fs::remove_dir_all(&scratch)?;
+        }
+        std::fs::create_dir_all(&scratch)?;
+        Ok(Self {
+            dir,
+            pools: RwLock::new(HashMap::new()),
+            archived: RwLock::new(HashMap::new()),
+        })
+    }
+
+    fn db_path(&self, key: &EventPartitionKey) -> PathBuf {
+        self.dir.join(format!(
+            "{PARTITION_FILE_PREFIX}{}{PARTITION_FILE_EXT}",
+            key.name()
+        ))
+    }
+
+    fn archive_path(&self, key: &EventPartitionKey) -> PathBuf {
+        self.dir.join(format!(
+            "{PARTITION_FILE_PREFIX}{}{ARCHIVE_FILE_EXT}",
+            key.name()
+        ))
+    }
+
+    fn scratch_path(&self, key: &EventPartitionKey) -> PathBuf {
+        self.dir.join(SCRATCH_DIR).join(format!(
+            "{PARTITION_FILE_PREFIX}{}{PARTITION_FILE_EXT}",
+            key.name()
+        ))
+    }
+
+    /// Pool for writing into a partition, creating the file on first use.
+    pub async fn writer(&self, key: &EventPartitionKey) -> Result<Pool<Sqlite>, sqlx::Error> {
+        if self.archive_path(key).exists() {
+            self.close(key).await;
+            self.decompress(key)?;
+        }
+        self.open(key).await
+    }
+
+    /// Pool for reading a partition, or `None` if it was never written, was
+    /// deleted, or is compressed and `include_archived` is off.
+    pub async fn reader(
+        &self,
+        key: &EventPartitionKey,
+        include_archived: bool,
+    ) -> Result<Option<Pool<Sqlite>>, sqlx::Error> {
+        if self.pools.read().await.contains_key(key) || self.db_path(key).exists() {
+            return self.open(key).await.map(Some);
+        }
+        if include_archived && self.archive_path(key).exists() {
+            return self.open_archived(key).await.map(Some);
+        }
+        Ok(None)
+    }
+
+    /// Opens a read-only scratch copy of a compressed partition. The archive
+    /// itself is left in place.
+    async fn open_archived(&self, key: &EventPartitionKey) -> Result<Pool<Sqlite>, sqlx::Error> {
+        if let Some(pool) = self.archived.read().await.get(key) {
+            return Ok(pool.clone());
+        }
+
+        let mut archived = self.archived.write().await;
+        if let Some(pool) = archived.get(key) {
+            return Ok(pool.clone());
+        }
+
+        let scratch = self.scratch_path(key);
+        let compressed = std::fs::read(self.archive_path(key))?;
+        std::fs::write(&scratch, zstd::decode_all(&compressed[..])?)?;
+        let pool = SqlitePool::connect(&format!("sqlite:{}?mode=ro", scratch.display())).await?;
+
+        archived.insert(*key, pool.clone());
+        Ok(pool)
+    }
+
+    async fn open(&self, key: &EventPartitionKey) -> Result<Pool<Sqlite>, sqlx::Error> {
+        if let Some(pool) = self.pools.read().await.get(key) {
+            return Ok(pool.clone());
+        }
+
+        let mut pools = self.pools.write().await;
+        if let Some(pool) = pools.get(key) {
+            return Ok(pool.clone());
+        }
+
+        let db_url = format!("sqlite:{}?mode=rwc", self.db_path(key).display());
+        let pool = SqlitePool::connect(&db_url).await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS events (
+                id TEXT PRIMARY KEY,
+                event_type TEXT NOT NULL,
+                event_data TEXT NOT NULL,
+                aggregate_id TEXT NOT NULL,
+                sequence INTEGER NOT NULL,
+                timestamp TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_events_aggregate ON events(aggregate_id, sequence);
+            CREATE INDEX IF NOT EXISTS idx_events_timestamp ON events(timestamp);
+            CREATE INDEX IF NOT EXISTS idx_events_type ON events(event_type);
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        pools.insert(*key, pool.clone());
+        Ok(pool)
+    }
+
+    async fn close(&self, key: &EventPartitionKey) {
+        if let Some(pool) = self.pools.write().await.remove(key) {
+            pool.close().await;
+        }
+        if let Some(pool) = self.archived.write().await.remove(key) {
+            pool.close().await;
+            if let Err(err) = remove_partition_files(&self.scratch_path(key)) {
+                tracing::warn!(
+                    error = %err,
+                    partition = %key.name(),
+                    "failed to remove scratch copy"
+                );
+            }
+        }
+    }
+
+    fn decompress(&self, key: &EventPartitionKey) -> Result<(), sqlx::Error> {
+        let compressed = std::fs::read(self.archive_path(key))?;
+        let data = zstd::decode_all(&compressed[..])?;
+        std::fs::write(self.db_path(key), data)?;
+        std::fs::remove_file(self.archive_path(key))?;
+        Ok(())
+    }
+
+    /// Compresses a partition file in place and returns the bytes saved.
+    pub async fn compress(&self, key: &EventPartitionKey, level: i32) -> Result<i64, sqlx::Error> {
+        self.close(key).await;
+        let db_path = self.db_path(key);
+        let data = std::fs::read(&db_path)?;
+        let compressed = zstd::encode_all(&data[..], level)?;
+        std::fs::write(self.archive_path(key), &compressed)?;
+        remove_partition_files(&db_path)?;
+        Ok(data.len() as i64 - compressed.len() as i64)
+    }
+
+    pub async fn delete(&self, key: &EventPartitionKey) -> Result<(), sqlx::Error> {
+        self.close(key).await;
+        remove_partition_files(&self.db_path(key))?;
+        let archive = self.archive_path(key);
+        if archive.exists() {
+            std::fs::remove_file(archive)?;
+        }
+        Ok(())
+    }
+
+    pub fn list(&self) -> Vec<EventPartitionInfo> {
+        let mut partitions: Vec<EventPartitionInfo> = std::fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                let (name, state) = parse_partition_file(&file_name)?;
+                let key = EventPartitionKey::parse(name)?;
+                Some(EventPartitionInfo {
+                    partition: key.name(),
+                    state,
+                    size_bytes: entry.metadata().map(|meta| meta.len()).unwrap_or(0),
+                    starts_at: key.start(),
+                    ends_at: key.end(),
+                })
+            })
+            .collect();
+        partitions.sort_by(|a, b| a.partition.cmp(&b.partition));
+        partitions
+    }
+
+    pub fn keys(&self) -> Vec<(EventPartitionKey, EventPartitionState)> {
+        self.list()
+            .into_iter()
+            .filter_map(|info| Some((EventPartitionKey::parse(&info.partition)?, info.state)))
+            .collect()
+    }
+}
+
+fn parse_partition_file(file_name: &str) -> Option<(&str, EventPartitionState)> {
+    let rest = file_name.strip_prefix(PARTITION_FILE_PREFIX)?;
+    if let Some(name) = rest.strip_suffix(ARCHIVE_FILE_EXT) {
+        return Some((name, EventPartitionState::Compressed));
+    }
+    rest.strip_suffix(PARTITION_FILE_EXT)
+        .map(|name| (name, EventPartitionState::Active))
+}
+
+/// Removes a SQLite file together with its WAL and shared-memory siblings.
+fn remove_partition_files(db_path: &Path) -> Result<(), std::io::Error> {
+    for suffix in ["", "-wal", "-shm"] {
+        let path = PathBuf::from(format!("{}{suffix}", db_path.display()));
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(year: i32, month: u32) -> EventPartitionKey {
+        EventPartitionKey { year, month }
+    }
+
+    #[test]
+    fn partition_keys_round_trip_and_span_a_month() {
+        let december = key(2024, 12);
+        assert_eq!(EventPartitionKey::parse(&december.name()), Some(december));
+        assert_eq!(EventPartitionKey::parse("2024-13"), None);
+        assert_eq!(december.end(), key(2025, 1).start());
+        assert_eq!(december.age_in_months(&key(2025, 2)), 2);
+    }
+
+    #[test]
+    fn time_ranges_prune_partitions() {
+        let may = key(2024, 5);
+        let june_first = key(2024, 6).start();
+
+        assert!(may.overlaps(None, None));
+        assert!(!may.overlaps(Some(june_first), None));
+        assert!(may.overlaps(Some(june_first - chrono::Duration::seconds(1)), None));
+        assert!(!may.overlaps(None, Some(may.start() - chrono::Duration::seconds(1))));
+    }
+
+    #[test]
+    fn policy_compresses_then_deletes_by_age() {
+        let policy = EventPartitionPolicy {
+            compress_after_months: 2,
+            delete_after_months: Some(12),
+            pinned_partitions: vec!["2023-01".to_string()],
+        };
+        let partitions = vec![
+            (key(2023, 1), EventPartitionState::Compressed),
+            (key(2023, 2), EventPartitionState::Compressed),
+            (key(2023, 11), EventPartitionState::Active),
+            (key(2023, 12), EventPartitionState::Compressed),
+            (key(2024, 1), EventPartitionState::Active),
+        ];
+
+        let actions = plan_partition_actions(&partitions, &policy, &key(2024, 2));
+        assert_eq!(
+            actions,
+            vec![
+                (key(2023, 2), EventPartitionAction::Delete),
+                (key(2023, 11), EventPartitionAction::Compress),
+            ]
+        );
+    }
+}
@@ -7,6 +7,12 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use super::audit_export::{audit_signing_keypair, sign_audit_export, SignedAuditExport};
+use super::event_partitions::{EventPartitionInfo, EventPartitionKey, EventPartitions};
+use crate::security::keystore::Keystore;
+
+const PARTITION_DIR_NAME: &str = "event_partitions";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Event {
@@ -38,6 +44,8 @@ pub enum Event {
     },
     PositionClosed {
         position_id: String,
+        #[serde(default)]
+        symbol: Option<String>,
         exit_price: f64,
         pnl: f64,
         timestamp: DateTime<Utc>,
@@ -74,6 +82,46 @@ pub enum Event {
         price: f64,
         timestamp: DateTime<Utc>,
     },
+    AlertTriggered {
+        alert_id: String,
+        alert_name: String,
+        symbol: String,
+        price: f64,
+        timestamp: DateTime<Utc>,
+    },
+    BotTradeExecuted {
+        bot_type: String,
+        bot_id: String,
+        execution_id: String,
+        input_mint: String,
+        output_mint: String,
+        input_amount: f64,
+        output_amount: f64,
+        timestamp: DateTime<Utc>,
+    },
+    RiskScoreChanged {
+        token_address: String,
+        previous_score: Option<f64>,
+        score: f64,
+        risk_level: String,
+        timestamp: DateTime<Utc>,
+    },
+    WatchlistPriceMoved {
+        symbol: String,
+        mint: String,
+        from_price: f64,
+        to_price: f64,
+        change_percent: f64,
+        timestamp: DateTime<Utc>,
+    },
+    TokenAuthorityChanged {
+        mint: String,
+        symbol: String,
+        change: String,
+        previous: Option<String>,
+        current: Option<String>,
+        timestamp: DateTime<Utc>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -103,10 +151,17 @@ pub struct EventFilter {
     pub to_time: Option<DateTime<Utc>>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    /// Also read compressed partitions. Off by default so routine queries don't
+    /// unpack archived months.
+    #[serde(default)]
+    pub include_archived: bool,
 }
 
+/// Events are written to monthly partition files; `events.db` keeps snapshots,
+/// the partition index and any events recorded before partitioning existed.
 pub struct EventStore {
     pool: Pool<Sqlite>,
+    partitions: Arc<EventPartitions>,
     sequence_counters: Arc<RwLock<HashMap<String, i64>>>,
     point_in_time_cache: Arc<RwLock<HashMap<String, (DateTime<Utc>, String)>>>,
 }
@@ -115,9 +170,14 @@ impl EventStore {
     pub async fn new(db_path: PathBuf) -> Result<Self, sqlx::Error> {
         let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
         let pool = SqlitePool::connect(&db_url).await?;
+        let partition_dir = db_path
+            .parent()
+            .map(|dir| dir.join(PARTITION_DIR_NAME))
+            .unwrap_or_else(|| PathBuf::from(PARTITION_DIR_NAME));
 
         let store = Self {
             pool,
+            partitions: Arc::new(EventPartitions::new(partition_dir)?),
             sequence_counters: Arc::new(RwLock::new(HashMap::new())),
             point_in_time_cache: Arc::new(RwLock::new(HashMap::new())),
         };
@@ -181,14 +241,35 @@ impl EventStore {
         .execute(&self.pool)
         .await?;
 
+        // Which partitions hold events for an aggregate, used to prune replays.
+        // Rows outlive deleted partitions so sequence numbers never restart.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS event_partition_index (
+                aggregate_id TEXT NOT NULL,
+                partition TEXT NOT NULL,
+                max_sequence INTEGER NOT NULL,
+                first_timestamp TEXT NOT NULL,
+                last_timestamp TEXT NOT NULL,
+                PRIMARY KEY (aggregate_id, partition)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
         Ok(())
     }
 
     async fn load_sequence_counters(&self) -> Result<(), sqlx::Error> {
         let records = sqlx::query_as::<_, (String, i64)>(
             r#"
-            SELECT aggregate_id, MAX(sequence) as max_sequence
-            FROM events
+            SELECT aggregate_id, MAX(max_sequence) as max_sequence
+            FROM (
+                SELECT aggregate_id, MAX(sequence) as max_sequence FROM events GROUP BY aggregate_id
+                UNION ALL
+                SELECT aggregate_id, max_sequence FROM event_partition_index
+            )
             GROUP BY aggregate_id
             "#,
         )
@@ -219,7 +300,10 @@ impl EventStore {
         let event_type = self.get_event_type(&event);
         let event_data = serde_json::to_string(&event)?;
         let sequence = self.get_next_sequence(aggregate_id).await;
-        let timestamp = Utc::now().to_rfc3339();
+        let now = Utc::now();
+        let timestamp = now.to_rfc3339();
+        let partition = EventPartitionKey::for_time(&now);
+        let partition_pool = self.partitions.writer(&partition).await?;
 
         sqlx::query(
             r#"
@@ -233,6 +317,22 @@ impl EventStore {
         .bind(aggregate_id)
         .bind(sequence)
         .bind(&timestamp)
+        .execute(&partition_pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO event_partition_index (aggregate_id, partition, max_sequence, first_timestamp, last_timestamp)
+            VALUES (?1, ?2, ?3, ?4, ?4)
+            ON CONFLICT(aggregate_id, partition) DO UPDATE SET
+                max_sequence = MAX(max_sequence, excluded.max_sequence),
+                last_timestamp = excluded.last_timestamp
+            "#,
+        )
+        .bind(aggregate_id)
+        .bind(partition.name())
+        .bind(sequence)
+        .bind(&timestamp)
         .execute(&self.pool)
         .await?;
 
@@ -258,10 +358,63 @@ impl EventStore {
             Event::WalletConnected { .. } => "wallet_connected",
             Event::WalletDisconnected { .. } => "wallet_disconnected",
             Event::TradeExecuted { .. } => "trade_executed",
+            Event::AlertTriggered { .. } => "alert_triggered",
+            Event::BotTradeExecuted { .. } => "bot_trade_executed",
+            Event::RiskScoreChanged { .. } => "risk_score_changed",
+            Event::WatchlistPriceMoved { .. } => "watchlist_price_moved",
+            Event::TokenAuthorityChanged { .. } => "token_authority_changed",
         }
         .to_string()
     }
 
+    pub fn partitions(&self) -> Arc<EventPartitions> {
+        Arc::clone(&self.partitions)
+    }
+
+    /// Pools that can hold matching events: the legacy table plus every partition
+    /// that survives pruning by aggregate and time range. Compressed partitions
+    /// are only included when `include_archived` is set.
+    async fn query_pools(
+        &self,
+        aggregate_id: Option<&str>,
+        from_time: Option<DateTime<Utc>>,
+        to_time: Option<DateTime<Utc>>,
+        include_archived: bool,
+    ) -> Result<Vec<Pool<Sqlite>>, sqlx::Error> {
+        let keys: Vec<EventPartitionKey> = match aggregate_id {
+            Some(aggregate_id) => sqlx::query_as::<_, (String,)>(
+                "SELECT partition FROM event_partition_index WHERE aggregate_id = ?1",
+            )
+            .bind(aggregate_id)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .filter_map(|(name,)| EventPartitionKey::parse(&name))
+            .collect(),
+            None => self.partitions.keys().into_iter().map(|(key, _)| key).collect(),
+        };
+
+        self.open_partitions(
+            keys.into_iter().filter(|key| key.overlaps(from_time, to_time)),
+            include_archived,
+        )
+        .await
+    }
+
+    async fn open_partitions(
+        &self,
+        keys: impl Iterator<Item = EventPartitionKey>,
+        include_archived: bool,
+    ) -> Result<Vec<Pool<Sqlite>>, sqlx::Error> {
+        let mut pools = vec![self.pool.clone()];
+        for key in keys {
+            if let Some(pool) = self.partitions.reader(&key, include_archived).await? {
+                pools.push(pool);
+            }
+        }
+        Ok(pools)
+    }
+
     pub async fn get_events(
         &self,
         filter: EventFilter,
@@ -288,29 +441,53 @@ impl EventStore {
 
         query.push_str(" ORDER BY sequence ASC");
 
+        // Each partition returns enough rows to cover the page; the offset is
+        // applied after merging.
+        let offset = filter.offset.unwrap_or(0).max(0);
         if let Some(limit) = filter.limit {
-            query.push_str(&format!(" LIMIT {}", limit));
-        }
-        if let Some(offset) = filter.offset {
-            query.push_str(&format!(" OFFSET {}", offset));
+            query.push_str(&format!(" LIMIT {}", limit + offset));
         }
 
-        let mut sql_query = sqlx::query_as::<_, EventRecord>(&query);
+        let pools = self
+            .query_pools(
+                filter.aggregate_id.as_deref(),
+                filter.from_time,
+                filter.to_time,
+                filter.include_archived,
+            )
+            .await?;
 
-        if let Some(ref aggregate_id) = filter.aggregate_id {
-            sql_query = sql_query.bind(aggregate_id);
-        }
-        if let Some(ref event_type) = filter.event_type {
-            sql_query = sql_query.bind(event_type);
-        }
-        if let Some(from_time) = filter.from_time {
-            sql_query = sql_query.bind(from_time.to_rfc3339());
-        }
-        if let Some(to_time) = filter.to_time {
-            sql_query = sql_query.bind(to_time.to_rfc3339());
+        let mut records = Vec::new();
+        for pool in &pools {
+            let mut sql_query = sqlx::query_as::<_, EventRecord>(&query);
+
+            if let Some(ref aggregate_id) = filter.aggregate_id {
+                sql_query = sql_query.bind(aggregate_id);
+            }
+            if let Some(ref event_type) = filter.event_type {
+                sql_query = sql_query.bind(event_type);
+            }
+            if let Some(from_time) = filter.from_time {
+                sql_query = sql_query.bind(from_time.to_rfc3339());
+            }
+            if let Some(to_time) = filter.to_time {
+                sql_query = sql_query.bind(to_time.to_rfc3339());
+            }
+
+            records.extend(sql_query.fetch_all(pool).await?);
         }
 
-        sql_query.fetch_all(&self.pool).await
+        records.sort_by(|a, b| {
+            a.sequence
+                .cmp(&b.sequence)
+                .then_with(|| a.timestamp.cmp(&b.timestamp))
+        });
+
+        let records = records.into_iter().skip(offset as usize);
+        Ok(match filter.limit {
+            Some(limit) => records.take(limit.max(0) as usize).collect(),
+            None => records.collect(),
+        })
     }
 
     pub async fn replay_events(
@@ -324,6 +501,8 @@ impl EventStore {
             to_time: None,
             limit: None,
             offset: None,
+            // Replays need the aggregate's full history
+            include_archived: true,
         };
 
         let records = self.get_events(filter).await?;
@@ -375,11 +554,12 @@ impl EventStore {
         // Load events after snapshot
         let from_sequence = snapshot_opt.as_ref().map(|s| s.sequence).unwrap_or(0);
 
-        let records = sqlx::query_as::<_, EventRecord>(
+        // Only partitions with events after the snapshot and before the
+        // requested time need to be opened.
+        let partitions = sqlx::query_as::<_, (String,)>(
             r#"
-            SELECT * FROM events
-            WHERE aggregate_id = ?1 AND sequence > ?2 AND timestamp <= ?3
-            ORDER BY sequence ASC
+            SELECT partition FROM event_partition_index
+            WHERE aggregate_id = ?1 AND max_sequence > ?2 AND first_timestamp <= ?3
             "#,
         )
         .bind(aggregate_id)
@@ -387,6 +567,34 @@ impl EventStore {
         .bind(timestamp.to_rfc3339())
         .fetch_all(&self.pool)
         .await?;
+        // Reconstructing state needs every event after the snapshot, archived or not
+        let pools = self
+            .open_partitions(
+                partitions
+                    .into_iter()
+                    .filter_map(|(name,)| EventPartitionKey::parse(&name)),
+                true,
+            )
+            .await?;
+
+        let mut records = Vec::new();
+        for pool in &pools {
+            records.extend(
+                sqlx::query_as::<_, EventRecord>(
+                    r#"
+                    SELECT * FROM events
+                    WHERE aggregate_id = ?1 AND sequence > ?2 AND timestamp <= ?3
+                    ORDER BY sequence ASC
+                    "#,
+                )
+                .bind(aggregate_id)
+                .bind(from_sequence)
+                .bind(timestamp.to_rfc3339())
+                .fetch_all(pool)
+                .await?,
+            );
+        }
+        records.sort_by_key(|record| record.sequence);
 
         for record in records {
             let event: Event = serde_json::from_str(&record.event_data)?;
@@ -531,29 +739,99 @@ impl EventStore {
             Event::TradeExecuted { trade_id, from_token, to_token, from_amount, to_amount, price, .. } => {
                 format!("Trade {} executed: {} {} -> {} {} at {}", trade_id, from_amount, from_token, to_amount, to_token, price)
             }
+            Event::AlertTriggered { alert_name, symbol, price, .. } => {
+                format!("Alert '{}' triggered for {} at {}", alert_name, symbol, price)
+            }
+            Event::BotTradeExecuted { bot_type, bot_id, input_amount, input_mint, output_amount, output_mint, .. } => {
+                format!("{} bot {} traded {} {} -> {} {}", bot_type, bot_id, input_amount, input_mint, output_amount, output_mint)
+            }
+            Event::RiskScoreChanged { token_address, previous_score, score, risk_level, .. } => {
+                format!(
+                    "Risk score for {} changed: {} -> {} ({})",
+                    token_address,
+                    previous_score.map(|s| s.to_string()).unwrap_or_else(|| "none".to_string()),
+                    score,
+                    risk_level
+                )
+            }
+            Event::WatchlistPriceMoved { symbol, from_price, to_price, change_percent, .. } => {
+                format!("{} moved {:.1}%: {} -> {}", symbol, change_percent, from_price, to_price)
+            }
+            Event::TokenAuthorityChanged { symbol, change, previous, current, .. } => {
+                format!(
+                    "{} {}: {} -> {}",
+                    symbol,
+                    change.replace('_', " "),
+                    previous.as_deref().unwrap_or("none"),
+                    current.as_deref().unwrap_or("none")
+                )
+            }
         }
     }
 
     pub async fn get_event_count(&self, aggregate_id: Option<&str>) -> Result<i64, sqlx::Error> {
-        if let Some(aggregate_id) = aggregate_id {
-            let (count,) = sqlx::query_as::<_, (i64,)>(
-                "SELECT COUNT(*) FROM events WHERE aggregate_id = ?1",
+        let mut total = 0;
+        for pool in &self.query_pools(aggregate_id, None, None, false).await? {
+            let (count,) = if let Some(aggregate_id) = aggregate_id {
+                sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events WHERE aggregate_id = ?1")
+                    .bind(aggregate_id)
+                    .fetch_one(pool)
+                    .await?
+            } else {
+                sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events")
+                    .fetch_one(pool)
+                    .await?
+            };
+            total += count;
+        }
+        Ok(total)
+    }
+
+    pub async fn get_event_type_counts(&self) -> Result<HashMap<String, i64>, sqlx::Error> {
+        let mut type_counts: HashMap<String, i64> = HashMap::new();
+        for pool in &self.query_pools(None, None, None, false).await? {
+            let counts = sqlx::query_as::<_, (String, i64)>(
+                "SELECT event_type, COUNT(*) as count FROM events GROUP BY event_type",
             )
-            .bind(aggregate_id)
-            .fetch_one(&self.pool)
+            .fetch_all(pool)
             .await?;
-            Ok(count)
-        } else {
-            let (count,) = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events")
-                .fetch_one(&self.pool)
-                .await?;
-            Ok(count)
+            for (event_type, count) in counts {
+                *type_counts.entry(event_type).or_insert(0) += count;
+            }
         }
+        Ok(type_counts)
+    }
+
+    pub async fn get_event_count_since(&self, since: DateTime<Utc>) -> Result<i64, sqlx::Error> {
+        let mut total = 0;
+        for pool in &self.query_pools(None, Some(since), None, false).await? {
+            let (count,) =
+                sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM events WHERE timestamp >= ?1")
+                    .bind(since.to_rfc3339())
+                    .fetch_one(pool)
+                    .await?;
+            total += count;
+        }
+        Ok(total)
     }
 }
 
 pub type SharedEventStore = Arc<RwLock<EventStore>>;
 
+/// Appends an event for subsystems that only hold an app handle. Failures are
+/// logged, never returned, so recording can't break the caller.
+pub async fn record_app_event(app: &tauri::AppHandle, aggregate_id: &str, event: Event) {
+    use tauri::Manager;
+
+    let Some(store) = app.try_state::<SharedEventStore>() else {
+        return;
+    };
+    let result = store.read().await.publish_event(event, aggregate_id).await;
+    if let Err(err) = result {
+        tracing::warn!(aggregate_id, error = %err, "failed to record event");
+    }
+}
+
 // Tauri commands
 #[tauri::command]
 pub async fn get_events_command(
@@ -564,6 +842,7 @@ pub async fn get_events_command(
     to_time: Option<String>,
     limit: Option<i64>,
     offset: Option<i64>,
+    include_archived: Option<bool>,
 ) -> Result<Vec<EventRecord>, String> {
     let from_time = if let Some(time_str) = from_time {
         Some(
@@ -592,6 +871,7 @@ pub async fn get_events_command(
         to_time,
         limit,
         offset,
+        include_archived: include_archived.unwrap_or(false),
     };
 
     let store = event_store.read().await;
@@ -627,15 +907,25 @@ pub async fn get_state_at_time_command(
         .map_err(|e| e.to_string())
 }
 
+/// `json` and `csv` come back as text; `jsonl` comes back signed, with a
+/// manifest for third-party verification.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum AuditTrailExport {
+    Text(String),
+    Signed(SignedAuditExport),
+}
+
 #[tauri::command]
 pub async fn export_audit_trail_command(
     event_store: tauri::State<'_, SharedEventStore>,
+    keystore: tauri::State<'_, Keystore>,
     aggregate_id: Option<String>,
     event_type: Option<String>,
     from_time: Option<String>,
     to_time: Option<String>,
     format: String,
-) -> Result<String, String> {
+) -> Result<AuditTrailExport, String> {
     let from_time = if let Some(time_str) = from_time {
         Some(
             DateTime::parse_from_rfc3339(&time_str)
@@ -663,12 +953,21 @@ pub async fn export_audit_trail_command(
         to_time,
         limit: None,
         offset: None,
+        // An audit export has to cover archived months too
+        include_archived: true,
     };
 
     let store = event_store.read().await;
+    if format == "jsonl" {
+        let keypair = audit_signing_keypair(&keystore)?;
+        let records = store.get_events(filter.clone()).await.map_err(|e| e.to_string())?;
+        return sign_audit_export(&records, &filter, &keypair).map(AuditTrailExport::Signed);
+    }
+
     store
         .export_events(filter, &format)
         .await
+        .map(AuditTrailExport::Text)
         .map_err(|e| e.to_string())
 }
 
@@ -692,37 +991,14 @@ pub async fn get_event_stats(
     let store = event_store.read().await;
     let total_count = store.get_event_count(None).await.map_err(|e| e.to_string())?;
 
-    // Get event type counts
-    let event_type_counts = sqlx::query_as::<_, (String, i64)>(
-        r#"
-        SELECT event_type, COUNT(*) as count
-        FROM events
-        GROUP BY event_type
-        ORDER BY count DESC
-        "#,
-    )
-    .fetch_all(&store.pool)
-    .await
-    .map_err(|e| e.to_string())?;
-
-    let mut type_counts: HashMap<String, i64> = HashMap::new();
-    for (event_type, count) in event_type_counts {
-        type_counts.insert(event_type, count);
-    }
+    let type_counts = store.get_event_type_counts().await.map_err(|e| e.to_string())?;
 
     // Get recent events count (last 24 hours)
     let yesterday = Utc::now() - chrono::Duration::hours(24);
-    let recent_count = sqlx::query_as::<_, (i64,)>(
-        r#"
-        SELECT COUNT(*) FROM events
-        WHERE timestamp >= ?1
-        "#,
-    )
-    .bind(yesterday.to_rfc3339())
-    .fetch_one(&store.pool)
-    .await
-    .map_err(|e| e.to_string())?
-    .0;
+    let recent_count = store
+        .get_event_count_since(yesterday)
+        .await
+        .map_err(|e| e.to_string())?;
 
     Ok(serde_json::json!({
         "total_events": total_count,
@@ -730,3 +1006,11 @@ pub async fn get_event_stats(
         "event_type_counts": type_counts,
     }))
 }
+
+#[tauri::command]
+pub async fn list_event_partitions(
+    event_store: tauri::State<'_, SharedEventStore>,
+) -> Result<Vec<EventPartitionInfo>, String> {
+    let store = event_store.read().await;
+    Ok(store.partitions().list())
+}
@@ -34,6 +34,17 @@ impl HistoricalDataFetcher {
         &self,
         request: FetchRequest,
     ) -> Result<HistoricalDataSet, Box<dyn std::error::Error>> {
+        // Synthetic candles are never cached, so they can't outlive demo mode
+        if crate::demo::is_demo_mode() {
+            let data = self.generate_mock_data(&request);
+            return Ok(HistoricalDataSet {
+                symbol: request.symbol,
+                interval: request.interval,
+                data,
+                fetched_at: Utc::now(),
+            });
+        }
+
         // Check if data already exists in cache
         let has_data = self
             .storage
@@ -65,18 +76,17 @@ impl HistoricalDataFetcher {
             });
         }
 
-        // Fetch from API or generate mock data
-        let data = if let Some(ref api_key) = self.api_key {
-            match self.fetch_from_birdeye(&request, api_key).await {
-                Ok(data) => data,
-                Err(e) => {
-                    eprintln!("Birdeye API error: {}, falling back to mock data", e);
-                    self.generate_mock_data(&request)
-                }
-            }
-        } else {
-            self.generate_mock_data(&request)
-        };
+        let api_key = self
+            .api_key
+            .as_deref()
+            .ok_or("UNAVAILABLE: historical data requires a Birdeye API key")?;
+        let data = self
+            .fetch_from_birdeye(&request, api_key)
+            .await
+            .map_err(|e| {
+                tracing::warn!(symbol = %request.symbol, error = %e, "Birdeye OHLCV fetch failed");
+                e
+            })?;
 
         // Store in cache
         self.storage
@@ -219,15 +229,9 @@ impl HistoricalDataFetcher {
             return Ok(snapshots);
         }
 
-        // Generate mock orderbook snapshots
-        let snapshots = self.generate_mock_orderbooks(symbol, start_time, end_time);
-
-        // Store in cache
-        for snapshot in &snapshots {
-            self.storage.store_orderbook_snapshot(snapshot).await?;
-        }
-
-        Ok(snapshots)
+        // There is no order book history provider; only demo mode has data
+        crate::demo::require_demo_mode("Order book history")?;
+        Ok(self.generate_mock_orderbooks(symbol, start_time, end_time))
     }
 
     fn generate_mock_orderbooks(
@@ -118,6 +118,19 @@ impl HistoricalReplayManager {
         Ok(compute_hold_counterfactual(request, &data))
     }
 
+    pub async fn get_price_data(
+        &self,
+        symbol: &str,
+        interval: &str,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<HistoricalDataPoint>, String> {
+        self.storage
+            .get_price_data(symbol, interval, start_time, end_time)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
     pub async fn get_cache_stats(
         &self,
         symbol: &str,
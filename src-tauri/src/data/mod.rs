@@ -1,9 +1,17 @@
 pub mod event_store;
+pub mod event_partitions;
 pub mod database;
 pub mod compression_commands;
 pub mod historical;
+pub mod privacy;
+pub mod away_summary;
+pub mod audit_export;
 
 pub use event_store::*;
+pub use event_partitions::*;
 pub use database::*;
 pub use compression_commands::*;
 pub use historical::*;
+pub use privacy::*;
+pub use away_summary::*;
+pub use audit_export::*;
@@ -0,0 +1,651 @@
+use base64::{engine::general_purpose::STANDARD as BASE64_ENGINE, Engine};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Column, Connection, Row, SqliteConnection, TypeInfo, ValueRef};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, State};
+
+use crate::security::activity_log::{ActivityAction, ActivityLogError, ActivityLogger};
+use crate::security::keystore::{Keystore, KeystoreBackup};
+
+const EXPORT_DIR: &str = "exports";
+const KEYSTORE_FILE: &str = "keystore.json";
+const ACTIVITY_DB_FILE: &str = "activity_logs.db";
+const ARCHIVE_FORMAT_VERSION: u32 = 1;
+const AUDIT_SUBJECT: &str = "local_user";
+pub const WIPE_CONFIRMATION_PHRASE: &str = "WIPE MY DATA";
+const MIN_SECRETS_PASSWORD_LEN: usize = 12;
+const REDACTED: &str = "[redacted]";
+/// Field names holding credentials outside the keystore, such as chat
+/// integration bot tokens and webhook URLs. Compared lowercase without `_`/`-`.
+const SECRET_FIELDS: &[&str] = &[
+    "bottoken",
+    "webhookurl",
+    "apikey",
+    "apisecret",
+    "secret",
+    "password",
+    "accesstoken",
+    "refreshtoken",
+    "authtoken",
+    "clientsecret",
+    "privatekey",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum PrivacyError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("activity log error: {0}")]
+    ActivityLog(#[from] ActivityLogError),
+    #[error("keystore error: {0}")]
+    Keystore(String),
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DataWipeScope {
+    SocialCache,
+    ActivityLogs,
+    TradingHistory,
+}
+
+/// Which rows of a database file a scope deletes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WipeTables {
+    All,
+    /// `(table, condition)` pairs; a `None` condition deletes every row.
+    Only(&'static [(&'static str, Option<&'static str>)]),
+}
+
+/// `orders.db` also holds live limit orders, automation rules, trade ideas and
+/// exit ladders, so only finished orders and past executions count as history.
+const ORDER_HISTORY_TABLES: &[(&str, Option<&str>)] = &[
+    ("orders", Some("status IN ('filled', 'cancelled', 'expired', 'failed')")),
+    ("alert_automation_executions", None),
+];
+
+impl DataWipeScope {
+    /// Database files holding the scope's data, relative to the app data directory.
+    pub fn database_files(&self) -> &'static [(&'static str, WipeTables)] {
+        match self {
+            DataWipeScope::SocialCache => &[("social_intel.db", WipeTables::All)],
+            DataWipeScope::ActivityLogs => &[(ACTIVITY_DB_FILE, WipeTables::All)],
+            DataWipeScope::TradingHistory => &[
+                ("orders.db", WipeTables::Only(ORDER_HISTORY_TABLES)),
+                ("paper_trading.db", WipeTables::All),
+                ("performance.db", WipeTables::All),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFileKind {
+    Database,
+    Settings,
+    Keystore,
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTableExport {
+    pub name: String,
+    pub row_count: usize,
+    pub rows: Vec<Map<String, Value>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDatabaseExport {
+    pub file: String,
+    pub tables: Vec<UserTableExport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettingsExport {
+    pub file: String,
+    pub content: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserFileEntry {
+    pub file: String,
+    pub size_bytes: u64,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserKeystoreExport {
+    /// Names of stored secrets; values are never written in plain text.
+    pub key_names: Vec<String>,
+    /// Every secret sealed with the export password, when one was supplied.
+    pub encrypted_secrets: Option<KeystoreBackup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDataArchive {
+    pub format_version: u32,
+    pub app_version: String,
+    pub generated_at: DateTime<Utc>,
+    pub databases: Vec<UserDatabaseExport>,
+    pub settings: Vec<UserSettingsExport>,
+    /// Files that are neither databases nor settings, listed without their contents.
+    pub other_files: Vec<UserFileEntry>,
+    pub keystore: UserKeystoreExport,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDataExportSummary {
+    pub path: String,
+    pub size_bytes: u64,
+    pub database_count: usize,
+    pub table_count: usize,
+    pub row_count: usize,
+    pub secrets_included: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScopeWipeResult {
+    pub scope: DataWipeScope,
+    pub files: Vec<String>,
+    pub rows_deleted: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataWipeReport {
+    pub results: Vec<ScopeWipeResult>,
+    pub completed_at: DateTime<Utc>,
+}
+
+pub fn classify_data_file(relative: &str) -> DataFileKind {
+    let name = Path::new(relative)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(relative);
+
+    if name == KEYSTORE_FILE {
+        DataFileKind::Keystore
+    } else if name.ends_with(".db") || name.ends_with(".sqlite") {
+        DataFileKind::Database
+    } else if name.ends_with(".json") {
+        DataFileKind::Settings
+    } else {
+        DataFileKind::Other
+    }
+}
+
+pub fn validate_wipe_request(scopes: &[DataWipeScope], confirmation: &str) -> Result<(), PrivacyError> {
+    if scopes.is_empty() {
+        return Err(PrivacyError::InvalidRequest("Select at least one dataset to wipe".to_string()));
+    }
+    if confirmation.trim() != WIPE_CONFIRMATION_PHRASE {
+        return Err(PrivacyError::InvalidRequest(format!(
+            "Type \"{}\" to confirm",
+            WIPE_CONFIRMATION_PHRASE
+        )));
+    }
+    Ok(())
+}
+
+fn is_sqlite_sidecar(name: &str) -> bool {
+    name.ends_with("-wal") || name.ends_with("-shm") || name.ends_with("-journal")
+}
+
+fn app_data_dir(app: &AppHandle) -> Result<PathBuf, PrivacyError> {
+    app.path_resolver().app_data_dir().ok_or_else(|| {
+        PrivacyError::Io(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "App data directory not found",
+        ))
+    })
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), PrivacyError> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            // Earlier exports are excluded so archives don't nest
+            if path == root.join(EXPORT_DIR) {
+                continue;
+            }
+            collect_files(root, &path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn relative_name(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn column_value(row: &SqliteRow, index: usize) -> Result<Value, PrivacyError> {
+    let raw = row.try_get_raw(index)?;
+    if raw.is_null() {
+        return Ok(Value::Null);
+    }
+
+    let type_name = raw.type_info().name().to_string();
+    let value = match type_name.as_str() {
+        "INTEGER" | "BOOLEAN" => json!(row.try_get::<i64, _>(index)?),
+        "REAL" => json!(row.try_get::<f64, _>(index)?),
+        "BLOB" => json!(BASE64_ENGINE.encode(row.try_get::<Vec<u8>, _>(index)?)),
+        _ => json!(row.try_get::<String, _>(index)?),
+    };
+    Ok(value)
+}
+
+async fn table_names(conn: &mut SqliteConnection) -> Result<Vec<String>, PrivacyError> {
+    let names = sqlx::query_scalar(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+    Ok(names)
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn is_secret_field(name: &str) -> bool {
+    let normalized: String = name
+        .chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .collect::<String>()
+        .to_lowercase();
+    SECRET_FIELDS.contains(&normalized.as_str())
+}
+
+/// Blanks credentials anywhere in `value`, including JSON stored as text such
+/// as `chat_integrations.config_data`.
+fn redact_secrets(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if is_secret_field(key) && !field.is_null() {
+                    *field = json!(REDACTED);
+                } else {
+                    redact_secrets(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_secrets),
+        Value::String(text) if text.starts_with('{') || text.starts_with('[') => {
+            if let Ok(mut nested) = serde_json::from_str::<Value>(text) {
+                let original = nested.clone();
+                redact_secrets(&mut nested);
+                if nested != original {
+                    *text = nested.to_string();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+async fn export_database(path: &Path, file: String) -> Result<UserDatabaseExport, PrivacyError> {
+    let url = format!("sqlite:{}?mode=ro", path.display());
+    let mut conn = SqliteConnection::connect(&url).await?;
+    let mut tables = Vec::new();
+
+    for name in table_names(&mut conn).await? {
+        let rows = sqlx::query(&format!("SELECT * FROM {}", quote_identifier(&name)))
+            .fetch_all(&mut conn)
+            .await?;
+
+        let mut exported = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let mut object = Map::new();
+            for (index, column) in row.columns().iter().enumerate() {
+                let mut value = column_value(row, index)?;
+                if is_secret_field(column.name()) && !value.is_null() {
+                    value = json!(REDACTED);
+                } else {
+                    redact_secrets(&mut value);
+                }
+                object.insert(column.name().to_string(), value);
+            }
+            exported.push(object);
+        }
+
+        tables.push(UserTableExport {
+            name,
+            row_count: exported.len(),
+            rows: exported,
+        });
+    }
+
+    conn.close().await?;
+    Ok(UserDatabaseExport { file, tables })
+}
+
+async fn open_read_write(path: &Path) -> Result<SqliteConnection, PrivacyError> {
+    let url = format!("sqlite:{}?mode=rw", path.display());
+    Ok(SqliteConnection::connect(&url).await?)
+}
+
+async fn vacuum_database(path: &Path) -> Result<(), PrivacyError> {
+    let mut conn = open_read_write(path).await?;
+    sqlx::query("VACUUM").execute(&mut conn).await?;
+    conn.close().await?;
+    Ok(())
+}
+
+fn delete_statement(table: &str, condition: Option<&str>) -> String {
+    match condition {
+        Some(condition) => format!("DELETE FROM {} WHERE {}", quote_identifier(table), condition),
+        None => format!("DELETE FROM {}", quote_identifier(table)),
+    }
+}
+
+/// Deletes the scope's rows with `secure_delete` on, then vacuums so freed
+/// pages leave the file.
+async fn secure_wipe_database(path: &Path, tables: WipeTables) -> Result<u64, PrivacyError> {
+    let mut conn = open_read_write(path).await?;
+    sqlx::query("PRAGMA secure_delete = ON").execute(&mut conn).await?;
+
+    let existing = table_names(&mut conn).await?;
+    let statements: Vec<String> = match tables {
+        WipeTables::All => existing
+            .iter()
+            .map(|name| delete_statement(name, None))
+            .collect(),
+        WipeTables::Only(targets) => targets
+            .iter()
+            .filter(|(table, _)| existing.iter().any(|name| name == table))
+            .map(|(table, condition)| delete_statement(table, *condition))
+            .collect(),
+    };
+
+    let mut deleted = 0;
+    for statement in statements {
+        deleted += sqlx::query(&statement)
+            .execute(&mut conn)
+            .await?
+            .rows_affected();
+    }
+
+    sqlx::query("VACUUM").execute(&mut conn).await?;
+    conn.close().await?;
+    Ok(deleted)
+}
+
+fn file_entry(path: &Path, file: String) -> Result<UserFileEntry, PrivacyError> {
+    let data = fs::read(path)?;
+    let mut hasher = Sha256::new();
+    hasher.update(&data);
+    Ok(UserFileEntry {
+        file,
+        size_bytes: data.len() as u64,
+        sha256: format!("{:x}", hasher.finalize()),
+    })
+}
+
+pub async fn build_user_data_archive(
+    app: &AppHandle,
+    keystore: &Keystore,
+    secrets_password: Option<&str>,
+) -> Result<UserDataArchive, PrivacyError> {
+    let root = app_data_dir(app)?;
+    let mut files = Vec::new();
+    if root.exists() {
+        collect_files(&root, &root, &mut files)?;
+    }
+    files.sort();
+
+    let mut archive = UserDataArchive {
+        format_version: ARCHIVE_FORMAT_VERSION,
+        app_version: app.package_info().version.to_string(),
+        generated_at: Utc::now(),
+        databases: Vec::new(),
+        settings: Vec::new(),
+        other_files: Vec::new(),
+        keystore: UserKeystoreExport {
+            key_names: keystore
+                .list_keys()
+                .map_err(|e| PrivacyError::Keystore(e.to_string()))?,
+            encrypted_secrets: None,
+        },
+    };
+
+    for path in files {
+        let file = relative_name(&root, &path);
+        if is_sqlite_sidecar(&file) {
+            continue;
+        }
+
+        match classify_data_file(&file) {
+            // Secrets only leave through the password-sealed export below
+            DataFileKind::Keystore => {}
+            DataFileKind::Database => archive.databases.push(export_database(&path, file).await?),
+            DataFileKind::Settings => match serde_json::from_slice(&fs::read(&path)?) {
+                Ok(mut content) => {
+                    redact_secrets(&mut content);
+                    archive.settings.push(UserSettingsExport { file, content })
+                }
+                Err(_) => archive.other_files.push(file_entry(&path, file)?),
+            },
+            DataFileKind::Other => archive.other_files.push(file_entry(&path, file)?),
+        }
+    }
+
+    if let Some(password) = secrets_password {
+        archive.keystore.encrypted_secrets = Some(
+            keystore
+                .export_backup(password)
+                .map_err(|e| PrivacyError::Keystore(e.to_string()))?,
+        );
+    }
+
+    Ok(archive)
+}
+
+pub async fn wipe_scopes(
+    app: &AppHandle,
+    logger: &ActivityLogger,
+    scopes: &[DataWipeScope],
+) -> Result<Vec<ScopeWipeResult>, PrivacyError> {
+    let root = app_data_dir(app)?;
+    let mut results = Vec::new();
+
+    for scope in scopes {
+        let mut result = ScopeWipeResult {
+            scope: *scope,
+            files: Vec::new(),
+            rows_deleted: 0,
+        };
+
+        for (file, tables) in scope.database_files() {
+            let path = root.join(file);
+            if !path.exists() {
+                continue;
+            }
+
+            if *file == ACTIVITY_DB_FILE {
+                // Go through the logger so its hash chain stays verifiable
+                result.rows_deleted += logger.clear_all_logs().await?;
+                vacuum_database(&path).await?;
+            } else {
+                result.rows_deleted += secure_wipe_database(&path, *tables).await?;
+            }
+            result.files.push(file.to_string());
+        }
+
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn export_all_user_data(
+    destination_dir: Option<String>,
+    secrets_password: Option<String>,
+    app_handle: AppHandle,
+    keystore: State<'_, Keystore>,
+    logger: State<'_, ActivityLogger>,
+) -> Result<UserDataExportSummary, String> {
+    if let Some(password) = &secrets_password {
+        if password.len() < MIN_SECRETS_PASSWORD_LEN {
+            return Err(format!(
+                "Secrets password must be at least {} characters",
+                MIN_SECRETS_PASSWORD_LEN
+            ));
+        }
+    }
+
+    let archive = build_user_data_archive(&app_handle, &keystore, secrets_password.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let dir = match destination_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => app_data_dir(&app_handle)
+            .map_err(|e| e.to_string())?
+            .join(EXPORT_DIR),
+    };
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let path = dir.join(format!(
+        "eclipse_data_export_{}.json",
+        archive.generated_at.format("%Y%m%d_%H%M%S")
+    ));
+    let data = serde_json::to_vec_pretty(&archive).map_err(|e| e.to_string())?;
+    fs::write(&path, &data).map_err(|e| e.to_string())?;
+
+    let summary = UserDataExportSummary {
+        path: path.to_string_lossy().to_string(),
+        size_bytes: data.len() as u64,
+        database_count: archive.databases.len(),
+        table_count: archive.databases.iter().map(|db| db.tables.len()).sum(),
+        row_count: archive
+            .databases
+            .iter()
+            .flat_map(|db| db.tables.iter())
+            .map(|table| table.row_count)
+            .sum(),
+        secrets_included: archive.keystore.encrypted_secrets.is_some(),
+    };
+
+    if let Err(err) = logger
+        .log_activity(AUDIT_SUBJECT, ActivityAction::DataExport, &summary, true, None)
+        .await
+    {
+        tracing::warn!(error = %err, "failed to audit data export");
+    }
+
+    Ok(summary)
+}
+
+#[tauri::command]
+pub async fn wipe_data(
+    scopes: Vec<DataWipeScope>,
+    confirmation: String,
+    app_handle: AppHandle,
+    logger: State<'_, ActivityLogger>,
+) -> Result<DataWipeReport, String> {
+    validate_wipe_request(&scopes, &confirmation).map_err(|e| e.to_string())?;
+
+    let outcome = wipe_scopes(&app_handle, &logger, &scopes).await;
+
+    // Logged after the wipe so the record survives an activity log wipe
+    let details = match &outcome {
+        Ok(results) => json!({ "scopes": scopes, "results": results }),
+        Err(err) => json!({ "scopes": scopes, "error": err.to_string() }),
+    };
+    if let Err(err) = logger
+        .log_activity(AUDIT_SUBJECT, ActivityAction::DataWipe, details, outcome.is_ok(), None)
+        .await
+    {
+        tracing::warn!(error = %err, "failed to audit data wipe");
+    }
+
+    Ok(DataWipeReport {
+        results: outcome.map_err(|e| e.to_string())?,
+        completed_at: Utc::now(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_data_files() {
+        assert_eq!(classify_data_file("keystore.json"), DataFileKind::Keystore);
+        assert_eq!(classify_data_file("orders.db"), DataFileKind::Database);
+        assert_eq!(classify_data_file("indicators/rsi.json"), DataFileKind::Settings);
+        assert_eq!(classify_data_file("backup_config.enc"), DataFileKind::Other);
+        assert!(is_sqlite_sidecar("orders.db-wal"));
+    }
+
+    #[test]
+    fn wipe_requires_scopes_and_confirmation() {
+        assert!(validate_wipe_request(&[], WIPE_CONFIRMATION_PHRASE).is_err());
+        assert!(validate_wipe_request(&[DataWipeScope::SocialCache], "yes").is_err());
+        assert!(validate_wipe_request(&[DataWipeScope::SocialCache], WIPE_CONFIRMATION_PHRASE).is_ok());
+    }
+
+    #[test]
+    fn quotes_table_identifiers() {
+        assert_eq!(quote_identifier("trades"), "\"trades\"");
+        assert_eq!(quote_identifier("we\"ird"), "\"we\"\"ird\"");
+        assert_eq!(
+            delete_statement("orders", Some("status = 'filled'")),
+            "DELETE FROM \"orders\" WHERE status = 'filled'"
+        );
+    }
+
+    #[test]
+    fn trading_history_keeps_live_order_data() {
+        let (file, tables) = DataWipeScope::TradingHistory.database_files()[0];
+        assert_eq!(file, "orders.db");
+        let WipeTables::Only(targets) = tables else {
+            panic!("orders.db must not be wiped whole");
+        };
+        let names: Vec<&str> = targets.iter().map(|(table, _)| *table).collect();
+        for live in ["alert_automation_rules", "trade_ideas", "exit_ladders"] {
+            assert!(!names.contains(&live));
+        }
+        let (_, condition) = targets[0];
+        assert!(!condition.unwrap().contains("pending"));
+    }
+
+    #[test]
+    fn redacts_chat_credentials_in_stored_json() {
+        let config = json!({ "id": "t1", "botToken": "123:abc", "chatId": "42" }).to_string();
+        let mut row = json!({ "service_type": "telegram", "config_data": config, "api_key": "k" });
+        redact_secrets(&mut row);
+
+        let stored: Value = serde_json::from_str(row["config_data"].as_str().unwrap()).unwrap();
+        assert_eq!(stored["botToken"], REDACTED);
+        assert_eq!(stored["chatId"], "42");
+        assert_eq!(row["api_key"], REDACTED);
+        assert!(is_secret_field("webhook_url") && !is_secret_field("token_address"));
+    }
+}
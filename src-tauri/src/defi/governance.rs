@@ -161,6 +161,8 @@ impl GovernanceHub {
 
 #[tauri::command]
 pub async fn get_governance_proposals() -> Result<Vec<GovernanceProposal>, String> {
+    // Proposals are demo fixtures until a live governance source exists
+    crate::demo::require_demo_mode("Governance proposals")?;
     Ok(GovernanceHub::new().list_proposals().await)
 }
 
@@ -171,6 +173,7 @@ pub async fn vote_on_proposal(
     choice: VoteChoice,
     voting_power: f64,
 ) -> Result<GovernanceProposal, String> {
+    crate::demo::require_demo_mode("Governance voting")?;
     GovernanceHub::new()
         .submit_vote(&proposal_id, &wallet, choice, voting_power)
         .await
@@ -178,5 +181,6 @@ pub async fn vote_on_proposal(
 
 #[tauri::command]
 pub async fn get_governance_participation(proposal_id: String) -> Result<f64, String> {
+    crate::demo::require_demo_mode("Governance participation")?;
     Ok(GovernanceHub::new().get_participation_rate(&proposal_id).await)
 }
@@ -35,11 +35,14 @@ impl KaminoAdapter {
         Self
     }
 
+    // No live Kamino source yet; vaults and positions are demo fixtures
     pub async fn get_vaults(&self) -> Result<Vec<KaminoVault>, String> {
+        crate::demo::require_demo_mode("Kamino vault data")?;
         Ok(self.generate_mock_vaults())
     }
 
     pub async fn get_user_positions(&self, wallet: &str) -> Result<Vec<DeFiPosition>, String> {
+        crate::demo::require_demo_mode("Kamino position data")?;
         let positions = self.generate_mock_user_positions(wallet);
         Ok(positions)
     }
@@ -0,0 +1,880 @@
+use crate::defi::marginfi::MarginfiAdapter;
+use crate::defi::solend::SolendAdapter;
+use crate::defi::types::{Protocol, RiskLevel};
+use crate::notifications::router::SharedNotificationRouter;
+use crate::portfolio::SharedPortfolioData;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+const LENDING_MONITOR_DB_FILE: &str = "lending_monitor.db";
+const REFRESH_INTERVAL_SECS: u64 = 120;
+const LENDING_RISK_EVENT: &str = "lending_risk_alert";
+
+const KAMINO_OBLIGATIONS_URL: &str =
+    "https://api.kamino.finance/kamino-market/7u3HeHxYDLhnCoErrtycNokbQYbWGzLs6JSDqGAv5PfF/users/{wallet}/obligations";
+
+#[derive(Debug, thiserror::Error)]
+pub enum LendingMonitorError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("no position endpoint configured for {0:?}")]
+    NoEndpoint(Protocol),
+    #[error("account not found: {0}")]
+    NotFound(String),
+    #[error("invalid configuration: {0}")]
+    Invalid(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackedLendingAccount {
+    pub id: String,
+    pub protocol: Protocol,
+    pub wallet: String,
+    pub label: Option<String>,
+    /// Endpoint returning the wallet's obligations; `{wallet}` is substituted. Falls back to
+    /// the built-in endpoint where the protocol has one.
+    pub api_url: Option<String>,
+    /// Per-account overrides of the global alert thresholds.
+    pub warning_health: Option<f64>,
+    pub critical_health: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackLendingAccountRequest {
+    pub id: Option<String>,
+    pub protocol: Protocol,
+    pub wallet: String,
+    pub label: Option<String>,
+    pub api_url: Option<String>,
+    pub warning_health: Option<f64>,
+    pub critical_health: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LendingAsset {
+    pub symbol: String,
+    pub amount: f64,
+    pub value_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LendingPosition {
+    pub account_id: String,
+    pub protocol: Protocol,
+    pub wallet: String,
+    pub obligation: Option<String>,
+    pub collateral: Vec<LendingAsset>,
+    pub debt: Vec<LendingAsset>,
+    pub collateral_usd: f64,
+    pub debt_usd: f64,
+    /// Debt value at which the obligation becomes liquidatable.
+    pub liquidation_limit_usd: Option<f64>,
+    /// `None` when there is no debt.
+    pub health_factor: Option<f64>,
+    pub risk: RiskLevel,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LendingAlertSettings {
+    pub enabled: bool,
+    pub warning_health: f64,
+    pub critical_health: f64,
+    /// Minimum time between repeat alerts at the same level.
+    pub repeat_after_minutes: i64,
+}
+
+impl Default for LendingAlertSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            warning_health: 1.5,
+            critical_health: 1.15,
+            repeat_after_minutes: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LendingExposure {
+    pub positions: Vec<LendingPosition>,
+    pub total_collateral_usd: f64,
+    pub total_debt_usd: f64,
+    pub net_exposure_usd: f64,
+    pub lowest_health_factor: Option<f64>,
+    pub errors: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LendingRiskAlert {
+    pub position: LendingPosition,
+    pub threshold: f64,
+}
+
+#[derive(Debug, Clone)]
+struct AlertState {
+    level: RiskLevel,
+    sent_at: DateTime<Utc>,
+}
+
+fn number_in(row: &Value, keys: &[&str]) -> Option<f64> {
+    // Some APIs nest the aggregate figures under a stats object.
+    let scopes = [Some(row), row.get("refreshedStats"), row.get("stats")];
+    scopes.iter().flatten().find_map(|scope| {
+        keys.iter().find_map(|key| match scope.get(*key)? {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.parse().ok(),
+            _ => None,
+        })
+    })
+}
+
+fn parse_assets(row: &Value, keys: &[&str]) -> Vec<LendingAsset> {
+    keys.iter()
+        .find_map(|key| row.get(*key).and_then(|v| v.as_array()))
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| {
+                    Some(LendingAsset {
+                        symbol: ["symbol", "token", "mint"]
+                            .iter()
+                            .find_map(|k| item.get(*k).and_then(|v| v.as_str()))?
+                            .to_string(),
+                        amount: number_in(item, &["amount", "quantity"]).unwrap_or(0.0),
+                        value_usd: number_in(item, &["valueUsd", "marketValue", "value_usd", "usdValue"])
+                            .unwrap_or(0.0),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn health_factor(debt_usd: f64, liquidation_limit_usd: Option<f64>) -> Option<f64> {
+    if debt_usd <= 0.0 {
+        return None;
+    }
+    liquidation_limit_usd.map(|limit| limit / debt_usd)
+}
+
+pub fn classify_health(health: Option<f64>, warning: f64, critical: f64) -> RiskLevel {
+    match health {
+        None => RiskLevel::Low,
+        Some(h) if h <= 1.0 => RiskLevel::Critical,
+        Some(h) if h <= critical => RiskLevel::High,
+        Some(h) if h <= warning => RiskLevel::Medium,
+        Some(_) => RiskLevel::Low,
+    }
+}
+
+/// Reads obligations from a protocol or indexer response: a bare array, a `data` wrapper,
+/// or a single object.
+pub fn parse_obligations(
+    account: &TrackedLendingAccount,
+    body: &Value,
+    settings: &LendingAlertSettings,
+) -> Vec<LendingPosition> {
+    let rows: Vec<Value> = match body {
+        Value::Array(items) => items.clone(),
+        Value::Object(_) => match body.get("data") {
+            Some(Value::Array(items)) => items.clone(),
+            Some(obj @ Value::Object(_)) => vec![obj.clone()],
+            _ => vec![body.clone()],
+        },
+        _ => Vec::new(),
+    };
+    let (warning, critical) = thresholds(account, settings);
+
+    rows.iter()
+        .map(|row| {
+            let collateral = parse_assets(row, &["deposits", "collateral", "assets"]);
+            let debt = parse_assets(row, &["borrows", "debt", "liabilities"]);
+            let collateral_usd = number_in(row, &["depositedValue", "userTotalDeposit", "collateralUsd"])
+                .unwrap_or_else(|| collateral.iter().map(|a| a.value_usd).sum());
+            let debt_usd = number_in(row, &["borrowedValue", "userTotalBorrow", "debtUsd"])
+                .unwrap_or_else(|| debt.iter().map(|a| a.value_usd).sum());
+            let liquidation_limit_usd = number_in(
+                row,
+                &["borrowLiquidationLimit", "liquidationLimitUsd", "unhealthyBorrowValue"],
+            );
+            let health = number_in(row, &["healthFactor", "health_factor"])
+                .filter(|_| debt_usd > 0.0)
+                .or_else(|| health_factor(debt_usd, liquidation_limit_usd));
+
+            LendingPosition {
+                account_id: account.id.clone(),
+                protocol: account.protocol.clone(),
+                wallet: account.wallet.clone(),
+                obligation: ["obligationAddress", "address", "pubkey"]
+                    .iter()
+                    .find_map(|k| row.get(*k).and_then(|v| v.as_str()))
+                    .map(|s| s.to_string()),
+                collateral,
+                debt,
+                collateral_usd,
+                debt_usd,
+                liquidation_limit_usd,
+                health_factor: health,
+                risk: classify_health(health, warning, critical),
+                updated_at: Utc::now(),
+            }
+        })
+        .collect()
+}
+
+fn thresholds(account: &TrackedLendingAccount, settings: &LendingAlertSettings) -> (f64, f64) {
+    (
+        account.warning_health.unwrap_or(settings.warning_health),
+        account.critical_health.unwrap_or(settings.critical_health),
+    )
+}
+
+pub fn summarize_exposure(positions: Vec<LendingPosition>, errors: HashMap<String, String>) -> LendingExposure {
+    let total_collateral_usd: f64 = positions.iter().map(|p| p.collateral_usd).sum();
+    let total_debt_usd: f64 = positions.iter().map(|p| p.debt_usd).sum();
+    let lowest_health_factor = positions
+        .iter()
+        .filter_map(|p| p.health_factor)
+        .fold(None, |lowest: Option<f64>, h| Some(lowest.map_or(h, |l| l.min(h))));
+
+    LendingExposure {
+        positions,
+        total_collateral_usd,
+        total_debt_usd,
+        net_exposure_usd: total_collateral_usd - total_debt_usd,
+        lowest_health_factor,
+        errors,
+    }
+}
+
+fn risk_rank(level: &RiskLevel) -> u8 {
+    match level {
+        RiskLevel::Low => 0,
+        RiskLevel::Medium => 1,
+        RiskLevel::High => 2,
+        RiskLevel::Critical => 3,
+    }
+}
+
+fn protocol_str(protocol: &Protocol) -> String {
+    serde_json::to_value(protocol)
+        .ok()
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default()
+}
+
+pub struct LendingMonitor {
+    pool: Pool<Sqlite>,
+    http: reqwest::Client,
+    latest: HashMap<String, Vec<LendingPosition>>,
+    alert_state: HashMap<String, AlertState>,
+}
+
+pub type SharedLendingMonitor = Arc<RwLock<LendingMonitor>>;
+
+impl LendingMonitor {
+    pub async fn new(app: &AppHandle) -> Result<Self, LendingMonitorError> {
+        let db_path = lending_monitor_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let monitor = Self {
+            pool,
+            http: reqwest::Client::new(),
+            latest: HashMap::new(),
+            alert_state: HashMap::new(),
+        };
+        monitor.initialize().await?;
+        Ok(monitor)
+    }
+
+    async fn initialize(&self) -> Result<(), LendingMonitorError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS lending_accounts (
+                id TEXT PRIMARY KEY,
+                protocol TEXT NOT NULL,
+                wallet TEXT NOT NULL,
+                label TEXT,
+                api_url TEXT,
+                warning_health REAL,
+                critical_health REAL,
+                created_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS lending_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                account_id TEXT NOT NULL,
+                captured_at TEXT NOT NULL,
+                collateral_usd REAL NOT NULL,
+                debt_usd REAL NOT NULL,
+                health_factor REAL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS lending_alert_settings (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                settings TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    fn row_to_account(row: &SqliteRow) -> Result<TrackedLendingAccount, LendingMonitorError> {
+        let protocol: String = row.try_get("protocol")?;
+        let created_at: String = row.try_get("created_at")?;
+
+        Ok(TrackedLendingAccount {
+            id: row.try_get("id")?,
+            protocol: serde_json::from_value(Value::String(protocol))?,
+            wallet: row.try_get("wallet")?,
+            label: row.try_get("label")?,
+            api_url: row.try_get("api_url")?,
+            warning_health: row.try_get("warning_health")?,
+            critical_health: row.try_get("critical_health")?,
+            created_at: DateTime::parse_from_rfc3339(&created_at)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| LendingMonitorError::Internal(e.to_string()))?,
+        })
+    }
+
+    pub async fn list_accounts(&self) -> Result<Vec<TrackedLendingAccount>, LendingMonitorError> {
+        let rows = sqlx::query("SELECT * FROM lending_accounts ORDER BY created_at")
+            .fetch_all(&self.pool)
+            .await?;
+        rows.iter().map(Self::row_to_account).collect()
+    }
+
+    pub async fn track_account(
+        &self,
+        request: TrackLendingAccountRequest,
+    ) -> Result<TrackedLendingAccount, LendingMonitorError> {
+        if let Some(url) = &request.api_url {
+            if !url.starts_with("https://") {
+                return Err(LendingMonitorError::Invalid("endpoint must use https".to_string()));
+            }
+        }
+        if let (Some(warning), Some(critical)) = (request.warning_health, request.critical_health) {
+            if critical >= warning {
+                return Err(LendingMonitorError::Invalid(
+                    "critical threshold must be below the warning threshold".to_string(),
+                ));
+            }
+        }
+
+        let account = TrackedLendingAccount {
+            id: request.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+            protocol: request.protocol,
+            wallet: request.wallet,
+            label: request.label,
+            api_url: request.api_url,
+            warning_health: request.warning_health,
+            critical_health: request.critical_health,
+            created_at: Utc::now(),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO lending_accounts (
+                id, protocol, wallet, label, api_url, warning_health, critical_health, created_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                protocol = excluded.protocol,
+                wallet = excluded.wallet,
+                label = excluded.label,
+                api_url = excluded.api_url,
+                warning_health = excluded.warning_health,
+                critical_health = excluded.critical_health
+            "#,
+        )
+        .bind(&account.id)
+        .bind(protocol_str(&account.protocol))
+        .bind(&account.wallet)
+        .bind(&account.label)
+        .bind(&account.api_url)
+        .bind(account.warning_health)
+        .bind(account.critical_health)
+        .bind(account.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(account)
+    }
+
+    pub async fn untrack_account(&mut self, id: &str) -> Result<(), LendingMonitorError> {
+        let result = sqlx::query("DELETE FROM lending_accounts WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(LendingMonitorError::NotFound(id.to_string()));
+        }
+
+        sqlx::query("DELETE FROM lending_snapshots WHERE account_id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        self.latest.remove(id);
+        self.alert_state.remove(id);
+        Ok(())
+    }
+
+    pub async fn alert_settings(&self) -> Result<LendingAlertSettings, LendingMonitorError> {
+        let row = sqlx::query("SELECT settings FROM lending_alert_settings WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match row {
+            Some(row) => {
+                let settings: String = row.try_get("settings")?;
+                Ok(serde_json::from_str(&settings)?)
+            }
+            None => Ok(LendingAlertSettings::default()),
+        }
+    }
+
+    pub async fn set_alert_settings(&self, settings: &LendingAlertSettings) -> Result<(), LendingMonitorError> {
+        if settings.critical_health >= settings.warning_health || settings.critical_health < 1.0 {
+            return Err(LendingMonitorError::Invalid(
+                "critical threshold must be at least 1.0 and below the warning threshold".to_string(),
+            ));
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO lending_alert_settings (id, settings) VALUES (1, ?)
+            ON CONFLICT(id) DO UPDATE SET settings = excluded.settings
+            "#,
+        )
+        .bind(serde_json::to_string(settings)?)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Demo fixtures come from the existing mock adapters so the dashboards stay consistent.
+    async fn demo_positions(
+        &self,
+        account: &TrackedLendingAccount,
+        settings: &LendingAlertSettings,
+    ) -> Result<Vec<LendingPosition>, LendingMonitorError> {
+        let body = match account.protocol {
+            Protocol::Solend => {
+                let obligation = SolendAdapter::new()
+                    .fetch_obligation(&account.wallet)
+                    .await
+                    .map_err(LendingMonitorError::Internal)?;
+                serde_json::to_value(obligation.into_iter().collect::<Vec<_>>())?
+            }
+            Protocol::MarginFi => {
+                let account_data = MarginfiAdapter::new()
+                    .get_account(&account.wallet)
+                    .await
+                    .map_err(LendingMonitorError::Internal)?;
+                serde_json::to_value(account_data.into_iter().collect::<Vec<_>>())?
+            }
+            _ => Value::Array(Vec::new()),
+        };
+        Ok(parse_obligations(account, &body, settings))
+    }
+
+    async fn fetch_positions(
+        &self,
+        account: &TrackedLendingAccount,
+        settings: &LendingAlertSettings,
+    ) -> Result<Vec<LendingPosition>, LendingMonitorError> {
+        if crate::demo::is_demo_mode() {
+            return self.demo_positions(account, settings).await;
+        }
+
+        let template = match (&account.api_url, &account.protocol) {
+            (Some(url), _) => url.as_str(),
+            (None, Protocol::Kamino) => KAMINO_OBLIGATIONS_URL,
+            (None, protocol) => return Err(LendingMonitorError::NoEndpoint(protocol.clone())),
+        };
+        let url = template.replace("{wallet}", &account.wallet);
+
+        let body: Value = self
+            .http
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(parse_obligations(account, &body, settings))
+    }
+
+    async fn record_snapshot(&self, position: &LendingPosition) -> Result<(), LendingMonitorError> {
+        sqlx::query(
+            r#"
+            INSERT INTO lending_snapshots (account_id, captured_at, collateral_usd, debt_usd, health_factor)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&position.account_id)
+        .bind(position.updated_at.to_rfc3339())
+        .bind(position.collateral_usd)
+        .bind(position.debt_usd)
+        .bind(position.health_factor)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Refreshes every tracked account. Accounts that fail keep their last known positions.
+    pub async fn refresh(&mut self) -> Result<LendingExposure, LendingMonitorError> {
+        let settings = self.alert_settings().await?;
+        let mut errors = HashMap::new();
+
+        for account in self.list_accounts().await? {
+            match self.fetch_positions(&account, &settings).await {
+                Ok(positions) => {
+                    for position in &positions {
+                        self.record_snapshot(position).await?;
+                    }
+                    self.latest.insert(account.id.clone(), positions);
+                }
+                Err(err) => {
+                    tracing::warn!(error = %err, account = %account.id, "lending position refresh failed");
+                    errors.insert(account.id.clone(), err.to_string());
+                }
+            }
+        }
+
+        Ok(self.exposure(errors))
+    }
+
+    pub fn exposure(&self, errors: HashMap<String, String>) -> LendingExposure {
+        summarize_exposure(self.latest.values().flatten().cloned().collect(), errors)
+    }
+
+    /// Positions that crossed into (or stayed in) a risky band and are due an alert.
+    pub async fn take_alerts(&mut self) -> Result<Vec<LendingRiskAlert>, LendingMonitorError> {
+        let settings = self.alert_settings().await?;
+        if !settings.enabled {
+            return Ok(Vec::new());
+        }
+
+        let accounts: HashMap<String, TrackedLendingAccount> = self
+            .list_accounts()
+            .await?
+            .into_iter()
+            .map(|a| (a.id.clone(), a))
+            .collect();
+        let now = Utc::now();
+        let mut alerts = Vec::new();
+
+        for position in self.latest.values().flatten() {
+            let key = format!(
+                "{}:{}",
+                position.account_id,
+                position.obligation.as_deref().unwrap_or_default()
+            );
+            if risk_rank(&position.risk) == 0 {
+                self.alert_state.remove(&key);
+                continue;
+            }
+
+            let due = match self.alert_state.get(&key) {
+                None => true,
+                Some(state) => {
+                    risk_rank(&position.risk) > risk_rank(&state.level)
+                        || now - state.sent_at >= Duration::minutes(settings.repeat_after_minutes)
+                }
+            };
+            if !due {
+                continue;
+            }
+
+            let (warning, critical) = accounts
+                .get(&position.account_id)
+                .map(|a| thresholds(a, &settings))
+                .unwrap_or((settings.warning_health, settings.critical_health));
+            self.alert_state.insert(
+                key,
+                AlertState {
+                    level: position.risk.clone(),
+                    sent_at: now,
+                },
+            );
+            alerts.push(LendingRiskAlert {
+                position: position.clone(),
+                threshold: if position.risk == RiskLevel::Medium { warning } else { critical },
+            });
+        }
+
+        Ok(alerts)
+    }
+}
+
+fn lending_monitor_db_path(app: &AppHandle) -> Result<PathBuf, LendingMonitorError> {
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| LendingMonitorError::Internal("Unable to resolve app data directory".to_string()))?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(LENDING_MONITOR_DB_FILE))
+}
+
+fn publish_exposure(app: &AppHandle, exposure: &LendingExposure) {
+    if let Some(data) = app.try_state::<SharedPortfolioData>() {
+        match data.lock() {
+            Ok(mut guard) => {
+                guard.set_lending_exposure(exposure.total_collateral_usd, exposure.total_debt_usd)
+            }
+            Err(_) => tracing::warn!("portfolio data locked; skipping lending exposure update"),
+        }
+    }
+}
+
+async fn send_lending_alert(app: &AppHandle, alert: &LendingRiskAlert) {
+    if let Err(err) = app.emit_all(LENDING_RISK_EVENT, alert) {
+        tracing::warn!(error = %err, "failed to emit lending risk alert");
+    }
+
+    let Some(router) = app.try_state::<SharedNotificationRouter>() else {
+        return;
+    };
+
+    let position = &alert.position;
+    let title = match position.risk {
+        RiskLevel::Critical => "Liquidation imminent",
+        RiskLevel::High => "Lending position at risk",
+        _ => "Lending health dropping",
+    };
+    let message = format!(
+        "{:?} position for {} has health factor {:.2} (threshold {:.2}). Collateral ${:.0}, debt ${:.0}.",
+        position.protocol,
+        position.wallet,
+        position.health_factor.unwrap_or(0.0),
+        alert.threshold,
+        position.collateral_usd,
+        position.debt_usd,
+    );
+
+    let router = router.read().await;
+    let result = if position.risk == RiskLevel::Critical {
+        router
+            .send_critical_notification(&position.account_id, title, &message)
+            .await
+    } else {
+        router
+            .send_broadcast_notification(&position.account_id, title, &message)
+            .await
+    };
+    if let Err(err) = result {
+        tracing::warn!(error = %err, "failed to deliver lending risk notification");
+    }
+}
+
+async fn refresh_and_alert(app: &AppHandle, monitor: &SharedLendingMonitor) -> Result<LendingExposure, String> {
+    let (exposure, alerts) = {
+        let mut monitor = monitor.write().await;
+        let exposure = monitor.refresh().await.map_err(|e| e.to_string())?;
+        let alerts = monitor.take_alerts().await.map_err(|e| e.to_string())?;
+        (exposure, alerts)
+    };
+
+    publish_exposure(app, &exposure);
+    for alert in &alerts {
+        send_lending_alert(app, alert).await;
+    }
+    Ok(exposure)
+}
+
+pub fn start_lending_monitor(app: AppHandle, monitor: SharedLendingMonitor) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(REFRESH_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            if let Err(err) = refresh_and_alert(&app, &monitor).await {
+                tracing::warn!(error = %err, "lending monitor cycle failed");
+            }
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn get_lending_exposure(
+    refresh: Option<bool>,
+    app_handle: AppHandle,
+    monitor: State<'_, SharedLendingMonitor>,
+) -> Result<LendingExposure, String> {
+    if refresh.unwrap_or(false) {
+        return refresh_and_alert(&app_handle, monitor.inner()).await;
+    }
+    Ok(monitor.read().await.exposure(HashMap::new()))
+}
+
+#[tauri::command]
+pub async fn list_lending_accounts(
+    monitor: State<'_, SharedLendingMonitor>,
+) -> Result<Vec<TrackedLendingAccount>, String> {
+    monitor.read().await.list_accounts().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn track_lending_account(
+    request: TrackLendingAccountRequest,
+    monitor: State<'_, SharedLendingMonitor>,
+) -> Result<TrackedLendingAccount, String> {
+    monitor.read().await.track_account(request).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn untrack_lending_account(
+    id: String,
+    app_handle: AppHandle,
+    monitor: State<'_, SharedLendingMonitor>,
+) -> Result<(), String> {
+    let exposure = {
+        let mut monitor = monitor.write().await;
+        monitor.untrack_account(&id).await.map_err(|e| e.to_string())?;
+        monitor.exposure(HashMap::new())
+    };
+    publish_exposure(&app_handle, &exposure);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_lending_alert_settings(
+    monitor: State<'_, SharedLendingMonitor>,
+) -> Result<LendingAlertSettings, String> {
+    monitor.read().await.alert_settings().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_lending_alert_settings(
+    settings: LendingAlertSettings,
+    monitor: State<'_, SharedLendingMonitor>,
+) -> Result<LendingAlertSettings, String> {
+    monitor
+        .read()
+        .await
+        .set_alert_settings(&settings)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn account(warning: Option<f64>) -> TrackedLendingAccount {
+        TrackedLendingAccount {
+            id: "acct".to_string(),
+            protocol: Protocol::Kamino,
+            wallet: "wallet".to_string(),
+            label: None,
+            api_url: None,
+            warning_health: warning,
+            critical_health: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn derives_health_from_liquidation_limit() {
+        let body = json!([{
+            "obligationAddress": "obl",
+            "refreshedStats": {
+                "userTotalDeposit": 10000.0,
+                "userTotalBorrow": 5000.0,
+                "borrowLiquidationLimit": "6000"
+            }
+        }]);
+
+        let positions = parse_obligations(&account(None), &body, &LendingAlertSettings::default());
+        assert_eq!(positions.len(), 1);
+        let position = &positions[0];
+        assert_eq!(position.obligation.as_deref(), Some("obl"));
+        assert!((position.health_factor.unwrap() - 1.2).abs() < 1e-9);
+        assert_eq!(position.risk, RiskLevel::Medium);
+    }
+
+    #[test]
+    fn reads_mock_adapter_shapes_and_per_account_thresholds() {
+        let body = json!([{
+            "deposits": [{"symbol": "SOL", "amount": 10.0, "valueUsd": 1500.0}],
+            "borrows": [{"symbol": "USDC", "amount": 500.0, "valueUsd": 500.0}],
+            "healthFactor": 1.8
+        }]);
+
+        let positions = parse_obligations(&account(Some(2.0)), &body, &LendingAlertSettings::default());
+        assert_eq!(positions[0].collateral_usd, 1500.0);
+        assert_eq!(positions[0].debt_usd, 500.0);
+        assert_eq!(positions[0].risk, RiskLevel::Medium);
+    }
+
+    #[test]
+    fn classifies_health_bands() {
+        assert_eq!(classify_health(None, 1.5, 1.15), RiskLevel::Low);
+        assert_eq!(classify_health(Some(2.0), 1.5, 1.15), RiskLevel::Low);
+        assert_eq!(classify_health(Some(1.4), 1.5, 1.15), RiskLevel::Medium);
+        assert_eq!(classify_health(Some(1.1), 1.5, 1.15), RiskLevel::High);
+        assert_eq!(classify_health(Some(0.98), 1.5, 1.15), RiskLevel::Critical);
+    }
+
+    #[test]
+    fn summarizes_net_exposure_and_lowest_health() {
+        let mut a = parse_obligations(
+            &account(None),
+            &json!({"depositedValue": 1000.0, "borrowedValue": 400.0, "healthFactor": 1.9}),
+            &LendingAlertSettings::default(),
+        );
+        a.extend(parse_obligations(
+            &account(None),
+            &json!({"depositedValue": 500.0, "borrowedValue": 0.0}),
+            &LendingAlertSettings::default(),
+        ));
+
+        let exposure = summarize_exposure(a, HashMap::new());
+        assert_eq!(exposure.net_exposure_usd, 1100.0);
+        assert_eq!(exposure.lowest_health_factor, Some(1.9));
+    }
+}
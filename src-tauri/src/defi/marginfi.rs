@@ -43,11 +43,14 @@ impl MarginfiAdapter {
         Self
     }
 
+    // No live MarginFi source yet; banks and accounts are demo fixtures
     pub async fn get_banks(&self) -> Result<Vec<MarginfiBank>, String> {
+        crate::demo::require_demo_mode("MarginFi bank data")?;
         Ok(self.generate_mock_banks())
     }
 
     pub async fn get_account(&self, wallet: &str) -> Result<Option<MarginfiAccount>, String> {
+        crate::demo::require_demo_mode("MarginFi account data")?;
         Ok(Some(self.generate_mock_account(wallet)))
     }
 
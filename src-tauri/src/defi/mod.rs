@@ -1,19 +1,27 @@
 pub mod auto_compound;
 pub mod governance;
 pub mod kamino;
+pub mod lending_monitor;
 pub mod marginfi;
+pub mod pools;
 pub mod position_manager;
 pub mod solend;
 pub mod staking;
 pub mod types;
+pub mod validators;
 pub mod yield_farming;
+pub mod yield_scanner;
 
 pub use auto_compound::*;
 pub use governance::*;
 pub use kamino::*;
+pub use lending_monitor::*;
 pub use marginfi::*;
+pub use pools::*;
 pub use position_manager::*;
 pub use solend::*;
 pub use staking::*;
 pub use types::*;
+pub use validators::*;
 pub use yield_farming::*;
+pub use yield_scanner::*;
@@ -0,0 +1,758 @@
+use crate::defi::types::{Protocol, YieldFarm};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+use tokio::sync::RwLock;
+
+const POOL_ANALYTICS_DB_FILE: &str = "pool_analytics.db";
+const SNAPSHOT_INTERVAL_SECS: u64 = 900;
+const HISTORY_RETENTION_DAYS: i64 = 90;
+
+const RAYDIUM_POOL_URL: &str = "https://api-v3.raydium.io/pools/info/ids?ids=";
+const ORCA_POOL_URL: &str = "https://api.orca.so/v2/solana/pools/";
+const METEORA_POOL_URL: &str = "https://dlmm-api.meteora.ai/pair/";
+
+#[derive(Debug, thiserror::Error)]
+pub enum PoolAnalyticsError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("unsupported protocol for pool tracking")]
+    UnsupportedProtocol,
+    #[error("unexpected pool response: {0}")]
+    InvalidResponse(String),
+    #[error("pool not tracked: {0}")]
+    NotFound(String),
+    #[error("invalid range: {0}")]
+    InvalidRange(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PoolKind {
+    /// Constant-product pool, liquidity over the full price range.
+    Standard,
+    /// CLMM, whirlpool or DLMM: liquidity is placed in a price range.
+    Concentrated,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackedPool {
+    pub address: String,
+    pub protocol: Protocol,
+    pub kind: PoolKind,
+    pub name: String,
+    pub token_a: String,
+    pub token_b: String,
+    pub fee_rate: Option<f64>,
+    /// The user's LP range, for concentrated pools.
+    pub range_lower: Option<f64>,
+    pub range_upper: Option<f64>,
+    pub added_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackPoolRequest {
+    pub address: String,
+    pub protocol: Protocol,
+    pub range_lower: Option<f64>,
+    pub range_upper: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolSnapshot {
+    pub captured_at: DateTime<Utc>,
+    pub tvl: f64,
+    pub volume_24h: f64,
+    pub fees_24h: f64,
+    pub fee_apr: f64,
+    pub price: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RangeUtilization {
+    pub lower: f64,
+    pub upper: f64,
+    pub current_in_range: bool,
+    /// Where the current price sits in the range, 0 at the lower bound and 1 at the upper.
+    pub position_in_range: f64,
+    /// Share of recorded snapshots with the price inside the range.
+    pub time_in_range_percent: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolAnalytics {
+    pub pool: TrackedPool,
+    pub latest: Option<PoolSnapshot>,
+    pub history: Vec<PoolSnapshot>,
+    pub average_fee_apr: Option<f64>,
+    pub tvl_change_percent: Option<f64>,
+    pub range: Option<RangeUtilization>,
+}
+
+/// Pool metadata plus a point-in-time reading, as parsed from a venue API.
+#[derive(Debug, Clone)]
+struct PoolReading {
+    kind: PoolKind,
+    name: String,
+    token_a: String,
+    token_b: String,
+    fee_rate: Option<f64>,
+    snapshot: PoolSnapshot,
+}
+
+/// Reads a number that venue APIs return either as JSON number or as a string.
+fn number_at(value: &Value, path: &[&str]) -> Option<f64> {
+    let mut current = value;
+    for key in path {
+        current = current.get(*key)?;
+    }
+    match current {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn string_at(value: &Value, path: &[&str]) -> Option<String> {
+    let mut current = value;
+    for key in path {
+        current = current.get(*key)?;
+    }
+    current.as_str().map(|s| s.to_string())
+}
+
+pub fn fee_apr(fees_24h: f64, tvl: f64) -> f64 {
+    if tvl <= 0.0 {
+        return 0.0;
+    }
+    fees_24h * 365.0 / tvl * 100.0
+}
+
+fn build_snapshot(tvl: f64, volume_24h: f64, fees_24h: Option<f64>, fee_rate: Option<f64>, price: f64) -> PoolSnapshot {
+    let fees_24h = fees_24h.unwrap_or_else(|| volume_24h * fee_rate.unwrap_or(0.0));
+    PoolSnapshot {
+        captured_at: Utc::now(),
+        tvl,
+        volume_24h,
+        fees_24h,
+        fee_apr: fee_apr(fees_24h, tvl),
+        price,
+    }
+}
+
+fn parse_raydium(body: &Value) -> Result<PoolReading, PoolAnalyticsError> {
+    let pool = body
+        .get("data")
+        .and_then(|data| data.get(0))
+        .ok_or_else(|| PoolAnalyticsError::InvalidResponse("raydium pool not found".to_string()))?;
+
+    let token_a = string_at(pool, &["mintA", "symbol"]).unwrap_or_default();
+    let token_b = string_at(pool, &["mintB", "symbol"]).unwrap_or_default();
+    let fee_rate = number_at(pool, &["feeRate"]);
+    let kind = match string_at(pool, &["type"]).as_deref() {
+        Some("Concentrated") => PoolKind::Concentrated,
+        _ => PoolKind::Standard,
+    };
+
+    Ok(PoolReading {
+        kind,
+        name: format!("{}-{}", token_a, token_b),
+        token_a,
+        token_b,
+        fee_rate,
+        snapshot: build_snapshot(
+            number_at(pool, &["tvl"]).unwrap_or(0.0),
+            number_at(pool, &["day", "volume"]).unwrap_or(0.0),
+            number_at(pool, &["day", "volumeFee"]),
+            fee_rate,
+            number_at(pool, &["price"]).unwrap_or(0.0),
+        ),
+    })
+}
+
+fn parse_orca(body: &Value) -> Result<PoolReading, PoolAnalyticsError> {
+    let pool = body
+        .get("data")
+        .ok_or_else(|| PoolAnalyticsError::InvalidResponse("orca pool not found".to_string()))?;
+
+    let token_a = string_at(pool, &["tokenA", "symbol"]).unwrap_or_default();
+    let token_b = string_at(pool, &["tokenB", "symbol"]).unwrap_or_default();
+    // Whirlpool fee rates are in hundredths of a basis point.
+    let fee_rate = number_at(pool, &["feeRate"]).map(|rate| rate / 1_000_000.0);
+
+    Ok(PoolReading {
+        kind: PoolKind::Concentrated,
+        name: format!("{}-{}", token_a, token_b),
+        token_a,
+        token_b,
+        fee_rate,
+        snapshot: build_snapshot(
+            number_at(pool, &["tvlUsdc"]).unwrap_or(0.0),
+            number_at(pool, &["stats", "24h", "volume"]).unwrap_or(0.0),
+            number_at(pool, &["stats", "24h", "fees"]),
+            fee_rate,
+            number_at(pool, &["price"]).unwrap_or(0.0),
+        ),
+    })
+}
+
+fn parse_meteora(body: &Value) -> Result<PoolReading, PoolAnalyticsError> {
+    let name = string_at(body, &["name"])
+        .ok_or_else(|| PoolAnalyticsError::InvalidResponse("meteora pair not found".to_string()))?;
+    let (token_a, token_b) = name
+        .split_once('-')
+        .map(|(a, b)| (a.to_string(), b.to_string()))
+        .unwrap_or_else(|| (name.clone(), String::new()));
+    let fee_rate = number_at(body, &["base_fee_percentage"]).map(|pct| pct / 100.0);
+
+    Ok(PoolReading {
+        kind: PoolKind::Concentrated,
+        name,
+        token_a,
+        token_b,
+        fee_rate,
+        snapshot: build_snapshot(
+            number_at(body, &["liquidity"]).unwrap_or(0.0),
+            number_at(body, &["trade_volume_24h"]).unwrap_or(0.0),
+            number_at(body, &["fees_24h"]),
+            fee_rate,
+            number_at(body, &["current_price"]).unwrap_or(0.0),
+        ),
+    })
+}
+
+pub fn range_utilization(
+    lower: f64,
+    upper: f64,
+    history: &[PoolSnapshot],
+) -> Option<RangeUtilization> {
+    let current = history.last()?.price;
+    let in_range = |price: f64| price >= lower && price <= upper;
+    let inside = history.iter().filter(|s| in_range(s.price)).count();
+
+    Some(RangeUtilization {
+        lower,
+        upper,
+        current_in_range: in_range(current),
+        position_in_range: ((current - lower) / (upper - lower)).clamp(0.0, 1.0),
+        time_in_range_percent: inside as f64 / history.len() as f64 * 100.0,
+    })
+}
+
+pub fn summarize_pool(pool: TrackedPool, history: Vec<PoolSnapshot>) -> PoolAnalytics {
+    let latest = history.last().cloned();
+    let average_fee_apr = (!history.is_empty())
+        .then(|| history.iter().map(|s| s.fee_apr).sum::<f64>() / history.len() as f64);
+    let tvl_change_percent = match (history.first(), history.last()) {
+        (Some(first), Some(last)) if first.tvl > 0.0 && history.len() > 1 => {
+            Some((last.tvl - first.tvl) / first.tvl * 100.0)
+        }
+        _ => None,
+    };
+    let range = match (pool.kind, pool.range_lower, pool.range_upper) {
+        (PoolKind::Concentrated, Some(lower), Some(upper)) => range_utilization(lower, upper, &history),
+        _ => None,
+    };
+
+    PoolAnalytics {
+        pool,
+        latest,
+        history,
+        average_fee_apr,
+        tvl_change_percent,
+        range,
+    }
+}
+
+fn protocol_str(protocol: &Protocol) -> Result<&'static str, PoolAnalyticsError> {
+    match protocol {
+        Protocol::Raydium => Ok("raydium"),
+        Protocol::Orca => Ok("orca"),
+        Protocol::Meteora => Ok("meteora"),
+        _ => Err(PoolAnalyticsError::UnsupportedProtocol),
+    }
+}
+
+fn protocol_from_str(value: &str) -> Result<Protocol, PoolAnalyticsError> {
+    match value {
+        "raydium" => Ok(Protocol::Raydium),
+        "orca" => Ok(Protocol::Orca),
+        "meteora" => Ok(Protocol::Meteora),
+        other => Err(PoolAnalyticsError::Internal(format!("unknown protocol {}", other))),
+    }
+}
+
+pub struct PoolAnalyticsManager {
+    pool: Pool<Sqlite>,
+    http: reqwest::Client,
+}
+
+pub type SharedPoolAnalyticsManager = Arc<RwLock<PoolAnalyticsManager>>;
+
+impl PoolAnalyticsManager {
+    pub async fn new(app: &AppHandle) -> Result<Self, PoolAnalyticsError> {
+        let db_path = pool_analytics_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let manager = Self {
+            pool,
+            http: reqwest::Client::new(),
+        };
+        manager.initialize().await?;
+        Ok(manager)
+    }
+
+    async fn initialize(&self) -> Result<(), PoolAnalyticsError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS tracked_pools (
+                address TEXT PRIMARY KEY,
+                protocol TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                name TEXT NOT NULL,
+                token_a TEXT NOT NULL,
+                token_b TEXT NOT NULL,
+                fee_rate REAL,
+                range_lower REAL,
+                range_upper REAL,
+                added_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS pool_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                pool_address TEXT NOT NULL,
+                captured_at TEXT NOT NULL,
+                tvl REAL NOT NULL,
+                volume_24h REAL NOT NULL,
+                fees_24h REAL NOT NULL,
+                fee_apr REAL NOT NULL,
+                price REAL NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_pool_snapshots_pool ON pool_snapshots(pool_address, captured_at)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_reading(&self, protocol: &Protocol, address: &str) -> Result<PoolReading, PoolAnalyticsError> {
+        let url = match protocol {
+            Protocol::Raydium => format!("{}{}", RAYDIUM_POOL_URL, address),
+            Protocol::Orca => format!("{}{}", ORCA_POOL_URL, address),
+            Protocol::Meteora => format!("{}{}", METEORA_POOL_URL, address),
+            _ => return Err(PoolAnalyticsError::UnsupportedProtocol),
+        };
+
+        let body: Value = self
+            .http
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        match protocol {
+            Protocol::Raydium => parse_raydium(&body),
+            Protocol::Orca => parse_orca(&body),
+            _ => parse_meteora(&body),
+        }
+    }
+
+    fn row_to_pool(row: &SqliteRow) -> Result<TrackedPool, PoolAnalyticsError> {
+        let protocol: String = row.try_get("protocol")?;
+        let kind: String = row.try_get("kind")?;
+        let added_at: String = row.try_get("added_at")?;
+
+        Ok(TrackedPool {
+            address: row.try_get("address")?,
+            protocol: protocol_from_str(&protocol)?,
+            kind: if kind == "concentrated" {
+                PoolKind::Concentrated
+            } else {
+                PoolKind::Standard
+            },
+            name: row.try_get("name")?,
+            token_a: row.try_get("token_a")?,
+            token_b: row.try_get("token_b")?,
+            fee_rate: row.try_get("fee_rate")?,
+            range_lower: row.try_get("range_lower")?,
+            range_upper: row.try_get("range_upper")?,
+            added_at: parse_timestamp(&added_at)?,
+        })
+    }
+
+    fn row_to_snapshot(row: &SqliteRow) -> Result<PoolSnapshot, PoolAnalyticsError> {
+        let captured_at: String = row.try_get("captured_at")?;
+        Ok(PoolSnapshot {
+            captured_at: parse_timestamp(&captured_at)?,
+            tvl: row.try_get("tvl")?,
+            volume_24h: row.try_get("volume_24h")?,
+            fees_24h: row.try_get("fees_24h")?,
+            fee_apr: row.try_get("fee_apr")?,
+            price: row.try_get("price")?,
+        })
+    }
+
+    async fn insert_snapshot(&self, address: &str, snapshot: &PoolSnapshot) -> Result<(), PoolAnalyticsError> {
+        sqlx::query(
+            r#"
+            INSERT INTO pool_snapshots (
+                pool_address, captured_at, tvl, volume_24h, fees_24h, fee_apr, price
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(address)
+        .bind(snapshot.captured_at.to_rfc3339())
+        .bind(snapshot.tvl)
+        .bind(snapshot.volume_24h)
+        .bind(snapshot.fees_24h)
+        .bind(snapshot.fee_apr)
+        .bind(snapshot.price)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn track_pool(&self, request: TrackPoolRequest) -> Result<TrackedPool, PoolAnalyticsError> {
+        let protocol = protocol_str(&request.protocol)?;
+        if let (Some(lower), Some(upper)) = (request.range_lower, request.range_upper) {
+            if lower <= 0.0 || upper <= lower {
+                return Err(PoolAnalyticsError::InvalidRange(
+                    "upper bound must be above a positive lower bound".to_string(),
+                ));
+            }
+        }
+
+        let reading = self.fetch_reading(&request.protocol, &request.address).await?;
+        let pool = TrackedPool {
+            address: request.address,
+            protocol: request.protocol,
+            kind: reading.kind,
+            name: reading.name,
+            token_a: reading.token_a,
+            token_b: reading.token_b,
+            fee_rate: reading.fee_rate,
+            range_lower: request.range_lower,
+            range_upper: request.range_upper,
+            added_at: Utc::now(),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO tracked_pools (
+                address, protocol, kind, name, token_a, token_b, fee_rate,
+                range_lower, range_upper, added_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(address) DO UPDATE SET
+                range_lower = excluded.range_lower,
+                range_upper = excluded.range_upper,
+                fee_rate = excluded.fee_rate
+            "#,
+        )
+        .bind(&pool.address)
+        .bind(protocol)
+        .bind(match pool.kind {
+            PoolKind::Standard => "standard",
+            PoolKind::Concentrated => "concentrated",
+        })
+        .bind(&pool.name)
+        .bind(&pool.token_a)
+        .bind(&pool.token_b)
+        .bind(pool.fee_rate)
+        .bind(pool.range_lower)
+        .bind(pool.range_upper)
+        .bind(pool.added_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        self.insert_snapshot(&pool.address, &reading.snapshot).await?;
+        self.get_pool(&pool.address).await
+    }
+
+    pub async fn untrack_pool(&self, address: &str) -> Result<(), PoolAnalyticsError> {
+        let result = sqlx::query("DELETE FROM tracked_pools WHERE address = ?")
+            .bind(address)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(PoolAnalyticsError::NotFound(address.to_string()));
+        }
+
+        sqlx::query("DELETE FROM pool_snapshots WHERE pool_address = ?")
+            .bind(address)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_pool(&self, address: &str) -> Result<TrackedPool, PoolAnalyticsError> {
+        let row = sqlx::query("SELECT * FROM tracked_pools WHERE address = ?")
+            .bind(address)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| PoolAnalyticsError::NotFound(address.to_string()))?;
+        Self::row_to_pool(&row)
+    }
+
+    pub async fn list_pools(&self) -> Result<Vec<TrackedPool>, PoolAnalyticsError> {
+        let rows = sqlx::query("SELECT * FROM tracked_pools ORDER BY added_at")
+            .fetch_all(&self.pool)
+            .await?;
+        rows.iter().map(Self::row_to_pool).collect()
+    }
+
+    pub async fn history(&self, address: &str, days: i64) -> Result<Vec<PoolSnapshot>, PoolAnalyticsError> {
+        let since = Utc::now() - Duration::days(days.clamp(1, HISTORY_RETENTION_DAYS));
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM pool_snapshots
+            WHERE pool_address = ? AND captured_at >= ?
+            ORDER BY captured_at
+            "#,
+        )
+        .bind(address)
+        .bind(since.to_rfc3339())
+        .fetch_all(&self.pool)
+        .await?;
+        rows.iter().map(Self::row_to_snapshot).collect()
+    }
+
+    pub async fn analytics(&self, address: &str, days: i64) -> Result<PoolAnalytics, PoolAnalyticsError> {
+        let pool = self.get_pool(address).await?;
+        let history = self.history(address, days).await?;
+        Ok(summarize_pool(pool, history))
+    }
+
+    /// Takes a snapshot of every tracked pool and prunes old history.
+    pub async fn snapshot_all(&self) -> Result<(), PoolAnalyticsError> {
+        for pool in self.list_pools().await? {
+            match self.fetch_reading(&pool.protocol, &pool.address).await {
+                Ok(reading) => self.insert_snapshot(&pool.address, &reading.snapshot).await?,
+                Err(err) => {
+                    tracing::warn!(error = %err, pool = %pool.address, "pool snapshot failed");
+                }
+            }
+        }
+
+        let cutoff = Utc::now() - Duration::days(HISTORY_RETENTION_DAYS);
+        sqlx::query("DELETE FROM pool_snapshots WHERE captured_at < ?")
+            .bind(cutoff.to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Tracked pools in the shape the yield comparison views use, with fee APR from the
+    /// latest snapshot.
+    pub async fn as_yield_farms(&self) -> Result<Vec<YieldFarm>, PoolAnalyticsError> {
+        let mut farms = Vec::new();
+        for pool in self.list_pools().await? {
+            let Some(latest) = self.history(&pool.address, 1).await?.pop() else {
+                continue;
+            };
+
+            // Deeper pools are harder to move and carry less smart-contract TVL risk.
+            let risk_score = match latest.tvl {
+                tvl if tvl >= 10_000_000.0 => 35,
+                tvl if tvl >= 1_000_000.0 => 50,
+                _ => 70,
+            } + if pool.kind == PoolKind::Concentrated { 10 } else { 0 };
+
+            farms.push(YieldFarm {
+                id: format!("pool-{}", pool.address),
+                protocol: pool.protocol.clone(),
+                name: pool.name.clone(),
+                token_a: pool.token_a.clone(),
+                token_b: pool.token_b.clone(),
+                apy: latest.fee_apr,
+                tvl: latest.tvl,
+                rewards_token: Vec::new(),
+                risk_score,
+            });
+        }
+        Ok(farms)
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, PoolAnalyticsError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| PoolAnalyticsError::Internal(format!("invalid timestamp {}: {}", value, e)))
+}
+
+fn pool_analytics_db_path(app: &AppHandle) -> Result<PathBuf, PoolAnalyticsError> {
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| PoolAnalyticsError::Internal("Unable to resolve app data directory".to_string()))?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(POOL_ANALYTICS_DB_FILE))
+}
+
+pub fn start_pool_snapshots(manager: SharedPoolAnalyticsManager) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(SNAPSHOT_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            if let Err(err) = manager.read().await.snapshot_all().await {
+                tracing::warn!(error = %err, "pool snapshot cycle failed");
+            }
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn track_pool(
+    request: TrackPoolRequest,
+    manager: State<'_, SharedPoolAnalyticsManager>,
+) -> Result<TrackedPool, String> {
+    manager.read().await.track_pool(request).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn untrack_pool(
+    address: String,
+    manager: State<'_, SharedPoolAnalyticsManager>,
+) -> Result<(), String> {
+    manager.read().await.untrack_pool(&address).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_tracked_pools(
+    manager: State<'_, SharedPoolAnalyticsManager>,
+) -> Result<Vec<TrackedPool>, String> {
+    manager.read().await.list_pools().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_pool_analytics(
+    pool: String,
+    days: Option<i64>,
+    manager: State<'_, SharedPoolAnalyticsManager>,
+) -> Result<PoolAnalytics, String> {
+    manager
+        .read()
+        .await
+        .analytics(&pool, days.unwrap_or(30))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn snapshot(price: f64, tvl: f64, fee_apr: f64) -> PoolSnapshot {
+        PoolSnapshot {
+            captured_at: Utc::now(),
+            tvl,
+            volume_24h: 0.0,
+            fees_24h: 0.0,
+            fee_apr,
+            price,
+        }
+    }
+
+    #[test]
+    fn parses_raydium_clmm_and_derives_fee_apr() {
+        let body = json!({
+            "success": true,
+            "data": [{
+                "type": "Concentrated",
+                "mintA": {"symbol": "SOL"},
+                "mintB": {"symbol": "USDC"},
+                "price": 150.0,
+                "tvl": 1_000_000.0,
+                "feeRate": 0.0004,
+                "day": {"volume": 5_000_000.0, "volumeFee": 2000.0}
+            }]
+        });
+
+        let reading = parse_raydium(&body).unwrap();
+        assert_eq!(reading.kind, PoolKind::Concentrated);
+        assert_eq!(reading.name, "SOL-USDC");
+        assert!((reading.snapshot.fee_apr - 73.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn parses_string_numbers_and_falls_back_to_fee_rate() {
+        let body = json!({
+            "name": "JUP-USDC",
+            "liquidity": "200000",
+            "trade_volume_24h": 100000.0,
+            "current_price": 0.9,
+            "base_fee_percentage": "0.2"
+        });
+
+        let reading = parse_meteora(&body).unwrap();
+        assert_eq!(reading.token_a, "JUP");
+        assert!((reading.snapshot.fees_24h - 200.0).abs() < 1e-9);
+        assert_eq!(reading.snapshot.tvl, 200_000.0);
+    }
+
+    #[test]
+    fn summarizes_history_and_range_utilization() {
+        let pool = TrackedPool {
+            address: "pool".to_string(),
+            protocol: Protocol::Orca,
+            kind: PoolKind::Concentrated,
+            name: "SOL-USDC".to_string(),
+            token_a: "SOL".to_string(),
+            token_b: "USDC".to_string(),
+            fee_rate: Some(0.0004),
+            range_lower: Some(100.0),
+            range_upper: Some(200.0),
+            added_at: Utc::now(),
+        };
+        let history = vec![
+            snapshot(90.0, 1000.0, 10.0),
+            snapshot(120.0, 1100.0, 20.0),
+            snapshot(150.0, 1200.0, 30.0),
+            snapshot(175.0, 1500.0, 40.0),
+        ];
+
+        let analytics = summarize_pool(pool, history);
+        assert_eq!(analytics.average_fee_apr, Some(25.0));
+        assert_eq!(analytics.tvl_change_percent, Some(50.0));
+
+        let range = analytics.range.unwrap();
+        assert!(range.current_in_range);
+        assert!((range.position_in_range - 0.75).abs() < 1e-9);
+        assert_eq!(range.time_in_range_percent, 75.0);
+    }
+}
@@ -131,6 +131,8 @@ impl SolendAdapter {
             return Ok(reserves);
         }
 
+        // No live Solend source yet; reserves are demo fixtures
+        crate::demo::require_demo_mode("Solend reserve data")?;
         let reserves = self.generate_mock_reserves();
         self.cache_set(cache_key, &reserves).await;
         Ok(reserves)
@@ -142,6 +144,7 @@ impl SolendAdapter {
             return Ok(obligation);
         }
 
+        crate::demo::require_demo_mode("Solend obligation data")?;
         let obligation = self.generate_mock_obligation(wallet);
         self.cache_set(&cache_key, &obligation).await;
         Ok(obligation)
@@ -17,7 +17,9 @@ impl StakingAdapter {
         Self
     }
 
+    // No live staking source yet; pools and schedules are demo fixtures
     pub async fn get_pools(&self) -> Result<Vec<StakingPool>, String> {
+        crate::demo::require_demo_mode("Staking pool data")?;
         Ok(self.generate_mock_pools())
     }
 
@@ -49,6 +51,7 @@ impl StakingAdapter {
     }
 
     pub async fn get_reward_schedule(&self, pool_id: &str) -> Result<Vec<StakingRewardSchedule>, String> {
+        crate::demo::require_demo_mode("Staking reward schedules")?;
         Ok(self.generate_mock_schedule(pool_id))
     }
 
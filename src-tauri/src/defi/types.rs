@@ -25,6 +25,7 @@ pub enum Protocol {
     Jupiter,
     Raydium,
     Orca,
+    Meteora,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
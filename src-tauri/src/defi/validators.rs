@@ -0,0 +1,855 @@
+use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
+use base64::Engine;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use solana_sdk::{
+    hash::Hash,
+    message::Message,
+    pubkey::Pubkey,
+    stake::{
+        self,
+        state::{Authorized, Lockup},
+    },
+    transaction::Transaction,
+};
+use sqlx::{FromRow, Pool, Sqlite, SqlitePool};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, State};
+use tokio::sync::RwLock;
+
+const STAKING_DB_FILE: &str = "staking_rewards.db";
+const DEFAULT_RPC_ENDPOINT: &str = "https://api.mainnet-beta.solana.com";
+const JITO_VALIDATORS_URL: &str = "https://kobe.mainnet.jito.network/api/v1/validators";
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+const STAKE_ACCOUNT_SIZE: usize = 200;
+// Withdraw authority sits after the 4-byte enum tag, rent reserve and staker pubkey
+const STAKE_WITHDRAWER_OFFSET: usize = 44;
+const SUPERMINORITY_THRESHOLD_PERCENT: f64 = 100.0 / 3.0;
+const VALIDATOR_CACHE_TTL: Duration = Duration::from_secs(600);
+const DEFAULT_REWARD_EPOCHS: u64 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorInfo {
+    pub vote_account: String,
+    pub identity: String,
+    pub commission: u8,
+    pub activated_stake_sol: f64,
+    pub stake_share_percent: f64,
+    /// Share of stake held by this validator and every larger one.
+    pub cumulative_stake_percent: f64,
+    pub superminority: bool,
+    pub delinquent: bool,
+    /// Credits earned last epoch relative to the best performing validator.
+    pub uptime: f64,
+    pub estimated_apy: f64,
+    pub jito_enabled: bool,
+    pub mev_commission_bps: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkStakingStats {
+    pub epoch: u64,
+    pub base_apy: f64,
+    pub total_active_stake_sol: f64,
+    pub validator_count: usize,
+    /// Minimum number of validators controlling more than a third of stake.
+    pub nakamoto_coefficient: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorOverview {
+    pub stats: NetworkStakingStats,
+    pub validators: Vec<ValidatorInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorFilter {
+    pub jito_only: Option<bool>,
+    pub exclude_superminority: Option<bool>,
+    pub max_commission: Option<u8>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeTransaction {
+    pub stake_account: String,
+    pub seed: String,
+    pub vote_account: String,
+    pub delegated_lamports: u64,
+    pub rent_reserve_lamports: u64,
+    /// Base64 encoded, unsigned legacy transaction for the wallet to sign.
+    pub transaction: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeReward {
+    pub stake_account: String,
+    pub epoch: i64,
+    pub amount_lamports: i64,
+    pub post_balance_lamports: i64,
+    pub commission: Option<i64>,
+    pub effective_slot: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeAccountInfo {
+    pub address: String,
+    pub state: String,
+    pub vote_account: Option<String>,
+    pub delegated_sol: f64,
+    pub balance_sol: f64,
+    pub activation_epoch: Option<u64>,
+    pub deactivation_epoch: Option<u64>,
+    pub total_rewards_sol: f64,
+    pub rewards: Vec<StakeReward>,
+}
+
+/// Vote account as reported by `getVoteAccounts`, before ranking.
+#[derive(Debug, Clone)]
+pub struct RawValidator {
+    pub vote_account: String,
+    pub identity: String,
+    pub commission: u8,
+    pub activated_stake: u64,
+    pub last_epoch_credits: u64,
+    pub delinquent: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct JitoInfo {
+    pub mev_commission_bps: Option<u16>,
+    pub running_jito: bool,
+}
+
+/// Network-wide staking yield before commission, in percent.
+pub fn estimate_base_apy(validator_inflation: f64, total_supply: u64, total_active_stake: u64) -> f64 {
+    if total_active_stake == 0 {
+        return 0.0;
+    }
+    validator_inflation * (total_supply as f64 / total_active_stake as f64) * 100.0
+}
+
+pub fn validator_apy(base_apy: f64, commission: u8, uptime: f64) -> f64 {
+    base_apy * (1.0 - commission.min(100) as f64 / 100.0) * uptime.clamp(0.0, 1.0)
+}
+
+/// Ranks validators by stake and fills in share, superminority and APY estimates.
+///
+/// Returns the validators (largest stake first) together with the Nakamoto coefficient.
+pub fn rank_validators(
+    raw: Vec<RawValidator>,
+    base_apy: f64,
+    jito: &HashMap<String, JitoInfo>,
+) -> (Vec<ValidatorInfo>, usize) {
+    let mut raw = raw;
+    raw.sort_by(|a, b| b.activated_stake.cmp(&a.activated_stake));
+
+    let total_stake: u64 = raw.iter().map(|v| v.activated_stake).sum();
+    let max_credits = raw.iter().map(|v| v.last_epoch_credits).max().unwrap_or(0);
+
+    let mut cumulative = 0.0;
+    let mut nakamoto = 0;
+    let validators = raw
+        .into_iter()
+        .map(|v| {
+            let share = if total_stake > 0 {
+                v.activated_stake as f64 / total_stake as f64 * 100.0
+            } else {
+                0.0
+            };
+            let superminority = cumulative < SUPERMINORITY_THRESHOLD_PERCENT;
+            if superminority {
+                nakamoto += 1;
+            }
+            cumulative += share;
+
+            let uptime = if v.delinquent || max_credits == 0 {
+                0.0
+            } else {
+                v.last_epoch_credits as f64 / max_credits as f64
+            };
+            let jito_info = jito.get(&v.vote_account);
+
+            ValidatorInfo {
+                estimated_apy: validator_apy(base_apy, v.commission, uptime),
+                vote_account: v.vote_account,
+                identity: v.identity,
+                commission: v.commission,
+                activated_stake_sol: v.activated_stake as f64 / LAMPORTS_PER_SOL,
+                stake_share_percent: share,
+                cumulative_stake_percent: cumulative,
+                superminority,
+                delinquent: v.delinquent,
+                uptime,
+                jito_enabled: jito_info.map(|j| j.running_jito).unwrap_or(false),
+                mev_commission_bps: jito_info.and_then(|j| j.mev_commission_bps),
+            }
+        })
+        .collect();
+
+    (validators, nakamoto)
+}
+
+pub fn apply_validator_filter(
+    validators: &[ValidatorInfo],
+    filter: Option<&ValidatorFilter>,
+) -> Vec<ValidatorInfo> {
+    let jito_only = filter.and_then(|f| f.jito_only).unwrap_or(false);
+    let exclude_superminority = filter.and_then(|f| f.exclude_superminority).unwrap_or(false);
+    let max_commission = filter.and_then(|f| f.max_commission).unwrap_or(100);
+
+    let mut selected: Vec<ValidatorInfo> = validators
+        .iter()
+        .filter(|v| !v.delinquent)
+        .filter(|v| !jito_only || v.jito_enabled)
+        .filter(|v| !exclude_superminority || !v.superminority)
+        .filter(|v| v.commission <= max_commission)
+        .cloned()
+        .collect();
+
+    selected.sort_by(|a, b| {
+        b.estimated_apy
+            .partial_cmp(&a.estimated_apy)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    if let Some(limit) = filter.and_then(|f| f.limit) {
+        selected.truncate(limit);
+    }
+    selected
+}
+
+fn parse_vote_accounts(result: &Value) -> Vec<RawValidator> {
+    let mut validators = Vec::new();
+    for (key, delinquent) in [("current", false), ("delinquent", true)] {
+        for entry in result.get(key).and_then(|v| v.as_array()).into_iter().flatten() {
+            let (Some(vote_account), Some(identity)) = (
+                entry.get("votePubkey").and_then(|v| v.as_str()),
+                entry.get("nodePubkey").and_then(|v| v.as_str()),
+            ) else {
+                continue;
+            };
+
+            // epochCredits entries are [epoch, credits, previousCredits]
+            let last_epoch_credits = entry
+                .get("epochCredits")
+                .and_then(|v| v.as_array())
+                .and_then(|credits| credits.last())
+                .and_then(|last| last.as_array())
+                .map(|last| {
+                    let credits = last.get(1).and_then(|v| v.as_u64()).unwrap_or(0);
+                    let previous = last.get(2).and_then(|v| v.as_u64()).unwrap_or(0);
+                    credits.saturating_sub(previous)
+                })
+                .unwrap_or(0);
+
+            validators.push(RawValidator {
+                vote_account: vote_account.to_string(),
+                identity: identity.to_string(),
+                commission: entry.get("commission").and_then(|v| v.as_u64()).unwrap_or(0) as u8,
+                activated_stake: entry.get("activatedStake").and_then(|v| v.as_u64()).unwrap_or(0),
+                last_epoch_credits,
+                delinquent,
+            });
+        }
+    }
+    validators
+}
+
+fn parse_stake_account(address: &str, lamports: u64, data: &Value) -> StakeAccountInfo {
+    let parsed = data.get("parsed");
+    let state = parsed
+        .and_then(|p| p.get("type"))
+        .and_then(|t| t.as_str())
+        .unwrap_or("unknown")
+        .to_string();
+    let delegation = parsed
+        .and_then(|p| p.get("info"))
+        .and_then(|i| i.get("stake"))
+        .and_then(|s| s.get("delegation"));
+
+    // The RPC encodes u64 fields of parsed stake accounts as strings
+    let field_u64 = |name: &str| -> Option<u64> {
+        delegation.and_then(|d| d.get(name)).and_then(|v| {
+            v.as_u64()
+                .or_else(|| v.as_str().and_then(|s| s.parse::<u64>().ok()))
+        })
+    };
+
+    StakeAccountInfo {
+        address: address.to_string(),
+        state,
+        vote_account: delegation
+            .and_then(|d| d.get("voter"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        delegated_sol: field_u64("stake").unwrap_or(0) as f64 / LAMPORTS_PER_SOL,
+        balance_sol: lamports as f64 / LAMPORTS_PER_SOL,
+        activation_epoch: field_u64("activationEpoch"),
+        deactivation_epoch: field_u64("deactivationEpoch").filter(|epoch| *epoch != u64::MAX),
+        total_rewards_sol: 0.0,
+        rewards: Vec::new(),
+    }
+}
+
+pub struct StakingAdvisor {
+    pool: Pool<Sqlite>,
+    client: reqwest::Client,
+    /// Kept behind its own lock so refreshing it never needs the advisor write lock.
+    cache: Mutex<Option<(Instant, ValidatorOverview)>>,
+}
+
+pub type SharedStakingAdvisor = Arc<RwLock<StakingAdvisor>>;
+
+impl StakingAdvisor {
+    pub async fn new(app: &AppHandle) -> Result<Self, String> {
+        let mut db_path: PathBuf = app
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+
+        std::fs::create_dir_all(&db_path)
+            .map_err(|e| format!("Failed to create data directory: {e}"))?;
+        db_path.push(STAKING_DB_FILE);
+
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url)
+            .await
+            .map_err(|e| format!("Failed to open staking database: {e}"))?;
+
+        let advisor = Self {
+            pool,
+            client: reqwest::Client::new(),
+            cache: Mutex::new(None),
+        };
+        advisor
+            .initialize()
+            .await
+            .map_err(|e| format!("Failed to initialize staking database: {e}"))?;
+        Ok(advisor)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS stake_rewards (
+                stake_account TEXT NOT NULL,
+                epoch INTEGER NOT NULL,
+                amount_lamports INTEGER NOT NULL,
+                post_balance_lamports INTEGER NOT NULL,
+                commission INTEGER,
+                effective_slot INTEGER NOT NULL,
+                PRIMARY KEY (stake_account, epoch)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn rpc(&self, rpc_url: &str, method: &str, params: Value) -> Result<Value, String> {
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let data: Value = self
+            .client
+            .post(rpc_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("RPC request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Some(error) = data.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+
+        Ok(data["result"].clone())
+    }
+
+    async fn fetch_jito_validators(&self) -> HashMap<String, JitoInfo> {
+        // Jito availability is best-effort; the list is still useful without it
+        let response = match self.client.get(JITO_VALIDATORS_URL).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                tracing::warn!(error = %e, "failed to fetch Jito validators");
+                return HashMap::new();
+            }
+        };
+        let data: Value = match response.json().await {
+            Ok(data) => data,
+            Err(e) => {
+                tracing::warn!(error = %e, "failed to parse Jito validators");
+                return HashMap::new();
+            }
+        };
+
+        data.get("validators")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let vote_account = entry.get("vote_account")?.as_str()?.to_string();
+                Some((
+                    vote_account,
+                    JitoInfo {
+                        mev_commission_bps: entry
+                            .get("mev_commission_bps")
+                            .and_then(|v| v.as_u64())
+                            .map(|v| v as u16),
+                        running_jito: entry
+                            .get("running_jito")
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false),
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    pub async fn get_overview(&self, rpc_url: &str) -> Result<ValidatorOverview, String> {
+        if let Some((fetched_at, overview)) = &*self.cache.lock() {
+            if fetched_at.elapsed() < VALIDATOR_CACHE_TTL {
+                return Ok(overview.clone());
+            }
+        }
+
+        let vote_accounts = self.rpc(rpc_url, "getVoteAccounts", json!([])).await?;
+        let inflation = self.rpc(rpc_url, "getInflationRate", json!([])).await?;
+        let supply = self
+            .rpc(rpc_url, "getSupply", json!([{ "excludeNonCirculatingAccountsList": true }]))
+            .await?;
+        let jito = self.fetch_jito_validators().await;
+
+        let raw = parse_vote_accounts(&vote_accounts);
+        let total_active_stake: u64 = raw.iter().map(|v| v.activated_stake).sum();
+        let base_apy = estimate_base_apy(
+            inflation.get("validator").and_then(|v| v.as_f64()).unwrap_or(0.0),
+            supply
+                .get("value")
+                .and_then(|v| v.get("total"))
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0),
+            total_active_stake,
+        );
+        let epoch = inflation.get("epoch").and_then(|v| v.as_u64()).unwrap_or(0);
+
+        let (validators, nakamoto_coefficient) = rank_validators(raw, base_apy, &jito);
+        let overview = ValidatorOverview {
+            stats: NetworkStakingStats {
+                epoch,
+                base_apy,
+                total_active_stake_sol: total_active_stake as f64 / LAMPORTS_PER_SOL,
+                validator_count: validators.len(),
+                nakamoto_coefficient,
+            },
+            validators,
+        };
+
+        *self.cache.lock() = Some((Instant::now(), overview.clone()));
+        Ok(overview)
+    }
+
+    /// Builds an unsigned transaction that funds a seed-derived stake account and delegates it.
+    pub async fn build_stake_transaction(
+        &self,
+        wallet: &str,
+        vote_account: &str,
+        amount_sol: f64,
+        rpc_url: &str,
+    ) -> Result<StakeTransaction, String> {
+        if !amount_sol.is_finite() || amount_sol <= 0.0 {
+            return Err("Stake amount must be greater than zero".to_string());
+        }
+
+        let wallet_pubkey =
+            Pubkey::from_str(wallet).map_err(|e| format!("Invalid wallet address: {e}"))?;
+        let vote_pubkey =
+            Pubkey::from_str(vote_account).map_err(|e| format!("Invalid vote account: {e}"))?;
+
+        let rent_reserve = self
+            .rpc(rpc_url, "getMinimumBalanceForRentExemption", json!([STAKE_ACCOUNT_SIZE]))
+            .await?
+            .as_u64()
+            .ok_or_else(|| "Invalid rent exemption response".to_string())?;
+        let blockhash = self
+            .rpc(rpc_url, "getLatestBlockhash", json!([]))
+            .await?
+            .get("value")
+            .and_then(|v| v.get("blockhash"))
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| "Invalid blockhash response".to_string())
+            .and_then(|s| Hash::from_str(s).map_err(|e| format!("Invalid blockhash: {e}")))?;
+
+        let delegated_lamports = (amount_sol * LAMPORTS_PER_SOL).round() as u64;
+        let seed = format!("stake:{}", chrono::Utc::now().timestamp_millis());
+        let stake_pubkey = Pubkey::create_with_seed(&wallet_pubkey, &seed, &stake::program::id())
+            .map_err(|e| format!("Failed to derive stake account: {e}"))?;
+
+        let instructions = stake::instruction::create_account_with_seed_and_delegate_stake(
+            &wallet_pubkey,
+            &stake_pubkey,
+            &wallet_pubkey,
+            &seed,
+            &vote_pubkey,
+            &Authorized::auto(&wallet_pubkey),
+            &Lockup::default(),
+            delegated_lamports + rent_reserve,
+        );
+        let message = Message::new_with_blockhash(&instructions, Some(&wallet_pubkey), &blockhash);
+        let transaction = Transaction::new_unsigned(message);
+        let bytes = bincode::serialize(&transaction)
+            .map_err(|e| format!("Failed to serialize transaction: {e}"))?;
+
+        Ok(StakeTransaction {
+            stake_account: stake_pubkey.to_string(),
+            seed,
+            vote_account: vote_account.to_string(),
+            delegated_lamports,
+            rent_reserve_lamports: rent_reserve,
+            transaction: BASE64_ENGINE.encode(bytes),
+        })
+    }
+
+    pub async fn submit_signed_transaction(
+        &self,
+        signed_transaction: &str,
+        rpc_url: &str,
+    ) -> Result<String, String> {
+        let signature = self
+            .rpc(
+                rpc_url,
+                "sendTransaction",
+                json!([signed_transaction, { "encoding": "base64" }]),
+            )
+            .await?;
+
+        signature
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| "Invalid sendTransaction response".to_string())
+    }
+
+    async fn fetch_stake_accounts(
+        &self,
+        wallet: &str,
+        rpc_url: &str,
+    ) -> Result<Vec<StakeAccountInfo>, String> {
+        let accounts = self
+            .rpc(
+                rpc_url,
+                "getProgramAccounts",
+                json!([
+                    stake::program::id().to_string(),
+                    {
+                        "encoding": "jsonParsed",
+                        "filters": [
+                            { "memcmp": { "offset": STAKE_WITHDRAWER_OFFSET, "bytes": wallet } }
+                        ]
+                    }
+                ]),
+            )
+            .await?;
+
+        Ok(accounts
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let address = entry.get("pubkey")?.as_str()?;
+                let account = entry.get("account")?;
+                let lamports = account.get("lamports").and_then(|v| v.as_u64()).unwrap_or(0);
+                Some(parse_stake_account(address, lamports, account.get("data")?))
+            })
+            .collect())
+    }
+
+    pub async fn get_rewards(&self, stake_account: &str) -> Result<Vec<StakeReward>, sqlx::Error> {
+        sqlx::query_as::<_, StakeReward>(
+            "SELECT * FROM stake_rewards WHERE stake_account = ?1 ORDER BY epoch DESC",
+        )
+        .bind(stake_account)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn get_stake_accounts(
+        &self,
+        wallet: &str,
+        rpc_url: &str,
+    ) -> Result<Vec<StakeAccountInfo>, String> {
+        let mut accounts = self.fetch_stake_accounts(wallet, rpc_url).await?;
+        for account in accounts.iter_mut() {
+            account.rewards = self
+                .get_rewards(&account.address)
+                .await
+                .map_err(|e| e.to_string())?;
+            account.total_rewards_sol = account
+                .rewards
+                .iter()
+                .map(|r| r.amount_lamports as f64)
+                .sum::<f64>()
+                / LAMPORTS_PER_SOL;
+        }
+        Ok(accounts)
+    }
+
+    /// Fetches inflation rewards for the wallet's stake accounts over the last `epochs` epochs.
+    pub async fn sync_rewards(
+        &self,
+        wallet: &str,
+        epochs: u64,
+        rpc_url: &str,
+    ) -> Result<usize, String> {
+        let accounts = self.fetch_stake_accounts(wallet, rpc_url).await?;
+        if accounts.is_empty() {
+            return Ok(0);
+        }
+        let addresses: Vec<String> = accounts.iter().map(|a| a.address.clone()).collect();
+
+        let current_epoch = self
+            .rpc(rpc_url, "getEpochInfo", json!([]))
+            .await?
+            .get("epoch")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| "Invalid epoch info response".to_string())?;
+
+        let mut recorded = 0;
+        // Rewards for an epoch are paid out at the start of the next one
+        for epoch in current_epoch.saturating_sub(epochs)..current_epoch {
+            let rewards = self
+                .rpc(rpc_url, "getInflationReward", json!([addresses, { "epoch": epoch }]))
+                .await?;
+
+            for (address, reward) in addresses.iter().zip(rewards.as_array().into_iter().flatten()) {
+                if reward.is_null() {
+                    continue;
+                }
+                let result = sqlx::query(
+                    r#"
+                    INSERT OR IGNORE INTO stake_rewards (
+                        stake_account, epoch, amount_lamports, post_balance_lamports,
+                        commission, effective_slot
+                    ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                    "#,
+                )
+                .bind(address)
+                .bind(reward.get("epoch").and_then(|v| v.as_i64()).unwrap_or(epoch as i64))
+                .bind(reward.get("amount").and_then(|v| v.as_i64()).unwrap_or(0))
+                .bind(reward.get("postBalance").and_then(|v| v.as_i64()).unwrap_or(0))
+                .bind(reward.get("commission").and_then(|v| v.as_i64()))
+                .bind(reward.get("effectiveSlot").and_then(|v| v.as_i64()).unwrap_or(0))
+                .execute(&self.pool)
+                .await
+                .map_err(|e| e.to_string())?;
+                recorded += result.rows_affected() as usize;
+            }
+        }
+
+        Ok(recorded)
+    }
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn get_validators(
+    advisor: State<'_, SharedStakingAdvisor>,
+    filter: Option<ValidatorFilter>,
+    rpc_url: Option<String>,
+) -> Result<ValidatorOverview, String> {
+    let advisor = advisor.read().await;
+    let overview = advisor
+        .get_overview(rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT))
+        .await?;
+
+    Ok(ValidatorOverview {
+        validators: apply_validator_filter(&overview.validators, filter.as_ref()),
+        stats: overview.stats,
+    })
+}
+
+#[tauri::command]
+pub async fn stake_sol(
+    advisor: State<'_, SharedStakingAdvisor>,
+    wallet: String,
+    validator: String,
+    amount: f64,
+    rpc_url: Option<String>,
+) -> Result<StakeTransaction, String> {
+    let advisor = advisor.read().await;
+    advisor
+        .build_stake_transaction(
+            &wallet,
+            &validator,
+            amount,
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await
+}
+
+#[tauri::command]
+pub async fn submit_stake_transaction(
+    advisor: State<'_, SharedStakingAdvisor>,
+    signed_transaction: String,
+    rpc_url: Option<String>,
+) -> Result<String, String> {
+    let advisor = advisor.read().await;
+    advisor
+        .submit_signed_transaction(
+            &signed_transaction,
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await
+}
+
+#[tauri::command]
+pub async fn get_stake_accounts(
+    advisor: State<'_, SharedStakingAdvisor>,
+    wallet: String,
+    rpc_url: Option<String>,
+) -> Result<Vec<StakeAccountInfo>, String> {
+    let advisor = advisor.read().await;
+    advisor
+        .get_stake_accounts(&wallet, rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT))
+        .await
+}
+
+#[tauri::command]
+pub async fn sync_stake_rewards(
+    advisor: State<'_, SharedStakingAdvisor>,
+    wallet: String,
+    epochs: Option<u64>,
+    rpc_url: Option<String>,
+) -> Result<usize, String> {
+    let advisor = advisor.read().await;
+    advisor
+        .sync_rewards(
+            &wallet,
+            epochs.unwrap_or(DEFAULT_REWARD_EPOCHS),
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(vote: &str, stake: u64, commission: u8, credits: u64) -> RawValidator {
+        RawValidator {
+            vote_account: vote.to_string(),
+            identity: format!("{}-node", vote),
+            commission,
+            activated_stake: stake,
+            last_epoch_credits: credits,
+            delinquent: false,
+        }
+    }
+
+    #[test]
+    fn base_apy_scales_with_staked_ratio() {
+        let apy = estimate_base_apy(0.045, 600, 400);
+        assert!((apy - 6.75).abs() < 1e-9);
+        assert_eq!(estimate_base_apy(0.045, 600, 0), 0.0);
+    }
+
+    #[test]
+    fn ranks_superminority_and_nakamoto() {
+        let mut jito = HashMap::new();
+        jito.insert(
+            "c".to_string(),
+            JitoInfo {
+                mev_commission_bps: Some(800),
+                running_jito: true,
+            },
+        );
+
+        let (validators, nakamoto) = rank_validators(
+            vec![
+                raw("c", 200, 5, 400),
+                raw("a", 400, 10, 400),
+                raw("b", 300, 0, 200),
+                raw("d", 100, 100, 400),
+            ],
+            8.0,
+            &jito,
+        );
+
+        assert_eq!(nakamoto, 1);
+        assert_eq!(validators[0].vote_account, "a");
+        assert!(validators[0].superminority);
+        assert!(!validators[1].superminority);
+        assert!((validators[0].estimated_apy - 7.2).abs() < 1e-9);
+        assert!((validators[1].estimated_apy - 4.0).abs() < 1e-9);
+        assert!(validators[2].jito_enabled);
+        assert_eq!(validators[2].mev_commission_bps, Some(800));
+        assert_eq!(validators[3].estimated_apy, 0.0);
+    }
+
+    #[test]
+    fn filter_sorts_by_apy_and_drops_excluded() {
+        let (validators, _) = rank_validators(
+            vec![raw("a", 400, 10, 400), raw("b", 300, 0, 400), raw("c", 200, 5, 400)],
+            8.0,
+            &HashMap::new(),
+        );
+
+        let filtered = apply_validator_filter(
+            &validators,
+            Some(&ValidatorFilter {
+                jito_only: None,
+                exclude_superminority: Some(true),
+                max_commission: Some(5),
+                limit: Some(1),
+            }),
+        );
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].vote_account, "b");
+    }
+
+    #[test]
+    fn parses_delegated_stake_account() {
+        let data = json!({
+            "parsed": {
+                "type": "delegated",
+                "info": {
+                    "stake": {
+                        "delegation": {
+                            "voter": "vote-1",
+                            "stake": "5000000000",
+                            "activationEpoch": "600",
+                            "deactivationEpoch": "18446744073709551615"
+                        }
+                    }
+                }
+            }
+        });
+
+        let account = parse_stake_account("stake-1", 5_002_282_880, &data);
+        assert_eq!(account.state, "delegated");
+        assert_eq!(account.vote_account.as_deref(), Some("vote-1"));
+        assert!((account.delegated_sol - 5.0).abs() < 1e-9);
+        assert_eq!(account.activation_epoch, Some(600));
+        assert_eq!(account.deactivation_epoch, None);
+    }
+}
@@ -1,5 +1,7 @@
+use crate::defi::pools::SharedPoolAnalyticsManager;
 use crate::defi::types::*;
 use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -18,7 +20,9 @@ impl YieldFarmingAdapter {
         Self
     }
 
+    // Only tracked pools are live; the adapter's own farms are demo fixtures
     pub async fn get_all_farms(&self) -> Result<Vec<YieldFarm>, String> {
+        crate::demo::require_demo_mode("Yield farm data")?;
         Ok(self.generate_mock_farms())
     }
 
@@ -27,17 +31,22 @@ impl YieldFarmingAdapter {
         let opportunities: Vec<FarmingOpportunity> = farms
             .into_iter()
             .filter(|farm| farm.apy >= min_apy && farm.risk_score <= max_risk)
-            .map(|farm| FarmingOpportunity {
-                projected_earnings_24h: (farm.tvl * farm.apy / 100.0) / 365.0,
-                projected_earnings_30d: (farm.tvl * farm.apy / 100.0) / 12.0,
-                risk_adjusted_apy: farm.apy * (1.0 - (farm.risk_score as f64 / 100.0) * 0.3),
-                farm,
-            })
+            .map(Self::to_opportunity)
             .collect();
         Ok(opportunities)
     }
 
+    pub fn to_opportunity(farm: YieldFarm) -> FarmingOpportunity {
+        FarmingOpportunity {
+            projected_earnings_24h: (farm.tvl * farm.apy / 100.0) / 365.0,
+            projected_earnings_30d: (farm.tvl * farm.apy / 100.0) / 12.0,
+            risk_adjusted_apy: farm.apy * (1.0 - (farm.risk_score as f64 / 100.0) * 0.3),
+            farm,
+        }
+    }
+
     pub async fn get_positions(&self, wallet: &str) -> Result<Vec<DeFiPosition>, String> {
+        crate::demo::require_demo_mode("Yield farming position data")?;
         let positions = self.generate_mock_positions(wallet);
         Ok(positions)
     }
@@ -142,14 +151,50 @@ impl YieldFarmingAdapter {
     }
 }
 
+/// Live fee APRs from pools the user tracks, so they can be compared with the farms above.
+async fn tracked_pool_farms(app: &AppHandle) -> Vec<YieldFarm> {
+    let Some(manager) = app.try_state::<SharedPoolAnalyticsManager>() else {
+        return Vec::new();
+    };
+    let farms = manager.read().await.as_yield_farms().await;
+    farms.unwrap_or_else(|err| {
+        tracing::warn!(error = %err, "failed to load tracked pools for yield comparison");
+        Vec::new()
+    })
+}
+
 #[tauri::command]
-pub async fn get_yield_farms() -> Result<Vec<YieldFarm>, String> {
-    YieldFarmingAdapter::new().get_all_farms().await
+pub async fn get_yield_farms(app_handle: AppHandle) -> Result<Vec<YieldFarm>, String> {
+    let mut farms = if crate::demo::is_demo_mode() {
+        YieldFarmingAdapter::new().get_all_farms().await?
+    } else {
+        Vec::new()
+    };
+    farms.extend(tracked_pool_farms(&app_handle).await);
+    Ok(farms)
 }
 
 #[tauri::command]
-pub async fn get_farming_opportunities(min_apy: f64, max_risk: u8) -> Result<Vec<FarmingOpportunity>, String> {
-    YieldFarmingAdapter::new().get_opportunities(min_apy, max_risk).await
+pub async fn get_farming_opportunities(
+    app_handle: AppHandle,
+    min_apy: f64,
+    max_risk: u8,
+) -> Result<Vec<FarmingOpportunity>, String> {
+    let mut opportunities = if crate::demo::is_demo_mode() {
+        YieldFarmingAdapter::new()
+            .get_opportunities(min_apy, max_risk)
+            .await?
+    } else {
+        Vec::new()
+    };
+    opportunities.extend(
+        tracked_pool_farms(&app_handle)
+            .await
+            .into_iter()
+            .filter(|farm| farm.apy >= min_apy && farm.risk_score <= max_risk)
+            .map(YieldFarmingAdapter::to_opportunity),
+    );
+    Ok(opportunities)
 }
 
 #[tauri::command]
@@ -0,0 +1,579 @@
+use crate::defi::pools::SharedPoolAnalyticsManager;
+use crate::defi::types::{Protocol, RiskLevel};
+use crate::wallet::multi_wallet::MultiWalletManager;
+use crate::wallet::operations::WalletOperationsManager;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+const YIELD_SCANNER_SETTINGS_FILE: &str = "yield_scanner.json";
+const SCAN_INTERVAL_SECS: u64 = 1800;
+const YIELD_OPPORTUNITIES_EVENT: &str = "yield_opportunities_updated";
+
+const KAMINO_RESERVES_URL: &str =
+    "https://api.kamino.finance/kamino-market/7u3HeHxYDLhnCoErrtycNokbQYbWGzLs6JSDqGAv5PfF/reserves/metrics";
+const METEORA_VAULTS_URL: &str = "https://merv2-api.meteora.ai/vault_info";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum YieldVenue {
+    Kamino,
+    MarginFi,
+    Meteora,
+}
+
+impl YieldVenue {
+    fn default_url(&self) -> Option<&'static str> {
+        match self {
+            YieldVenue::Kamino => Some(KAMINO_RESERVES_URL),
+            YieldVenue::Meteora => Some(METEORA_VAULTS_URL),
+            // No public REST endpoint; users point this at an indexer or their own feed.
+            YieldVenue::MarginFi => None,
+        }
+    }
+
+    fn protocol(&self) -> Protocol {
+        match self {
+            YieldVenue::Kamino => Protocol::Kamino,
+            YieldVenue::MarginFi => Protocol::MarginFi,
+            YieldVenue::Meteora => Protocol::Meteora,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum YieldKind {
+    Lending,
+    Vault,
+    LiquidityPool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YieldVenueConfig {
+    pub venue: YieldVenue,
+    pub enabled: bool,
+    /// Overrides the built-in endpoint. Required for MarginFi.
+    pub api_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YieldScannerSettings {
+    pub venues: Vec<YieldVenueConfig>,
+    /// Holdings below this value aren't worth suggesting a move for.
+    pub min_idle_usd: f64,
+    /// Skip opportunities riskier than this.
+    pub max_risk: RiskLevel,
+    pub include_tracked_pools: bool,
+}
+
+impl Default for YieldScannerSettings {
+    fn default() -> Self {
+        Self {
+            venues: vec![
+                YieldVenueConfig {
+                    venue: YieldVenue::Kamino,
+                    enabled: true,
+                    api_url: None,
+                },
+                YieldVenueConfig {
+                    venue: YieldVenue::MarginFi,
+                    enabled: false,
+                    api_url: None,
+                },
+                YieldVenueConfig {
+                    venue: YieldVenue::Meteora,
+                    enabled: true,
+                    api_url: None,
+                },
+            ],
+            min_idle_usd: 50.0,
+            max_risk: RiskLevel::High,
+            include_tracked_pools: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YieldOpportunity {
+    pub id: String,
+    pub protocol: Protocol,
+    pub kind: YieldKind,
+    pub name: String,
+    /// Symbols accepted as deposit; two for LP pools.
+    pub assets: Vec<String>,
+    pub mint: Option<String>,
+    pub apy: f64,
+    pub tvl_usd: f64,
+    pub risk: RiskLevel,
+    /// The venue's own label, before normalization.
+    pub venue_risk_label: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeldAsset {
+    pub mint: String,
+    pub symbol: String,
+    pub usd_value: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdleAssetSuggestion {
+    pub asset: HeldAsset,
+    pub best: YieldOpportunity,
+    pub alternatives: Vec<YieldOpportunity>,
+    pub projected_earnings_30d: f64,
+    pub projected_earnings_365d: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueScanError {
+    pub venue: YieldVenue,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YieldScanResult {
+    pub scanned_at: DateTime<Utc>,
+    pub opportunities: Vec<YieldOpportunity>,
+    pub suggestions: Vec<IdleAssetSuggestion>,
+    pub venue_errors: Vec<VenueScanError>,
+}
+
+fn first_string(row: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .find_map(|key| row.get(*key).and_then(|v| v.as_str()))
+        .map(|s| s.to_string())
+}
+
+fn first_number(row: &Value, keys: &[&str]) -> Option<f64> {
+    keys.iter().find_map(|key| match row.get(*key)? {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    })
+}
+
+fn risk_rank(level: &RiskLevel) -> u8 {
+    match level {
+        RiskLevel::Low => 0,
+        RiskLevel::Medium => 1,
+        RiskLevel::High => 2,
+        RiskLevel::Critical => 3,
+    }
+}
+
+/// Maps venue-specific tiers onto the shared scale, falling back to product type and depth
+/// when a venue gives no label.
+pub fn normalize_risk(label: Option<&str>, kind: YieldKind, tvl_usd: f64) -> RiskLevel {
+    if let Some(label) = label {
+        match label.trim().to_lowercase().as_str() {
+            "collateral" | "main" | "low" | "safe" | "conservative" | "prime" => return RiskLevel::Low,
+            "medium" | "moderate" | "balanced" | "cross" => return RiskLevel::Medium,
+            "isolated" | "high" | "aggressive" | "degen" => return RiskLevel::High,
+            "critical" | "deprecated" | "paused" => return RiskLevel::Critical,
+            _ => {}
+        }
+    }
+
+    let base = match kind {
+        YieldKind::Lending => RiskLevel::Low,
+        YieldKind::Vault => RiskLevel::Medium,
+        YieldKind::LiquidityPool => RiskLevel::Medium,
+    };
+    match tvl_usd {
+        tvl if tvl < 1_000_000.0 => RiskLevel::High,
+        tvl if tvl < 10_000_000.0 && base == RiskLevel::Low => RiskLevel::Medium,
+        _ => base,
+    }
+}
+
+/// Reads lending reserves or vaults from a venue response. Accepts a bare array or a
+/// `data` wrapper, and the field names used by Kamino, Meteora and generic indexers.
+pub fn parse_venue_rows(venue: YieldVenue, body: &Value) -> Vec<YieldOpportunity> {
+    let rows = body
+        .as_array()
+        .or_else(|| body.get("data").and_then(|d| d.as_array()))
+        .cloned()
+        .unwrap_or_default();
+    let kind = match venue {
+        YieldVenue::Meteora => YieldKind::Vault,
+        _ => YieldKind::Lending,
+    };
+
+    rows.iter()
+        .filter_map(|row| {
+            let symbol = first_string(row, &["symbol", "liquidityToken", "tokenSymbol"])?;
+            let apy = first_number(row, &["apy", "closest_apy", "lendingApy", "average_apy"])
+                .or_else(|| first_number(row, &["supplyApy", "supply_apy"]).map(|f| f * 100.0))?;
+            let mint = first_string(row, &["mint", "liquidityTokenMint", "token_address"]);
+            let tvl_usd =
+                first_number(row, &["tvlUsd", "totalSupplyUsd", "tvl", "total_deposits_usd"]).unwrap_or(0.0);
+            let label = first_string(row, &["riskTier", "risk", "tier"]);
+            let id = first_string(row, &["reserve", "address", "bank", "pubkey"])
+                .or_else(|| mint.clone())
+                .unwrap_or_else(|| symbol.clone());
+
+            Some(YieldOpportunity {
+                id: format!("{:?}:{}", venue, id).to_lowercase(),
+                protocol: venue.protocol(),
+                kind,
+                name: format!("{} {}", symbol, if kind == YieldKind::Vault { "vault" } else { "supply" }),
+                assets: vec![symbol],
+                mint,
+                apy,
+                tvl_usd,
+                risk: normalize_risk(label.as_deref(), kind, tvl_usd),
+                venue_risk_label: label,
+            })
+        })
+        .collect()
+}
+
+fn matches_asset(opportunity: &YieldOpportunity, asset: &HeldAsset) -> bool {
+    match (&opportunity.mint, opportunity.kind) {
+        (Some(mint), YieldKind::Lending | YieldKind::Vault) => mint == &asset.mint,
+        _ => opportunity
+            .assets
+            .iter()
+            .any(|symbol| symbol.eq_ignore_ascii_case(&asset.symbol)),
+    }
+}
+
+/// Wallet balances are idle by definition: deposited assets leave the wallet.
+pub fn build_suggestions(
+    holdings: &[HeldAsset],
+    opportunities: &[YieldOpportunity],
+    settings: &YieldScannerSettings,
+) -> Vec<IdleAssetSuggestion> {
+    let max_risk = risk_rank(&settings.max_risk);
+    let mut suggestions: Vec<IdleAssetSuggestion> = holdings
+        .iter()
+        .filter(|asset| asset.usd_value >= settings.min_idle_usd)
+        .filter_map(|asset| {
+            let mut matches: Vec<YieldOpportunity> = opportunities
+                .iter()
+                .filter(|o| risk_rank(&o.risk) <= max_risk && o.apy > 0.0 && matches_asset(o, asset))
+                .cloned()
+                .collect();
+            matches.sort_by(|a, b| b.apy.partial_cmp(&a.apy).unwrap_or(std::cmp::Ordering::Equal));
+
+            let best = matches.first()?.clone();
+            let yearly = asset.usd_value * best.apy / 100.0;
+            Some(IdleAssetSuggestion {
+                asset: asset.clone(),
+                projected_earnings_30d: yearly * 30.0 / 365.0,
+                projected_earnings_365d: yearly,
+                alternatives: matches.into_iter().skip(1).take(3).collect(),
+                best,
+            })
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| {
+        b.projected_earnings_365d
+            .partial_cmp(&a.projected_earnings_365d)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    suggestions
+}
+
+pub struct YieldScanner {
+    http: reqwest::Client,
+    settings_path: PathBuf,
+    settings: YieldScannerSettings,
+    last_scan: Option<YieldScanResult>,
+}
+
+pub type SharedYieldScanner = Arc<RwLock<YieldScanner>>;
+
+impl YieldScanner {
+    pub fn new(app: &AppHandle) -> Result<Self, String> {
+        let app_dir = app
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+        fs::create_dir_all(&app_dir)
+            .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+
+        let settings_path = app_dir.join(YIELD_SCANNER_SETTINGS_FILE);
+        let settings = if settings_path.exists() {
+            let data = fs::read_to_string(&settings_path)
+                .map_err(|e| format!("Failed to read yield scanner settings: {}", e))?;
+            serde_json::from_str(&data)
+                .map_err(|e| format!("Failed to parse yield scanner settings: {}", e))?
+        } else {
+            YieldScannerSettings::default()
+        };
+
+        Ok(Self {
+            http: reqwest::Client::new(),
+            settings_path,
+            settings,
+            last_scan: None,
+        })
+    }
+
+    pub fn settings(&self) -> YieldScannerSettings {
+        self.settings.clone()
+    }
+
+    pub fn update_settings(&mut self, settings: YieldScannerSettings) -> Result<(), String> {
+        for venue in &settings.venues {
+            if let Some(url) = &venue.api_url {
+                if !url.starts_with("https://") {
+                    return Err(format!("{:?} endpoint must use https", venue.venue));
+                }
+            }
+        }
+
+        let data = serde_json::to_string_pretty(&settings)
+            .map_err(|e| format!("Failed to serialize yield scanner settings: {}", e))?;
+        fs::write(&self.settings_path, data)
+            .map_err(|e| format!("Failed to write yield scanner settings: {}", e))?;
+        self.settings = settings;
+        Ok(())
+    }
+
+    pub fn last_scan(&self) -> Option<YieldScanResult> {
+        self.last_scan.clone()
+    }
+
+    async fn fetch_venue(&self, config: &YieldVenueConfig) -> Result<Vec<YieldOpportunity>, String> {
+        let url = config
+            .api_url
+            .as_deref()
+            .or_else(|| config.venue.default_url())
+            .ok_or_else(|| "No endpoint configured".to_string())?;
+
+        let body: Value = self
+            .http
+            .get(url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("Request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Parse failed: {}", e))?;
+
+        Ok(parse_venue_rows(config.venue, &body))
+    }
+
+    pub async fn scan(
+        &mut self,
+        holdings: &[HeldAsset],
+        pools: Option<&SharedPoolAnalyticsManager>,
+    ) -> YieldScanResult {
+        let mut opportunities = Vec::new();
+        let mut venue_errors = Vec::new();
+
+        for config in self.settings.venues.iter().filter(|v| v.enabled) {
+            match self.fetch_venue(config).await {
+                Ok(rows) => opportunities.extend(rows),
+                Err(error) => venue_errors.push(VenueScanError {
+                    venue: config.venue,
+                    error,
+                }),
+            }
+        }
+
+        if let (true, Some(pools)) = (self.settings.include_tracked_pools, pools) {
+            match pools.read().await.as_yield_farms().await {
+                Ok(farms) => opportunities.extend(farms.into_iter().map(|farm| YieldOpportunity {
+                    id: farm.id,
+                    protocol: farm.protocol,
+                    kind: YieldKind::LiquidityPool,
+                    name: farm.name,
+                    assets: vec![farm.token_a, farm.token_b],
+                    mint: None,
+                    apy: farm.apy,
+                    tvl_usd: farm.tvl,
+                    risk: normalize_risk(None, YieldKind::LiquidityPool, farm.tvl),
+                    venue_risk_label: None,
+                })),
+                Err(err) => tracing::warn!(error = %err, "failed to include tracked pools in yield scan"),
+            }
+        }
+
+        opportunities.sort_by(|a, b| b.apy.partial_cmp(&a.apy).unwrap_or(std::cmp::Ordering::Equal));
+        let result = YieldScanResult {
+            scanned_at: Utc::now(),
+            suggestions: build_suggestions(holdings, &opportunities, &self.settings),
+            opportunities,
+            venue_errors,
+        };
+        self.last_scan = Some(result.clone());
+        result
+    }
+}
+
+/// Current wallet holdings from the balance cache, merged across wallets by mint.
+fn collect_holdings(app: &AppHandle) -> Vec<HeldAsset> {
+    let (Some(wallets), Some(operations)) = (
+        app.try_state::<MultiWalletManager>(),
+        app.try_state::<WalletOperationsManager>(),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut merged: HashMap<String, HeldAsset> = HashMap::new();
+    match wallets.list_wallets() {
+        Ok(list) => {
+            for wallet in list {
+                for balance in operations.cached_token_balances(&wallet.public_key) {
+                    merged
+                        .entry(balance.mint.clone())
+                        .or_insert_with(|| HeldAsset {
+                            mint: balance.mint.clone(),
+                            symbol: balance.symbol.clone(),
+                            usd_value: 0.0,
+                        })
+                        .usd_value += balance.usd_value;
+                }
+            }
+        }
+        Err(err) => tracing::warn!(error = %err, "failed to list wallets for yield scan"),
+    }
+    merged.into_values().collect()
+}
+
+async fn run_scan(app: &AppHandle, scanner: &SharedYieldScanner) -> YieldScanResult {
+    let holdings = collect_holdings(app);
+    let pools = app.try_state::<SharedPoolAnalyticsManager>();
+    let result = scanner
+        .write()
+        .await
+        .scan(&holdings, pools.as_ref().map(|p| p.inner()))
+        .await;
+
+    if let Err(err) = app.emit_all(YIELD_OPPORTUNITIES_EVENT, &result) {
+        tracing::warn!(error = %err, "failed to emit yield opportunities");
+    }
+    result
+}
+
+pub fn start_yield_scanner(app: AppHandle, scanner: SharedYieldScanner) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(SCAN_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            run_scan(&app, &scanner).await;
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn get_yield_opportunities(
+    refresh: Option<bool>,
+    app_handle: AppHandle,
+    scanner: State<'_, SharedYieldScanner>,
+) -> Result<YieldScanResult, String> {
+    if !refresh.unwrap_or(false) {
+        if let Some(result) = scanner.read().await.last_scan() {
+            return Ok(result);
+        }
+    }
+    Ok(run_scan(&app_handle, scanner.inner()).await)
+}
+
+#[tauri::command]
+pub async fn get_yield_scanner_settings(
+    scanner: State<'_, SharedYieldScanner>,
+) -> Result<YieldScannerSettings, String> {
+    Ok(scanner.read().await.settings())
+}
+
+#[tauri::command]
+pub async fn update_yield_scanner_settings(
+    settings: YieldScannerSettings,
+    scanner: State<'_, SharedYieldScanner>,
+) -> Result<YieldScannerSettings, String> {
+    scanner.write().await.update_settings(settings.clone())?;
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_kamino_reserves_and_meteora_vaults() {
+        let kamino = json!([{
+            "reserve": "res1",
+            "liquidityToken": "USDC",
+            "liquidityTokenMint": "usdc-mint",
+            "supplyApy": "0.085",
+            "totalSupplyUsd": "250000000"
+        }]);
+        let rows = parse_venue_rows(YieldVenue::Kamino, &kamino);
+        assert_eq!(rows.len(), 1);
+        assert!((rows[0].apy - 8.5).abs() < 1e-9);
+        assert_eq!(rows[0].risk, RiskLevel::Low);
+        assert_eq!(rows[0].mint.as_deref(), Some("usdc-mint"));
+
+        let meteora = json!({"data": [{"symbol": "SOL", "token_address": "sol-mint", "closest_apy": 6.2}]});
+        let rows = parse_venue_rows(YieldVenue::Meteora, &meteora);
+        assert_eq!(rows[0].kind, YieldKind::Vault);
+        // No TVL reported: treated as shallow.
+        assert_eq!(rows[0].risk, RiskLevel::High);
+    }
+
+    #[test]
+    fn venue_labels_take_precedence_over_heuristics() {
+        assert_eq!(normalize_risk(Some("Isolated"), YieldKind::Lending, 1e9), RiskLevel::High);
+        assert_eq!(normalize_risk(Some("Collateral"), YieldKind::Lending, 1e5), RiskLevel::Low);
+        assert_eq!(normalize_risk(None, YieldKind::Lending, 5e6), RiskLevel::Medium);
+        assert_eq!(normalize_risk(Some("unknown"), YieldKind::LiquidityPool, 5e7), RiskLevel::Medium);
+    }
+
+    fn opportunity(id: &str, assets: &[&str], mint: Option<&str>, apy: f64, risk: RiskLevel) -> YieldOpportunity {
+        YieldOpportunity {
+            id: id.to_string(),
+            protocol: Protocol::Kamino,
+            kind: if assets.len() > 1 { YieldKind::LiquidityPool } else { YieldKind::Lending },
+            name: id.to_string(),
+            assets: assets.iter().map(|s| s.to_string()).collect(),
+            mint: mint.map(|m| m.to_string()),
+            apy,
+            tvl_usd: 1e8,
+            risk,
+            venue_risk_label: None,
+        }
+    }
+
+    #[test]
+    fn suggests_best_opportunity_within_risk_for_idle_assets() {
+        let holdings = vec![
+            HeldAsset { mint: "usdc-mint".into(), symbol: "USDC".into(), usd_value: 10_000.0 },
+            HeldAsset { mint: "bonk-mint".into(), symbol: "BONK".into(), usd_value: 20.0 },
+        ];
+        let opportunities = vec![
+            opportunity("lend", &["USDC"], Some("usdc-mint"), 8.0, RiskLevel::Low),
+            opportunity("pool", &["SOL", "USDC"], None, 20.0, RiskLevel::Medium),
+            opportunity("degen", &["USDC"], Some("usdc-mint"), 60.0, RiskLevel::Critical),
+            opportunity("bonk", &["BONK"], Some("bonk-mint"), 30.0, RiskLevel::Low),
+        ];
+
+        let suggestions = build_suggestions(&holdings, &opportunities, &YieldScannerSettings::default());
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].best.id, "pool");
+        assert_eq!(suggestions[0].alternatives.len(), 1);
+        assert!((suggestions[0].projected_earnings_365d - 2000.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,291 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+use crate::bots::dca_bot::CreateDcaRequest;
+
+const HOUR_SECS: i64 = 3600;
+pub const DEMO_WALLET_ADDRESS: &str = "DemoWa11et1111111111111111111111111111111111";
+
+#[derive(Debug, Clone, Copy)]
+pub struct DemoToken {
+    pub symbol: &'static str,
+    pub name: &'static str,
+    pub address: &'static str,
+    pub decimals: u8,
+    pub base_price: f64,
+    pub market_cap: f64,
+}
+
+/// The single token universe every demo fixture is drawn from.
+pub const DEMO_TOKENS: &[DemoToken] = &[
+    DemoToken { symbol: "SOL", name: "Solana", address: "So11111111111111111111111111111111111111112", decimals: 9, base_price: 150.0, market_cap: 70_000_000_000.0 },
+    DemoToken { symbol: "USDC", name: "USD Coin", address: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", decimals: 6, base_price: 1.0, market_cap: 25_000_000_000.0 },
+    DemoToken { symbol: "JUP", name: "Jupiter", address: "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN", decimals: 6, base_price: 1.23, market_cap: 1_500_000_000.0 },
+    DemoToken { symbol: "BONK", name: "Bonk", address: "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", decimals: 5, base_price: 0.000023, market_cap: 1_000_000_000.0 },
+    DemoToken { symbol: "WIF", name: "dogwifhat", address: "EKpQGSJtjMFqKZ9KQanSqYXRcF8fBopzLHYxdM65zcjm", decimals: 6, base_price: 2.45, market_cap: 3_500_000_000.0 },
+    DemoToken { symbol: "PYTH", name: "Pyth Network", address: "HZ1JovNiVvGrGNiiYvEozEVgZ58xaU3RKwX8eACQBCt3", decimals: 6, base_price: 0.87, market_cap: 2_800_000_000.0 },
+    DemoToken { symbol: "JTO", name: "Jito", address: "jtojtomepa8beP8AuQc6eXt5FriJwfFMwQx2v2f9mCL", decimals: 9, base_price: 3.21, market_cap: 4_100_000_000.0 },
+    DemoToken { symbol: "ORCA", name: "Orca", address: "orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE", decimals: 6, base_price: 4.56, market_cap: 3_600_000_000.0 },
+    DemoToken { symbol: "RAY", name: "Raydium", address: "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R", decimals: 6, base_price: 3.78, market_cap: 1_000_000_000.0 },
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoTokenSnapshot {
+    pub symbol: String,
+    pub name: String,
+    pub address: String,
+    pub price: f64,
+    pub price_change_24h: f64,
+    pub volume_24h: f64,
+    pub market_cap: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoCandle {
+    pub timestamp: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoSocialPost {
+    pub id: String,
+    pub symbol: String,
+    pub text: String,
+    pub source: String,
+    pub author: String,
+    pub timestamp: i64,
+    pub engagement: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoPosition {
+    pub symbol: String,
+    pub amount: f64,
+    pub average_price: f64,
+    pub current_price: f64,
+    pub value: f64,
+    pub unrealized_pnl: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoDataset {
+    pub wallet_address: String,
+    pub tokens: Vec<DemoTokenSnapshot>,
+    pub social_posts: Vec<DemoSocialPost>,
+    pub portfolio: Vec<DemoPosition>,
+    pub bots: Vec<CreateDcaRequest>,
+}
+
+pub fn find_demo_token(symbol_or_address: &str) -> Option<&'static DemoToken> {
+    DEMO_TOKENS.iter().find(|token| {
+        token.address == symbol_or_address || token.symbol.eq_ignore_ascii_case(symbol_or_address)
+    })
+}
+
+fn token_phase(symbol: &str) -> f64 {
+    // Stable per-token offset so charts don't move in lockstep
+    let seed = symbol.bytes().fold(7u64, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u64));
+    (seed % 1000) as f64 / 1000.0 * 2.0 * PI
+}
+
+/// Deterministic price curve shared by every demo view, so a token shows the same
+/// price on charts, in the portfolio and in bot fills.
+pub fn demo_price_at(token: &DemoToken, timestamp: i64) -> f64 {
+    if token.symbol == "USDC" {
+        return token.base_price;
+    }
+
+    let hours = timestamp as f64 / HOUR_SECS as f64;
+    let phase = token_phase(token.symbol);
+    let daily = 0.06 * (hours / 24.0 * 2.0 * PI + phase).sin();
+    let weekly = 0.12 * (hours / 168.0 * 2.0 * PI + phase * 0.5).sin();
+    let noise = 0.01 * (hours * 1.7 + phase * 3.0).sin();
+    token.base_price * (1.0 + daily + weekly + noise)
+}
+
+pub fn demo_price(token: &DemoToken) -> f64 {
+    demo_price_at(token, Utc::now().timestamp())
+}
+
+pub fn demo_change_24h(token: &DemoToken) -> f64 {
+    let now = Utc::now().timestamp();
+    let previous = demo_price_at(token, now - 24 * HOUR_SECS);
+    (demo_price_at(token, now) - previous) / previous * 100.0
+}
+
+pub fn demo_volume_24h(token: &DemoToken) -> f64 {
+    token.market_cap * (0.05 + 0.02 * (token_phase(token.symbol)).sin().abs())
+}
+
+pub fn demo_snapshot(token: &DemoToken) -> DemoTokenSnapshot {
+    DemoTokenSnapshot {
+        symbol: token.symbol.to_string(),
+        name: token.name.to_string(),
+        address: token.address.to_string(),
+        price: demo_price(token),
+        price_change_24h: demo_change_24h(token),
+        volume_24h: demo_volume_24h(token),
+        market_cap: token.market_cap,
+    }
+}
+
+/// Hourly candles ending at the current hour; the last close is the current demo price.
+pub fn demo_price_history(token: &DemoToken, hours: i64) -> Vec<DemoCandle> {
+    let now = Utc::now().timestamp();
+    let hourly_volume = demo_volume_24h(token) / 24.0;
+
+    (0..hours)
+        .rev()
+        .map(|offset| {
+            let close_at = now - offset * HOUR_SECS;
+            let open = demo_price_at(token, close_at - HOUR_SECS);
+            let close = demo_price_at(token, close_at);
+            let mid = demo_price_at(token, close_at - HOUR_SECS / 2);
+            DemoCandle {
+                timestamp: close_at - HOUR_SECS,
+                open,
+                high: open.max(close).max(mid),
+                low: open.min(close).min(mid),
+                close,
+                volume: hourly_volume * (1.0 + 0.3 * ((close_at / HOUR_SECS) as f64).sin()),
+            }
+        })
+        .collect()
+}
+
+pub fn demo_social_posts(symbol: &str) -> Vec<DemoSocialPost> {
+    let now = Utc::now().timestamp();
+    let templates = [
+        "{} looks strong here, great fundamentals and an active team.",
+        "Love the recent {} updates. Bullish on this one!",
+        "Not sure about {}. Price action looks weak this week.",
+        "Just added more {} on the dip.",
+        "{} volume is picking up, keeping an eye on support levels.",
+        "Taking some {} profits after the run.",
+    ];
+
+    templates
+        .iter()
+        .enumerate()
+        .map(|(i, template)| DemoSocialPost {
+            id: format!("demo_post_{}_{}", symbol.to_lowercase(), i),
+            symbol: symbol.to_uppercase(),
+            text: template.replace("{}", &symbol.to_uppercase()),
+            source: if i % 2 == 0 { "twitter" } else { "reddit" }.to_string(),
+            author: format!("demo_trader_{}", i),
+            timestamp: now - (i as i64 * HOUR_SECS),
+            engagement: (120 + i * 45) as i32,
+        })
+        .collect()
+}
+
+pub fn demo_portfolio() -> Vec<DemoPosition> {
+    [("SOL", 25.5, 138.0), ("JUP", 1200.0, 1.31), ("BONK", 45_000_000.0, 0.000019), ("USDC", 2500.0, 1.0)]
+        .iter()
+        .filter_map(|(symbol, amount, average_price)| {
+            let token = find_demo_token(symbol)?;
+            let current_price = demo_price(token);
+            Some(DemoPosition {
+                symbol: token.symbol.to_string(),
+                amount: *amount,
+                average_price: *average_price,
+                current_price,
+                value: amount * current_price,
+                unrealized_pnl: amount * (current_price - average_price),
+            })
+        })
+        .collect()
+}
+
+pub fn demo_bots() -> Vec<CreateDcaRequest> {
+    let usdc = find_demo_token("USDC").map(|t| t.address).unwrap_or_default();
+
+    [("Weekly SOL stack", "SOL", 9, 50.0, "0 0 9 * * MON"), ("Daily JUP", "JUP", 6, 10.0, "0 0 12 * * *")]
+        .iter()
+        .filter_map(|(name, symbol, decimals, amount, cron)| {
+            let token = find_demo_token(symbol)?;
+            Some(CreateDcaRequest {
+                name: name.to_string(),
+                wallet_address: DEMO_WALLET_ADDRESS.to_string(),
+                input_mint: usdc.to_string(),
+                output_mint: token.address.to_string(),
+                input_symbol: "USDC".to_string(),
+                output_symbol: token.symbol.to_string(),
+                input_decimals: 6,
+                output_decimals: *decimals,
+                amount_per_execution: *amount,
+                total_budget: amount * 52.0,
+                schedule_cron: cron.to_string(),
+                slippage_bps: 50,
+                priority_fee_micro_lamports: 0,
+                max_price_impact_pct: 1.0,
+                daily_spend_cap: None,
+            })
+        })
+        .collect()
+}
+
+pub fn build_demo_dataset() -> DemoDataset {
+    DemoDataset {
+        wallet_address: DEMO_WALLET_ADDRESS.to_string(),
+        tokens: DEMO_TOKENS.iter().map(demo_snapshot).collect(),
+        social_posts: DEMO_TOKENS
+            .iter()
+            .take(4)
+            .flat_map(|token| demo_social_posts(token.symbol))
+            .collect(),
+        portfolio: demo_portfolio(),
+        bots: demo_bots(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_tokens_by_symbol_or_address() {
+        assert_eq!(find_demo_token("sol").map(|t| t.symbol), Some("SOL"));
+        assert_eq!(
+            find_demo_token("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN").map(|t| t.symbol),
+            Some("JUP")
+        );
+        assert!(find_demo_token("NOPE").is_none());
+    }
+
+    #[test]
+    fn price_curve_is_deterministic_and_consistent() {
+        let sol = find_demo_token("SOL").unwrap();
+        assert_eq!(demo_price_at(sol, 1_700_000_000), demo_price_at(sol, 1_700_000_000));
+
+        let history = demo_price_history(sol, 24);
+        assert_eq!(history.len(), 24);
+        let last = history.last().unwrap();
+        assert!((last.close - demo_price(sol)).abs() / last.close < 0.01);
+        assert!(history.iter().all(|c| c.low <= c.open && c.high >= c.close));
+    }
+
+    #[test]
+    fn portfolio_and_bots_use_demo_universe() {
+        let dataset = build_demo_dataset();
+        assert!(dataset
+            .portfolio
+            .iter()
+            .all(|p| find_demo_token(&p.symbol).is_some()));
+        assert!(dataset
+            .bots
+            .iter()
+            .all(|b| find_demo_token(&b.output_mint).is_some()));
+        assert_eq!(find_demo_token("USDC").map(demo_price), Some(1.0));
+    }
+}
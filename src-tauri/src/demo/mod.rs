@@ -0,0 +1,99 @@
+pub mod fixtures;
+
+pub use fixtures::*;
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Manager};
+
+const DEMO_CONFIG_FILE: &str = "demo_mode.json";
+const DEMO_MODE_EVENT: &str = "demo_mode_changed";
+
+// Process-wide so the data-source factory used by market and social code can check
+// it without threading state through every call.
+static DEMO_MODE: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DemoModeConfig {
+    enabled: bool,
+}
+
+pub fn is_demo_mode() -> bool {
+    DEMO_MODE.load(Ordering::Relaxed)
+}
+
+/// Guard for sources that only have fixtures so far: outside demo mode they
+/// report the data as unavailable instead of serving made-up values.
+pub fn require_demo_mode(feature: &str) -> Result<(), String> {
+    if is_demo_mode() {
+        Ok(())
+    } else {
+        Err(format!("UNAVAILABLE: {feature} is not available outside demo mode"))
+    }
+}
+
+fn config_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut path = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+    if !path.exists() {
+        fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+    }
+    path.push(DEMO_CONFIG_FILE);
+    Ok(path)
+}
+
+/// Restores the persisted flag; demo mode stays off unless the user turned it on.
+pub fn load_demo_mode(app: &AppHandle) -> Result<bool, String> {
+    let path = config_path(app)?;
+    let config = if path.exists() {
+        let data = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        serde_json::from_str::<DemoModeConfig>(&data).map_err(|e| e.to_string())?
+    } else {
+        DemoModeConfig::default()
+    };
+
+    DEMO_MODE.store(config.enabled, Ordering::Relaxed);
+    Ok(config.enabled)
+}
+
+fn save_demo_mode(app: &AppHandle, enabled: bool) -> Result<(), String> {
+    let path = config_path(app)?;
+    let json = serde_json::to_string_pretty(&DemoModeConfig { enabled }).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_demo_mode() -> Result<bool, String> {
+    Ok(is_demo_mode())
+}
+
+#[tauri::command]
+pub async fn set_demo_mode(enabled: bool, app_handle: AppHandle) -> Result<bool, String> {
+    save_demo_mode(&app_handle, enabled)?;
+    DEMO_MODE.store(enabled, Ordering::Relaxed);
+
+    if let Some(data) = app_handle.try_state::<crate::portfolio::SharedPortfolioData>() {
+        if let Ok(mut data) = data.lock() {
+            data.reseed();
+        }
+    }
+
+    if let Err(err) = app_handle.emit_all(DEMO_MODE_EVENT, enabled) {
+        tracing::warn!(error = %err, "failed to emit demo mode change");
+    }
+
+    Ok(enabled)
+}
+
+#[tauri::command]
+pub async fn get_demo_dataset() -> Result<DemoDataset, String> {
+    if !is_demo_mode() {
+        return Err("Demo mode is disabled".to_string());
+    }
+    Ok(build_demo_dataset())
+}
@@ -6,6 +6,7 @@ pub mod file_repair;
 pub mod issue_detector;
 pub mod network_repair;
 pub mod performance_repair;
+pub mod startup_check;
 pub mod types;
 
 pub mod tauri_commands;
@@ -0,0 +1,722 @@
+use super::types::HealthLevel;
+use crate::api_config::{ping_service, ApiConfigManager, API_SERVICES};
+use crate::security::keystore::Keystore;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool};
+use sqlx::Row;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tokio::sync::RwLock;
+
+const STARTUP_HEALTH_EVENT: &str = "startup_health";
+const KEYSTORE_PROBE_KEY: &str = "startup_health_probe";
+const REPAIR_BACKUP_SUFFIX: &str = "corrupt";
+
+pub type SharedStartupHealth = Arc<RwLock<Option<StartupHealthReport>>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupCheckConfig {
+    pub enabled: bool,
+    pub check_databases: bool,
+    pub check_keystore: bool,
+    pub ping_apis: bool,
+    pub api_timeout_secs: u64,
+    /// Database files left out of the pass, e.g. very large archives.
+    #[serde(default)]
+    pub skip_databases: Vec<String>,
+}
+
+impl Default for StartupCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            check_databases: true,
+            check_keystore: true,
+            ping_apis: true,
+            api_timeout_secs: 5,
+            skip_databases: Vec::new(),
+        }
+    }
+}
+
+impl StartupCheckConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.api_timeout_secs == 0 || self.api_timeout_secs > 60 {
+            return Err("API timeout must be between 1 and 60 seconds".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupCheckCategory {
+    Database,
+    Schema,
+    Keystore,
+    Api,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupCheckStatus {
+    Passed,
+    Warning,
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SelfRepairKind {
+    /// Back up and recreate a database that only holds refetchable data.
+    RebuildCacheDatabase,
+    /// Run the owning module's table setup again to create missing tables.
+    RerunMigrations,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfRepairOption {
+    pub kind: SelfRepairKind,
+    pub target: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupCheckResult {
+    /// Stable across runs, e.g. `database:holders.db`.
+    pub id: String,
+    pub category: StartupCheckCategory,
+    pub name: String,
+    pub status: StartupCheckStatus,
+    pub detail: String,
+    pub duration_ms: u64,
+    pub repair: Option<SelfRepairOption>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupHealthReport {
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub overall: HealthLevel,
+    pub passed: usize,
+    pub warnings: usize,
+    pub failed: usize,
+    pub checks: Vec<StartupCheckResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfRepairResult {
+    pub check_id: String,
+    pub kind: SelfRepairKind,
+    pub message: String,
+    pub backup_location: Option<String>,
+    /// Modules keep their connection open, so a rebuilt database is only picked
+    /// up after a restart.
+    pub restart_required: bool,
+}
+
+/// A database whose schema the startup pass knows about.
+struct KnownDatabase {
+    file: &'static str,
+    tables: &'static [&'static str],
+    cache: bool,
+}
+
+const KNOWN_DATABASES: &[KnownDatabase] = &[
+    KnownDatabase {
+        file: "historical_replay.db",
+        tables: &[
+            "historical_prices",
+            "historical_orderbooks",
+            "data_cache_metadata",
+        ],
+        cache: true,
+    },
+    KnownDatabase {
+        file: "holders.db",
+        tables: &[
+            "holders",
+            "holder_trends",
+            "large_transfers",
+            "token_metadata",
+            "verification_status",
+            "vulnerabilities",
+            "holder_snapshots",
+        ],
+        cache: false,
+    },
+    KnownDatabase {
+        file: "watchlists.db",
+        tables: &["watchlists", "watchlist_items"],
+        cache: false,
+    },
+    KnownDatabase {
+        file: "price_alerts.db",
+        tables: &["price_alerts"],
+        cache: false,
+    },
+    KnownDatabase {
+        file: "webhooks.db",
+        tables: &[
+            "webhooks",
+            "webhook_delivery_logs",
+            "webhook_dead_letters",
+            "webhook_subscriptions",
+        ],
+        cache: false,
+    },
+    KnownDatabase {
+        file: "risk_scores.db",
+        tables: &["risk_scores", "risk_models"],
+        cache: false,
+    },
+];
+
+fn known_database(file: &str) -> Option<&'static KnownDatabase> {
+    KNOWN_DATABASES.iter().find(|db| db.file == file)
+}
+
+/// Re-runs the table setup of the module owning `file`. Every module creates its
+/// tables with `IF NOT EXISTS`, so this only adds what is missing.
+async fn rerun_migrations(app: &AppHandle, file: &str) -> Result<(), String> {
+    match file {
+        "historical_replay.db" => crate::data::historical::HistoricalReplayManager::new(app, None)
+            .await
+            .map(drop),
+        "holders.db" => crate::market::HolderAnalyzer::new(app)
+            .await
+            .map(drop)
+            .map_err(|e| e.to_string()),
+        "watchlists.db" => crate::portfolio::WatchlistManager::new(app)
+            .await
+            .map(drop)
+            .map_err(|e| e.to_string()),
+        "price_alerts.db" => crate::alerts::price_alerts::AlertManager::new(app)
+            .await
+            .map(drop)
+            .map_err(|e| e.to_string()),
+        "webhooks.db" => crate::webhooks::manager::WebhookManager::new(app)
+            .await
+            .map(drop)
+            .map_err(|e| e.to_string()),
+        "risk_scores.db" => crate::ai::RiskAnalyzer::new(app)
+            .await
+            .map(drop)
+            .map_err(|e| e.to_string()),
+        _ => Err(format!("No migrations registered for {}", file)),
+    }
+}
+
+pub fn missing_tables(expected: &[&str], present: &BTreeSet<String>) -> Vec<String> {
+    expected
+        .iter()
+        .filter(|table| !present.contains(**table))
+        .map(|table| table.to_string())
+        .collect()
+}
+
+pub fn summarize_startup_checks(
+    checks: &[StartupCheckResult],
+) -> (HealthLevel, usize, usize, usize) {
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let passed = count(StartupCheckStatus::Passed);
+    let warnings = count(StartupCheckStatus::Warning);
+    let failed = count(StartupCheckStatus::Failed);
+
+    let keystore_failed = checks.iter().any(|c| {
+        c.category == StartupCheckCategory::Keystore && c.status == StartupCheckStatus::Failed
+    });
+    let overall = if checks.is_empty() {
+        HealthLevel::Unknown
+    } else if keystore_failed || failed * 4 >= checks.len() {
+        HealthLevel::Critical
+    } else if failed > 0 {
+        HealthLevel::Degraded
+    } else if warnings > 0 {
+        HealthLevel::Good
+    } else {
+        HealthLevel::Excellent
+    };
+    (overall, passed, warnings, failed)
+}
+
+fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Failed to resolve app data directory".to_string())
+}
+
+fn config_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(app_data_dir(app)?
+        .join("settings")
+        .join("startup_check.json"))
+}
+
+pub fn load_startup_check_config(app: &AppHandle) -> StartupCheckConfig {
+    config_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn result(
+    id: String,
+    category: StartupCheckCategory,
+    name: String,
+    status: StartupCheckStatus,
+    detail: String,
+    started: Instant,
+) -> StartupCheckResult {
+    StartupCheckResult {
+        id,
+        category,
+        name,
+        status,
+        detail,
+        duration_ms: started.elapsed().as_millis() as u64,
+        repair: None,
+    }
+}
+
+/// Opens the database read-only, runs SQLite's quick check and reads the table list.
+async fn inspect_database(path: &Path) -> Result<(String, i64, BTreeSet<String>), String> {
+    let options = SqliteConnectOptions::from_str(&format!("sqlite:{}", path.display()))
+        .map_err(|e| e.to_string())?
+        .read_only(true);
+    let pool = SqlitePool::connect_with(options)
+        .await
+        .map_err(|e| format!("Failed to open: {}", e))?;
+
+    let integrity: String = sqlx::query_scalar("PRAGMA quick_check")
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| format!("Integrity check failed: {}", e))?;
+    let user_version: i64 = sqlx::query_scalar("PRAGMA user_version")
+        .fetch_one(&pool)
+        .await
+        .unwrap_or(0);
+    let tables = sqlx::query("SELECT name FROM sqlite_master WHERE type = 'table'")
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Failed to read schema: {}", e))?
+        .iter()
+        .map(|row| row.get::<String, _>("name"))
+        .collect();
+    pool.close().await;
+
+    Ok((integrity, user_version, tables))
+}
+
+async fn check_databases(dir: &Path, config: &StartupCheckConfig) -> Vec<StartupCheckResult> {
+    let mut files: BTreeSet<String> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter(|name| name.ends_with(".db"))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.retain(|file| !config.skip_databases.contains(file));
+
+    let mut results = Vec::new();
+    for file in files {
+        let started = Instant::now();
+        let known = known_database(&file);
+        let cache = known.map(|db| db.cache).unwrap_or(false) || file.contains("cache");
+        let rebuild = cache.then(|| SelfRepairOption {
+            kind: SelfRepairKind::RebuildCacheDatabase,
+            target: file.clone(),
+            description: format!(
+                "Back up {} and recreate it empty; cached data is refetched",
+                file
+            ),
+        });
+
+        let (integrity, user_version, tables) = match inspect_database(&dir.join(&file)).await {
+            Ok(inspection) => inspection,
+            Err(err) => {
+                let mut failed = result(
+                    format!("database:{}", file),
+                    StartupCheckCategory::Database,
+                    file.clone(),
+                    StartupCheckStatus::Failed,
+                    err,
+                    started,
+                );
+                failed.repair = rebuild;
+                results.push(failed);
+                continue;
+            }
+        };
+
+        let mut database = result(
+            format!("database:{}", file),
+            StartupCheckCategory::Database,
+            file.clone(),
+            StartupCheckStatus::Passed,
+            format!("{} tables, integrity {}", tables.len(), integrity),
+            started,
+        );
+        if integrity != "ok" {
+            database.status = StartupCheckStatus::Failed;
+            database.repair = rebuild;
+        }
+        results.push(database);
+
+        let Some(known) = known else {
+            continue;
+        };
+        let missing = missing_tables(known.tables, &tables);
+        let mut schema = result(
+            format!("schema:{}", file),
+            StartupCheckCategory::Schema,
+            file.clone(),
+            StartupCheckStatus::Passed,
+            format!(
+                "Schema version {}, {} expected tables present",
+                user_version,
+                known.tables.len()
+            ),
+            started,
+        );
+        if !missing.is_empty() {
+            schema.status = StartupCheckStatus::Failed;
+            schema.detail = format!(
+                "Schema version {}, missing tables: {}",
+                user_version,
+                missing.join(", ")
+            );
+            schema.repair = Some(SelfRepairOption {
+                kind: SelfRepairKind::RerunMigrations,
+                target: file.clone(),
+                description: format!("Re-run table setup for {}", file),
+            });
+        }
+        results.push(schema);
+    }
+    results
+}
+
+/// Writes, reads back and removes a probe secret.
+fn check_keystore(app: &AppHandle) -> StartupCheckResult {
+    let started = Instant::now();
+    let outcome = match app.try_state::<Keystore>() {
+        None => Err("Keystore was not initialized".to_string()),
+        Some(keystore) => {
+            let probe = Utc::now().timestamp().to_string();
+            keystore
+                .store_secret(KEYSTORE_PROBE_KEY, probe.as_bytes())
+                .and_then(|_| keystore.retrieve_secret(KEYSTORE_PROBE_KEY))
+                .map_err(|e| e.to_string())
+                .and_then(|secret| {
+                    let _ = keystore.remove_secret(KEYSTORE_PROBE_KEY);
+                    if secret.as_slice() == probe.as_bytes() {
+                        Ok(())
+                    } else {
+                        Err("Keystore returned a different value than was stored".to_string())
+                    }
+                })
+        }
+    };
+
+    let (status, detail) = match outcome {
+        Ok(()) => (
+            StartupCheckStatus::Passed,
+            "Secrets can be stored and read".to_string(),
+        ),
+        Err(err) => (StartupCheckStatus::Failed, err),
+    };
+    result(
+        "keystore".to_string(),
+        StartupCheckCategory::Keystore,
+        "Keystore".to_string(),
+        status,
+        detail,
+        started,
+    )
+}
+
+/// API failures are warnings: the app still starts and falls back where it can.
+async fn check_apis(app: &AppHandle, timeout: Duration) -> Vec<StartupCheckResult> {
+    let (Some(keystore), Some(manager)) = (
+        app.try_state::<Keystore>(),
+        app.try_state::<ApiConfigManager>(),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut results = Vec::new();
+    for service in API_SERVICES {
+        let started = Instant::now();
+        let ping = tokio::time::timeout(timeout, ping_service(service, &keystore, &manager)).await;
+        let (status, detail) = match ping {
+            Ok(Ok(code)) => (StartupCheckStatus::Passed, format!("HTTP {}", code)),
+            Ok(Err(err)) => (StartupCheckStatus::Warning, err),
+            Err(_) => (
+                StartupCheckStatus::Warning,
+                format!("No response within {}s", timeout.as_secs()),
+            ),
+        };
+        results.push(result(
+            format!("api:{}", service),
+            StartupCheckCategory::Api,
+            service.to_string(),
+            status,
+            detail,
+            started,
+        ));
+    }
+    results
+}
+
+pub async fn run_startup_check(
+    app: &AppHandle,
+    config: &StartupCheckConfig,
+) -> StartupHealthReport {
+    let started_at = Utc::now();
+    let started = Instant::now();
+    let mut checks = Vec::new();
+
+    if config.check_databases {
+        match app_data_dir(app) {
+            Ok(dir) => checks.extend(check_databases(&dir, config).await),
+            Err(err) => tracing::warn!(error = %err, "skipping database health checks"),
+        }
+    }
+    if config.check_keystore {
+        checks.push(check_keystore(app));
+    }
+    if config.ping_apis {
+        checks.extend(check_apis(app, Duration::from_secs(config.api_timeout_secs)).await);
+    }
+
+    let (overall, passed, warnings, failed) = summarize_startup_checks(&checks);
+    StartupHealthReport {
+        started_at,
+        duration_ms: started.elapsed().as_millis() as u64,
+        overall,
+        passed,
+        warnings,
+        failed,
+        checks,
+    }
+}
+
+async fn publish_report(app: &AppHandle, report: StartupHealthReport) -> StartupHealthReport {
+    if let Err(err) = app.emit_all(STARTUP_HEALTH_EVENT, &report) {
+        tracing::warn!(error = %err, "failed to emit startup health report");
+    }
+    if let Some(state) = app.try_state::<SharedStartupHealth>() {
+        *state.write().await = Some(report.clone());
+    }
+    report
+}
+
+/// Runs the health pass once the other modules have created their databases.
+pub fn register_startup_check(app: &tauri::App) {
+    let state: SharedStartupHealth = Arc::new(RwLock::new(None));
+    app.manage(state);
+
+    let handle = app.handle();
+    tauri::async_runtime::spawn(async move {
+        let config = load_startup_check_config(&handle);
+        if !config.enabled {
+            return;
+        }
+        let report = run_startup_check(&handle, &config).await;
+        if report.failed > 0 {
+            tracing::warn!(
+                failed = report.failed,
+                "startup health check found problems"
+            );
+        }
+        publish_report(&handle, report).await;
+    });
+}
+
+async fn rebuild_cache_database(app: &AppHandle, file: &str) -> Result<SelfRepairResult, String> {
+    let path = app_data_dir(app)?.join(file);
+    let backup = format!(
+        "{}.{}.{}",
+        path.display(),
+        REPAIR_BACKUP_SUFFIX,
+        Utc::now().timestamp()
+    );
+    std::fs::rename(&path, &backup).map_err(|e| format!("Failed to back up {}: {}", file, e))?;
+    for suffix in ["-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+    }
+    if known_database(file).is_some() {
+        rerun_migrations(app, file).await?;
+    }
+
+    Ok(SelfRepairResult {
+        check_id: format!("database:{}", file),
+        kind: SelfRepairKind::RebuildCacheDatabase,
+        message: format!("{} was rebuilt; cached data will be refetched", file),
+        backup_location: Some(backup),
+        restart_required: true,
+    })
+}
+
+#[tauri::command]
+pub async fn get_startup_health(
+    state: tauri::State<'_, SharedStartupHealth>,
+) -> Result<Option<StartupHealthReport>, String> {
+    Ok(state.read().await.clone())
+}
+
+#[tauri::command]
+pub async fn run_startup_health_check(
+    app_handle: AppHandle,
+) -> Result<StartupHealthReport, String> {
+    let config = load_startup_check_config(&app_handle);
+    let report = run_startup_check(&app_handle, &config).await;
+    Ok(publish_report(&app_handle, report).await)
+}
+
+#[tauri::command]
+pub async fn get_startup_check_config(app_handle: AppHandle) -> Result<StartupCheckConfig, String> {
+    Ok(load_startup_check_config(&app_handle))
+}
+
+#[tauri::command]
+pub async fn update_startup_check_config(
+    config: StartupCheckConfig,
+    app_handle: AppHandle,
+) -> Result<StartupCheckConfig, String> {
+    config.validate()?;
+    let path = config_path(&app_handle)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create settings directory: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(&config)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write settings: {}", e))?;
+    Ok(config)
+}
+
+/// Applies the repair offered by a check from the last report. Nothing is touched
+/// unless the user confirmed it.
+#[tauri::command]
+pub async fn run_startup_repair(
+    check_id: String,
+    confirmed: bool,
+    app_handle: AppHandle,
+    state: tauri::State<'_, SharedStartupHealth>,
+) -> Result<SelfRepairResult, String> {
+    let repair = state
+        .read()
+        .await
+        .as_ref()
+        .and_then(|report| report.checks.iter().find(|check| check.id == check_id))
+        .ok_or_else(|| format!("No check {} in the last health report", check_id))?
+        .repair
+        .clone()
+        .ok_or_else(|| format!("Check {} has no repair option", check_id))?;
+    if !confirmed {
+        return Err(format!("Confirm before running: {}", repair.description));
+    }
+
+    let outcome = match repair.kind {
+        SelfRepairKind::RebuildCacheDatabase => {
+            rebuild_cache_database(&app_handle, &repair.target).await?
+        }
+        SelfRepairKind::RerunMigrations => {
+            rerun_migrations(&app_handle, &repair.target).await?;
+            SelfRepairResult {
+                check_id: check_id.clone(),
+                kind: repair.kind,
+                message: format!("Table setup for {} completed", repair.target),
+                backup_location: None,
+                restart_required: false,
+            }
+        }
+    };
+    tracing::info!(check_id = %check_id, kind = ?repair.kind, "startup self-repair applied");
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(category: StartupCheckCategory, status: StartupCheckStatus) -> StartupCheckResult {
+        StartupCheckResult {
+            id: "check".to_string(),
+            category,
+            name: "check".to_string(),
+            status,
+            detail: String::new(),
+            duration_ms: 0,
+            repair: None,
+        }
+    }
+
+    #[test]
+    fn test_missing_tables() {
+        let present: BTreeSet<String> = ["watchlists".to_string()].into_iter().collect();
+        let missing = missing_tables(&["watchlists", "watchlist_items"], &present);
+        assert_eq!(missing, vec!["watchlist_items".to_string()]);
+        assert!(missing_tables(&["watchlists"], &present).is_empty());
+    }
+
+    #[test]
+    fn test_overall_health() {
+        let (overall, ..) = summarize_startup_checks(&[]);
+        assert_eq!(overall, HealthLevel::Unknown);
+
+        let mut checks: Vec<_> = (0..5)
+            .map(|_| check(StartupCheckCategory::Database, StartupCheckStatus::Passed))
+            .collect();
+        assert_eq!(summarize_startup_checks(&checks).0, HealthLevel::Excellent);
+
+        checks.push(check(
+            StartupCheckCategory::Api,
+            StartupCheckStatus::Warning,
+        ));
+        assert_eq!(summarize_startup_checks(&checks).0, HealthLevel::Good);
+
+        checks.push(check(
+            StartupCheckCategory::Schema,
+            StartupCheckStatus::Failed,
+        ));
+        let (overall, passed, warnings, failed) = summarize_startup_checks(&checks);
+        assert_eq!(overall, HealthLevel::Degraded);
+        assert_eq!((passed, warnings, failed), (5, 1, 1));
+
+        // The app cannot sign or reach keyed APIs without its keystore
+        checks.push(check(
+            StartupCheckCategory::Keystore,
+            StartupCheckStatus::Failed,
+        ));
+        assert_eq!(summarize_startup_checks(&checks).0, HealthLevel::Critical);
+    }
+
+    #[test]
+    fn test_config_validation_and_registry() {
+        assert!(StartupCheckConfig::default().validate().is_ok());
+        let config = StartupCheckConfig {
+            api_timeout_secs: 0,
+            ..StartupCheckConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        assert!(known_database("historical_replay.db").unwrap().cache);
+        assert!(!known_database("watchlists.db").unwrap().cache);
+        assert!(known_database("unknown.db").is_none());
+    }
+}
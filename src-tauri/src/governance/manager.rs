@@ -304,6 +304,11 @@ impl GovernanceManager {
     }
 
     async fn fetch_dao_memberships(&self, wallet_address: &str) -> Result<Vec<DAOMembership>, AppError> {
+        // Realms and other platforms aren't queried yet, so only demo mode has memberships
+        if !crate::demo::is_demo_mode() {
+            return Ok(Vec::new());
+        }
+
         let mock_memberships = vec![
             DAOMembership {
                 dao_id: "realms-marinade-dao".to_string(),
@@ -339,6 +344,10 @@ impl GovernanceManager {
     }
 
     async fn fetch_dao_proposals(&self, dao_id: &str) -> Result<Vec<GovernanceProposal>, AppError> {
+        if !crate::demo::is_demo_mode() {
+            return Ok(Vec::new());
+        }
+
         let now = chrono::Utc::now().timestamp();
         
         let mock_proposals = match dao_id {
@@ -0,0 +1,282 @@
+//! Idempotency keys for mutating commands.
+//!
+//! The webview can resubmit a command when a response is lost. Commands that
+//! accept an `idempotency_key` run through [`run_idempotent`]: the first call with
+//! a key executes, every later call with the same key inside the window gets the
+//! original result back, and concurrent duplicates wait for the first one. Failed
+//! calls are not cached, so a retry after an error runs again. Commands whose
+//! result goes stale sooner than the default window pass their own through
+//! [`run_idempotent_within`].
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::OnceCell;
+
+const IDEMPOTENCY_WINDOW_MINUTES: i64 = 15;
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 128;
+
+type CachedResult = Arc<OnceCell<Value>>;
+
+struct IdempotencyEntry {
+    fingerprint: u64,
+    expires_at: DateTime<Utc>,
+    result: CachedResult,
+}
+
+pub struct IdempotencyCache {
+    entries: Mutex<HashMap<String, IdempotencyEntry>>,
+    window: Duration,
+}
+
+impl IdempotencyCache {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            window,
+        }
+    }
+
+    /// Runs `run` once per `(command, key)`. Without a key the command always runs.
+    /// Reusing a key with different arguments is rejected rather than replayed.
+    pub async fn run<T, A, F, Fut>(
+        &self,
+        command: &str,
+        key: Option<&str>,
+        args: &A,
+        run: F,
+    ) -> Result<T, String>
+    where
+        T: Serialize + DeserializeOwned,
+        A: Serialize + ?Sized,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, String>>,
+    {
+        self.run_within(command, key, args, self.window, run).await
+    }
+
+    /// Like [`IdempotencyCache::run`], but the result is only replayed for `window`.
+    pub async fn run_within<T, A, F, Fut>(
+        &self,
+        command: &str,
+        key: Option<&str>,
+        args: &A,
+        window: Duration,
+        run: F,
+    ) -> Result<T, String>
+    where
+        T: Serialize + DeserializeOwned,
+        A: Serialize + ?Sized,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, String>>,
+    {
+        let Some(key) = key.map(str::trim).filter(|key| !key.is_empty()) else {
+            return run().await;
+        };
+        if key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+            return Err(format!(
+                "idempotency key exceeds {MAX_IDEMPOTENCY_KEY_LEN} characters"
+            ));
+        }
+
+        let fingerprint = fingerprint(args)?;
+        let cell = self.claim(&format!("{command}:{key}"), fingerprint, Utc::now(), window)?;
+        let value = cell
+            .get_or_try_init(|| async {
+                run()
+                    .await
+                    .and_then(|value| serde_json::to_value(value).map_err(|e| e.to_string()))
+            })
+            .await?
+            .clone();
+
+        serde_json::from_value(value).map_err(|e| e.to_string())
+    }
+
+    fn claim(
+        &self,
+        scoped_key: &str,
+        fingerprint: u64,
+        now: DateTime<Utc>,
+        window: Duration,
+    ) -> Result<CachedResult, String> {
+        let mut entries = self.entries.lock();
+        entries.retain(|_, entry| now < entry.expires_at);
+
+        if let Some(entry) = entries.get(scoped_key) {
+            if entry.fingerprint != fingerprint {
+                return Err("idempotency key was already used with different arguments".into());
+            }
+            return Ok(entry.result.clone());
+        }
+
+        let result: CachedResult = Arc::new(OnceCell::new());
+        entries.insert(
+            scoped_key.to_string(),
+            IdempotencyEntry {
+                fingerprint,
+                expires_at: now + window,
+                result: result.clone(),
+            },
+        );
+        Ok(result)
+    }
+}
+
+fn fingerprint<A: Serialize + ?Sized>(args: &A) -> Result<u64, String> {
+    let encoded = serde_json::to_string(args).map_err(|e| e.to_string())?;
+    let mut hasher = DefaultHasher::new();
+    encoded.hash(&mut hasher);
+    Ok(hasher.finish())
+}
+
+lazy_static::lazy_static! {
+    static ref IDEMPOTENCY_CACHE: IdempotencyCache =
+        IdempotencyCache::new(Duration::minutes(IDEMPOTENCY_WINDOW_MINUTES));
+}
+
+pub async fn run_idempotent<T, A, F, Fut>(
+    command: &str,
+    key: Option<&str>,
+    args: &A,
+    run: F,
+) -> Result<T, String>
+where
+    T: Serialize + DeserializeOwned,
+    A: Serialize + ?Sized,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, String>>,
+{
+    IDEMPOTENCY_CACHE.run(command, key, args, run).await
+}
+
+/// [`run_idempotent`] with a shorter replay window for results that expire on
+/// their own, such as transactions built against a recent blockhash.
+pub async fn run_idempotent_within<T, A, F, Fut>(
+    command: &str,
+    key: Option<&str>,
+    args: &A,
+    window: Duration,
+    run: F,
+) -> Result<T, String>
+where
+    T: Serialize + DeserializeOwned,
+    A: Serialize + ?Sized,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, String>>,
+{
+    IDEMPOTENCY_CACHE
+        .run_within(command, key, args, window, run)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    async fn submit(
+        cache: &IdempotencyCache,
+        key: Option<&str>,
+        amount: f64,
+        calls: &AtomicUsize,
+    ) -> Result<String, String> {
+        cache
+            .run("create_order", key, &amount, || async {
+                let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(format!("order-{call}"))
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn duplicate_submissions_return_the_original_result() {
+        let cache = IdempotencyCache::new(Duration::minutes(5));
+        let calls = AtomicUsize::new(0);
+
+        let first = submit(&cache, Some("k1"), 1.5, &calls).await.unwrap();
+        let second = submit(&cache, Some("k1"), 1.5, &calls).await.unwrap();
+        let unkeyed = submit(&cache, None, 1.5, &calls).await.unwrap();
+
+        assert_eq!(first, "order-1");
+        assert_eq!(second, "order-1");
+        assert_eq!(unkeyed, "order-2");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn reused_key_with_different_arguments_is_rejected() {
+        let cache = IdempotencyCache::new(Duration::minutes(5));
+        let calls = AtomicUsize::new(0);
+
+        submit(&cache, Some("k1"), 1.5, &calls).await.unwrap();
+        assert!(submit(&cache, Some("k1"), 2.0, &calls).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_calls_can_be_retried_with_the_same_key() {
+        let cache = IdempotencyCache::new(Duration::minutes(5));
+
+        let failed: Result<String, String> = cache
+            .run("create_order", Some("k1"), &1.5, || async {
+                Err("rpc timeout".to_string())
+            })
+            .await;
+        let retried: Result<String, String> = cache
+            .run("create_order", Some("k1"), &1.5, || async {
+                Ok("order-1".to_string())
+            })
+            .await;
+
+        assert!(failed.is_err());
+        assert_eq!(retried.unwrap(), "order-1");
+    }
+
+    #[test]
+    fn keys_expire_after_the_window() {
+        let window = Duration::minutes(5);
+        let cache = IdempotencyCache::new(window);
+        let start = Utc::now();
+
+        let first = cache.claim("create_order:k1", 1, start, window).unwrap();
+        let within = cache
+            .claim("create_order:k1", 1, start + Duration::minutes(4), window)
+            .unwrap();
+        let after = cache
+            .claim("create_order:k1", 1, start + Duration::minutes(6), window)
+            .unwrap();
+
+        assert!(Arc::ptr_eq(&first, &within));
+        assert!(!Arc::ptr_eq(&first, &after));
+    }
+
+    #[test]
+    fn short_windows_expire_independently() {
+        let cache = IdempotencyCache::new(Duration::minutes(15));
+        let start = Utc::now();
+        let short = Duration::seconds(60);
+
+        let swap = cache.claim("jupiter_swap:k1", 1, start, short).unwrap();
+        let order = cache
+            .claim("create_order:k1", 1, start, Duration::minutes(15))
+            .unwrap();
+        let later = start + Duration::seconds(90);
+
+        let swap_again = cache.claim("jupiter_swap:k1", 1, later, short).unwrap();
+        let order_again = cache
+            .claim("create_order:k1", 1, later, Duration::minutes(15))
+            .unwrap();
+
+        assert!(!Arc::ptr_eq(&swap, &swap_again));
+        assert!(Arc::ptr_eq(&order, &order_again));
+    }
+}
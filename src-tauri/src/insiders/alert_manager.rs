@@ -1,10 +1,43 @@
 use super::types::*;
+use crate::notifications::router::SharedNotificationRouter;
+use crate::portfolio::{SharedPortfolioData, SharedWatchlistManager};
+use crate::webhooks::{publish_event, EventSeverity, WebhookEvent, WebhookEventType};
 use chrono::{DateTime, Duration, Utc};
 use serde_json::json;
 use sqlx::{Row, SqlitePool};
 use tauri::{AppHandle, Manager};
 use uuid::Uuid;
 
+fn same_token(token: &TrackedToken, mint: Option<&String>, symbol: Option<&String>) -> bool {
+    mint.is_some_and(|m| !token.mint.is_empty() && *m == token.mint)
+        || symbol.is_some_and(|s| s.eq_ignore_ascii_case(&token.symbol))
+}
+
+/// Finds the tracked token a trade touches and which way the wallet moved it.
+/// Transfers carry addresses in the mint columns and are never matched.
+pub fn match_tracked_token<'a>(
+    activity: &WalletActivity,
+    tokens: &'a [TrackedToken],
+) -> Option<(&'a TrackedToken, &'static str)> {
+    let action = ActivityAction::from_str(&activity.action_type);
+    if !matches!(action, ActivityAction::Buy | ActivityAction::Sell | ActivityAction::Swap) {
+        return None;
+    }
+
+    let output = tokens
+        .iter()
+        .find(|t| same_token(t, activity.output_mint.as_ref(), activity.output_symbol.as_ref()));
+    let input = tokens
+        .iter()
+        .find(|t| same_token(t, activity.input_mint.as_ref(), activity.input_symbol.as_ref()));
+
+    match action {
+        ActivityAction::Buy => output.or(input).map(|t| (t, "buy")),
+        ActivityAction::Sell => input.or(output).map(|t| (t, "sell")),
+        _ => output.map(|t| (t, "buy")).or_else(|| input.map(|t| (t, "sell"))),
+    }
+}
+
 pub struct AlertManager {
     pool: SqlitePool,
     app_handle: AppHandle,
@@ -97,6 +130,227 @@ impl AlertManager {
         Ok(())
     }
 
+    /// Alerts when a monitored wallet trades a token I hold or watch.
+    pub async fn process_portfolio_token_trade(&self, activity: &WalletActivity) -> Result<(), String> {
+        let configs = self.get_alert_configs().await?;
+        let Some(config) = configs
+            .iter()
+            .find(|c| c.alert_type == AlertType::PortfolioTokenTrade && c.enabled)
+        else {
+            return Ok(());
+        };
+
+        let amount_usd = activity.amount_usd.unwrap_or(0.0);
+        if amount_usd < config.threshold.unwrap_or(0.0) {
+            return Ok(());
+        }
+
+        let settings = self.get_token_interaction_settings().await?;
+        let tokens = self.tracked_tokens(&settings).await;
+        let Some((token, direction)) = match_tracked_token(activity, &tokens) else {
+            return Ok(());
+        };
+
+        let alert = TokenInteractionAlert {
+            id: Uuid::new_v4().to_string(),
+            wallet_address: activity.wallet_address.clone(),
+            wallet_label: activity.wallet_label.clone(),
+            activity_id: activity.id.clone(),
+            tx_signature: activity.tx_signature.clone(),
+            direction: direction.to_string(),
+            token_mint: token.mint.clone(),
+            token_symbol: token.symbol.clone(),
+            source: token.source.clone(),
+            amount: activity.amount,
+            amount_usd,
+            price: activity.price,
+            timestamp: activity.timestamp,
+        };
+
+        self.save_token_interaction_alert(&alert).await?;
+
+        let message = format!(
+            "{} {} ${:.2} of {} ({})",
+            alert.wallet_label.as_ref().unwrap_or(&alert.wallet_address),
+            if direction == "buy" { "bought" } else { "sold" },
+            alert.amount_usd,
+            alert.token_symbol,
+            alert.source
+        );
+
+        if config.push_enabled {
+            let _ = self.app_handle.emit_all("portfolio_token_trade_alert", &alert);
+            if let Some(router) = self.app_handle.try_state::<SharedNotificationRouter>() {
+                if let Err(err) = router
+                    .read()
+                    .await
+                    .send_broadcast_notification(&alert.id, "Watched wallet traded your token", &message)
+                    .await
+                {
+                    tracing::warn!(error = %err, "failed to deliver portfolio token trade notification");
+                }
+            }
+        }
+
+        if config.telegram_enabled {
+            if let Some(telegram_config_id) = &config.telegram_config_id {
+                let _ = self
+                    .app_handle
+                    .emit_all("send_telegram_alert", (telegram_config_id, &message));
+            }
+        }
+
+        publish_event(
+            &self.app_handle,
+            WebhookEvent::new(
+                WebhookEventType::WalletActivity,
+                EventSeverity::Info,
+                "Watched wallet traded your token",
+                message,
+            )
+            .with_token(alert.token_mint.clone())
+            .with_notional(alert.amount_usd)
+            .with_data(json!(alert)),
+        );
+
+        Ok(())
+    }
+
+    async fn tracked_tokens(&self, settings: &TokenInteractionSettings) -> Vec<TrackedToken> {
+        let mut tokens = Vec::new();
+
+        if settings.include_portfolio {
+            if let Some(data) = self.app_handle.try_state::<SharedPortfolioData>() {
+                if let Ok(data) = data.lock() {
+                    tokens.extend(data.positions().into_iter().map(|p| TrackedToken {
+                        mint: p.mint,
+                        symbol: p.symbol,
+                        source: "portfolio".to_string(),
+                    }));
+                }
+            }
+        }
+
+        if !settings.watchlist_ids.is_empty() {
+            if let Some(manager) = self.app_handle.try_state::<SharedWatchlistManager>() {
+                let manager = manager.read().await;
+                for id in &settings.watchlist_ids {
+                    match manager.get_watchlist(id).await {
+                        Ok(watchlist) => tokens.extend(watchlist.items.into_iter().map(|item| TrackedToken {
+                            mint: item.mint,
+                            symbol: item.symbol,
+                            source: watchlist.name.clone(),
+                        })),
+                        Err(err) => tracing::warn!(error = %err, watchlist = %id, "failed to load watchlist"),
+                    }
+                }
+            }
+        }
+
+        tokens
+    }
+
+    pub async fn get_token_interaction_settings(&self) -> Result<TokenInteractionSettings, String> {
+        let row = sqlx::query("SELECT settings FROM token_interaction_settings WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to fetch token interaction settings: {e}"))?;
+
+        match row {
+            Some(row) => {
+                let settings: String = row.try_get("settings").map_err(|e| e.to_string())?;
+                serde_json::from_str(&settings).map_err(|e| e.to_string())
+            }
+            None => Ok(TokenInteractionSettings::default()),
+        }
+    }
+
+    pub async fn update_token_interaction_settings(
+        &self,
+        settings: &TokenInteractionSettings,
+    ) -> Result<(), String> {
+        let serialized = serde_json::to_string(settings).map_err(|e| e.to_string())?;
+        sqlx::query("INSERT OR REPLACE INTO token_interaction_settings (id, settings) VALUES (1, ?1)")
+            .bind(serialized)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to update token interaction settings: {e}"))?;
+        Ok(())
+    }
+
+    async fn save_token_interaction_alert(&self, alert: &TokenInteractionAlert) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO token_interaction_alerts (
+                id, wallet_address, wallet_label, activity_id, tx_signature, direction,
+                token_mint, token_symbol, source, amount, amount_usd, price, timestamp
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+            "#,
+        )
+        .bind(&alert.id)
+        .bind(&alert.wallet_address)
+        .bind(&alert.wallet_label)
+        .bind(&alert.activity_id)
+        .bind(&alert.tx_signature)
+        .bind(&alert.direction)
+        .bind(&alert.token_mint)
+        .bind(&alert.token_symbol)
+        .bind(&alert.source)
+        .bind(alert.amount)
+        .bind(alert.amount_usd)
+        .bind(alert.price)
+        .bind(alert.timestamp.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save token interaction alert: {e}"))?;
+
+        Ok(())
+    }
+
+    pub async fn get_token_interaction_alerts(
+        &self,
+        token_mint: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<TokenInteractionAlert>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM token_interaction_alerts
+            WHERE ?1 IS NULL OR token_mint = ?1
+            ORDER BY timestamp DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(token_mint)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to fetch token interaction alerts: {e}"))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| TokenInteractionAlert {
+                id: row.try_get("id").unwrap_or_default(),
+                wallet_address: row.try_get("wallet_address").unwrap_or_default(),
+                wallet_label: row.try_get("wallet_label").ok().flatten(),
+                activity_id: row.try_get("activity_id").unwrap_or_default(),
+                tx_signature: row.try_get("tx_signature").unwrap_or_default(),
+                direction: row.try_get("direction").unwrap_or_default(),
+                token_mint: row.try_get("token_mint").unwrap_or_default(),
+                token_symbol: row.try_get("token_symbol").unwrap_or_default(),
+                source: row.try_get("source").unwrap_or_default(),
+                amount: row.try_get("amount").ok().flatten(),
+                amount_usd: row.try_get("amount_usd").unwrap_or(0.0),
+                price: row.try_get("price").ok().flatten(),
+                timestamp: row
+                    .try_get::<String, _>("timestamp")
+                    .ok()
+                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(Utc::now),
+            })
+            .collect())
+    }
+
     async fn save_whale_alert(&self, alert: &WhaleAlert) -> Result<(), String> {
         sqlx::query(
             r#"
@@ -187,6 +441,7 @@ impl AlertManager {
                 "smart_money_buy" => AlertType::SmartMoneyBuy,
                 "smart_money_sell" => AlertType::SmartMoneySell,
                 "smart_money_consensus" => AlertType::SmartMoneyConsensus,
+                "portfolio_token_trade" => AlertType::PortfolioTokenTrade,
                 _ => continue,
             };
 
@@ -267,3 +522,64 @@ impl AlertManager {
         Ok(alerts)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(action: &str, input: Option<&str>, output: Option<&str>) -> WalletActivity {
+        WalletActivity {
+            id: "a1".to_string(),
+            wallet_address: "whale".to_string(),
+            wallet_label: None,
+            tx_signature: "sig".to_string(),
+            action_type: action.to_string(),
+            input_mint: input.map(|s| s.to_string()),
+            output_mint: output.map(|s| s.to_string()),
+            input_symbol: None,
+            output_symbol: None,
+            amount: Some(10.0),
+            amount_usd: Some(5_000.0),
+            price: Some(500.0),
+            is_whale: true,
+            behavior: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn tokens() -> Vec<TrackedToken> {
+        vec![
+            TrackedToken {
+                mint: "BONK_MINT".to_string(),
+                symbol: "BONK".to_string(),
+                source: "portfolio".to_string(),
+            },
+            TrackedToken {
+                mint: "JUP_MINT".to_string(),
+                symbol: "JUP".to_string(),
+                source: "Majors".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn swap_direction_follows_the_tracked_side() {
+        let tokens = tokens();
+        let (token, direction) = match_tracked_token(&activity("swap", Some("SOL_MINT"), Some("JUP_MINT")), &tokens).unwrap();
+        assert_eq!((token.symbol.as_str(), direction), ("JUP", "buy"));
+
+        let (token, direction) = match_tracked_token(&activity("swap", Some("BONK_MINT"), Some("SOL_MINT")), &tokens).unwrap();
+        assert_eq!((token.source.as_str(), direction), ("portfolio", "sell"));
+    }
+
+    #[test]
+    fn matches_by_symbol_and_ignores_transfers() {
+        let tokens = tokens();
+        let mut sell = activity("sell", None, None);
+        sell.output_symbol = Some("bonk".to_string());
+        assert_eq!(match_tracked_token(&sell, &tokens).map(|(_, d)| d), Some("sell"));
+
+        assert!(match_tracked_token(&activity("transfer", Some("BONK_MINT"), Some("JUP_MINT")), &tokens).is_none());
+        assert!(match_tracked_token(&activity("buy", None, Some("OTHER")), &tokens).is_none());
+    }
+}
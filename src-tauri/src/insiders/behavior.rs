@@ -0,0 +1,545 @@
+use super::types::*;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::{HashMap, HashSet};
+
+/// Below this many activities a wallet is not labeled at all.
+const MIN_SAMPLE_SIZE: usize = 10;
+/// Sample size at which label confidence is no longer discounted.
+const FULL_CONFIDENCE_SAMPLE: f64 = 50.0;
+const MIN_LABEL_CONFIDENCE: f64 = 0.5;
+/// A buy this soon after a token's first observed activity counts as an early entry.
+const EARLY_ENTRY_MINUTES: i64 = 10;
+const LOOKBACK_DAYS: i64 = 90;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum WalletBehavior {
+    Sniper,
+    MarketMaker,
+    Exchange,
+    LongTermHolder,
+    ActiveTrader,
+}
+
+impl WalletBehavior {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WalletBehavior::Sniper => "sniper",
+            WalletBehavior::MarketMaker => "market_maker",
+            WalletBehavior::Exchange => "exchange",
+            WalletBehavior::LongTermHolder => "long_term_holder",
+            WalletBehavior::ActiveTrader => "active_trader",
+        }
+    }
+
+    /// Exchange and market-maker flow says nothing about conviction, so it isn't worth copying.
+    pub fn is_copyable(&self) -> bool {
+        !matches!(self, WalletBehavior::Exchange | WalletBehavior::MarketMaker)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BehaviorFeatures {
+    pub activity_count: usize,
+    pub active_days: f64,
+    pub tx_per_day: f64,
+    pub buy_count: usize,
+    pub sell_count: usize,
+    pub transfer_count: usize,
+    pub distinct_tokens: usize,
+    pub distinct_counterparties: usize,
+    /// Median hours between a token's first buy and first subsequent sell.
+    pub median_hold_hours: Option<f64>,
+    /// Share of bought tokens that were never sold within the lookback window.
+    pub still_held_ratio: f64,
+    /// Share of buys placed within minutes of a token's first observed activity.
+    pub early_entry_ratio: f64,
+    pub avg_trade_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BehaviorLabel {
+    pub label: WalletBehavior,
+    pub confidence: f64,
+    pub reasons: Vec<String>,
+}
+
+/// Primary label attached to feed entries and holder rows.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BehaviorTag {
+    pub label: WalletBehavior,
+    pub confidence: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletBehaviorProfile {
+    pub wallet_address: String,
+    pub primary: Option<BehaviorTag>,
+    pub labels: Vec<BehaviorLabel>,
+    pub features: BehaviorFeatures,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopyTradeCandidate {
+    pub wallet: SmartMoneyWallet,
+    pub behavior: Option<BehaviorTag>,
+}
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = values.len() / 2;
+    Some(if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+/// Token a trade is about; live-ingested rows only carry the symbol.
+fn token_key(activity: &WalletActivityRecord) -> Option<String> {
+    activity
+        .output_symbol
+        .clone()
+        .or_else(|| activity.output_mint.clone())
+}
+
+/// `token_first_seen` maps each token to its earliest activity across all observed wallets,
+/// which stands in for the launch time.
+pub fn extract_features(
+    wallet_address: &str,
+    activities: &[WalletActivityRecord],
+    token_first_seen: &HashMap<String, DateTime<Utc>>,
+) -> BehaviorFeatures {
+    if activities.is_empty() {
+        return BehaviorFeatures::default();
+    }
+
+    let mut sorted: Vec<&WalletActivityRecord> = activities.iter().collect();
+    sorted.sort_by_key(|a| a.timestamp);
+    let first = sorted.first().map(|a| a.timestamp).unwrap_or_else(Utc::now);
+    let last = sorted.last().map(|a| a.timestamp).unwrap_or_else(Utc::now);
+    let active_days = ((last - first).num_minutes() as f64 / 1440.0).max(1.0);
+
+    let mut tokens = HashSet::new();
+    let mut counterparties = HashSet::new();
+    let mut first_buy: HashMap<String, DateTime<Utc>> = HashMap::new();
+    let mut hold_hours = Vec::new();
+    let mut sold = HashSet::new();
+    let (mut buys, mut sells, mut transfers, mut early) = (0, 0, 0, 0);
+    let mut trade_usd = 0.0;
+
+    for activity in &sorted {
+        match ActivityAction::from_str(&activity.action_type) {
+            ActivityAction::Buy | ActivityAction::Swap => {
+                buys += 1;
+                trade_usd += activity.amount_usd.unwrap_or(0.0);
+                if let Some(token) = token_key(activity) {
+                    if let Some(launched) = token_first_seen.get(&token) {
+                        if activity.timestamp - *launched <= Duration::minutes(EARLY_ENTRY_MINUTES) {
+                            early += 1;
+                        }
+                    }
+                    first_buy.entry(token.clone()).or_insert(activity.timestamp);
+                    tokens.insert(token);
+                }
+            }
+            ActivityAction::Sell => {
+                sells += 1;
+                trade_usd += activity.amount_usd.unwrap_or(0.0);
+                if let Some(token) = token_key(activity) {
+                    if let Some(bought_at) = first_buy.get(&token) {
+                        if sold.insert(token.clone()) {
+                            hold_hours.push((activity.timestamp - *bought_at).num_minutes() as f64 / 60.0);
+                        }
+                    }
+                    tokens.insert(token);
+                }
+            }
+            ActivityAction::Transfer => {
+                transfers += 1;
+                // Transfers record the sending and receiving addresses in the mint columns
+                for party in [&activity.input_mint, &activity.output_mint].into_iter().flatten() {
+                    if party != wallet_address {
+                        counterparties.insert(party.clone());
+                    }
+                }
+            }
+            ActivityAction::Unknown => {}
+        }
+    }
+
+    let bought_tokens = first_buy.len();
+    let trades = buys + sells;
+
+    BehaviorFeatures {
+        activity_count: sorted.len(),
+        active_days,
+        tx_per_day: sorted.len() as f64 / active_days,
+        buy_count: buys,
+        sell_count: sells,
+        transfer_count: transfers,
+        distinct_tokens: tokens.len(),
+        distinct_counterparties: counterparties.len(),
+        median_hold_hours: median(&mut hold_hours),
+        still_held_ratio: if bought_tokens > 0 {
+            first_buy.keys().filter(|t| !sold.contains(*t)).count() as f64 / bought_tokens as f64
+        } else {
+            0.0
+        },
+        early_entry_ratio: if buys > 0 { early as f64 / buys as f64 } else { 0.0 },
+        avg_trade_usd: if trades > 0 { trade_usd / trades as f64 } else { 0.0 },
+    }
+}
+
+/// Scores each behavior from 0 to 1 and keeps the ones above the labeling threshold,
+/// strongest first. Small samples are discounted.
+pub fn classify_behavior(features: &BehaviorFeatures) -> Vec<BehaviorLabel> {
+    if features.activity_count < MIN_SAMPLE_SIZE {
+        return Vec::new();
+    }
+
+    let sample_weight = (features.activity_count as f64 / FULL_CONFIDENCE_SAMPLE).min(1.0) * 0.3 + 0.7;
+    let trades = (features.buy_count + features.sell_count) as f64;
+    let transfer_share = features.transfer_count as f64 / features.activity_count as f64;
+    let buy_share = if trades > 0.0 { features.buy_count as f64 / trades } else { 0.0 };
+    let hold = features.median_hold_hours;
+    let mut labels = Vec::new();
+
+    let mut push = |label: WalletBehavior, parts: &[(bool, f64, &str)]| {
+        let score: f64 = parts.iter().filter(|(hit, _, _)| *hit).map(|(_, w, _)| w).sum();
+        let confidence = (score * sample_weight).min(1.0);
+        if confidence >= MIN_LABEL_CONFIDENCE {
+            labels.push(BehaviorLabel {
+                label,
+                confidence,
+                reasons: parts
+                    .iter()
+                    .filter(|(hit, _, _)| *hit)
+                    .map(|(_, _, reason)| reason.to_string())
+                    .collect(),
+            });
+        }
+    };
+
+    push(
+        WalletBehavior::Exchange,
+        &[
+            (features.tx_per_day >= 200.0, 0.35, "Very high transaction rate"),
+            (features.distinct_counterparties >= 100, 0.35, "Hundreds of counterparties"),
+            (transfer_share >= 0.7, 0.3, "Mostly plain transfers"),
+        ],
+    );
+
+    push(
+        WalletBehavior::MarketMaker,
+        &[
+            (features.tx_per_day >= 50.0, 0.3, "High trade frequency"),
+            ((0.4..=0.6).contains(&buy_share) && trades >= 20.0, 0.3, "Balanced buys and sells"),
+            (hold.is_some_and(|h| h < 1.0), 0.25, "Positions turned over within the hour"),
+            (features.distinct_tokens <= 5, 0.15, "Concentrated on a few tokens"),
+        ],
+    );
+
+    push(
+        WalletBehavior::Sniper,
+        &[
+            (features.early_entry_ratio >= 0.5, 0.45, "Buys within minutes of launch"),
+            (hold.is_some_and(|h| h < 24.0), 0.25, "Exits within a day"),
+            (features.distinct_tokens >= 10, 0.2, "Touches many new tokens"),
+            (features.still_held_ratio < 0.5, 0.1, "Rarely keeps positions"),
+        ],
+    );
+
+    push(
+        WalletBehavior::LongTermHolder,
+        &[
+            (hold.map_or(features.buy_count > 0, |h| h >= 24.0 * 30.0), 0.35, "Holds for months"),
+            (features.still_held_ratio >= 0.7, 0.3, "Keeps most positions"),
+            (features.tx_per_day < 1.0, 0.2, "Trades infrequently"),
+            (buy_share >= 0.7, 0.15, "Mostly accumulates"),
+        ],
+    );
+
+    push(
+        WalletBehavior::ActiveTrader,
+        &[
+            (trades >= 20.0, 0.3, "Regular trading"),
+            (hold.is_some_and(|h| (24.0..24.0 * 14.0).contains(&h)), 0.35, "Holds for days to weeks"),
+            ((1.0..50.0).contains(&features.tx_per_day), 0.2, "Moderate trade frequency"),
+            (features.early_entry_ratio < 0.3, 0.15, "Enters established tokens"),
+        ],
+    );
+
+    labels.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+    labels
+}
+
+#[derive(Clone)]
+pub struct WalletBehaviorClassifier {
+    pool: SqlitePool,
+}
+
+impl WalletBehaviorClassifier {
+    pub async fn new(pool: SqlitePool) -> Result<Self, String> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS wallet_behavior_labels (
+                wallet_address TEXT PRIMARY KEY,
+                primary_label TEXT,
+                confidence REAL,
+                profile TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("Failed to create wallet behavior table: {e}"))?;
+
+        Ok(Self { pool })
+    }
+
+    async fn token_first_seen(&self, tokens: &HashSet<String>) -> Result<HashMap<String, DateTime<Utc>>, String> {
+        let mut first_seen = HashMap::new();
+        for token in tokens {
+            let row = sqlx::query(
+                "SELECT MIN(timestamp) AS first_seen FROM wallet_activities WHERE output_symbol = ?1 OR output_mint = ?1",
+            )
+            .bind(token)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load token first activity: {e}"))?;
+
+            if let Some(ts) = row
+                .try_get::<Option<String>, _>("first_seen")
+                .ok()
+                .flatten()
+                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            {
+                first_seen.insert(token.clone(), ts.with_timezone(&Utc));
+            }
+        }
+        Ok(first_seen)
+    }
+
+    pub async fn classify(&self, wallet_address: &str) -> Result<WalletBehaviorProfile, String> {
+        let cutoff = Utc::now() - Duration::days(LOOKBACK_DAYS);
+        let activities = sqlx::query_as::<_, WalletActivityRecord>(
+            "SELECT * FROM wallet_activities WHERE wallet_address = ?1 AND timestamp >= ?2 ORDER BY timestamp ASC",
+        )
+        .bind(wallet_address)
+        .bind(cutoff.to_rfc3339())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to fetch wallet activities: {e}"))?;
+
+        let tokens: HashSet<String> = activities.iter().filter_map(token_key).collect();
+        let first_seen = self.token_first_seen(&tokens).await?;
+        let features = extract_features(wallet_address, &activities, &first_seen);
+        let labels = classify_behavior(&features);
+
+        let profile = WalletBehaviorProfile {
+            wallet_address: wallet_address.to_string(),
+            primary: labels.first().map(|l| BehaviorTag {
+                label: l.label,
+                confidence: l.confidence,
+            }),
+            labels,
+            features,
+            updated_at: Utc::now(),
+        };
+        self.save(&profile).await?;
+        Ok(profile)
+    }
+
+    async fn save(&self, profile: &WalletBehaviorProfile) -> Result<(), String> {
+        let serialized = serde_json::to_string(profile).map_err(|e| e.to_string())?;
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO wallet_behavior_labels (
+                wallet_address, primary_label, confidence, profile, updated_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+        )
+        .bind(&profile.wallet_address)
+        .bind(profile.primary.as_ref().map(|p| p.label.as_str()))
+        .bind(profile.primary.as_ref().map(|p| p.confidence))
+        .bind(serialized)
+        .bind(profile.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save wallet behavior: {e}"))?;
+        Ok(())
+    }
+
+    pub async fn get_profile(&self, wallet_address: &str) -> Result<Option<WalletBehaviorProfile>, String> {
+        let row = sqlx::query("SELECT profile FROM wallet_behavior_labels WHERE wallet_address = ?1")
+            .bind(wallet_address)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load wallet behavior: {e}"))?;
+
+        row.map(|row| {
+            let profile: String = row.try_get("profile").map_err(|e| e.to_string())?;
+            serde_json::from_str(&profile).map_err(|e| e.to_string())
+        })
+        .transpose()
+    }
+
+    pub async fn list_profiles(
+        &self,
+        label: Option<WalletBehavior>,
+        min_confidence: f64,
+    ) -> Result<Vec<WalletBehaviorProfile>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT profile FROM wallet_behavior_labels
+            WHERE primary_label IS NOT NULL
+            AND (?1 IS NULL OR primary_label = ?1)
+            AND confidence >= ?2
+            ORDER BY confidence DESC
+            "#,
+        )
+        .bind(label.map(|l| l.as_str()))
+        .bind(min_confidence)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list wallet behaviors: {e}"))?;
+
+        rows.iter()
+            .map(|row| {
+                let profile: String = row.try_get("profile").map_err(|e| e.to_string())?;
+                serde_json::from_str(&profile).map_err(|e| e.to_string())
+            })
+            .collect()
+    }
+
+    /// Primary labels for a set of addresses, for decorating feeds and holder lists.
+    pub async fn tags_for(&self, addresses: &[String]) -> HashMap<String, BehaviorTag> {
+        let mut tags = HashMap::new();
+        for address in addresses {
+            let row = sqlx::query(
+                "SELECT primary_label, confidence FROM wallet_behavior_labels WHERE wallet_address = ?1 AND primary_label IS NOT NULL",
+            )
+            .bind(address)
+            .fetch_optional(&self.pool)
+            .await;
+
+            if let Ok(Some(row)) = row {
+                let label: Option<WalletBehavior> = row
+                    .try_get::<String, _>("primary_label")
+                    .ok()
+                    .and_then(|l| serde_json::from_value(serde_json::Value::String(l)).ok());
+                if let Some(label) = label {
+                    tags.insert(
+                        address.clone(),
+                        BehaviorTag {
+                            label,
+                            confidence: row.try_get("confidence").unwrap_or(0.0),
+                        },
+                    );
+                }
+            }
+        }
+        tags
+    }
+
+    /// Re-labels every wallet with activity in the last week.
+    pub async fn classify_recent(&self) -> Result<usize, String> {
+        let cutoff = Utc::now() - Duration::days(7);
+        let rows = sqlx::query("SELECT DISTINCT wallet_address FROM wallet_activities WHERE timestamp >= ?1")
+            .bind(cutoff.to_rfc3339())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to fetch active wallets: {e}"))?;
+
+        let mut classified = 0;
+        for row in rows {
+            let wallet_address: String = row.try_get("wallet_address").map_err(|e| e.to_string())?;
+            match self.classify(&wallet_address).await {
+                Ok(_) => classified += 1,
+                Err(err) => eprintln!("Failed to classify wallet behavior for {wallet_address}: {err}"),
+            }
+        }
+        Ok(classified)
+    }
+
+    pub async fn start_reclassification(classifier: std::sync::Arc<Self>) {
+        let mut ticker = tokio::time::interval(std::time::Duration::from_secs(1800));
+        loop {
+            ticker.tick().await;
+            if let Err(err) = classifier.classify_recent().await {
+                eprintln!("Failed to refresh wallet behavior labels: {err}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(action: &str, token: &str, minutes: i64, usd: f64) -> WalletActivityRecord {
+        WalletActivityRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            wallet_address: "wallet".to_string(),
+            tx_signature: uuid::Uuid::new_v4().to_string(),
+            action_type: action.to_string(),
+            input_mint: None,
+            output_mint: None,
+            input_symbol: None,
+            output_symbol: Some(token.to_string()),
+            amount: Some(usd),
+            amount_usd: Some(usd),
+            price: Some(1.0),
+            timestamp: Utc::now() - Duration::days(30) + Duration::minutes(minutes),
+        }
+    }
+
+    #[test]
+    fn labels_sniper_pattern() {
+        let mut activities = Vec::new();
+        let mut first_seen = HashMap::new();
+        for i in 0..12 {
+            let token = format!("NEW{i}");
+            let launch = i * 600;
+            first_seen.insert(token.clone(), Utc::now() - Duration::days(30) + Duration::minutes(launch));
+            activities.push(activity("buy", &token, launch + 2, 500.0));
+            activities.push(activity("sell", &token, launch + 90, 900.0));
+        }
+
+        let features = extract_features("wallet", &activities, &first_seen);
+        assert_eq!(features.early_entry_ratio, 1.0);
+        assert_eq!(features.median_hold_hours, Some(88.0 / 60.0));
+
+        let labels = classify_behavior(&features);
+        assert_eq!(labels.first().map(|l| l.label), Some(WalletBehavior::Sniper));
+    }
+
+    #[test]
+    fn labels_long_term_holder() {
+        let activities: Vec<_> = (0..12)
+            .map(|i| activity("buy", &format!("BLUE{}", i % 4), i * 60 * 24 * 2, 2_000.0))
+            .collect();
+
+        let features = extract_features("wallet", &activities, &HashMap::new());
+        assert_eq!(features.still_held_ratio, 1.0);
+        assert!(features.median_hold_hours.is_none());
+
+        let labels = classify_behavior(&features);
+        assert_eq!(labels.first().map(|l| l.label), Some(WalletBehavior::LongTermHolder));
+        assert!(labels.iter().all(|l| l.label != WalletBehavior::Sniper));
+    }
+
+    #[test]
+    fn skips_small_samples() {
+        let activities = vec![activity("buy", "A", 0, 10.0), activity("sell", "A", 5, 12.0)];
+        assert!(classify_behavior(&extract_features("wallet", &activities, &HashMap::new())).is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+use super::behavior::*;
 use super::types::*;
 use crate::insiders::wallet_monitor::require_state;
 use sqlx::Row;
@@ -51,6 +52,30 @@ pub async fn get_recent_whale_alerts(limit: i64) -> Result<Vec<WhaleAlert>, Stri
     alert_manager.get_recent_whale_alerts(limit).await
 }
 
+#[tauri::command]
+pub async fn get_token_interaction_settings() -> Result<TokenInteractionSettings, String> {
+    let state = require_state()?;
+    state.alert_manager.get_token_interaction_settings().await
+}
+
+#[tauri::command]
+pub async fn update_token_interaction_settings(settings: TokenInteractionSettings) -> Result<(), String> {
+    let state = require_state()?;
+    state.alert_manager.update_token_interaction_settings(&settings).await
+}
+
+#[tauri::command]
+pub async fn get_token_interaction_alerts(
+    token_mint: Option<String>,
+    limit: i64,
+) -> Result<Vec<TokenInteractionAlert>, String> {
+    let state = require_state()?;
+    state
+        .alert_manager
+        .get_token_interaction_alerts(token_mint.as_deref(), limit)
+        .await
+}
+
 #[tauri::command]
 pub async fn scan_wallets_for_smart_money() -> Result<Vec<SmartMoneyClassification>, String> {
     let state = require_state()?;
@@ -82,3 +107,64 @@ pub async fn scan_wallets_for_smart_money() -> Result<Vec<SmartMoneyClassificati
     
     Ok(classifications)
 }
+
+#[tauri::command]
+pub async fn get_wallet_behavior(
+    wallet_address: String,
+    refresh: Option<bool>,
+) -> Result<Option<WalletBehaviorProfile>, String> {
+    let state = require_state()?;
+    let classifier = state.behavior_classifier.clone();
+    if refresh.unwrap_or(false) {
+        return classifier.classify(&wallet_address).await.map(Some);
+    }
+    match classifier.get_profile(&wallet_address).await? {
+        Some(profile) => Ok(Some(profile)),
+        None => classifier.classify(&wallet_address).await.map(Some),
+    }
+}
+
+#[tauri::command]
+pub async fn list_wallet_behaviors(
+    label: Option<WalletBehavior>,
+    min_confidence: Option<f64>,
+) -> Result<Vec<WalletBehaviorProfile>, String> {
+    let state = require_state()?;
+    state
+        .behavior_classifier
+        .list_profiles(label, min_confidence.unwrap_or(0.0))
+        .await
+}
+
+#[tauri::command]
+pub async fn classify_wallet_behaviors() -> Result<usize, String> {
+    let state = require_state()?;
+    state.behavior_classifier.classify_recent().await
+}
+
+/// Smart-money wallets worth following, with exchange and market-maker flow filtered out.
+#[tauri::command]
+pub async fn discover_copy_trade_wallets(limit: Option<usize>) -> Result<Vec<CopyTradeCandidate>, String> {
+    let state = require_state()?;
+    let wallets = state.smart_money_detector.get_smart_money_wallets().await?;
+    let addresses: Vec<String> = wallets.iter().map(|w| w.wallet_address.clone()).collect();
+    let tags = state.behavior_classifier.tags_for(&addresses).await;
+
+    let mut candidates: Vec<CopyTradeCandidate> = wallets
+        .into_iter()
+        .map(|wallet| CopyTradeCandidate {
+            behavior: tags.get(&wallet.wallet_address).cloned(),
+            wallet,
+        })
+        .filter(|c| c.behavior.as_ref().map_or(true, |b| b.label.is_copyable()))
+        .collect();
+
+    candidates.sort_by(|a, b| {
+        b.wallet
+            .smart_money_score
+            .partial_cmp(&a.wallet.smart_money_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    candidates.truncate(limit.unwrap_or(20));
+    Ok(candidates)
+}
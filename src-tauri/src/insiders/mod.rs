@@ -1,10 +1,12 @@
 pub mod alert_manager;
+pub mod behavior;
 pub mod commands;
 pub mod smart_money;
 pub mod types;
 pub mod wallet_monitor;
 
 pub use alert_manager::*;
+pub use behavior::*;
 pub use commands::*;
 pub use smart_money::*;
 pub use types::*;
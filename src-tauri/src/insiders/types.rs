@@ -50,6 +50,8 @@ pub struct WalletActivity {
     pub amount_usd: Option<f64>,
     pub price: Option<f64>,
     pub is_whale: bool,
+    #[serde(default)]
+    pub behavior: Option<super::behavior::BehaviorTag>,
     pub timestamp: DateTime<Utc>,
 }
 
@@ -220,6 +222,7 @@ pub enum AlertType {
     SmartMoneyBuy,
     SmartMoneySell,
     SmartMoneyConsensus,
+    PortfolioTokenTrade,
 }
 
 impl AlertType {
@@ -229,10 +232,54 @@ impl AlertType {
             AlertType::SmartMoneyBuy => "smart_money_buy",
             AlertType::SmartMoneySell => "smart_money_sell",
             AlertType::SmartMoneyConsensus => "smart_money_consensus",
+            AlertType::PortfolioTokenTrade => "portfolio_token_trade",
         }
     }
 }
 
+/// Which of my tokens the portfolio-token-trade alert watches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenInteractionSettings {
+    pub include_portfolio: bool,
+    #[serde(default)]
+    pub watchlist_ids: Vec<String>,
+}
+
+impl Default for TokenInteractionSettings {
+    fn default() -> Self {
+        Self {
+            include_portfolio: true,
+            watchlist_ids: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrackedToken {
+    pub mint: String,
+    pub symbol: String,
+    /// "portfolio" or the watchlist name
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenInteractionAlert {
+    pub id: String,
+    pub wallet_address: String,
+    pub wallet_label: Option<String>,
+    pub activity_id: String,
+    pub tx_signature: String,
+    pub direction: String,
+    pub token_mint: String,
+    pub token_symbol: String,
+    pub source: String,
+    pub amount: Option<f64>,
+    pub amount_usd: f64,
+    /// Token price when the alert fired, for measuring the move that followed
+    pub price: Option<f64>,
+    pub timestamp: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SentimentComparison {
     pub token_mint: String,
@@ -366,6 +413,39 @@ impl WalletMonitorDatabase {
         .execute(&self.pool)
         .await?;
 
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS token_interaction_alerts (
+                id TEXT PRIMARY KEY,
+                wallet_address TEXT NOT NULL,
+                wallet_label TEXT,
+                activity_id TEXT NOT NULL,
+                tx_signature TEXT NOT NULL,
+                direction TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                token_symbol TEXT NOT NULL,
+                source TEXT NOT NULL,
+                amount REAL,
+                amount_usd REAL NOT NULL,
+                price REAL,
+                timestamp TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS token_interaction_settings (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                settings TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
         sqlx::query(
             r#"
             CREATE INDEX IF NOT EXISTS idx_smart_money_wallets_score ON smart_money_wallets(smart_money_score);
@@ -373,6 +453,7 @@ impl WalletMonitorDatabase {
             CREATE INDEX IF NOT EXISTS idx_whale_alerts_timestamp ON whale_alerts(timestamp);
             CREATE INDEX IF NOT EXISTS idx_whale_alerts_wallet ON whale_alerts(wallet_address);
             CREATE INDEX IF NOT EXISTS idx_alert_configs_type ON alert_configs(alert_type);
+            CREATE INDEX IF NOT EXISTS idx_token_interaction_alerts_token ON token_interaction_alerts(token_mint, timestamp);
             "#,
         )
         .execute(&self.pool)
@@ -391,7 +472,8 @@ impl WalletMonitorDatabase {
                 ('whale_tx', 'whale_transaction', 1, 50000.0, 1, 0, 0),
                 ('smart_buy', 'smart_money_buy', 1, 10000.0, 1, 0, 0),
                 ('smart_sell', 'smart_money_sell', 1, 10000.0, 1, 0, 0),
-                ('consensus', 'smart_money_consensus', 1, NULL, 1, 0, 0)
+                ('consensus', 'smart_money_consensus', 1, NULL, 1, 0, 0),
+                ('portfolio_token', 'portfolio_token_trade', 1, 1000.0, 1, 0, 0)
             "#,
         )
         .execute(&self.pool)
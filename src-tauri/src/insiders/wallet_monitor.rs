@@ -1,4 +1,5 @@
-use super::{AlertManager, SmartMoneyDetector, types::*};
+use super::{AlertManager, SmartMoneyDetector, WalletBehaviorClassifier, types::*};
+use crate::core::events::{publish_domain_event, DomainEvent};
 use crate::core::WebSocketManager;
 use crate::websocket::types::{StreamEvent, TransactionUpdate};
 use chrono::Utc;
@@ -19,6 +20,7 @@ pub struct WalletMonitor {
     ws_manager: WebSocketManager,
     smart_money_detector: Arc<SmartMoneyDetector>,
     alert_manager: Arc<AlertManager>,
+    behavior_classifier: Arc<WalletBehaviorClassifier>,
     monitored_wallets: Arc<RwLock<HashSet<String>>>,
     processed_transactions: Arc<RwLock<HashSet<String>>>,
     event_handler: Arc<tokio::sync::Mutex<Option<tauri::EventHandler>>>,
@@ -32,6 +34,7 @@ impl WalletMonitor {
         ws_manager: WebSocketManager,
         smart_money_detector: Arc<SmartMoneyDetector>,
         alert_manager: Arc<AlertManager>,
+        behavior_classifier: Arc<WalletBehaviorClassifier>,
     ) -> Self {
         Self {
             db,
@@ -39,6 +42,7 @@ impl WalletMonitor {
             ws_manager,
             smart_money_detector,
             alert_manager,
+            behavior_classifier,
             monitored_wallets: Arc::new(RwLock::new(HashSet::new())),
             processed_transactions: Arc::new(RwLock::new(HashSet::new())),
             event_handler: Arc::new(tokio::sync::Mutex::new(None)),
@@ -244,6 +248,14 @@ impl WalletMonitor {
             .map(|w| (w.wallet_address.clone(), w.label))
             .collect();
 
+        let addresses: Vec<String> = records
+            .iter()
+            .map(|r| r.wallet_address.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let behaviors = self.behavior_classifier.tags_for(&addresses).await;
+
         let activities: Vec<WalletActivity> = records
             .into_iter()
             .map(|r| {
@@ -266,6 +278,7 @@ impl WalletMonitor {
                     amount_usd: r.amount_usd,
                     price: r.price,
                     is_whale,
+                    behavior: behaviors.get(&r.wallet_address).cloned(),
                     timestamp: r.timestamp,
                 }
             })
@@ -294,6 +307,20 @@ impl WalletMonitor {
             let activities: Vec<WalletActivity> = queue.drain(..).collect();
             drop(queue);
 
+            for activity in activities.iter().filter(|activity| activity.is_whale) {
+                publish_domain_event(
+                    "wallet_monitor",
+                    DomainEvent::WhaleActivity {
+                        wallet_address: activity.wallet_address.clone(),
+                        wallet_label: activity.wallet_label.clone(),
+                        action: activity.action_type.clone(),
+                        token: activity.output_mint.clone().or_else(|| activity.input_mint.clone()),
+                        amount_usd: activity.amount_usd,
+                        tx_signature: activity.tx_signature.clone(),
+                    },
+                );
+            }
+
             let batch = WalletActivityBatch {
                 activities: activities.clone(),
                 timestamp: Utc::now(),
@@ -368,7 +395,10 @@ impl WalletMonitor {
             let wallet_activity = WalletActivity {
                 id: activity.id.clone(),
                 wallet_address: wallet_address.clone(),
-                wallet_label: wallet_info.and_then(|w| w.label.clone()),
+                wallet_label: match wallet_info.and_then(|w| w.label.clone()) {
+                    Some(label) => Some(label),
+                    None => crate::wallet::sns::display_domain(&self.app_handle, &wallet_address).await,
+                },
                 tx_signature: activity.tx_signature.clone(),
                 action_type: activity.action_type.clone(),
                 input_mint: activity.input_mint.clone(),
@@ -379,6 +409,11 @@ impl WalletMonitor {
                 amount_usd: activity.amount_usd,
                 price: activity.price,
                 is_whale: is_whale_flag,
+                behavior: self
+                    .behavior_classifier
+                    .tags_for(std::slice::from_ref(&wallet_address))
+                    .await
+                    .remove(&wallet_address),
                 timestamp: activity.timestamp,
             };
 
@@ -392,6 +427,14 @@ impl WalletMonitor {
                 eprintln!("Failed to process whale alert: {err}");
             }
 
+            if let Err(err) = self
+                .alert_manager
+                .process_portfolio_token_trade(&wallet_activity)
+                .await
+            {
+                eprintln!("Failed to process portfolio token trade alert: {err}");
+            }
+
             match self
                 .smart_money_detector
                 .classify_wallet(&wallet_address)
@@ -466,6 +509,7 @@ pub struct WalletMonitorState {
     pub monitor: Arc<WalletMonitor>,
     pub smart_money_detector: Arc<SmartMoneyDetector>,
     pub alert_manager: Arc<AlertManager>,
+    pub behavior_classifier: Arc<WalletBehaviorClassifier>,
 }
 
 static WALLET_MONITOR_STATE: OnceCell<WalletMonitorState> = OnceCell::const_new();
@@ -506,12 +550,15 @@ pub async fn init_wallet_monitor(app_handle: &AppHandle) -> Result<(), String> {
 
     let alert_manager = Arc::new(AlertManager::new(pool.clone(), app_handle.clone()));
 
+    let behavior_classifier = Arc::new(WalletBehaviorClassifier::new(pool.clone()).await?);
+
     let monitor = Arc::new(WalletMonitor::new(
         shared_db.clone(),
         app_handle.clone(),
         ws_manager,
         smart_money_detector.clone(),
         alert_manager.clone(),
+        behavior_classifier.clone(),
     ));
 
     monitor.initialize().await?;
@@ -526,12 +573,18 @@ pub async fn init_wallet_monitor(app_handle: &AppHandle) -> Result<(), String> {
         batch_processor.run_batch_processor().await;
     });
 
+    let classifier = behavior_classifier.clone();
+    tauri::async_runtime::spawn(async move {
+        WalletBehaviorClassifier::start_reclassification(classifier).await;
+    });
+
     WALLET_MONITOR_STATE
         .set(WalletMonitorState {
             db: shared_db,
             monitor: monitor.clone(),
             smart_money_detector: smart_money_detector.clone(),
             alert_manager: alert_manager.clone(),
+            behavior_classifier,
         })
         .map_err(|_| "Wallet monitor state already initialized".to_string())?;
 
@@ -551,8 +604,14 @@ pub async fn wallet_monitor_init(handle: AppHandle) -> Result<(), String> {
 
 #[tauri::command]
 pub async fn wallet_monitor_add_wallet(
-    request: AddMonitoredWalletRequest,
+    mut request: AddMonitoredWalletRequest,
+    handle: AppHandle,
 ) -> Result<MonitoredWallet, String> {
+    let address = crate::wallet::sns::resolve_address_input(&handle, &request.wallet_address).await?;
+    if address != request.wallet_address.trim() && request.label.is_none() {
+        request.label = Some(request.wallet_address.trim().to_lowercase());
+    }
+    request.wallet_address = address;
     let state = require_state()?;
     state.monitor.add_wallet(request).await
 }
@@ -582,9 +641,14 @@ pub async fn wallet_monitor_get_activities(
     filter: ActivityFilter,
     limit: i32,
     offset: i32,
+    handle: AppHandle,
 ) -> Result<Vec<WalletActivity>, String> {
     let state = require_state()?;
-    state.monitor.get_activities(filter, limit, offset).await
+    let mut activities = state.monitor.get_activities(filter, limit, offset).await?;
+    for activity in activities.iter_mut().filter(|a| a.wallet_label.is_none()) {
+        activity.wallet_label = crate::wallet::sns::display_domain(&handle, &activity.wallet_address).await;
+    }
+    Ok(activities)
 }
 
 #[tauri::command]
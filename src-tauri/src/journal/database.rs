@@ -391,24 +391,9 @@ impl JournalDatabase {
             trade_id: row.get("trade_id"),
             entry_type: serde_json::from_str(row.get("entry_type")).unwrap_or(EntryType::Reflection),
             strategy_tags: serde_json::from_str(row.get("strategy_tags")).unwrap_or_default(),
-            emotions: serde_json::from_str(row.get("emotions")).unwrap_or_else(|_| EmotionTracking {
-                primary_emotion: Emotion::Neutral,
-                intensity: 0.5,
-                secondary_emotions: vec![],
-                stress_level: 0.5,
-                clarity_level: 0.5,
-                fomo_level: 0.0,
-                revenge_trading: false,
-                discipline_score: 0.5,
-            }),
+            emotions: serde_json::from_str(row.get("emotions")).unwrap_or_default(),
             notes: row.get("notes"),
-            market_conditions: serde_json::from_str(row.get("market_conditions")).unwrap_or_else(|_| MarketConditions {
-                trend: MarketTrend::Neutral,
-                volatility: Volatility::Medium,
-                volume: VolumeLevel::Medium,
-                news_sentiment: 0.0,
-                notes: String::new(),
-            }),
+            market_conditions: serde_json::from_str(row.get("market_conditions")).unwrap_or_default(),
             confidence_level: row.get("confidence_level"),
             position_size: row.get("position_size"),
             entry_price: row.get("entry_price"),
@@ -44,6 +44,21 @@ pub struct EmotionTracking {
     pub discipline_score: f32,
 }
 
+impl Default for EmotionTracking {
+    fn default() -> Self {
+        Self {
+            primary_emotion: Emotion::Neutral,
+            intensity: 0.5,
+            secondary_emotions: vec![],
+            stress_level: 0.5,
+            clarity_level: 0.5,
+            fomo_level: 0.0,
+            revenge_trading: false,
+            discipline_score: 0.5,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum Emotion {
@@ -70,6 +85,18 @@ pub struct MarketConditions {
     pub notes: String,
 }
 
+impl Default for MarketConditions {
+    fn default() -> Self {
+        Self {
+            trend: MarketTrend::Neutral,
+            volatility: Volatility::Medium,
+            volume: VolumeLevel::Medium,
+            news_sentiment: 0.0,
+            notes: String::new(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum MarketTrend {
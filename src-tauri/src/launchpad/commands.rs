@@ -5,6 +5,7 @@ use super::security::LaunchpadKeyManager;
 use super::token::TokenManager;
 use super::types::*;
 use super::vesting::VestingManager;
+use crate::config::feature_flags::ModuleState;
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -43,7 +44,7 @@ pub fn create_launchpad_state(rpc_url: String) -> SharedLaunchpadState {
 
 #[tauri::command]
 pub async fn create_launch_config(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     name: String,
     symbol: String,
     decimals: u8,
@@ -81,7 +82,7 @@ pub async fn create_launch_config(
 
 #[tauri::command]
 pub async fn update_launch_config(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     launch_id: String,
     config: TokenLaunchConfig,
 ) -> Result<TokenLaunchConfig, String> {
@@ -96,7 +97,7 @@ pub async fn update_launch_config(
 
 #[tauri::command]
 pub async fn get_launch_config(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     launch_id: String,
 ) -> Result<TokenLaunchConfig, String> {
     state
@@ -109,7 +110,7 @@ pub async fn get_launch_config(
 
 #[tauri::command]
 pub async fn list_launches(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
 ) -> Result<Vec<TokenLaunchConfig>, String> {
     let launches: Vec<_> = state.read().launches.values().cloned().collect();
     Ok(launches)
@@ -117,7 +118,7 @@ pub async fn list_launches(
 
 #[tauri::command]
 pub async fn simulate_token_creation(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     request: CreateTokenRequest,
 ) -> Result<TransactionSimulation, String> {
     state
@@ -130,7 +131,7 @@ pub async fn simulate_token_creation(
 
 #[tauri::command]
 pub async fn launchpad_create_token(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     request: CreateTokenRequest,
     app: AppHandle,
 ) -> Result<CreateTokenResponse, String> {
@@ -171,7 +172,7 @@ pub async fn check_liquidity_lock_compliance(
 
 #[tauri::command]
 pub async fn create_liquidity_lock(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     request: LockLiquidityRequest,
     app: AppHandle,
 ) -> Result<LiquidityLockConfig, String> {
@@ -185,7 +186,7 @@ pub async fn create_liquidity_lock(
 
 #[tauri::command]
 pub async fn unlock_liquidity(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     lock_id: String,
     app: AppHandle,
 ) -> Result<String, String> {
@@ -199,7 +200,7 @@ pub async fn unlock_liquidity(
 
 #[tauri::command]
 pub async fn get_liquidity_lock(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     lock_id: String,
 ) -> Result<LiquidityLockConfig, String> {
     state
@@ -211,7 +212,7 @@ pub async fn get_liquidity_lock(
 
 #[tauri::command]
 pub async fn list_liquidity_locks(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
 ) -> Result<Vec<LiquidityLockConfig>, String> {
     Ok(state.read().liquidity_locker.get_all_locks())
 }
@@ -220,7 +221,7 @@ pub async fn list_liquidity_locks(
 
 #[tauri::command]
 pub async fn create_vesting_schedule(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     request: CreateVestingRequest,
 ) -> Result<VestingSchedule, String> {
     state
@@ -232,7 +233,7 @@ pub async fn create_vesting_schedule(
 
 #[tauri::command]
 pub async fn release_vested_tokens(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     schedule_id: String,
     amount: u64,
 ) -> Result<VestingSchedule, String> {
@@ -245,7 +246,7 @@ pub async fn release_vested_tokens(
 
 #[tauri::command]
 pub async fn get_vesting_schedule(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     schedule_id: String,
 ) -> Result<VestingSchedule, String> {
     state
@@ -257,7 +258,7 @@ pub async fn get_vesting_schedule(
 
 #[tauri::command]
 pub async fn list_vesting_schedules(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     token_mint: Option<String>,
     beneficiary: Option<String>,
 ) -> Result<Vec<VestingSchedule>, String> {
@@ -276,7 +277,7 @@ pub async fn list_vesting_schedules(
 
 #[tauri::command]
 pub async fn create_airdrop(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     request: CreateAirdropRequest,
 ) -> Result<AirdropConfig, String> {
     state
@@ -288,7 +289,7 @@ pub async fn create_airdrop(
 
 #[tauri::command]
 pub async fn activate_airdrop(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     airdrop_id: String,
 ) -> Result<AirdropConfig, String> {
     state
@@ -300,7 +301,7 @@ pub async fn activate_airdrop(
 
 #[tauri::command]
 pub async fn claim_airdrop_tokens(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     airdrop_id: String,
     recipient_address: String,
 ) -> Result<AirdropRecipient, String> {
@@ -313,7 +314,7 @@ pub async fn claim_airdrop_tokens(
 
 #[tauri::command]
 pub async fn get_airdrop(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     airdrop_id: String,
 ) -> Result<AirdropConfig, String> {
     state
@@ -325,7 +326,7 @@ pub async fn get_airdrop(
 
 #[tauri::command]
 pub async fn get_airdrop_metrics(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     airdrop_id: String,
 ) -> Result<AirdropMetrics, String> {
     state
@@ -339,7 +340,7 @@ pub async fn get_airdrop_metrics(
 
 #[tauri::command]
 pub async fn get_distribution_metrics(
-    state: tauri::State<'_, SharedLaunchpadState>,
+    state: ModuleState<'_, SharedLaunchpadState>,
     token_mint: String,
 ) -> Result<DistributionMetrics, String> {
     let state = state.read();
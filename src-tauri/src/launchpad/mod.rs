@@ -9,3 +9,19 @@ pub mod commands;
 
 pub use commands::*;
 pub use types::*;
+
+use tauri::{AppHandle, Manager};
+
+use crate::config::feature_flags::{FeatureModule, ModuleOwned};
+
+const LAUNCHPAD_RPC_URL: &str = "https://api.mainnet-beta.solana.com";
+
+impl ModuleOwned for SharedLaunchpadState {
+    const MODULE: FeatureModule = FeatureModule::Launchpad;
+}
+
+/// Manages the launchpad state. Skipped at startup while the launchpad module
+/// is switched off.
+pub fn start_launchpad_module(app_handle: &AppHandle) {
+    app_handle.manage(create_launchpad_state(LAUNCHPAD_RPC_URL.to_string()));
+}
@@ -8,6 +8,7 @@ mod api_config;
 mod auth;
 mod auto_start;
 mod backup;
+mod batch_commands;
 mod bots;
 mod cache_commands;
 mod chains;
@@ -20,10 +21,12 @@ mod core;
 mod diagnostics;
 mod data;
 mod defi;
+mod demo;
 mod dev_tools;
 mod drawings;
 mod errors;
 mod fixer;
+mod idempotency;
 mod indicators;
 mod insiders;
 mod launchpad;
@@ -48,6 +51,7 @@ mod updater;
 mod voice;
 pub mod voice;
 mod wallet;
+mod watchlist_stream;
 mod websocket;
 mod webhooks;
 mod windowing;
@@ -77,6 +81,7 @@ pub use bots::*;
 pub use chains::*;
 pub use bridges::*;
 pub use chart_stream::*;
+pub use watchlist_stream::*;
 pub use collab::*;
 pub use compiler::*;
 pub use config::*;
@@ -116,6 +121,7 @@ pub use webhooks::*;
 
 pub use wallet::multisig::*;
 pub use wallet::performance::*;
+pub use wallet::sns::*;
 pub use windowing::*;
 
 use ai::launch_predictor::{
@@ -130,7 +136,10 @@ use ai::launch_predictor::{
     SharedLaunchPredictor,
 };
 use ai::SharedAIAssistant;
-use alerts::{AlertManager, SharedAlertManager, SharedSmartAlertManager, SmartAlertManager};
+use alerts::{
+    AlertHistoryManager, AlertManager, SharedAlertHistoryManager, SharedAlertManager,
+    SharedSmartAlertManager, SmartAlertManager,
+};
 use api::{ApiHealthMonitor, SharedApiHealthMonitor};
 use collab::state::CollabState;
 use drawings::{DrawingManager, SharedDrawingManager};
@@ -164,7 +173,7 @@ use wallet::multi_wallet::MultiWalletManager;
 use wallet::phantom::{hydrate_wallet_state, WalletState};
 use auto_start::{AutoStartManager, SharedAutoStartManager};
 use tray::{attach_window_listeners, handle_tray_event, SharedTrayManager, TrayManager};
-use core::cache_manager::{CacheType, SharedCacheManager};
+use core::cache_manager::SharedCacheManager;
 use market::{HolderAnalyzer, SharedHolderAnalyzer};
 use chains::{ChainManager, SharedChainManager};
 use bridges::{BridgeManager, SharedBridgeManager};
@@ -182,26 +191,23 @@ use voice::commands::{SharedVoiceState, VoiceState};
 use config::settings_manager::{SettingsManager, SharedSettingsManager};
 use governance::commands::*;
 use journal::{JournalDatabase, SharedJournalDatabase};
-use p2p::{init_p2p_system, SharedP2PDatabase};
 
 async fn warm_cache_on_startup(
     _app_handle: tauri::AppHandle,
     cache_manager: SharedCacheManager,
 ) -> Result<(), String> {
-    use serde_json::json;
-
     // Define top tokens to warm
-    let top_tokens = vec![
-        "So11111111111111111111111111111111111111112", // SOL
-        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", // USDC
-        "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", // USDT
-        "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", // BONK
-        "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN", // JUP
-        "7vfCXTUXx5WJV5JADk17DUJ4ksgau7utNKj4b963voxs", // ETH
-        "mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So", // mSOL
-        "7dHbWXmci3dT8UFYWYZweBLXgycu7Y3iL6trKn1Y7ARj", // stSOL
-        "orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE", // ORCA
-        "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R", // RAY
+    let top_tokens = [
+        ("So11111111111111111111111111111111111111112", "SOL"),
+        ("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "USDC"),
+        ("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", "USDT"),
+        ("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", "BONK"),
+        ("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN", "JUP"),
+        ("7vfCXTUXx5WJV5JADk17DUJ4ksgau7utNKj4b963voxs", "ETH"),
+        ("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So", "mSOL"),
+        ("7dHbWXmci3dT8UFYWYZweBLXgycu7Y3iL6trKn1Y7ARj", "stSOL"),
+        ("orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE", "ORCA"),
+        ("4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R", "RAY"),
     ];
 
     let manager = cache_manager.read().await;
@@ -213,17 +219,16 @@ async fn warm_cache_on_startup(
     // Warm cache with top tokens
     let keys: Vec<String> = top_tokens
         .iter()
-        .map(|addr| format!("token_price_{}", addr))
+        .map(|(addr, _)| format!("token_price_{}", addr))
         .collect();
 
-    let _ = manager.warm_cache(keys, |key| async move {
-        // Mock data - in real implementation would fetch from API
-        let data = json!({
-            "price": 100.0,
-            "change24h": 5.0,
-            "volume": 1000000.0,
-        });
-        Ok((data, CacheType::TokenPrice))
+    // The price engine is keyed by symbol; tokens it has no price for yet are skipped
+    let _ = manager.warm_cache(keys, |key| {
+        let symbol = top_tokens
+            .iter()
+            .find(|(addr, _)| key.ends_with(addr))
+            .map(|(_, symbol)| *symbol);
+        async move { cache_commands::price_warm_entry(&key, symbol) }
     }).await;
 
     Ok(())
@@ -239,11 +244,45 @@ pub fn run() {
         .manage(WalletState::new())
         .manage(HardwareWalletState::new())
         .manage(LedgerState::new())
+        .register_uri_scheme_protocol(market::TOKEN_IMAGE_PROTOCOL, market::token_image_protocol)
         .setup(|app| {
             if let Err(e) = hydrate_wallet_state(&app.handle()) {
                 eprintln!("Failed to hydrate wallet state: {e}");
             }
 
+            if let Err(e) = demo::load_demo_mode(&app.handle()) {
+                eprintln!("Failed to load demo mode setting: {e}");
+            }
+
+            if let Err(e) = config::data_sources::load_data_sources(&app.handle()) {
+                eprintln!("Failed to load data source settings: {e}");
+            }
+
+            if let Err(e) = config::token_registry::load_token_registry(&app.handle()) {
+                eprintln!("Failed to load token registry: {e}");
+            }
+
+            if let Err(e) = config::localization::load_localization(&app.handle()) {
+                eprintln!("Failed to load localization settings: {e}");
+            }
+
+            if let Err(e) = config::feature_flags::load_feature_flags(&app.handle()) {
+                eprintln!("Failed to load feature flags: {e}");
+            }
+
+            match market::TokenImageCache::new(&app.handle()) {
+                Ok(cache) => {
+                    app.manage::<market::SharedTokenImageCache>(Arc::new(cache));
+                }
+                Err(e) => eprintln!("Failed to initialize token image cache: {e}"),
+            }
+
+            if let Err(e) = tauri::async_runtime::block_on(
+                config::http_recorder::init_http_recorder(&app.handle()),
+            ) {
+                eprintln!("Failed to initialize HTTP recorder: {e}");
+            }
+
             let keystore = Keystore::initialize(&app.handle()).map_err(|e| {
                 eprintln!("Failed to initialize keystore: {e}");
                 Box::new(e) as Box<dyn Error>
@@ -296,14 +335,45 @@ pub fn run() {
             let shared_reputation_engine: SharedReputationEngine = Arc::new(RwLock::new(reputation_engine));
             app.manage(shared_reputation_engine.clone());
 
-            // Initialize P2P system
-            let p2p_db = tauri::async_runtime::block_on(async {
-                init_p2p_system(&app.handle()).await
+            // Initialize community blocklist sync
+            let blocklist_sync = tauri::async_runtime::block_on(async {
+                let denylist_engine = shared_reputation_engine.read().await;
+                if let Err(e) = security::blocklist_sync::refresh_denylist(&denylist_engine).await {
+                    eprintln!("Failed to load blocklist denylist: {e}");
+                }
+                drop(denylist_engine);
+                security::blocklist_sync::BlocklistSync::new(
+                    &app.handle(),
+                    shared_reputation_engine.clone(),
+                )
+                .await
             }).map_err(|e| {
-                eprintln!("Failed to initialize P2P system: {e}");
-                e
+                eprintln!("Failed to initialize blocklist sync: {e}");
+                Box::new(e) as Box<dyn Error>
+            })?;
+
+            let shared_blocklist_sync: security::blocklist_sync::SharedBlocklistSync =
+                Arc::new(blocklist_sync);
+            app.manage(shared_blocklist_sync.clone());
+
+            tauri::async_runtime::spawn(async move {
+                loop {
+                    if let Err(err) = shared_blocklist_sync.sync_all().await {
+                        eprintln!("Failed to sync community blocklists: {err}");
+                    }
+                    tokio::time::sleep(security::blocklist_sync::BLOCKLIST_SYNC_INTERVAL).await;
+                }
+            });
+
+            // Initialize P2P system
+            tauri::async_runtime::block_on(config::feature_flags::start_feature_module(
+                &app.handle(),
+                config::FeatureModule::P2p,
+            ))
+            .map_err(|e| {
+                eprintln!("{e}");
+                Box::new(std::io::Error::new(std::io::ErrorKind::Other, e)) as Box<dyn Error>
             })?;
-            app.manage(p2p_db.clone());
 
             // Initialize academy engine
             let academy_engine = tauri::async_runtime::block_on(async {
@@ -335,12 +405,14 @@ pub fn run() {
             app.manage(keystore);
             app.manage(multi_wallet_manager);
             app.manage(wallet_operations_manager);
+            app.manage(wallet::sns::SnsResolver::new());
             app.manage(session_manager);
             app.manage(two_factor_manager);
             app.manage(ws_manager);
             app.manage(activity_logger);
             app.manage(api_config_manager);
             app.manage(api_health_state.clone());
+            api_config::start_failover_monitor(app.handle());
 
             let chain_manager: SharedChainManager = Arc::new(RwLock::new(ChainManager::new()));
             app.manage(chain_manager.clone());
@@ -362,10 +434,31 @@ pub fn run() {
             let settings_state: SharedSettingsManager = Arc::new(RwLock::new(settings_manager));
             app.manage(settings_state.clone());
 
+            // Initialize config version history
+            let config_history = tauri::async_runtime::block_on(async {
+                config::version_history::ConfigHistoryStore::new(&app.handle()).await
+            }).map_err(|e| {
+                eprintln!("Failed to initialize config history: {e}");
+                Box::new(e) as Box<dyn Error>
+            })?;
+            let config_history_state: config::version_history::SharedConfigHistory = Arc::new(RwLock::new(config_history));
+            app.manage(config_history_state);
+
+            // Initialize recycle bin for undoable deletes
+            let recycle_bin = tauri::async_runtime::block_on(async {
+                config::recycle_bin::RecycleBin::new(&app.handle()).await
+            }).map_err(|e| {
+                eprintln!("Failed to initialize recycle bin: {e}");
+                Box::new(e) as Box<dyn Error>
+            })?;
+            let recycle_bin_state: config::recycle_bin::SharedRecycleBin = Arc::new(RwLock::new(recycle_bin));
+            app.manage(recycle_bin_state);
+
             // Initialize launchpad state
-            let rpc_url = "https://api.mainnet-beta.solana.com".to_string();
-            let launchpad_state = launchpad::commands::create_launchpad_state(rpc_url);
-            app.manage(launchpad_state);
+            tauri::async_runtime::block_on(config::feature_flags::start_feature_module(
+                &app.handle(),
+                config::FeatureModule::Launchpad,
+            ))?;
 
             // Initialize collaborative rooms state
             let collab_websocket = collab::websocket::CollabWebSocketManager::new(app.handle());
@@ -390,6 +483,12 @@ pub fn run() {
             trading::register_trading_state(app);
             trading::register_paper_trading_state(app);
             trading::register_auto_trading_state(app);
+            trading::register_promotion_state(app);
+            trading::register_risk_policy_state(app);
+            trading::register_execution_presets(app);
+            trading::register_snipe_engine(app);
+            portfolio::register_dust_consolidator(app);
+            portfolio::start_weekly_pnl_recap(app.handle());
             trading::register_optimizer_state(app);
 
             // Initialize safety engine
@@ -446,6 +545,91 @@ pub fn run() {
             let performance_state: SharedPerformanceDatabase = Arc::new(RwLock::new(performance_db));
             app.manage(performance_state.clone());
 
+            // Initialize fee tracker
+            let fee_tracker = tauri::async_runtime::block_on(wallet::fee_tracker::FeeTracker::new(&app.handle()))
+                .map_err(|e| {
+                    eprintln!("Failed to initialize fee tracker: {e}");
+                    Box::new(std::io::Error::new(std::io::ErrorKind::Other, e)) as Box<dyn Error>
+                })?;
+
+            let fee_tracker_state: wallet::fee_tracker::SharedFeeTracker = Arc::new(RwLock::new(fee_tracker));
+            app.manage(fee_tracker_state.clone());
+
+            if let Err(e) = tauri::async_runtime::block_on(wallet::fee_disclosure::init_fee_disclosure(&app.handle())) {
+                eprintln!("Failed to initialize fee disclosure ledger: {e}");
+            }
+
+            if let Err(e) = tauri::async_runtime::block_on(wallet::compute_budget::init_compute_budget(&app.handle())) {
+                eprintln!("Failed to initialize compute budget tuner: {e}");
+            }
+
+            // Initialize rent reclaimer
+            let rent_reclaimer = tauri::async_runtime::block_on(wallet::rent_reclaim::RentReclaimer::new(&app.handle()))
+                .map_err(|e| {
+                    eprintln!("Failed to initialize rent reclaimer: {e}");
+                    Box::new(std::io::Error::new(std::io::ErrorKind::Other, e)) as Box<dyn Error>
+                })?;
+
+            let rent_reclaimer_state: wallet::rent_reclaim::SharedRentReclaimer = Arc::new(RwLock::new(rent_reclaimer));
+            app.manage(rent_reclaimer_state);
+
+            // Initialize staking advisor
+            let staking_advisor = tauri::async_runtime::block_on(defi::StakingAdvisor::new(&app.handle()))
+                .map_err(|e| {
+                    eprintln!("Failed to initialize staking advisor: {e}");
+                    Box::new(std::io::Error::new(std::io::ErrorKind::Other, e)) as Box<dyn Error>
+                })?;
+
+            let staking_advisor_state: defi::SharedStakingAdvisor = Arc::new(RwLock::new(staking_advisor));
+            app.manage(staking_advisor_state);
+
+            // Initialize DEX pool analytics
+            let pool_analytics = tauri::async_runtime::block_on(defi::PoolAnalyticsManager::new(&app.handle()))
+                .map_err(|e| {
+                    eprintln!("Failed to initialize pool analytics: {e}");
+                    Box::new(e) as Box<dyn Error>
+                })?;
+
+            let pool_analytics_state: defi::SharedPoolAnalyticsManager = Arc::new(RwLock::new(pool_analytics));
+            app.manage(pool_analytics_state.clone());
+            defi::start_pool_snapshots(pool_analytics_state);
+
+            // Initialize yield opportunity scanner
+            let yield_scanner = defi::YieldScanner::new(&app.handle()).map_err(|e| {
+                eprintln!("Failed to initialize yield scanner: {e}");
+                Box::new(std::io::Error::new(std::io::ErrorKind::Other, e)) as Box<dyn Error>
+            })?;
+
+            let yield_scanner_state: defi::SharedYieldScanner = Arc::new(RwLock::new(yield_scanner));
+            app.manage(yield_scanner_state.clone());
+            defi::start_yield_scanner(app.handle(), yield_scanner_state);
+
+            // Initialize lending position monitor
+            let lending_monitor = tauri::async_runtime::block_on(async {
+                defi::LendingMonitor::new(&app.handle()).await
+            })
+            .map_err(|e| {
+                eprintln!("Failed to initialize lending monitor: {e}");
+                Box::new(e) as Box<dyn Error>
+            })?;
+
+            let lending_monitor_state: defi::SharedLendingMonitor = Arc::new(RwLock::new(lending_monitor));
+            app.manage(lending_monitor_state.clone());
+            defi::start_lending_monitor(app.handle(), lending_monitor_state);
+
+            // Initialize cross-chain bridge tracker
+            let bridge_tracker = tauri::async_runtime::block_on(async {
+                bridges::BridgeTracker::new(&app.handle()).await
+            })
+            .map_err(|e| {
+                eprintln!("Failed to initialize bridge tracker: {e}");
+                Box::new(e) as Box<dyn Error>
+            })?;
+
+            let bridge_tracker_state: bridges::SharedBridgeTracker = Arc::new(RwLock::new(bridge_tracker));
+            app.manage(bridge_tracker_state.clone());
+            bridges::start_bridge_tracker(app.handle(), bridge_tracker_state);
+
             // Initialize journal database
             let mut journal_db_path = app
                 .path_resolver()
@@ -512,6 +696,9 @@ pub fn run() {
              let top_coins_cache: market::SharedTopCoinsCache = Arc::new(RwLock::new(market::TopCoinsCache::new()));
              app.manage(top_coins_cache.clone());
 
+             let order_book_manager: market::SharedOrderBookManager = Arc::new(RwLock::new(market::OrderBookManager::new()));
+             app.manage(order_book_manager.clone());
+
              // Initialize watchlist manager
              let watchlist_manager = tauri::async_runtime::block_on(async {
                  WatchlistManager::new(&app.handle()).await
@@ -523,6 +710,65 @@ pub fn run() {
              let watchlist_state: SharedWatchlistManager = Arc::new(RwLock::new(watchlist_manager));
              app.manage(watchlist_state.clone());
 
+             // Initialize token unlock calendar
+             let unlock_manager = tauri::async_runtime::block_on(async {
+                 market::TokenUnlockManager::new(&app.handle()).await
+             }).map_err(|e| {
+                 eprintln!("Failed to initialize token unlock calendar: {e}");
+                 Box::new(e) as Box<dyn Error>
+             })?;
+
+             let unlock_state: market::SharedTokenUnlockManager = Arc::new(RwLock::new(unlock_manager));
+             app.manage(unlock_state.clone());
+             market::start_unlock_alerts(app.handle(), unlock_state);
+
+             // Initialize macro event calendar
+             let macro_manager = tauri::async_runtime::block_on(async {
+                 market::MacroEventManager::new(&app.handle()).await
+             }).map_err(|e| {
+                 eprintln!("Failed to initialize macro event calendar: {e}");
+                 Box::new(e) as Box<dyn Error>
+             })?;
+
+             let macro_state: market::SharedMacroEventManager = Arc::new(RwLock::new(macro_manager));
+             app.manage(macro_state.clone());
+             market::start_macro_event_monitor(app.handle(), macro_state);
+
+             // Initialize chart annotation store
+             let annotation_store = tauri::async_runtime::block_on(async {
+                 market::ChartAnnotationStore::new(&app.handle()).await
+             }).map_err(|e| {
+                 eprintln!("Failed to initialize chart annotations: {e}");
+                 Box::new(e) as Box<dyn Error>
+             })?;
+
+             let annotation_state: market::SharedChartAnnotationStore = Arc::new(RwLock::new(annotation_store));
+             app.manage(annotation_state.clone());
+             market::start_chart_annotation_sync(app.handle(), annotation_state);
+
+             // Initialize exposure tracker
+             let exposure_tracker = tauri::async_runtime::block_on(async {
+                 portfolio::ExposureTracker::new(&app.handle()).await
+             }).map_err(|e| {
+                 eprintln!("Failed to initialize exposure tracker: {e}");
+                 Box::new(e) as Box<dyn Error>
+             })?;
+
+             let exposure_state: portfolio::SharedExposureTracker = Arc::new(RwLock::new(exposure_tracker));
+             app.manage(exposure_state.clone());
+
+             // Initialize encrypted portfolio sharing
+             let share_manager = tauri::async_runtime::block_on(async {
+                 portfolio::PortfolioShareManager::new(&app.handle()).await
+             }).map_err(|e| {
+                 eprintln!("Failed to initialize portfolio sharing: {e}");
+                 Box::new(e) as Box<dyn Error>
+             })?;
+
+             let share_state: portfolio::SharedPortfolioShareManager = Arc::new(RwLock::new(share_manager));
+             app.manage(share_state.clone());
+             portfolio::start_share_publisher(app.handle(), share_state);
+
              let token_flow_state = token_flow::commands::create_token_flow_state();
              app.manage(token_flow_state.clone());
 
@@ -537,6 +783,17 @@ pub fn run() {
              let alert_state: SharedAlertManager = Arc::new(RwLock::new(alert_manager));
              app.manage(alert_state.clone());
 
+             let alert_history_manager = tauri::async_runtime::block_on(async {
+                 AlertHistoryManager::new(&app.handle()).await
+             }).map_err(|e| {
+                 eprintln!("Failed to initialize alert history manager: {e}");
+                 Box::new(e) as Box<dyn Error>
+             })?;
+
+             let alert_history_state: SharedAlertHistoryManager =
+                 Arc::new(RwLock::new(alert_history_manager));
+             app.manage(alert_history_state.clone());
+
              let smart_alert_manager = tauri::async_runtime::block_on(async {
                  SmartAlertManager::new(&app.handle()).await
              }).map_err(|e| {
@@ -560,6 +817,21 @@ pub fn run() {
                  }
              });
 
+             // Sample alert outcomes at their 1h/4h/24h checkpoints
+             tauri::async_runtime::spawn(async move {
+                 use tokio::time::{sleep, Duration};
+                 loop {
+                     sleep(Duration::from_secs(300)).await;
+                     let engine = core::price_engine::get_price_engine();
+                     let lookup = |symbol: &str| engine.get_cached_price(symbol).map(|p| p.price);
+                     let mgr = alert_history_state.read().await;
+                     let now = chrono::Utc::now();
+                     if let Err(err) = mgr.update_pending_outcomes(lookup, now).await {
+                         eprintln!("Failed to update alert outcomes: {err}");
+                     }
+                 }
+             });
+
              // Initialize notification router
 
                  NotificationRouter::new(&app.handle()).await
@@ -570,6 +842,53 @@ pub fn run() {
 
              let notification_state: SharedNotificationRouter = Arc::new(RwLock::new(notification_router));
              app.manage(notification_state.clone());
+             notifications::register_telegram_bot(app);
+             notifications::register_discord_bot(app);
+             notifications::register_integration_health(app);
+
+             wallet::multisig_notifications::start_reminder_loop(
+                 app.handle(),
+                 multisig_state.clone(),
+                 notification_state.clone(),
+             );
+
+             // Initialize scheduled transaction executor
+             let tx_scheduler = tauri::async_runtime::block_on(wallet::tx_scheduler::TransactionScheduler::new(&app.handle()))
+                 .map_err(|e| {
+                     eprintln!("Failed to initialize transaction scheduler: {e}");
+                     Box::new(std::io::Error::new(std::io::ErrorKind::Other, e)) as Box<dyn Error>
+                 })?;
+
+             let tx_scheduler_state: wallet::tx_scheduler::SharedTransactionScheduler = Arc::new(RwLock::new(tx_scheduler));
+             app.manage(tx_scheduler_state.clone());
+
+             wallet::tx_scheduler::start_scheduler_executor(
+                 app.handle(),
+                 tx_scheduler_state.clone(),
+                 multisig_state.clone(),
+                 notification_state.clone(),
+             );
+
+             wallet::nonce_accounts::register_nonce_accounts(app);
+             wallet::offline_signing::register_offline_signing(app);
+
+             // Initialize dead man's switch monitor
+             let dead_mans_switch = security::dead_mans_switch::DeadMansSwitch::new(&app.handle())
+                 .map_err(|e| {
+                     eprintln!("Failed to initialize dead man's switch: {e}");
+                     Box::new(std::io::Error::new(std::io::ErrorKind::Other, e.to_string())) as Box<dyn Error>
+                 })?;
+
+             let dead_mans_switch_state: security::dead_mans_switch::SharedDeadMansSwitch = Arc::new(RwLock::new(dead_mans_switch));
+             app.manage(dead_mans_switch_state.clone());
+
+             security::dead_mans_switch::start_switch_monitor(
+                 app.handle(),
+                 dead_mans_switch_state,
+                 tx_scheduler_state,
+                 multisig_state.clone(),
+                 notification_state.clone(),
+             );
 
              // Initialize indicator manager
              let app_data_dir = app
@@ -597,6 +916,11 @@ pub fn run() {
              let webhook_state: SharedWebhookManager = Arc::new(RwLock::new(webhook_manager));
              app.manage(webhook_state.clone());
 
+             // Durable hand-off between producers and notification/webhook consumers
+             if let Err(e) = tauri::async_runtime::block_on(core::message_queue::init_delivery_queue(&app.handle())) {
+                 eprintln!("Failed to initialize delivery queue: {e}");
+             }
+
              // Initialize cache manager
              let cache_manager = core::cache_manager::CacheManager::new(100, 1000);
              let shared_cache_manager = Arc::new(RwLock::new(cache_manager));
@@ -616,54 +940,16 @@ pub fn run() {
              let sentiment_state: sentiment::SharedSentimentManager = Arc::new(RwLock::new(sentiment_manager));
              app.manage(sentiment_state.clone());
 
-             // Initialize social data service
-             let social_service = tauri::async_runtime::block_on(async {
-                 SocialDataService::new(&app.handle()).await
-             }).map_err(|e| {
-                 eprintln!("Failed to initialize social data service: {e}");
-                 Box::new(std::io::Error::new(std::io::ErrorKind::Other, e.to_string())) as Box<dyn Error>
-             })?;
-             let social_state: SharedSocialDataService = Arc::new(RwLock::new(social_service));
-             app.manage(social_state.clone());
-
-             // Initialize social analysis service
-             let mut social_data_dir = app
-                 .path_resolver()
-                 .app_data_dir()
-                 .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
-             social_data_dir.push("social");
-             std::fs::create_dir_all(&social_data_dir)
-                 .map_err(|e| format!("Failed to create social data directory: {e}"))?;
-
-             let social_cache = tauri::async_runtime::block_on(async {
-                 social::SocialCache::new(social_data_dir).await
-             }).map_err(|e| {
-                 eprintln!("Failed to initialize social cache for analysis: {e}");
-                 Box::new(std::io::Error::new(std::io::ErrorKind::Other, e.to_string())) as Box<dyn Error>
-             })?;
-
-             let mut analysis_service = social::SocialAnalysisService::new(social_cache);
-             tauri::async_runtime::block_on(async {
-                 analysis_service.initialize().await
-             }).map_err(|e| {
-                 eprintln!("Failed to initialize social analysis service: {e}");
-                 Box::new(std::io::Error::new(std::io::ErrorKind::Other, e.to_string())) as Box<dyn Error>
-             })?;
-
-             let analysis_state: social::SharedSocialAnalysisService = Arc::new(RwLock::new(analysis_service));
-             app.manage(analysis_state.clone());
-
-             // Initialize whale tracking service
-             let whale_pool = social_cache.pool();
-             let whale_service = social::WhaleService::new(whale_pool);
-             tauri::async_runtime::block_on(whale_service.initialize()).map_err(|e| {
-                 eprintln!("Failed to initialize whale service: {e}");
-                 Box::new(std::io::Error::new(std::io::ErrorKind::Other, e.to_string())) as Box<dyn Error>
+             // Initialize social data, analysis and whale services
+             tauri::async_runtime::block_on(config::feature_flags::start_feature_module(
+                 &app.handle(),
+                 config::FeatureModule::Social,
+             ))
+             .map_err(|e| {
+                 eprintln!("{e}");
+                 Box::new(std::io::Error::new(std::io::ErrorKind::Other, e)) as Box<dyn Error>
              })?;
 
-             let whale_state: social::SharedWhaleService = Arc::new(RwLock::new(whale_service));
-             app.manage(whale_state.clone());
-
              // Initialize anomaly detector
              let anomaly_detector = anomalies::AnomalyDetector::new();
              let anomaly_state: anomalies::SharedAnomalyDetector = Arc::new(RwLock::new(anomaly_detector));
@@ -714,6 +1000,18 @@ pub fn run() {
              let shared_holder_analyzer: SharedHolderAnalyzer = Arc::new(RwLock::new(holder_analyzer));
              app.manage(shared_holder_analyzer.clone());
 
+             // Keep holder distributions for portfolio and watchlist tokens fresh
+             let holder_refresh_handle = app.handle();
+             tauri::async_runtime::spawn(async move {
+                 loop {
+                     let interval = market::holder_refresh_interval(&holder_refresh_handle).await;
+                     tokio::time::sleep(interval).await;
+                     if let Err(err) = market::run_scheduled_holder_refresh(&holder_refresh_handle).await {
+                         eprintln!("Scheduled holder refresh failed: {err}");
+                     }
+                 }
+             });
+
              // Initialize stock cache state
              let stock_cache: stocks::SharedStockCache = Arc::new(RwLock::new(stocks::StockCache::default()));
              app.manage(stock_cache.clone());
@@ -803,6 +1101,22 @@ pub fn run() {
              let shared_historical_manager: SharedHistoricalReplayManager = Arc::new(RwLock::new(historical_replay_manager));
              app.manage(shared_historical_manager.clone());
 
+             // Initialize benchmark index manager
+             let benchmark_index_manager = tauri::async_runtime::block_on(async {
+                 market::BenchmarkIndexManager::new(&app.handle()).await
+             }).map_err(|e| {
+                 eprintln!("Failed to initialize benchmark index manager: {e}");
+                 Box::new(e) as Box<dyn Error>
+             })?;
+
+             let shared_benchmark_index_manager: market::SharedBenchmarkIndexManager = Arc::new(RwLock::new(benchmark_index_manager));
+             app.manage(shared_benchmark_index_manager.clone());
+             market::start_index_alert_loop(
+                 app.handle(),
+                 shared_benchmark_index_manager.clone(),
+                 shared_historical_manager.clone(),
+             );
+
              // Initialize voice state
              let voice_state = VoiceState::new();
              let shared_voice_state: SharedVoiceState = Arc::new(RwLock::new(voice_state));
@@ -850,6 +1164,7 @@ pub fn run() {
 
              // Start background compression job (runs daily at 3 AM)
               let compression_job = shared_compression_manager.clone();
+              let partition_job = shared_event_store.clone();
               tauri::async_runtime::spawn(async move {
 
                   use tokio::time::{sleep, Duration};
@@ -884,6 +1199,10 @@ pub fn run() {
                           if let Err(err) = manager.compress_old_trades().await {
                               eprintln!("Failed to compress old trades: {err}");
                           }
+                          let partitions = partition_job.read().await.partitions();
+                          if let Err(err) = manager.apply_event_partition_policy(&partitions).await {
+                              eprintln!("Failed to apply event partition policy: {err}");
+                          }
                           manager.cleanup_cache().await;
                       }
                   }
@@ -939,6 +1258,24 @@ pub fn run() {
               app.manage(shared_performance_monitor.clone());
               shared_performance_monitor.start();
 
+              // Keep caches and in-memory stores within their memory budgets
+              match monitor::ResourceBudgetMonitor::new(
+                  &app.handle(),
+                  app.state::<SharedCacheManager>().inner().clone(),
+                  app.state::<sentiment::SharedSentimentManager>().inner().clone(),
+                  app.state::<token_flow::commands::SharedFlowAnalysisState>().inner().clone(),
+              ) {
+                  Ok(budget_monitor) => {
+                      let budget_monitor: monitor::SharedResourceBudgetMonitor = Arc::new(budget_monitor);
+                      app.manage(budget_monitor.clone());
+                      monitor::start_resource_budget_monitor(
+                          budget_monitor,
+                          shared_performance_monitor.clone(),
+                      );
+                  }
+                  Err(e) => eprintln!("Failed to initialize resource budget monitor: {e}"),
+              }
+
               let auto_compiler = compiler::AutoCompiler::new();
               let shared_auto_compiler = Arc::new(auto_compiler);
               app.manage(shared_auto_compiler.clone());
@@ -988,6 +1325,12 @@ pub fn run() {
               let governance_state: governance::SharedGovernanceManager = Arc::new(RwLock::new(governance_manager));
               app.manage(governance_state.clone());
 
+              // Registered last so the startup digest sees every other subsystem
+              data::register_away_summary(app);
+              notifications::report_email::register_report_emails(app);
+            security::authority_monitor::register_authority_monitor(app);
+            diagnostics::startup_check::register_startup_check(app);
+
               Ok(())
               })
 
@@ -1042,6 +1385,9 @@ pub fn run() {
             address_book_search_contacts,
             address_book_export,
             address_book_import,
+            sns_resolve,
+            sns_reverse_lookup,
+            sns_clear_cache,
             swap_history_add_entry,
             swap_history_get_recent,
             wallet_get_bridge_providers,
@@ -1056,6 +1402,20 @@ pub fn run() {
             get_best_worst_trades_data,
             get_benchmark_comparison_data,
             get_performance_alerts,
+
+            // Fee & Rent Tracking
+            wallet::fee_tracker::sync_wallet_fees,
+            wallet::fee_tracker::get_fee_summary,
+            wallet::fee_tracker::get_fee_adjusted_pnl,
+            wallet::fee_disclosure::get_fee_disclosure,
+            wallet::fee_disclosure::get_execution_quality_report,
+            wallet::compute_budget::get_compute_budget_stats,
+            wallet::compute_budget::recommend_compute_budget,
+            wallet::compute_budget::apply_compute_budget,
+            wallet::rent_reclaim::scan_empty_token_accounts,
+            wallet::rent_reclaim::build_close_empty_accounts,
+            wallet::rent_reclaim::submit_close_empty_accounts,
+            wallet::rent_reclaim::get_rent_reclaim_history,
             
             // Multisig
             create_multisig_wallet,
@@ -1066,6 +1426,33 @@ pub fn run() {
             sign_proposal,
             execute_proposal,
             cancel_proposal,
+            create_proposal_template,
+            list_proposal_templates,
+            delete_proposal_template,
+            create_proposal_from_template,
+            set_spending_policy,
+            get_spending_policy,
+            remove_spending_policy,
+            wallet::multisig_notifications::set_multisig_reminder_settings,
+            wallet::multisig_notifications::get_multisig_reminder_settings,
+            wallet::multisig_notifications::send_multisig_reminders_now,
+            wallet::tx_scheduler::schedule_transaction,
+            wallet::tx_scheduler::list_scheduled_transactions,
+            wallet::tx_scheduler::cancel_scheduled_transaction,
+            wallet::offline_signing::export_offline_signing_bundle,
+            wallet::offline_signing::import_offline_signed_transaction,
+            wallet::offline_signing::submit_offline_signed_transaction,
+            wallet::offline_signing::list_offline_signing_requests,
+            wallet::offline_signing::cancel_offline_signing_request,
+            wallet::offline_signing::get_offline_signing_config,
+            wallet::offline_signing::update_offline_signing_config,
+            wallet::nonce_accounts::list_nonce_accounts,
+            wallet::nonce_accounts::build_create_nonce_account,
+            wallet::nonce_accounts::build_advance_nonce_account,
+            wallet::nonce_accounts::build_close_nonce_account,
+            wallet::nonce_accounts::submit_nonce_account_transaction,
+            wallet::nonce_accounts::prepare_durable_transaction,
+            wallet::nonce_accounts::release_nonce_account,
             
             // Auth
             biometric_get_status,
@@ -1098,10 +1485,20 @@ pub fn run() {
             check_rotation_reminders,
             export_api_keys,
             import_api_keys,
+            get_api_failover_status,
+            set_api_failover_policy,
+            save_secondary_api_key,
+            remove_secondary_api_key,
+            report_api_request_outcome,
             // API Analytics
             record_api_usage,
             get_api_analytics,
             get_fair_use_status,
+            get_usage_forecast,
+            list_api_budgets,
+            set_api_budget,
+            remove_api_budget,
+            check_api_budget,
             // AI & Sentiment
             assess_risk,
             analyze_text_sentiment,
@@ -1163,6 +1560,11 @@ pub fn run() {
             get_trending_coins,
             get_coin_sentiment,
             refresh_trending,
+
+            // Demo Mode
+            demo::get_demo_mode,
+            demo::set_demo_mode,
+            demo::get_demo_dataset,
             
             // New Coins Scanner
             get_new_coins,
@@ -1172,6 +1574,55 @@ pub fn run() {
             // Top Coins
             get_top_coins,
             refresh_top_coins,
+
+            // Market Heatmap
+            market::get_market_heatmap,
+
+            // Benchmark Indices
+            market::index_create,
+            market::index_list,
+            market::index_delete,
+            market::index_get_series,
+            market::index_compare_performance,
+
+            // CLOB Order Books
+            market::order_book_track,
+            market::order_book_untrack,
+            market::order_book_list_markets,
+            market::get_order_book,
+            market::estimate_order_book_slippage,
+
+            // Token Unlocks
+            market::get_upcoming_unlocks,
+            market::list_unlock_schedules,
+            market::save_unlock_schedule,
+            market::delete_unlock_schedule,
+            market::import_unlock_dataset,
+            market::get_unlock_alert_settings,
+            market::update_unlock_alert_settings,
+
+            // Macro Events
+            market::get_macro_events,
+            market::list_macro_sources,
+            market::save_macro_source,
+            market::delete_macro_source,
+            market::refresh_macro_events,
+            market::get_macro_guard_settings,
+            market::update_macro_guard_settings,
+            market::get_automation_blackout,
+
+            // Token Images
+            market::cache_token_image,
+            market::get_token_image_cache_stats,
+            market::clear_token_image_cache,
+
+            // Chart Annotations
+            market::list_chart_annotations,
+            market::create_chart_annotation,
+            market::update_chart_annotation,
+            market::delete_chart_annotation,
+            market::export_chart_annotations,
+            market::import_chart_annotations,
             
             // Portfolio & Analytics
             get_portfolio_metrics,
@@ -1192,6 +1643,7 @@ pub fn run() {
             export_tax_report,
             get_tax_loss_harvesting_suggestions,
             get_tax_center_summary,
+            get_pnl_calendar,
             update_tax_settings,
             export_tax_center_report,
             calculate_portfolio_analytics,
@@ -1208,6 +1660,24 @@ pub fn run() {
             watchlist_reorder_items,
             watchlist_export,
             watchlist_import,
+            get_exposure_report,
+            get_exposure_history,
+            get_liquidation_value,
+            get_liquidation_history,
+            plan_dust_consolidation,
+            execute_dust_consolidation,
+            list_exposure_thresholds,
+            add_exposure_threshold,
+            remove_exposure_threshold,
+            create_portfolio_share,
+            list_portfolio_shares,
+            publish_portfolio_share,
+            revoke_portfolio_share,
+            subscribe_portfolio_share,
+            list_portfolio_subscriptions,
+            refresh_shared_portfolio,
+            get_shared_portfolio,
+            unsubscribe_portfolio_share,
             // AI Portfolio Advisor
             save_risk_profile,
             get_risk_profile,
@@ -1227,6 +1697,18 @@ pub fn run() {
             alert_test,
             alert_check_triggers,
             alert_reset_cooldowns,
+            alert_set_mute_schedule,
+            alert_history_list,
+            alert_history_get,
+            alert_history_update,
+            alert_history_delete,
+            alert_history_stats,
+            alert_history_export_csv,
+            get_alert_history,
+            alert_get_mute_schedules,
+            alert_list_muted_triggers,
+            preview_notification,
+            send_notification_preview,
             smart_alert_create_rule,
             smart_alert_update_rule,
             smart_alert_delete_rule,
@@ -1252,6 +1734,19 @@ pub fn run() {
             chat_integration_get_delivery_logs,
             chat_integration_clear_delivery_logs,
             chat_integration_get_rate_limits,
+            chat_integration_list_push_subscriptions,
+            chat_integration_register_push,
+            chat_integration_set_push_enabled,
+            chat_integration_remove_push,
+            chat_integration_get_push_relay,
+            chat_integration_save_push_relay,
+            chat_integration_test_push,
+            get_telegram_bot_config,
+            update_telegram_bot_config,
+            get_discord_bot_status,
+            update_discord_bot_config,
+            set_discord_bot_token,
+            clear_discord_bot_token,
             // Webhooks
             list_webhooks,
             get_webhook,
@@ -1261,6 +1756,15 @@ pub fn run() {
             trigger_webhook,
             test_webhook,
             list_webhook_delivery_logs,
+            list_webhook_dead_letters,
+            replay_failed_delivery,
+            discard_webhook_dead_letter,
+            get_webhook_delivery_stats,
+            dispatch_webhook_event,
+            preview_webhook_event,
+            core::message_queue::get_delivery_queue_stats,
+            core::message_queue::list_dead_letter_messages,
+            core::message_queue::retry_dead_letter_message,
             // API Health
             get_api_health_dashboard,
             get_service_health_metrics,
@@ -1272,10 +1776,15 @@ pub fn run() {
             unsubscribe_wallet_stream,
             get_stream_status,
             reconnect_stream,
+            get_stream_incidents,
+            reset_stream_incidents,
             // Chart Streams
             subscribe_chart_prices,
             unsubscribe_chart_prices,
             get_chart_subscriptions,
+            set_watchlist_stream,
+            clear_watchlist_stream,
+            get_watchlist_stream_status,
             // Jupiter v6 & execution safeguards
             jupiter_quote,
             jupiter_swap,
@@ -1292,6 +1801,31 @@ pub fn run() {
             get_order,
             acknowledge_order,
             update_order_prices,
+            create_trade_idea,
+            get_trade_idea,
+            list_trade_ideas,
+            set_trade_idea_checklist_item,
+            execute_trade_idea,
+            cancel_trade_idea,
+            get_exit_ladder_settings,
+            update_exit_ladder_settings,
+            list_exit_ladders,
+            get_exit_ladder,
+            cancel_exit_ladder,
+            get_risk_per_trade_policy,
+            update_risk_per_trade_policy,
+            calculate_position_size,
+            list_execution_presets,
+            save_execution_preset,
+            delete_execution_preset,
+            preview_execution_preset,
+            get_execution_preset_audit,
+            create_alert_automation_rule,
+            list_alert_automation_rules,
+            set_alert_automation_enabled,
+            set_alert_automation_mode,
+            delete_alert_automation_rule,
+            get_alert_automation_log,
             
             // Auto Trading Engine
             auto_trading_create_strategy,
@@ -1306,6 +1840,19 @@ pub fn run() {
             auto_trading_get_strategy,
             auto_trading_get_executions,
             auto_trading_apply_parameters,
+            auto_trading_process_signals,
+            get_promotion_gates,
+            update_promotion_gates,
+            evaluate_strategy_promotion,
+            request_strategy_promotion,
+            approve_strategy_promotion,
+            reject_strategy_promotion,
+            demote_strategy_to_paper,
+            list_strategy_promotions,
+            get_snipe_status,
+            update_snipe_config,
+            list_snipe_tickets,
+            submit_snipe,
             
             // Backtesting & Optimization
             backtest_run,
@@ -1323,6 +1870,7 @@ pub fn run() {
             get_paper_trade_history,
             get_paper_performance,
             update_paper_position_prices,
+            get_paper_fill_comparison,
             
             // DCA Bots
             dca_init,
@@ -1334,6 +1882,7 @@ pub fn run() {
             dca_delete,
             dca_history,
             dca_performance,
+            get_bot_analytics,
             // Copy Trading
             copy_trading_init,
             copy_trading_create,
@@ -1364,7 +1913,14 @@ pub fn run() {
             get_alert_configs,
             update_alert_config,
             get_recent_whale_alerts,
+            get_token_interaction_settings,
+            update_token_interaction_settings,
+            get_token_interaction_alerts,
             scan_wallets_for_smart_money,
+            get_wallet_behavior,
+            list_wallet_behaviors,
+            classify_wallet_behaviors,
+            discover_copy_trade_wallets,
             
             // Activity Logging
             security::activity_log::get_activity_logs,
@@ -1374,6 +1930,14 @@ pub fn run() {
             security::activity_log::cleanup_activity_logs,
             security::activity_log::get_activity_retention,
             security::activity_log::set_activity_retention,
+            security::activity_log::verify_activity_log_integrity,
+
+            // Dead Man's Switch
+            security::dead_mans_switch::dead_mans_switch_get_status,
+            security::dead_mans_switch::dead_mans_switch_configure,
+            security::dead_mans_switch::dead_mans_switch_check_in,
+            security::dead_mans_switch::dead_mans_switch_set_enabled,
+            security::dead_mans_switch::dead_mans_switch_set_instructions,
 
             // Smart Contract Security
             security::audit::scan_contract,
@@ -1398,6 +1962,15 @@ pub fn run() {
             security::reputation::get_reputation_stats,
             security::reputation::get_reputation_settings,
             security::reputation::update_reputation_settings,
+            security::blocklist_sync::list_blocklist_sources,
+            security::blocklist_sync::add_blocklist_source,
+            security::blocklist_sync::set_blocklist_source_enabled,
+            security::blocklist_sync::remove_blocklist_source,
+            security::blocklist_sync::sync_blocklists,
+            security::blocklist_sync::get_blocklist_provenance,
+            security::authority_monitor::get_token_authority_snapshots,
+            security::authority_monitor::get_token_authority_changes,
+            security::authority_monitor::check_token_authorities,
 
             // Academy System
             academy::create_course,
@@ -1447,8 +2020,10 @@ pub fn run() {
             get_performance_metrics,
             run_performance_test,
             reset_performance_stats,
+            inspect_domain_events,
 
             // Cache Management
+            batch_commands::batch_invoke,
             cache_commands::get_cache_statistics,
             cache_commands::clear_cache,
             cache_commands::warm_cache,
@@ -1456,6 +2031,9 @@ pub fn run() {
             cache_commands::update_ttl_config,
             cache_commands::reset_ttl_config,
             cache_commands::test_cache_performance,
+            monitor::get_resource_usage,
+            monitor::get_resource_budgets,
+            monitor::set_resource_budgets,
 
             // Market Surveillance & Anomaly Detection
             add_price_data,
@@ -1473,17 +2051,30 @@ pub fn run() {
             data::event_store::replay_events_command,
             data::event_store::get_state_at_time_command,
             data::event_store::export_audit_trail_command,
+            data::audit_export::get_audit_export_public_key,
+            data::audit_export::verify_audit_export_command,
             data::event_store::create_snapshot_command,
             data::event_store::get_event_stats,
+            data::event_store::list_event_partitions,
+            data::away_summary::get_away_summary,
+            data::away_summary::get_away_summary_settings,
+            data::away_summary::update_away_summary_settings,
 
             // Data Compression
             data::compression_commands::get_compression_stats,
             data::compression_commands::compress_old_data,
             data::compression_commands::update_compression_config,
             data::compression_commands::get_compression_config,
+            data::compression_commands::get_event_partition_policy,
+            data::compression_commands::update_event_partition_policy,
+            data::compression_commands::apply_event_partition_policy,
             data::compression_commands::decompress_data,
             data::compression_commands::get_database_size,
 
+            // Data Privacy
+            data::privacy::export_all_user_data,
+            data::privacy::wipe_data,
+
             // Email Notifications
             email_save_config,
             email_get_config,
@@ -1492,6 +2083,11 @@ pub fn run() {
             email_send,
             email_get_stats,
             email_get_history,
+            get_report_email_settings,
+            update_report_email_settings,
+            preview_report_email,
+            send_report_email_now,
+            get_report_email_history,
 
             // Twitter Integration
             twitter_save_config,
@@ -1508,6 +2104,7 @@ pub fn run() {
             twitter_get_sentiment_history,
             twitter_get_stats,
             twitter_get_tweet_history,
+            get_integration_health,
 
             // Token Flow Intelligence
             token_flow::commands::analyze_token_flows,
@@ -1517,12 +2114,18 @@ pub fn run() {
             token_flow::commands::remove_cluster_subscription,
             // Holder Analysis & Metadata
             market::holders::get_holder_distribution,
+            market::holders::diff_holder_snapshots,
             market::holders::get_holder_trends,
             market::holders::get_large_transfers,
             market::holders::get_token_metadata,
             market::holders::get_verification_status,
             market::holders::export_holder_data,
             market::holders::export_metadata_snapshot,
+            market::holder_refresh::refresh_holder_data,
+            market::holder_refresh::get_holder_refresh_config,
+            market::holder_refresh::update_holder_refresh_config,
+            market::holder_refresh::get_holder_refresh_runs,
+            market::series::get_series,
 
             // Prediction Markets
             market::get_prediction_markets,
@@ -1571,6 +2174,11 @@ pub fn run() {
             bridge_update_transaction_status,
             bridge_update_transaction_hash,
             bridge_poll_status,
+            list_tracked_bridges,
+            refresh_tracked_bridges,
+            resolve_tracked_bridge,
+            get_bridge_tracker_settings,
+            update_bridge_tracker_settings,
 
             // Launchpad commands
             create_launch_config,
@@ -1620,9 +2228,27 @@ pub fn run() {
             get_staking_pools,
             get_staking_positions,
             get_staking_schedule,
+            get_validators,
+            stake_sol,
+            submit_stake_transaction,
+            get_stake_accounts,
+            sync_stake_rewards,
             get_yield_farms,
             get_farming_opportunities,
             get_farming_positions,
+            track_pool,
+            untrack_pool,
+            list_tracked_pools,
+            get_pool_analytics,
+            get_yield_opportunities,
+            get_yield_scanner_settings,
+            update_yield_scanner_settings,
+            get_lending_exposure,
+            list_lending_accounts,
+            track_lending_account,
+            untrack_lending_account,
+            get_lending_alert_settings,
+            update_lending_alert_settings,
             get_defi_portfolio_summary,
             get_defi_risk_metrics,
             get_defi_snapshot,
@@ -1683,6 +2309,33 @@ pub fn run() {
             config::commands::list_settings_profiles,
             config::commands::get_settings_change_history,
             config::commands::get_settings_template,
+            config::version_history::get_config_history,
+            config::version_history::rollback_config,
+            config::recycle_bin::list_deleted_items,
+            config::recycle_bin::restore_item,
+            config::recycle_bin::undo_last_operation,
+            config::data_sources::get_data_sources,
+            config::data_sources::set_data_source_mode,
+            config::data_sources::set_data_source_capture,
+            config::token_registry::list_token_registry,
+            config::token_registry::register_custom_token,
+            config::token_registry::set_token_metadata_override,
+            config::token_registry::remove_token_registry_entry,
+            config::localization::get_localization_settings,
+            config::localization::update_localization_settings,
+            config::localization::list_supported_locales,
+            config::localization::preview_localization,
+            config::feature_flags::get_feature_flags,
+            config::feature_flags::set_feature_flag,
+            config::http_recorder::get_http_recorder_status,
+            config::http_recorder::set_http_recorder_mode,
+            config::http_recorder::set_http_recorder_retention,
+            config::setup_bundle::setup_export_yaml,
+            config::setup_bundle::setup_preview_import,
+            config::setup_bundle::setup_apply_import,
+            config::http_recorder::list_http_recordings,
+            config::http_recorder::get_http_recording,
+            config::http_recorder::clear_http_recordings,
 
             // System Tray
             get_tray_settings,
@@ -1842,6 +2495,11 @@ pub fn run() {
             diagnostics::tauri_commands::backup_before_repair,
             diagnostics::tauri_commands::rollback_repair,
             diagnostics::tauri_commands::export_diagnostics_report,
+            diagnostics::startup_check::get_startup_health,
+            diagnostics::startup_check::run_startup_health_check,
+            diagnostics::startup_check::get_startup_check_config,
+            diagnostics::startup_check::update_startup_check_config,
+            diagnostics::startup_check::run_startup_repair,
 
             // Governance
             sync_governance_memberships,
@@ -0,0 +1,847 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite, SqlitePool};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use crate::alerts::SharedAlertManager;
+use crate::core::price_engine::get_price_engine;
+use crate::data::historical::{HistoricalDataPoint, SharedHistoricalReplayManager};
+use crate::portfolio::{ExposureSnapshot, SharedExposureTracker};
+
+const BENCHMARK_INDEX_DB_FILE: &str = "benchmark_indices.db";
+const DEFAULT_BASE_VALUE: f64 = 100.0;
+const DEFAULT_INTERVAL: &str = "1h";
+const MAX_CONSTITUENTS: usize = 50;
+const INDEX_ALERT_INTERVAL: Duration = Duration::from_secs(300);
+const DAY_SECONDS: i64 = 86_400;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexConstituent {
+    pub symbol: String,
+    pub mint: String,
+    pub weight: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkIndex {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub constituents: Vec<IndexConstituent>,
+    pub base_value: f64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIndexRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub constituents: Vec<IndexConstituent>,
+    pub base_value: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexSeriesPoint {
+    pub timestamp: i64,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexSeries {
+    pub index_id: String,
+    pub name: String,
+    pub interval: String,
+    pub points: Vec<IndexSeriesPoint>,
+    pub total_return_percent: f64,
+    /// Constituents with no stored candles in the requested range; their weight is redistributed.
+    pub missing_constituents: Vec<String>,
+    /// Level at current prices against the same base as `points`, when every
+    /// constituent with candles has a live price.
+    pub live_value: Option<f64>,
+    /// Symbol to create price alerts on; they are checked against the live level.
+    pub alert_symbol: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexBenchmarkComparison {
+    pub index_id: String,
+    pub index_return_percent: f64,
+    pub portfolio_return_percent: f64,
+    pub excess_return_percent: f64,
+    pub outperformed: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BenchmarkIndexError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("index not found: {0}")]
+    NotFound(String),
+    #[error("invalid index definition: {0}")]
+    Invalid(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+#[derive(Clone)]
+pub struct BenchmarkIndexManager {
+    pool: Pool<Sqlite>,
+}
+
+pub type SharedBenchmarkIndexManager = Arc<RwLock<BenchmarkIndexManager>>;
+
+impl BenchmarkIndexManager {
+    pub async fn new(app: &AppHandle) -> Result<Self, BenchmarkIndexError> {
+        let db_path = benchmark_index_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let manager = Self { pool };
+        manager.initialize().await?;
+        Ok(manager)
+    }
+
+    async fn initialize(&self) -> Result<(), BenchmarkIndexError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS benchmark_indices (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL UNIQUE,
+                description TEXT,
+                constituents TEXT NOT NULL,
+                base_value REAL NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_index(
+        &self,
+        request: CreateIndexRequest,
+    ) -> Result<BenchmarkIndex, BenchmarkIndexError> {
+        let constituents = normalize_constituents(request.constituents)?;
+        let name = request.name.trim().to_string();
+        if name.is_empty() {
+            return Err(BenchmarkIndexError::Invalid("name is required".to_string()));
+        }
+
+        let base_value = request.base_value.unwrap_or(DEFAULT_BASE_VALUE);
+        if !(base_value > 0.0) {
+            return Err(BenchmarkIndexError::Invalid(
+                "base value must be positive".to_string(),
+            ));
+        }
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = Utc::now().to_rfc3339();
+
+        sqlx::query(
+            r#"
+            INSERT INTO benchmark_indices (id, name, description, constituents, base_value, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )
+        .bind(&id)
+        .bind(&name)
+        .bind(&request.description)
+        .bind(serde_json::to_string(&constituents)?)
+        .bind(base_value)
+        .bind(&now)
+        .bind(&now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(BenchmarkIndex {
+            id,
+            name,
+            description: request.description,
+            constituents,
+            base_value,
+            created_at: now.clone(),
+            updated_at: now,
+        })
+    }
+
+    pub async fn list_indices(&self) -> Result<Vec<BenchmarkIndex>, BenchmarkIndexError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, name, description, constituents, base_value, created_at, updated_at
+            FROM benchmark_indices
+            ORDER BY name ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_index).collect()
+    }
+
+    pub async fn get_index(&self, id: &str) -> Result<BenchmarkIndex, BenchmarkIndexError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, name, description, constituents, base_value, created_at, updated_at
+            FROM benchmark_indices
+            WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| BenchmarkIndexError::NotFound(id.to_string()))?;
+
+        row_to_index(&row)
+    }
+
+    pub async fn delete_index(&self, id: &str) -> Result<(), BenchmarkIndexError> {
+        let result = sqlx::query("DELETE FROM benchmark_indices WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(BenchmarkIndexError::NotFound(id.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+fn row_to_index(row: &sqlx::sqlite::SqliteRow) -> Result<BenchmarkIndex, BenchmarkIndexError> {
+    let constituents_json: String = row.try_get("constituents")?;
+    Ok(BenchmarkIndex {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        description: row.try_get("description")?,
+        constituents: serde_json::from_str(&constituents_json)?,
+        base_value: row.try_get("base_value")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+fn benchmark_index_db_path(app: &AppHandle) -> Result<PathBuf, BenchmarkIndexError> {
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| {
+            BenchmarkIndexError::Internal("Unable to resolve app data directory".to_string())
+        })?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(BENCHMARK_INDEX_DB_FILE))
+}
+
+/// Validates a basket and rescales its weights so they sum to 1.
+pub fn normalize_constituents(
+    constituents: Vec<IndexConstituent>,
+) -> Result<Vec<IndexConstituent>, BenchmarkIndexError> {
+    if constituents.is_empty() {
+        return Err(BenchmarkIndexError::Invalid(
+            "an index needs at least one constituent".to_string(),
+        ));
+    }
+    if constituents.len() > MAX_CONSTITUENTS {
+        return Err(BenchmarkIndexError::Invalid(format!(
+            "an index supports at most {} constituents",
+            MAX_CONSTITUENTS
+        )));
+    }
+
+    let mut seen = HashSet::new();
+    let mut seen_mints = HashSet::new();
+    for constituent in &constituents {
+        if !seen.insert(constituent.symbol.clone()) {
+            return Err(BenchmarkIndexError::Invalid(format!(
+                "duplicate constituent: {}",
+                constituent.symbol
+            )));
+        }
+        if constituent.mint.trim().is_empty() {
+            return Err(BenchmarkIndexError::Invalid(format!(
+                "mint for {} is required",
+                constituent.symbol
+            )));
+        }
+        if !seen_mints.insert(constituent.mint.trim().to_string()) {
+            return Err(BenchmarkIndexError::Invalid(format!(
+                "duplicate mint: {}",
+                constituent.mint
+            )));
+        }
+        if !(constituent.weight > 0.0) || !constituent.weight.is_finite() {
+            return Err(BenchmarkIndexError::Invalid(format!(
+                "weight for {} must be positive",
+                constituent.symbol
+            )));
+        }
+    }
+
+    let total: f64 = constituents.iter().map(|c| c.weight).sum();
+    Ok(constituents
+        .into_iter()
+        .map(|c| IndexConstituent {
+            weight: c.weight / total,
+            mint: c.mint.trim().to_string(),
+            ..c
+        })
+        .collect())
+}
+
+pub fn index_alert_symbol(index_id: &str) -> String {
+    format!("INDEX:{index_id}")
+}
+
+struct AlignedCloses<'a> {
+    available: Vec<&'a IndexConstituent>,
+    available_weight: f64,
+    /// Timestamps where every available constituent has a close, oldest first.
+    rows: Vec<(i64, Vec<f64>)>,
+    missing: Vec<String>,
+}
+
+fn align_closes<'a>(
+    constituents: &'a [IndexConstituent],
+    candles: &HashMap<String, Vec<HistoricalDataPoint>>,
+) -> AlignedCloses<'a> {
+    let mut available = Vec::new();
+    let mut missing = Vec::new();
+    for constituent in constituents {
+        match candles.get(&constituent.symbol) {
+            Some(points) if points.iter().any(|p| p.close > 0.0) => available.push(constituent),
+            _ => missing.push(constituent.symbol.clone()),
+        }
+    }
+
+    let mut aligned: BTreeMap<i64, Vec<f64>> = BTreeMap::new();
+    for (slot, constituent) in available.iter().enumerate() {
+        for point in &candles[&constituent.symbol] {
+            if point.close <= 0.0 {
+                continue;
+            }
+            let closes = aligned
+                .entry(point.timestamp)
+                .or_insert_with(|| vec![f64::NAN; available.len()]);
+            closes[slot] = point.close;
+        }
+    }
+
+    AlignedCloses {
+        available_weight: available.iter().map(|c| c.weight).sum(),
+        available,
+        rows: aligned
+            .into_iter()
+            .filter(|(_, closes)| !closes.iter().any(|c| c.is_nan()))
+            .collect(),
+        missing,
+    }
+}
+
+/// Computes a fixed-weight index level series from per-constituent candles.
+///
+/// Each constituent contributes `weight * close_t / close_0`, so the index starts at
+/// `base_value`. Only timestamps present for every available constituent are used.
+pub fn compute_index_series(
+    constituents: &[IndexConstituent],
+    candles: &HashMap<String, Vec<HistoricalDataPoint>>,
+    base_value: f64,
+) -> (Vec<IndexSeriesPoint>, Vec<String>) {
+    let aligned = align_closes(constituents, candles);
+    if aligned.available.is_empty() || aligned.available_weight <= 0.0 {
+        return (Vec::new(), aligned.missing);
+    }
+
+    let Some((_, base)) = aligned.rows.first() else {
+        return (Vec::new(), aligned.missing);
+    };
+    let series = aligned
+        .rows
+        .iter()
+        .map(|(timestamp, closes)| {
+            let level: f64 = aligned
+                .available
+                .iter()
+                .enumerate()
+                .map(|(slot, c)| (c.weight / aligned.available_weight) * closes[slot] / base[slot])
+                .sum();
+            IndexSeriesPoint {
+                timestamp: *timestamp,
+                value: base_value * level,
+            }
+        })
+        .collect();
+
+    (series, aligned.missing)
+}
+
+/// Index level at `live_prices` (keyed by symbol), measured against the same
+/// base closes as `compute_index_series`.
+pub fn live_index_value(
+    constituents: &[IndexConstituent],
+    candles: &HashMap<String, Vec<HistoricalDataPoint>>,
+    base_value: f64,
+    live_prices: &HashMap<String, f64>,
+) -> Option<f64> {
+    let aligned = align_closes(constituents, candles);
+    let (_, base) = aligned.rows.first()?;
+    if aligned.available_weight <= 0.0 {
+        return None;
+    }
+
+    let level: f64 = aligned
+        .available
+        .iter()
+        .enumerate()
+        .map(|(slot, c)| {
+            let live = live_prices.get(&c.symbol).copied().filter(|p| *p > 0.0)?;
+            Some((c.weight / aligned.available_weight) * live / base[slot])
+        })
+        .sum::<Option<f64>>()?;
+    Some(base_value * level)
+}
+
+/// Time-weighted return of the tracked portfolio between the first and last
+/// exposure snapshot inside the window. Each period prices the holdings at its
+/// start with the prices at its end, so deposits and withdrawals in between
+/// don't count as performance. `None` when a period has no recorded holdings.
+pub fn portfolio_return_percent(
+    history: &[ExposureSnapshot],
+    start_time: i64,
+    end_time: i64,
+) -> Option<f64> {
+    let snapshots: Vec<&ExposureSnapshot> = history
+        .iter()
+        .filter(|snapshot| {
+            DateTime::parse_from_rfc3339(&snapshot.timestamp)
+                .map(|ts| (start_time..=end_time).contains(&ts.timestamp()))
+                .unwrap_or(false)
+        })
+        .skip_while(|snapshot| snapshot.total_value_usd <= 0.0)
+        .collect();
+    if snapshots.len() < 2 {
+        return None;
+    }
+
+    let growth = snapshots
+        .windows(2)
+        .map(|pair| period_growth(pair[0], pair[1]))
+        .product::<Option<f64>>()?;
+    Some((growth - 1.0) * 100.0)
+}
+
+/// Growth of `from`'s holdings by the time of `to`. Tokens that are gone or
+/// unpriced at `to` keep their earlier price.
+fn period_growth(from: &ExposureSnapshot, to: &ExposureSnapshot) -> Option<f64> {
+    if from.total_value_usd <= 0.0 {
+        // Nothing was invested over the period
+        return Some(1.0);
+    }
+    let start: f64 = from
+        .holdings
+        .values()
+        .map(|mark| mark.amount * mark.price_usd)
+        .sum();
+    if start <= 0.0 {
+        return None;
+    }
+
+    let end: f64 = from
+        .holdings
+        .iter()
+        .map(|(mint, mark)| {
+            let price = to
+                .holdings
+                .get(mint)
+                .map(|later| later.price_usd)
+                .filter(|price| *price > 0.0)
+                .unwrap_or(mark.price_usd);
+            mark.amount * price
+        })
+        .sum();
+    Some(end / start)
+}
+
+fn series_return_percent(points: &[IndexSeriesPoint]) -> f64 {
+    match (points.first(), points.last()) {
+        (Some(first), Some(last)) if first.value > 0.0 => {
+            (last.value - first.value) / first.value * 100.0
+        }
+        _ => 0.0,
+    }
+}
+
+/// Candles per constituent symbol, concatenated over `ranges` (oldest first).
+async fn load_candles(
+    index: &BenchmarkIndex,
+    historical: &SharedHistoricalReplayManager,
+    interval: &str,
+    ranges: &[(i64, i64)],
+) -> Result<HashMap<String, Vec<HistoricalDataPoint>>, String> {
+    let mut candles = HashMap::new();
+    let mgr = historical.read().await;
+    for constituent in &index.constituents {
+        let mut points = Vec::new();
+        for &(start_time, end_time) in ranges {
+            // Backfills store candles under the mint; older imports used the symbol
+            let mut range = mgr
+                .get_price_data(&constituent.mint, interval, start_time, end_time)
+                .await?;
+            if range.is_empty() {
+                range = mgr
+                    .get_price_data(&constituent.symbol, interval, start_time, end_time)
+                    .await?;
+            }
+            points.extend(range);
+        }
+        candles.insert(constituent.symbol.clone(), points);
+    }
+    Ok(candles)
+}
+
+fn live_prices(index: &BenchmarkIndex) -> HashMap<String, f64> {
+    let prices = get_price_engine();
+    index
+        .constituents
+        .iter()
+        .filter_map(|c| {
+            let cached = prices.get_cached_price(&c.symbol)?;
+            Some((c.symbol.clone(), cached.price))
+        })
+        .collect()
+}
+
+async fn build_series(
+    index: &BenchmarkIndex,
+    historical: &SharedHistoricalReplayManager,
+    interval: &str,
+    start_time: i64,
+    end_time: i64,
+) -> Result<IndexSeries, String> {
+    let candles = load_candles(index, historical, interval, &[(start_time, end_time)]).await?;
+    let (points, missing_constituents) =
+        compute_index_series(&index.constituents, &candles, index.base_value);
+    let live_value =
+        live_index_value(&index.constituents, &candles, index.base_value, &live_prices(index));
+
+    Ok(IndexSeries {
+        index_id: index.id.clone(),
+        name: index.name.clone(),
+        interval: interval.to_string(),
+        total_return_percent: series_return_percent(&points),
+        points,
+        missing_constituents,
+        live_value,
+        alert_symbol: index_alert_symbol(&index.id),
+    })
+}
+
+/// Checks price alerts on each index's `alert_symbol`. Levels are measured from
+/// the index's creation, and the level a day earlier backs percent-change rules.
+/// Only the day after creation (for the base closes) and the last day are read,
+/// not the whole history in between.
+async fn check_index_alerts(
+    app: &AppHandle,
+    manager: &SharedBenchmarkIndexManager,
+    historical: &SharedHistoricalReplayManager,
+) -> Result<(), String> {
+    let Some(alerts) = app.try_state::<SharedAlertManager>() else {
+        return Ok(());
+    };
+    let indices = manager.read().await.list_indices().await.map_err(|e| e.to_string())?;
+    let now = Utc::now().timestamp();
+
+    for index in indices {
+        // One extra day of recent candles so there is a close at or before a day ago
+        let recent_start = now - 2 * DAY_SECONDS;
+        let start_time = DateTime::parse_from_rfc3339(&index.created_at)
+            .map(|created| created.timestamp().min(recent_start))
+            .unwrap_or(recent_start);
+        let ranges = if start_time + DAY_SECONDS >= recent_start {
+            vec![(start_time, now)]
+        } else {
+            vec![(start_time, start_time + DAY_SECONDS), (recent_start, now)]
+        };
+        let candles = match load_candles(&index, historical, DEFAULT_INTERVAL, &ranges).await {
+            Ok(candles) => candles,
+            Err(err) => {
+                tracing::warn!(index = %index.id, error = %err, "index level unavailable");
+                continue;
+            }
+        };
+
+        let prices = live_prices(&index);
+        let Some(live_value) =
+            live_index_value(&index.constituents, &candles, index.base_value, &prices)
+        else {
+            continue;
+        };
+        let (points, _) = compute_index_series(&index.constituents, &candles, index.base_value);
+        let day_ago = points
+            .iter()
+            .rev()
+            .find(|point| point.timestamp <= now - DAY_SECONDS)
+            .map(|point| point.value);
+
+        let alert_symbol = index_alert_symbol(&index.id);
+        if let Err(err) = alerts
+            .read()
+            .await
+            .check_and_trigger_alerts(&alert_symbol, live_value, day_ago, None)
+            .await
+        {
+            tracing::warn!(index = %index.id, error = %err, "index alert check failed");
+        }
+    }
+
+    Ok(())
+}
+
+pub fn start_index_alert_loop(
+    app_handle: AppHandle,
+    manager: SharedBenchmarkIndexManager,
+    historical: SharedHistoricalReplayManager,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(INDEX_ALERT_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = check_index_alerts(&app_handle, &manager, &historical).await {
+                tracing::warn!(error = %err, "index alert check failed");
+            }
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn index_create(
+    manager: State<'_, SharedBenchmarkIndexManager>,
+    request: CreateIndexRequest,
+) -> Result<BenchmarkIndex, String> {
+    let mgr = manager.read().await;
+    mgr.create_index(request).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn index_list(
+    manager: State<'_, SharedBenchmarkIndexManager>,
+) -> Result<Vec<BenchmarkIndex>, String> {
+    let mgr = manager.read().await;
+    mgr.list_indices().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn index_delete(
+    manager: State<'_, SharedBenchmarkIndexManager>,
+    id: String,
+) -> Result<(), String> {
+    let mgr = manager.read().await;
+    mgr.delete_index(&id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn index_get_series(
+    manager: State<'_, SharedBenchmarkIndexManager>,
+    historical: State<'_, SharedHistoricalReplayManager>,
+    id: String,
+    start_time: i64,
+    end_time: Option<i64>,
+    interval: Option<String>,
+) -> Result<IndexSeries, String> {
+    let index = {
+        let mgr = manager.read().await;
+        mgr.get_index(&id).await.map_err(|e| e.to_string())?
+    };
+
+    let interval = interval.unwrap_or_else(|| DEFAULT_INTERVAL.to_string());
+    let end_time = end_time.unwrap_or_else(|| Utc::now().timestamp());
+    build_series(&index, &historical, &interval, start_time, end_time).await
+}
+
+#[tauri::command]
+pub async fn index_compare_performance(
+    manager: State<'_, SharedBenchmarkIndexManager>,
+    historical: State<'_, SharedHistoricalReplayManager>,
+    exposure: State<'_, SharedExposureTracker>,
+    id: String,
+    start_time: i64,
+    end_time: i64,
+) -> Result<IndexBenchmarkComparison, String> {
+    let index = {
+        let mgr = manager.read().await;
+        mgr.get_index(&id).await.map_err(|e| e.to_string())?
+    };
+
+    let days = (Utc::now().timestamp() - start_time) / DAY_SECONDS + 1;
+    let history = exposure
+        .read()
+        .await
+        .get_history(days.max(1))
+        .await
+        .map_err(|e| e.to_string())?;
+    let portfolio_return_percent = portfolio_return_percent(&history, start_time, end_time)
+        .ok_or_else(|| "Not enough portfolio history in this period".to_string())?;
+
+    let series = build_series(&index, &historical, DEFAULT_INTERVAL, start_time, end_time).await?;
+    let excess = portfolio_return_percent - series.total_return_percent;
+
+    Ok(IndexBenchmarkComparison {
+        index_id: index.id,
+        index_return_percent: series.total_return_percent,
+        portfolio_return_percent,
+        excess_return_percent: excess,
+        outperformed: excess > 0.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(timestamp: i64, close: f64) -> HistoricalDataPoint {
+        HistoricalDataPoint {
+            timestamp,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 0.0,
+        }
+    }
+
+    fn constituent(symbol: &str, weight: f64) -> IndexConstituent {
+        IndexConstituent {
+            symbol: symbol.to_string(),
+            mint: format!("{}-mint", symbol),
+            weight,
+        }
+    }
+
+    #[test]
+    fn weights_are_normalized() {
+        let normalized =
+            normalize_constituents(vec![constituent("JUP", 3.0), constituent("RAY", 1.0)])
+                .unwrap();
+        assert!((normalized[0].weight - 0.75).abs() < 1e-9);
+        assert!((normalized[1].weight - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rejects_duplicate_or_non_positive_weights() {
+        assert!(normalize_constituents(vec![constituent("JUP", 1.0), constituent("JUP", 1.0)])
+            .is_err());
+        assert!(normalize_constituents(vec![constituent("JUP", 0.0)]).is_err());
+        assert!(normalize_constituents(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn series_tracks_weighted_returns_on_shared_timestamps() {
+        let constituents = vec![constituent("JUP", 0.5), constituent("RAY", 0.5)];
+        let mut candles = HashMap::new();
+        candles.insert("JUP".to_string(), vec![candle(1, 1.0), candle(2, 2.0), candle(3, 2.0)]);
+        candles.insert("RAY".to_string(), vec![candle(1, 4.0), candle(3, 2.0)]);
+
+        let (series, missing) = compute_index_series(&constituents, &candles, 100.0);
+
+        assert!(missing.is_empty());
+        assert_eq!(series.len(), 2);
+        assert!((series[0].value - 100.0).abs() < 1e-9);
+        // JUP doubled, RAY halved
+        assert!((series[1].value - 125.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn missing_constituents_are_reported() {
+        let constituents = vec![constituent("JUP", 0.5), constituent("ORCA", 0.5)];
+        let mut candles = HashMap::new();
+        candles.insert("JUP".to_string(), vec![candle(1, 1.0), candle(2, 1.1)]);
+
+        let (series, missing) = compute_index_series(&constituents, &candles, 100.0);
+
+        assert_eq!(missing, vec!["ORCA".to_string()]);
+        assert!((series[1].value - 110.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn live_value_uses_series_base_and_needs_every_price() {
+        let constituents = vec![constituent("JUP", 0.5), constituent("RAY", 0.5)];
+        let mut candles = HashMap::new();
+        candles.insert("JUP".to_string(), vec![candle(1, 1.0), candle(2, 2.0)]);
+        candles.insert("RAY".to_string(), vec![candle(1, 4.0), candle(2, 4.0)]);
+
+        let mut live = HashMap::from([("JUP".to_string(), 3.0)]);
+        assert_eq!(live_index_value(&constituents, &candles, 100.0, &live), None);
+
+        live.insert("RAY".to_string(), 2.0);
+        let value = live_index_value(&constituents, &candles, 100.0, &live).unwrap();
+        // JUP tripled, RAY halved
+        assert!((value - 175.0).abs() < 1e-9);
+    }
+
+    fn sol_snapshot(ts: i64, sol: f64, price: f64) -> ExposureSnapshot {
+        ExposureSnapshot {
+            timestamp: DateTime::from_timestamp(ts, 0).unwrap().to_rfc3339(),
+            total_value_usd: sol * price,
+            by_asset_class: HashMap::new(),
+            by_custody: HashMap::new(),
+            holdings: HashMap::from([(
+                "SOL-mint".to_string(),
+                crate::portfolio::HoldingMark {
+                    amount: sol,
+                    price_usd: price,
+                },
+            )]),
+        }
+    }
+
+    #[test]
+    fn portfolio_return_spans_snapshots_in_window() {
+        let history = vec![
+            sol_snapshot(100, 5.0, 100.0),
+            sol_snapshot(200, 10.0, 100.0),
+            sol_snapshot(300, 10.0, 110.0),
+            sol_snapshot(400, 20.0, 100.0),
+        ];
+
+        let result = portfolio_return_percent(&history, 150, 350).unwrap();
+        assert!((result - 10.0).abs() < 1e-9);
+        assert_eq!(portfolio_return_percent(&history, 150, 250), None);
+    }
+
+    #[test]
+    fn portfolio_return_excludes_deposits_and_withdrawals() {
+        // Doubled by a deposit while the price rose 10%, then half withdrawn flat
+        let history = vec![
+            sol_snapshot(100, 10.0, 100.0),
+            sol_snapshot(200, 20.0, 110.0),
+            sol_snapshot(300, 10.0, 110.0),
+        ];
+        let result = portfolio_return_percent(&history, 0, 300).unwrap();
+        assert!((result - 10.0).abs() < 1e-9);
+
+        let mut legacy = history.clone();
+        legacy[0].holdings.clear();
+        assert_eq!(portfolio_return_percent(&legacy, 0, 300), None);
+    }
+}
@@ -0,0 +1,728 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::core::events::{get_domain_event_bus, DomainEvent, DomainEventKind};
+
+const CHART_ANNOTATIONS_DB_FILE: &str = "chart_annotations.db";
+pub const CHART_ANNOTATIONS_FORMAT: &str = "eclipse-chart-annotations/v1";
+pub const CHART_ANNOTATIONS_CHANGED_EVENT: &str = "chart_annotations_changed";
+
+const BUY_COLOR: &str = "#22c55e";
+const SELL_COLOR: &str = "#ef4444";
+const ALERT_COLOR: &str = "#f59e0b";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChartAnnotationError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("annotation not found: {0}")]
+    NotFound(String),
+    #[error("invalid annotation: {0}")]
+    Invalid(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationKind {
+    Line,
+    Note,
+    Zone,
+}
+
+impl AnnotationKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AnnotationKind::Line => "line",
+            AnnotationKind::Note => "note",
+            AnnotationKind::Zone => "zone",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "line" => Some(AnnotationKind::Line),
+            "note" => Some(AnnotationKind::Note),
+            "zone" => Some(AnnotationKind::Zone),
+            _ => None,
+        }
+    }
+}
+
+/// Who created the annotation. Trade and alert annotations are added by the
+/// app and carry the order or alert they came from in `reference_id`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationSource {
+    User,
+    Trade,
+    Alert,
+}
+
+impl AnnotationSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AnnotationSource::User => "user",
+            AnnotationSource::Trade => "trade",
+            AnnotationSource::Alert => "alert",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "user" => Some(AnnotationSource::User),
+            "trade" => Some(AnnotationSource::Trade),
+            "alert" => Some(AnnotationSource::Alert),
+            _ => None,
+        }
+    }
+}
+
+/// A line, note or zone drawn on a token's chart. Chart coordinates are a
+/// price and a unix time in seconds; a line with only `price` is horizontal,
+/// and a zone spans `price` to `price_to`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartAnnotation {
+    pub id: String,
+    pub token: String,
+    /// `None` shows the annotation on every timeframe.
+    pub timeframe: Option<String>,
+    pub kind: AnnotationKind,
+    pub source: AnnotationSource,
+    pub price: Option<f64>,
+    pub price_to: Option<f64>,
+    pub time: Option<i64>,
+    pub time_to: Option<i64>,
+    pub text: Option<String>,
+    pub color: Option<String>,
+    pub reference_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartAnnotationInput {
+    pub token: String,
+    pub timeframe: Option<String>,
+    pub kind: AnnotationKind,
+    pub price: Option<f64>,
+    pub price_to: Option<f64>,
+    pub time: Option<i64>,
+    pub time_to: Option<i64>,
+    pub text: Option<String>,
+    pub color: Option<String>,
+}
+
+impl ChartAnnotationInput {
+    pub fn validate(&self) -> Result<(), ChartAnnotationError> {
+        if self.token.trim().is_empty() {
+            return Err(ChartAnnotationError::Invalid(
+                "token is required".to_string(),
+            ));
+        }
+        let has_text = self.text.as_deref().map_or(false, |t| !t.trim().is_empty());
+        match self.kind {
+            AnnotationKind::Line if self.price.is_none() => Err(ChartAnnotationError::Invalid(
+                "a line needs a price".to_string(),
+            )),
+            AnnotationKind::Zone if self.price.is_none() || self.price_to.is_none() => Err(
+                ChartAnnotationError::Invalid("a zone needs both price bounds".to_string()),
+            ),
+            AnnotationKind::Note if !has_text => Err(ChartAnnotationError::Invalid(
+                "a note needs text".to_string(),
+            )),
+            AnnotationKind::Note if self.price.is_none() && self.time.is_none() => Err(
+                ChartAnnotationError::Invalid("a note needs a price or a time".to_string()),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Portable file written by export and read by import, so annotations
+/// survive a reinstall and can be shared.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartAnnotationExport {
+    pub format: String,
+    pub exported_at: DateTime<Utc>,
+    pub annotations: Vec<ChartAnnotation>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartAnnotationImportSummary {
+    pub imported: usize,
+    pub skipped: usize,
+}
+
+/// Builds the system annotation for a filled order. The token is the one
+/// bought, or the one sold, so the marker lands on that token's chart.
+pub fn trade_annotation(event: &DomainEvent, at: DateTime<Utc>) -> Option<ChartAnnotation> {
+    let DomainEvent::OrderFilled {
+        order_id,
+        side,
+        input_mint,
+        output_mint,
+        amount,
+        fill_price,
+        ..
+    } = event
+    else {
+        return None;
+    };
+    let is_buy = side == "buy";
+    Some(ChartAnnotation {
+        id: Uuid::new_v4().to_string(),
+        token: if is_buy { output_mint } else { input_mint }.clone(),
+        timeframe: None,
+        kind: AnnotationKind::Note,
+        source: AnnotationSource::Trade,
+        price: (*fill_price > 0.0).then_some(*fill_price),
+        price_to: None,
+        time: Some(at.timestamp()),
+        time_to: None,
+        text: Some(format!(
+            "{} {} @ {}",
+            if is_buy { "Buy" } else { "Sell" },
+            amount,
+            fill_price
+        )),
+        color: Some(if is_buy { BUY_COLOR } else { SELL_COLOR }.to_string()),
+        reference_id: Some(order_id.clone()),
+        created_at: at,
+        updated_at: at,
+    })
+}
+
+/// Builds the system annotation for a triggered alert. Each trigger gets its
+/// own marker, keyed by alert and trigger time.
+pub fn alert_annotation(event: &DomainEvent, at: DateTime<Utc>) -> Option<ChartAnnotation> {
+    let DomainEvent::AlertTriggered {
+        alert_id,
+        alert_name,
+        mint,
+        price,
+        triggered_at,
+        ..
+    } = event
+    else {
+        return None;
+    };
+    Some(ChartAnnotation {
+        id: Uuid::new_v4().to_string(),
+        token: mint.clone(),
+        timeframe: None,
+        kind: AnnotationKind::Note,
+        source: AnnotationSource::Alert,
+        price: Some(*price),
+        price_to: None,
+        time: Some(at.timestamp()),
+        time_to: None,
+        text: Some(alert_name.clone()),
+        color: Some(ALERT_COLOR.to_string()),
+        reference_id: Some(format!("{alert_id}:{triggered_at}")),
+        created_at: at,
+        updated_at: at,
+    })
+}
+
+pub struct ChartAnnotationStore {
+    pool: Pool<Sqlite>,
+}
+
+pub type SharedChartAnnotationStore = Arc<RwLock<ChartAnnotationStore>>;
+
+impl ChartAnnotationStore {
+    pub async fn new(app: &AppHandle) -> Result<Self, ChartAnnotationError> {
+        let db_path = chart_annotations_db_path(app)?;
+        Self::open(&format!("sqlite:{}?mode=rwc", db_path.display())).await
+    }
+
+    pub async fn open(db_url: &str) -> Result<Self, ChartAnnotationError> {
+        let pool = SqlitePool::connect(db_url).await?;
+        let store = Self { pool };
+        store.initialize().await?;
+        Ok(store)
+    }
+
+    async fn initialize(&self) -> Result<(), ChartAnnotationError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS chart_annotations (
+                id TEXT PRIMARY KEY,
+                token TEXT NOT NULL,
+                timeframe TEXT,
+                kind TEXT NOT NULL,
+                source TEXT NOT NULL,
+                price REAL,
+                price_to REAL,
+                time INTEGER,
+                time_to INTEGER,
+                text TEXT,
+                color TEXT,
+                reference_id TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                UNIQUE(source, reference_id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_chart_annotations_token ON chart_annotations(token, timeframe)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    fn row_to_annotation(row: &SqliteRow) -> Result<ChartAnnotation, ChartAnnotationError> {
+        let kind: String = row.try_get("kind")?;
+        let source: String = row.try_get("source")?;
+        let created_at: String = row.try_get("created_at")?;
+        let updated_at: String = row.try_get("updated_at")?;
+
+        Ok(ChartAnnotation {
+            id: row.try_get("id")?,
+            token: row.try_get("token")?,
+            timeframe: row.try_get("timeframe")?,
+            kind: AnnotationKind::parse(&kind).unwrap_or(AnnotationKind::Note),
+            source: AnnotationSource::parse(&source).unwrap_or(AnnotationSource::User),
+            price: row.try_get("price")?,
+            price_to: row.try_get("price_to")?,
+            time: row.try_get("time")?,
+            time_to: row.try_get("time_to")?,
+            text: row.try_get("text")?,
+            color: row.try_get("color")?,
+            reference_id: row.try_get("reference_id")?,
+            created_at: parse_timestamp(&created_at)?,
+            updated_at: parse_timestamp(&updated_at)?,
+        })
+    }
+
+    /// Returns `false` when the annotation's id, or its system reference, is
+    /// already stored.
+    async fn insert(&self, annotation: &ChartAnnotation) -> Result<bool, ChartAnnotationError> {
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO chart_annotations (
+                id, token, timeframe, kind, source, price, price_to, time, time_to,
+                text, color, reference_id, created_at, updated_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+            "#,
+        )
+        .bind(&annotation.id)
+        .bind(&annotation.token)
+        .bind(&annotation.timeframe)
+        .bind(annotation.kind.as_str())
+        .bind(annotation.source.as_str())
+        .bind(annotation.price)
+        .bind(annotation.price_to)
+        .bind(annotation.time)
+        .bind(annotation.time_to)
+        .bind(&annotation.text)
+        .bind(&annotation.color)
+        .bind(&annotation.reference_id)
+        .bind(annotation.created_at.to_rfc3339())
+        .bind(annotation.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Annotations for a token on one timeframe, including the ones shown on
+    /// every timeframe. Without a timeframe every annotation is returned.
+    pub async fn list(
+        &self,
+        token: &str,
+        timeframe: Option<&str>,
+    ) -> Result<Vec<ChartAnnotation>, ChartAnnotationError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM chart_annotations
+            WHERE token = ?1 AND (?2 IS NULL OR timeframe IS NULL OR timeframe = ?2)
+            ORDER BY COALESCE(time, 0), created_at
+            "#,
+        )
+        .bind(token)
+        .bind(timeframe)
+        .fetch_all(&self.pool)
+        .await?;
+        rows.iter().map(Self::row_to_annotation).collect()
+    }
+
+    pub async fn get(&self, id: &str) -> Result<ChartAnnotation, ChartAnnotationError> {
+        let row = sqlx::query("SELECT * FROM chart_annotations WHERE id = ?1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| ChartAnnotationError::NotFound(id.to_string()))?;
+        Self::row_to_annotation(&row)
+    }
+
+    pub async fn create(
+        &self,
+        input: ChartAnnotationInput,
+    ) -> Result<ChartAnnotation, ChartAnnotationError> {
+        input.validate()?;
+        let now = Utc::now();
+        let annotation = ChartAnnotation {
+            id: Uuid::new_v4().to_string(),
+            token: input.token.trim().to_string(),
+            timeframe: input.timeframe,
+            kind: input.kind,
+            source: AnnotationSource::User,
+            price: input.price,
+            price_to: input.price_to,
+            time: input.time,
+            time_to: input.time_to,
+            text: input.text,
+            color: input.color,
+            reference_id: None,
+            created_at: now,
+            updated_at: now,
+        };
+        self.insert(&annotation).await?;
+        Ok(annotation)
+    }
+
+    /// Replaces the drawing of an annotation. Source and reference are kept,
+    /// so an edited trade marker still belongs to its order.
+    pub async fn update(
+        &self,
+        id: &str,
+        input: ChartAnnotationInput,
+    ) -> Result<ChartAnnotation, ChartAnnotationError> {
+        input.validate()?;
+        let existing = self.get(id).await?;
+        let annotation = ChartAnnotation {
+            token: input.token.trim().to_string(),
+            timeframe: input.timeframe,
+            kind: input.kind,
+            price: input.price,
+            price_to: input.price_to,
+            time: input.time,
+            time_to: input.time_to,
+            text: input.text,
+            color: input.color,
+            updated_at: Utc::now(),
+            ..existing
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE chart_annotations SET
+                token = ?2, timeframe = ?3, kind = ?4, price = ?5, price_to = ?6,
+                time = ?7, time_to = ?8, text = ?9, color = ?10, updated_at = ?11
+            WHERE id = ?1
+            "#,
+        )
+        .bind(&annotation.id)
+        .bind(&annotation.token)
+        .bind(&annotation.timeframe)
+        .bind(annotation.kind.as_str())
+        .bind(annotation.price)
+        .bind(annotation.price_to)
+        .bind(annotation.time)
+        .bind(annotation.time_to)
+        .bind(&annotation.text)
+        .bind(&annotation.color)
+        .bind(annotation.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(annotation)
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), ChartAnnotationError> {
+        let result = sqlx::query("DELETE FROM chart_annotations WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(ChartAnnotationError::NotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Stores a trade or alert annotation once; redelivered events are ignored.
+    pub async fn record_system(
+        &self,
+        annotation: &ChartAnnotation,
+    ) -> Result<bool, ChartAnnotationError> {
+        self.insert(annotation).await
+    }
+
+    pub async fn export(
+        &self,
+        token: Option<&str>,
+    ) -> Result<ChartAnnotationExport, ChartAnnotationError> {
+        let rows = sqlx::query(
+            "SELECT * FROM chart_annotations WHERE ?1 IS NULL OR token = ?1 ORDER BY token, created_at",
+        )
+        .bind(token)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(ChartAnnotationExport {
+            format: CHART_ANNOTATIONS_FORMAT.to_string(),
+            exported_at: Utc::now(),
+            annotations: rows
+                .iter()
+                .map(Self::row_to_annotation)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Adds annotations from an export. Ones already present, by id or by the
+    /// trade or alert they mark, are skipped rather than overwritten.
+    pub async fn import(
+        &self,
+        export: &ChartAnnotationExport,
+    ) -> Result<ChartAnnotationImportSummary, ChartAnnotationError> {
+        if export.format != CHART_ANNOTATIONS_FORMAT {
+            return Err(ChartAnnotationError::Invalid(format!(
+                "unsupported export format: {}",
+                export.format
+            )));
+        }
+
+        let mut summary = ChartAnnotationImportSummary::default();
+        for annotation in &export.annotations {
+            if self.insert(annotation).await? {
+                summary.imported += 1;
+            } else {
+                summary.skipped += 1;
+            }
+        }
+        Ok(summary)
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, ChartAnnotationError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ChartAnnotationError::Internal(format!("invalid timestamp {}: {}", value, e)))
+}
+
+fn chart_annotations_db_path(app: &AppHandle) -> Result<PathBuf, ChartAnnotationError> {
+    let app_data_dir = app.path_resolver().app_data_dir().ok_or_else(|| {
+        ChartAnnotationError::Internal("Unable to resolve app data directory".to_string())
+    })?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(CHART_ANNOTATIONS_DB_FILE))
+}
+
+/// Adds trade and alert markers as fills and triggers are published, and
+/// tells open charts to reload the token.
+pub fn start_chart_annotation_sync(app: AppHandle, store: SharedChartAnnotationStore) {
+    let mut subscription = get_domain_event_bus().subscribe(&[
+        DomainEventKind::OrderFilled,
+        DomainEventKind::AlertTriggered,
+    ]);
+    tauri::async_runtime::spawn(async move {
+        while let Some(envelope) = subscription.recv().await {
+            let annotation = trade_annotation(&envelope.event, envelope.published_at)
+                .or_else(|| alert_annotation(&envelope.event, envelope.published_at));
+            let Some(annotation) = annotation else {
+                continue;
+            };
+            match store.read().await.record_system(&annotation).await {
+                Ok(true) => {
+                    let _ = app.emit_all(CHART_ANNOTATIONS_CHANGED_EVENT, &annotation.token);
+                }
+                Ok(false) => {}
+                Err(err) => {
+                    tracing::warn!(error = %err, token = %annotation.token, "failed to record chart annotation");
+                }
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn list_chart_annotations(
+    token: String,
+    timeframe: Option<String>,
+    store: State<'_, SharedChartAnnotationStore>,
+) -> Result<Vec<ChartAnnotation>, String> {
+    store
+        .read()
+        .await
+        .list(&token, timeframe.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn create_chart_annotation(
+    annotation: ChartAnnotationInput,
+    store: State<'_, SharedChartAnnotationStore>,
+) -> Result<ChartAnnotation, String> {
+    store
+        .read()
+        .await
+        .create(annotation)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_chart_annotation(
+    id: String,
+    annotation: ChartAnnotationInput,
+    store: State<'_, SharedChartAnnotationStore>,
+) -> Result<ChartAnnotation, String> {
+    store
+        .read()
+        .await
+        .update(&id, annotation)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_chart_annotation(
+    id: String,
+    store: State<'_, SharedChartAnnotationStore>,
+) -> Result<(), String> {
+    store
+        .read()
+        .await
+        .delete(&id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn export_chart_annotations(
+    token: Option<String>,
+    store: State<'_, SharedChartAnnotationStore>,
+) -> Result<String, String> {
+    let export = store
+        .read()
+        .await
+        .export(token.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+    serde_json::to_string_pretty(&export).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn import_chart_annotations(
+    data: String,
+    store: State<'_, SharedChartAnnotationStore>,
+) -> Result<ChartAnnotationImportSummary, String> {
+    let export: ChartAnnotationExport = serde_json::from_str(&data).map_err(|e| e.to_string())?;
+    store
+        .read()
+        .await
+        .import(&export)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn memory_store() -> ChartAnnotationStore {
+        let url = format!("sqlite:file:{}?mode=memory&cache=shared", Uuid::new_v4());
+        ChartAnnotationStore::open(&url).await.unwrap()
+    }
+
+    fn zone(token: &str, timeframe: Option<&str>) -> ChartAnnotationInput {
+        ChartAnnotationInput {
+            token: token.to_string(),
+            timeframe: timeframe.map(str::to_string),
+            kind: AnnotationKind::Zone,
+            price: Some(1.0),
+            price_to: Some(1.2),
+            time: None,
+            time_to: None,
+            text: None,
+            color: None,
+        }
+    }
+
+    fn fill(order_id: &str, side: &str) -> DomainEvent {
+        DomainEvent::OrderFilled {
+            order_id: order_id.to_string(),
+            wallet_address: "wallet".to_string(),
+            side: side.to_string(),
+            input_mint: "USDC".to_string(),
+            output_mint: "BONK".to_string(),
+            amount: 100.0,
+            fill_price: 0.5,
+            tx_signature: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_annotations_for_timeframe_and_all_timeframes() {
+        let store = memory_store().await;
+        store.create(zone("BONK", Some("1h"))).await.unwrap();
+        store.create(zone("BONK", Some("1d"))).await.unwrap();
+        store.create(zone("BONK", None)).await.unwrap();
+        store.create(zone("WIF", None)).await.unwrap();
+
+        assert_eq!(store.list("BONK", Some("1h")).await.unwrap().len(), 2);
+        assert_eq!(store.list("BONK", None).await.unwrap().len(), 3);
+
+        let mut missing_bound = zone("BONK", None);
+        missing_bound.price_to = None;
+        assert!(store.create(missing_bound).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn trade_annotations_are_recorded_once_on_the_traded_token() {
+        let store = memory_store().await;
+        let now = Utc::now();
+
+        let buy = trade_annotation(&fill("order-1", "buy"), now).unwrap();
+        assert_eq!(buy.token, "BONK");
+        assert!(store.record_system(&buy).await.unwrap());
+
+        let again = trade_annotation(&fill("order-1", "buy"), now).unwrap();
+        assert!(!store.record_system(&again).await.unwrap());
+
+        let sell = trade_annotation(&fill("order-2", "sell"), now).unwrap();
+        assert_eq!(sell.token, "USDC");
+        assert_eq!(store.list("BONK", None).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn export_round_trips_and_skips_existing() {
+        let source = memory_store().await;
+        source.create(zone("BONK", Some("1h"))).await.unwrap();
+        source
+            .record_system(&trade_annotation(&fill("order-1", "buy"), Utc::now()).unwrap())
+            .await
+            .unwrap();
+        let export = source.export(Some("BONK")).await.unwrap();
+
+        let target = memory_store().await;
+        let summary = target.import(&export).await.unwrap();
+        assert_eq!(summary.imported, 2);
+        assert_eq!(target.list("BONK", None).await.unwrap(), export.annotations);
+
+        let summary = target.import(&export).await.unwrap();
+        assert_eq!(summary.skipped, 2);
+    }
+}
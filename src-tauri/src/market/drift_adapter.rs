@@ -1,3 +1,4 @@
+use crate::config::http_recorder;
 use reqwest;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -146,10 +147,10 @@ impl DriftAdapter {
     async fn fetch_markets_raw(&self) -> Result<Vec<DriftMarket>, String> {
         let url = format!("{}/v2/markets", DRIFT_API_BASE);
 
-        let response = self
+        let request = self
             .client
-            .get(&url)
-            .send()
+            .get(&url);
+        let response = http_recorder::send("drift", request)
             .await
             .map_err(|e| format!("Drift API request failed: {e}"))?;
 
@@ -178,10 +179,10 @@ impl DriftAdapter {
     async fn fetch_market_raw(&self, market_index: u32) -> Result<DriftMarket, String> {
         let url = format!("{}/v2/markets/{}", DRIFT_API_BASE, market_index);
 
-        let response = self
+        let request = self
             .client
-            .get(&url)
-            .send()
+            .get(&url);
+        let response = http_recorder::send("drift", request)
             .await
             .map_err(|e| format!("Drift API request failed: {e}"))?;
 
@@ -210,10 +211,10 @@ impl DriftAdapter {
     pub async fn fetch_order_book(&self, market_index: u32) -> Result<DriftOrderBook, String> {
         let url = format!("{}/v2/orderbook/{}", DRIFT_API_BASE, market_index);
 
-        let response = self
+        let request = self
             .client
-            .get(&url)
-            .send()
+            .get(&url);
+        let response = http_recorder::send("drift", request)
             .await
             .map_err(|e| format!("Drift order book request failed: {e}"))?;
 
@@ -236,10 +237,10 @@ impl DriftAdapter {
 
         let url = format!("{}/v2/predictions", DRIFT_API_BASE);
 
-        let response = self
+        let request = self
             .client
-            .get(&url)
-            .send()
+            .get(&url);
+        let response = http_recorder::send("drift", request)
             .await
             .map_err(|e| format!("Drift predictions request failed: {e}"))?;
 
@@ -0,0 +1,430 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::State;
+
+use crate::ai::{RiskHistoryPoint, SharedRiskAnalyzer};
+use crate::core::price_engine::get_price_engine;
+use crate::portfolio::SharedPortfolioData;
+
+use super::top_coins::{fetch_top_coins, SharedTopCoinsCache};
+
+const TOP_COINS_UNIVERSE_SIZE: usize = 50;
+const RISK_DELTA_WINDOW_DAYS: u32 = 1;
+// Full-intensity color is reached at +/-10% for price change and +/-20 points for risk delta
+const CHANGE_SATURATION_PERCENT: f64 = 10.0;
+const RISK_SATURATION_POINTS: f64 = 20.0;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum HeatmapUniverse {
+    TopCoins,
+    Portfolio,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum HeatmapSizeMetric {
+    MarketCap,
+    Holdings,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum HeatmapColorMetric {
+    Change24h,
+    RiskDelta,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapMetric {
+    pub size: Option<HeatmapSizeMetric>,
+    pub color: Option<HeatmapColorMetric>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapTile {
+    pub address: String,
+    pub symbol: String,
+    pub name: String,
+    pub size_value: f64,
+    /// `None` when the metric isn't known for this token; such tiles are drawn neutral.
+    pub color_value: Option<f64>,
+    /// Color value normalized to [-1, 1] so the frontend can map it straight onto a gradient.
+    pub intensity: f64,
+    pub weight: f64,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketHeatmap {
+    pub universe: HeatmapUniverse,
+    pub size_metric: HeatmapSizeMetric,
+    pub color_metric: HeatmapColorMetric,
+    pub total_size: f64,
+    pub tiles: Vec<HeatmapTile>,
+    /// Symbols left out because the size metric isn't known for them.
+    pub missing: Vec<String>,
+    pub generated_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct HeatmapInput {
+    pub address: String,
+    pub symbol: String,
+    pub name: String,
+    pub size_value: f64,
+    pub color_value: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl HeatmapSizeMetric {
+    fn default_for(universe: HeatmapUniverse) -> Self {
+        match universe {
+            HeatmapUniverse::TopCoins => HeatmapSizeMetric::MarketCap,
+            HeatmapUniverse::Portfolio => HeatmapSizeMetric::Holdings,
+        }
+    }
+}
+
+impl HeatmapColorMetric {
+    fn saturation(&self) -> f64 {
+        match self {
+            HeatmapColorMetric::Change24h => CHANGE_SATURATION_PERCENT,
+            HeatmapColorMetric::RiskDelta => RISK_SATURATION_POINTS,
+        }
+    }
+}
+
+/// Lays out the inputs as a squarified treemap in the unit square.
+///
+/// Tiles are returned largest first; entries with a non-positive size are dropped.
+pub fn build_heatmap_tiles(
+    inputs: Vec<HeatmapInput>,
+    color_metric: HeatmapColorMetric,
+) -> Vec<HeatmapTile> {
+    let mut inputs: Vec<HeatmapInput> = inputs
+        .into_iter()
+        .filter(|input| input.size_value.is_finite() && input.size_value > 0.0)
+        .collect();
+    inputs.sort_by(|a, b| {
+        b.size_value
+            .partial_cmp(&a.size_value)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let total: f64 = inputs.iter().map(|input| input.size_value).sum();
+    if total <= 0.0 {
+        return Vec::new();
+    }
+
+    let weights: Vec<f64> = inputs.iter().map(|input| input.size_value / total).collect();
+    let rects = squarify(&weights);
+    let saturation = color_metric.saturation();
+
+    inputs
+        .into_iter()
+        .zip(weights)
+        .zip(rects)
+        .map(|((input, weight), rect)| HeatmapTile {
+            intensity: input
+                .color_value
+                .map_or(0.0, |value| (value / saturation).clamp(-1.0, 1.0)),
+            address: input.address,
+            symbol: input.symbol,
+            name: input.name,
+            size_value: input.size_value,
+            color_value: input.color_value,
+            weight,
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        })
+        .collect()
+}
+
+fn squarify(weights: &[f64]) -> Vec<Rect> {
+    let mut rects = Vec::with_capacity(weights.len());
+    let mut free = Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 1.0,
+        height: 1.0,
+    };
+    let mut start = 0;
+
+    while start < weights.len() {
+        let side = free.width.min(free.height);
+        let mut end = start + 1;
+        while end < weights.len()
+            && worst_ratio(&weights[start..=end], side) <= worst_ratio(&weights[start..end], side)
+        {
+            end += 1;
+        }
+
+        let row = &weights[start..end];
+        let row_area: f64 = row.iter().sum();
+        if free.width >= free.height {
+            let column_width = if free.height > 0.0 { row_area / free.height } else { 0.0 };
+            let mut y = free.y;
+            for weight in row {
+                let height = if column_width > 0.0 { weight / column_width } else { 0.0 };
+                rects.push(Rect { x: free.x, y, width: column_width, height });
+                y += height;
+            }
+            free.x += column_width;
+            free.width = (free.width - column_width).max(0.0);
+        } else {
+            let row_height = if free.width > 0.0 { row_area / free.width } else { 0.0 };
+            let mut x = free.x;
+            for weight in row {
+                let width = if row_height > 0.0 { weight / row_height } else { 0.0 };
+                rects.push(Rect { x, y: free.y, width, height: row_height });
+                x += width;
+            }
+            free.y += row_height;
+            free.height = (free.height - row_height).max(0.0);
+        }
+
+        start = end;
+    }
+
+    rects
+}
+
+fn worst_ratio(row: &[f64], side: f64) -> f64 {
+    let sum: f64 = row.iter().sum();
+    if sum <= 0.0 || side <= 0.0 {
+        return f64::MAX;
+    }
+    let max = row.iter().cloned().fold(f64::MIN, f64::max);
+    let min = row.iter().cloned().fold(f64::MAX, f64::min);
+    let side_sq = side * side;
+    let sum_sq = sum * sum;
+    (side_sq * max / sum_sq).max(sum_sq / (side_sq * min))
+}
+
+/// Risk score change over the window, or `None` when there are not two scores
+/// to compare. Unknown must not read as "unchanged".
+async fn risk_delta(analyzer: &SharedRiskAnalyzer, address: &str) -> Option<f64> {
+    let analyzer = analyzer.read().await;
+    match analyzer
+        .get_risk_history(address, RISK_DELTA_WINDOW_DAYS)
+        .await
+    {
+        Ok(history) => score_change(&history.history),
+        Err(err) => {
+            tracing::warn!(error = %err, address, "failed to load risk history for heatmap");
+            None
+        }
+    }
+}
+
+fn score_change(history: &[RiskHistoryPoint]) -> Option<f64> {
+    match history {
+        [first, .., last] => Some(last.score - first.score),
+        _ => None,
+    }
+}
+
+#[tauri::command]
+pub async fn get_market_heatmap(
+    universe: HeatmapUniverse,
+    metric: Option<HeatmapMetric>,
+    api_key: Option<String>,
+    top_coins: State<'_, SharedTopCoinsCache>,
+    portfolio: State<'_, SharedPortfolioData>,
+    risk_analyzer: State<'_, SharedRiskAnalyzer>,
+) -> Result<MarketHeatmap, String> {
+    let size_metric = metric
+        .as_ref()
+        .and_then(|m| m.size)
+        .unwrap_or_else(|| HeatmapSizeMetric::default_for(universe));
+    let color_metric = metric
+        .as_ref()
+        .and_then(|m| m.color)
+        .unwrap_or(HeatmapColorMetric::Change24h);
+
+    let mut missing = Vec::new();
+    let mut inputs = match universe {
+        HeatmapUniverse::TopCoins => {
+            let coins = fetch_top_coins(&top_coins, TOP_COINS_UNIVERSE_SIZE, 0, api_key).await?;
+            let holdings = if size_metric == HeatmapSizeMetric::Holdings {
+                portfolio
+                    .lock()
+                    .map_err(|_| "Portfolio data locked".to_string())?
+                    .positions()
+            } else {
+                Vec::new()
+            };
+
+            coins
+                .into_iter()
+                .map(|coin| {
+                    let size_value = match size_metric {
+                        HeatmapSizeMetric::MarketCap => coin.market_cap,
+                        HeatmapSizeMetric::Holdings => holdings
+                            .iter()
+                            .find(|p| p.mint == coin.address)
+                            .map(|p| p.total_value)
+                            .unwrap_or(0.0),
+                    };
+                    HeatmapInput {
+                        address: coin.address,
+                        symbol: coin.symbol,
+                        name: coin.name,
+                        size_value,
+                        color_value: Some(coin.price_change_24h),
+                    }
+                })
+                .collect::<Vec<_>>()
+        }
+        HeatmapUniverse::Portfolio => {
+            let positions = portfolio
+                .lock()
+                .map_err(|_| "Portfolio data locked".to_string())?
+                .positions();
+
+            // Positions carry no supply data, so market caps come from the top coins list
+            let market_caps: HashMap<String, f64> = if size_metric == HeatmapSizeMetric::MarketCap {
+                fetch_top_coins(&top_coins, TOP_COINS_UNIVERSE_SIZE, 0, api_key)
+                    .await?
+                    .into_iter()
+                    .map(|coin| (coin.address, coin.market_cap))
+                    .collect()
+            } else {
+                HashMap::new()
+            };
+            let prices = get_price_engine();
+
+            let mut inputs = Vec::with_capacity(positions.len());
+            for position in positions {
+                let size_value = match size_metric {
+                    HeatmapSizeMetric::Holdings => Some(position.total_value),
+                    HeatmapSizeMetric::MarketCap => market_caps.get(&position.mint).copied(),
+                };
+                let Some(size_value) = size_value else {
+                    missing.push(position.symbol);
+                    continue;
+                };
+                inputs.push(HeatmapInput {
+                    size_value,
+                    color_value: prices
+                        .get_cached_price(&position.symbol)
+                        .map(|price| price.change_24h),
+                    address: position.mint,
+                    name: position.symbol.clone(),
+                    symbol: position.symbol,
+                });
+            }
+            inputs
+        }
+    };
+
+    if color_metric == HeatmapColorMetric::RiskDelta {
+        for input in inputs.iter_mut() {
+            input.color_value = risk_delta(&risk_analyzer, &input.address).await;
+        }
+    }
+
+    let total_size = inputs
+        .iter()
+        .filter(|input| input.size_value > 0.0)
+        .map(|input| input.size_value)
+        .sum();
+
+    Ok(MarketHeatmap {
+        universe,
+        size_metric,
+        color_metric,
+        total_size,
+        tiles: build_heatmap_tiles(inputs, color_metric),
+        missing,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(symbol: &str, size: f64, color: f64) -> HeatmapInput {
+        HeatmapInput {
+            address: format!("{}-mint", symbol),
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            size_value: size,
+            color_value: Some(color),
+        }
+    }
+
+    #[test]
+    fn tiles_cover_unit_square() {
+        let tiles = build_heatmap_tiles(
+            vec![
+                input("SOL", 600.0, 4.0),
+                input("JUP", 300.0, -2.0),
+                input("BONK", 200.0, 12.0),
+                input("WIF", 100.0, -15.0),
+            ],
+            HeatmapColorMetric::Change24h,
+        );
+
+        assert_eq!(tiles.len(), 4);
+        assert_eq!(tiles[0].symbol, "SOL");
+        let area: f64 = tiles.iter().map(|t| t.width * t.height).sum();
+        assert!((area - 1.0).abs() < 1e-9);
+        for tile in &tiles {
+            assert!((tile.width * tile.height - tile.weight).abs() < 1e-9);
+            assert!(tile.x + tile.width <= 1.0 + 1e-9);
+            assert!(tile.y + tile.height <= 1.0 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn intensity_is_clamped_and_empty_sizes_dropped() {
+        let tiles = build_heatmap_tiles(
+            vec![input("A", 10.0, 25.0), input("B", 0.0, 1.0), input("C", 5.0, -5.0)],
+            HeatmapColorMetric::Change24h,
+        );
+
+        assert_eq!(tiles.len(), 2);
+        assert_eq!(tiles[0].intensity, 1.0);
+        assert!((tiles[1].intensity + 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unknown_color_is_neutral() {
+        let mut unpriced = input("NEW", 10.0, 0.0);
+        unpriced.color_value = None;
+        let tiles = build_heatmap_tiles(vec![unpriced], HeatmapColorMetric::Change24h);
+
+        assert_eq!(tiles[0].color_value, None);
+        assert_eq!(tiles[0].intensity, 0.0);
+    }
+
+    #[test]
+    fn risk_delta_needs_two_scores() {
+        let point = |score| RiskHistoryPoint {
+            timestamp: String::new(),
+            score,
+            risk_level: String::new(),
+        };
+        assert_eq!(score_change(&[]), None);
+        assert_eq!(score_change(&[point(40.0)]), None);
+        assert_eq!(score_change(&[point(40.0), point(45.0), point(52.5)]), Some(12.5));
+    }
+}
@@ -0,0 +1,524 @@
+use super::holders::{
+    refresh_holder_distribution, HolderAnalyzer, HolderError, SharedHolderAnalyzer,
+};
+use crate::alerts::{AlertState, SharedAlertManager};
+use crate::portfolio::{SharedPortfolioData, SharedWatchlistManager};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Pool, Row, Sqlite};
+use std::collections::{BTreeMap, HashMap};
+use std::time::Instant;
+use tauri::{AppHandle, Manager, State};
+
+/// Upper bound for a single `refresh_holder_data` call.
+const MAX_BATCH_TOKENS: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HolderRefreshConfig {
+    pub enabled: bool,
+    /// Portfolio and watchlist tokens older than this are refreshed on the next tick.
+    pub staleness_minutes: i64,
+    pub max_tokens_per_run: usize,
+    pub check_interval_minutes: u64,
+}
+
+impl Default for HolderRefreshConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            staleness_minutes: 120,
+            max_tokens_per_run: 25,
+            check_interval_minutes: 15,
+        }
+    }
+}
+
+impl HolderRefreshConfig {
+    fn validate(&self) -> Result<(), HolderError> {
+        if self.staleness_minutes < 5 {
+            return Err(HolderError::InvalidInput(
+                "staleness budget must be at least 5 minutes".to_string(),
+            ));
+        }
+        if self.max_tokens_per_run == 0 || self.max_tokens_per_run > MAX_BATCH_TOKENS {
+            return Err(HolderError::InvalidInput(format!(
+                "tokens per run must be between 1 and {MAX_BATCH_TOKENS}"
+            )));
+        }
+        if self.check_interval_minutes == 0 {
+            return Err(HolderError::InvalidInput(
+                "check interval must be at least 1 minute".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HolderRefreshCandidate {
+    pub token_address: String,
+    pub has_active_alert: bool,
+    pub last_refreshed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolderRefreshFailure {
+    pub token_address: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolderRefreshReport {
+    pub refreshed: Vec<String>,
+    pub failed: Vec<HolderRefreshFailure>,
+    /// Stale tokens left for later runs because of the per-run cap.
+    pub deferred: usize,
+    pub duration_ms: i64,
+}
+
+/// One row per batch, manual or scheduled, for the jobs dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct HolderRefreshRun {
+    pub id: i64,
+    pub trigger: String,
+    pub started_at: String,
+    pub duration_ms: i64,
+    pub requested: i64,
+    pub refreshed: i64,
+    pub failed: i64,
+    pub deferred: i64,
+}
+
+pub(crate) async fn init_holder_refresh_tables(pool: &Pool<Sqlite>) -> Result<(), HolderError> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS holder_refresh_state (
+            token_address TEXT PRIMARY KEY NOT NULL,
+            last_refreshed_at TEXT,
+            last_attempt_at TEXT NOT NULL,
+            last_error TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS holder_refresh_runs (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            trigger TEXT NOT NULL,
+            started_at TEXT NOT NULL,
+            duration_ms INTEGER NOT NULL,
+            requested INTEGER NOT NULL,
+            refreshed INTEGER NOT NULL,
+            failed INTEGER NOT NULL,
+            deferred INTEGER NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS holder_refresh_config (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            config_json TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Picks the stale candidates to refresh: tokens behind an active alert first, then
+/// never-refreshed tokens, then oldest first. Returns the selection and how many
+/// stale tokens were deferred by the cap.
+pub fn plan_holder_refresh(
+    mut candidates: Vec<HolderRefreshCandidate>,
+    now: DateTime<Utc>,
+    config: &HolderRefreshConfig,
+) -> (Vec<String>, usize) {
+    let budget = Duration::minutes(config.staleness_minutes);
+    candidates.retain(|c| c.last_refreshed_at.map_or(true, |at| now - at >= budget));
+    candidates.sort_by(|a, b| {
+        b.has_active_alert
+            .cmp(&a.has_active_alert)
+            .then_with(|| a.last_refreshed_at.cmp(&b.last_refreshed_at))
+            .then_with(|| a.token_address.cmp(&b.token_address))
+    });
+
+    let deferred = candidates.len().saturating_sub(config.max_tokens_per_run);
+    let selected = candidates
+        .into_iter()
+        .take(config.max_tokens_per_run)
+        .map(|c| c.token_address)
+        .collect();
+    (selected, deferred)
+}
+
+pub async fn get_holder_refresh_settings(
+    pool: &Pool<Sqlite>,
+) -> Result<HolderRefreshConfig, HolderError> {
+    let row = sqlx::query("SELECT config_json FROM holder_refresh_config WHERE id = 1")
+        .fetch_optional(pool)
+        .await?;
+
+    match row {
+        Some(row) => Ok(serde_json::from_str(
+            &row.try_get::<String, _>("config_json")?,
+        )?),
+        None => Ok(HolderRefreshConfig::default()),
+    }
+}
+
+async fn save_holder_refresh_settings(
+    pool: &Pool<Sqlite>,
+    config: &HolderRefreshConfig,
+) -> Result<(), HolderError> {
+    config.validate()?;
+    sqlx::query(
+        r#"
+        INSERT INTO holder_refresh_config (id, config_json, updated_at)
+        VALUES (1, ?1, ?2)
+        ON CONFLICT(id) DO UPDATE SET config_json = excluded.config_json, updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(serde_json::to_string(config)?)
+    .bind(Utc::now().to_rfc3339())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn last_refreshed(
+    pool: &Pool<Sqlite>,
+) -> Result<HashMap<String, DateTime<Utc>>, HolderError> {
+    let rows = sqlx::query(
+        "SELECT token_address, last_refreshed_at FROM holder_refresh_state WHERE last_refreshed_at IS NOT NULL",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut refreshed = HashMap::new();
+    for row in rows {
+        let at: String = row.try_get("last_refreshed_at")?;
+        if let Ok(at) = DateTime::parse_from_rfc3339(&at) {
+            refreshed.insert(row.try_get("token_address")?, at.with_timezone(&Utc));
+        }
+    }
+    Ok(refreshed)
+}
+
+async fn record_attempt(
+    pool: &Pool<Sqlite>,
+    token_address: &str,
+    error: Option<&str>,
+) -> Result<(), HolderError> {
+    let now = Utc::now().to_rfc3339();
+    let refreshed_at = error.is_none().then_some(now.as_str());
+    sqlx::query(
+        r#"
+        INSERT INTO holder_refresh_state (token_address, last_refreshed_at, last_attempt_at, last_error)
+        VALUES (?1, ?2, ?3, ?4)
+        ON CONFLICT(token_address) DO UPDATE SET
+            last_refreshed_at = COALESCE(excluded.last_refreshed_at, holder_refresh_state.last_refreshed_at),
+            last_attempt_at = excluded.last_attempt_at,
+            last_error = excluded.last_error
+        "#,
+    )
+    .bind(token_address)
+    .bind(refreshed_at)
+    .bind(&now)
+    .bind(error)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn record_run(
+    pool: &Pool<Sqlite>,
+    trigger: &str,
+    started_at: DateTime<Utc>,
+    requested: usize,
+    report: &HolderRefreshReport,
+) -> Result<(), HolderError> {
+    sqlx::query(
+        r#"
+        INSERT INTO holder_refresh_runs (trigger, started_at, duration_ms, requested, refreshed, failed, deferred)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        "#,
+    )
+    .bind(trigger)
+    .bind(started_at.to_rfc3339())
+    .bind(report.duration_ms)
+    .bind(requested as i64)
+    .bind(report.refreshed.len() as i64)
+    .bind(report.failed.len() as i64)
+    .bind(report.deferred as i64)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Refreshes each token in turn; one failing token does not stop the batch.
+pub async fn refresh_holder_batch(
+    analyzer: &HolderAnalyzer,
+    app: &AppHandle,
+    tokens: &[String],
+    trigger: &str,
+    deferred: usize,
+) -> Result<HolderRefreshReport, HolderError> {
+    let started_at = Utc::now();
+    let timer = Instant::now();
+    let mut report = HolderRefreshReport {
+        deferred,
+        ..HolderRefreshReport::default()
+    };
+
+    for token in tokens {
+        match refresh_holder_distribution(analyzer, app, token).await {
+            Ok(_) => {
+                record_attempt(analyzer.pool(), token, None).await?;
+                report.refreshed.push(token.clone());
+            }
+            Err(err) => {
+                let error = err.to_string();
+                record_attempt(analyzer.pool(), token, Some(&error)).await?;
+                report.failed.push(HolderRefreshFailure {
+                    token_address: token.clone(),
+                    error,
+                });
+            }
+        }
+    }
+
+    report.duration_ms = timer.elapsed().as_millis() as i64;
+    record_run(analyzer.pool(), trigger, started_at, tokens.len(), &report).await?;
+    Ok(report)
+}
+
+/// Portfolio positions and watchlist items, flagged when an enabled alert covers them.
+async fn collect_refresh_candidates(
+    app: &AppHandle,
+    refreshed: &HashMap<String, DateTime<Utc>>,
+) -> Vec<HolderRefreshCandidate> {
+    let mut tokens: BTreeMap<String, bool> = BTreeMap::new();
+
+    if let Some(portfolio) = app.try_state::<SharedPortfolioData>() {
+        if let Ok(portfolio) = portfolio.lock() {
+            for position in portfolio.positions() {
+                tokens.insert(position.mint, false);
+            }
+        }
+    }
+
+    if let Some(manager) = app.try_state::<SharedWatchlistManager>() {
+        match manager.read().await.list_watchlists().await {
+            Ok(watchlists) => {
+                for item in watchlists.into_iter().flat_map(|w| w.items) {
+                    tokens.insert(item.mint, false);
+                }
+            }
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to load watchlists for holder refresh")
+            }
+        }
+    }
+
+    if let Some(manager) = app.try_state::<SharedAlertManager>() {
+        match manager.read().await.list_alerts().await {
+            Ok(alerts) => {
+                for alert in alerts {
+                    if !matches!(alert.state, AlertState::Disabled) {
+                        if let Some(has_alert) = tokens.get_mut(&alert.mint) {
+                            *has_alert = true;
+                        }
+                    }
+                }
+            }
+            Err(err) => tracing::warn!(error = %err, "failed to load alerts for holder refresh"),
+        }
+    }
+
+    tokens
+        .into_iter()
+        .filter(|(token, _)| !token.is_empty())
+        .map(|(token_address, has_active_alert)| HolderRefreshCandidate {
+            last_refreshed_at: refreshed.get(&token_address).copied(),
+            token_address,
+            has_active_alert,
+        })
+        .collect()
+}
+
+/// One scheduler tick: refreshes the stale portfolio and watchlist tokens within the
+/// configured per-run cap. Returns `None` when the scheduler is disabled.
+pub async fn run_scheduled_holder_refresh(
+    app: &AppHandle,
+) -> Result<Option<HolderRefreshReport>, HolderError> {
+    let Some(analyzer) = app.try_state::<SharedHolderAnalyzer>() else {
+        return Ok(None);
+    };
+    let analyzer = analyzer.read().await;
+    let config = get_holder_refresh_settings(analyzer.pool()).await?;
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    let refreshed = last_refreshed(analyzer.pool()).await?;
+    let candidates = collect_refresh_candidates(app, &refreshed).await;
+    let (tokens, deferred) = plan_holder_refresh(candidates, Utc::now(), &config);
+    if tokens.is_empty() {
+        return Ok(None);
+    }
+
+    refresh_holder_batch(&analyzer, app, &tokens, "scheduled", deferred)
+        .await
+        .map(Some)
+}
+
+pub async fn holder_refresh_interval(app: &AppHandle) -> std::time::Duration {
+    let minutes = match app.try_state::<SharedHolderAnalyzer>() {
+        Some(analyzer) => get_holder_refresh_settings(analyzer.read().await.pool())
+            .await
+            .map(|config| config.check_interval_minutes)
+            .unwrap_or(HolderRefreshConfig::default().check_interval_minutes),
+        None => HolderRefreshConfig::default().check_interval_minutes,
+    };
+    std::time::Duration::from_secs(minutes * 60)
+}
+
+#[tauri::command]
+pub async fn refresh_holder_data(
+    tokens: Vec<String>,
+    analyzer: State<'_, SharedHolderAnalyzer>,
+    app: AppHandle,
+) -> Result<HolderRefreshReport, String> {
+    let mut unique: Vec<String> = Vec::new();
+    for token in tokens {
+        let token = token.trim().to_string();
+        if !token.is_empty() && !unique.contains(&token) {
+            unique.push(token);
+        }
+    }
+    if unique.len() > MAX_BATCH_TOKENS {
+        return Err(format!(
+            "at most {MAX_BATCH_TOKENS} tokens can be refreshed at once"
+        ));
+    }
+
+    let analyzer = analyzer.read().await;
+    refresh_holder_batch(&analyzer, &app, &unique, "manual", 0)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_holder_refresh_config(
+    analyzer: State<'_, SharedHolderAnalyzer>,
+) -> Result<HolderRefreshConfig, String> {
+    let analyzer = analyzer.read().await;
+    get_holder_refresh_settings(analyzer.pool())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_holder_refresh_config(
+    config: HolderRefreshConfig,
+    analyzer: State<'_, SharedHolderAnalyzer>,
+) -> Result<HolderRefreshConfig, String> {
+    let analyzer = analyzer.read().await;
+    save_holder_refresh_settings(analyzer.pool(), &config)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(config)
+}
+
+#[tauri::command]
+pub async fn get_holder_refresh_runs(
+    limit: Option<i64>,
+    analyzer: State<'_, SharedHolderAnalyzer>,
+) -> Result<Vec<HolderRefreshRun>, String> {
+    let analyzer = analyzer.read().await;
+    sqlx::query_as::<_, HolderRefreshRun>(
+        "SELECT * FROM holder_refresh_runs ORDER BY id DESC LIMIT ?1",
+    )
+    .bind(limit.unwrap_or(50).clamp(1, 500))
+    .fetch_all(analyzer.pool())
+    .await
+    .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(token: &str, alert: bool, age_minutes: Option<i64>) -> HolderRefreshCandidate {
+        let now = Utc::now();
+        HolderRefreshCandidate {
+            token_address: token.to_string(),
+            has_active_alert: alert,
+            last_refreshed_at: age_minutes.map(|m| now - Duration::minutes(m)),
+        }
+    }
+
+    #[test]
+    fn fresh_tokens_are_skipped() {
+        let config = HolderRefreshConfig::default();
+        let (selected, deferred) = plan_holder_refresh(
+            vec![
+                candidate("fresh", true, Some(30)),
+                candidate("stale", false, Some(300)),
+            ],
+            Utc::now(),
+            &config,
+        );
+        assert_eq!(selected, vec!["stale".to_string()]);
+        assert_eq!(deferred, 0);
+    }
+
+    #[test]
+    fn alerted_then_oldest_tokens_go_first_within_cap() {
+        let config = HolderRefreshConfig {
+            max_tokens_per_run: 3,
+            ..HolderRefreshConfig::default()
+        };
+        let (selected, deferred) = plan_holder_refresh(
+            vec![
+                candidate("old", false, Some(1_000)),
+                candidate("older", false, Some(2_000)),
+                candidate("never", false, None),
+                candidate("alerted", true, Some(200)),
+            ],
+            Utc::now(),
+            &config,
+        );
+        assert_eq!(selected, vec!["alerted", "never", "older"]);
+        assert_eq!(deferred, 1);
+    }
+
+    #[test]
+    fn config_bounds_are_validated() {
+        assert!(HolderRefreshConfig::default().validate().is_ok());
+        let too_eager = HolderRefreshConfig {
+            staleness_minutes: 1,
+            ..HolderRefreshConfig::default()
+        };
+        assert!(too_eager.validate().is_err());
+        let unbounded = HolderRefreshConfig {
+            max_tokens_per_run: MAX_BATCH_TOKENS + 1,
+            ..HolderRefreshConfig::default()
+        };
+        assert!(unbounded.validate().is_err());
+    }
+}
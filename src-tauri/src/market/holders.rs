@@ -1,13 +1,20 @@
-use chrono::Utc;
+use crate::config::data_sources::{effective_mode, fetch_from_source, DataModule, DataSourceMode};
+use crate::config::token_registry::{registered_token, TokenEntryKind, TokenRegistryEntry};
+use crate::notifications::router::SharedNotificationRouter;
+use crate::webhooks::{publish_event, EventSeverity, WebhookEvent, WebhookEventType};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{Pool, Row, Sqlite, SqlitePool};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Manager, State};
 use tokio::sync::RwLock;
 
 const HOLDERS_DB_FILE: &str = "holders.db";
+/// Distribution fetches closer together than this reuse the latest snapshot.
+const SNAPSHOT_INTERVAL_MINUTES: i64 = 60;
+const SNAPSHOT_DIFF_TOP_N: usize = 10;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -18,6 +25,8 @@ pub struct HolderInfo {
     pub is_known_wallet: bool,
     pub wallet_label: Option<String>,
     pub rank: u32,
+    #[serde(default)]
+    pub behavior: Option<crate::insiders::BehaviorTag>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,6 +116,42 @@ pub struct CommunityVotes {
     pub trust_score: f64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolderBalanceChange {
+    pub address: String,
+    pub balance_before: f64,
+    pub balance_after: f64,
+    pub change: f64,
+    pub is_smart_money: bool,
+    #[serde(default)]
+    pub behavior: Option<crate::insiders::BehaviorTag>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolderSnapshotDiff {
+    pub token_address: String,
+    pub from_snapshot: String,
+    pub to_snapshot: String,
+    pub new_entrants: Vec<HolderBalanceChange>,
+    pub exits: Vec<HolderBalanceChange>,
+    pub top_accumulators: Vec<HolderBalanceChange>,
+    pub top_distributors: Vec<HolderBalanceChange>,
+    pub total_accumulated: f64,
+    pub total_distributed: f64,
+    /// "accumulation", "distribution" or "neutral"
+    pub net_flow: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmartMoneyEntryAlert {
+    pub token_address: String,
+    pub wallets: Vec<HolderBalanceChange>,
+    pub detected_at: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HolderDataExport {
@@ -136,6 +181,8 @@ pub enum HolderError {
     Serialization(#[from] serde_json::Error),
     #[error("token not found: {0}")]
     NotFound(String),
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
     #[error("internal error: {0}")]
     Internal(String),
 }
@@ -162,6 +209,10 @@ impl HolderAnalyzer {
         Self { pool }
     }
 
+    pub(crate) fn pool(&self) -> &Pool<Sqlite> {
+        &self.pool
+    }
+
     async fn initialize(&self) -> Result<(), HolderError> {
         // Create holders table
         sqlx::query(
@@ -279,11 +330,29 @@ impl HolderAnalyzer {
         .execute(&self.pool)
         .await?;
 
+        // Create holder snapshots table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS holder_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                token_address TEXT NOT NULL,
+                holder_address TEXT NOT NULL,
+                balance REAL NOT NULL,
+                percentage REAL NOT NULL,
+                snapshot_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
         // Create indexes
         sqlx::query(
             r#"
             CREATE INDEX IF NOT EXISTS idx_holders_token 
             ON holders(token_address);
+            CREATE INDEX IF NOT EXISTS idx_holder_snapshots_token 
+            ON holder_snapshots(token_address, snapshot_at);
             CREATE INDEX IF NOT EXISTS idx_holder_trends_token 
             ON holder_trends(token_address, timestamp);
             CREATE INDEX IF NOT EXISTS idx_large_transfers_token 
@@ -293,6 +362,8 @@ impl HolderAnalyzer {
         .execute(&self.pool)
         .await?;
 
+        super::holder_refresh::init_holder_refresh_tables(&self.pool).await?;
+
         Ok(())
     }
 
@@ -326,16 +397,23 @@ impl HolderAnalyzer {
         &self,
         token_address: &str,
     ) -> Result<HolderDistribution, HolderError> {
-        // In production, this would fetch from Solana RPC or indexer
-        // For now, we'll generate mock data with realistic distribution
-
-        // Generate mock holder data
-        let mut holders = self.generate_mock_holders(token_address);
+        // In production, this would fetch from Solana RPC or indexer;
+        // until then live mode has no holders and only fixtures carry data
+        let mut holders = fetch_from_source(
+            DataModule::Market,
+            &format!("holders_{token_address}"),
+            || async { Ok(Vec::new()) },
+            || Ok(self.generate_mock_holders(token_address)),
+        )
+        .await
+        .map_err(HolderError::Internal)?;
         
         // Calculate percentages
         let total_balance: f64 = holders.iter().map(|h| h.balance).sum();
-        for holder in &mut holders {
-            holder.percentage = (holder.balance / total_balance) * 100.0;
+        if total_balance > 0.0 {
+            for holder in &mut holders {
+                holder.percentage = (holder.balance / total_balance) * 100.0;
+            }
         }
 
         // Sort by balance descending
@@ -418,6 +496,7 @@ impl HolderAnalyzer {
                 is_known_wallet,
                 wallet_label,
                 rank: 0, // Will be assigned
+                behavior: None,
             });
         }
 
@@ -430,6 +509,7 @@ impl HolderAnalyzer {
                 is_known_wallet: false,
                 wallet_label: None,
                 rank: 0,
+                behavior: None,
             });
         }
 
@@ -442,6 +522,7 @@ impl HolderAnalyzer {
                 is_known_wallet: false,
                 wallet_label: None,
                 rank: 0,
+                behavior: None,
             });
         }
 
@@ -453,6 +534,10 @@ impl HolderAnalyzer {
         token_address: &str,
         days: u32,
     ) -> Result<Vec<HolderTrend>, HolderError> {
+        if effective_mode(DataModule::Market) != DataSourceMode::Mock {
+            return Ok(Vec::new());
+        }
+
         use rand::Rng;
         let mut rng = rand::thread_rng();
         
@@ -482,6 +567,10 @@ impl HolderAnalyzer {
         token_address: &str,
         days: u32,
     ) -> Result<Vec<LargeTransfer>, HolderError> {
+        if effective_mode(DataModule::Market) != DataSourceMode::Mock {
+            return Ok(Vec::new());
+        }
+
         use rand::Rng;
         let mut rng = rand::thread_rng();
         
@@ -513,10 +602,35 @@ impl HolderAnalyzer {
     pub async fn get_token_metadata(
         &self,
         token_address: &str,
+    ) -> Result<TokenMetadata, HolderError> {
+        // The local registry answers before any provider is asked
+        let registered = registered_token(token_address);
+        if let Some(entry) = registered.as_ref().filter(|e| e.kind == TokenEntryKind::Custom) {
+            return Ok(custom_token_metadata(entry));
+        }
+
+        let mut metadata = self.fetch_token_metadata(token_address).await?;
+        if let Some(entry) = &registered {
+            entry.apply(
+                &mut metadata.symbol,
+                Some(&mut metadata.name),
+                Some(&mut metadata.decimals),
+                Some(&mut metadata.logo_uri),
+            );
+        }
+        Ok(metadata)
+    }
+
+    async fn fetch_token_metadata(
+        &self,
+        token_address: &str,
     ) -> Result<TokenMetadata, HolderError> {
         // In production, fetch from Solana RPC
-        // For now, return mock data
-        
+        // For now, metadata only exists as fixtures
+        if effective_mode(DataModule::Market) != DataSourceMode::Mock {
+            return Err(HolderError::NotFound(token_address.to_string()));
+        }
+
         Ok(TokenMetadata {
             address: token_address.to_string(),
             name: "Example Token".to_string(),
@@ -605,6 +719,165 @@ impl HolderAnalyzer {
         })
     }
 
+    async fn latest_snapshot_at(
+        &self,
+        token_address: &str,
+        at_or_before: Option<&str>,
+    ) -> Result<Option<String>, HolderError> {
+        let row = sqlx::query(
+            r#"
+            SELECT MAX(snapshot_at) AS snapshot_at FROM holder_snapshots
+            WHERE token_address = ?1 AND (?2 IS NULL OR snapshot_at <= ?2)
+            "#,
+        )
+        .bind(token_address)
+        .bind(at_or_before)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.try_get::<Option<String>, _>("snapshot_at")?)
+    }
+
+    async fn earliest_snapshot_at(&self, token_address: &str) -> Result<Option<String>, HolderError> {
+        let row = sqlx::query(
+            "SELECT MIN(snapshot_at) AS snapshot_at FROM holder_snapshots WHERE token_address = ?1",
+        )
+        .bind(token_address)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.try_get::<Option<String>, _>("snapshot_at")?)
+    }
+
+    async fn load_snapshot(
+        &self,
+        token_address: &str,
+        snapshot_at: &str,
+    ) -> Result<HashMap<String, f64>, HolderError> {
+        let rows = sqlx::query(
+            "SELECT holder_address, balance FROM holder_snapshots WHERE token_address = ?1 AND snapshot_at = ?2",
+        )
+        .bind(token_address)
+        .bind(snapshot_at)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| Ok((row.try_get("holder_address")?, row.try_get("balance")?)))
+            .collect()
+    }
+
+    /// Stores the distribution's top holders unless a recent snapshot already exists.
+    /// Returns whether a snapshot was written.
+    pub async fn record_snapshot(&self, distribution: &HolderDistribution) -> Result<bool, HolderError> {
+        let previous = self.latest_snapshot_at(&distribution.token_address, None).await?;
+        let now = Utc::now();
+        let is_recent = previous
+            .as_deref()
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            .is_some_and(|at| now - at.with_timezone(&Utc) < chrono::Duration::minutes(SNAPSHOT_INTERVAL_MINUTES));
+        if is_recent || distribution.top_holders.is_empty() {
+            return Ok(false);
+        }
+
+        let snapshot_at = now.to_rfc3339();
+        let previous_count: Option<i64> = sqlx::query_scalar(
+            "SELECT holder_count FROM holder_trends WHERE token_address = ?1 ORDER BY timestamp DESC LIMIT 1",
+        )
+        .bind(&distribution.token_address)
+        .fetch_optional(&self.pool)
+        .await?;
+        let total = distribution.total_holders as i64;
+        let existing = previous_count.map_or(total, |count| count.min(total));
+
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"
+            INSERT INTO holder_trends (token_address, timestamp, holder_count, new_holders, existing_holders)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+        )
+        .bind(&distribution.token_address)
+        .bind(&snapshot_at)
+        .bind(total)
+        .bind(total - existing)
+        .bind(existing)
+        .execute(&mut *tx)
+        .await?;
+        for holder in &distribution.top_holders {
+            sqlx::query(
+                r#"
+                INSERT INTO holder_snapshots (token_address, holder_address, balance, percentage, snapshot_at)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                "#,
+            )
+            .bind(&distribution.token_address)
+            .bind(&holder.address)
+            .bind(holder.balance)
+            .bind(holder.percentage)
+            .bind(&snapshot_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Recorded holder counts between two RFC 3339 timestamps, oldest first.
+    pub async fn get_holder_count_series(
+        &self,
+        token_address: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<(String, i64)>, HolderError> {
+        let rows = sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT timestamp, holder_count FROM holder_trends
+            WHERE token_address = ?1 AND timestamp >= ?2 AND timestamp <= ?3
+            ORDER BY timestamp ASC
+            "#,
+        )
+        .bind(token_address)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    /// Compares the latest snapshots taken at or before `from` and `to`. When nothing
+    /// predates `from`, the token's earliest snapshot is used instead.
+    pub async fn diff_holder_snapshots(
+        &self,
+        token_address: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<HolderSnapshotDiff, HolderError> {
+        let from = normalize_snapshot_time(from)?;
+        let to = normalize_snapshot_time(to)?;
+        if from >= to {
+            return Err(HolderError::InvalidInput("from must be before to".to_string()));
+        }
+
+        let from_snapshot = match self.latest_snapshot_at(token_address, Some(&from)).await? {
+            Some(at) => at,
+            None => self
+                .earliest_snapshot_at(token_address)
+                .await?
+                .ok_or_else(|| HolderError::NotFound(format!("no holder snapshots for {token_address}")))?,
+        };
+        let to_snapshot = self
+            .latest_snapshot_at(token_address, Some(&to))
+            .await?
+            .filter(|at| *at > from_snapshot)
+            .ok_or_else(|| HolderError::NotFound(format!("no holder snapshot after {from_snapshot}")))?;
+
+        let before = self.load_snapshot(token_address, &from_snapshot).await?;
+        let after = self.load_snapshot(token_address, &to_snapshot).await?;
+        Ok(diff_snapshots(token_address, &from_snapshot, &to_snapshot, &before, &after))
+    }
+
     pub async fn export_holder_data(
         &self,
         token_address: &str,
@@ -639,6 +912,205 @@ impl HolderAnalyzer {
     }
 }
 
+fn normalize_snapshot_time(value: &str) -> Result<String, HolderError> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(at.with_timezone(&Utc).to_rfc3339());
+    }
+    // Plain dates cover the whole day
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(23, 59, 59))
+        .map(|at| at.and_utc().to_rfc3339())
+        .ok_or_else(|| HolderError::InvalidInput(format!("invalid date: {value}")))
+}
+
+fn balance_change(address: &str, before: f64, after: f64) -> HolderBalanceChange {
+    HolderBalanceChange {
+        address: address.to_string(),
+        balance_before: before,
+        balance_after: after,
+        change: after - before,
+        is_smart_money: false,
+        behavior: None,
+    }
+}
+
+/// Snapshots only cover the top holders, so an exit can also mean a wallet fell out of that set.
+pub fn diff_snapshots(
+    token_address: &str,
+    from_snapshot: &str,
+    to_snapshot: &str,
+    before: &HashMap<String, f64>,
+    after: &HashMap<String, f64>,
+) -> HolderSnapshotDiff {
+    let mut new_entrants = Vec::new();
+    let mut exits = Vec::new();
+    let mut changes = Vec::new();
+
+    for (address, &balance) in after {
+        match before.get(address) {
+            None => new_entrants.push(balance_change(address, 0.0, balance)),
+            Some(&previous) if previous != balance => changes.push(balance_change(address, previous, balance)),
+            Some(_) => {}
+        }
+    }
+    for (address, &balance) in before {
+        if !after.contains_key(address) {
+            exits.push(balance_change(address, balance, 0.0));
+        }
+    }
+
+    let by_change_desc = |a: &HolderBalanceChange, b: &HolderBalanceChange| {
+        b.change.partial_cmp(&a.change).unwrap_or(std::cmp::Ordering::Equal)
+    };
+    new_entrants.sort_by(by_change_desc);
+    exits.sort_by(|a, b| by_change_desc(b, a));
+
+    let total_accumulated: f64 = changes
+        .iter()
+        .chain(new_entrants.iter())
+        .filter(|c| c.change > 0.0)
+        .map(|c| c.change)
+        .sum();
+    let total_distributed: f64 = changes
+        .iter()
+        .chain(exits.iter())
+        .filter(|c| c.change < 0.0)
+        .map(|c| -c.change)
+        .sum();
+
+    let mut top_accumulators: Vec<_> = changes
+        .iter()
+        .chain(new_entrants.iter())
+        .filter(|c| c.change > 0.0)
+        .cloned()
+        .collect();
+    top_accumulators.sort_by(by_change_desc);
+    top_accumulators.truncate(SNAPSHOT_DIFF_TOP_N);
+
+    let mut top_distributors: Vec<_> = changes
+        .iter()
+        .chain(exits.iter())
+        .filter(|c| c.change < 0.0)
+        .cloned()
+        .collect();
+    top_distributors.sort_by(|a, b| by_change_desc(b, a));
+    top_distributors.truncate(SNAPSHOT_DIFF_TOP_N);
+
+    let flow_total = total_accumulated + total_distributed;
+    let net_flow = if flow_total == 0.0 || (total_accumulated - total_distributed).abs() / flow_total < 0.1 {
+        "neutral"
+    } else if total_accumulated > total_distributed {
+        "accumulation"
+    } else {
+        "distribution"
+    };
+
+    HolderSnapshotDiff {
+        token_address: token_address.to_string(),
+        from_snapshot: from_snapshot.to_string(),
+        to_snapshot: to_snapshot.to_string(),
+        new_entrants,
+        exits,
+        top_accumulators,
+        top_distributors,
+        total_accumulated,
+        total_distributed,
+        net_flow: net_flow.to_string(),
+    }
+}
+
+/// Flags smart-money and labels every wallet in the diff using the wallet monitor, when it's running.
+async fn annotate_diff(diff: &mut HolderSnapshotDiff) {
+    let Ok(state) = crate::insiders::wallet_monitor::require_state() else {
+        return;
+    };
+
+    let smart_money: HashSet<String> = match state.smart_money_detector.get_smart_money_wallets().await {
+        Ok(wallets) => wallets
+            .into_iter()
+            .filter(|w| w.is_smart_money)
+            .map(|w| w.wallet_address)
+            .collect(),
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to load smart money wallets for holder diff");
+            HashSet::new()
+        }
+    };
+
+    let mut addresses: Vec<String> = diff
+        .new_entrants
+        .iter()
+        .chain(diff.exits.iter())
+        .chain(diff.top_accumulators.iter())
+        .chain(diff.top_distributors.iter())
+        .map(|c| c.address.clone())
+        .collect();
+    addresses.sort();
+    addresses.dedup();
+    let tags = state.behavior_classifier.tags_for(&addresses).await;
+
+    for change in diff
+        .new_entrants
+        .iter_mut()
+        .chain(diff.exits.iter_mut())
+        .chain(diff.top_accumulators.iter_mut())
+        .chain(diff.top_distributors.iter_mut())
+    {
+        change.is_smart_money = smart_money.contains(&change.address);
+        change.behavior = tags.get(&change.address).cloned();
+    }
+}
+
+async fn notify_smart_money_entry(app: &AppHandle, diff: &HolderSnapshotDiff) {
+    let wallets: Vec<HolderBalanceChange> = diff
+        .new_entrants
+        .iter()
+        .filter(|c| c.is_smart_money)
+        .cloned()
+        .collect();
+    if wallets.is_empty() {
+        return;
+    }
+
+    let alert = SmartMoneyEntryAlert {
+        token_address: diff.token_address.clone(),
+        wallets,
+        detected_at: Utc::now().to_rfc3339(),
+    };
+    let _ = app.emit_all("smart_money_holder_entry", &alert);
+
+    let message = format!(
+        "{} smart money wallet(s) entered the top holders of {} with {:.0} tokens",
+        alert.wallets.len(),
+        alert.token_address,
+        alert.wallets.iter().map(|w| w.balance_after).sum::<f64>()
+    );
+    publish_event(
+        app,
+        WebhookEvent::new(
+            WebhookEventType::SmartMoney,
+            EventSeverity::Warning,
+            "Smart money entry",
+            message.clone(),
+        )
+        .with_token(alert.token_address.clone())
+        .with_data(serde_json::to_value(&alert).unwrap_or_default()),
+    );
+
+    let Some(router) = app.try_state::<SharedNotificationRouter>() else {
+        return;
+    };
+    if let Err(err) = router
+        .read()
+        .await
+        .send_broadcast_notification(&alert.token_address, "Smart money entry", &message)
+        .await
+    {
+        tracing::warn!(error = %err, "failed to deliver smart money entry notification");
+    }
+}
+
 fn holder_db_path(app: &AppHandle) -> Result<PathBuf, HolderError> {
     let mut path = app
         .path_resolver()
@@ -652,19 +1124,85 @@ fn holder_db_path(app: &AppHandle) -> Result<PathBuf, HolderError> {
     Ok(path)
 }
 
+/// Fetches a fresh distribution, tags holder behavior and records a snapshot, notifying
+/// on smart-money entries. Shared by the command and the batch refresher.
+pub async fn refresh_holder_distribution(
+    analyzer: &HolderAnalyzer,
+    app: &AppHandle,
+    token_address: &str,
+) -> Result<HolderDistribution, HolderError> {
+    let mut distribution = analyzer.get_holder_distribution(token_address).await?;
+
+    // Behavior labels are optional; the wallet monitor may not be running yet
+    if let Ok(state) = crate::insiders::wallet_monitor::require_state() {
+        let addresses: Vec<String> = distribution
+            .top_holders
+            .iter()
+            .map(|h| h.address.clone())
+            .collect();
+        let tags = state.behavior_classifier.tags_for(&addresses).await;
+        for holder in distribution.top_holders.iter_mut() {
+            holder.behavior = tags.get(&holder.address).cloned();
+        }
+    }
+
+    // Each new snapshot is compared against the previous one to catch smart-money entries
+    let previous = analyzer.latest_snapshot_at(token_address, None).await.ok().flatten();
+    match analyzer.record_snapshot(&distribution).await {
+        Ok(true) => {
+            if let Some(previous) = previous {
+                match analyzer.load_snapshot(token_address, &previous).await {
+                    Ok(before) => {
+                        let after = distribution
+                            .top_holders
+                            .iter()
+                            .map(|h| (h.address.clone(), h.balance))
+                            .collect();
+                        let mut diff =
+                            diff_snapshots(token_address, &previous, &distribution.updated_at, &before, &after);
+                        annotate_diff(&mut diff).await;
+                        notify_smart_money_entry(app, &diff).await;
+                    }
+                    Err(err) => tracing::warn!(error = %err, "failed to load previous holder snapshot"),
+                }
+            }
+        }
+        Ok(false) => {}
+        Err(err) => tracing::warn!(error = %err, "failed to record holder snapshot"),
+    }
+
+    Ok(distribution)
+}
+
 // Tauri commands
 #[tauri::command]
 pub async fn get_holder_distribution(
     token_address: String,
     analyzer: State<'_, SharedHolderAnalyzer>,
+    app: AppHandle,
 ) -> Result<HolderDistribution, String> {
     let analyzer = analyzer.read().await;
-    analyzer
-        .get_holder_distribution(&token_address)
+    refresh_holder_distribution(&analyzer, &app, &token_address)
         .await
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub async fn diff_holder_snapshots(
+    token: String,
+    from: String,
+    to: String,
+    analyzer: State<'_, SharedHolderAnalyzer>,
+) -> Result<HolderSnapshotDiff, String> {
+    let analyzer = analyzer.read().await;
+    let mut diff = analyzer
+        .diff_holder_snapshots(&token, &from, &to)
+        .await
+        .map_err(|e| e.to_string())?;
+    annotate_diff(&mut diff).await;
+    Ok(diff)
+}
+
 #[tauri::command]
 pub async fn get_holder_trends(
     token_address: String,
@@ -691,6 +1229,30 @@ pub async fn get_large_transfers(
         .map_err(|e| e.to_string())
 }
 
+/// Metadata for a hand-registered token. Anything the registry doesn't hold is
+/// left blank rather than guessed.
+fn custom_token_metadata(entry: &TokenRegistryEntry) -> TokenMetadata {
+    TokenMetadata {
+        address: entry.mint.clone(),
+        name: entry.name.clone().unwrap_or_default(),
+        symbol: entry.symbol.clone().unwrap_or_default(),
+        decimals: entry.decimals.unwrap_or_default(),
+        total_supply: 0.0,
+        mint_authority: None,
+        freeze_authority: None,
+        update_authority: None,
+        creation_date: String::new(),
+        creator: String::new(),
+        logo_uri: entry.logo_path.clone(),
+        website: None,
+        twitter: None,
+        telegram: None,
+        discord: None,
+        description: None,
+        token_program: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA".to_string(),
+    }
+}
+
 #[tauri::command]
 pub async fn get_token_metadata(
     token_address: String,
@@ -739,3 +1301,47 @@ pub async fn export_metadata_snapshot(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(entries: &[(&str, f64)]) -> HashMap<String, f64> {
+        entries.iter().map(|(a, b)| (a.to_string(), *b)).collect()
+    }
+
+    #[test]
+    fn diff_detects_entrants_exits_and_flows() {
+        let before = snapshot(&[("whale", 1_000.0), ("seller", 500.0), ("leaver", 200.0), ("flat", 50.0)]);
+        let after = snapshot(&[("whale", 1_600.0), ("seller", 100.0), ("newbie", 300.0), ("flat", 50.0)]);
+
+        let diff = diff_snapshots("TOKEN", "a", "b", &before, &after);
+
+        assert_eq!(diff.new_entrants.len(), 1);
+        assert_eq!(diff.new_entrants[0].address, "newbie");
+        assert_eq!(diff.exits.len(), 1);
+        assert_eq!(diff.exits[0].change, -200.0);
+        assert_eq!(diff.top_accumulators[0].address, "whale");
+        assert_eq!(diff.top_accumulators[0].change, 600.0);
+        assert_eq!(diff.top_distributors[0].address, "seller");
+        assert_eq!(diff.total_accumulated, 900.0);
+        assert_eq!(diff.total_distributed, 600.0);
+        assert_eq!(diff.net_flow, "accumulation");
+    }
+
+    #[test]
+    fn small_imbalances_are_neutral() {
+        let before = snapshot(&[("a", 100.0), ("b", 100.0)]);
+        let after = snapshot(&[("a", 150.0), ("b", 55.0)]);
+        assert_eq!(diff_snapshots("TOKEN", "a", "b", &before, &after).net_flow, "neutral");
+    }
+
+    #[test]
+    fn snapshot_times_accept_dates() {
+        assert_eq!(
+            normalize_snapshot_time("2024-03-01").unwrap(),
+            "2024-03-01T23:59:59+00:00"
+        );
+        assert!(normalize_snapshot_time("yesterday").is_err());
+    }
+}
@@ -0,0 +1,967 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use crate::notifications::router::SharedNotificationRouter;
+
+const MACRO_EVENTS_DB_FILE: &str = "macro_events.db";
+const REFRESH_INTERVAL_SECS: u64 = 6 * 3600;
+const WARNING_CHECK_INTERVAL_SECS: u64 = 300;
+const MAX_WINDOW_DAYS: i64 = 90;
+pub const MACRO_EVENT_WARNING_EVENT: &str = "macro_event_warning";
+
+/// Release names that move crypto markets even when a feed doesn't rate them.
+const HIGH_IMPACT_KEYWORDS: &[&str] = &[
+    "fomc",
+    "fed interest rate",
+    "federal funds rate",
+    "cpi",
+    "consumer price index",
+    "nonfarm",
+    "non-farm",
+    "pce",
+    "powell",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum MacroEventError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("feed request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("source not found: {0}")]
+    NotFound(String),
+    #[error("invalid source: {0}")]
+    Invalid(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum MacroImpact {
+    Low,
+    Medium,
+    High,
+}
+
+impl MacroImpact {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MacroImpact::Low => "low",
+            MacroImpact::Medium => "medium",
+            MacroImpact::High => "high",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "low" | "1" => Some(MacroImpact::Low),
+            "medium" | "moderate" | "2" => Some(MacroImpact::Medium),
+            "high" | "3" => Some(MacroImpact::High),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MacroFeedFormat {
+    Ics,
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MacroEventSource {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub format: MacroFeedFormat,
+    pub enabled: bool,
+    /// Offset applied to ICS times that carry a TZID or no zone at all.
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+    pub last_refreshed_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MacroEventSourceInput {
+    pub id: Option<String>,
+    pub name: String,
+    pub url: String,
+    pub format: MacroFeedFormat,
+    pub enabled: bool,
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MacroEvent {
+    pub id: String,
+    pub source_id: String,
+    pub title: String,
+    pub country: Option<String>,
+    pub impact: MacroImpact,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub forecast: Option<String>,
+    pub previous: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MacroGuardSettings {
+    /// Hold automated trading (DCA, copy trading) around qualifying events.
+    pub auto_pause_enabled: bool,
+    pub min_impact: MacroImpact,
+    pub pause_before_minutes: i64,
+    pub pause_after_minutes: i64,
+    /// How far ahead to notify about upcoming qualifying events.
+    pub warn_before_minutes: i64,
+}
+
+impl Default for MacroGuardSettings {
+    fn default() -> Self {
+        Self {
+            auto_pause_enabled: false,
+            min_impact: MacroImpact::High,
+            pause_before_minutes: 30,
+            pause_after_minutes: 60,
+            warn_before_minutes: 120,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationBlackout {
+    pub event: MacroEvent,
+    pub paused_from: DateTime<Utc>,
+    pub paused_until: DateTime<Utc>,
+}
+
+/// Rows in a JSON feed. Field names follow the common economic-calendar exports.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonFeedEvent {
+    id: Option<String>,
+    #[serde(alias = "name", alias = "event")]
+    title: String,
+    #[serde(alias = "date", alias = "time", alias = "start")]
+    starts_at: DateTime<Utc>,
+    #[serde(alias = "end")]
+    ends_at: Option<DateTime<Utc>>,
+    #[serde(alias = "currency")]
+    country: Option<String>,
+    #[serde(alias = "importance")]
+    impact: Option<String>,
+    forecast: Option<String>,
+    previous: Option<String>,
+}
+
+pub fn infer_impact(title: &str, declared: Option<&str>) -> MacroImpact {
+    let lower = title.to_lowercase();
+    let by_keyword = HIGH_IMPACT_KEYWORDS
+        .iter()
+        .any(|keyword| lower.contains(keyword));
+    let declared = declared.and_then(MacroImpact::parse);
+
+    match (declared, by_keyword) {
+        (_, true) => MacroImpact::High,
+        (Some(impact), false) => impact,
+        (None, false) => MacroImpact::Medium,
+    }
+}
+
+pub fn parse_json_feed(source_id: &str, body: &str) -> Result<Vec<MacroEvent>, MacroEventError> {
+    let rows: Vec<JsonFeedEvent> = serde_json::from_str(body)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| MacroEvent {
+            id: format!(
+                "{}:{}",
+                source_id,
+                row.id
+                    .unwrap_or_else(|| format!("{}@{}", row.title, row.starts_at.timestamp()))
+            ),
+            source_id: source_id.to_string(),
+            impact: infer_impact(&row.title, row.impact.as_deref()),
+            title: row.title,
+            country: row.country,
+            starts_at: row.starts_at,
+            ends_at: row.ends_at,
+            forecast: row.forecast,
+            previous: row.previous,
+        })
+        .collect())
+}
+
+fn parse_ics_time(value: &str, params: &str, offset: FixedOffset) -> Option<DateTime<Utc>> {
+    if let Some(utc) = value.strip_suffix('Z') {
+        return NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S")
+            .ok()
+            .map(|naive| Utc.from_utc_datetime(&naive));
+    }
+
+    let naive = if params.contains("VALUE=DATE") || value.len() == 8 {
+        NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()?
+            .and_hms_opt(0, 0, 0)?
+    } else {
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?
+    };
+
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn unescape_ics(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+/// Minimal VEVENT reader covering what calendar exports use: UID, SUMMARY, DTSTART/DTEND,
+/// LOCATION as the country and an optional X-IMPACT / PRIORITY rating.
+pub fn parse_ics_feed(
+    source_id: &str,
+    body: &str,
+    utc_offset_minutes: i32,
+) -> Result<Vec<MacroEvent>, MacroEventError> {
+    let offset = FixedOffset::east_opt(utc_offset_minutes * 60)
+        .ok_or_else(|| MacroEventError::Invalid("utc offset out of range".to_string()))?;
+
+    // Unfold continuation lines first.
+    let mut lines: Vec<String> = Vec::new();
+    for raw in body.lines() {
+        let raw = raw.trim_end_matches('\r');
+        if (raw.starts_with(' ') || raw.starts_with('\t')) && !lines.is_empty() {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(&raw[1..]);
+            }
+        } else {
+            lines.push(raw.to_string());
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut current: Option<Vec<(String, String, String)>> = None;
+    for line in lines {
+        match line.as_str() {
+            "BEGIN:VEVENT" => current = Some(Vec::new()),
+            "END:VEVENT" => {
+                let Some(props) = current.take() else { continue };
+                let get = |name: &str| {
+                    props
+                        .iter()
+                        .find(|(key, _, _)| key == name)
+                        .map(|(_, params, value)| (params.as_str(), value.as_str()))
+                };
+
+                let Some(title) = get("SUMMARY").map(|(_, v)| unescape_ics(v)) else {
+                    continue;
+                };
+                let Some(starts_at) = get("DTSTART").and_then(|(p, v)| parse_ics_time(v, p, offset))
+                else {
+                    continue;
+                };
+                let ends_at = get("DTEND").and_then(|(p, v)| parse_ics_time(v, p, offset));
+                let declared = get("X-IMPACT").map(|(_, v)| v.to_string()).or_else(|| {
+                    // PRIORITY runs 1 (highest) to 9 (lowest).
+                    get("PRIORITY").and_then(|(_, v)| match v.trim().parse::<u8>().ok()? {
+                        1..=3 => Some("high".to_string()),
+                        4..=6 => Some("medium".to_string()),
+                        7..=9 => Some("low".to_string()),
+                        _ => None,
+                    })
+                });
+                let uid = get("UID")
+                    .map(|(_, v)| v.to_string())
+                    .unwrap_or_else(|| format!("{}@{}", title, starts_at.timestamp()));
+
+                events.push(MacroEvent {
+                    id: format!("{}:{}", source_id, uid),
+                    source_id: source_id.to_string(),
+                    impact: infer_impact(&title, declared.as_deref()),
+                    country: get("LOCATION").map(|(_, v)| unescape_ics(v)).filter(|v| !v.is_empty()),
+                    title,
+                    starts_at,
+                    ends_at,
+                    forecast: None,
+                    previous: None,
+                });
+            }
+            _ => {
+                let Some(props) = current.as_mut() else { continue };
+                let Some((head, value)) = line.split_once(':') else { continue };
+                let (name, params) = head.split_once(';').unwrap_or((head, ""));
+                props.push((name.to_uppercase(), params.to_uppercase(), value.to_string()));
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+/// The qualifying event whose pause window covers `now`, if any.
+pub fn find_blackout(
+    events: &[MacroEvent],
+    settings: &MacroGuardSettings,
+    now: DateTime<Utc>,
+) -> Option<AutomationBlackout> {
+    events
+        .iter()
+        .filter(|event| event.impact >= settings.min_impact)
+        .filter_map(|event| {
+            let paused_from = event.starts_at - Duration::minutes(settings.pause_before_minutes);
+            let paused_until = event.ends_at.unwrap_or(event.starts_at)
+                + Duration::minutes(settings.pause_after_minutes);
+            (paused_from <= now && now <= paused_until).then(|| AutomationBlackout {
+                event: event.clone(),
+                paused_from,
+                paused_until,
+            })
+        })
+        .max_by_key(|blackout| blackout.paused_until)
+}
+
+pub struct MacroEventManager {
+    pool: Pool<Sqlite>,
+    http: reqwest::Client,
+}
+
+pub type SharedMacroEventManager = Arc<RwLock<MacroEventManager>>;
+
+impl MacroEventManager {
+    pub async fn new(app: &AppHandle) -> Result<Self, MacroEventError> {
+        let db_path = macro_events_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let manager = Self {
+            pool,
+            http: reqwest::Client::new(),
+        };
+        manager.initialize().await?;
+        Ok(manager)
+    }
+
+    async fn initialize(&self) -> Result<(), MacroEventError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS macro_sources (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                url TEXT NOT NULL,
+                format TEXT NOT NULL,
+                enabled INTEGER NOT NULL,
+                utc_offset_minutes INTEGER NOT NULL DEFAULT 0,
+                last_refreshed_at TEXT,
+                last_error TEXT
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS macro_events (
+                id TEXT PRIMARY KEY,
+                source_id TEXT NOT NULL,
+                title TEXT NOT NULL,
+                country TEXT,
+                impact TEXT NOT NULL,
+                starts_at TEXT NOT NULL,
+                ends_at TEXT,
+                forecast TEXT,
+                previous TEXT
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_macro_events_start ON macro_events(starts_at)")
+            .execute(&self.pool)
+            .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS macro_event_warnings (
+                event_id TEXT PRIMARY KEY,
+                sent_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS macro_guard_settings (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                settings TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    fn row_to_source(row: &SqliteRow) -> Result<MacroEventSource, MacroEventError> {
+        let format: String = row.try_get("format")?;
+        let last_refreshed_at: Option<String> = row.try_get("last_refreshed_at")?;
+
+        Ok(MacroEventSource {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            url: row.try_get("url")?,
+            format: if format == "json" {
+                MacroFeedFormat::Json
+            } else {
+                MacroFeedFormat::Ics
+            },
+            enabled: row.try_get::<i64, _>("enabled")? != 0,
+            utc_offset_minutes: row.try_get::<i64, _>("utc_offset_minutes")? as i32,
+            last_refreshed_at: last_refreshed_at.as_deref().map(parse_timestamp).transpose()?,
+            last_error: row.try_get("last_error")?,
+        })
+    }
+
+    fn row_to_event(row: &SqliteRow) -> Result<MacroEvent, MacroEventError> {
+        let impact: String = row.try_get("impact")?;
+        let starts_at: String = row.try_get("starts_at")?;
+        let ends_at: Option<String> = row.try_get("ends_at")?;
+
+        Ok(MacroEvent {
+            id: row.try_get("id")?,
+            source_id: row.try_get("source_id")?,
+            title: row.try_get("title")?,
+            country: row.try_get("country")?,
+            impact: MacroImpact::parse(&impact).unwrap_or(MacroImpact::Medium),
+            starts_at: parse_timestamp(&starts_at)?,
+            ends_at: ends_at.as_deref().map(parse_timestamp).transpose()?,
+            forecast: row.try_get("forecast")?,
+            previous: row.try_get("previous")?,
+        })
+    }
+
+    pub async fn list_sources(&self) -> Result<Vec<MacroEventSource>, MacroEventError> {
+        let rows = sqlx::query("SELECT * FROM macro_sources ORDER BY name")
+            .fetch_all(&self.pool)
+            .await?;
+        rows.iter().map(Self::row_to_source).collect()
+    }
+
+    pub async fn save_source(
+        &self,
+        input: MacroEventSourceInput,
+    ) -> Result<MacroEventSource, MacroEventError> {
+        if !input.url.starts_with("https://") {
+            return Err(MacroEventError::Invalid("feed url must use https".to_string()));
+        }
+        if FixedOffset::east_opt(input.utc_offset_minutes * 60).is_none() {
+            return Err(MacroEventError::Invalid("utc offset out of range".to_string()));
+        }
+
+        let id = input.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        sqlx::query(
+            r#"
+            INSERT INTO macro_sources (id, name, url, format, enabled, utc_offset_minutes)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                url = excluded.url,
+                format = excluded.format,
+                enabled = excluded.enabled,
+                utc_offset_minutes = excluded.utc_offset_minutes
+            "#,
+        )
+        .bind(&id)
+        .bind(&input.name)
+        .bind(&input.url)
+        .bind(match input.format {
+            MacroFeedFormat::Ics => "ics",
+            MacroFeedFormat::Json => "json",
+        })
+        .bind(input.enabled as i64)
+        .bind(input.utc_offset_minutes as i64)
+        .execute(&self.pool)
+        .await?;
+
+        let row = sqlx::query("SELECT * FROM macro_sources WHERE id = ?")
+            .bind(&id)
+            .fetch_one(&self.pool)
+            .await?;
+        Self::row_to_source(&row)
+    }
+
+    pub async fn delete_source(&self, id: &str) -> Result<(), MacroEventError> {
+        let result = sqlx::query("DELETE FROM macro_sources WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(MacroEventError::NotFound(id.to_string()));
+        }
+
+        sqlx::query("DELETE FROM macro_events WHERE source_id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn fetch_source(&self, source: &MacroEventSource) -> Result<Vec<MacroEvent>, MacroEventError> {
+        let body = self
+            .http
+            .get(&source.url)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        match source.format {
+            MacroFeedFormat::Ics => parse_ics_feed(&source.id, &body, source.utc_offset_minutes),
+            MacroFeedFormat::Json => parse_json_feed(&source.id, &body),
+        }
+    }
+
+    async fn replace_events(&self, source_id: &str, events: &[MacroEvent]) -> Result<(), MacroEventError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM macro_events WHERE source_id = ?")
+            .bind(source_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for event in events {
+            sqlx::query(
+                r#"
+                INSERT OR REPLACE INTO macro_events (
+                    id, source_id, title, country, impact, starts_at, ends_at, forecast, previous
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&event.id)
+            .bind(&event.source_id)
+            .bind(&event.title)
+            .bind(&event.country)
+            .bind(event.impact.as_str())
+            .bind(event.starts_at.to_rfc3339())
+            .bind(event.ends_at.map(|dt| dt.to_rfc3339()))
+            .bind(&event.forecast)
+            .bind(&event.previous)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Re-downloads every enabled source. A failing source keeps its previous events.
+    pub async fn refresh(&self) -> Result<Vec<MacroEventSource>, MacroEventError> {
+        for source in self.list_sources().await?.into_iter().filter(|s| s.enabled) {
+            let (refreshed_at, error) = match self.fetch_source(&source).await {
+                Ok(events) => {
+                    self.replace_events(&source.id, &events).await?;
+                    (Some(Utc::now().to_rfc3339()), None)
+                }
+                Err(err) => {
+                    tracing::warn!(error = %err, source = %source.name, "macro calendar refresh failed");
+                    (source.last_refreshed_at.map(|dt| dt.to_rfc3339()), Some(err.to_string()))
+                }
+            };
+
+            sqlx::query("UPDATE macro_sources SET last_refreshed_at = ?, last_error = ? WHERE id = ?")
+                .bind(refreshed_at)
+                .bind(error)
+                .bind(&source.id)
+                .execute(&self.pool)
+                .await?;
+        }
+
+        self.list_sources().await
+    }
+
+    pub async fn events_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        min_impact: MacroImpact,
+    ) -> Result<Vec<MacroEvent>, MacroEventError> {
+        let rows = sqlx::query(
+            "SELECT * FROM macro_events WHERE starts_at >= ? AND starts_at <= ? ORDER BY starts_at",
+        )
+        .bind(from.to_rfc3339())
+        .bind(to.to_rfc3339())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .iter()
+            .map(Self::row_to_event)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|event| event.impact >= min_impact)
+            .collect())
+    }
+
+    pub async fn guard_settings(&self) -> Result<MacroGuardSettings, MacroEventError> {
+        let row = sqlx::query("SELECT settings FROM macro_guard_settings WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match row {
+            Some(row) => {
+                let settings: String = row.try_get("settings")?;
+                Ok(serde_json::from_str(&settings)?)
+            }
+            None => Ok(MacroGuardSettings::default()),
+        }
+    }
+
+    pub async fn set_guard_settings(&self, settings: &MacroGuardSettings) -> Result<(), MacroEventError> {
+        if settings.pause_before_minutes < 0
+            || settings.pause_after_minutes < 0
+            || settings.warn_before_minutes < 0
+        {
+            return Err(MacroEventError::Invalid("windows must not be negative".to_string()));
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO macro_guard_settings (id, settings) VALUES (1, ?)
+            ON CONFLICT(id) DO UPDATE SET settings = excluded.settings
+            "#,
+        )
+        .bind(serde_json::to_string(settings)?)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn current_blackout(&self) -> Result<Option<AutomationBlackout>, MacroEventError> {
+        let settings = self.guard_settings().await?;
+        if !settings.auto_pause_enabled {
+            return Ok(None);
+        }
+
+        // Events are stored by start time, so look back far enough to catch long ones.
+        let now = Utc::now();
+        let events = self
+            .events_between(
+                now - Duration::minutes(settings.pause_after_minutes) - Duration::days(1),
+                now + Duration::minutes(settings.pause_before_minutes),
+                settings.min_impact,
+            )
+            .await?;
+        Ok(find_blackout(&events, &settings, now))
+    }
+
+    /// Qualifying events inside the warning window that haven't been announced yet.
+    pub async fn take_pending_warnings(&self) -> Result<Vec<MacroEvent>, MacroEventError> {
+        let settings = self.guard_settings().await?;
+        let now = Utc::now();
+        let events = self
+            .events_between(
+                now,
+                now + Duration::minutes(settings.warn_before_minutes),
+                settings.min_impact,
+            )
+            .await?;
+
+        let mut pending = Vec::new();
+        for event in events {
+            let inserted = sqlx::query(
+                "INSERT OR IGNORE INTO macro_event_warnings (event_id, sent_at) VALUES (?, ?)",
+            )
+            .bind(&event.id)
+            .bind(now.to_rfc3339())
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+
+            if inserted > 0 {
+                pending.push(event);
+            }
+        }
+        Ok(pending)
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, MacroEventError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| MacroEventError::Internal(format!("invalid timestamp {}: {}", value, e)))
+}
+
+fn macro_events_db_path(app: &AppHandle) -> Result<PathBuf, MacroEventError> {
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| MacroEventError::Internal("Unable to resolve app data directory".to_string()))?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(MACRO_EVENTS_DB_FILE))
+}
+
+/// Checked by automated trading before acting. Returns the event holding automation
+/// back, or `None` when the guard is off, not initialized, or no event is close.
+pub async fn automation_blackout(app: &AppHandle) -> Option<AutomationBlackout> {
+    let manager = app.try_state::<SharedMacroEventManager>()?;
+    let manager = manager.read().await;
+    match manager.current_blackout().await {
+        Ok(blackout) => blackout,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to check macro event blackout");
+            None
+        }
+    }
+}
+
+async fn send_macro_warning(app: &AppHandle, event: &MacroEvent, auto_pause: bool) {
+    if let Err(err) = app.emit_all(MACRO_EVENT_WARNING_EVENT, event) {
+        tracing::warn!(error = %err, "failed to emit macro event warning");
+    }
+
+    let Some(router) = app.try_state::<SharedNotificationRouter>() else {
+        return;
+    };
+
+    let minutes = (event.starts_at - Utc::now()).num_minutes().max(0);
+    let message = format!(
+        "{}{} in {} min ({}). {}",
+        event.title,
+        event
+            .country
+            .as_deref()
+            .map(|c| format!(" [{}]", c))
+            .unwrap_or_default(),
+        minutes,
+        event.starts_at.format("%H:%M UTC"),
+        if auto_pause {
+            "Automated trading will pause around the release."
+        } else {
+            "Expect elevated volatility."
+        }
+    );
+
+    if let Err(err) = router
+        .read()
+        .await
+        .send_broadcast_notification(&event.id, "Macro event ahead", &message)
+        .await
+    {
+        tracing::warn!(error = %err, "failed to deliver macro event notification");
+    }
+}
+
+pub fn start_macro_event_monitor(app: AppHandle, manager: SharedMacroEventManager) {
+    let refresh_manager = manager.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(REFRESH_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            if let Err(err) = refresh_manager.read().await.refresh().await {
+                tracing::warn!(error = %err, "macro calendar refresh cycle failed");
+            }
+        }
+    });
+
+    tauri::async_runtime::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(WARNING_CHECK_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            let (pending, settings) = {
+                let manager = manager.read().await;
+                (manager.take_pending_warnings().await, manager.guard_settings().await)
+            };
+            let auto_pause = settings.map(|s| s.auto_pause_enabled).unwrap_or(false);
+            match pending {
+                Ok(events) => {
+                    for event in &events {
+                        send_macro_warning(&app, event, auto_pause).await;
+                    }
+                }
+                Err(err) => tracing::warn!(error = %err, "macro event warning check failed"),
+            }
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn get_macro_events(
+    window_days: Option<i64>,
+    min_impact: Option<MacroImpact>,
+    manager: State<'_, SharedMacroEventManager>,
+) -> Result<Vec<MacroEvent>, String> {
+    let now = Utc::now();
+    let window = window_days.unwrap_or(7).clamp(1, MAX_WINDOW_DAYS);
+    manager
+        .read()
+        .await
+        .events_between(now, now + Duration::days(window), min_impact.unwrap_or(MacroImpact::Low))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_macro_sources(
+    manager: State<'_, SharedMacroEventManager>,
+) -> Result<Vec<MacroEventSource>, String> {
+    manager.read().await.list_sources().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn save_macro_source(
+    source: MacroEventSourceInput,
+    manager: State<'_, SharedMacroEventManager>,
+) -> Result<MacroEventSource, String> {
+    manager.read().await.save_source(source).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_macro_source(
+    id: String,
+    manager: State<'_, SharedMacroEventManager>,
+) -> Result<(), String> {
+    manager.read().await.delete_source(&id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn refresh_macro_events(
+    manager: State<'_, SharedMacroEventManager>,
+) -> Result<Vec<MacroEventSource>, String> {
+    manager.read().await.refresh().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_macro_guard_settings(
+    manager: State<'_, SharedMacroEventManager>,
+) -> Result<MacroGuardSettings, String> {
+    manager.read().await.guard_settings().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_macro_guard_settings(
+    settings: MacroGuardSettings,
+    manager: State<'_, SharedMacroEventManager>,
+) -> Result<MacroGuardSettings, String> {
+    manager
+        .read()
+        .await
+        .set_guard_settings(&settings)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn get_automation_blackout(
+    manager: State<'_, SharedMacroEventManager>,
+) -> Result<Option<AutomationBlackout>, String> {
+    manager.read().await.current_blackout().await.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ICS: &str = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+UID:fomc-2025-01\r\n\
+SUMMARY:FOMC Rate\r\n  Decision\r\n\
+DTSTART:20250129T190000Z\r\n\
+LOCATION:US\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+UID:retail-sales\r\n\
+SUMMARY:Retail Sales\\, m/m\r\n\
+DTSTART;TZID=America/New_York:20250115T083000\r\n\
+PRIORITY:5\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    #[test]
+    fn parses_ics_events_with_folding_and_offsets() {
+        let events = parse_ics_feed("cal", ICS, -300).unwrap();
+        assert_eq!(events.len(), 2);
+
+        assert_eq!(events[0].id, "cal:fomc-2025-01");
+        assert_eq!(events[0].title, "FOMC Rate Decision");
+        assert_eq!(events[0].impact, MacroImpact::High);
+        assert_eq!(events[0].country.as_deref(), Some("US"));
+
+        assert_eq!(events[1].title, "Retail Sales, m/m");
+        assert_eq!(events[1].impact, MacroImpact::Medium);
+        assert_eq!(events[1].starts_at, Utc.with_ymd_and_hms(2025, 1, 15, 13, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn parses_json_feed_and_upgrades_known_releases() {
+        let body = r#"[
+            {"title": "CPI y/y", "date": "2025-02-12T13:30:00Z", "impact": "medium", "country": "US"},
+            {"id": "gdp", "name": "GDP q/q", "startsAt": "2025-02-27T13:30:00Z", "importance": "low"}
+        ]"#;
+        let events = parse_json_feed("feed", body).unwrap();
+
+        assert_eq!(events[0].impact, MacroImpact::High);
+        assert_eq!(events[1].id, "feed:gdp");
+        assert_eq!(events[1].impact, MacroImpact::Low);
+    }
+
+    #[test]
+    fn blackout_covers_window_around_qualifying_events() {
+        let starts_at = Utc.with_ymd_and_hms(2025, 1, 29, 19, 0, 0).unwrap();
+        let event = |impact| MacroEvent {
+            id: "e".to_string(),
+            source_id: "s".to_string(),
+            title: "FOMC".to_string(),
+            country: None,
+            impact,
+            starts_at,
+            ends_at: None,
+            forecast: None,
+            previous: None,
+        };
+        let settings = MacroGuardSettings {
+            auto_pause_enabled: true,
+            ..MacroGuardSettings::default()
+        };
+
+        let events = vec![event(MacroImpact::High)];
+        assert!(find_blackout(&events, &settings, starts_at - Duration::minutes(31)).is_none());
+        assert!(find_blackout(&events, &settings, starts_at - Duration::minutes(10)).is_some());
+        let blackout = find_blackout(&events, &settings, starts_at + Duration::minutes(59)).unwrap();
+        assert_eq!(blackout.paused_until, starts_at + Duration::minutes(60));
+
+        let minor = vec![event(MacroImpact::Medium)];
+        assert!(find_blackout(&minor, &settings, starts_at).is_none());
+    }
+}
@@ -3,19 +3,44 @@ pub use trending_coins::*;
 pub mod new_coins_scanner;
 pub mod top_coins;
 pub mod holders;
+pub mod holder_refresh;
 pub mod polymarket_adapter;
 pub mod drift_adapter;
 pub mod predictions;
+pub mod heatmap;
+pub mod benchmark_index;
+pub mod order_book;
+pub mod token_unlocks;
+pub mod macro_events;
+pub mod series;
+pub mod chart_annotations;
+pub mod token_images;
 
 pub use new_coins_scanner::*;
 pub use top_coins::*;
 pub use holders::*;
+pub use holder_refresh::*;
 pub use polymarket_adapter::*;
 pub use drift_adapter::*;
 pub use predictions::*;
+pub use heatmap::*;
+pub use benchmark_index::*;
+pub use order_book::*;
+pub use token_unlocks::*;
+pub use macro_events::*;
+pub use series::*;
+pub use chart_annotations::*;
+pub use token_images::*;
 
+use crate::api_analytics::{ensure_within_budget, RequestPriority};
+use crate::api_config::{report_api_outcome, resolve_app_key, RequestOutcome};
+use crate::config::data_sources::{fetch_from_source, provider_for, DataModule, DataProvider};
+use crate::config::http_recorder;
+use crate::config::token_registry::{apply_token_overrides, custom_tokens};
 use serde::{Deserialize, Serialize};
 use reqwest;
+use std::time::Instant;
+use tauri::AppHandle;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CoinPrice {
@@ -52,10 +77,10 @@ async fn fetch_birdeye_price(token: &str, api_key: &str) -> Result<CoinPrice, St
     let client = reqwest::Client::new();
     let url = format!("https://public-api.birdeye.so/defi/price?address={}", token);
     
-    let response = client
+    let request = client
         .get(&url)
-        .header("X-API-KEY", api_key)
-        .send()
+        .header("X-API-KEY", api_key);
+    let response = http_recorder::send("birdeye", request)
         .await
         .map_err(|e| format!("Request failed: {}", e))?;
 
@@ -88,70 +113,85 @@ async fn fetch_birdeye_price(token: &str, api_key: &str) -> Result<CoinPrice, St
     })
 }
 
-// Mock data generator for development
-fn generate_mock_price(symbol: &str) -> CoinPrice {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
-    
-    let base_price = match symbol {
-        "SOL" => 100.0,
-        "BONK" => 0.000023,
-        "JUP" => 1.23,
-        _ => 1.0,
-    };
+// Fixtures, served when the market module runs in mock mode
+fn generate_mock_price(token: &crate::demo::DemoToken) -> CoinPrice {
+    let snapshot = crate::demo::demo_snapshot(token);
 
     CoinPrice {
-        address: "mock".to_string(),
-        symbol: symbol.to_string(),
-        name: format!("{} Token", symbol),
-        price: base_price * (1.0 + rng.gen_range(-0.05..0.05)),
-        price_change_24h: rng.gen_range(-20.0..20.0),
-        volume_24h: rng.gen_range(100000.0..10000000.0),
-        market_cap: rng.gen_range(1000000.0..100000000.0),
-        liquidity: Some(rng.gen_range(50000.0..5000000.0)),
+        address: snapshot.address,
+        symbol: snapshot.symbol,
+        name: snapshot.name,
+        price: snapshot.price,
+        price_change_24h: snapshot.price_change_24h,
+        volume_24h: snapshot.volume_24h,
+        market_cap: snapshot.market_cap,
+        liquidity: Some(snapshot.volume_24h * 0.5),
     }
 }
 
-fn generate_mock_history(hours: i64) -> Vec<PricePoint> {
-    use rand::Rng;
-    let mut rng = rand::thread_rng();
-    let mut history = Vec::new();
-    let mut price = 100.0;
-    
-    let now = chrono::Utc::now().timestamp();
-    
-    for i in (0..hours).rev() {
-        let change = rng.gen_range(-2.0..2.0);
-        price += change;
-        let volatility = rng.gen_range(0.5..2.0);
-        
-        history.push(PricePoint {
-            timestamp: now - (i * 3600),
-            open: price,
-            high: price + volatility,
-            low: price - volatility,
-            close: price + rng.gen_range(-1.0..1.0),
-            volume: rng.gen_range(10000.0..100000.0),
-        });
-    }
-    
-    history
+fn generate_mock_history(token: &crate::demo::DemoToken, hours: i64) -> Vec<PricePoint> {
+    crate::demo::demo_price_history(token, hours)
+        .into_iter()
+        .map(|candle| PricePoint {
+            timestamp: candle.timestamp,
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: candle.volume,
+        })
+        .collect()
 }
 
 #[tauri::command]
 pub async fn get_coin_price(address: String, api_key: Option<String>) -> Result<CoinPrice, String> {
-    // If API key provided, use real API
-    if let Some(key) = api_key {
-        if !key.is_empty() {
-            match fetch_birdeye_price(&address, &key).await {
-                Ok(price) => return Ok(price),
-                Err(_) => {} // Fall through to mock data
+    fetch_from_source(
+        DataModule::Market,
+        &format!("price_{address}"),
+        || async {
+            match api_key.as_deref().filter(|key| !key.is_empty()) {
+                Some(key) => fetch_birdeye_price(&address, key).await,
+                None => Err(format!("No price available for {address}: Birdeye API key not configured")),
             }
-        }
+        },
+        || {
+            crate::demo::find_demo_token(&address)
+                .map(generate_mock_price)
+                .ok_or_else(|| format!("No price available for {address}"))
+        },
+    )
+    .await
+    .map(|mut price| {
+        apply_token_overrides(&price.address, &mut price.symbol, Some(&mut price.name), None, None);
+        price
+    })
+}
+
+/// Price lookup for backend callers. Uses the app's Birdeye key, respects the
+/// Birdeye budget and reports how the live request went, so a failing key can
+/// fail over.
+pub async fn quote_coin_price(
+    app: &AppHandle,
+    address: &str,
+    priority: RequestPriority,
+) -> Result<CoinPrice, String> {
+    let resolved = resolve_app_key(app, "birdeye").filter(|r| !r.key.is_empty());
+    let live = matches!(
+        provider_for(DataModule::Market, &format!("price_{address}")),
+        DataProvider::Live { .. }
+    );
+    if live && resolved.is_some() {
+        ensure_within_budget(app, "birdeye", priority)?;
     }
-    
-    // Otherwise use mock data
-    Ok(generate_mock_price(&address))
+    let api_key = resolved.as_ref().map(|r| r.key.clone());
+    let started = Instant::now();
+    let result = get_coin_price(address.to_string(), api_key).await;
+
+    if let Some(resolved) = resolved.filter(|_| live) {
+        let outcome = RequestOutcome::from_result(started, &result);
+        report_api_outcome(app, &resolved.service, resolved.source, outcome).await;
+    }
+    result
 }
 
 #[tauri::command]
@@ -169,36 +209,56 @@ pub async fn get_price_history(
         _ => 24,
     };
     
-    // For now, return mock data
-    Ok(generate_mock_history(hours))
+    // No live history source yet, so live mode has no candles
+    fetch_from_source(
+        DataModule::Market,
+        &format!("history_{address}_{timeframe}"),
+        || async { Ok(Vec::new()) },
+        || {
+            Ok(crate::demo::find_demo_token(&address)
+                .map(|token| generate_mock_history(token, hours))
+                .unwrap_or_default())
+        },
+    )
+    .await
 }
 
 #[tauri::command]
 pub async fn search_tokens(query: String) -> Result<Vec<TokenSearchResult>, String> {
-    // Mock search results
-    let tokens = vec![
-        TokenSearchResult {
-            address: "So11111111111111111111111111111111111111112".to_string(),
-            symbol: "SOL".to_string(),
-            name: "Solana".to_string(),
-            logo_uri: None,
-        },
-        TokenSearchResult {
-            address: "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263".to_string(),
-            symbol: "BONK".to_string(),
-            name: "Bonk".to_string(),
-            logo_uri: None,
-        },
-        TokenSearchResult {
-            address: "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN".to_string(),
-            symbol: "JUP".to_string(),
-            name: "Jupiter".to_string(),
-            logo_uri: None,
+    // Token search is only backed by the fixture universe for now
+    let tokens: Vec<TokenSearchResult> = fetch_from_source(
+        DataModule::Market,
+        "token_universe",
+        || async { Ok(Vec::new()) },
+        || {
+            Ok(crate::demo::DEMO_TOKENS
+                .iter()
+                .map(|token| TokenSearchResult {
+                    address: token.address.to_string(),
+                    symbol: token.symbol.to_string(),
+                    name: token.name.to_string(),
+                    logo_uri: None,
+                })
+                .collect())
         },
-    ];
+    )
+    .await?;
+
+    // Hand-registered tokens are searchable even though no provider lists them
+    let custom = custom_tokens().into_iter().map(|entry| TokenSearchResult {
+        address: entry.mint,
+        symbol: entry.symbol.unwrap_or_default(),
+        name: entry.name.unwrap_or_default(),
+        logo_uri: entry.logo_path,
+    });
     
     let filtered: Vec<TokenSearchResult> = tokens
         .into_iter()
+        .map(|mut t| {
+            apply_token_overrides(&t.address, &mut t.symbol, Some(&mut t.name), None, Some(&mut t.logo_uri));
+            t
+        })
+        .chain(custom)
         .filter(|t| {
             t.symbol.to_lowercase().contains(&query.to_lowercase()) ||
             t.name.to_lowercase().contains(&query.to_lowercase())
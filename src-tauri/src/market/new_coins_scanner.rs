@@ -5,6 +5,10 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::config::data_sources::{provider_for, read_recording, DataModule, DataProvider};
+use crate::core::events::{publish_domain_event, DomainEvent};
+use crate::security::blocklist_sync::denylist_match;
+use crate::webhooks::{queue_event, EventSeverity, WebhookEvent, WebhookEventType};
 use serde::{Deserialize, Serialize};
 use chrono::{Duration as ChronoDuration, Utc};
 use sqlx::{Pool, Row, Sqlite, SqlitePool};
@@ -117,6 +121,12 @@ impl NewCoinsScanner {
             is_spam = true;
         }
 
+        if denylist_match(&coin.address).is_some()
+            || denylist_match(&coin.creator_address).is_some()
+        {
+            is_spam = true;
+        }
+
         let name_lower = coin.name.to_lowercase();
         let symbol_lower = coin.symbol.to_lowercase();
         for pattern in &self.spam_filters.suspicious_patterns {
@@ -367,23 +377,49 @@ impl NewCoinsScanner {
         // 5. Analyze holder distribution
         // 6. Check mint/freeze authorities
         
-        let mock_coins = self.generate_mock_new_coins().await?;
-        
-        // Store new coins in database
-        for coin in &mock_coins {
-            self.store_coin(coin).await?;
-        }
+        // Without a chain indexer live mode finds nothing; fixture and recorded
+        // coins are never written to the real database
+        let coins = match provider_for(DataModule::Scanner, "new_coins") {
+            DataProvider::Live { .. } => return Ok(Vec::new()),
+            DataProvider::Mock => self.generate_mock_new_coins().await?,
+            DataProvider::Recorded(path) => {
+                read_recording(&path).map_err(NewCoinsScannerError::Internal)?
+            }
+        };
 
         // Emit event for high-safety coins
         if let Some(app) = &self.app_handle {
-            for coin in &mock_coins {
+            for coin in &coins {
                 if coin.safety_score >= 70 && !coin.is_spam {
                     let _ = app.emit_all("new-coin-detected", coin);
+                    publish_domain_event(
+                        "new_coins_scanner",
+                        DomainEvent::CoinDetected {
+                            mint: coin.address.clone(),
+                            symbol: coin.symbol.clone(),
+                            liquidity: coin.liquidity,
+                            safety_score: coin.safety_score as f64,
+                        },
+                    );
+                    let event = WebhookEvent::new(
+                        WebhookEventType::CoinDetected,
+                        EventSeverity::Info,
+                        format!("New coin: {}", coin.symbol),
+                        format!(
+                            "{} detected with safety score {} and ${:.0} liquidity",
+                            coin.symbol, coin.safety_score, coin.liquidity
+                        ),
+                    )
+                    .with_token(coin.address.clone())
+                    .with_data(serde_json::to_value(coin).unwrap_or_default());
+                    // Rescans see the same mint again; the key keeps it to one delivery
+                    queue_event(app, event, Some(&format!("coin:{}", coin.address))).await;
                 }
             }
+            crate::trading::notify_snipe_engine(app, &coins);
         }
 
-        Ok(mock_coins)
+        Ok(coins)
     }
 
     async fn generate_mock_new_coins(&self) -> Result<Vec<NewCoin>, NewCoinsScannerError> {
@@ -493,6 +529,22 @@ impl NewCoinsScanner {
     ) -> Result<Vec<NewCoin>, NewCoinsScannerError> {
         let hours = hours.unwrap_or(24);
         let min_safety = min_safety_score.unwrap_or(0);
+
+        // Fixture and recorded coins are served directly instead of read from the database
+        let sourced = match provider_for(DataModule::Scanner, "new_coins") {
+            DataProvider::Live { .. } => None,
+            DataProvider::Mock => Some(self.generate_mock_new_coins().await?),
+            DataProvider::Recorded(path) => Some(
+                read_recording::<Vec<NewCoin>>(&path).map_err(NewCoinsScannerError::Internal)?,
+            ),
+        };
+        if let Some(coins) = sourced {
+            return Ok(coins
+                .into_iter()
+                .filter(|coin| coin.safety_score as i64 >= min_safety && !coin.is_spam)
+                .collect());
+        }
+
         let cutoff_time = (Utc::now() - ChronoDuration::hours(hours)).to_rfc3339();
 
         let rows = sqlx::query(
@@ -0,0 +1,663 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+use tokio::time::Duration;
+
+use crate::config::http_recorder;
+use crate::trading::types::OrderSide;
+
+const DEFAULT_DEPTH: usize = 20;
+const MAX_DEPTH: usize = 200;
+const DEFAULT_REFRESH_MS: u64 = 2_000;
+const ORDER_BOOK_UPDATE_EVENT: &str = "order_book_update";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ClobVenue {
+    Openbook,
+    Phoenix,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClobMarket {
+    pub token: String,
+    pub market_address: String,
+    pub venue: ClobVenue,
+    /// L2 endpoint returning `{ bids: [[price, size]], asks: [[price, size]] }`.
+    /// Markets without one can't be tracked; there is no estimated fallback book.
+    pub l2_endpoint: Option<String>,
+    pub refresh_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BookLevel {
+    pub price: f64,
+    pub size: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepthLevel {
+    pub price: f64,
+    pub size: f64,
+    pub cumulative_size: f64,
+    pub cumulative_notional: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClobBookSnapshot {
+    pub token: String,
+    pub market_address: String,
+    pub venue: ClobVenue,
+    pub sequence: u64,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBookDepth {
+    pub token: String,
+    pub venue: ClobVenue,
+    pub sequence: u64,
+    pub best_bid: Option<f64>,
+    pub best_ask: Option<f64>,
+    pub mid_price: Option<f64>,
+    pub spread_bps: Option<f64>,
+    pub bids: Vec<DepthLevel>,
+    pub asks: Vec<DepthLevel>,
+    pub updated_at: String,
+}
+
+/// Incremental update emitted on `order_book_update`. A level with `size == 0` was removed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBookDelta {
+    pub token: String,
+    pub sequence: u64,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlippageEstimate {
+    pub token: String,
+    pub side: OrderSide,
+    pub requested_quantity: f64,
+    pub filled_quantity: f64,
+    pub average_price: f64,
+    pub reference_price: f64,
+    pub slippage_percent: f64,
+    pub levels_consumed: usize,
+    pub fully_filled: bool,
+}
+
+struct TrackedBook {
+    market: ClobMarket,
+    snapshot: Option<ClobBookSnapshot>,
+    /// Identifies the refresh loop that owns this book; loops and in-flight
+    /// refreshes from an earlier generation stop instead of touching it.
+    generation: u64,
+}
+
+pub struct OrderBookManager {
+    books: HashMap<String, TrackedBook>,
+    next_generation: u64,
+    client: reqwest::Client,
+}
+
+pub type SharedOrderBookManager = Arc<RwLock<OrderBookManager>>;
+
+impl OrderBookManager {
+    pub fn new() -> Self {
+        Self {
+            books: HashMap::new(),
+            next_generation: 1,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn is_tracked(&self, token: &str) -> bool {
+        self.books.contains_key(token)
+    }
+
+    pub fn tracked_markets(&self) -> Vec<ClobMarket> {
+        self.books.values().map(|b| b.market.clone()).collect()
+    }
+
+    pub fn snapshot(&self, token: &str) -> Option<&ClobBookSnapshot> {
+        self.books.get(token).and_then(|b| b.snapshot.as_ref())
+    }
+
+    fn is_current(&self, token: &str, generation: u64) -> bool {
+        self.books
+            .get(token)
+            .is_some_and(|book| book.generation == generation)
+    }
+
+    /// Starts tracking `market`, returning the generation a new refresh loop
+    /// should run under. Re-tracking the same market only updates its settings
+    /// and keeps the running loop and the current snapshot.
+    fn track(&mut self, market: ClobMarket) -> Option<u64> {
+        if let Some(book) = self.books.get_mut(&market.token) {
+            let unchanged = book.market.market_address == market.market_address
+                && book.market.venue == market.venue
+                && book.market.l2_endpoint == market.l2_endpoint;
+            if unchanged {
+                book.market = market;
+                return None;
+            }
+        }
+
+        let generation = self.next_generation;
+        self.next_generation += 1;
+        self.books.insert(
+            market.token.clone(),
+            TrackedBook {
+                market,
+                snapshot: None,
+                generation,
+            },
+        );
+        Some(generation)
+    }
+
+    fn untrack(&mut self, token: &str) -> bool {
+        self.books.remove(token).is_some()
+    }
+
+    /// Stores freshly fetched levels and returns the delta against the previous snapshot.
+    fn apply_levels(
+        &mut self,
+        token: &str,
+        mut bids: Vec<BookLevel>,
+        mut asks: Vec<BookLevel>,
+    ) -> Result<Option<OrderBookDelta>, String> {
+        sort_levels(&mut bids, &mut asks);
+
+        let book = self
+            .books
+            .get_mut(token)
+            .ok_or_else(|| format!("Order book not tracked: {}", token))?;
+
+        let sequence = book.snapshot.as_ref().map(|s| s.sequence + 1).unwrap_or(1);
+        let delta = match &book.snapshot {
+            Some(previous) => {
+                let bid_changes = diff_levels(&previous.bids, &bids);
+                let ask_changes = diff_levels(&previous.asks, &asks);
+                if bid_changes.is_empty() && ask_changes.is_empty() {
+                    None
+                } else {
+                    Some((bid_changes, ask_changes))
+                }
+            }
+            None => Some((bids.clone(), asks.clone())),
+        };
+
+        let Some((bid_changes, ask_changes)) = delta else {
+            return Ok(None);
+        };
+
+        book.snapshot = Some(ClobBookSnapshot {
+            token: token.to_string(),
+            market_address: book.market.market_address.clone(),
+            venue: book.market.venue,
+            sequence,
+            bids,
+            asks,
+            updated_at: Utc::now().to_rfc3339(),
+        });
+
+        Ok(Some(OrderBookDelta {
+            token: token.to_string(),
+            sequence,
+            bids: bid_changes,
+            asks: ask_changes,
+            timestamp: Utc::now().timestamp_millis(),
+        }))
+    }
+
+    pub fn depth(&self, token: &str, depth: usize) -> Option<OrderBookDepth> {
+        self.snapshot(token).map(|snapshot| aggregate_depth(snapshot, depth))
+    }
+
+    pub fn estimate_slippage(
+        &self,
+        token: &str,
+        side: OrderSide,
+        quantity: f64,
+    ) -> Option<SlippageEstimate> {
+        self.snapshot(token)
+            .and_then(|snapshot| walk_book(snapshot, side, quantity))
+    }
+}
+
+impl Default for OrderBookManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn fetch_levels(
+    client: &reqwest::Client,
+    market: &ClobMarket,
+) -> Result<(Vec<BookLevel>, Vec<BookLevel>), String> {
+    #[derive(Deserialize)]
+    struct L2Response {
+        bids: Vec<(f64, f64)>,
+        asks: Vec<(f64, f64)>,
+    }
+
+    let endpoint = market.l2_endpoint.as_ref().ok_or_else(|| {
+        format!(
+            "Order book unavailable for {}: no L2 endpoint configured",
+            market.token
+        )
+    })?;
+
+    let request = client
+        .get(endpoint)
+        .query(&[("market", market.market_address.as_str())]);
+    let response: L2Response = http_recorder::send("order_book", request)
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Parse failed: {}", e))?;
+
+    let to_levels = |raw: Vec<(f64, f64)>| {
+        raw.into_iter()
+            .filter(|(price, size)| *price > 0.0 && *size > 0.0)
+            .map(|(price, size)| BookLevel { price, size })
+            .collect::<Vec<_>>()
+    };
+
+    Ok((to_levels(response.bids), to_levels(response.asks)))
+}
+
+/// Refreshes a tracked book. The manager lock is only taken to read the market
+/// and to store the result, never across the HTTP request.
+pub async fn refresh_book(
+    manager: &SharedOrderBookManager,
+    token: &str,
+) -> Result<Option<OrderBookDelta>, String> {
+    let (market, generation, client) = {
+        let mgr = manager.read().await;
+        let book = mgr
+            .books
+            .get(token)
+            .ok_or_else(|| format!("Order book not tracked: {}", token))?;
+        (book.market.clone(), book.generation, mgr.client.clone())
+    };
+
+    let (bids, asks) = fetch_levels(&client, &market).await?;
+    let mut mgr = manager.write().await;
+    // Untracked or switched to another market while the request was out
+    if !mgr.is_current(token, generation) {
+        return Ok(None);
+    }
+    mgr.apply_levels(token, bids, asks)
+}
+
+fn sort_levels(bids: &mut [BookLevel], asks: &mut [BookLevel]) {
+    bids.sort_by(|a, b| b.price.partial_cmp(&a.price).unwrap_or(std::cmp::Ordering::Equal));
+    asks.sort_by(|a, b| a.price.partial_cmp(&b.price).unwrap_or(std::cmp::Ordering::Equal));
+}
+
+// Prices are keyed at 1e-9 precision so float noise doesn't produce phantom updates
+fn price_key(price: f64) -> i64 {
+    (price * 1e9).round() as i64
+}
+
+pub fn diff_levels(previous: &[BookLevel], current: &[BookLevel]) -> Vec<BookLevel> {
+    let before: BTreeMap<i64, &BookLevel> =
+        previous.iter().map(|l| (price_key(l.price), l)).collect();
+    let after: BTreeMap<i64, &BookLevel> =
+        current.iter().map(|l| (price_key(l.price), l)).collect();
+
+    let mut changes = Vec::new();
+    for (key, level) in &after {
+        match before.get(key) {
+            Some(old) if (old.size - level.size).abs() < f64::EPSILON => {}
+            _ => changes.push((*level).clone()),
+        }
+    }
+    for (key, level) in &before {
+        if !after.contains_key(key) {
+            changes.push(BookLevel {
+                price: level.price,
+                size: 0.0,
+            });
+        }
+    }
+    changes
+}
+
+fn accumulate(levels: &[BookLevel], depth: usize) -> Vec<DepthLevel> {
+    let mut cumulative_size = 0.0;
+    let mut cumulative_notional = 0.0;
+    levels
+        .iter()
+        .take(depth)
+        .map(|level| {
+            cumulative_size += level.size;
+            cumulative_notional += level.size * level.price;
+            DepthLevel {
+                price: level.price,
+                size: level.size,
+                cumulative_size,
+                cumulative_notional,
+            }
+        })
+        .collect()
+}
+
+pub fn aggregate_depth(snapshot: &ClobBookSnapshot, depth: usize) -> OrderBookDepth {
+    let best_bid = snapshot.bids.first().map(|l| l.price);
+    let best_ask = snapshot.asks.first().map(|l| l.price);
+    let mid_price = match (best_bid, best_ask) {
+        (Some(bid), Some(ask)) => Some((bid + ask) / 2.0),
+        _ => None,
+    };
+    let spread_bps = match (best_bid, best_ask, mid_price) {
+        (Some(bid), Some(ask), Some(mid)) if mid > 0.0 => Some((ask - bid) / mid * 10_000.0),
+        _ => None,
+    };
+
+    OrderBookDepth {
+        token: snapshot.token.clone(),
+        venue: snapshot.venue,
+        sequence: snapshot.sequence,
+        best_bid,
+        best_ask,
+        mid_price,
+        spread_bps,
+        bids: accumulate(&snapshot.bids, depth),
+        asks: accumulate(&snapshot.asks, depth),
+        updated_at: snapshot.updated_at.clone(),
+    }
+}
+
+/// Walks the opposite side of the book to estimate the average fill for `quantity`.
+pub fn walk_book(
+    snapshot: &ClobBookSnapshot,
+    side: OrderSide,
+    quantity: f64,
+) -> Option<SlippageEstimate> {
+    if quantity <= 0.0 {
+        return None;
+    }
+
+    let levels = match side {
+        OrderSide::Buy => &snapshot.asks,
+        OrderSide::Sell => &snapshot.bids,
+    };
+    let reference_price = levels.first()?.price;
+
+    let mut remaining = quantity;
+    let mut notional = 0.0;
+    let mut levels_consumed = 0;
+    for level in levels {
+        if remaining <= 0.0 {
+            break;
+        }
+        let take = remaining.min(level.size);
+        notional += take * level.price;
+        remaining -= take;
+        levels_consumed += 1;
+    }
+
+    let filled_quantity = quantity - remaining.max(0.0);
+    if filled_quantity <= 0.0 {
+        return None;
+    }
+    let average_price = notional / filled_quantity;
+    let slippage_percent = match side {
+        OrderSide::Buy => (average_price - reference_price) / reference_price * 100.0,
+        OrderSide::Sell => (reference_price - average_price) / reference_price * 100.0,
+    };
+
+    Some(SlippageEstimate {
+        token: snapshot.token.clone(),
+        side,
+        requested_quantity: quantity,
+        filled_quantity,
+        average_price,
+        reference_price,
+        slippage_percent,
+        levels_consumed,
+        fully_filled: remaining <= f64::EPSILON,
+    })
+}
+
+fn spawn_refresh_loop(
+    app_handle: AppHandle,
+    manager: SharedOrderBookManager,
+    token: String,
+    generation: u64,
+) {
+    tokio::spawn(async move {
+        loop {
+            let refresh_ms = {
+                let mgr = manager.read().await;
+                match mgr.books.get(&token) {
+                    Some(book) if book.generation == generation => {
+                        book.market.refresh_ms.unwrap_or(DEFAULT_REFRESH_MS)
+                    }
+                    _ => break,
+                }
+            };
+
+            let result = refresh_book(&manager, &token).await;
+            if !manager.read().await.is_current(&token, generation) {
+                break;
+            }
+
+            match result {
+                Ok(Some(delta)) => {
+                    let _ = app_handle.emit_all(ORDER_BOOK_UPDATE_EVENT, &delta);
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    tracing::warn!(token = %token, error = %err, "order book refresh failed");
+                }
+            }
+
+            tokio::time::sleep(Duration::from_millis(refresh_ms)).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn order_book_track(
+    app_handle: AppHandle,
+    manager: State<'_, SharedOrderBookManager>,
+    market: ClobMarket,
+) -> Result<(), String> {
+    if market.l2_endpoint.is_none() {
+        return Err(format!(
+            "Order book unavailable for {}: no L2 endpoint configured",
+            market.token
+        ));
+    }
+    let token = market.token.clone();
+    let generation = {
+        let mut mgr = manager.write().await;
+        mgr.track(market)
+    };
+
+    if let Some(generation) = generation {
+        spawn_refresh_loop(app_handle, manager.inner().clone(), token, generation);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn order_book_untrack(
+    manager: State<'_, SharedOrderBookManager>,
+    token: String,
+) -> Result<bool, String> {
+    let mut mgr = manager.write().await;
+    Ok(mgr.untrack(&token))
+}
+
+#[tauri::command]
+pub async fn order_book_list_markets(
+    manager: State<'_, SharedOrderBookManager>,
+) -> Result<Vec<ClobMarket>, String> {
+    let mgr = manager.read().await;
+    Ok(mgr.tracked_markets())
+}
+
+#[tauri::command]
+pub async fn get_order_book(
+    manager: State<'_, SharedOrderBookManager>,
+    token: String,
+    depth: Option<usize>,
+) -> Result<OrderBookDepth, String> {
+    let depth = depth.unwrap_or(DEFAULT_DEPTH).clamp(1, MAX_DEPTH);
+
+    {
+        let mgr = manager.read().await;
+        if let Some(book) = mgr.depth(&token, depth) {
+            return Ok(book);
+        }
+        if !mgr.is_tracked(&token) {
+            return Err(format!("No CLOB market tracked for {}", token));
+        }
+    }
+
+    // Tracked but not fetched yet
+    refresh_book(&manager, &token).await?;
+    manager
+        .read()
+        .await
+        .depth(&token, depth)
+        .ok_or_else(|| format!("Order book unavailable for {}", token))
+}
+
+#[tauri::command]
+pub async fn estimate_order_book_slippage(
+    manager: State<'_, SharedOrderBookManager>,
+    token: String,
+    side: OrderSide,
+    quantity: f64,
+) -> Result<Option<SlippageEstimate>, String> {
+    let mgr = manager.read().await;
+    Ok(mgr.estimate_slippage(&token, side, quantity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(price: f64, size: f64) -> BookLevel {
+        BookLevel { price, size }
+    }
+
+    fn snapshot() -> ClobBookSnapshot {
+        ClobBookSnapshot {
+            token: "SOL".to_string(),
+            market_address: "market".to_string(),
+            venue: ClobVenue::Phoenix,
+            sequence: 1,
+            bids: vec![level(99.0, 5.0), level(98.0, 10.0)],
+            asks: vec![level(101.0, 5.0), level(102.0, 10.0)],
+            updated_at: Utc::now().to_rfc3339(),
+        }
+    }
+
+    #[test]
+    fn depth_accumulates_and_reports_spread() {
+        let depth = aggregate_depth(&snapshot(), 10);
+        assert_eq!(depth.mid_price, Some(100.0));
+        assert!((depth.spread_bps.unwrap() - 200.0).abs() < 1e-9);
+        assert_eq!(depth.asks[1].cumulative_size, 15.0);
+        assert_eq!(depth.bids[1].cumulative_notional, 99.0 * 5.0 + 98.0 * 10.0);
+    }
+
+    #[test]
+    fn walking_the_book_across_levels() {
+        let estimate = walk_book(&snapshot(), OrderSide::Buy, 10.0).unwrap();
+        assert!(estimate.fully_filled);
+        assert_eq!(estimate.levels_consumed, 2);
+        assert!((estimate.average_price - 101.5).abs() < 1e-9);
+
+        let partial = walk_book(&snapshot(), OrderSide::Sell, 20.0).unwrap();
+        assert!(!partial.fully_filled);
+        assert_eq!(partial.filled_quantity, 15.0);
+    }
+
+    #[test]
+    fn diff_marks_removed_levels_with_zero_size() {
+        let previous = vec![level(99.0, 5.0), level(98.0, 10.0)];
+        let current = vec![level(99.0, 6.0), level(97.0, 1.0)];
+        let changes = diff_levels(&previous, &current);
+
+        assert_eq!(changes.len(), 3);
+        assert!(changes.contains(&level(99.0, 6.0)));
+        assert!(changes.contains(&level(97.0, 1.0)));
+        assert!(changes.contains(&level(98.0, 0.0)));
+    }
+
+    #[test]
+    fn applying_levels_sorts_and_sequences_the_book() {
+        let mut manager = OrderBookManager::new();
+        manager.track(ClobMarket {
+            token: "SOL".to_string(),
+            market_address: "market".to_string(),
+            venue: ClobVenue::Openbook,
+            l2_endpoint: Some("http://localhost/l2".to_string()),
+            refresh_ms: None,
+        });
+        assert!(manager.estimate_slippage("SOL", OrderSide::Buy, 1.0).is_none());
+
+        let bids = vec![level(98.0, 10.0), level(99.0, 5.0)];
+        let asks = vec![level(102.0, 10.0), level(101.0, 5.0)];
+        let first = manager.apply_levels("SOL", bids.clone(), asks.clone()).unwrap();
+        assert_eq!(first.unwrap().sequence, 1);
+        assert_eq!(manager.snapshot("SOL").unwrap().bids[0].price, 99.0);
+
+        assert!(manager.apply_levels("SOL", bids, asks).unwrap().is_none());
+        assert!(manager.apply_levels("BONK", vec![], vec![]).is_err());
+    }
+
+    #[test]
+    fn retracking_keeps_the_loop_and_snapshot_unless_the_market_changes() {
+        let market = |address: &str| ClobMarket {
+            token: "SOL".to_string(),
+            market_address: address.to_string(),
+            venue: ClobVenue::Phoenix,
+            l2_endpoint: Some("http://localhost/l2".to_string()),
+            refresh_ms: None,
+        };
+        let mut manager = OrderBookManager::new();
+        let first = manager.track(market("market")).unwrap();
+        manager
+            .apply_levels("SOL", vec![level(99.0, 5.0)], vec![level(101.0, 5.0)])
+            .unwrap();
+
+        assert_eq!(manager.track(market("market")), None);
+        assert!(manager.snapshot("SOL").is_some());
+        assert!(manager.is_current("SOL", first));
+
+        // The old loop must stop even if the token is tracked again before it wakes
+        manager.untrack("SOL");
+        let second = manager.track(market("market")).unwrap();
+        assert!(!manager.is_current("SOL", first));
+
+        let third = manager.track(market("other")).unwrap();
+        assert!(!manager.is_current("SOL", second) && manager.is_current("SOL", third));
+        assert!(manager.snapshot("SOL").is_none());
+    }
+}
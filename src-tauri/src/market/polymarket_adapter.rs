@@ -1,3 +1,4 @@
+use crate::config::http_recorder;
 use reqwest;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -140,10 +141,10 @@ impl PolymarketAdapter {
     async fn fetch_markets_raw(&self) -> Result<Vec<PolymarketMarket>, String> {
         let url = format!("{}/markets", self.base_url);
 
-        let response = self
+        let request = self
             .client
-            .get(&url)
-            .send()
+            .get(&url);
+        let response = http_recorder::send("polymarket", request)
             .await
             .map_err(|e| format!("Polymarket API request failed: {e}"))?;
 
@@ -175,10 +176,10 @@ impl PolymarketAdapter {
     async fn fetch_market_raw(&self, condition_id: &str) -> Result<PolymarketMarket, String> {
         let url = format!("{}/markets/{}", self.base_url, condition_id);
 
-        let response = self
+        let request = self
             .client
-            .get(&url)
-            .send()
+            .get(&url);
+        let response = http_recorder::send("polymarket", request)
             .await
             .map_err(|e| format!("Polymarket API request failed: {e}"))?;
 
@@ -210,11 +211,11 @@ impl PolymarketAdapter {
     pub async fn fetch_order_book(&self, token_id: &str) -> Result<PolymarketOrderBook, String> {
         let url = format!("{}/book", self.base_url);
 
-        let response = self
+        let request = self
             .client
             .get(&url)
-            .query(&[("token_id", token_id)])
-            .send()
+            .query(&[("token_id", token_id)]);
+        let response = http_recorder::send("polymarket", request)
             .await
             .map_err(|e| format!("Polymarket order book request failed: {e}"))?;
 
@@ -234,11 +235,11 @@ impl PolymarketAdapter {
     pub async fn fetch_trades(&self, market: &str) -> Result<Vec<PolymarketTrade>, String> {
         let url = format!("{}/trades", self.base_url);
 
-        let response = self
+        let request = self
             .client
             .get(&url)
-            .query(&[("market", market)])
-            .send()
+            .query(&[("market", market)]);
+        let response = http_recorder::send("polymarket", request)
             .await
             .map_err(|e| format!("Polymarket trades request failed: {e}"))?;
 
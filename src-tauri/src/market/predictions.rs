@@ -1,3 +1,4 @@
+use crate::config::data_sources::{fetch_from_source, DataModule};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -154,10 +155,19 @@ impl PredictionMarketService {
         let mut all_markets = Vec::new();
 
         // Fetch from Polymarket
+        // One venue being down should not hide the other; the failure is logged
+        // by the data-source layer and that venue contributes nothing
         let polymarket_markets = if use_mock {
             generate_mock_polymarket_markets()
         } else {
-            self.polymarket_adapter.fetch_markets().await.unwrap_or_else(|_| generate_mock_polymarket_markets())
+            fetch_from_source(
+                DataModule::Market,
+                "polymarket_markets",
+                || self.polymarket_adapter.fetch_markets(),
+                || Ok(generate_mock_polymarket_markets()),
+            )
+            .await
+            .unwrap_or_default()
         };
 
         for market in polymarket_markets {
@@ -168,7 +178,14 @@ impl PredictionMarketService {
         let drift_predictions = if use_mock {
             generate_mock_drift_predictions()
         } else {
-            self.drift_adapter.fetch_predictions().await.unwrap_or_else(|_| generate_mock_drift_predictions())
+            fetch_from_source(
+                DataModule::Market,
+                "drift_predictions",
+                || self.drift_adapter.fetch_predictions(),
+                || Ok(generate_mock_drift_predictions()),
+            )
+            .await
+            .unwrap_or_default()
         };
 
         for prediction in drift_predictions {
@@ -0,0 +1,359 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tokio::sync::RwLock;
+
+use super::holders::SharedHolderAnalyzer;
+use crate::ai::SharedRiskAnalyzer;
+use crate::data::historical::{FetchRequest, SharedHistoricalReplayManager};
+use crate::sentiment::SharedSentimentManager;
+
+const SERIES_CACHE_TTL: Duration = Duration::from_secs(120);
+const SERIES_CACHE_CAPACITY: usize = 256;
+const DEFAULT_MAX_POINTS: usize = 500;
+const MAX_POINTS: usize = 5_000;
+/// Price and volume use the finest candle interval that stays under this count.
+const MAX_RAW_CANDLES: i64 = 20_000;
+const CANDLE_INTERVALS: [(&str, i64); 6] = [
+    ("1m", 60),
+    ("5m", 300),
+    ("15m", 900),
+    ("1h", 3_600),
+    ("4h", 14_400),
+    ("1d", 86_400),
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SeriesMetric {
+    Price,
+    Volume,
+    HolderCount,
+    Sentiment,
+    Risk,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesPoint {
+    /// Unix seconds.
+    pub timestamp: i64,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartSeries {
+    pub address: String,
+    pub metric: SeriesMetric,
+    pub from: i64,
+    pub to: i64,
+    /// Points before downsampling.
+    pub source_points: usize,
+    pub points: Vec<SeriesPoint>,
+    pub cached: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct SeriesKey {
+    address: String,
+    metric: SeriesMetric,
+    from: i64,
+    to: i64,
+    max_points: usize,
+}
+
+struct CachedSeries {
+    series: ChartSeries,
+    stored_at: Instant,
+}
+
+lazy_static::lazy_static! {
+    static ref SERIES_CACHE: RwLock<HashMap<SeriesKey, CachedSeries>> =
+        RwLock::new(HashMap::new());
+}
+
+/// Largest-Triangle-Three-Buckets: keeps the first and last point and, from each
+/// bucket in between, the point forming the largest triangle with its neighbours.
+/// Points must be sorted by timestamp.
+pub fn lttb_downsample(points: &[SeriesPoint], threshold: usize) -> Vec<SeriesPoint> {
+    if threshold >= points.len() || threshold < 3 {
+        return points.to_vec();
+    }
+
+    let bucket_size = (points.len() - 2) as f64 / (threshold - 2) as f64;
+    let mut sampled = Vec::with_capacity(threshold);
+    sampled.push(points[0]);
+    let mut selected = 0;
+
+    for bucket in 0..threshold - 2 {
+        let start = (bucket as f64 * bucket_size) as usize + 1;
+        let end = (((bucket + 1) as f64 * bucket_size) as usize + 1).min(points.len() - 1);
+
+        // Average of the next bucket, or the last point for the final bucket
+        let next_start = end;
+        let next_end = (((bucket + 2) as f64 * bucket_size) as usize + 1).min(points.len());
+        let next = &points[next_start..next_end.max(next_start + 1)];
+        let avg_x = next.iter().map(|p| p.timestamp as f64).sum::<f64>() / next.len() as f64;
+        let avg_y = next.iter().map(|p| p.value).sum::<f64>() / next.len() as f64;
+
+        let anchor = points[selected];
+        let (ax, ay) = (anchor.timestamp as f64, anchor.value);
+        let mut best = start;
+        let mut best_area = -1.0;
+        for (index, point) in points.iter().enumerate().take(end).skip(start) {
+            let area = ((ax - avg_x) * (point.value - ay)
+                - (ax - point.timestamp as f64) * (avg_y - ay))
+                .abs();
+            if area > best_area {
+                best_area = area;
+                best = index;
+            }
+        }
+        sampled.push(points[best]);
+        selected = best;
+    }
+
+    sampled.push(points[points.len() - 1]);
+    sampled
+}
+
+/// Finest candle interval that keeps `[from, to]` under the raw candle cap.
+fn candle_interval(from: i64, to: i64) -> &'static str {
+    let span = (to - from).max(1);
+    CANDLE_INTERVALS
+        .iter()
+        .find(|(_, seconds)| span / seconds <= MAX_RAW_CANDLES)
+        .map(|(name, _)| *name)
+        .unwrap_or("1d")
+}
+
+fn unix_seconds(timestamp: &str) -> Option<i64> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|time| time.timestamp())
+}
+
+fn rfc3339(seconds: i64) -> String {
+    Utc.timestamp_opt(seconds, 0)
+        .single()
+        .unwrap_or_else(Utc::now)
+        .to_rfc3339()
+}
+
+async fn load_raw_series(
+    app: &AppHandle,
+    address: &str,
+    metric: SeriesMetric,
+    from: i64,
+    to: i64,
+) -> Result<Vec<SeriesPoint>, String> {
+    match metric {
+        SeriesMetric::Price | SeriesMetric::Volume => {
+            let manager = app
+                .try_state::<SharedHistoricalReplayManager>()
+                .ok_or_else(|| "Historical data unavailable".to_string())?;
+            let request = FetchRequest {
+                symbol: address.to_string(),
+                interval: candle_interval(from, to).to_string(),
+                start_time: from,
+                end_time: to,
+            };
+            let dataset = manager
+                .read()
+                .await
+                .fetch_dataset(request)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(dataset
+                .data
+                .into_iter()
+                .map(|candle| SeriesPoint {
+                    timestamp: candle.timestamp,
+                    value: if metric == SeriesMetric::Price {
+                        candle.close
+                    } else {
+                        candle.volume
+                    },
+                })
+                .collect())
+        }
+        SeriesMetric::HolderCount => {
+            let analyzer = app
+                .try_state::<SharedHolderAnalyzer>()
+                .ok_or_else(|| "Holder analytics unavailable".to_string())?;
+            let rows = analyzer
+                .read()
+                .await
+                .get_holder_count_series(address, &rfc3339(from), &rfc3339(to))
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(rows
+                .into_iter()
+                .filter_map(|(timestamp, count)| {
+                    Some(SeriesPoint {
+                        timestamp: unix_seconds(&timestamp)?,
+                        value: count as f64,
+                    })
+                })
+                .collect())
+        }
+        SeriesMetric::Risk => {
+            let analyzer = app
+                .try_state::<SharedRiskAnalyzer>()
+                .ok_or_else(|| "Risk analyzer unavailable".to_string())?;
+            let history = analyzer
+                .read()
+                .await
+                .get_risk_series(address, &rfc3339(from), &rfc3339(to))
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(history
+                .into_iter()
+                .filter_map(|point| {
+                    Some(SeriesPoint {
+                        timestamp: unix_seconds(&point.timestamp)?,
+                        value: point.score,
+                    })
+                })
+                .collect())
+        }
+        SeriesMetric::Sentiment => {
+            let manager = app
+                .try_state::<SharedSentimentManager>()
+                .ok_or_else(|| "Sentiment data unavailable".to_string())?;
+            let sentiment = manager.read().await.get_token_sentiment(address);
+            let mut points: Vec<SeriesPoint> = sentiment
+                .map(|sentiment| sentiment.trend)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|point| point.timestamp >= from && point.timestamp <= to)
+                .map(|point| SeriesPoint {
+                    timestamp: point.timestamp,
+                    value: point.score as f64,
+                })
+                .collect();
+            points.sort_by_key(|point| point.timestamp);
+            Ok(points)
+        }
+    }
+}
+
+/// Chart-ready series for a token, downsampled to at most `max_points` with
+/// LTTB. `from` and `to` are unix seconds; results are cached briefly per
+/// parameter set.
+#[tauri::command]
+pub async fn get_series(
+    address: String,
+    metric: SeriesMetric,
+    from: i64,
+    to: i64,
+    max_points: Option<usize>,
+    app: AppHandle,
+) -> Result<ChartSeries, String> {
+    if address.trim().is_empty() {
+        return Err("Token address is required".into());
+    }
+    if from >= to {
+        return Err("Series start must be before its end".into());
+    }
+    let max_points = max_points
+        .unwrap_or(DEFAULT_MAX_POINTS)
+        .clamp(3, MAX_POINTS);
+    let key = SeriesKey {
+        address: address.clone(),
+        metric,
+        from,
+        to,
+        max_points,
+    };
+
+    if let Some(entry) = SERIES_CACHE.read().await.get(&key) {
+        if entry.stored_at.elapsed() < SERIES_CACHE_TTL {
+            let mut series = entry.series.clone();
+            series.cached = true;
+            return Ok(series);
+        }
+    }
+
+    let raw = load_raw_series(&app, &address, metric, from, to).await?;
+    let series = ChartSeries {
+        address,
+        metric,
+        from,
+        to,
+        source_points: raw.len(),
+        points: lttb_downsample(&raw, max_points),
+        cached: false,
+    };
+
+    let mut cache = SERIES_CACHE.write().await;
+    cache.retain(|_, entry| entry.stored_at.elapsed() < SERIES_CACHE_TTL);
+    if cache.len() >= SERIES_CACHE_CAPACITY {
+        if let Some(oldest) = cache
+            .iter()
+            .min_by_key(|(_, entry)| entry.stored_at)
+            .map(|(key, _)| key.clone())
+        {
+            cache.remove(&oldest);
+        }
+    }
+    cache.insert(
+        key,
+        CachedSeries {
+            series: series.clone(),
+            stored_at: Instant::now(),
+        },
+    );
+    Ok(series)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(values: &[f64]) -> Vec<SeriesPoint> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| SeriesPoint {
+                timestamp: i as i64 * 60,
+                value: *value,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lttb_keeps_endpoints_and_peaks() {
+        let mut values = vec![1.0; 100];
+        values[37] = 50.0;
+        values[71] = -40.0;
+        let points = series(&values);
+
+        let sampled = lttb_downsample(&points, 10);
+        assert_eq!(sampled.len(), 10);
+        assert_eq!(sampled.first(), points.first());
+        assert_eq!(sampled.last(), points.last());
+        assert!(sampled.iter().any(|p| p.value == 50.0));
+        assert!(sampled.iter().any(|p| p.value == -40.0));
+        assert!(sampled.windows(2).all(|w| w[0].timestamp < w[1].timestamp));
+    }
+
+    #[test]
+    fn short_series_are_returned_unchanged() {
+        let points = series(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(lttb_downsample(&points, 10), points);
+        assert_eq!(lttb_downsample(&points, 2), points);
+        assert!(lttb_downsample(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn candle_interval_scales_with_range() {
+        assert_eq!(candle_interval(0, 86_400), "1m");
+        assert_eq!(candle_interval(0, 86_400 * 30), "5m");
+        assert_eq!(candle_interval(0, 86_400 * 365), "1h");
+        assert_eq!(candle_interval(0, 86_400 * 365 * 200), "1d");
+    }
+}
@@ -0,0 +1,512 @@
+use image::codecs::webp::WebPEncoder;
+use image::io::{Limits, Reader as ImageReader};
+use image::{ColorType, ImageFormat};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tauri::http::{Request as HttpRequest, Response as HttpResponse, ResponseBuilder};
+use tauri::{AppHandle, Manager, State};
+use url::{Host, Url};
+
+/// Logos are served as `tokenimg://localhost/<encoded source url>`; the
+/// frontend builds these with `convertFileSrc(url, 'tokenimg')`.
+pub const TOKEN_IMAGE_PROTOCOL: &str = "tokenimg";
+const TOKEN_IMAGE_DIR: &str = "token_images";
+const IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+const MAX_DOWNLOAD_BYTES: usize = 2 * 1024 * 1024;
+const MAX_SOURCE_DIMENSION: u32 = 4096;
+const THUMBNAIL_SIZE: u32 = 128;
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_REDIRECTS: usize = 5;
+pub const DEFAULT_TOKEN_IMAGE_CACHE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// SVG is deliberately missing: it can carry script and has to be rasterized.
+const ALLOWED_CONTENT_TYPES: &[&str] = &["image/png", "image/jpeg", "image/gif", "image/webp"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum TokenImageError {
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid image url: {0}")]
+    InvalidUrl(String),
+    #[error("unsupported image type: {0}")]
+    UnsupportedType(String),
+    #[error("image larger than {0} bytes")]
+    TooLarge(usize),
+    #[error("image decode failed: {0}")]
+    Decode(#[from] image::ImageError),
+    #[error("image fetch failed with status {0}")]
+    Status(u16),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenImageCacheStats {
+    pub entries: usize,
+    pub total_bytes: u64,
+    pub max_bytes: u64,
+}
+
+/// Only plain http(s) URLs on public hosts are fetched, so a token's metadata
+/// can't point the app at the local network. `ipfs://` goes through a gateway.
+pub fn normalize_image_url(source: &str) -> Result<Url, TokenImageError> {
+    let source = source.trim();
+    let source = match source.strip_prefix("ipfs://") {
+        Some(cid) => format!("{IPFS_GATEWAY}{}", cid.trim_start_matches("ipfs/")),
+        None => source.to_string(),
+    };
+    let url = Url::parse(&source).map_err(|e| TokenImageError::InvalidUrl(e.to_string()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(TokenImageError::InvalidUrl(format!(
+            "scheme {} is not allowed",
+            url.scheme()
+        )));
+    }
+
+    let private = match url.host() {
+        Some(Host::Domain(domain)) => {
+            domain.eq_ignore_ascii_case("localhost") || domain.ends_with(".localhost")
+        }
+        Some(Host::Ipv4(ip)) => is_private_ip(IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => is_private_ip(IpAddr::V6(ip)),
+        None => true,
+    };
+    if private {
+        return Err(TokenImageError::InvalidUrl(
+            "local hosts are not allowed".to_string(),
+        ));
+    }
+    Ok(url)
+}
+
+fn is_private_ip(ip: IpAddr) -> bool {
+    if let IpAddr::V6(v6) = ip {
+        if let Some(v4) = v6.to_ipv4_mapped() {
+            return is_private_ip(IpAddr::V4(v4));
+        }
+    }
+    match ip {
+        IpAddr::V4(ip) => {
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+        }
+        IpAddr::V6(ip) => {
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || (ip.segments()[0] & 0xfe00) == 0xfc00
+                || (ip.segments()[0] & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/// Rejects a host if any of its addresses is local, so a public name can't
+/// be pointed (or rebound) at the local network.
+fn public_addrs(
+    host: &str,
+    addrs: Vec<SocketAddr>,
+) -> Result<Vec<SocketAddr>, TokenImageError> {
+    if addrs.is_empty() || addrs.iter().any(|addr| is_private_ip(addr.ip())) {
+        return Err(TokenImageError::InvalidUrl(format!(
+            "{host} does not resolve to a public address"
+        )));
+    }
+    Ok(addrs)
+}
+
+struct PublicResolver;
+
+impl reqwest::dns::Resolve for PublicResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        Box::pin(async move {
+            let host = name.as_str();
+            let addrs = tokio::net::lookup_host((host, 0)).await?.collect();
+            let addrs = public_addrs(host, addrs)?;
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// Every hop gets the same checks as the original URL.
+fn redirect_policy() -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::custom(|attempt| {
+        if attempt.previous().len() >= MAX_REDIRECTS {
+            return attempt.error("too many redirects");
+        }
+        match normalize_image_url(attempt.url().as_str()) {
+            Ok(_) => attempt.follow(),
+            Err(err) => attempt.error(err),
+        }
+    })
+}
+
+/// Checks the declared type, then the bytes themselves, since hosts often
+/// serve images as `application/octet-stream` or lie about the format.
+pub fn validate_image(
+    content_type: Option<&str>,
+    bytes: &[u8],
+) -> Result<ImageFormat, TokenImageError> {
+    if let Some(content_type) = content_type {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        let allowed =
+            ALLOWED_CONTENT_TYPES.contains(&mime.as_str()) || mime == "application/octet-stream";
+        if !allowed {
+            return Err(TokenImageError::UnsupportedType(mime));
+        }
+    }
+
+    let format = image::guess_format(bytes)
+        .map_err(|_| TokenImageError::UnsupportedType("unrecognized image data".to_string()))?;
+    match format {
+        ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::Gif | ImageFormat::WebP => Ok(format),
+        other => Err(TokenImageError::UnsupportedType(format!("{other:?}"))),
+    }
+}
+
+/// Decodes with size limits, so a tiny file declaring huge dimensions can't
+/// exhaust memory, and re-encodes as a lossless webp thumbnail.
+pub fn make_thumbnail(bytes: &[u8], format: ImageFormat) -> Result<Vec<u8>, TokenImageError> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_SOURCE_DIMENSION);
+    limits.max_image_height = Some(MAX_SOURCE_DIMENSION);
+
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    reader.limits(limits);
+    let thumbnail = reader
+        .decode()?
+        .thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+        .to_rgba8();
+
+    let mut encoded = Vec::new();
+    WebPEncoder::new_lossless(&mut encoded).encode(
+        thumbnail.as_raw(),
+        thumbnail.width(),
+        thumbnail.height(),
+        ColorType::Rgba8,
+    )?;
+    Ok(encoded)
+}
+
+fn cache_key(url: &Url) -> String {
+    hex::encode(&Sha256::digest(url.as_str().as_bytes())[..16])
+}
+
+/// Logo thumbnails stored under app data. Files are evicted least recently
+/// served first once the cache outgrows `max_bytes`.
+pub struct TokenImageCache {
+    dir: PathBuf,
+    http: reqwest::Client,
+    max_bytes: u64,
+}
+
+pub type SharedTokenImageCache = Arc<TokenImageCache>;
+
+impl TokenImageCache {
+    pub fn new(app: &AppHandle) -> Result<Self, TokenImageError> {
+        let dir = app
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| {
+                TokenImageError::Io(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "Unable to resolve app data directory",
+                ))
+            })?
+            .join(TOKEN_IMAGE_DIR);
+        Self::with_dir(dir, DEFAULT_TOKEN_IMAGE_CACHE_BYTES)
+    }
+
+    pub fn with_dir(dir: PathBuf, max_bytes: u64) -> Result<Self, TokenImageError> {
+        std::fs::create_dir_all(&dir)?;
+        let http = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .redirect(redirect_policy())
+            .dns_resolver(Arc::new(PublicResolver))
+            .build()?;
+        Ok(Self {
+            dir,
+            http,
+            max_bytes,
+        })
+    }
+
+    fn path_for(&self, url: &Url) -> PathBuf {
+        self.dir.join(format!("{}.webp", cache_key(url)))
+    }
+
+    /// Reads a cached thumbnail and marks it as recently used.
+    pub fn cached(&self, url: &Url) -> Option<Vec<u8>> {
+        let path = self.path_for(url);
+        let bytes = std::fs::read(&path).ok()?;
+        if let Ok(file) = std::fs::File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(bytes)
+    }
+
+    pub fn store(&self, url: &Url, thumbnail: &[u8]) -> Result<(), TokenImageError> {
+        let path = self.path_for(url);
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, thumbnail)?;
+        std::fs::rename(&tmp, &path)?;
+        self.evict()?;
+        Ok(())
+    }
+
+    /// Returns the thumbnail, downloading and converting it on a miss.
+    pub async fn get_or_fetch(&self, source: &str) -> Result<Vec<u8>, TokenImageError> {
+        let url = normalize_image_url(source)?;
+        if let Some(bytes) = self.cached(&url) {
+            return Ok(bytes);
+        }
+
+        let mut response = self.http.get(url.clone()).send().await?;
+        if !response.status().is_success() {
+            return Err(TokenImageError::Status(response.status().as_u16()));
+        }
+        if response
+            .content_length()
+            .map_or(false, |len| len as usize > MAX_DOWNLOAD_BYTES)
+        {
+            return Err(TokenImageError::TooLarge(MAX_DOWNLOAD_BYTES));
+        }
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        // Content-Length is optional, so the limit is enforced while reading too
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if body.len() + chunk.len() > MAX_DOWNLOAD_BYTES {
+                return Err(TokenImageError::TooLarge(MAX_DOWNLOAD_BYTES));
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        let format = validate_image(content_type.as_deref(), &body)?;
+        let thumbnail = make_thumbnail(&body, format)?;
+        self.store(&url, &thumbnail)?;
+        Ok(thumbnail)
+    }
+
+    fn entries(&self) -> Result<Vec<(PathBuf, u64, SystemTime)>, TokenImageError> {
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_file() {
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                entries.push((entry.path(), metadata.len(), modified));
+            }
+        }
+        Ok(entries)
+    }
+
+    pub fn evict(&self) -> Result<usize, TokenImageError> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|(_, size, _)| size).sum();
+        if total <= self.max_bytes {
+            return Ok(0);
+        }
+
+        entries.sort_by_key(|(_, _, modified)| *modified);
+        let mut removed = 0;
+        for (path, size, _) in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            std::fs::remove_file(&path)?;
+            total = total.saturating_sub(size);
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
+    pub fn stats(&self) -> Result<TokenImageCacheStats, TokenImageError> {
+        let entries = self.entries()?;
+        Ok(TokenImageCacheStats {
+            entries: entries.len(),
+            total_bytes: entries.iter().map(|(_, size, _)| size).sum(),
+            max_bytes: self.max_bytes,
+        })
+    }
+
+    pub fn clear(&self) -> Result<(), TokenImageError> {
+        for (path, _, _) in self.entries()? {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Source URL carried in the path of a `tokenimg` request. Windows serves
+/// custom protocols as `https://tokenimg.localhost/...`, so only the path is
+/// read.
+fn requested_source(uri: &str) -> Option<String> {
+    let path = Url::parse(uri)
+        .ok()?
+        .path()
+        .trim_start_matches('/')
+        .to_string();
+    let decoded = url::form_urlencoded::parse(format!("s={path}").as_bytes())
+        .next()
+        .map(|(_, value)| value.into_owned())?;
+    (!decoded.is_empty()).then_some(decoded)
+}
+
+/// Serves cached thumbnails. Protocol handlers run synchronously, so a miss
+/// starts the download in the background and answers 404; the image shows
+/// on the next render, or immediately when the page awaits `cache_token_image`.
+pub fn token_image_protocol(
+    app: &AppHandle,
+    request: &HttpRequest,
+) -> Result<HttpResponse, Box<dyn std::error::Error>> {
+    let Some(cache) = app.try_state::<SharedTokenImageCache>() else {
+        return ResponseBuilder::new().status(503).body(Vec::new());
+    };
+    let url = match requested_source(request.uri()).map(|source| normalize_image_url(&source)) {
+        Some(Ok(url)) => url,
+        _ => return ResponseBuilder::new().status(400).body(Vec::new()),
+    };
+
+    if let Some(bytes) = cache.cached(&url) {
+        return ResponseBuilder::new()
+            .status(200)
+            .mimetype("image/webp")
+            .header("Cache-Control", "max-age=86400")
+            .body(bytes);
+    }
+
+    let cache = cache.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(err) = cache.get_or_fetch(url.as_str()).await {
+            tracing::debug!(error = %err, url = %url, "token image fetch failed");
+        }
+    });
+    ResponseBuilder::new()
+        .status(404)
+        .header("Cache-Control", "no-store")
+        .body(Vec::new())
+}
+
+/// Downloads a logo into the cache so the protocol URL resolves on first use.
+#[tauri::command]
+pub async fn cache_token_image(
+    url: String,
+    cache: State<'_, SharedTokenImageCache>,
+) -> Result<(), String> {
+    cache
+        .get_or_fetch(&url)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_token_image_cache_stats(
+    cache: State<'_, SharedTokenImageCache>,
+) -> Result<TokenImageCacheStats, String> {
+    cache.stats().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn clear_token_image_cache(
+    cache: State<'_, SharedTokenImageCache>,
+) -> Result<(), String> {
+    cache.clear().map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageOutputFormat, RgbaImage};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image::DynamicImage::ImageRgba8(RgbaImage::new(width, height))
+            .write_to(&mut Cursor::new(&mut bytes), ImageOutputFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn only_public_http_urls_are_fetched() {
+        assert!(normalize_image_url("https://arweave.net/logo.png").is_ok());
+        assert_eq!(
+            normalize_image_url("ipfs://bafy123/logo.png")
+                .unwrap()
+                .as_str(),
+            "https://ipfs.io/ipfs/bafy123/logo.png"
+        );
+        assert!(normalize_image_url("file:///etc/passwd").is_err());
+        assert!(normalize_image_url("http://localhost:1420/logo.png").is_err());
+        assert!(normalize_image_url("http://192.168.1.10/logo.png").is_err());
+        assert!(normalize_image_url("http://[::1]/logo.png").is_err());
+        assert!(normalize_image_url("http://[::ffff:127.0.0.1]/logo.png").is_err());
+        assert!(normalize_image_url("http://[fe80::1]/logo.png").is_err());
+    }
+
+    #[test]
+    fn hosts_resolving_to_local_addresses_are_rejected() {
+        let public: SocketAddr = "93.184.216.34:0".parse().unwrap();
+        let local: SocketAddr = "10.0.0.5:0".parse().unwrap();
+        assert_eq!(public_addrs("cdn.example", vec![public]).unwrap(), vec![public]);
+        assert!(public_addrs("rebind.example", vec![public, local]).is_err());
+        assert!(public_addrs("empty.example", Vec::new()).is_err());
+    }
+
+    #[test]
+    fn validates_declared_and_sniffed_type() {
+        let bytes = png(4, 4);
+        assert_eq!(
+            validate_image(Some("image/png"), &bytes).unwrap(),
+            ImageFormat::Png
+        );
+        assert!(validate_image(Some("application/octet-stream"), &bytes).is_ok());
+        assert!(validate_image(Some("image/svg+xml"), &bytes).is_err());
+        assert!(validate_image(Some("text/html"), &bytes).is_err());
+        assert!(validate_image(Some("image/png"), b"<svg></svg>").is_err());
+    }
+
+    #[test]
+    fn thumbnails_are_webp_and_cache_evicts_oldest() {
+        let thumbnail = make_thumbnail(&png(512, 256), ImageFormat::Png).unwrap();
+        assert_eq!(image::guess_format(&thumbnail).unwrap(), ImageFormat::WebP);
+        let decoded = image::load_from_memory(&thumbnail).unwrap();
+        assert_eq!(
+            (decoded.width(), decoded.height()),
+            (THUMBNAIL_SIZE, THUMBNAIL_SIZE / 2)
+        );
+
+        let dir = std::env::temp_dir().join(format!("token-images-{}", uuid::Uuid::new_v4()));
+        let cache = TokenImageCache::with_dir(dir.clone(), thumbnail.len() as u64 * 2).unwrap();
+        let urls: Vec<Url> = (0..3)
+            .map(|i| Url::parse(&format!("https://example.com/{i}.png")).unwrap())
+            .collect();
+        for url in &urls {
+            cache.store(url, &thumbnail).unwrap();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+
+        assert_eq!(cache.stats().unwrap().entries, 2);
+        assert!(cache.cached(&urls[0]).is_none());
+        assert!(cache.cached(&urls[2]).is_some());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
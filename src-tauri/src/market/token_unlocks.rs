@@ -0,0 +1,965 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use crate::notifications::router::SharedNotificationRouter;
+use crate::portfolio::SharedWatchlistManager;
+use crate::wallet::multi_wallet::MultiWalletManager;
+use crate::wallet::operations::WalletOperationsManager;
+use crate::webhooks::{publish_event, EventSeverity, WebhookEvent, WebhookEventType};
+
+const TOKEN_UNLOCKS_DB_FILE: &str = "token_unlocks.db";
+const ALERT_CHECK_INTERVAL_SECS: u64 = 3600;
+const MAX_WINDOW_DAYS: i64 = 365;
+pub const TOKEN_UNLOCK_ALERT_EVENT: &str = "token_unlock_alert";
+
+#[derive(Debug, thiserror::Error)]
+pub enum TokenUnlockError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("dataset request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("schedule not found: {0}")]
+    NotFound(String),
+    #[error("invalid schedule: {0}")]
+    Invalid(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnlockSource {
+    User,
+    Community,
+}
+
+impl UnlockSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            UnlockSource::User => "user",
+            UnlockSource::Community => "community",
+        }
+    }
+
+    fn from_str(value: &str) -> Self {
+        match value {
+            "community" => UnlockSource::Community,
+            _ => UnlockSource::User,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnlockCategory {
+    Team,
+    Investors,
+    Ecosystem,
+    Treasury,
+    Community,
+    Emission,
+    Other,
+}
+
+/// How the tokens are released: a single cliff, or equal tranches between two dates.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum UnlockScheduleKind {
+    Cliff {
+        unlock_at: DateTime<Utc>,
+        amount: f64,
+    },
+    Linear {
+        start_at: DateTime<Utc>,
+        end_at: DateTime<Utc>,
+        interval_days: u32,
+        total_amount: f64,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockSchedule {
+    pub id: String,
+    pub mint: String,
+    pub symbol: String,
+    pub category: UnlockCategory,
+    pub source: UnlockSource,
+    pub schedule: UnlockScheduleKind,
+    pub total_supply: Option<f64>,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockScheduleInput {
+    pub id: Option<String>,
+    pub mint: String,
+    pub symbol: String,
+    pub category: UnlockCategory,
+    pub schedule: UnlockScheduleKind,
+    pub total_supply: Option<f64>,
+    pub description: Option<String>,
+}
+
+/// One dated release produced by expanding a schedule.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockEvent {
+    pub id: String,
+    pub schedule_id: String,
+    pub mint: String,
+    pub symbol: String,
+    pub category: UnlockCategory,
+    pub source: UnlockSource,
+    pub unlock_at: DateTime<Utc>,
+    pub amount: f64,
+    pub percent_of_supply: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpcomingUnlock {
+    #[serde(flatten)]
+    pub event: UnlockEvent,
+    pub estimated_usd_value: Option<f64>,
+    pub held: bool,
+    pub watchlisted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockAlertSettings {
+    pub enabled: bool,
+    /// How far ahead of an unlock to alert.
+    pub lead_days: u32,
+    /// An unlock counts as large at or above either threshold.
+    pub min_percent_of_supply: f64,
+    pub min_usd_value: Option<f64>,
+}
+
+impl Default for UnlockAlertSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            lead_days: 3,
+            min_percent_of_supply: 1.0,
+            min_usd_value: Some(1_000_000.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockImportSummary {
+    pub imported: usize,
+    pub replaced: usize,
+}
+
+/// Per-mint context used to flag and value unlocks.
+#[derive(Debug, Clone, Default)]
+pub struct UnlockRelevance {
+    pub held: HashSet<String>,
+    pub watchlisted: HashSet<String>,
+    pub prices: HashMap<String, f64>,
+}
+
+/// Expands a schedule into the releases that fall inside `[from, to]`.
+pub fn expand_schedule(
+    schedule: &UnlockSchedule,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<UnlockEvent> {
+    let tranches: Vec<(DateTime<Utc>, f64)> = match &schedule.schedule {
+        UnlockScheduleKind::Cliff { unlock_at, amount } => vec![(*unlock_at, *amount)],
+        UnlockScheduleKind::Linear {
+            start_at,
+            end_at,
+            interval_days,
+            total_amount,
+        } => {
+            let step = Duration::days((*interval_days).max(1) as i64);
+            let mut dates = Vec::new();
+            let mut at = *start_at;
+            while at <= *end_at {
+                dates.push(at);
+                at = at + step;
+            }
+            let per_tranche = if dates.is_empty() {
+                0.0
+            } else {
+                total_amount / dates.len() as f64
+            };
+            dates.into_iter().map(|at| (at, per_tranche)).collect()
+        }
+    };
+
+    tranches
+        .into_iter()
+        .filter(|(at, _)| *at >= from && *at <= to)
+        .map(|(unlock_at, amount)| UnlockEvent {
+            id: format!("{}:{}", schedule.id, unlock_at.timestamp()),
+            schedule_id: schedule.id.clone(),
+            mint: schedule.mint.clone(),
+            symbol: schedule.symbol.clone(),
+            category: schedule.category,
+            source: schedule.source,
+            unlock_at,
+            amount,
+            percent_of_supply: schedule
+                .total_supply
+                .filter(|supply| *supply > 0.0)
+                .map(|supply| amount / supply * 100.0),
+        })
+        .collect()
+}
+
+pub fn annotate_unlock(event: UnlockEvent, relevance: &UnlockRelevance) -> UpcomingUnlock {
+    UpcomingUnlock {
+        estimated_usd_value: relevance.prices.get(&event.mint).map(|price| price * event.amount),
+        held: relevance.held.contains(&event.mint),
+        watchlisted: relevance.watchlisted.contains(&event.mint),
+        event,
+    }
+}
+
+pub fn is_large_unlock(unlock: &UpcomingUnlock, settings: &UnlockAlertSettings) -> bool {
+    let by_supply = unlock
+        .event
+        .percent_of_supply
+        .map(|percent| percent >= settings.min_percent_of_supply)
+        .unwrap_or(false);
+    let by_value = match (unlock.estimated_usd_value, settings.min_usd_value) {
+        (Some(value), Some(min)) => value >= min,
+        _ => false,
+    };
+    by_supply || by_value
+}
+
+fn validate_schedule(kind: &UnlockScheduleKind) -> Result<(), TokenUnlockError> {
+    match kind {
+        UnlockScheduleKind::Cliff { amount, .. } if *amount <= 0.0 => Err(
+            TokenUnlockError::Invalid("unlock amount must be positive".to_string()),
+        ),
+        UnlockScheduleKind::Linear {
+            start_at,
+            end_at,
+            interval_days,
+            total_amount,
+        } => {
+            if end_at < start_at {
+                return Err(TokenUnlockError::Invalid(
+                    "linear schedule ends before it starts".to_string(),
+                ));
+            }
+            if *interval_days == 0 || *total_amount <= 0.0 {
+                return Err(TokenUnlockError::Invalid(
+                    "linear schedule needs a positive interval and amount".to_string(),
+                ));
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+pub struct TokenUnlockManager {
+    pool: Pool<Sqlite>,
+    http: reqwest::Client,
+}
+
+pub type SharedTokenUnlockManager = Arc<RwLock<TokenUnlockManager>>;
+
+impl TokenUnlockManager {
+    pub async fn new(app: &AppHandle) -> Result<Self, TokenUnlockError> {
+        let db_path = token_unlocks_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let manager = Self {
+            pool,
+            http: reqwest::Client::new(),
+        };
+        manager.initialize().await?;
+        Ok(manager)
+    }
+
+    async fn initialize(&self) -> Result<(), TokenUnlockError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS unlock_schedules (
+                id TEXT PRIMARY KEY,
+                mint TEXT NOT NULL,
+                symbol TEXT NOT NULL,
+                category TEXT NOT NULL,
+                source TEXT NOT NULL,
+                schedule TEXT NOT NULL,
+                total_supply REAL,
+                description TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_unlock_schedules_mint ON unlock_schedules(mint)")
+            .execute(&self.pool)
+            .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS unlock_alerts_sent (
+                event_id TEXT PRIMARY KEY,
+                sent_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS unlock_alert_settings (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                settings TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    fn row_to_schedule(row: &SqliteRow) -> Result<UnlockSchedule, TokenUnlockError> {
+        let category: String = row.try_get("category")?;
+        let source: String = row.try_get("source")?;
+        let schedule: String = row.try_get("schedule")?;
+        let created_at: String = row.try_get("created_at")?;
+        let updated_at: String = row.try_get("updated_at")?;
+
+        Ok(UnlockSchedule {
+            id: row.try_get("id")?,
+            mint: row.try_get("mint")?,
+            symbol: row.try_get("symbol")?,
+            category: serde_json::from_value(serde_json::Value::String(category))?,
+            source: UnlockSource::from_str(&source),
+            schedule: serde_json::from_str(&schedule)?,
+            total_supply: row.try_get("total_supply")?,
+            description: row.try_get("description")?,
+            created_at: parse_timestamp(&created_at)?,
+            updated_at: parse_timestamp(&updated_at)?,
+        })
+    }
+
+    async fn upsert(&self, schedule: &UnlockSchedule) -> Result<(), TokenUnlockError> {
+        let category = serde_json::to_value(schedule.category)?
+            .as_str()
+            .unwrap_or("other")
+            .to_string();
+
+        sqlx::query(
+            r#"
+            INSERT INTO unlock_schedules (
+                id, mint, symbol, category, source, schedule, total_supply,
+                description, created_at, updated_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                mint = excluded.mint,
+                symbol = excluded.symbol,
+                category = excluded.category,
+                source = excluded.source,
+                schedule = excluded.schedule,
+                total_supply = excluded.total_supply,
+                description = excluded.description,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&schedule.id)
+        .bind(&schedule.mint)
+        .bind(&schedule.symbol)
+        .bind(category)
+        .bind(schedule.source.as_str())
+        .bind(serde_json::to_string(&schedule.schedule)?)
+        .bind(schedule.total_supply)
+        .bind(&schedule.description)
+        .bind(schedule.created_at.to_rfc3339())
+        .bind(schedule.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_schedules(&self, mint: Option<&str>) -> Result<Vec<UnlockSchedule>, TokenUnlockError> {
+        let rows = match mint {
+            Some(mint) => {
+                sqlx::query("SELECT * FROM unlock_schedules WHERE mint = ? ORDER BY symbol")
+                    .bind(mint)
+                    .fetch_all(&self.pool)
+                    .await?
+            }
+            None => {
+                sqlx::query("SELECT * FROM unlock_schedules ORDER BY symbol")
+                    .fetch_all(&self.pool)
+                    .await?
+            }
+        };
+
+        rows.iter().map(Self::row_to_schedule).collect()
+    }
+
+    pub async fn save_schedule(
+        &self,
+        input: UnlockScheduleInput,
+    ) -> Result<UnlockSchedule, TokenUnlockError> {
+        validate_schedule(&input.schedule)?;
+        let now = Utc::now();
+
+        let existing = match &input.id {
+            Some(id) => {
+                let row = sqlx::query("SELECT * FROM unlock_schedules WHERE id = ?")
+                    .bind(id)
+                    .fetch_optional(&self.pool)
+                    .await?
+                    .ok_or_else(|| TokenUnlockError::NotFound(id.clone()))?;
+                Some(Self::row_to_schedule(&row)?)
+            }
+            None => None,
+        };
+
+        // Editing a community entry turns it into the user's own copy so re-imports
+        // don't overwrite the correction.
+        let schedule = UnlockSchedule {
+            id: input.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+            mint: input.mint,
+            symbol: input.symbol,
+            category: input.category,
+            source: UnlockSource::User,
+            schedule: input.schedule,
+            total_supply: input.total_supply,
+            description: input.description,
+            created_at: existing.map(|s| s.created_at).unwrap_or(now),
+            updated_at: now,
+        };
+
+        self.upsert(&schedule).await?;
+        Ok(schedule)
+    }
+
+    pub async fn delete_schedule(&self, id: &str) -> Result<(), TokenUnlockError> {
+        let result = sqlx::query("DELETE FROM unlock_schedules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(TokenUnlockError::NotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Replaces all community entries with the given dataset. User entries are untouched.
+    pub async fn import_community(
+        &self,
+        entries: Vec<UnlockScheduleInput>,
+    ) -> Result<UnlockImportSummary, TokenUnlockError> {
+        for entry in &entries {
+            validate_schedule(&entry.schedule)?;
+        }
+
+        let replaced = sqlx::query("DELETE FROM unlock_schedules WHERE source = ?")
+            .bind(UnlockSource::Community.as_str())
+            .execute(&self.pool)
+            .await?
+            .rows_affected() as usize;
+
+        let user_ids: HashSet<String> = self
+            .list_schedules(None)
+            .await?
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+
+        let now = Utc::now();
+        let mut imported = 0;
+        for entry in entries {
+            let id = entry
+                .id
+                .unwrap_or_else(|| format!("community:{}:{}", entry.mint, imported));
+            if user_ids.contains(&id) {
+                continue;
+            }
+
+            self.upsert(&UnlockSchedule {
+                id,
+                mint: entry.mint,
+                symbol: entry.symbol,
+                category: entry.category,
+                source: UnlockSource::Community,
+                schedule: entry.schedule,
+                total_supply: entry.total_supply,
+                description: entry.description,
+                created_at: now,
+                updated_at: now,
+            })
+            .await?;
+            imported += 1;
+        }
+
+        Ok(UnlockImportSummary { imported, replaced })
+    }
+
+    pub async fn import_community_from_url(
+        &self,
+        url: &str,
+    ) -> Result<UnlockImportSummary, TokenUnlockError> {
+        if !url.starts_with("https://") {
+            return Err(TokenUnlockError::Invalid(
+                "dataset url must use https".to_string(),
+            ));
+        }
+
+        let entries: Vec<UnlockScheduleInput> = self
+            .http
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        self.import_community(entries).await
+    }
+
+    pub async fn upcoming_unlocks(
+        &self,
+        window_days: i64,
+        relevance: &UnlockRelevance,
+    ) -> Result<Vec<UpcomingUnlock>, TokenUnlockError> {
+        let now = Utc::now();
+        let until = now + Duration::days(window_days.clamp(1, MAX_WINDOW_DAYS));
+
+        let mut unlocks: Vec<UpcomingUnlock> = self
+            .list_schedules(None)
+            .await?
+            .iter()
+            .flat_map(|schedule| expand_schedule(schedule, now, until))
+            .map(|event| annotate_unlock(event, relevance))
+            .collect();
+
+        unlocks.sort_by(|a, b| a.event.unlock_at.cmp(&b.event.unlock_at));
+        Ok(unlocks)
+    }
+
+    pub async fn alert_settings(&self) -> Result<UnlockAlertSettings, TokenUnlockError> {
+        let row = sqlx::query("SELECT settings FROM unlock_alert_settings WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match row {
+            Some(row) => {
+                let settings: String = row.try_get("settings")?;
+                Ok(serde_json::from_str(&settings)?)
+            }
+            None => Ok(UnlockAlertSettings::default()),
+        }
+    }
+
+    pub async fn set_alert_settings(
+        &self,
+        settings: &UnlockAlertSettings,
+    ) -> Result<(), TokenUnlockError> {
+        sqlx::query(
+            r#"
+            INSERT INTO unlock_alert_settings (id, settings) VALUES (1, ?)
+            ON CONFLICT(id) DO UPDATE SET settings = excluded.settings
+            "#,
+        )
+        .bind(serde_json::to_string(settings)?)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Large upcoming unlocks on held or watchlisted tokens that haven't been alerted yet.
+    /// Marks the returned events as sent.
+    pub async fn take_pending_alerts(
+        &self,
+        relevance: &UnlockRelevance,
+    ) -> Result<Vec<UpcomingUnlock>, TokenUnlockError> {
+        let settings = self.alert_settings().await?;
+        if !settings.enabled {
+            return Ok(Vec::new());
+        }
+
+        let candidates = self
+            .upcoming_unlocks(settings.lead_days.max(1) as i64, relevance)
+            .await?;
+
+        let mut pending = Vec::new();
+        for unlock in candidates {
+            if !(unlock.held || unlock.watchlisted) || !is_large_unlock(&unlock, &settings) {
+                continue;
+            }
+
+            let inserted = sqlx::query(
+                "INSERT OR IGNORE INTO unlock_alerts_sent (event_id, sent_at) VALUES (?, ?)",
+            )
+            .bind(&unlock.event.id)
+            .bind(Utc::now().to_rfc3339())
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+
+            if inserted > 0 {
+                pending.push(unlock);
+            }
+        }
+
+        Ok(pending)
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, TokenUnlockError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| TokenUnlockError::Internal(format!("invalid timestamp {}: {}", value, e)))
+}
+
+fn token_unlocks_db_path(app: &AppHandle) -> Result<PathBuf, TokenUnlockError> {
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| TokenUnlockError::Internal("Unable to resolve app data directory".to_string()))?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(TOKEN_UNLOCKS_DB_FILE))
+}
+
+/// Held mints (with a spot price derived from cached balances) and watchlisted mints.
+pub async fn collect_relevance(app: &AppHandle) -> UnlockRelevance {
+    let mut relevance = UnlockRelevance::default();
+
+    if let (Some(wallets), Some(operations)) = (
+        app.try_state::<MultiWalletManager>(),
+        app.try_state::<WalletOperationsManager>(),
+    ) {
+        match wallets.list_wallets() {
+            Ok(list) => {
+                for wallet in list {
+                    for balance in operations.cached_token_balances(&wallet.public_key) {
+                        if balance.balance > 0.0 {
+                            relevance
+                                .prices
+                                .insert(balance.mint.clone(), balance.usd_value / balance.balance);
+                        }
+                        relevance.held.insert(balance.mint);
+                    }
+                }
+            }
+            Err(err) => tracing::warn!(error = %err, "failed to list wallets for unlock alerts"),
+        }
+    }
+
+    if let Some(watchlists) = app.try_state::<SharedWatchlistManager>() {
+        match watchlists.read().await.list_watchlists().await {
+            Ok(lists) => {
+                for item in lists.into_iter().flat_map(|list| list.items) {
+                    relevance.watchlisted.insert(item.mint);
+                }
+            }
+            Err(err) => tracing::warn!(error = %err, "failed to load watchlists for unlock alerts"),
+        }
+    }
+
+    relevance
+}
+
+async fn send_unlock_alert(app: &AppHandle, unlock: &UpcomingUnlock) {
+    if let Err(err) = app.emit_all(TOKEN_UNLOCK_ALERT_EVENT, unlock) {
+        tracing::warn!(error = %err, "failed to emit token unlock alert");
+    }
+
+    let mut details = Vec::new();
+    if let Some(percent) = unlock.event.percent_of_supply {
+        details.push(format!("{:.2}% of supply", percent));
+    }
+    if let Some(value) = unlock.estimated_usd_value {
+        details.push(format!("~${:.0}", value));
+    }
+    let message = format!(
+        "{} unlocks {:.0} tokens on {}{} ({})",
+        unlock.event.symbol,
+        unlock.event.amount,
+        unlock.event.unlock_at.format("%Y-%m-%d %H:%M UTC"),
+        if details.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", details.join(", "))
+        },
+        if unlock.held { "held" } else { "watchlisted" },
+    );
+
+    let mut event = WebhookEvent::new(
+        WebhookEventType::TokenUnlock,
+        if unlock.held {
+            EventSeverity::Warning
+        } else {
+            EventSeverity::Info
+        },
+        "Upcoming token unlock",
+        message.clone(),
+    )
+    .with_token(unlock.event.mint.clone())
+    .with_data(serde_json::to_value(unlock).unwrap_or_default());
+    if let Some(value) = unlock.estimated_usd_value {
+        event = event.with_notional(value);
+    }
+    publish_event(app, event);
+
+    let Some(router) = app.try_state::<SharedNotificationRouter>() else {
+        return;
+    };
+
+    if let Err(err) = router
+        .read()
+        .await
+        .send_broadcast_notification(&unlock.event.id, "Upcoming token unlock", &message)
+        .await
+    {
+        tracing::warn!(error = %err, "failed to deliver token unlock notification");
+    }
+}
+
+pub fn start_unlock_alerts(app: AppHandle, manager: SharedTokenUnlockManager) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(ALERT_CHECK_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            let relevance = collect_relevance(&app).await;
+            let pending = manager.read().await.take_pending_alerts(&relevance).await;
+            match pending {
+                Ok(unlocks) => {
+                    for unlock in &unlocks {
+                        send_unlock_alert(&app, unlock).await;
+                    }
+                }
+                Err(err) => tracing::warn!(error = %err, "token unlock alert check failed"),
+            }
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn get_upcoming_unlocks(
+    window_days: Option<i64>,
+    relevant_only: Option<bool>,
+    app_handle: AppHandle,
+    manager: State<'_, SharedTokenUnlockManager>,
+) -> Result<Vec<UpcomingUnlock>, String> {
+    let relevance = collect_relevance(&app_handle).await;
+    let unlocks = manager
+        .read()
+        .await
+        .upcoming_unlocks(window_days.unwrap_or(30), &relevance)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(if relevant_only.unwrap_or(false) {
+        unlocks.into_iter().filter(|u| u.held || u.watchlisted).collect()
+    } else {
+        unlocks
+    })
+}
+
+#[tauri::command]
+pub async fn list_unlock_schedules(
+    mint: Option<String>,
+    manager: State<'_, SharedTokenUnlockManager>,
+) -> Result<Vec<UnlockSchedule>, String> {
+    manager
+        .read()
+        .await
+        .list_schedules(mint.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn save_unlock_schedule(
+    schedule: UnlockScheduleInput,
+    manager: State<'_, SharedTokenUnlockManager>,
+) -> Result<UnlockSchedule, String> {
+    manager
+        .read()
+        .await
+        .save_schedule(schedule)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn delete_unlock_schedule(
+    id: String,
+    manager: State<'_, SharedTokenUnlockManager>,
+) -> Result<(), String> {
+    manager
+        .read()
+        .await
+        .delete_schedule(&id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn import_unlock_dataset(
+    url: Option<String>,
+    data: Option<String>,
+    manager: State<'_, SharedTokenUnlockManager>,
+) -> Result<UnlockImportSummary, String> {
+    let manager = manager.read().await;
+    match (url, data) {
+        (_, Some(data)) => {
+            let entries: Vec<UnlockScheduleInput> =
+                serde_json::from_str(&data).map_err(|e| e.to_string())?;
+            manager.import_community(entries).await
+        }
+        (Some(url), None) => manager.import_community_from_url(&url).await,
+        (None, None) => return Err("Provide a dataset url or JSON data".to_string()),
+    }
+    .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_unlock_alert_settings(
+    manager: State<'_, SharedTokenUnlockManager>,
+) -> Result<UnlockAlertSettings, String> {
+    manager
+        .read()
+        .await
+        .alert_settings()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn update_unlock_alert_settings(
+    settings: UnlockAlertSettings,
+    manager: State<'_, SharedTokenUnlockManager>,
+) -> Result<UnlockAlertSettings, String> {
+    manager
+        .read()
+        .await
+        .set_alert_settings(&settings)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn schedule(kind: UnlockScheduleKind) -> UnlockSchedule {
+        UnlockSchedule {
+            id: "jup-team".to_string(),
+            mint: "JUP".to_string(),
+            symbol: "JUP".to_string(),
+            category: UnlockCategory::Team,
+            source: UnlockSource::Community,
+            schedule: kind,
+            total_supply: Some(10_000.0),
+            description: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn cliff_is_only_returned_inside_window() {
+        let s = schedule(UnlockScheduleKind::Cliff {
+            unlock_at: at(10),
+            amount: 500.0,
+        });
+
+        let events = expand_schedule(&s, at(1), at(15));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].percent_of_supply, Some(5.0));
+        assert!(expand_schedule(&s, at(11), at(20)).is_empty());
+    }
+
+    #[test]
+    fn linear_schedule_splits_amount_evenly() {
+        let s = schedule(UnlockScheduleKind::Linear {
+            start_at: at(1),
+            end_at: at(29),
+            interval_days: 7,
+            total_amount: 1000.0,
+        });
+
+        let all = expand_schedule(&s, at(1), at(31));
+        assert_eq!(all.len(), 5);
+        assert!(all.iter().all(|e| (e.amount - 200.0).abs() < 1e-9));
+
+        let later = expand_schedule(&s, at(10), at(31));
+        assert_eq!(later.iter().map(|e| e.unlock_at).collect::<Vec<_>>(), vec![at(15), at(22), at(29)]);
+    }
+
+    #[test]
+    fn large_unlocks_match_either_threshold() {
+        let s = schedule(UnlockScheduleKind::Cliff {
+            unlock_at: at(10),
+            amount: 50.0,
+        });
+        let event = expand_schedule(&s, at(1), at(15)).remove(0);
+
+        let mut relevance = UnlockRelevance::default();
+        relevance.held.insert("JUP".to_string());
+        let settings = UnlockAlertSettings::default();
+
+        // 0.5% of supply, no price: not large.
+        let unlock = annotate_unlock(event.clone(), &relevance);
+        assert!(unlock.held);
+        assert!(!is_large_unlock(&unlock, &settings));
+
+        relevance.prices.insert("JUP".to_string(), 25_000.0);
+        let unlock = annotate_unlock(event, &relevance);
+        assert_eq!(unlock.estimated_usd_value, Some(1_250_000.0));
+        assert!(is_large_unlock(&unlock, &settings));
+    }
+
+    #[test]
+    fn rejects_inverted_linear_schedule() {
+        let kind = UnlockScheduleKind::Linear {
+            start_at: at(10),
+            end_at: at(1),
+            interval_days: 1,
+            total_amount: 10.0,
+        };
+        assert!(matches!(validate_schedule(&kind), Err(TokenUnlockError::Invalid(_))));
+    }
+}
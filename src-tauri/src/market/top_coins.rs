@@ -1,3 +1,5 @@
+use crate::config::data_sources::{fetch_from_source, DataModule};
+use crate::config::http_recorder;
 use serde::{Deserialize, Serialize};
 use std::time::{Duration, SystemTime};
 use tokio::sync::RwLock;
@@ -84,18 +86,18 @@ impl TopCoinsCache {
         }
         drop(cache);
 
-        let full_data = if let Some(key) = api_key.clone() {
-            if !key.is_empty() {
-                match self.fetch_from_birdeye(&key).await {
-                    Ok(data) => data,
-                    Err(_) => self.generate_mock_top_coins(),
+        let full_data = fetch_from_source(
+            DataModule::Market,
+            "top_coins",
+            || async {
+                match api_key.as_deref().filter(|key| !key.is_empty()) {
+                    Some(key) => self.fetch_from_birdeye(key).await,
+                    None => Err("Birdeye API key not configured".to_string()),
                 }
-            } else {
-                self.generate_mock_top_coins()
-            }
-        } else {
-            self.generate_mock_top_coins()
-        };
+            },
+            || Ok(self.generate_mock_top_coins()),
+        )
+        .await?;
 
         let mut cache = self.cache.write().await;
         *cache = Some(CacheEntry {
@@ -124,10 +126,10 @@ impl TopCoinsCache {
             self.page_size
         );
 
-        let response = client
+        let request = client
             .get(&url)
-            .header("X-API-KEY", api_key)
-            .send()
+            .header("X-API-KEY", api_key);
+        let response = http_recorder::send("birdeye", request)
             .await
             .map_err(|e| format!("Request failed: {}", e))?;
 
@@ -359,10 +361,10 @@ async fn fetch_birdeye_top_coins(api_key: &str, limit: usize, offset: usize) ->
         offset, limit
     );
     
-    let response = client
+    let request = client
         .get(&url)
-        .header("X-API-KEY", api_key)
-        .send()
+        .header("X-API-KEY", api_key);
+    let response = http_recorder::send("birdeye", request)
         .await
         .map_err(|e| format!("Request failed: {}", e))?;
 
@@ -513,18 +515,18 @@ pub async fn fetch_top_coins(
         }
     }
     
-    let coins = if let Some(key) = api_key {
-        if !key.is_empty() {
-            match fetch_birdeye_top_coins(&key, limit, offset).await {
-                Ok(coins) => coins,
-                Err(_) => generate_mock_top_coins(limit, offset),
+    let coins = fetch_from_source(
+        DataModule::Market,
+        &format!("top_coins_{limit}_{offset}"),
+        || async {
+            match api_key.as_deref().filter(|key| !key.is_empty()) {
+                Some(key) => fetch_birdeye_top_coins(key, limit, offset).await,
+                None => Err("Birdeye API key not configured".to_string()),
             }
-        } else {
-            generate_mock_top_coins(limit, offset)
-        }
-    } else {
-        generate_mock_top_coins(limit, offset)
-    };
+        },
+        || Ok(generate_mock_top_coins(limit, offset)),
+    )
+    .await?;
     
     if offset == 0 {
         let mut cache_guard = cache.write().await;
@@ -52,13 +52,13 @@ impl TrendingCoinsCache {
             if !key.is_empty() {
                 match self.fetch_from_birdeye(&key).await {
                     Ok(coins) => coins,
-                    Err(_) => self.generate_mock_trending(),
+                    Err(_) => crate::demo::demo_or_default(|| self.generate_mock_trending()),
                 }
             } else {
-                self.generate_mock_trending()
+                crate::demo::demo_or_default(|| self.generate_mock_trending())
             }
         } else {
-            self.generate_mock_trending()
+            crate::demo::demo_or_default(|| self.generate_mock_trending())
         };
 
         let mut cache = self.cache.write().await;
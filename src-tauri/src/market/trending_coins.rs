@@ -1,3 +1,5 @@
+use crate::config::data_sources::{fetch_from_source, DataModule};
+use crate::config::http_recorder;
 use serde::{Deserialize, Serialize};
 use reqwest;
 use std::sync::{Arc, RwLock};
@@ -104,10 +106,10 @@ async fn fetch_birdeye_trending(api_key: &str, limit: usize) -> Result<Vec<Trend
         limit
     );
 
-    let response = client
+    let request = client
         .get(&url)
-        .header("X-API-KEY", api_key)
-        .send()
+        .header("X-API-KEY", api_key);
+    let response = http_recorder::send("birdeye", request)
         .await
         .map_err(|e| format!("Request failed: {}", e))?;
 
@@ -273,28 +275,33 @@ pub async fn get_trending_coins(limit: usize, api_key: Option<String>) -> Result
         return Ok(cached.into_iter().take(limit).collect());
     }
 
-    let coins = if let Some(key) = api_key {
-        if !key.is_empty() {
-            match fetch_birdeye_trending(&key, limit).await {
-                Ok(coins) => {
-                    TRENDING_CACHE.set(coins.clone());
-                    coins
-                }
-                Err(_) => generate_mock_trending(limit),
-            }
-        } else {
-            generate_mock_trending(limit)
-        }
-    } else {
-        generate_mock_trending(limit)
-    };
-
-    Ok(coins)
+    fetch_from_source(
+        DataModule::Market,
+        &format!("trending_{limit}"),
+        || async {
+            let key = api_key
+                .as_deref()
+                .filter(|key| !key.is_empty())
+                .ok_or_else(|| "Birdeye API key not configured".to_string())?;
+            let coins = fetch_birdeye_trending(key, limit).await?;
+            TRENDING_CACHE.set(coins.clone());
+            Ok(coins)
+        },
+        || Ok(generate_mock_trending(limit)),
+    )
+    .await
 }
 
 #[tauri::command]
 pub async fn get_coin_sentiment(symbol: String, _api_key: Option<String>) -> Result<CoinSentiment, String> {
-    Ok(generate_mock_sentiment(&symbol))
+    fetch_from_source(
+        DataModule::Market,
+        &format!("sentiment_{symbol}"),
+        // There is no live sentiment source yet
+        || async { Err(format!("UNAVAILABLE: no sentiment data for {symbol}")) },
+        || Ok(generate_mock_sentiment(&symbol)),
+    )
+    .await
 }
 
 #[tauri::command]
@@ -1,3 +1,5 @@
 pub mod performance;
+pub mod resource_budget;
 
 pub use performance::*;
+pub use resource_budget::*;
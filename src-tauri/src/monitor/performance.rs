@@ -6,6 +6,8 @@ use sysinfo::{CpuExt, NetworkExt, ProcessExt, System, SystemExt};
 use tokio::sync::broadcast;
 use tokio::time::{self, Duration};
 
+use super::resource_budget::ResourceUsage;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PerformanceMetrics {
@@ -22,6 +24,8 @@ pub struct PerformanceMetrics {
     pub process_memory_mb: f64,
     pub fps_estimate: Option<f32>,
     pub event_loop_lag_ms: Option<f64>,
+    #[serde(default)]
+    pub resource_usage: Vec<ResourceUsage>,
 }
 
 pub type SharedPerformanceMonitor = Arc<PerformanceMonitor>;
@@ -29,6 +33,7 @@ pub type SharedPerformanceMonitor = Arc<PerformanceMonitor>;
 pub struct PerformanceMonitor {
     system: Arc<RwLock<System>>,
     latest_metrics: Arc<RwLock<PerformanceMetrics>>,
+    resource_usage: Arc<RwLock<Vec<ResourceUsage>>>,
     subscribers: broadcast::Sender<PerformanceMetrics>,
 }
 
@@ -43,6 +48,7 @@ impl PerformanceMonitor {
         Self {
             system: Arc::new(RwLock::new(system)),
             latest_metrics: Arc::new(RwLock::new(metrics)),
+            resource_usage: Arc::new(RwLock::new(Vec::new())),
             subscribers: tx,
         }
     }
@@ -50,6 +56,7 @@ impl PerformanceMonitor {
     pub fn start(self: &Arc<Self>) {
         let system = self.system.clone();
         let latest_metrics = self.latest_metrics.clone();
+        let resource_usage = self.resource_usage.clone();
         let tx = self.subscribers.clone();
 
         tokio::spawn(async move {
@@ -59,7 +66,8 @@ impl PerformanceMonitor {
                 {
                     let mut system = system.write();
                     system.refresh_all();
-                    let metrics = PerformanceMonitor::capture_metrics(&system);
+                    let mut metrics = PerformanceMonitor::capture_metrics(&system);
+                    metrics.resource_usage = resource_usage.read().clone();
                     *latest_metrics.write() = metrics.clone();
                     let _ = tx.send(metrics);
                 }
@@ -110,6 +118,7 @@ impl PerformanceMonitor {
             process_memory_mb: process_memory,
             fps_estimate: None,
             event_loop_lag_ms: None,
+            resource_usage: Vec::new(),
         }
     }
 
@@ -117,6 +126,12 @@ impl PerformanceMonitor {
         self.latest_metrics.read().clone()
     }
 
+    /// Latest per-store memory usage from the resource budget monitor; it is
+    /// included in every metrics sample from then on.
+    pub fn record_resource_usage(&self, usage: Vec<ResourceUsage>) {
+        *self.resource_usage.write() = usage;
+    }
+
     pub fn subscribe(&self) -> broadcast::Receiver<PerformanceMetrics> {
         self.subscribers.subscribe()
     }
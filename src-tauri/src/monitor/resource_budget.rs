@@ -0,0 +1,418 @@
+use chrono::{DateTime, Utc};
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, State};
+
+use super::performance::SharedPerformanceMonitor;
+use crate::core::cache_manager::SharedCacheManager;
+use crate::sentiment::SharedSentimentManager;
+use crate::token_flow::commands::SharedFlowAnalysisState;
+
+const RESOURCE_BUDGETS_FILE: &str = "resource_budgets.json";
+pub const RESOURCE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const MAX_BUDGET_MB: u32 = 4096;
+/// An over-budget store is shrunk to this share of its budget, so it doesn't
+/// cross the line again on the next write.
+const SHRINK_TARGET_PERCENT: usize = 80;
+
+/// In-memory stores whose size is kept within a budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceStore {
+    /// Market data response cache; entries dropped from memory stay on disk.
+    ResponseCache,
+    /// Per-token sentiment with sample posts.
+    SocialSentiment,
+    /// Token flow graph snapshots.
+    TokenFlowGraphs,
+}
+
+impl ResourceStore {
+    pub const ALL: [ResourceStore; 3] = [
+        ResourceStore::ResponseCache,
+        ResourceStore::SocialSentiment,
+        ResourceStore::TokenFlowGraphs,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResourceStore::ResponseCache => "Response cache",
+            ResourceStore::SocialSentiment => "Social sentiment",
+            ResourceStore::TokenFlowGraphs => "Token flow graphs",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceBudgets {
+    pub response_cache_mb: u32,
+    pub social_sentiment_mb: u32,
+    pub token_flow_mb: u32,
+}
+
+impl Default for ResourceBudgets {
+    fn default() -> Self {
+        Self {
+            response_cache_mb: 64,
+            social_sentiment_mb: 16,
+            token_flow_mb: 32,
+        }
+    }
+}
+
+impl ResourceBudgets {
+    pub fn budget_bytes(&self, store: ResourceStore) -> usize {
+        let mb = match store {
+            ResourceStore::ResponseCache => self.response_cache_mb,
+            ResourceStore::SocialSentiment => self.social_sentiment_mb,
+            ResourceStore::TokenFlowGraphs => self.token_flow_mb,
+        };
+        mb as usize * 1024 * 1024
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for store in ResourceStore::ALL {
+            let mb = self.budget_bytes(store) / (1024 * 1024);
+            if mb == 0 || mb > MAX_BUDGET_MB as usize {
+                return Err(format!(
+                    "{} budget must be between 1 and {MAX_BUDGET_MB} MB",
+                    store.label()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn shrink_target(budget_bytes: usize) -> usize {
+    budget_bytes / 100 * SHRINK_TARGET_PERCENT
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceUsage {
+    pub store: ResourceStore,
+    pub label: String,
+    /// Estimated from serialized size, so it tracks growth rather than the
+    /// exact heap footprint.
+    pub used_bytes: u64,
+    pub budget_bytes: u64,
+    pub entries: usize,
+    pub shrinks: u64,
+    pub evicted_entries: u64,
+    pub last_shrunk_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default)]
+struct ShrinkHistory {
+    shrinks: u64,
+    evicted_entries: u64,
+    last_shrunk_at: Option<DateTime<Utc>>,
+}
+
+pub type SharedResourceBudgetMonitor = Arc<ResourceBudgetMonitor>;
+
+/// Measures the in-memory stores and shrinks any that exceed their budget by
+/// dropping their least recently used entries.
+pub struct ResourceBudgetMonitor {
+    cache: SharedCacheManager,
+    sentiment: SharedSentimentManager,
+    token_flow: SharedFlowAnalysisState,
+    budgets: RwLock<ResourceBudgets>,
+    budgets_path: PathBuf,
+    history: Mutex<HashMap<ResourceStore, ShrinkHistory>>,
+}
+
+impl ResourceBudgetMonitor {
+    pub fn new(
+        app: &AppHandle,
+        cache: SharedCacheManager,
+        sentiment: SharedSentimentManager,
+        token_flow: SharedFlowAnalysisState,
+    ) -> Result<Self, String> {
+        let dir = app
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        Self::with_path(
+            dir.join(RESOURCE_BUDGETS_FILE),
+            cache,
+            sentiment,
+            token_flow,
+        )
+    }
+
+    pub fn with_path(
+        budgets_path: PathBuf,
+        cache: SharedCacheManager,
+        sentiment: SharedSentimentManager,
+        token_flow: SharedFlowAnalysisState,
+    ) -> Result<Self, String> {
+        let budgets = if budgets_path.exists() {
+            let data = fs::read_to_string(&budgets_path).map_err(|e| e.to_string())?;
+            let budgets: ResourceBudgets =
+                serde_json::from_str(&data).map_err(|e| e.to_string())?;
+            budgets.validate().map(|_| budgets).unwrap_or_default()
+        } else {
+            ResourceBudgets::default()
+        };
+
+        Ok(Self {
+            cache,
+            sentiment,
+            token_flow,
+            budgets: RwLock::new(budgets),
+            budgets_path,
+            history: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn budgets(&self) -> ResourceBudgets {
+        self.budgets.read().clone()
+    }
+
+    pub fn set_budgets(&self, budgets: ResourceBudgets) -> Result<(), String> {
+        budgets.validate()?;
+        let json = serde_json::to_string_pretty(&budgets).map_err(|e| e.to_string())?;
+        fs::write(&self.budgets_path, json).map_err(|e| e.to_string())?;
+        *self.budgets.write() = budgets;
+        Ok(())
+    }
+
+    /// Returns the estimated size and entry count of `store`.
+    async fn measure(&self, store: ResourceStore) -> (usize, usize) {
+        match store {
+            ResourceStore::ResponseCache => {
+                let stats = self.cache.read().await.get_statistics().await;
+                (stats.total_size_bytes, stats.total_entries)
+            }
+            ResourceStore::SocialSentiment => {
+                let sentiment = self.sentiment.read().await;
+                (sentiment.estimated_size_bytes(), sentiment.tracked_tokens())
+            }
+            ResourceStore::TokenFlowGraphs => {
+                let token_flow = self.token_flow.read().await;
+                (
+                    token_flow.estimated_size_bytes(),
+                    token_flow.snapshots.len(),
+                )
+            }
+        }
+    }
+
+    async fn shrink(&self, store: ResourceStore, target_bytes: usize) -> usize {
+        match store {
+            ResourceStore::ResponseCache => {
+                self.cache.read().await.shrink_memory_to(target_bytes).await
+            }
+            ResourceStore::SocialSentiment => self.sentiment.write().await.shrink_to(target_bytes),
+            ResourceStore::TokenFlowGraphs => self.token_flow.write().await.shrink_to(target_bytes),
+        }
+    }
+
+    /// Shrinks every store that is over budget and reports the usage after.
+    pub async fn enforce(&self) -> Vec<ResourceUsage> {
+        let budgets = self.budgets();
+        let mut usage = Vec::with_capacity(ResourceStore::ALL.len());
+
+        for store in ResourceStore::ALL {
+            let budget = budgets.budget_bytes(store);
+            let (mut used, mut entries) = self.measure(store).await;
+
+            if used > budget {
+                let evicted = self.shrink(store, shrink_target(budget)).await;
+                tracing::info!(
+                    store = store.label(),
+                    used_bytes = used,
+                    budget_bytes = budget,
+                    evicted,
+                    "shrank store over its memory budget"
+                );
+                {
+                    let mut history = self.history.lock();
+                    let entry = history.entry(store).or_default();
+                    entry.shrinks += 1;
+                    entry.evicted_entries += evicted as u64;
+                    entry.last_shrunk_at = Some(Utc::now());
+                }
+                (used, entries) = self.measure(store).await;
+            }
+
+            let history = self.history.lock();
+            let entry = history.get(&store);
+            usage.push(ResourceUsage {
+                store,
+                label: store.label().to_string(),
+                used_bytes: used as u64,
+                budget_bytes: budget as u64,
+                entries,
+                shrinks: entry.map_or(0, |e| e.shrinks),
+                evicted_entries: entry.map_or(0, |e| e.evicted_entries),
+                last_shrunk_at: entry.and_then(|e| e.last_shrunk_at),
+            });
+        }
+
+        usage
+    }
+}
+
+/// Enforces budgets on an interval and publishes usage with the performance
+/// metrics.
+pub fn start_resource_budget_monitor(
+    monitor: SharedResourceBudgetMonitor,
+    performance: SharedPerformanceMonitor,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(RESOURCE_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            performance.record_resource_usage(monitor.enforce().await);
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_resource_usage(
+    monitor: State<'_, SharedResourceBudgetMonitor>,
+    performance: State<'_, SharedPerformanceMonitor>,
+) -> Result<Vec<ResourceUsage>, String> {
+    let usage = monitor.enforce().await;
+    performance.record_resource_usage(usage.clone());
+    Ok(usage)
+}
+
+#[tauri::command]
+pub async fn get_resource_budgets(
+    monitor: State<'_, SharedResourceBudgetMonitor>,
+) -> Result<ResourceBudgets, String> {
+    Ok(monitor.budgets())
+}
+
+/// Saves new budgets and applies them right away, so lowering one frees
+/// memory without waiting for the next check.
+#[tauri::command]
+pub async fn set_resource_budgets(
+    budgets: ResourceBudgets,
+    monitor: State<'_, SharedResourceBudgetMonitor>,
+    performance: State<'_, SharedPerformanceMonitor>,
+) -> Result<Vec<ResourceUsage>, String> {
+    monitor.set_budgets(budgets)?;
+    let usage = monitor.enforce().await;
+    performance.record_resource_usage(usage.clone());
+    Ok(usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cache_manager::{CacheManager, CacheType, SystemTimeProvider};
+    use crate::sentiment::SentimentManager;
+    use crate::token_flow::commands::FlowAnalysisState;
+    use crate::token_flow::types::{FlowSnapshot, TimeRange, TokenFlowGraph};
+    use tokio::sync::RwLock as AsyncRwLock;
+
+    fn monitor(dir: &tempfile::TempDir) -> ResourceBudgetMonitor {
+        let cache = CacheManager::with_time_provider_and_path(
+            16,
+            1000,
+            dir.path().join("cache").join("ttl.json"),
+            Arc::new(SystemTimeProvider::default()),
+        );
+        ResourceBudgetMonitor::with_path(
+            dir.path().join(RESOURCE_BUDGETS_FILE),
+            Arc::new(AsyncRwLock::new(cache)),
+            Arc::new(AsyncRwLock::new(SentimentManager::new())),
+            Arc::new(AsyncRwLock::new(FlowAnalysisState::default())),
+        )
+        .unwrap()
+    }
+
+    fn snapshot(id: usize) -> FlowSnapshot {
+        FlowSnapshot {
+            id: id.to_string(),
+            timestamp: id as i64,
+            graph: TokenFlowGraph {
+                nodes: Vec::new(),
+                edges: Vec::new(),
+                token_address: "mint".to_string(),
+                time_range: TimeRange { start: 0, end: 0 },
+            },
+            clusters: Vec::new(),
+            alerts: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn budgets_are_validated_and_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let monitor = monitor(&dir);
+        assert_eq!(monitor.budgets(), ResourceBudgets::default());
+
+        let zero = ResourceBudgets {
+            token_flow_mb: 0,
+            ..Default::default()
+        };
+        assert!(monitor.set_budgets(zero).is_err());
+
+        let small = ResourceBudgets {
+            response_cache_mb: 8,
+            ..Default::default()
+        };
+        monitor.set_budgets(small.clone()).unwrap();
+        assert_eq!(self::monitor(&dir).budgets(), small);
+    }
+
+    #[tokio::test]
+    async fn over_budget_cache_drops_least_recent_entries_from_memory() {
+        let dir = tempfile::tempdir().unwrap();
+        let monitor = monitor(&dir);
+        monitor
+            .set_budgets(ResourceBudgets {
+                response_cache_mb: 1,
+                ..Default::default()
+            })
+            .unwrap();
+
+        let payload = serde_json::Value::String("x".repeat(300 * 1024));
+        for i in 0..5 {
+            let cache = monitor.cache.read().await;
+            cache
+                .set(format!("key-{i}"), payload.clone(), CacheType::MarketData)
+                .await
+                .unwrap();
+        }
+
+        let usage = monitor.enforce().await;
+        let cache_usage = &usage[0];
+        assert_eq!(cache_usage.store, ResourceStore::ResponseCache);
+        assert!(cache_usage.used_bytes <= shrink_target(1024 * 1024) as u64);
+        assert_eq!(cache_usage.shrinks, 1);
+        assert_eq!(cache_usage.evicted_entries, 3);
+
+        // The newest entry stays in memory; evicted ones rehydrate from disk
+        let cache = monitor.cache.read().await;
+        assert!(cache.get("key-4", CacheType::MarketData).await.is_some());
+        assert!(cache.get("key-0", CacheType::MarketData).await.is_some());
+    }
+
+    #[test]
+    fn token_flow_drops_oldest_snapshots_first() {
+        let mut state = FlowAnalysisState::default();
+        for id in 0..4 {
+            state.snapshots.push(snapshot(id));
+        }
+        let one = state.estimated_size_bytes() / 4;
+
+        assert_eq!(state.shrink_to(one * 2), 2);
+        let ids: Vec<&str> = state.snapshots.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["2", "3"]);
+        assert_eq!(state.shrink_to(usize::MAX), 0);
+    }
+}
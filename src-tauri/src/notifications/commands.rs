@@ -1,5 +1,8 @@
 use tauri::State;
 
+use super::push_bridge::{
+    PushRegistration, PushRelayConfig, PushRelayStatus, PushSubscription, PushSubscriptionInput,
+};
 use super::router::SharedNotificationRouter;
 use super::types::{
     ChatIntegrationSettings, DeliveryLog, DiscordConfig, RateLimitStatus, SlackConfig,
@@ -214,3 +217,91 @@ pub async fn chat_integration_get_rate_limits(
     let limiter = rate_limiter.read().await;
     Ok(limiter.get_statuses().await)
 }
+
+#[tauri::command]
+pub async fn chat_integration_list_push_subscriptions(
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<Vec<PushSubscription>, String> {
+    let router = router.read().await;
+    router
+        .push_bridge()
+        .list_subscriptions()
+        .await
+        .map_err(|e| format!("Failed to list push subscriptions: {}", e))
+}
+
+#[tauri::command]
+pub async fn chat_integration_register_push(
+    input: PushSubscriptionInput,
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<PushRegistration, String> {
+    let router = router.read().await;
+    router
+        .push_bridge()
+        .register_subscription(input)
+        .await
+        .map_err(|e| format!("Failed to register push subscription: {}", e))
+}
+
+#[tauri::command]
+pub async fn chat_integration_set_push_enabled(
+    id: String,
+    enabled: bool,
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<(), String> {
+    let router = router.read().await;
+    router
+        .push_bridge()
+        .set_enabled(&id, enabled)
+        .await
+        .map_err(|e| format!("Failed to update push subscription: {}", e))
+}
+
+#[tauri::command]
+pub async fn chat_integration_remove_push(
+    id: String,
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<(), String> {
+    let router = router.read().await;
+    router
+        .push_bridge()
+        .remove_subscription(&id)
+        .await
+        .map_err(|e| format!("Failed to remove push subscription: {}", e))
+}
+
+#[tauri::command]
+pub async fn chat_integration_get_push_relay(
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<PushRelayStatus, String> {
+    let router = router.read().await;
+    router
+        .push_bridge()
+        .relay_status()
+        .map_err(|e| format!("Failed to get push relay: {}", e))
+}
+
+#[tauri::command]
+pub async fn chat_integration_save_push_relay(
+    config: Option<PushRelayConfig>,
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<(), String> {
+    let router = router.read().await;
+    router
+        .push_bridge()
+        .save_relay_config(config)
+        .map_err(|e| format!("Failed to save push relay: {}", e))
+}
+
+#[tauri::command]
+pub async fn chat_integration_test_push(
+    id: String,
+    message: String,
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<TestMessageResult, String> {
+    let router = router.read().await;
+    router
+        .test_push(&id, &message)
+        .await
+        .map_err(|e| format!("Failed to test push: {}", e))
+}
@@ -3,28 +3,55 @@ use serde::Serialize;
 use std::time::Duration;
 
 use super::types::{DiscordConfig, NotificationError};
+use crate::config::localization::current_localizer;
 
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
 
-#[derive(Debug, Serialize)]
-struct DiscordEmbed {
-    title: String,
-    description: String,
-    color: u32,
+#[derive(Debug, Clone, Serialize)]
+pub struct DiscordEmbed {
+    pub title: String,
+    pub description: String,
+    pub color: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
-    fields: Option<Vec<EmbedField>>,
-    timestamp: String,
+    pub fields: Option<Vec<EmbedField>>,
+    pub timestamp: String,
 }
 
-#[derive(Debug, Serialize)]
-struct EmbedField {
-    name: String,
-    value: String,
-    inline: bool,
+impl DiscordEmbed {
+    pub fn new(title: impl Into<String>, description: impl Into<String>, color: u32) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            color,
+            fields: None,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    pub fn field(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+        inline: bool,
+    ) -> Self {
+        self.fields.get_or_insert_with(Vec::new).push(EmbedField {
+            name: name.into(),
+            value: value.into(),
+            inline,
+        });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EmbedField {
+    pub name: String,
+    pub value: String,
+    pub inline: bool,
 }
 
 #[derive(Debug, Serialize)]
-struct DiscordMessage {
+pub struct DiscordMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -100,6 +127,35 @@ impl DiscordClient {
         current_price: f64,
         condition: &str,
     ) -> Result<(), NotificationError> {
+        let payload = Self::alert_payload(config, alert_name, symbol, current_price, condition);
+
+        let response = self
+            .client
+            .post(&config.webhook_url)
+            .json(&payload)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(NotificationError::Internal(format!(
+                "Discord webhook failed: {} {}",
+                status, body
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// The webhook body `send_alert_embed` posts, exposed for previews.
+    pub fn alert_payload(
+        config: &DiscordConfig,
+        alert_name: &str,
+        symbol: &str,
+        current_price: f64,
+        condition: &str,
+    ) -> DiscordMessage {
         let role_mentions = config
             .role_mentions
             .as_ref()
@@ -118,23 +174,24 @@ impl DiscordClient {
             None
         };
 
+        let l10n = current_localizer();
         let embed = DiscordEmbed {
-            title: "🚨 Price Alert Triggered".to_string(),
-            description: format!("Alert **{}** has been triggered", alert_name),
+            title: format!("🚨 {}", l10n.text("alert.title", &[])),
+            description: l10n.text("alert.triggered", &[("name", &format!("**{alert_name}**"))]),
             color: 0xFF0000,
             fields: Some(vec![
                 EmbedField {
-                    name: "Symbol".to_string(),
+                    name: l10n.text("alert.field.symbol", &[]),
                     value: symbol.to_string(),
                     inline: true,
                 },
                 EmbedField {
-                    name: "Price".to_string(),
-                    value: format!("${:.4}", current_price),
+                    name: l10n.text("alert.field.price", &[]),
+                    value: l10n.currency(current_price, 4),
                     inline: true,
                 },
                 EmbedField {
-                    name: "Condition".to_string(),
+                    name: l10n.text("alert.field.condition", &[]),
                     value: condition.to_string(),
                     inline: false,
                 },
@@ -142,24 +199,23 @@ impl DiscordClient {
             timestamp: chrono::Utc::now().to_rfc3339(),
         };
 
-        let payload = DiscordMessage {
+        DiscordMessage {
             content,
             username: config.username.clone(),
             embeds: Some(vec![embed]),
-        };
+        }
+    }
 
-        let response = self
-            .client
-            .post(&config.webhook_url)
-            .json(&payload)
-            .send()
-            .await?;
+    /// Verifies the webhook without posting: a GET returns the webhook object
+    /// while it exists and 401/404 once it has been deleted or its token rotated.
+    pub async fn check_webhook(&self, config: &DiscordConfig) -> Result<(), NotificationError> {
+        let response = self.client.get(&config.webhook_url).send().await?;
 
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
             return Err(NotificationError::Internal(format!(
-                "Discord webhook failed: {} {}",
+                "Discord webhook unreachable: {} {}",
                 status, body
             )));
         }
@@ -0,0 +1,948 @@
+use super::discord::DiscordEmbed;
+use super::rate_limiter::RateLimiter;
+use super::types::ChatServiceType;
+use crate::api_analytics::RequestPriority;
+use crate::market::{quote_coin_price, search_tokens, CoinPrice, SharedNewCoinsScanner};
+use crate::portfolio::SharedPortfolioData;
+use crate::security::activity_log::{ActivityAction, ActivityLogger};
+use crate::security::keystore::Keystore;
+use futures_util::{SinkExt, StreamExt};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+
+const DISCORD_API_URL: &str = "https://discord.com/api/v10";
+const DISCORD_GATEWAY_URL: &str = "wss://gateway.discord.gg/?v=10&encoding=json";
+const KEY_DISCORD_BOT_TOKEN: &str = "discord_bot_token";
+const DISCORD_BOT_FILE: &str = "discord_bot.json";
+const CONFIG_AUDIT_SUBJECT: &str = "discord_bot";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const IDLE_POLL_DELAY: Duration = Duration::from_secs(10);
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(15);
+const MAX_LISTED_ITEMS: usize = 10;
+
+const COLOR_INFO: u32 = 0x5865F2;
+const COLOR_UP: u32 = 0x2ECC71;
+const COLOR_DOWN: u32 = 0xE74C3C;
+
+// Gateway opcodes and interaction types from the Discord API.
+const OP_DISPATCH: u8 = 0;
+const OP_HEARTBEAT: u8 = 1;
+const OP_IDENTIFY: u8 = 2;
+const OP_RECONNECT: u8 = 7;
+const OP_INVALID_SESSION: u8 = 9;
+const OP_HELLO: u8 = 10;
+const INTERACTION_APPLICATION_COMMAND: u8 = 2;
+const RESPONSE_CHANNEL_MESSAGE: u8 = 4;
+const RESPONSE_DEFERRED_MESSAGE: u8 = 5;
+const FLAG_EPHEMERAL: u64 = 1 << 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlashCommand {
+    Price,
+    Portfolio,
+    ScreenerRun,
+}
+
+impl SlashCommand {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SlashCommand::Price => "/price",
+            SlashCommand::Portfolio => "/portfolio",
+            SlashCommand::ScreenerRun => "/screener run",
+        }
+    }
+
+    /// Portfolio data is only ever shown to the user who asked.
+    fn is_private(&self) -> bool {
+        matches!(self, SlashCommand::Portfolio)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordGuildRule {
+    pub guild_id: String,
+    pub commands: Vec<SlashCommand>,
+    /// Empty lets every member of the guild use the commands.
+    #[serde(default)]
+    pub allowed_role_ids: Vec<String>,
+}
+
+/// Grants commands to a user anywhere, including direct messages.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordUserRule {
+    pub user_id: String,
+    pub commands: Vec<SlashCommand>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordBotConfig {
+    pub enabled: bool,
+    pub application_id: String,
+    pub guilds: Vec<DiscordGuildRule>,
+    pub users: Vec<DiscordUserRule>,
+    pub user_requests_per_minute: i32,
+}
+
+impl Default for DiscordBotConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            application_id: String::new(),
+            guilds: Vec::new(),
+            users: Vec::new(),
+            user_requests_per_minute: 5,
+        }
+    }
+}
+
+fn is_snowflake(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_digit())
+}
+
+impl DiscordBotConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.enabled && !is_snowflake(&self.application_id) {
+            return Err("A numeric Discord application ID is required".into());
+        }
+        if !(1..=60).contains(&self.user_requests_per_minute) {
+            return Err("Requests per minute must be between 1 and 60".into());
+        }
+        let ids = self
+            .guilds
+            .iter()
+            .flat_map(|rule| std::iter::once(&rule.guild_id).chain(&rule.allowed_role_ids))
+            .chain(self.users.iter().map(|rule| &rule.user_id));
+        for id in ids {
+            if !is_snowflake(id) {
+                return Err(format!("Invalid Discord ID: {}", id));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A slash command as received, with the caller's identity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlashInvocation {
+    pub command: SlashCommand,
+    pub guild_id: Option<String>,
+    pub user_id: String,
+    pub role_ids: Vec<String>,
+    pub token: Option<String>,
+    pub min_safety: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiscordInteraction {
+    pub id: String,
+    pub token: String,
+    #[serde(rename = "type")]
+    pub kind: u8,
+    pub guild_id: Option<String>,
+    pub data: Option<DiscordInteractionData>,
+    pub member: Option<DiscordInteractionMember>,
+    pub user: Option<DiscordUser>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiscordInteractionData {
+    pub name: String,
+    #[serde(default)]
+    pub options: Vec<DiscordInteractionOption>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiscordInteractionOption {
+    pub name: String,
+    pub value: Option<Value>,
+    #[serde(default)]
+    pub options: Vec<DiscordInteractionOption>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiscordInteractionMember {
+    pub user: DiscordUser,
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiscordUser {
+    pub id: String,
+}
+
+impl DiscordInteraction {
+    /// Guild interactions carry the user on `member`, direct messages on `user`.
+    pub fn invocation(&self) -> Option<SlashInvocation> {
+        let data = self.data.as_ref()?;
+        let (command, options) = match data.name.as_str() {
+            "price" => (SlashCommand::Price, &data.options),
+            "portfolio" => (SlashCommand::Portfolio, &data.options),
+            "screener" => {
+                let run = data.options.iter().find(|option| option.name == "run")?;
+                (SlashCommand::ScreenerRun, &run.options)
+            }
+            _ => return None,
+        };
+        let option = |name: &str| {
+            options
+                .iter()
+                .find(|option| option.name == name)
+                .and_then(|option| option.value.clone())
+        };
+
+        let (user_id, role_ids) = match (&self.member, &self.user) {
+            (Some(member), _) => (member.user.id.clone(), member.roles.clone()),
+            (None, Some(user)) => (user.id.clone(), Vec::new()),
+            (None, None) => return None,
+        };
+
+        Some(SlashInvocation {
+            command,
+            guild_id: self.guild_id.clone(),
+            user_id,
+            role_ids,
+            token: option("token").and_then(|value| value.as_str().map(str::to_string)),
+            min_safety: option("min_safety").and_then(|value| value.as_i64()),
+        })
+    }
+}
+
+pub fn authorize_slash_command(config: &DiscordBotConfig, invocation: &SlashInvocation) -> bool {
+    let user_allowed = config.users.iter().any(|rule| {
+        rule.user_id == invocation.user_id && rule.commands.contains(&invocation.command)
+    });
+    if user_allowed {
+        return true;
+    }
+
+    let Some(guild_id) = &invocation.guild_id else {
+        return false;
+    };
+    config.guilds.iter().any(|rule| {
+        &rule.guild_id == guild_id
+            && rule.commands.contains(&invocation.command)
+            && (rule.allowed_role_ids.is_empty()
+                || rule
+                    .allowed_role_ids
+                    .iter()
+                    .any(|role| invocation.role_ids.contains(role)))
+    })
+}
+
+/// Slash command definitions, registered globally when the bot connects.
+pub fn slash_command_definitions() -> Value {
+    json!([
+        {
+            "name": "price",
+            "description": "Current price of a token",
+            "options": [{
+                "type": 3,
+                "name": "token",
+                "description": "Symbol or mint address",
+                "required": true
+            }]
+        },
+        {
+            "name": "portfolio",
+            "description": "Portfolio value, P&L and top positions"
+        },
+        {
+            "name": "screener",
+            "description": "Token screener",
+            "options": [{
+                "type": 1,
+                "name": "run",
+                "description": "Scan for new tokens and report the safest",
+                "options": [{
+                    "type": 4,
+                    "name": "min_safety",
+                    "description": "Minimum safety score (0-100)",
+                    "min_value": 0,
+                    "max_value": 100
+                }]
+            }]
+        }
+    ])
+}
+
+pub struct DiscordBotState {
+    config: Mutex<DiscordBotConfig>,
+    config_path: Option<PathBuf>,
+    rate_limiter: RateLimiter,
+}
+
+impl DiscordBotState {
+    fn load(config_path: Option<PathBuf>) -> Self {
+        let config = config_path
+            .as_ref()
+            .filter(|path| path.exists())
+            .and_then(|path| match fs::read_to_string(path) {
+                Ok(data) => serde_json::from_str(&data).ok(),
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to read discord bot config");
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            config: Mutex::new(config),
+            config_path,
+            rate_limiter: RateLimiter::new(),
+        }
+    }
+
+    fn config(&self) -> Result<DiscordBotConfig, String> {
+        self.config
+            .lock()
+            .map(|config| config.clone())
+            .map_err(|e| e.to_string())
+    }
+
+    fn save_config(&self, config: DiscordBotConfig) -> Result<(), String> {
+        if let Some(path) = &self.config_path {
+            let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+            fs::write(path, json).map_err(|e| e.to_string())?;
+        }
+        *self.config.lock().map_err(|e| e.to_string())? = config;
+        Ok(())
+    }
+}
+
+pub type SharedDiscordBot = Arc<DiscordBotState>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordBotStatus {
+    pub config: DiscordBotConfig,
+    pub token_configured: bool,
+}
+
+pub fn register_discord_bot(app: &tauri::App) {
+    let config_path = app.path_resolver().app_data_dir().map(|dir| {
+        if let Err(err) = fs::create_dir_all(&dir) {
+            tracing::warn!(error = %err, "failed to create app data directory");
+        }
+        dir.join(DISCORD_BOT_FILE)
+    });
+    let state: SharedDiscordBot = Arc::new(DiscordBotState::load(config_path));
+    app.manage(state);
+
+    start_discord_bot(app.handle());
+}
+
+fn bot_token(app: &AppHandle) -> Option<String> {
+    let keystore = app.try_state::<Keystore>()?;
+    let secret = keystore.retrieve_secret(KEY_DISCORD_BOT_TOKEN).ok()?;
+    String::from_utf8(secret.to_vec()).ok()
+}
+
+/// Keeps a gateway session open while the bot is enabled, reconnecting with a
+/// fresh identify whenever Discord drops or recycles the session.
+fn start_discord_bot(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_else(|_| Client::new());
+
+        loop {
+            let enabled = app
+                .try_state::<SharedDiscordBot>()
+                .and_then(|bot| bot.config().ok())
+                .filter(|config| config.enabled);
+            let (Some(config), Some(token)) = (enabled, bot_token(&app)) else {
+                tokio::time::sleep(IDLE_POLL_DELAY).await;
+                continue;
+            };
+
+            if let Err(err) = run_gateway(&app, &client, &config, &token).await {
+                tracing::warn!(error = %err, "discord bot gateway session ended");
+                tokio::time::sleep(RECONNECT_BACKOFF).await;
+            }
+        }
+    });
+}
+
+#[derive(Debug, Deserialize)]
+struct GatewayPayload {
+    op: u8,
+    #[serde(default)]
+    d: Value,
+    s: Option<i64>,
+    t: Option<String>,
+}
+
+async fn run_gateway(
+    app: &AppHandle,
+    client: &Client,
+    config: &DiscordBotConfig,
+    token: &str,
+) -> anyhow::Result<()> {
+    let (ws_stream, _) = connect_async(DISCORD_GATEWAY_URL).await?;
+    let (mut write, mut read) = ws_stream.split();
+
+    let heartbeat_ms = loop {
+        match read.next().await {
+            Some(Ok(Message::Text(text))) => {
+                let payload: GatewayPayload = serde_json::from_str(&text)?;
+                if payload.op == OP_HELLO {
+                    break payload.d["heartbeat_interval"].as_u64().unwrap_or(41_250);
+                }
+            }
+            Some(Ok(_)) => {}
+            Some(Err(err)) => return Err(err.into()),
+            None => anyhow::bail!("gateway closed before hello"),
+        }
+    };
+
+    let identify = json!({
+        "op": OP_IDENTIFY,
+        "d": {
+            "token": token,
+            // Interactions arrive without any privileged intents.
+            "intents": 0,
+            "properties": {
+                "os": std::env::consts::OS,
+                "browser": "eclipse-market",
+                "device": "eclipse-market"
+            }
+        }
+    });
+    write.send(Message::Text(identify.to_string())).await?;
+
+    let mut heartbeat = tokio::time::interval(Duration::from_millis(heartbeat_ms));
+    let mut sequence: Option<i64> = None;
+
+    loop {
+        tokio::select! {
+            _ = heartbeat.tick() => {
+                let beat = json!({ "op": OP_HEARTBEAT, "d": sequence });
+                write.send(Message::Text(beat.to_string())).await?;
+            }
+            message = read.next() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None => return Ok(()),
+                    Some(Ok(_)) => continue,
+                    Some(Err(err)) => return Err(err.into()),
+                };
+                let payload: GatewayPayload = serde_json::from_str(&text)?;
+                if payload.s.is_some() {
+                    sequence = payload.s;
+                }
+
+                match payload.op {
+                    OP_DISPATCH => on_dispatch(app, client, config, token, payload),
+                    OP_HEARTBEAT => {
+                        let beat = json!({ "op": OP_HEARTBEAT, "d": sequence });
+                        write.send(Message::Text(beat.to_string())).await?;
+                    }
+                    OP_RECONNECT | OP_INVALID_SESSION => return Ok(()),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Handles a gateway event in the background so the heartbeat loop never waits
+/// on the Discord REST API.
+fn on_dispatch(
+    app: &AppHandle,
+    client: &Client,
+    config: &DiscordBotConfig,
+    token: &str,
+    payload: GatewayPayload,
+) {
+    let (app, client, config, token) = (
+        app.clone(),
+        client.clone(),
+        config.clone(),
+        token.to_string(),
+    );
+    match payload.t.as_deref() {
+        Some("READY") => {
+            tauri::async_runtime::spawn(async move {
+                if let Err(err) = register_commands(&client, &config, &token).await {
+                    tracing::warn!(error = %err, "failed to register discord slash commands");
+                }
+            });
+        }
+        Some("INTERACTION_CREATE") => {
+            match serde_json::from_value::<DiscordInteraction>(payload.d) {
+                Ok(interaction) => {
+                    tauri::async_runtime::spawn(async move {
+                        handle_interaction(&app, &client, &token, interaction).await;
+                    });
+                }
+                Err(err) => tracing::warn!(error = %err, "failed to parse discord interaction"),
+            }
+        }
+        _ => {}
+    }
+}
+
+async fn discord_request(request: reqwest::RequestBuilder, token: &str) -> Result<(), String> {
+    let response = request
+        .header("Authorization", format!("Bot {}", token))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Discord API error: {} {}", status, body));
+    }
+    Ok(())
+}
+
+async fn register_commands(
+    client: &Client,
+    config: &DiscordBotConfig,
+    token: &str,
+) -> Result<(), String> {
+    let url = format!(
+        "{}/applications/{}/commands",
+        DISCORD_API_URL, config.application_id
+    );
+    discord_request(client.put(url).json(&slash_command_definitions()), token).await
+}
+
+async fn respond(
+    client: &Client,
+    token: &str,
+    interaction: &DiscordInteraction,
+    body: Value,
+) -> Result<(), String> {
+    let url = format!(
+        "{}/interactions/{}/{}/callback",
+        DISCORD_API_URL, interaction.id, interaction.token
+    );
+    discord_request(client.post(url).json(&body), token).await
+}
+
+fn embed_response(embed: DiscordEmbed) -> Value {
+    json!({
+        "type": RESPONSE_CHANNEL_MESSAGE,
+        "data": { "embeds": [embed], "flags": FLAG_EPHEMERAL }
+    })
+}
+
+/// Authorizes and rate-limits the caller, then defers the reply so slower
+/// lookups aren't cut off by Discord's three-second response window.
+async fn handle_interaction(
+    app: &AppHandle,
+    client: &Client,
+    token: &str,
+    interaction: DiscordInteraction,
+) {
+    if interaction.kind != INTERACTION_APPLICATION_COMMAND {
+        return;
+    }
+    let Some(invocation) = interaction.invocation() else {
+        return;
+    };
+    let Some(bot) = app.try_state::<SharedDiscordBot>() else {
+        return;
+    };
+    let Ok(config) = bot.config() else {
+        return;
+    };
+
+    let subject = format!("discord:{}", invocation.user_id);
+    let details = json!({
+        "command": invocation.command,
+        "guildId": invocation.guild_id,
+    });
+
+    let denial = if !authorize_slash_command(&config, &invocation) {
+        Some((
+            "You are not allowed to use this command here.",
+            "unauthorized",
+        ))
+    } else if bot
+        .rate_limiter
+        .acquire_with_limit(
+            &ChatServiceType::Discord,
+            &format!("user:{}", invocation.user_id),
+            config.user_requests_per_minute,
+        )
+        .await
+        .is_err()
+    {
+        Some((
+            "Slow down, you have hit the command rate limit.",
+            "rate_limited",
+        ))
+    } else {
+        None
+    };
+    if let Some((message, reason)) = denial {
+        audit(
+            app,
+            &subject,
+            json!({ "denied": reason, "request": details }),
+            false,
+        )
+        .await;
+        let embed = DiscordEmbed::new(invocation.command.label(), message, COLOR_DOWN);
+        if let Err(err) = respond(client, token, &interaction, embed_response(embed)).await {
+            tracing::warn!(error = %err, "failed to answer discord interaction");
+        }
+        return;
+    }
+
+    let flags = if invocation.command.is_private() {
+        FLAG_EPHEMERAL
+    } else {
+        0
+    };
+    let deferred = json!({ "type": RESPONSE_DEFERRED_MESSAGE, "data": { "flags": flags } });
+    if let Err(err) = respond(client, token, &interaction, deferred).await {
+        tracing::warn!(error = %err, "failed to acknowledge discord interaction");
+        return;
+    }
+
+    let result = run_command(app, &invocation).await;
+    audit(app, &subject, details, result.is_ok()).await;
+    let embed =
+        result.unwrap_or_else(|err| DiscordEmbed::new(invocation.command.label(), err, COLOR_DOWN));
+
+    let url = format!(
+        "{}/webhooks/{}/{}/messages/@original",
+        DISCORD_API_URL, config.application_id, interaction.token
+    );
+    let body = json!({ "embeds": [embed] });
+    if let Err(err) = discord_request(client.patch(url).json(&body), token).await {
+        tracing::warn!(error = %err, "failed to send discord command reply");
+    }
+}
+
+async fn run_command(
+    app: &AppHandle,
+    invocation: &SlashInvocation,
+) -> Result<DiscordEmbed, String> {
+    match invocation.command {
+        SlashCommand::Price => {
+            let query = invocation.token.as_deref().unwrap_or_default().trim();
+            Ok(price_embed(&lookup_price(app, query).await?))
+        }
+        SlashCommand::Portfolio => portfolio_embed(app),
+        SlashCommand::ScreenerRun => screener_embed(app, invocation.min_safety.unwrap_or(50)).await,
+    }
+}
+
+/// Accepts a mint address directly, otherwise resolves the symbol first.
+async fn lookup_price(app: &AppHandle, query: &str) -> Result<CoinPrice, String> {
+    if query.is_empty() {
+        return Err("Give a token symbol or mint address.".into());
+    }
+    let address = if (32..=44).contains(&query.len()) {
+        query.to_string()
+    } else {
+        search_tokens(query.to_string())
+            .await?
+            .into_iter()
+            .find(|token| token.symbol.eq_ignore_ascii_case(query))
+            .map(|token| token.address)
+            .ok_or_else(|| format!("No token found for {}", query))?
+    };
+
+    quote_coin_price(app, &address, RequestPriority::Interactive).await
+}
+
+fn price_embed(price: &CoinPrice) -> DiscordEmbed {
+    let color = if price.price_change_24h >= 0.0 {
+        COLOR_UP
+    } else {
+        COLOR_DOWN
+    };
+    let mut embed = DiscordEmbed::new(
+        format!("{} ({})", price.symbol, price.name),
+        format!("`{}`", price.address),
+        color,
+    )
+    .field("Price", format!("${:.6}", price.price), true)
+    .field("24h", format!("{:+.2}%", price.price_change_24h), true)
+    .field("Volume 24h", format!("${:.0}", price.volume_24h), true)
+    .field("Market cap", format!("${:.0}", price.market_cap), true);
+    if let Some(liquidity) = price.liquidity {
+        embed = embed.field("Liquidity", format!("${:.0}", liquidity), true);
+    }
+    embed
+}
+
+fn portfolio_embed(app: &AppHandle) -> Result<DiscordEmbed, String> {
+    let portfolio = app
+        .try_state::<SharedPortfolioData>()
+        .ok_or("Portfolio data is unavailable")?;
+    let (metrics, mut positions) = {
+        let data = portfolio.lock().map_err(|e| e.to_string())?;
+        (data.metrics(), data.positions())
+    };
+    positions.sort_by(|a, b| b.total_value.total_cmp(&a.total_value));
+
+    let color = if metrics.daily_pnl >= 0.0 {
+        COLOR_UP
+    } else {
+        COLOR_DOWN
+    };
+    let mut embed = DiscordEmbed::new(
+        "Portfolio",
+        format!("**${:.2}**", metrics.total_value),
+        color,
+    )
+    .field(
+        "Today",
+        format!(
+            "${:+.2} ({:+.2}%)",
+            metrics.daily_pnl, metrics.daily_pnl_percent
+        ),
+        true,
+    )
+    .field(
+        "Unrealized",
+        format!("${:+.2}", metrics.unrealized_pnl),
+        true,
+    )
+    .field("Realized", format!("${:+.2}", metrics.realized_pnl), true);
+    for position in positions.iter().take(MAX_LISTED_ITEMS) {
+        embed = embed.field(
+            &position.symbol,
+            format!(
+                "${:.2} ({:+.2}%)",
+                position.total_value, position.unrealized_pnl_percent
+            ),
+            true,
+        );
+    }
+    Ok(embed)
+}
+
+async fn screener_embed(app: &AppHandle, min_safety: i64) -> Result<DiscordEmbed, String> {
+    let scanner = app
+        .try_state::<SharedNewCoinsScanner>()
+        .ok_or("The token screener is unavailable")?;
+    let scanner = scanner.read().await;
+    if let Err(err) = scanner.scan_for_new_tokens().await {
+        tracing::warn!(error = %err, "discord screener scan failed, reporting stored results");
+    }
+    let mut coins = scanner
+        .get_new_coins(Some(24), Some(min_safety))
+        .await
+        .map_err(|e| e.to_string())?;
+    coins.sort_by(|a, b| b.liquidity.total_cmp(&a.liquidity));
+
+    let mut embed = DiscordEmbed::new(
+        "Screener: new tokens (24h)",
+        format!(
+            "{} token(s) with safety score of at least {}",
+            coins.len(),
+            min_safety
+        ),
+        COLOR_INFO,
+    );
+    for coin in coins.iter().take(MAX_LISTED_ITEMS) {
+        embed = embed.field(
+            format!("{} ({})", coin.symbol, coin.name),
+            format!(
+                "Safety {} · Liquidity ${:.0} · {} holders\n`{}`",
+                coin.safety_score, coin.liquidity, coin.holder_count, coin.address
+            ),
+            false,
+        );
+    }
+    Ok(embed)
+}
+
+async fn audit(app: &AppHandle, subject: &str, details: Value, success: bool) {
+    let Some(logger) = app.try_state::<ActivityLogger>() else {
+        return;
+    };
+    if let Err(err) = logger
+        .log_activity(
+            subject,
+            ActivityAction::RemoteCommand,
+            details,
+            success,
+            None,
+        )
+        .await
+    {
+        tracing::warn!(error = %err, subject, "failed to audit discord bot command");
+    }
+}
+
+#[tauri::command]
+pub fn get_discord_bot_status(
+    bot: State<'_, SharedDiscordBot>,
+    keystore: State<'_, Keystore>,
+) -> Result<DiscordBotStatus, String> {
+    Ok(DiscordBotStatus {
+        config: bot.config()?,
+        token_configured: keystore.retrieve_secret(KEY_DISCORD_BOT_TOKEN).is_ok(),
+    })
+}
+
+#[tauri::command]
+pub async fn update_discord_bot_config(
+    config: DiscordBotConfig,
+    bot: State<'_, SharedDiscordBot>,
+    logger: State<'_, ActivityLogger>,
+) -> Result<DiscordBotConfig, String> {
+    config.validate()?;
+    bot.save_config(config.clone())?;
+
+    let details = json!({
+        "enabled": config.enabled,
+        "guilds": config.guilds,
+        "users": config.users,
+    });
+    if let Err(err) = logger
+        .log_activity(
+            CONFIG_AUDIT_SUBJECT,
+            ActivityAction::RemoteCommand,
+            details,
+            true,
+            None,
+        )
+        .await
+    {
+        tracing::warn!(error = %err, "failed to audit discord bot config change");
+    }
+
+    Ok(config)
+}
+
+#[tauri::command]
+pub fn set_discord_bot_token(token: String, keystore: State<'_, Keystore>) -> Result<(), String> {
+    let token = token.trim();
+    if token.is_empty() {
+        return Err("Bot token cannot be empty".into());
+    }
+    keystore
+        .store_secret(KEY_DISCORD_BOT_TOKEN, token.as_bytes())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn clear_discord_bot_token(keystore: State<'_, Keystore>) -> Result<(), String> {
+    keystore
+        .remove_secret(KEY_DISCORD_BOT_TOKEN)
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interaction(raw: Value) -> DiscordInteraction {
+        serde_json::from_value(raw).expect("valid interaction")
+    }
+
+    #[test]
+    fn parses_guild_and_dm_invocations() {
+        let guild = interaction(json!({
+            "id": "1", "token": "t", "type": 2, "guild_id": "10",
+            "member": { "user": { "id": "42" }, "roles": ["7"] },
+            "data": { "name": "screener", "options": [{
+                "name": "run", "options": [{ "name": "min_safety", "value": 70 }]
+            }]}
+        }))
+        .invocation()
+        .unwrap();
+        assert_eq!(guild.command, SlashCommand::ScreenerRun);
+        assert_eq!(guild.guild_id.as_deref(), Some("10"));
+        assert_eq!(guild.role_ids, vec!["7".to_string()]);
+        assert_eq!(guild.min_safety, Some(70));
+
+        let dm = interaction(json!({
+            "id": "2", "token": "t", "type": 2,
+            "user": { "id": "42" },
+            "data": { "name": "price", "options": [{ "name": "token", "value": "SOL" }] }
+        }))
+        .invocation()
+        .unwrap();
+        assert_eq!(dm.command, SlashCommand::Price);
+        assert_eq!(dm.guild_id, None);
+        assert_eq!(dm.token.as_deref(), Some("SOL"));
+    }
+
+    #[test]
+    fn authorizes_by_guild_roles_and_user_rules() {
+        let config = DiscordBotConfig {
+            guilds: vec![DiscordGuildRule {
+                guild_id: "10".into(),
+                commands: vec![SlashCommand::Price, SlashCommand::ScreenerRun],
+                allowed_role_ids: vec!["7".into()],
+            }],
+            users: vec![DiscordUserRule {
+                user_id: "42".into(),
+                commands: vec![SlashCommand::Portfolio],
+            }],
+            ..DiscordBotConfig::default()
+        };
+        let invocation =
+            |command, guild: Option<&str>, user: &str, roles: &[&str]| SlashInvocation {
+                command,
+                guild_id: guild.map(str::to_string),
+                user_id: user.into(),
+                role_ids: roles.iter().map(|r| r.to_string()).collect(),
+                token: None,
+                min_safety: None,
+            };
+
+        assert!(authorize_slash_command(
+            &config,
+            &invocation(SlashCommand::Price, Some("10"), "1", &["7"])
+        ));
+        assert!(!authorize_slash_command(
+            &config,
+            &invocation(SlashCommand::Price, Some("10"), "1", &["8"])
+        ));
+        assert!(!authorize_slash_command(
+            &config,
+            &invocation(SlashCommand::Price, Some("11"), "1", &["7"])
+        ));
+        assert!(!authorize_slash_command(
+            &config,
+            &invocation(SlashCommand::Portfolio, Some("10"), "1", &["7"])
+        ));
+        assert!(authorize_slash_command(
+            &config,
+            &invocation(SlashCommand::Portfolio, None, "42", &[])
+        ));
+        assert!(!authorize_slash_command(
+            &config,
+            &invocation(SlashCommand::Price, None, "42", &[])
+        ));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn command_definitions_cover_every_slash_command() {
+        let definitions = slash_command_definitions();
+        let names: Vec<&str> = definitions
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|command| command["name"].as_str())
+            .collect();
+        assert_eq!(names, vec!["price", "portfolio", "screener"]);
+        assert_eq!(definitions[2]["options"][0]["name"], "run");
+    }
+}
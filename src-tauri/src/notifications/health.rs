@@ -0,0 +1,510 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use super::discord::DiscordClient;
+use super::email::{EmailManager, SendEmailRequest};
+use super::router::SharedNotificationRouter;
+use super::slack::SlackClient;
+use super::telegram::TelegramClient;
+use super::twitter::TwitterManager;
+use crate::security::keystore::Keystore;
+
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30 * 60);
+const INITIAL_CHECK_DELAY: Duration = Duration::from_secs(60);
+/// Consecutive failures before a previously working integration counts as
+/// broken, so a single network blip doesn't trigger a warning.
+const FAILURES_BEFORE_NOTIFY: u32 = 2;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum IntegrationKind {
+    Email,
+    Twitter,
+    Telegram,
+    Slack,
+    Discord,
+}
+
+impl IntegrationKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            IntegrationKind::Email => "Email (SMTP)",
+            IntegrationKind::Twitter => "Twitter",
+            IntegrationKind::Telegram => "Telegram",
+            IntegrationKind::Slack => "Slack",
+            IntegrationKind::Discord => "Discord",
+        }
+    }
+
+    /// Order in which working channels are tried for breakage warnings.
+    fn fallback_rank(&self) -> Option<u8> {
+        match self {
+            IntegrationKind::Telegram => Some(0),
+            IntegrationKind::Discord => Some(1),
+            IntegrationKind::Slack => Some(2),
+            IntegrationKind::Email => Some(3),
+            IntegrationKind::Twitter => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationHealth {
+    pub kind: IntegrationKind,
+    pub config_id: String,
+    pub name: String,
+    pub healthy: bool,
+    pub error: Option<String>,
+    pub latency_ms: Option<u64>,
+    pub checked_at: String,
+    pub last_healthy_at: Option<String>,
+    pub consecutive_failures: u32,
+    /// Whether the integration may carry warnings about other integrations.
+    pub can_notify: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationHealthReport {
+    pub checked_at: Option<String>,
+    pub integrations: Vec<IntegrationHealth>,
+    pub failing_count: usize,
+}
+
+/// Emitted on `integration_health_alert` when integrations stop working.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationHealthAlert {
+    pub broken: Vec<IntegrationHealth>,
+    pub notified_via: Option<IntegrationKind>,
+}
+
+/// Result of verifying one configured credential.
+#[derive(Debug, Clone)]
+pub struct IntegrationProbe {
+    pub kind: IntegrationKind,
+    pub config_id: String,
+    pub name: String,
+    pub can_notify: bool,
+    pub outcome: Result<u64, String>,
+}
+
+#[derive(Default)]
+pub struct IntegrationHealthMonitor {
+    results: HashMap<(IntegrationKind, String), IntegrationHealth>,
+    checked_at: Option<String>,
+}
+
+pub type SharedIntegrationHealth = Arc<RwLock<IntegrationHealthMonitor>>;
+
+impl IntegrationHealthMonitor {
+    /// Replaces the stored results with `probes`, returning integrations that
+    /// just crossed the failure threshold after having worked before.
+    pub fn apply(&mut self, probes: Vec<IntegrationProbe>, now: &str) -> Vec<IntegrationHealth> {
+        let mut broken = Vec::new();
+        let mut results = HashMap::new();
+
+        for probe in probes {
+            let key = (probe.kind, probe.config_id.clone());
+            let health = merge_probe(self.results.get(&key), probe, now);
+            if just_broke(&health) {
+                broken.push(health.clone());
+            }
+            results.insert(key, health);
+        }
+
+        self.results = results;
+        self.checked_at = Some(now.to_string());
+        broken
+    }
+
+    pub fn report(&self) -> IntegrationHealthReport {
+        let mut integrations: Vec<_> = self.results.values().cloned().collect();
+        integrations.sort_by(|a, b| {
+            a.healthy
+                .cmp(&b.healthy)
+                .then_with(|| a.kind.label().cmp(b.kind.label()))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        IntegrationHealthReport {
+            checked_at: self.checked_at.clone(),
+            failing_count: integrations.iter().filter(|h| !h.healthy).count(),
+            integrations,
+        }
+    }
+}
+
+pub fn merge_probe(
+    previous: Option<&IntegrationHealth>,
+    probe: IntegrationProbe,
+    now: &str,
+) -> IntegrationHealth {
+    let last_healthy_at = previous.and_then(|p| p.last_healthy_at.clone());
+    let failures = previous.map(|p| p.consecutive_failures).unwrap_or(0);
+
+    let (healthy, error, latency_ms, last_healthy_at, consecutive_failures) = match probe.outcome {
+        Ok(latency) => (true, None, Some(latency), Some(now.to_string()), 0),
+        Err(err) => (false, Some(err), None, last_healthy_at, failures + 1),
+    };
+
+    IntegrationHealth {
+        kind: probe.kind,
+        config_id: probe.config_id,
+        name: probe.name,
+        healthy,
+        error,
+        latency_ms,
+        checked_at: now.to_string(),
+        last_healthy_at,
+        consecutive_failures,
+        can_notify: probe.can_notify,
+    }
+}
+
+/// Only integrations that worked at some point are reported, and only once,
+/// when they reach the failure threshold.
+fn just_broke(health: &IntegrationHealth) -> bool {
+    !health.healthy
+        && health.last_healthy_at.is_some()
+        && health.consecutive_failures == FAILURES_BEFORE_NOTIFY
+}
+
+pub fn pick_fallback_channel(results: &[IntegrationHealth]) -> Option<&IntegrationHealth> {
+    results
+        .iter()
+        .filter(|h| h.healthy && h.can_notify)
+        .filter_map(|h| h.kind.fallback_rank().map(|rank| (rank, h)))
+        .min_by_key(|(rank, _)| *rank)
+        .map(|(_, h)| h)
+}
+
+async fn timed<F, E>(check: F) -> Result<u64, String>
+where
+    F: Future<Output = Result<(), E>>,
+    E: Display,
+{
+    let start = Instant::now();
+    check
+        .await
+        .map(|_| start.elapsed().as_millis() as u64)
+        .map_err(|e| e.to_string())
+}
+
+/// Verifies every enabled integration without sending anything to it.
+pub async fn probe_integrations(app: &AppHandle) -> Vec<IntegrationProbe> {
+    let mut probes = Vec::new();
+
+    if let Some(router) = app.try_state::<SharedNotificationRouter>() {
+        match router.read().await.get_settings().await {
+            Ok(settings) => {
+                let telegram = TelegramClient::new();
+                for config in settings.telegram.iter().filter(|c| c.enabled) {
+                    probes.push(IntegrationProbe {
+                        kind: IntegrationKind::Telegram,
+                        config_id: config.id.clone(),
+                        name: config.name.clone(),
+                        can_notify: !config.sandbox,
+                        outcome: timed(telegram.test_connection(config)).await,
+                    });
+                }
+
+                let slack = SlackClient::new();
+                for config in settings.slack.iter().filter(|c| c.enabled) {
+                    probes.push(IntegrationProbe {
+                        kind: IntegrationKind::Slack,
+                        config_id: config.id.clone(),
+                        name: config.name.clone(),
+                        can_notify: !config.sandbox,
+                        outcome: timed(slack.check_webhook(config)).await,
+                    });
+                }
+
+                let discord = DiscordClient::new();
+                for config in settings.discord.iter().filter(|c| c.enabled) {
+                    probes.push(IntegrationProbe {
+                        kind: IntegrationKind::Discord,
+                        config_id: config.id.clone(),
+                        name: config.name.clone(),
+                        can_notify: !config.sandbox,
+                        outcome: timed(discord.check_webhook(config)).await,
+                    });
+                }
+            }
+            Err(err) => tracing::warn!(error = %err, "failed to load chat integrations"),
+        }
+    }
+
+    let Some(keystore) = app.try_state::<Keystore>() else {
+        return probes;
+    };
+
+    if let Ok(manager) = EmailManager::new(app).await {
+        if let Ok(config) = manager.get_config(&keystore).await {
+            probes.push(IntegrationProbe {
+                kind: IntegrationKind::Email,
+                config_id: config.from_address.clone(),
+                name: config.server.clone(),
+                can_notify: true,
+                outcome: timed(async { manager.test_connection(&config).await.map(|_| ()) }).await,
+            });
+        }
+    }
+
+    if let Ok(manager) = TwitterManager::new(app).await {
+        if let Ok(config) = manager.get_config(&keystore).await {
+            if config.enabled {
+                probes.push(IntegrationProbe {
+                    kind: IntegrationKind::Twitter,
+                    config_id: "twitter".to_string(),
+                    name: "Twitter API".to_string(),
+                    can_notify: false,
+                    outcome: timed(async { manager.test_connection(&config).await.map(|_| ()) })
+                        .await,
+                });
+            }
+        }
+    }
+
+    probes
+}
+
+fn breakage_message(broken: &[IntegrationHealth]) -> String {
+    let mut message = String::from("These notification integrations stopped working:\n");
+    for health in broken {
+        message.push_str(&format!(
+            "- {} \"{}\": {}\n",
+            health.kind.label(),
+            health.name,
+            health.error.as_deref().unwrap_or("unknown error")
+        ));
+    }
+    message.push_str("Alerts routed only to them are not being delivered.");
+    message
+}
+
+async fn send_via(
+    app: &AppHandle,
+    channel: &IntegrationHealth,
+    message: &str,
+) -> Result<(), String> {
+    match channel.kind {
+        IntegrationKind::Telegram | IntegrationKind::Slack | IntegrationKind::Discord => {
+            let router = app
+                .try_state::<SharedNotificationRouter>()
+                .ok_or("Notification router not initialized")?;
+            let settings = router
+                .read()
+                .await
+                .get_settings()
+                .await
+                .map_err(|e| e.to_string())?;
+            let missing = || {
+                format!(
+                    "{} config {} not found",
+                    channel.kind.label(),
+                    channel.config_id
+                )
+            };
+
+            match channel.kind {
+                IntegrationKind::Telegram => {
+                    let config = settings
+                        .telegram
+                        .iter()
+                        .find(|c| c.id == channel.config_id)
+                        .ok_or_else(missing)?;
+                    TelegramClient::new()
+                        .send_message(config, message, false)
+                        .await
+                }
+                IntegrationKind::Slack => {
+                    let config = settings
+                        .slack
+                        .iter()
+                        .find(|c| c.id == channel.config_id)
+                        .ok_or_else(missing)?;
+                    SlackClient::new().send_message(config, message).await
+                }
+                _ => {
+                    let config = settings
+                        .discord
+                        .iter()
+                        .find(|c| c.id == channel.config_id)
+                        .ok_or_else(missing)?;
+                    DiscordClient::new()
+                        .send_message(config, message, false)
+                        .await
+                }
+            }
+            .map_err(|e| e.to_string())
+        }
+        IntegrationKind::Email => {
+            let keystore = app
+                .try_state::<Keystore>()
+                .ok_or("Keystore not initialized")?;
+            let manager = EmailManager::new(app).await.map_err(|e| e.to_string())?;
+            let config = manager
+                .get_config(&keystore)
+                .await
+                .map_err(|e| e.to_string())?;
+            let request = SendEmailRequest {
+                to: vec![config.from_address.clone()],
+                subject: "Notification integration stopped working".to_string(),
+                html_body: None,
+                text_body: Some(message.to_string()),
+                template: None,
+                template_vars: None,
+                attachments: None,
+                include_unsubscribe: false,
+            };
+            manager
+                .send_email(request, &config)
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        }
+        IntegrationKind::Twitter => Err("Twitter is never used for warnings".to_string()),
+    }
+}
+
+async fn notify_broken(
+    app: &AppHandle,
+    results: &[IntegrationHealth],
+    broken: Vec<IntegrationHealth>,
+) {
+    let message = breakage_message(&broken);
+    let mut notified_via = None;
+
+    if let Some(channel) = pick_fallback_channel(results) {
+        match send_via(app, channel, &message).await {
+            Ok(()) => notified_via = Some(channel.kind),
+            Err(err) => tracing::warn!(
+                error = %err,
+                channel = channel.kind.label(),
+                "failed to send integration health warning"
+            ),
+        }
+    }
+
+    let alert = IntegrationHealthAlert {
+        broken,
+        notified_via,
+    };
+    if let Err(err) = app.emit_all("integration_health_alert", &alert) {
+        tracing::warn!(error = %err, "failed to emit integration health alert");
+    }
+}
+
+/// Runs all checks, stores the results and warns about newly broken
+/// integrations through one that still works.
+pub async fn run_integration_health_checks(app: &AppHandle) -> IntegrationHealthReport {
+    let probes = probe_integrations(app).await;
+    let Some(state) = app.try_state::<SharedIntegrationHealth>() else {
+        let mut monitor = IntegrationHealthMonitor::default();
+        monitor.apply(probes, &Utc::now().to_rfc3339());
+        return monitor.report();
+    };
+
+    let (broken, report) = {
+        let mut monitor = state.write().await;
+        let broken = monitor.apply(probes, &Utc::now().to_rfc3339());
+        (broken, monitor.report())
+    };
+
+    if !broken.is_empty() {
+        notify_broken(app, &report.integrations, broken).await;
+    }
+    report
+}
+
+pub fn register_integration_health(app: &tauri::App) {
+    let state: SharedIntegrationHealth = Arc::new(RwLock::new(IntegrationHealthMonitor::default()));
+    app.manage(state);
+
+    let handle = app.handle();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(INITIAL_CHECK_DELAY).await;
+        loop {
+            run_integration_health_checks(&handle).await;
+            tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+        }
+    });
+}
+
+/// Latest credential check results; `refresh` re-runs every check first.
+#[tauri::command]
+pub async fn get_integration_health(
+    app: AppHandle,
+    state: State<'_, SharedIntegrationHealth>,
+    refresh: Option<bool>,
+) -> Result<IntegrationHealthReport, String> {
+    if refresh.unwrap_or(false) {
+        return Ok(run_integration_health_checks(&app).await);
+    }
+    Ok(state.read().await.report())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(kind: IntegrationKind, id: &str, outcome: Result<u64, String>) -> IntegrationProbe {
+        IntegrationProbe {
+            kind,
+            config_id: id.to_string(),
+            name: id.to_string(),
+            can_notify: kind != IntegrationKind::Twitter,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn warns_once_after_repeated_failures() {
+        let mut monitor = IntegrationHealthMonitor::default();
+        let slack = |outcome| vec![probe(IntegrationKind::Slack, "s1", outcome)];
+
+        assert!(monitor.apply(slack(Ok(120)), "t0").is_empty());
+        assert!(monitor.apply(slack(Err("404".into())), "t1").is_empty());
+        let broken = monitor.apply(slack(Err("404".into())), "t2");
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].last_healthy_at.as_deref(), Some("t0"));
+        assert!(monitor.apply(slack(Err("404".into())), "t3").is_empty());
+    }
+
+    #[test]
+    fn never_working_integrations_do_not_warn() {
+        let mut monitor = IntegrationHealthMonitor::default();
+        let email = |outcome| vec![probe(IntegrationKind::Email, "me@x.io", outcome)];
+
+        assert!(monitor.apply(email(Err("auth".into())), "t0").is_empty());
+        assert!(monitor.apply(email(Err("auth".into())), "t1").is_empty());
+        assert_eq!(monitor.report().failing_count, 1);
+    }
+
+    #[test]
+    fn fallback_prefers_working_chat_channels() {
+        let results = vec![
+            merge_probe(
+                None,
+                probe(IntegrationKind::Telegram, "t", Err("401".into())),
+                "t0",
+            ),
+            merge_probe(None, probe(IntegrationKind::Twitter, "x", Ok(10)), "t0"),
+            merge_probe(None, probe(IntegrationKind::Email, "e", Ok(10)), "t0"),
+            merge_probe(None, probe(IntegrationKind::Discord, "d", Ok(10)), "t0"),
+        ];
+
+        let fallback = pick_fallback_channel(&results).unwrap();
+        assert_eq!(fallback.kind, IntegrationKind::Discord);
+        assert!(pick_fallback_channel(&results[..2]).is_none());
+    }
+}
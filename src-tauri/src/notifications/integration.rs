@@ -1,33 +1,95 @@
 use crate::alerts::price_alerts::{AlertTriggerEvent, NotificationChannel};
 use super::router::SharedNotificationRouter;
+use crate::core::message_queue::{enqueue_message, NotificationJob, QueueTopic};
+use tauri::{AppHandle, Manager};
+
+fn wants_chat_delivery(channels: &[NotificationChannel]) -> bool {
+    channels.iter().any(|c| {
+        matches!(
+            c,
+            NotificationChannel::Telegram | NotificationChannel::Slack | NotificationChannel::Discord
+        )
+    })
+}
+
+fn wants_push_delivery(channels: &[NotificationChannel]) -> bool {
+    channels.contains(&NotificationChannel::Push)
+}
 
 pub async fn send_alert_notifications(
     router: SharedNotificationRouter,
     event: AlertTriggerEvent,
     channels: Vec<NotificationChannel>,
 ) {
-    let should_send_chat = channels.iter().any(|c| {
-        matches!(
-            c,
-            NotificationChannel::Telegram | NotificationChannel::Slack | NotificationChannel::Discord
-        )
-    });
+    let router_guard = router.read().await;
+    if wants_chat_delivery(&channels) {
+        if let Err(e) = router_guard
+            .send_alert_notification(
+                &event.alert_id,
+                &event.alert_name,
+                &event.symbol,
+                event.current_price,
+                &event.conditions_met,
+            )
+            .await
+        {
+            eprintln!("Failed to send chat notifications: {}", e);
+        }
+    }
+
+    if wants_push_delivery(&channels) {
+        if let Err(e) = router_guard
+            .send_alert_push(
+                &event.alert_id,
+                &event.alert_name,
+                &event.symbol,
+                event.current_price,
+                &event.conditions_met,
+            )
+            .await
+        {
+            eprintln!("Failed to send push notifications: {}", e);
+        }
+    }
+}
 
-    if !should_send_chat {
+/// Queues the chat and push fan-out for a triggered alert so a restart or a
+/// provider outage retries it instead of dropping it.
+pub async fn queue_alert_notifications(
+    app: &AppHandle,
+    event: &AlertTriggerEvent,
+    channels: &[NotificationChannel],
+) {
+    let chat = wants_chat_delivery(channels);
+    let push = wants_push_delivery(channels);
+    if !chat && !push {
         return;
     }
 
-    let router_guard = router.read().await;
-    if let Err(e) = router_guard
-        .send_alert_notification(
-            &event.alert_id,
-            &event.alert_name,
-            &event.symbol,
-            event.current_price,
-            &event.conditions_met,
-        )
+    let job = NotificationJob::Alert {
+        alert_id: event.alert_id.clone(),
+        alert_name: event.alert_name.clone(),
+        symbol: event.symbol.clone(),
+        current_price: event.current_price,
+        condition: event.conditions_met.clone(),
+        chat,
+        push,
+    };
+    let Ok(payload) = serde_json::to_value(&job) else {
+        return;
+    };
+    let dedupe_key = format!("alert:{}:{}", event.alert_id, event.triggered_at);
+    if enqueue_message(QueueTopic::Notification, Some(&dedupe_key), payload)
         .await
+        .is_ok()
     {
-        eprintln!("Failed to send chat notifications: {}", e);
+        return;
+    }
+
+    if let Some(router) = app.try_state::<SharedNotificationRouter>() {
+        let router = router.inner().clone();
+        let event = event.clone();
+        let channels = channels.to_vec();
+        tauri::async_runtime::spawn(send_alert_notifications(router, event, channels));
     }
 }
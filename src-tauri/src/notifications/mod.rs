@@ -4,21 +4,31 @@ pub mod twitter;
 pub use email::*;
 pub use twitter::*;
 pub mod telegram;
+pub mod telegram_bot;
 pub mod slack;
 pub mod discord;
+pub mod discord_bot;
+pub mod push_bridge;
 pub mod delivery_log;
 pub mod rate_limiter;
 pub mod router;
 pub mod types;
 pub mod commands;
 pub mod integration;
+pub mod health;
+pub mod report_email;
 
 pub use telegram::*;
+pub use telegram_bot::*;
 pub use slack::*;
 pub use discord::*;
+pub use discord_bot::*;
+pub use push_bridge::*;
 pub use delivery_log::*;
 pub use rate_limiter::*;
 pub use router::*;
 pub use types::*;
 pub use commands::*;
 pub use integration::*;
+pub use health::*;
+pub use report_email::*;
@@ -0,0 +1,482 @@
+use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::{
+    aead::{Aead, KeyInit, OsRng},
+    Aes256Gcm,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64_ENGINE, Engine};
+use chrono::Utc;
+use rand_core::RngCore;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use uuid::Uuid;
+use zeroize::Zeroizing;
+
+use super::types::NotificationError;
+use crate::security::keystore::{Keystore, KeystoreError};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const KEY_PUSH_RELAY_CONFIG: &str = "push_relay_config";
+const PUSH_KEY_PREFIX: &str = "push_content_key_";
+const PAYLOAD_VERSION: u8 = 1;
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+/// Pushes older than a day are useless for alerts, so distributors may drop them.
+const PUSH_TTL_SECONDS: u32 = 86_400;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PushProvider {
+    /// The phone's UnifiedPush distributor endpoint, posted to directly.
+    UnifiedPush,
+    /// An FCM registration token, delivered through the configured relay.
+    FcmRelay,
+}
+
+impl PushProvider {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PushProvider::UnifiedPush => "unified_push",
+            PushProvider::FcmRelay => "fcm_relay",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "unified_push" => Some(PushProvider::UnifiedPush),
+            "fcm_relay" => Some(PushProvider::FcmRelay),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushSubscription {
+    pub id: String,
+    pub name: String,
+    pub provider: PushProvider,
+    /// UnifiedPush endpoint URL or FCM registration token.
+    pub endpoint: String,
+    pub enabled: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushSubscriptionInput {
+    pub name: String,
+    pub provider: PushProvider,
+    pub endpoint: String,
+}
+
+/// Returned once when a phone is registered. The content key is what the phone
+/// decrypts pushes with and is not retrievable afterwards.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushRegistration {
+    pub subscription: PushSubscription,
+    pub content_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushRelayConfig {
+    pub url: String,
+    pub api_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushRelayStatus {
+    pub url: Option<String>,
+    pub api_key_configured: bool,
+}
+
+/// What relays and distributors see: only the subscription id and ciphertext.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EncryptedPushPayload {
+    pub v: u8,
+    pub kid: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PushContent {
+    pub reference_id: String,
+    pub title: String,
+    pub body: String,
+    pub sent_at: String,
+}
+
+pub fn encrypt_payload(
+    key: &[u8],
+    kid: &str,
+    content: &PushContent,
+) -> Result<EncryptedPushPayload, NotificationError> {
+    if key.len() != KEY_LEN {
+        return Err(NotificationError::Internal(
+            "Invalid push content key".into(),
+        ));
+    }
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+
+    let plaintext = serde_json::to_vec(content)?;
+    let cipher = Aes256Gcm::new(GenericArray::from_slice(key));
+    // The subscription id is bound as associated data so a payload can't be
+    // replayed to a different phone.
+    let ciphertext = cipher
+        .encrypt(
+            GenericArray::from_slice(&nonce),
+            aes_gcm::aead::Payload {
+                msg: &plaintext,
+                aad: kid.as_bytes(),
+            },
+        )
+        .map_err(|_| NotificationError::Internal("Failed to encrypt push payload".into()))?;
+
+    Ok(EncryptedPushPayload {
+        v: PAYLOAD_VERSION,
+        kid: kid.to_string(),
+        nonce: BASE64_ENGINE.encode(nonce),
+        ciphertext: BASE64_ENGINE.encode(ciphertext),
+    })
+}
+
+/// Mirror of what the phone does on receipt.
+pub fn decrypt_payload(
+    key: &[u8],
+    payload: &EncryptedPushPayload,
+) -> Result<PushContent, NotificationError> {
+    let invalid = || NotificationError::Internal("Invalid push payload".into());
+    if key.len() != KEY_LEN || payload.v != PAYLOAD_VERSION {
+        return Err(invalid());
+    }
+    let nonce = BASE64_ENGINE
+        .decode(&payload.nonce)
+        .map_err(|_| invalid())?;
+    let ciphertext = BASE64_ENGINE
+        .decode(&payload.ciphertext)
+        .map_err(|_| invalid())?;
+    if nonce.len() != NONCE_LEN {
+        return Err(invalid());
+    }
+
+    let cipher = Aes256Gcm::new(GenericArray::from_slice(key));
+    let plaintext = cipher
+        .decrypt(
+            GenericArray::from_slice(&nonce),
+            aes_gcm::aead::Payload {
+                msg: &ciphertext,
+                aad: payload.kid.as_bytes(),
+            },
+        )
+        .map_err(|_| invalid())?;
+    Ok(serde_json::from_slice(&plaintext)?)
+}
+
+pub struct PushBridge {
+    pool: Pool<Sqlite>,
+    client: Client,
+    app: AppHandle,
+}
+
+impl PushBridge {
+    pub fn new(pool: Pool<Sqlite>, app: AppHandle) -> Self {
+        Self {
+            pool,
+            client: Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_else(|_| Client::new()),
+            app,
+        }
+    }
+
+    pub async fn initialize(&self) -> Result<(), NotificationError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS push_subscriptions (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                provider TEXT NOT NULL,
+                endpoint TEXT NOT NULL,
+                enabled INTEGER NOT NULL,
+                created_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    fn keystore(&self) -> Result<tauri::State<'_, Keystore>, NotificationError> {
+        self.app
+            .try_state::<Keystore>()
+            .ok_or_else(|| NotificationError::Internal("Keystore unavailable".into()))
+    }
+
+    pub async fn list_subscriptions(&self) -> Result<Vec<PushSubscription>, NotificationError> {
+        let rows = sqlx::query("SELECT * FROM push_subscriptions ORDER BY created_at DESC")
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let provider: String = row.try_get("provider")?;
+                Ok(PushSubscription {
+                    id: row.try_get("id")?,
+                    name: row.try_get("name")?,
+                    provider: PushProvider::from_str(&provider).ok_or_else(|| {
+                        NotificationError::Internal(format!("Unknown push provider: {}", provider))
+                    })?,
+                    endpoint: row.try_get("endpoint")?,
+                    enabled: row.try_get::<i64, _>("enabled")? == 1,
+                    created_at: row.try_get("created_at")?,
+                })
+            })
+            .collect()
+    }
+
+    pub async fn get_subscription(&self, id: &str) -> Result<PushSubscription, NotificationError> {
+        self.list_subscriptions()
+            .await?
+            .into_iter()
+            .find(|subscription| subscription.id == id)
+            .ok_or_else(|| NotificationError::ConfigNotFound(id.to_string()))
+    }
+
+    pub async fn register_subscription(
+        &self,
+        input: PushSubscriptionInput,
+    ) -> Result<PushRegistration, NotificationError> {
+        let endpoint = input.endpoint.trim().to_string();
+        if endpoint.is_empty() {
+            return Err(NotificationError::Internal(
+                "Push endpoint is required".into(),
+            ));
+        }
+        if input.provider == PushProvider::UnifiedPush && !endpoint.starts_with("https://") {
+            return Err(NotificationError::Internal(
+                "UnifiedPush endpoints must use https".into(),
+            ));
+        }
+
+        let subscription = PushSubscription {
+            id: Uuid::new_v4().to_string(),
+            name: input.name,
+            provider: input.provider,
+            endpoint,
+            enabled: true,
+            created_at: Utc::now().to_rfc3339(),
+        };
+
+        let mut key = Zeroizing::new([0u8; KEY_LEN]);
+        OsRng.fill_bytes(&mut key[..]);
+        self.keystore()?
+            .store_secret(&content_key_id(&subscription.id), &key[..])
+            .map_err(|e| NotificationError::Internal(format!("Failed to store push key: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO push_subscriptions (id, name, provider, endpoint, enabled, created_at)
+            VALUES (?1, ?2, ?3, ?4, 1, ?5)
+            "#,
+        )
+        .bind(&subscription.id)
+        .bind(&subscription.name)
+        .bind(subscription.provider.as_str())
+        .bind(&subscription.endpoint)
+        .bind(&subscription.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(PushRegistration {
+            content_key: BASE64_ENGINE.encode(&key[..]),
+            subscription,
+        })
+    }
+
+    pub async fn set_enabled(&self, id: &str, enabled: bool) -> Result<(), NotificationError> {
+        let result = sqlx::query("UPDATE push_subscriptions SET enabled = ?1 WHERE id = ?2")
+            .bind(if enabled { 1 } else { 0 })
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(NotificationError::ConfigNotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    pub async fn remove_subscription(&self, id: &str) -> Result<(), NotificationError> {
+        sqlx::query("DELETE FROM push_subscriptions WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if let Err(err) = self.keystore()?.remove_secret(&content_key_id(id)) {
+            tracing::warn!(error = %err, "failed to remove push content key");
+        }
+        Ok(())
+    }
+
+    pub fn relay_status(&self) -> Result<PushRelayStatus, NotificationError> {
+        let config = self.relay_config()?;
+        Ok(PushRelayStatus {
+            url: config.as_ref().map(|config| config.url.clone()),
+            api_key_configured: config.is_some_and(|config| config.api_key.is_some()),
+        })
+    }
+
+    pub fn save_relay_config(
+        &self,
+        config: Option<PushRelayConfig>,
+    ) -> Result<(), NotificationError> {
+        let keystore = self.keystore()?;
+        let Some(config) = config else {
+            if let Err(err) = keystore.remove_secret(KEY_PUSH_RELAY_CONFIG) {
+                tracing::warn!(error = %err, "failed to remove push relay config");
+            }
+            return Ok(());
+        };
+        if !config.url.starts_with("https://") {
+            return Err(NotificationError::Internal(
+                "Push relay URL must use https".into(),
+            ));
+        }
+
+        let serialized = serde_json::to_vec(&config)?;
+        keystore
+            .store_secret(KEY_PUSH_RELAY_CONFIG, &serialized)
+            .map_err(|e| {
+                NotificationError::Internal(format!("Failed to store relay config: {}", e))
+            })
+    }
+
+    fn relay_config(&self) -> Result<Option<PushRelayConfig>, NotificationError> {
+        match self.keystore()?.retrieve_secret(KEY_PUSH_RELAY_CONFIG) {
+            Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+            Err(KeystoreError::NotFound) => Ok(None),
+            Err(e) => Err(NotificationError::Internal(format!(
+                "Failed to read relay config: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Encrypts the message with the phone's content key and hands it to the
+    /// distributor or relay. Neither can read the title or body.
+    pub async fn send(
+        &self,
+        subscription: &PushSubscription,
+        reference_id: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<(), NotificationError> {
+        let key = self
+            .keystore()?
+            .retrieve_secret(&content_key_id(&subscription.id))
+            .map_err(|_| NotificationError::ConfigNotFound(subscription.id.clone()))?;
+        let content = PushContent {
+            reference_id: reference_id.to_string(),
+            title: title.to_string(),
+            body: body.to_string(),
+            sent_at: Utc::now().to_rfc3339(),
+        };
+        let payload = encrypt_payload(&key, &subscription.id, &content)?;
+
+        let request = match subscription.provider {
+            PushProvider::UnifiedPush => self
+                .client
+                .post(&subscription.endpoint)
+                .header("TTL", PUSH_TTL_SECONDS.to_string())
+                .header("Urgency", "high")
+                .json(&payload),
+            PushProvider::FcmRelay => {
+                let relay = self
+                    .relay_config()?
+                    .ok_or_else(|| NotificationError::ConfigNotFound("push relay".to_string()))?;
+                let request = self.client.post(&relay.url).json(&serde_json::json!({
+                    "token": subscription.endpoint,
+                    "priority": "high",
+                    "ttlSeconds": PUSH_TTL_SECONDS,
+                    "data": payload,
+                }));
+                match relay.api_key.as_deref() {
+                    Some(api_key) => request.bearer_auth(api_key),
+                    None => request,
+                }
+            }
+        };
+
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(NotificationError::Internal(format!(
+                "Push delivery failed: {} {}",
+                status, body
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn content_key_id(subscription_id: &str) -> String {
+    format!("{}{}", PUSH_KEY_PREFIX, subscription_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content() -> PushContent {
+        PushContent {
+            reference_id: "loan-1".into(),
+            title: "Liquidation risk".into(),
+            body: "Health factor 1.02".into(),
+            sent_at: "2024-01-01T00:00:00Z".into(),
+        }
+    }
+
+    #[test]
+    fn payload_round_trips_without_leaking_content() {
+        let key = [7u8; KEY_LEN];
+        let payload = encrypt_payload(&key, "sub-1", &content()).unwrap();
+
+        let wire = serde_json::to_string(&payload).unwrap();
+        assert!(!wire.contains("Liquidation"));
+        assert_eq!(decrypt_payload(&key, &payload).unwrap(), content());
+    }
+
+    #[test]
+    fn payload_is_bound_to_key_and_subscription() {
+        let key = [7u8; KEY_LEN];
+        let payload = encrypt_payload(&key, "sub-1", &content()).unwrap();
+
+        assert!(decrypt_payload(&[8u8; KEY_LEN], &payload).is_err());
+        let moved = EncryptedPushPayload {
+            kid: "sub-2".into(),
+            ..payload
+        };
+        assert!(decrypt_payload(&key, &moved).is_err());
+    }
+
+    #[test]
+    fn nonces_are_unique_per_payload() {
+        let key = [7u8; KEY_LEN];
+        let first = encrypt_payload(&key, "sub-1", &content()).unwrap();
+        let second = encrypt_payload(&key, "sub-1", &content()).unwrap();
+        assert_ne!(first.nonce, second.nonce);
+        assert!(encrypt_payload(&[1u8; 16], "sub-1", &content()).is_err());
+    }
+}
@@ -9,6 +9,7 @@ use super::types::{ChatServiceType, NotificationError, RateLimitStatus};
 const TELEGRAM_MAX_PER_MINUTE: i32 = 30; // Conservative limit per bot per minute
 const SLACK_MAX_PER_MINUTE: i32 = 60; // Slack incoming webhooks allow ~1 msg/sec
 const DISCORD_MAX_PER_MINUTE: i32 = 60; // Discord webhooks are rate limited server-side
+const PUSH_MAX_PER_MINUTE: i32 = 10; // Critical alerts only; keeps a phone from being flooded
 
 #[derive(Debug, Clone)]
 struct RateLimitEntry {
@@ -77,6 +78,7 @@ impl RateLimiter {
             ChatServiceType::Telegram => TELEGRAM_MAX_PER_MINUTE,
             ChatServiceType::Slack => SLACK_MAX_PER_MINUTE,
             ChatServiceType::Discord => DISCORD_MAX_PER_MINUTE,
+            ChatServiceType::Push => PUSH_MAX_PER_MINUTE,
         }
     }
 
@@ -84,6 +86,18 @@ impl RateLimiter {
         &self,
         service_type: &ChatServiceType,
         config_id: &str,
+    ) -> Result<(), NotificationError> {
+        self.acquire_with_limit(service_type, config_id, Self::max_per_minute(service_type))
+            .await
+    }
+
+    /// Same as `acquire`, for callers with their own per-minute budget such as
+    /// per-user limits on bot commands.
+    pub async fn acquire_with_limit(
+        &self,
+        service_type: &ChatServiceType,
+        config_id: &str,
+        max_per_minute: i32,
     ) -> Result<(), NotificationError> {
         let key = Self::key(service_type, config_id);
         let mut guard = self.inner.lock().await;
@@ -93,9 +107,10 @@ impl RateLimiter {
                 RateLimitEntry::new(
                     service_type.clone(),
                     config_id.to_string(),
-                    Self::max_per_minute(service_type),
+                    max_per_minute,
                 )
             });
+        entry.max_per_minute = max_per_minute;
 
         let now = Utc::now();
         entry.reset_if_needed(now);
@@ -0,0 +1,883 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveTime, TimeZone, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Pool, Row, Sqlite, SqlitePool};
+use std::fs;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use uuid::Uuid;
+
+use super::email::{EmailManager, SendEmailRequest};
+use crate::config::localization::{current_localizer, Localizer};
+use crate::data::away_summary::{load_away_summary, AwayCategory, AwayTimelineEntry};
+use crate::market::token_unlocks::{collect_relevance, SharedTokenUnlockManager, UpcomingUnlock};
+use crate::portfolio::exposure::SharedExposureTracker;
+use crate::portfolio::pnl_calendar::load_pnl_calendar;
+use crate::security::keystore::Keystore;
+
+const REPORT_EMAIL_DB_FILE: &str = "report_emails.db";
+const SCHEDULE_CHECK_INTERVAL_SECS: u64 = 300;
+const REPORT_PERIOD_DAYS: i64 = 7;
+const UNLOCK_LOOKAHEAD_DAYS: i64 = 14;
+const MAX_SECTION_ROWS: usize = 10;
+
+/// Sections can be switched off individually, but not all at once.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportSections {
+    pub portfolio_change: bool,
+    pub alerts_fired: bool,
+    pub bot_trades: bool,
+    pub upcoming_unlocks: bool,
+}
+
+impl Default for ReportSections {
+    fn default() -> Self {
+        Self {
+            portfolio_change: true,
+            alerts_fired: true,
+            bot_trades: true,
+            upcoming_unlocks: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportEmailSettings {
+    pub enabled: bool,
+    pub recipients: Vec<String>,
+    pub sections: ReportSections,
+    pub send_day: Weekday,
+    /// Local time, `HH:MM`.
+    pub send_time: String,
+    /// Offset of the user's local time used for the send slot.
+    pub utc_offset_minutes: i32,
+}
+
+impl Default for ReportEmailSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            recipients: Vec::new(),
+            sections: ReportSections::default(),
+            send_day: Weekday::Mon,
+            send_time: "08:00".to_string(),
+            utc_offset_minutes: 0,
+        }
+    }
+}
+
+fn parse_send_time(value: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(value, "%H:%M")
+        .map_err(|_| format!("Invalid send time '{value}', expected HH:MM"))
+}
+
+fn local_offset(minutes: i32) -> Result<FixedOffset, String> {
+    FixedOffset::east_opt(minutes * 60).ok_or_else(|| "Invalid UTC offset".to_string())
+}
+
+impl ReportEmailSettings {
+    fn validate(&self) -> Result<(), String> {
+        parse_send_time(&self.send_time)?;
+        local_offset(self.utc_offset_minutes)?;
+        let sections = &self.sections;
+        if !(sections.portfolio_change
+            || sections.alerts_fired
+            || sections.bot_trades
+            || sections.upcoming_unlocks)
+        {
+            return Err("Enable at least one report section".into());
+        }
+        if self.enabled && self.recipients.is_empty() {
+            return Err("Add at least one recipient".into());
+        }
+        if let Some(bad) = self
+            .recipients
+            .iter()
+            .find(|r| r.parse::<lettre::Address>().is_err())
+        {
+            return Err(format!("Invalid recipient address: {bad}"));
+        }
+        Ok(())
+    }
+
+    /// The most recent send slot at or before `now`.
+    pub fn latest_slot(&self, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+        let offset = local_offset(self.utc_offset_minutes)?;
+        let time = parse_send_time(&self.send_time)?;
+        let local_now = now.with_timezone(&offset);
+        let days_back = (local_now.weekday().num_days_from_monday() + 7
+            - self.send_day.num_days_from_monday())
+            % 7;
+        let date = local_now.date_naive() - Duration::days(days_back as i64);
+        let slot = offset
+            .from_local_datetime(&date.and_time(time))
+            .single()
+            .ok_or_else(|| "Send time does not exist in this offset".to_string())?
+            .with_timezone(&Utc);
+        Ok(if slot > now {
+            slot - Duration::days(7)
+        } else {
+            slot
+        })
+    }
+
+    pub fn next_slot(&self, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+        Ok(self.latest_slot(now)? + Duration::days(7))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioChange {
+    pub start_value_usd: Option<f64>,
+    pub end_value_usd: Option<f64>,
+    pub change_usd: Option<f64>,
+    pub change_percent: Option<f64>,
+    pub realized_pnl_usd: f64,
+    pub closed_trades: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklySummaryReport {
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub portfolio: Option<PortfolioChange>,
+    pub alerts_fired: Option<Vec<AwayTimelineEntry>>,
+    pub bot_trades: Option<Vec<AwayTimelineEntry>>,
+    pub upcoming_unlocks: Option<Vec<UpcomingUnlock>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderedReport {
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportEmailPreview {
+    pub subject: String,
+    pub html: String,
+    pub next_send_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportEmailRecord {
+    pub id: String,
+    /// The scheduled slot, or the request time for manual sends.
+    pub slot: String,
+    pub sent_at: String,
+    pub recipients: String,
+    pub subject: String,
+    /// `sent` or `failed`.
+    pub status: String,
+    pub error: Option<String>,
+    pub manual: bool,
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders the report as HTML and plain text. Sections that were switched off
+/// are `None` and are left out entirely. Dates use the report's own offset;
+/// the localizer only decides how they are written.
+pub fn render_weekly_report(
+    report: &WeeklySummaryReport,
+    offset: FixedOffset,
+    l10n: &Localizer,
+) -> RenderedReport {
+    let local = |time: DateTime<Utc>| time.with_timezone(&offset);
+    let period = format!(
+        "{} – {}",
+        l10n.short_date(&local(report.period_start)),
+        l10n.medium_date(&local(report.period_end))
+    );
+    let subject = l10n.text("report.subject", &[("period", &period)]);
+    let title = l10n.text("report.title", &[]);
+
+    let mut html = format!(
+        "<h2 style=\"margin:0 0 4px\">{}</h2><p style=\"color:#666;margin:0 0 16px\">{}</p>",
+        escape_html(&title),
+        escape_html(&period)
+    );
+    let mut text = format!("{title}\n{period}\n");
+
+    let mut section = |title: &str, rows: Vec<String>, empty: &str| {
+        html.push_str(&format!("<h3>{}</h3>", escape_html(title)));
+        text.push_str(&format!("\n{title}\n"));
+        if rows.is_empty() {
+            html.push_str(&format!(
+                "<p style=\"color:#666\">{}</p>",
+                escape_html(empty)
+            ));
+            text.push_str(&format!("  {empty}\n"));
+            return;
+        }
+        html.push_str("<ul>");
+        for row in rows {
+            html.push_str(&format!("<li>{}</li>", escape_html(&row)));
+            text.push_str(&format!("  - {row}\n"));
+        }
+        html.push_str("</ul>");
+    };
+
+    if let Some(portfolio) = &report.portfolio {
+        let mut rows = Vec::new();
+        if let (Some(end), Some(change), Some(percent)) = (
+            portfolio.end_value_usd,
+            portfolio.change_usd,
+            portfolio.change_percent,
+        ) {
+            rows.push(l10n.text(
+                "report.portfolio.value_change",
+                &[
+                    ("value", &l10n.currency(end, 2)),
+                    ("change", &l10n.signed_currency(change, 2)),
+                    ("percent", &l10n.percent(percent, 2)),
+                ],
+            ));
+        } else if let Some(end) = portfolio.end_value_usd {
+            rows.push(l10n.text(
+                "report.portfolio.value",
+                &[("value", &l10n.currency(end, 2))],
+            ));
+        }
+        rows.push(l10n.plural(
+            "report.portfolio.realized",
+            portfolio.closed_trades as u64,
+            &[("pnl", &l10n.signed_currency(portfolio.realized_pnl_usd, 2))],
+        ));
+        section(&l10n.text("report.portfolio", &[]), rows, "");
+    }
+
+    let timeline_rows = |entries: &[AwayTimelineEntry]| -> Vec<String> {
+        let mut rows: Vec<String> = entries
+            .iter()
+            .rev()
+            .take(MAX_SECTION_ROWS)
+            .map(|entry| {
+                format!(
+                    "{} · {} — {}",
+                    l10n.weekday_time(&local(entry.timestamp)),
+                    entry.title,
+                    entry.detail
+                )
+            })
+            .collect();
+        if entries.len() > MAX_SECTION_ROWS {
+            let more = l10n.number((entries.len() - MAX_SECTION_ROWS) as f64, 0);
+            rows.push(l10n.text("report.more", &[("count", &more)]));
+        }
+        rows
+    };
+
+    if let Some(alerts) = &report.alerts_fired {
+        let count = l10n.number(alerts.len() as f64, 0);
+        section(
+            &l10n.text("report.alerts", &[("count", &count)]),
+            timeline_rows(alerts),
+            &l10n.text("report.alerts.empty", &[]),
+        );
+    }
+
+    if let Some(trades) = &report.bot_trades {
+        let count = l10n.number(trades.len() as f64, 0);
+        section(
+            &l10n.text("report.bot_trades", &[("count", &count)]),
+            timeline_rows(trades),
+            &l10n.text("report.bot_trades.empty", &[]),
+        );
+    }
+
+    if let Some(unlocks) = &report.upcoming_unlocks {
+        let rows = unlocks
+            .iter()
+            .take(MAX_SECTION_ROWS)
+            .map(|unlock| {
+                let mut row = l10n.text(
+                    "report.unlocks.row",
+                    &[
+                        ("date", &l10n.weekday_date(&local(unlock.event.unlock_at))),
+                        ("symbol", &unlock.event.symbol),
+                        ("amount", &l10n.number(unlock.event.amount, 0)),
+                    ],
+                );
+                if let Some(value) = unlock.estimated_usd_value {
+                    row.push_str(&format!(" (~{})", l10n.currency(value, 2)));
+                }
+                if unlock.held {
+                    row.push_str(&format!(" · {}", l10n.text("report.unlocks.held", &[])));
+                }
+                row
+            })
+            .collect();
+        section(
+            &l10n.text("report.unlocks", &[]),
+            rows,
+            &l10n.text("report.unlocks.empty", &[]),
+        );
+    }
+
+    RenderedReport {
+        subject,
+        html,
+        text,
+    }
+}
+
+/// Assembles the report for `[start, end)` from the subsystems that track each
+/// section. A section whose source is unavailable is reported as empty.
+pub async fn build_weekly_report(
+    app: &AppHandle,
+    sections: &ReportSections,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    offset: FixedOffset,
+) -> Result<WeeklySummaryReport, String> {
+    let portfolio = if sections.portfolio_change {
+        Some(portfolio_change(app, start, end, offset).await)
+    } else {
+        None
+    };
+
+    let (alerts_fired, bot_trades) = if sections.alerts_fired || sections.bot_trades {
+        let summary = load_away_summary(app, start, end).await?;
+        let pick = |category: AwayCategory| -> Vec<AwayTimelineEntry> {
+            summary
+                .timeline
+                .iter()
+                .filter(|entry| entry.category == category)
+                .cloned()
+                .collect()
+        };
+        (
+            sections
+                .alerts_fired
+                .then(|| pick(AwayCategory::AlertFired)),
+            sections.bot_trades.then(|| pick(AwayCategory::BotTrade)),
+        )
+    } else {
+        (None, None)
+    };
+
+    let upcoming_unlocks = if sections.upcoming_unlocks {
+        Some(relevant_unlocks(app).await)
+    } else {
+        None
+    };
+
+    Ok(WeeklySummaryReport {
+        period_start: start,
+        period_end: end,
+        portfolio,
+        alerts_fired,
+        bot_trades,
+        upcoming_unlocks,
+    })
+}
+
+async fn portfolio_change(
+    app: &AppHandle,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    offset: FixedOffset,
+) -> PortfolioChange {
+    let mut change = PortfolioChange {
+        start_value_usd: None,
+        end_value_usd: None,
+        change_usd: None,
+        change_percent: None,
+        realized_pnl_usd: 0.0,
+        closed_trades: 0,
+    };
+
+    if let Some(tracker) = app.try_state::<SharedExposureTracker>() {
+        match tracker
+            .read()
+            .await
+            .get_history(REPORT_PERIOD_DAYS + 1)
+            .await
+        {
+            Ok(history) => {
+                let in_period: Vec<_> = history
+                    .iter()
+                    .filter(|snapshot| {
+                        DateTime::parse_from_rfc3339(&snapshot.timestamp)
+                            .map(|ts| ts >= start && ts <= end)
+                            .unwrap_or(false)
+                    })
+                    .collect();
+                change.start_value_usd = in_period.first().map(|s| s.total_value_usd);
+                change.end_value_usd = in_period.last().map(|s| s.total_value_usd);
+                if let (Some(first), Some(last)) = (change.start_value_usd, change.end_value_usd) {
+                    change.change_usd = Some(last - first);
+                    change.change_percent = (first > 0.0).then(|| (last - first) / first * 100.0);
+                }
+            }
+            Err(err) => tracing::warn!(error = %err, "failed to load exposure history for report"),
+        }
+    }
+
+    match load_pnl_calendar(app, start, end, offset).await {
+        Ok(calendar) => {
+            change.realized_pnl_usd = calendar.total_realized_pnl;
+            change.closed_trades = calendar.trades;
+        }
+        Err(err) => tracing::warn!(error = %err, "failed to load realized P&L for report"),
+    }
+    change
+}
+
+async fn relevant_unlocks(app: &AppHandle) -> Vec<UpcomingUnlock> {
+    let Some(manager) = app.try_state::<SharedTokenUnlockManager>() else {
+        return Vec::new();
+    };
+    let relevance = collect_relevance(app).await;
+    match manager
+        .read()
+        .await
+        .upcoming_unlocks(UNLOCK_LOOKAHEAD_DAYS, &relevance)
+        .await
+    {
+        Ok(unlocks) => unlocks
+            .into_iter()
+            .filter(|u| u.held || u.watchlisted)
+            .collect(),
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to load unlocks for report");
+            Vec::new()
+        }
+    }
+}
+
+/// Settings, the last slot handled and the send history.
+pub struct ReportEmailScheduler {
+    pool: Pool<Sqlite>,
+}
+
+pub type SharedReportEmailScheduler = Arc<ReportEmailScheduler>;
+
+impl ReportEmailScheduler {
+    async fn open(url: &str) -> Result<Self, sqlx::Error> {
+        let pool = SqlitePool::connect(url).await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS report_email_settings (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                config_json TEXT NOT NULL,
+                last_slot TEXT
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS report_email_history (
+                id TEXT PRIMARY KEY NOT NULL,
+                slot TEXT NOT NULL,
+                sent_at TEXT NOT NULL,
+                recipients TEXT NOT NULL,
+                subject TEXT NOT NULL,
+                status TEXT NOT NULL,
+                error TEXT,
+                manual INTEGER NOT NULL DEFAULT 0
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_report_email_history_sent ON report_email_history(sent_at DESC)",
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(Self { pool })
+    }
+
+    pub async fn settings(&self) -> Result<ReportEmailSettings, String> {
+        let row = sqlx::query("SELECT config_json FROM report_email_settings WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        match row {
+            Some(row) => {
+                let json: String = row.try_get("config_json").map_err(|e| e.to_string())?;
+                serde_json::from_str(&json).map_err(|e| e.to_string())
+            }
+            None => Ok(ReportEmailSettings::default()),
+        }
+    }
+
+    /// Saving marks the current slot as handled, so changing the schedule
+    /// never sends a report for a slot that has already passed.
+    async fn save_settings(&self, settings: &ReportEmailSettings) -> Result<(), String> {
+        settings.validate()?;
+        let json = serde_json::to_string(settings).map_err(|e| e.to_string())?;
+        let slot = settings.latest_slot(Utc::now())?.to_rfc3339();
+        sqlx::query(
+            r#"
+            INSERT INTO report_email_settings (id, config_json, last_slot) VALUES (1, ?1, ?2)
+            ON CONFLICT(id) DO UPDATE SET
+                config_json = excluded.config_json,
+                last_slot = excluded.last_slot
+            "#,
+        )
+        .bind(json)
+        .bind(slot)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn last_slot(&self) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+        let value: Option<Option<String>> =
+            sqlx::query_scalar("SELECT last_slot FROM report_email_settings WHERE id = 1")
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(value
+            .flatten()
+            .and_then(|raw| DateTime::parse_from_rfc3339(&raw).ok())
+            .map(|time| time.with_timezone(&Utc)))
+    }
+
+    async fn mark_slot(&self, slot: DateTime<Utc>) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE report_email_settings SET last_slot = ?1 WHERE id = 1")
+            .bind(slot.to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn record(&self, record: &ReportEmailRecord) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO report_email_history
+                (id, slot, sent_at, recipients, subject, status, error, manual)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#,
+        )
+        .bind(&record.id)
+        .bind(&record.slot)
+        .bind(&record.sent_at)
+        .bind(&record.recipients)
+        .bind(&record.subject)
+        .bind(&record.status)
+        .bind(&record.error)
+        .bind(record.manual)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn history(&self, limit: i64) -> Result<Vec<ReportEmailRecord>, sqlx::Error> {
+        sqlx::query_as::<_, ReportEmailRecord>(
+            "SELECT * FROM report_email_history ORDER BY sent_at DESC LIMIT ?1",
+        )
+        .bind(limit.clamp(1, 500))
+        .fetch_all(&self.pool)
+        .await
+    }
+}
+
+/// Builds, renders and emails the report for the week ending at `slot`, and
+/// records the outcome in the send history.
+async fn send_report(
+    app: &AppHandle,
+    scheduler: &ReportEmailScheduler,
+    settings: &ReportEmailSettings,
+    slot: DateTime<Utc>,
+    manual: bool,
+) -> Result<ReportEmailRecord, String> {
+    let offset = local_offset(settings.utc_offset_minutes)?;
+    let start = slot - Duration::days(REPORT_PERIOD_DAYS);
+    let report = build_weekly_report(app, &settings.sections, start, slot, offset).await?;
+    let rendered = render_weekly_report(&report, offset, &current_localizer());
+
+    let result = async {
+        let keystore = app
+            .try_state::<Keystore>()
+            .ok_or_else(|| "Keystore unavailable".to_string())?;
+        let manager = EmailManager::new(app).await.map_err(|e| e.to_string())?;
+        let config = manager
+            .get_config(&keystore)
+            .await
+            .map_err(|e| e.to_string())?;
+        let request = SendEmailRequest {
+            to: settings.recipients.clone(),
+            subject: rendered.subject.clone(),
+            html_body: Some(rendered.html.clone()),
+            text_body: Some(rendered.text.clone()),
+            template: None,
+            template_vars: None,
+            attachments: None,
+            include_unsubscribe: false,
+        };
+        manager
+            .send_email(request, &config)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+    .await;
+
+    let record = ReportEmailRecord {
+        id: Uuid::new_v4().to_string(),
+        slot: slot.to_rfc3339(),
+        sent_at: Utc::now().to_rfc3339(),
+        recipients: settings.recipients.join(", "),
+        subject: rendered.subject,
+        status: if result.is_ok() { "sent" } else { "failed" }.to_string(),
+        error: result.err(),
+        manual,
+    };
+    if let Err(err) = scheduler.record(&record).await {
+        tracing::warn!(error = %err, "failed to record report email");
+    }
+    Ok(record)
+}
+
+/// Sends the report once per slot. A slot missed while the app was closed is
+/// sent on the next start, as long as no newer slot has come around.
+async fn run_report_email_tick(app: &AppHandle, scheduler: &ReportEmailScheduler) {
+    let settings = match scheduler.settings().await {
+        Ok(settings) => settings,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to load report email settings");
+            return;
+        }
+    };
+    if !settings.enabled || settings.recipients.is_empty() {
+        return;
+    }
+    let Ok(slot) = settings.latest_slot(Utc::now()) else {
+        return;
+    };
+    match scheduler.last_slot().await {
+        Ok(Some(last)) if last >= slot => return,
+        Ok(_) => {}
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to read last report slot");
+            return;
+        }
+    }
+
+    // Marked first so a failing SMTP server isn't retried every few minutes;
+    // the failure is in the history and the user can resend manually
+    if let Err(err) = scheduler.mark_slot(slot).await {
+        tracing::warn!(error = %err, "failed to mark report slot");
+        return;
+    }
+    match send_report(app, scheduler, &settings, slot, false).await {
+        Ok(record) if record.status == "failed" => tracing::warn!(
+            error = record.error.as_deref().unwrap_or_default(),
+            "weekly report email failed"
+        ),
+        Ok(_) => {}
+        Err(err) => tracing::warn!(error = %err, "failed to build weekly report"),
+    }
+}
+
+pub fn register_report_emails(app: &tauri::App) {
+    let Some(dir) = app.path_resolver().app_data_dir() else {
+        tracing::warn!("app data directory unavailable; report emails disabled");
+        return;
+    };
+    if let Err(err) = fs::create_dir_all(&dir) {
+        tracing::warn!(error = %err, "failed to create app data directory");
+    }
+    let url = format!(
+        "sqlite:{}?mode=rwc",
+        dir.join(REPORT_EMAIL_DB_FILE).display()
+    );
+
+    let scheduler = match tauri::async_runtime::block_on(ReportEmailScheduler::open(&url)) {
+        Ok(scheduler) => Arc::new(scheduler),
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to open report email store");
+            return;
+        }
+    };
+    app.manage::<SharedReportEmailScheduler>(scheduler.clone());
+
+    let handle = app.handle();
+    tauri::async_runtime::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(SCHEDULE_CHECK_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            run_report_email_tick(&handle, &scheduler).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_report_email_settings(
+    scheduler: State<'_, SharedReportEmailScheduler>,
+) -> Result<ReportEmailSettings, String> {
+    scheduler.settings().await
+}
+
+#[tauri::command]
+pub async fn update_report_email_settings(
+    settings: ReportEmailSettings,
+    scheduler: State<'_, SharedReportEmailScheduler>,
+) -> Result<ReportEmailSettings, String> {
+    scheduler.save_settings(&settings).await?;
+    Ok(settings)
+}
+
+/// Renders the report for the past week with the current settings, without
+/// sending it.
+#[tauri::command]
+pub async fn preview_report_email(
+    scheduler: State<'_, SharedReportEmailScheduler>,
+    app: AppHandle,
+) -> Result<ReportEmailPreview, String> {
+    let settings = scheduler.settings().await?;
+    let offset = local_offset(settings.utc_offset_minutes)?;
+    let now = Utc::now();
+    let report = build_weekly_report(
+        &app,
+        &settings.sections,
+        now - Duration::days(REPORT_PERIOD_DAYS),
+        now,
+        offset,
+    )
+    .await?;
+    let rendered = render_weekly_report(&report, offset, &current_localizer());
+    Ok(ReportEmailPreview {
+        subject: rendered.subject,
+        html: rendered.html,
+        next_send_at: settings
+            .enabled
+            .then(|| settings.next_slot(now).ok())
+            .flatten(),
+    })
+}
+
+#[tauri::command]
+pub async fn send_report_email_now(
+    scheduler: State<'_, SharedReportEmailScheduler>,
+    app: AppHandle,
+) -> Result<ReportEmailRecord, String> {
+    let settings = scheduler.settings().await?;
+    if settings.recipients.is_empty() {
+        return Err("Add at least one recipient".into());
+    }
+    send_report(&app, &scheduler, &settings, Utc::now(), true).await
+}
+
+#[tauri::command]
+pub async fn get_report_email_history(
+    limit: Option<i64>,
+    scheduler: State<'_, SharedReportEmailScheduler>,
+) -> Result<Vec<ReportEmailRecord>, String> {
+    scheduler
+        .history(limit.unwrap_or(20))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(day: Weekday, time: &str, offset_minutes: i32) -> ReportEmailSettings {
+        ReportEmailSettings {
+            enabled: true,
+            recipients: vec!["me@example.com".to_string()],
+            send_day: day,
+            send_time: time.to_string(),
+            utc_offset_minutes: offset_minutes,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn slots_follow_the_local_send_time() {
+        // Wednesday 2024-05-15 12:00 UTC
+        let now = Utc.with_ymd_and_hms(2024, 5, 15, 12, 0, 0).unwrap();
+
+        let monday = settings(Weekday::Mon, "08:00", 0);
+        assert_eq!(
+            monday.latest_slot(now).unwrap(),
+            Utc.with_ymd_and_hms(2024, 5, 13, 8, 0, 0).unwrap()
+        );
+        assert_eq!(
+            monday.next_slot(now).unwrap(),
+            Utc.with_ymd_and_hms(2024, 5, 20, 8, 0, 0).unwrap()
+        );
+
+        // 18:00 on Wednesday at UTC-5 is 23:00 UTC, later today
+        let evening = settings(Weekday::Wed, "18:00", -300);
+        assert_eq!(
+            evening.latest_slot(now).unwrap(),
+            Utc.with_ymd_and_hms(2024, 5, 8, 23, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(settings(Weekday::Mon, "25:00", 0).validate().is_err());
+        assert!(settings(Weekday::Mon, "08:00", 20 * 60 * 60)
+            .validate()
+            .is_err());
+        let mut no_recipients = settings(Weekday::Mon, "08:00", 0);
+        no_recipients.recipients.clear();
+        assert!(no_recipients.validate().is_err());
+        no_recipients.recipients.push("not an address".to_string());
+        assert!(no_recipients.validate().is_err());
+        let mut no_sections = settings(Weekday::Mon, "08:00", 0);
+        no_sections.sections = ReportSections {
+            portfolio_change: false,
+            alerts_fired: false,
+            bot_trades: false,
+            upcoming_unlocks: false,
+        };
+        assert!(no_sections.validate().is_err());
+        assert!(settings(Weekday::Fri, "17:30", 60).validate().is_ok());
+    }
+
+    #[test]
+    fn render_leaves_out_disabled_sections_and_escapes() {
+        let start = Utc.with_ymd_and_hms(2024, 5, 6, 8, 0, 0).unwrap();
+        let report = WeeklySummaryReport {
+            period_start: start,
+            period_end: start + Duration::days(7),
+            portfolio: None,
+            alerts_fired: Some(vec![AwayTimelineEntry {
+                timestamp: start + Duration::days(1),
+                category: AwayCategory::AlertFired,
+                title: "Alert '<SOL>' fired".to_string(),
+                detail: "SOL at 150".to_string(),
+                reference: "a1".to_string(),
+            }]),
+            bot_trades: Some(Vec::new()),
+            upcoming_unlocks: None,
+        };
+
+        let rendered =
+            render_weekly_report(&report, local_offset(0).unwrap(), &Localizer::default());
+        assert!(rendered.html.contains("Alerts fired (1)"));
+        assert!(rendered.html.contains("&lt;SOL&gt;"));
+        assert!(rendered.text.contains("No bot trades this week."));
+        assert!(!rendered.html.contains("Portfolio"));
+        assert!(!rendered.text.contains("Upcoming unlocks"));
+        assert_eq!(rendered.subject, "Weekly summary: May 6 – May 13, 2024");
+    }
+}
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use sqlx::{Pool, Row, Sqlite, SqlitePool};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
@@ -6,25 +8,52 @@ use uuid::Uuid;
 
 use super::delivery_log::DeliveryLogger;
 use super::discord::DiscordClient;
+use super::push_bridge::{PushBridge, PushSubscription};
 use super::rate_limiter::RateLimiter;
-use super::slack::SlackClient;
+use super::slack::{format_slack_alert, SlackClient};
 use super::telegram::{TelegramClient, format_alert_message};
+use crate::alerts::price_alerts::AlertTriggerEvent;
 use super::types::{
     notifications_db_path, ChatIntegrationSettings, ChatServiceType, DeliveryStatus,
     DiscordConfig, NotificationError, SlackConfig, TelegramConfig, TestMessageResult,
 };
 
+/// Title and body of the push sent for a triggered price alert.
+pub fn alert_push_content(
+    alert_name: &str,
+    symbol: &str,
+    current_price: f64,
+    condition: &str,
+) -> (String, String) {
+    (
+        format!("{alert_name} ({symbol})"),
+        format!("{symbol} at ${current_price:.4}: {condition}"),
+    )
+}
+
 pub struct NotificationRouter {
     pool: Pool<Sqlite>,
     telegram_client: TelegramClient,
     slack_client: SlackClient,
     discord_client: DiscordClient,
+    push_bridge: PushBridge,
     rate_limiter: Arc<RwLock<RateLimiter>>,
     delivery_logger: DeliveryLogger,
 }
 
 pub type SharedNotificationRouter = Arc<RwLock<NotificationRouter>>;
 
+/// An alert notification as it would be posted to one configured channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderedNotification {
+    pub target_id: String,
+    pub target_name: String,
+    pub enabled: bool,
+    pub sandbox: bool,
+    pub payload: Value,
+}
+
 impl NotificationRouter {
     pub async fn new(app: &AppHandle) -> Result<Self, NotificationError> {
         let db_path = notifications_db_path(app)?;
@@ -34,11 +63,15 @@ impl NotificationRouter {
         let delivery_logger = DeliveryLogger::new(pool.clone());
         delivery_logger.initialize().await?;
 
+        let push_bridge = PushBridge::new(pool.clone(), app.clone());
+        push_bridge.initialize().await?;
+
         let router = Self {
             pool,
             telegram_client: TelegramClient::new(),
             slack_client: SlackClient::new(),
             discord_client: DiscordClient::new(),
+            push_bridge,
             rate_limiter: Arc::new(RwLock::new(RateLimiter::new())),
             delivery_logger,
         };
@@ -448,7 +481,172 @@ impl NotificationRouter {
         }
     }
 
-    async fn get_telegram_config(&self, id: &str) -> Result<TelegramConfig, NotificationError> {
+    pub async fn test_push(
+        &self,
+        id: &str,
+        message: &str,
+    ) -> Result<TestMessageResult, NotificationError> {
+        let subscription = self.push_bridge.get_subscription(id).await?;
+        let start = std::time::Instant::now();
+
+        match self
+            .push_bridge
+            .send(&subscription, "test", "Test notification", message)
+            .await
+        {
+            Ok(_) => {
+                let duration = start.elapsed().as_millis() as u64;
+                Ok(TestMessageResult {
+                    success: true,
+                    message: "Test push sent successfully".to_string(),
+                    delivery_time: Some(duration),
+                    error: None,
+                })
+            }
+            Err(e) => Ok(TestMessageResult {
+                success: false,
+                message: "Failed to send test push".to_string(),
+                delivery_time: None,
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+
+    /// Renders the alert payload for every configured channel of a service, using
+    /// the same builders the live send path uses.
+    pub async fn render_alert(
+        &self,
+        service_type: &ChatServiceType,
+        event: &AlertTriggerEvent,
+    ) -> Result<Vec<RenderedNotification>, NotificationError> {
+        let settings = self.get_settings().await?;
+        let (name, symbol, price, condition) = (
+            event.alert_name.as_str(),
+            event.symbol.as_str(),
+            event.current_price,
+            event.conditions_met.as_str(),
+        );
+
+        let rendered = match service_type {
+            ChatServiceType::Telegram => settings
+                .telegram
+                .iter()
+                .map(|config| {
+                    let message = format_alert_message(name, symbol, price, condition, true);
+                    let payload = TelegramClient::message_payload(config, &message, true);
+                    Ok(RenderedNotification {
+                        target_id: config.id.clone(),
+                        target_name: config.name.clone(),
+                        enabled: config.enabled,
+                        sandbox: config.sandbox,
+                        payload: serde_json::to_value(payload)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, NotificationError>>()?,
+            ChatServiceType::Slack => settings
+                .slack
+                .iter()
+                .map(|config| {
+                    let message = format_slack_alert(name, symbol, price, condition);
+                    let payload = SlackClient::message_payload(config, &message);
+                    Ok(RenderedNotification {
+                        target_id: config.id.clone(),
+                        target_name: config.name.clone(),
+                        enabled: config.enabled,
+                        sandbox: config.sandbox,
+                        payload: serde_json::to_value(payload)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, NotificationError>>()?,
+            ChatServiceType::Discord => settings
+                .discord
+                .iter()
+                .map(|config| {
+                    let payload =
+                        DiscordClient::alert_payload(config, name, symbol, price, condition);
+                    Ok(RenderedNotification {
+                        target_id: config.id.clone(),
+                        target_name: config.name.clone(),
+                        enabled: config.enabled,
+                        sandbox: config.sandbox,
+                        payload: serde_json::to_value(payload)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, NotificationError>>()?,
+            ChatServiceType::Push => {
+                return Err(NotificationError::Internal(
+                    "Push notifications are end-to-end encrypted and cannot be previewed".into(),
+                ))
+            }
+        };
+
+        Ok(rendered)
+    }
+
+    /// Delivers an alert through the live send path, but only to a channel marked
+    /// as a sandbox so previews can never reach a real audience.
+    pub async fn send_alert_to_sandbox(
+        &self,
+        service_type: &ChatServiceType,
+        config_id: &str,
+        event: &AlertTriggerEvent,
+    ) -> Result<TestMessageResult, NotificationError> {
+        let not_sandbox = || {
+            NotificationError::Internal(format!("{} is not a sandbox channel", config_id))
+        };
+        let (name, symbol, price, condition) = (
+            event.alert_name.as_str(),
+            event.symbol.as_str(),
+            event.current_price,
+            event.conditions_met.as_str(),
+        );
+        let start = std::time::Instant::now();
+
+        let result = match service_type {
+            ChatServiceType::Telegram => {
+                let config = self.get_telegram_config(config_id).await?;
+                if !config.sandbox {
+                    return Err(not_sandbox());
+                }
+                self.send_telegram_alert(&config, &event.alert_id, name, symbol, price, condition)
+                    .await
+            }
+            ChatServiceType::Slack => {
+                let config = self.get_slack_config(config_id).await?;
+                if !config.sandbox {
+                    return Err(not_sandbox());
+                }
+                self.send_slack_alert(&config, &event.alert_id, name, symbol, price, condition)
+                    .await
+            }
+            ChatServiceType::Discord => {
+                let config = self.get_discord_config(config_id).await?;
+                if !config.sandbox {
+                    return Err(not_sandbox());
+                }
+                self.send_discord_alert(&config, &event.alert_id, name, symbol, price, condition)
+                    .await
+            }
+            ChatServiceType::Push => return Err(not_sandbox()),
+        };
+
+        Ok(match result {
+            Ok(_) => TestMessageResult {
+                success: true,
+                message: "Preview delivered to sandbox channel".to_string(),
+                delivery_time: Some(start.elapsed().as_millis() as u64),
+                error: None,
+            },
+            Err(e) => TestMessageResult {
+                success: false,
+                message: "Failed to deliver preview".to_string(),
+                delivery_time: None,
+                error: Some(e.to_string()),
+            },
+        })
+    }
+
+    pub async fn get_telegram_config(&self, id: &str) -> Result<TelegramConfig, NotificationError> {
         let row = sqlx::query(
             r#"
             SELECT config_data
@@ -515,7 +713,7 @@ impl NotificationRouter {
     ) -> Result<(), NotificationError> {
         let settings = self.get_settings().await?;
 
-        for config in settings.telegram.iter().filter(|c| c.enabled) {
+        for config in settings.telegram.iter().filter(|c| c.enabled && !c.sandbox) {
             let result = self
                 .send_telegram_alert(config, alert_id, alert_name, symbol, current_price, condition)
                 .await;
@@ -532,7 +730,7 @@ impl NotificationRouter {
             .await;
         }
 
-        for config in settings.slack.iter().filter(|c| c.enabled) {
+        for config in settings.slack.iter().filter(|c| c.enabled && !c.sandbox) {
             let result = self
                 .send_slack_alert(config, alert_id, alert_name, symbol, current_price, condition)
                 .await;
@@ -549,7 +747,7 @@ impl NotificationRouter {
             .await;
         }
 
-        for config in settings.discord.iter().filter(|c| c.enabled) {
+        for config in settings.discord.iter().filter(|c| c.enabled && !c.sandbox) {
             let result = self
                 .send_discord_alert(config, alert_id, alert_name, symbol, current_price, condition)
                 .await;
@@ -569,6 +767,179 @@ impl NotificationRouter {
         Ok(())
     }
 
+    /// Sends a free-form message to every enabled channel, e.g. for wallet or multisig events.
+    pub async fn send_broadcast_notification(
+        &self,
+        reference_id: &str,
+        title: &str,
+        message: &str,
+    ) -> Result<(), NotificationError> {
+        let settings = self.get_settings().await?;
+        let text = format!("{}\n\n{}", title, message);
+
+        for config in settings.telegram.iter().filter(|c| c.enabled && !c.sandbox) {
+            let result = self
+                .deliver_rate_limited(
+                    ChatServiceType::Telegram,
+                    &config.id,
+                    self.telegram_client.send_message(config, &text, false),
+                )
+                .await;
+
+            self.log_delivery(
+                ChatServiceType::Telegram,
+                &config.id,
+                &config.name,
+                Some(reference_id),
+                Some(title),
+                message,
+                &result,
+            )
+            .await;
+        }
+
+        for config in settings.slack.iter().filter(|c| c.enabled && !c.sandbox) {
+            let slack_text = format!("*{}*\n\n{}", title, message);
+            let result = self
+                .deliver_rate_limited(
+                    ChatServiceType::Slack,
+                    &config.id,
+                    self.slack_client.send_message(config, &slack_text),
+                )
+                .await;
+
+            self.log_delivery(
+                ChatServiceType::Slack,
+                &config.id,
+                &config.name,
+                Some(reference_id),
+                Some(title),
+                message,
+                &result,
+            )
+            .await;
+        }
+
+        for config in settings.discord.iter().filter(|c| c.enabled && !c.sandbox) {
+            let result = self
+                .deliver_rate_limited(
+                    ChatServiceType::Discord,
+                    &config.id,
+                    self.discord_client.send_message(config, &text, false),
+                )
+                .await;
+
+            self.log_delivery(
+                ChatServiceType::Discord,
+                &config.id,
+                &config.name,
+                Some(reference_id),
+                Some(title),
+                message,
+                &result,
+            )
+            .await;
+        }
+
+        Ok(())
+    }
+
+    /// Broadcasts to every chat channel and additionally pushes to registered
+    /// phones. Reserved for alerts that need attention away from the desktop.
+    pub async fn send_critical_notification(
+        &self,
+        reference_id: &str,
+        title: &str,
+        message: &str,
+    ) -> Result<(), NotificationError> {
+        self.send_broadcast_notification(reference_id, title, message)
+            .await?;
+        self.send_push_notification(reference_id, title, message)
+            .await
+    }
+
+    /// Pushes to every enabled phone. Failures per phone are logged, not returned.
+    pub async fn send_push_notification(
+        &self,
+        reference_id: &str,
+        title: &str,
+        message: &str,
+    ) -> Result<(), NotificationError> {
+        let subscriptions = self.push_bridge.list_subscriptions().await?;
+        for subscription in subscriptions.iter().filter(|s| s.enabled) {
+            self.push_to(subscription, reference_id, title, message)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    /// Push delivery for price alerts that have the push channel selected.
+    pub async fn send_alert_push(
+        &self,
+        alert_id: &str,
+        alert_name: &str,
+        symbol: &str,
+        current_price: f64,
+        condition: &str,
+    ) -> Result<(), NotificationError> {
+        let (title, message) = alert_push_content(alert_name, symbol, current_price, condition);
+        self.send_push_notification(alert_id, &title, &message)
+            .await
+    }
+
+    async fn push_to(
+        &self,
+        subscription: &PushSubscription,
+        reference_id: &str,
+        title: &str,
+        message: &str,
+    ) {
+        let result = self
+            .deliver_rate_limited(
+                ChatServiceType::Push,
+                &subscription.id,
+                self.push_bridge
+                    .send(subscription, reference_id, title, message),
+            )
+            .await;
+
+        // Only the title is logged; the body stays end-to-end encrypted.
+        self.log_delivery(
+            ChatServiceType::Push,
+            &subscription.id,
+            &subscription.name,
+            Some(reference_id),
+            Some(title),
+            "Encrypted push notification",
+            &result,
+        )
+        .await;
+    }
+
+    async fn deliver_rate_limited<F>(
+        &self,
+        service_type: ChatServiceType,
+        config_id: &str,
+        send: F,
+    ) -> Result<(), NotificationError>
+    where
+        F: std::future::Future<Output = Result<(), NotificationError>>,
+    {
+        let rate_limiter = self.rate_limiter.read().await;
+        rate_limiter.acquire(&service_type, config_id).await?;
+        drop(rate_limiter);
+
+        match send.await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                let rate_limiter = self.rate_limiter.read().await;
+                rate_limiter.register_failure(&service_type, config_id).await;
+                Err(e)
+            }
+        }
+    }
+
     async fn send_telegram_alert(
         &self,
         config: &TelegramConfig,
@@ -613,19 +984,7 @@ impl NotificationRouter {
             .await?;
         drop(rate_limiter);
 
-        let message = format!(
-            "*🚨 Price Alert Triggered*\n\n\
-            *Alert:* {}\n\
-            *Symbol:* {}\n\
-            *Price:* ${:.4}\n\
-            *Condition:* {}\n\n\
-            _Triggered at: {}_",
-            alert_name,
-            symbol,
-            current_price,
-            condition,
-            chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
-        );
+        let message = format_slack_alert(alert_name, symbol, current_price, condition);
 
         match self.slack_client.send_message(config, &message).await {
             Ok(_) => Ok(()),
@@ -709,6 +1068,10 @@ impl NotificationRouter {
         &self.delivery_logger
     }
 
+    pub fn push_bridge(&self) -> &PushBridge {
+        &self.push_bridge
+    }
+
     pub fn get_rate_limiter(&self) -> Arc<RwLock<RateLimiter>> {
         Arc::clone(&self.rate_limiter)
     }
@@ -3,11 +3,12 @@ use serde::Serialize;
 use std::time::Duration;
 
 use super::types::{NotificationError, SlackConfig};
+use crate::config::localization::current_localizer;
 
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Serialize)]
-struct SlackMessage<'a> {
+pub struct SlackMessage<'a> {
     text: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
     channel: Option<&'a str>,
@@ -34,11 +35,7 @@ impl SlackClient {
         config: &SlackConfig,
         message: &str,
     ) -> Result<(), NotificationError> {
-        let payload = SlackMessage {
-            text: message,
-            channel: config.channel.as_deref(),
-            markdown: true,
-        };
+        let payload = Self::message_payload(config, message);
 
         let response = self
             .client
@@ -59,6 +56,40 @@ impl SlackClient {
         Ok(())
     }
 
+    /// The webhook body `send_message` posts, exposed for previews.
+    pub fn message_payload<'a>(config: &'a SlackConfig, message: &'a str) -> SlackMessage<'a> {
+        SlackMessage {
+            text: message,
+            channel: config.channel.as_deref(),
+            markdown: true,
+        }
+    }
+
+    /// Verifies the webhook without posting anything: Slack answers an empty
+    /// payload with `400 no_text` only when the webhook itself is still valid.
+    pub async fn check_webhook(&self, config: &SlackConfig) -> Result<(), NotificationError> {
+        let response = self
+            .client
+            .post(&config.webhook_url)
+            .json(&serde_json::json!({}))
+            .send()
+            .await?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        let valid = status.is_success()
+            || (status == reqwest::StatusCode::BAD_REQUEST
+                && matches!(body.as_str(), "no_text" | "invalid_payload"));
+        if !valid {
+            return Err(NotificationError::Internal(format!(
+                "Slack webhook unreachable: {} {}",
+                status, body
+            )));
+        }
+
+        Ok(())
+    }
+
     pub async fn test_connection(&self, config: &SlackConfig) -> Result<(), NotificationError> {
         self.send_message(
             config,
@@ -68,6 +99,34 @@ impl SlackClient {
     }
 }
 
+pub fn format_slack_alert(
+    alert_name: &str,
+    symbol: &str,
+    current_price: f64,
+    condition: &str,
+) -> String {
+    let l10n = current_localizer();
+    format!(
+        "*🚨 {}*\n\n\
+        *{}:* {}\n\
+        *{}:* {}\n\
+        *{}:* {}\n\
+        *{}:* {}\n\n\
+        _{}: {}_",
+        l10n.text("alert.title", &[]),
+        l10n.text("alert.field.alert", &[]),
+        alert_name,
+        l10n.text("alert.field.symbol", &[]),
+        symbol,
+        l10n.text("alert.field.price", &[]),
+        l10n.currency(current_price, 4),
+        l10n.text("alert.field.condition", &[]),
+        condition,
+        l10n.text("alert.field.triggered_at", &[]),
+        l10n.date_time(chrono::Utc::now())
+    )
+}
+
 impl Default for SlackClient {
     fn default() -> Self {
         Self::new()
@@ -3,12 +3,13 @@ use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 use super::types::{NotificationError, TelegramConfig};
+use crate::config::localization::current_localizer;
 
 const TELEGRAM_API_URL: &str = "https://api.telegram.org";
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Serialize)]
-struct TelegramMessage {
+pub struct TelegramMessage {
     chat_id: String,
     text: String,
     parse_mode: Option<String>,
@@ -21,6 +22,40 @@ struct TelegramResponse {
     description: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct TelegramUpdatesResponse {
+    ok: bool,
+    description: Option<String>,
+    #[serde(default)]
+    result: Vec<TelegramUpdate>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TelegramUpdate {
+    pub update_id: i64,
+    pub message: Option<TelegramIncomingMessage>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TelegramIncomingMessage {
+    pub chat: TelegramChat,
+    pub from: Option<TelegramUser>,
+    pub text: Option<String>,
+    /// Unix timestamp of when the message was sent.
+    pub date: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TelegramChat {
+    pub id: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TelegramUser {
+    pub id: i64,
+    pub username: Option<String>,
+}
+
 pub struct TelegramClient {
     client: Client,
 }
@@ -41,18 +76,23 @@ impl TelegramClient {
         message: &str,
         use_markdown: bool,
     ) -> Result<(), NotificationError> {
-        let url = format!(
-            "{}/bot{}/sendMessage",
-            TELEGRAM_API_URL, config.bot_token
-        );
+        let payload = Self::message_payload(config, message, use_markdown);
+        self.post_message(&config.bot_token, &payload).await
+    }
 
+    /// The sendMessage body `send_message` posts, exposed for previews.
+    pub fn message_payload(
+        config: &TelegramConfig,
+        message: &str,
+        use_markdown: bool,
+    ) -> TelegramMessage {
         let formatted_message = if use_markdown {
             Self::format_markdown(message)
         } else {
             message.to_string()
         };
 
-        let payload = TelegramMessage {
+        TelegramMessage {
             chat_id: config.chat_id.clone(),
             text: formatted_message,
             parse_mode: if use_markdown {
@@ -61,12 +101,71 @@ impl TelegramClient {
                 None
             },
             disable_web_page_preview: Some(true),
+        }
+    }
+
+    /// Plain-text reply to a chat the bot received a message from.
+    pub async fn send_to_chat(
+        &self,
+        bot_token: &str,
+        chat_id: &str,
+        text: &str,
+    ) -> Result<(), NotificationError> {
+        let payload = TelegramMessage {
+            chat_id: chat_id.to_string(),
+            text: text.to_string(),
+            parse_mode: None,
+            disable_web_page_preview: Some(true),
         };
 
+        self.post_message(bot_token, &payload).await
+    }
+
+    /// Long-polls for inbound messages. `offset` acknowledges every update before it.
+    pub async fn get_updates(
+        &self,
+        bot_token: &str,
+        offset: i64,
+        timeout_secs: u64,
+    ) -> Result<Vec<TelegramUpdate>, NotificationError> {
+        let url = format!("{}/bot{}/getUpdates", TELEGRAM_API_URL, bot_token);
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&[
+                ("offset", offset.to_string()),
+                ("timeout", timeout_secs.to_string()),
+                ("allowed_updates", "[\"message\"]".to_string()),
+            ])
+            .timeout(REQUEST_TIMEOUT + Duration::from_secs(timeout_secs))
+            .send()
+            .await?;
+
+        let status = response.status();
+        let body: TelegramUpdatesResponse = response.json().await?;
+
+        if !body.ok || !status.is_success() {
+            return Err(NotificationError::Internal(
+                body.description
+                    .unwrap_or_else(|| format!("Telegram API error: {}", status)),
+            ));
+        }
+
+        Ok(body.result)
+    }
+
+    async fn post_message(
+        &self,
+        bot_token: &str,
+        payload: &TelegramMessage,
+    ) -> Result<(), NotificationError> {
+        let url = format!("{}/bot{}/sendMessage", TELEGRAM_API_URL, bot_token);
+
         let response = self
             .client
             .post(&url)
-            .json(&payload)
+            .json(payload)
             .send()
             .await?;
 
@@ -137,33 +236,38 @@ pub fn format_alert_message(
     condition: &str,
     use_markdown: bool,
 ) -> String {
+    let l10n = current_localizer();
+    let title = l10n.text("alert.title", &[]);
+    let lines = [
+        (l10n.text("alert.field.alert", &[]), alert_name.to_string()),
+        (l10n.text("alert.field.symbol", &[]), symbol.to_string()),
+        (l10n.text("alert.field.price", &[]), l10n.currency(current_price, 4)),
+        (l10n.text("alert.field.condition", &[]), condition.to_string()),
+    ];
+    let triggered_label = l10n.text("alert.field.triggered_at", &[]);
+    let triggered_at = l10n.date_time(chrono::Utc::now());
+
     if use_markdown {
+        let escape = TelegramClient::escape_markdown;
+        let fields: Vec<String> = lines
+            .iter()
+            .map(|(label, value)| format!("*{}:* {}", escape(label), escape(value)))
+            .collect();
         format!(
-            "*🚨 Price Alert Triggered*\n\n\
-            *Alert:* {}\n\
-            *Symbol:* {}\n\
-            *Price:* ${:.4}\n\
-            *Condition:* {}\n\n\
-            _Triggered at: {}_",
-            TelegramClient::escape_markdown(alert_name),
-            TelegramClient::escape_markdown(symbol),
-            current_price,
-            TelegramClient::escape_markdown(condition),
-            TelegramClient::escape_markdown(&chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            "*🚨 {}*\n\n{}\n\n_{}: {}_",
+            escape(&title),
+            fields.join("\n"),
+            escape(&triggered_label),
+            escape(&triggered_at)
         )
     } else {
+        let fields: Vec<String> = lines
+            .iter()
+            .map(|(label, value)| format!("{label}: {value}"))
+            .collect();
         format!(
-            "🚨 Price Alert Triggered\n\n\
-            Alert: {}\n\
-            Symbol: {}\n\
-            Price: ${:.4}\n\
-            Condition: {}\n\n\
-            Triggered at: {}",
-            alert_name,
-            symbol,
-            current_price,
-            condition,
-            chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+            "🚨 {title}\n\n{}\n\n{triggered_label}: {triggered_at}",
+            fields.join("\n")
         )
     }
 }
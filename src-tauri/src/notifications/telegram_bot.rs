@@ -0,0 +1,846 @@
+use super::router::SharedNotificationRouter;
+use super::telegram::TelegramClient;
+use crate::alerts::{AlertState, SharedAlertManager};
+use crate::auth::two_factor::TwoFactorManager;
+use crate::portfolio::SharedPortfolioData;
+use crate::security::activity_log::{ActivityAction, ActivityLogger};
+use crate::security::keystore::Keystore;
+use crate::trading::{ExecutionStatus, SharedAutoTradingEngine};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+const TELEGRAM_BOT_FILE: &str = "telegram_bot.json";
+const CONFIG_AUDIT_SUBJECT: &str = "telegram_bot";
+/// How long a trading command waits for its /confirm, and the oldest message
+/// still acted on after the app comes back online.
+const CHALLENGE_TTL_SECONDS: i64 = 120;
+/// Wrong 2FA codes a chat may send before trading commands are locked.
+const MAX_FAILED_CONFIRMATIONS: u32 = 3;
+/// The first lockout; each further one doubles, up to a day.
+const CONFIRMATION_LOCKOUT_MINUTES: i64 = 15;
+const MAX_CONFIRMATION_LOCKOUT_MINUTES: i64 = 24 * 60;
+const IDLE_POLL_DELAY: Duration = Duration::from_secs(10);
+const ERROR_BACKOFF: Duration = Duration::from_secs(15);
+const MAX_LISTED_ITEMS: usize = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BotPermission {
+    /// Read-only commands: /status, /portfolio and /alerts.
+    View,
+    /// Commands that change trading state. These also need a 2FA code.
+    Trade,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotChat {
+    pub chat_id: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub permissions: Vec<BotPermission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TelegramBotConfig {
+    pub enabled: bool,
+    /// Telegram chat integration whose bot token receives the commands.
+    pub telegram_config_id: Option<String>,
+    /// Messages from any other chat are ignored.
+    pub allowed_chats: Vec<BotChat>,
+    pub poll_timeout_secs: u64,
+}
+
+impl Default for TelegramBotConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            telegram_config_id: None,
+            allowed_chats: Vec::new(),
+            poll_timeout_secs: 25,
+        }
+    }
+}
+
+impl TelegramBotConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.enabled
+            && self
+                .telegram_config_id
+                .as_deref()
+                .map_or(true, |id| id.trim().is_empty())
+        {
+            return Err("Select the Telegram integration the bot should use".into());
+        }
+        if !(1..=50).contains(&self.poll_timeout_secs) {
+            return Err("Poll timeout must be between 1 and 50 seconds".into());
+        }
+        for chat in &self.allowed_chats {
+            if chat.chat_id.trim().parse::<i64>().is_err() {
+                return Err(format!("Invalid Telegram chat ID: {}", chat.chat_id));
+            }
+        }
+        Ok(())
+    }
+
+    fn chat(&self, chat_id: &str) -> Option<&BotChat> {
+        self.allowed_chats
+            .iter()
+            .find(|chat| chat.chat_id.trim() == chat_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BotCommand {
+    Help,
+    Status,
+    Portfolio,
+    Alerts,
+    PauseBots,
+    Confirm(String),
+    Cancel,
+    Unknown(String),
+}
+
+impl BotCommand {
+    /// Parses `/command [args]`, including the `/command@bot_name` form used in
+    /// group chats. Plain messages are not commands.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.split_whitespace();
+        let head = parts.next()?.strip_prefix('/')?;
+        let name = head.split('@').next().unwrap_or_default().to_lowercase();
+
+        Some(match name.as_str() {
+            "start" | "help" => BotCommand::Help,
+            "status" => BotCommand::Status,
+            "portfolio" => BotCommand::Portfolio,
+            "alerts" => BotCommand::Alerts,
+            "pause_bots" => BotCommand::PauseBots,
+            "confirm" => BotCommand::Confirm(parts.next().unwrap_or_default().to_string()),
+            "cancel" => BotCommand::Cancel,
+            _ => BotCommand::Unknown(name),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            BotCommand::Help => "help",
+            BotCommand::Status => "status",
+            BotCommand::Portfolio => "portfolio",
+            BotCommand::Alerts => "alerts",
+            BotCommand::PauseBots => "pause_bots",
+            BotCommand::Confirm(_) => "confirm",
+            BotCommand::Cancel => "cancel",
+            BotCommand::Unknown(name) => name,
+        }
+    }
+
+    pub fn required_permission(&self) -> Option<BotPermission> {
+        match self {
+            BotCommand::Status | BotCommand::Portfolio | BotCommand::Alerts => {
+                Some(BotPermission::View)
+            }
+            BotCommand::PauseBots | BotCommand::Confirm(_) => Some(BotPermission::Trade),
+            BotCommand::Help | BotCommand::Cancel | BotCommand::Unknown(_) => None,
+        }
+    }
+
+    fn requires_two_factor(&self) -> bool {
+        matches!(self, BotCommand::PauseBots)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BotDenial {
+    UnknownChat,
+    MissingPermission(BotPermission),
+}
+
+pub fn authorize(
+    config: &TelegramBotConfig,
+    chat_id: &str,
+    command: &BotCommand,
+) -> Result<(), BotDenial> {
+    let chat = config.chat(chat_id).ok_or(BotDenial::UnknownChat)?;
+    match command.required_permission() {
+        Some(permission) if !chat.permissions.contains(&permission) => {
+            Err(BotDenial::MissingPermission(permission))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PendingChallenge {
+    command: BotCommand,
+    expires_at: DateTime<Utc>,
+}
+
+/// Wrong 2FA codes from one chat. Survives new challenges, so resending the
+/// command doesn't reset the count; only a correct code does.
+#[derive(Debug, Clone, Default)]
+struct FailedConfirmations {
+    count: u32,
+    lockouts: u32,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+pub struct TelegramBotState {
+    config: TelegramBotConfig,
+    config_path: Option<PathBuf>,
+    challenges: HashMap<String, PendingChallenge>,
+    failed_confirmations: HashMap<String, FailedConfirmations>,
+}
+
+impl TelegramBotState {
+    fn load(config_path: Option<PathBuf>) -> Self {
+        let config = config_path
+            .as_ref()
+            .filter(|path| path.exists())
+            .and_then(|path| match fs::read_to_string(path) {
+                Ok(data) => serde_json::from_str(&data).ok(),
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to read telegram bot config");
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            config,
+            config_path,
+            challenges: HashMap::new(),
+            failed_confirmations: HashMap::new(),
+        }
+    }
+
+    fn save_config(&self) -> Result<(), String> {
+        let Some(path) = &self.config_path else {
+            return Ok(());
+        };
+        let json = serde_json::to_string_pretty(&self.config).map_err(|e| e.to_string())?;
+        fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    fn issue_challenge(&mut self, chat_id: &str, command: BotCommand, now: DateTime<Utc>) {
+        self.challenges.insert(
+            chat_id.to_string(),
+            PendingChallenge {
+                command,
+                expires_at: now + ChronoDuration::seconds(CHALLENGE_TTL_SECONDS),
+            },
+        );
+    }
+
+    /// Removes the chat's pending command, returning it only if still valid.
+    fn take_challenge(&mut self, chat_id: &str, now: DateTime<Utc>) -> Option<BotCommand> {
+        self.challenges
+            .remove(chat_id)
+            .filter(|challenge| challenge.expires_at > now)
+            .map(|challenge| challenge.command)
+    }
+
+    fn locked_until(&self, chat_id: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.failed_confirmations
+            .get(chat_id)
+            .and_then(|failed| failed.locked_until)
+            .filter(|until| *until > now)
+    }
+
+    /// Counts a wrong code and returns the lockout it triggered, if any.
+    fn record_failed_confirmation(
+        &mut self,
+        chat_id: &str,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let failed = self
+            .failed_confirmations
+            .entry(chat_id.to_string())
+            .or_default();
+        failed.count += 1;
+        if failed.count < MAX_FAILED_CONFIRMATIONS {
+            return None;
+        }
+
+        let minutes = CONFIRMATION_LOCKOUT_MINUTES
+            .saturating_mul(1 << failed.lockouts.min(16))
+            .min(MAX_CONFIRMATION_LOCKOUT_MINUTES);
+        failed.count = 0;
+        failed.lockouts += 1;
+        let until = now + ChronoDuration::minutes(minutes);
+        failed.locked_until = Some(until);
+        Some(until)
+    }
+
+    fn clear_failed_confirmations(&mut self, chat_id: &str) {
+        self.failed_confirmations.remove(chat_id);
+    }
+}
+
+fn lockout_message(until: DateTime<Utc>) -> String {
+    format!(
+        "Too many invalid 2FA codes. Trading commands are locked until {}.",
+        until.to_rfc3339()
+    )
+}
+
+pub type SharedTelegramBot = Arc<Mutex<TelegramBotState>>;
+
+pub fn register_telegram_bot(app: &tauri::App) {
+    let config_path = app.path_resolver().app_data_dir().map(|dir| {
+        if let Err(err) = fs::create_dir_all(&dir) {
+            tracing::warn!(error = %err, "failed to create app data directory");
+        }
+        dir.join(TELEGRAM_BOT_FILE)
+    });
+    let state: SharedTelegramBot = Arc::new(Mutex::new(TelegramBotState::load(config_path)));
+    app.manage(state);
+
+    start_telegram_bot(app.handle());
+}
+
+/// Long-polls the configured bot for commands. Config changes are picked up on
+/// the next poll, so the loop runs for the life of the app.
+fn start_telegram_bot(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let client = TelegramClient::new();
+        let mut offset = 0;
+        let mut polled_token: Option<String> = None;
+
+        loop {
+            let config = app
+                .try_state::<SharedTelegramBot>()
+                .and_then(|bot| bot.lock().ok().map(|state| state.config.clone()))
+                .filter(|config| config.enabled);
+            let Some(config) = config else {
+                tokio::time::sleep(IDLE_POLL_DELAY).await;
+                continue;
+            };
+            let Some(bot_token) = bot_token(&app, &config).await else {
+                tokio::time::sleep(IDLE_POLL_DELAY).await;
+                continue;
+            };
+            if polled_token.as_deref() != Some(bot_token.as_str()) {
+                offset = 0;
+                polled_token = Some(bot_token.clone());
+            }
+
+            let updates = match client
+                .get_updates(&bot_token, offset, config.poll_timeout_secs)
+                .await
+            {
+                Ok(updates) => updates,
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to poll telegram bot updates");
+                    tokio::time::sleep(ERROR_BACKOFF).await;
+                    continue;
+                }
+            };
+
+            let oldest = Utc::now().timestamp() - CHALLENGE_TTL_SECONDS;
+            for update in updates {
+                offset = offset.max(update.update_id + 1);
+                let Some(message) = update.message else {
+                    continue;
+                };
+                // Commands queued while the app was offline are dropped, not replayed.
+                let Some(text) = message.text.filter(|_| message.date >= oldest) else {
+                    continue;
+                };
+
+                let chat_id = message.chat.id.to_string();
+                if let Some(reply) = handle_command(&app, &chat_id, &text).await {
+                    if let Err(err) = client.send_to_chat(&bot_token, &chat_id, &reply).await {
+                        tracing::warn!(error = %err, "failed to reply to telegram command");
+                    }
+                }
+            }
+        }
+    });
+}
+
+async fn bot_token(app: &AppHandle, config: &TelegramBotConfig) -> Option<String> {
+    let config_id = config.telegram_config_id.as_deref()?;
+    let router = app.try_state::<SharedNotificationRouter>()?;
+    let result = router.read().await.get_telegram_config(config_id).await;
+    match result {
+        Ok(telegram) => Some(telegram.bot_token),
+        Err(err) => {
+            tracing::warn!(error = %err, "telegram bot integration is unavailable");
+            None
+        }
+    }
+}
+
+/// Runs one inbound command and returns the reply. Chats outside the whitelist
+/// get no reply at all.
+async fn handle_command(app: &AppHandle, chat_id: &str, text: &str) -> Option<String> {
+    let command = BotCommand::parse(text)?;
+    let bot = app.try_state::<SharedTelegramBot>()?;
+    let authorization = {
+        let state = bot.lock().ok()?;
+        authorize(&state.config, chat_id, &command)
+    };
+
+    let subject = format!("telegram:{}", chat_id);
+    match authorization {
+        Err(BotDenial::UnknownChat) => {
+            audit(
+                app,
+                &subject,
+                json!({ "command": command.name(), "denied": "unknown_chat" }),
+                false,
+            )
+            .await;
+            return None;
+        }
+        Err(BotDenial::MissingPermission(permission)) => {
+            audit(
+                app,
+                &subject,
+                json!({ "command": command.name(), "denied": permission }),
+                false,
+            )
+            .await;
+            return Some(format!(
+                "This chat is not allowed to use /{}.",
+                command.name()
+            ));
+        }
+        Ok(()) => {}
+    }
+
+    let result = match &command {
+        BotCommand::Help => Ok(help_text()),
+        BotCommand::Unknown(name) => Err(format!("Unknown command /{}. Send /help.", name)),
+        BotCommand::Cancel => {
+            let cancelled = bot
+                .lock()
+                .map(|mut state| state.challenges.remove(chat_id).is_some())
+                .unwrap_or(false);
+            Ok(if cancelled {
+                "Cancelled."
+            } else {
+                "Nothing to cancel."
+            }
+            .to_string())
+        }
+        BotCommand::Confirm(code) => confirm(app, &bot, chat_id, code).await,
+        command if command.requires_two_factor() => challenge(app, &bot, chat_id, command),
+        command => execute(app, command).await,
+    };
+
+    if !matches!(command, BotCommand::Help | BotCommand::Unknown(_)) {
+        let details = match &result {
+            Ok(_) => json!({ "command": command.name() }),
+            Err(err) => json!({ "command": command.name(), "error": err }),
+        };
+        audit(app, &subject, details, result.is_ok()).await;
+    }
+
+    Some(result.unwrap_or_else(|err| err))
+}
+
+fn challenge(
+    app: &AppHandle,
+    bot: &SharedTelegramBot,
+    chat_id: &str,
+    command: &BotCommand,
+) -> Result<String, String> {
+    let enrolled = app
+        .try_state::<TwoFactorManager>()
+        .and_then(|two_factor| two_factor.status().ok())
+        .is_some_and(|status| status.enrolled);
+    if !enrolled {
+        return Err("Enable two-factor authentication in the app to use trading commands.".into());
+    }
+
+    let now = Utc::now();
+    let mut state = bot.lock().map_err(|e| e.to_string())?;
+    if let Some(until) = state.locked_until(chat_id, now) {
+        return Err(lockout_message(until));
+    }
+    state.issue_challenge(chat_id, command.clone(), now);
+    Ok(format!(
+        "Reply /confirm <2FA code> within {} minutes to run /{}, or /cancel.",
+        CHALLENGE_TTL_SECONDS / 60,
+        command.name()
+    ))
+}
+
+/// A wrong code still consumes the challenge, and repeated wrong codes lock the
+/// chat out of trading commands for a growing period, so codes can't be guessed
+/// by resending the command.
+async fn confirm(
+    app: &AppHandle,
+    bot: &SharedTelegramBot,
+    chat_id: &str,
+    code: &str,
+) -> Result<String, String> {
+    let command = {
+        let now = Utc::now();
+        let mut state = bot.lock().map_err(|e| e.to_string())?;
+        if let Some(until) = state.locked_until(chat_id, now) {
+            state.challenges.remove(chat_id);
+            return Err(lockout_message(until));
+        }
+        state
+            .take_challenge(chat_id, now)
+            .ok_or_else(|| "Nothing to confirm, or the request expired.".to_string())?
+    };
+
+    let two_factor = app
+        .try_state::<TwoFactorManager>()
+        .ok_or("Two-factor authentication is unavailable")?;
+    let keystore = app
+        .try_state::<Keystore>()
+        .ok_or("Keystore is unavailable")?;
+    let verified = !code.is_empty()
+        && two_factor
+            .verify(code, &keystore)
+            .map_err(|e| e.to_string())?;
+    if !verified {
+        let lockout = bot
+            .lock()
+            .map_err(|e| e.to_string())?
+            .record_failed_confirmation(chat_id, Utc::now());
+        let Some(until) = lockout else {
+            return Err("Invalid 2FA code. Send the command again to retry.".into());
+        };
+        audit(
+            app,
+            &format!("telegram:{}", chat_id),
+            json!({ "command": command.name(), "lockedUntil": until.to_rfc3339() }),
+            false,
+        )
+        .await;
+        return Err(lockout_message(until));
+    }
+
+    bot.lock()
+        .map_err(|e| e.to_string())?
+        .clear_failed_confirmations(chat_id);
+    execute(app, &command).await
+}
+
+async fn execute(app: &AppHandle, command: &BotCommand) -> Result<String, String> {
+    match command {
+        BotCommand::Status => status_text(app).await,
+        BotCommand::Portfolio => portfolio_text(app),
+        BotCommand::Alerts => alerts_text(app).await,
+        BotCommand::PauseBots => pause_bots(app),
+        _ => Err(format!("/{} cannot be run directly.", command.name())),
+    }
+}
+
+fn help_text() -> String {
+    [
+        "Available commands:",
+        "/status - bots, alerts and portfolio at a glance",
+        "/portfolio - positions and P&L",
+        "/alerts - active price alerts",
+        "/pause_bots - pause all running trading bots (2FA)",
+        "/confirm <code> - confirm a pending trading command",
+        "/cancel - discard a pending trading command",
+    ]
+    .join("\n")
+}
+
+async fn status_text(app: &AppHandle) -> Result<String, String> {
+    let mut lines = vec!["Status".to_string()];
+
+    if let Some(engine) = app.try_state::<SharedAutoTradingEngine>() {
+        let engine = engine.lock().map_err(|e| e.to_string())?;
+        let executions = engine.get_all_executions();
+        let count = |status: ExecutionStatus| {
+            executions
+                .iter()
+                .filter(|execution| execution.status == status)
+                .count()
+        };
+        lines.push(format!(
+            "Bots: {} running, {} paused",
+            count(ExecutionStatus::Running),
+            count(ExecutionStatus::Paused)
+        ));
+        if engine.is_kill_switch_active() {
+            lines.push("Kill switch: ACTIVE".into());
+        }
+    }
+
+    if let Some(alerts) = app.try_state::<SharedAlertManager>() {
+        let alerts = alerts
+            .read()
+            .await
+            .list_alerts()
+            .await
+            .map_err(|e| e.to_string())?;
+        let active = alerts
+            .iter()
+            .filter(|alert| alert.state == AlertState::Active)
+            .count();
+        lines.push(format!("Alerts: {} active of {}", active, alerts.len()));
+    }
+
+    if let Some(portfolio) = app.try_state::<SharedPortfolioData>() {
+        let metrics = portfolio.lock().map_err(|e| e.to_string())?.metrics();
+        lines.push(format!(
+            "Portfolio: ${:.2} ({:+.2}% today)",
+            metrics.total_value, metrics.daily_pnl_percent
+        ));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+fn portfolio_text(app: &AppHandle) -> Result<String, String> {
+    let portfolio = app
+        .try_state::<SharedPortfolioData>()
+        .ok_or("Portfolio data is unavailable")?;
+    let (metrics, mut positions) = {
+        let data = portfolio.lock().map_err(|e| e.to_string())?;
+        (data.metrics(), data.positions())
+    };
+    positions.sort_by(|a, b| b.total_value.total_cmp(&a.total_value));
+
+    let mut lines = vec![
+        format!("Portfolio: ${:.2}", metrics.total_value),
+        format!(
+            "Today: ${:+.2} ({:+.2}%)",
+            metrics.daily_pnl, metrics.daily_pnl_percent
+        ),
+        format!("Unrealized: ${:+.2}", metrics.unrealized_pnl),
+    ];
+    lines.extend(positions.iter().take(MAX_LISTED_ITEMS).map(|position| {
+        format!(
+            "{}: ${:.2} ({:+.2}%)",
+            position.symbol, position.total_value, position.unrealized_pnl_percent
+        )
+    }));
+
+    Ok(lines.join("\n"))
+}
+
+async fn alerts_text(app: &AppHandle) -> Result<String, String> {
+    let alerts = app
+        .try_state::<SharedAlertManager>()
+        .ok_or("Alerts are unavailable")?;
+    let alerts = alerts
+        .read()
+        .await
+        .list_alerts()
+        .await
+        .map_err(|e| e.to_string())?;
+    let enabled: Vec<_> = alerts
+        .iter()
+        .filter(|alert| alert.state != AlertState::Disabled)
+        .collect();
+    if enabled.is_empty() {
+        return Ok("No enabled alerts.".into());
+    }
+
+    let mut lines = vec![format!("Alerts ({})", enabled.len())];
+    lines.extend(enabled.iter().take(MAX_LISTED_ITEMS).map(|alert| {
+        format!(
+            "{} ({}): {}",
+            alert.name,
+            alert.symbol,
+            alert.state.as_str()
+        )
+    }));
+    Ok(lines.join("\n"))
+}
+
+fn pause_bots(app: &AppHandle) -> Result<String, String> {
+    let engine = app
+        .try_state::<SharedAutoTradingEngine>()
+        .ok_or("Auto trading is unavailable")?;
+    let paused = engine.lock().map_err(|e| e.to_string())?.pause_running();
+    if paused.is_empty() {
+        return Ok("No bots were running.".into());
+    }
+    Ok(format!(
+        "Paused {} bot(s): {}",
+        paused.len(),
+        paused.join(", ")
+    ))
+}
+
+async fn audit(app: &AppHandle, subject: &str, details: serde_json::Value, success: bool) {
+    let Some(logger) = app.try_state::<ActivityLogger>() else {
+        return;
+    };
+    if let Err(err) = logger
+        .log_activity(
+            subject,
+            ActivityAction::RemoteCommand,
+            details,
+            success,
+            None,
+        )
+        .await
+    {
+        tracing::warn!(error = %err, subject, "failed to audit telegram bot command");
+    }
+}
+
+#[tauri::command]
+pub fn get_telegram_bot_config(
+    bot: State<'_, SharedTelegramBot>,
+) -> Result<TelegramBotConfig, String> {
+    Ok(bot.lock().map_err(|e| e.to_string())?.config.clone())
+}
+
+#[tauri::command]
+pub async fn update_telegram_bot_config(
+    config: TelegramBotConfig,
+    bot: State<'_, SharedTelegramBot>,
+    logger: State<'_, ActivityLogger>,
+) -> Result<TelegramBotConfig, String> {
+    config.validate()?;
+    {
+        let mut state = bot.lock().map_err(|e| e.to_string())?;
+        state.config = config.clone();
+        state.challenges.clear();
+        state.save_config()?;
+    }
+
+    let details = json!({
+        "enabled": config.enabled,
+        "allowedChats": config.allowed_chats,
+    });
+    if let Err(err) = logger
+        .log_activity(
+            CONFIG_AUDIT_SUBJECT,
+            ActivityAction::RemoteCommand,
+            details,
+            true,
+            None,
+        )
+        .await
+    {
+        tracing::warn!(error = %err, "failed to audit telegram bot config change");
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> TelegramBotConfig {
+        TelegramBotConfig {
+            enabled: true,
+            telegram_config_id: Some("tg".into()),
+            allowed_chats: vec![
+                BotChat {
+                    chat_id: "100".into(),
+                    label: None,
+                    permissions: vec![BotPermission::View, BotPermission::Trade],
+                },
+                BotChat {
+                    chat_id: "200".into(),
+                    label: Some("family".into()),
+                    permissions: vec![BotPermission::View],
+                },
+            ],
+            ..TelegramBotConfig::default()
+        }
+    }
+
+    #[test]
+    fn parses_commands_with_bot_suffix_and_args() {
+        assert_eq!(BotCommand::parse("/status"), Some(BotCommand::Status));
+        assert_eq!(
+            BotCommand::parse("/Pause_Bots@eclipse_bot now"),
+            Some(BotCommand::PauseBots)
+        );
+        assert_eq!(
+            BotCommand::parse("/confirm 123456"),
+            Some(BotCommand::Confirm("123456".into()))
+        );
+        assert_eq!(
+            BotCommand::parse("/withdraw"),
+            Some(BotCommand::Unknown("withdraw".into()))
+        );
+        assert_eq!(BotCommand::parse("status please"), None);
+    }
+
+    #[test]
+    fn authorizes_by_whitelist_and_permission() {
+        let config = config();
+        assert_eq!(authorize(&config, "100", &BotCommand::PauseBots), Ok(()));
+        assert_eq!(authorize(&config, "200", &BotCommand::Portfolio), Ok(()));
+        assert_eq!(
+            authorize(&config, "200", &BotCommand::PauseBots),
+            Err(BotDenial::MissingPermission(BotPermission::Trade))
+        );
+        assert_eq!(
+            authorize(&config, "200", &BotCommand::Confirm("1".into())),
+            Err(BotDenial::MissingPermission(BotPermission::Trade))
+        );
+        assert_eq!(
+            authorize(&config, "300", &BotCommand::Help),
+            Err(BotDenial::UnknownChat)
+        );
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn challenges_expire_and_are_single_use() {
+        let mut state = TelegramBotState::load(None);
+        let now = Utc::now();
+
+        state.issue_challenge("100", BotCommand::PauseBots, now);
+        assert_eq!(
+            state.take_challenge("100", now + ChronoDuration::seconds(30)),
+            Some(BotCommand::PauseBots)
+        );
+        assert_eq!(state.take_challenge("100", now), None);
+
+        state.issue_challenge("100", BotCommand::PauseBots, now);
+        assert_eq!(
+            state.take_challenge(
+                "100",
+                now + ChronoDuration::seconds(CHALLENGE_TTL_SECONDS + 1)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn repeated_wrong_codes_lock_the_chat_out() {
+        let mut state = TelegramBotState::load(None);
+        let now = Utc::now();
+
+        for _ in 1..MAX_FAILED_CONFIRMATIONS {
+            assert_eq!(state.record_failed_confirmation("100", now), None);
+        }
+        let until = state.record_failed_confirmation("100", now).unwrap();
+        assert_eq!(until, now + ChronoDuration::minutes(CONFIRMATION_LOCKOUT_MINUTES));
+        assert_eq!(state.locked_until("100", now), Some(until));
+        assert_eq!(state.locked_until("200", now), None);
+        assert_eq!(state.locked_until("100", until), None);
+
+        // A new challenge doesn't reset the count, and the next lockout is longer
+        state.issue_challenge("100", BotCommand::PauseBots, until);
+        for _ in 1..MAX_FAILED_CONFIRMATIONS {
+            assert_eq!(state.record_failed_confirmation("100", until), None);
+        }
+        let again = state.record_failed_confirmation("100", until).unwrap();
+        assert_eq!(again, until + ChronoDuration::minutes(2 * CONFIRMATION_LOCKOUT_MINUTES));
+
+        state.clear_failed_confirmations("100");
+        assert_eq!(state.locked_until("100", until), None);
+    }
+}
@@ -11,6 +11,7 @@ pub enum ChatServiceType {
     Telegram,
     Slack,
     Discord,
+    Push,
 }
 
 impl ChatServiceType {
@@ -19,6 +20,7 @@ impl ChatServiceType {
             ChatServiceType::Telegram => "telegram",
             ChatServiceType::Slack => "slack",
             ChatServiceType::Discord => "discord",
+            ChatServiceType::Push => "push",
         }
     }
 
@@ -27,6 +29,7 @@ impl ChatServiceType {
             "telegram" => Some(ChatServiceType::Telegram),
             "slack" => Some(ChatServiceType::Slack),
             "discord" => Some(ChatServiceType::Discord),
+            "push" => Some(ChatServiceType::Push),
             _ => None,
         }
     }
@@ -79,6 +82,9 @@ pub struct TelegramConfig {
     pub bot_token: String,
     pub chat_id: String,
     pub enabled: bool,
+    /// Sandbox channels only receive notification previews, never live alerts.
+    #[serde(default)]
+    pub sandbox: bool,
     pub alert_types: Option<Vec<String>>,
     pub alert_priorities: Option<Vec<String>>,
 }
@@ -91,6 +97,9 @@ pub struct SlackConfig {
     pub webhook_url: String,
     pub channel: Option<String>,
     pub enabled: bool,
+    /// Sandbox channels only receive notification previews, never live alerts.
+    #[serde(default)]
+    pub sandbox: bool,
     pub alert_types: Option<Vec<String>>,
     pub alert_priorities: Option<Vec<String>>,
 }
@@ -103,6 +112,9 @@ pub struct DiscordConfig {
     pub webhook_url: String,
     pub username: Option<String>,
     pub enabled: bool,
+    /// Sandbox channels only receive notification previews, never live alerts.
+    #[serde(default)]
+    pub sandbox: bool,
     pub role_mentions: Option<Vec<String>>,
     pub alert_types: Option<Vec<String>>,
     pub alert_priorities: Option<Vec<String>>,
@@ -5,6 +5,7 @@ use super::{
     matching::LocalMatcher,
     types::*,
 };
+use crate::config::feature_flags::ModuleState;
 use crate::security::reputation::SharedReputationEngine;
 use anyhow::Result;
 use std::sync::Arc;
@@ -16,7 +17,7 @@ pub type SharedP2PDatabase = Arc<RwLock<P2PDatabase>>;
 #[tauri::command]
 pub async fn create_p2p_offer(
     request: CreateOfferRequest,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
     reputation: State<'_, SharedReputationEngine>,
 ) -> Result<P2POffer, String> {
     let reputation_guard = reputation.read().await;
@@ -61,7 +62,7 @@ pub async fn create_p2p_offer(
 #[tauri::command]
 pub async fn get_p2p_offer(
     offer_id: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<Option<P2POffer>, String> {
     let db_guard = db.read().await;
     db_guard.get_offer(&offer_id).await.map_err(|e| e.to_string())
@@ -72,7 +73,7 @@ pub async fn list_p2p_offers(
     offer_type: Option<String>,
     token_address: Option<String>,
     active_only: bool,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<Vec<P2POffer>, String> {
     let db_guard = db.read().await;
     db_guard
@@ -85,7 +86,7 @@ pub async fn list_p2p_offers(
 pub async fn update_offer_status(
     offer_id: String,
     is_active: bool,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<(), String> {
     let db_guard = db.write().await;
     db_guard
@@ -98,7 +99,7 @@ pub async fn update_offer_status(
 pub async fn match_p2p_offers(
     user_address: String,
     offers: Vec<P2POffer>,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
     reputation: State<'_, SharedReputationEngine>,
 ) -> Result<Vec<super::matching::TraderMatch>, String> {
     let db_guard = db.read().await;
@@ -127,7 +128,7 @@ pub async fn match_p2p_offers(
 #[tauri::command]
 pub async fn create_p2p_escrow(
     request: CreateEscrowRequest,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
     reputation: State<'_, SharedReputationEngine>,
 ) -> Result<Escrow, String> {
     let reputation_guard = reputation.read().await;
@@ -188,7 +189,7 @@ pub async fn create_p2p_escrow(
 #[tauri::command]
 pub async fn get_p2p_escrow(
     escrow_id: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<Option<Escrow>, String> {
     let db_guard = db.read().await;
     db_guard.get_escrow(&escrow_id).await.map_err(|e| e.to_string())
@@ -197,7 +198,7 @@ pub async fn get_p2p_escrow(
 #[tauri::command]
 pub async fn list_p2p_escrows(
     user_address: Option<String>,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<Vec<Escrow>, String> {
     let db_guard = db.read().await;
     db_guard
@@ -209,7 +210,7 @@ pub async fn list_p2p_escrows(
 #[tauri::command]
 pub async fn fund_p2p_escrow(
     escrow_id: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<String, String> {
     let db_guard = db.read().await;
     let escrow = db_guard
@@ -263,7 +264,7 @@ pub async fn fund_p2p_escrow(
 #[tauri::command]
 pub async fn confirm_payment_p2p(
     escrow_id: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<(), String> {
     let db_guard = db.read().await;
     let escrow = db_guard
@@ -288,7 +289,7 @@ pub async fn confirm_payment_p2p(
 #[tauri::command]
 pub async fn release_p2p_escrow(
     escrow_id: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<String, String> {
     let db_guard = db.read().await;
     let escrow = db_guard
@@ -335,7 +336,7 @@ pub async fn release_p2p_escrow(
 #[tauri::command]
 pub async fn cancel_p2p_escrow(
     escrow_id: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<(), String> {
     let db_guard = db.read().await;
     let escrow = db_guard
@@ -372,7 +373,7 @@ pub async fn cancel_p2p_escrow(
 #[tauri::command]
 pub async fn file_p2p_dispute(
     request: FileDisputeRequest,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<Dispute, String> {
     let db_guard = db.read().await;
     let escrow = db_guard
@@ -400,7 +401,7 @@ pub async fn file_p2p_dispute(
 #[tauri::command]
 pub async fn get_p2p_dispute(
     dispute_id: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<Option<Dispute>, String> {
     let db_guard = db.read().await;
     db_guard.get_dispute(&dispute_id).await.map_err(|e| e.to_string())
@@ -409,7 +410,7 @@ pub async fn get_p2p_dispute(
 #[tauri::command]
 pub async fn submit_dispute_evidence(
     request: SubmitEvidenceRequest,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<DisputeEvidence, String> {
     let db_guard = db.write().await;
     db_guard.submit_evidence(request).await.map_err(|e| e.to_string())
@@ -420,7 +421,7 @@ pub async fn resolve_p2p_dispute(
     dispute_id: String,
     resolution: String,
     release_to: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<String, String> {
     let db_guard = db.read().await;
     let dispute = db_guard
@@ -476,7 +477,7 @@ pub async fn resolve_p2p_dispute(
 #[tauri::command]
 pub async fn send_p2p_message(
     request: SendMessageRequest,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<ChatMessage, String> {
     let db_guard = db.write().await;
     db_guard.send_message(request).await.map_err(|e| e.to_string())
@@ -485,7 +486,7 @@ pub async fn send_p2p_message(
 #[tauri::command]
 pub async fn get_p2p_messages(
     escrow_id: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<Vec<ChatMessage>, String> {
     let db_guard = db.read().await;
     db_guard.get_messages(&escrow_id).await.map_err(|e| e.to_string())
@@ -494,7 +495,7 @@ pub async fn get_p2p_messages(
 #[tauri::command]
 pub async fn get_trader_profile(
     address: String,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<TraderProfile, String> {
     let db_guard = db.read().await;
     db_guard
@@ -507,7 +508,7 @@ pub async fn get_trader_profile(
 pub async fn check_p2p_compliance(
     offer_id: Option<String>,
     escrow_id: Option<String>,
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
     reputation: State<'_, SharedReputationEngine>,
 ) -> Result<ComplianceCheck, String> {
     let checker = ComplianceChecker::new();
@@ -558,7 +559,7 @@ pub async fn check_p2p_compliance(
 
 #[tauri::command]
 pub async fn get_p2p_stats(
-    db: State<'_, SharedP2PDatabase>,
+    db: ModuleState<'_, SharedP2PDatabase>,
 ) -> Result<P2PStats, String> {
     let db_guard = db.read().await;
     db_guard.get_stats().await.map_err(|e| e.to_string())
@@ -13,11 +13,27 @@ pub use matching::LocalMatcher;
 pub use commands::*;
 
 use std::sync::Arc;
-use tauri::AppHandle;
+use tauri::{AppHandle, Manager};
 use tokio::sync::RwLock;
 
+use crate::config::feature_flags::{FeatureModule, ModuleOwned};
+
 pub type SharedP2PDatabase = Arc<RwLock<P2PDatabase>>;
 
+impl ModuleOwned for SharedP2PDatabase {
+    const MODULE: FeatureModule = FeatureModule::P2p;
+}
+
+/// Opens the P2P database and manages it. Skipped at startup while the P2P
+/// module is switched off.
+pub async fn start_p2p_module(app_handle: &AppHandle) -> Result<(), String> {
+    let db = init_p2p_system(app_handle)
+        .await
+        .map_err(|e| format!("Failed to initialize P2P system: {e}"))?;
+    app_handle.manage(db);
+    Ok(())
+}
+
 pub async fn init_p2p_system(app_handle: &AppHandle) -> Result<SharedP2PDatabase, Box<dyn std::error::Error>> {
     let app_dir = app_handle
         .path_resolver()
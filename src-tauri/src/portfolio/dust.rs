@@ -0,0 +1,604 @@
+use chrono::Utc;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::api::jupiter::{
+    jupiter_quote, jupiter_swap, QuoteCommandInput, SwapCommandInput, SwapMode,
+};
+use crate::api_config::{report_api_outcome, ApiConfigManager, RequestOutcome, ResolvedApiKey};
+use crate::core::price_engine::get_price_engine;
+use crate::market::order_book::SharedOrderBookManager;
+use crate::security::activity_log::{ActivityAction, ActivityLogger};
+use crate::security::keystore::Keystore;
+use crate::trading::types::OrderSide;
+use crate::wallet::multi_wallet::MultiWalletManager;
+use crate::wallet::offline_signing::{inspect_unsigned_transaction, verify_signed_transaction};
+use crate::wallet::operations::{TokenBalance, WalletOperationsManager};
+
+const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+const SOL_DECIMALS: i32 = 9;
+const USDC_DECIMALS: i32 = 6;
+const BASE_FEE_LAMPORTS: u64 = 5_000;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum DustTarget {
+    Sol,
+    Usdc,
+}
+
+impl DustTarget {
+    fn mint(self) -> &'static str {
+        match self {
+            DustTarget::Sol => SOL_MINT,
+            DustTarget::Usdc => USDC_MINT,
+        }
+    }
+
+    /// USD value of `amount` base units of the target, given the SOL price.
+    fn to_usd(self, amount: f64, sol_price: f64) -> f64 {
+        match self {
+            DustTarget::Sol => amount / 10f64.powi(SOL_DECIMALS) * sol_price,
+            DustTarget::Usdc => amount / 10f64.powi(USDC_DECIMALS),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustSettings {
+    /// Holdings worth less than this are treated as dust.
+    pub threshold_usd: f64,
+    pub target: DustTarget,
+    /// Skip a token when network fees eat more than this share of its proceeds.
+    pub max_fee_percent: f64,
+    pub max_slippage_percent: f64,
+}
+
+impl Default for DustSettings {
+    fn default() -> Self {
+        Self {
+            threshold_usd: 5.0,
+            target: DustTarget::Usdc,
+            max_fee_percent: 25.0,
+            max_slippage_percent: 5.0,
+        }
+    }
+}
+
+impl DustSettings {
+    fn validate(&self) -> Result<(), String> {
+        if !(self.threshold_usd.is_finite() && self.threshold_usd > 0.0) {
+            return Err("Dust threshold must be a positive USD amount".into());
+        }
+        if !(0.0..=100.0).contains(&self.max_fee_percent)
+            || !(0.0..=100.0).contains(&self.max_slippage_percent)
+        {
+            return Err("Fee and slippage limits must be between 0 and 100%".into());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DustSkipReason {
+    /// Already held in the consolidation target.
+    IsTarget,
+    /// Native SOL pays the fees and is never swapped away.
+    NativeSol,
+    /// No USD value, so there is nothing to weigh the fees against.
+    Unpriced,
+    NoRoute,
+    SlippageTooHigh,
+    FeeExceedsValue,
+    BuildFailed,
+    NotSigned,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DustItemStatus {
+    Ready,
+    Skipped,
+    Submitted,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustItem {
+    pub id: String,
+    pub wallet_address: String,
+    pub mint: String,
+    pub symbol: String,
+    pub balance: f64,
+    pub value_usd: f64,
+    pub expected_output_usd: f64,
+    pub network_fee_usd: f64,
+    pub slippage_percent: f64,
+    pub status: DustItemStatus,
+    pub skip_reason: Option<DustSkipReason>,
+    pub detail: Option<String>,
+    /// Unsigned swap transaction, present while the item is ready.
+    pub transaction: Option<String>,
+    pub signature: Option<String>,
+}
+
+impl DustItem {
+    fn skip(&mut self, reason: DustSkipReason, detail: Option<String>) {
+        self.status = DustItemStatus::Skipped;
+        self.skip_reason = Some(reason);
+        self.detail = detail;
+        self.transaction = None;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustPlan {
+    pub id: String,
+    pub settings: DustSettings,
+    pub items: Vec<DustItem>,
+    pub dust_value_usd: f64,
+    pub expected_output_usd: f64,
+    pub network_fees_usd: f64,
+    pub created_at: String,
+}
+
+impl DustPlan {
+    fn summarize(&mut self) {
+        let ready = || {
+            self.items
+                .iter()
+                .filter(|i| i.status != DustItemStatus::Skipped)
+        };
+        self.dust_value_usd = self.items.iter().map(|item| item.value_usd).sum();
+        self.expected_output_usd = ready().map(|item| item.expected_output_usd).sum();
+        self.network_fees_usd = ready().map(|item| item.network_fee_usd).sum();
+    }
+}
+
+/// Balances under the threshold, excluding anything that cannot be weighed
+/// or should not be swapped.
+pub fn select_dust(balances: &[(String, TokenBalance)], settings: &DustSettings) -> Vec<DustItem> {
+    balances
+        .iter()
+        .filter(|(_, token)| token.balance > 0.0 && token.usd_value < settings.threshold_usd)
+        .map(|(wallet, token)| {
+            let mut item = DustItem {
+                id: Uuid::new_v4().to_string(),
+                wallet_address: wallet.clone(),
+                mint: token.mint.clone(),
+                symbol: token.symbol.clone(),
+                balance: token.balance,
+                value_usd: token.usd_value.max(0.0),
+                expected_output_usd: 0.0,
+                network_fee_usd: 0.0,
+                slippage_percent: 0.0,
+                status: DustItemStatus::Ready,
+                skip_reason: None,
+                detail: None,
+                transaction: None,
+                signature: None,
+            };
+            if token.mint == settings.target.mint() {
+                item.skip(DustSkipReason::IsTarget, None);
+            } else if token.mint == SOL_MINT {
+                item.skip(DustSkipReason::NativeSol, None);
+            } else if token.usd_value <= 0.0 {
+                item.skip(DustSkipReason::Unpriced, None);
+            }
+            item
+        })
+        .collect()
+}
+
+/// Only consolidate when the swap nets something after slippage and fees.
+pub fn check_economics(item: &DustItem, settings: &DustSettings) -> Result<(), DustSkipReason> {
+    if item.slippage_percent > settings.max_slippage_percent {
+        return Err(DustSkipReason::SlippageTooHigh);
+    }
+    let max_fee = item.expected_output_usd * settings.max_fee_percent / 100.0;
+    if item.expected_output_usd <= item.network_fee_usd || item.network_fee_usd > max_fee {
+        return Err(DustSkipReason::FeeExceedsValue);
+    }
+    Ok(())
+}
+
+pub struct DustConsolidator {
+    plans: Mutex<HashMap<String, DustPlan>>,
+    client: Client,
+}
+
+pub type SharedDustConsolidator = Arc<DustConsolidator>;
+
+pub fn register_dust_consolidator(app: &tauri::App) {
+    let consolidator: SharedDustConsolidator = Arc::new(DustConsolidator {
+        plans: Mutex::new(HashMap::new()),
+        client: Client::new(),
+    });
+    app.manage(consolidator);
+}
+
+fn resolve_rpc(app: &AppHandle) -> Result<ResolvedApiKey, String> {
+    let manager = app
+        .try_state::<ApiConfigManager>()
+        .ok_or_else(|| "API configuration unavailable".to_string())?;
+    let keystore = app
+        .try_state::<Keystore>()
+        .ok_or_else(|| "Keystore unavailable".to_string())?;
+    manager.resolve_key("solana_rpc", &keystore)
+}
+
+impl DustConsolidator {
+    async fn send_transaction(&self, app: &AppHandle, signed: &str) -> Result<String, String> {
+        let rpc = resolve_rpc(app)?;
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sendTransaction",
+            "params": [signed, { "encoding": "base64" }],
+        });
+        let started = Instant::now();
+        let response = self.client.post(&rpc.key).json(&payload).send().await;
+        let outcome = RequestOutcome::from_response(started, &response);
+        report_api_outcome(app, &rpc.service, rpc.source, outcome).await;
+
+        let data: Value = response
+            .map_err(|e| format!("RPC request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Some(error) = data.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+        data["result"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| "Invalid sendTransaction response".to_string())
+    }
+}
+
+/// Quotes the swap, weighs it and, if worthwhile, builds the transaction.
+async fn prepare_item(
+    item: &mut DustItem,
+    decimals: u8,
+    settings: &DustSettings,
+    sol_price: f64,
+    book_slippage: Option<f64>,
+) {
+    let amount = (item.balance * 10f64.powi(decimals as i32)).floor() as u64;
+    let quote = match jupiter_quote(QuoteCommandInput {
+        input_mint: item.mint.clone(),
+        output_mint: settings.target.mint().to_string(),
+        amount,
+        slippage_bps: Some((settings.max_slippage_percent * 100.0).round() as u16),
+        swap_mode: Some(SwapMode::ExactIn),
+        platform_fee_bps: None,
+        only_direct_routes: None,
+        referral_account: None,
+        as_legacy_transaction: None,
+        priority_fee_config: None,
+    })
+    .await
+    {
+        Ok(quote) => quote,
+        Err(err) => return item.skip(DustSkipReason::NoRoute, Some(err)),
+    };
+
+    let output: f64 = quote.quote.output_amount.parse().unwrap_or(0.0);
+    item.expected_output_usd = settings.target.to_usd(output, sol_price);
+    // Thin CLOB books can be worse than the routed impact suggests
+    item.slippage_percent =
+        (quote.quote.price_impact_pct * 100.0).max(book_slippage.unwrap_or(0.0));
+    if item.slippage_percent > settings.max_slippage_percent {
+        return item.skip(DustSkipReason::SlippageTooHigh, None);
+    }
+
+    let swap = match jupiter_swap(SwapCommandInput {
+        quote: quote.quote,
+        user_public_key: item.wallet_address.clone(),
+        fee_account: None,
+        wrap_and_unwrap_sol: Some(true),
+        as_legacy_transaction: None,
+        priority_fee_config: None,
+        simulate: None,
+    })
+    .await
+    {
+        Ok(swap) => swap,
+        Err(err) => return item.skip(DustSkipReason::BuildFailed, Some(err)),
+    };
+
+    let priority_fee: u64 = swap
+        .prioritization_fee_lamports
+        .as_deref()
+        .and_then(|fee| fee.parse().ok())
+        .unwrap_or(0);
+    item.network_fee_usd =
+        DustTarget::Sol.to_usd((BASE_FEE_LAMPORTS + priority_fee) as f64, sol_price);
+    item.transaction = Some(swap.transaction.base64);
+
+    if let Err(reason) = check_economics(item, settings) {
+        item.skip(reason, None);
+    }
+}
+
+/// Finds dust across wallets and prepares swaps for the tokens worth
+/// consolidating, with a reason for every token left alone.
+#[tauri::command]
+pub async fn plan_dust_consolidation(
+    consolidator: State<'_, SharedDustConsolidator>,
+    wallets: State<'_, MultiWalletManager>,
+    operations: State<'_, WalletOperationsManager>,
+    order_books: State<'_, SharedOrderBookManager>,
+    settings: Option<DustSettings>,
+    wallet: Option<String>,
+) -> Result<DustPlan, String> {
+    let settings = settings.unwrap_or_default();
+    settings.validate()?;
+    let sol_price = get_price_engine()
+        .get_cached_price("SOL")
+        .map(|p| p.price)
+        .filter(|price| *price > 0.0)
+        .ok_or_else(|| "SOL price unavailable; cannot weigh network fees".to_string())?;
+
+    let addresses: Vec<String> = match wallet {
+        Some(address) => vec![address],
+        None => wallets
+            .list_wallets()
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|wallet| wallet.public_key)
+            .collect(),
+    };
+    let balances: Vec<(String, TokenBalance)> = addresses
+        .iter()
+        .flat_map(|address| {
+            operations
+                .cached_token_balances(address)
+                .into_iter()
+                .map(move |token| (address.clone(), token))
+        })
+        .collect();
+    let decimals: HashMap<&str, u8> = balances
+        .iter()
+        .map(|(_, token)| (token.mint.as_str(), token.decimals))
+        .collect();
+
+    let mut items = select_dust(&balances, &settings);
+    for item in items
+        .iter_mut()
+        .filter(|i| i.status == DustItemStatus::Ready)
+    {
+        let book_slippage = order_books
+            .read()
+            .await
+            .estimate_slippage(&item.symbol, OrderSide::Sell, item.balance)
+            .map(|estimate| estimate.slippage_percent);
+        let decimals = decimals.get(item.mint.as_str()).copied().unwrap_or(0);
+        prepare_item(item, decimals, &settings, sol_price, book_slippage).await;
+    }
+
+    let mut plan = DustPlan {
+        id: Uuid::new_v4().to_string(),
+        settings,
+        items,
+        dust_value_usd: 0.0,
+        expected_output_usd: 0.0,
+        network_fees_usd: 0.0,
+        created_at: Utc::now().to_rfc3339(),
+    };
+    plan.summarize();
+
+    consolidator
+        .plans
+        .lock()
+        .await
+        .insert(plan.id.clone(), plan.clone());
+    Ok(plan)
+}
+
+/// The returned transaction must carry exactly the prepared swap, signed by the
+/// item's wallet; anything else would be broadcast under the dust plan's name.
+fn verify_signed_dust(item: &DustItem, signed_transaction: &str) -> Result<(), String> {
+    let prepared = item
+        .transaction
+        .as_deref()
+        .ok_or_else(|| "No prepared swap to match".to_string())
+        .and_then(inspect_unsigned_transaction)?;
+    if prepared.required_signers.first() != Some(&item.wallet_address) {
+        return Err("Prepared swap is not payable by the dust wallet".to_string());
+    }
+    verify_signed_transaction(&prepared.message_hash, signed_transaction)
+        .map(|_| ())
+        .map_err(|err| format!("Signed transaction rejected: {}", err))
+}
+
+/// Submits the signed swaps of a plan as one batch. Items without a signed
+/// transaction are skipped rather than failing the batch.
+#[tauri::command]
+pub async fn execute_dust_consolidation(
+    app: AppHandle,
+    consolidator: State<'_, SharedDustConsolidator>,
+    plan_id: String,
+    signed_transactions: HashMap<String, String>,
+) -> Result<DustPlan, String> {
+    let mut plan = consolidator
+        .plans
+        .lock()
+        .await
+        .remove(&plan_id)
+        .ok_or_else(|| format!("Dust plan {plan_id} not found or already executed"))?;
+    resolve_rpc(&app)?;
+
+    for item in plan
+        .items
+        .iter_mut()
+        .filter(|i| i.status == DustItemStatus::Ready)
+    {
+        let Some(signed) = signed_transactions.get(&item.id) else {
+            item.skip(DustSkipReason::NotSigned, None);
+            continue;
+        };
+        if let Err(err) = verify_signed_dust(item, signed) {
+            item.status = DustItemStatus::Failed;
+            item.detail = Some(err);
+            item.transaction = None;
+            continue;
+        }
+        match consolidator.send_transaction(&app, signed).await {
+            Ok(signature) => {
+                item.status = DustItemStatus::Submitted;
+                item.signature = Some(signature);
+            }
+            Err(err) => {
+                item.status = DustItemStatus::Failed;
+                item.detail = Some(err);
+            }
+        }
+        item.transaction = None;
+    }
+    plan.summarize();
+
+    if let Some(logger) = app.try_state::<ActivityLogger>() {
+        for item in plan
+            .items
+            .iter()
+            .filter(|i| i.signature.is_some() || i.status == DustItemStatus::Failed)
+        {
+            let details = json!({
+                "event": "dust_consolidation",
+                "planId": plan.id,
+                "mint": item.mint,
+                "valueUsd": item.value_usd,
+                "target": plan.settings.target,
+                "signature": item.signature,
+                "error": item.detail,
+            });
+            let success = item.status == DustItemStatus::Submitted;
+            if let Err(err) = logger
+                .log_activity(
+                    &item.wallet_address,
+                    ActivityAction::Swap,
+                    details,
+                    success,
+                    None,
+                )
+                .await
+            {
+                tracing::warn!(error = %err, "failed to audit dust consolidation");
+            }
+        }
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn balance(mint: &str, usd: f64) -> (String, TokenBalance) {
+        (
+            "wallet".to_string(),
+            TokenBalance {
+                mint: mint.to_string(),
+                symbol: mint.to_uppercase(),
+                name: mint.to_string(),
+                balance: 10.0,
+                decimals: 6,
+                usd_value: usd,
+                change_24h: 0.0,
+                logo_uri: None,
+                last_updated: Utc::now(),
+            },
+        )
+    }
+
+    fn ready(output_usd: f64, fee_usd: f64, slippage: f64) -> DustItem {
+        let mut items = select_dust(&[balance("bonk", 2.0)], &DustSettings::default());
+        let mut item = items.remove(0);
+        item.expected_output_usd = output_usd;
+        item.network_fee_usd = fee_usd;
+        item.slippage_percent = slippage;
+        item
+    }
+
+    #[test]
+    fn selects_balances_under_the_threshold_with_skip_reasons() {
+        let items = select_dust(
+            &[
+                balance("bonk", 2.0),
+                balance("wif", 50.0),
+                balance(USDC_MINT, 1.0),
+                balance(SOL_MINT, 0.5),
+                balance("rug", 0.0),
+            ],
+            &DustSettings::default(),
+        );
+        let reasons: Vec<_> = items.iter().map(|item| item.skip_reason).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                None,
+                Some(DustSkipReason::IsTarget),
+                Some(DustSkipReason::NativeSol),
+                Some(DustSkipReason::Unpriced),
+            ]
+        );
+    }
+
+    #[test]
+    fn fees_must_stay_a_small_share_of_proceeds() {
+        let settings = DustSettings::default();
+        assert!(check_economics(&ready(2.0, 0.1, 1.0), &settings).is_ok());
+        assert_eq!(
+            check_economics(&ready(0.3, 0.1, 1.0), &settings),
+            Err(DustSkipReason::FeeExceedsValue)
+        );
+        assert_eq!(
+            check_economics(&ready(0.05, 0.1, 1.0), &settings),
+            Err(DustSkipReason::FeeExceedsValue)
+        );
+        assert_eq!(
+            check_economics(&ready(2.0, 0.1, 8.0), &settings),
+            Err(DustSkipReason::SlippageTooHigh)
+        );
+    }
+
+    #[test]
+    fn plan_totals_ignore_skipped_items() {
+        let mut skipped = ready(5.0, 0.1, 1.0);
+        skipped.skip(DustSkipReason::NoRoute, None);
+        let mut plan = DustPlan {
+            id: "plan".to_string(),
+            settings: DustSettings::default(),
+            items: vec![ready(2.0, 0.1, 1.0), skipped],
+            dust_value_usd: 0.0,
+            expected_output_usd: 0.0,
+            network_fees_usd: 0.0,
+            created_at: Utc::now().to_rfc3339(),
+        };
+        plan.summarize();
+        assert!((plan.dust_value_usd - 4.0).abs() < 1e-9);
+        assert!((plan.expected_output_usd - 2.0).abs() < 1e-9);
+        assert!((plan.network_fees_usd - 0.1).abs() < 1e-9);
+        assert!(DustSettings {
+            threshold_usd: 0.0,
+            ..DustSettings::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
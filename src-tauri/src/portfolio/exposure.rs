@@ -0,0 +1,939 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite, SqlitePool};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use crate::core::price_engine::get_price_engine;
+use crate::notifications::router::SharedNotificationRouter;
+use crate::wallet::multi_wallet::{MultiWalletManager, WalletInfo, WalletType};
+use crate::wallet::operations::{TokenBalance, WalletOperationsManager};
+
+const EXPOSURE_DB_FILE: &str = "exposure.db";
+const EXPOSURE_ALERT_EVENT: &str = "exposure_threshold_breached";
+/// Reports are built on every refresh; history keeps at most one per interval.
+const SNAPSHOT_INTERVAL_MINUTES: i64 = 60;
+/// A breach that stays open is notified again after this long.
+const BREACH_RENOTIFY_HOURS: i64 = 24;
+
+const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+const STABLECOIN_MINTS: &[(&str, &str)] = &[
+    ("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "USDC"),
+    ("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", "USDT"),
+    ("7XSjzSPQJ49z6VvPF41ytsYEy8Z9KdwdHFuDeRh4Vj2U", "UXD"),
+    ("2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo", "PYUSD"),
+    ("USDH1SM1ojwWUga67PGrgFWUHibbjqMvuMaDkRJTgkX", "USDH"),
+];
+
+const LST_MINTS: &[(&str, &str)] = &[
+    ("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So", "mSOL"),
+    ("7dHbWXmci3dT8UFYWYZweBLXgycu7Y3iL6trKn1Y7ARj", "stSOL"),
+    ("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn", "JitoSOL"),
+    ("bSo13r4TkiE4KumL71LsHTPpL2euBYLFx6h9HP3piy1", "bSOL"),
+    ("jupSoLaHXQiZZTSfEWMTRRgpnyFm8f6sZdosWBjx93v", "JupSOL"),
+];
+
+const MEMECOIN_SYMBOLS: &[&str] = &["BONK", "WIF", "POPCAT", "MEW", "BOME", "SAMO", "MYRO", "SLERF"];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum AssetClass {
+    Sol,
+    Stablecoin,
+    LiquidStaking,
+    Memecoin,
+    Nft,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum CustodyType {
+    Hot,
+    Hardware,
+    Multisig,
+    WatchOnly,
+    Cex,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExposureBucket {
+    pub value_usd: f64,
+    pub percent: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletExposure {
+    pub wallet_id: String,
+    pub label: String,
+    pub custody: CustodyType,
+    pub value_usd: f64,
+    pub by_asset_class: HashMap<AssetClass, f64>,
+}
+
+/// Amount held of one token and its unit price when the report was built.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HoldingMark {
+    pub amount: f64,
+    pub price_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExposureReport {
+    pub total_value_usd: f64,
+    pub by_asset_class: HashMap<AssetClass, ExposureBucket>,
+    pub by_custody: HashMap<CustodyType, ExposureBucket>,
+    /// Per-stablecoin value, keyed by symbol.
+    pub stablecoins: HashMap<String, f64>,
+    pub stablecoin_percent: f64,
+    pub wallets: Vec<WalletExposure>,
+    /// Combined holdings per mint, which let returns be told apart from transfers.
+    pub holdings: HashMap<String, HoldingMark>,
+    pub breaches: Vec<ExposureBreach>,
+    pub generated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExposureSnapshot {
+    pub timestamp: String,
+    pub total_value_usd: f64,
+    pub by_asset_class: HashMap<AssetClass, f64>,
+    pub by_custody: HashMap<CustodyType, f64>,
+    /// Empty for snapshots recorded before holdings were kept.
+    #[serde(default)]
+    pub holdings: HashMap<String, HoldingMark>,
+}
+
+/// Mark-to-market value next to what selling everything would realize.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidationSnapshot {
+    pub timestamp: String,
+    pub mark_value_usd: f64,
+    pub liquidation_value_usd: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ThresholdDirection {
+    Below,
+    Above,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "kind", content = "value")]
+pub enum ExposureTarget {
+    AssetClass(AssetClass),
+    Custody(CustodyType),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExposureThreshold {
+    pub id: String,
+    pub target: ExposureTarget,
+    pub direction: ThresholdDirection,
+    pub percent: f64,
+    pub enabled: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExposureThresholdInput {
+    pub target: ExposureTarget,
+    pub direction: ThresholdDirection,
+    pub percent: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExposureBreach {
+    pub threshold_id: String,
+    pub target: ExposureTarget,
+    pub direction: ThresholdDirection,
+    pub threshold_percent: f64,
+    pub current_percent: f64,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExposureError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("threshold not found: {0}")]
+    NotFound(String),
+    #[error("invalid threshold: {0}")]
+    Invalid(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+pub fn classify_asset(token: &TokenBalance) -> AssetClass {
+    match classify_mint(&token.mint, &token.symbol) {
+        AssetClass::Memecoin | AssetClass::Other if token.decimals == 0 && token.balance <= 1.0 => {
+            AssetClass::Nft
+        }
+        class => class,
+    }
+}
+
+/// Classifies a token from its mint and symbol alone, without NFT detection.
+pub fn classify_mint(mint: &str, symbol: &str) -> AssetClass {
+    if mint == WSOL_MINT {
+        return AssetClass::Sol;
+    }
+    if STABLECOIN_MINTS.iter().any(|(stable, _)| *stable == mint) {
+        return AssetClass::Stablecoin;
+    }
+    if LST_MINTS.iter().any(|(lst, _)| *lst == mint) {
+        return AssetClass::LiquidStaking;
+    }
+    if MEMECOIN_SYMBOLS.contains(&symbol.to_uppercase().as_str()) {
+        return AssetClass::Memecoin;
+    }
+    AssetClass::Other
+}
+
+pub fn custody_for(wallet_type: &WalletType) -> CustodyType {
+    match wallet_type {
+        WalletType::Phantom | WalletType::Imported => CustodyType::Hot,
+        WalletType::HardwareLedger | WalletType::HardwareTrezor => CustodyType::Hardware,
+        WalletType::Multisig => CustodyType::Multisig,
+        WalletType::WatchOnly => CustodyType::WatchOnly,
+        WalletType::Exchange => CustodyType::Cex,
+    }
+}
+
+fn stablecoin_symbol(token: &TokenBalance) -> String {
+    STABLECOIN_MINTS
+        .iter()
+        .find(|(mint, _)| *mint == token.mint)
+        .map(|(_, symbol)| symbol.to_string())
+        .unwrap_or_else(|| token.symbol.clone())
+}
+
+/// SOL price for valuing native balances. Only required when some wallet
+/// falls back to its native balance; otherwise a missing price is harmless.
+pub fn native_sol_price(wallets: &[(WalletInfo, Vec<TokenBalance>)]) -> Result<f64, String> {
+    let needed = wallets
+        .iter()
+        .any(|(wallet, balances)| balances.is_empty() && wallet.balance > 0.0);
+    match get_price_engine()
+        .get_cached_price("SOL")
+        .map(|p| p.price)
+        .filter(|price| *price > 0.0)
+    {
+        Some(price) => Ok(price),
+        None if needed => Err("SOL price unavailable; cannot value native balances".to_string()),
+        None => Ok(0.0),
+    }
+}
+
+fn to_buckets<K: std::hash::Hash + Eq + Copy>(
+    values: &HashMap<K, f64>,
+    total: f64,
+) -> HashMap<K, ExposureBucket> {
+    values
+        .iter()
+        .map(|(key, value)| {
+            let percent = if total > 0.0 { value / total * 100.0 } else { 0.0 };
+            (*key, ExposureBucket { value_usd: *value, percent })
+        })
+        .collect()
+}
+
+/// Builds the exposure report from each wallet's token balances.
+///
+/// Wallets with no cached balances contribute their native balance as SOL,
+/// valued at `sol_price`.
+pub fn build_exposure_report(
+    wallets: &[(WalletInfo, Vec<TokenBalance>)],
+    thresholds: &[ExposureThreshold],
+    sol_price: f64,
+) -> ExposureReport {
+    let mut by_class: HashMap<AssetClass, f64> = HashMap::new();
+    let mut by_custody: HashMap<CustodyType, f64> = HashMap::new();
+    let mut stablecoins: HashMap<String, f64> = HashMap::new();
+    let mut holdings: HashMap<String, HoldingMark> = HashMap::new();
+    let mut wallet_rows = Vec::with_capacity(wallets.len());
+    let mut hold = |mint: &str, amount: f64, price_usd: f64| {
+        let mark = holdings.entry(mint.to_string()).or_default();
+        mark.amount += amount;
+        if price_usd > 0.0 {
+            mark.price_usd = price_usd;
+        }
+    };
+
+    for (wallet, balances) in wallets {
+        let custody = custody_for(&wallet.wallet_type);
+        let mut wallet_classes: HashMap<AssetClass, f64> = HashMap::new();
+
+        if balances.is_empty() {
+            *wallet_classes.entry(AssetClass::Sol).or_default() +=
+                wallet.balance.max(0.0) * sol_price;
+            hold(WSOL_MINT, wallet.balance.max(0.0), sol_price);
+        }
+        for token in balances {
+            let value = token.usd_value.max(0.0);
+            if token.balance > 0.0 {
+                hold(&token.mint, token.balance, value / token.balance);
+            }
+            let class = classify_asset(token);
+            *wallet_classes.entry(class).or_default() += value;
+            if class == AssetClass::Stablecoin {
+                *stablecoins.entry(stablecoin_symbol(token)).or_default() += value;
+            }
+        }
+
+        let wallet_total: f64 = wallet_classes.values().sum();
+        for (class, value) in &wallet_classes {
+            *by_class.entry(*class).or_default() += value;
+        }
+        *by_custody.entry(custody).or_default() += wallet_total;
+
+        wallet_rows.push(WalletExposure {
+            wallet_id: wallet.id.clone(),
+            label: wallet.label.clone(),
+            custody,
+            value_usd: wallet_total,
+            by_asset_class: wallet_classes,
+        });
+    }
+
+    let total: f64 = by_class.values().sum();
+    let by_asset_class = to_buckets(&by_class, total);
+    let by_custody = to_buckets(&by_custody, total);
+    let stablecoin_percent = by_asset_class
+        .get(&AssetClass::Stablecoin)
+        .map(|b| b.percent)
+        .unwrap_or(0.0);
+
+    let mut report = ExposureReport {
+        total_value_usd: total,
+        by_asset_class,
+        by_custody,
+        stablecoins,
+        stablecoin_percent,
+        wallets: wallet_rows,
+        holdings,
+        breaches: Vec::new(),
+        generated_at: Utc::now().to_rfc3339(),
+    };
+    report.breaches = evaluate_thresholds(&report, thresholds);
+    report
+}
+
+pub fn evaluate_thresholds(
+    report: &ExposureReport,
+    thresholds: &[ExposureThreshold],
+) -> Vec<ExposureBreach> {
+    if report.total_value_usd <= 0.0 {
+        return Vec::new();
+    }
+
+    thresholds
+        .iter()
+        .filter(|t| t.enabled)
+        .filter_map(|threshold| {
+            let current = match &threshold.target {
+                ExposureTarget::AssetClass(class) => report.by_asset_class.get(class),
+                ExposureTarget::Custody(custody) => report.by_custody.get(custody),
+            }
+            .map(|b| b.percent)
+            .unwrap_or(0.0);
+
+            let breached = match threshold.direction {
+                ThresholdDirection::Below => current < threshold.percent,
+                ThresholdDirection::Above => current > threshold.percent,
+            };
+
+            breached.then(|| ExposureBreach {
+                threshold_id: threshold.id.clone(),
+                target: threshold.target.clone(),
+                direction: threshold.direction,
+                threshold_percent: threshold.percent,
+                current_percent: current,
+            })
+        })
+        .collect()
+}
+
+/// Picks the breaches to notify about: new ones, and open ones last notified
+/// more than `BREACH_RENOTIFY_HOURS` ago. Breaches that cleared are forgotten,
+/// so they notify again if they reopen.
+fn breaches_to_notify<'a>(
+    notified: &mut HashMap<String, DateTime<Utc>>,
+    breaches: &'a [ExposureBreach],
+    now: DateTime<Utc>,
+) -> Vec<&'a ExposureBreach> {
+    notified.retain(|id, _| breaches.iter().any(|b| &b.threshold_id == id));
+    breaches
+        .iter()
+        .filter(|breach| {
+            let due = notified
+                .get(&breach.threshold_id)
+                .map_or(true, |at| now - *at >= Duration::hours(BREACH_RENOTIFY_HOURS));
+            if due {
+                notified.insert(breach.threshold_id.clone(), now);
+            }
+            due
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct ExposureTracker {
+    pool: Pool<Sqlite>,
+    /// Open breaches by threshold id, with when they were last notified.
+    notified: Arc<Mutex<HashMap<String, DateTime<Utc>>>>,
+}
+
+pub type SharedExposureTracker = Arc<RwLock<ExposureTracker>>;
+
+impl ExposureTracker {
+    pub async fn new(app: &AppHandle) -> Result<Self, ExposureError> {
+        let db_path = exposure_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let tracker = Self {
+            pool,
+            notified: Arc::new(Mutex::new(HashMap::new())),
+        };
+        tracker.initialize().await?;
+        Ok(tracker)
+    }
+
+    async fn initialize(&self) -> Result<(), ExposureError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS exposure_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp TEXT NOT NULL,
+                total_value_usd REAL NOT NULL,
+                by_asset_class TEXT NOT NULL,
+                by_custody TEXT NOT NULL,
+                holdings TEXT
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        let has_holdings = sqlx::query(
+            "SELECT 1 FROM pragma_table_info('exposure_snapshots') WHERE name = 'holdings'",
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .is_some();
+        if !has_holdings {
+            sqlx::query("ALTER TABLE exposure_snapshots ADD COLUMN holdings TEXT")
+                .execute(&self.pool)
+                .await?;
+        }
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS exposure_thresholds (
+                id TEXT PRIMARY KEY,
+                target TEXT NOT NULL,
+                direction TEXT NOT NULL,
+                percent REAL NOT NULL,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                created_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_exposure_snapshots_timestamp ON exposure_snapshots(timestamp)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS liquidation_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp TEXT NOT NULL,
+                mark_value_usd REAL NOT NULL,
+                liquidation_value_usd REAL NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_liquidation_snapshots_timestamp ON liquidation_snapshots(timestamp)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn record_snapshot(&self, report: &ExposureReport) -> Result<(), ExposureError> {
+        let by_class: HashMap<AssetClass, f64> = report
+            .by_asset_class
+            .iter()
+            .map(|(k, v)| (*k, v.value_usd))
+            .collect();
+        let by_custody: HashMap<CustodyType, f64> = report
+            .by_custody
+            .iter()
+            .map(|(k, v)| (*k, v.value_usd))
+            .collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO exposure_snapshots
+                (timestamp, total_value_usd, by_asset_class, by_custody, holdings)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+        )
+        .bind(&report.generated_at)
+        .bind(report.total_value_usd)
+        .bind(serde_json::to_string(&by_class)?)
+        .bind(serde_json::to_string(&by_custody)?)
+        .bind(serde_json::to_string(&report.holdings)?)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records the report unless the latest snapshot is under
+    /// `SNAPSHOT_INTERVAL_MINUTES` old. Returns whether it was recorded.
+    pub async fn record_snapshot_if_due(
+        &self,
+        report: &ExposureReport,
+    ) -> Result<bool, ExposureError> {
+        let latest: Option<String> =
+            sqlx::query_scalar("SELECT MAX(timestamp) FROM exposure_snapshots")
+                .fetch_one(&self.pool)
+                .await?;
+        let due = latest
+            .and_then(|ts| DateTime::parse_from_rfc3339(&ts).ok())
+            .map_or(true, |ts| {
+                Utc::now() - ts.with_timezone(&Utc) >= Duration::minutes(SNAPSHOT_INTERVAL_MINUTES)
+            });
+        if due {
+            self.record_snapshot(report).await?;
+        }
+        Ok(due)
+    }
+
+    /// Breaches in `report` that should be notified now; see `breaches_to_notify`.
+    pub fn take_breaches_to_notify(&self, report: &ExposureReport) -> Vec<ExposureBreach> {
+        let mut notified = match self.notified.lock() {
+            Ok(notified) => notified,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        breaches_to_notify(&mut notified, &report.breaches, Utc::now())
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    pub async fn get_history(&self, days: i64) -> Result<Vec<ExposureSnapshot>, ExposureError> {
+        let cutoff = (Utc::now() - Duration::days(days)).to_rfc3339();
+        let rows = sqlx::query(
+            r#"
+            SELECT timestamp, total_value_usd, by_asset_class, by_custody, holdings
+            FROM exposure_snapshots
+            WHERE timestamp >= ?1
+            ORDER BY timestamp ASC
+            "#,
+        )
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut history = Vec::with_capacity(rows.len());
+        for row in rows {
+            let by_class: String = row.try_get("by_asset_class")?;
+            let by_custody: String = row.try_get("by_custody")?;
+            let holdings: Option<String> = row.try_get("holdings")?;
+            history.push(ExposureSnapshot {
+                timestamp: row.try_get("timestamp")?,
+                total_value_usd: row.try_get("total_value_usd")?,
+                by_asset_class: serde_json::from_str(&by_class)?,
+                by_custody: serde_json::from_str(&by_custody)?,
+                holdings: match holdings {
+                    Some(holdings) => serde_json::from_str(&holdings)?,
+                    None => HashMap::new(),
+                },
+            });
+        }
+
+        Ok(history)
+    }
+
+    pub async fn record_liquidation_snapshot(
+        &self,
+        snapshot: &LiquidationSnapshot,
+    ) -> Result<(), ExposureError> {
+        sqlx::query(
+            r#"
+            INSERT INTO liquidation_snapshots (timestamp, mark_value_usd, liquidation_value_usd)
+            VALUES (?1, ?2, ?3)
+            "#,
+        )
+        .bind(&snapshot.timestamp)
+        .bind(snapshot.mark_value_usd)
+        .bind(snapshot.liquidation_value_usd)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_liquidation_history(
+        &self,
+        days: i64,
+    ) -> Result<Vec<LiquidationSnapshot>, ExposureError> {
+        let cutoff = (Utc::now() - Duration::days(days)).to_rfc3339();
+        let rows = sqlx::query(
+            r#"
+            SELECT timestamp, mark_value_usd, liquidation_value_usd
+            FROM liquidation_snapshots
+            WHERE timestamp >= ?1
+            ORDER BY timestamp ASC
+            "#,
+        )
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut history = Vec::with_capacity(rows.len());
+        for row in rows {
+            history.push(LiquidationSnapshot {
+                timestamp: row.try_get("timestamp")?,
+                mark_value_usd: row.try_get("mark_value_usd")?,
+                liquidation_value_usd: row.try_get("liquidation_value_usd")?,
+            });
+        }
+
+        Ok(history)
+    }
+
+    pub async fn list_thresholds(&self) -> Result<Vec<ExposureThreshold>, ExposureError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, target, direction, percent, enabled, created_at
+            FROM exposure_thresholds
+            ORDER BY created_at ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut thresholds = Vec::with_capacity(rows.len());
+        for row in rows {
+            let target: String = row.try_get("target")?;
+            let direction: String = row.try_get("direction")?;
+            let enabled: i64 = row.try_get("enabled")?;
+            thresholds.push(ExposureThreshold {
+                id: row.try_get("id")?,
+                target: serde_json::from_str(&target)?,
+                direction: serde_json::from_str(&direction)?,
+                percent: row.try_get("percent")?,
+                enabled: enabled != 0,
+                created_at: row.try_get("created_at")?,
+            });
+        }
+
+        Ok(thresholds)
+    }
+
+    pub async fn add_threshold(
+        &self,
+        input: ExposureThresholdInput,
+    ) -> Result<ExposureThreshold, ExposureError> {
+        if !(0.0..=100.0).contains(&input.percent) {
+            return Err(ExposureError::Invalid(
+                "percent must be between 0 and 100".to_string(),
+            ));
+        }
+
+        let threshold = ExposureThreshold {
+            id: uuid::Uuid::new_v4().to_string(),
+            target: input.target,
+            direction: input.direction,
+            percent: input.percent,
+            enabled: true,
+            created_at: Utc::now().to_rfc3339(),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO exposure_thresholds (id, target, direction, percent, enabled, created_at)
+            VALUES (?1, ?2, ?3, ?4, 1, ?5)
+            "#,
+        )
+        .bind(&threshold.id)
+        .bind(serde_json::to_string(&threshold.target)?)
+        .bind(serde_json::to_string(&threshold.direction)?)
+        .bind(threshold.percent)
+        .bind(&threshold.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(threshold)
+    }
+
+    pub async fn remove_threshold(&self, id: &str) -> Result<(), ExposureError> {
+        let result = sqlx::query("DELETE FROM exposure_thresholds WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExposureError::NotFound(id.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+fn exposure_db_path(app: &AppHandle) -> Result<PathBuf, ExposureError> {
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| ExposureError::Internal("Unable to resolve app data directory".to_string()))?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(EXPOSURE_DB_FILE))
+}
+
+fn describe_target(target: &ExposureTarget) -> String {
+    match target {
+        ExposureTarget::AssetClass(class) => format!("{:?}", class),
+        ExposureTarget::Custody(custody) => format!("{:?}", custody),
+    }
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn get_exposure_report(
+    app_handle: AppHandle,
+    wallets: State<'_, MultiWalletManager>,
+    operations: State<'_, WalletOperationsManager>,
+    tracker: State<'_, SharedExposureTracker>,
+    router: State<'_, SharedNotificationRouter>,
+    record: Option<bool>,
+) -> Result<ExposureReport, String> {
+    let wallet_list = wallets.list_wallets().map_err(|e| e.to_string())?;
+    let inputs: Vec<(WalletInfo, Vec<TokenBalance>)> = wallet_list
+        .into_iter()
+        .map(|wallet| {
+            let balances = operations.cached_token_balances(&wallet.public_key);
+            (wallet, balances)
+        })
+        .collect();
+
+    let sol_price = native_sol_price(&inputs)?;
+    let tracker = tracker.read().await;
+    let thresholds = tracker.list_thresholds().await.map_err(|e| e.to_string())?;
+    let report = build_exposure_report(&inputs, &thresholds, sol_price);
+
+    if record.unwrap_or(true) {
+        tracker
+            .record_snapshot_if_due(&report)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    let breaches = tracker.take_breaches_to_notify(&report);
+    if !breaches.is_empty() {
+        let _ = app_handle.emit_all(EXPOSURE_ALERT_EVENT, &breaches);
+
+        let router = router.read().await;
+        for breach in &breaches {
+            let condition = format!(
+                "{} allocation {:?} {:.1}%",
+                describe_target(&breach.target),
+                breach.direction,
+                breach.threshold_percent
+            );
+            if let Err(err) = router
+                .send_alert_notification(
+                    &breach.threshold_id,
+                    "Exposure threshold",
+                    &describe_target(&breach.target),
+                    breach.current_percent,
+                    &condition,
+                )
+                .await
+            {
+                tracing::warn!(error = %err, "failed to deliver exposure alert");
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[tauri::command]
+pub async fn get_exposure_history(
+    tracker: State<'_, SharedExposureTracker>,
+    days: Option<i64>,
+) -> Result<Vec<ExposureSnapshot>, String> {
+    let tracker = tracker.read().await;
+    tracker
+        .get_history(days.unwrap_or(30))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_exposure_thresholds(
+    tracker: State<'_, SharedExposureTracker>,
+) -> Result<Vec<ExposureThreshold>, String> {
+    let tracker = tracker.read().await;
+    tracker.list_thresholds().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn add_exposure_threshold(
+    tracker: State<'_, SharedExposureTracker>,
+    input: ExposureThresholdInput,
+) -> Result<ExposureThreshold, String> {
+    let tracker = tracker.read().await;
+    tracker.add_threshold(input).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn remove_exposure_threshold(
+    tracker: State<'_, SharedExposureTracker>,
+    id: String,
+) -> Result<(), String> {
+    let tracker = tracker.read().await;
+    tracker.remove_threshold(&id).await.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::multi_wallet::{PerformanceMetrics, WalletPreferences};
+
+    fn wallet(id: &str, wallet_type: WalletType, balance: f64) -> WalletInfo {
+        WalletInfo {
+            id: id.to_string(),
+            public_key: format!("{}-key", id),
+            label: id.to_string(),
+            network: "solana".to_string(),
+            chain_id: "solana".to_string(),
+            wallet_type,
+            group_id: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_used: None,
+            balance,
+            preferences: WalletPreferences::default(),
+            performance: PerformanceMetrics::default(),
+        }
+    }
+
+    fn token(mint: &str, symbol: &str, usd_value: f64) -> TokenBalance {
+        TokenBalance {
+            mint: mint.to_string(),
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            balance: 10.0,
+            decimals: 6,
+            usd_value,
+            change_24h: 0.0,
+            logo_uri: None,
+            last_updated: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn breaks_down_by_class_and_custody() {
+        let wallets = vec![
+            (
+                wallet("hot", WalletType::Phantom, 0.0),
+                vec![
+                    token(WSOL_MINT, "SOL", 600.0),
+                    token("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "USDC", 50.0),
+                    token("bonk-mint", "BONK", 150.0),
+                ],
+            ),
+            (wallet("ledger", WalletType::HardwareLedger, 2.0), Vec::new()),
+        ];
+
+        let report = build_exposure_report(&wallets, &[], 100.0);
+
+        assert!((report.total_value_usd - 1000.0).abs() < 1e-9);
+        assert!((report.by_asset_class[&AssetClass::Sol].percent - 80.0).abs() < 1e-9);
+        assert!((report.by_asset_class[&AssetClass::Memecoin].value_usd - 150.0).abs() < 1e-9);
+        assert!((report.by_custody[&CustodyType::Hardware].percent - 20.0).abs() < 1e-9);
+        assert!((report.stablecoin_percent - 5.0).abs() < 1e-9);
+        assert_eq!(report.stablecoins.get("USDC"), Some(&50.0));
+    }
+
+    #[test]
+    fn flags_low_stablecoin_allocation() {
+        let wallets = vec![(
+            wallet("hot", WalletType::Phantom, 0.0),
+            vec![
+                token(WSOL_MINT, "SOL", 950.0),
+                token("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "USDC", 50.0),
+            ],
+        )];
+        let threshold = ExposureThreshold {
+            id: "stables".to_string(),
+            target: ExposureTarget::AssetClass(AssetClass::Stablecoin),
+            direction: ThresholdDirection::Below,
+            percent: 10.0,
+            enabled: true,
+            created_at: Utc::now().to_rfc3339(),
+        };
+
+        let report = build_exposure_report(&wallets, &[threshold], 100.0);
+
+        assert_eq!(report.breaches.len(), 1);
+        assert!((report.breaches[0].current_percent - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn open_breaches_notify_once_per_period() {
+        let breach = ExposureBreach {
+            threshold_id: "stables".to_string(),
+            target: ExposureTarget::AssetClass(AssetClass::Stablecoin),
+            direction: ThresholdDirection::Below,
+            threshold_percent: 10.0,
+            current_percent: 5.0,
+        };
+        let breaches = vec![breach];
+        let mut notified = HashMap::new();
+        let now = Utc::now();
+
+        assert_eq!(breaches_to_notify(&mut notified, &breaches, now).len(), 1);
+        let soon = now + Duration::minutes(5);
+        assert!(breaches_to_notify(&mut notified, &breaches, soon).is_empty());
+        let later = now + Duration::hours(BREACH_RENOTIFY_HOURS);
+        assert_eq!(breaches_to_notify(&mut notified, &breaches, later).len(), 1);
+
+        // Clearing forgets the breach, so reopening notifies right away
+        assert!(breaches_to_notify(&mut notified, &[], later).is_empty());
+        assert_eq!(breaches_to_notify(&mut notified, &breaches, later).len(), 1);
+    }
+}
@@ -0,0 +1,329 @@
+use chrono::Utc;
+use futures_util::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::State;
+
+use crate::api::jupiter::{JupiterClient, QuoteCommandInput, SwapMode};
+use crate::portfolio::exposure::{LiquidationSnapshot, SharedExposureTracker};
+use crate::wallet::multi_wallet::MultiWalletManager;
+use crate::wallet::operations::{TokenBalance, WalletOperationsManager};
+
+const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+const USDC_DECIMALS: i32 = 6;
+/// Stablecoins realize their face value without a trade.
+const USD_STABLE_MINTS: &[&str] = &[
+    USDC_MINT,
+    "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB",
+    "2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo",
+];
+/// Holdings below this are not worth a quote and are counted at mark.
+const DUST_VALUE_USD: f64 = 1.0;
+const QUOTE_SLIPPAGE_BPS: u16 = 100;
+/// Quotes requested at once, to stay inside Jupiter's rate limits.
+const MAX_CONCURRENT_QUOTES: usize = 4;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum LiquidationMethod {
+    /// USD stablecoin, realized at face value.
+    Stable,
+    /// Priced from a route quote for the whole balance.
+    Quoted,
+    /// Dust, counted at its mark value.
+    Mark,
+    /// No route could absorb the balance; counted as zero.
+    Unroutable,
+    /// The quote request failed; counted at mark, so the estimate is incomplete.
+    Unquoted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenLiquidation {
+    pub mint: String,
+    pub symbol: String,
+    pub balance: f64,
+    pub mark_value_usd: f64,
+    pub liquidation_value_usd: f64,
+    pub price_impact_percent: f64,
+    pub method: LiquidationMethod,
+    pub error: Option<String>,
+}
+
+impl TokenLiquidation {
+    pub fn slippage_percent(&self) -> f64 {
+        if self.mark_value_usd <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - self.liquidation_value_usd / self.mark_value_usd) * 100.0
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidationEstimate {
+    pub mark_value_usd: f64,
+    pub liquidation_value_usd: f64,
+    /// Value lost to price impact and fees if everything were sold now.
+    pub haircut_usd: f64,
+    pub haircut_percent: f64,
+    /// False when a quote request failed and some holdings are only at mark.
+    pub complete: bool,
+    pub tokens: Vec<TokenLiquidation>,
+    pub generated_at: String,
+}
+
+impl LiquidationEstimate {
+    pub fn from_tokens(mut tokens: Vec<TokenLiquidation>) -> Self {
+        tokens.sort_by(|a, b| {
+            let loss = |t: &TokenLiquidation| t.mark_value_usd - t.liquidation_value_usd;
+            loss(b).total_cmp(&loss(a))
+        });
+        let mark_value_usd: f64 = tokens.iter().map(|t| t.mark_value_usd).sum();
+        let liquidation_value_usd: f64 = tokens.iter().map(|t| t.liquidation_value_usd).sum();
+        let haircut_usd = mark_value_usd - liquidation_value_usd;
+        let haircut_percent = if mark_value_usd > 0.0 {
+            haircut_usd / mark_value_usd * 100.0
+        } else {
+            0.0
+        };
+        let complete = tokens
+            .iter()
+            .all(|t| t.method != LiquidationMethod::Unquoted);
+
+        Self {
+            mark_value_usd,
+            liquidation_value_usd,
+            haircut_usd,
+            haircut_percent,
+            complete,
+            tokens,
+            generated_at: Utc::now().to_rfc3339(),
+        }
+    }
+
+    pub fn snapshot(&self) -> LiquidationSnapshot {
+        LiquidationSnapshot {
+            timestamp: self.generated_at.clone(),
+            mark_value_usd: self.mark_value_usd,
+            liquidation_value_usd: self.liquidation_value_usd,
+        }
+    }
+}
+
+/// Merges the same mint across wallets, since selling everything means
+/// selling the combined balance in one go.
+pub fn combine_holdings(balances: impl IntoIterator<Item = TokenBalance>) -> Vec<TokenBalance> {
+    let mut by_mint: HashMap<String, TokenBalance> = HashMap::new();
+    for token in balances {
+        match by_mint.get_mut(&token.mint) {
+            Some(existing) => {
+                existing.balance += token.balance;
+                existing.usd_value += token.usd_value;
+            }
+            None => {
+                by_mint.insert(token.mint.clone(), token);
+            }
+        }
+    }
+    by_mint
+        .into_values()
+        .filter(|token| token.balance > 0.0)
+        .collect()
+}
+
+async fn estimate_token(token: TokenBalance) -> TokenLiquidation {
+    let mark_value_usd = token.usd_value.max(0.0);
+    let mut result = TokenLiquidation {
+        mint: token.mint.clone(),
+        symbol: token.symbol.clone(),
+        balance: token.balance,
+        mark_value_usd,
+        liquidation_value_usd: mark_value_usd,
+        price_impact_percent: 0.0,
+        method: LiquidationMethod::Mark,
+        error: None,
+    };
+
+    if USD_STABLE_MINTS.contains(&token.mint.as_str()) {
+        result.liquidation_value_usd = token.balance;
+        result.method = LiquidationMethod::Stable;
+        return result;
+    }
+    if mark_value_usd < DUST_VALUE_USD {
+        return result;
+    }
+
+    let amount = (token.balance * 10f64.powi(token.decimals as i32)).floor() as u64;
+    let input = QuoteCommandInput {
+        input_mint: token.mint.clone(),
+        output_mint: USDC_MINT.to_string(),
+        amount,
+        slippage_bps: Some(QUOTE_SLIPPAGE_BPS),
+        swap_mode: Some(SwapMode::ExactIn),
+        platform_fee_bps: None,
+        only_direct_routes: None,
+        referral_account: None,
+        as_legacy_transaction: None,
+        priority_fee_config: None,
+    };
+
+    match JupiterClient::default().quote(&input).await {
+        Ok(quote) => match quote.output_amount.parse::<f64>() {
+            Ok(output) => {
+                result.liquidation_value_usd = output / 10f64.powi(USDC_DECIMALS);
+                result.price_impact_percent = quote.price_impact_pct * 100.0;
+                result.method = LiquidationMethod::Quoted;
+            }
+            Err(_) => {
+                result.method = LiquidationMethod::Unquoted;
+                result.error = Some("Invalid quote output amount".to_string());
+            }
+        },
+        Err(err) if err.is_no_route() => {
+            result.liquidation_value_usd = 0.0;
+            result.method = LiquidationMethod::Unroutable;
+            result.error = Some(err.to_string());
+        }
+        Err(err) => {
+            result.method = LiquidationMethod::Unquoted;
+            result.error = Some(err.to_string());
+        }
+    }
+    result
+}
+
+/// What the portfolio (or one wallet) would realize in USDC if every holding
+/// were sold now, priced from live route quotes rather than mid prices.
+#[tauri::command]
+pub async fn get_liquidation_value(
+    wallets: State<'_, MultiWalletManager>,
+    operations: State<'_, WalletOperationsManager>,
+    tracker: State<'_, SharedExposureTracker>,
+    wallet: Option<String>,
+    record: Option<bool>,
+) -> Result<LiquidationEstimate, String> {
+    // Single-wallet estimates stay out of the history unless asked for.
+    let record = record.unwrap_or(wallet.is_none());
+    let addresses: Vec<String> = match wallet {
+        Some(address) => vec![address],
+        None => wallets
+            .list_wallets()
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|wallet| wallet.public_key)
+            .collect(),
+    };
+    let holdings = combine_holdings(
+        addresses
+            .iter()
+            .flat_map(|address| operations.cached_token_balances(address)),
+    );
+
+    let tokens = stream::iter(holdings.into_iter().map(estimate_token))
+        .buffer_unordered(MAX_CONCURRENT_QUOTES)
+        .collect()
+        .await;
+    let estimate = LiquidationEstimate::from_tokens(tokens);
+
+    // A partial estimate would show up in the history as a jump in liquidity
+    if record && estimate.complete {
+        tracker
+            .read()
+            .await
+            .record_liquidation_snapshot(&estimate.snapshot())
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(estimate)
+}
+
+#[tauri::command]
+pub async fn get_liquidation_history(
+    tracker: State<'_, SharedExposureTracker>,
+    days: Option<i64>,
+) -> Result<Vec<LiquidationSnapshot>, String> {
+    let tracker = tracker.read().await;
+    tracker
+        .get_liquidation_history(days.unwrap_or(30))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn balance(mint: &str, amount: f64, usd: f64) -> TokenBalance {
+        TokenBalance {
+            mint: mint.to_string(),
+            symbol: mint.to_uppercase(),
+            name: mint.to_string(),
+            balance: amount,
+            decimals: 6,
+            usd_value: usd,
+            change_24h: 0.0,
+            logo_uri: None,
+            last_updated: Utc::now(),
+        }
+    }
+
+    fn liquidation(mark: f64, realized: f64, method: LiquidationMethod) -> TokenLiquidation {
+        TokenLiquidation {
+            mint: "mint".to_string(),
+            symbol: "TKN".to_string(),
+            balance: 1.0,
+            mark_value_usd: mark,
+            liquidation_value_usd: realized,
+            price_impact_percent: 0.0,
+            method,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn combines_the_same_mint_across_wallets() {
+        let combined = combine_holdings(vec![
+            balance("bonk", 100.0, 10.0),
+            balance("bonk", 50.0, 5.0),
+            balance("wif", 0.0, 0.0),
+        ]);
+        assert_eq!(combined.len(), 1);
+        assert!((combined[0].balance - 150.0).abs() < 1e-9);
+        assert!((combined[0].usd_value - 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn haircut_is_mark_minus_realizable() {
+        let estimate = LiquidationEstimate::from_tokens(vec![
+            liquidation(1_000.0, 1_000.0, LiquidationMethod::Stable),
+            liquidation(500.0, 450.0, LiquidationMethod::Quoted),
+            liquidation(200.0, 0.0, LiquidationMethod::Unroutable),
+        ]);
+        assert!((estimate.mark_value_usd - 1_700.0).abs() < 1e-9);
+        assert!((estimate.liquidation_value_usd - 1_450.0).abs() < 1e-9);
+        assert!((estimate.haircut_percent - 250.0 / 1_700.0 * 100.0).abs() < 1e-9);
+        assert_eq!(estimate.tokens[0].method, LiquidationMethod::Unroutable);
+        assert!(estimate.complete);
+
+        let estimate = LiquidationEstimate::from_tokens(vec![
+            liquidation(500.0, 450.0, LiquidationMethod::Quoted),
+            liquidation(200.0, 200.0, LiquidationMethod::Unquoted),
+        ]);
+        assert!(!estimate.complete);
+    }
+
+    #[test]
+    fn slippage_is_relative_to_mark() {
+        assert!(
+            (liquidation(500.0, 450.0, LiquidationMethod::Quoted).slippage_percent() - 10.0).abs()
+                < 1e-9
+        );
+        assert_eq!(
+            liquidation(0.0, 0.0, LiquidationMethod::Mark).slippage_percent(),
+            0.0
+        );
+    }
+}
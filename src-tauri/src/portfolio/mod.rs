@@ -1,13 +1,23 @@
 pub mod ai_advisor;
 pub mod analytics;
+pub mod dust;
+pub mod exposure;
+pub mod liquidation;
+pub mod pnl_calendar;
 pub mod rebalancer;
+pub mod sharing;
 pub mod tax_lots;
 pub mod types;
 pub mod watchlists;
 
 pub use ai_advisor::*;
 pub use analytics::*;
+pub use dust::*;
+pub use exposure::*;
+pub use liquidation::*;
+pub use pnl_calendar::*;
 pub use rebalancer::*;
+pub use sharing::*;
 pub use tax_lots::*;
 pub use types::*;
 pub use watchlists::*;
@@ -0,0 +1,500 @@
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, Timelike, Utc, Weekday,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+use crate::data::event_store::{Event, EventFilter, EventRecord, SharedEventStore};
+use crate::notifications::router::SharedNotificationRouter;
+
+const POSITION_CLOSED_EVENT: &str = "position_closed";
+const MAX_CALENDAR_EVENTS: i64 = 20_000;
+const DEFAULT_CALENDAR_DAYS: i64 = 365;
+const RECAP_CHECK_INTERVAL_SECS: u64 = 60 * 60;
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// One realized P&L booking, read from a `PositionClosed` event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RealizedPnlEntry {
+    pub position_id: String,
+    pub symbol: Option<String>,
+    pub pnl: f64,
+    pub closed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PnlCalendarDay {
+    pub date: NaiveDate,
+    pub realized_pnl: f64,
+    pub trades: usize,
+    pub wins: usize,
+    pub losses: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PnlCalendarWeek {
+    /// Monday of the week.
+    pub week_start: NaiveDate,
+    pub realized_pnl: f64,
+    pub trades: usize,
+    pub trading_days: usize,
+    pub green_days: usize,
+}
+
+/// Realized P&L grouped by weekday or hour of day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PnlTimeBucket {
+    pub label: String,
+    pub realized_pnl: f64,
+    pub trades: usize,
+    pub wins: usize,
+    pub average_pnl: f64,
+}
+
+/// Streaks count trading days in a row; a flat day ends any streak.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PnlStreaks {
+    /// Positive for a run of green days, negative for red days.
+    pub current: i32,
+    pub longest_win: u32,
+    pub longest_loss: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PnlCalendar {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub utc_offset_minutes: i32,
+    pub total_realized_pnl: f64,
+    pub trades: usize,
+    pub days: Vec<PnlCalendarDay>,
+    pub weeks: Vec<PnlCalendarWeek>,
+    pub best_day: Option<PnlCalendarDay>,
+    pub worst_day: Option<PnlCalendarDay>,
+    pub streaks: PnlStreaks,
+    pub weekdays: Vec<PnlTimeBucket>,
+    pub hours: Vec<PnlTimeBucket>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PnlRecapState {
+    last_week_start: Option<NaiveDate>,
+}
+
+pub fn realized_pnl_entries(records: &[EventRecord]) -> Vec<RealizedPnlEntry> {
+    let mut entries: Vec<RealizedPnlEntry> = records
+        .iter()
+        .filter_map(
+            |record| match serde_json::from_str(&record.event_data).ok()? {
+                Event::PositionClosed {
+                    position_id,
+                    symbol,
+                    pnl,
+                    timestamp,
+                    ..
+                } if pnl.is_finite() => Some(RealizedPnlEntry {
+                    position_id,
+                    symbol,
+                    pnl,
+                    closed_at: timestamp,
+                }),
+                _ => None,
+            },
+        )
+        .collect();
+    entries.sort_by_key(|entry| entry.closed_at);
+    entries
+}
+
+fn time_bucket(label: String, entries: &[&RealizedPnlEntry]) -> PnlTimeBucket {
+    let realized_pnl: f64 = entries.iter().map(|entry| entry.pnl).sum();
+    PnlTimeBucket {
+        label,
+        realized_pnl,
+        trades: entries.len(),
+        wins: entries.iter().filter(|entry| entry.pnl > 0.0).count(),
+        average_pnl: if entries.is_empty() {
+            0.0
+        } else {
+            realized_pnl / entries.len() as f64
+        },
+    }
+}
+
+pub fn pnl_streaks(days: &[PnlCalendarDay]) -> PnlStreaks {
+    let mut streaks = PnlStreaks::default();
+    for day in days {
+        streaks.current = match day.realized_pnl {
+            pnl if pnl > 0.0 => streaks.current.max(0) + 1,
+            pnl if pnl < 0.0 => streaks.current.min(0) - 1,
+            _ => 0,
+        };
+        if streaks.current > 0 {
+            streaks.longest_win = streaks.longest_win.max(streaks.current as u32);
+        } else {
+            streaks.longest_loss = streaks.longest_loss.max(streaks.current.unsigned_abs());
+        }
+    }
+    streaks
+}
+
+/// Groups realized P&L by local calendar day, where local is `offset` from UTC.
+pub fn build_pnl_calendar(
+    entries: &[RealizedPnlEntry],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    offset: FixedOffset,
+) -> PnlCalendar {
+    let entries: Vec<&RealizedPnlEntry> = entries
+        .iter()
+        .filter(|entry| entry.closed_at >= from && entry.closed_at < to)
+        .collect();
+    let local = |entry: &RealizedPnlEntry| entry.closed_at.with_timezone(&offset);
+
+    let mut by_day: BTreeMap<NaiveDate, PnlCalendarDay> = BTreeMap::new();
+    for entry in &entries {
+        let date = local(entry).date_naive();
+        let day = by_day.entry(date).or_insert_with(|| PnlCalendarDay {
+            date,
+            ..PnlCalendarDay::default()
+        });
+        day.realized_pnl += entry.pnl;
+        day.trades += 1;
+        if entry.pnl > 0.0 {
+            day.wins += 1;
+        } else if entry.pnl < 0.0 {
+            day.losses += 1;
+        }
+    }
+    let days: Vec<PnlCalendarDay> = by_day.into_values().collect();
+
+    let mut by_week: BTreeMap<NaiveDate, PnlCalendarWeek> = BTreeMap::new();
+    for day in &days {
+        let week_start =
+            day.date - Duration::days(day.date.weekday().num_days_from_monday() as i64);
+        let week = by_week.entry(week_start).or_insert(PnlCalendarWeek {
+            week_start,
+            realized_pnl: 0.0,
+            trades: 0,
+            trading_days: 0,
+            green_days: 0,
+        });
+        week.realized_pnl += day.realized_pnl;
+        week.trades += day.trades;
+        week.trading_days += 1;
+        if day.realized_pnl > 0.0 {
+            week.green_days += 1;
+        }
+    }
+
+    let weekdays = WEEKDAYS
+        .iter()
+        .map(|weekday| {
+            let matching: Vec<_> = entries
+                .iter()
+                .copied()
+                .filter(|entry| local(entry).weekday() == *weekday)
+                .collect();
+            time_bucket(weekday.to_string(), &matching)
+        })
+        .collect();
+    let hours = (0..24)
+        .map(|hour| {
+            let matching: Vec<_> = entries
+                .iter()
+                .copied()
+                .filter(|entry| local(entry).hour() == hour)
+                .collect();
+            time_bucket(format!("{:02}:00", hour), &matching)
+        })
+        .collect();
+
+    let by_pnl =
+        |a: &&PnlCalendarDay, b: &&PnlCalendarDay| a.realized_pnl.total_cmp(&b.realized_pnl);
+    PnlCalendar {
+        from,
+        to,
+        utc_offset_minutes: offset.local_minus_utc() / 60,
+        total_realized_pnl: entries.iter().map(|entry| entry.pnl).sum(),
+        trades: entries.len(),
+        best_day: days
+            .iter()
+            .max_by(by_pnl)
+            .filter(|day| day.realized_pnl > 0.0)
+            .cloned(),
+        worst_day: days
+            .iter()
+            .min_by(by_pnl)
+            .filter(|day| day.realized_pnl < 0.0)
+            .cloned(),
+        streaks: pnl_streaks(&days),
+        weeks: by_week.into_values().collect(),
+        days,
+        weekdays,
+        hours,
+    }
+}
+
+/// One-line summary for the weekly recap notification.
+pub fn weekly_pnl_recap(calendar: &PnlCalendar) -> String {
+    let green_days = calendar
+        .days
+        .iter()
+        .filter(|day| day.realized_pnl > 0.0)
+        .count();
+    let mut recap = format!(
+        "Realized {}${:.2} across {} trade{}, {} of {} trading days green",
+        if calendar.total_realized_pnl < 0.0 {
+            "-"
+        } else {
+            "+"
+        },
+        calendar.total_realized_pnl.abs(),
+        calendar.trades,
+        if calendar.trades == 1 { "" } else { "s" },
+        green_days,
+        calendar.days.len()
+    );
+    if let Some(best) = &calendar.best_day {
+        recap.push_str(&format!(
+            ". Best day {} +${:.2}",
+            best.date.weekday(),
+            best.realized_pnl
+        ));
+    }
+    if let Some(worst) = &calendar.worst_day {
+        recap.push_str(&format!(
+            ", worst {} -${:.2}",
+            worst.date.weekday(),
+            worst.realized_pnl.abs()
+        ));
+    }
+    recap
+}
+
+fn utc_offset(minutes: i32) -> Result<FixedOffset, String> {
+    FixedOffset::east_opt(minutes * 60).ok_or_else(|| "UTC offset is out of range".to_string())
+}
+
+pub async fn load_pnl_calendar(
+    app: &AppHandle,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    offset: FixedOffset,
+) -> Result<PnlCalendar, String> {
+    let store = app
+        .try_state::<SharedEventStore>()
+        .ok_or_else(|| "Event store unavailable".to_string())?;
+    let records = store
+        .read()
+        .await
+        .get_events(EventFilter {
+            aggregate_id: None,
+            event_type: Some(POSITION_CLOSED_EVENT.to_string()),
+            from_time: Some(from),
+            to_time: Some(to),
+            limit: Some(MAX_CALENDAR_EVENTS),
+            offset: None,
+            // Browsing back to an archived month asks for it explicitly
+            include_archived: true,
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(build_pnl_calendar(
+        &realized_pnl_entries(&records),
+        from,
+        to,
+        offset,
+    ))
+}
+
+fn recap_state_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_data_dir()
+        .map(|dir| dir.join("settings").join("pnl_recap.json"))
+}
+
+fn load_recap_state(app: &AppHandle) -> PnlRecapState {
+    recap_state_path(app)
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_recap_state(app: &AppHandle, state: &PnlRecapState) -> Result<(), String> {
+    let path = recap_state_path(app).ok_or("Failed to resolve app data directory")?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let content = serde_json::to_string_pretty(state).map_err(|e| e.to_string())?;
+    std::fs::write(path, content).map_err(|e| e.to_string())
+}
+
+/// Sends last week's recap once, the first time the app runs after the week ends.
+async fn send_weekly_recap(app: &AppHandle) -> Result<(), String> {
+    let today = Utc::now().date_naive();
+    let this_week = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let last_week = this_week - Duration::days(7);
+
+    let mut state = load_recap_state(app);
+    if state
+        .last_week_start
+        .map_or(false, |sent| sent >= last_week)
+    {
+        return Ok(());
+    }
+
+    let start = |date: NaiveDate| date.and_time(NaiveTime::MIN).and_utc();
+    let calendar =
+        load_pnl_calendar(app, start(last_week), start(this_week), utc_offset(0)?).await?;
+    if calendar.trades > 0 {
+        let _ = app.emit_all("weekly_pnl_recap", &calendar);
+        if let Some(router) = app.try_state::<SharedNotificationRouter>() {
+            router
+                .read()
+                .await
+                .send_broadcast_notification(
+                    &format!("pnl_recap_{}", last_week),
+                    &format!("Weekly recap: week of {}", last_week.format("%b %-d")),
+                    &weekly_pnl_recap(&calendar),
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    state.last_week_start = Some(last_week);
+    save_recap_state(app, &state)
+}
+
+pub fn start_weekly_pnl_recap(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(RECAP_CHECK_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            if let Err(err) = send_weekly_recap(&app).await {
+                tracing::warn!(error = %err, "failed to send weekly P&L recap");
+            }
+        }
+    });
+}
+
+/// Realized P&L calendar for `[from, to)`, grouped in the caller's time zone.
+/// Defaults to the last year.
+#[tauri::command]
+pub async fn get_pnl_calendar(
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    utc_offset_minutes: Option<i32>,
+    app: AppHandle,
+) -> Result<PnlCalendar, String> {
+    let to = to.unwrap_or_else(Utc::now);
+    let from = from.unwrap_or(to - Duration::days(DEFAULT_CALENDAR_DAYS));
+    if from >= to {
+        return Err("Calendar start must be before its end".to_string());
+    }
+    load_pnl_calendar(&app, from, to, utc_offset(utc_offset_minutes.unwrap_or(0))?).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn entry(day: u32, hour: u32, pnl: f64) -> RealizedPnlEntry {
+        RealizedPnlEntry {
+            position_id: format!("p{day}{hour}"),
+            symbol: Some("SOL".to_string()),
+            pnl,
+            // March 2026 starts on a Sunday
+            closed_at: Utc.with_ymd_and_hms(2026, 3, day, hour, 0, 0).unwrap(),
+        }
+    }
+
+    fn calendar(entries: &[RealizedPnlEntry], offset_hours: i32) -> PnlCalendar {
+        build_pnl_calendar(
+            entries,
+            Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap(),
+            FixedOffset::east_opt(offset_hours * 3600).unwrap(),
+        )
+    }
+
+    #[test]
+    fn days_weeks_and_extremes() {
+        let entries = vec![
+            entry(2, 10, 50.0),
+            entry(2, 15, -20.0),
+            entry(3, 9, -40.0),
+            entry(9, 14, 100.0),
+        ];
+        let calendar = calendar(&entries, 0);
+
+        assert_eq!(calendar.trades, 4);
+        assert!((calendar.total_realized_pnl - 90.0).abs() < 1e-9);
+        assert_eq!(calendar.days.len(), 3);
+        assert_eq!((calendar.days[0].wins, calendar.days[0].losses), (1, 1));
+        assert_eq!(calendar.weeks.len(), 2);
+        assert_eq!(
+            calendar.weeks[0].week_start,
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()
+        );
+        assert_eq!(calendar.weeks[0].green_days, 1);
+        assert_eq!(calendar.best_day.as_ref().unwrap().realized_pnl, 100.0);
+        assert_eq!(calendar.worst_day.as_ref().unwrap().realized_pnl, -40.0);
+
+        assert_eq!(calendar.weekdays[0].label, "Mon");
+        assert_eq!(calendar.weekdays[0].trades, 3);
+        assert_eq!(calendar.hours[14].realized_pnl, 100.0);
+    }
+
+    #[test]
+    fn streaks_track_runs_of_trading_days() {
+        let days: Vec<PnlCalendarDay> = [10.0, 5.0, 7.0, -1.0, -2.0, 0.0, -3.0]
+            .iter()
+            .enumerate()
+            .map(|(i, pnl)| PnlCalendarDay {
+                date: NaiveDate::from_ymd_opt(2026, 3, 1 + i as u32).unwrap(),
+                realized_pnl: *pnl,
+                ..PnlCalendarDay::default()
+            })
+            .collect();
+
+        let streaks = pnl_streaks(&days);
+        assert_eq!(streaks.longest_win, 3);
+        assert_eq!(streaks.longest_loss, 2);
+        assert_eq!(streaks.current, -1);
+        assert_eq!(pnl_streaks(&days[..3]).current, 3);
+    }
+
+    #[test]
+    fn local_offset_moves_late_trades_to_the_next_day() {
+        // 23:00 UTC on Monday is Tuesday morning in UTC+2
+        let entries = vec![entry(2, 23, 30.0)];
+        assert_eq!(calendar(&entries, 0).days[0].date.weekday(), Weekday::Mon);
+
+        let shifted = calendar(&entries, 2);
+        assert_eq!(shifted.days[0].date.weekday(), Weekday::Tue);
+        assert_eq!(shifted.hours[1].trades, 1);
+        assert_eq!(shifted.utc_offset_minutes, 120);
+        assert!(weekly_pnl_recap(&shifted).starts_with("Realized +$30.00 across 1 trade,"));
+    }
+}
@@ -3,11 +3,14 @@ use std::sync::Mutex;
 
 use chrono::{DateTime, Duration, Utc};
 use serde::Deserialize;
-use tauri::State;
+use tauri::{AppHandle, State};
 
+use crate::config::version_history::{record_config_change, ConfigEntity, LOCAL_ACTOR};
+
+use super::exposure::classify_mint;
 use super::types::{
-    AllocationTarget, PortfolioMetrics, Position, RebalanceAction, RebalanceHistory,
-    RebalanceProfile,
+    AllocationTarget, AssetClassTarget, PortfolioMetrics, Position, RebalanceAction,
+    RebalanceHistory, RebalanceProfile,
 };
 
 #[derive(Debug)]
@@ -43,20 +46,25 @@ impl Default for RebalancerState {
                 AllocationTarget {
                     symbol: "SOL".to_string(),
                     target_percent: 40.0,
+                    ..Default::default()
                 },
                 AllocationTarget {
                     symbol: "BTC".to_string(),
                     target_percent: 30.0,
+                    ..Default::default()
                 },
                 AllocationTarget {
                     symbol: "ETH".to_string(),
                     target_percent: 20.0,
+                    ..Default::default()
                 },
                 AllocationTarget {
                     symbol: "USDC".to_string(),
                     target_percent: 10.0,
+                    ..Default::default()
                 },
             ],
+            class_targets: Vec::new(),
             deviation_trigger_percent: 5.0,
             time_interval_hours: Some(168),
             enabled: true,
@@ -81,14 +89,27 @@ impl RebalancerState {
             .collect::<Vec<_>>()
     }
 
-    fn upsert_profile(&mut self, profile: RebalanceProfile) -> RebalanceProfile {
+    pub(crate) fn upsert_profile(&mut self, profile: RebalanceProfile) -> RebalanceProfile {
         let id = profile.id.clone();
         self.profiles
             .insert(id.clone(), ProfileState::new(profile.clone()));
         profile
     }
 
-    fn remove_profile(&mut self, profile_id: &str) -> bool {
+    /// Writes a saved definition back. A live profile keeps its schedule position
+    /// and notification throttle; only the config fields are replaced.
+    pub(crate) fn restore_profile(&mut self, profile: RebalanceProfile) -> RebalanceProfile {
+        match self.profiles.get_mut(&profile.id) {
+            Some(existing) => existing.profile = profile.clone(),
+            None => {
+                self.profiles
+                    .insert(profile.id.clone(), ProfileState::new(profile.clone()));
+            }
+        }
+        profile
+    }
+
+    pub(crate) fn remove_profile(&mut self, profile_id: &str) -> bool {
         self.profiles.remove(profile_id).is_some()
     }
 
@@ -124,81 +145,96 @@ impl Default for PortfolioDataState {
 }
 
 impl PortfolioDataState {
+    /// Starts from the demo portfolio in demo mode and empty otherwise; real
+    /// holdings are never invented.
     pub fn new() -> Self {
-        let positions = Self::default_positions();
+        Self::with_positions(Self::seed_positions())
+    }
+
+    pub fn with_positions(positions: Vec<Position>) -> Self {
         let mut state = Self {
             metrics: Self::baseline_metrics(),
             positions,
         };
+        state.reset_pnl_percentages();
         state.recalculate();
         state
     }
 
+    /// Re-seeds holdings after demo mode is switched, keeping the lending and
+    /// bridge figures reported by their monitors.
+    pub fn reseed(&mut self) {
+        self.positions = Self::seed_positions();
+        self.metrics.realized_pnl = 0.0;
+        self.reset_pnl_percentages();
+        self.recalculate();
+    }
+
     fn baseline_metrics() -> PortfolioMetrics {
-        let now = Utc::now().to_rfc3339();
         PortfolioMetrics {
             total_value: 0.0,
             daily_pnl: 0.0,
-            daily_pnl_percent: 1.2,
+            daily_pnl_percent: 0.0,
             weekly_pnl: 0.0,
-            weekly_pnl_percent: 4.1,
+            weekly_pnl_percent: 0.0,
             monthly_pnl: 0.0,
-            monthly_pnl_percent: 12.4,
+            monthly_pnl_percent: 0.0,
             all_time_pnl: 0.0,
-            all_time_pnl_percent: 28.7,
-            realized_pnl: 14850.0,
+            all_time_pnl_percent: 0.0,
+            realized_pnl: 0.0,
             unrealized_pnl: 0.0,
-            last_updated: now,
+            last_updated: Utc::now().to_rfc3339(),
+            lending_collateral: 0.0,
+            lending_debt: 0.0,
+            net_lending_exposure: 0.0,
+            bridge_in_transit: 0.0,
         }
     }
 
-    fn default_positions() -> Vec<Position> {
-        vec![
-            Position {
-                symbol: "SOL".to_string(),
-                mint: "So11111111111111111111111111111111111111112".to_string(),
-                amount: 320.0,
-                current_price: 175.4,
-                avg_entry_price: 142.0,
-                total_value: 0.0,
-                unrealized_pnl: 0.0,
-                unrealized_pnl_percent: 0.0,
-                allocation: 0.0,
-            },
-            Position {
-                symbol: "BTC".to_string(),
-                mint: "11111111111111111111111111111111".to_string(),
-                amount: 2.6,
-                current_price: 64000.0,
-                avg_entry_price: 42800.0,
-                total_value: 0.0,
-                unrealized_pnl: 0.0,
-                unrealized_pnl_percent: 0.0,
-                allocation: 0.0,
-            },
-            Position {
-                symbol: "ETH".to_string(),
-                mint: "22222222222222222222222222222222".to_string(),
-                amount: 35.0,
-                current_price: 3400.0,
-                avg_entry_price: 2600.0,
-                total_value: 0.0,
-                unrealized_pnl: 0.0,
-                unrealized_pnl_percent: 0.0,
-                allocation: 0.0,
-            },
-            Position {
-                symbol: "USDC".to_string(),
-                mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
-                amount: 85000.0,
-                current_price: 1.0,
-                avg_entry_price: 1.0,
+    fn seed_positions() -> Vec<Position> {
+        if !crate::demo::is_demo_mode() {
+            return Vec::new();
+        }
+
+        crate::demo::demo_portfolio()
+            .into_iter()
+            .map(|position| Position {
+                mint: crate::demo::find_demo_token(&position.symbol)
+                    .map(|token| token.address.to_string())
+                    .unwrap_or_default(),
+                symbol: position.symbol,
+                amount: position.amount,
+                current_price: position.current_price,
+                avg_entry_price: position.average_price,
                 total_value: 0.0,
                 unrealized_pnl: 0.0,
                 unrealized_pnl_percent: 0.0,
                 allocation: 0.0,
-            },
-        ]
+            })
+            .collect()
+    }
+
+    /// Only the daily change is known, from the demo price curve; longer windows
+    /// stay at zero rather than being made up.
+    fn reset_pnl_percentages(&mut self) {
+        let (weighted_change, total_value) = self
+            .positions
+            .iter()
+            .filter_map(|position| {
+                let token = crate::demo::find_demo_token(&position.symbol)?;
+                let value = position.amount * position.current_price;
+                Some((crate::demo::demo_change_24h(token) * value, value))
+            })
+            .fold((0.0, 0.0), |(change, total), (c, v)| (change + c, total + v));
+
+        self.metrics.daily_pnl_percent = if total_value > f64::EPSILON {
+            weighted_change / total_value
+        } else {
+            0.0
+        };
+        self.metrics.weekly_pnl_percent = 0.0;
+        self.metrics.monthly_pnl_percent = 0.0;
+        self.metrics.all_time_pnl_percent = 0.0;
     }
 
     pub fn metrics(&self) -> PortfolioMetrics {
@@ -209,6 +245,18 @@ impl PortfolioDataState {
         self.positions.clone()
     }
 
+    /// Collateral and debt held on lending markets, reported by the lending monitor.
+    pub fn set_lending_exposure(&mut self, collateral: f64, debt: f64) {
+        self.metrics.lending_collateral = collateral;
+        self.metrics.lending_debt = debt;
+        self.metrics.net_lending_exposure = collateral - debt;
+    }
+
+    /// Value of assets in flight on a bridge, reported by the bridge tracker.
+    pub fn set_bridge_in_transit(&mut self, value: f64) {
+        self.metrics.bridge_in_transit = value;
+    }
+
     pub fn recalculate(&mut self) {
         let total_value: f64 = self
             .positions
@@ -298,6 +346,8 @@ pub struct RebalanceProfileInput {
     pub id: Option<String>,
     pub name: String,
     pub targets: Vec<AllocationTarget>,
+    #[serde(rename = "classTargets", default)]
+    pub class_targets: Vec<AssetClassTarget>,
     #[serde(rename = "deviationTriggerPercent")]
     pub deviation_trigger_percent: f64,
     #[serde(rename = "timeIntervalHours")]
@@ -305,24 +355,103 @@ pub struct RebalanceProfileInput {
     pub enabled: bool,
 }
 
+/// Spreads `delta` percentage points across `members` in proportion to their
+/// current weight, or evenly when they have none.
+fn spread_class_delta(desired: &mut [f64], members: &[usize], delta: f64) {
+    if members.is_empty() {
+        return;
+    }
+    let weight: f64 = members.iter().map(|&i| desired[i]).sum();
+    for &i in members {
+        let share = if weight > f64::EPSILON {
+            desired[i] / weight
+        } else {
+            1.0 / members.len() as f64
+        };
+        desired[i] = (desired[i] + delta * share).max(0.0);
+    }
+}
+
+/// Target allocation per position. Token bands come first, asset-class bounds
+/// override them, and "do not sell" tokens are never planned below their
+/// current weight. Whatever a class bound adds or removes is offset across the
+/// sellable positions outside every bounded class, so targets still sum to 100%.
+fn desired_allocations(profile: &RebalanceProfile, positions: &[Position]) -> Vec<f64> {
+    let target_lookup: HashMap<&str, &AllocationTarget> = profile
+        .targets
+        .iter()
+        .map(|t| (t.symbol.as_str(), t))
+        .collect();
+    let locked: Vec<bool> = positions
+        .iter()
+        .map(|p| {
+            target_lookup
+                .get(p.symbol.as_str())
+                .map(|t| t.do_not_sell)
+                .unwrap_or(false)
+        })
+        .collect();
+
+    let mut desired: Vec<f64> = positions
+        .iter()
+        .map(|position| {
+            let (low, high) = target_lookup
+                .get(position.symbol.as_str())
+                .map(|t| t.band())
+                .unwrap_or((0.0, 0.0));
+            position.allocation.clamp(low, high)
+        })
+        .collect();
+
+    let classes: Vec<_> = positions
+        .iter()
+        .map(|p| classify_mint(&p.mint, &p.symbol))
+        .collect();
+    let funding: Vec<usize> = (0..positions.len())
+        .filter(|&i| {
+            !locked[i]
+                && !profile
+                    .class_targets
+                    .iter()
+                    .any(|t| t.asset_class == classes[i])
+        })
+        .collect();
+
+    for class_target in &profile.class_targets {
+        let members: Vec<usize> = (0..positions.len())
+            .filter(|&i| classes[i] == class_target.asset_class)
+            .collect();
+        let class_percent: f64 = members.iter().map(|&i| desired[i]).sum();
+
+        if let Some(min) = class_target.min_percent.filter(|min| class_percent < *min) {
+            spread_class_delta(&mut desired, &members, min - class_percent);
+            spread_class_delta(&mut desired, &funding, class_percent - min);
+        } else if let Some(max) = class_target.max_percent.filter(|max| class_percent > *max) {
+            let sellable: Vec<usize> = members.into_iter().filter(|&i| !locked[i]).collect();
+            let before: f64 = sellable.iter().map(|&i| desired[i]).sum();
+            spread_class_delta(&mut desired, &sellable, max - class_percent);
+            let sold = before - sellable.iter().map(|&i| desired[i]).sum::<f64>();
+            spread_class_delta(&mut desired, &funding, sold);
+        }
+    }
+
+    for (i, position) in positions.iter().enumerate() {
+        if locked[i] {
+            desired[i] = desired[i].max(position.allocation);
+        }
+    }
+    desired
+}
+
 fn map_actions(
     profile: &RebalanceProfile,
     positions: &[Position],
     metrics: &PortfolioMetrics,
 ) -> Vec<RebalanceAction> {
-    let mut target_lookup: HashMap<String, &AllocationTarget> = profile
-        .targets
-        .iter()
-        .map(|t| (t.symbol.clone(), t))
-        .collect();
+    let desired = desired_allocations(profile, positions);
 
     let mut actions = Vec::new();
-    for position in positions.iter() {
-        let target_percent = target_lookup
-            .get(&position.symbol)
-            .map(|t| t.target_percent)
-            .unwrap_or(0.0);
-
+    for (position, target_percent) in positions.iter().zip(desired) {
         let deviation = position.allocation - target_percent;
         if deviation.abs() < 0.25 {
             continue;
@@ -473,37 +602,59 @@ pub fn list_rebalance_profiles(
 }
 
 #[tauri::command]
-pub fn save_rebalance_profile(
+pub async fn save_rebalance_profile(
     input: RebalanceProfileInput,
+    app_handle: AppHandle,
     state: State<'_, SharedRebalancerState>,
 ) -> Result<RebalanceProfile, String> {
-    let mut guard = state
-        .lock()
-        .map_err(|_| "Rebalancer unavailable".to_string())?;
-
     let profile = RebalanceProfile {
         id: input
             .id
             .unwrap_or_else(|| format!("profile-{}", Utc::now().timestamp_millis())),
         name: input.name,
         targets: input.targets,
+        class_targets: input.class_targets,
         deviation_trigger_percent: input.deviation_trigger_percent,
         time_interval_hours: input.time_interval_hours,
         enabled: input.enabled,
     };
 
-    Ok(guard.upsert_profile(profile))
+    let saved = state
+        .lock()
+        .map_err(|_| "Rebalancer unavailable".to_string())?
+        .upsert_profile(profile);
+
+    record_config_change(
+        &app_handle,
+        ConfigEntity::rebalance_profile(&saved.id),
+        Some(&saved),
+        LOCAL_ACTOR,
+    )
+    .await;
+    Ok(saved)
 }
 
 #[tauri::command]
-pub fn delete_rebalance_profile(
+pub async fn delete_rebalance_profile(
     profile_id: String,
+    app_handle: AppHandle,
     state: State<'_, SharedRebalancerState>,
 ) -> Result<bool, String> {
-    state
+    let removed = state
         .lock()
-        .map_err(|_| "Rebalancer unavailable".to_string())
-        .map(|mut guard| guard.remove_profile(&profile_id))
+        .map_err(|_| "Rebalancer unavailable".to_string())?
+        .remove_profile(&profile_id);
+
+    if removed {
+        record_config_change::<RebalanceProfile>(
+            &app_handle,
+            ConfigEntity::rebalance_profile(&profile_id),
+            None,
+            LOCAL_ACTOR,
+        )
+        .await;
+    }
+    Ok(removed)
 }
 
 #[tauri::command]
@@ -608,6 +759,37 @@ pub fn check_rebalance_triggers(
 mod tests {
     use super::*;
 
+    fn position(symbol: &str, mint: &str, amount: f64, price: f64, entry: f64) -> Position {
+        Position {
+            symbol: symbol.to_string(),
+            mint: mint.to_string(),
+            amount,
+            current_price: price,
+            avg_entry_price: entry,
+            total_value: 0.0,
+            unrealized_pnl: 0.0,
+            unrealized_pnl_percent: 0.0,
+            allocation: 0.0,
+        }
+    }
+
+    fn sample_data() -> PortfolioDataState {
+        PortfolioDataState::with_positions(vec![
+            position("SOL", "So11111111111111111111111111111111111111112", 320.0, 175.4, 142.0),
+            position("BTC", "11111111111111111111111111111111", 2.6, 64000.0, 42800.0),
+            position("ETH", "22222222222222222222222222222222", 35.0, 3400.0, 2600.0),
+            position("USDC", "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", 85000.0, 1.0, 1.0),
+        ])
+    }
+
+    #[test]
+    fn portfolio_starts_empty_outside_demo_mode() {
+        let data = PortfolioDataState::new();
+        assert!(data.positions().is_empty());
+        assert_eq!(data.metrics().total_value, 0.0);
+        assert_eq!(data.metrics().daily_pnl_percent, 0.0);
+    }
+
     #[test]
     fn map_actions_detects_overweight_position() {
         let profile = RebalanceProfile {
@@ -616,13 +798,15 @@ mod tests {
             targets: vec![AllocationTarget {
                 symbol: "SOL".to_string(),
                 target_percent: 10.0,
+                ..Default::default()
             }],
+            class_targets: Vec::new(),
             deviation_trigger_percent: 2.0,
             time_interval_hours: None,
             enabled: true,
         };
 
-        let data = PortfolioDataState::new();
+        let data = sample_data();
         let positions = data.positions();
         let metrics = data.metrics();
         let actions = map_actions(&profile, &positions, &metrics);
@@ -635,7 +819,7 @@ mod tests {
 
     #[test]
     fn apply_rebalance_moves_allocation_toward_target() {
-        let mut data = PortfolioDataState::new();
+        let mut data = sample_data();
 
         let profile = RebalanceProfile {
             id: "test".to_string(),
@@ -644,12 +828,15 @@ mod tests {
                 AllocationTarget {
                     symbol: "SOL".to_string(),
                     target_percent: 20.0,
+                    ..Default::default()
                 },
                 AllocationTarget {
                     symbol: "BTC".to_string(),
                     target_percent: 40.0,
+                    ..Default::default()
                 },
             ],
+            class_targets: Vec::new(),
             deviation_trigger_percent: 2.0,
             time_interval_hours: None,
             enabled: true,
@@ -673,8 +860,132 @@ mod tests {
     #[test]
     fn deviation_trigger_detection() {
         let mut rebalancer = RebalancerState::default();
-        let data = PortfolioDataState::new();
+        let data = sample_data();
         let notifications = check_rebalance_triggers_internal(&mut rebalancer, &data);
         assert!(notifications.iter().any(|h| h.trigger_type == "deviation"));
     }
+
+    fn profile_with(
+        targets: Vec<AllocationTarget>,
+        class_targets: Vec<AssetClassTarget>,
+    ) -> RebalanceProfile {
+        RebalanceProfile {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            targets,
+            class_targets,
+            deviation_trigger_percent: 2.0,
+            time_interval_hours: None,
+            enabled: true,
+        }
+    }
+
+    fn held_positions() -> Vec<AllocationTarget> {
+        ["SOL", "BTC", "ETH", "USDC"]
+            .iter()
+            .map(|symbol| AllocationTarget {
+                symbol: symbol.to_string(),
+                min_percent: Some(0.0),
+                max_percent: Some(100.0),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn allocation_inside_target_range_needs_no_trade() {
+        let data = sample_data();
+        let mut targets = held_positions();
+        // SOL sits near 13%, BTC near 39%
+        targets[0] = AllocationTarget {
+            symbol: "SOL".to_string(),
+            target_percent: 15.0,
+            min_percent: Some(10.0),
+            max_percent: Some(20.0),
+            ..Default::default()
+        };
+        targets[1] = AllocationTarget {
+            symbol: "BTC".to_string(),
+            target_percent: 30.0,
+            min_percent: Some(25.0),
+            max_percent: Some(35.0),
+            ..Default::default()
+        };
+
+        let actions = map_actions(
+            &profile_with(targets, Vec::new()),
+            &data.positions(),
+            &data.metrics(),
+        );
+        assert!(actions.iter().all(|a| a.symbol != "SOL"));
+        let btc = actions.iter().find(|a| a.symbol == "BTC").unwrap();
+        assert_eq!(btc.action, "sell");
+        assert!((btc.target_percent - 35.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn asset_class_minimum_buys_into_the_class() {
+        let data = sample_data();
+        let profile = profile_with(
+            held_positions(),
+            vec![AssetClassTarget {
+                asset_class: crate::portfolio::AssetClass::Stablecoin,
+                min_percent: Some(25.0),
+                max_percent: None,
+            }],
+        );
+
+        let positions = data.positions();
+        let desired = desired_allocations(&profile, &positions);
+        assert!((desired.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+
+        let actions = map_actions(&profile, &positions, &data.metrics());
+        let (buys, sells): (Vec<_>, Vec<_>) = actions.iter().partition(|a| a.action == "buy");
+        assert_eq!(buys.len(), 1);
+        assert_eq!(buys[0].symbol, "USDC");
+        assert!((buys[0].target_percent - 25.0).abs() < 1e-9);
+
+        // The buy is funded by selling the unbounded positions pro rata
+        assert_eq!(sells.len(), 3);
+        let sold: f64 = sells.iter().map(|a| a.estimated_value).sum();
+        assert!((sold - buys[0].estimated_value).abs() < 1e-6);
+    }
+
+    #[test]
+    fn do_not_sell_tokens_are_never_sold() {
+        let data = sample_data();
+        let mut targets = held_positions();
+        targets[0] = AllocationTarget {
+            symbol: "SOL".to_string(),
+            target_percent: 5.0,
+            do_not_sell: true,
+            ..Default::default()
+        };
+        let profile = profile_with(
+            targets,
+            vec![AssetClassTarget {
+                asset_class: crate::portfolio::AssetClass::Sol,
+                min_percent: None,
+                max_percent: Some(5.0),
+            }],
+        );
+
+        let actions = map_actions(&profile, &data.positions(), &data.metrics());
+        assert!(actions.iter().all(|a| a.symbol != "SOL"));
+    }
+
+    #[test]
+    fn restoring_a_profile_keeps_its_schedule() {
+        let mut state = RebalancerState::default();
+        let executed_at = Utc::now();
+        state.find_profile_mut("core-growth").unwrap().last_executed_at = Some(executed_at);
+
+        let mut older = state.find_profile("core-growth").unwrap().profile.clone();
+        older.deviation_trigger_percent = 8.0;
+        state.restore_profile(older);
+
+        let restored = state.find_profile("core-growth").unwrap();
+        assert_eq!(restored.profile.deviation_trigger_percent, 8.0);
+        assert_eq!(restored.last_executed_at, Some(executed_at));
+    }
 }
@@ -0,0 +1,1148 @@
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use crate::collab::crypto::RoomEncryption;
+use crate::portfolio::exposure::{
+    build_exposure_report, native_sol_price, AssetClass, ExposureSnapshot, SharedExposureTracker,
+};
+use crate::security::keystore::Keystore;
+use crate::wallet::multi_wallet::{MultiWalletManager, WalletInfo};
+use crate::wallet::operations::{TokenBalance, WalletOperationsManager};
+
+const SHARING_DB_FILE: &str = "portfolio_shares.db";
+const SHARE_CODE_PREFIX: &str = "eclipse-share:";
+const SHARE_CODE_VERSION: u8 = 1;
+const OWNER_KEY_PREFIX: &str = "portfolio_share.";
+const SUBSCRIBER_KEY_PREFIX: &str = "portfolio_subscription.";
+const SHARED_PORTFOLIO_EVENT: &str = "shared_portfolio_updated";
+const PUBLISH_INTERVAL_SECS: u64 = 300;
+const HISTORY_DAYS: i64 = 30;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SharingError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("relay error: {0}")]
+    Relay(#[from] reqwest::Error),
+    #[error("encryption error: {0}")]
+    Crypto(String),
+    #[error("keystore error: {0}")]
+    Keystore(String),
+    #[error("invalid share code")]
+    InvalidShareCode,
+    #[error("share not found: {0}")]
+    NotFound(String),
+    #[error("share revoked: {0}")]
+    Revoked(String),
+    #[error("stale snapshot: sequence {received} is not newer than {latest}")]
+    StaleSnapshot { received: i64, latest: i64 },
+    #[error("invalid request: {0}")]
+    Invalid(String),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+/// Which parts of the portfolio a share exposes. Keys, addresses and wallet labels
+/// are never included regardless of scope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareScope {
+    pub include_positions: bool,
+    pub include_performance: bool,
+    /// Replaces amounts and USD values with allocation percentages and indexed returns.
+    #[serde(default)]
+    pub hide_values: bool,
+}
+
+impl Default for ShareScope {
+    fn default() -> Self {
+        Self {
+            include_positions: true,
+            include_performance: true,
+            hide_values: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioShare {
+    pub id: String,
+    pub label: String,
+    pub scope: ShareScope,
+    pub relay_url: Option<String>,
+    pub sequence: i64,
+    pub created_at: DateTime<Utc>,
+    pub last_published_at: Option<DateTime<Utc>>,
+    pub revoked: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateShareRequest {
+    pub label: String,
+    #[serde(default)]
+    pub scope: ShareScope,
+    pub relay_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareInvite {
+    pub share: PortfolioShare,
+    /// Carries the decryption key; anyone holding it can read the share.
+    pub share_code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareCode {
+    pub version: u8,
+    pub share_id: String,
+    pub key: String,
+    pub relay_url: Option<String>,
+}
+
+/// What travels between instances. The relay only ever sees this.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareEnvelope {
+    pub share_id: String,
+    pub sequence: i64,
+    pub published_at: DateTime<Utc>,
+    pub ciphertext: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedPosition {
+    pub mint: String,
+    pub symbol: String,
+    pub name: String,
+    pub amount: Option<f64>,
+    pub value_usd: Option<f64>,
+    pub allocation_percent: f64,
+    pub change_24h: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedValuePoint {
+    pub timestamp: String,
+    /// USD value, or an index starting at 100 when values are hidden.
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedPerformance {
+    pub total_value_usd: Option<f64>,
+    pub change_24h_percent: Option<f64>,
+    pub change_7d_percent: Option<f64>,
+    pub change_30d_percent: Option<f64>,
+    pub history: Vec<SharedValuePoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedPortfolioSnapshot {
+    pub share_id: String,
+    pub sequence: i64,
+    pub label: String,
+    pub generated_at: DateTime<Utc>,
+    pub allocation: HashMap<AssetClass, f64>,
+    pub positions: Option<Vec<SharedPosition>>,
+    pub performance: Option<SharedPerformance>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareSubscription {
+    pub share_id: String,
+    pub label: String,
+    pub relay_url: Option<String>,
+    pub last_sequence: i64,
+    pub subscribed_at: DateTime<Utc>,
+    pub last_received_at: Option<DateTime<Utc>>,
+}
+
+pub fn encode_share_code(code: &ShareCode) -> Result<String, SharingError> {
+    let json = serde_json::to_vec(code)?;
+    Ok(format!(
+        "{}{}",
+        SHARE_CODE_PREFIX,
+        general_purpose::URL_SAFE_NO_PAD.encode(json)
+    ))
+}
+
+pub fn decode_share_code(code: &str) -> Result<ShareCode, SharingError> {
+    let encoded = code
+        .trim()
+        .strip_prefix(SHARE_CODE_PREFIX)
+        .ok_or(SharingError::InvalidShareCode)?;
+    let json = general_purpose::URL_SAFE_NO_PAD
+        .decode(encoded)
+        .map_err(|_| SharingError::InvalidShareCode)?;
+    let code: ShareCode =
+        serde_json::from_slice(&json).map_err(|_| SharingError::InvalidShareCode)?;
+
+    if code.version != SHARE_CODE_VERSION || code.share_id.is_empty() {
+        return Err(SharingError::InvalidShareCode);
+    }
+    decode_key(&code.key).map_err(|_| SharingError::InvalidShareCode)?;
+    Ok(code)
+}
+
+fn decode_key(encoded: &str) -> Result<[u8; 32], SharingError> {
+    let bytes = general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| SharingError::Crypto(e.to_string()))?;
+    bytes
+        .try_into()
+        .map_err(|_| SharingError::Crypto("share key must be 32 bytes".to_string()))
+}
+
+pub fn seal_snapshot(
+    key: &[u8; 32],
+    snapshot: &SharedPortfolioSnapshot,
+) -> Result<ShareEnvelope, SharingError> {
+    let cipher = RoomEncryption::new(key).map_err(|e| SharingError::Crypto(e.to_string()))?;
+    let ciphertext = cipher
+        .encrypt(&serde_json::to_string(snapshot)?)
+        .map_err(|e| SharingError::Crypto(e.to_string()))?;
+
+    Ok(ShareEnvelope {
+        share_id: snapshot.share_id.clone(),
+        sequence: snapshot.sequence,
+        published_at: Utc::now(),
+        ciphertext,
+    })
+}
+
+/// Decrypts an envelope and checks the plaintext matches its unauthenticated header,
+/// so a relay can't replay one share's data under another id or sequence.
+pub fn open_snapshot(
+    key: &[u8; 32],
+    envelope: &ShareEnvelope,
+) -> Result<SharedPortfolioSnapshot, SharingError> {
+    let cipher = RoomEncryption::new(key).map_err(|e| SharingError::Crypto(e.to_string()))?;
+    let plaintext = cipher
+        .decrypt(&envelope.ciphertext)
+        .map_err(|e| SharingError::Crypto(e.to_string()))?;
+    let snapshot: SharedPortfolioSnapshot = serde_json::from_str(&plaintext)?;
+
+    if snapshot.share_id != envelope.share_id || snapshot.sequence != envelope.sequence {
+        return Err(SharingError::Crypto(
+            "envelope header does not match snapshot".to_string(),
+        ));
+    }
+    Ok(snapshot)
+}
+
+/// Aggregates balances across wallets by mint so the share never reveals how holdings
+/// are split between addresses.
+pub fn aggregate_positions(
+    wallets: &[(WalletInfo, Vec<TokenBalance>)],
+    hide_values: bool,
+) -> Vec<SharedPosition> {
+    let mut by_mint: HashMap<String, (TokenBalance, f64, f64)> = HashMap::new();
+    for (_, balances) in wallets {
+        for token in balances {
+            let entry = by_mint
+                .entry(token.mint.clone())
+                .or_insert_with(|| (token.clone(), 0.0, 0.0));
+            entry.1 += token.balance;
+            entry.2 += token.usd_value.max(0.0);
+        }
+    }
+
+    let total: f64 = by_mint.values().map(|(_, _, value)| value).sum();
+    let mut positions: Vec<SharedPosition> = by_mint
+        .into_values()
+        .map(|(token, amount, value)| SharedPosition {
+            mint: token.mint,
+            symbol: token.symbol,
+            name: token.name,
+            amount: (!hide_values).then_some(amount),
+            value_usd: (!hide_values).then_some(value),
+            allocation_percent: if total > 0.0 { value / total * 100.0 } else { 0.0 },
+            change_24h: token.change_24h,
+        })
+        .collect();
+
+    positions.sort_by(|a, b| {
+        b.allocation_percent
+            .partial_cmp(&a.allocation_percent)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    positions
+}
+
+fn change_since(history: &[ExposureSnapshot], now: DateTime<Utc>, window: Duration) -> Option<f64> {
+    let current = history.last()?.total_value_usd;
+    let cutoff = now - window;
+    let baseline = history.iter().find(|snapshot| {
+        DateTime::parse_from_rfc3339(&snapshot.timestamp)
+            .map(|ts| ts.with_timezone(&Utc) >= cutoff)
+            .unwrap_or(false)
+    })?;
+
+    (baseline.total_value_usd > 0.0)
+        .then(|| (current - baseline.total_value_usd) / baseline.total_value_usd * 100.0)
+}
+
+/// Derives returns from recorded exposure snapshots. With hidden values the history is
+/// rebased to 100 so subscribers see the shape of returns but not the portfolio size.
+pub fn build_performance(
+    history: &[ExposureSnapshot],
+    current_total: f64,
+    hide_values: bool,
+) -> SharedPerformance {
+    let now = Utc::now();
+    let base = history
+        .iter()
+        .map(|s| s.total_value_usd)
+        .find(|v| *v > 0.0)
+        .unwrap_or(0.0);
+
+    let points = history
+        .iter()
+        .map(|snapshot| SharedValuePoint {
+            timestamp: snapshot.timestamp.clone(),
+            value: if !hide_values {
+                snapshot.total_value_usd
+            } else if base > 0.0 {
+                snapshot.total_value_usd / base * 100.0
+            } else {
+                100.0
+            },
+        })
+        .collect();
+
+    SharedPerformance {
+        total_value_usd: (!hide_values).then_some(current_total),
+        change_24h_percent: change_since(history, now, Duration::hours(24)),
+        change_7d_percent: change_since(history, now, Duration::days(7)),
+        change_30d_percent: change_since(history, now, Duration::days(30)),
+        history: points,
+    }
+}
+
+pub fn build_snapshot(
+    share: &PortfolioShare,
+    sequence: i64,
+    wallets: &[(WalletInfo, Vec<TokenBalance>)],
+    history: &[ExposureSnapshot],
+    sol_price: f64,
+) -> SharedPortfolioSnapshot {
+    let report = build_exposure_report(wallets, &[], sol_price);
+    let scope = &share.scope;
+
+    SharedPortfolioSnapshot {
+        share_id: share.id.clone(),
+        sequence,
+        label: share.label.clone(),
+        generated_at: Utc::now(),
+        allocation: report
+            .by_asset_class
+            .iter()
+            .map(|(class, bucket)| (*class, bucket.percent))
+            .collect(),
+        positions: scope
+            .include_positions
+            .then(|| aggregate_positions(wallets, scope.hide_values)),
+        performance: scope.include_performance.then(|| {
+            build_performance(history, report.total_value_usd, scope.hide_values)
+        }),
+    }
+}
+
+fn validate_relay_url(relay_url: &Option<String>) -> Result<(), SharingError> {
+    if let Some(url) = relay_url {
+        let parsed =
+            reqwest::Url::parse(url).map_err(|e| SharingError::Invalid(e.to_string()))?;
+        if parsed.scheme() != "https" && parsed.host_str() != Some("localhost") {
+            return Err(SharingError::Invalid(
+                "relay url must use https".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn relay_endpoint(relay_url: &str, share_id: &str) -> String {
+    format!("{}/shares/{}", relay_url.trim_end_matches('/'), share_id)
+}
+
+fn owner_key_name(share_id: &str) -> String {
+    format!("{}{}", OWNER_KEY_PREFIX, share_id)
+}
+
+fn subscriber_key_name(share_id: &str) -> String {
+    format!("{}{}", SUBSCRIBER_KEY_PREFIX, share_id)
+}
+
+fn load_key(keystore: &Keystore, name: &str) -> Result<[u8; 32], SharingError> {
+    let secret = keystore
+        .retrieve_secret(name)
+        .map_err(|e| SharingError::Keystore(e.to_string()))?;
+    secret
+        .as_slice()
+        .try_into()
+        .map_err(|_| SharingError::Keystore("share key must be 32 bytes".to_string()))
+}
+
+pub struct PortfolioShareManager {
+    pool: Pool<Sqlite>,
+    http: reqwest::Client,
+}
+
+pub type SharedPortfolioShareManager = Arc<RwLock<PortfolioShareManager>>;
+
+impl PortfolioShareManager {
+    pub async fn new(app: &AppHandle) -> Result<Self, SharingError> {
+        let db_path = sharing_db_path(app)?;
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+
+        let manager = Self {
+            pool,
+            http: reqwest::Client::new(),
+        };
+        manager.initialize().await?;
+        Ok(manager)
+    }
+
+    async fn initialize(&self) -> Result<(), SharingError> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS portfolio_shares (
+                id TEXT PRIMARY KEY,
+                label TEXT NOT NULL,
+                scope TEXT NOT NULL,
+                relay_url TEXT,
+                sequence INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL,
+                last_published_at TEXT,
+                revoked INTEGER NOT NULL DEFAULT 0,
+                latest_envelope TEXT
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS share_subscriptions (
+                share_id TEXT PRIMARY KEY,
+                label TEXT NOT NULL,
+                relay_url TEXT,
+                last_sequence INTEGER NOT NULL DEFAULT 0,
+                subscribed_at TEXT NOT NULL,
+                last_received_at TEXT,
+                latest_envelope TEXT
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_share(
+        &self,
+        keystore: &Keystore,
+        request: CreateShareRequest,
+    ) -> Result<ShareInvite, SharingError> {
+        let label = request.label.trim().to_string();
+        if label.is_empty() {
+            return Err(SharingError::Invalid("label is required".to_string()));
+        }
+        if !request.scope.include_positions && !request.scope.include_performance {
+            return Err(SharingError::Invalid(
+                "share must include positions or performance".to_string(),
+            ));
+        }
+        validate_relay_url(&request.relay_url)?;
+
+        let share = PortfolioShare {
+            id: uuid::Uuid::new_v4().to_string(),
+            label,
+            scope: request.scope,
+            relay_url: request.relay_url,
+            sequence: 0,
+            created_at: Utc::now(),
+            last_published_at: None,
+            revoked: false,
+        };
+
+        let key = RoomEncryption::generate_key();
+        keystore
+            .store_secret(&owner_key_name(&share.id), &key)
+            .map_err(|e| SharingError::Keystore(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO portfolio_shares (id, label, scope, relay_url, sequence, created_at, revoked)
+            VALUES (?1, ?2, ?3, ?4, 0, ?5, 0)
+            "#,
+        )
+        .bind(&share.id)
+        .bind(&share.label)
+        .bind(serde_json::to_string(&share.scope)?)
+        .bind(&share.relay_url)
+        .bind(share.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        let share_code = encode_share_code(&ShareCode {
+            version: SHARE_CODE_VERSION,
+            share_id: share.id.clone(),
+            key: general_purpose::STANDARD.encode(key),
+            relay_url: share.relay_url.clone(),
+        })?;
+
+        Ok(ShareInvite { share, share_code })
+    }
+
+    pub async fn list_shares(&self) -> Result<Vec<PortfolioShare>, SharingError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, label, scope, relay_url, sequence, created_at, last_published_at, revoked
+            FROM portfolio_shares
+            ORDER BY created_at DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_share).collect()
+    }
+
+    pub async fn get_share(&self, id: &str) -> Result<PortfolioShare, SharingError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, label, scope, relay_url, sequence, created_at, last_published_at, revoked
+            FROM portfolio_shares
+            WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| SharingError::NotFound(id.to_string()))?;
+
+        row_to_share(&row)
+    }
+
+    /// Seals a fresh snapshot for the share and pushes it to the relay, if one is set.
+    /// The envelope is returned either way so it can also be handed over out of band.
+    pub async fn publish(
+        &self,
+        keystore: &Keystore,
+        share_id: &str,
+        wallets: &[(WalletInfo, Vec<TokenBalance>)],
+        history: &[ExposureSnapshot],
+        sol_price: f64,
+    ) -> Result<ShareEnvelope, SharingError> {
+        let share = self.get_share(share_id).await?;
+        if share.revoked {
+            return Err(SharingError::Revoked(share.id));
+        }
+
+        let key = load_key(keystore, &owner_key_name(&share.id))?;
+        let sequence = share.sequence + 1;
+        let snapshot = build_snapshot(&share, sequence, wallets, history, sol_price);
+        let envelope = seal_snapshot(&key, &snapshot)?;
+
+        if let Some(relay_url) = &share.relay_url {
+            self.http
+                .put(relay_endpoint(relay_url, &share.id))
+                .json(&envelope)
+                .send()
+                .await?
+                .error_for_status()?;
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE portfolio_shares
+            SET sequence = ?1, last_published_at = ?2, latest_envelope = ?3
+            WHERE id = ?4
+            "#,
+        )
+        .bind(sequence)
+        .bind(envelope.published_at.to_rfc3339())
+        .bind(serde_json::to_string(&envelope)?)
+        .bind(&share.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(envelope)
+    }
+
+    /// Revoking destroys the share key, so nothing further can be published under the
+    /// code; the last snapshot is also withdrawn from the relay.
+    pub async fn revoke_share(&self, keystore: &Keystore, share_id: &str) -> Result<(), SharingError> {
+        let share = self.get_share(share_id).await?;
+
+        if let Some(relay_url) = &share.relay_url {
+            if let Err(err) = self
+                .http
+                .delete(relay_endpoint(relay_url, &share.id))
+                .send()
+                .await
+            {
+                tracing::warn!(error = %err, share_id = %share.id, "failed to withdraw share from relay");
+            }
+        }
+
+        if let Err(err) = keystore.remove_secret(&owner_key_name(&share.id)) {
+            tracing::warn!(error = %err, share_id = %share.id, "share key already removed");
+        }
+
+        sqlx::query("UPDATE portfolio_shares SET revoked = 1, latest_envelope = NULL WHERE id = ?1")
+            .bind(&share.id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn subscribe(
+        &self,
+        keystore: &Keystore,
+        share_code: &str,
+        label: Option<String>,
+    ) -> Result<ShareSubscription, SharingError> {
+        let code = decode_share_code(share_code)?;
+        let key = decode_key(&code.key)?;
+        validate_relay_url(&code.relay_url)?;
+
+        keystore
+            .store_secret(&subscriber_key_name(&code.share_id), &key)
+            .map_err(|e| SharingError::Keystore(e.to_string()))?;
+
+        let subscription = ShareSubscription {
+            share_id: code.share_id,
+            label: label
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty())
+                .unwrap_or_else(|| "Shared portfolio".to_string()),
+            relay_url: code.relay_url,
+            last_sequence: 0,
+            subscribed_at: Utc::now(),
+            last_received_at: None,
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO share_subscriptions (share_id, label, relay_url, last_sequence, subscribed_at)
+            VALUES (?1, ?2, ?3, 0, ?4)
+            ON CONFLICT(share_id) DO UPDATE SET label = excluded.label, relay_url = excluded.relay_url
+            "#,
+        )
+        .bind(&subscription.share_id)
+        .bind(&subscription.label)
+        .bind(&subscription.relay_url)
+        .bind(subscription.subscribed_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        self.get_subscription(&subscription.share_id).await
+    }
+
+    pub async fn list_subscriptions(&self) -> Result<Vec<ShareSubscription>, SharingError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT share_id, label, relay_url, last_sequence, subscribed_at, last_received_at
+            FROM share_subscriptions
+            ORDER BY subscribed_at DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_subscription).collect()
+    }
+
+    pub async fn get_subscription(&self, share_id: &str) -> Result<ShareSubscription, SharingError> {
+        let row = sqlx::query(
+            r#"
+            SELECT share_id, label, relay_url, last_sequence, subscribed_at, last_received_at
+            FROM share_subscriptions
+            WHERE share_id = ?1
+            "#,
+        )
+        .bind(share_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| SharingError::NotFound(share_id.to_string()))?;
+
+        row_to_subscription(&row)
+    }
+
+    /// Accepts a newer envelope, either pasted in or fetched from the share's relay.
+    /// Envelopes are stored encrypted; only the decrypted result is returned.
+    pub async fn receive(
+        &self,
+        keystore: &Keystore,
+        share_id: &str,
+        envelope: Option<ShareEnvelope>,
+    ) -> Result<SharedPortfolioSnapshot, SharingError> {
+        let subscription = self.get_subscription(share_id).await?;
+        let envelope = match envelope {
+            Some(envelope) => envelope,
+            None => {
+                let relay_url = subscription.relay_url.as_deref().ok_or_else(|| {
+                    SharingError::Invalid("share has no relay; import an envelope instead".to_string())
+                })?;
+                self.http
+                    .get(relay_endpoint(relay_url, share_id))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<ShareEnvelope>()
+                    .await?
+            }
+        };
+
+        if envelope.share_id != subscription.share_id {
+            return Err(SharingError::Invalid("envelope belongs to another share".to_string()));
+        }
+        if envelope.sequence <= subscription.last_sequence {
+            return Err(SharingError::StaleSnapshot {
+                received: envelope.sequence,
+                latest: subscription.last_sequence,
+            });
+        }
+
+        let key = load_key(keystore, &subscriber_key_name(share_id))?;
+        let snapshot = open_snapshot(&key, &envelope)?;
+
+        sqlx::query(
+            r#"
+            UPDATE share_subscriptions
+            SET last_sequence = ?1, last_received_at = ?2, latest_envelope = ?3
+            WHERE share_id = ?4
+            "#,
+        )
+        .bind(envelope.sequence)
+        .bind(Utc::now().to_rfc3339())
+        .bind(serde_json::to_string(&envelope)?)
+        .bind(share_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(snapshot)
+    }
+
+    pub async fn latest_snapshot(
+        &self,
+        keystore: &Keystore,
+        share_id: &str,
+    ) -> Result<Option<SharedPortfolioSnapshot>, SharingError> {
+        let row = sqlx::query("SELECT latest_envelope FROM share_subscriptions WHERE share_id = ?1")
+            .bind(share_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| SharingError::NotFound(share_id.to_string()))?;
+
+        let envelope: Option<String> = row.try_get("latest_envelope")?;
+        match envelope {
+            Some(json) => {
+                let envelope: ShareEnvelope = serde_json::from_str(&json)?;
+                let key = load_key(keystore, &subscriber_key_name(share_id))?;
+                open_snapshot(&key, &envelope).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub async fn unsubscribe(&self, keystore: &Keystore, share_id: &str) -> Result<(), SharingError> {
+        let result = sqlx::query("DELETE FROM share_subscriptions WHERE share_id = ?1")
+            .bind(share_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(SharingError::NotFound(share_id.to_string()));
+        }
+
+        if let Err(err) = keystore.remove_secret(&subscriber_key_name(share_id)) {
+            tracing::warn!(error = %err, share_id = %share_id, "subscription key already removed");
+        }
+        Ok(())
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, SharingError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| SharingError::Internal(e.to_string()))
+}
+
+fn row_to_share(row: &SqliteRow) -> Result<PortfolioShare, SharingError> {
+    let scope: String = row.try_get("scope")?;
+    let created_at: String = row.try_get("created_at")?;
+    let last_published_at: Option<String> = row.try_get("last_published_at")?;
+    let revoked: i64 = row.try_get("revoked")?;
+
+    Ok(PortfolioShare {
+        id: row.try_get("id")?,
+        label: row.try_get("label")?,
+        scope: serde_json::from_str(&scope)?,
+        relay_url: row.try_get("relay_url")?,
+        sequence: row.try_get("sequence")?,
+        created_at: parse_timestamp(&created_at)?,
+        last_published_at: last_published_at.as_deref().map(parse_timestamp).transpose()?,
+        revoked: revoked != 0,
+    })
+}
+
+fn row_to_subscription(row: &SqliteRow) -> Result<ShareSubscription, SharingError> {
+    let subscribed_at: String = row.try_get("subscribed_at")?;
+    let last_received_at: Option<String> = row.try_get("last_received_at")?;
+
+    Ok(ShareSubscription {
+        share_id: row.try_get("share_id")?,
+        label: row.try_get("label")?,
+        relay_url: row.try_get("relay_url")?,
+        last_sequence: row.try_get("last_sequence")?,
+        subscribed_at: parse_timestamp(&subscribed_at)?,
+        last_received_at: last_received_at.as_deref().map(parse_timestamp).transpose()?,
+    })
+}
+
+fn sharing_db_path(app: &AppHandle) -> Result<PathBuf, SharingError> {
+    let app_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| SharingError::Internal("Unable to resolve app data directory".to_string()))?;
+
+    std::fs::create_dir_all(&app_data_dir)?;
+    Ok(app_data_dir.join(SHARING_DB_FILE))
+}
+
+fn collect_wallet_balances(
+    wallets: &MultiWalletManager,
+    operations: &WalletOperationsManager,
+) -> Result<Vec<(WalletInfo, Vec<TokenBalance>)>, String> {
+    let wallet_list = wallets.list_wallets().map_err(|e| e.to_string())?;
+    Ok(wallet_list
+        .into_iter()
+        .map(|wallet| {
+            let balances = operations.cached_token_balances(&wallet.public_key);
+            (wallet, balances)
+        })
+        .collect())
+}
+
+async fn publish_all(app: &AppHandle, manager: &SharedPortfolioShareManager) -> Result<(), String> {
+    let keystore = app.state::<Keystore>();
+    let wallets = collect_wallet_balances(
+        &app.state::<MultiWalletManager>(),
+        &app.state::<WalletOperationsManager>(),
+    )?;
+    let sol_price = native_sol_price(&wallets)?;
+    let history = {
+        let tracker = app.state::<SharedExposureTracker>();
+        let tracker = tracker.read().await;
+        tracker.get_history(HISTORY_DAYS).await.map_err(|e| e.to_string())?
+    };
+
+    let manager = manager.read().await;
+    let shares = manager.list_shares().await.map_err(|e| e.to_string())?;
+    for share in shares.iter().filter(|s| !s.revoked && s.relay_url.is_some()) {
+        let published = manager
+            .publish(&keystore, &share.id, &wallets, &history, sol_price)
+            .await;
+        if let Err(err) = published {
+            tracing::warn!(error = %err, share_id = %share.id, "failed to publish portfolio share");
+        }
+    }
+    Ok(())
+}
+
+/// Keeps relay-backed shares fresh. Shares without a relay are only published on demand.
+pub fn start_share_publisher(app: AppHandle, manager: SharedPortfolioShareManager) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(PUBLISH_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            if let Err(err) = publish_all(&app, &manager).await {
+                tracing::warn!(error = %err, "portfolio share publish cycle failed");
+            }
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn create_portfolio_share(
+    request: CreateShareRequest,
+    keystore: State<'_, Keystore>,
+    manager: State<'_, SharedPortfolioShareManager>,
+) -> Result<ShareInvite, String> {
+    let manager = manager.read().await;
+    manager
+        .create_share(&keystore, request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_portfolio_shares(
+    manager: State<'_, SharedPortfolioShareManager>,
+) -> Result<Vec<PortfolioShare>, String> {
+    let manager = manager.read().await;
+    manager.list_shares().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn publish_portfolio_share(
+    share_id: String,
+    keystore: State<'_, Keystore>,
+    wallets: State<'_, MultiWalletManager>,
+    operations: State<'_, WalletOperationsManager>,
+    tracker: State<'_, SharedExposureTracker>,
+    manager: State<'_, SharedPortfolioShareManager>,
+) -> Result<ShareEnvelope, String> {
+    let inputs = collect_wallet_balances(&wallets, &operations)?;
+    let sol_price = native_sol_price(&inputs)?;
+    let history = {
+        let tracker = tracker.read().await;
+        tracker.get_history(HISTORY_DAYS).await.map_err(|e| e.to_string())?
+    };
+
+    let manager = manager.read().await;
+    manager
+        .publish(&keystore, &share_id, &inputs, &history, sol_price)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn revoke_portfolio_share(
+    share_id: String,
+    keystore: State<'_, Keystore>,
+    manager: State<'_, SharedPortfolioShareManager>,
+) -> Result<(), String> {
+    let manager = manager.read().await;
+    manager
+        .revoke_share(&keystore, &share_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn subscribe_portfolio_share(
+    share_code: String,
+    label: Option<String>,
+    keystore: State<'_, Keystore>,
+    manager: State<'_, SharedPortfolioShareManager>,
+) -> Result<ShareSubscription, String> {
+    let manager = manager.read().await;
+    manager
+        .subscribe(&keystore, &share_code, label)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_portfolio_subscriptions(
+    manager: State<'_, SharedPortfolioShareManager>,
+) -> Result<Vec<ShareSubscription>, String> {
+    let manager = manager.read().await;
+    manager.list_subscriptions().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn refresh_shared_portfolio(
+    share_id: String,
+    envelope: Option<ShareEnvelope>,
+    app_handle: AppHandle,
+    keystore: State<'_, Keystore>,
+    manager: State<'_, SharedPortfolioShareManager>,
+) -> Result<SharedPortfolioSnapshot, String> {
+    let manager = manager.read().await;
+    let snapshot = manager
+        .receive(&keystore, &share_id, envelope)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if let Err(err) = app_handle.emit_all(SHARED_PORTFOLIO_EVENT, &snapshot) {
+        tracing::warn!(error = %err, "failed to emit shared portfolio update");
+    }
+    Ok(snapshot)
+}
+
+#[tauri::command]
+pub async fn get_shared_portfolio(
+    share_id: String,
+    keystore: State<'_, Keystore>,
+    manager: State<'_, SharedPortfolioShareManager>,
+) -> Result<Option<SharedPortfolioSnapshot>, String> {
+    let manager = manager.read().await;
+    manager
+        .latest_snapshot(&keystore, &share_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn unsubscribe_portfolio_share(
+    share_id: String,
+    keystore: State<'_, Keystore>,
+    manager: State<'_, SharedPortfolioShareManager>,
+) -> Result<(), String> {
+    let manager = manager.read().await;
+    manager
+        .unsubscribe(&keystore, &share_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::multi_wallet::{PerformanceMetrics, WalletPreferences, WalletType};
+
+    fn wallet(id: &str) -> WalletInfo {
+        WalletInfo {
+            id: id.to_string(),
+            public_key: format!("{}-key", id),
+            label: id.to_string(),
+            network: "solana".to_string(),
+            chain_id: "solana".to_string(),
+            wallet_type: WalletType::Phantom,
+            group_id: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_used: None,
+            balance: 0.0,
+            preferences: WalletPreferences::default(),
+            performance: PerformanceMetrics::default(),
+        }
+    }
+
+    fn token(mint: &str, symbol: &str, balance: f64, usd_value: f64) -> TokenBalance {
+        TokenBalance {
+            mint: mint.to_string(),
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            balance,
+            decimals: 6,
+            usd_value,
+            change_24h: 1.5,
+            logo_uri: None,
+            last_updated: Utc::now(),
+        }
+    }
+
+    fn share(scope: ShareScope) -> PortfolioShare {
+        PortfolioShare {
+            id: "share-1".to_string(),
+            label: "Desk".to_string(),
+            scope,
+            relay_url: None,
+            sequence: 0,
+            created_at: Utc::now(),
+            last_published_at: None,
+            revoked: false,
+        }
+    }
+
+    fn snapshot(sequence: i64) -> SharedPortfolioSnapshot {
+        build_snapshot(&share(ShareScope::default()), sequence, &[], &[], 0.0)
+    }
+
+    #[test]
+    fn share_code_round_trips_and_rejects_garbage() {
+        let code = ShareCode {
+            version: SHARE_CODE_VERSION,
+            share_id: "share-1".to_string(),
+            key: general_purpose::STANDARD.encode(RoomEncryption::generate_key()),
+            relay_url: Some("https://relay.example.com".to_string()),
+        };
+        let encoded = encode_share_code(&code).unwrap();
+        assert!(encoded.starts_with(SHARE_CODE_PREFIX));
+        assert_eq!(decode_share_code(&encoded).unwrap(), code);
+
+        assert!(decode_share_code("eclipse-share:not-base64!").is_err());
+        assert!(decode_share_code("share-1").is_err());
+    }
+
+    #[test]
+    fn envelopes_only_open_with_the_share_key() {
+        let key = RoomEncryption::generate_key();
+        let envelope = seal_snapshot(&key, &snapshot(3)).unwrap();
+        assert!(!envelope.ciphertext.contains("Desk"));
+        assert_eq!(open_snapshot(&key, &envelope).unwrap().sequence, 3);
+
+        let other = RoomEncryption::generate_key();
+        assert!(open_snapshot(&other, &envelope).is_err());
+
+        let mut replayed = envelope.clone();
+        replayed.sequence = 9;
+        assert!(open_snapshot(&key, &replayed).is_err());
+    }
+
+    #[test]
+    fn positions_merge_across_wallets_and_can_hide_values() {
+        let wallets = vec![
+            (wallet("a"), vec![token("mint-sol", "SOL", 2.0, 300.0)]),
+            (
+                wallet("b"),
+                vec![token("mint-sol", "SOL", 1.0, 150.0), token("mint-usdc", "USDC", 50.0, 50.0)],
+            ),
+        ];
+
+        let positions = aggregate_positions(&wallets, false);
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[0].symbol, "SOL");
+        assert_eq!(positions[0].amount, Some(3.0));
+        assert!((positions[0].allocation_percent - 90.0).abs() < 1e-9);
+
+        let hidden = aggregate_positions(&wallets, true);
+        assert!(hidden.iter().all(|p| p.amount.is_none() && p.value_usd.is_none()));
+        assert!((hidden[1].allocation_percent - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hidden_performance_is_indexed() {
+        let now = Utc::now();
+        let point = |hours_ago: i64, value: f64| ExposureSnapshot {
+            timestamp: (now - Duration::hours(hours_ago)).to_rfc3339(),
+            total_value_usd: value,
+            by_asset_class: HashMap::new(),
+            by_custody: HashMap::new(),
+            holdings: HashMap::new(),
+        };
+        let history = vec![point(48, 1000.0), point(20, 1200.0), point(0, 1320.0)];
+
+        let visible = build_performance(&history, 1320.0, false);
+        assert_eq!(visible.total_value_usd, Some(1320.0));
+        assert!((visible.change_24h_percent.unwrap() - 10.0).abs() < 1e-9);
+        assert!((visible.change_7d_percent.unwrap() - 32.0).abs() < 1e-9);
+
+        let hidden = build_performance(&history, 1320.0, true);
+        assert_eq!(hidden.total_value_usd, None);
+        assert_eq!(hidden.history[0].value, 100.0);
+        assert!((hidden.history[2].value - 132.0).abs() < 1e-9);
+    }
+}
@@ -1,9 +1,11 @@
-use std::collections::HashMap;
 use std::sync::Mutex;
 
 use chrono::{DateTime, Datelike, Duration, Utc};
 use serde::Deserialize;
-use tauri::State;
+use tauri::{AppHandle, State};
+
+use crate::config::token_registry::apply_token_overrides;
+use crate::data::event_store::{record_app_event, Event as AuditEvent};
 
 use super::types::{LotStrategy, TaxLossHarvestingSuggestion, TaxLot, TaxReport};
 
@@ -176,6 +178,82 @@ impl TaxLotsState {
         Ok(lot.clone())
     }
 
+    /// Moves open lots across a bridge without realizing a gain. The destination lots keep
+    /// the original acquisition date and cost basis, plus a share of the bridge fee.
+    pub fn carry_over(&mut self, transfer: &LotCarryOver) -> Result<CarryOverResult, String> {
+        if transfer.amount_sent <= 0.0 || transfer.amount_received <= 0.0 {
+            return Err("Bridge amounts must be positive".to_string());
+        }
+
+        let mut candidates: Vec<usize> = self
+            .lots
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.disposed_at.is_none() && l.amount > 0.0)
+            .filter(|(_, l)| {
+                l.mint == transfer.source_mint
+                    || ((l.mint.is_empty() || transfer.source_mint.is_empty())
+                        && l.symbol.eq_ignore_ascii_case(&transfer.source_symbol))
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+
+        match self.strategy {
+            LotStrategy::LIFO => {
+                candidates.sort_by(|a, b| self.lots[*b].acquired_at.cmp(&self.lots[*a].acquired_at))
+            }
+            LotStrategy::HIFO => candidates.sort_by(|a, b| {
+                self.lots[*b]
+                    .price_per_unit
+                    .partial_cmp(&self.lots[*a].price_per_unit)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }),
+            LotStrategy::FIFO | LotStrategy::SPECIFIC => {
+                candidates.sort_by(|a, b| self.lots[*a].acquired_at.cmp(&self.lots[*b].acquired_at))
+            }
+        }
+
+        let ratio = transfer.amount_received / transfer.amount_sent;
+        let mut remaining = transfer.amount_sent;
+        let mut carried = Vec::new();
+
+        for idx in candidates {
+            if remaining <= f64::EPSILON {
+                break;
+            }
+            let lot = &mut self.lots[idx];
+            let taken = remaining.min(lot.amount);
+            let basis = lot.cost_basis * taken / lot.amount;
+            lot.amount -= taken;
+            lot.cost_basis -= basis;
+            remaining -= taken;
+
+            let fee_share = transfer.fee_usd * taken / transfer.amount_sent;
+            let amount = taken * ratio;
+            carried.push(TaxLot {
+                id: format!("{}-{}", lot.id, transfer.reference),
+                symbol: transfer.destination_symbol.clone(),
+                mint: transfer.destination_mint.clone(),
+                amount,
+                cost_basis: basis + fee_share,
+                price_per_unit: (basis + fee_share) / amount,
+                acquired_at: lot.acquired_at.clone(),
+                disposed_amount: None,
+                disposed_at: None,
+                realized_gain: None,
+            });
+        }
+
+        self.lots
+            .retain(|l| l.disposed_at.is_some() || l.amount > f64::EPSILON);
+        self.lots.extend(carried.iter().cloned());
+
+        Ok(CarryOverResult {
+            lots: carried,
+            uncovered_amount: remaining.max(0.0),
+        })
+    }
+
     fn report(&self, tax_year: i32) -> TaxReport {
         let disposed_in_year: Vec<TaxLot> = self
             .all_lots()
@@ -236,6 +314,10 @@ impl TaxLotsState {
                     .map(|dt| dt.year() == tax_year)
                     .unwrap_or(false)
             })
+            .map(|mut lot| {
+                apply_token_overrides(&lot.mint, &mut lot.symbol, None, None, None);
+                lot
+            })
             .collect();
 
         match format {
@@ -254,13 +336,14 @@ impl TaxLotsState {
         let open_lots = self.open_lots();
         let mut suggestions = Vec::new();
 
-        let mock_prices: HashMap<&str, f64> = [("SOL", 175.4), ("BTC", 64000.0), ("ETH", 3400.0)]
-            .iter()
-            .cloned()
-            .collect();
-
+        // Only demo prices exist here; lots without a price can't be evaluated
         for lot in open_lots.iter() {
-            let current_price = mock_prices.get(lot.symbol.as_str()).cloned().unwrap_or(0.0);
+            let current_price = match crate::demo::find_demo_token(&lot.symbol)
+                .filter(|_| crate::demo::is_demo_mode())
+            {
+                Some(token) => crate::demo::demo_price(token),
+                None => continue,
+            };
             let current_value = lot.amount * current_price;
             let unrealized = current_value - lot.cost_basis;
 
@@ -295,6 +378,26 @@ impl TaxLotsState {
 
 pub type SharedTaxLotsState = Mutex<TaxLotsState>;
 
+/// A transfer of the same asset between chains, e.g. SOL bridged to wrapped SOL on Ethereum.
+#[derive(Debug, Clone)]
+pub struct LotCarryOver {
+    pub reference: String,
+    pub source_symbol: String,
+    pub source_mint: String,
+    pub destination_symbol: String,
+    pub destination_mint: String,
+    pub amount_sent: f64,
+    pub amount_received: f64,
+    pub fee_usd: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CarryOverResult {
+    pub lots: Vec<TaxLot>,
+    /// Part of the transfer not covered by any open lot.
+    pub uncovered_amount: f64,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct DisposeLotInput {
     #[serde(rename = "lotId")]
@@ -345,12 +448,26 @@ pub fn get_tax_lot_strategy(state: State<'_, SharedTaxLotsState>) -> Result<LotS
 #[tauri::command]
 pub fn dispose_tax_lot(
     input: DisposeLotInput,
+    app: AppHandle,
     state: State<'_, SharedTaxLotsState>,
 ) -> Result<TaxLot, String> {
     let mut guard = state
         .lock()
         .map_err(|_| "Tax lots unavailable".to_string())?;
-    guard.dispose(&input.lot_id, input.amount, input.sale_price)
+    let lot = guard.dispose(&input.lot_id, input.amount, input.sale_price)?;
+
+    let event = AuditEvent::PositionClosed {
+        position_id: lot.id.clone(),
+        symbol: Some(lot.symbol.clone()),
+        exit_price: input.sale_price,
+        pnl: lot.realized_gain.unwrap_or(0.0),
+        timestamp: Utc::now(),
+    };
+    let aggregate_id = format!("tax_lot_{}", lot.id);
+    tauri::async_runtime::spawn(async move {
+        record_app_event(&app, &aggregate_id, event).await;
+    });
+    Ok(lot)
 }
 
 fn parse_datetime(s: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
@@ -561,6 +678,50 @@ mod tests {
         assert!(first <= second);
     }
 
+    #[test]
+    fn bridge_carry_over_preserves_basis_and_dates() {
+        let mut state = TaxLotsState::default();
+        let sol_basis: f64 = state
+            .open_lots()
+            .iter()
+            .filter(|l| l.symbol == "SOL")
+            .map(|l| l.cost_basis)
+            .sum();
+        let oldest = state
+            .open_lots()
+            .into_iter()
+            .filter(|l| l.symbol == "SOL")
+            .min_by(|a, b| a.acquired_at.cmp(&b.acquired_at))
+            .unwrap();
+
+        let result = state
+            .carry_over(&LotCarryOver {
+                reference: "bridge-1".to_string(),
+                source_symbol: "SOL".to_string(),
+                source_mint: "So11111111111111111111111111111111111111112".to_string(),
+                destination_symbol: "SOL".to_string(),
+                destination_mint: "0xD31a59c85aE9D8edEFeC411D448f90841571b89c".to_string(),
+                amount_sent: 200.0,
+                amount_received: 199.0,
+                fee_usd: 10.0,
+            })
+            .unwrap();
+
+        assert_eq!(result.uncovered_amount, 0.0);
+        assert_eq!(result.lots.len(), 2);
+        assert_eq!(result.lots[0].acquired_at, oldest.acquired_at);
+        assert!((result.lots.iter().map(|l| l.amount).sum::<f64>() - 199.0).abs() < 1e-9);
+
+        let remaining_basis: f64 = state
+            .open_lots()
+            .iter()
+            .filter(|l| l.symbol == "SOL")
+            .map(|l| l.cost_basis)
+            .sum();
+        assert!((remaining_basis - (sol_basis + 10.0)).abs() < 1e-6);
+        assert!(state.all_lots().iter().all(|l| l.realized_gain.is_none() || l.disposed_at.is_some()));
+    }
+
     #[test]
     fn dispose_lot_calculates_realized_gain() {
         let mut state = TaxLotsState::default();
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::exposure::AssetClass;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub symbol: String,
@@ -44,13 +46,48 @@ pub struct PortfolioMetrics {
     pub unrealized_pnl: f64,
     #[serde(rename = "lastUpdated")]
     pub last_updated: String,
+    #[serde(rename = "lendingCollateral", default)]
+    pub lending_collateral: f64,
+    #[serde(rename = "lendingDebt", default)]
+    pub lending_debt: f64,
+    #[serde(rename = "netLendingExposure", default)]
+    pub net_lending_exposure: f64,
+    #[serde(rename = "bridgeInTransit", default)]
+    pub bridge_in_transit: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AllocationTarget {
     pub symbol: String,
     #[serde(rename = "targetPercent")]
     pub target_percent: f64,
+    /// With `max_percent`, an allocation anywhere in the range needs no trade.
+    #[serde(rename = "minPercent", default)]
+    pub min_percent: Option<f64>,
+    #[serde(rename = "maxPercent", default)]
+    pub max_percent: Option<f64>,
+    #[serde(rename = "doNotSell", default)]
+    pub do_not_sell: bool,
+}
+
+impl AllocationTarget {
+    /// Allowed allocation range; a fixed weight when no range is set.
+    pub fn band(&self) -> (f64, f64) {
+        let low = self.min_percent.unwrap_or(self.target_percent);
+        let high = self.max_percent.unwrap_or(self.target_percent);
+        (low.min(high), low.max(high))
+    }
+}
+
+/// Bounds on the combined weight of every position in an asset class.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetClassTarget {
+    #[serde(rename = "assetClass")]
+    pub asset_class: AssetClass,
+    #[serde(rename = "minPercent", default)]
+    pub min_percent: Option<f64>,
+    #[serde(rename = "maxPercent", default)]
+    pub max_percent: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,6 +95,8 @@ pub struct RebalanceProfile {
     pub id: String,
     pub name: String,
     pub targets: Vec<AllocationTarget>,
+    #[serde(rename = "classTargets", default)]
+    pub class_targets: Vec<AssetClassTarget>,
     #[serde(rename = "deviationTriggerPercent")]
     pub deviation_trigger_percent: f64,
     #[serde(rename = "timeIntervalHours")]
@@ -6,6 +6,15 @@ use std::sync::Arc;
 use tauri::{AppHandle, State};
 use tokio::sync::RwLock;
 
+use crate::config::entity_versions::{
+    bump_entity_version, entity_version, initialize_entity_versions, VersionBump,
+    VersionConflict, VersionedEditError,
+};
+use crate::config::recycle_bin::{
+    discard_recycled, recycle_deleted, undo_unavailable, RecycledEntityKind,
+};
+use crate::idempotency::run_idempotent;
+
 const WATCHLIST_DB_FILE: &str = "watchlists.db";
 const MAX_WATCHLISTS: usize = 10;
 
@@ -26,6 +35,9 @@ pub struct Watchlist {
     pub items: Vec<WatchlistItem>,
     pub created_at: String,
     pub updated_at: String,
+    /// Incremented on every edit; pass it back to reject stale updates.
+    #[serde(default)]
+    pub version: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,10 +105,21 @@ pub enum WatchlistError {
     MaxWatchlistsReached(usize),
     #[error("duplicate item: {0}")]
     DuplicateItem(String),
+    #[error(transparent)]
+    Conflict(#[from] VersionConflict),
     #[error("internal error: {0}")]
     Internal(String),
 }
 
+impl From<WatchlistError> for VersionedEditError {
+    fn from(error: WatchlistError) -> Self {
+        match error {
+            WatchlistError::Conflict(conflict) => VersionedEditError::Conflict(conflict),
+            other => VersionedEditError::Message(other.to_string()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct WatchlistManager {
     pool: Pool<Sqlite>,
@@ -157,6 +180,8 @@ impl WatchlistManager {
         .execute(&self.pool)
         .await?;
 
+        initialize_entity_versions(&self.pool).await?;
+
         Ok(())
     }
 
@@ -191,6 +216,7 @@ impl WatchlistManager {
             items: vec![],
             created_at: now.clone(),
             updated_at: now,
+            version: 1,
         })
     }
 
@@ -209,6 +235,7 @@ impl WatchlistManager {
         for row in rows {
             let id: String = row.try_get("id")?;
             let items = self.get_watchlist_items(&id).await?;
+            let version = entity_version(&self.pool, &id).await?;
 
             watchlists.push(Watchlist {
                 id: row.try_get("id")?,
@@ -216,6 +243,7 @@ impl WatchlistManager {
                 items,
                 created_at: row.try_get("created_at")?,
                 updated_at: row.try_get("updated_at")?,
+                version,
             });
         }
 
@@ -243,14 +271,41 @@ impl WatchlistManager {
             items,
             created_at: row.try_get("created_at")?,
             updated_at: row.try_get("updated_at")?,
+            version: entity_version(&self.pool, id).await?,
         })
     }
 
+    /// Bumps the watchlist's version, rejecting the edit if `expected_version`
+    /// is set and another window has changed the watchlist since.
+    async fn bump_version(
+        &self,
+        id: &str,
+        expected_version: Option<i64>,
+    ) -> Result<i64, WatchlistError> {
+        match bump_entity_version(&self.pool, id, expected_version).await? {
+            VersionBump::Bumped(version) => Ok(version),
+            VersionBump::Stale { latest } => {
+                let current = self.get_watchlist(id).await?;
+                Err(VersionConflict::new(
+                    "watchlist",
+                    id,
+                    expected_version.unwrap_or_default(),
+                    latest,
+                    Some(&current),
+                )
+                .into())
+            }
+        }
+    }
+
     pub async fn update_watchlist(
         &self,
         id: &str,
         name: String,
+        expected_version: Option<i64>,
     ) -> Result<Watchlist, WatchlistError> {
+        self.get_watchlist(id).await?;
+        self.bump_version(id, expected_version).await?;
         let now = Utc::now().to_rfc3339();
 
         let result = sqlx::query(
@@ -291,11 +346,60 @@ impl WatchlistManager {
         Ok(())
     }
 
+    /// Recreates a deleted watchlist with its original id and items.
+    pub async fn restore_watchlist(&self, watchlist: Watchlist) -> Result<Watchlist, WatchlistError> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM watchlists")
+            .fetch_one(&self.pool)
+            .await?;
+
+        if count >= MAX_WATCHLISTS as i64 {
+            return Err(WatchlistError::MaxWatchlistsReached(MAX_WATCHLISTS));
+        }
+
+        let now = Utc::now().to_rfc3339();
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO watchlists (id, name, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+        )
+        .bind(&watchlist.id)
+        .bind(&watchlist.name)
+        .bind(&watchlist.created_at)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await?;
+
+        for item in &watchlist.items {
+            sqlx::query(
+                r#"
+                INSERT INTO watchlist_items (watchlist_id, symbol, mint, position, added_at)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                "#,
+            )
+            .bind(&watchlist.id)
+            .bind(&item.symbol)
+            .bind(&item.mint)
+            .bind(item.position)
+            .bind(&item.added_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        self.bump_version(&watchlist.id, None).await?;
+
+        self.get_watchlist(&watchlist.id).await
+    }
+
     pub async fn add_item(
         &self,
         watchlist_id: &str,
         symbol: String,
         mint: String,
+        expected_version: Option<i64>,
     ) -> Result<Watchlist, WatchlistError> {
         let exists: bool = sqlx::query_scalar(
             "SELECT EXISTS(SELECT 1 FROM watchlist_items WHERE watchlist_id = ?1 AND mint = ?2)",
@@ -317,6 +421,7 @@ impl WatchlistManager {
         .await?;
 
         let position = max_position.map(|p| p + 1).unwrap_or(0);
+        self.bump_version(watchlist_id, expected_version).await?;
         let now = Utc::now().to_rfc3339();
 
         sqlx::query(
@@ -346,7 +451,9 @@ impl WatchlistManager {
         &self,
         watchlist_id: &str,
         mint: &str,
+        expected_version: Option<i64>,
     ) -> Result<Watchlist, WatchlistError> {
+        self.bump_version(watchlist_id, expected_version).await?;
         let result = sqlx::query(
             "DELETE FROM watchlist_items WHERE watchlist_id = ?1 AND mint = ?2",
         )
@@ -376,7 +483,10 @@ impl WatchlistManager {
         &self,
         watchlist_id: &str,
         items: Vec<ReorderItem>,
+        expected_version: Option<i64>,
     ) -> Result<Watchlist, WatchlistError> {
+        self.get_watchlist(watchlist_id).await?;
+        self.bump_version(watchlist_id, expected_version).await?;
         let mut tx = self.pool.begin().await?;
 
         for item in items {
@@ -504,11 +614,15 @@ fn watchlist_db_path(app: &AppHandle) -> Result<PathBuf, WatchlistError> {
 pub async fn watchlist_create(
     manager: State<'_, SharedWatchlistManager>,
     name: String,
+    idempotency_key: Option<String>,
 ) -> Result<Watchlist, String> {
     let mgr = manager.read().await;
-    mgr.create_watchlist(name)
-        .await
-        .map_err(|e| e.to_string())
+    run_idempotent("watchlist_create", idempotency_key.as_deref(), &name, || async {
+        mgr.create_watchlist(name.clone())
+            .await
+            .map_err(|e| e.to_string())
+    })
+    .await
 }
 
 #[tauri::command]
@@ -533,20 +647,34 @@ pub async fn watchlist_update(
     manager: State<'_, SharedWatchlistManager>,
     id: String,
     name: String,
-) -> Result<Watchlist, String> {
+    expected_version: Option<i64>,
+) -> Result<Watchlist, VersionedEditError> {
     let mgr = manager.read().await;
-    mgr.update_watchlist(&id, name)
-        .await
-        .map_err(|e| e.to_string())
+    Ok(mgr.update_watchlist(&id, name, expected_version).await?)
 }
 
 #[tauri::command]
 pub async fn watchlist_delete(
+    app_handle: AppHandle,
     manager: State<'_, SharedWatchlistManager>,
     id: String,
 ) -> Result<(), String> {
     let mgr = manager.read().await;
-    mgr.delete_watchlist(&id).await.map_err(|e| e.to_string())
+    let watchlist = mgr.get_watchlist(&id).await.map_err(|e| e.to_string())?;
+    let recycled = recycle_deleted(
+        &app_handle,
+        RecycledEntityKind::Watchlist,
+        &id,
+        &watchlist.name,
+        &watchlist,
+    )
+    .await
+    .map_err(undo_unavailable)?;
+    if let Err(err) = mgr.delete_watchlist(&id).await {
+        discard_recycled(&app_handle, &recycled.id).await;
+        return Err(err.to_string());
+    }
+    Ok(())
 }
 
 #[tauri::command]
@@ -555,11 +683,10 @@ pub async fn watchlist_add_item(
     watchlist_id: String,
     symbol: String,
     mint: String,
-) -> Result<Watchlist, String> {
+    expected_version: Option<i64>,
+) -> Result<Watchlist, VersionedEditError> {
     let mgr = manager.read().await;
-    mgr.add_item(&watchlist_id, symbol, mint)
-        .await
-        .map_err(|e| e.to_string())
+    Ok(mgr.add_item(&watchlist_id, symbol, mint, expected_version).await?)
 }
 
 #[tauri::command]
@@ -567,11 +694,10 @@ pub async fn watchlist_remove_item(
     manager: State<'_, SharedWatchlistManager>,
     watchlist_id: String,
     mint: String,
-) -> Result<Watchlist, String> {
+    expected_version: Option<i64>,
+) -> Result<Watchlist, VersionedEditError> {
     let mgr = manager.read().await;
-    mgr.remove_item(&watchlist_id, &mint)
-        .await
-        .map_err(|e| e.to_string())
+    Ok(mgr.remove_item(&watchlist_id, &mint, expected_version).await?)
 }
 
 #[tauri::command]
@@ -579,11 +705,10 @@ pub async fn watchlist_reorder_items(
     manager: State<'_, SharedWatchlistManager>,
     watchlist_id: String,
     items: Vec<ReorderItem>,
-) -> Result<Watchlist, String> {
+    expected_version: Option<i64>,
+) -> Result<Watchlist, VersionedEditError> {
     let mgr = manager.read().await;
-    mgr.reorder_items(&watchlist_id, items)
-        .await
-        .map_err(|e| e.to_string())
+    Ok(mgr.reorder_items(&watchlist_id, items, expected_version).await?)
 }
 
 #[tauri::command]
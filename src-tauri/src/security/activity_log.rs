@@ -1,6 +1,7 @@
 use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use serde::{Deserialize, Serialize};
 use serde::ser::Serialize as SerializeValue;
+use sha2::{Digest, Sha256};
 use sqlx::{Pool, Row, Sqlite, SqlitePool};
 use sqlx::sqlite::SqliteArguments;
 use std::collections::HashMap;
@@ -8,11 +9,13 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use tauri::AppHandle;
+use tokio::sync::Mutex;
 
 const ACTIVITY_DB_FILE: &str = "activity_logs.db";
 const ACTIVITY_CONFIG_FILE: &str = "activity_log_config.json";
 pub const DEFAULT_RETENTION_DAYS: i64 = 90;
 const MAX_RETENTION_DAYS: i64 = 3650; // ~10 years
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -24,6 +27,11 @@ pub enum ActivityAction {
     Swap,
     Approve,
     Reject,
+    DataExport,
+    DataWipe,
+    Promote,
+    RemoteCommand,
+    Snipe,
 }
 
 impl ActivityAction {
@@ -36,6 +44,11 @@ impl ActivityAction {
             ActivityAction::Swap => "swap",
             ActivityAction::Approve => "approve",
             ActivityAction::Reject => "reject",
+            ActivityAction::DataExport => "data_export",
+            ActivityAction::DataWipe => "data_wipe",
+            ActivityAction::Promote => "promote",
+            ActivityAction::RemoteCommand => "remote_command",
+            ActivityAction::Snipe => "snipe",
         }
     }
 }
@@ -86,6 +99,56 @@ pub struct SuspiciousActivity {
     pub severity: String,
 }
 
+/// Latest link in the activity log hash chain, recorded in backups for cross-checking.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityChainHead {
+    pub log_id: i64,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainLink {
+    pub prev_hash: String,
+    pub entry_hash: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChainedLogEntry {
+    pub log: ActivityLog,
+    pub link: Option<ChainLink>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityIssueKind {
+    MissingLink,
+    Modified,
+    BrokenChain,
+    DeletedEntry,
+    HeadMismatch,
+    BackupHeadMismatch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityIssue {
+    pub log_id: i64,
+    pub kind: IntegrityIssueKind,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityLogIntegrityReport {
+    pub valid: bool,
+    pub entries_checked: usize,
+    pub head: ActivityChainHead,
+    pub issues: Vec<IntegrityIssue>,
+    pub verified_at: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ActivityLogConfig {
     retention_days: i64,
@@ -112,6 +175,9 @@ pub struct ActivityLogger {
     pool: Pool<Sqlite>,
     retention_days: Arc<RwLock<i64>>,
     config_path: Arc<PathBuf>,
+    chain_head: Arc<RwLock<ActivityChainHead>>,
+    // Serializes appends so each entry links to the one written before it
+    chain_lock: Arc<Mutex<()>>,
 }
 
 enum BindValue {
@@ -151,6 +217,11 @@ impl ActivityLogger {
             pool,
             retention_days: Arc::new(RwLock::new(retention_days)),
             config_path: Arc::new(config_path.into()),
+            chain_head: Arc::new(RwLock::new(ActivityChainHead {
+                log_id: 0,
+                hash: GENESIS_HASH.to_string(),
+            })),
+            chain_lock: Arc::new(Mutex::new(())),
         };
 
         logger.initialize().await?;
@@ -185,7 +256,95 @@ impl ActivityLogger {
         .execute(&self.pool)
         .await?;
 
-        Ok(())
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS activity_log_chain (
+                log_id INTEGER PRIMARY KEY,
+                prev_hash TEXT NOT NULL,
+                entry_hash TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // Single row tracking the pruned prefix (anchor) and the latest link (head)
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS activity_log_chain_state (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                anchor_id INTEGER NOT NULL,
+                anchor_hash TEXT NOT NULL,
+                head_id INTEGER NOT NULL,
+                head_hash TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        let state = sqlx::query("SELECT head_id, head_hash FROM activity_log_chain_state WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await?;
+
+        let head = match state {
+            Some(row) => ActivityChainHead {
+                log_id: row.get::<i64, _>("head_id"),
+                hash: row.get::<String, _>("head_hash"),
+            },
+            None => self.seal_existing_logs().await?,
+        };
+
+        self.set_chain_head(head)
+    }
+
+    /// Links rows written before hash chaining existed; only runs once per database.
+    async fn seal_existing_logs(&self) -> Result<ActivityChainHead, ActivityLogError> {
+        let _guard = self.chain_lock.lock().await;
+        let rows = sqlx::query(
+            "SELECT id, wallet_address, action, details_json, ip_address, timestamp, result FROM activity_logs ORDER BY id ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut tx = self.pool.begin().await?;
+        let mut head = ActivityChainHead {
+            log_id: 0,
+            hash: GENESIS_HASH.to_string(),
+        };
+
+        for row in rows {
+            let log = row_to_log(&row);
+            let entry_hash = compute_entry_hash(&head.hash, &log);
+            sqlx::query(
+                "INSERT OR REPLACE INTO activity_log_chain (log_id, prev_hash, entry_hash) VALUES (?1, ?2, ?3)",
+            )
+            .bind(log.id)
+            .bind(&head.hash)
+            .bind(&entry_hash)
+            .execute(&mut *tx)
+            .await?;
+
+            head = ActivityChainHead {
+                log_id: log.id,
+                hash: entry_hash,
+            };
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO activity_log_chain_state (id, anchor_id, anchor_hash, head_id, head_hash)
+            VALUES (1, 0, ?1, ?2, ?3)
+            "#,
+        )
+        .bind(GENESIS_HASH)
+        .bind(head.log_id)
+        .bind(&head.hash)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(head)
     }
 
     pub async fn log_activity<T: SerializeValue + Send + Sync>(
@@ -200,7 +359,11 @@ impl ActivityLogger {
         let result_str = if result { "success" } else { "failure" };
         let details_json = serde_json::to_string(&details)?;
 
-        sqlx::query(
+        let _guard = self.chain_lock.lock().await;
+        let prev_hash = self.chain_head()?.hash;
+        let mut tx = self.pool.begin().await?;
+
+        let id = sqlx::query(
             r#"
             INSERT INTO activity_logs (
                 wallet_address, action, details_json, ip_address, timestamp, result
@@ -209,14 +372,44 @@ impl ActivityLogger {
         )
         .bind(wallet_address)
         .bind(action.as_str())
-        .bind(details_json)
-        .bind(ip_address)
-        .bind(timestamp)
+        .bind(&details_json)
+        .bind(&ip_address)
+        .bind(&timestamp)
         .bind(result_str)
-        .execute(&self.pool)
-        .await?;
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+
+        let log = ActivityLog {
+            id,
+            wallet_address: wallet_address.to_string(),
+            action: action.as_str().to_string(),
+            details_json,
+            ip_address,
+            timestamp,
+            result: result_str.to_string(),
+        };
+        let entry_hash = compute_entry_hash(&prev_hash, &log);
 
-        Ok(())
+        sqlx::query("INSERT INTO activity_log_chain (log_id, prev_hash, entry_hash) VALUES (?1, ?2, ?3)")
+            .bind(id)
+            .bind(&prev_hash)
+            .bind(&entry_hash)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("UPDATE activity_log_chain_state SET head_id = ?1, head_hash = ?2 WHERE id = 1")
+            .bind(id)
+            .bind(&entry_hash)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        self.set_chain_head(ActivityChainHead {
+            log_id: id,
+            hash: entry_hash,
+        })
     }
 
     pub async fn log_connect<T: SerializeValue + Send + Sync>(
@@ -347,18 +540,7 @@ impl ActivityLogger {
             .fetch_all(&self.pool)
             .await?;
 
-        let logs = rows
-            .into_iter()
-            .map(|row| ActivityLog {
-                id: row.get::<i64, _>("id"),
-                wallet_address: row.get::<String, _>("wallet_address"),
-                action: row.get::<String, _>("action"),
-                details_json: row.get::<String, _>("details_json"),
-                ip_address: row.get::<Option<String>, _>("ip_address"),
-                timestamp: row.get::<String, _>("timestamp"),
-                result: row.get::<String, _>("result"),
-            })
-            .collect();
+        let logs = rows.iter().map(row_to_log).collect();
 
         Ok(logs)
     }
@@ -605,14 +787,198 @@ impl ActivityLogger {
         };
 
         let cutoff = (Utc::now() - ChronoDuration::days(days)).to_rfc3339();
-        let result = sqlx::query("DELETE FROM activity_logs WHERE timestamp < ?")
+
+        // Prune a contiguous prefix so the surviving entries still form one chain
+        let _guard = self.chain_lock.lock().await;
+        let boundary: Option<i64> = sqlx::query_scalar("SELECT MAX(id) FROM activity_logs WHERE timestamp < ?")
             .bind(cutoff)
-            .execute(&self.pool)
+            .fetch_one(&self.pool)
             .await?;
 
+        match boundary {
+            Some(boundary) => self.prune_through(boundary).await,
+            None => Ok(0),
+        }
+    }
+
+    /// Deletes every entry; the chain continues from the current head so later entries still verify.
+    pub async fn clear_all_logs(&self) -> Result<u64, ActivityLogError> {
+        let _guard = self.chain_lock.lock().await;
+        let boundary: Option<i64> = sqlx::query_scalar("SELECT MAX(id) FROM activity_logs")
+            .fetch_one(&self.pool)
+            .await?;
+
+        match boundary {
+            Some(boundary) => self.prune_through(boundary).await,
+            None => Ok(0),
+        }
+    }
+
+    // Callers must hold `chain_lock`. Moves the anchor to the last pruned link.
+    async fn prune_through(&self, boundary: i64) -> Result<u64, ActivityLogError> {
+        let mut tx = self.pool.begin().await?;
+        let anchor_hash: Option<String> =
+            sqlx::query_scalar("SELECT entry_hash FROM activity_log_chain WHERE log_id = ?")
+                .bind(boundary)
+                .fetch_optional(&mut *tx)
+                .await?;
+
+        let result = sqlx::query("DELETE FROM activity_logs WHERE id <= ?")
+            .bind(boundary)
+            .execute(&mut *tx)
+            .await?;
+
+        if let Some(anchor_hash) = anchor_hash {
+            sqlx::query("DELETE FROM activity_log_chain WHERE log_id <= ?")
+                .bind(boundary)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query("UPDATE activity_log_chain_state SET anchor_id = ?1, anchor_hash = ?2 WHERE id = 1")
+                .bind(boundary)
+                .bind(anchor_hash)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
         Ok(result.rows_affected())
     }
 
+    pub fn chain_head(&self) -> Result<ActivityChainHead, ActivityLogError> {
+        self.chain_head
+            .read()
+            .map(|guard| guard.clone())
+            .map_err(|_| ActivityLogError::Internal("Failed to read activity chain head".to_string()))
+    }
+
+    fn set_chain_head(&self, head: ActivityChainHead) -> Result<(), ActivityLogError> {
+        let mut guard = self
+            .chain_head
+            .write()
+            .map_err(|_| ActivityLogError::Internal("Failed to update activity chain head".to_string()))?;
+        *guard = head;
+        Ok(())
+    }
+
+    /// Recomputes the hash chain, optionally cross-checking a head recorded in a backup.
+    pub async fn verify_integrity(
+        &self,
+        expected_head: Option<ActivityChainHead>,
+    ) -> Result<ActivityLogIntegrityReport, ActivityLogError> {
+        let _guard = self.chain_lock.lock().await;
+
+        let state = sqlx::query(
+            "SELECT anchor_id, anchor_hash, head_id, head_hash FROM activity_log_chain_state WHERE id = 1",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        let anchor_id: i64 = state.get("anchor_id");
+        let anchor_hash: String = state.get("anchor_hash");
+        let stored_head = ActivityChainHead {
+            log_id: state.get("head_id"),
+            hash: state.get("head_hash"),
+        };
+
+        let rows = sqlx::query(
+            r#"
+            SELECT l.id, l.wallet_address, l.action, l.details_json, l.ip_address, l.timestamp, l.result,
+                   c.prev_hash, c.entry_hash
+            FROM activity_logs l
+            LEFT JOIN activity_log_chain c ON c.log_id = l.id
+            ORDER BY l.id ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let entries: Vec<ChainedLogEntry> = rows
+            .iter()
+            .map(|row| ChainedLogEntry {
+                log: row_to_log(row),
+                link: row
+                    .get::<Option<String>, _>("entry_hash")
+                    .map(|entry_hash| ChainLink {
+                        prev_hash: row.get::<String, _>("prev_hash"),
+                        entry_hash,
+                    }),
+            })
+            .collect();
+
+        let mut issues = verify_chain(&anchor_hash, &entries);
+
+        // Links whose log row is gone mean the row was deleted outside retention cleanup
+        let orphans = sqlx::query(
+            "SELECT log_id FROM activity_log_chain WHERE log_id NOT IN (SELECT id FROM activity_logs) ORDER BY log_id",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        for row in orphans {
+            let log_id: i64 = row.get("log_id");
+            issues.push(IntegrityIssue {
+                log_id,
+                kind: IntegrityIssueKind::DeletedEntry,
+                description: format!("Entry {} was deleted", log_id),
+            });
+        }
+
+        let computed_head = entries
+            .iter()
+            .rev()
+            .find_map(|entry| {
+                entry.link.as_ref().map(|link| ActivityChainHead {
+                    log_id: entry.log.id,
+                    hash: link.entry_hash.clone(),
+                })
+            })
+            .unwrap_or(ActivityChainHead {
+                log_id: anchor_id,
+                hash: anchor_hash.clone(),
+            });
+
+        if computed_head != stored_head {
+            issues.push(IntegrityIssue {
+                log_id: stored_head.log_id,
+                kind: IntegrityIssueKind::HeadMismatch,
+                description: format!(
+                    "Recorded head is entry {} but the chain ends at entry {}",
+                    stored_head.log_id, computed_head.log_id
+                ),
+            });
+        }
+
+        if let Some(expected) = expected_head {
+            // Heads older than the retention anchor were pruned and can't be compared
+            if expected.log_id > anchor_id {
+                let matches = entries.iter().any(|entry| {
+                    entry.log.id == expected.log_id
+                        && entry
+                            .link
+                            .as_ref()
+                            .map(|link| link.entry_hash == expected.hash)
+                            .unwrap_or(false)
+                });
+                if !matches {
+                    issues.push(IntegrityIssue {
+                        log_id: expected.log_id,
+                        kind: IntegrityIssueKind::BackupHeadMismatch,
+                        description: format!(
+                            "Backup head at entry {} is not part of the current chain",
+                            expected.log_id
+                        ),
+                    });
+                }
+            }
+        }
+
+        Ok(ActivityLogIntegrityReport {
+            valid: issues.is_empty(),
+            entries_checked: entries.len(),
+            head: stored_head,
+            issues,
+            verified_at: Utc::now().to_rfc3339(),
+        })
+    }
+
     pub fn current_retention_days(&self) -> Result<i64, ActivityLogError> {
         self.retention_days
             .read()
@@ -645,6 +1011,74 @@ impl ActivityLogger {
     }
 }
 
+/// Hashes an entry together with its predecessor's hash.
+pub fn compute_entry_hash(prev_hash: &str, log: &ActivityLog) -> String {
+    // Encode as a JSON array so field boundaries are unambiguous
+    let payload = serde_json::json!([
+        prev_hash,
+        log.id,
+        log.wallet_address,
+        log.action,
+        log.details_json,
+        log.ip_address,
+        log.timestamp,
+        log.result,
+    ]);
+    let mut hasher = Sha256::new();
+    hasher.update(payload.to_string().as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Walks entries in id order starting from `anchor_hash` and reports every broken link.
+pub fn verify_chain(anchor_hash: &str, entries: &[ChainedLogEntry]) -> Vec<IntegrityIssue> {
+    let mut issues = Vec::new();
+    let mut expected_prev = anchor_hash.to_string();
+
+    for entry in entries {
+        let id = entry.log.id;
+        let Some(link) = &entry.link else {
+            issues.push(IntegrityIssue {
+                log_id: id,
+                kind: IntegrityIssueKind::MissingLink,
+                description: format!("Entry {} was inserted outside the activity logger", id),
+            });
+            continue;
+        };
+
+        if link.prev_hash != expected_prev {
+            issues.push(IntegrityIssue {
+                log_id: id,
+                kind: IntegrityIssueKind::BrokenChain,
+                description: format!("Entry {} does not follow the previous entry; entries may have been removed", id),
+            });
+        }
+
+        if compute_entry_hash(&link.prev_hash, &entry.log) != link.entry_hash {
+            issues.push(IntegrityIssue {
+                log_id: id,
+                kind: IntegrityIssueKind::Modified,
+                description: format!("Entry {} was modified after it was recorded", id),
+            });
+        }
+
+        expected_prev = link.entry_hash.clone();
+    }
+
+    issues
+}
+
+fn row_to_log(row: &sqlx::sqlite::SqliteRow) -> ActivityLog {
+    ActivityLog {
+        id: row.get::<i64, _>("id"),
+        wallet_address: row.get::<String, _>("wallet_address"),
+        action: row.get::<String, _>("action"),
+        details_json: row.get::<String, _>("details_json"),
+        ip_address: row.get::<Option<String>, _>("ip_address"),
+        timestamp: row.get::<String, _>("timestamp"),
+        result: row.get::<String, _>("result"),
+    }
+}
+
 fn bind_values<'q>(
     mut query: sqlx::query::Query<'q, Sqlite, SqliteArguments<'q>>,
     binds: Vec<BindValue>,
@@ -819,3 +1253,14 @@ pub async fn set_activity_retention(
         .set_retention_days(retention_days)
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+pub async fn verify_activity_log_integrity(
+    expected_head: Option<ActivityChainHead>,
+    logger: tauri::State<'_, ActivityLogger>,
+) -> Result<ActivityLogIntegrityReport, String> {
+    logger
+        .verify_integrity(expected_head)
+        .await
+        .map_err(|e| e.to_string())
+}
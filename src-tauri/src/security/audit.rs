@@ -261,13 +261,27 @@ impl HeuristicScanner {
     }
 }
 
+impl AuditSource {
+    fn unavailable(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            status: AuditStatus::Unavailable,
+            score: None,
+            last_updated: None,
+            report_url: None,
+        }
+    }
+}
+
 pub struct CertikClient;
 
 impl CertikClient {
     pub async fn fetch_audit(contract_address: &str) -> Result<AuditSource, String> {
-        // Mock implementation - in production, would call Certik API
-        // For now, simulate with random data based on address
-        
+        // No CertiK integration yet; scores are demo fixtures
+        if !crate::demo::is_demo_mode() {
+            return Ok(AuditSource::unavailable("CertiK"));
+        }
+
         let score = Self::mock_score(contract_address);
         
         Ok(AuditSource {
@@ -298,8 +312,11 @@ pub struct TrailOfBitsClient;
 
 impl TrailOfBitsClient {
     pub async fn fetch_audit(contract_address: &str) -> Result<AuditSource, String> {
-        // Mock implementation - in production, would call Trail of Bits API
-        
+        // No Trail of Bits integration yet; scores are demo fixtures
+        if !crate::demo::is_demo_mode() {
+            return Ok(AuditSource::unavailable("Trail of Bits"));
+        }
+
         let score = Self::mock_score(contract_address);
         
         Ok(AuditSource {
@@ -0,0 +1,758 @@
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+use sqlx::{sqlite::SqliteRow, Pool, Row, Sqlite, SqlitePool};
+use std::collections::BTreeMap;
+use std::fs;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use uuid::Uuid;
+
+use super::audit::Severity;
+use super::keystore::Keystore;
+use crate::api_analytics::{ensure_within_budget, RequestPriority};
+use crate::api_config::{report_api_outcome, ApiConfigManager, RequestOutcome, ResolvedApiKey};
+use crate::data::event_store::{record_app_event, Event as AuditEvent};
+use crate::notifications::router::SharedNotificationRouter;
+use crate::portfolio::{SharedPortfolioData, SharedWatchlistManager};
+
+const AUTHORITY_DB_FILE: &str = "authority_monitor.db";
+pub const AUTHORITY_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+const AUTHORITY_CHANGE_EVENT: &str = "token_authority_changed";
+const RPC_TIMEOUT: Duration = Duration::from_secs(20);
+const METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+/// Metadata accounts start with a key byte and the 32-byte update authority.
+const METADATA_HEADER_LEN: usize = 33;
+
+/// Last observed authorities of a held or watchlisted mint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenAuthoritySnapshot {
+    pub mint: String,
+    pub symbol: String,
+    pub mint_authority: Option<String>,
+    pub freeze_authority: Option<String>,
+    pub update_authority: Option<String>,
+    /// SHA-256 of the metadata account after its header, when the mint has one.
+    pub metadata_hash: Option<String>,
+    pub checked_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorityChangeKind {
+    MintAuthorityEnabled,
+    MintAuthorityChanged,
+    MintAuthorityRevoked,
+    FreezeAuthorityEnabled,
+    FreezeAuthorityChanged,
+    FreezeAuthorityRevoked,
+    UpdateAuthorityChanged,
+    MetadataUpdated,
+}
+
+impl AuthorityChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthorityChangeKind::MintAuthorityEnabled => "mint_authority_enabled",
+            AuthorityChangeKind::MintAuthorityChanged => "mint_authority_changed",
+            AuthorityChangeKind::MintAuthorityRevoked => "mint_authority_revoked",
+            AuthorityChangeKind::FreezeAuthorityEnabled => "freeze_authority_enabled",
+            AuthorityChangeKind::FreezeAuthorityChanged => "freeze_authority_changed",
+            AuthorityChangeKind::FreezeAuthorityRevoked => "freeze_authority_revoked",
+            AuthorityChangeKind::UpdateAuthorityChanged => "update_authority_changed",
+            AuthorityChangeKind::MetadataUpdated => "metadata_updated",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "mint_authority_enabled" => Some(AuthorityChangeKind::MintAuthorityEnabled),
+            "mint_authority_changed" => Some(AuthorityChangeKind::MintAuthorityChanged),
+            "mint_authority_revoked" => Some(AuthorityChangeKind::MintAuthorityRevoked),
+            "freeze_authority_enabled" => Some(AuthorityChangeKind::FreezeAuthorityEnabled),
+            "freeze_authority_changed" => Some(AuthorityChangeKind::FreezeAuthorityChanged),
+            "freeze_authority_revoked" => Some(AuthorityChangeKind::FreezeAuthorityRevoked),
+            "update_authority_changed" => Some(AuthorityChangeKind::UpdateAuthorityChanged),
+            "metadata_updated" => Some(AuthorityChangeKind::MetadataUpdated),
+            _ => None,
+        }
+    }
+
+    /// Regaining the power to mint or freeze is the classic rug setup; any other
+    /// change after launch is still worth a high-severity alert. Revocations only
+    /// reduce risk.
+    pub fn severity(&self) -> Severity {
+        match self {
+            AuthorityChangeKind::MintAuthorityEnabled
+            | AuthorityChangeKind::FreezeAuthorityEnabled => Severity::Critical,
+            AuthorityChangeKind::MintAuthorityRevoked
+            | AuthorityChangeKind::FreezeAuthorityRevoked => Severity::Info,
+            _ => Severity::High,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            AuthorityChangeKind::MintAuthorityEnabled => "Mint authority re-enabled",
+            AuthorityChangeKind::MintAuthorityChanged => "Mint authority changed",
+            AuthorityChangeKind::MintAuthorityRevoked => "Mint authority revoked",
+            AuthorityChangeKind::FreezeAuthorityEnabled => "Freeze authority re-enabled",
+            AuthorityChangeKind::FreezeAuthorityChanged => "Freeze authority changed",
+            AuthorityChangeKind::FreezeAuthorityRevoked => "Freeze authority revoked",
+            AuthorityChangeKind::UpdateAuthorityChanged => "Metadata update authority changed",
+            AuthorityChangeKind::MetadataUpdated => "Token metadata updated",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenAuthorityChange {
+    pub id: String,
+    pub mint: String,
+    pub symbol: String,
+    pub kind: AuthorityChangeKind,
+    pub severity: Severity,
+    pub previous: Option<String>,
+    pub current: Option<String>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl TokenAuthorityChange {
+    fn message(&self) -> String {
+        let describe = |value: &Option<String>| value.clone().unwrap_or_else(|| "none".into());
+        match self.kind {
+            AuthorityChangeKind::MetadataUpdated => format!(
+                "{} ({}) metadata was modified after launch",
+                self.symbol, self.mint
+            ),
+            _ => format!(
+                "{} ({}): {} -> {}",
+                self.symbol,
+                self.mint,
+                describe(&self.previous),
+                describe(&self.current)
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorityCheckReport {
+    pub checked: usize,
+    pub failed: usize,
+    pub changes: Vec<TokenAuthorityChange>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthorityMonitorError {
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("RPC request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("RPC error: {0}")]
+    Rpc(String),
+    #[error("invalid mint: {0}")]
+    InvalidMint(String),
+}
+
+fn authority_transition(
+    previous: &Option<String>,
+    current: &Option<String>,
+    enabled: AuthorityChangeKind,
+    changed: AuthorityChangeKind,
+    revoked: AuthorityChangeKind,
+) -> Option<AuthorityChangeKind> {
+    match (previous, current) {
+        (None, Some(_)) => Some(enabled),
+        (Some(_), None) => Some(revoked),
+        (Some(before), Some(after)) if before != after => Some(changed),
+        _ => None,
+    }
+}
+
+/// Changes between two observations of the same mint.
+pub fn detect_authority_changes(
+    previous: &TokenAuthoritySnapshot,
+    current: &TokenAuthoritySnapshot,
+) -> Vec<TokenAuthorityChange> {
+    let mut found = Vec::new();
+    if let Some(kind) = authority_transition(
+        &previous.mint_authority,
+        &current.mint_authority,
+        AuthorityChangeKind::MintAuthorityEnabled,
+        AuthorityChangeKind::MintAuthorityChanged,
+        AuthorityChangeKind::MintAuthorityRevoked,
+    ) {
+        found.push((kind, &previous.mint_authority, &current.mint_authority));
+    }
+    if let Some(kind) = authority_transition(
+        &previous.freeze_authority,
+        &current.freeze_authority,
+        AuthorityChangeKind::FreezeAuthorityEnabled,
+        AuthorityChangeKind::FreezeAuthorityChanged,
+        AuthorityChangeKind::FreezeAuthorityRevoked,
+    ) {
+        found.push((kind, &previous.freeze_authority, &current.freeze_authority));
+    }
+    if previous.update_authority.is_some()
+        && current.update_authority.is_some()
+        && previous.update_authority != current.update_authority
+    {
+        found.push((
+            AuthorityChangeKind::UpdateAuthorityChanged,
+            &previous.update_authority,
+            &current.update_authority,
+        ));
+    }
+    if previous.metadata_hash != current.metadata_hash {
+        found.push((
+            AuthorityChangeKind::MetadataUpdated,
+            &previous.metadata_hash,
+            &current.metadata_hash,
+        ));
+    }
+
+    found
+        .into_iter()
+        .map(|(kind, before, after)| TokenAuthorityChange {
+            id: Uuid::new_v4().to_string(),
+            mint: current.mint.clone(),
+            symbol: current.symbol.clone(),
+            kind,
+            severity: kind.severity(),
+            previous: before.clone(),
+            current: after.clone(),
+            detected_at: current.checked_at,
+        })
+        .collect()
+}
+
+/// Update authority and content hash of a raw Metaplex metadata account.
+pub fn parse_metadata_account(data: &[u8]) -> Option<(String, String)> {
+    if data.len() <= METADATA_HEADER_LEN {
+        return None;
+    }
+    let update_authority = bs58::encode(&data[1..METADATA_HEADER_LEN]).into_string();
+    let hash = hex::encode(Sha256::digest(&data[METADATA_HEADER_LEN..]));
+    Some((update_authority, hash))
+}
+
+fn metadata_address(mint: &str) -> Result<Pubkey, AuthorityMonitorError> {
+    let mint_key =
+        Pubkey::from_str(mint).map_err(|_| AuthorityMonitorError::InvalidMint(mint.into()))?;
+    let program = Pubkey::from_str(METADATA_PROGRAM_ID)
+        .map_err(|_| AuthorityMonitorError::InvalidMint(METADATA_PROGRAM_ID.into()))?;
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"metadata", program.as_ref(), mint_key.as_ref()],
+        &program,
+    );
+    Ok(address)
+}
+
+fn parse_time(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now())
+}
+
+fn snapshot_from_row(row: &SqliteRow) -> TokenAuthoritySnapshot {
+    TokenAuthoritySnapshot {
+        mint: row.get("mint"),
+        symbol: row.get("symbol"),
+        mint_authority: row.get("mint_authority"),
+        freeze_authority: row.get("freeze_authority"),
+        update_authority: row.get("update_authority"),
+        metadata_hash: row.get("metadata_hash"),
+        checked_at: parse_time(&row.get::<String, _>("checked_at")),
+    }
+}
+
+fn change_from_row(row: &SqliteRow) -> Option<TokenAuthorityChange> {
+    let kind = AuthorityChangeKind::parse(&row.get::<String, _>("kind"))?;
+    Some(TokenAuthorityChange {
+        id: row.get("id"),
+        mint: row.get("mint"),
+        symbol: row.get("symbol"),
+        kind,
+        severity: kind.severity(),
+        previous: row.get("previous"),
+        current: row.get("current"),
+        detected_at: parse_time(&row.get::<String, _>("detected_at")),
+    })
+}
+
+pub struct AuthorityMonitor {
+    pool: Pool<Sqlite>,
+    client: reqwest::Client,
+}
+
+pub type SharedAuthorityMonitor = Arc<AuthorityMonitor>;
+
+impl AuthorityMonitor {
+    pub async fn open(url: &str) -> Result<Self, AuthorityMonitorError> {
+        let pool = SqlitePool::connect(url).await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS token_authority_snapshots (
+                mint TEXT PRIMARY KEY NOT NULL,
+                symbol TEXT NOT NULL,
+                mint_authority TEXT,
+                freeze_authority TEXT,
+                update_authority TEXT,
+                metadata_hash TEXT,
+                checked_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS token_authority_changes (
+                id TEXT PRIMARY KEY NOT NULL,
+                mint TEXT NOT NULL,
+                symbol TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                previous TEXT,
+                current TEXT,
+                detected_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_token_authority_changes_mint ON token_authority_changes(mint, detected_at)",
+        )
+        .execute(&pool)
+        .await?;
+
+        let client = reqwest::Client::builder().timeout(RPC_TIMEOUT).build()?;
+        Ok(Self { pool, client })
+    }
+
+    async fn rpc(
+        &self,
+        app: &AppHandle,
+        rpc: &ResolvedApiKey,
+        method: &str,
+        params: Value,
+    ) -> Result<Value, AuthorityMonitorError> {
+        let payload = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let started = Instant::now();
+        let response = self.client.post(&rpc.key).json(&payload).send().await;
+        let outcome = RequestOutcome::from_response(started, &response);
+        report_api_outcome(app, &rpc.service, rpc.source, outcome).await;
+
+        let data: Value = response?.json().await?;
+        if let Some(error) = data.get("error") {
+            return Err(AuthorityMonitorError::Rpc(error.to_string()));
+        }
+        Ok(data["result"].clone())
+    }
+
+    /// Reads the mint account and its metadata account. A mint without metadata
+    /// has no update authority or hash.
+    async fn fetch_snapshot(
+        &self,
+        app: &AppHandle,
+        rpc: &ResolvedApiKey,
+        mint: &str,
+        symbol: &str,
+    ) -> Result<TokenAuthoritySnapshot, AuthorityMonitorError> {
+        let account = self
+            .rpc(
+                app,
+                rpc,
+                "getAccountInfo",
+                json!([mint, { "encoding": "jsonParsed", "commitment": "confirmed" }]),
+            )
+            .await?;
+        let info = &account["value"]["data"]["parsed"]["info"];
+        if !info.is_object() {
+            return Err(AuthorityMonitorError::InvalidMint(mint.to_string()));
+        }
+
+        let metadata = self
+            .rpc(
+                app,
+                rpc,
+                "getAccountInfo",
+                json!([
+                    metadata_address(mint)?.to_string(),
+                    { "encoding": "base64", "commitment": "confirmed" }
+                ]),
+            )
+            .await?;
+        let parsed = metadata["value"]["data"][0]
+            .as_str()
+            .and_then(|encoded| general_purpose::STANDARD.decode(encoded).ok())
+            .and_then(|data| parse_metadata_account(&data));
+        let (update_authority, metadata_hash) = parsed.unzip();
+
+        Ok(TokenAuthoritySnapshot {
+            mint: mint.to_string(),
+            symbol: symbol.to_string(),
+            mint_authority: info["mintAuthority"].as_str().map(str::to_string),
+            freeze_authority: info["freezeAuthority"].as_str().map(str::to_string),
+            update_authority,
+            metadata_hash,
+            checked_at: Utc::now(),
+        })
+    }
+
+    async fn snapshot(
+        &self,
+        mint: &str,
+    ) -> Result<Option<TokenAuthoritySnapshot>, AuthorityMonitorError> {
+        let row = sqlx::query("SELECT * FROM token_authority_snapshots WHERE mint = ?1")
+            .bind(mint)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.as_ref().map(snapshot_from_row))
+    }
+
+    /// Fetches the current authorities, stores them and returns what changed since
+    /// the last check. The first observation of a mint is the baseline.
+    pub async fn check_token(
+        &self,
+        app: &AppHandle,
+        rpc: &ResolvedApiKey,
+        mint: &str,
+        symbol: &str,
+    ) -> Result<Vec<TokenAuthorityChange>, AuthorityMonitorError> {
+        let current = self.fetch_snapshot(app, rpc, mint, symbol).await?;
+        let changes = match self.snapshot(mint).await? {
+            Some(previous) => detect_authority_changes(&previous, &current),
+            None => Vec::new(),
+        };
+
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO token_authority_snapshots
+                (mint, symbol, mint_authority, freeze_authority, update_authority, metadata_hash, checked_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )
+        .bind(&current.mint)
+        .bind(&current.symbol)
+        .bind(&current.mint_authority)
+        .bind(&current.freeze_authority)
+        .bind(&current.update_authority)
+        .bind(&current.metadata_hash)
+        .bind(current.checked_at.to_rfc3339())
+        .execute(&mut *tx)
+        .await?;
+
+        for change in &changes {
+            sqlx::query(
+                r#"
+                INSERT INTO token_authority_changes
+                    (id, mint, symbol, kind, previous, current, detected_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                "#,
+            )
+            .bind(&change.id)
+            .bind(&change.mint)
+            .bind(&change.symbol)
+            .bind(change.kind.as_str())
+            .bind(&change.previous)
+            .bind(&change.current)
+            .bind(change.detected_at.to_rfc3339())
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(changes)
+    }
+
+    pub async fn list_snapshots(
+        &self,
+    ) -> Result<Vec<TokenAuthoritySnapshot>, AuthorityMonitorError> {
+        let rows = sqlx::query("SELECT * FROM token_authority_snapshots ORDER BY symbol")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows.iter().map(snapshot_from_row).collect())
+    }
+
+    pub async fn list_changes(
+        &self,
+        mint: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<TokenAuthorityChange>, AuthorityMonitorError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM token_authority_changes
+            WHERE ?1 IS NULL OR mint = ?1
+            ORDER BY detected_at DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(mint)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.iter().filter_map(change_from_row).collect())
+    }
+}
+
+fn resolve_rpc(app: &AppHandle) -> Result<ResolvedApiKey, String> {
+    let manager = app
+        .try_state::<ApiConfigManager>()
+        .ok_or_else(|| "API configuration unavailable".to_string())?;
+    let keystore = app
+        .try_state::<Keystore>()
+        .ok_or_else(|| "Keystore unavailable".to_string())?;
+    manager.resolve_key("solana_rpc", &keystore)
+}
+
+/// Held and watchlisted mints with their symbols.
+async fn monitored_tokens(app: &AppHandle) -> BTreeMap<String, String> {
+    let mut tokens = BTreeMap::new();
+
+    if let Some(portfolio) = app.try_state::<SharedPortfolioData>() {
+        if let Ok(portfolio) = portfolio.lock() {
+            for position in portfolio.positions() {
+                tokens.insert(position.mint, position.symbol);
+            }
+        }
+    }
+
+    if let Some(manager) = app.try_state::<SharedWatchlistManager>() {
+        match manager.read().await.list_watchlists().await {
+            Ok(watchlists) => {
+                for item in watchlists.into_iter().flat_map(|w| w.items) {
+                    tokens.entry(item.mint).or_insert(item.symbol);
+                }
+            }
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to load watchlists for authority monitor")
+            }
+        }
+    }
+
+    tokens.retain(|mint, _| !mint.is_empty());
+    tokens
+}
+
+/// Stores the change as an event and alerts on anything that increases risk.
+async fn raise_authority_alert(app: &AppHandle, change: &TokenAuthorityChange) {
+    record_app_event(
+        app,
+        &format!("token_{}", change.mint),
+        AuditEvent::TokenAuthorityChanged {
+            mint: change.mint.clone(),
+            symbol: change.symbol.clone(),
+            change: change.kind.as_str().to_string(),
+            previous: change.previous.clone(),
+            current: change.current.clone(),
+            timestamp: change.detected_at,
+        },
+    )
+    .await;
+
+    if let Err(err) = app.emit_all(AUTHORITY_CHANGE_EVENT, change) {
+        tracing::warn!(error = %err, "failed to emit authority change");
+    }
+    if change.severity == Severity::Info {
+        return;
+    }
+
+    let Some(router) = app.try_state::<SharedNotificationRouter>() else {
+        return;
+    };
+    let title = format!("{}: {}", change.kind.title(), change.symbol);
+    let message = change.message();
+    let router = router.read().await;
+    let result = if change.severity == Severity::Critical {
+        router
+            .send_critical_notification(&change.id, &title, &message)
+            .await
+    } else {
+        router
+            .send_broadcast_notification(&change.id, &title, &message)
+            .await
+    };
+    if let Err(err) = result {
+        tracing::warn!(error = %err, "failed to deliver authority change alert");
+    }
+}
+
+/// Checks every held and watchlisted mint once, alerting on each change.
+pub async fn run_authority_check(
+    app: &AppHandle,
+    monitor: &AuthorityMonitor,
+    priority: RequestPriority,
+) -> Result<AuthorityCheckReport, String> {
+    let rpc = resolve_rpc(app)?;
+    ensure_within_budget(app, &rpc.service, priority)?;
+    let mut report = AuthorityCheckReport::default();
+
+    for (mint, symbol) in monitored_tokens(app).await {
+        match monitor.check_token(app, &rpc, &mint, &symbol).await {
+            Ok(changes) => {
+                report.checked += 1;
+                for change in &changes {
+                    raise_authority_alert(app, change).await;
+                }
+                report.changes.extend(changes);
+            }
+            Err(err) => {
+                report.failed += 1;
+                tracing::warn!(mint = %mint, error = %err, "authority check failed");
+            }
+        }
+    }
+    Ok(report)
+}
+
+pub fn register_authority_monitor(app: &tauri::App) {
+    let Some(dir) = app.path_resolver().app_data_dir() else {
+        tracing::warn!("app data directory unavailable; authority monitoring disabled");
+        return;
+    };
+    if let Err(err) = fs::create_dir_all(&dir) {
+        tracing::warn!(error = %err, "failed to create app data directory");
+    }
+    let url = format!("sqlite:{}?mode=rwc", dir.join(AUTHORITY_DB_FILE).display());
+
+    let monitor = match tauri::async_runtime::block_on(AuthorityMonitor::open(&url)) {
+        Ok(monitor) => Arc::new(monitor),
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to open authority monitor");
+            return;
+        }
+    };
+    app.manage::<SharedAuthorityMonitor>(monitor.clone());
+
+    let handle = app.handle();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let result =
+                run_authority_check(&handle, &monitor, RequestPriority::Background).await;
+            if let Err(err) = result {
+                tracing::debug!(error = %err, "skipping authority check");
+            }
+            tokio::time::sleep(AUTHORITY_CHECK_INTERVAL).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_token_authority_snapshots(
+    monitor: State<'_, SharedAuthorityMonitor>,
+) -> Result<Vec<TokenAuthoritySnapshot>, String> {
+    monitor.list_snapshots().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_token_authority_changes(
+    mint: Option<String>,
+    limit: Option<i64>,
+    monitor: State<'_, SharedAuthorityMonitor>,
+) -> Result<Vec<TokenAuthorityChange>, String> {
+    monitor
+        .list_changes(mint.as_deref(), limit.unwrap_or(100).clamp(1, 1_000))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn check_token_authorities(
+    app: AppHandle,
+    monitor: State<'_, SharedAuthorityMonitor>,
+) -> Result<AuthorityCheckReport, String> {
+    run_authority_check(&app, &monitor, RequestPriority::Interactive).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(
+        mint_authority: Option<&str>,
+        freeze_authority: Option<&str>,
+    ) -> TokenAuthoritySnapshot {
+        TokenAuthoritySnapshot {
+            mint: "Mint111".into(),
+            symbol: "TEST".into(),
+            mint_authority: mint_authority.map(str::to_string),
+            freeze_authority: freeze_authority.map(str::to_string),
+            update_authority: Some("Update111".into()),
+            metadata_hash: Some("abc".into()),
+            checked_at: Utc::now(),
+        }
+    }
+
+    fn kinds(changes: &[TokenAuthorityChange]) -> Vec<AuthorityChangeKind> {
+        changes.iter().map(|change| change.kind).collect()
+    }
+
+    #[test]
+    fn reenabled_authorities_are_critical() {
+        let before = snapshot(None, None);
+        let after = snapshot(Some("Attacker"), Some("Attacker"));
+
+        let changes = detect_authority_changes(&before, &after);
+        assert_eq!(
+            kinds(&changes),
+            vec![
+                AuthorityChangeKind::MintAuthorityEnabled,
+                AuthorityChangeKind::FreezeAuthorityEnabled
+            ]
+        );
+        assert!(changes.iter().all(|c| c.severity == Severity::Critical));
+        assert_eq!(changes[0].current.as_deref(), Some("Attacker"));
+
+        let revoked = detect_authority_changes(&after, &before);
+        assert!(revoked.iter().all(|c| c.severity == Severity::Info));
+        assert!(detect_authority_changes(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn metadata_and_update_authority_changes_are_high() {
+        let before = snapshot(None, None);
+        let mut after = before.clone();
+        after.update_authority = Some("Update222".into());
+        after.metadata_hash = Some("def".into());
+
+        let changes = detect_authority_changes(&before, &after);
+        assert_eq!(
+            kinds(&changes),
+            vec![
+                AuthorityChangeKind::UpdateAuthorityChanged,
+                AuthorityChangeKind::MetadataUpdated
+            ]
+        );
+        assert!(changes.iter().all(|c| c.severity == Severity::High));
+    }
+
+    #[test]
+    fn metadata_account_header_is_split_from_content() {
+        let mut data = vec![4u8];
+        data.extend([7u8; 32]);
+        data.extend(b"name");
+        let (update_authority, hash) = parse_metadata_account(&data).unwrap();
+        assert_eq!(update_authority, bs58::encode([7u8; 32]).into_string());
+        assert_eq!(hash, hex::encode(Sha256::digest(b"name")));
+
+        // Changing the update authority alone leaves the content hash untouched
+        data[1] = 9;
+        assert_eq!(parse_metadata_account(&data).unwrap().1, hash);
+        assert!(parse_metadata_account(&data[..METADATA_HEADER_LEN]).is_none());
+        assert!(metadata_address("not-a-mint").is_err());
+    }
+}
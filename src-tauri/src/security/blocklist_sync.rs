@@ -0,0 +1,654 @@
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock as SyncRwLock;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use sqlx::{FromRow, Pool, Sqlite, SqlitePool};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, State};
+use uuid::Uuid;
+
+use super::reputation::{
+    ReputationEngine, ReputationError, SharedReputationEngine, REPUTATION_DB_FILE,
+};
+
+pub const BLOCKLIST_SYNC_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+const SOURCE_LABEL_PREFIX: &str = "community:";
+
+/// A community list endpoint. Lists are only merged when the payload is signed by
+/// `public_key` (base58 Ed25519 key, same encoding as Solana addresses).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct BlocklistSource {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub public_key: String,
+    pub enabled: bool,
+    pub last_synced_at: Option<String>,
+    pub last_error: Option<String>,
+    pub entry_count: i64,
+    pub created_at: String,
+}
+
+/// Wire format served by a source: `payload` is the list JSON exactly as signed,
+/// `signature` is the base58 Ed25519 signature over its bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedBlocklist {
+    pub payload: String,
+    pub signature: String,
+}
+
+fn default_entry_type() -> String {
+    "token".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommunityBlocklistEntry {
+    pub address: String,
+    #[serde(default = "default_entry_type")]
+    pub entry_type: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityBlocklist {
+    #[serde(default)]
+    pub generated_at: Option<DateTime<Utc>>,
+    pub entries: Vec<CommunityBlocklistEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct BlocklistProvenance {
+    pub address: String,
+    pub entry_type: String,
+    pub source_id: String,
+    pub reason: String,
+    pub first_seen: String,
+    pub last_seen: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlocklistSyncReport {
+    pub source_id: String,
+    pub added: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+    pub rejected: usize,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DenylistHit {
+    pub reason: String,
+    pub source: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BlocklistSyncError {
+    #[error(transparent)]
+    Reputation(#[from] ReputationError),
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+    #[error("fetch failed: {0}")]
+    Fetch(#[from] reqwest::Error),
+    #[error("invalid list: {0}")]
+    InvalidList(String),
+    #[error("signature verification failed")]
+    InvalidSignature,
+    #[error("source not found: {0}")]
+    NotFound(String),
+}
+
+lazy_static::lazy_static! {
+    static ref DENYLIST: SyncRwLock<HashMap<String, DenylistHit>> =
+        SyncRwLock::new(HashMap::new());
+}
+
+/// Synchronous lookup for the scanner and trading guards.
+pub fn denylist_match(address: &str) -> Option<DenylistHit> {
+    DENYLIST.read().get(address).cloned()
+}
+
+/// Rebuilds the in-memory denylist from the active blacklist entries.
+pub async fn refresh_denylist(engine: &ReputationEngine) -> Result<usize, ReputationError> {
+    let entries = engine.get_blacklist(None).await?;
+    let denylist: HashMap<String, DenylistHit> = entries
+        .into_iter()
+        .map(|entry| {
+            (
+                entry.address,
+                DenylistHit {
+                    reason: entry.reason,
+                    source: entry.source,
+                },
+            )
+        })
+        .collect();
+
+    let count = denylist.len();
+    *DENYLIST.write() = denylist;
+    Ok(count)
+}
+
+pub fn source_label(source_id: &str) -> String {
+    format!("{SOURCE_LABEL_PREFIX}{source_id}")
+}
+
+/// Checks the signature and decodes the list. Nothing from an unsigned or
+/// tampered payload is returned.
+pub fn verify_signed_blocklist(
+    signed: &SignedBlocklist,
+    public_key: &str,
+) -> Result<CommunityBlocklist, BlocklistSyncError> {
+    let public_key = Pubkey::from_str(public_key)
+        .map_err(|e| BlocklistSyncError::InvalidList(format!("invalid public key: {e}")))?;
+    let signature =
+        Signature::from_str(&signed.signature).map_err(|_| BlocklistSyncError::InvalidSignature)?;
+
+    if !signature.verify(public_key.as_ref(), signed.payload.as_bytes()) {
+        return Err(BlocklistSyncError::InvalidSignature);
+    }
+
+    serde_json::from_str(&signed.payload)
+        .map_err(|e| BlocklistSyncError::InvalidList(e.to_string()))
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct BlocklistMergePlan {
+    pub added: Vec<CommunityBlocklistEntry>,
+    pub kept: Vec<CommunityBlocklistEntry>,
+    pub removed: Vec<(String, String)>,
+    pub rejected: usize,
+}
+
+/// Splits a verified list into new, still-listed and withdrawn entries relative to
+/// what the source contributed before. Malformed addresses are dropped.
+pub fn plan_blocklist_merge(
+    previous: &HashSet<(String, String)>,
+    incoming: Vec<CommunityBlocklistEntry>,
+) -> BlocklistMergePlan {
+    let mut plan = BlocklistMergePlan::default();
+    let mut seen = HashSet::new();
+
+    for entry in incoming {
+        let valid_type = entry.entry_type == "token" || entry.entry_type == "wallet";
+        if !valid_type || Pubkey::from_str(&entry.address).is_err() {
+            plan.rejected += 1;
+            continue;
+        }
+        let key = (entry.address.clone(), entry.entry_type.clone());
+        if !seen.insert(key.clone()) {
+            continue;
+        }
+        if previous.contains(&key) {
+            plan.kept.push(entry);
+        } else {
+            plan.added.push(entry);
+        }
+    }
+
+    let mut removed: Vec<(String, String)> = previous.difference(&seen).cloned().collect();
+    removed.sort();
+    plan.removed = removed;
+    plan
+}
+
+pub struct BlocklistSync {
+    pool: Pool<Sqlite>,
+    reputation: SharedReputationEngine,
+    client: reqwest::Client,
+}
+
+pub type SharedBlocklistSync = Arc<BlocklistSync>;
+
+impl BlocklistSync {
+    pub async fn new(
+        app_handle: &AppHandle,
+        reputation: SharedReputationEngine,
+    ) -> Result<Self, BlocklistSyncError> {
+        let app_dir = app_handle.path_resolver().app_data_dir().ok_or_else(|| {
+            ReputationError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Unable to resolve app data directory",
+            ))
+        })?;
+        let db_path = app_dir.join(REPUTATION_DB_FILE);
+        let pool = SqlitePool::connect(&format!("sqlite:{}?mode=rwc", db_path.display())).await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS blocklist_sources (
+                id TEXT PRIMARY KEY NOT NULL,
+                name TEXT NOT NULL,
+                url TEXT NOT NULL,
+                public_key TEXT NOT NULL,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                last_synced_at TEXT,
+                last_error TEXT,
+                entry_count INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS blocklist_provenance (
+                address TEXT NOT NULL,
+                entry_type TEXT NOT NULL,
+                source_id TEXT NOT NULL,
+                reason TEXT NOT NULL,
+                first_seen TEXT NOT NULL,
+                last_seen TEXT NOT NULL,
+                PRIMARY KEY (address, entry_type, source_id)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        let client = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .build()?;
+
+        Ok(Self {
+            pool,
+            reputation,
+            client,
+        })
+    }
+
+    pub async fn list_sources(&self) -> Result<Vec<BlocklistSource>, BlocklistSyncError> {
+        Ok(sqlx::query_as::<_, BlocklistSource>(
+            "SELECT * FROM blocklist_sources ORDER BY created_at ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    async fn get_source(&self, id: &str) -> Result<BlocklistSource, BlocklistSyncError> {
+        sqlx::query_as::<_, BlocklistSource>("SELECT * FROM blocklist_sources WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| BlocklistSyncError::NotFound(id.to_string()))
+    }
+
+    pub async fn add_source(
+        &self,
+        name: &str,
+        url: &str,
+        public_key: &str,
+    ) -> Result<BlocklistSource, BlocklistSyncError> {
+        if !url.starts_with("https://") {
+            return Err(BlocklistSyncError::InvalidList(
+                "source URL must use https".to_string(),
+            ));
+        }
+        Pubkey::from_str(public_key)
+            .map_err(|e| BlocklistSyncError::InvalidList(format!("invalid public key: {e}")))?;
+
+        let id = Uuid::new_v4().to_string();
+        sqlx::query(
+            r#"
+            INSERT INTO blocklist_sources (id, name, url, public_key, enabled, created_at)
+            VALUES (?, ?, ?, ?, 1, ?)
+            "#,
+        )
+        .bind(&id)
+        .bind(name)
+        .bind(url)
+        .bind(public_key)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        self.get_source(&id).await
+    }
+
+    /// Disabling a source withdraws everything it contributed; enabling it again
+    /// takes effect on the next sync.
+    pub async fn set_source_enabled(
+        &self,
+        id: &str,
+        enabled: bool,
+    ) -> Result<BlocklistSource, BlocklistSyncError> {
+        self.get_source(id).await?;
+        sqlx::query("UPDATE blocklist_sources SET enabled = ? WHERE id = ?")
+            .bind(if enabled { 1 } else { 0 })
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        if !enabled {
+            self.withdraw_source(id).await?;
+        }
+        self.get_source(id).await
+    }
+
+    pub async fn remove_source(&self, id: &str) -> Result<(), BlocklistSyncError> {
+        self.withdraw_source(id).await?;
+        sqlx::query("DELETE FROM blocklist_sources WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn source_entries(
+        &self,
+        source_id: &str,
+    ) -> Result<HashSet<(String, String)>, BlocklistSyncError> {
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT address, entry_type FROM blocklist_provenance WHERE source_id = ?",
+        )
+        .bind(source_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    async fn withdraw_source(&self, source_id: &str) -> Result<(), BlocklistSyncError> {
+        let label = source_label(source_id);
+        let engine = self.reputation.read().await;
+        for (address, entry_type) in self.source_entries(source_id).await? {
+            engine
+                .remove_blacklist_source_entry(&address, &entry_type, &label)
+                .await?;
+        }
+
+        sqlx::query("DELETE FROM blocklist_provenance WHERE source_id = ?")
+            .bind(source_id)
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("UPDATE blocklist_sources SET entry_count = 0 WHERE id = ?")
+            .bind(source_id)
+            .execute(&self.pool)
+            .await?;
+
+        refresh_denylist(&engine).await?;
+        Ok(())
+    }
+
+    async fn fetch_and_merge(
+        &self,
+        source: &BlocklistSource,
+    ) -> Result<BlocklistSyncReport, BlocklistSyncError> {
+        let signed: SignedBlocklist = self
+            .client
+            .get(&source.url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let list = verify_signed_blocklist(&signed, &source.public_key)?;
+
+        let previous = self.source_entries(&source.id).await?;
+        let plan = plan_blocklist_merge(&previous, list.entries);
+        let label = source_label(&source.id);
+        let now = Utc::now().to_rfc3339();
+        let engine = self.reputation.read().await;
+
+        for entry in &plan.added {
+            engine
+                .add_to_blacklist(
+                    &entry.address,
+                    &entry.entry_type,
+                    &entry.reason,
+                    None,
+                    &label,
+                )
+                .await?;
+        }
+        for entry in plan.added.iter().chain(plan.kept.iter()) {
+            sqlx::query(
+                r#"
+                INSERT INTO blocklist_provenance (address, entry_type, source_id, reason, first_seen, last_seen)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?5)
+                ON CONFLICT(address, entry_type, source_id) DO UPDATE SET
+                    reason = excluded.reason,
+                    last_seen = excluded.last_seen
+                "#,
+            )
+            .bind(&entry.address)
+            .bind(&entry.entry_type)
+            .bind(&source.id)
+            .bind(&entry.reason)
+            .bind(&now)
+            .execute(&self.pool)
+            .await?;
+        }
+        for (address, entry_type) in &plan.removed {
+            engine
+                .remove_blacklist_source_entry(address, entry_type, &label)
+                .await?;
+            sqlx::query(
+                "DELETE FROM blocklist_provenance WHERE address = ? AND entry_type = ? AND source_id = ?",
+            )
+            .bind(address)
+            .bind(entry_type)
+            .bind(&source.id)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        refresh_denylist(&engine).await?;
+
+        Ok(BlocklistSyncReport {
+            source_id: source.id.clone(),
+            added: plan.added.len(),
+            removed: plan.removed.len(),
+            unchanged: plan.kept.len(),
+            rejected: plan.rejected,
+            error: None,
+        })
+    }
+
+    pub async fn sync_source(&self, id: &str) -> Result<BlocklistSyncReport, BlocklistSyncError> {
+        let source = self.get_source(id).await?;
+        let now = Utc::now().to_rfc3339();
+
+        match self.fetch_and_merge(&source).await {
+            Ok(report) => {
+                sqlx::query(
+                    r#"
+                    UPDATE blocklist_sources
+                    SET last_synced_at = ?, last_error = NULL, entry_count = ?
+                    WHERE id = ?
+                    "#,
+                )
+                .bind(&now)
+                .bind((report.added + report.unchanged) as i64)
+                .bind(id)
+                .execute(&self.pool)
+                .await?;
+                Ok(report)
+            }
+            Err(e) => {
+                // A failed fetch or bad signature keeps the previously merged entries.
+                sqlx::query("UPDATE blocklist_sources SET last_error = ? WHERE id = ?")
+                    .bind(e.to_string())
+                    .bind(id)
+                    .execute(&self.pool)
+                    .await?;
+                Ok(BlocklistSyncReport {
+                    source_id: id.to_string(),
+                    error: Some(e.to_string()),
+                    ..BlocklistSyncReport::default()
+                })
+            }
+        }
+    }
+
+    pub async fn sync_all(&self) -> Result<Vec<BlocklistSyncReport>, BlocklistSyncError> {
+        let mut reports = Vec::new();
+        for source in self.list_sources().await? {
+            if source.enabled {
+                reports.push(self.sync_source(&source.id).await?);
+            }
+        }
+        Ok(reports)
+    }
+
+    pub async fn provenance(
+        &self,
+        address: &str,
+    ) -> Result<Vec<BlocklistProvenance>, BlocklistSyncError> {
+        Ok(sqlx::query_as::<_, BlocklistProvenance>(
+            "SELECT * FROM blocklist_provenance WHERE address = ? ORDER BY first_seen ASC",
+        )
+        .bind(address)
+        .fetch_all(&self.pool)
+        .await?)
+    }
+}
+
+#[tauri::command]
+pub async fn list_blocklist_sources(
+    sync: State<'_, SharedBlocklistSync>,
+) -> Result<Vec<BlocklistSource>, String> {
+    sync.list_sources().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn add_blocklist_source(
+    name: String,
+    url: String,
+    public_key: String,
+    sync: State<'_, SharedBlocklistSync>,
+) -> Result<BlocklistSource, String> {
+    sync.add_source(&name, &url, &public_key)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn set_blocklist_source_enabled(
+    id: String,
+    enabled: bool,
+    sync: State<'_, SharedBlocklistSync>,
+) -> Result<BlocklistSource, String> {
+    sync.set_source_enabled(&id, enabled)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn remove_blocklist_source(
+    id: String,
+    sync: State<'_, SharedBlocklistSync>,
+) -> Result<(), String> {
+    sync.remove_source(&id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn sync_blocklists(
+    source_id: Option<String>,
+    sync: State<'_, SharedBlocklistSync>,
+) -> Result<Vec<BlocklistSyncReport>, String> {
+    let result = match source_id {
+        Some(id) => sync.sync_source(&id).await.map(|report| vec![report]),
+        None => sync.sync_all().await,
+    };
+    result.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_blocklist_provenance(
+    address: String,
+    sync: State<'_, SharedBlocklistSync>,
+) -> Result<Vec<BlocklistProvenance>, String> {
+    sync.provenance(&address).await.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    fn entry(address: &str, reason: &str) -> CommunityBlocklistEntry {
+        CommunityBlocklistEntry {
+            address: address.to_string(),
+            entry_type: "token".to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    fn signed_list(keypair: &Keypair, entries: Vec<CommunityBlocklistEntry>) -> SignedBlocklist {
+        let payload = serde_json::to_string(&CommunityBlocklist {
+            generated_at: None,
+            entries,
+        })
+        .unwrap();
+        SignedBlocklist {
+            signature: keypair.sign_message(payload.as_bytes()).to_string(),
+            payload,
+        }
+    }
+
+    #[test]
+    fn only_correctly_signed_lists_are_accepted() {
+        let publisher = Keypair::new();
+        let mint = Pubkey::new_unique().to_string();
+        let signed = signed_list(&publisher, vec![entry(&mint, "honeypot")]);
+
+        let list = verify_signed_blocklist(&signed, &publisher.pubkey().to_string()).unwrap();
+        assert_eq!(list.entries, vec![entry(&mint, "honeypot")]);
+
+        let other_key = Keypair::new().pubkey().to_string();
+        assert!(matches!(
+            verify_signed_blocklist(&signed, &other_key),
+            Err(BlocklistSyncError::InvalidSignature)
+        ));
+
+        let mut tampered = signed.clone();
+        tampered.payload = tampered.payload.replace("honeypot", "fine");
+        assert!(verify_signed_blocklist(&tampered, &publisher.pubkey().to_string()).is_err());
+    }
+
+    #[test]
+    fn merge_plan_tracks_additions_and_withdrawals() {
+        let kept = Pubkey::new_unique().to_string();
+        let withdrawn = Pubkey::new_unique().to_string();
+        let new = Pubkey::new_unique().to_string();
+        let previous: HashSet<(String, String)> = [
+            (kept.clone(), "token".to_string()),
+            (withdrawn.clone(), "token".to_string()),
+        ]
+        .into_iter()
+        .collect();
+
+        let plan = plan_blocklist_merge(
+            &previous,
+            vec![
+                entry(&kept, "rug"),
+                entry(&new, "drainer"),
+                entry(&new, "duplicate"),
+                entry("not-an-address", "junk"),
+            ],
+        );
+
+        assert_eq!(plan.added, vec![entry(&new, "drainer")]);
+        assert_eq!(plan.kept, vec![entry(&kept, "rug")]);
+        assert_eq!(plan.removed, vec![(withdrawn, "token".to_string())]);
+        assert_eq!(plan.rejected, 1);
+    }
+
+    #[test]
+    fn entry_type_defaults_to_token() {
+        let list: CommunityBlocklist =
+            serde_json::from_str(r#"{"entries":[{"address":"abc","reason":"scam"}]}"#).unwrap();
+        assert_eq!(list.entries[0].entry_type, "token");
+        assert!(list.generated_at.is_none());
+    }
+}
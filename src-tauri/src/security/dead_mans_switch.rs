@@ -0,0 +1,951 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use crate::notifications::email::{EmailAttachment, EmailManager, SendEmailRequest};
+use crate::notifications::router::SharedNotificationRouter;
+use crate::security::keystore::{seal_with_password, Keystore};
+use crate::wallet::multisig::{MultisigDatabase, ProposalStatus, SharedMultisigDatabase};
+use crate::wallet::tx_scheduler::{
+    create_schedule, ScheduleTransactionRequest, ScheduledTxKind, SharedTransactionScheduler,
+    MIN_CANCELLATION_WINDOW_SECS,
+};
+
+const SWITCH_CONFIG_FILE: &str = "dead_mans_switch.json";
+const KEY_INSTRUCTIONS: &str = "dead_mans_switch.instructions";
+const KEY_RELEASE_PASSPHRASE: &str = "dead_mans_switch.passphrase";
+const REMINDER_EVENT: &str = "dead_mans_switch_reminder";
+const RELEASE_EVENT: &str = "dead_mans_switch_released";
+const MONITOR_INTERVAL: StdDuration = StdDuration::from_secs(10 * 60);
+/// Delay before the first check, so an owner returning after a long absence
+/// has time to open the app and check in.
+const STARTUP_GRACE: StdDuration = StdDuration::from_secs(30 * 60);
+/// Minimum time between the final reminder and a release.
+const RELEASE_NOTICE_HOURS: i64 = 12;
+const EXPORT_FILENAME: &str = "estate-instructions.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeadMansSwitchError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("invalid configuration: {0}")]
+    InvalidConfig(String),
+    #[error("switch has already been released")]
+    AlreadyReleased,
+    #[error("switch is not configured")]
+    NotConfigured,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DesignatedContact {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ReleaseAction {
+    /// Email the instructions, sealed with the release passphrase, to every contact.
+    EncryptedExport,
+    /// Schedule an approved multisig proposal to execute after `delay_secs`.
+    #[serde(rename_all = "camelCase")]
+    ActivateProposal {
+        proposal_id: String,
+        delay_secs: i64,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadMansSwitchConfig {
+    pub enabled: bool,
+    pub check_in_period_hours: u32,
+    /// Hours before the deadline at which reminders go out; later reminders escalate.
+    pub reminder_hours_before: Vec<u32>,
+    pub owner_email: Option<String>,
+    pub contacts: Vec<DesignatedContact>,
+    pub release_actions: Vec<ReleaseAction>,
+    pub last_check_in: DateTime<Utc>,
+    pub reminders_sent: usize,
+    /// When the final reminder went out; releases wait `RELEASE_NOTICE_HOURS` after it.
+    #[serde(default)]
+    pub final_notice_at: Option<DateTime<Utc>>,
+    pub released_at: Option<DateTime<Utc>>,
+    /// Latest outcome per action. Successful actions are not repeated when a
+    /// failed release is retried.
+    pub release_results: Vec<ReleaseResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigureSwitchRequest {
+    pub enabled: bool,
+    pub check_in_period_hours: u32,
+    pub reminder_hours_before: Option<Vec<u32>>,
+    pub owner_email: Option<String>,
+    pub contacts: Vec<DesignatedContact>,
+    pub release_actions: Vec<ReleaseAction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReleaseResult {
+    pub action: ReleaseAction,
+    pub success: bool,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadMansSwitchStatus {
+    pub config: Option<DeadMansSwitchConfig>,
+    pub has_instructions: bool,
+    pub deadline: Option<DateTime<Utc>>,
+    pub next_reminder_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchReminder {
+    /// 1-based escalation level; the last level also emails the owner.
+    pub level: usize,
+    pub final_reminder: bool,
+    pub deadline: DateTime<Utc>,
+    pub hours_remaining: i64,
+    /// Release actions that would fail if the switch released now.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SwitchAction {
+    Idle,
+    Remind { level: usize },
+    Release,
+}
+
+impl DeadMansSwitchConfig {
+    pub fn deadline(&self) -> DateTime<Utc> {
+        self.last_check_in + Duration::hours(self.check_in_period_hours as i64)
+    }
+
+    fn sorted_stages(&self) -> Vec<u32> {
+        let mut stages = self.reminder_hours_before.clone();
+        stages.sort_unstable_by(|a, b| b.cmp(a));
+        stages.dedup();
+        stages
+    }
+
+    pub fn next_reminder_at(&self) -> Option<DateTime<Utc>> {
+        self.sorted_stages()
+            .get(self.reminders_sent)
+            .map(|hours| self.deadline() - Duration::hours(*hours as i64))
+    }
+
+    /// The deadline, pushed back until the final reminder has had its notice period.
+    pub fn release_at(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let notice_from = self.final_notice_at.unwrap_or(now);
+        self.deadline().max(notice_from + Duration::hours(RELEASE_NOTICE_HOURS))
+    }
+
+    fn is_final_stage(&self, level: usize) -> bool {
+        level >= self.sorted_stages().len()
+    }
+
+    fn action_succeeded(&self, action: &ReleaseAction) -> bool {
+        self.release_results
+            .iter()
+            .any(|result| result.success && result.action == *action)
+    }
+}
+
+fn default_reminder_stages(period_hours: u32) -> Vec<u32> {
+    // A week's notice, then a day, then a final two hours, trimmed to fit the period
+    [168, 24, 2]
+        .into_iter()
+        .filter(|hours| *hours < period_hours)
+        .collect()
+}
+
+/// Decides whether the switch should remind the owner or release its instructions.
+pub fn next_action(config: &DeadMansSwitchConfig, now: DateTime<Utc>) -> SwitchAction {
+    if !config.enabled || config.released_at.is_some() {
+        return SwitchAction::Idle;
+    }
+
+    let deadline = config.deadline();
+    let stages = config.sorted_stages();
+    if now >= deadline {
+        // Never release without a final warning first, e.g. when the app was
+        // closed through every reminder stage
+        return match config.final_notice_at {
+            None => SwitchAction::Remind {
+                level: stages.len().max(1),
+            },
+            Some(_) if now >= config.release_at(now) => SwitchAction::Release,
+            Some(_) => SwitchAction::Idle,
+        };
+    }
+
+    let remaining = deadline - now;
+    let due = stages
+        .iter()
+        .filter(|hours| remaining <= Duration::hours(**hours as i64))
+        .count();
+
+    if due > config.reminders_sent {
+        SwitchAction::Remind { level: due }
+    } else {
+        SwitchAction::Idle
+    }
+}
+
+pub fn validate_request(request: &ConfigureSwitchRequest) -> Result<(), DeadMansSwitchError> {
+    if request.check_in_period_hours < 24 {
+        return Err(DeadMansSwitchError::InvalidConfig(
+            "Check-in period must be at least 24 hours".to_string(),
+        ));
+    }
+
+    if request.contacts.is_empty() && request.enabled {
+        return Err(DeadMansSwitchError::InvalidConfig(
+            "At least one designated contact is required".to_string(),
+        ));
+    }
+
+    if request.contacts.iter().any(|c| !c.email.contains('@')) {
+        return Err(DeadMansSwitchError::InvalidConfig(
+            "Every contact needs a valid email address".to_string(),
+        ));
+    }
+
+    if let Some(stages) = &request.reminder_hours_before {
+        if stages
+            .iter()
+            .any(|hours| *hours == 0 || *hours >= request.check_in_period_hours)
+        {
+            return Err(DeadMansSwitchError::InvalidConfig(
+                "Reminders must fall inside the check-in period".to_string(),
+            ));
+        }
+    }
+
+    if request.release_actions.is_empty() && request.enabled {
+        return Err(DeadMansSwitchError::InvalidConfig(
+            "At least one release action is required".to_string(),
+        ));
+    }
+
+    if request.release_actions.iter().any(|action| {
+        matches!(action, ReleaseAction::ActivateProposal { delay_secs, .. }
+            if *delay_secs < MIN_CANCELLATION_WINDOW_SECS)
+    }) {
+        return Err(DeadMansSwitchError::InvalidConfig(format!(
+            "Proposal delays must be at least {} seconds",
+            MIN_CANCELLATION_WINDOW_SECS
+        )));
+    }
+
+    Ok(())
+}
+
+/// Describes every proposal release action that could not be scheduled today:
+/// the proposal is gone or no longer approved.
+pub async fn unusable_proposals(
+    multisig: &MultisigDatabase,
+    actions: &[ReleaseAction],
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    for action in actions {
+        let ReleaseAction::ActivateProposal { proposal_id, .. } = action else {
+            continue;
+        };
+        match multisig.get_proposal(proposal_id).await {
+            Ok(Some(proposal)) if proposal.status == ProposalStatus::Approved => {}
+            Ok(Some(proposal)) => problems.push(format!(
+                "Proposal {} is {}, not approved",
+                proposal_id, proposal.status
+            )),
+            Ok(None) => problems.push(format!("Proposal {} does not exist", proposal_id)),
+            Err(err) => {
+                problems.push(format!("Proposal {} could not be read: {}", proposal_id, err))
+            }
+        }
+    }
+    problems
+}
+
+pub type SharedDeadMansSwitch = Arc<RwLock<DeadMansSwitch>>;
+
+pub struct DeadMansSwitch {
+    app_handle: AppHandle,
+    config: Option<DeadMansSwitchConfig>,
+}
+
+impl DeadMansSwitch {
+    pub fn new(app: &AppHandle) -> Result<Self, DeadMansSwitchError> {
+        let mut switch = Self {
+            app_handle: app.clone(),
+            config: None,
+        };
+        switch.config = switch.load_config()?;
+        Ok(switch)
+    }
+
+    fn config_path(&self) -> Result<PathBuf, DeadMansSwitchError> {
+        let mut path = self
+            .app_handle
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::NotFound, "App data directory not found")
+            })?;
+
+        if !path.exists() {
+            fs::create_dir_all(&path)?;
+        }
+
+        path.push(SWITCH_CONFIG_FILE);
+        Ok(path)
+    }
+
+    fn load_config(&self) -> Result<Option<DeadMansSwitchConfig>, DeadMansSwitchError> {
+        let path = self.config_path()?;
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let data = fs::read_to_string(&path)?;
+        Ok(Some(serde_json::from_str(&data)?))
+    }
+
+    fn save_config(&self) -> Result<(), DeadMansSwitchError> {
+        let path = self.config_path()?;
+        let json = serde_json::to_string_pretty(&self.config)?;
+        fs::write(&path, json)?;
+        Ok(())
+    }
+
+    pub fn config(&self) -> Option<&DeadMansSwitchConfig> {
+        self.config.as_ref()
+    }
+
+    pub fn configure(
+        &mut self,
+        request: ConfigureSwitchRequest,
+    ) -> Result<DeadMansSwitchConfig, DeadMansSwitchError> {
+        validate_request(&request)?;
+
+        let config = DeadMansSwitchConfig {
+            enabled: request.enabled,
+            check_in_period_hours: request.check_in_period_hours,
+            reminder_hours_before: request
+                .reminder_hours_before
+                .unwrap_or_else(|| default_reminder_stages(request.check_in_period_hours)),
+            owner_email: request.owner_email,
+            contacts: request.contacts,
+            release_actions: request.release_actions,
+            // Reconfiguring counts as a check-in and re-arms a released switch
+            last_check_in: Utc::now(),
+            reminders_sent: 0,
+            final_notice_at: None,
+            released_at: None,
+            release_results: Vec::new(),
+        };
+
+        self.config = Some(config.clone());
+        self.save_config()?;
+        Ok(config)
+    }
+
+    pub fn check_in(&mut self) -> Result<DeadMansSwitchConfig, DeadMansSwitchError> {
+        let config = self
+            .config
+            .as_mut()
+            .ok_or(DeadMansSwitchError::NotConfigured)?;
+        if config.released_at.is_some() {
+            return Err(DeadMansSwitchError::AlreadyReleased);
+        }
+
+        config.last_check_in = Utc::now();
+        config.reminders_sent = 0;
+        config.final_notice_at = None;
+        let config = config.clone();
+        self.save_config()?;
+        Ok(config)
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) -> Result<(), DeadMansSwitchError> {
+        let config = self
+            .config
+            .as_mut()
+            .ok_or(DeadMansSwitchError::NotConfigured)?;
+        config.enabled = enabled;
+        if enabled {
+            config.last_check_in = Utc::now();
+            config.reminders_sent = 0;
+            config.final_notice_at = None;
+        }
+        self.save_config()
+    }
+
+    fn record_reminder(&mut self, level: usize) -> Result<(), DeadMansSwitchError> {
+        if let Some(config) = self.config.as_mut() {
+            config.reminders_sent = level;
+            if config.is_final_stage(level) && config.final_notice_at.is_none() {
+                config.final_notice_at = Some(Utc::now());
+            }
+        }
+        self.save_config()
+    }
+
+    /// Stores the outcome of a release attempt. The switch only counts as
+    /// released once every action has succeeded; until then the monitor keeps
+    /// retrying the failed ones. Returns whether the switch is now released.
+    fn record_release(&mut self, results: Vec<ReleaseResult>) -> Result<bool, DeadMansSwitchError> {
+        let Some(config) = self.config.as_mut() else {
+            return Ok(false);
+        };
+        let released = merge_release_results(config, results);
+        self.save_config()?;
+        Ok(released)
+    }
+}
+
+async fn email_owner(
+    app_handle: &AppHandle,
+    to: &str,
+    subject: &str,
+    body: &str,
+) -> Result<(), String> {
+    let keystore = app_handle.state::<Keystore>();
+    let manager = EmailManager::new(app_handle)
+        .await
+        .map_err(|e| e.to_string())?;
+    let config = manager
+        .get_config(&keystore)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    manager
+        .send_email(
+            SendEmailRequest {
+                to: vec![to.to_string()],
+                subject: subject.to_string(),
+                html_body: None,
+                text_body: Some(body.to_string()),
+                template: None,
+                template_vars: None,
+                attachments: None,
+                include_unsubscribe: false,
+            },
+            &config,
+        )
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+async fn send_reminder(
+    app_handle: &AppHandle,
+    router: &SharedNotificationRouter,
+    config: &DeadMansSwitchConfig,
+    level: usize,
+    warnings: Vec<String>,
+) {
+    let now = Utc::now();
+    let final_reminder = config.is_final_stage(level);
+    // The final reminder starts the notice period, which may push the release
+    // past the deadline
+    let deadline = if final_reminder {
+        config.release_at(now)
+    } else {
+        config.deadline()
+    };
+    let reminder = SwitchReminder {
+        level,
+        final_reminder,
+        deadline,
+        hours_remaining: (deadline - now).num_hours().max(0),
+        warnings,
+    };
+    let mut message = format!(
+        "Check in before {} or your estate instructions will be released to {} contact(s). {} hour(s) remaining.",
+        deadline.to_rfc3339(),
+        config.contacts.len(),
+        reminder.hours_remaining
+    );
+    if !reminder.warnings.is_empty() {
+        message.push_str(&format!(
+            "\n\nThese release actions will fail unless you update the switch:\n{}",
+            reminder.warnings.join("\n")
+        ));
+    }
+
+    if let Err(err) = app_handle.emit_all(REMINDER_EVENT, &reminder) {
+        tracing::warn!(error = %err, "failed to emit check-in reminder");
+    }
+
+    // Escalate: chat channels from the second reminder or when a release action
+    // is broken, email on the last one
+    if level >= 2 || !reminder.warnings.is_empty() {
+        let router = router.read().await;
+        if let Err(err) = router
+            .send_broadcast_notification("dead_mans_switch", "Check-in reminder", &message)
+            .await
+        {
+            tracing::warn!(error = %err, "failed to deliver check-in reminder");
+        }
+    }
+
+    if reminder.final_reminder {
+        if let Some(owner_email) = &config.owner_email {
+            if let Err(err) =
+                email_owner(app_handle, owner_email, "Final check-in reminder", &message).await
+            {
+                tracing::warn!(error = %err, "failed to email final check-in reminder");
+            }
+        }
+    }
+}
+
+async fn release_encrypted_export(
+    app_handle: &AppHandle,
+    config: &DeadMansSwitchConfig,
+) -> Result<String, String> {
+    let keystore = app_handle.state::<Keystore>();
+    let instructions = keystore
+        .retrieve_secret(KEY_INSTRUCTIONS)
+        .map_err(|_| "No estate instructions have been stored".to_string())?;
+    let passphrase = keystore
+        .retrieve_secret(KEY_RELEASE_PASSPHRASE)
+        .map_err(|_| "No release passphrase has been stored".to_string())?;
+    let passphrase = String::from_utf8(passphrase.to_vec())
+        .map_err(|_| "Release passphrase is not valid UTF-8".to_string())?;
+
+    let sealed = seal_with_password(&instructions, &passphrase).map_err(|e| e.to_string())?;
+    let attachment = serde_json::to_vec_pretty(&sealed).map_err(|e| e.to_string())?;
+
+    let manager = EmailManager::new(app_handle)
+        .await
+        .map_err(|e| e.to_string())?;
+    let smtp = manager
+        .get_config(&keystore)
+        .await
+        .map_err(|e| e.to_string())?;
+    let recipients: Vec<String> = config.contacts.iter().map(|c| c.email.clone()).collect();
+
+    manager
+        .send_email(
+            SendEmailRequest {
+                to: recipients.clone(),
+                subject: "Estate instructions released".to_string(),
+                html_body: None,
+                text_body: Some(
+                    "You were designated to receive these instructions. The attachment is \
+                     encrypted with the passphrase the owner shared with you in advance."
+                        .to_string(),
+                ),
+                template: None,
+                template_vars: None,
+                attachments: Some(vec![EmailAttachment {
+                    filename: EXPORT_FILENAME.to_string(),
+                    content: attachment,
+                    mime_type: "application/json".to_string(),
+                }]),
+                include_unsubscribe: false,
+            },
+            &smtp,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(format!(
+        "Encrypted export sent to {}",
+        recipients.join(", ")
+    ))
+}
+
+async fn release(
+    app_handle: &AppHandle,
+    scheduler: &SharedTransactionScheduler,
+    multisig: &SharedMultisigDatabase,
+    config: &DeadMansSwitchConfig,
+) -> Vec<ReleaseResult> {
+    let mut results = Vec::new();
+
+    for action in &config.release_actions {
+        if config.action_succeeded(action) {
+            continue;
+        }
+        let outcome = match action {
+            ReleaseAction::EncryptedExport => release_encrypted_export(app_handle, config).await,
+            ReleaseAction::ActivateProposal {
+                proposal_id,
+                delay_secs,
+            } => {
+                let multisig = multisig.read().await;
+                let scheduler = scheduler.read().await;
+                create_schedule(
+                    &scheduler,
+                    &multisig,
+                    ScheduleTransactionRequest {
+                        kind: ScheduledTxKind::MultisigProposal,
+                        proposal_id: Some(proposal_id.clone()),
+                        wallet_address: None,
+                        transaction: None,
+                        rpc_url: None,
+                        description: Some("Estate plan release".to_string()),
+                        execute_at: None,
+                        delay_secs: Some(*delay_secs),
+                        notify_before_secs: None,
+                    },
+                )
+                .await
+                .map(|item| format!("Proposal scheduled for {}", item.execute_at.to_rfc3339()))
+            }
+        };
+
+        results.push(match outcome {
+            Ok(detail) => ReleaseResult {
+                action: action.clone(),
+                success: true,
+                detail,
+            },
+            Err(detail) => ReleaseResult {
+                action: action.clone(),
+                success: false,
+                detail,
+            },
+        });
+    }
+
+    results
+}
+
+fn merge_release_results(config: &mut DeadMansSwitchConfig, results: Vec<ReleaseResult>) -> bool {
+    for result in results {
+        config.release_results.retain(|r| r.action != result.action);
+        config.release_results.push(result);
+    }
+
+    let released = config
+        .release_actions
+        .iter()
+        .all(|action| config.action_succeeded(action));
+    if released {
+        config.released_at = Some(Utc::now());
+    }
+    released
+}
+
+/// Evaluates the switch once, sending a due reminder or releasing the instructions.
+pub async fn run_switch_check(
+    app_handle: &AppHandle,
+    switch: &SharedDeadMansSwitch,
+    scheduler: &SharedTransactionScheduler,
+    multisig: &SharedMultisigDatabase,
+    router: &SharedNotificationRouter,
+) -> Result<SwitchAction, String> {
+    let config = match switch.read().await.config().cloned() {
+        Some(config) => config,
+        None => return Ok(SwitchAction::Idle),
+    };
+
+    let action = next_action(&config, Utc::now());
+    match &action {
+        SwitchAction::Idle => {}
+        SwitchAction::Remind { level } => {
+            switch
+                .write()
+                .await
+                .record_reminder(*level)
+                .map_err(|e| e.to_string())?;
+            // Proposals approved at configure time may since have been executed,
+            // cancelled or removed; the final reminder is the owner's last chance
+            let warnings = if config.is_final_stage(*level) {
+                unusable_proposals(&*multisig.read().await, &config.release_actions).await
+            } else {
+                Vec::new()
+            };
+            send_reminder(app_handle, router, &config, *level, warnings).await;
+        }
+        SwitchAction::Release => {
+            // Hold the lock through the release and decide again under it, so a
+            // check-in that landed since the read above always wins
+            let mut switch = switch.write().await;
+            let config = match switch.config().cloned() {
+                Some(config) if next_action(&config, Utc::now()) == SwitchAction::Release => {
+                    config
+                }
+                _ => return Ok(SwitchAction::Idle),
+            };
+
+            let results = release(app_handle, scheduler, multisig, &config).await;
+            let released = switch
+                .record_release(results.clone())
+                .map_err(|e| e.to_string())?;
+            drop(switch);
+
+            if !released {
+                let failures = results
+                    .iter()
+                    .filter(|r| !r.success)
+                    .map(|r| r.detail.clone())
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                return Err(format!("release incomplete, will retry: {}", failures));
+            }
+
+            if let Err(err) = app_handle.emit_all(RELEASE_EVENT, &results) {
+                tracing::warn!(error = %err, "failed to emit switch release");
+            }
+            let summary = results
+                .iter()
+                .map(|r| r.detail.clone())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let router = router.read().await;
+            if let Err(err) = router
+                .send_critical_notification(
+                    "dead_mans_switch",
+                    "Estate instructions released",
+                    &summary,
+                )
+                .await
+            {
+                tracing::warn!(error = %err, "failed to deliver switch release notice");
+            }
+        }
+    }
+
+    Ok(action)
+}
+
+pub fn start_switch_monitor(
+    app_handle: AppHandle,
+    switch: SharedDeadMansSwitch,
+    scheduler: SharedTransactionScheduler,
+    multisig: SharedMultisigDatabase,
+    router: SharedNotificationRouter,
+) {
+    tauri::async_runtime::spawn(async move {
+        let start = tokio::time::Instant::now() + STARTUP_GRACE;
+        let mut interval = tokio::time::interval_at(start, MONITOR_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) =
+                run_switch_check(&app_handle, &switch, &scheduler, &multisig, &router).await
+            {
+                tracing::warn!(error = %err, "dead man's switch check failed");
+            }
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn dead_mans_switch_get_status(
+    switch: State<'_, SharedDeadMansSwitch>,
+    keystore: State<'_, Keystore>,
+) -> Result<DeadMansSwitchStatus, String> {
+    let switch = switch.read().await;
+    let config = switch.config().cloned();
+    let has_instructions = keystore
+        .list_keys()
+        .map(|keys| keys.iter().any(|k| k == KEY_INSTRUCTIONS))
+        .unwrap_or(false);
+
+    Ok(DeadMansSwitchStatus {
+        deadline: config.as_ref().map(|c| c.deadline()),
+        next_reminder_at: config.as_ref().and_then(|c| c.next_reminder_at()),
+        config,
+        has_instructions,
+    })
+}
+
+#[tauri::command]
+pub async fn dead_mans_switch_configure(
+    request: ConfigureSwitchRequest,
+    switch: State<'_, SharedDeadMansSwitch>,
+    multisig: State<'_, SharedMultisigDatabase>,
+) -> Result<DeadMansSwitchConfig, String> {
+    let problems = unusable_proposals(&*multisig.read().await, &request.release_actions).await;
+    if !problems.is_empty() {
+        return Err(DeadMansSwitchError::InvalidConfig(problems.join("; ")).to_string());
+    }
+
+    let mut switch = switch.write().await;
+    switch.configure(request).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn dead_mans_switch_check_in(
+    switch: State<'_, SharedDeadMansSwitch>,
+) -> Result<DeadMansSwitchConfig, String> {
+    let mut switch = switch.write().await;
+    switch.check_in().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn dead_mans_switch_set_enabled(
+    enabled: bool,
+    switch: State<'_, SharedDeadMansSwitch>,
+) -> Result<(), String> {
+    let mut switch = switch.write().await;
+    switch.set_enabled(enabled).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn dead_mans_switch_set_instructions(
+    instructions: String,
+    passphrase: String,
+    keystore: State<'_, Keystore>,
+) -> Result<(), String> {
+    if instructions.trim().is_empty() {
+        return Err("Instructions cannot be empty".to_string());
+    }
+    if passphrase.len() < 12 {
+        return Err("Release passphrase must be at least 12 characters".to_string());
+    }
+
+    keystore
+        .store_secret(KEY_INSTRUCTIONS, instructions.as_bytes())
+        .map_err(|e| e.to_string())?;
+    keystore
+        .store_secret(KEY_RELEASE_PASSPHRASE, passphrase.as_bytes())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(last_check_in: DateTime<Utc>) -> DeadMansSwitchConfig {
+        DeadMansSwitchConfig {
+            enabled: true,
+            check_in_period_hours: 30 * 24,
+            reminder_hours_before: vec![2, 168, 24],
+            owner_email: None,
+            contacts: vec![DesignatedContact {
+                name: "Heir".to_string(),
+                email: "heir@example.com".to_string(),
+            }],
+            release_actions: vec![ReleaseAction::EncryptedExport],
+            last_check_in,
+            reminders_sent: 0,
+            final_notice_at: None,
+            released_at: None,
+            release_results: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn escalates_through_reminder_stages() {
+        let now = Utc::now();
+        let mut cfg = config(now - Duration::days(20));
+        assert_eq!(next_action(&cfg, now), SwitchAction::Idle);
+
+        cfg.last_check_in = now - Duration::days(24);
+        assert_eq!(next_action(&cfg, now), SwitchAction::Remind { level: 1 });
+
+        cfg.reminders_sent = 1;
+        assert_eq!(next_action(&cfg, now), SwitchAction::Idle);
+
+        cfg.last_check_in = now - Duration::hours(30 * 24 - 1);
+        assert_eq!(next_action(&cfg, now), SwitchAction::Remind { level: 3 });
+    }
+
+    #[test]
+    fn releases_only_after_final_notice() {
+        let now = Utc::now();
+        let mut cfg = config(now - Duration::days(31));
+        assert_eq!(
+            next_action(&cfg, now),
+            SwitchAction::Remind { level: 3 },
+            "a missed deadline first sends the final reminder"
+        );
+
+        cfg.reminders_sent = 3;
+        cfg.final_notice_at = Some(now - Duration::hours(1));
+        assert_eq!(next_action(&cfg, now), SwitchAction::Idle);
+
+        cfg.final_notice_at = Some(now - Duration::hours(RELEASE_NOTICE_HOURS));
+        assert_eq!(next_action(&cfg, now), SwitchAction::Release);
+
+        cfg.released_at = Some(now);
+        assert_eq!(next_action(&cfg, now), SwitchAction::Idle);
+    }
+
+    #[test]
+    fn failed_release_actions_are_retried() {
+        let proposal = ReleaseAction::ActivateProposal {
+            proposal_id: "p1".to_string(),
+            delay_secs: 3600,
+        };
+        let mut cfg = config(Utc::now() - Duration::days(31));
+        cfg.release_actions.push(proposal.clone());
+        let result = |action: &ReleaseAction, success| ReleaseResult {
+            action: action.clone(),
+            success,
+            detail: String::new(),
+        };
+
+        let export = ReleaseAction::EncryptedExport;
+        assert!(!merge_release_results(
+            &mut cfg,
+            vec![result(&export, false), result(&proposal, true)]
+        ));
+        assert!(cfg.released_at.is_none());
+        assert!(!cfg.action_succeeded(&export) && cfg.action_succeeded(&proposal));
+
+        assert!(merge_release_results(&mut cfg, vec![result(&export, true)]));
+        assert!(cfg.released_at.is_some());
+        assert_eq!(cfg.release_results.len(), 2);
+    }
+
+    #[test]
+    fn validates_requests() {
+        let request = ConfigureSwitchRequest {
+            enabled: true,
+            check_in_period_hours: 48,
+            reminder_hours_before: Some(vec![72]),
+            owner_email: None,
+            contacts: vec![DesignatedContact {
+                name: "Heir".to_string(),
+                email: "heir@example.com".to_string(),
+            }],
+            release_actions: vec![ReleaseAction::EncryptedExport],
+        };
+        assert!(validate_request(&request).is_err());
+
+        let request = ConfigureSwitchRequest {
+            reminder_hours_before: None,
+            ..request
+        };
+        assert!(validate_request(&request).is_ok());
+        assert_eq!(default_reminder_stages(48), vec![24, 2]);
+
+        let proposal = |delay_secs| ReleaseAction::ActivateProposal {
+            proposal_id: "p1".to_string(),
+            delay_secs,
+        };
+        let request = ConfigureSwitchRequest {
+            release_actions: vec![proposal(MIN_CANCELLATION_WINDOW_SECS - 1)],
+            ..request
+        };
+        assert!(validate_request(&request).is_err());
+        let request = ConfigureSwitchRequest {
+            release_actions: vec![proposal(MIN_CANCELLATION_WINDOW_SECS)],
+            ..request
+        };
+        assert!(validate_request(&request).is_ok());
+    }
+}
@@ -179,25 +179,7 @@ impl Keystore {
         document.exported_at = Some(Utc::now());
         let serialized = serde_json::to_vec(&document)?;
 
-        let mut salt = [0u8; SALT_LEN];
-        let mut nonce = [0u8; NONCE_LEN];
-        OsRng.fill_bytes(&mut salt);
-        OsRng.fill_bytes(&mut nonce);
-
-        let derived_key = derive_key(password.as_bytes(), &salt)?;
-        let cipher = Aes256Gcm::new(GenericArray::from_slice(derived_key.as_ref()));
-
-        let ciphertext = cipher
-            .encrypt(GenericArray::from_slice(&nonce), serialized.as_ref())
-            .map_err(|_| KeystoreError::Encryption)?;
-
-        Ok(KeystoreBackup {
-            version: document.version,
-            salt: BASE64_ENGINE.encode(salt),
-            nonce: BASE64_ENGINE.encode(nonce),
-            ciphertext: BASE64_ENGINE.encode(ciphertext),
-            created_at: document.exported_at.unwrap_or_else(Utc::now),
-        })
+        seal_with_password(&serialized, password)
     }
 
     pub fn import_backup(
@@ -205,26 +187,7 @@ impl Keystore {
         password: &str,
         backup: KeystoreBackup,
     ) -> Result<(), KeystoreError> {
-        if backup.version != KEYSTORE_VERSION {
-            return Err(KeystoreError::Decryption);
-        }
-
-        let salt = BASE64_ENGINE
-            .decode(backup.salt.as_bytes())
-            .map_err(|_| KeystoreError::Decryption)?;
-        let nonce = BASE64_ENGINE
-            .decode(backup.nonce.as_bytes())
-            .map_err(|_| KeystoreError::Decryption)?;
-        let ciphertext = BASE64_ENGINE
-            .decode(backup.ciphertext.as_bytes())
-            .map_err(|_| KeystoreError::Decryption)?;
-
-        let derived_key = derive_key(password.as_bytes(), &salt)?;
-        let cipher = Aes256Gcm::new(GenericArray::from_slice(derived_key.as_ref()));
-
-        let plaintext = cipher
-            .decrypt(GenericArray::from_slice(&nonce), ciphertext.as_ref())
-            .map_err(|_| KeystoreError::Decryption)?;
+        let plaintext = open_with_password(&backup, password)?;
 
         let mut document: KeystoreDocument = serde_json::from_slice(&plaintext)?;
         document.exported_at = None;
@@ -327,6 +290,56 @@ impl Keystore {
     }
 }
 
+/// Encrypts arbitrary data with a password, using the same envelope as keystore backups.
+pub fn seal_with_password(data: &[u8], password: &str) -> Result<KeystoreBackup, KeystoreError> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let derived_key = derive_key(password.as_bytes(), &salt)?;
+    let cipher = Aes256Gcm::new(GenericArray::from_slice(derived_key.as_ref()));
+
+    let ciphertext = cipher
+        .encrypt(GenericArray::from_slice(&nonce), data)
+        .map_err(|_| KeystoreError::Encryption)?;
+
+    Ok(KeystoreBackup {
+        version: KEYSTORE_VERSION,
+        salt: BASE64_ENGINE.encode(salt),
+        nonce: BASE64_ENGINE.encode(nonce),
+        ciphertext: BASE64_ENGINE.encode(ciphertext),
+        created_at: Utc::now(),
+    })
+}
+
+pub fn open_with_password(
+    sealed: &KeystoreBackup,
+    password: &str,
+) -> Result<Zeroizing<Vec<u8>>, KeystoreError> {
+    if sealed.version != KEYSTORE_VERSION {
+        return Err(KeystoreError::Decryption);
+    }
+
+    let salt = BASE64_ENGINE
+        .decode(sealed.salt.as_bytes())
+        .map_err(|_| KeystoreError::Decryption)?;
+    let nonce = BASE64_ENGINE
+        .decode(sealed.nonce.as_bytes())
+        .map_err(|_| KeystoreError::Decryption)?;
+    let ciphertext = BASE64_ENGINE
+        .decode(sealed.ciphertext.as_bytes())
+        .map_err(|_| KeystoreError::Decryption)?;
+
+    let derived_key = derive_key(password.as_bytes(), &salt)?;
+    let cipher = Aes256Gcm::new(GenericArray::from_slice(derived_key.as_ref()));
+
+    cipher
+        .decrypt(GenericArray::from_slice(&nonce), ciphertext.as_ref())
+        .map(Zeroizing::new)
+        .map_err(|_| KeystoreError::Decryption)
+}
+
 fn derive_key(secret: &[u8], salt: &[u8]) -> Result<Zeroizing<Vec<u8>>, KeystoreError> {
     let params = Params::new(ARGON2_M_COST, ARGON2_T_COST, ARGON2_P_COST, Some(32))
         .map_err(|_| KeystoreError::Encryption)?;
@@ -1,4 +1,6 @@
 pub mod keystore;
 pub mod activity_log;
 pub mod audit;
-pub mod reputation;
\ No newline at end of file
+pub mod reputation;pub mod dead_mans_switch;
+pub mod blocklist_sync;
+pub mod authority_monitor;
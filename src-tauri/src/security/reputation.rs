@@ -7,7 +7,9 @@ use std::sync::Arc;
 use tauri::{AppHandle, Manager};
 use tokio::sync::RwLock;
 
-const REPUTATION_DB_FILE: &str = "reputation.db";
+use super::blocklist_sync::refresh_denylist;
+
+pub(crate) const REPUTATION_DB_FILE: &str = "reputation.db";
 
 // Shared type for the reputation engine state
 pub type SharedReputationEngine = Arc<RwLock<ReputationEngine>>;
@@ -803,6 +805,37 @@ impl ReputationEngine {
         Ok(())
     }
 
+    /// Withdraws the entries one source contributed. The reputation flags are only
+    /// cleared when no other active entry still lists the address.
+    pub async fn remove_blacklist_source_entry(
+        &self,
+        address: &str,
+        entry_type: &str,
+        source: &str,
+    ) -> Result<(), ReputationError> {
+        sqlx::query(
+            "UPDATE blacklist SET is_active = 0 WHERE address = ? AND entry_type = ? AND source = ?",
+        )
+        .bind(address)
+        .bind(entry_type)
+        .bind(source)
+        .execute(&self.pool)
+        .await?;
+
+        let remaining: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM blacklist WHERE address = ? AND entry_type = ? AND is_active = 1",
+        )
+        .bind(address)
+        .bind(entry_type)
+        .fetch_one(&self.pool)
+        .await?;
+
+        if remaining == 0 {
+            self.remove_from_blacklist(address, entry_type).await?;
+        }
+        Ok(())
+    }
+
     pub async fn get_blacklist(&self, entry_type: Option<String>) -> Result<Vec<BlacklistEntry>, ReputationError> {
         let query = if let Some(t) = entry_type {
             sqlx::query(
@@ -1109,7 +1142,9 @@ pub async fn add_to_blacklist(
     engine
         .add_to_blacklist(&address, &entry_type, &reason, reporter, &source)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    refresh_denylist(&engine).await.map_err(|e| e.to_string())?;
+    Ok(())
 }
 
 #[tauri::command]
@@ -1122,7 +1157,9 @@ pub async fn remove_from_blacklist(
     engine
         .remove_from_blacklist(&address, &entry_type)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    refresh_denylist(&engine).await.map_err(|e| e.to_string())?;
+    Ok(())
 }
 
 #[tauri::command]
@@ -4,6 +4,8 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use chrono::Utc;
 
+use crate::config::data_sources::{effective_mode, DataModule, DataSourceMode};
+
 pub use crate::social::models::{SentimentResult, SocialPost};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -251,6 +253,44 @@ impl SentimentManager {
         self.alert_config.clone()
     }
 
+    /// Approximate memory held by per-token sentiment, as serialized size.
+    pub fn estimated_size_bytes(&self) -> usize {
+        self.token_sentiments
+            .values()
+            .map(|sentiment| serde_json::to_vec(sentiment).map_or(0, |bytes| bytes.len()))
+            .sum()
+    }
+
+    pub fn tracked_tokens(&self) -> usize {
+        self.token_sentiments.len()
+    }
+
+    /// Forgets the tokens updated longest ago until the rest fits in
+    /// `target_bytes`. Returns the number of tokens dropped.
+    pub fn shrink_to(&mut self, target_bytes: usize) -> usize {
+        let mut sizes: Vec<(String, i64, usize)> = self
+            .token_sentiments
+            .iter()
+            .map(|(address, sentiment)| {
+                let size = serde_json::to_vec(sentiment).map_or(0, |bytes| bytes.len());
+                (address.clone(), sentiment.last_updated, size)
+            })
+            .collect();
+        let mut total: usize = sizes.iter().map(|(_, _, size)| size).sum();
+        sizes.sort_by_key(|(_, last_updated, _)| *last_updated);
+
+        let mut removed = 0;
+        for (address, _, size) in sizes {
+            if total <= target_bytes {
+                break;
+            }
+            self.token_sentiments.remove(&address);
+            total = total.saturating_sub(size);
+            removed += 1;
+        }
+        removed
+    }
+
     pub fn dismiss_alert(&mut self, alert_id: &str) {
         if let Some(alert) = self.alerts.iter_mut().find(|a| a.id == alert_id) {
             alert.is_active = false;
@@ -372,39 +412,31 @@ pub async fn dismiss_sentiment_alert(
     Ok(())
 }
 
-// Mock function to simulate social media data ingestion
+// Social ingestion lives in the social module; this only serves fixture posts
 #[tauri::command]
 pub async fn fetch_social_mentions(
     token_address: String,
 ) -> Result<Vec<SocialPost>, String> {
-    // In a real implementation, this would call Twitter/Reddit APIs
-    // For now, return mock data
-    let now = Utc::now().timestamp();
-    
-    let sample_texts = vec![
-        "This token is amazing! Great project with strong fundamentals.",
-        "Love the recent updates. Bullish on this one!",
-        "Not sure about this. Price action looks weak.",
-        "Terrible performance lately. Might be time to sell.",
-        "Just bought some more. Going to the moon! 🚀",
-        "This is a scam. Don't invest!",
-        "Good entry point here. Strong support levels.",
-        "The team delivered on their roadmap. Very impressed.",
-    ];
+    if effective_mode(DataModule::Social) != DataSourceMode::Mock {
+        return Ok(Vec::new());
+    }
+
+    let symbol = crate::demo::find_demo_token(&token_address)
+        .map(|token| token.symbol)
+        .unwrap_or("SOL");
 
-    let posts: Vec<SocialPost> = sample_texts
-        .iter()
-        .enumerate()
-        .map(|(i, text)| {
-            let sentiment = analyze_sentiment(text);
+    let posts: Vec<SocialPost> = crate::demo::demo_social_posts(symbol)
+        .into_iter()
+        .map(|post| {
+            let sentiment = analyze_sentiment(&post.text);
             SocialPost {
-                id: format!("post_{}", i),
-                text: text.to_string(),
-                source: if i % 2 == 0 { "twitter" } else { "reddit" }.to_string(),
-                author: format!("user_{}", i),
-                timestamp: now - (i as i64 * 3600),
+                id: post.id,
+                text: post.text,
+                source: post.source,
+                author: post.author,
+                timestamp: post.timestamp,
                 sentiment,
-                engagement: (100 + i * 50) as i32,
+                engagement: post.engagement,
             }
         })
         .collect();
@@ -2,6 +2,7 @@ use tauri::State;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use crate::config::feature_flags::ModuleState;
 use crate::security::keystore::Keystore;
 
 use super::analysis::{AnalysisSummary, GaugeReading, InfluencerScore, SentimentSnapshot as AnalysisSentimentSnapshot, SharedSocialAnalysisService, TrendRecord};
@@ -17,7 +18,7 @@ pub async fn social_fetch_reddit(
     query: Option<String>,
     limit: Option<u32>,
     token: Option<String>,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
 ) -> Result<SocialFetchResult, String> {
     let srv = service.read().await;
     srv.fetch_reddit(&subreddit, query.as_deref(), limit, token.as_deref())
@@ -31,7 +32,7 @@ pub async fn social_search_reddit_mentions(
     keyword: String,
     limit: Option<u32>,
     token: Option<String>,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
 ) -> Result<Vec<SocialFetchResult>, String> {
     let srv = service.read().await;
     let subreddit_refs: Vec<&str> = subreddits.iter().map(|s| s.as_str()).collect();
@@ -46,7 +47,7 @@ pub async fn social_fetch_twitter(
     max_results: Option<u32>,
     token: Option<String>,
     bearer_token_override: Option<String>,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
     keystore: State<'_, Keystore>,
 ) -> Result<SocialFetchResult, String> {
     let srv = service.read().await;
@@ -67,7 +68,7 @@ pub async fn social_fetch_twitter_user(
     max_results: Option<u32>,
     token: Option<String>,
     bearer_token_override: Option<String>,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
     keystore: State<'_, Keystore>,
 ) -> Result<SocialFetchResult, String> {
     let srv = service.read().await;
@@ -87,7 +88,7 @@ pub async fn social_get_cached_mentions(
     source: Option<String>,
     token: Option<String>,
     limit: Option<i32>,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
 ) -> Result<Vec<SocialPost>, String> {
     let srv = service.read().await;
     srv.get_cached_posts(source.as_deref(), token.as_deref(), limit)
@@ -98,7 +99,7 @@ pub async fn social_get_cached_mentions(
 #[tauri::command]
 pub async fn social_get_mention_aggregates(
     token: Option<String>,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
 ) -> Result<Vec<MentionAggregate>, String> {
     let srv = service.read().await;
     srv.get_mention_aggregates(token.as_deref())
@@ -110,7 +111,7 @@ pub async fn social_get_mention_aggregates(
 pub async fn social_get_trend_snapshots(
     token: String,
     hours: Option<i64>,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
 ) -> Result<Vec<TrendSnapshot>, String> {
     let srv = service.read().await;
     srv.get_trend_snapshots(&token, hours)
@@ -122,7 +123,7 @@ pub async fn social_get_trend_snapshots(
 pub async fn social_create_trend_snapshot(
     token: String,
     source: String,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
 ) -> Result<(), String> {
     let srv = service.read().await;
     srv.create_trend_snapshot(&token, &source)
@@ -133,7 +134,7 @@ pub async fn social_create_trend_snapshot(
 #[tauri::command]
 pub async fn social_set_twitter_bearer_token(
     bearer_token: String,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
     keystore: State<'_, Keystore>,
 ) -> Result<(), String> {
     let srv = service.read().await;
@@ -144,7 +145,7 @@ pub async fn social_set_twitter_bearer_token(
 #[tauri::command]
 pub async fn social_cleanup_old_posts(
     days: i64,
-    service: State<'_, SharedSocialDataService>,
+    service: ModuleState<'_, SharedSocialDataService>,
 ) -> Result<i64, String> {
     let srv = service.read().await;
     srv.cleanup_old_posts(days)
@@ -155,7 +156,7 @@ pub async fn social_cleanup_old_posts(
 #[tauri::command]
 pub async fn social_run_sentiment_analysis(
     token: String,
-    analysis_service: State<'_, SharedSocialAnalysisService>,
+    analysis_service: ModuleState<'_, SharedSocialAnalysisService>,
 ) -> Result<AnalysisSummary, String> {
     let mut srv = analysis_service.write().await;
     srv.run_full_analysis(&token)
@@ -165,7 +166,7 @@ pub async fn social_run_sentiment_analysis(
 
 #[tauri::command]
 pub async fn social_run_full_analysis_all(
-    analysis_service: State<'_, SharedSocialAnalysisService>,
+    analysis_service: ModuleState<'_, SharedSocialAnalysisService>,
 ) -> Result<AnalysisSummary, String> {
     let mut srv = analysis_service.write().await;
     srv.run_analysis_all()
@@ -176,7 +177,7 @@ pub async fn social_run_full_analysis_all(
 #[tauri::command]
 pub async fn social_get_sentiment_snapshot(
     token: String,
-    analysis_service: State<'_, SharedSocialAnalysisService>,
+    analysis_service: ModuleState<'_, SharedSocialAnalysisService>,
 ) -> Result<Option<AnalysisSentimentSnapshot>, String> {
     let srv = analysis_service.read().await;
     srv.get_sentiment_snapshot(&token)
@@ -187,7 +188,7 @@ pub async fn social_get_sentiment_snapshot(
 #[tauri::command]
 pub async fn social_get_sentiment_snapshots(
     token: Option<String>,
-    analysis_service: State<'_, SharedSocialAnalysisService>,
+    analysis_service: ModuleState<'_, SharedSocialAnalysisService>,
 ) -> Result<Vec<AnalysisSentimentSnapshot>, String> {
     let srv = analysis_service.read().await;
     srv.get_sentiment_snapshots(token.as_deref())
@@ -198,7 +199,7 @@ pub async fn social_get_sentiment_snapshots(
 #[tauri::command]
 pub async fn social_get_trending_tokens(
     window: Option<i64>,
-    analysis_service: State<'_, SharedSocialAnalysisService>,
+    analysis_service: ModuleState<'_, SharedSocialAnalysisService>,
 ) -> Result<Vec<TrendRecord>, String> {
     let srv = analysis_service.read().await;
     srv.get_trending_tokens(window)
@@ -209,7 +210,7 @@ pub async fn social_get_trending_tokens(
 #[tauri::command]
 pub async fn social_get_token_trends(
     token: String,
-    analysis_service: State<'_, SharedSocialAnalysisService>,
+    analysis_service: ModuleState<'_, SharedSocialAnalysisService>,
 ) -> Result<Vec<TrendRecord>, String> {
     let srv = analysis_service.read().await;
     srv.get_token_trends(&token)
@@ -221,7 +222,7 @@ pub async fn social_get_token_trends(
 pub async fn social_get_influencer_scores(
     token: Option<String>,
     min_impact: Option<f32>,
-    analysis_service: State<'_, SharedSocialAnalysisService>,
+    analysis_service: ModuleState<'_, SharedSocialAnalysisService>,
 ) -> Result<Vec<InfluencerScore>, String> {
     let srv = analysis_service.read().await;
     srv.get_influencer_scores(token.as_deref(), min_impact)
@@ -232,7 +233,7 @@ pub async fn social_get_influencer_scores(
 #[tauri::command]
 pub async fn social_get_fomo_fud(
     token: Option<String>,
-    analysis_service: State<'_, SharedSocialAnalysisService>,
+    analysis_service: ModuleState<'_, SharedSocialAnalysisService>,
 ) -> Result<Vec<GaugeReading>, String> {
     let srv = analysis_service.read().await;
     srv.get_fomo_fud_gauges(token.as_deref())
@@ -242,7 +243,7 @@ pub async fn social_get_fomo_fud(
 
 #[tauri::command]
 pub async fn social_get_whale_clusters(
-    whale_service: State<'_, SharedWhaleService>,
+    whale_service: ModuleState<'_, SharedWhaleService>,
 ) -> Result<Vec<WhaleCluster>, String> {
     let srv = whale_service.read().await;
     srv.get_clusters().await.map_err(|e| e.to_string())
@@ -251,7 +252,7 @@ pub async fn social_get_whale_clusters(
 #[tauri::command]
 pub async fn social_get_whale_feed(
     limit: Option<i32>,
-    whale_service: State<'_, SharedWhaleService>,
+    whale_service: ModuleState<'_, SharedWhaleService>,
 ) -> Result<Vec<WhaleFeedEntry>, String> {
     let limit = limit.unwrap_or(50);
     let srv = whale_service.read().await;
@@ -260,7 +261,7 @@ pub async fn social_get_whale_feed(
 
 #[tauri::command]
 pub async fn social_list_followed_wallets(
-    whale_service: State<'_, SharedWhaleService>,
+    whale_service: ModuleState<'_, SharedWhaleService>,
 ) -> Result<Vec<FollowedWallet>, String> {
     let srv = whale_service.read().await;
     srv.get_followed_wallets().await.map_err(|e| e.to_string())
@@ -272,7 +273,7 @@ pub async fn social_follow_wallet(
     label: Option<String>,
     cluster_id: Option<String>,
     priority: Option<i32>,
-    whale_service: State<'_, SharedWhaleService>,
+    whale_service: ModuleState<'_, SharedWhaleService>,
 ) -> Result<FollowedWallet, String> {
     let priority = priority.unwrap_or(0);
     let mut srv = whale_service.write().await;
@@ -284,7 +285,7 @@ pub async fn social_follow_wallet(
 #[tauri::command]
 pub async fn social_unfollow_wallet(
     wallet_address: String,
-    whale_service: State<'_, SharedWhaleService>,
+    whale_service: ModuleState<'_, SharedWhaleService>,
 ) -> Result<(), String> {
     let mut srv = whale_service.write().await;
     srv.unfollow_wallet(&wallet_address)
@@ -295,7 +296,7 @@ pub async fn social_unfollow_wallet(
 #[tauri::command]
 pub async fn social_get_whale_insights(
     wallet_address: String,
-    whale_service: State<'_, SharedWhaleService>,
+    whale_service: ModuleState<'_, SharedWhaleService>,
 ) -> Result<WhaleInsight, String> {
     let srv = whale_service.read().await;
     srv.get_whale_insights(&wallet_address)
@@ -7,11 +7,17 @@ pub mod service;
 pub mod twitter;
 pub mod whales;
 
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tokio::sync::RwLock;
+
 use cache::CacheError;
 use reddit::RedditError;
 use twitter::TwitterError;
 use whales::WhaleError;
 
+use crate::config::feature_flags::{FeatureModule, ModuleOwned};
+
 pub use analysis::{
     AnalysisError, AnalysisSummary, GaugeReading, InfluencerScore, 
     SentimentSnapshot as AnalysisSentimentSnapshot, SharedSocialAnalysisService, 
@@ -30,6 +36,59 @@ pub use whales::{
 
 pub type SharedWhaleService = std::sync::Arc<tokio::sync::RwLock<WhaleService>>;
 
+impl ModuleOwned for SharedSocialDataService {
+    const MODULE: FeatureModule = FeatureModule::Social;
+}
+
+impl ModuleOwned for SharedSocialAnalysisService {
+    const MODULE: FeatureModule = FeatureModule::Social;
+}
+
+impl ModuleOwned for SharedWhaleService {
+    const MODULE: FeatureModule = FeatureModule::Social;
+}
+
+/// Opens the social databases and manages the data, analysis and whale
+/// services. Skipped at startup while the social module is switched off.
+pub async fn start_social_module(app: &AppHandle) -> Result<(), String> {
+    let social_service = SocialDataService::new(app)
+        .await
+        .map_err(|e| format!("Failed to initialize social data service: {e}"))?;
+    let social_state: SharedSocialDataService = Arc::new(RwLock::new(social_service));
+    app.manage(social_state);
+
+    let social_data_dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Unable to resolve app data directory".to_string())?
+        .join("social");
+    std::fs::create_dir_all(&social_data_dir)
+        .map_err(|e| format!("Failed to create social data directory: {e}"))?;
+
+    let social_cache = SocialCache::new(social_data_dir)
+        .await
+        .map_err(|e| format!("Failed to initialize social cache for analysis: {e}"))?;
+    let whale_pool = social_cache.pool();
+
+    let mut analysis_service = SocialAnalysisService::new(social_cache);
+    analysis_service
+        .initialize()
+        .await
+        .map_err(|e| format!("Failed to initialize social analysis service: {e}"))?;
+    let analysis_state: SharedSocialAnalysisService = Arc::new(RwLock::new(analysis_service));
+    app.manage(analysis_state);
+
+    let whale_service = WhaleService::new(whale_pool);
+    whale_service
+        .initialize()
+        .await
+        .map_err(|e| format!("Failed to initialize whale service: {e}"))?;
+    let whale_state: SharedWhaleService = Arc::new(RwLock::new(whale_service));
+    app.manage(whale_state);
+
+    Ok(())
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum SocialError {
     #[error("reddit error: {0}")]
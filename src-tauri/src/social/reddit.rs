@@ -3,6 +3,7 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+use crate::config::http_recorder::{self, HttpRecorderError};
 use crate::sentiment::analyze_sentiment;
 
 use super::models::{FetchMetadata, RateLimitInfo, SocialFetchResult, SocialPost};
@@ -43,12 +44,23 @@ struct RedditPost {
 pub enum RedditError {
     #[error("http error: {0}")]
     Http(#[from] reqwest::Error),
+    #[error("{0}")]
+    Recorder(HttpRecorderError),
     #[error("rate limit exceeded")]
     RateLimitExceeded,
     #[error("parse error: {0}")]
     Parse(String),
 }
 
+impl From<HttpRecorderError> for RedditError {
+    fn from(err: HttpRecorderError) -> Self {
+        match err {
+            HttpRecorderError::Request(err) => RedditError::Http(err),
+            other => RedditError::Recorder(other),
+        }
+    }
+}
+
 pub struct RedditClient {
     client: Client,
     base_url: String,
@@ -97,9 +109,9 @@ impl RedditClient {
             }
         };
 
-        let response = self.client
-            .get(&url)
-            .send()
+        let request = self.client
+            .get(&url);
+        let response = http_recorder::send("reddit", request)
             .await?;
 
         let rate_limit = extract_rate_limit_info(&response);
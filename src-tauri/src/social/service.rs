@@ -1,12 +1,14 @@
+use std::future::Future;
 use std::sync::Arc;
 
 use tauri::AppHandle;
 use tokio::sync::RwLock;
 
+use crate::config::data_sources::{provider_for, read_recording, write_recording, DataModule, DataProvider};
 use crate::security::keystore::Keystore;
 
 use super::cache::{MentionAggregate, SocialCache, TrendSnapshot};
-use super::models::{SocialFetchResult, SocialPost};
+use super::models::{FetchMetadata, RateLimitInfo, SocialFetchResult, SocialPost};
 use super::reddit::RedditClient;
 use super::twitter::TwitterClient;
 use super::SocialError;
@@ -54,16 +56,14 @@ impl SocialDataService {
         limit: Option<u32>,
         token: Option<&str>,
     ) -> Result<SocialFetchResult, SocialError> {
-        let result = self
-            .reddit_client
-            .fetch_subreddit_posts(subreddit, query, limit)
-            .await?;
-
-        self.cache
-            .store_posts(&result.posts, token)
-            .await?;
-
-        Ok(result)
+        let key = format!("reddit_{subreddit}_{}", query.unwrap_or_default());
+        self.fetch_sourced("reddit", query.unwrap_or(subreddit), &key, token, async {
+            Ok(self
+                .reddit_client
+                .fetch_subreddit_posts(subreddit, query, limit)
+                .await?)
+        })
+        .await
     }
 
     pub async fn search_reddit_mentions(
@@ -73,6 +73,14 @@ impl SocialDataService {
         limit: Option<u32>,
         token: Option<&str>,
     ) -> Result<Vec<SocialFetchResult>, SocialError> {
+        match provider_for(DataModule::Social, &format!("reddit_mentions_{keyword}")) {
+            DataProvider::Mock => return Ok(vec![fixture_result("reddit", keyword)]),
+            DataProvider::Recorded(path) => {
+                return read_recording(&path).map_err(SocialError::Internal);
+            }
+            DataProvider::Live { .. } => {}
+        }
+
         let mut aggregated = Vec::new();
 
         for result in self
@@ -97,17 +105,14 @@ impl SocialDataService {
         bearer_override: Option<&str>,
         keystore: Option<&Keystore>,
     ) -> Result<SocialFetchResult, SocialError> {
-        let bearer_token = self.resolve_bearer_token(bearer_override, keystore)?;
-        let result = self
-            .twitter_client
-            .search_tweets(query, &bearer_token, max_results)
-            .await?;
-
-        self.cache
-            .store_posts(&result.posts, token_address)
-            .await?;
-
-        Ok(result)
+        self.fetch_sourced("twitter", query, &format!("twitter_{query}"), token_address, async {
+            let bearer_token = self.resolve_bearer_token(bearer_override, keystore)?;
+            Ok(self
+                .twitter_client
+                .search_tweets(query, &bearer_token, max_results)
+                .await?)
+        })
+        .await
     }
 
     pub async fn fetch_twitter_user(
@@ -118,17 +123,41 @@ impl SocialDataService {
         bearer_override: Option<&str>,
         keystore: Option<&Keystore>,
     ) -> Result<SocialFetchResult, SocialError> {
-        let bearer_token = self.resolve_bearer_token(bearer_override, keystore)?;
-        let result = self
-            .twitter_client
-            .search_user_tweets(username, &bearer_token, max_results)
-            .await?;
-
-        self.cache
-            .store_posts(&result.posts, token_address)
-            .await?;
+        let key = format!("twitter_user_{username}");
+        self.fetch_sourced("twitter", username, &key, token_address, async {
+            let bearer_token = self.resolve_bearer_token(bearer_override, keystore)?;
+            Ok(self
+                .twitter_client
+                .search_user_tweets(username, &bearer_token, max_results)
+                .await?)
+        })
+        .await
+    }
 
-        Ok(result)
+    /// Serves a fetch from the provider the data-source matrix selects. Only live
+    /// results are cached, so fixtures never leak into real mention history.
+    async fn fetch_sourced(
+        &self,
+        source: &str,
+        query: &str,
+        key: &str,
+        token: Option<&str>,
+        live: impl Future<Output = Result<SocialFetchResult, SocialError>>,
+    ) -> Result<SocialFetchResult, SocialError> {
+        match provider_for(DataModule::Social, key) {
+            DataProvider::Mock => Ok(fixture_result(source, query)),
+            DataProvider::Recorded(path) => read_recording(&path).map_err(SocialError::Internal),
+            DataProvider::Live { capture } => {
+                let result = live.await?;
+                self.cache.store_posts(&result.posts, token).await?;
+                if let Some(path) = capture {
+                    if let Err(err) = write_recording(&path, &result) {
+                        tracing::warn!(error = %err, key, "failed to capture social recording");
+                    }
+                }
+                Ok(result)
+            }
+        }
     }
 
     fn resolve_bearer_token(
@@ -204,3 +233,39 @@ impl SocialDataService {
         Ok(())
     }
 }
+
+fn fixture_result(source: &str, query: &str) -> SocialFetchResult {
+    let symbol = crate::demo::find_demo_token(query)
+        .map(|token| token.symbol.to_string())
+        .unwrap_or_else(|| query.trim_start_matches('$').to_string());
+
+    let posts: Vec<SocialPost> = crate::demo::demo_social_posts(&symbol)
+        .into_iter()
+        .filter(|post| post.source == source)
+        .map(|post| SocialPost {
+            sentiment: crate::sentiment::analyze_sentiment(&post.text),
+            id: post.id,
+            text: post.text,
+            source: post.source,
+            author: post.author,
+            timestamp: post.timestamp,
+            engagement: post.engagement,
+        })
+        .collect();
+
+    SocialFetchResult {
+        metadata: FetchMetadata {
+            source: source.to_string(),
+            query: query.to_string(),
+            fetched_at: chrono::Utc::now().timestamp(),
+            result_count: posts.len(),
+            rate_limit: RateLimitInfo {
+                limit: None,
+                remaining: None,
+                used: None,
+                reset_after_seconds: None,
+            },
+        },
+        posts,
+    }
+}
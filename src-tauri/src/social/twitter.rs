@@ -6,6 +6,7 @@ use std::time::Duration;
 use tokio::sync::{RwLock, Semaphore};
 
 use crate::security::keystore::Keystore;
+use crate::config::http_recorder::{self, HttpRecorderError};
 use crate::sentiment::analyze_sentiment;
 
 use super::models::{FetchMetadata, RateLimitInfo, SocialFetchResult, SocialPost};
@@ -49,6 +50,8 @@ struct TwitterSearchMeta {
 pub enum TwitterError {
     #[error("http error: {0}")]
     Http(#[from] reqwest::Error),
+    #[error("{0}")]
+    Recorder(HttpRecorderError),
     #[error("rate limit exceeded")]
     RateLimitExceeded,
     #[error("authentication failed: {0}")]
@@ -59,6 +62,15 @@ pub enum TwitterError {
     Parse(String),
 }
 
+impl From<HttpRecorderError> for TwitterError {
+    fn from(err: HttpRecorderError) -> Self {
+        match err {
+            HttpRecorderError::Request(err) => TwitterError::Http(err),
+            other => TwitterError::Recorder(other),
+        }
+    }
+}
+
 pub struct TwitterClient {
     client: Client,
     rate_limiter: Arc<Semaphore>,
@@ -128,15 +140,15 @@ impl TwitterClient {
         
         let url = format!("{}/tweets/search/recent", self.base_url);
         
-        let response = self.client
+        let request = self.client
             .get(&url)
             .bearer_auth(bearer_token)
             .query(&[
                 ("query", query),
                 ("max_results", &max_results.to_string()),
                 ("tweet.fields", "created_at,public_metrics,author_id"),
-            ])
-            .send()
+            ]);
+        let response = http_recorder::send("twitter", request)
             .await?;
 
         let rate_limit = extract_rate_limit_info(&response);
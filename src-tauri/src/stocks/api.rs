@@ -8,6 +8,17 @@ const POLYGON_BASE_URL: &str = "https://api.polygon.io";
 const IEX_BASE_URL: &str = "https://cloud.iexapis.com/stable";
 const FINNHUB_BASE_URL: &str = "https://finnhub.io/api/v1";
 
+/// Fixtures are served in demo mode only; without a live provider the data is
+/// reported as unavailable.
+fn demo_only<T>(feature: &str, fixture: impl FnOnce() -> T) -> Result<T, String> {
+    crate::demo::require_demo_mode(feature).map(|()| fixture())
+}
+
+/// Providers whose responses aren't parsed yet can't back live data.
+fn unparsed<T>(provider: &str) -> Result<T, String> {
+    Err(format!("UNAVAILABLE: {provider} responses are not supported yet"))
+}
+
 pub struct StockApiClient {
     alpha_vantage_key: Option<String>,
     polygon_key: Option<String>,
@@ -34,17 +45,12 @@ impl StockApiClient {
 
     pub async fn fetch_trending_stocks(&self) -> Result<Vec<TrendingStock>, String> {
         // Use Finnhub for most actives (trending)
-        if let Some(api_key) = &self.finnhub_key {
-            return self.fetch_finnhub_actives(api_key).await;
+        match &self.finnhub_key {
+            Some(api_key) if !crate::demo::is_demo_mode() => {
+                self.fetch_finnhub_actives(api_key).await
+            }
+            _ => demo_only("Trending stocks", || self.generate_mock_trending_stocks()),
         }
-
-        // Fallback to Alpha Vantage
-        if let Some(api_key) = &self.alpha_vantage_key {
-            return self.fetch_alpha_vantage_actives(api_key).await;
-        }
-
-        // Return mock data if no API key
-        Ok(self.generate_mock_trending_stocks())
     }
 
     async fn fetch_finnhub_actives(&self, api_key: &str) -> Result<Vec<TrendingStock>, String> {
@@ -112,22 +118,16 @@ impl StockApiClient {
         Ok(stocks)
     }
 
-    async fn fetch_alpha_vantage_actives(&self, api_key: &str) -> Result<Vec<TrendingStock>, String> {
-        // Alpha Vantage doesn't have a direct "actives" endpoint
-        // Would need to fetch multiple symbols or use premium endpoint
-        // Return mock for now
-        Ok(self.generate_mock_trending_stocks())
-    }
-
     pub async fn fetch_top_movers(&self, session: TradingSession) -> Result<Vec<TopMover>, String> {
-        if let Some(api_key) = &self.finnhub_key {
-            return self.fetch_finnhub_movers(api_key, session).await;
+        match &self.finnhub_key {
+            Some(api_key) if !crate::demo::is_demo_mode() => {
+                self.fetch_finnhub_movers(api_key, session).await
+            }
+            _ => demo_only("Stock top movers", || self.generate_mock_top_movers(session)),
         }
-
-        Ok(self.generate_mock_top_movers(session))
     }
 
-    async fn fetch_finnhub_movers(&self, api_key: &str, session: TradingSession) -> Result<Vec<TopMover>, String> {
+    async fn fetch_finnhub_movers(&self, api_key: &str, _session: TradingSession) -> Result<Vec<TopMover>, String> {
         let url = format!("{}/stock/market-movers?token={}", FINNHUB_BASE_URL, api_key);
 
         let response = self
@@ -141,17 +141,14 @@ impl StockApiClient {
             return Err(format!("Finnhub API error: {}", response.status()));
         }
 
-        // Parse and transform to TopMover
-        // For now return mock
-        Ok(self.generate_mock_top_movers(session))
+        unparsed("Finnhub market movers")
     }
 
     pub async fn fetch_new_ipos(&self) -> Result<Vec<NewIPO>, String> {
-        if let Some(api_key) = &self.iex_key {
-            return self.fetch_iex_ipos(api_key).await;
+        match &self.iex_key {
+            Some(api_key) if !crate::demo::is_demo_mode() => self.fetch_iex_ipos(api_key).await,
+            _ => demo_only("IPO listings", || self.generate_mock_ipos()),
         }
-
-        Ok(self.generate_mock_ipos())
     }
 
     async fn fetch_iex_ipos(&self, api_key: &str) -> Result<Vec<NewIPO>, String> {
@@ -168,16 +165,18 @@ impl StockApiClient {
             return Err(format!("IEX API error: {}", response.status()));
         }
 
-        // Parse and transform
-        Ok(self.generate_mock_ipos())
+        unparsed("IEX IPO")
     }
 
     pub async fn fetch_earnings_calendar(&self, days_ahead: u32) -> Result<Vec<EarningsEvent>, String> {
-        if let Some(api_key) = &self.alpha_vantage_key {
-            return self.fetch_alpha_vantage_earnings(api_key).await;
+        match &self.alpha_vantage_key {
+            Some(api_key) if !crate::demo::is_demo_mode() => {
+                self.fetch_alpha_vantage_earnings(api_key).await
+            }
+            _ => demo_only("Earnings calendar", || {
+                self.generate_mock_earnings_calendar(days_ahead)
+            }),
         }
-
-        Ok(self.generate_mock_earnings_calendar(days_ahead))
     }
 
     async fn fetch_alpha_vantage_earnings(&self, api_key: &str) -> Result<Vec<EarningsEvent>, String> {
@@ -197,20 +196,19 @@ impl StockApiClient {
             return Err(format!("Alpha Vantage API error: {}", response.status()));
         }
 
-        // Parse CSV response
-        // For now return mock
-        Ok(self.generate_mock_earnings_calendar(30))
+        unparsed("Alpha Vantage earnings")
     }
 
     pub async fn fetch_stock_news(&self, symbol: &str, limit: usize) -> Result<Vec<StockNews>, String> {
-        if let Some(api_key) = &self.finnhub_key {
-            return self.fetch_finnhub_news(api_key, symbol, limit).await;
+        match &self.finnhub_key {
+            Some(api_key) if !crate::demo::is_demo_mode() => {
+                self.fetch_finnhub_news(api_key, symbol).await
+            }
+            _ => demo_only("Stock news", || self.generate_mock_stock_news(symbol, limit)),
         }
-
-        Ok(self.generate_mock_stock_news(symbol, limit))
     }
 
-    async fn fetch_finnhub_news(&self, api_key: &str, symbol: &str, limit: usize) -> Result<Vec<StockNews>, String> {
+    async fn fetch_finnhub_news(&self, api_key: &str, symbol: &str) -> Result<Vec<StockNews>, String> {
         let url = format!(
             "{}/company-news?symbol={}&from={}&to={}&token={}",
             FINNHUB_BASE_URL,
@@ -231,16 +229,18 @@ impl StockApiClient {
             return Err(format!("Finnhub API error: {}", response.status()));
         }
 
-        // Parse and transform
-        Ok(self.generate_mock_stock_news(symbol, limit))
+        unparsed("Finnhub company news")
     }
 
     pub async fn fetch_institutional_holdings(&self, symbol: &str) -> Result<Vec<InstitutionalHolding>, String> {
-        if let Some(api_key) = &self.finnhub_key {
-            return self.fetch_finnhub_institutional(api_key, symbol).await;
+        match &self.finnhub_key {
+            Some(api_key) if !crate::demo::is_demo_mode() => {
+                self.fetch_finnhub_institutional(api_key, symbol).await
+            }
+            _ => demo_only("Institutional holdings", || {
+                self.generate_mock_institutional_holdings(symbol)
+            }),
         }
-
-        Ok(self.generate_mock_institutional_holdings(symbol))
     }
 
     async fn fetch_finnhub_institutional(&self, api_key: &str, symbol: &str) -> Result<Vec<InstitutionalHolding>, String> {
@@ -257,16 +257,16 @@ impl StockApiClient {
             return Err(format!("Finnhub API error: {}", response.status()));
         }
 
-        // Parse and transform
-        Ok(self.generate_mock_institutional_holdings(symbol))
+        unparsed("Finnhub institutional ownership")
     }
 
     pub async fn fetch_insider_activity(&self, symbol: &str) -> Result<Vec<InsiderActivity>, String> {
-        if let Some(api_key) = &self.finnhub_key {
-            return self.fetch_finnhub_insider(api_key, symbol).await;
+        match &self.finnhub_key {
+            Some(api_key) if !crate::demo::is_demo_mode() => {
+                self.fetch_finnhub_insider(api_key, symbol).await
+            }
+            _ => demo_only("Insider activity", || self.generate_mock_insider_activity(symbol)),
         }
-
-        Ok(self.generate_mock_insider_activity(symbol))
     }
 
     async fn fetch_finnhub_insider(&self, api_key: &str, symbol: &str) -> Result<Vec<InsiderActivity>, String> {
@@ -283,11 +283,10 @@ impl StockApiClient {
             return Err(format!("Finnhub API error: {}", response.status()));
         }
 
-        // Parse and transform
-        Ok(self.generate_mock_insider_activity(symbol))
+        unparsed("Finnhub insider transactions")
     }
 
-    // Mock data generators for fallback
+    // Demo fixtures
     fn generate_mock_trending_stocks(&self) -> Vec<TrendingStock> {
         vec![
             TrendingStock {
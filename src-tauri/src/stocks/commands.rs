@@ -49,7 +49,7 @@ pub async fn get_trending_stocks(
         }
     }
 
-    // Fetch fresh data (using mock data for now)
+    // No provider keys are wired in yet, so this only succeeds in demo mode
     let client = StockApiClient::new(None, None, None, None);
     let stocks = client.fetch_trending_stocks().await?;
 
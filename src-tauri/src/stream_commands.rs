@@ -1,5 +1,6 @@
 use crate::core::WebSocketManager;
 use crate::websocket::types::{StreamProvider, StreamStatus};
+use crate::websocket::watchdog::ProviderIncidentStats;
 use tauri::State;
 
 #[tauri::command]
@@ -66,3 +67,17 @@ pub async fn reconnect_stream(
 
     manager.reconnect(provider).await.map_err(|e| e.to_string())
 }
+
+/// Stalls the stream watchdog recovered from, per provider.
+#[tauri::command]
+pub async fn get_stream_incidents(
+    manager: State<'_, WebSocketManager>,
+) -> Result<Vec<ProviderIncidentStats>, String> {
+    Ok(manager.incident_stats())
+}
+
+#[tauri::command]
+pub async fn reset_stream_incidents(manager: State<'_, WebSocketManager>) -> Result<(), String> {
+    manager.reset_incidents();
+    Ok(())
+}
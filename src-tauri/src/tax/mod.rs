@@ -13,6 +13,8 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use crate::config::token_registry::apply_token_overrides;
+use crate::core::price_engine::get_price_engine;
 use crate::portfolio::{TaxLot, TaxReportParams, SharedTaxLotsState};
 use crate::security::keystore::Keystore;
 use tauri::State;
@@ -41,7 +43,7 @@ pub type SharedTaxPlanningEngine = Arc<RwLock<TaxPlanningEngine>>;
 
 impl TaxPlanningEngine {
     pub fn new(jurisdiction: TaxJurisdiction) -> Self {
-        let mut engine = Self {
+        Self {
             jurisdiction: jurisdiction.clone(),
             settings: TaxSettings {
                 jurisdiction,
@@ -51,22 +53,21 @@ impl TaxPlanningEngine {
             current_prices: HashMap::new(),
             carryforward_losses: 0.0,
             ytd_realized_gains: 0.0,
-        };
-
-        // Populate with mock price data
-        engine.current_prices.insert("SOL".to_string(), 170.5);
-        engine.current_prices.insert("BTC".to_string(), 64000.0);
-        engine.current_prices.insert("ETH".to_string(), 3200.0);
-        engine.current_prices.insert("JUP".to_string(), 1.98);
-
-        engine
+        }
     }
 
+    /// Demo fixtures: prices and recent trades for the wash-sale and
+    /// harvesting views. Only used while demo mode is on.
     pub fn generate_mock_transactions(&mut self) {
         if !self.recent_transactions.is_empty() {
             return;
         }
 
+        self.current_prices.insert("SOL".to_string(), 170.5);
+        self.current_prices.insert("BTC".to_string(), 64000.0);
+        self.current_prices.insert("ETH".to_string(), 3200.0);
+        self.current_prices.insert("JUP".to_string(), 1.98);
+
         let now = Utc::now();
         self.recent_transactions = vec![
             ("SOL".to_string(), now - chrono::Duration::days(5), 120.0, "BUY".to_string()),
@@ -144,14 +145,22 @@ pub async fn get_tax_center_summary(
         }
     }
 
-    let mut unrealized = Vec::new();
-    let mut current_prices = engine.current_prices.clone();
-    if current_prices.is_empty() {
-        current_prices.insert("SOL".to_string(), 170.5);
-    }
+    // Outside demo mode prices come from the price engine and there is no
+    // trade feed yet, so wash-sale checks only see the lots themselves.
+    let (current_prices, recent_transactions) = if crate::demo::is_demo_mode() {
+        let mut fixtures = TaxPlanningEngine::default();
+        fixtures.generate_mock_transactions();
+        (fixtures.current_prices, fixtures.recent_transactions)
+    } else {
+        (live_lot_prices(&open_lots), engine.recent_transactions.clone())
+    };
 
+    let mut unrealized = Vec::new();
     for lot in open_lots.iter() {
-        let price = current_prices.get(&lot.symbol).cloned().unwrap_or(lot.price_per_unit);
+        // Lots without a known price can't be valued
+        let Some(price) = current_prices.get(&lot.symbol).copied() else {
+            continue;
+        };
         let current_value = price * lot.amount;
         let gain_loss = current_value - lot.cost_basis;
         if gain_loss >= 0.0 {
@@ -168,10 +177,10 @@ pub async fn get_tax_center_summary(
         tax_year,
     );
 
-    let warnings = wash_sale_detector.detect_wash_sales(&lots, &engine.recent_transactions);
+    let warnings = wash_sale_detector.detect_wash_sales(&lots, &recent_transactions);
 
     let mut recent_purchases: HashMap<String, Vec<(chrono::DateTime<Utc>, f64)>> = HashMap::new();
-    for (asset, date, amount, txn_type) in engine.recent_transactions.iter() {
+    for (asset, date, amount, txn_type) in recent_transactions.iter() {
         if txn_type == "BUY" || txn_type == "RECEIVE" {
             recent_purchases
                 .entry(asset.clone())
@@ -180,7 +189,8 @@ pub async fn get_tax_center_summary(
         }
     }
 
-    let recommendations = harvester.generate_recommendations(&open_lots, &engine.current_prices, &recent_purchases);
+    let recommendations =
+        harvester.generate_recommendations(&open_lots, &current_prices, &recent_purchases);
 
     let alerts = build_tax_alerts(&projection, &warnings, &recommendations);
     let available_jurisdictions = JurisdictionManager::get_available_jurisdictions();
@@ -195,6 +205,16 @@ pub async fn get_tax_center_summary(
     })
 }
 
+fn live_lot_prices(lots: &[TaxLot]) -> HashMap<String, f64> {
+    let prices = get_price_engine();
+    lots.iter()
+        .filter_map(|lot| {
+            let cached = prices.get_cached_price(&lot.symbol)?;
+            (cached.price > 0.0).then(|| (lot.symbol.clone(), cached.price))
+        })
+        .collect()
+}
+
 #[tauri::command]
 pub async fn update_tax_settings(
     settings: TaxSettings,
@@ -231,6 +251,10 @@ pub async fn export_tax_center_report(
                 .map(|dt| dt.year() == params.tax_year)
                 .unwrap_or(false)
         })
+        .map(|mut lot| {
+            apply_token_overrides(&lot.mint, &mut lot.symbol, None, None, None);
+            lot
+        })
         .collect::<Vec<_>>();
 
     match format.as_str() {
@@ -352,8 +376,5 @@ pub fn initialize_tax_engine(keystore: &Keystore) -> SharedTaxPlanningEngine {
     let jurisdiction = manager
         .load_jurisdiction(keystore, "default")
         .unwrap_or_else(|_| TaxJurisdiction::default());
-    let mut engine = TaxPlanningEngine::new(jurisdiction);
-    engine.generate_mock_transactions();
-
-    Arc::new(RwLock::new(engine))
+    Arc::new(RwLock::new(TaxPlanningEngine::new(jurisdiction)))
 }
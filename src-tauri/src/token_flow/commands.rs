@@ -46,6 +46,30 @@ pub struct FlowAnalysisState {
     pub subscriptions: Vec<ClusterSubscription>,
 }
 
+impl FlowAnalysisState {
+    /// Approximate memory held by stored graph snapshots, as serialized size.
+    pub fn estimated_size_bytes(&self) -> usize {
+        self.snapshots.iter().map(snapshot_size).sum()
+    }
+
+    /// Drops the oldest snapshots until the rest fit in `target_bytes`.
+    /// Returns the number of snapshots dropped.
+    pub fn shrink_to(&mut self, target_bytes: usize) -> usize {
+        let mut total = self.estimated_size_bytes();
+        let mut removed = 0;
+        while total > target_bytes && !self.snapshots.is_empty() {
+            let snapshot = self.snapshots.remove(0);
+            total = total.saturating_sub(snapshot_size(&snapshot));
+            removed += 1;
+        }
+        removed
+    }
+}
+
+fn snapshot_size(snapshot: &FlowSnapshot) -> usize {
+    serde_json::to_vec(snapshot).map_or(0, |bytes| bytes.len())
+}
+
 pub type SharedFlowAnalysisState = std::sync::Arc<RwLock<FlowAnalysisState>>;
 
 pub fn create_token_flow_state() -> SharedFlowAnalysisState {
@@ -0,0 +1,1107 @@
+use crate::ai::SharedRiskAnalyzer;
+use crate::api::jupiter::{jupiter_quote, QuoteCommandInput, SwapMode};
+use crate::api_analytics::RequestPriority;
+use crate::data::event_store::{record_app_event, Event as AuditEvent};
+use crate::sentiment::SharedSentimentManager;
+use crate::trading::order_manager::SharedOrderManager;
+use crate::trading::paper_trading::{execute_paper_trade, ExecutePaperTradeRequest};
+use crate::trading::safety::{SafetyCheckRequest, SharedSafetyEngine};
+use crate::trading::types::{CreateOrderRequest, OrderSide, OrderType};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqlitePool, Pool, Row, Sqlite};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tokio::sync::{OnceCell, RwLock};
+use tokio::time::{interval, Duration};
+use uuid::Uuid;
+
+const AUTOMATION_EVAL_INTERVAL_SECS: u64 = 30;
+const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+const DEFAULT_SLIPPAGE_BPS: i32 = 100;
+const DEFAULT_COOLDOWN_MINUTES: i64 = 60;
+/// A rule must fill this many times on paper, over at least this long, before
+/// it may trade live.
+pub const PAPER_TRIAL_MIN_FILLS: u32 = 3;
+pub const PAPER_TRIAL_MIN_HOURS: i64 = 24;
+const RISK_WEIGHT: f64 = 0.6;
+const SENTIMENT_WEIGHT: f64 = 0.4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertAutomationMetric {
+    /// 0-100 blend of token safety (inverse risk score) and social sentiment.
+    CompositeScore,
+    Price,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrossDirection {
+    Above,
+    Below,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertAutomationTrigger {
+    pub metric: AlertAutomationMetric,
+    pub direction: CrossDirection,
+    pub threshold: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertAutomationMode {
+    Paper,
+    Live,
+}
+
+impl AlertAutomationMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertAutomationMode::Paper => "paper",
+            AlertAutomationMode::Live => "live",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertAutomationStatus {
+    /// Paper trade filled.
+    Filled,
+    /// Live order placed through the order manager.
+    Placed,
+    /// Stopped by a spend limit or the safety engine.
+    Blocked,
+    Failed,
+}
+
+impl AlertAutomationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertAutomationStatus::Filled => "filled",
+            AlertAutomationStatus::Placed => "placed",
+            AlertAutomationStatus::Blocked => "blocked",
+            AlertAutomationStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "filled" => AlertAutomationStatus::Filled,
+            "placed" => AlertAutomationStatus::Placed,
+            "blocked" => AlertAutomationStatus::Blocked,
+            _ => AlertAutomationStatus::Failed,
+        }
+    }
+}
+
+/// Buys `amount_sol` SOL of a token when the trigger crosses its threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertAutomationRule {
+    pub id: String,
+    pub name: String,
+    pub wallet_address: String,
+    pub token_mint: String,
+    pub token_symbol: String,
+    pub trigger: AlertAutomationTrigger,
+    pub amount_sol: f64,
+    pub max_spend_per_day_sol: f64,
+    pub max_spend_total_sol: f64,
+    pub slippage_bps: i32,
+    pub cooldown_minutes: i64,
+    pub mode: AlertAutomationMode,
+    pub enabled: bool,
+    pub paper_fills: u32,
+    pub trial_started_at: DateTime<Utc>,
+    /// Trigger value at the last evaluation, used to detect crossings.
+    pub last_value: Option<f64>,
+    pub last_triggered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub promoted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAlertAutomationRequest {
+    pub name: String,
+    pub wallet_address: String,
+    pub token_mint: String,
+    pub token_symbol: String,
+    pub trigger: AlertAutomationTrigger,
+    pub amount_sol: f64,
+    pub max_spend_per_day_sol: f64,
+    pub max_spend_total_sol: f64,
+    #[serde(default)]
+    pub slippage_bps: Option<i32>,
+    #[serde(default)]
+    pub cooldown_minutes: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertAutomationExecution {
+    pub id: String,
+    pub rule_id: String,
+    pub mode: AlertAutomationMode,
+    pub status: AlertAutomationStatus,
+    pub trigger_value: f64,
+    pub threshold: f64,
+    pub amount_sol: f64,
+    pub token_price: Option<f64>,
+    pub order_id: Option<String>,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Jupiter quote for the buy a live rule would place.
+fn buy_quote_input(rule: &AlertAutomationRule) -> QuoteCommandInput {
+    QuoteCommandInput {
+        input_mint: SOL_MINT.to_string(),
+        output_mint: rule.token_mint.clone(),
+        amount: (rule.amount_sol * LAMPORTS_PER_SOL).round() as u64,
+        slippage_bps: Some(rule.slippage_bps.clamp(0, u16::MAX as i32) as u16),
+        swap_mode: Some(SwapMode::ExactIn),
+        platform_fee_bps: None,
+        only_direct_routes: None,
+        referral_account: None,
+        as_legacy_transaction: None,
+        priority_fee_config: None,
+    }
+}
+
+/// Blend of safety (100 minus the 0-100 risk score) and sentiment (-1 to 1),
+/// re-weighted over whichever inputs are available.
+pub fn composite_token_score(risk_score: Option<f64>, sentiment: Option<f64>) -> Option<f64> {
+    let parts = [
+        risk_score.map(|risk| ((100.0 - risk).clamp(0.0, 100.0), RISK_WEIGHT)),
+        sentiment.map(|s| (((s + 1.0) * 50.0).clamp(0.0, 100.0), SENTIMENT_WEIGHT)),
+    ];
+    let weight: f64 = parts.iter().flatten().map(|(_, w)| w).sum();
+    if weight <= 0.0 {
+        return None;
+    }
+    Some(parts.iter().flatten().map(|(v, w)| v * w).sum::<f64>() / weight)
+}
+
+/// True only on the evaluation where the value moves through the threshold;
+/// the first reading just primes the rule.
+pub fn trigger_crossed(
+    trigger: &AlertAutomationTrigger,
+    previous: Option<f64>,
+    current: f64,
+) -> bool {
+    let Some(previous) = previous else {
+        return false;
+    };
+    match trigger.direction {
+        CrossDirection::Above => previous < trigger.threshold && current >= trigger.threshold,
+        CrossDirection::Below => previous > trigger.threshold && current <= trigger.threshold,
+    }
+}
+
+/// Reason the next trade would break a spend limit, if it would.
+pub fn spend_limit_block(
+    rule: &AlertAutomationRule,
+    spent_today: f64,
+    spent_total: f64,
+) -> Option<String> {
+    if spent_today + rule.amount_sol > rule.max_spend_per_day_sol + f64::EPSILON {
+        return Some(format!(
+            "Daily limit: {:.3} of {:.3} SOL already spent today",
+            spent_today, rule.max_spend_per_day_sol
+        ));
+    }
+    if spent_total + rule.amount_sol > rule.max_spend_total_sol + f64::EPSILON {
+        return Some(format!(
+            "Total limit: {:.3} of {:.3} SOL already spent",
+            spent_total, rule.max_spend_total_sol
+        ));
+    }
+    None
+}
+
+/// What still stands between a paper rule and live trading.
+pub fn paper_trial_blockers(rule: &AlertAutomationRule, now: DateTime<Utc>) -> Vec<String> {
+    let mut blockers = Vec::new();
+    if rule.paper_fills < PAPER_TRIAL_MIN_FILLS {
+        blockers.push(format!(
+            "{} of {} paper fills completed",
+            rule.paper_fills, PAPER_TRIAL_MIN_FILLS
+        ));
+    }
+    let hours = (now - rule.trial_started_at).num_hours();
+    if hours < PAPER_TRIAL_MIN_HOURS {
+        blockers.push(format!(
+            "Paper trial has run {}h of the required {}h",
+            hours, PAPER_TRIAL_MIN_HOURS
+        ));
+    }
+    blockers
+}
+
+fn validate_request(request: &CreateAlertAutomationRequest) -> Result<(), String> {
+    if request.name.trim().is_empty() || request.token_mint.trim().is_empty() {
+        return Err("A rule needs a name and a token".to_string());
+    }
+    if !request.trigger.threshold.is_finite() {
+        return Err("Trigger threshold must be a number".to_string());
+    }
+    if request.trigger.metric == AlertAutomationMetric::CompositeScore
+        && !(0.0..=100.0).contains(&request.trigger.threshold)
+    {
+        return Err("Composite score thresholds run from 0 to 100".to_string());
+    }
+    if request.amount_sol <= 0.0 {
+        return Err("Trade amount must be positive".to_string());
+    }
+    if request.max_spend_per_day_sol < request.amount_sol
+        || request.max_spend_total_sol < request.max_spend_per_day_sol
+    {
+        return Err(
+            "Spend limits must cover one trade, and the total must cover the daily limit"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
+pub struct AlertAutomationDatabase {
+    pool: Pool<Sqlite>,
+}
+
+impl AlertAutomationDatabase {
+    pub async fn new(db_path: PathBuf) -> Result<Self, sqlx::Error> {
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+        let db = Self { pool };
+        db.initialize().await?;
+        Ok(db)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_automation_rules (
+                id TEXT PRIMARY KEY,
+                enabled INTEGER NOT NULL,
+                data TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS alert_automation_executions (
+                id TEXT PRIMARY KEY,
+                rule_id TEXT NOT NULL,
+                mode TEXT NOT NULL,
+                status TEXT NOT NULL,
+                trigger_value REAL NOT NULL,
+                threshold REAL NOT NULL,
+                amount_sol REAL NOT NULL,
+                token_price REAL,
+                order_id TEXT,
+                message TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_alert_automation_exec_rule
+                ON alert_automation_executions(rule_id, created_at);
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn save_rule(&self, rule: &AlertAutomationRule) -> Result<(), String> {
+        let data = serde_json::to_string(rule).map_err(|e| e.to_string())?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO alert_automation_rules (id, enabled, data, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(id) DO UPDATE SET
+                enabled = excluded.enabled,
+                data = excluded.data,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&rule.id)
+        .bind(rule.enabled)
+        .bind(data)
+        .bind(rule.created_at.to_rfc3339())
+        .bind(rule.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save automation rule: {e}"))?;
+
+        Ok(())
+    }
+
+    pub async fn get_rule(&self, id: &str) -> Result<Option<AlertAutomationRule>, String> {
+        let row = sqlx::query("SELECT data FROM alert_automation_rules WHERE id = ?1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load automation rule: {e}"))?;
+
+        row.map(|row| {
+            let data: String = row.get("data");
+            serde_json::from_str(&data).map_err(|e| e.to_string())
+        })
+        .transpose()
+    }
+
+    pub async fn list_rules(&self, enabled_only: bool) -> Result<Vec<AlertAutomationRule>, String> {
+        let sql = if enabled_only {
+            "SELECT data FROM alert_automation_rules WHERE enabled = 1 ORDER BY created_at DESC"
+        } else {
+            "SELECT data FROM alert_automation_rules ORDER BY created_at DESC"
+        };
+        let rows = sqlx::query(sql)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to list automation rules: {e}"))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|row| serde_json::from_str(row.get::<String, _>("data").as_str()).ok())
+            .collect())
+    }
+
+    pub async fn delete_rule(&self, id: &str) -> Result<(), String> {
+        sqlx::query("DELETE FROM alert_automation_rules WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to delete automation rule: {e}"))?;
+        Ok(())
+    }
+
+    pub async fn record_execution(
+        &self,
+        execution: &AlertAutomationExecution,
+    ) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO alert_automation_executions (
+                id, rule_id, mode, status, trigger_value, threshold, amount_sol,
+                token_price, order_id, message, created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            "#,
+        )
+        .bind(&execution.id)
+        .bind(&execution.rule_id)
+        .bind(execution.mode.as_str())
+        .bind(execution.status.as_str())
+        .bind(execution.trigger_value)
+        .bind(execution.threshold)
+        .bind(execution.amount_sol)
+        .bind(execution.token_price)
+        .bind(&execution.order_id)
+        .bind(&execution.message)
+        .bind(execution.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to record automation execution: {e}"))?;
+        Ok(())
+    }
+
+    pub async fn list_executions(
+        &self,
+        rule_id: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<AlertAutomationExecution>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM alert_automation_executions
+            WHERE ?1 IS NULL OR rule_id = ?1
+            ORDER BY created_at DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(rule_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load automation log: {e}"))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| AlertAutomationExecution {
+                id: row.get("id"),
+                rule_id: row.get("rule_id"),
+                mode: if row.get::<String, _>("mode") == "live" {
+                    AlertAutomationMode::Live
+                } else {
+                    AlertAutomationMode::Paper
+                },
+                status: AlertAutomationStatus::parse(row.get::<String, _>("status").as_str()),
+                trigger_value: row.get("trigger_value"),
+                threshold: row.get("threshold"),
+                amount_sol: row.get("amount_sol"),
+                token_price: row.get("token_price"),
+                order_id: row.get("order_id"),
+                message: row.get("message"),
+                created_at: DateTime::parse_from_rfc3339(
+                    row.get::<String, _>("created_at").as_str(),
+                )
+                .map(|time| time.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+            })
+            .collect())
+    }
+
+    /// SOL spent by a rule in one mode, since `since` or ever.
+    pub async fn spent_since(
+        &self,
+        rule_id: &str,
+        mode: AlertAutomationMode,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<f64, String> {
+        let row = sqlx::query(
+            r#"
+            SELECT COALESCE(SUM(amount_sol), 0) as spent
+            FROM alert_automation_executions
+            WHERE rule_id = ?1 AND mode = ?2 AND status IN ('filled', 'placed')
+              AND (?3 IS NULL OR created_at >= ?3)
+            "#,
+        )
+        .bind(rule_id)
+        .bind(mode.as_str())
+        .bind(since.map(|time| time.to_rfc3339()))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to total automation spend: {e}"))?;
+        Ok(row.get("spent"))
+    }
+}
+
+pub type SharedAlertAutomationDatabase = Arc<RwLock<AlertAutomationDatabase>>;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AlertAutomationEvent {
+    pub rule_id: String,
+    pub name: String,
+    pub token_symbol: String,
+    pub execution: AlertAutomationExecution,
+}
+
+pub struct AlertAutomationManager {
+    db: SharedAlertAutomationDatabase,
+    orders: SharedOrderManager,
+    app_handle: AppHandle,
+}
+
+impl AlertAutomationManager {
+    pub fn new(
+        db: SharedAlertAutomationDatabase,
+        orders: SharedOrderManager,
+        app_handle: AppHandle,
+    ) -> Self {
+        Self {
+            db,
+            orders,
+            app_handle,
+        }
+    }
+
+    /// New rules always start in paper mode.
+    pub async fn create_rule(
+        &self,
+        request: CreateAlertAutomationRequest,
+    ) -> Result<AlertAutomationRule, String> {
+        validate_request(&request)?;
+        let now = Utc::now();
+        let rule = AlertAutomationRule {
+            id: Uuid::new_v4().to_string(),
+            name: request.name.trim().to_string(),
+            wallet_address: request.wallet_address,
+            token_mint: request.token_mint,
+            token_symbol: request.token_symbol,
+            trigger: request.trigger,
+            amount_sol: request.amount_sol,
+            max_spend_per_day_sol: request.max_spend_per_day_sol,
+            max_spend_total_sol: request.max_spend_total_sol,
+            slippage_bps: request.slippage_bps.unwrap_or(DEFAULT_SLIPPAGE_BPS).max(0),
+            cooldown_minutes: request
+                .cooldown_minutes
+                .unwrap_or(DEFAULT_COOLDOWN_MINUTES)
+                .max(0),
+            mode: AlertAutomationMode::Paper,
+            enabled: true,
+            paper_fills: 0,
+            trial_started_at: now,
+            last_value: None,
+            last_triggered_at: None,
+            created_at: now,
+            updated_at: now,
+            promoted_at: None,
+        };
+        self.db.read().await.save_rule(&rule).await?;
+        Ok(rule)
+    }
+
+    pub async fn get_rule(&self, id: &str) -> Result<AlertAutomationRule, String> {
+        self.db
+            .read()
+            .await
+            .get_rule(id)
+            .await?
+            .ok_or_else(|| "Automation rule not found".to_string())
+    }
+
+    pub async fn set_enabled(
+        &self,
+        id: &str,
+        enabled: bool,
+    ) -> Result<AlertAutomationRule, String> {
+        let mut rule = self.get_rule(id).await?;
+        rule.enabled = enabled;
+        // Re-prime on resume so a stale reading cannot fire a trade
+        rule.last_value = None;
+        rule.updated_at = Utc::now();
+        self.db.read().await.save_rule(&rule).await?;
+        Ok(rule)
+    }
+
+    /// Moves a rule to live once its paper trial is complete. Dropping back to
+    /// paper never needs confirmation but restarts the trial.
+    pub async fn set_mode(
+        &self,
+        id: &str,
+        mode: AlertAutomationMode,
+        confirmed: bool,
+    ) -> Result<AlertAutomationRule, String> {
+        let mut rule = self.get_rule(id).await?;
+        let now = Utc::now();
+        match mode {
+            AlertAutomationMode::Live => {
+                if rule.mode == AlertAutomationMode::Live {
+                    return Ok(rule);
+                }
+                let blockers = paper_trial_blockers(&rule, now);
+                if !blockers.is_empty() {
+                    return Err(format!("Paper trial incomplete: {}", blockers.join(", ")));
+                }
+                if !confirmed {
+                    return Err("Going live places real orders and must be confirmed".to_string());
+                }
+                rule.promoted_at = Some(now);
+            }
+            AlertAutomationMode::Paper => {
+                rule.paper_fills = 0;
+                rule.trial_started_at = now;
+                rule.promoted_at = None;
+            }
+        }
+        rule.mode = mode;
+        rule.last_value = None;
+        rule.updated_at = now;
+        self.db.read().await.save_rule(&rule).await?;
+        Ok(rule)
+    }
+
+    async fn quote_price(&self, mint: &str) -> Option<f64> {
+        crate::market::quote_coin_price(&self.app_handle, mint, RequestPriority::Background)
+            .await
+            .ok()
+            .map(|quote| quote.price)
+            .filter(|price| *price > 0.0)
+    }
+
+    async fn current_value(&self, rule: &AlertAutomationRule) -> Option<f64> {
+        match rule.trigger.metric {
+            AlertAutomationMetric::Price => self.quote_price(&rule.token_mint).await,
+            AlertAutomationMetric::CompositeScore => {
+                let risk = match self.app_handle.try_state::<SharedRiskAnalyzer>() {
+                    Some(analyzer) => analyzer
+                        .read()
+                        .await
+                        .get_latest_risk_score(&rule.token_mint)
+                        .await
+                        .ok()
+                        .flatten()
+                        .map(|score| score.score),
+                    None => None,
+                };
+                let sentiment = match self.app_handle.try_state::<SharedSentimentManager>() {
+                    Some(manager) => manager
+                        .read()
+                        .await
+                        .get_token_sentiment(&rule.token_mint)
+                        .map(|sentiment| sentiment.current_score as f64),
+                    None => None,
+                };
+                composite_token_score(risk, sentiment)
+            }
+        }
+    }
+
+    async fn evaluate_rule(&self, mut rule: AlertAutomationRule) -> Result<(), String> {
+        let Some(value) = self.current_value(&rule).await else {
+            return Ok(());
+        };
+        let now = Utc::now();
+        let cooling_down = rule
+            .last_triggered_at
+            .is_some_and(|at| now - at < ChronoDuration::minutes(rule.cooldown_minutes));
+        let crossed = trigger_crossed(&rule.trigger, rule.last_value, value);
+        rule.last_value = Some(value);
+
+        if crossed && !cooling_down {
+            rule.last_triggered_at = Some(now);
+            let execution = self.execute(&rule, value).await;
+            if execution.status == AlertAutomationStatus::Filled {
+                rule.paper_fills += 1;
+            }
+            self.db.read().await.record_execution(&execution).await?;
+            self.publish(&rule, execution).await;
+        }
+
+        rule.updated_at = now;
+        self.db.read().await.save_rule(&rule).await
+    }
+
+    async fn execute(&self, rule: &AlertAutomationRule, value: f64) -> AlertAutomationExecution {
+        let mut execution = AlertAutomationExecution {
+            id: Uuid::new_v4().to_string(),
+            rule_id: rule.id.clone(),
+            mode: rule.mode,
+            status: AlertAutomationStatus::Failed,
+            trigger_value: value,
+            threshold: rule.trigger.threshold,
+            amount_sol: rule.amount_sol,
+            token_price: None,
+            order_id: None,
+            message: String::new(),
+            created_at: Utc::now(),
+        };
+
+        let result = match self.check_spend(rule).await {
+            Ok(Some(reason)) => {
+                execution.status = AlertAutomationStatus::Blocked;
+                Err(reason)
+            }
+            Ok(None) => match rule.mode {
+                AlertAutomationMode::Paper => self.execute_paper(rule, &mut execution).await,
+                AlertAutomationMode::Live => self.execute_live(rule, &mut execution).await,
+            },
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(message) => execution.message = message,
+            Err(message) => {
+                if execution.status != AlertAutomationStatus::Blocked {
+                    execution.status = AlertAutomationStatus::Failed;
+                }
+                execution.message = message;
+            }
+        }
+        execution
+    }
+
+    async fn check_spend(&self, rule: &AlertAutomationRule) -> Result<Option<String>, String> {
+        let db = self.db.read().await;
+        let midnight = Utc::now()
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .map(|time| time.and_utc());
+        let spent_today = db.spent_since(&rule.id, rule.mode, midnight).await?;
+        let spent_total = db.spent_since(&rule.id, rule.mode, None).await?;
+        Ok(spend_limit_block(rule, spent_today, spent_total))
+    }
+
+    async fn execute_paper(
+        &self,
+        rule: &AlertAutomationRule,
+        execution: &mut AlertAutomationExecution,
+    ) -> Result<String, String> {
+        let token_price = self
+            .quote_price(&rule.token_mint)
+            .await
+            .ok_or_else(|| format!("No price for {}", rule.token_symbol))?;
+        let sol_price = self
+            .quote_price(SOL_MINT)
+            .await
+            .ok_or_else(|| "No SOL price".to_string())?;
+        execution.token_price = Some(token_price);
+
+        let result = execute_paper_trade(
+            ExecutePaperTradeRequest {
+                symbol: rule.token_symbol.clone(),
+                side: OrderSide::Buy,
+                order_type: OrderType::Market,
+                quantity: rule.amount_sol * sol_price / token_price,
+                price: token_price,
+                limit_price: None,
+                stop_price: None,
+                pool_liquidity_usd: None,
+            },
+            self.app_handle.clone(),
+        )
+        .await?;
+        execution.status = AlertAutomationStatus::Filled;
+        execution.order_id = Some(result.trade.id.clone());
+        Ok(format!(
+            "Paper bought {:.4} {} at {}",
+            result.trade.quantity, rule.token_symbol, result.trade.price
+        ))
+    }
+
+    /// Live trades go through the safety engine and then the order manager,
+    /// which applies risk-per-trade limits and execution presets.
+    async fn execute_live(
+        &self,
+        rule: &AlertAutomationRule,
+        execution: &mut AlertAutomationExecution,
+    ) -> Result<String, String> {
+        let safety = self
+            .app_handle
+            .try_state::<SharedSafetyEngine>()
+            .ok_or_else(|| "Safety engine unavailable; live automation is disabled".to_string())?;
+        let sol_price = self
+            .quote_price(SOL_MINT)
+            .await
+            .ok_or_else(|| "No SOL price".to_string())?;
+        execution.token_price = self.quote_price(&rule.token_mint).await;
+        let security_score = match self.app_handle.try_state::<SharedRiskAnalyzer>() {
+            Some(analyzer) => analyzer
+                .read()
+                .await
+                .get_latest_risk_score(&rule.token_mint)
+                .await
+                .ok()
+                .flatten()
+                .map(|score| 100.0 - score.score),
+            None => None,
+        };
+        // Jupiter reports impact as a fraction; the safety engine expects percent
+        let quote = jupiter_quote(buy_quote_input(rule))
+            .await
+            .map_err(|e| format!("Failed to quote the trade: {e}"))?;
+        let price_impact_percent = quote.quote.price_impact_pct * 100.0;
+
+        let check = safety
+            .write()
+            .await
+            .check_trade_safety(SafetyCheckRequest {
+                wallet_address: rule.wallet_address.clone(),
+                input_amount: rule.amount_sol,
+                input_mint: SOL_MINT.to_string(),
+                output_mint: rule.token_mint.clone(),
+                input_symbol: "SOL".to_string(),
+                output_symbol: rule.token_symbol.clone(),
+                amount_usd: rule.amount_sol * sol_price,
+                slippage_bps: rule.slippage_bps as u64,
+                price_impact_percent,
+                security_score,
+            })
+            .await?;
+        if !check.allowed {
+            execution.status = AlertAutomationStatus::Blocked;
+            let reasons: Vec<String> = check
+                .policy_result
+                .violations
+                .iter()
+                .map(|violation| violation.message.clone())
+                .collect();
+            return Err(if reasons.is_empty() {
+                "Blocked by the safety engine cooldown".to_string()
+            } else {
+                format!("Blocked by safety policy: {}", reasons.join("; "))
+            });
+        }
+
+        let order = self
+            .orders
+            .create_order(CreateOrderRequest {
+                order_type: OrderType::Market,
+                side: OrderSide::Buy,
+                input_mint: SOL_MINT.to_string(),
+                output_mint: rule.token_mint.clone(),
+                input_symbol: "SOL".to_string(),
+                output_symbol: rule.token_symbol.clone(),
+                amount: rule.amount_sol,
+                limit_price: None,
+                stop_price: None,
+                trailing_percent: None,
+                linked_order_id: None,
+                slippage_bps: rule.slippage_bps,
+                priority_fee_micro_lamports: 0,
+                wallet_address: rule.wallet_address.clone(),
+                stop_loss_price: None,
+            })
+            .await?;
+        safety.write().await.approve_trade(&rule.wallet_address);
+        execution.status = AlertAutomationStatus::Placed;
+        execution.order_id = Some(order.id.clone());
+        Ok(format!(
+            "Placed market buy of {} SOL of {}",
+            rule.amount_sol, rule.token_symbol
+        ))
+    }
+
+    async fn publish(&self, rule: &AlertAutomationRule, execution: AlertAutomationExecution) {
+        if matches!(
+            execution.status,
+            AlertAutomationStatus::Filled | AlertAutomationStatus::Placed
+        ) {
+            record_app_event(
+                &self.app_handle,
+                &format!("alert_automation_{}", rule.id),
+                AuditEvent::BotTradeExecuted {
+                    bot_type: format!("alert_automation_{}", rule.mode.as_str()),
+                    bot_id: rule.id.clone(),
+                    execution_id: execution.id.clone(),
+                    input_mint: SOL_MINT.to_string(),
+                    output_mint: rule.token_mint.clone(),
+                    input_amount: execution.amount_sol,
+                    output_amount: 0.0,
+                    timestamp: execution.created_at,
+                },
+            )
+            .await;
+        }
+
+        let _ = self.app_handle.emit_all(
+            "alert_automation_execution",
+            AlertAutomationEvent {
+                rule_id: rule.id.clone(),
+                name: rule.name.clone(),
+                token_symbol: rule.token_symbol.clone(),
+                execution,
+            },
+        );
+    }
+
+    pub async fn start_monitoring(manager: Arc<Self>) {
+        let mut ticker = interval(Duration::from_secs(AUTOMATION_EVAL_INTERVAL_SECS));
+        loop {
+            ticker.tick().await;
+            let rules = match manager.db.read().await.list_rules(true).await {
+                Ok(rules) => rules,
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to load automation rules");
+                    continue;
+                }
+            };
+            for rule in rules {
+                let id = rule.id.clone();
+                if let Err(err) = manager.evaluate_rule(rule).await {
+                    tracing::warn!(error = %err, rule = %id, "failed to evaluate automation rule");
+                }
+            }
+        }
+    }
+}
+
+pub struct AlertAutomationState {
+    pub db: SharedAlertAutomationDatabase,
+    pub manager: Arc<AlertAutomationManager>,
+}
+
+static ALERT_AUTOMATION_STATE: OnceCell<AlertAutomationState> = OnceCell::const_new();
+
+pub async fn init_alert_automation(
+    app_handle: &AppHandle,
+    orders: SharedOrderManager,
+) -> Result<(), String> {
+    if ALERT_AUTOMATION_STATE.get().is_some() {
+        return Ok(());
+    }
+
+    let app_dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Failed to resolve app data directory".to_string())?;
+
+    let mut db_path = PathBuf::from(app_dir);
+    db_path.push("orders.db");
+
+    let db = AlertAutomationDatabase::new(db_path)
+        .await
+        .map_err(|e| format!("Failed to initialize alert automation database: {e}"))?;
+
+    let shared_db = Arc::new(RwLock::new(db));
+    let manager = Arc::new(AlertAutomationManager::new(
+        shared_db.clone(),
+        orders,
+        app_handle.clone(),
+    ));
+
+    ALERT_AUTOMATION_STATE
+        .set(AlertAutomationState {
+            db: shared_db,
+            manager: manager.clone(),
+        })
+        .map_err(|_| "Alert automation state already initialized".to_string())?;
+
+    tauri::async_runtime::spawn(AlertAutomationManager::start_monitoring(manager));
+
+    Ok(())
+}
+
+fn require_state<'a>() -> Result<&'a AlertAutomationState, String> {
+    ALERT_AUTOMATION_STATE
+        .get()
+        .ok_or_else(|| "Alert automation not initialized".to_string())
+}
+
+#[tauri::command]
+pub async fn create_alert_automation_rule(
+    request: CreateAlertAutomationRequest,
+) -> Result<AlertAutomationRule, String> {
+    let state = require_state()?;
+    state.manager.create_rule(request).await
+}
+
+#[tauri::command]
+pub async fn list_alert_automation_rules() -> Result<Vec<AlertAutomationRule>, String> {
+    let state = require_state()?;
+    let rules = state.db.read().await.list_rules(false).await;
+    rules
+}
+
+#[tauri::command]
+pub async fn set_alert_automation_enabled(
+    id: String,
+    enabled: bool,
+) -> Result<AlertAutomationRule, String> {
+    let state = require_state()?;
+    state.manager.set_enabled(&id, enabled).await
+}
+
+#[tauri::command]
+pub async fn set_alert_automation_mode(
+    id: String,
+    mode: AlertAutomationMode,
+    confirmed: bool,
+) -> Result<AlertAutomationRule, String> {
+    let state = require_state()?;
+    state.manager.set_mode(&id, mode, confirmed).await
+}
+
+#[tauri::command]
+pub async fn delete_alert_automation_rule(id: String) -> Result<(), String> {
+    let state = require_state()?;
+    let result = state.db.read().await.delete_rule(&id).await;
+    result
+}
+
+#[tauri::command]
+pub async fn get_alert_automation_log(
+    rule_id: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<AlertAutomationExecution>, String> {
+    let state = require_state()?;
+    let log = state
+        .db
+        .read()
+        .await
+        .list_executions(rule_id.as_deref(), limit.unwrap_or(100).clamp(1, 1_000))
+        .await;
+    log
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule() -> AlertAutomationRule {
+        let now = Utc::now();
+        AlertAutomationRule {
+            id: "rule".into(),
+            name: "Score breakout".into(),
+            wallet_address: "wallet".into(),
+            token_mint: "mint".into(),
+            token_symbol: "TKN".into(),
+            trigger: AlertAutomationTrigger {
+                metric: AlertAutomationMetric::CompositeScore,
+                direction: CrossDirection::Above,
+                threshold: 70.0,
+            },
+            amount_sol: 0.5,
+            max_spend_per_day_sol: 1.0,
+            max_spend_total_sol: 3.0,
+            slippage_bps: DEFAULT_SLIPPAGE_BPS,
+            cooldown_minutes: DEFAULT_COOLDOWN_MINUTES,
+            mode: AlertAutomationMode::Paper,
+            enabled: true,
+            paper_fills: 0,
+            trial_started_at: now,
+            last_value: None,
+            last_triggered_at: None,
+            created_at: now,
+            updated_at: now,
+            promoted_at: None,
+        }
+    }
+
+    #[test]
+    fn triggers_fire_only_on_crossings() {
+        let above = rule().trigger;
+        assert!(!trigger_crossed(&above, None, 90.0));
+        assert!(trigger_crossed(&above, Some(65.0), 70.0));
+        assert!(!trigger_crossed(&above, Some(72.0), 80.0));
+
+        let below = AlertAutomationTrigger {
+            direction: CrossDirection::Below,
+            ..above
+        };
+        assert!(trigger_crossed(&below, Some(75.0), 69.0));
+        assert!(!trigger_crossed(&below, Some(65.0), 60.0));
+
+        assert_eq!(composite_token_score(Some(20.0), Some(0.0)), Some(68.0));
+        assert_eq!(composite_token_score(None, Some(1.0)), Some(100.0));
+        assert_eq!(composite_token_score(None, None), None);
+    }
+
+    #[test]
+    fn spend_limits_cap_daily_and_total() {
+        let rule = rule();
+        assert!(spend_limit_block(&rule, 0.5, 0.5).is_none());
+        assert!(spend_limit_block(&rule, 1.0, 1.0)
+            .unwrap()
+            .starts_with("Daily"));
+        assert!(spend_limit_block(&rule, 0.0, 2.75)
+            .unwrap()
+            .starts_with("Total"));
+    }
+
+    #[test]
+    fn live_mode_requires_completed_paper_trial() {
+        let mut rule = rule();
+        let now = rule.trial_started_at;
+        assert_eq!(paper_trial_blockers(&rule, now).len(), 2);
+
+        rule.paper_fills = PAPER_TRIAL_MIN_FILLS;
+        assert_eq!(paper_trial_blockers(&rule, now).len(), 1);
+        let later = now + ChronoDuration::hours(PAPER_TRIAL_MIN_HOURS);
+        assert!(paper_trial_blockers(&rule, later).is_empty());
+    }
+
+    #[test]
+    fn live_buys_are_quoted_in_lamports_at_the_rule_slippage() {
+        let input = buy_quote_input(&rule());
+        assert_eq!(input.input_mint, SOL_MINT);
+        assert_eq!(input.output_mint, "mint");
+        assert_eq!(input.amount, 500_000_000);
+        assert_eq!(input.slippage_bps, Some(DEFAULT_SLIPPAGE_BPS as u16));
+    }
+}
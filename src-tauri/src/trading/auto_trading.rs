@@ -3,9 +3,11 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use tauri::Manager;
 use uuid::Uuid;
 
 const DEFAULT_STARTING_CAPITAL: f64 = 100_000.0;
+const LIVE_ORDER_EVENT: &str = "auto_trading_live_order";
 
 fn is_config_empty(config: &HashMap<String, Value>) -> bool {
     config.is_empty()
@@ -44,6 +46,16 @@ pub struct PositionSizingConfig {
     pub target_volatility: Option<f64>,
 }
 
+/// Whether a strategy's fills are simulated or routed to real orders. New
+/// strategies start on paper and only go live through the promotion pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TradingMode {
+    #[default]
+    Paper,
+    Live,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradingStrategyInput {
     pub name: String,
@@ -71,6 +83,8 @@ pub struct TradingStrategy {
     pub allowed_symbols: Vec<String>,
     #[serde(default, skip_serializing_if = "is_param_empty")]
     pub optimized_parameters: HashMap<String, f64>,
+    #[serde(default)]
+    pub mode: TradingMode,
     #[serde(with = "chrono::serde::ts_milliseconds")]
     pub created_at: DateTime<Utc>,
     #[serde(with = "chrono::serde::ts_milliseconds")]
@@ -110,6 +124,12 @@ pub struct StrategyExecution {
     pub total_pnl_percent: f64,
     pub win_rate: f64,
     pub current_drawdown: f64,
+    #[serde(default)]
+    pub max_drawdown: f64,
+    #[serde(default)]
+    pub winning_trades: u32,
+    #[serde(default)]
+    pub peak_pnl: f64,
     pub daily_pnl: f64,
     pub last_error: Option<String>,
 }
@@ -135,6 +155,36 @@ pub struct Position {
     pub opened_at: DateTime<Utc>,
 }
 
+/// What the engine did with a price update or a set of signals.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StrategyAction {
+    /// Simulated entry on the strategy's paper book.
+    PaperEntry {
+        symbol: String,
+        quantity: f64,
+        price: f64,
+    },
+    /// Simulated exit; its P&L is what the promotion gates are measured on.
+    PaperExit {
+        symbol: String,
+        quantity: f64,
+        price: f64,
+        pnl: f64,
+    },
+    /// Live strategies don't fill themselves: the order is handed to the
+    /// trading flow, where the wallet signs it.
+    LiveOrder {
+        strategy_id: String,
+        symbol: String,
+        side: String,
+        quantity: f64,
+        price: f64,
+    },
+    /// Signals didn't agree or a risk control held the entry back.
+    Skipped { reason: String },
+}
+
 #[derive(Debug)]
 pub struct AutoTradingEngine {
     strategies: HashMap<String, TradingStrategy>,
@@ -171,6 +221,7 @@ impl AutoTradingEngine {
             risk_controls: input.risk_controls,
             allowed_symbols: input.allowed_symbols,
             optimized_parameters: HashMap::new(),
+            mode: TradingMode::Paper,
             created_at: now,
             updated_at: now,
         };
@@ -262,6 +313,9 @@ impl AutoTradingEngine {
             total_pnl_percent: 0.0,
             win_rate: 0.0,
             current_drawdown: 0.0,
+            max_drawdown: 0.0,
+            winning_trades: 0,
+            peak_pnl: 0.0,
             daily_pnl: 0.0,
             last_error: None,
         };
@@ -292,6 +346,195 @@ impl AutoTradingEngine {
         }
     }
 
+    /// Pauses every running strategy, returning the names of those paused.
+    pub fn pause_running(&mut self) -> Vec<String> {
+        self.executions
+            .values_mut()
+            .filter(|execution| execution.status == ExecutionStatus::Running)
+            .map(|execution| {
+                execution.status = ExecutionStatus::Paused;
+                execution.strategy_name.clone()
+            })
+            .collect()
+    }
+
+    /// Records a closed paper trade for the strategy's current run. Drawdown is
+    /// measured from the run's peak equity as a percentage of that peak.
+    fn record_trade(
+        &mut self,
+        strategy_id: &str,
+        pnl: f64,
+    ) -> Result<StrategyExecution, String> {
+        let starting_capital = self.starting_capital;
+        let execution = self
+            .executions
+            .get_mut(strategy_id)
+            .ok_or_else(|| format!("No execution found for strategy {}", strategy_id))?;
+
+        execution.trades_executed += 1;
+        if pnl > 0.0 {
+            execution.winning_trades += 1;
+        }
+        execution.total_pnl += pnl;
+        execution.daily_pnl += pnl;
+        execution.win_rate =
+            execution.winning_trades as f64 / execution.trades_executed as f64 * 100.0;
+        execution.peak_pnl = execution.peak_pnl.max(execution.total_pnl);
+
+        if starting_capital > 0.0 {
+            execution.total_pnl_percent = execution.total_pnl / starting_capital * 100.0;
+            let peak_equity = starting_capital + execution.peak_pnl;
+            execution.current_drawdown =
+                (execution.peak_pnl - execution.total_pnl) / peak_equity * 100.0;
+            execution.max_drawdown = execution.max_drawdown.max(execution.current_drawdown);
+        }
+        Ok(execution.clone())
+    }
+
+    /// Switches a strategy between paper and live. Only the promotion pipeline
+    /// should move a strategy to live; the update command cannot.
+    pub fn set_mode(
+        &mut self,
+        strategy_id: &str,
+        mode: TradingMode,
+    ) -> Result<TradingStrategy, String> {
+        let strategy = self
+            .strategies
+            .get_mut(strategy_id)
+            .ok_or_else(|| format!("Strategy {} not found", strategy_id))?;
+
+        strategy.mode = mode;
+        strategy.updated_at = Utc::now();
+        Ok(strategy.clone())
+    }
+
+    fn running_strategy(&self, strategy_id: &str) -> Result<&TradingStrategy, String> {
+        if self.kill_switch_active {
+            return Err("Kill switch is active".to_string());
+        }
+        let strategy = self
+            .strategies
+            .get(strategy_id)
+            .ok_or_else(|| format!("Strategy {} not found", strategy_id))?;
+        match self.executions.get(strategy_id) {
+            Some(execution) if execution.status == ExecutionStatus::Running => Ok(strategy),
+            _ => Err(format!("Strategy {} is not running", strategy.name)),
+        }
+    }
+
+    /// Marks the strategy's paper positions in `symbol` to `price` and closes
+    /// those that hit their stop or target. Closed trades feed the track record.
+    pub fn on_price(
+        &mut self,
+        strategy_id: &str,
+        symbol: &str,
+        price: f64,
+    ) -> Result<Vec<StrategyAction>, String> {
+        let trailing_percent = self
+            .running_strategy(strategy_id)?
+            .risk_controls
+            .trailing_stop_percent;
+        let positions = self.positions.entry(strategy_id.to_string()).or_default();
+
+        let mut closed = Vec::new();
+        positions.retain_mut(|position| {
+            if position.symbol != symbol {
+                return true;
+            }
+            position.current_price = price;
+            if let Some(percent) = trailing_percent {
+                let trail = price * (1.0 - percent / 100.0);
+                position.trailing_stop =
+                    Some(position.trailing_stop.map_or(trail, |stop| stop.max(trail)));
+            }
+            let stopped = [position.stop_loss, position.trailing_stop]
+                .into_iter()
+                .flatten()
+                .any(|stop| price <= stop);
+            let target_hit = position.take_profit.is_some_and(|target| price >= target);
+            if stopped || target_hit {
+                let pnl = (price - position.entry_price) * position.quantity;
+                closed.push((position.quantity, pnl));
+                return false;
+            }
+            true
+        });
+
+        let mut actions = Vec::new();
+        for (quantity, pnl) in closed {
+            self.record_trade(strategy_id, pnl)?;
+            self.current_capital += pnl;
+            actions.push(StrategyAction::PaperExit {
+                symbol: symbol.to_string(),
+                quantity,
+                price,
+                pnl,
+            });
+        }
+        Ok(actions)
+    }
+
+    /// Acts on a set of signals at `price`. Paper strategies fill on their own
+    /// book; live strategies return an order for the trading flow to execute.
+    pub fn on_signals(
+        &mut self,
+        strategy_id: &str,
+        symbol: &str,
+        signals: &HashMap<String, f64>,
+        price: f64,
+    ) -> Result<StrategyAction, String> {
+        if !price.is_finite() || price <= 0.0 {
+            return Err(format!("No usable price for {}", symbol));
+        }
+        let strategy = self.running_strategy(strategy_id)?.clone();
+        if !strategy.allowed_symbols.is_empty()
+            && !strategy.allowed_symbols.iter().any(|allowed| allowed == symbol)
+        {
+            return Err(format!("{} is not traded by {}", symbol, strategy.name));
+        }
+        if !self.evaluate_signals(strategy_id, signals) {
+            return Ok(StrategyAction::Skipped {
+                reason: "Signals did not meet the strategy's entry logic".to_string(),
+            });
+        }
+
+        let quantity = self.calculate_position_size(strategy_id, price)?;
+        if let Err(reason) = self.check_risk_controls(strategy_id, quantity * price) {
+            return Ok(StrategyAction::Skipped { reason });
+        }
+
+        match strategy.mode {
+            TradingMode::Paper => {
+                let controls = &strategy.risk_controls;
+                self.positions
+                    .entry(strategy_id.to_string())
+                    .or_default()
+                    .push(Position {
+                        symbol: symbol.to_string(),
+                        quantity,
+                        entry_price: price,
+                        current_price: price,
+                        stop_loss: Some(price * (1.0 - controls.stop_loss_percent / 100.0)),
+                        take_profit: Some(price * (1.0 + controls.take_profit_percent / 100.0)),
+                        trailing_stop: None,
+                        opened_at: Utc::now(),
+                    });
+                Ok(StrategyAction::PaperEntry {
+                    symbol: symbol.to_string(),
+                    quantity,
+                    price,
+                })
+            }
+            TradingMode::Live => Ok(StrategyAction::LiveOrder {
+                strategy_id: strategy_id.to_string(),
+                symbol: symbol.to_string(),
+                side: "buy".to_string(),
+                quantity,
+                price,
+            }),
+        }
+    }
+
     pub fn activate_kill_switch(&mut self) {
         self.kill_switch_active = true;
         for execution in self.executions.values_mut() {
@@ -597,6 +840,39 @@ pub async fn auto_trading_get_executions(
     Ok(engine.get_all_executions())
 }
 
+/// Runs a strategy against the latest price for `symbol`: paper positions are
+/// marked and closed first, then the signals are acted on. Prices come from
+/// the price engine, never from the caller.
+#[tauri::command]
+pub async fn auto_trading_process_signals(
+    strategy_id: String,
+    symbol: String,
+    signals: HashMap<String, f64>,
+    app: tauri::AppHandle,
+    engine: tauri::State<'_, SharedAutoTradingEngine>,
+) -> Result<Vec<StrategyAction>, String> {
+    let price = crate::core::price_engine::get_price_engine()
+        .get_cached_price(&symbol)
+        .map(|cached| cached.price)
+        .ok_or_else(|| format!("No price available for {}", symbol))?;
+
+    let actions = {
+        let mut engine = engine.lock().map_err(|e| e.to_string())?;
+        let mut actions = engine.on_price(&strategy_id, &symbol, price)?;
+        actions.push(engine.on_signals(&strategy_id, &symbol, &signals, price)?);
+        actions
+    };
+
+    for action in &actions {
+        if let StrategyAction::LiveOrder { .. } = action {
+            if let Err(err) = app.emit_all(LIVE_ORDER_EVENT, action) {
+                tracing::warn!(error = %err, "failed to emit live strategy order");
+            }
+        }
+    }
+    Ok(actions)
+}
+
 #[tauri::command]
 pub async fn auto_trading_apply_parameters(
     strategy_id: String,
@@ -606,3 +882,73 @@ pub async fn auto_trading_apply_parameters(
     let mut engine = engine.lock().map_err(|e| e.to_string())?;
     engine.apply_parameters(&strategy_id, parameters)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn running_strategy(engine: &mut AutoTradingEngine) -> String {
+        let strategy = engine.add_strategy(TradingStrategyInput {
+            name: "Momentum".to_string(),
+            description: String::new(),
+            enabled: true,
+            signal_sources: vec![SignalSource {
+                source_type: "indicator".to_string(),
+                id: "rsi".to_string(),
+                weight: 1.0,
+                enabled: true,
+                config: HashMap::new(),
+            }],
+            combination_logic: "any".to_string(),
+            weight_threshold: None,
+            position_sizing: PositionSizingConfig {
+                method: "fixed".to_string(),
+                fixed_percent: Some(10.0),
+                kelly_fraction: None,
+                target_volatility: None,
+            },
+            risk_controls: RiskControls {
+                max_position_size: 20.0,
+                max_daily_loss: 5.0,
+                max_drawdown: 20.0,
+                max_open_positions: 3,
+                stop_loss_percent: 5.0,
+                take_profit_percent: 10.0,
+                trailing_stop_percent: None,
+            },
+            allowed_symbols: Vec::new(),
+        });
+        engine.start_strategy(&strategy.id).unwrap();
+        strategy.id
+    }
+
+    #[test]
+    fn paper_fills_build_the_track_record() {
+        let mut engine = AutoTradingEngine::new(100_000.0);
+        let id = running_strategy(&mut engine);
+        let signals = HashMap::from([("rsi".to_string(), 1.0)]);
+
+        let entry = engine.on_signals(&id, "SOL", &signals, 100.0).unwrap();
+        assert!(matches!(entry, StrategyAction::PaperEntry { quantity, .. } if quantity == 100.0));
+        assert!(engine.on_price(&id, "SOL", 105.0).unwrap().is_empty());
+
+        let exits = engine.on_price(&id, "SOL", 111.0).unwrap();
+        assert!(matches!(exits[..], [StrategyAction::PaperExit { pnl, .. }] if pnl == 1_100.0));
+        let execution = engine.get_execution(&id).unwrap();
+        assert_eq!(execution.trades_executed, 1);
+        assert_eq!(execution.total_pnl, 1_100.0);
+    }
+
+    #[test]
+    fn live_strategies_hand_orders_off_instead_of_filling() {
+        let mut engine = AutoTradingEngine::new(100_000.0);
+        let id = running_strategy(&mut engine);
+        engine.set_mode(&id, TradingMode::Live).unwrap();
+        let signals = HashMap::from([("rsi".to_string(), 1.0)]);
+
+        let action = engine.on_signals(&id, "SOL", &signals, 100.0).unwrap();
+        assert!(matches!(action, StrategyAction::LiveOrder { .. }));
+        assert!(engine.on_price(&id, "SOL", 200.0).unwrap().is_empty());
+        assert_eq!(engine.get_execution(&id).unwrap().trades_executed, 0);
+    }
+}
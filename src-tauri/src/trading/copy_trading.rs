@@ -9,6 +9,13 @@ use tokio::sync::{OnceCell, RwLock};
 use tokio::time::{interval, Duration};
 use uuid::Uuid;
 
+use crate::api_analytics::RequestPriority;
+use crate::data::event_store::{record_app_event, Event as AuditEvent};
+
+use super::execution_presets::{
+    record_execution_preset, resolve_execution, ExecutionPresetSource,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct CopyTradeConfig {
     pub id: String,
@@ -80,6 +87,39 @@ pub struct CopyTradePerformance {
     pub total_pnl: f64,
     pub win_rate: f64,
     pub avg_trade_size: f64,
+    /// Copy delta across every config following this config's source wallet.
+    pub copy_delta: Option<CopyDeltaSummary>,
+}
+
+/// Leader execution next to my fill for one copied trade.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CopyTradeLatency {
+    pub execution_id: String,
+    pub config_id: String,
+    pub source_wallet: String,
+    pub action: String,
+    pub leader_price: Option<f64>,
+    #[sqlx(try_from = "String")]
+    pub leader_time: DateTime<Utc>,
+    pub fill_price: Option<f64>,
+    #[sqlx(try_from = "String")]
+    pub fill_time: DateTime<Utc>,
+    pub delay_ms: i64,
+    /// How much worse (positive) my fill was than the leader's, in bps.
+    pub price_delta_bps: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopyDeltaSummary {
+    pub source_wallet: String,
+    pub samples: i64,
+    pub priced_samples: i64,
+    pub avg_delay_ms: f64,
+    pub avg_price_delta_bps: Option<f64>,
+    pub worst_price_delta_bps: Option<f64>,
+    /// False when copies give up enough to the leader's fill that the wallet is
+    /// a signal to front-run rather than one to follow.
+    pub copyable: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -93,6 +133,9 @@ pub struct WalletActivity {
     pub input_symbol: String,
     pub output_symbol: String,
     pub amount: f64,
+    /// Leader's execution price, quote per output token.
+    #[serde(default)]
+    pub price: Option<f64>,
     pub performance_pct: Option<f64>,
     pub pnl: Option<f64>,
 }
@@ -194,6 +237,35 @@ impl CopyTradeDatabase {
         .execute(&self.pool)
         .await?;
 
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS copy_trade_latency (
+                execution_id TEXT PRIMARY KEY,
+                config_id TEXT NOT NULL,
+                source_wallet TEXT NOT NULL,
+                action TEXT NOT NULL,
+                leader_price REAL,
+                leader_time TEXT NOT NULL,
+                fill_price REAL,
+                fill_time TEXT NOT NULL,
+                delay_ms INTEGER NOT NULL,
+                price_delta_bps REAL,
+                FOREIGN KEY (execution_id) REFERENCES copy_trade_executions(id) ON DELETE CASCADE
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_copy_trade_latency_source
+                ON copy_trade_latency(source_wallet)
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
         Ok(())
     }
 
@@ -273,6 +345,39 @@ impl CopyTradeDatabase {
         Ok(())
     }
 
+    pub async fn update_definition(&self, config: &CopyTradeConfig) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE copy_trade_configs SET
+                name = ?1, source_wallet = ?2, allocation_percentage = ?3, multiplier = ?4,
+                min_trade_amount = ?5, max_trade_amount = ?6, delay_seconds = ?7,
+                token_whitelist = ?8, token_blacklist = ?9, stop_loss_percentage = ?10,
+                take_profit_percentage = ?11, max_daily_trades = ?12, max_total_loss = ?13,
+                is_active = ?14, updated_at = ?15
+            WHERE id = ?16
+            "#,
+        )
+        .bind(&config.name)
+        .bind(&config.source_wallet)
+        .bind(config.allocation_percentage)
+        .bind(config.multiplier)
+        .bind(config.min_trade_amount)
+        .bind(config.max_trade_amount)
+        .bind(config.delay_seconds)
+        .bind(&config.token_whitelist)
+        .bind(&config.token_blacklist)
+        .bind(config.stop_loss_percentage)
+        .bind(config.take_profit_percentage)
+        .bind(config.max_daily_trades)
+        .bind(config.max_total_loss)
+        .bind(if config.is_active { 1 } else { 0 })
+        .bind(config.updated_at.to_rfc3339())
+        .bind(&config.id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
     pub async fn delete_config(&self, id: &str) -> Result<(), sqlx::Error> {
         sqlx::query("DELETE FROM copy_trade_configs WHERE id = ?1")
             .bind(id)
@@ -318,6 +423,42 @@ impl CopyTradeDatabase {
         Ok(())
     }
 
+    pub async fn record_latency(&self, latency: &CopyTradeLatency) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO copy_trade_latency (
+                execution_id, config_id, source_wallet, action, leader_price, leader_time,
+                fill_price, fill_time, delay_ms, price_delta_bps
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            "#,
+        )
+        .bind(&latency.execution_id)
+        .bind(&latency.config_id)
+        .bind(&latency.source_wallet)
+        .bind(&latency.action)
+        .bind(latency.leader_price)
+        .bind(latency.leader_time.to_rfc3339())
+        .bind(latency.fill_price)
+        .bind(latency.fill_time.to_rfc3339())
+        .bind(latency.delay_ms)
+        .bind(latency.price_delta_bps)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn latency_for_wallet(
+        &self,
+        source_wallet: &str,
+    ) -> Result<Vec<CopyTradeLatency>, sqlx::Error> {
+        sqlx::query_as::<_, CopyTradeLatency>(
+            "SELECT * FROM copy_trade_latency WHERE source_wallet = ?1 ORDER BY fill_time DESC",
+        )
+        .bind(source_wallet)
+        .fetch_all(&self.pool)
+        .await
+    }
+
     pub async fn get_executions(
         &self,
         config_id: &str,
@@ -451,6 +592,53 @@ impl CopyTradeManager {
         Ok(config)
     }
 
+    /// Replaces the user-editable settings of an existing config; its history and
+    /// wallet stay as they are.
+    pub async fn update_copy_trade(
+        &self,
+        id: &str,
+        request: CreateCopyTradeRequest,
+        is_active: bool,
+    ) -> Result<CopyTradeConfig, String> {
+        if !(0.0..=100.0).contains(&request.allocation_percentage) {
+            return Err("Allocation percentage must be between 0 and 100".into());
+        }
+        if request.multiplier <= 0.0 {
+            return Err("Multiplier must be greater than zero".into());
+        }
+
+        let mut config = self.get_copy_trade(id).await?;
+        config.name = request.name;
+        config.source_wallet = request.source_wallet.clone();
+        config.allocation_percentage = request.allocation_percentage;
+        config.multiplier = request.multiplier;
+        config.min_trade_amount = request.min_trade_amount;
+        config.max_trade_amount = request.max_trade_amount;
+        config.delay_seconds = request.delay_seconds;
+        config.token_whitelist = request.token_whitelist.map(|list| list.join(","));
+        config.token_blacklist = request.token_blacklist.map(|list| list.join(","));
+        config.stop_loss_percentage = request.stop_loss_percentage;
+        config.take_profit_percentage = request.take_profit_percentage;
+        config.max_daily_trades = request.max_daily_trades;
+        config.max_total_loss = request.max_total_loss;
+        config.is_active = is_active;
+        config.updated_at = Utc::now();
+
+        self.db
+            .write()
+            .await
+            .update_definition(&config)
+            .await
+            .map_err(|e| format!("Failed to update copy trade config: {e}"))?;
+
+        self.monitored_wallets
+            .write()
+            .await
+            .insert(request.source_wallet);
+
+        Ok(config)
+    }
+
     pub async fn get_copy_trade(&self, id: &str) -> Result<CopyTradeConfig, String> {
         self.db
             .read()
@@ -511,13 +699,26 @@ impl CopyTradeManager {
     }
 
     pub async fn get_performance(&self, id: &str) -> Result<CopyTradePerformance, String> {
-        let stats = self
-            .db
-            .read()
-            .await
+        let db = self.db.read().await;
+        let stats = db
             .stats(id)
             .await
             .map_err(|e| format!("Failed to compute performance: {e}"))?;
+        let copy_delta = match db
+            .get_config(id)
+            .await
+            .map_err(|e| format!("Failed to load copy trade config: {e}"))?
+        {
+            Some(config) => {
+                let latency = db
+                    .latency_for_wallet(&config.source_wallet)
+                    .await
+                    .map_err(|e| format!("Failed to load copy latency: {e}"))?;
+                summarize_copy_deltas(&config.source_wallet, &latency)
+            }
+            None => None,
+        };
+        drop(db);
 
         let failed = stats.total_trades - stats.successful;
         let win_rate = if stats.total_trades > 0 {
@@ -539,6 +740,7 @@ impl CopyTradeManager {
             total_pnl: stats.total_pnl,
             win_rate,
             avg_trade_size: avg_trade,
+            copy_delta,
         })
     }
 
@@ -643,6 +845,18 @@ impl CopyTradeManager {
             * (config.allocation_percentage / 100.0)
             * config.multiplier;
 
+        // Copy configs carry no execution settings, so presets fill in over the globals
+        let resolved = resolve_execution(
+            &self.app_handle,
+            &[activity.output_mint.as_str(), activity.input_mint.as_str()],
+            0,
+            0,
+            None,
+        )
+        .await;
+
+        let fill_price = self.quote_fill_price(&activity.output_mint).await;
+
         let execution = CopyTradeExecution {
             id: Uuid::new_v4().to_string(),
             config_id: config.id.clone(),
@@ -671,6 +885,45 @@ impl CopyTradeManager {
             .create_execution(&execution)
             .await
             .map_err(|e| format!("Failed to record execution: {e}"))?;
+        let latency = CopyTradeLatency {
+            execution_id: execution.id.clone(),
+            config_id: config.id.clone(),
+            source_wallet: activity.wallet.clone(),
+            action: activity.action.clone(),
+            leader_price: activity.price,
+            leader_time: activity.timestamp,
+            fill_price,
+            fill_time: execution.executed_at,
+            delay_ms: (execution.executed_at - activity.timestamp)
+                .num_milliseconds()
+                .max(0),
+            price_delta_bps: copy_price_delta_bps(&activity.action, activity.price, fill_price),
+        };
+        if let Err(err) = self.db.write().await.record_latency(&latency).await {
+            eprintln!("Failed to record copy trade latency: {err}");
+        }
+        record_execution_preset(
+            &self.app_handle,
+            &execution.id,
+            ExecutionPresetSource::CopyTrade,
+            &resolved,
+        )
+        .await;
+        record_app_event(
+            &self.app_handle,
+            &format!("copy_trade_{}", config.id),
+            AuditEvent::BotTradeExecuted {
+                bot_type: "copy_trade".to_string(),
+                bot_id: config.id.clone(),
+                execution_id: execution.id.clone(),
+                input_mint: execution.input_mint.clone(),
+                output_mint: execution.output_mint.clone(),
+                input_amount: execution.copied_amount,
+                output_amount: execution.copied_amount * execution.price,
+                timestamp: execution.executed_at,
+            },
+        )
+        .await;
 
         self.emit_execution_event(config, &execution);
 
@@ -682,6 +935,13 @@ impl CopyTradeManager {
         config: &CopyTradeConfig,
         activity: &WalletActivity,
     ) -> Result<TradeDecision, String> {
+        if let Some(blackout) = crate::market::automation_blackout(&self.app_handle).await {
+            return Ok(TradeDecision::Skip(format!(
+                "Paused around macro event: {}",
+                blackout.event.title
+            )));
+        }
+
         let allocation_amount =
             activity.amount * (config.allocation_percentage / 100.0) * config.multiplier;
 
@@ -761,6 +1021,16 @@ impl CopyTradeManager {
             .map_err(|e| format!("Failed to record execution: {e}"))
     }
 
+    /// Market price of `mint` at the time of my fill; copies are simulated, so
+    /// this stands in for the fill price.
+    async fn quote_fill_price(&self, mint: &str) -> Option<f64> {
+        crate::market::quote_coin_price(&self.app_handle, mint, RequestPriority::Background)
+            .await
+            .ok()
+            .map(|quote| quote.price)
+            .filter(|price| *price > 0.0)
+    }
+
     fn emit_execution_event(&self, config: &CopyTradeConfig, execution: &CopyTradeExecution) {
         let event = CopyTradeEvent {
             config_id: config.id.clone(),
@@ -817,6 +1087,53 @@ enum TradeDecision {
     Stop(String),
 }
 
+/// Average copy delta above which a leader counts as front-run-only.
+const COPYABLE_MAX_DELTA_BPS: f64 = 150.0;
+
+/// How much worse (positive) my fill was than the leader's, in bps: paying more
+/// on buys, receiving less on sells.
+fn copy_price_delta_bps(
+    action: &str,
+    leader_price: Option<f64>,
+    fill_price: Option<f64>,
+) -> Option<f64> {
+    let (leader, fill) = (leader_price?, fill_price?);
+    if leader <= 0.0 {
+        return None;
+    }
+    let delta = (fill - leader) / leader * 10_000.0;
+    Some(if action.eq_ignore_ascii_case("sell") {
+        -delta
+    } else {
+        delta
+    })
+}
+
+fn summarize_copy_deltas(
+    source_wallet: &str,
+    latency: &[CopyTradeLatency],
+) -> Option<CopyDeltaSummary> {
+    if latency.is_empty() {
+        return None;
+    }
+    let deltas: Vec<f64> = latency.iter().filter_map(|l| l.price_delta_bps).collect();
+    let avg_delay_ms =
+        latency.iter().map(|l| l.delay_ms as f64).sum::<f64>() / latency.len() as f64;
+    let avg_price_delta_bps =
+        (!deltas.is_empty()).then(|| deltas.iter().sum::<f64>() / deltas.len() as f64);
+    let worst_price_delta_bps = deltas.iter().copied().reduce(f64::max);
+
+    Some(CopyDeltaSummary {
+        source_wallet: source_wallet.to_string(),
+        samples: latency.len() as i64,
+        priced_samples: deltas.len() as i64,
+        avg_delay_ms,
+        avg_price_delta_bps,
+        worst_price_delta_bps,
+        copyable: avg_price_delta_bps.map_or(true, |delta| delta <= COPYABLE_MAX_DELTA_BPS),
+    })
+}
+
 fn evaluate_trade_decision(
     config: &CopyTradeConfig,
     activity: &WalletActivity,
@@ -937,6 +1254,14 @@ fn require_state<'a>() -> Result<&'a CopyTradingState, String> {
         .ok_or_else(|| "Copy trading module not initialized".to_string())
 }
 
+pub fn copy_trade_database() -> Result<SharedCopyTradeDatabase, String> {
+    require_state().map(|state| state.db.clone())
+}
+
+pub fn copy_trade_manager() -> Result<Arc<CopyTradeManager>, String> {
+    require_state().map(|state| state.manager.clone())
+}
+
 #[tauri::command]
 pub async fn copy_trading_init(handle: AppHandle) -> Result<(), String> {
     init_copy_trading(&handle).await
@@ -944,8 +1269,10 @@ pub async fn copy_trading_init(handle: AppHandle) -> Result<(), String> {
 
 #[tauri::command]
 pub async fn copy_trading_create(
-    request: CreateCopyTradeRequest,
+    mut request: CreateCopyTradeRequest,
+    handle: AppHandle,
 ) -> Result<CopyTradeConfig, String> {
+    request.source_wallet = crate::wallet::sns::resolve_address_input(&handle, &request.source_wallet).await?;
     let state = require_state()?;
     state.manager.create_copy_trade(request).await
 }
@@ -1042,6 +1369,7 @@ mod tests {
             input_symbol: "IN".into(),
             output_symbol: "OUT".into(),
             amount: 100.0,
+            price: Some(2.0),
             performance_pct: performance,
             pnl: Some(-60.0),
         }
@@ -1069,4 +1397,45 @@ mod tests {
         let decision = evaluate_trade_decision(&config, &activity, allocation, None, None);
         assert!(matches!(decision, TradeDecision::Skip(_)));
     }
+
+    #[test]
+    fn test_copy_delta_is_signed_by_side() {
+        let buy = copy_price_delta_bps("buy", Some(2.0), Some(2.02)).unwrap();
+        assert!((buy - 100.0).abs() < 1e-9);
+        let sell = copy_price_delta_bps("sell", Some(2.0), Some(2.02)).unwrap();
+        assert!((sell + 100.0).abs() < 1e-9);
+        assert!(copy_price_delta_bps("buy", None, Some(2.0)).is_none());
+        assert!(copy_price_delta_bps("buy", Some(0.0), Some(2.0)).is_none());
+    }
+
+    #[test]
+    fn test_copy_delta_summary_flags_front_run_leaders() {
+        let sample = |delay_ms: i64, delta: Option<f64>| CopyTradeLatency {
+            execution_id: Uuid::new_v4().to_string(),
+            config_id: "cfg".into(),
+            source_wallet: "source".into(),
+            action: "buy".into(),
+            leader_price: Some(1.0),
+            leader_time: Utc::now(),
+            fill_price: delta.map(|d| 1.0 + d / 10_000.0),
+            fill_time: Utc::now(),
+            delay_ms,
+            price_delta_bps: delta,
+        };
+        assert!(summarize_copy_deltas("source", &[]).is_none());
+
+        let summary = summarize_copy_deltas(
+            "source",
+            &[sample(1_000, Some(300.0)), sample(3_000, Some(100.0)), sample(2_000, None)],
+        )
+        .unwrap();
+        assert_eq!((summary.samples, summary.priced_samples), (3, 2));
+        assert!((summary.avg_delay_ms - 2_000.0).abs() < 1e-9);
+        assert_eq!(summary.avg_price_delta_bps, Some(200.0));
+        assert_eq!(summary.worst_price_delta_bps, Some(300.0));
+        assert!(!summary.copyable);
+
+        let unpriced = summarize_copy_deltas("source", &[sample(500, None)]).unwrap();
+        assert!(unpriced.copyable && unpriced.avg_price_delta_bps.is_none());
+    }
 }
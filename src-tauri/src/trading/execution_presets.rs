@@ -0,0 +1,522 @@
+use crate::api::trading_execution::get_priority_fee_estimates;
+use crate::config::settings_manager::SharedSettingsManager;
+use crate::config::settings_schema::GasPriority;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Pool, Row, Sqlite, SqlitePool};
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+
+const EXECUTION_PRESETS_DB_FILE: &str = "execution_presets.db";
+const MAX_PRESET_SLIPPAGE_BPS: u16 = 5_000;
+
+/// Execution settings for one token. Unset fields fall through to the order's or
+/// bot's own value, then to the global trading settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenExecutionPreset {
+    pub mint: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub max_slippage_bps: Option<u16>,
+    #[serde(default)]
+    pub priority_fee: Option<GasPriority>,
+    #[serde(default)]
+    pub mev_protection: Option<bool>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl TokenExecutionPreset {
+    fn validate(&self) -> Result<(), String> {
+        if self.mint.trim().is_empty() {
+            return Err("Preset mint must not be empty".into());
+        }
+        if self
+            .max_slippage_bps
+            .is_some_and(|bps| bps == 0 || bps > MAX_PRESET_SLIPPAGE_BPS)
+        {
+            return Err(format!(
+                "Preset slippage must be between 1 and {} bps",
+                MAX_PRESET_SLIPPAGE_BPS
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionPresetSource {
+    Order,
+    Dca,
+    CopyTrade,
+}
+
+impl ExecutionPresetSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ExecutionPresetSource::Order => "order",
+            ExecutionPresetSource::Dca => "dca",
+            ExecutionPresetSource::CopyTrade => "copy_trade",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionParams {
+    pub slippage_bps: u16,
+    pub priority_fee_micro_lamports: u64,
+    pub mev_protection: bool,
+}
+
+/// Parameters an execution will use and the preset they came from, if any.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedExecution {
+    pub params: ExecutionParams,
+    pub preset_mint: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionPresetAudit {
+    pub id: i64,
+    pub order_ref: String,
+    pub source: String,
+    pub preset_mint: Option<String>,
+    pub slippage_bps: i64,
+    pub priority_fee_micro_lamports: i64,
+    pub mev_protection: bool,
+    pub recorded_at: String,
+}
+
+fn fee_for(priority: &GasPriority, estimates: &HashMap<String, u64>) -> u64 {
+    let (preset, fallback) = match priority {
+        GasPriority::Custom(micro_lamports) => return *micro_lamports,
+        GasPriority::Slow => ("slow", 1_000),
+        GasPriority::Medium => ("normal", 5_000),
+        GasPriority::Fast => ("fast", 10_000),
+    };
+    estimates.get(preset).copied().unwrap_or(fallback)
+}
+
+/// Layers a preset over the caller's parameters. Zero slippage or fee on the
+/// caller's side means "not set" and takes the global default.
+pub fn merge_execution_params(
+    preset: Option<&TokenExecutionPreset>,
+    requested: ExecutionParams,
+    global: ExecutionParams,
+    estimates: &HashMap<String, u64>,
+) -> ExecutionParams {
+    let base = ExecutionParams {
+        slippage_bps: if requested.slippage_bps > 0 {
+            requested.slippage_bps
+        } else {
+            global.slippage_bps
+        },
+        priority_fee_micro_lamports: if requested.priority_fee_micro_lamports > 0 {
+            requested.priority_fee_micro_lamports
+        } else {
+            global.priority_fee_micro_lamports
+        },
+        mev_protection: requested.mev_protection,
+    };
+    let Some(preset) = preset else {
+        return base;
+    };
+
+    ExecutionParams {
+        slippage_bps: preset.max_slippage_bps.unwrap_or(base.slippage_bps),
+        priority_fee_micro_lamports: preset
+            .priority_fee
+            .as_ref()
+            .map(|priority| fee_for(priority, estimates))
+            .unwrap_or(base.priority_fee_micro_lamports),
+        mev_protection: preset.mev_protection.unwrap_or(base.mev_protection),
+    }
+}
+
+pub struct ExecutionPresetStore {
+    pool: Pool<Sqlite>,
+}
+
+pub type SharedExecutionPresetStore = Arc<ExecutionPresetStore>;
+
+impl ExecutionPresetStore {
+    async fn open(url: &str) -> Result<Self, sqlx::Error> {
+        let pool = SqlitePool::connect(url).await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS token_execution_presets (
+                mint TEXT PRIMARY KEY NOT NULL,
+                preset_json TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS execution_preset_audit (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                order_ref TEXT NOT NULL,
+                source TEXT NOT NULL,
+                preset_mint TEXT,
+                slippage_bps INTEGER NOT NULL,
+                priority_fee_micro_lamports INTEGER NOT NULL,
+                mev_protection INTEGER NOT NULL,
+                recorded_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_execution_preset_audit_ref ON execution_preset_audit(order_ref)",
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(Self { pool })
+    }
+
+    pub async fn list(&self) -> Result<Vec<TokenExecutionPreset>, String> {
+        let rows = sqlx::query("SELECT preset_json FROM token_execution_presets ORDER BY mint")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        rows.iter()
+            .map(|row| {
+                let json: String = row.try_get("preset_json").map_err(|e| e.to_string())?;
+                serde_json::from_str(&json).map_err(|e| e.to_string())
+            })
+            .collect()
+    }
+
+    pub async fn get(&self, mint: &str) -> Result<Option<TokenExecutionPreset>, String> {
+        let row = sqlx::query("SELECT preset_json FROM token_execution_presets WHERE mint = ?1")
+            .bind(mint)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        match row {
+            Some(row) => {
+                let json: String = row.try_get("preset_json").map_err(|e| e.to_string())?;
+                serde_json::from_str(&json)
+                    .map(Some)
+                    .map_err(|e| e.to_string())
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub async fn upsert(
+        &self,
+        mut preset: TokenExecutionPreset,
+    ) -> Result<TokenExecutionPreset, String> {
+        preset.validate()?;
+        let now = Utc::now().to_rfc3339();
+        preset.updated_at = Some(now.clone());
+        let json = serde_json::to_string(&preset).map_err(|e| e.to_string())?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO token_execution_presets (mint, preset_json, updated_at)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(mint) DO UPDATE SET
+                preset_json = excluded.preset_json,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&preset.mint)
+        .bind(json)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(preset)
+    }
+
+    pub async fn delete(&self, mint: &str) -> Result<(), String> {
+        sqlx::query("DELETE FROM token_execution_presets WHERE mint = ?1")
+            .bind(mint)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn record(
+        &self,
+        order_ref: &str,
+        source: ExecutionPresetSource,
+        resolved: &ResolvedExecution,
+    ) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO execution_preset_audit (
+                order_ref, source, preset_mint, slippage_bps,
+                priority_fee_micro_lamports, mev_protection, recorded_at
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )
+        .bind(order_ref)
+        .bind(source.as_str())
+        .bind(&resolved.preset_mint)
+        .bind(resolved.params.slippage_bps as i64)
+        .bind(resolved.params.priority_fee_micro_lamports as i64)
+        .bind(resolved.params.mev_protection)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    pub async fn audit(
+        &self,
+        order_ref: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<ExecutionPresetAudit>, String> {
+        sqlx::query_as::<_, ExecutionPresetAudit>(
+            r#"
+            SELECT * FROM execution_preset_audit
+            WHERE ?1 IS NULL OR order_ref = ?1
+            ORDER BY id DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(order_ref)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
+
+pub fn register_execution_presets(app: &tauri::App) {
+    let Some(dir) = app.path_resolver().app_data_dir() else {
+        tracing::warn!("app data directory unavailable; execution presets disabled");
+        return;
+    };
+    if let Err(err) = fs::create_dir_all(&dir) {
+        tracing::warn!(error = %err, "failed to create app data directory");
+    }
+    let url = format!(
+        "sqlite:{}?mode=rwc",
+        dir.join(EXECUTION_PRESETS_DB_FILE).display()
+    );
+
+    match tauri::async_runtime::block_on(ExecutionPresetStore::open(&url)) {
+        Ok(store) => {
+            let state: SharedExecutionPresetStore = Arc::new(store);
+            app.manage(state);
+        }
+        Err(err) => tracing::warn!(error = %err, "failed to open execution presets"),
+    }
+}
+
+/// Global defaults from the trading settings.
+async fn global_execution_params(
+    app: &AppHandle,
+    estimates: &HashMap<String, u64>,
+) -> ExecutionParams {
+    let trading = match app.try_state::<SharedSettingsManager>() {
+        Some(settings) => settings.read().await.get_all_settings().trading,
+        None => Default::default(),
+    };
+    ExecutionParams {
+        slippage_bps: (trading.default_slippage * 100.0)
+            .round()
+            .clamp(1.0, u16::MAX as f64) as u16,
+        priority_fee_micro_lamports: fee_for(&trading.gas_priority, estimates),
+        mev_protection: trading.mev_protection,
+    }
+}
+
+/// Resolves the parameters for a trade, taking the first of `mints` that has a
+/// preset. `requested.mev_protection` of `None` uses the global setting.
+pub async fn resolve_execution(
+    app: &AppHandle,
+    mints: &[&str],
+    slippage_bps: u16,
+    priority_fee_micro_lamports: u64,
+    mev_protection: Option<bool>,
+) -> ResolvedExecution {
+    let estimates: HashMap<String, u64> = get_priority_fee_estimates()
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|estimate| (estimate.preset, estimate.micro_lamports))
+        .collect();
+    let global = global_execution_params(app, &estimates).await;
+    let requested = ExecutionParams {
+        slippage_bps,
+        priority_fee_micro_lamports,
+        mev_protection: mev_protection.unwrap_or(global.mev_protection),
+    };
+
+    let mut preset = None;
+    if let Some(store) = app.try_state::<SharedExecutionPresetStore>() {
+        for mint in mints {
+            match store.get(mint).await {
+                Ok(Some(found)) => {
+                    preset = Some(found);
+                    break;
+                }
+                Ok(None) => {}
+                Err(err) => tracing::warn!(error = %err, mint, "failed to load execution preset"),
+            }
+        }
+    }
+
+    ResolvedExecution {
+        params: merge_execution_params(preset.as_ref(), requested, global, &estimates),
+        preset_mint: preset.map(|preset| preset.mint),
+    }
+}
+
+/// Records which parameters an order or bot execution ran with.
+pub async fn record_execution_preset(
+    app: &AppHandle,
+    order_ref: &str,
+    source: ExecutionPresetSource,
+    resolved: &ResolvedExecution,
+) {
+    let Some(store) = app.try_state::<SharedExecutionPresetStore>() else {
+        return;
+    };
+    if let Err(err) = store.record(order_ref, source, resolved).await {
+        tracing::warn!(error = %err, order_ref, "failed to audit execution preset");
+    }
+}
+
+#[tauri::command]
+pub async fn list_execution_presets(
+    store: State<'_, SharedExecutionPresetStore>,
+) -> Result<Vec<TokenExecutionPreset>, String> {
+    store.list().await
+}
+
+#[tauri::command]
+pub async fn save_execution_preset(
+    preset: TokenExecutionPreset,
+    store: State<'_, SharedExecutionPresetStore>,
+) -> Result<TokenExecutionPreset, String> {
+    store.upsert(preset).await
+}
+
+#[tauri::command]
+pub async fn delete_execution_preset(
+    mint: String,
+    store: State<'_, SharedExecutionPresetStore>,
+) -> Result<(), String> {
+    store.delete(&mint).await
+}
+
+/// What a trade of `mint` would use right now, for prefilling trade forms.
+#[tauri::command]
+pub async fn preview_execution_preset(
+    app: AppHandle,
+    mint: String,
+) -> Result<ResolvedExecution, String> {
+    Ok(resolve_execution(&app, &[&mint], 0, 0, None).await)
+}
+
+#[tauri::command]
+pub async fn get_execution_preset_audit(
+    order_ref: Option<String>,
+    limit: Option<i64>,
+    store: State<'_, SharedExecutionPresetStore>,
+) -> Result<Vec<ExecutionPresetAudit>, String> {
+    store
+        .audit(order_ref.as_deref(), limit.unwrap_or(100).clamp(1, 1_000))
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(slippage_bps: u16, fee: u64, mev: bool) -> ExecutionParams {
+        ExecutionParams {
+            slippage_bps,
+            priority_fee_micro_lamports: fee,
+            mev_protection: mev,
+        }
+    }
+
+    fn preset() -> TokenExecutionPreset {
+        TokenExecutionPreset {
+            mint: "BONK".into(),
+            label: None,
+            max_slippage_bps: Some(300),
+            priority_fee: Some(GasPriority::Fast),
+            mev_protection: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn preset_fields_override_and_unset_fields_fall_through() {
+        let estimates = HashMap::from([("fast".to_string(), 20_000)]);
+        let merged = merge_execution_params(
+            Some(&preset()),
+            params(50, 1_000, false),
+            params(100, 5_000, true),
+            &estimates,
+        );
+        assert_eq!(merged, params(300, 20_000, false));
+    }
+
+    #[test]
+    fn unset_request_values_take_global_defaults() {
+        let merged = merge_execution_params(
+            None,
+            params(0, 0, true),
+            params(100, 5_000, false),
+            &HashMap::new(),
+        );
+        assert_eq!(merged, params(100, 5_000, true));
+
+        let custom = TokenExecutionPreset {
+            priority_fee: Some(GasPriority::Custom(42)),
+            ..preset()
+        };
+        let merged = merge_execution_params(
+            Some(&custom),
+            params(0, 0, true),
+            params(100, 5_000, true),
+            &HashMap::new(),
+        );
+        assert_eq!(merged.priority_fee_micro_lamports, 42);
+    }
+
+    #[test]
+    fn presets_reject_out_of_range_slippage() {
+        assert!(preset().validate().is_ok());
+        let zero = TokenExecutionPreset {
+            max_slippage_bps: Some(0),
+            ..preset()
+        };
+        assert!(zero.validate().is_err());
+        let wide = TokenExecutionPreset {
+            max_slippage_bps: Some(MAX_PRESET_SLIPPAGE_BPS + 1),
+            ..preset()
+        };
+        assert!(wide.validate().is_err());
+    }
+}
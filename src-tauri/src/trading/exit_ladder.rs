@@ -0,0 +1,959 @@
+use crate::data::event_store::{record_app_event, Event as AuditEvent};
+use crate::trading::order_manager::SharedOrderManager;
+use crate::trading::types::{CreateOrderRequest, Order, OrderSide, OrderStatus, OrderType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqlitePool, Pool, Row, Sqlite};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tokio::sync::{Mutex, OnceCell, RwLock};
+use tokio::time::{interval, Duration};
+use uuid::Uuid;
+
+const LADDER_SYNC_INTERVAL_SECS: u64 = 5;
+const DEFAULT_SLIPPAGE_BPS: i32 = 100;
+/// Quantities below this are treated as a fully exited position.
+const DUST_QUANTITY: f64 = 1e-9;
+
+/// One take-profit step: sell `sell_percent` of the position at `multiple`
+/// times the average entry price.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LadderRung {
+    pub multiple: f64,
+    pub sell_percent: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExitLadderSettings {
+    pub wallet_address: String,
+    pub enabled: bool,
+    pub apply_to_manual: bool,
+    pub apply_to_snipes: bool,
+    pub rungs: Vec<LadderRung>,
+    /// Stop distance below the average entry, in percent.
+    pub stop_loss_percent: Option<f64>,
+}
+
+impl ExitLadderSettings {
+    pub fn defaults_for(wallet_address: &str) -> Self {
+        Self {
+            wallet_address: wallet_address.to_string(),
+            enabled: false,
+            apply_to_manual: true,
+            apply_to_snipes: true,
+            rungs: vec![
+                LadderRung {
+                    multiple: 2.0,
+                    sell_percent: 25.0,
+                },
+                LadderRung {
+                    multiple: 3.0,
+                    sell_percent: 25.0,
+                },
+            ],
+            stop_loss_percent: Some(30.0),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.enabled && self.rungs.is_empty() && self.stop_loss_percent.is_none() {
+            return Err("An exit ladder needs at least one take-profit or a stop-loss".to_string());
+        }
+
+        let mut previous_multiple = 1.0;
+        let mut total_percent = 0.0;
+        for rung in &self.rungs {
+            if !rung.multiple.is_finite() || rung.multiple <= previous_multiple {
+                return Err("Take-profit multiples must be above 1x and increasing".to_string());
+            }
+            if !(rung.sell_percent > 0.0 && rung.sell_percent <= 100.0) {
+                return Err("Each take-profit must sell between 0 and 100%".to_string());
+            }
+            previous_multiple = rung.multiple;
+            total_percent += rung.sell_percent;
+        }
+        if total_percent > 100.0 + f64::EPSILON {
+            return Err(format!(
+                "Take-profits sell {total_percent}% of the position"
+            ));
+        }
+
+        if let Some(stop) = self.stop_loss_percent {
+            if !(stop > 0.0 && stop < 100.0) {
+                return Err("Stop-loss must be between 0 and 100% below entry".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn applies_to(&self, source: PositionSource) -> bool {
+        self.enabled
+            && match source {
+                PositionSource::Manual => self.apply_to_manual,
+                PositionSource::Snipe => self.apply_to_snipes,
+            }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionSource {
+    Manual,
+    Snipe,
+}
+
+/// A buy that opened or added to a position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionFill {
+    pub wallet_address: String,
+    pub token_mint: String,
+    pub token_symbol: String,
+    pub quote_mint: String,
+    pub quote_symbol: String,
+    /// Tokens received.
+    pub quantity: f64,
+    /// Quote spent.
+    pub cost: f64,
+    pub source: PositionSource,
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LadderLegStatus {
+    Working,
+    Filled,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderLeg {
+    /// Index into the ladder's rungs; `None` for the stop-loss.
+    pub rung_index: Option<usize>,
+    pub order_id: String,
+    pub price: f64,
+    pub amount: f64,
+    pub status: LadderLegStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitLadderStatus {
+    Active,
+    Closed,
+    Cancelled,
+}
+
+impl ExitLadderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExitLadderStatus::Active => "active",
+            ExitLadderStatus::Closed => "closed",
+            ExitLadderStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExitLadder {
+    pub id: String,
+    pub wallet_address: String,
+    pub token_mint: String,
+    pub token_symbol: String,
+    pub quote_mint: String,
+    pub quote_symbol: String,
+    pub source: PositionSource,
+    /// Total tokens bought into the position, including later adds.
+    pub quantity: f64,
+    /// Total quote spent on those tokens.
+    pub cost: f64,
+    pub sold_quantity: f64,
+    pub rungs: Vec<LadderRung>,
+    pub stop_loss_percent: Option<f64>,
+    pub legs: Vec<LadderLeg>,
+    /// Buy orders or snipe tickets that make up the position.
+    pub entries: Vec<String>,
+    pub status: ExitLadderStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExitLadder {
+    pub fn average_entry(&self) -> f64 {
+        if self.quantity > 0.0 {
+            self.cost / self.quantity
+        } else {
+            0.0
+        }
+    }
+
+    pub fn remaining_quantity(&self) -> f64 {
+        (self.quantity - self.sold_quantity).max(0.0)
+    }
+
+    fn filled_rungs(&self) -> Vec<usize> {
+        self.legs
+            .iter()
+            .filter(|leg| leg.status == LadderLegStatus::Filled)
+            .filter_map(|leg| leg.rung_index)
+            .collect()
+    }
+
+    fn working_legs(&self) -> impl Iterator<Item = &LadderLeg> {
+        self.legs
+            .iter()
+            .filter(|leg| leg.status == LadderLegStatus::Working)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlannedLeg {
+    pub rung_index: Option<usize>,
+    pub order_type: OrderType,
+    pub price: f64,
+    pub amount: f64,
+}
+
+/// Sizes the open rungs against the whole position and the stop against what
+/// is still held. Rungs are capped so the ladder never sells more than remains.
+pub fn plan_ladder_legs(ladder: &ExitLadder) -> Vec<PlannedLeg> {
+    let entry = ladder.average_entry();
+    let remaining = ladder.remaining_quantity();
+    if entry <= 0.0 || remaining <= DUST_QUANTITY {
+        return Vec::new();
+    }
+
+    let filled = ladder.filled_rungs();
+    let mut unallocated = remaining;
+    let mut legs: Vec<PlannedLeg> = ladder
+        .rungs
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !filled.contains(index))
+        .filter_map(|(index, rung)| {
+            let amount = (ladder.quantity * rung.sell_percent / 100.0).min(unallocated);
+            if amount <= DUST_QUANTITY {
+                return None;
+            }
+            unallocated -= amount;
+            Some(PlannedLeg {
+                rung_index: Some(index),
+                order_type: OrderType::TakeProfit,
+                price: entry * rung.multiple,
+                amount,
+            })
+        })
+        .collect();
+
+    if let Some(stop) = ladder.stop_loss_percent {
+        legs.push(PlannedLeg {
+            rung_index: None,
+            order_type: OrderType::StopLoss,
+            price: entry * (1.0 - stop / 100.0),
+            amount: remaining,
+        });
+    }
+    legs
+}
+
+pub struct ExitLadderDatabase {
+    pool: Pool<Sqlite>,
+}
+
+impl ExitLadderDatabase {
+    pub async fn new(db_path: PathBuf) -> Result<Self, sqlx::Error> {
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+        let db = Self { pool };
+        db.initialize().await?;
+        Ok(db)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS exit_ladders (
+                id TEXT PRIMARY KEY,
+                wallet_address TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                status TEXT NOT NULL,
+                data TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS exit_ladder_settings (
+                wallet_address TEXT PRIMARY KEY,
+                data TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_exit_ladders_position
+                ON exit_ladders(wallet_address, token_mint, status);
+            CREATE INDEX IF NOT EXISTS idx_exit_ladders_status ON exit_ladders(status);
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn save_ladder(&self, ladder: &ExitLadder) -> Result<(), String> {
+        let data = serde_json::to_string(ladder).map_err(|e| e.to_string())?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO exit_ladders (id, wallet_address, token_mint, status, data, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            ON CONFLICT(id) DO UPDATE SET
+                status = excluded.status,
+                data = excluded.data,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&ladder.id)
+        .bind(&ladder.wallet_address)
+        .bind(&ladder.token_mint)
+        .bind(ladder.status.as_str())
+        .bind(data)
+        .bind(ladder.created_at.to_rfc3339())
+        .bind(ladder.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save exit ladder: {e}"))?;
+
+        Ok(())
+    }
+
+    pub async fn get_ladder(&self, id: &str) -> Result<Option<ExitLadder>, String> {
+        let row = sqlx::query("SELECT data FROM exit_ladders WHERE id = ?1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load exit ladder: {e}"))?;
+
+        row.map(|row| {
+            let data: String = row.get("data");
+            serde_json::from_str(&data).map_err(|e| e.to_string())
+        })
+        .transpose()
+    }
+
+    pub async fn active_ladder_for(
+        &self,
+        wallet_address: &str,
+        token_mint: &str,
+    ) -> Result<Option<ExitLadder>, String> {
+        let row = sqlx::query(
+            "SELECT data FROM exit_ladders WHERE wallet_address = ?1 AND token_mint = ?2 AND status = 'active'",
+        )
+        .bind(wallet_address)
+        .bind(token_mint)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load exit ladder: {e}"))?;
+
+        row.map(|row| {
+            let data: String = row.get("data");
+            serde_json::from_str(&data).map_err(|e| e.to_string())
+        })
+        .transpose()
+    }
+
+    pub async fn list_ladders(
+        &self,
+        wallet_address: &str,
+        status: Option<ExitLadderStatus>,
+    ) -> Result<Vec<ExitLadder>, String> {
+        let rows = match status {
+            Some(status) => sqlx::query(
+                "SELECT data FROM exit_ladders WHERE wallet_address = ?1 AND status = ?2 ORDER BY created_at DESC",
+            )
+            .bind(wallet_address)
+            .bind(status.as_str())
+            .fetch_all(&self.pool)
+            .await,
+            None => sqlx::query(
+                "SELECT data FROM exit_ladders WHERE wallet_address = ?1 ORDER BY created_at DESC",
+            )
+            .bind(wallet_address)
+            .fetch_all(&self.pool)
+            .await,
+        }
+        .map_err(|e| format!("Failed to list exit ladders: {e}"))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|row| serde_json::from_str(row.get::<String, _>("data").as_str()).ok())
+            .collect())
+    }
+
+    pub async fn active_ladders(&self) -> Result<Vec<ExitLadder>, String> {
+        let rows = sqlx::query("SELECT data FROM exit_ladders WHERE status = 'active'")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load active exit ladders: {e}"))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|row| serde_json::from_str(row.get::<String, _>("data").as_str()).ok())
+            .collect())
+    }
+
+    pub async fn get_settings(&self, wallet_address: &str) -> Result<ExitLadderSettings, String> {
+        let row = sqlx::query("SELECT data FROM exit_ladder_settings WHERE wallet_address = ?1")
+            .bind(wallet_address)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load exit ladder settings: {e}"))?;
+
+        match row {
+            Some(row) => serde_json::from_str(row.get::<String, _>("data").as_str())
+                .map_err(|e| e.to_string()),
+            None => Ok(ExitLadderSettings::defaults_for(wallet_address)),
+        }
+    }
+
+    pub async fn save_settings(&self, settings: &ExitLadderSettings) -> Result<(), String> {
+        let data = serde_json::to_string(settings).map_err(|e| e.to_string())?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO exit_ladder_settings (wallet_address, data, updated_at)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(wallet_address) DO UPDATE SET
+                data = excluded.data,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&settings.wallet_address)
+        .bind(data)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save exit ladder settings: {e}"))?;
+
+        Ok(())
+    }
+}
+
+pub type SharedExitLadderDatabase = Arc<RwLock<ExitLadderDatabase>>;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExitLadderEvent {
+    pub ladder_id: String,
+    pub token_symbol: String,
+    pub status: ExitLadderStatus,
+    pub remaining_quantity: f64,
+    pub average_entry: f64,
+}
+
+pub struct ExitLadderManager {
+    db: SharedExitLadderDatabase,
+    orders: SharedOrderManager,
+    app_handle: AppHandle,
+    /// Fills and syncs both rewrite a ladder's legs; one at a time.
+    updates: Mutex<()>,
+}
+
+impl ExitLadderManager {
+    pub fn new(
+        db: SharedExitLadderDatabase,
+        orders: SharedOrderManager,
+        app_handle: AppHandle,
+    ) -> Self {
+        Self {
+            db,
+            orders,
+            app_handle,
+            updates: Mutex::new(()),
+        }
+    }
+
+    /// Opens a ladder for a new position, or resizes the existing one when the
+    /// buy adds to a position that already has a ladder working.
+    pub async fn record_fill(&self, fill: PositionFill) -> Result<Option<ExitLadder>, String> {
+        if fill.quantity <= 0.0 || fill.cost <= 0.0 {
+            return Ok(None);
+        }
+
+        let _guard = self.updates.lock().await;
+        let existing = self
+            .db
+            .read()
+            .await
+            .active_ladder_for(&fill.wallet_address, &fill.token_mint)
+            .await?;
+
+        let mut ladder = match existing {
+            Some(mut ladder) => {
+                self.cancel_working_legs(&mut ladder).await?;
+                ladder.quantity += fill.quantity;
+                ladder.cost += fill.cost;
+                ladder
+            }
+            None => {
+                let settings = self
+                    .db
+                    .read()
+                    .await
+                    .get_settings(&fill.wallet_address)
+                    .await?;
+                if !settings.applies_to(fill.source) {
+                    return Ok(None);
+                }
+                let now = Utc::now();
+                ExitLadder {
+                    id: Uuid::new_v4().to_string(),
+                    wallet_address: fill.wallet_address.clone(),
+                    token_mint: fill.token_mint.clone(),
+                    token_symbol: fill.token_symbol.clone(),
+                    quote_mint: fill.quote_mint.clone(),
+                    quote_symbol: fill.quote_symbol.clone(),
+                    source: fill.source,
+                    quantity: fill.quantity,
+                    cost: fill.cost,
+                    sold_quantity: 0.0,
+                    rungs: settings.rungs,
+                    stop_loss_percent: settings.stop_loss_percent,
+                    legs: Vec::new(),
+                    entries: Vec::new(),
+                    status: ExitLadderStatus::Active,
+                    created_at: now,
+                    updated_at: now,
+                }
+            }
+        };
+        ladder.entries.extend(fill.reference);
+
+        self.place_legs(&mut ladder).await?;
+        ladder.updated_at = Utc::now();
+        self.db.read().await.save_ladder(&ladder).await?;
+        self.emit_update(&ladder);
+        Ok(Some(ladder))
+    }
+
+    pub async fn cancel_ladder(&self, id: &str) -> Result<ExitLadder, String> {
+        let _guard = self.updates.lock().await;
+        let mut ladder = self.get_ladder(id).await?;
+        if ladder.status != ExitLadderStatus::Active {
+            return Err("Exit ladder is already finished".to_string());
+        }
+
+        self.cancel_working_legs(&mut ladder).await?;
+        ladder.status = ExitLadderStatus::Cancelled;
+        ladder.updated_at = Utc::now();
+        self.db.read().await.save_ladder(&ladder).await?;
+        self.emit_update(&ladder);
+        Ok(ladder)
+    }
+
+    pub async fn get_ladder(&self, id: &str) -> Result<ExitLadder, String> {
+        self.db
+            .read()
+            .await
+            .get_ladder(id)
+            .await?
+            .ok_or_else(|| "Exit ladder not found".to_string())
+    }
+
+    pub async fn list_ladders(
+        &self,
+        wallet_address: &str,
+        status: Option<ExitLadderStatus>,
+    ) -> Result<Vec<ExitLadder>, String> {
+        self.db
+            .read()
+            .await
+            .list_ladders(wallet_address, status)
+            .await
+    }
+
+    /// Reconciles a ladder with its orders. A take-profit fill shrinks the
+    /// stop to what is left; a stop fill or a fully sold position closes it.
+    pub async fn sync_ladder(&self, id: &str) -> Result<ExitLadder, String> {
+        let _guard = self.updates.lock().await;
+        let mut ladder = self.get_ladder(id).await?;
+        if ladder.status != ExitLadderStatus::Active {
+            return Ok(ladder);
+        }
+
+        let mut changed = false;
+        let mut take_profit_filled = false;
+        let mut stop_filled = false;
+        let mut filled = Vec::new();
+        for leg in ladder.legs.iter_mut() {
+            if leg.status != LadderLegStatus::Working {
+                continue;
+            }
+            let order = self.orders.get_order(&leg.order_id).await?;
+            match order.status {
+                OrderStatus::Filled => {
+                    leg.status = LadderLegStatus::Filled;
+                    ladder.sold_quantity += leg.amount;
+                    filled.push(leg.clone());
+                    if leg.rung_index.is_some() {
+                        take_profit_filled = true;
+                    } else {
+                        stop_filled = true;
+                    }
+                    changed = true;
+                }
+                OrderStatus::Cancelled | OrderStatus::Expired | OrderStatus::Failed => {
+                    leg.status = LadderLegStatus::Cancelled;
+                    changed = true;
+                }
+                _ => {}
+            }
+        }
+        for leg in &filled {
+            self.record_realized(&ladder, leg).await;
+        }
+
+        if stop_filled || ladder.remaining_quantity() <= DUST_QUANTITY {
+            self.cancel_working_legs(&mut ladder).await?;
+            ladder.status = ExitLadderStatus::Closed;
+        } else if take_profit_filled {
+            self.cancel_working_legs(&mut ladder).await?;
+            self.place_legs(&mut ladder).await?;
+        } else if changed && ladder.working_legs().next().is_none() {
+            // Every exit was cancelled outside the ladder.
+            ladder.status = ExitLadderStatus::Cancelled;
+        }
+
+        if changed {
+            ladder.updated_at = Utc::now();
+            self.db.read().await.save_ladder(&ladder).await?;
+            self.emit_update(&ladder);
+        }
+        Ok(ladder)
+    }
+
+    async fn place_legs(&self, ladder: &mut ExitLadder) -> Result<(), String> {
+        for planned in plan_ladder_legs(ladder) {
+            let (limit_price, stop_price) = match planned.order_type {
+                OrderType::StopLoss => (None, Some(planned.price)),
+                _ => (Some(planned.price), None),
+            };
+            let order = self
+                .orders
+                .create_order(CreateOrderRequest {
+                    order_type: planned.order_type,
+                    side: OrderSide::Sell,
+                    input_mint: ladder.token_mint.clone(),
+                    output_mint: ladder.quote_mint.clone(),
+                    input_symbol: ladder.token_symbol.clone(),
+                    output_symbol: ladder.quote_symbol.clone(),
+                    amount: planned.amount,
+                    limit_price,
+                    stop_price,
+                    trailing_percent: None,
+                    // Not linked: a take-profit fill must not cancel the rest.
+                    linked_order_id: None,
+                    slippage_bps: DEFAULT_SLIPPAGE_BPS,
+                    priority_fee_micro_lamports: 0,
+                    wallet_address: ladder.wallet_address.clone(),
+                    stop_loss_price: None,
+                })
+                .await?;
+
+            ladder.legs.push(LadderLeg {
+                rung_index: planned.rung_index,
+                order_id: order.id,
+                price: planned.price,
+                amount: planned.amount,
+                status: LadderLegStatus::Working,
+            });
+        }
+        Ok(())
+    }
+
+    async fn cancel_working_legs(&self, ladder: &mut ExitLadder) -> Result<(), String> {
+        let mut filled = Vec::new();
+        for leg in ladder.legs.iter_mut() {
+            if leg.status != LadderLegStatus::Working {
+                continue;
+            }
+            let order = self.orders.get_order(&leg.order_id).await?;
+            if is_working(&order) {
+                self.orders.cancel_order(&leg.order_id).await?;
+            }
+            if order.status == OrderStatus::Filled {
+                leg.status = LadderLegStatus::Filled;
+                ladder.sold_quantity += leg.amount;
+                filled.push(leg.clone());
+            } else {
+                leg.status = LadderLegStatus::Cancelled;
+            }
+        }
+        for leg in &filled {
+            self.record_realized(ladder, leg).await;
+        }
+        Ok(())
+    }
+
+    /// Books the realized P&L of a filled exit leg against the average entry.
+    async fn record_realized(&self, ladder: &ExitLadder, leg: &LadderLeg) {
+        record_app_event(
+            &self.app_handle,
+            &format!("exit_ladder_{}", ladder.id),
+            AuditEvent::PositionClosed {
+                position_id: ladder.id.clone(),
+                symbol: Some(ladder.token_symbol.clone()),
+                exit_price: leg.price,
+                pnl: leg.amount * (leg.price - ladder.average_entry()),
+                timestamp: Utc::now(),
+            },
+        )
+        .await;
+    }
+
+    fn emit_update(&self, ladder: &ExitLadder) {
+        let _ = self.app_handle.emit_all(
+            "exit_ladder_update",
+            ExitLadderEvent {
+                ladder_id: ladder.id.clone(),
+                token_symbol: ladder.token_symbol.clone(),
+                status: ladder.status,
+                remaining_quantity: ladder.remaining_quantity(),
+                average_entry: ladder.average_entry(),
+            },
+        );
+    }
+
+    pub async fn start_monitoring(manager: Arc<Self>) {
+        let mut ticker = interval(Duration::from_secs(LADDER_SYNC_INTERVAL_SECS));
+
+        loop {
+            ticker.tick().await;
+            let ladders = match manager.db.read().await.active_ladders().await {
+                Ok(ladders) => ladders,
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to load active exit ladders");
+                    continue;
+                }
+            };
+
+            for ladder in ladders {
+                if let Err(err) = manager.sync_ladder(&ladder.id).await {
+                    tracing::warn!(error = %err, ladder = %ladder.id, "failed to sync exit ladder");
+                }
+            }
+        }
+    }
+}
+
+fn is_working(order: &Order) -> bool {
+    matches!(
+        order.status,
+        OrderStatus::Pending | OrderStatus::PartiallyFilled
+    )
+}
+
+pub struct ExitLadderState {
+    pub db: SharedExitLadderDatabase,
+    pub manager: Arc<ExitLadderManager>,
+}
+
+static EXIT_LADDER_STATE: OnceCell<ExitLadderState> = OnceCell::const_new();
+
+pub async fn init_exit_ladders(
+    app_handle: &AppHandle,
+    orders: SharedOrderManager,
+) -> Result<(), String> {
+    if EXIT_LADDER_STATE.get().is_some() {
+        return Ok(());
+    }
+
+    let app_dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Failed to resolve app data directory".to_string())?;
+
+    let mut db_path = PathBuf::from(app_dir);
+    db_path.push("orders.db");
+
+    let db = ExitLadderDatabase::new(db_path)
+        .await
+        .map_err(|e| format!("Failed to initialize exit ladder database: {e}"))?;
+
+    let shared_db = Arc::new(RwLock::new(db));
+    let manager = Arc::new(ExitLadderManager::new(
+        shared_db.clone(),
+        orders,
+        app_handle.clone(),
+    ));
+
+    EXIT_LADDER_STATE
+        .set(ExitLadderState {
+            db: shared_db,
+            manager: manager.clone(),
+        })
+        .map_err(|_| "Exit ladder state already initialized".to_string())?;
+
+    tauri::async_runtime::spawn(ExitLadderManager::start_monitoring(manager));
+
+    Ok(())
+}
+
+fn require_state<'a>() -> Result<&'a ExitLadderState, String> {
+    EXIT_LADDER_STATE
+        .get()
+        .ok_or_else(|| "Exit ladders not initialized".to_string())
+}
+
+/// Called when a buy lands. Trade idea entries are skipped because the idea
+/// already places its own stop and target.
+pub fn notify_position_opened(fill: PositionFill) {
+    let Ok(state) = require_state() else {
+        return;
+    };
+    let manager = state.manager.clone();
+
+    tauri::async_runtime::spawn(async move {
+        if let Some(reference) = &fill.reference {
+            if crate::trading::trade_ideas::is_trade_idea_order(reference).await {
+                return;
+            }
+        }
+        if let Err(err) = manager.record_fill(fill).await {
+            tracing::warn!(error = %err, "failed to place exit ladder");
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_exit_ladder_settings(
+    wallet_address: String,
+) -> Result<ExitLadderSettings, String> {
+    let state = require_state()?;
+    let settings = state.db.read().await.get_settings(&wallet_address).await;
+    settings
+}
+
+#[tauri::command]
+pub async fn update_exit_ladder_settings(
+    settings: ExitLadderSettings,
+) -> Result<ExitLadderSettings, String> {
+    settings.validate()?;
+    let state = require_state()?;
+    state.db.read().await.save_settings(&settings).await?;
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn list_exit_ladders(
+    wallet_address: String,
+    status: Option<ExitLadderStatus>,
+) -> Result<Vec<ExitLadder>, String> {
+    let state = require_state()?;
+    state.manager.list_ladders(&wallet_address, status).await
+}
+
+#[tauri::command]
+pub async fn get_exit_ladder(id: String) -> Result<ExitLadder, String> {
+    let state = require_state()?;
+    state.manager.sync_ladder(&id).await
+}
+
+#[tauri::command]
+pub async fn cancel_exit_ladder(id: String) -> Result<ExitLadder, String> {
+    let state = require_state()?;
+    state.manager.cancel_ladder(&id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ladder(quantity: f64, cost: f64) -> ExitLadder {
+        let settings = ExitLadderSettings::defaults_for("wallet");
+        let now = Utc::now();
+        ExitLadder {
+            id: "ladder".to_string(),
+            wallet_address: "wallet".to_string(),
+            token_mint: "mint".to_string(),
+            token_symbol: "GEM".to_string(),
+            quote_mint: "usdc".to_string(),
+            quote_symbol: "USDC".to_string(),
+            source: PositionSource::Manual,
+            quantity,
+            cost,
+            sold_quantity: 0.0,
+            rungs: settings.rungs,
+            stop_loss_percent: settings.stop_loss_percent,
+            legs: Vec::new(),
+            entries: Vec::new(),
+            status: ExitLadderStatus::Active,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn plans_rungs_from_average_entry() {
+        let legs = plan_ladder_legs(&ladder(1_000.0, 100.0));
+
+        assert_eq!(legs.len(), 3);
+        assert_eq!(legs[0].order_type, OrderType::TakeProfit);
+        assert!((legs[0].price - 0.2).abs() < 1e-12);
+        assert!((legs[0].amount - 250.0).abs() < 1e-9);
+        assert!((legs[1].price - 0.3).abs() < 1e-12);
+        assert_eq!(legs[2].order_type, OrderType::StopLoss);
+        assert!((legs[2].price - 0.07).abs() < 1e-12);
+        assert!((legs[2].amount - 1_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn filled_rungs_are_not_replaced_and_stop_shrinks() {
+        let mut position = ladder(1_000.0, 100.0);
+        position.sold_quantity = 250.0;
+        position.legs.push(LadderLeg {
+            rung_index: Some(0),
+            order_id: "tp1".to_string(),
+            price: 0.2,
+            amount: 250.0,
+            status: LadderLegStatus::Filled,
+        });
+        // Adding to the position re-prices the open rungs off the new average.
+        position.quantity += 1_000.0;
+        position.cost += 300.0;
+
+        let legs = plan_ladder_legs(&position);
+        assert_eq!(legs.len(), 2);
+        assert_eq!(legs[0].rung_index, Some(1));
+        assert!((legs[0].price - 0.6).abs() < 1e-12);
+        assert!((legs[0].amount - 500.0).abs() < 1e-9);
+        assert!((legs[1].amount - 1_750.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rejects_ladders_that_oversell() {
+        let mut settings = ExitLadderSettings::defaults_for("wallet");
+        assert!(settings.validate().is_ok());
+
+        settings.rungs.push(LadderRung {
+            multiple: 5.0,
+            sell_percent: 60.0,
+        });
+        assert!(settings.validate().is_err());
+
+        settings.rungs.pop();
+        settings.rungs[1].multiple = 1.5;
+        assert!(settings.validate().is_err());
+    }
+}
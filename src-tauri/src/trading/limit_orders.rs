@@ -1,3 +1,4 @@
+use crate::idempotency::run_idempotent;
 use crate::trading::database::{OrderDatabase, SharedOrderDatabase};
 use crate::trading::order_manager::{OrderManager, SharedOrderManager};
 use crate::trading::types::{CreateOrderRequest, Order, OrderStatus};
@@ -43,6 +44,10 @@ pub async fn init_trading(app_handle: &AppHandle) -> Result<(), String> {
         })
         .map_err(|_| "Trading state already initialized".to_string())?;
 
+    crate::trading::trade_ideas::init_trade_ideas(app_handle, manager.clone()).await?;
+    crate::trading::exit_ladder::init_exit_ladders(app_handle, manager.clone()).await?;
+    crate::trading::alert_automation::init_alert_automation(app_handle, manager.clone()).await?;
+
     let handle = app_handle.clone();
     tauri::async_runtime::spawn(async move {
         OrderManager::start_monitoring(manager).await;
@@ -66,9 +71,15 @@ pub async fn trading_init(handle: AppHandle) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub async fn create_order(request: CreateOrderRequest) -> Result<Order, String> {
+pub async fn create_order(
+    request: CreateOrderRequest,
+    idempotency_key: Option<String>,
+) -> Result<Order, String> {
     let state = require_state()?;
-    state.manager.create_order(request).await
+    run_idempotent("create_order", idempotency_key.as_deref(), &request, || {
+        state.manager.create_order(request.clone())
+    })
+    .await
 }
 
 #[tauri::command]
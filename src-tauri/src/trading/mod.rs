@@ -1,25 +1,39 @@
+pub mod alert_automation;
 pub mod auto_trading;
 pub mod backtesting;
 pub mod copy_trading;
 pub mod database;
+pub mod execution_presets;
+pub mod exit_ladder;
 pub mod limit_orders;
 pub mod optimizer;
 pub mod order_manager;
+pub mod paper_fill_model;
 pub mod paper_trading;
+pub mod position_sizing;
 pub mod price_listener;
+pub mod promotion;
 pub mod safety;
 pub mod safety_commands;
+pub mod snipe;
+pub mod trade_ideas;
 pub mod types;
 
+pub use alert_automation::*;
 pub use auto_trading::*;
 pub use backtesting::*;
 pub use copy_trading::*;
 pub use database::{OrderDatabase, SharedOrderDatabase};
+pub use execution_presets::*;
+pub use exit_ladder::*;
 pub use limit_orders::*;
 pub use optimizer::*;
 pub use order_manager::{OrderManager, SharedOrderManager};
+pub use paper_fill_model::*;
 pub use paper_trading::*;
+pub use position_sizing::*;
 pub use price_listener::{start_price_listener, update_order_prices, PriceUpdate};
+pub use promotion::*;
 pub use safety::{
     ImpactPreview,
     InsuranceProvider,
@@ -36,4 +50,6 @@ pub use safety::{
     ViolationSeverity,
 };
 pub use safety_commands::*;
+pub use snipe::*;
+pub use trade_ideas::*;
 pub use types::*;
@@ -1,9 +1,17 @@
+use crate::config::token_registry::apply_token_overrides;
+use crate::core::events::{publish_domain_event, DomainEvent};
 use crate::data::event_store::{Event as AuditEvent, SharedEventStore};
 use crate::trading::database::{OrderDatabase, SharedOrderDatabase};
+use crate::trading::execution_presets::{
+    record_execution_preset, resolve_execution, ExecutionPresetSource,
+};
+use crate::trading::exit_ladder::{notify_position_opened, PositionFill, PositionSource};
+use crate::trading::position_sizing::enforce_risk_per_trade;
 use crate::trading::types::{
     CreateOrderRequest, Order, OrderFill, OrderSide, OrderStatus, OrderType, OrderUpdate,
     QuickTradeRequest,
 };
+use crate::webhooks::{publish_event, EventSeverity, WebhookEvent, WebhookEventType};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -52,6 +60,25 @@ impl OrderManager {
     }
 
     pub async fn create_order(&self, request: CreateOrderRequest) -> Result<Order, String> {
+        {
+            let prices = self.current_prices.read().await;
+            enforce_risk_per_trade(&self.app_handle, &request, &prices)?;
+        }
+
+        // Per-token presets apply to every order, manual or bot-created
+        let (traded_mint, counter_mint) = match request.side {
+            OrderSide::Buy => (&request.output_mint, &request.input_mint),
+            OrderSide::Sell => (&request.input_mint, &request.output_mint),
+        };
+        let execution = resolve_execution(
+            &self.app_handle,
+            &[traded_mint.as_str(), counter_mint.as_str()],
+            request.slippage_bps.clamp(0, u16::MAX as i32) as u16,
+            request.priority_fee_micro_lamports.max(0) as u64,
+            None,
+        )
+        .await;
+
         let order = Order {
             id: Uuid::new_v4().to_string(),
             order_type: request.order_type,
@@ -59,8 +86,8 @@ impl OrderManager {
             status: OrderStatus::Pending,
             input_mint: request.input_mint,
             output_mint: request.output_mint,
-            input_symbol: request.input_symbol,
-            output_symbol: request.output_symbol,
+            input_symbol: registry_symbol(&request.input_mint, request.input_symbol),
+            output_symbol: registry_symbol(&request.output_mint, request.output_symbol),
             amount: request.amount,
             filled_amount: 0.0,
             limit_price: request.limit_price,
@@ -69,8 +96,11 @@ impl OrderManager {
             highest_price: None,
             lowest_price: None,
             linked_order_id: request.linked_order_id,
-            slippage_bps: request.slippage_bps,
-            priority_fee_micro_lamports: request.priority_fee_micro_lamports,
+            slippage_bps: execution.params.slippage_bps as i32,
+            priority_fee_micro_lamports: execution
+                .params
+                .priority_fee_micro_lamports
+                .min(i32::MAX as u64) as i32,
             wallet_address: request.wallet_address,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -85,6 +115,13 @@ impl OrderManager {
             .create_order(&order)
             .await
             .map_err(|e| format!("Failed to create order: {}", e))?;
+        record_execution_preset(
+            &self.app_handle,
+            &order.id,
+            ExecutionPresetSource::Order,
+            &execution,
+        )
+        .await;
 
         // Publish event to event store
         if let Some(ref event_store) = self.event_store {
@@ -347,10 +384,61 @@ impl OrderManager {
         }
 
         self.emit_order_update(&filled_order);
+        self.publish_fill_webhook(&filled_order, trigger_price);
+        publish_domain_event(
+            "order_manager",
+            DomainEvent::OrderFilled {
+                order_id: filled_order.id.clone(),
+                wallet_address: filled_order.wallet_address.clone(),
+                side: filled_order.side.to_string(),
+                input_mint: filled_order.input_mint.clone(),
+                output_mint: filled_order.output_mint.clone(),
+                amount: filled_order.amount,
+                fill_price: trigger_price,
+                tx_signature: filled_order.tx_signature.clone(),
+            },
+        );
+
+        if order.side == OrderSide::Buy && trigger_price > 0.0 {
+            notify_position_opened(PositionFill {
+                wallet_address: order.wallet_address.clone(),
+                token_mint: order.output_mint.clone(),
+                token_symbol: order.output_symbol.clone(),
+                quote_mint: order.input_mint.clone(),
+                quote_symbol: order.input_symbol.clone(),
+                quantity: order.amount / trigger_price,
+                cost: order.amount,
+                source: PositionSource::Manual,
+                reference: Some(order.id.clone()),
+            });
+        }
 
         Ok(())
     }
 
+    fn publish_fill_webhook(&self, order: &Order, fill_price: f64) {
+        // Buys spend the quote asset, so their amount is already the notional.
+        let (token, notional) = match order.side {
+            OrderSide::Buy => (&order.output_mint, order.amount),
+            OrderSide::Sell => (&order.input_mint, order.amount * fill_price),
+        };
+
+        let event = WebhookEvent::new(
+            WebhookEventType::Trade,
+            EventSeverity::Info,
+            format!("{} order filled", order.order_type),
+            format!(
+                "{} {} {} at {}",
+                order.side, order.amount, order.input_symbol, fill_price
+            ),
+        )
+        .with_token(token.clone())
+        .with_notional(notional)
+        .with_data(serde_json::to_value(order).unwrap_or_default());
+
+        publish_event(&self.app_handle, event);
+    }
+
     async fn publish_audit_event(&self, aggregate_id: String, event: AuditEvent) {
         if let Some(store) = &self.event_store {
             let store = store.clone();
@@ -399,3 +487,9 @@ impl OrderManager {
 }
 
 pub type SharedOrderManager = Arc<OrderManager>;
+
+/// Symbols from the local token registry win over what the caller passed.
+fn registry_symbol(mint: &str, mut symbol: String) -> String {
+    apply_token_overrides(mint, &mut symbol, None, None, None);
+    symbol
+}
@@ -0,0 +1,320 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::market::order_book::SlippageEstimate;
+use crate::trading::types::OrderSide;
+
+/// Parameters for modeled paper fills. Impact comes from the order book or the
+/// pool's liquidity; spread and latency drift are applied on top.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperFillModelConfig {
+    pub enabled: bool,
+    /// Half of the quoted spread, paid on every fill.
+    pub half_spread_bps: f64,
+    pub min_latency_ms: u64,
+    pub max_latency_ms: u64,
+    /// Price drift per second of latency, one standard move in bps.
+    pub volatility_bps_per_sec: f64,
+    /// Trades with a larger modeled impact are rejected, as a router would.
+    pub max_price_impact: f64,
+}
+
+impl Default for PaperFillModelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            half_spread_bps: 5.0,
+            min_latency_ms: 150,
+            max_latency_ms: 900,
+            volatility_bps_per_sec: 4.0,
+            max_price_impact: 0.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaperFillLiquiditySource {
+    OrderBook,
+    Pool,
+    /// No depth data; the size-tier slippage stands in for impact.
+    SizeTier,
+}
+
+impl PaperFillLiquiditySource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaperFillLiquiditySource::OrderBook => "order_book",
+            PaperFillLiquiditySource::Pool => "pool",
+            PaperFillLiquiditySource::SizeTier => "size_tier",
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "order_book" => PaperFillLiquiditySource::OrderBook,
+            "pool" => PaperFillLiquiditySource::Pool,
+            _ => PaperFillLiquiditySource::SizeTier,
+        }
+    }
+}
+
+/// Depth available for a paper fill, best source first.
+#[derive(Debug, Clone, Default)]
+pub struct PaperFillDepth {
+    pub book: Option<SlippageEstimate>,
+    pub pool_liquidity_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaperModeledFill {
+    pub price: f64,
+    pub source: PaperFillLiquiditySource,
+    pub price_impact: f64,
+    pub spread: f64,
+    pub latency_ms: u64,
+    pub latency_drift: f64,
+}
+
+/// Modeled fill next to the naive one it replaces.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperFillReport {
+    pub trade_id: String,
+    pub symbol: String,
+    pub side: String,
+    pub quantity: f64,
+    pub market_price: f64,
+    pub naive_price: f64,
+    pub modeled_price: f64,
+    pub price_impact: f64,
+    pub spread: f64,
+    pub latency_ms: u64,
+    pub latency_drift: f64,
+    pub liquidity_source: PaperFillLiquiditySource,
+    pub pool_liquidity_usd: Option<f64>,
+}
+
+impl PaperFillReport {
+    /// How much worse (positive) the modeled fill is than the naive one, in bps.
+    pub fn naive_gap_bps(&self) -> f64 {
+        if self.naive_price <= 0.0 {
+            return 0.0;
+        }
+        let gap = (self.modeled_price - self.naive_price) / self.naive_price * 10_000.0;
+        match self.side.as_str() {
+            "sell" => -gap,
+            _ => gap,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperFillComparison {
+    pub trades: Vec<PaperFillReport>,
+    pub avg_price_impact_bps: f64,
+    pub avg_naive_gap_bps: f64,
+    /// Extra quote spent (or forgone on sells) compared with naive fills.
+    pub modeled_cost_vs_naive: f64,
+}
+
+pub fn summarize_paper_fills(trades: Vec<PaperFillReport>) -> PaperFillComparison {
+    let count = trades.len().max(1) as f64;
+    let avg_price_impact_bps = trades
+        .iter()
+        .map(|t| t.price_impact * 10_000.0)
+        .sum::<f64>()
+        / count;
+    let avg_naive_gap_bps = trades
+        .iter()
+        .map(PaperFillReport::naive_gap_bps)
+        .sum::<f64>()
+        / count;
+    let modeled_cost_vs_naive = trades
+        .iter()
+        .map(|t| t.naive_gap_bps() / 10_000.0 * t.naive_price * t.quantity)
+        .sum();
+
+    PaperFillComparison {
+        trades,
+        avg_price_impact_bps,
+        avg_naive_gap_bps,
+        modeled_cost_vs_naive,
+    }
+}
+
+/// Impact of swapping `order_value_usd` through a constant-product pool whose two
+/// reserves together hold `liquidity_usd`.
+pub fn pool_price_impact(liquidity_usd: f64, order_value_usd: f64, side: OrderSide) -> Option<f64> {
+    if liquidity_usd <= 0.0 || order_value_usd <= 0.0 {
+        return None;
+    }
+    let reserve = liquidity_usd / 2.0;
+    Some(match side {
+        // Average price rises by (x + v) / x on the way in
+        OrderSide::Buy => order_value_usd / reserve,
+        // and falls by y / (y + v) on the way out
+        OrderSide::Sell => order_value_usd / (reserve + order_value_usd),
+    })
+}
+
+/// Models a fill at `market_price`. `fallback_slippage` is the naive size-tier
+/// slippage, used as the impact when there is no depth data.
+pub fn model_paper_fill(
+    config: &PaperFillModelConfig,
+    side: OrderSide,
+    market_price: f64,
+    quantity: f64,
+    depth: &PaperFillDepth,
+    fallback_slippage: f64,
+    rng: &mut impl Rng,
+) -> Result<PaperModeledFill, String> {
+    let order_value = market_price * quantity;
+    let book_impact = depth
+        .book
+        .as_ref()
+        .filter(|estimate| estimate.fully_filled)
+        .map(|estimate| estimate.slippage_percent.max(0.0) / 100.0);
+    let pool_impact = depth
+        .pool_liquidity_usd
+        .and_then(|liquidity| pool_price_impact(liquidity, order_value, side));
+
+    let (source, impact) = match (book_impact, pool_impact) {
+        (Some(impact), _) => (PaperFillLiquiditySource::OrderBook, impact),
+        (None, Some(impact)) => (PaperFillLiquiditySource::Pool, impact),
+        (None, None) => (PaperFillLiquiditySource::SizeTier, fallback_slippage),
+    };
+    if impact > config.max_price_impact {
+        return Err(format!(
+            "Modeled price impact {:.1}% exceeds the {:.1}% limit for this pool",
+            impact * 100.0,
+            config.max_price_impact * 100.0
+        ));
+    }
+
+    let spread = config.half_spread_bps.max(0.0) / 10_000.0;
+    let latency_ms = if config.max_latency_ms > config.min_latency_ms {
+        rng.gen_range(config.min_latency_ms..=config.max_latency_ms)
+    } else {
+        config.min_latency_ms
+    };
+    // Drift while the order is in flight can go either way
+    let drift_scale =
+        config.volatility_bps_per_sec.max(0.0) / 10_000.0 * (latency_ms as f64 / 1_000.0).sqrt();
+    let latency_drift = if drift_scale > 0.0 {
+        rng.gen_range(-drift_scale..=drift_scale)
+    } else {
+        0.0
+    };
+
+    let drifted = market_price * (1.0 + latency_drift);
+    let price = match side {
+        OrderSide::Buy => drifted * (1.0 + spread + impact),
+        OrderSide::Sell => drifted * (1.0 - spread - impact).max(0.0),
+    };
+    Ok(PaperModeledFill {
+        price,
+        source,
+        price_impact: impact,
+        spread,
+        latency_ms,
+        latency_drift,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn no_jitter() -> PaperFillModelConfig {
+        PaperFillModelConfig {
+            volatility_bps_per_sec: 0.0,
+            min_latency_ms: 200,
+            max_latency_ms: 200,
+            ..PaperFillModelConfig::default()
+        }
+    }
+
+    #[test]
+    fn pool_impact_follows_constant_product() {
+        // $1k into a $100k pool moves the average price 2% on the way in
+        let buy = pool_price_impact(100_000.0, 1_000.0, OrderSide::Buy).unwrap();
+        assert!((buy - 0.02).abs() < 1e-12);
+        let sell = pool_price_impact(100_000.0, 1_000.0, OrderSide::Sell).unwrap();
+        assert!((sell - 1_000.0 / 51_000.0).abs() < 1e-12);
+        assert!(pool_price_impact(0.0, 1_000.0, OrderSide::Buy).is_none());
+    }
+
+    #[test]
+    fn modeled_fill_adds_spread_and_pool_impact() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let depth = PaperFillDepth {
+            book: None,
+            pool_liquidity_usd: Some(200_000.0),
+        };
+
+        let fill = model_paper_fill(
+            &no_jitter(),
+            OrderSide::Buy,
+            10.0,
+            100.0,
+            &depth,
+            0.002,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(fill.source, PaperFillLiquiditySource::Pool);
+        assert!((fill.price_impact - 0.01).abs() < 1e-12);
+        assert_eq!((fill.latency_ms, fill.latency_drift), (200, 0.0));
+        assert!((fill.price - 10.0 * 1.0105).abs() < 1e-9);
+
+        let fill = model_paper_fill(
+            &no_jitter(),
+            OrderSide::Sell,
+            10.0,
+            100.0,
+            &PaperFillDepth::default(),
+            0.002,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(fill.source, PaperFillLiquiditySource::SizeTier);
+        assert!((fill.price - 10.0 * (1.0 - 0.0025)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn thin_pools_reject_and_jitter_stays_bounded() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let thin = PaperFillDepth {
+            book: None,
+            pool_liquidity_usd: Some(1_000.0),
+        };
+        assert!(model_paper_fill(
+            &PaperFillModelConfig::default(),
+            OrderSide::Buy,
+            1.0,
+            800.0,
+            &thin,
+            0.0,
+            &mut rng
+        )
+        .is_err());
+
+        let config = PaperFillModelConfig::default();
+        for _ in 0..100 {
+            let fill = model_paper_fill(
+                &config,
+                OrderSide::Buy,
+                1.0,
+                1.0,
+                &PaperFillDepth::default(),
+                0.0,
+                &mut rng,
+            )
+            .unwrap();
+            assert!((config.min_latency_ms..=config.max_latency_ms).contains(&fill.latency_ms));
+            assert!(fill.latency_drift.abs() <= 4.0 / 10_000.0 * (0.9f64).sqrt() + 1e-12);
+        }
+    }
+}
@@ -1,18 +1,24 @@
 use chrono::{DateTime, Utc};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
-use sqlx::{FromRow, Pool, Sqlite, SqlitePool};
+use sqlx::{FromRow, Pool, Row, Sqlite, SqlitePool};
 use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
 use std::sync::Arc;
-use tauri::AppHandle;
+use tauri::{AppHandle, Manager};
 use tokio::sync::{OnceCell, RwLock};
 use uuid::Uuid;
 
+use crate::market::order_book::{SharedOrderBookManager, SlippageEstimate};
+use crate::trading::paper_fill_model::{
+    model_paper_fill, summarize_paper_fills, PaperFillComparison, PaperFillDepth,
+    PaperFillLiquiditySource, PaperFillModelConfig, PaperFillReport,
+};
 use crate::trading::types::{OrderSide, OrderType};
 
 const DEFAULT_INITIAL_BALANCE: f64 = 10_000.0;
 const MINIMUM_QUANTITY: f64 = 1e-9;
+const DEFAULT_FILL_REPORT_LIMIT: i64 = 100;
 
 // ============================================================================
 // Types and Structs
@@ -85,6 +91,9 @@ pub struct ExecutePaperTradeRequest {
     pub limit_price: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stop_price: Option<f64>,
+    /// USD liquidity of the pool the trade would route through.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_liquidity_usd: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,6 +110,8 @@ pub struct PaperTradeResult {
     pub account: PaperAccount,
     pub position: Option<PaperPosition>,
     pub fees: FeeBreakdown,
+    /// Present when the fill model priced the trade.
+    pub fill: Option<PaperFillReport>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -240,6 +251,25 @@ impl PaperTradingDatabase {
         .execute(&self.pool)
         .await?;
 
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS paper_fill_models (
+                trade_id TEXT PRIMARY KEY,
+                naive_price REAL NOT NULL,
+                market_price REAL NOT NULL,
+                price_impact REAL NOT NULL,
+                spread REAL NOT NULL,
+                latency_ms INTEGER NOT NULL,
+                latency_drift REAL NOT NULL,
+                liquidity_source TEXT NOT NULL,
+                pool_liquidity_usd REAL,
+                FOREIGN KEY (trade_id) REFERENCES paper_trades(id) ON DELETE CASCADE
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
         sqlx::query(
             r#"
             CREATE INDEX IF NOT EXISTS idx_paper_trades_account ON paper_trades(account_id);
@@ -357,6 +387,75 @@ impl PaperTradingDatabase {
         Ok(())
     }
 
+    pub async fn record_fill_model(&self, report: &PaperFillReport) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO paper_fill_models (
+                trade_id, naive_price, market_price, price_impact, spread,
+                latency_ms, latency_drift, liquidity_source, pool_liquidity_usd
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "#,
+        )
+        .bind(&report.trade_id)
+        .bind(report.naive_price)
+        .bind(report.market_price)
+        .bind(report.price_impact)
+        .bind(report.spread)
+        .bind(report.latency_ms as i64)
+        .bind(report.latency_drift)
+        .bind(report.liquidity_source.as_str())
+        .bind(report.pool_liquidity_usd)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_fill_models(
+        &self,
+        account_id: &str,
+        limit: i64,
+    ) -> Result<Vec<PaperFillReport>, sqlx::Error> {
+        let rows = sqlx::query(
+            r#"
+            SELECT t.id, t.symbol, t.side, t.quantity, t.price, m.naive_price, m.market_price,
+                   m.price_impact, m.spread, m.latency_ms, m.latency_drift,
+                   m.liquidity_source, m.pool_liquidity_usd
+            FROM paper_fill_models m
+            JOIN paper_trades t ON t.id = m.trade_id
+            WHERE t.account_id = ?1
+            ORDER BY t.timestamp DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(account_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let source: String = row.try_get("liquidity_source")?;
+                let latency_ms: i64 = row.try_get("latency_ms")?;
+                Ok(PaperFillReport {
+                    trade_id: row.try_get("id")?,
+                    symbol: row.try_get("symbol")?,
+                    side: row.try_get("side")?,
+                    quantity: row.try_get("quantity")?,
+                    market_price: row.try_get("market_price")?,
+                    naive_price: row.try_get("naive_price")?,
+                    modeled_price: row.try_get("price")?,
+                    price_impact: row.try_get("price_impact")?,
+                    spread: row.try_get("spread")?,
+                    latency_ms: latency_ms.max(0) as u64,
+                    latency_drift: row.try_get("latency_drift")?,
+                    liquidity_source: PaperFillLiquiditySource::parse(&source),
+                    pool_liquidity_usd: row.try_get("pool_liquidity_usd")?,
+                })
+            })
+            .collect()
+    }
+
     pub async fn get_trade_history(&self, account_id: &str) -> Result<Vec<PaperTrade>, sqlx::Error> {
         sqlx::query_as::<_, PaperTrade>(
             "SELECT * FROM paper_trades WHERE account_id = ?1 ORDER BY timestamp ASC",
@@ -587,6 +686,7 @@ pub struct PaperTradingManager {
     db: SharedPaperTradingDatabase,
     slippage_config: SlippageConfig,
     fee_config: FeeConfig,
+    fill_model: PaperFillModelConfig,
     current_prices: Arc<RwLock<HashMap<String, f64>>>,
 }
 
@@ -604,10 +704,16 @@ impl PaperTradingManager {
             db,
             slippage_config,
             fee_config,
+            fill_model: PaperFillModelConfig::default(),
             current_prices: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    pub fn with_fill_model(mut self, fill_model: PaperFillModelConfig) -> Self {
+        self.fill_model = fill_model;
+        self
+    }
+
     fn validate_request(&self, request: &ExecutePaperTradeRequest) -> Result<(), String> {
         if request.quantity <= 0.0 {
             return Err("Quantity must be greater than zero".to_string());
@@ -707,18 +813,55 @@ impl PaperTradingManager {
     pub async fn execute_trade(
         &self,
         request: ExecutePaperTradeRequest,
+    ) -> Result<PaperTradeResult, String> {
+        self.execute_trade_with_book(request, None).await
+    }
+
+    /// Executes with `book` as the depth estimate when the symbol has a tracked
+    /// order book; otherwise the request's pool liquidity is used.
+    pub async fn execute_trade_with_book(
+        &self,
+        request: ExecutePaperTradeRequest,
+        book: Option<SlippageEstimate>,
     ) -> Result<PaperTradeResult, String> {
         self.validate_request(&request)?;
 
+        let order_value = request.quantity * request.price;
+        let naive_slippage = self.calculate_slippage(order_value);
+        let naive_price = self.execution_price(request.price, naive_slippage, request.side);
+
+        let depth = PaperFillDepth {
+            book,
+            pool_liquidity_usd: request.pool_liquidity_usd,
+        };
+        let modeled = if self.fill_model.enabled {
+            let mut rng = rand::thread_rng();
+            Some(model_paper_fill(
+                &self.fill_model,
+                request.side,
+                request.price,
+                request.quantity,
+                &depth,
+                naive_slippage,
+                &mut rng,
+            )?)
+        } else {
+            None
+        };
+        let (execution_price, slippage) = match &modeled {
+            Some(fill) => (
+                fill.price,
+                ((fill.price - request.price) / request.price).abs(),
+            ),
+            None => (naive_price, naive_slippage),
+        };
+
         let db_read = self.db.read().await;
         let mut account = db_read
             .get_or_create_account(DEFAULT_INITIAL_BALANCE)
             .await
             .map_err(|e| format!("Failed to load paper account: {e}"))?;
 
-        let order_value = request.quantity * request.price;
-        let slippage = self.calculate_slippage(order_value);
-        let execution_price = self.execution_price(request.price, slippage, request.side);
         let executed_value = request.quantity * execution_price;
 
         let trading_fee = self.calculate_trading_fee(executed_value);
@@ -775,6 +918,27 @@ impl PaperTradingManager {
             .await
             .map_err(|e| format!("Failed to store paper trade: {e}"))?;
 
+        let fill = modeled.map(|fill| PaperFillReport {
+            trade_id: trade.id.clone(),
+            symbol: trade.symbol.clone(),
+            side: trade.side.clone(),
+            quantity: trade.quantity,
+            market_price: request.price,
+            naive_price,
+            modeled_price: fill.price,
+            price_impact: fill.price_impact,
+            spread: fill.spread,
+            latency_ms: fill.latency_ms,
+            latency_drift: fill.latency_drift,
+            liquidity_source: fill.source,
+            pool_liquidity_usd: request.pool_liquidity_usd,
+        });
+        if let Some(report) = &fill {
+            if let Err(e) = db_read.record_fill_model(report).await {
+                eprintln!("Failed to store paper fill model: {e}");
+            }
+        }
+
         let position = self
             .update_position(&db_read, &account.id, &request, execution_price)
             .await?;
@@ -784,6 +948,7 @@ impl PaperTradingManager {
             trade,
             account,
             position,
+            fill,
         })
     }
 
@@ -940,6 +1105,20 @@ impl PaperTradingManager {
             .map_err(|e| format!("Failed to load paper performance: {e}"))
     }
 
+    pub async fn get_fill_comparison(&self, limit: i64) -> Result<PaperFillComparison, String> {
+        let db_read = self.db.read().await;
+        let account = db_read
+            .get_or_create_account(DEFAULT_INITIAL_BALANCE)
+            .await
+            .map_err(|e| format!("Failed to load paper account: {e}"))?;
+
+        db_read
+            .get_fill_models(&account.id, limit)
+            .await
+            .map(summarize_paper_fills)
+            .map_err(|e| format!("Failed to load paper fill models: {e}"))
+    }
+
     pub async fn update_position_prices(&self, symbol: &str, price: f64) -> Result<(), String> {
         self.current_prices
             .write()
@@ -1037,9 +1216,25 @@ pub async fn reset_paper_account(initial_balance: Option<f64>) -> Result<PaperAc
 #[tauri::command]
 pub async fn execute_paper_trade(
     request: ExecutePaperTradeRequest,
+    app: AppHandle,
 ) -> Result<PaperTradeResult, String> {
     let manager = require_state()?;
-    manager.execute_trade(request).await
+    let book = match app.try_state::<SharedOrderBookManager>() {
+        Some(books) => books
+            .read()
+            .await
+            .estimate_slippage(&request.symbol, request.side, request.quantity),
+        None => None,
+    };
+    manager.execute_trade_with_book(request, book).await
+}
+
+#[tauri::command]
+pub async fn get_paper_fill_comparison(limit: Option<i64>) -> Result<PaperFillComparison, String> {
+    let manager = require_state()?;
+    manager
+        .get_fill_comparison(limit.unwrap_or(DEFAULT_FILL_REPORT_LIMIT).clamp(1, 1_000))
+        .await
 }
 
 #[tauri::command]
@@ -1124,6 +1319,7 @@ mod tests {
             price: 100.0,
             limit_price: None,
             stop_price: None,
+            pool_liquidity_usd: None,
         };
 
         let result = manager
@@ -1165,6 +1361,7 @@ mod tests {
             price: 100.0,
             limit_price: None,
             stop_price: None,
+            pool_liquidity_usd: None,
         };
 
         let result = manager
@@ -1191,6 +1388,7 @@ mod tests {
             price: 100.0,
             limit_price: None,
             stop_price: None,
+            pool_liquidity_usd: None,
         };
         manager
             .execute_trade(buy_request)
@@ -1205,6 +1403,7 @@ mod tests {
             price: 110.0,
             limit_price: None,
             stop_price: None,
+            pool_liquidity_usd: None,
         };
         let sell_result = manager
             .execute_trade(sell_request)
@@ -1231,6 +1430,7 @@ mod tests {
             price: 50.0,
             limit_price: None,
             stop_price: None,
+            pool_liquidity_usd: None,
         };
         manager
             .execute_trade(buy_request)
@@ -1245,6 +1445,7 @@ mod tests {
             price: 60.0,
             limit_price: None,
             stop_price: None,
+            pool_liquidity_usd: None,
         };
         manager
             .execute_trade(sell_request)
@@ -1259,4 +1460,40 @@ mod tests {
         assert_eq!(performance.total_trades, 2);
         assert!(performance.total_pnl > 0.0);
     }
+
+    #[tokio::test]
+    async fn test_pool_liquidity_fill_model() {
+        let manager = create_manager_with_configs(deterministic_slippage_config(), FeeConfig::default())
+            .await
+            .with_fill_model(PaperFillModelConfig {
+                volatility_bps_per_sec: 0.0,
+                ..Default::default()
+            });
+
+        let request = ExecutePaperTradeRequest {
+            symbol: "BONK".to_string(),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: 1_000.0,
+            price: 1.0,
+            limit_price: None,
+            stop_price: None,
+            pool_liquidity_usd: Some(100_000.0),
+        };
+        let result = manager
+            .execute_trade(request)
+            .await
+            .expect("trade execution");
+
+        let fill = result.fill.expect("fill report");
+        assert_eq!(fill.liquidity_source, PaperFillLiquiditySource::Pool);
+        assert!((fill.modeled_price - 1.0205).abs() < 1e-9);
+        assert!(fill.modeled_price > fill.naive_price);
+        assert!((result.trade.price - fill.modeled_price).abs() < 1e-12);
+
+        let comparison = manager.get_fill_comparison(10).await.expect("comparison");
+        assert_eq!(comparison.trades.len(), 1);
+        assert!(comparison.avg_naive_gap_bps > 0.0);
+        assert!(comparison.modeled_cost_vs_naive > 0.0);
+    }
 }
@@ -0,0 +1,359 @@
+use crate::trading::types::{CreateOrderRequest, OrderSide};
+use crate::wallet::operations::{TokenBalance, WalletOperationsManager};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager, State};
+
+const RISK_POLICY_FILE: &str = "risk_per_trade.json";
+const USD_STABLECOINS: &[&str] = &["USDC", "USDT", "PYUSD", "USDH", "UXD"];
+/// Older balances may no longer reflect the wallet, so risk is not sized on them.
+const MAX_BALANCE_AGE_MINUTES: i64 = 5;
+
+/// How much of the portfolio a single trade may put at risk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskPerTradePolicy {
+    /// Risk used when the calculator is not given one.
+    pub default_risk_percent: f64,
+    pub max_risk_percent: f64,
+    /// Reject buy orders whose risk exceeds `max_risk_percent` of equity.
+    pub enforce_on_orders: bool,
+}
+
+impl Default for RiskPerTradePolicy {
+    fn default() -> Self {
+        Self {
+            default_risk_percent: 1.0,
+            max_risk_percent: 2.0,
+            enforce_on_orders: false,
+        }
+    }
+}
+
+impl RiskPerTradePolicy {
+    fn validate(&self) -> Result<(), String> {
+        let valid = |value: f64| value.is_finite() && value > 0.0 && value <= 100.0;
+        if !valid(self.default_risk_percent) || !valid(self.max_risk_percent) {
+            return Err("Risk per trade must be between 0 and 100%".into());
+        }
+        if self.default_risk_percent > self.max_risk_percent {
+            return Err("Default risk cannot exceed the maximum risk per trade".into());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionSizeSuggestion {
+    pub wallet_address: String,
+    pub equity_usd: f64,
+    pub risk_percent: f64,
+    pub risk_amount_usd: f64,
+    pub stop_distance_percent: f64,
+    pub quantity: f64,
+    pub notional_usd: f64,
+    /// The requested risk was above the policy maximum and was lowered.
+    pub capped_by_policy: bool,
+    /// The stop is so tight the risk-based size would exceed total equity.
+    pub limited_by_equity: bool,
+}
+
+/// Sizes a position so that a stop-out loses `risk_percent` of `equity_usd`.
+pub fn size_position(
+    equity_usd: f64,
+    entry: f64,
+    stop: f64,
+    risk_percent: f64,
+) -> Result<(f64, f64, bool), String> {
+    if !(entry.is_finite() && entry > 0.0) || !(stop.is_finite() && stop > 0.0) {
+        return Err("Entry and stop must be positive prices".into());
+    }
+    if stop >= entry {
+        return Err("Stop must be below entry for a long position".into());
+    }
+    if equity_usd <= 0.0 {
+        return Err("Wallet has no equity to size against".into());
+    }
+
+    let risk_amount = equity_usd * risk_percent / 100.0;
+    let quantity = risk_amount / (entry - stop);
+    if quantity * entry > equity_usd {
+        return Ok((equity_usd / entry, equity_usd, true));
+    }
+    Ok((quantity, quantity * entry, false))
+}
+
+/// Fraction of a buy's notional lost if its stop is hit. A buy with no stop
+/// can lose all of it.
+pub fn order_risk_fraction(entry: f64, stop: Option<f64>) -> f64 {
+    match stop {
+        Some(stop) if entry > 0.0 => ((entry - stop) / entry).clamp(0.0, 1.0),
+        _ => 1.0,
+    }
+}
+
+#[derive(Debug)]
+pub struct RiskPolicyState {
+    policy: RiskPerTradePolicy,
+    path: Option<PathBuf>,
+}
+
+impl RiskPolicyState {
+    fn load(path: Option<PathBuf>) -> Self {
+        let policy = path
+            .as_ref()
+            .filter(|path| path.exists())
+            .and_then(|path| match fs::read_to_string(path) {
+                Ok(data) => serde_json::from_str(&data).ok(),
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to read risk-per-trade policy");
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self { policy, path }
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let json = serde_json::to_string_pretty(&self.policy).map_err(|e| e.to_string())?;
+        fs::write(path, json).map_err(|e| e.to_string())
+    }
+}
+
+pub type SharedRiskPolicyState = Arc<Mutex<RiskPolicyState>>;
+
+pub fn register_risk_policy_state(app: &tauri::App) {
+    let path = app.path_resolver().app_data_dir().map(|dir| {
+        if let Err(err) = fs::create_dir_all(&dir) {
+            tracing::warn!(error = %err, "failed to create app data directory");
+        }
+        dir.join(RISK_POLICY_FILE)
+    });
+    let state: SharedRiskPolicyState = Arc::new(Mutex::new(RiskPolicyState::load(path)));
+    app.manage(state);
+}
+
+/// Wallet equity from the live balance cache.
+fn wallet_equity(
+    operations: &WalletOperationsManager,
+    wallet_address: &str,
+) -> Result<f64, String> {
+    equity_from_balances(
+        operations.token_balances_with_age(wallet_address),
+        wallet_address,
+        Utc::now(),
+    )
+}
+
+/// Fails closed: missing, stale or unpriced balances never size a trade.
+fn equity_from_balances(
+    balances: Option<(Vec<TokenBalance>, DateTime<Utc>)>,
+    wallet_address: &str,
+    now: DateTime<Utc>,
+) -> Result<f64, String> {
+    let (balances, fetched_at) = balances.ok_or_else(|| {
+        format!(
+            "No balances loaded for {}; refresh the wallet before sizing trades",
+            wallet_address
+        )
+    })?;
+    if now - fetched_at > Duration::minutes(MAX_BALANCE_AGE_MINUTES) {
+        return Err(format!(
+            "Balances for {} are more than {} minutes old; refresh the wallet before sizing trades",
+            wallet_address, MAX_BALANCE_AGE_MINUTES
+        ));
+    }
+
+    let equity: f64 = balances.iter().map(|token| token.usd_value.max(0.0)).sum();
+    if equity <= 0.0 {
+        return Err(format!(
+            "No priced holdings in {}; cannot size trades against zero equity",
+            wallet_address
+        ));
+    }
+    Ok(equity)
+}
+
+/// Rejects a buy whose loss at its stop would exceed the policy maximum. Only
+/// applies when enforcement is switched on; sells never add risk.
+pub fn enforce_risk_per_trade(
+    app: &AppHandle,
+    request: &CreateOrderRequest,
+    prices: &HashMap<String, f64>,
+) -> Result<(), String> {
+    if request.side != OrderSide::Buy {
+        return Ok(());
+    }
+    let Some(state) = app.try_state::<SharedRiskPolicyState>() else {
+        return Ok(());
+    };
+    let policy = state.lock().map_err(|e| e.to_string())?.policy.clone();
+    if !policy.enforce_on_orders {
+        return Ok(());
+    }
+
+    let operations = app
+        .try_state::<WalletOperationsManager>()
+        .ok_or_else(|| "Wallet balances unavailable; cannot check trade risk".to_string())?;
+    let equity = wallet_equity(&operations, &request.wallet_address)?;
+
+    let quote_price = prices
+        .get(&request.input_symbol)
+        .copied()
+        .or_else(|| {
+            USD_STABLECOINS
+                .contains(&request.input_symbol.as_str())
+                .then_some(1.0)
+        })
+        .ok_or_else(|| {
+            format!(
+                "No price for {}; cannot check trade risk",
+                request.input_symbol
+            )
+        })?;
+    let entry = request
+        .limit_price
+        .or_else(|| prices.get(&request.output_symbol).copied())
+        .ok_or_else(|| {
+            format!(
+                "No price for {}; cannot check trade risk",
+                request.output_symbol
+            )
+        })?;
+
+    let notional_usd = request.amount * quote_price;
+    let risk_usd = notional_usd * order_risk_fraction(entry, request.stop_loss_price);
+    let allowed_usd = equity * policy.max_risk_percent / 100.0;
+    if risk_usd > allowed_usd {
+        return Err(format!(
+            "Order risks ${:.2} ({:.2}% of equity), above the {:.2}% per-trade limit",
+            risk_usd,
+            risk_usd / equity * 100.0,
+            policy.max_risk_percent
+        ));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_risk_per_trade_policy(
+    state: State<'_, SharedRiskPolicyState>,
+) -> Result<RiskPerTradePolicy, String> {
+    let state = state.lock().map_err(|e| e.to_string())?;
+    Ok(state.policy.clone())
+}
+
+#[tauri::command]
+pub async fn update_risk_per_trade_policy(
+    policy: RiskPerTradePolicy,
+    state: State<'_, SharedRiskPolicyState>,
+) -> Result<RiskPerTradePolicy, String> {
+    policy.validate()?;
+    let mut state = state.lock().map_err(|e| e.to_string())?;
+    let previous = std::mem::replace(&mut state.policy, policy.clone());
+    if let Err(err) = state.save() {
+        state.policy = previous;
+        return Err(err);
+    }
+    Ok(policy)
+}
+
+#[tauri::command]
+pub async fn calculate_position_size(
+    entry: f64,
+    stop: f64,
+    risk_percent: Option<f64>,
+    wallet: String,
+    state: State<'_, SharedRiskPolicyState>,
+    operations: State<'_, WalletOperationsManager>,
+) -> Result<PositionSizeSuggestion, String> {
+    let policy = state.lock().map_err(|e| e.to_string())?.policy.clone();
+    let requested = risk_percent.unwrap_or(policy.default_risk_percent);
+    if !(requested.is_finite() && requested > 0.0) {
+        return Err("Risk percent must be positive".into());
+    }
+    let risk_percent = requested.min(policy.max_risk_percent);
+
+    let equity_usd = wallet_equity(&operations, &wallet)?;
+    let (quantity, notional_usd, limited_by_equity) =
+        size_position(equity_usd, entry, stop, risk_percent)?;
+
+    Ok(PositionSizeSuggestion {
+        wallet_address: wallet,
+        equity_usd,
+        risk_percent,
+        risk_amount_usd: quantity * (entry - stop),
+        stop_distance_percent: (entry - stop) / entry * 100.0,
+        quantity,
+        notional_usd,
+        capped_by_policy: requested > policy.max_risk_percent,
+        limited_by_equity,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_so_a_stop_out_loses_the_risk_budget() {
+        let (quantity, notional, limited) = size_position(10_000.0, 2.0, 1.8, 1.0).unwrap();
+        assert!((quantity - 500.0).abs() < 1e-9);
+        assert!((notional - 1_000.0).abs() < 1e-9);
+        assert!(!limited);
+        assert!(size_position(10_000.0, 2.0, 2.5, 1.0).is_err());
+    }
+
+    #[test]
+    fn tight_stops_are_limited_to_equity() {
+        let (quantity, notional, limited) = size_position(1_000.0, 10.0, 9.99, 2.0).unwrap();
+        assert!(limited);
+        assert!((notional - 1_000.0).abs() < 1e-9);
+        assert!((quantity - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn buys_without_a_stop_risk_their_full_notional() {
+        assert_eq!(order_risk_fraction(2.0, None), 1.0);
+        assert!((order_risk_fraction(2.0, Some(1.5)) - 0.25).abs() < 1e-12);
+        assert_eq!(order_risk_fraction(2.0, Some(3.0)), 0.0);
+
+        let policy = RiskPerTradePolicy {
+            default_risk_percent: 3.0,
+            ..RiskPerTradePolicy::default()
+        };
+        assert!(policy.validate().is_err());
+    }
+
+    #[test]
+    fn equity_fails_closed_without_fresh_priced_balances() {
+        let now = Utc::now();
+        let token = |usd_value| TokenBalance {
+            mint: "mint".to_string(),
+            symbol: "TKN".to_string(),
+            name: "Token".to_string(),
+            balance: 1.0,
+            decimals: 6,
+            usd_value,
+            change_24h: 0.0,
+            logo_uri: None,
+            last_updated: now,
+        };
+
+        assert!(equity_from_balances(None, "wallet", now).is_err());
+        assert!(equity_from_balances(Some((vec![token(0.0)], now)), "wallet", now).is_err());
+        let stale = now - Duration::minutes(MAX_BALANCE_AGE_MINUTES + 1);
+        assert!(equity_from_balances(Some((vec![token(50.0)], stale)), "wallet", now).is_err());
+
+        let equity =
+            equity_from_balances(Some((vec![token(50.0), token(25.0)], now)), "wallet", now);
+        assert_eq!(equity, Ok(75.0));
+    }
+}
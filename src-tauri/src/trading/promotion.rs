@@ -0,0 +1,565 @@
+use super::auto_trading::{SharedAutoTradingEngine, StrategyExecution, TradingMode};
+use crate::auth::two_factor::TwoFactorManager;
+use crate::security::activity_log::{ActivityAction, ActivityLogger};
+use crate::security::keystore::Keystore;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::{Manager, State};
+use uuid::Uuid;
+
+const PROMOTION_GATES_FILE: &str = "promotion_gates.json";
+
+/// Requirements a paper strategy must meet before it can trade live.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromotionGates {
+    pub min_duration_hours: f64,
+    pub min_trades: u32,
+    pub max_drawdown_percent: f64,
+}
+
+impl Default for PromotionGates {
+    fn default() -> Self {
+        Self {
+            min_duration_hours: 72.0,
+            min_trades: 20,
+            max_drawdown_percent: 15.0,
+        }
+    }
+}
+
+impl PromotionGates {
+    fn validate(&self) -> Result<(), String> {
+        if !self.min_duration_hours.is_finite() || self.min_duration_hours < 0.0 {
+            return Err("Minimum duration must be zero or more hours".into());
+        }
+        if !self.max_drawdown_percent.is_finite()
+            || self.max_drawdown_percent <= 0.0
+            || self.max_drawdown_percent > 100.0
+        {
+            return Err("Maximum drawdown must be between 0 and 100 percent".into());
+        }
+        Ok(())
+    }
+}
+
+/// Paper performance the gates are evaluated against.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackRecord {
+    pub started_at: DateTime<Utc>,
+    pub trades: u32,
+    pub max_drawdown_percent: f64,
+    pub total_pnl: f64,
+}
+
+impl From<&StrategyExecution> for TrackRecord {
+    fn from(execution: &StrategyExecution) -> Self {
+        Self {
+            started_at: execution.started_at,
+            trades: execution.trades_executed,
+            max_drawdown_percent: execution.max_drawdown,
+            total_pnl: execution.total_pnl,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromotionGate {
+    MinDuration,
+    MinTrades,
+    MaxDrawdown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateResult {
+    pub gate: PromotionGate,
+    pub required: f64,
+    pub actual: f64,
+    pub passed: bool,
+}
+
+pub fn evaluate_gates(
+    record: &TrackRecord,
+    gates: &PromotionGates,
+    now: DateTime<Utc>,
+) -> Vec<GateResult> {
+    let hours = (now - record.started_at).num_seconds().max(0) as f64 / 3600.0;
+    vec![
+        GateResult {
+            gate: PromotionGate::MinDuration,
+            required: gates.min_duration_hours,
+            actual: hours,
+            passed: hours >= gates.min_duration_hours,
+        },
+        GateResult {
+            gate: PromotionGate::MinTrades,
+            required: gates.min_trades as f64,
+            actual: record.trades as f64,
+            passed: record.trades >= gates.min_trades,
+        },
+        GateResult {
+            gate: PromotionGate::MaxDrawdown,
+            required: gates.max_drawdown_percent,
+            actual: record.max_drawdown_percent,
+            passed: record.max_drawdown_percent <= gates.max_drawdown_percent,
+        },
+    ]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromotionStatus {
+    /// Gates passed; waiting for a 2FA-confirmed approval.
+    Pending,
+    /// One or more gates failed when the request was made.
+    Blocked,
+    Approved,
+    Rejected,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromotionRequest {
+    pub id: String,
+    pub strategy_id: String,
+    pub strategy_name: String,
+    pub status: PromotionStatus,
+    pub gates: PromotionGates,
+    pub track_record: TrackRecord,
+    pub results: Vec<GateResult>,
+    pub requested_at: DateTime<Utc>,
+    pub decided_at: Option<DateTime<Utc>>,
+    pub note: Option<String>,
+}
+
+impl PromotionRequest {
+    fn blocking_gates(&self) -> Vec<PromotionGate> {
+        self.results
+            .iter()
+            .filter(|result| !result.passed)
+            .map(|result| result.gate)
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct PromotionState {
+    gates: PromotionGates,
+    requests: Vec<PromotionRequest>,
+    gates_path: Option<PathBuf>,
+}
+
+impl PromotionState {
+    fn load(gates_path: Option<PathBuf>) -> Self {
+        let gates = gates_path
+            .as_ref()
+            .filter(|path| path.exists())
+            .and_then(|path| match fs::read_to_string(path) {
+                Ok(data) => serde_json::from_str(&data).ok(),
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to read promotion gates");
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            gates,
+            requests: Vec::new(),
+            gates_path,
+        }
+    }
+
+    fn save_gates(&self) -> Result<(), String> {
+        let Some(path) = &self.gates_path else {
+            return Ok(());
+        };
+        let json = serde_json::to_string_pretty(&self.gates).map_err(|e| e.to_string())?;
+        fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    fn request_mut(&mut self, id: &str) -> Result<&mut PromotionRequest, String> {
+        self.requests
+            .iter_mut()
+            .find(|request| request.id == id)
+            .ok_or_else(|| format!("Promotion request {} not found", id))
+    }
+}
+
+pub type SharedPromotionState = Arc<Mutex<PromotionState>>;
+
+pub fn register_promotion_state(app: &tauri::App) {
+    let gates_path = app.path_resolver().app_data_dir().map(|dir| {
+        if let Err(err) = fs::create_dir_all(&dir) {
+            tracing::warn!(error = %err, "failed to create app data directory");
+        }
+        dir.join(PROMOTION_GATES_FILE)
+    });
+    let state: SharedPromotionState = Arc::new(Mutex::new(PromotionState::load(gates_path)));
+    app.manage(state);
+}
+
+const GATES_AUDIT_SUBJECT: &str = "promotion_gates";
+
+fn audit_subject(strategy_id: &str) -> String {
+    format!("strategy:{}", strategy_id)
+}
+
+async fn audit(logger: &ActivityLogger, subject: &str, details: serde_json::Value, success: bool) {
+    if let Err(err) = logger
+        .log_activity(subject, ActivityAction::Promote, details, success, None)
+        .await
+    {
+        tracing::warn!(error = %err, subject, "failed to audit strategy promotion");
+    }
+}
+
+/// Snapshot of a paper strategy's current run, evaluated against the gates.
+fn evaluate_strategy(
+    engine: &SharedAutoTradingEngine,
+    strategy_id: &str,
+    gates: &PromotionGates,
+) -> Result<(String, TrackRecord, Vec<GateResult>), String> {
+    let engine = engine.lock().map_err(|e| e.to_string())?;
+    let strategy = engine
+        .get_strategy(strategy_id)
+        .ok_or_else(|| format!("Strategy {} not found", strategy_id))?;
+    if strategy.mode == TradingMode::Live {
+        return Err(format!("Strategy {} is already live", strategy.name));
+    }
+    let execution = engine
+        .get_execution(strategy_id)
+        .ok_or_else(|| format!("Strategy {} has no paper run to evaluate", strategy.name))?;
+
+    let record = TrackRecord::from(&execution);
+    let results = evaluate_gates(&record, gates, Utc::now());
+    Ok((strategy.name, record, results))
+}
+
+#[tauri::command]
+pub async fn get_promotion_gates(
+    state: State<'_, SharedPromotionState>,
+) -> Result<PromotionGates, String> {
+    let state = state.lock().map_err(|e| e.to_string())?;
+    Ok(state.gates.clone())
+}
+
+#[tauri::command]
+pub async fn update_promotion_gates(
+    gates: PromotionGates,
+    state: State<'_, SharedPromotionState>,
+    logger: State<'_, ActivityLogger>,
+) -> Result<PromotionGates, String> {
+    gates.validate()?;
+    let previous = {
+        let mut state = state.lock().map_err(|e| e.to_string())?;
+        let previous = std::mem::replace(&mut state.gates, gates.clone());
+        if let Err(err) = state.save_gates() {
+            state.gates = previous;
+            return Err(err);
+        }
+        previous
+    };
+
+    audit(
+        &logger,
+        GATES_AUDIT_SUBJECT,
+        json!({ "event": "gates_updated", "previous": previous, "gates": gates }),
+        true,
+    )
+    .await;
+    Ok(gates)
+}
+
+#[tauri::command]
+pub async fn evaluate_strategy_promotion(
+    strategy_id: String,
+    engine: State<'_, SharedAutoTradingEngine>,
+    state: State<'_, SharedPromotionState>,
+) -> Result<Vec<GateResult>, String> {
+    let gates = state.lock().map_err(|e| e.to_string())?.gates.clone();
+    let (_, _, results) = evaluate_strategy(&engine, &strategy_id, &gates)?;
+    Ok(results)
+}
+
+/// Evaluates the gates and files a promotion request. Requests that fail a gate
+/// are kept as blocked so the attempt stays visible in the history.
+#[tauri::command]
+pub async fn request_strategy_promotion(
+    strategy_id: String,
+    engine: State<'_, SharedAutoTradingEngine>,
+    state: State<'_, SharedPromotionState>,
+    logger: State<'_, ActivityLogger>,
+) -> Result<PromotionRequest, String> {
+    let request = {
+        let mut state = state.lock().map_err(|e| e.to_string())?;
+        if state.requests.iter().any(|request| {
+            request.strategy_id == strategy_id && request.status == PromotionStatus::Pending
+        }) {
+            return Err("A promotion request is already pending for this strategy".into());
+        }
+
+        let gates = state.gates.clone();
+        let (strategy_name, track_record, results) =
+            evaluate_strategy(&engine, &strategy_id, &gates)?;
+        let status = if results.iter().all(|result| result.passed) {
+            PromotionStatus::Pending
+        } else {
+            PromotionStatus::Blocked
+        };
+
+        let request = PromotionRequest {
+            id: Uuid::new_v4().to_string(),
+            strategy_id: strategy_id.clone(),
+            strategy_name,
+            status,
+            gates,
+            track_record,
+            results,
+            requested_at: Utc::now(),
+            decided_at: None,
+            note: None,
+        };
+        state.requests.push(request.clone());
+        request
+    };
+
+    audit(
+        &logger,
+        &audit_subject(&strategy_id),
+        json!({
+            "event": "requested",
+            "request_id": request.id,
+            "gates": request.gates,
+            "track_record": request.track_record,
+            "blocked_by": request.blocking_gates(),
+        }),
+        request.status == PromotionStatus::Pending,
+    )
+    .await;
+    Ok(request)
+}
+
+/// Promotes a pending request to live. Requires an enrolled second factor and
+/// re-checks the gates, since the paper run may have moved since the request.
+#[tauri::command]
+pub async fn approve_strategy_promotion(
+    request_id: String,
+    code: String,
+    engine: State<'_, SharedAutoTradingEngine>,
+    state: State<'_, SharedPromotionState>,
+    two_factor: State<'_, TwoFactorManager>,
+    keystore: State<'_, Keystore>,
+    logger: State<'_, ActivityLogger>,
+) -> Result<PromotionRequest, String> {
+    let (strategy_id, gates) = {
+        let mut state = state.lock().map_err(|e| e.to_string())?;
+        let request = state.request_mut(&request_id)?;
+        if request.status != PromotionStatus::Pending {
+            return Err("Only pending promotion requests can be approved".into());
+        }
+        (request.strategy_id.clone(), request.gates.clone())
+    };
+
+    let enrolled = two_factor.status().map_err(|e| e.to_string())?.enrolled;
+    let verified = enrolled
+        && two_factor
+            .verify(&code, &keystore)
+            .map_err(|e| e.to_string())?;
+    if !verified {
+        let reason = if enrolled {
+            "Invalid two-factor code"
+        } else {
+            "Two-factor authentication must be enrolled to promote a strategy to live"
+        };
+        audit(
+            &logger,
+            &audit_subject(&strategy_id),
+            json!({ "event": "approval_denied", "request_id": request_id, "reason": reason }),
+            false,
+        )
+        .await;
+        return Err(reason.to_string());
+    }
+
+    let (track_record, results) = match evaluate_strategy(&engine, &strategy_id, &gates) {
+        Ok((_, track_record, results)) => (track_record, results),
+        Err(err) => {
+            audit(
+                &logger,
+                &audit_subject(&strategy_id),
+                json!({ "event": "approval_failed", "request_id": request_id, "error": err }),
+                false,
+            )
+            .await;
+            return Err(err);
+        }
+    };
+    let passed = results.iter().all(|result| result.passed);
+    if passed {
+        let mut engine = engine.lock().map_err(|e| e.to_string())?;
+        engine.set_mode(&strategy_id, TradingMode::Live)?;
+    }
+
+    let request = {
+        let mut state = state.lock().map_err(|e| e.to_string())?;
+        let request = state.request_mut(&request_id)?;
+        request.track_record = track_record;
+        request.results = results;
+        request.decided_at = Some(Utc::now());
+        if passed {
+            request.status = PromotionStatus::Approved;
+        } else {
+            request.status = PromotionStatus::Rejected;
+            request.note = Some("Gates no longer pass at approval time".into());
+        }
+        request.clone()
+    };
+
+    audit(
+        &logger,
+        &audit_subject(&strategy_id),
+        json!({
+            "event": if passed { "approved" } else { "rejected" },
+            "request_id": request.id,
+            "track_record": request.track_record,
+            "blocked_by": request.blocking_gates(),
+        }),
+        passed,
+    )
+    .await;
+    Ok(request)
+}
+
+#[tauri::command]
+pub async fn reject_strategy_promotion(
+    request_id: String,
+    note: Option<String>,
+    state: State<'_, SharedPromotionState>,
+    logger: State<'_, ActivityLogger>,
+) -> Result<PromotionRequest, String> {
+    let request = {
+        let mut state = state.lock().map_err(|e| e.to_string())?;
+        let request = state.request_mut(&request_id)?;
+        if request.status != PromotionStatus::Pending {
+            return Err("Only pending promotion requests can be rejected".into());
+        }
+        request.status = PromotionStatus::Rejected;
+        request.decided_at = Some(Utc::now());
+        request.note = note;
+        request.clone()
+    };
+
+    audit(
+        &logger,
+        &audit_subject(&request.strategy_id),
+        json!({ "event": "rejected", "request_id": request.id, "note": request.note }),
+        true,
+    )
+    .await;
+    Ok(request)
+}
+
+/// Moves a live strategy back to paper. Demotion never needs approval.
+#[tauri::command]
+pub async fn demote_strategy_to_paper(
+    strategy_id: String,
+    reason: Option<String>,
+    engine: State<'_, SharedAutoTradingEngine>,
+    logger: State<'_, ActivityLogger>,
+) -> Result<(), String> {
+    {
+        let mut engine = engine.lock().map_err(|e| e.to_string())?;
+        engine.set_mode(&strategy_id, TradingMode::Paper)?;
+    }
+
+    audit(
+        &logger,
+        &audit_subject(&strategy_id),
+        json!({ "event": "demoted", "reason": reason }),
+        true,
+    )
+    .await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn list_strategy_promotions(
+    strategy_id: Option<String>,
+    state: State<'_, SharedPromotionState>,
+) -> Result<Vec<PromotionRequest>, String> {
+    let state = state.lock().map_err(|e| e.to_string())?;
+    let mut requests: Vec<PromotionRequest> = state
+        .requests
+        .iter()
+        .filter(|request| {
+            strategy_id
+                .as_deref()
+                .map_or(true, |id| request.strategy_id == id)
+        })
+        .cloned()
+        .collect();
+    requests.sort_by(|a, b| b.requested_at.cmp(&a.requested_at));
+    Ok(requests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn record(hours: i64, trades: u32, drawdown: f64) -> (TrackRecord, DateTime<Utc>) {
+        let now = Utc::now();
+        let record = TrackRecord {
+            started_at: now - Duration::hours(hours),
+            trades,
+            max_drawdown_percent: drawdown,
+            total_pnl: 0.0,
+        };
+        (record, now)
+    }
+
+    #[test]
+    fn seasoned_paper_run_passes_all_gates() {
+        let (record, now) = record(100, 25, 8.0);
+        let results = evaluate_gates(&record, &PromotionGates::default(), now);
+        assert!(results.iter().all(|result| result.passed));
+    }
+
+    #[test]
+    fn each_gate_fails_independently() {
+        let gates = PromotionGates::default();
+
+        let (young, now) = record(10, 25, 8.0);
+        let failed: Vec<_> = evaluate_gates(&young, &gates, now)
+            .into_iter()
+            .filter(|r| !r.passed)
+            .map(|r| r.gate)
+            .collect();
+        assert_eq!(failed, vec![PromotionGate::MinDuration]);
+
+        let (risky, now) = record(100, 5, 30.0);
+        let failed: Vec<_> = evaluate_gates(&risky, &gates, now)
+            .into_iter()
+            .filter(|r| !r.passed)
+            .map(|r| r.gate)
+            .collect();
+        assert_eq!(
+            failed,
+            vec![PromotionGate::MinTrades, PromotionGate::MaxDrawdown]
+        );
+    }
+
+    #[test]
+    fn gates_reject_out_of_range_drawdown() {
+        let gates = PromotionGates {
+            max_drawdown_percent: 0.0,
+            ..PromotionGates::default()
+        };
+        assert!(gates.validate().is_err());
+        assert!(PromotionGates::default().validate().is_ok());
+    }
+}
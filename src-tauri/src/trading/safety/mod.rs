@@ -13,6 +13,7 @@ pub use insurance::{InsuranceProvider, InsuranceQuote, InsuranceSelection};
 pub use policy::{PolicyCheckResult, PolicyViolation, SafetyPolicy, ViolationSeverity};
 pub use simulator::{ImpactPreview, MevRiskLevel, RouteHop, TransactionSimulation};
 
+use crate::security::blocklist_sync::denylist_match;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -77,7 +78,7 @@ impl SafetyEngine {
         request: SafetyCheckRequest,
     ) -> Result<SafetyCheckResult, String> {
         // Check policy violations
-        let policy_result = self.policy_engine.check_trade_policy(
+        let mut policy_result = self.policy_engine.check_trade_policy(
             &request.wallet_address,
             request.amount_usd,
             request.price_impact_percent,
@@ -85,6 +86,23 @@ impl SafetyEngine {
             request.security_score,
         );
 
+        // Community denylist hits cannot be overridden
+        if self.get_policy().enabled {
+            for mint in [&request.input_mint, &request.output_mint] {
+                if let Some(hit) = denylist_match(mint) {
+                    policy_result.add_violation(PolicyViolation {
+                        rule: "community_denylist".to_string(),
+                        message: format!(
+                            "{} is denylisted by {}: {}",
+                            mint, hit.source, hit.reason
+                        ),
+                        severity: ViolationSeverity::Critical,
+                        can_override: false,
+                    });
+                }
+            }
+        }
+
         // Check cooldown
         let cooldown_status = if self.get_policy().cooldown_enabled {
             self.cooldown_manager
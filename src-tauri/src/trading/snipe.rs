@@ -0,0 +1,1058 @@
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::{Mutex, RwLock};
+use uuid::Uuid;
+
+use crate::api::jupiter::{
+    jupiter_quote, jupiter_swap, PriorityFeeConfig, QuoteCommandInput, QuoteResult,
+    SwapCommandInput, SwapMode,
+};
+use crate::api_analytics::{ensure_within_budget, RequestPriority};
+use crate::api_config::{report_api_outcome, ApiConfigManager, RequestOutcome, ResolvedApiKey};
+use crate::market::NewCoin;
+use crate::security::activity_log::{ActivityAction, ActivityLogger};
+use crate::security::keystore::Keystore;
+use crate::trading::exit_ladder::{notify_position_opened, PositionFill, PositionSource};
+use crate::wallet::offline_signing::{inspect_unsigned_transaction, verify_signed_transaction};
+
+const SNIPE_CONFIG_FILE: &str = "snipe_mode.json";
+const SNIPE_LEDGER_FILE: &str = "snipe_ledger.json";
+const SNIPE_READY_EVENT: &str = "snipe-ready";
+const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+const USDC_DECIMALS: i32 = 6;
+const BLOCKHASH_REFRESH_SECS: u64 = 20;
+/// A cached blockhash older than this means the RPC is unhealthy; no snipes then.
+const BLOCKHASH_MAX_AGE_SECS: i64 = 60;
+/// Blocks a blockhash stays usable after it was produced.
+const BLOCKHASH_VALIDITY_BLOCKS: u64 = 150;
+const LEDGER_RETENTION_DAYS: i64 = 7;
+
+/// What a newly detected coin must look like before snipe mode considers it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnipeFilterProfile {
+    pub min_safety_score: i64,
+    pub min_liquidity_usd: f64,
+    pub min_holders: i64,
+    pub max_top_holder_percent: f64,
+    pub require_mint_authority_revoked: bool,
+}
+
+impl Default for SnipeFilterProfile {
+    fn default() -> Self {
+        Self {
+            min_safety_score: 80,
+            min_liquidity_usd: 10_000.0,
+            min_holders: 100,
+            max_top_holder_percent: 20.0,
+            require_mint_authority_revoked: true,
+        }
+    }
+}
+
+/// Hard limits every snipe is checked against. They can be tightened or
+/// loosened, but not switched off.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnipeGuardRails {
+    pub max_spend_per_snipe_usd: f64,
+    pub daily_cap_usd: f64,
+    pub max_slippage_bps: u16,
+    pub max_price_impact_pct: f64,
+    /// Largest loss allowed when quoting the bought amount straight back.
+    pub max_round_trip_loss_pct: f64,
+}
+
+impl Default for SnipeGuardRails {
+    fn default() -> Self {
+        Self {
+            max_spend_per_snipe_usd: 50.0,
+            daily_cap_usd: 200.0,
+            max_slippage_bps: 300,
+            max_price_impact_pct: 5.0,
+            max_round_trip_loss_pct: 25.0,
+        }
+    }
+}
+
+/// Priority fee settings fixed ahead of time so a snipe never waits on a fee
+/// estimate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComputeBudgetSettings {
+    pub unit_price_micro_lamports: u64,
+    pub max_priority_fee_lamports: u64,
+}
+
+impl Default for ComputeBudgetSettings {
+    fn default() -> Self {
+        Self {
+            unit_price_micro_lamports: 100_000,
+            max_priority_fee_lamports: 5_000_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnipeConfig {
+    pub enabled: bool,
+    pub wallet_address: Option<String>,
+    /// USDC spent on each snipe.
+    pub spend_usd: f64,
+    #[serde(default)]
+    pub profile: SnipeFilterProfile,
+    #[serde(default)]
+    pub guard_rails: SnipeGuardRails,
+    #[serde(default)]
+    pub compute_budget: ComputeBudgetSettings,
+}
+
+impl SnipeConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        let rails = &self.guard_rails;
+        let positive = |value: f64| value.is_finite() && value > 0.0;
+
+        if !positive(rails.max_spend_per_snipe_usd) || !positive(rails.daily_cap_usd) {
+            return Err("Per-snipe and daily spend limits must be greater than zero".into());
+        }
+        if rails.daily_cap_usd < rails.max_spend_per_snipe_usd {
+            return Err("Daily cap must be at least the per-snipe limit".into());
+        }
+        if rails.max_slippage_bps == 0 || rails.max_slippage_bps > 5_000 {
+            return Err("Slippage must be between 1 and 5000 bps".into());
+        }
+        if !positive(rails.max_price_impact_pct) || rails.max_price_impact_pct > 100.0 {
+            return Err("Max price impact must be between 0 and 100%".into());
+        }
+        if !positive(rails.max_round_trip_loss_pct) || rails.max_round_trip_loss_pct >= 100.0 {
+            return Err("Max round-trip loss must be between 0 and 100%".into());
+        }
+        if self.compute_budget.max_priority_fee_lamports == 0 {
+            return Err("Max priority fee must be greater than zero".into());
+        }
+
+        if self.enabled {
+            if self.wallet_address.as_deref().map_or(true, str::is_empty) {
+                return Err("A wallet is required to enable snipe mode".into());
+            }
+            if !positive(self.spend_usd) || self.spend_usd > rails.max_spend_per_snipe_usd {
+                return Err(format!(
+                    "Spend per snipe must be between 0 and ${:.2}",
+                    rails.max_spend_per_snipe_usd
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The scanner fields snipe mode decides on.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnipeCandidate {
+    pub address: String,
+    pub symbol: String,
+    pub safety_score: i64,
+    pub liquidity_usd: f64,
+    pub holder_count: i64,
+    pub top_holder_percent: f64,
+    pub mint_authority_revoked: bool,
+    pub freeze_authority_revoked: bool,
+    pub is_spam: bool,
+}
+
+impl From<&NewCoin> for SnipeCandidate {
+    fn from(coin: &NewCoin) -> Self {
+        Self {
+            address: coin.address.clone(),
+            symbol: coin.symbol.clone(),
+            safety_score: coin.safety_score as i64,
+            liquidity_usd: coin.liquidity,
+            holder_count: coin.holder_count as i64,
+            top_holder_percent: coin.top_holder_percent,
+            mint_authority_revoked: coin.mint_authority_revoked,
+            freeze_authority_revoked: coin.freeze_authority_revoked,
+            is_spam: coin.is_spam,
+        }
+    }
+}
+
+impl SnipeFilterProfile {
+    pub fn matches(&self, candidate: &SnipeCandidate) -> bool {
+        !candidate.is_spam
+            && candidate.safety_score >= self.min_safety_score
+            && candidate.liquidity_usd >= self.min_liquidity_usd
+            && candidate.holder_count >= self.min_holders
+            && candidate.top_holder_percent <= self.max_top_holder_percent
+            && (!self.require_mint_authority_revoked || candidate.mint_authority_revoked)
+    }
+}
+
+/// Loss in percent when `returned` comes back for `spent`, both in input units.
+pub fn round_trip_loss_pct(spent: u64, returned: u64) -> f64 {
+    if spent == 0 {
+        return 100.0;
+    }
+    ((spent as f64 - returned as f64) / spent as f64 * 100.0).max(0.0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnipeStatus {
+    /// Swap built and waiting for the wallet to sign it.
+    Prepared,
+    Submitted,
+    Expired,
+    Failed,
+}
+
+impl SnipeStatus {
+    /// Whether the snipe's spend counts against the daily cap.
+    fn reserves_budget(&self) -> bool {
+        matches!(self, SnipeStatus::Prepared | SnipeStatus::Submitted)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnipeTicket {
+    pub id: String,
+    pub wallet_address: String,
+    pub token_address: String,
+    pub symbol: String,
+    pub spend_usd: f64,
+    pub expected_output: String,
+    pub price_impact_pct: f64,
+    pub round_trip_loss_pct: f64,
+    pub priority_fee_lamports: Option<u64>,
+    /// Unsigned swap transaction, base64.
+    pub transaction: String,
+    pub last_valid_block_height: u64,
+    pub status: SnipeStatus,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedBlockhash {
+    pub blockhash: String,
+    pub last_valid_block_height: u64,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CachedBlockhash {
+    fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        now - self.fetched_at <= ChronoDuration::seconds(BLOCKHASH_MAX_AGE_SECS)
+    }
+
+    /// Block height when the blockhash was fetched.
+    fn block_height(&self) -> u64 {
+        self.last_valid_block_height
+            .saturating_sub(BLOCKHASH_VALIDITY_BLOCKS)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnipeStatusReport {
+    pub config: SnipeConfig,
+    pub spent_today_usd: f64,
+    pub remaining_today_usd: f64,
+    pub blockhash: Option<CachedBlockhash>,
+}
+
+pub struct SnipeState {
+    config: SnipeConfig,
+    tickets: Vec<SnipeTicket>,
+    config_path: Option<PathBuf>,
+    ledger_path: Option<PathBuf>,
+}
+
+impl SnipeState {
+    fn load(dir: Option<PathBuf>) -> Self {
+        let config_path = dir.as_ref().map(|dir| dir.join(SNIPE_CONFIG_FILE));
+        let ledger_path = dir.as_ref().map(|dir| dir.join(SNIPE_LEDGER_FILE));
+        Self {
+            config: read_json(config_path.as_ref()).unwrap_or_default(),
+            tickets: read_json(ledger_path.as_ref()).unwrap_or_default(),
+            config_path,
+            ledger_path,
+        }
+    }
+
+    fn save_config(&self) -> Result<(), String> {
+        write_json(self.config_path.as_ref(), &self.config)
+    }
+
+    fn save_ledger(&mut self) -> Result<(), String> {
+        let cutoff = Utc::now() - ChronoDuration::days(LEDGER_RETENTION_DAYS);
+        self.tickets.retain(|ticket| ticket.created_at >= cutoff);
+        write_json(self.ledger_path.as_ref(), &self.tickets)
+    }
+
+    fn spent_since(&self, since: DateTime<Utc>) -> f64 {
+        self.tickets
+            .iter()
+            .filter(|ticket| ticket.created_at >= since && ticket.status.reserves_budget())
+            .map(|ticket| ticket.spend_usd)
+            .sum()
+    }
+
+    fn has_ticket_for(&self, token_address: &str) -> bool {
+        self.tickets
+            .iter()
+            .any(|ticket| ticket.token_address == token_address)
+    }
+
+    fn check_daily_cap(&self, config: &SnipeConfig) -> Result<(), String> {
+        let spent_today = self.spent_since(start_of_day(Utc::now()));
+        if spent_today + config.spend_usd > config.guard_rails.daily_cap_usd {
+            return Err(format!(
+                "Daily cap of ${:.2} reached (${:.2} spent today)",
+                config.guard_rails.daily_cap_usd, spent_today
+            ));
+        }
+        Ok(())
+    }
+
+    fn ticket_mut(&mut self, id: &str) -> Result<&mut SnipeTicket, String> {
+        self.tickets
+            .iter_mut()
+            .find(|ticket| ticket.id == id)
+            .ok_or_else(|| format!("Snipe {} not found", id))
+    }
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: Option<&PathBuf>) -> Option<T> {
+    let path = path.filter(|path| path.exists())?;
+    match fs::read_to_string(path) {
+        Ok(data) => serde_json::from_str(&data).ok(),
+        Err(err) => {
+            tracing::warn!(error = %err, path = %path.display(), "failed to read snipe state");
+            None
+        }
+    }
+}
+
+fn write_json<T: Serialize>(path: Option<&PathBuf>, value: &T) -> Result<(), String> {
+    let Some(path) = path else {
+        return Ok(());
+    };
+    let json = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+fn start_of_day(now: DateTime<Utc>) -> DateTime<Utc> {
+    now.date_naive()
+        .and_hms_opt(0, 0, 0)
+        .map(|midnight| midnight.and_utc())
+        .unwrap_or(now)
+}
+
+/// Snipes run one at a time under the state lock, so two detections can never
+/// both pass the daily cap check.
+pub struct SnipeEngine {
+    state: Mutex<SnipeState>,
+    blockhash: RwLock<Option<CachedBlockhash>>,
+    client: Client,
+}
+
+pub type SharedSnipeEngine = Arc<SnipeEngine>;
+
+pub fn register_snipe_engine(app: &tauri::App) {
+    let dir = app.path_resolver().app_data_dir().map(|dir| {
+        if let Err(err) = fs::create_dir_all(&dir) {
+            tracing::warn!(error = %err, "failed to create app data directory");
+        }
+        dir
+    });
+    let engine: SharedSnipeEngine = Arc::new(SnipeEngine {
+        state: Mutex::new(SnipeState::load(dir)),
+        blockhash: RwLock::new(None),
+        client: Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap_or_else(|_| Client::new()),
+    });
+    app.manage(engine.clone());
+
+    let handle = app.handle();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if engine.state.lock().await.config.enabled {
+                if let Err(err) = engine.refresh_blockhash(&handle).await {
+                    tracing::warn!(error = %err, "failed to refresh snipe blockhash");
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(BLOCKHASH_REFRESH_SECS)).await;
+        }
+    });
+}
+
+fn resolve_rpc(app: &AppHandle) -> Result<ResolvedApiKey, String> {
+    let manager = app
+        .try_state::<ApiConfigManager>()
+        .ok_or_else(|| "API configuration unavailable".to_string())?;
+    let keystore = app
+        .try_state::<Keystore>()
+        .ok_or_else(|| "Keystore unavailable".to_string())?;
+    manager.resolve_key("solana_rpc", &keystore)
+}
+
+async fn audit(app: &AppHandle, wallet: &str, details: Value, success: bool) {
+    let Some(logger) = app.try_state::<ActivityLogger>() else {
+        return;
+    };
+    if let Err(err) = logger
+        .log_activity(wallet, ActivityAction::Snipe, details, success, None)
+        .await
+    {
+        tracing::warn!(error = %err, "failed to audit snipe");
+    }
+}
+
+impl SnipeEngine {
+    async fn rpc(&self, app: &AppHandle, method: &str, params: Value) -> Result<Value, String> {
+        let rpc = resolve_rpc(app)?;
+        let payload = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let started = Instant::now();
+        let response = self.client.post(&rpc.key).json(&payload).send().await;
+        let outcome = RequestOutcome::from_response(started, &response);
+        report_api_outcome(app, &rpc.service, rpc.source, outcome).await;
+
+        let data: Value = response
+            .map_err(|e| format!("RPC request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Some(error) = data.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+        Ok(data["result"].clone())
+    }
+
+    async fn refresh_blockhash(&self, app: &AppHandle) -> Result<(), String> {
+        ensure_within_budget(app, "solana_rpc", RequestPriority::Background)?;
+        let result = self
+            .rpc(
+                app,
+                "getLatestBlockhash",
+                json!([{ "commitment": "confirmed" }]),
+            )
+            .await?;
+        let value = &result["value"];
+        let cached = CachedBlockhash {
+            blockhash: value["blockhash"]
+                .as_str()
+                .ok_or_else(|| "Invalid blockhash response".to_string())?
+                .to_string(),
+            last_valid_block_height: value["lastValidBlockHeight"]
+                .as_u64()
+                .ok_or_else(|| "Invalid blockhash response".to_string())?,
+            fetched_at: Utc::now(),
+        };
+        *self.blockhash.write().await = Some(cached);
+        Ok(())
+    }
+
+    async fn fresh_blockhash(&self) -> Result<CachedBlockhash, String> {
+        self.blockhash
+            .read()
+            .await
+            .clone()
+            .filter(|cached| cached.is_fresh(Utc::now()))
+            .ok_or_else(|| "No recent blockhash; RPC may be unavailable".to_string())
+    }
+
+    /// Runs a detected coin through the profile, then the guard rails, and
+    /// prepares the swap. Every attempt past the profile is audited.
+    async fn consider(&self, app: &AppHandle, candidate: SnipeCandidate) {
+        let (config, wallet, cap_check) = {
+            let state = self.state.lock().await;
+            let config = state.config.clone();
+            if !config.enabled || !config.profile.matches(&candidate) {
+                return;
+            }
+            let Some(wallet) = config.wallet_address.clone() else {
+                return;
+            };
+            if state.has_ticket_for(&candidate.address) {
+                return;
+            }
+            let cap_check = state.check_daily_cap(&config);
+            (config, wallet, cap_check)
+        };
+
+        // Quotes and the swap build happen without the lock so tickets can be
+        // listed and submitted meanwhile; the checks are repeated before the
+        // ticket is recorded.
+        let result = match cap_check {
+            Ok(()) => self.prepare(&config, &wallet, &candidate).await,
+            Err(reason) => Err(reason),
+        };
+
+        let mut state = self.state.lock().await;
+        let result = result.and_then(|ticket| {
+            if state.has_ticket_for(&candidate.address) {
+                return Err("A snipe for this token was prepared meanwhile".to_string());
+            }
+            state.check_daily_cap(&config).map(|_| ticket)
+        });
+
+        match result {
+            Ok(ticket) => {
+                state.tickets.push(ticket.clone());
+                if let Err(err) = state.save_ledger() {
+                    tracing::warn!(error = %err, "failed to persist snipe ledger");
+                }
+                drop(state);
+
+                if let Err(err) = app.emit_all(SNIPE_READY_EVENT, &ticket) {
+                    tracing::warn!(error = %err, "failed to emit snipe ticket");
+                }
+                audit(
+                    app,
+                    &wallet,
+                    json!({
+                        "event": "prepared",
+                        "ticketId": ticket.id,
+                        "candidate": candidate,
+                        "spendUsd": ticket.spend_usd,
+                        "priceImpactPct": ticket.price_impact_pct,
+                        "roundTripLossPct": ticket.round_trip_loss_pct,
+                        "priorityFeeLamports": ticket.priority_fee_lamports,
+                    }),
+                    true,
+                )
+                .await;
+            }
+            Err(reason) => {
+                drop(state);
+                audit(
+                    app,
+                    &wallet,
+                    json!({ "event": "rejected", "candidate": candidate, "reason": reason }),
+                    false,
+                )
+                .await;
+            }
+        }
+    }
+
+    async fn prepare(
+        &self,
+        config: &SnipeConfig,
+        wallet: &str,
+        candidate: &SnipeCandidate,
+    ) -> Result<SnipeTicket, String> {
+        let rails = &config.guard_rails;
+        if config.spend_usd > rails.max_spend_per_snipe_usd {
+            return Err("Spend exceeds the per-snipe limit".into());
+        }
+        // A freeze authority can lock holders out of selling at any time.
+        if !candidate.freeze_authority_revoked {
+            return Err("Freeze authority is not revoked; token may not be sellable".into());
+        }
+        let blockhash = self.fresh_blockhash().await?;
+
+        let spend_units = (config.spend_usd * 10f64.powi(USDC_DECIMALS)).round() as u64;
+        let priority_fee = PriorityFeeConfig {
+            compute_unit_price_micro_lamports: Some(
+                config.compute_budget.unit_price_micro_lamports,
+            ),
+            auto_multiplier: None,
+        };
+        let buy = quote(
+            USDC_MINT,
+            &candidate.address,
+            spend_units,
+            rails,
+            &priority_fee,
+        )
+        .await?;
+        let price_impact_pct = buy.quote.price_impact_pct * 100.0;
+        if price_impact_pct > rails.max_price_impact_pct {
+            return Err(format!(
+                "Price impact {:.2}% exceeds {:.2}%",
+                price_impact_pct, rails.max_price_impact_pct
+            ));
+        }
+
+        // Sellability: the bought amount has to route straight back without
+        // losing more than the guard rail allows.
+        let output_units: u64 = buy
+            .quote
+            .output_amount
+            .parse()
+            .map_err(|_| "Invalid quote output amount".to_string())?;
+        let sell = quote(
+            &candidate.address,
+            USDC_MINT,
+            output_units,
+            rails,
+            &priority_fee,
+        )
+        .await
+        .map_err(|e| format!("Sellability check failed: {}", e))?;
+        let returned_units: u64 = sell
+            .quote
+            .output_amount
+            .parse()
+            .map_err(|_| "Invalid quote output amount".to_string())?;
+        let loss_pct = round_trip_loss_pct(spend_units, returned_units);
+        if loss_pct > rails.max_round_trip_loss_pct {
+            return Err(format!(
+                "Round trip loses {:.1}%, above the {:.1}% limit",
+                loss_pct, rails.max_round_trip_loss_pct
+            ));
+        }
+
+        let swap = jupiter_swap(SwapCommandInput {
+            quote: buy.quote.clone(),
+            user_public_key: wallet.to_string(),
+            fee_account: None,
+            wrap_and_unwrap_sol: Some(true),
+            as_legacy_transaction: None,
+            priority_fee_config: Some(priority_fee),
+            simulate: Some(false),
+        })
+        .await?;
+
+        let priority_fee_lamports = swap
+            .prioritization_fee_lamports
+            .as_deref()
+            .and_then(|fee| fee.parse::<u64>().ok());
+        if priority_fee_lamports
+            .is_some_and(|fee| fee > config.compute_budget.max_priority_fee_lamports)
+        {
+            return Err("Priority fee exceeds the configured maximum".into());
+        }
+        if swap.last_valid_block_height <= blockhash.block_height() {
+            return Err("Swap transaction expired before it could be signed".into());
+        }
+
+        Ok(SnipeTicket {
+            id: Uuid::new_v4().to_string(),
+            wallet_address: wallet.to_string(),
+            token_address: candidate.address.clone(),
+            symbol: candidate.symbol.clone(),
+            spend_usd: config.spend_usd,
+            expected_output: buy.quote.output_amount,
+            price_impact_pct,
+            round_trip_loss_pct: loss_pct,
+            priority_fee_lamports,
+            transaction: swap.transaction.base64,
+            last_valid_block_height: swap.last_valid_block_height,
+            status: SnipeStatus::Prepared,
+            signature: None,
+            error: None,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Hands a submitted snipe to the exit ladder. The quoted output is in base
+    /// units, so the mint's decimals are needed to size the position.
+    async fn open_position(&self, app: &AppHandle, ticket: &SnipeTicket) -> Result<(), String> {
+        let supply = self
+            .rpc(
+                app,
+                "getTokenSupply",
+                json!([ticket.token_address]),
+            )
+            .await?;
+        let decimals = supply["value"]["decimals"]
+            .as_i64()
+            .ok_or_else(|| "Invalid token supply response".to_string())?;
+        let output: f64 = ticket
+            .expected_output
+            .parse()
+            .map_err(|_| "Invalid quote output amount".to_string())?;
+
+        notify_position_opened(PositionFill {
+            wallet_address: ticket.wallet_address.clone(),
+            token_mint: ticket.token_address.clone(),
+            token_symbol: ticket.symbol.clone(),
+            quote_mint: USDC_MINT.to_string(),
+            quote_symbol: "USDC".to_string(),
+            quantity: output / 10f64.powi(decimals as i32),
+            cost: ticket.spend_usd,
+            source: PositionSource::Snipe,
+            reference: Some(ticket.id.clone()),
+        });
+        Ok(())
+    }
+
+    /// Marks prepared tickets whose transaction can no longer land as expired,
+    /// releasing their share of the daily cap.
+    async fn expire_stale(&self, state: &mut SnipeState) {
+        let Some(height) = self
+            .blockhash
+            .read()
+            .await
+            .as_ref()
+            .map(CachedBlockhash::block_height)
+        else {
+            return;
+        };
+        for ticket in state.tickets.iter_mut() {
+            if ticket.status == SnipeStatus::Prepared && ticket.last_valid_block_height <= height {
+                ticket.status = SnipeStatus::Expired;
+            }
+        }
+    }
+}
+
+async fn quote(
+    input_mint: &str,
+    output_mint: &str,
+    amount: u64,
+    rails: &SnipeGuardRails,
+    priority_fee: &PriorityFeeConfig,
+) -> Result<QuoteResult, String> {
+    jupiter_quote(QuoteCommandInput {
+        input_mint: input_mint.to_string(),
+        output_mint: output_mint.to_string(),
+        amount,
+        slippage_bps: Some(rails.max_slippage_bps),
+        swap_mode: Some(SwapMode::ExactIn),
+        platform_fee_bps: None,
+        only_direct_routes: None,
+        referral_account: None,
+        as_legacy_transaction: None,
+        priority_fee_config: Some(priority_fee.clone()),
+    })
+    .await
+}
+
+/// Hands scanner detections to snipe mode without holding up the scan.
+pub fn notify_snipe_engine(app: &AppHandle, coins: &[NewCoin]) {
+    let Some(engine) = app.try_state::<SharedSnipeEngine>() else {
+        return;
+    };
+    let engine = engine.inner().clone();
+    let app = app.clone();
+    let candidates: Vec<SnipeCandidate> = coins.iter().map(SnipeCandidate::from).collect();
+
+    tauri::async_runtime::spawn(async move {
+        for candidate in candidates {
+            engine.consider(&app, candidate).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_snipe_status(
+    engine: State<'_, SharedSnipeEngine>,
+) -> Result<SnipeStatusReport, String> {
+    let mut state = engine.state.lock().await;
+    engine.expire_stale(&mut state).await;
+    let spent_today_usd = state.spent_since(start_of_day(Utc::now()));
+    Ok(SnipeStatusReport {
+        remaining_today_usd: (state.config.guard_rails.daily_cap_usd - spent_today_usd).max(0.0),
+        spent_today_usd,
+        config: state.config.clone(),
+        blockhash: engine.blockhash.read().await.clone(),
+    })
+}
+
+#[tauri::command]
+pub async fn update_snipe_config(
+    app: AppHandle,
+    config: SnipeConfig,
+    engine: State<'_, SharedSnipeEngine>,
+) -> Result<SnipeConfig, String> {
+    config.validate()?;
+    let previous = {
+        let mut state = engine.state.lock().await;
+        let previous = std::mem::replace(&mut state.config, config.clone());
+        if let Err(err) = state.save_config() {
+            state.config = previous;
+            return Err(err);
+        }
+        previous
+    };
+
+    if config.enabled {
+        if let Err(err) = engine.refresh_blockhash(&app).await {
+            tracing::warn!(error = %err, "failed to warm snipe blockhash cache");
+        }
+    }
+
+    let subject = config
+        .wallet_address
+        .as_deref()
+        .or(previous.wallet_address.as_deref())
+        .unwrap_or("snipe_mode");
+    audit(
+        &app,
+        subject,
+        json!({ "event": "config_updated", "previous": previous, "config": config }),
+        true,
+    )
+    .await;
+    Ok(config)
+}
+
+#[tauri::command]
+pub async fn list_snipe_tickets(
+    engine: State<'_, SharedSnipeEngine>,
+) -> Result<Vec<SnipeTicket>, String> {
+    let mut state = engine.state.lock().await;
+    engine.expire_stale(&mut state).await;
+    let mut tickets = state.tickets.clone();
+    tickets.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(tickets)
+}
+
+/// The returned transaction must carry exactly the prepared message, signed by
+/// the ticket's wallet; anything else could spend beyond the checked quote.
+fn verify_signed_snipe(ticket: &SnipeTicket, signed_transaction: &str) -> Result<(), String> {
+    let prepared = inspect_unsigned_transaction(&ticket.transaction)?;
+    if prepared.required_signers.first() != Some(&ticket.wallet_address) {
+        return Err("Prepared snipe is not payable by the snipe wallet".to_string());
+    }
+    verify_signed_transaction(&prepared.message_hash, signed_transaction)
+        .map(|_| ())
+        .map_err(|err| format!("Signed transaction rejected: {}", err))
+}
+
+/// Sends a prepared snipe once the wallet has signed it. Preflight is skipped
+/// since the swap was already quoted and checked moments ago.
+#[tauri::command]
+pub async fn submit_snipe(
+    app: AppHandle,
+    ticket_id: String,
+    signed_transaction: String,
+    engine: State<'_, SharedSnipeEngine>,
+) -> Result<SnipeTicket, String> {
+    let mut state = engine.state.lock().await;
+    engine.expire_stale(&mut state).await;
+    let ticket = state.ticket_mut(&ticket_id)?.clone();
+    if ticket.status != SnipeStatus::Prepared {
+        return Err(format!(
+            "Snipe is {:?} and cannot be submitted",
+            ticket.status
+        ));
+    }
+    verify_signed_snipe(&ticket, &signed_transaction)?;
+
+    let result = engine
+        .rpc(
+            &app,
+            "sendTransaction",
+            json!([signed_transaction, { "encoding": "base64", "skipPreflight": true }]),
+        )
+        .await
+        .and_then(|value| {
+            value
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| "Invalid sendTransaction response".to_string())
+        });
+
+    let updated = {
+        let ticket = state.ticket_mut(&ticket_id)?;
+        match &result {
+            Ok(signature) => {
+                ticket.status = SnipeStatus::Submitted;
+                ticket.signature = Some(signature.clone());
+            }
+            Err(err) => {
+                ticket.status = SnipeStatus::Failed;
+                ticket.error = Some(err.clone());
+            }
+        }
+        ticket.clone()
+    };
+    if let Err(err) = state.save_ledger() {
+        tracing::warn!(error = %err, "failed to persist snipe ledger");
+    }
+    drop(state);
+
+    audit(
+        &app,
+        &updated.wallet_address,
+        json!({
+            "event": "submitted",
+            "ticketId": updated.id,
+            "tokenAddress": updated.token_address,
+            "spendUsd": updated.spend_usd,
+            "signature": updated.signature,
+            "error": updated.error,
+        }),
+        result.is_ok(),
+    )
+    .await;
+
+    if result.is_ok() {
+        let engine = engine.inner().clone();
+        let ticket = updated.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(err) = engine.open_position(&app, &ticket).await {
+                tracing::warn!(error = %err, ticket = %ticket.id, "failed to open snipe position");
+            }
+        });
+    }
+
+    result.map(|_| updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate() -> SnipeCandidate {
+        SnipeCandidate {
+            address: "Mint1".into(),
+            symbol: "GEM".into(),
+            safety_score: 90,
+            liquidity_usd: 25_000.0,
+            holder_count: 400,
+            top_holder_percent: 10.0,
+            mint_authority_revoked: true,
+            freeze_authority_revoked: true,
+            is_spam: false,
+        }
+    }
+
+    #[test]
+    fn profile_rejects_spam_and_weak_coins() {
+        let profile = SnipeFilterProfile::default();
+        assert!(profile.matches(&candidate()));
+
+        let spam = SnipeCandidate {
+            is_spam: true,
+            ..candidate()
+        };
+        assert!(!profile.matches(&spam));
+
+        let concentrated = SnipeCandidate {
+            top_holder_percent: 60.0,
+            ..candidate()
+        };
+        assert!(!profile.matches(&concentrated));
+    }
+
+    #[test]
+    fn guard_rails_cannot_be_disabled() {
+        let mut config = SnipeConfig {
+            enabled: true,
+            wallet_address: Some("Wallet1".into()),
+            spend_usd: 25.0,
+            ..SnipeConfig::default()
+        };
+        assert!(config.validate().is_ok());
+
+        config.spend_usd = 75.0;
+        assert!(config.validate().is_err(), "spend above per-snipe limit");
+
+        config.spend_usd = 25.0;
+        config.guard_rails.daily_cap_usd = 0.0;
+        assert!(config.validate().is_err(), "daily cap is mandatory");
+
+        config.guard_rails.daily_cap_usd = 200.0;
+        config.wallet_address = None;
+        assert!(config.validate().is_err(), "enabling requires a wallet");
+    }
+
+    #[test]
+    fn only_live_snipes_count_against_the_daily_cap() {
+        let ticket = |status, spend_usd| SnipeTicket {
+            id: Uuid::new_v4().to_string(),
+            wallet_address: "Wallet1".into(),
+            token_address: "Mint1".into(),
+            symbol: "GEM".into(),
+            spend_usd,
+            expected_output: "0".into(),
+            price_impact_pct: 0.0,
+            round_trip_loss_pct: 0.0,
+            priority_fee_lamports: None,
+            transaction: String::new(),
+            last_valid_block_height: 0,
+            status,
+            signature: None,
+            error: None,
+            created_at: Utc::now(),
+        };
+        let mut state = SnipeState::load(None);
+        state.tickets = vec![
+            ticket(SnipeStatus::Submitted, 50.0),
+            ticket(SnipeStatus::Prepared, 25.0),
+            ticket(SnipeStatus::Expired, 50.0),
+            ticket(SnipeStatus::Failed, 50.0),
+        ];
+
+        assert_eq!(state.spent_since(start_of_day(Utc::now())), 75.0);
+        assert_eq!(round_trip_loss_pct(1_000_000, 900_000), 10.0);
+        assert_eq!(round_trip_loss_pct(1_000_000, 1_100_000), 0.0);
+    }
+
+    #[test]
+    fn signed_snipe_must_match_the_prepared_message() {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        use solana_sdk::{
+            hash::Hash,
+            message::{Message, VersionedMessage},
+            signature::{Keypair, Signature, Signer},
+            system_instruction,
+            transaction::VersionedTransaction,
+        };
+
+        let wallet = Keypair::new();
+        let unsigned = |lamports| {
+            let transfer =
+                system_instruction::transfer(&wallet.pubkey(), &wallet.pubkey(), lamports);
+            let message =
+                Message::new_with_blockhash(&[transfer], Some(&wallet.pubkey()), &Hash::default());
+            VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message: VersionedMessage::Legacy(message),
+            }
+        };
+        let encode = |tx: &VersionedTransaction| STANDARD.encode(bincode::serialize(tx).unwrap());
+        let signed = |mut tx: VersionedTransaction| {
+            tx.signatures[0] = wallet.sign_message(&tx.message.serialize());
+            encode(&tx)
+        };
+
+        let prepared = unsigned(1_000);
+        let ticket = SnipeTicket {
+            id: "t1".into(),
+            wallet_address: wallet.pubkey().to_string(),
+            token_address: "Mint1".into(),
+            symbol: "GEM".into(),
+            spend_usd: 25.0,
+            expected_output: "0".into(),
+            price_impact_pct: 0.0,
+            round_trip_loss_pct: 0.0,
+            priority_fee_lamports: None,
+            transaction: encode(&prepared),
+            last_valid_block_height: 0,
+            status: SnipeStatus::Prepared,
+            signature: None,
+            error: None,
+            created_at: Utc::now(),
+        };
+
+        assert!(verify_signed_snipe(&ticket, &signed(prepared.clone())).is_ok());
+        assert!(verify_signed_snipe(&ticket, &signed(unsigned(5_000_000))).is_err());
+        assert!(verify_signed_snipe(&ticket, &encode(&prepared)).is_err());
+
+        let other_wallet = SnipeTicket {
+            wallet_address: Keypair::new().pubkey().to_string(),
+            ..ticket
+        };
+        assert!(verify_signed_snipe(&other_wallet, &signed(prepared)).is_err());
+    }
+}
@@ -0,0 +1,975 @@
+use crate::journal::{
+    EmotionTracking, EntryType, JournalEntry, MarketConditions, SharedJournalDatabase,
+    TradeOutcome,
+};
+use crate::trading::order_manager::SharedOrderManager;
+use crate::trading::types::{CreateOrderRequest, Order, OrderSide, OrderStatus, OrderType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqlitePool, Pool, Row, Sqlite};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tokio::sync::{OnceCell, RwLock};
+use tokio::time::{interval, Duration};
+use uuid::Uuid;
+
+const IDEA_SYNC_INTERVAL_SECS: u64 = 15;
+const DEFAULT_MIN_REWARD_RISK: f64 = 1.5;
+const DEFAULT_SLIPPAGE_BPS: i32 = 100;
+
+pub const CHECK_LEVELS_VALID: &str = "levels_valid";
+pub const CHECK_RISK_WITHIN_LIMIT: &str = "risk_within_limit";
+pub const CHECK_REWARD_RISK: &str = "reward_risk";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeIdeaStatus {
+    /// Idea captured, checklist not yet cleared.
+    Draft,
+    /// Entry order placed, waiting for a fill.
+    Pending,
+    /// Entry filled, stop-loss and take-profit orders working.
+    Open,
+    Closed,
+    Cancelled,
+}
+
+impl TradeIdeaStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradeIdeaStatus::Draft => "draft",
+            TradeIdeaStatus::Pending => "pending",
+            TradeIdeaStatus::Open => "open",
+            TradeIdeaStatus::Closed => "closed",
+            TradeIdeaStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub id: String,
+    pub label: String,
+    pub checked: bool,
+    /// Automatic items are evaluated from the idea's levels and cannot be
+    /// ticked by hand.
+    pub automatic: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeIdeaRisk {
+    pub risk_amount: f64,
+    pub reward_amount: f64,
+    pub reward_risk_ratio: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeIdeaOutcome {
+    pub exit_reason: String,
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub pnl: f64,
+    pub pnl_percent: f64,
+    pub r_multiple: f64,
+    pub closed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeIdea {
+    pub id: String,
+    pub wallet_address: String,
+    pub token_mint: String,
+    pub token_symbol: String,
+    pub quote_mint: String,
+    pub quote_symbol: String,
+    pub thesis: String,
+    pub entry_price: f64,
+    pub stop_loss: f64,
+    pub take_profit: f64,
+    /// Position size in quote units.
+    pub amount: f64,
+    /// Maximum loss in quote units the idea may risk at its stop.
+    pub max_risk: f64,
+    pub min_reward_risk: f64,
+    pub risk: TradeIdeaRisk,
+    pub checklist: Vec<ChecklistItem>,
+    pub status: TradeIdeaStatus,
+    pub entry_order_id: Option<String>,
+    pub stop_order_id: Option<String>,
+    pub take_profit_order_id: Option<String>,
+    pub journal_entry_ids: Vec<String>,
+    pub outcome: Option<TradeIdeaOutcome>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub executed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTradeIdeaRequest {
+    pub wallet_address: String,
+    pub token_mint: String,
+    pub token_symbol: String,
+    pub quote_mint: String,
+    pub quote_symbol: String,
+    pub thesis: String,
+    pub entry_price: f64,
+    pub stop_loss: f64,
+    pub take_profit: f64,
+    pub amount: f64,
+    pub max_risk: f64,
+    #[serde(default)]
+    pub min_reward_risk: Option<f64>,
+    /// Extra manual checklist items on top of the defaults.
+    #[serde(default)]
+    pub checklist: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeIdeaEvent {
+    pub idea_id: String,
+    pub token_symbol: String,
+    pub status: TradeIdeaStatus,
+    pub outcome: Option<TradeIdeaOutcome>,
+}
+
+fn default_manual_items() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("thesis_reviewed", "Thesis reviewed and still valid"),
+        ("liquidity_checked", "Liquidity and holder concentration checked"),
+        ("no_conflicting_position", "No conflicting open position"),
+    ]
+}
+
+/// Loss at the stop, gain at the target and their ratio for a long idea sized
+/// in quote units.
+pub fn compute_idea_risk(entry: f64, stop: f64, target: f64, amount: f64) -> TradeIdeaRisk {
+    if entry <= 0.0 {
+        return TradeIdeaRisk {
+            risk_amount: 0.0,
+            reward_amount: 0.0,
+            reward_risk_ratio: 0.0,
+        };
+    }
+
+    let quantity = amount / entry;
+    let risk_amount = (entry - stop).max(0.0) * quantity;
+    let reward_amount = (target - entry).max(0.0) * quantity;
+    let reward_risk_ratio = if risk_amount > 0.0 {
+        reward_amount / risk_amount
+    } else {
+        0.0
+    };
+
+    TradeIdeaRisk {
+        risk_amount,
+        reward_amount,
+        reward_risk_ratio,
+    }
+}
+
+/// Re-evaluates the automatic checklist items from the idea's levels.
+pub fn refresh_automatic_checks(idea: &mut TradeIdea) {
+    idea.risk = compute_idea_risk(idea.entry_price, idea.stop_loss, idea.take_profit, idea.amount);
+
+    let levels_valid =
+        idea.stop_loss > 0.0 && idea.stop_loss < idea.entry_price && idea.entry_price < idea.take_profit;
+    let risk_ok = levels_valid && idea.risk.risk_amount <= idea.max_risk;
+    let reward_ok = levels_valid && idea.risk.reward_risk_ratio >= idea.min_reward_risk;
+
+    for item in idea.checklist.iter_mut().filter(|item| item.automatic) {
+        item.checked = match item.id.as_str() {
+            CHECK_LEVELS_VALID => levels_valid,
+            CHECK_RISK_WITHIN_LIMIT => risk_ok,
+            CHECK_REWARD_RISK => reward_ok,
+            _ => item.checked,
+        };
+    }
+}
+
+/// Labels of checklist items still blocking execution.
+pub fn checklist_blockers(idea: &TradeIdea) -> Vec<String> {
+    idea.checklist
+        .iter()
+        .filter(|item| !item.checked)
+        .map(|item| item.label.clone())
+        .collect()
+}
+
+pub fn compute_idea_outcome(
+    idea: &TradeIdea,
+    exit_reason: &str,
+    exit_price: f64,
+    closed_at: DateTime<Utc>,
+) -> TradeIdeaOutcome {
+    let quantity = if idea.entry_price > 0.0 {
+        idea.amount / idea.entry_price
+    } else {
+        0.0
+    };
+    let pnl = (exit_price - idea.entry_price) * quantity;
+    let pnl_percent = if idea.amount > 0.0 {
+        pnl / idea.amount * 100.0
+    } else {
+        0.0
+    };
+    let r_multiple = if idea.risk.risk_amount > 0.0 {
+        pnl / idea.risk.risk_amount
+    } else {
+        0.0
+    };
+
+    TradeIdeaOutcome {
+        exit_reason: exit_reason.to_string(),
+        entry_price: idea.entry_price,
+        exit_price,
+        pnl,
+        pnl_percent,
+        r_multiple,
+        closed_at,
+    }
+}
+
+pub struct TradeIdeaDatabase {
+    pool: Pool<Sqlite>,
+}
+
+impl TradeIdeaDatabase {
+    pub async fn new(db_path: PathBuf) -> Result<Self, sqlx::Error> {
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url).await?;
+        let db = Self { pool };
+        db.initialize().await?;
+        Ok(db)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS trade_ideas (
+                id TEXT PRIMARY KEY,
+                wallet_address TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                status TEXT NOT NULL,
+                data TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_trade_ideas_wallet ON trade_ideas(wallet_address);
+            CREATE INDEX IF NOT EXISTS idx_trade_ideas_status ON trade_ideas(status);
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn save_idea(&self, idea: &TradeIdea) -> Result<(), String> {
+        let data = serde_json::to_string(idea).map_err(|e| e.to_string())?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO trade_ideas (id, wallet_address, token_mint, status, data, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            ON CONFLICT(id) DO UPDATE SET
+                status = excluded.status,
+                data = excluded.data,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(&idea.id)
+        .bind(&idea.wallet_address)
+        .bind(&idea.token_mint)
+        .bind(idea.status.as_str())
+        .bind(data)
+        .bind(idea.created_at.to_rfc3339())
+        .bind(idea.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save trade idea: {e}"))?;
+
+        Ok(())
+    }
+
+    pub async fn get_idea(&self, id: &str) -> Result<Option<TradeIdea>, String> {
+        let row = sqlx::query("SELECT data FROM trade_ideas WHERE id = ?1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load trade idea: {e}"))?;
+
+        row.map(|row| {
+            let data: String = row.get("data");
+            serde_json::from_str(&data).map_err(|e| e.to_string())
+        })
+        .transpose()
+    }
+
+    pub async fn list_ideas(
+        &self,
+        wallet_address: &str,
+        status: Option<TradeIdeaStatus>,
+    ) -> Result<Vec<TradeIdea>, String> {
+        let rows = match status {
+            Some(status) => sqlx::query(
+                "SELECT data FROM trade_ideas WHERE wallet_address = ?1 AND status = ?2 ORDER BY created_at DESC",
+            )
+            .bind(wallet_address)
+            .bind(status.as_str())
+            .fetch_all(&self.pool)
+            .await,
+            None => sqlx::query(
+                "SELECT data FROM trade_ideas WHERE wallet_address = ?1 ORDER BY created_at DESC",
+            )
+            .bind(wallet_address)
+            .fetch_all(&self.pool)
+            .await,
+        }
+        .map_err(|e| format!("Failed to list trade ideas: {e}"))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|row| serde_json::from_str(row.get::<String, _>("data").as_str()).ok())
+            .collect())
+    }
+
+    pub async fn active_ideas(&self) -> Result<Vec<TradeIdea>, String> {
+        let rows = sqlx::query("SELECT data FROM trade_ideas WHERE status IN ('pending', 'open')")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load active trade ideas: {e}"))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|row| serde_json::from_str(row.get::<String, _>("data").as_str()).ok())
+            .collect())
+    }
+}
+
+pub type SharedTradeIdeaDatabase = Arc<RwLock<TradeIdeaDatabase>>;
+
+pub struct TradeIdeaManager {
+    db: SharedTradeIdeaDatabase,
+    orders: SharedOrderManager,
+    app_handle: AppHandle,
+}
+
+impl TradeIdeaManager {
+    pub fn new(db: SharedTradeIdeaDatabase, orders: SharedOrderManager, app_handle: AppHandle) -> Self {
+        Self {
+            db,
+            orders,
+            app_handle,
+        }
+    }
+
+    pub async fn create_idea(&self, request: CreateTradeIdeaRequest) -> Result<TradeIdea, String> {
+        if request.thesis.trim().is_empty() {
+            return Err("A trade idea needs a thesis".to_string());
+        }
+        if request.amount <= 0.0 || request.entry_price <= 0.0 {
+            return Err("Entry price and amount must be positive".to_string());
+        }
+        if request.max_risk <= 0.0 {
+            return Err("Maximum risk must be positive".to_string());
+        }
+
+        let mut checklist = vec![
+            ChecklistItem {
+                id: CHECK_LEVELS_VALID.to_string(),
+                label: "Stop below entry and target above entry".to_string(),
+                checked: false,
+                automatic: true,
+            },
+            ChecklistItem {
+                id: CHECK_RISK_WITHIN_LIMIT.to_string(),
+                label: "Loss at stop within maximum risk".to_string(),
+                checked: false,
+                automatic: true,
+            },
+            ChecklistItem {
+                id: CHECK_REWARD_RISK.to_string(),
+                label: "Reward-to-risk meets minimum".to_string(),
+                checked: false,
+                automatic: true,
+            },
+        ];
+        checklist.extend(default_manual_items().into_iter().map(|(id, label)| ChecklistItem {
+            id: id.to_string(),
+            label: label.to_string(),
+            checked: false,
+            automatic: false,
+        }));
+        checklist.extend(
+            request
+                .checklist
+                .iter()
+                .filter(|label| !label.trim().is_empty())
+                .map(|label| ChecklistItem {
+                    id: Uuid::new_v4().to_string(),
+                    label: label.trim().to_string(),
+                    checked: false,
+                    automatic: false,
+                }),
+        );
+
+        let now = Utc::now();
+        let mut idea = TradeIdea {
+            id: Uuid::new_v4().to_string(),
+            wallet_address: request.wallet_address,
+            token_mint: request.token_mint,
+            token_symbol: request.token_symbol,
+            quote_mint: request.quote_mint,
+            quote_symbol: request.quote_symbol,
+            thesis: request.thesis,
+            entry_price: request.entry_price,
+            stop_loss: request.stop_loss,
+            take_profit: request.take_profit,
+            amount: request.amount,
+            max_risk: request.max_risk,
+            min_reward_risk: request.min_reward_risk.unwrap_or(DEFAULT_MIN_REWARD_RISK),
+            risk: compute_idea_risk(0.0, 0.0, 0.0, 0.0),
+            checklist,
+            status: TradeIdeaStatus::Draft,
+            entry_order_id: None,
+            stop_order_id: None,
+            take_profit_order_id: None,
+            journal_entry_ids: Vec::new(),
+            outcome: None,
+            created_at: now,
+            updated_at: now,
+            executed_at: None,
+        };
+        refresh_automatic_checks(&mut idea);
+
+        self.db.read().await.save_idea(&idea).await?;
+        Ok(idea)
+    }
+
+    pub async fn get_idea(&self, id: &str) -> Result<TradeIdea, String> {
+        self.db
+            .read()
+            .await
+            .get_idea(id)
+            .await?
+            .ok_or_else(|| "Trade idea not found".to_string())
+    }
+
+    pub async fn list_ideas(
+        &self,
+        wallet_address: &str,
+        status: Option<TradeIdeaStatus>,
+    ) -> Result<Vec<TradeIdea>, String> {
+        self.db.read().await.list_ideas(wallet_address, status).await
+    }
+
+    pub async fn set_checklist_item(
+        &self,
+        id: &str,
+        item_id: &str,
+        checked: bool,
+    ) -> Result<TradeIdea, String> {
+        let mut idea = self.get_idea(id).await?;
+        if idea.status != TradeIdeaStatus::Draft {
+            return Err("Checklist can only be changed before execution".to_string());
+        }
+
+        let item = idea
+            .checklist
+            .iter_mut()
+            .find(|item| item.id == item_id)
+            .ok_or_else(|| "Checklist item not found".to_string())?;
+        if item.automatic {
+            return Err("Automatic checklist items are evaluated from the idea's levels".to_string());
+        }
+        item.checked = checked;
+        idea.updated_at = Utc::now();
+
+        self.db.read().await.save_idea(&idea).await?;
+        Ok(idea)
+    }
+
+    /// Places the entry order once every checklist item is cleared. The
+    /// stop-loss and take-profit legs are placed when the entry fills.
+    pub async fn execute_idea(&self, id: &str) -> Result<TradeIdea, String> {
+        let mut idea = self.get_idea(id).await?;
+        if idea.status != TradeIdeaStatus::Draft {
+            return Err("Trade idea has already been executed".to_string());
+        }
+
+        refresh_automatic_checks(&mut idea);
+        let blockers = checklist_blockers(&idea);
+        if !blockers.is_empty() {
+            return Err(format!("Pre-trade checklist incomplete: {}", blockers.join(", ")));
+        }
+
+        let entry = self
+            .orders
+            .create_order(CreateOrderRequest {
+                order_type: OrderType::Limit,
+                side: OrderSide::Buy,
+                input_mint: idea.quote_mint.clone(),
+                output_mint: idea.token_mint.clone(),
+                input_symbol: idea.quote_symbol.clone(),
+                output_symbol: idea.token_symbol.clone(),
+                amount: idea.amount,
+                limit_price: Some(idea.entry_price),
+                stop_price: None,
+                trailing_percent: None,
+                linked_order_id: None,
+                slippage_bps: DEFAULT_SLIPPAGE_BPS,
+                priority_fee_micro_lamports: 0,
+                wallet_address: idea.wallet_address.clone(),
+                stop_loss_price: Some(idea.stop_loss),
+            })
+            .await?;
+
+        let now = Utc::now();
+        idea.entry_order_id = Some(entry.id);
+        idea.status = TradeIdeaStatus::Pending;
+        idea.executed_at = Some(now);
+        idea.updated_at = now;
+
+        if let Some(entry_id) = self.write_pre_trade_journal(&idea).await {
+            idea.journal_entry_ids.push(entry_id);
+        }
+
+        self.db.read().await.save_idea(&idea).await?;
+        self.emit_update(&idea);
+        Ok(idea)
+    }
+
+    pub async fn cancel_idea(&self, id: &str) -> Result<TradeIdea, String> {
+        let mut idea = self.get_idea(id).await?;
+        if matches!(idea.status, TradeIdeaStatus::Closed | TradeIdeaStatus::Cancelled) {
+            return Err("Trade idea is already finished".to_string());
+        }
+
+        for order_id in [&idea.entry_order_id, &idea.stop_order_id, &idea.take_profit_order_id]
+            .into_iter()
+            .flatten()
+        {
+            if let Ok(order) = self.orders.get_order(order_id).await {
+                if matches!(order.status, OrderStatus::Pending | OrderStatus::PartiallyFilled) {
+                    self.orders.cancel_order(order_id).await?;
+                }
+            }
+        }
+
+        idea.status = TradeIdeaStatus::Cancelled;
+        idea.updated_at = Utc::now();
+        self.db.read().await.save_idea(&idea).await?;
+        self.emit_update(&idea);
+        Ok(idea)
+    }
+
+    /// Advances an executed idea from the state of its linked orders.
+    pub async fn sync_idea(&self, mut idea: TradeIdea) -> Result<TradeIdea, String> {
+        let before = idea.status;
+
+        match idea.status {
+            TradeIdeaStatus::Pending => {
+                let Some(entry_id) = idea.entry_order_id.clone() else {
+                    return Ok(idea);
+                };
+                let entry = self.orders.get_order(&entry_id).await?;
+                match entry.status {
+                    OrderStatus::Filled => self.place_exits(&mut idea, &entry).await?,
+                    OrderStatus::Cancelled | OrderStatus::Expired | OrderStatus::Failed => {
+                        idea.status = TradeIdeaStatus::Cancelled;
+                    }
+                    _ => {}
+                }
+            }
+            TradeIdeaStatus::Open => {
+                let stop = match &idea.stop_order_id {
+                    Some(order_id) => Some(self.orders.get_order(order_id).await?),
+                    None => None,
+                };
+                let target = match &idea.take_profit_order_id {
+                    Some(order_id) => Some(self.orders.get_order(order_id).await?),
+                    None => None,
+                };
+
+                let filled_exit = [(&stop, "stop_loss", idea.stop_loss), (&target, "take_profit", idea.take_profit)]
+                    .into_iter()
+                    .find(|(order, _, _)| order.as_ref().is_some_and(|o| o.status == OrderStatus::Filled))
+                    .map(|(_, reason, price)| (reason, price));
+
+                if let Some((reason, exit_price)) = filled_exit {
+                    let outcome = compute_idea_outcome(&idea, reason, exit_price, Utc::now());
+                    idea.outcome = Some(outcome);
+                    idea.status = TradeIdeaStatus::Closed;
+                    if let Some(entry_id) = self.write_post_trade_journal(&idea).await {
+                        idea.journal_entry_ids.push(entry_id);
+                    }
+                } else if [&stop, &target]
+                    .into_iter()
+                    .all(|order| order.as_ref().map_or(true, |o| is_finished_unfilled(o)))
+                {
+                    // Both exits were cancelled outside the idea.
+                    idea.status = TradeIdeaStatus::Cancelled;
+                }
+            }
+            _ => {}
+        }
+
+        if idea.status != before {
+            idea.updated_at = Utc::now();
+            self.db.read().await.save_idea(&idea).await?;
+            self.emit_update(&idea);
+        }
+
+        Ok(idea)
+    }
+
+    async fn place_exits(&self, idea: &mut TradeIdea, entry: &Order) -> Result<(), String> {
+        let quantity = entry.filled_amount.max(entry.amount) / idea.entry_price;
+        let exit_request = |order_type: OrderType, limit_price: Option<f64>, stop_price: Option<f64>| {
+            CreateOrderRequest {
+                order_type,
+                side: OrderSide::Sell,
+                input_mint: idea.token_mint.clone(),
+                output_mint: idea.quote_mint.clone(),
+                input_symbol: idea.token_symbol.clone(),
+                output_symbol: idea.quote_symbol.clone(),
+                amount: quantity,
+                limit_price,
+                stop_price,
+                trailing_percent: None,
+                // Sharing the idea id makes the two exits one-cancels-other.
+                linked_order_id: Some(idea.id.clone()),
+                slippage_bps: DEFAULT_SLIPPAGE_BPS,
+                priority_fee_micro_lamports: 0,
+                wallet_address: idea.wallet_address.clone(),
+                stop_loss_price: None,
+            }
+        };
+
+        let stop = self
+            .orders
+            .create_order(exit_request(OrderType::StopLoss, None, Some(idea.stop_loss)))
+            .await?;
+        let target = self
+            .orders
+            .create_order(exit_request(OrderType::TakeProfit, Some(idea.take_profit), None))
+            .await?;
+
+        idea.stop_order_id = Some(stop.id);
+        idea.take_profit_order_id = Some(target.id);
+        idea.status = TradeIdeaStatus::Open;
+        Ok(())
+    }
+
+    async fn write_pre_trade_journal(&self, idea: &TradeIdea) -> Option<String> {
+        let notes = format!(
+            "{}\n\nEntry {} / stop {} / target {} (R:R {:.2})",
+            idea.thesis, idea.entry_price, idea.stop_loss, idea.take_profit, idea.risk.reward_risk_ratio
+        );
+        let entry = self.journal_entry(idea, EntryType::PreTrade, notes);
+        self.save_journal_entry(entry).await
+    }
+
+    async fn write_post_trade_journal(&self, idea: &TradeIdea) -> Option<String> {
+        let outcome = idea.outcome.as_ref()?;
+        let mut entry = self.journal_entry(
+            idea,
+            EntryType::PostTrade,
+            format!("Closed at {} via {}", outcome.exit_price, outcome.exit_reason),
+        );
+        entry.exit_price = Some(outcome.exit_price as f32);
+        entry.outcome = Some(TradeOutcome {
+            pnl: outcome.pnl as f32,
+            pnl_percent: outcome.pnl_percent as f32,
+            success: outcome.pnl > 0.0,
+            followed_plan: true,
+            risk_reward_ratio: idea.risk.reward_risk_ratio as f32,
+        });
+        self.save_journal_entry(entry).await
+    }
+
+    fn journal_entry(&self, idea: &TradeIdea, entry_type: EntryType, notes: String) -> JournalEntry {
+        let now = Utc::now().timestamp();
+        JournalEntry {
+            id: Uuid::new_v4().to_string(),
+            timestamp: now,
+            trade_id: Some(idea.id.clone()),
+            entry_type,
+            strategy_tags: vec!["trade_idea".to_string(), idea.token_symbol.clone()],
+            emotions: EmotionTracking::default(),
+            notes,
+            market_conditions: MarketConditions::default(),
+            confidence_level: 0.5,
+            position_size: Some(idea.amount as f32),
+            entry_price: Some(idea.entry_price as f32),
+            exit_price: None,
+            outcome: None,
+            lessons_learned: None,
+            attachments: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    async fn save_journal_entry(&self, entry: JournalEntry) -> Option<String> {
+        let journal = self.app_handle.try_state::<SharedJournalDatabase>()?;
+        let result = journal.read().await.create_entry(&entry).await;
+        match result {
+            Ok(()) => Some(entry.id),
+            Err(err) => {
+                tracing::warn!(error = %err, idea = ?entry.trade_id, "failed to journal trade idea");
+                None
+            }
+        }
+    }
+
+    fn emit_update(&self, idea: &TradeIdea) {
+        let _ = self.app_handle.emit_all(
+            "trade_idea_update",
+            TradeIdeaEvent {
+                idea_id: idea.id.clone(),
+                token_symbol: idea.token_symbol.clone(),
+                status: idea.status,
+                outcome: idea.outcome.clone(),
+            },
+        );
+    }
+
+    pub async fn start_monitoring(manager: Arc<Self>) {
+        let mut ticker = interval(Duration::from_secs(IDEA_SYNC_INTERVAL_SECS));
+
+        loop {
+            ticker.tick().await;
+            let ideas = match manager.db.read().await.active_ideas().await {
+                Ok(ideas) => ideas,
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to load active trade ideas");
+                    continue;
+                }
+            };
+
+            for idea in ideas {
+                let id = idea.id.clone();
+                if let Err(err) = manager.sync_idea(idea).await {
+                    tracing::warn!(error = %err, idea = %id, "failed to sync trade idea");
+                }
+            }
+        }
+    }
+}
+
+fn is_finished_unfilled(order: &Order) -> bool {
+    matches!(
+        order.status,
+        OrderStatus::Cancelled | OrderStatus::Expired | OrderStatus::Failed
+    )
+}
+
+pub struct TradeIdeaState {
+    pub db: SharedTradeIdeaDatabase,
+    pub manager: Arc<TradeIdeaManager>,
+}
+
+static TRADE_IDEA_STATE: OnceCell<TradeIdeaState> = OnceCell::const_new();
+
+pub async fn init_trade_ideas(app_handle: &AppHandle, orders: SharedOrderManager) -> Result<(), String> {
+    if TRADE_IDEA_STATE.get().is_some() {
+        return Ok(());
+    }
+
+    let app_dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Failed to resolve app data directory".to_string())?;
+
+    let mut db_path = PathBuf::from(app_dir);
+    db_path.push("orders.db");
+
+    let db = TradeIdeaDatabase::new(db_path)
+        .await
+        .map_err(|e| format!("Failed to initialize trade idea database: {e}"))?;
+
+    let shared_db = Arc::new(RwLock::new(db));
+    let manager = Arc::new(TradeIdeaManager::new(shared_db.clone(), orders, app_handle.clone()));
+
+    TRADE_IDEA_STATE
+        .set(TradeIdeaState {
+            db: shared_db,
+            manager: manager.clone(),
+        })
+        .map_err(|_| "Trade idea state already initialized".to_string())?;
+
+    tauri::async_runtime::spawn(TradeIdeaManager::start_monitoring(manager));
+
+    Ok(())
+}
+
+fn require_state<'a>() -> Result<&'a TradeIdeaState, String> {
+    TRADE_IDEA_STATE
+        .get()
+        .ok_or_else(|| "Trade ideas not initialized".to_string())
+}
+
+/// Whether an order is the entry of a trade idea that is still in play.
+pub async fn is_trade_idea_order(order_id: &str) -> bool {
+    let Ok(state) = require_state() else {
+        return false;
+    };
+    let ideas = state.db.read().await.active_ideas().await;
+    ideas.map_or(false, |ideas| {
+        ideas
+            .iter()
+            .any(|idea| idea.entry_order_id.as_deref() == Some(order_id))
+    })
+}
+
+#[tauri::command]
+pub async fn create_trade_idea(
+    mut request: CreateTradeIdeaRequest,
+    handle: AppHandle,
+) -> Result<TradeIdea, String> {
+    request.wallet_address =
+        crate::wallet::sns::resolve_address_input(&handle, &request.wallet_address).await?;
+    let state = require_state()?;
+    state.manager.create_idea(request).await
+}
+
+#[tauri::command]
+pub async fn get_trade_idea(id: String) -> Result<TradeIdea, String> {
+    let state = require_state()?;
+    let idea = state.manager.get_idea(&id).await?;
+    state.manager.sync_idea(idea).await
+}
+
+#[tauri::command]
+pub async fn list_trade_ideas(
+    wallet_address: String,
+    status: Option<TradeIdeaStatus>,
+) -> Result<Vec<TradeIdea>, String> {
+    let state = require_state()?;
+    state.manager.list_ideas(&wallet_address, status).await
+}
+
+#[tauri::command]
+pub async fn set_trade_idea_checklist_item(
+    id: String,
+    item_id: String,
+    checked: bool,
+) -> Result<TradeIdea, String> {
+    let state = require_state()?;
+    state.manager.set_checklist_item(&id, &item_id, checked).await
+}
+
+#[tauri::command]
+pub async fn execute_trade_idea(id: String) -> Result<TradeIdea, String> {
+    let state = require_state()?;
+    state.manager.execute_idea(&id).await
+}
+
+#[tauri::command]
+pub async fn cancel_trade_idea(id: String) -> Result<TradeIdea, String> {
+    let state = require_state()?;
+    state.manager.cancel_idea(&id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idea(entry: f64, stop: f64, target: f64, amount: f64, max_risk: f64) -> TradeIdea {
+        let now = Utc::now();
+        let mut idea = TradeIdea {
+            id: "idea".to_string(),
+            wallet_address: "wallet".to_string(),
+            token_mint: "mint".to_string(),
+            token_symbol: "BONK".to_string(),
+            quote_mint: "usdc".to_string(),
+            quote_symbol: "USDC".to_string(),
+            thesis: "breakout".to_string(),
+            entry_price: entry,
+            stop_loss: stop,
+            take_profit: target,
+            amount,
+            max_risk,
+            min_reward_risk: DEFAULT_MIN_REWARD_RISK,
+            risk: compute_idea_risk(0.0, 0.0, 0.0, 0.0),
+            checklist: vec![
+                ChecklistItem {
+                    id: CHECK_LEVELS_VALID.to_string(),
+                    label: "levels".to_string(),
+                    checked: false,
+                    automatic: true,
+                },
+                ChecklistItem {
+                    id: CHECK_RISK_WITHIN_LIMIT.to_string(),
+                    label: "risk".to_string(),
+                    checked: false,
+                    automatic: true,
+                },
+                ChecklistItem {
+                    id: CHECK_REWARD_RISK.to_string(),
+                    label: "reward".to_string(),
+                    checked: false,
+                    automatic: true,
+                },
+                ChecklistItem {
+                    id: "manual".to_string(),
+                    label: "manual".to_string(),
+                    checked: true,
+                    automatic: false,
+                },
+            ],
+            status: TradeIdeaStatus::Draft,
+            entry_order_id: None,
+            stop_order_id: None,
+            take_profit_order_id: None,
+            journal_entry_ids: Vec::new(),
+            outcome: None,
+            created_at: now,
+            updated_at: now,
+            executed_at: None,
+        };
+        refresh_automatic_checks(&mut idea);
+        idea
+    }
+
+    #[test]
+    fn risk_is_measured_in_quote_units() {
+        let risk = compute_idea_risk(10.0, 9.0, 13.0, 1_000.0);
+        assert!((risk.risk_amount - 100.0).abs() < 1e-9);
+        assert!((risk.reward_amount - 300.0).abs() < 1e-9);
+        assert!((risk.reward_risk_ratio - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn checklist_blocks_oversized_risk() {
+        let ok = idea(10.0, 9.0, 13.0, 1_000.0, 150.0);
+        assert!(checklist_blockers(&ok).is_empty());
+
+        let too_risky = idea(10.0, 9.0, 13.0, 1_000.0, 50.0);
+        assert_eq!(checklist_blockers(&too_risky), vec!["risk".to_string()]);
+
+        let inverted = idea(10.0, 11.0, 13.0, 1_000.0, 150.0);
+        assert_eq!(checklist_blockers(&inverted).len(), 3);
+    }
+
+    #[test]
+    fn outcome_reports_r_multiple() {
+        let idea = idea(10.0, 9.0, 13.0, 1_000.0, 150.0);
+        let win = compute_idea_outcome(&idea, "take_profit", 13.0, Utc::now());
+        assert!((win.pnl - 300.0).abs() < 1e-9);
+        assert!((win.r_multiple - 3.0).abs() < 1e-9);
+
+        let loss = compute_idea_outcome(&idea, "stop_loss", 9.0, Utc::now());
+        assert!((loss.pnl_percent + 10.0).abs() < 1e-9);
+        assert!((loss.r_multiple + 1.0).abs() < 1e-9);
+    }
+}
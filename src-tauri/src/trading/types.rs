@@ -127,6 +127,9 @@ pub struct CreateOrderRequest {
     pub slippage_bps: i32,
     pub priority_fee_micro_lamports: i32,
     pub wallet_address: String,
+    /// Protective stop for a buy, used to measure its risk per trade.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_loss_price: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -91,36 +91,55 @@ pub async fn get_portfolio_data(
     query_type: String,
     symbol: Option<String>,
 ) -> Result<serde_json::Value, String> {
+    // Portfolio queries are answered from the demo portfolio until wallet data is wired in
+    if !crate::demo::is_demo_mode() {
+        return Err("UNAVAILABLE: Portfolio data is not available".to_string());
+    }
+
+    let positions = crate::demo::demo_portfolio();
+    let total_value: f64 = positions.iter().map(|p| p.value).sum();
+    let total_pnl: f64 = positions.iter().map(|p| p.unrealized_pnl).sum();
+    let cost_basis = total_value - total_pnl;
+
     match query_type.as_str() {
         "balance" => {
             Ok(serde_json::json!({
-                "balance": 10500.50,
+                "balance": total_value,
                 "currency": "USD"
             }))
         }
         "position" => {
             let sym = symbol.unwrap_or_else(|| "SOL".to_string());
+            let position = positions
+                .iter()
+                .find(|p| p.symbol.eq_ignore_ascii_case(&sym))
+                .ok_or_else(|| format!("NO_POSITION: No {} position", sym))?;
             Ok(serde_json::json!({
-                "symbol": sym,
-                "amount": 25.5,
-                "value": 3825.0,
-                "averagePrice": 150.0
+                "symbol": position.symbol,
+                "amount": position.amount,
+                "value": position.value,
+                "averagePrice": position.average_price
             }))
         }
         "pnl" => {
             Ok(serde_json::json!({
-                "totalPnL": 1250.75,
-                "unrealizedPnL": 850.50,
-                "realizedPnL": 400.25,
-                "percentage": 13.5
+                "totalPnL": total_pnl,
+                "unrealizedPnL": total_pnl,
+                "realizedPnL": 0.0,
+                "percentage": if cost_basis > 0.0 { total_pnl / cost_basis * 100.0 } else { 0.0 }
             }))
         }
         "summary" => {
+            let by_pnl = |a: &&crate::demo::DemoPosition, b: &&crate::demo::DemoPosition| {
+                a.unrealized_pnl.partial_cmp(&b.unrealized_pnl).unwrap_or(std::cmp::Ordering::Equal)
+            };
+            let top_gainer = positions.iter().max_by(by_pnl);
+            let top_loser = positions.iter().min_by(by_pnl);
             Ok(serde_json::json!({
-                "totalValue": 10500.50,
-                "positions": 5,
-                "topGainer": { "symbol": "BONK", "pnl": 250.0 },
-                "topLoser": { "symbol": "RAY", "pnl": -50.0 }
+                "totalValue": total_value,
+                "positions": positions.len(),
+                "topGainer": top_gainer.map(|p| serde_json::json!({ "symbol": p.symbol, "pnl": p.unrealized_pnl })),
+                "topLoser": top_loser.map(|p| serde_json::json!({ "symbol": p.symbol, "pnl": p.unrealized_pnl }))
             }))
         }
         _ => Err(format!("Unknown query type: {}", query_type)),
@@ -133,18 +152,14 @@ pub async fn get_current_price(symbol: String) -> Result<f64, String> {
         return Err("INVALID_SYMBOL: Symbol cannot be empty".to_string());
     }
 
-    // Mock price data - in real implementation would fetch from Birdeye/Jupiter
-    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
-    
-    let price = match symbol.to_uppercase().as_str() {
-        "SOL" => 150.25,
-        "USDC" => 1.0,
-        "BONK" => 0.00001234,
-        "JUP" => 0.85,
-        _ => 100.0,
-    };
-    
-    Ok(price)
+    // Demo prices only - in real implementation would fetch from Birdeye/Jupiter
+    if !crate::demo::is_demo_mode() {
+        return Err("UNAVAILABLE: Price data is not available".to_string());
+    }
+
+    crate::demo::find_demo_token(&symbol)
+        .map(crate::demo::demo_price)
+        .ok_or_else(|| format!("UNKNOWN_SYMBOL: No price for {}", symbol))
 }
 
 #[tauri::command]
@@ -0,0 +1,808 @@
+use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
+use base64::Engine;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use solana_sdk::{
+    instruction::{CompiledInstruction, Instruction},
+    message::{Message, VersionedMessage},
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::VersionedTransaction,
+};
+use sqlx::{FromRow, Pool, Sqlite, SqlitePool};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tokio::sync::{OnceCell, RwLock};
+use uuid::Uuid;
+
+use crate::wallet::nonce_accounts::decompile_legacy_message;
+use crate::wallet::offline_signing::{decode_transaction, uses_durable_nonce};
+
+const COMPUTE_BUDGET_DB_FILE: &str = "compute_budget.db";
+const DEFAULT_RPC_ENDPOINT: &str = "https://api.mainnet-beta.solana.com";
+const COMPUTE_BUDGET_PROGRAM_ID: &str = "ComputeBudget111111111111111111111111111111";
+/// `ComputeBudgetInstruction::SetComputeUnitLimit` discriminator.
+const SET_COMPUTE_UNIT_LIMIT_TAG: u8 = 2;
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+/// Fewer on-chain samples than this and the tuner falls back to simulation.
+const MIN_HISTORY_SAMPLES: usize = 5;
+/// Only the most recent samples per type are used, so tuning follows program upgrades.
+const HISTORY_WINDOW: i64 = 50;
+pub const DEFAULT_SAFETY_MARGIN_PCT: f64 = 15.0;
+/// A transaction that ran out of compute used at least its limit; count it as
+/// needing this much more.
+const EXCEEDED_BUMP: f64 = 1.25;
+
+const SWAP_PROGRAMS: &[&str] = &[
+    "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
+    "JUP4Fb2cqiRUcaTHdrPC8h2gNsA2ETXiPDD33WcGuJB",
+    "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc",
+    "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+    "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK",
+];
+const MULTISIG_PROGRAMS: &[&str] = &[
+    "SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu",
+    "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf",
+];
+/// Programs that make up plain SOL and token transfers.
+const TRANSFER_PROGRAMS: &[&str] = &[
+    "11111111111111111111111111111111",
+    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PfnBqCXEpPxuEb",
+    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+    "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+    COMPUTE_BUDGET_PROGRAM_ID,
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ComputeTxKind {
+    Swap,
+    Transfer,
+    MultisigExec,
+    Other,
+}
+
+impl ComputeTxKind {
+    pub const ALL: [ComputeTxKind; 4] = [
+        ComputeTxKind::Swap,
+        ComputeTxKind::Transfer,
+        ComputeTxKind::MultisigExec,
+        ComputeTxKind::Other,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            ComputeTxKind::Swap => "swap",
+            ComputeTxKind::Transfer => "transfer",
+            ComputeTxKind::MultisigExec => "multisig_exec",
+            ComputeTxKind::Other => "other",
+        }
+    }
+
+    /// Limit used when there is neither history nor a transaction to simulate.
+    fn default_limit(&self) -> u32 {
+        match self {
+            ComputeTxKind::Swap => 600_000,
+            ComputeTxKind::Transfer => 50_000,
+            ComputeTxKind::MultisigExec => 400_000,
+            ComputeTxKind::Other => 200_000,
+        }
+    }
+}
+
+impl FromStr for ComputeTxKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "swap" => Ok(ComputeTxKind::Swap),
+            "transfer" => Ok(ComputeTxKind::Transfer),
+            "multisig_exec" => Ok(ComputeTxKind::MultisigExec),
+            "other" => Ok(ComputeTxKind::Other),
+            _ => Err(format!("Invalid transaction kind: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ComputeBudgetSource {
+    History,
+    Simulation,
+    Default,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ComputeUnitSample {
+    pub signature: String,
+    pub kind: String,
+    pub units_consumed: i64,
+    pub units_requested: Option<i64>,
+    /// The transaction failed for running out of compute units.
+    pub exceeded: bool,
+    /// `onchain` for landed transactions, `simulation` for simulated ones.
+    pub source: String,
+    pub recorded_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComputeBudgetRecommendation {
+    pub kind: ComputeTxKind,
+    pub units: u32,
+    pub source: ComputeBudgetSource,
+    pub samples: usize,
+    pub margin_pct: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComputeKindStats {
+    pub kind: ComputeTxKind,
+    pub samples: usize,
+    pub exceeded_failures: usize,
+    pub median_units: Option<u32>,
+    pub p95_units: Option<u32>,
+    /// Average limit requested by past transactions, to compare with the tuned one.
+    pub avg_requested_units: Option<u32>,
+    pub recommended_units: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TunedTransaction {
+    pub transaction: String,
+    pub recommendation: ComputeBudgetRecommendation,
+}
+
+/// Multisig execution wins over what it executes; otherwise a swap program
+/// makes a swap, and system/token-only transactions are transfers.
+pub fn classify_compute_kind(program_ids: &[&str]) -> ComputeTxKind {
+    if program_ids.iter().any(|id| MULTISIG_PROGRAMS.contains(id)) {
+        ComputeTxKind::MultisigExec
+    } else if program_ids.iter().any(|id| SWAP_PROGRAMS.contains(id)) {
+        ComputeTxKind::Swap
+    } else if !program_ids.is_empty() && program_ids.iter().all(|id| TRANSFER_PROGRAMS.contains(id))
+    {
+        ComputeTxKind::Transfer
+    } else {
+        ComputeTxKind::Other
+    }
+}
+
+fn compute_budget_program() -> Pubkey {
+    Pubkey::from_str(COMPUTE_BUDGET_PROGRAM_ID).expect("valid compute budget program id")
+}
+
+fn compute_unit_limit_data(limit: u32) -> Vec<u8> {
+    let mut data = vec![SET_COMPUTE_UNIT_LIMIT_TAG];
+    data.extend_from_slice(&limit.to_le_bytes());
+    data
+}
+
+fn parse_compute_unit_limit(data: &[u8]) -> Option<u32> {
+    match data {
+        [SET_COMPUTE_UNIT_LIMIT_TAG, a, b, c, d] => Some(u32::from_le_bytes([*a, *b, *c, *d])),
+        _ => None,
+    }
+}
+
+/// Extracts compute usage from a `getTransaction` (json encoding) result.
+pub fn decode_compute_sample(signature: &str, tx: &Value) -> Option<ComputeUnitSample> {
+    let meta = tx.get("meta")?;
+    let units_consumed = meta.get("computeUnitsConsumed")?.as_i64()?;
+    let message = tx.get("transaction")?.get("message")?;
+    let keys: Vec<&str> = message
+        .get("accountKeys")?
+        .as_array()?
+        .iter()
+        .filter_map(|k| {
+            k.as_str()
+                .or_else(|| k.get("pubkey").and_then(|p| p.as_str()))
+        })
+        .collect();
+
+    let mut program_ids = Vec::new();
+    let mut units_requested = None;
+    for instruction in message.get("instructions")?.as_array()? {
+        let Some(program_id) = instruction
+            .get("programIdIndex")
+            .and_then(|i| i.as_u64())
+            .and_then(|i| keys.get(i as usize).copied())
+        else {
+            continue;
+        };
+        if program_id == COMPUTE_BUDGET_PROGRAM_ID {
+            units_requested = instruction
+                .get("data")
+                .and_then(|d| d.as_str())
+                .and_then(|d| bs58::decode(d).into_vec().ok())
+                .and_then(|d| parse_compute_unit_limit(&d))
+                .map(i64::from)
+                .or(units_requested);
+        }
+        program_ids.push(program_id);
+    }
+
+    let exceeded = meta
+        .get("err")
+        .is_some_and(|err| err.to_string().contains("ComputationalBudgetExceeded"));
+
+    Some(ComputeUnitSample {
+        signature: signature.to_string(),
+        kind: classify_compute_kind(&program_ids).as_str().to_string(),
+        units_consumed,
+        units_requested,
+        exceeded,
+        source: "onchain".to_string(),
+        recorded_at: tx
+            .get("blockTime")
+            .and_then(|b| b.as_i64())
+            .unwrap_or_else(|| Utc::now().timestamp()),
+    })
+}
+
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    let rank = ((pct / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
+/// Rounds up to the next thousand units after adding the margin.
+pub fn with_safety_margin(units: f64, margin_pct: f64) -> u32 {
+    let padded = units * (1.0 + margin_pct.max(0.0) / 100.0);
+    let rounded = (padded / 1_000.0).ceil() * 1_000.0;
+    rounded.clamp(1_000.0, MAX_COMPUTE_UNIT_LIMIT as f64) as u32
+}
+
+/// Tunes to the 95th percentile of recent usage plus a margin. Out-of-compute
+/// failures count at a bumped value so the limit climbs past them.
+pub fn recommend_from_history(samples: &[ComputeUnitSample], margin_pct: f64) -> Option<u32> {
+    let mut usage: Vec<f64> = samples
+        .iter()
+        .filter(|sample| sample.source == "onchain")
+        .map(|sample| {
+            let units = sample.units_consumed as f64;
+            if sample.exceeded {
+                units * EXCEEDED_BUMP
+            } else {
+                units
+            }
+        })
+        .collect();
+    if usage.len() < MIN_HISTORY_SAMPLES {
+        return None;
+    }
+    usage.sort_by(|a, b| a.total_cmp(b));
+    Some(with_safety_margin(percentile(&usage, 95.0), margin_pct))
+}
+
+/// Sets the compute unit limit on an unsigned transaction, replacing an
+/// existing limit instruction or adding one (after a durable nonce advance,
+/// which must stay first).
+/// Compute unit limit already requested by a transaction, if it sets one.
+pub fn compute_unit_limit_of(encoded: &str) -> Result<Option<u32>, String> {
+    let transaction = decode_transaction(encoded)?;
+    let program = compute_budget_program();
+    let keys = transaction.message.static_account_keys();
+    Ok(transaction.message.instructions().iter().find_map(|ix| {
+        (keys.get(ix.program_id_index as usize) == Some(&program))
+            .then(|| parse_compute_unit_limit(&ix.data))
+            .flatten()
+    }))
+}
+
+/// Limit to write into a swap: history may raise the aggregator's simulated
+/// limit but never lowers it. `None` keeps the transaction as it is.
+fn tuned_swap_limit(existing: Option<u32>, recommended: u32) -> Option<u32> {
+    match existing {
+        Some(limit) if limit >= recommended => None,
+        _ => Some(recommended),
+    }
+}
+
+pub fn set_compute_unit_limit(encoded: &str, limit: u32) -> Result<String, String> {
+    let mut transaction = decode_transaction(encoded)?;
+    if transaction
+        .signatures
+        .iter()
+        .any(|s| *s != Signature::default())
+    {
+        return Err("Transaction is already signed; set the compute budget before signing".into());
+    }
+
+    let program = compute_budget_program();
+    let keys = transaction.message.static_account_keys().to_vec();
+    let existing = transaction.message.instructions().iter().position(|ix| {
+        keys.get(ix.program_id_index as usize) == Some(&program)
+            && parse_compute_unit_limit(&ix.data).is_some()
+    });
+    let nonce_first = uses_durable_nonce(&transaction);
+
+    match (&mut transaction.message, existing) {
+        (VersionedMessage::Legacy(message), Some(index)) => {
+            message.instructions[index].data = compute_unit_limit_data(limit);
+        }
+        (VersionedMessage::V0(message), Some(index)) => {
+            message.instructions[index].data = compute_unit_limit_data(limit);
+        }
+        (VersionedMessage::Legacy(message), None) => {
+            let mut instructions = decompile_legacy_message(message)?;
+            let at = usize::from(nonce_first && !instructions.is_empty());
+            instructions.insert(
+                at,
+                Instruction::new_with_bytes(program, &compute_unit_limit_data(limit), vec![]),
+            );
+            let blockhash = message.recent_blockhash;
+            let payer = keys
+                .first()
+                .ok_or_else(|| "Transaction has no fee payer".to_string())?;
+            *message = Message::new_with_blockhash(&instructions, Some(payer), &blockhash);
+        }
+        (VersionedMessage::V0(message), None) => {
+            let program_index = keys.iter().position(|key| *key == program).ok_or_else(|| {
+                "Transaction has no compute budget instruction to tune".to_string()
+            })?;
+            let at = usize::from(nonce_first && !message.instructions.is_empty());
+            message.instructions.insert(
+                at,
+                CompiledInstruction::new_from_raw_parts(
+                    program_index as u8,
+                    compute_unit_limit_data(limit),
+                    vec![],
+                ),
+            );
+        }
+    }
+
+    let signers = transaction.message.header().num_required_signatures as usize;
+    transaction.signatures = vec![Signature::default(); signers];
+    let bytes = bincode::serialize(&transaction)
+        .map_err(|e| format!("Failed to serialize transaction: {e}"))?;
+    Ok(BASE64_ENGINE.encode(bytes))
+}
+
+pub struct ComputeBudgetTuner {
+    pool: Pool<Sqlite>,
+    client: reqwest::Client,
+}
+
+pub type SharedComputeBudgetTuner = Arc<RwLock<ComputeBudgetTuner>>;
+
+static COMPUTE_BUDGET_TUNER: OnceCell<SharedComputeBudgetTuner> = OnceCell::const_new();
+
+impl ComputeBudgetTuner {
+    pub async fn new(app: &AppHandle) -> Result<Self, String> {
+        let mut db_path: PathBuf = app
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+
+        std::fs::create_dir_all(&db_path)
+            .map_err(|e| format!("Failed to create data directory: {e}"))?;
+        db_path.push(COMPUTE_BUDGET_DB_FILE);
+
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url)
+            .await
+            .map_err(|e| format!("Failed to open compute budget database: {e}"))?;
+
+        let tuner = Self {
+            pool,
+            client: reqwest::Client::new(),
+        };
+        tuner
+            .initialize()
+            .await
+            .map_err(|e| format!("Failed to initialize compute budget database: {e}"))?;
+        Ok(tuner)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS compute_unit_samples (
+                signature TEXT PRIMARY KEY,
+                kind TEXT NOT NULL,
+                units_consumed INTEGER NOT NULL,
+                units_requested INTEGER,
+                exceeded INTEGER NOT NULL,
+                source TEXT NOT NULL,
+                recorded_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_compute_unit_samples_kind ON compute_unit_samples(kind, recorded_at)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn record_sample(&self, sample: &ComputeUnitSample) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO compute_unit_samples (
+                signature, kind, units_consumed, units_requested, exceeded, source, recorded_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )
+        .bind(&sample.signature)
+        .bind(&sample.kind)
+        .bind(sample.units_consumed)
+        .bind(sample.units_requested)
+        .bind(sample.exceeded)
+        .bind(&sample.source)
+        .bind(sample.recorded_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn recent_samples(
+        &self,
+        kind: ComputeTxKind,
+    ) -> Result<Vec<ComputeUnitSample>, sqlx::Error> {
+        sqlx::query_as::<_, ComputeUnitSample>(
+            r#"
+            SELECT * FROM compute_unit_samples
+            WHERE kind = ?1 AND source = 'onchain'
+            ORDER BY recorded_at DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(kind.as_str())
+        .bind(HISTORY_WINDOW)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn simulate_units(&self, transaction: &str, rpc_url: &str) -> Result<u64, String> {
+        // Simulate at the maximum limit so a low existing limit cannot cut the run short
+        let transaction = set_compute_unit_limit(transaction, MAX_COMPUTE_UNIT_LIMIT)
+            .unwrap_or_else(|_| transaction.to_string());
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "simulateTransaction",
+            "params": [transaction, {
+                "encoding": "base64",
+                "sigVerify": false,
+                "replaceRecentBlockhash": true,
+            }],
+        });
+
+        let data: Value = self
+            .client
+            .post(rpc_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("RPC request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Some(error) = data.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+        let value = &data["result"]["value"];
+        if !value["err"].is_null() {
+            return Err(format!("Simulation failed: {}", value["err"]));
+        }
+        value["unitsConsumed"]
+            .as_u64()
+            .ok_or_else(|| "Simulation returned no compute usage".to_string())
+    }
+
+    /// Uses history when there is enough of it, otherwise simulates the given
+    /// transaction, otherwise falls back to a per-type default.
+    pub async fn recommend(
+        &self,
+        kind: ComputeTxKind,
+        transaction: Option<&str>,
+        margin_pct: f64,
+        rpc_url: &str,
+    ) -> Result<ComputeBudgetRecommendation, String> {
+        let samples = self.recent_samples(kind).await.map_err(|e| e.to_string())?;
+        let recommendation = |units, source| ComputeBudgetRecommendation {
+            kind,
+            units,
+            source,
+            samples: samples.len(),
+            margin_pct,
+        };
+
+        if let Some(units) = recommend_from_history(&samples, margin_pct) {
+            return Ok(recommendation(units, ComputeBudgetSource::History));
+        }
+        if let Some(transaction) = transaction {
+            match self.simulate_units(transaction, rpc_url).await {
+                Ok(consumed) => {
+                    let sample = ComputeUnitSample {
+                        signature: format!("sim_{}", Uuid::new_v4()),
+                        kind: kind.as_str().to_string(),
+                        units_consumed: consumed as i64,
+                        units_requested: None,
+                        exceeded: false,
+                        source: "simulation".to_string(),
+                        recorded_at: Utc::now().timestamp(),
+                    };
+                    if let Err(err) = self.record_sample(&sample).await {
+                        tracing::warn!(error = %err, "failed to record simulated compute usage");
+                    }
+                    let units = with_safety_margin(consumed as f64, margin_pct);
+                    return Ok(recommendation(units, ComputeBudgetSource::Simulation));
+                }
+                Err(err) => tracing::warn!(error = %err, "compute unit simulation failed"),
+            }
+        }
+        Ok(recommendation(
+            kind.default_limit(),
+            ComputeBudgetSource::Default,
+        ))
+    }
+
+    pub async fn stats(&self, margin_pct: f64) -> Result<Vec<ComputeKindStats>, String> {
+        let mut stats = Vec::with_capacity(ComputeTxKind::ALL.len());
+        for kind in ComputeTxKind::ALL {
+            let samples = self.recent_samples(kind).await.map_err(|e| e.to_string())?;
+            let mut used: Vec<f64> = samples
+                .iter()
+                .filter(|s| !s.exceeded)
+                .map(|s| s.units_consumed as f64)
+                .collect();
+            used.sort_by(|a, b| a.total_cmp(b));
+            let requested: Vec<i64> = samples.iter().filter_map(|s| s.units_requested).collect();
+
+            stats.push(ComputeKindStats {
+                kind,
+                samples: samples.len(),
+                exceeded_failures: samples.iter().filter(|s| s.exceeded).count(),
+                median_units: (!used.is_empty()).then(|| percentile(&used, 50.0) as u32),
+                p95_units: (!used.is_empty()).then(|| percentile(&used, 95.0) as u32),
+                avg_requested_units: (!requested.is_empty())
+                    .then(|| (requested.iter().sum::<i64>() / requested.len() as i64) as u32),
+                recommended_units: recommend_from_history(&samples, margin_pct),
+            });
+        }
+        Ok(stats)
+    }
+}
+
+pub async fn init_compute_budget(app: &AppHandle) -> Result<(), String> {
+    if COMPUTE_BUDGET_TUNER.get().is_some() {
+        return Ok(());
+    }
+    let tuner = ComputeBudgetTuner::new(app).await?;
+    COMPUTE_BUDGET_TUNER
+        .set(Arc::new(RwLock::new(tuner)))
+        .map_err(|_| "Compute budget tuner already initialized".to_string())
+}
+
+fn require_tuner() -> Result<&'static SharedComputeBudgetTuner, String> {
+    COMPUTE_BUDGET_TUNER
+        .get()
+        .ok_or_else(|| "Compute budget tuner not initialized".to_string())
+}
+
+/// Records compute usage for a fetched transaction; used by wallet fee sync.
+pub async fn record_transaction_compute(signature: &str, tx: &Value) {
+    let (Ok(tuner), Some(sample)) = (require_tuner(), decode_compute_sample(signature, tx)) else {
+        return;
+    };
+    if let Err(err) = tuner.read().await.record_sample(&sample).await {
+        tracing::warn!(error = %err, "failed to record compute usage");
+    }
+}
+
+/// Raises a swap transaction's limit to the history-tuned one. Without enough
+/// history, or when the aggregator's own simulated limit is already higher,
+/// the transaction is kept as it is.
+pub async fn tune_swap_transaction(transaction: &str) -> Option<String> {
+    let tuner = require_tuner().ok()?.read().await;
+    let samples = tuner.recent_samples(ComputeTxKind::Swap).await.ok()?;
+    let recommended = recommend_from_history(&samples, DEFAULT_SAFETY_MARGIN_PCT)?;
+    let existing = match compute_unit_limit_of(transaction) {
+        Ok(existing) => existing,
+        Err(err) => {
+            tracing::warn!(error = %err, "keeping swap compute budget");
+            return None;
+        }
+    };
+    let units = tuned_swap_limit(existing, recommended)?;
+    match set_compute_unit_limit(transaction, units) {
+        Ok(tuned) => Some(tuned),
+        Err(err) => {
+            tracing::warn!(error = %err, "keeping swap compute budget");
+            None
+        }
+    }
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn get_compute_budget_stats(
+    margin_pct: Option<f64>,
+) -> Result<Vec<ComputeKindStats>, String> {
+    let tuner = require_tuner()?.read().await;
+    tuner
+        .stats(margin_pct.unwrap_or(DEFAULT_SAFETY_MARGIN_PCT))
+        .await
+}
+
+#[tauri::command]
+pub async fn recommend_compute_budget(
+    kind: ComputeTxKind,
+    transaction: Option<String>,
+    margin_pct: Option<f64>,
+    rpc_url: Option<String>,
+) -> Result<ComputeBudgetRecommendation, String> {
+    let tuner = require_tuner()?.read().await;
+    tuner
+        .recommend(
+            kind,
+            transaction.as_deref(),
+            margin_pct.unwrap_or(DEFAULT_SAFETY_MARGIN_PCT),
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await
+}
+
+/// Recommends a limit for an unsigned transaction and writes it into the
+/// transaction's compute budget instruction.
+#[tauri::command]
+pub async fn apply_compute_budget(
+    kind: ComputeTxKind,
+    transaction: String,
+    margin_pct: Option<f64>,
+    rpc_url: Option<String>,
+) -> Result<TunedTransaction, String> {
+    let tuner = require_tuner()?.read().await;
+    let recommendation = tuner
+        .recommend(
+            kind,
+            Some(&transaction),
+            margin_pct.unwrap_or(DEFAULT_SAFETY_MARGIN_PCT),
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await?;
+    Ok(TunedTransaction {
+        transaction: set_compute_unit_limit(&transaction, recommendation.units)?,
+        recommendation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{hash::Hash, system_instruction};
+
+    fn sample(units: i64, exceeded: bool) -> ComputeUnitSample {
+        ComputeUnitSample {
+            signature: Uuid::new_v4().to_string(),
+            kind: "swap".to_string(),
+            units_consumed: units,
+            units_requested: None,
+            exceeded,
+            source: "onchain".to_string(),
+            recorded_at: 0,
+        }
+    }
+
+    #[test]
+    fn classifies_by_invoked_programs() {
+        let system = "11111111111111111111111111111111";
+        assert_eq!(classify_compute_kind(&[system]), ComputeTxKind::Transfer);
+        assert_eq!(
+            classify_compute_kind(&[COMPUTE_BUDGET_PROGRAM_ID, SWAP_PROGRAMS[0]]),
+            ComputeTxKind::Swap
+        );
+        assert_eq!(
+            classify_compute_kind(&[MULTISIG_PROGRAMS[1], SWAP_PROGRAMS[0]]),
+            ComputeTxKind::MultisigExec
+        );
+        assert_eq!(
+            classify_compute_kind(&[system, "Stake11111111111111111111111111111111111111"]),
+            ComputeTxKind::Other
+        );
+
+        let limit_data = bs58::encode(compute_unit_limit_data(180_000)).into_string();
+        let tx = json!({
+            "blockTime": 1_700_000_000,
+            "meta": {
+                "computeUnitsConsumed": 180_000,
+                "err": { "InstructionError": [1, "ComputationalBudgetExceeded"] }
+            },
+            "transaction": { "message": {
+                "accountKeys": ["payer", COMPUTE_BUDGET_PROGRAM_ID, SWAP_PROGRAMS[0]],
+                "instructions": [
+                    { "programIdIndex": 1, "data": limit_data },
+                    { "programIdIndex": 2, "data": "" }
+                ]
+            }}
+        });
+        let decoded = decode_compute_sample("sig", &tx).unwrap();
+        assert_eq!(decoded.kind, "swap");
+        assert_eq!(decoded.units_requested, Some(180_000));
+        assert!(decoded.exceeded);
+    }
+
+    #[test]
+    fn tunes_to_recent_usage_with_margin() {
+        let few: Vec<_> = (0..MIN_HISTORY_SAMPLES - 1)
+            .map(|_| sample(100_000, false))
+            .collect();
+        assert_eq!(recommend_from_history(&few, 15.0), None);
+
+        let steady: Vec<_> = (0..10)
+            .map(|i| sample(100_000 + i * 1_000, false))
+            .collect();
+        assert_eq!(recommend_from_history(&steady, 15.0), Some(126_000));
+
+        // An out-of-compute failure pushes the limit above where it failed
+        let mut failing = steady.clone();
+        failing.push(sample(200_000, true));
+        assert!(recommend_from_history(&failing, 15.0).unwrap() > 200_000);
+
+        assert_eq!(
+            with_safety_margin(2_000_000.0, 15.0),
+            MAX_COMPUTE_UNIT_LIMIT
+        );
+    }
+
+    #[test]
+    fn sets_limit_on_unsigned_transactions() {
+        let payer = Pubkey::new_unique();
+        let transfer = system_instruction::transfer(&payer, &Pubkey::new_unique(), 1);
+        let message = Message::new_with_blockhash(&[transfer], Some(&payer), &Hash::new_unique());
+        let unsigned = BASE64_ENGINE.encode(
+            bincode::serialize(&VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message: VersionedMessage::Legacy(message),
+            })
+            .unwrap(),
+        );
+
+        let limits = |encoded: &str| -> Vec<u32> {
+            let tx = decode_transaction(encoded).unwrap();
+            tx.message
+                .instructions()
+                .iter()
+                .filter_map(|ix| parse_compute_unit_limit(&ix.data))
+                .collect()
+        };
+        assert_eq!(compute_unit_limit_of(&unsigned).unwrap(), None);
+        let added = set_compute_unit_limit(&unsigned, 120_000).unwrap();
+        assert_eq!(limits(&added), vec![120_000]);
+        assert_eq!(compute_unit_limit_of(&added).unwrap(), Some(120_000));
+        let replaced = set_compute_unit_limit(&added, 90_000).unwrap();
+        assert_eq!(limits(&replaced), vec![90_000]);
+        assert_eq!(
+            decode_transaction(&replaced)
+                .unwrap()
+                .message
+                .instructions()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn swap_tuning_never_lowers_the_simulated_limit() {
+        assert_eq!(tuned_swap_limit(None, 150_000), Some(150_000));
+        assert_eq!(tuned_swap_limit(Some(100_000), 150_000), Some(150_000));
+        assert_eq!(tuned_swap_limit(Some(200_000), 150_000), None);
+        assert_eq!(tuned_swap_limit(Some(150_000), 150_000), None);
+    }
+}
@@ -0,0 +1,414 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Pool, Sqlite, SqlitePool};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tokio::sync::{OnceCell, RwLock};
+use uuid::Uuid;
+
+use crate::api::jupiter::{ParsedRoutePlan, SwapCommandInput, SwapMode};
+use crate::wallet::fee_tracker::FeePeriod;
+
+const FEE_TRACKER_DB_FILE: &str = "fee_tracker.db";
+
+/// One swap built through the app, with every fee the app or a referrer takes.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapFeeRecord {
+    pub id: String,
+    pub wallet_address: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub in_amount: String,
+    pub out_amount: String,
+    pub price_impact_pct: f64,
+    pub slippage_bps: i64,
+    /// Fees paid to the AMMs along the route; not app revenue.
+    pub route_fee_bps: i64,
+    pub platform_fee_bps: i64,
+    /// Base units of `platform_fee_mint`; "0" when no fee was collected.
+    pub platform_fee_amount: String,
+    pub platform_fee_mint: String,
+    /// Account the platform or referral fee is paid to.
+    pub fee_account: Option<String>,
+    pub created_at: i64,
+}
+
+impl SwapFeeRecord {
+    pub fn from_swap(input: &SwapCommandInput, route: &ParsedRoutePlan) -> Self {
+        let quote = &input.quote;
+        // Jupiter takes the platform fee from the output of ExactIn swaps and
+        // the input of ExactOut swaps, and only when a fee account is set.
+        let fee_mint = match quote.swap_mode {
+            SwapMode::ExactIn => &quote.output_mint,
+            SwapMode::ExactOut => &quote.input_mint,
+        };
+        let (fee_bps, fee_amount) = match (&input.fee_account, &quote.platform_fee) {
+            (Some(_), Some(fee)) => (fee.fee_bps as i64, fee.amount.clone()),
+            _ => (0, "0".to_string()),
+        };
+
+        Self {
+            id: Uuid::new_v4().to_string(),
+            wallet_address: input.user_public_key.clone(),
+            input_mint: quote.input_mint.clone(),
+            output_mint: quote.output_mint.clone(),
+            in_amount: quote.input_amount.clone(),
+            out_amount: quote.output_amount.clone(),
+            price_impact_pct: quote.price_impact_pct * 100.0,
+            slippage_bps: quote.slippage_bps.unwrap_or_default() as i64,
+            route_fee_bps: route.total_fee_bps as i64,
+            platform_fee_bps: fee_bps,
+            platform_fee_amount: fee_amount,
+            platform_fee_mint: fee_mint.clone(),
+            fee_account: input.fee_account.clone(),
+            created_at: Utc::now().timestamp(),
+        }
+    }
+
+    fn platform_fee_units(&self) -> u128 {
+        self.platform_fee_amount.parse().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRevenue {
+    pub mint: String,
+    /// Base units of `mint`.
+    pub amount: String,
+    pub swap_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeDisclosure {
+    pub period: FeePeriod,
+    pub swap_count: u64,
+    pub swaps_with_fees: u64,
+    pub max_platform_fee_bps: i64,
+    pub revenue: Vec<FeeRevenue>,
+    pub fee_accounts: Vec<String>,
+    pub records: Vec<SwapFeeRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionQualityReport {
+    pub period: FeePeriod,
+    pub swap_count: u64,
+    pub avg_price_impact_pct: f64,
+    pub avg_slippage_bps: f64,
+    pub avg_route_fee_bps: f64,
+    pub avg_platform_fee_bps: f64,
+    pub fee_disclosure: FeeDisclosure,
+}
+
+pub fn summarize_fee_disclosure(period: FeePeriod, records: Vec<SwapFeeRecord>) -> FeeDisclosure {
+    let mut revenue: BTreeMap<String, (u128, u64)> = BTreeMap::new();
+    let mut fee_accounts: Vec<String> = Vec::new();
+    let mut swaps_with_fees = 0;
+
+    for record in &records {
+        let amount = record.platform_fee_units();
+        if amount > 0 {
+            swaps_with_fees += 1;
+            let entry = revenue.entry(record.platform_fee_mint.clone()).or_default();
+            entry.0 += amount;
+            entry.1 += 1;
+        }
+        if let Some(account) = &record.fee_account {
+            if !fee_accounts.contains(account) {
+                fee_accounts.push(account.clone());
+            }
+        }
+    }
+
+    FeeDisclosure {
+        period,
+        swap_count: records.len() as u64,
+        swaps_with_fees,
+        max_platform_fee_bps: records
+            .iter()
+            .map(|record| record.platform_fee_bps)
+            .max()
+            .unwrap_or(0),
+        revenue: revenue
+            .into_iter()
+            .map(|(mint, (amount, swap_count))| FeeRevenue {
+                mint,
+                amount: amount.to_string(),
+                swap_count,
+            })
+            .collect(),
+        fee_accounts,
+        records,
+    }
+}
+
+pub fn summarize_execution_quality(
+    period: FeePeriod,
+    records: Vec<SwapFeeRecord>,
+) -> ExecutionQualityReport {
+    let count = records.len() as f64;
+    let average = |value: fn(&SwapFeeRecord) -> f64| {
+        if records.is_empty() {
+            0.0
+        } else {
+            records.iter().map(value).sum::<f64>() / count
+        }
+    };
+
+    ExecutionQualityReport {
+        period,
+        swap_count: records.len() as u64,
+        avg_price_impact_pct: average(|r| r.price_impact_pct),
+        avg_slippage_bps: average(|r| r.slippage_bps as f64),
+        avg_route_fee_bps: average(|r| r.route_fee_bps as f64),
+        avg_platform_fee_bps: average(|r| r.platform_fee_bps as f64),
+        fee_disclosure: summarize_fee_disclosure(period, records),
+    }
+}
+
+pub struct FeeDisclosureLedger {
+    pool: Pool<Sqlite>,
+}
+
+pub type SharedFeeDisclosureLedger = Arc<RwLock<FeeDisclosureLedger>>;
+
+static FEE_DISCLOSURE_LEDGER: OnceCell<SharedFeeDisclosureLedger> = OnceCell::const_new();
+
+impl FeeDisclosureLedger {
+    pub async fn new(app: &AppHandle) -> Result<Self, String> {
+        let mut db_path: PathBuf = app
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+
+        std::fs::create_dir_all(&db_path)
+            .map_err(|e| format!("Failed to create data directory: {e}"))?;
+        db_path.push(FEE_TRACKER_DB_FILE);
+
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url)
+            .await
+            .map_err(|e| format!("Failed to open fee disclosure database: {e}"))?;
+
+        let ledger = Self { pool };
+        ledger
+            .initialize()
+            .await
+            .map_err(|e| format!("Failed to initialize fee disclosure ledger: {e}"))?;
+        Ok(ledger)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS swap_fee_records (
+                id TEXT PRIMARY KEY,
+                wallet_address TEXT NOT NULL,
+                input_mint TEXT NOT NULL,
+                output_mint TEXT NOT NULL,
+                in_amount TEXT NOT NULL,
+                out_amount TEXT NOT NULL,
+                price_impact_pct REAL NOT NULL,
+                slippage_bps INTEGER NOT NULL,
+                route_fee_bps INTEGER NOT NULL,
+                platform_fee_bps INTEGER NOT NULL,
+                platform_fee_amount TEXT NOT NULL,
+                platform_fee_mint TEXT NOT NULL,
+                fee_account TEXT,
+                created_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_swap_fee_records_wallet_time ON swap_fee_records(wallet_address, created_at)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_record(&self, record: &SwapFeeRecord) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO swap_fee_records (
+                id, wallet_address, input_mint, output_mint, in_amount, out_amount,
+                price_impact_pct, slippage_bps, route_fee_bps, platform_fee_bps,
+                platform_fee_amount, platform_fee_mint, fee_account, created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+            "#,
+        )
+        .bind(&record.id)
+        .bind(&record.wallet_address)
+        .bind(&record.input_mint)
+        .bind(&record.output_mint)
+        .bind(&record.in_amount)
+        .bind(&record.out_amount)
+        .bind(record.price_impact_pct)
+        .bind(record.slippage_bps)
+        .bind(record.route_fee_bps)
+        .bind(record.platform_fee_bps)
+        .bind(&record.platform_fee_amount)
+        .bind(&record.platform_fee_mint)
+        .bind(&record.fee_account)
+        .bind(record.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_records(
+        &self,
+        wallet_address: Option<&str>,
+        since: i64,
+    ) -> Result<Vec<SwapFeeRecord>, sqlx::Error> {
+        match wallet_address {
+            Some(wallet) => {
+                sqlx::query_as::<_, SwapFeeRecord>(
+                    "SELECT * FROM swap_fee_records WHERE wallet_address = ?1 AND created_at >= ?2 ORDER BY created_at DESC",
+                )
+                .bind(wallet)
+                .bind(since)
+                .fetch_all(&self.pool)
+                .await
+            }
+            None => {
+                sqlx::query_as::<_, SwapFeeRecord>(
+                    "SELECT * FROM swap_fee_records WHERE created_at >= ?1 ORDER BY created_at DESC",
+                )
+                .bind(since)
+                .fetch_all(&self.pool)
+                .await
+            }
+        }
+    }
+}
+
+pub async fn init_fee_disclosure(app: &AppHandle) -> Result<(), String> {
+    if FEE_DISCLOSURE_LEDGER.get().is_some() {
+        return Ok(());
+    }
+    let ledger = FeeDisclosureLedger::new(app).await?;
+    FEE_DISCLOSURE_LEDGER
+        .set(Arc::new(RwLock::new(ledger)))
+        .map_err(|_| "Fee disclosure ledger already initialized".to_string())
+}
+
+fn require_ledger() -> Result<&'static SharedFeeDisclosureLedger, String> {
+    FEE_DISCLOSURE_LEDGER
+        .get()
+        .ok_or_else(|| "Fee disclosure ledger not initialized".to_string())
+}
+
+/// Records the fees embedded in a swap the app just built.
+pub async fn record_swap_fees(input: &SwapCommandInput, route: &ParsedRoutePlan) {
+    let Ok(ledger) = require_ledger() else {
+        return;
+    };
+    let record = SwapFeeRecord::from_swap(input, route);
+    if let Err(err) = ledger.read().await.insert_record(&record).await {
+        tracing::warn!(error = %err, "failed to record swap fees");
+    }
+}
+
+async fn records_for(
+    period: FeePeriod,
+    wallet: Option<&str>,
+) -> Result<Vec<SwapFeeRecord>, String> {
+    let since = period.start(Utc::now()).timestamp();
+    let ledger = require_ledger()?.read().await;
+    ledger
+        .get_records(wallet, since)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_fee_disclosure(
+    period: FeePeriod,
+    wallet: Option<String>,
+) -> Result<FeeDisclosure, String> {
+    let records = records_for(period, wallet.as_deref()).await?;
+    Ok(summarize_fee_disclosure(period, records))
+}
+
+#[tauri::command]
+pub async fn get_execution_quality_report(
+    period: FeePeriod,
+    wallet: Option<String>,
+) -> Result<ExecutionQualityReport, String> {
+    let records = records_for(period, wallet.as_deref()).await?;
+    Ok(summarize_execution_quality(period, records))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(fee_bps: i64, fee_amount: &str, fee_mint: &str, impact: f64) -> SwapFeeRecord {
+        SwapFeeRecord {
+            id: Uuid::new_v4().to_string(),
+            wallet_address: "wallet".into(),
+            input_mint: "SOL".into(),
+            output_mint: fee_mint.into(),
+            in_amount: "1000000".into(),
+            out_amount: "995000".into(),
+            price_impact_pct: impact,
+            slippage_bps: 50,
+            route_fee_bps: 25,
+            platform_fee_bps: fee_bps,
+            platform_fee_amount: fee_amount.into(),
+            platform_fee_mint: fee_mint.into(),
+            fee_account: (fee_bps > 0).then(|| "referral".to_string()),
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn disclosure_totals_revenue_per_mint() {
+        let disclosure = summarize_fee_disclosure(
+            FeePeriod::Month,
+            vec![
+                record(20, "2000", "USDC", 0.1),
+                record(20, "3000", "USDC", 0.1),
+                record(0, "0", "BONK", 0.1),
+            ],
+        );
+        assert_eq!(disclosure.swap_count, 3);
+        assert_eq!(disclosure.swaps_with_fees, 2);
+        assert_eq!(disclosure.max_platform_fee_bps, 20);
+        assert_eq!(disclosure.revenue.len(), 1);
+        assert_eq!(disclosure.revenue[0].amount, "5000");
+        assert_eq!(disclosure.fee_accounts, vec!["referral".to_string()]);
+    }
+
+    #[test]
+    fn execution_quality_averages_costs() {
+        let report = summarize_execution_quality(
+            FeePeriod::Week,
+            vec![record(20, "2000", "USDC", 0.2), record(0, "0", "USDC", 0.4)],
+        );
+        assert_eq!(report.swap_count, 2);
+        assert!((report.avg_price_impact_pct - 0.3).abs() < 1e-12);
+        assert!((report.avg_platform_fee_bps - 10.0).abs() < 1e-12);
+        assert_eq!(report.fee_disclosure.swaps_with_fees, 1);
+    }
+
+    #[test]
+    fn empty_period_reports_zeroes() {
+        let report = summarize_execution_quality(FeePeriod::All, Vec::new());
+        assert_eq!(report.swap_count, 0);
+        assert_eq!(report.avg_slippage_bps, 0.0);
+        assert!(report.fee_disclosure.revenue.is_empty());
+    }
+}
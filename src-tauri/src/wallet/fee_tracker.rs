@@ -0,0 +1,863 @@
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::{FromRow, Pool, Sqlite, SqlitePool};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use crate::api_config::ApiConfigManager;
+use crate::bots::analytics::BotStatementLine;
+use crate::security::keystore::Keystore;
+use crate::wallet::performance::SharedPerformanceDatabase;
+
+const FEE_TRACKER_DB_FILE: &str = "fee_tracker.db";
+const DEFAULT_RPC_ENDPOINT: &str = "https://api.mainnet-beta.solana.com";
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+const DEFAULT_SYNC_LIMIT: usize = 100;
+const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PfnBqCXEpPxuEb";
+const SYSTEM_CREATE_ACCOUNT: u32 = 0;
+const SYSTEM_TRANSFER: u32 = 2;
+const SYSTEM_CREATE_ACCOUNT_WITH_SEED: u32 = 3;
+const SYSTEM_ALLOCATE: u32 = 8;
+const SYSTEM_ALLOCATE_WITH_SEED: u32 = 9;
+const TOKEN_CLOSE_ACCOUNT: u8 = 9;
+const WRAPPED_SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+const SOL_PRICE_HISTORY_URL: &str = "https://public-api.birdeye.so/defi/history_price";
+/// Hourly candles are close enough for fees; one lookup covers every
+/// transaction in the same hour.
+const PRICE_BUCKET_SECS: i64 = 3_600;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRecord {
+    pub signature: String,
+    pub wallet_address: String,
+    pub slot: i64,
+    pub block_time: i64,
+    pub base_fee_lamports: i64,
+    pub priority_fee_lamports: i64,
+    pub rent_paid_lamports: i64,
+    pub rent_reclaimed_lamports: i64,
+    /// SOL/USD at the transaction's block time; 0 until a historical price is found.
+    pub sol_price_usd: f64,
+}
+
+impl FeeRecord {
+    pub fn network_fee_lamports(&self) -> i64 {
+        self.base_fee_lamports + self.priority_fee_lamports
+    }
+
+    pub fn total_cost_usd(&self) -> f64 {
+        let lamports =
+            self.network_fee_lamports() + self.rent_paid_lamports - self.rent_reclaimed_lamports;
+        lamports as f64 / LAMPORTS_PER_SOL * self.sol_price_usd
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FeePeriod {
+    #[serde(rename = "7d")]
+    Week,
+    #[serde(rename = "30d")]
+    Month,
+    #[serde(rename = "90d")]
+    Quarter,
+    Ytd,
+    All,
+}
+
+impl FeePeriod {
+    pub fn start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            FeePeriod::Week => now - Duration::days(7),
+            FeePeriod::Month => now - Duration::days(30),
+            FeePeriod::Quarter => now - Duration::days(90),
+            FeePeriod::Ytd => Utc
+                .with_ymd_and_hms(now.year(), 1, 1, 0, 0, 0)
+                .single()
+                .unwrap_or(now),
+            FeePeriod::All => DateTime::<Utc>::from_timestamp(0, 0).unwrap_or(now),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeTotals {
+    pub transaction_count: u64,
+    pub base_fees_sol: f64,
+    pub priority_fees_sol: f64,
+    pub rent_paid_sol: f64,
+    pub rent_reclaimed_sol: f64,
+    pub net_cost_sol: f64,
+    pub net_cost_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyFeeSummary {
+    /// Calendar month as `YYYY-MM`.
+    pub month: String,
+    pub totals: FeeTotals,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeSummary {
+    pub wallet_address: Option<String>,
+    pub period: FeePeriod,
+    pub totals: FeeTotals,
+    pub monthly: Vec<MonthlyFeeSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyStatementLine {
+    pub month: String,
+    pub realized_pnl: f64,
+    pub trading_fees: f64,
+    pub network_costs_usd: f64,
+    pub net_pnl: f64,
+    /// Per-bot activity for the month, shown alongside the totals rather than added to them.
+    #[serde(default)]
+    pub bots: Vec<BotStatementLine>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeAdjustedPnl {
+    pub wallet_address: String,
+    pub period: FeePeriod,
+    pub realized_pnl: f64,
+    pub trading_fees: f64,
+    pub network_costs_usd: f64,
+    pub net_pnl: f64,
+    pub monthly: Vec<MonthlyStatementLine>,
+}
+
+fn month_key(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0)
+        .map(|dt| format!("{:04}-{:02}", dt.year(), dt.month()))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn add_to_totals(totals: &mut FeeTotals, record: &FeeRecord) {
+    totals.transaction_count += 1;
+    totals.base_fees_sol += record.base_fee_lamports as f64 / LAMPORTS_PER_SOL;
+    totals.priority_fees_sol += record.priority_fee_lamports as f64 / LAMPORTS_PER_SOL;
+    totals.rent_paid_sol += record.rent_paid_lamports as f64 / LAMPORTS_PER_SOL;
+    totals.rent_reclaimed_sol += record.rent_reclaimed_lamports as f64 / LAMPORTS_PER_SOL;
+    totals.net_cost_sol = totals.base_fees_sol + totals.priority_fees_sol + totals.rent_paid_sol
+        - totals.rent_reclaimed_sol;
+    totals.net_cost_usd += record.total_cost_usd();
+}
+
+pub fn summarize_fees(
+    wallet_address: Option<String>,
+    period: FeePeriod,
+    records: &[FeeRecord],
+) -> FeeSummary {
+    let mut totals = FeeTotals::default();
+    let mut monthly: BTreeMap<String, FeeTotals> = BTreeMap::new();
+
+    for record in records {
+        add_to_totals(&mut totals, record);
+        add_to_totals(monthly.entry(month_key(record.block_time)).or_default(), record);
+    }
+
+    FeeSummary {
+        wallet_address,
+        period,
+        totals,
+        monthly: monthly
+            .into_iter()
+            .map(|(month, totals)| MonthlyFeeSummary { month, totals })
+            .collect(),
+    }
+}
+
+fn statement_line(
+    months: &mut BTreeMap<String, MonthlyStatementLine>,
+    month: String,
+) -> &mut MonthlyStatementLine {
+    months.entry(month.clone()).or_insert(MonthlyStatementLine {
+        month,
+        realized_pnl: 0.0,
+        trading_fees: 0.0,
+        network_costs_usd: 0.0,
+        net_pnl: 0.0,
+        bots: Vec::new(),
+    })
+}
+
+/// An instruction from a `getTransaction` (json encoding) result, top-level or inner.
+struct DecodedInstruction {
+    program_id: String,
+    accounts: Vec<usize>,
+    data: Vec<u8>,
+}
+
+fn decode_instruction(keys: &[String], raw: &Value) -> Option<DecodedInstruction> {
+    let program_id = keys.get(raw.get("programIdIndex")?.as_u64()? as usize)?.clone();
+    let accounts = raw
+        .get("accounts")?
+        .as_array()?
+        .iter()
+        .filter_map(|index| index.as_u64().map(|i| i as usize))
+        .collect();
+    let data = bs58::decode(raw.get("data")?.as_str()?).into_vec().ok()?;
+    Some(DecodedInstruction {
+        program_id,
+        accounts,
+        data,
+    })
+}
+
+/// All instructions in execution order, each top-level one followed by its CPIs.
+fn instruction_timeline(tx: &Value, keys: &[String]) -> Vec<DecodedInstruction> {
+    let mut inner: BTreeMap<u64, Vec<DecodedInstruction>> = BTreeMap::new();
+    let groups = tx
+        .pointer("/meta/innerInstructions")
+        .and_then(|g| g.as_array())
+        .cloned()
+        .unwrap_or_default();
+    for group in &groups {
+        let Some(index) = group.get("index").and_then(|i| i.as_u64()) else {
+            continue;
+        };
+        let instructions = group
+            .get("instructions")
+            .and_then(|i| i.as_array())
+            .map(|list| list.iter().filter_map(|raw| decode_instruction(keys, raw)).collect())
+            .unwrap_or_default();
+        inner.insert(index, instructions);
+    }
+
+    let top_level = tx
+        .pointer("/transaction/message/instructions")
+        .and_then(|i| i.as_array())
+        .cloned()
+        .unwrap_or_default();
+    let mut timeline = Vec::new();
+    for (index, raw) in top_level.iter().enumerate() {
+        if let Some(instruction) = decode_instruction(keys, raw) {
+            timeline.push(instruction);
+        }
+        timeline.extend(inner.remove(&(index as u64)).unwrap_or_default());
+    }
+    timeline
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
+
+/// Lamports moved by a system `CreateAccountWithSeed`: base pubkey, then a
+/// length-prefixed seed, then the lamports.
+fn create_with_seed_lamports(data: &[u8]) -> Option<u64> {
+    let seed_len = read_u64(data, 36)? as usize;
+    read_u64(data, 44 + seed_len)
+}
+
+/// Extracts fee and rent movements for `wallet` from a `getTransaction` (json encoding) result.
+///
+/// Rent is only attributed when the wallet paid the transaction fee, and only
+/// from what the instructions did: lamports the wallet put into system
+/// `CreateAccount` calls (or transferred into an account it then had allocated)
+/// count as rent paid, and token accounts closed back to the wallet as rent
+/// reclaimed. Other balance changes, such as swaps or transfers, are ignored.
+pub fn decode_fee_record(wallet: &str, signature: &str, tx: &Value) -> Option<FeeRecord> {
+    let meta = tx.get("meta")?;
+    let message = tx.get("transaction")?.get("message")?;
+    let mut keys: Vec<String> = message
+        .get("accountKeys")?
+        .as_array()?
+        .iter()
+        .filter_map(|k| {
+            k.as_str()
+                .map(|s| s.to_string())
+                .or_else(|| k.get("pubkey").and_then(|p| p.as_str()).map(|s| s.to_string()))
+        })
+        .collect();
+
+    let fee_payer = keys.first()?;
+    if fee_payer != wallet {
+        return None;
+    }
+
+    // Versioned transactions index lookup-table accounts after the static keys
+    for section in ["writable", "readonly"] {
+        if let Some(loaded) = meta
+            .get("loadedAddresses")
+            .and_then(|l| l.get(section))
+            .and_then(|l| l.as_array())
+        {
+            keys.extend(loaded.iter().filter_map(|k| k.as_str().map(|s| s.to_string())));
+        }
+    }
+
+    let fee = meta.get("fee")?.as_u64()?;
+    let signatures = tx
+        .get("transaction")
+        .and_then(|t| t.get("signatures"))
+        .and_then(|s| s.as_array())
+        .map(|s| s.len() as u64)
+        .unwrap_or(1)
+        .max(1);
+    let base_fee = (signatures * LAMPORTS_PER_SIGNATURE).min(fee);
+
+    let pre = meta.get("preBalances")?.as_array()?;
+    let is_wallet = |index: Option<&usize>| {
+        index.and_then(|i| keys.get(*i)).is_some_and(|key| key == wallet)
+    };
+    let mut prefunded: BTreeMap<usize, u64> = BTreeMap::new();
+    let mut rent_paid = 0u64;
+    let mut rent_reclaimed = 0u64;
+    for instruction in instruction_timeline(tx, &keys) {
+        let accounts = &instruction.accounts;
+        match instruction.program_id.as_str() {
+            SYSTEM_PROGRAM_ID => match read_u32(&instruction.data, 0) {
+                Some(SYSTEM_CREATE_ACCOUNT) if is_wallet(accounts.first()) => {
+                    rent_paid += read_u64(&instruction.data, 4).unwrap_or(0);
+                }
+                Some(SYSTEM_CREATE_ACCOUNT_WITH_SEED) if is_wallet(accounts.first()) => {
+                    rent_paid += create_with_seed_lamports(&instruction.data).unwrap_or(0);
+                }
+                // Funding and then allocating an account is how pre-funded
+                // accounts are created
+                Some(SYSTEM_TRANSFER) if is_wallet(accounts.first()) => {
+                    if let (Some(to), Some(lamports)) =
+                        (accounts.get(1), read_u64(&instruction.data, 4))
+                    {
+                        *prefunded.entry(*to).or_default() += lamports;
+                    }
+                }
+                Some(SYSTEM_ALLOCATE) | Some(SYSTEM_ALLOCATE_WITH_SEED) => {
+                    if let Some(account) = accounts.first() {
+                        rent_paid += prefunded.remove(account).unwrap_or(0);
+                    }
+                }
+                _ => {}
+            },
+            TOKEN_PROGRAM_ID | TOKEN_2022_PROGRAM_ID
+                if instruction.data.first() == Some(&TOKEN_CLOSE_ACCOUNT)
+                    && is_wallet(accounts.get(1)) =>
+            {
+                rent_reclaimed += accounts
+                    .first()
+                    .and_then(|account| pre.get(*account))
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(0);
+            }
+            _ => {}
+        }
+    }
+
+    Some(FeeRecord {
+        signature: signature.to_string(),
+        wallet_address: wallet.to_string(),
+        slot: tx.get("slot").and_then(|s| s.as_i64()).unwrap_or(0),
+        block_time: tx.get("blockTime").and_then(|b| b.as_i64()).unwrap_or(0),
+        base_fee_lamports: base_fee as i64,
+        priority_fee_lamports: (fee - base_fee) as i64,
+        rent_paid_lamports: rent_paid as i64,
+        rent_reclaimed_lamports: rent_reclaimed as i64,
+        sol_price_usd: 0.0,
+    })
+}
+
+pub struct FeeTracker {
+    pool: Pool<Sqlite>,
+    client: reqwest::Client,
+}
+
+pub type SharedFeeTracker = Arc<RwLock<FeeTracker>>;
+
+impl FeeTracker {
+    pub async fn new(app: &AppHandle) -> Result<Self, String> {
+        let mut db_path: PathBuf = app
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+
+        std::fs::create_dir_all(&db_path)
+            .map_err(|e| format!("Failed to create data directory: {e}"))?;
+        db_path.push(FEE_TRACKER_DB_FILE);
+
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url)
+            .await
+            .map_err(|e| format!("Failed to open fee tracker database: {e}"))?;
+
+        let tracker = Self {
+            pool,
+            client: reqwest::Client::new(),
+        };
+        tracker
+            .initialize()
+            .await
+            .map_err(|e| format!("Failed to initialize fee tracker: {e}"))?;
+        Ok(tracker)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS fee_records (
+                signature TEXT NOT NULL,
+                wallet_address TEXT NOT NULL,
+                slot INTEGER NOT NULL,
+                block_time INTEGER NOT NULL,
+                base_fee_lamports INTEGER NOT NULL,
+                priority_fee_lamports INTEGER NOT NULL,
+                rent_paid_lamports INTEGER NOT NULL,
+                rent_reclaimed_lamports INTEGER NOT NULL,
+                sol_price_usd REAL NOT NULL,
+                PRIMARY KEY (signature, wallet_address)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_fee_records_wallet_time ON fee_records(wallet_address, block_time)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_record(&self, record: &FeeRecord) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO fee_records (
+                signature, wallet_address, slot, block_time, base_fee_lamports,
+                priority_fee_lamports, rent_paid_lamports, rent_reclaimed_lamports, sol_price_usd
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "#,
+        )
+        .bind(&record.signature)
+        .bind(&record.wallet_address)
+        .bind(record.slot)
+        .bind(record.block_time)
+        .bind(record.base_fee_lamports)
+        .bind(record.priority_fee_lamports)
+        .bind(record.rent_paid_lamports)
+        .bind(record.rent_reclaimed_lamports)
+        .bind(record.sol_price_usd)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_records(
+        &self,
+        wallet_address: Option<&str>,
+        since: i64,
+    ) -> Result<Vec<FeeRecord>, sqlx::Error> {
+        match wallet_address {
+            Some(wallet) => {
+                sqlx::query_as::<_, FeeRecord>(
+                    r#"
+                    SELECT * FROM fee_records
+                    WHERE wallet_address = ?1 AND block_time >= ?2
+                    ORDER BY block_time ASC
+                    "#,
+                )
+                .bind(wallet)
+                .bind(since)
+                .fetch_all(&self.pool)
+                .await
+            }
+            None => {
+                sqlx::query_as::<_, FeeRecord>(
+                    "SELECT * FROM fee_records WHERE block_time >= ?1 ORDER BY block_time ASC",
+                )
+                .bind(since)
+                .fetch_all(&self.pool)
+                .await
+            }
+        }
+    }
+
+    async fn has_record(&self, signature: &str, wallet_address: &str) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM fee_records WHERE signature = ?1 AND wallet_address = ?2)",
+        )
+        .bind(signature)
+        .bind(wallet_address)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn rpc(&self, rpc_url: &str, method: &str, params: Value) -> Result<Value, String> {
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let data: Value = self
+            .client
+            .post(rpc_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("RPC request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Some(error) = data.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+
+        Ok(data["result"].clone())
+    }
+
+    /// SOL/USD for the hour starting at `bucket_start`, from Birdeye's price history.
+    async fn sol_price_at(&self, bucket_start: i64, api_key: &str) -> Result<f64, String> {
+        let response: Value = self
+            .client
+            .get(SOL_PRICE_HISTORY_URL)
+            .query(&[
+                ("address", WRAPPED_SOL_MINT),
+                ("address_type", "token"),
+                ("type", "1H"),
+            ])
+            .query(&[
+                ("time_from", bucket_start),
+                ("time_to", bucket_start + PRICE_BUCKET_SECS),
+            ])
+            .header("X-API-KEY", api_key)
+            .header("x-chain", "solana")
+            .send()
+            .await
+            .map_err(|e| format!("Price history request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse price history: {}", e))?;
+
+        response
+            .pointer("/data/items")
+            .and_then(|items| items.as_array())
+            .and_then(|items| items.iter().find_map(|item| item.get("value")?.as_f64()))
+            .filter(|price| *price > 0.0)
+            .ok_or_else(|| format!("No SOL price recorded at {}", bucket_start))
+    }
+
+    /// Prices records that still lack the SOL price at their block time.
+    async fn fill_missing_prices(&self, wallet_address: &str, api_key: &str) -> Result<(), String> {
+        let block_times: Vec<i64> = sqlx::query_scalar(
+            r#"
+            SELECT DISTINCT block_time FROM fee_records
+            WHERE wallet_address = ?1 AND sol_price_usd = 0 AND block_time > 0
+            "#,
+        )
+        .bind(wallet_address)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let mut buckets: HashMap<i64, Option<f64>> = HashMap::new();
+        for block_time in block_times {
+            let bucket = block_time - block_time.rem_euclid(PRICE_BUCKET_SECS);
+            if !buckets.contains_key(&bucket) {
+                let price = match self.sol_price_at(bucket, api_key).await {
+                    Ok(price) => Some(price),
+                    Err(err) => {
+                        tracing::warn!(error = %err, "no historical SOL price for fee records");
+                        None
+                    }
+                };
+                buckets.insert(bucket, price);
+            }
+            let Some(price) = buckets[&bucket] else {
+                continue;
+            };
+
+            sqlx::query(
+                r#"
+                UPDATE fee_records SET sol_price_usd = ?1
+                WHERE wallet_address = ?2 AND block_time = ?3 AND sol_price_usd = 0
+                "#,
+            )
+            .bind(price)
+            .bind(wallet_address)
+            .bind(block_time)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    /// Pulls recent signatures for a wallet and records fees for any not seen before.
+    /// Records are priced at their block time when a price history key is available;
+    /// records left unpriced by earlier syncs are retried.
+    pub async fn sync_wallet(
+        &self,
+        wallet_address: &str,
+        rpc_url: &str,
+        limit: usize,
+        price_api_key: Option<&str>,
+    ) -> Result<usize, String> {
+        let signatures = self
+            .rpc(
+                rpc_url,
+                "getSignaturesForAddress",
+                json!([wallet_address, { "limit": limit }]),
+            )
+            .await?;
+
+        let mut recorded = 0;
+        for entry in signatures.as_array().cloned().unwrap_or_default() {
+            let Some(signature) = entry.get("signature").and_then(|s| s.as_str()) else {
+                continue;
+            };
+            if self
+                .has_record(signature, wallet_address)
+                .await
+                .map_err(|e| e.to_string())?
+            {
+                continue;
+            }
+
+            let tx = self
+                .rpc(
+                    rpc_url,
+                    "getTransaction",
+                    json!([signature, { "encoding": "json", "maxSupportedTransactionVersion": 0 }]),
+                )
+                .await?;
+
+            if let Some(record) = decode_fee_record(wallet_address, signature, &tx) {
+                crate::wallet::compute_budget::record_transaction_compute(signature, &tx).await;
+                self.insert_record(&record).await.map_err(|e| e.to_string())?;
+                recorded += 1;
+            }
+        }
+
+        if let Some(api_key) = price_api_key {
+            self.fill_missing_prices(wallet_address, api_key).await?;
+        }
+
+        Ok(recorded)
+    }
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn sync_wallet_fees(
+    app_handle: AppHandle,
+    tracker: State<'_, SharedFeeTracker>,
+    wallet: String,
+    rpc_url: Option<String>,
+    limit: Option<usize>,
+) -> Result<usize, String> {
+    let price_api_key = match (
+        app_handle.try_state::<ApiConfigManager>(),
+        app_handle.try_state::<Keystore>(),
+    ) {
+        (Some(manager), Some(keystore)) => manager
+            .resolve_key("birdeye", &keystore)
+            .ok()
+            .map(|resolved| resolved.key)
+            .filter(|key| !key.is_empty()),
+        _ => None,
+    };
+
+    let tracker = tracker.read().await;
+    tracker
+        .sync_wallet(
+            &wallet,
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+            limit.unwrap_or(DEFAULT_SYNC_LIMIT),
+            price_api_key.as_deref(),
+        )
+        .await
+}
+
+#[tauri::command]
+pub async fn get_fee_summary(
+    tracker: State<'_, SharedFeeTracker>,
+    period: FeePeriod,
+    wallet: Option<String>,
+) -> Result<FeeSummary, String> {
+    let since = period.start(Utc::now()).timestamp();
+    let tracker = tracker.read().await;
+    let records = tracker
+        .get_records(wallet.as_deref(), since)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(summarize_fees(wallet, period, &records))
+}
+
+#[tauri::command]
+pub async fn get_fee_adjusted_pnl(
+    tracker: State<'_, SharedFeeTracker>,
+    performance: State<'_, SharedPerformanceDatabase>,
+    period: FeePeriod,
+    wallet: String,
+) -> Result<FeeAdjustedPnl, String> {
+    let now = Utc::now();
+    let start = period.start(now);
+
+    let records = {
+        let tracker = tracker.read().await;
+        tracker
+            .get_records(Some(&wallet), start.timestamp())
+            .await
+            .map_err(|e| e.to_string())?
+    };
+    let trades = {
+        let db = performance.read().await;
+        db.get_trades_between(&wallet, start, now)
+            .await
+            .map_err(|e| e.to_string())?
+    };
+
+    let mut months: BTreeMap<String, MonthlyStatementLine> = BTreeMap::new();
+    for trade in &trades {
+        let entry = statement_line(&mut months, month_key(trade.timestamp.timestamp()));
+        entry.realized_pnl += trade.pnl.unwrap_or(0.0);
+        entry.trading_fees += trade.fee;
+    }
+    for record in &records {
+        let entry = statement_line(&mut months, month_key(record.block_time));
+        entry.network_costs_usd += record.total_cost_usd();
+    }
+    for (month, bots) in crate::bots::analytics::bot_statement_lines(&wallet, start).await {
+        statement_line(&mut months, month).bots = bots;
+    }
+
+    let mut monthly: Vec<MonthlyStatementLine> = months.into_values().collect();
+    for entry in monthly.iter_mut() {
+        entry.net_pnl = entry.realized_pnl - entry.trading_fees - entry.network_costs_usd;
+    }
+
+    let realized_pnl = monthly.iter().map(|m| m.realized_pnl).sum();
+    let trading_fees = monthly.iter().map(|m| m.trading_fees).sum();
+    let network_costs_usd = monthly.iter().map(|m| m.network_costs_usd).sum();
+
+    Ok(FeeAdjustedPnl {
+        wallet_address: wallet,
+        period,
+        realized_pnl,
+        trading_fees,
+        network_costs_usd,
+        net_pnl: realized_pnl - trading_fees - network_costs_usd,
+        monthly,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn system(tag: u32, amount: u64) -> String {
+        let mut data = tag.to_le_bytes().to_vec();
+        data.extend(amount.to_le_bytes());
+        bs58::encode(data).into_string()
+    }
+
+    fn ix(program: usize, accounts: &[usize], data: String) -> Value {
+        json!({ "programIdIndex": program, "accounts": accounts, "data": data })
+    }
+
+    fn sample_tx() -> Value {
+        let (system_program, token_program) = (5, 6);
+        json!({
+            "slot": 250_000_000,
+            "blockTime": 1_700_000_000,
+            "meta": {
+                "fee": 15_000,
+                "preBalances": [1_000_000_000u64, 0, 2_039_280, 0, 0, 1, 1],
+                "postBalances": [998_094_120u64, 2_039_280, 0, 1_000_000, 890_880, 1, 1],
+                "innerInstructions": [{
+                    "index": 3,
+                    "instructions": [
+                        ix(system_program, &[0, 4], system(2, 890_880)),
+                        ix(system_program, &[4], system(8, 165)),
+                    ]
+                }]
+            },
+            "transaction": {
+                "signatures": ["sig"],
+                "message": {
+                    "accountKeys": [
+                        "wallet", "new-ata", "closed-ata", "recipient", "prefunded",
+                        SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID
+                    ],
+                    "instructions": [
+                        ix(system_program, &[0, 1], system(0, 2_039_280)),
+                        ix(token_program, &[2, 0, 0], bs58::encode([9u8]).into_string()),
+                        ix(system_program, &[0, 3], system(2, 1_000_000)),
+                        ix(token_program, &[4], bs58::encode([1u8]).into_string()),
+                    ]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn decodes_priority_fee_and_rent() {
+        let record = decode_fee_record("wallet", "sig", &sample_tx()).unwrap();
+        assert_eq!(record.base_fee_lamports, 5_000);
+        assert_eq!(record.priority_fee_lamports, 10_000);
+        // The created and the pre-funded then allocated account, not the plain transfer
+        assert_eq!(record.rent_paid_lamports, 2_039_280 + 890_880);
+        assert_eq!(record.rent_reclaimed_lamports, 2_039_280);
+        assert_eq!(record.block_time, 1_700_000_000);
+    }
+
+    #[test]
+    fn balance_changes_alone_are_not_rent() {
+        let mut tx = sample_tx();
+        tx["transaction"]["message"]["instructions"] = json!([]);
+        tx["meta"]["innerInstructions"] = json!([]);
+
+        let record = decode_fee_record("wallet", "sig", &tx).unwrap();
+        assert_eq!(record.rent_paid_lamports, 0);
+        assert_eq!(record.rent_reclaimed_lamports, 0);
+    }
+
+    #[test]
+    fn ignores_transactions_paid_by_others() {
+        assert!(decode_fee_record("someone-else", "sig", &sample_tx()).is_none());
+    }
+
+    #[test]
+    fn summary_groups_by_month() {
+        let mut first = decode_fee_record("wallet", "a", &sample_tx()).unwrap();
+        first.sol_price_usd = 100.0;
+        let mut second = first.clone();
+        second.signature = "b".to_string();
+        second.block_time += 40 * 24 * 60 * 60;
+
+        let summary = summarize_fees(Some("wallet".to_string()), FeePeriod::All, &[first, second]);
+
+        assert_eq!(summary.totals.transaction_count, 2);
+        assert_eq!(summary.monthly.len(), 2);
+        assert!((summary.totals.priority_fees_sol - 0.00002).abs() < 1e-12);
+        // 15,000 lamports in fees plus 890,880 net rent, twice, at $100/SOL
+        assert!((summary.totals.net_cost_usd - 0.181176).abs() < 1e-9);
+    }
+}
@@ -4,4 +4,13 @@ pub mod multi_wallet;
 pub mod operations;
 pub mod phantom;
 pub mod multisig;
+pub mod multisig_notifications;
+pub mod nonce_accounts;
+pub mod offline_signing;
 pub mod performance;
+pub mod sns;
+pub mod fee_tracker;
+pub mod fee_disclosure;
+pub mod compute_budget;
+pub mod rent_reclaim;
+pub mod tx_scheduler;
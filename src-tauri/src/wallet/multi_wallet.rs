@@ -2,10 +2,14 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Mutex, MutexGuard};
-use tauri::State;
+use tauri::{AppHandle, State};
 use uuid::Uuid;
 
+use crate::config::recycle_bin::{
+    discard_recycled, recycle_deleted, undo_unavailable, RecycledEntityKind,
+};
 use crate::security::keystore::{Keystore, KeystoreError};
+use crate::wallet::sns::SnsResolver;
 
 const KEYSTORE_STATE_KEY: &str = "wallet.multi_state";
 
@@ -56,6 +60,9 @@ pub enum WalletType {
     HardwareLedger,
     HardwareTrezor,
     Imported,
+    Multisig,
+    WatchOnly,
+    Exchange,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -347,10 +354,10 @@ impl MultiWalletManager {
         &self,
         wallet_id: &str,
         keystore: &Keystore,
-    ) -> Result<(), MultiWalletError> {
+    ) -> Result<WalletInfo, MultiWalletError> {
         let mut guard = self.lock_state()?;
 
-        guard
+        let removed = guard
             .wallets
             .remove(wallet_id)
             .ok_or_else(|| MultiWalletError::WalletNotFound(wallet_id.to_string()))?;
@@ -366,7 +373,49 @@ impl MultiWalletManager {
         guard.last_updated = Utc::now();
         self.persist_locked(&guard, keystore)?;
 
-        Ok(())
+        Ok(removed)
+    }
+
+    /// Re-adds a removed wallet under its original id. Group membership is
+    /// restored only if the group still exists.
+    pub fn restore_wallet(
+        &self,
+        mut wallet: WalletInfo,
+        keystore: &Keystore,
+    ) -> Result<WalletInfo, MultiWalletError> {
+        let mut guard = self.lock_state()?;
+
+        if guard
+            .wallets
+            .values()
+            .any(|w| w.id == wallet.id || w.public_key == wallet.public_key)
+        {
+            return Err(MultiWalletError::WalletExists(wallet.public_key));
+        }
+
+        match wallet
+            .group_id
+            .as_ref()
+            .and_then(|group_id| guard.groups.get_mut(group_id))
+        {
+            Some(group) => {
+                if !group.wallet_ids.contains(&wallet.id) {
+                    group.wallet_ids.push(wallet.id.clone());
+                }
+            }
+            None => wallet.group_id = None,
+        }
+
+        if guard.active_wallet_id.is_none() {
+            guard.active_wallet_id = Some(wallet.id.clone());
+        }
+
+        wallet.updated_at = Utc::now();
+        guard.wallets.insert(wallet.id.clone(), wallet.clone());
+        guard.last_updated = wallet.updated_at;
+        self.persist_locked(&guard, keystore)?;
+
+        Ok(wallet)
     }
 
     pub fn set_active_wallet(
@@ -616,10 +665,20 @@ impl MultiWalletManager {
 
 #[tauri::command]
 pub async fn multi_wallet_add(
-    request: AddWalletRequest,
+    mut request: AddWalletRequest,
     manager: State<'_, MultiWalletManager>,
     keystore: State<'_, Keystore>,
+    resolver: State<'_, SnsResolver>,
 ) -> Result<WalletInfo, String> {
+    let address = resolver
+        .resolve_input(&request.public_key)
+        .await
+        .map_err(|e| e.to_string())?;
+    if address != request.public_key.trim() && request.label.trim().is_empty() {
+        request.label = request.public_key.trim().to_lowercase();
+    }
+    request.public_key = address;
+
     manager
         .add_wallet(request, &keystore)
         .map_err(|e| e.to_string())
@@ -639,12 +698,25 @@ pub async fn multi_wallet_update(
 #[tauri::command]
 pub async fn multi_wallet_remove(
     wallet_id: String,
+    app_handle: AppHandle,
     manager: State<'_, MultiWalletManager>,
     keystore: State<'_, Keystore>,
 ) -> Result<(), String> {
-    manager
-        .remove_wallet(&wallet_id, &keystore)
-        .map_err(|e| e.to_string())
+    let wallet = manager
+        .list_wallets()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|wallet| wallet.id == wallet_id)
+        .ok_or_else(|| MultiWalletError::WalletNotFound(wallet_id.clone()).to_string())?;
+    let recycled =
+        recycle_deleted(&app_handle, RecycledEntityKind::Wallet, &wallet_id, &wallet.label, &wallet)
+            .await
+            .map_err(undo_unavailable)?;
+    if let Err(err) = manager.remove_wallet(&wallet_id, &keystore) {
+        discard_recycled(&app_handle, &recycled.id).await;
+        return Err(err.to_string());
+    }
+    Ok(())
 }
 
 #[tauri::command]
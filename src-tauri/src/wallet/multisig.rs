@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
@@ -12,13 +12,37 @@ use sqlx::{Pool, Row, Sqlite, SqlitePool};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
-use tauri::State;
+use tauri::{AppHandle, State};
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use crate::config::token_registry::apply_token_overrides;
+use crate::notifications::router::SharedNotificationRouter;
+use crate::wallet::multisig_notifications::{build_notification, dispatch_notification, MultisigEventKind};
+use crate::wallet::offline_signing::decode_transaction;
+
 // Squads Protocol Program ID (mainnet-beta)
 pub const SQUADS_PROGRAM_ID: &str = "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf";
 
+const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+const STABLECOIN_MINTS: [&str; 2] = [
+    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB",
+];
+const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+const TOKEN_PROGRAM_IDS: [&str; 2] = [
+    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PfnBqCXEpPxuEb",
+];
+/// Programs whose instructions move no value.
+const VALUELESS_PROGRAM_IDS: [&str; 2] = [
+    "ComputeBudget111111111111111111111111111111",
+    "MemoSq4gqABAXKb96qCCmGwJ5yv8t1iqwzSsBJH2ZMK",
+];
+/// `SystemInstruction::Transfer` and `TokenInstruction::TransferChecked` tags.
+const SYSTEM_TRANSFER_TAG: [u8; 4] = [2, 0, 0, 0];
+const TRANSFER_CHECKED_TAG: u8 = 12;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ProposalStatus {
@@ -81,6 +105,7 @@ pub struct MultisigProposal {
     pub signatures: Vec<ProposalSignature>,
     pub executed_at: Option<DateTime<Utc>>,
     pub tx_signature: Option<String>,
+    pub policy: Option<ProposalPolicyMetadata>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -108,6 +133,7 @@ pub struct CreateProposalRequest {
     pub transaction_data: String,
     pub description: Option<String>,
     pub created_by: String,
+    pub template_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,6 +144,307 @@ pub struct SignProposalRequest {
     pub signature: String,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProposalTemplateKind {
+    RecurringPayroll,
+    TreasurySwap,
+    Custom,
+}
+
+impl std::fmt::Display for ProposalTemplateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProposalTemplateKind::RecurringPayroll => write!(f, "recurring_payroll"),
+            ProposalTemplateKind::TreasurySwap => write!(f, "treasury_swap"),
+            ProposalTemplateKind::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+impl FromStr for ProposalTemplateKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "recurring_payroll" => Ok(ProposalTemplateKind::RecurringPayroll),
+            "treasury_swap" => Ok(ProposalTemplateKind::TreasurySwap),
+            "custom" => Ok(ProposalTemplateKind::Custom),
+            _ => Err(anyhow!("Invalid template kind: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalTemplate {
+    pub id: String,
+    pub wallet_id: String,
+    pub name: String,
+    pub kind: ProposalTemplateKind,
+    /// Kind specific payload: payroll `mint` and `recipients`, swap
+    /// `inputMint`/`outputMint`/`amount`. Amounts are whole tokens.
+    pub parameters: serde_json::Value,
+    /// USD value when the template was saved, if every token had a price.
+    pub amount: Option<f64>,
+    pub description: Option<String>,
+    pub recurrence_days: Option<u32>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub next_due_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTemplateRequest {
+    pub wallet_id: String,
+    pub name: String,
+    pub kind: ProposalTemplateKind,
+    pub parameters: serde_json::Value,
+    pub description: Option<String>,
+    pub recurrence_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpendingPolicy {
+    pub wallet_id: String,
+    /// Largest amount a proposal may move with only the wallet threshold.
+    pub max_amount_per_proposal: Option<f64>,
+    /// Approvals required on top of the threshold once the limit is exceeded.
+    pub extra_approvals: u32,
+    /// Delay between creation and execution for every proposal.
+    pub time_lock_secs: i64,
+    /// Delay for proposals over the limit; the longer of the two applies.
+    pub over_limit_time_lock_secs: i64,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalPolicyMetadata {
+    pub proposal_id: String,
+    pub template_id: Option<String>,
+    pub amount: Option<f64>,
+    pub required_approvals: u32,
+    pub exceeds_limit: bool,
+    pub executable_after: Option<DateTime<Utc>>,
+    /// Policy as it was when the proposal was created.
+    pub policy: Option<SpendingPolicy>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderSettings {
+    pub wallet_id: String,
+    pub enabled: bool,
+    /// Hours between reminders to members who have not signed a pending proposal.
+    pub interval_hours: u32,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalReminderState {
+    pub proposal_id: String,
+    pub last_reminded_at: DateTime<Utc>,
+    pub reminder_count: u32,
+}
+
+/// Derives approval and time lock requirements for a new proposal.
+pub fn evaluate_spending_policy(
+    proposal_id: &str,
+    policy: Option<&SpendingPolicy>,
+    threshold: u32,
+    amount: Option<f64>,
+    template_id: Option<String>,
+    created_at: DateTime<Utc>,
+) -> ProposalPolicyMetadata {
+    // An amount that can't be established counts as over the limit
+    let exceeds_limit = match (policy.and_then(|p| p.max_amount_per_proposal), amount) {
+        (Some(limit), Some(amount)) => amount > limit,
+        (Some(_), None) => true,
+        (None, _) => false,
+    };
+
+    let (required_approvals, lock_secs) = match policy {
+        Some(policy) if exceeds_limit => (
+            threshold + policy.extra_approvals,
+            policy.time_lock_secs.max(policy.over_limit_time_lock_secs),
+        ),
+        Some(policy) => (threshold, policy.time_lock_secs),
+        None => (threshold, 0),
+    };
+
+    ProposalPolicyMetadata {
+        proposal_id: proposal_id.to_string(),
+        template_id,
+        amount,
+        required_approvals,
+        exceeds_limit,
+        executable_after: (lock_secs > 0).then(|| created_at + Duration::seconds(lock_secs)),
+        policy: policy.cloned(),
+    }
+}
+
+/// USD price of a token, from the price engine by its registered symbol.
+pub fn mint_usd_price(mint: &str) -> Option<f64> {
+    if STABLECOIN_MINTS.contains(&mint) {
+        return Some(1.0);
+    }
+    let mut symbol = if mint == SOL_MINT { "SOL".to_string() } else { mint.to_string() };
+    apply_token_overrides(mint, &mut symbol, None, None, None);
+    crate::core::price_engine::get_price_engine()
+        .get_cached_price(&symbol)
+        .map(|cached| cached.price)
+        .filter(|price| *price > 0.0)
+}
+
+/// Token movements of a serialized transaction as `(mint, whole tokens)`. Only
+/// SOL transfers and checked token transfers can be read; any other
+/// instruction that may move value makes the whole transaction unreadable.
+fn decoded_movements(transaction_data: &str) -> Option<Vec<(String, f64)>> {
+    let transaction = decode_transaction(transaction_data).ok()?;
+    let keys = transaction.message.static_account_keys();
+    let mut movements = Vec::new();
+
+    for instruction in transaction.message.instructions() {
+        let program = keys.get(instruction.program_id_index as usize)?.to_string();
+        let data = &instruction.data;
+        if VALUELESS_PROGRAM_IDS.contains(&program.as_str()) {
+            continue;
+        }
+        let is_transfer = data.len() == 12 && data.starts_with(&SYSTEM_TRANSFER_TAG);
+        if program == SYSTEM_PROGRAM_ID && is_transfer {
+            let lamports = u64::from_le_bytes(data[4..12].try_into().ok()?);
+            movements.push((SOL_MINT.to_string(), lamports as f64 / 1_000_000_000.0));
+        } else if TOKEN_PROGRAM_IDS.contains(&program.as_str())
+            && data.len() == 10
+            && data[0] == TRANSFER_CHECKED_TAG
+        {
+            let amount = u64::from_le_bytes(data[1..9].try_into().ok()?);
+            let mint_index = *instruction.accounts.get(1)? as usize;
+            let mint = keys.get(mint_index)?.to_string();
+            movements.push((mint, amount as f64 / 10f64.powi(data[9] as i32)));
+        } else {
+            return None;
+        }
+    }
+    Some(movements)
+}
+
+/// USD value of a set of movements, or `None` when a token has no price.
+fn movements_value(
+    movements: &[(String, f64)],
+    price: impl Fn(&str) -> Option<f64>,
+) -> Option<f64> {
+    movements
+        .iter()
+        .map(|(mint, amount)| price(mint).map(|price| price * amount))
+        .sum()
+}
+
+/// USD value checked against the spending policy. Template proposals are
+/// valued from their parameters and other transactions from their decoded
+/// instructions; the creator's word is never taken. `None` means the value
+/// can't be established, which counts as over any limit.
+pub fn proposal_amount(
+    transaction_data: &str,
+    price: impl Fn(&str) -> Option<f64>,
+) -> Result<Option<f64>> {
+    let template = serde_json::from_str::<serde_json::Value>(transaction_data)
+        .ok()
+        .and_then(|data| {
+            let kind: ProposalTemplateKind =
+                serde_json::from_value(data.get("template")?.clone()).ok()?;
+            Some((kind, data.get("parameters")?.clone()))
+        });
+
+    let movements = match template {
+        Some((kind, parameters)) => template_movements(kind, &parameters)?,
+        None => decoded_movements(transaction_data),
+    };
+    Ok(movements.and_then(|movements| movements_value(&movements, price)))
+}
+
+/// Checks approvals and time lock before a proposal may be executed.
+pub fn check_execution_policy(
+    metadata: Option<&ProposalPolicyMetadata>,
+    threshold: u32,
+    signature_count: usize,
+    now: DateTime<Utc>,
+) -> Result<(), String> {
+    let required = metadata.map(|m| m.required_approvals).unwrap_or(threshold);
+    if signature_count < required as usize {
+        return Err(format!(
+            "Insufficient signatures: {} of {} required",
+            signature_count, required
+        ));
+    }
+
+    if let Some(unlocks_at) = metadata.and_then(|m| m.executable_after) {
+        if now < unlocks_at {
+            return Err(format!(
+                "Proposal is time locked until {}",
+                unlocks_at.to_rfc3339()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn positive_amount(value: Option<&serde_json::Value>) -> Option<f64> {
+    value
+        .and_then(|a| a.as_f64())
+        .filter(|a| a.is_finite() && *a > 0.0)
+}
+
+/// Validates template parameters and returns the `(mint, whole tokens)` a
+/// proposal built from it moves. Custom templates can't be read.
+pub fn template_movements(
+    kind: ProposalTemplateKind,
+    parameters: &serde_json::Value,
+) -> Result<Option<Vec<(String, f64)>>> {
+    match kind {
+        ProposalTemplateKind::RecurringPayroll => {
+            let mint = parameters
+                .get("mint")
+                .and_then(|m| m.as_str())
+                .ok_or_else(|| anyhow!("Payroll templates need the mint they pay in"))?;
+            let recipients = parameters
+                .get("recipients")
+                .and_then(|r| r.as_array())
+                .filter(|r| !r.is_empty())
+                .ok_or_else(|| anyhow!("Payroll templates need at least one recipient"))?;
+
+            let mut total = 0.0;
+            for recipient in recipients {
+                recipient
+                    .get("address")
+                    .and_then(|a| a.as_str())
+                    .ok_or_else(|| anyhow!("Payroll recipient is missing an address"))?;
+                total += positive_amount(recipient.get("amount"))
+                    .ok_or_else(|| anyhow!("Payroll recipient needs a positive amount"))?;
+            }
+            Ok(Some(vec![(mint.to_string(), total)]))
+        }
+        ProposalTemplateKind::TreasurySwap => {
+            for field in ["inputMint", "outputMint"] {
+                parameters
+                    .get(field)
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow!("Treasury swap templates need {}", field))?;
+            }
+            let amount = positive_amount(parameters.get("amount"))
+                .ok_or_else(|| anyhow!("Treasury swap templates need a positive amount"))?;
+            let input_mint = parameters["inputMint"].as_str().unwrap_or_default();
+            Ok(Some(vec![(input_mint.to_string(), amount)]))
+        }
+        ProposalTemplateKind::Custom => Ok(None),
+    }
+}
+
 pub struct MultisigDatabase {
     pool: Pool<Sqlite>,
 }
@@ -187,6 +514,91 @@ impl MultisigDatabase {
         .execute(&self.pool)
         .await?;
 
+        // Create multisig_templates table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS multisig_templates (
+                id TEXT PRIMARY KEY,
+                wallet_id TEXT NOT NULL,
+                name TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                parameters TEXT NOT NULL,
+                amount REAL,
+                description TEXT,
+                recurrence_days INTEGER,
+                last_used_at TEXT,
+                created_at TEXT NOT NULL,
+                FOREIGN KEY (wallet_id) REFERENCES multisig_wallets(id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // Create multisig_policies table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS multisig_policies (
+                wallet_id TEXT PRIMARY KEY,
+                max_amount_per_proposal REAL,
+                extra_approvals INTEGER NOT NULL DEFAULT 0,
+                time_lock_secs INTEGER NOT NULL DEFAULT 0,
+                over_limit_time_lock_secs INTEGER NOT NULL DEFAULT 0,
+                updated_at TEXT NOT NULL,
+                FOREIGN KEY (wallet_id) REFERENCES multisig_wallets(id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // Create multisig_proposal_policies table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS multisig_proposal_policies (
+                proposal_id TEXT PRIMARY KEY,
+                template_id TEXT,
+                amount REAL,
+                required_approvals INTEGER NOT NULL,
+                exceeds_limit INTEGER NOT NULL,
+                executable_after TEXT,
+                policy_snapshot TEXT,
+                FOREIGN KEY (proposal_id) REFERENCES multisig_proposals(id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // Create multisig_reminder_settings table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS multisig_reminder_settings (
+                wallet_id TEXT PRIMARY KEY,
+                enabled INTEGER NOT NULL,
+                interval_hours INTEGER NOT NULL,
+                updated_at TEXT NOT NULL,
+                FOREIGN KEY (wallet_id) REFERENCES multisig_wallets(id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // Create multisig_proposal_reminders table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS multisig_proposal_reminders (
+                proposal_id TEXT PRIMARY KEY,
+                last_reminded_at TEXT NOT NULL,
+                reminder_count INTEGER NOT NULL DEFAULT 0,
+                FOREIGN KEY (proposal_id) REFERENCES multisig_proposals(id)
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
         // Create indexes
         sqlx::query(
             r#"
@@ -194,6 +606,7 @@ impl MultisigDatabase {
             CREATE INDEX IF NOT EXISTS idx_multisig_proposals_status ON multisig_proposals(status);
             CREATE INDEX IF NOT EXISTS idx_multisig_signatures_proposal ON multisig_signatures(proposal_id);
             CREATE INDEX IF NOT EXISTS idx_multisig_signatures_signer ON multisig_signatures(signer);
+            CREATE INDEX IF NOT EXISTS idx_multisig_templates_wallet ON multisig_templates(wallet_id);
             "#,
         )
         .execute(&self.pool)
@@ -307,17 +720,44 @@ impl MultisigDatabase {
         &self,
         request: CreateProposalRequest,
     ) -> Result<MultisigProposal> {
+        let wallet = self
+            .get_wallet(&request.wallet_id)
+            .await?
+            .ok_or_else(|| anyhow!("Wallet not found"))?;
+        let policy = self.get_spending_policy(&request.wallet_id).await?;
+        let amount = proposal_amount(&request.transaction_data, mint_usd_price)?;
+
+        let proposal_id = format!("proposal_{}", Uuid::new_v4());
+        let created_at = Utc::now();
+        let metadata = evaluate_spending_policy(
+            &proposal_id,
+            policy.as_ref(),
+            wallet.threshold,
+            amount,
+            request.template_id,
+            created_at,
+        );
+
+        if metadata.required_approvals as usize > wallet.members.len() {
+            return Err(anyhow!(
+                "Amount exceeds the spending policy: {} approvals required but the wallet has {} members",
+                metadata.required_approvals,
+                wallet.members.len()
+            ));
+        }
+
         let proposal = MultisigProposal {
-            id: format!("proposal_{}", Uuid::new_v4()),
+            id: proposal_id,
             wallet_id: request.wallet_id,
             transaction_data: request.transaction_data,
             status: ProposalStatus::Pending,
             created_by: request.created_by,
-            created_at: Utc::now(),
+            created_at,
             description: request.description,
             signatures: vec![],
             executed_at: None,
             tx_signature: None,
+            policy: Some(metadata),
         };
 
         sqlx::query(
@@ -338,9 +778,77 @@ impl MultisigDatabase {
         .execute(&self.pool)
         .await?;
 
+        if let Some(metadata) = &proposal.policy {
+            self.save_proposal_policy(metadata).await?;
+        }
+
         Ok(proposal)
     }
 
+    async fn save_proposal_policy(&self, metadata: &ProposalPolicyMetadata) -> Result<()> {
+        let snapshot = metadata
+            .policy
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO multisig_proposal_policies (proposal_id, template_id, amount, required_approvals, exceeds_limit, executable_after, policy_snapshot)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )
+        .bind(&metadata.proposal_id)
+        .bind(&metadata.template_id)
+        .bind(metadata.amount)
+        .bind(metadata.required_approvals as i64)
+        .bind(metadata.exceeds_limit)
+        .bind(metadata.executable_after.map(|dt| dt.to_rfc3339()))
+        .bind(snapshot)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_proposal_policy(
+        &self,
+        proposal_id: &str,
+    ) -> Result<Option<ProposalPolicyMetadata>> {
+        let row = sqlx::query(
+            r#"
+            SELECT proposal_id, template_id, amount, required_approvals, exceeds_limit, executable_after, policy_snapshot
+            FROM multisig_proposal_policies
+            WHERE proposal_id = ?1
+            "#,
+        )
+        .bind(proposal_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(row) = row {
+            let policy = row
+                .try_get::<Option<String>, _>("policy_snapshot")?
+                .map(|json| serde_json::from_str::<SpendingPolicy>(&json))
+                .transpose()?;
+
+            Ok(Some(ProposalPolicyMetadata {
+                proposal_id: row.try_get("proposal_id")?,
+                template_id: row.try_get("template_id")?,
+                amount: row.try_get("amount")?,
+                required_approvals: row.try_get::<i64, _>("required_approvals")? as u32,
+                exceeds_limit: row.try_get("exceeds_limit")?,
+                executable_after: row
+                    .try_get::<Option<String>, _>("executable_after")?
+                    .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                    .map(|dt| dt.with_timezone(&Utc)),
+                policy,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub async fn get_proposal(&self, proposal_id: &str) -> Result<Option<MultisigProposal>> {
         let row = sqlx::query(
             r#"
@@ -369,6 +877,7 @@ impl MultisigDatabase {
                     .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
                     .map(|dt| dt.with_timezone(&Utc)),
                 tx_signature: row.try_get("tx_signature")?,
+                policy: self.get_proposal_policy(proposal_id).await?,
             };
 
             Ok(Some(proposal))
@@ -422,6 +931,7 @@ impl MultisigDatabase {
         for row in rows {
             let proposal_id: String = row.try_get("id")?;
             let signatures = self.get_proposal_signatures(&proposal_id).await?;
+            let policy = self.get_proposal_policy(&proposal_id).await?;
 
             let proposal = MultisigProposal {
                 id: proposal_id,
@@ -438,6 +948,7 @@ impl MultisigDatabase {
                     .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
                     .map(|dt| dt.with_timezone(&Utc)),
                 tx_signature: row.try_get("tx_signature")?,
+                policy,
             };
 
             proposals.push(proposal);
@@ -475,7 +986,12 @@ impl MultisigDatabase {
             let wallet = self.get_wallet(&proposal.wallet_id).await?;
             if let Some(wallet) = wallet {
                 let signatures = self.get_proposal_signatures(&request.proposal_id).await?;
-                if signatures.len() >= wallet.threshold as usize {
+                let required = proposal
+                    .policy
+                    .as_ref()
+                    .map(|p| p.required_approvals)
+                    .unwrap_or(wallet.threshold);
+                if signatures.len() >= required as usize {
                     // Update proposal status to approved
                     sqlx::query(
                         r#"
@@ -567,6 +1083,268 @@ impl MultisigDatabase {
         Ok(())
     }
 
+    pub async fn set_spending_policy(&self, policy: &SpendingPolicy) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO multisig_policies (wallet_id, max_amount_per_proposal, extra_approvals, time_lock_secs, over_limit_time_lock_secs, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+        )
+        .bind(&policy.wallet_id)
+        .bind(policy.max_amount_per_proposal)
+        .bind(policy.extra_approvals as i64)
+        .bind(policy.time_lock_secs)
+        .bind(policy.over_limit_time_lock_secs)
+        .bind(policy.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_spending_policy(&self, wallet_id: &str) -> Result<Option<SpendingPolicy>> {
+        let row = sqlx::query(
+            r#"
+            SELECT wallet_id, max_amount_per_proposal, extra_approvals, time_lock_secs, over_limit_time_lock_secs, updated_at
+            FROM multisig_policies
+            WHERE wallet_id = ?1
+            "#,
+        )
+        .bind(wallet_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(row) = row {
+            Ok(Some(SpendingPolicy {
+                wallet_id: row.try_get("wallet_id")?,
+                max_amount_per_proposal: row.try_get("max_amount_per_proposal")?,
+                extra_approvals: row.try_get::<i64, _>("extra_approvals")? as u32,
+                time_lock_secs: row.try_get("time_lock_secs")?,
+                over_limit_time_lock_secs: row.try_get("over_limit_time_lock_secs")?,
+                updated_at: DateTime::parse_from_rfc3339(&row.try_get::<String, _>("updated_at")?)
+                    .map(|dt| dt.with_timezone(&Utc))?,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub async fn remove_spending_policy(&self, wallet_id: &str) -> Result<()> {
+        sqlx::query("DELETE FROM multisig_policies WHERE wallet_id = ?1")
+            .bind(wallet_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_template(&self, request: CreateTemplateRequest) -> Result<ProposalTemplate> {
+        let amount = template_movements(request.kind, &request.parameters)?
+            .and_then(|movements| movements_value(&movements, mint_usd_price));
+
+        let template = ProposalTemplate {
+            id: format!("template_{}", Uuid::new_v4()),
+            wallet_id: request.wallet_id,
+            name: request.name,
+            kind: request.kind,
+            parameters: request.parameters,
+            amount,
+            description: request.description,
+            recurrence_days: request.recurrence_days,
+            last_used_at: None,
+            next_due_at: None,
+            created_at: Utc::now(),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO multisig_templates (id, wallet_id, name, kind, parameters, amount, description, recurrence_days, last_used_at, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            "#,
+        )
+        .bind(&template.id)
+        .bind(&template.wallet_id)
+        .bind(&template.name)
+        .bind(template.kind.to_string())
+        .bind(serde_json::to_string(&template.parameters)?)
+        .bind(template.amount)
+        .bind(&template.description)
+        .bind(template.recurrence_days.map(|d| d as i64))
+        .bind(template.last_used_at.map(|dt| dt.to_rfc3339()))
+        .bind(template.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(template)
+    }
+
+    fn template_from_row(row: &sqlx::sqlite::SqliteRow) -> Result<ProposalTemplate> {
+        let last_used_at = row
+            .try_get::<Option<String>, _>("last_used_at")?
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc));
+        let created_at = DateTime::parse_from_rfc3339(&row.try_get::<String, _>("created_at")?)
+            .map(|dt| dt.with_timezone(&Utc))?;
+        let recurrence_days = row
+            .try_get::<Option<i64>, _>("recurrence_days")?
+            .map(|d| d as u32);
+
+        Ok(ProposalTemplate {
+            id: row.try_get("id")?,
+            wallet_id: row.try_get("wallet_id")?,
+            name: row.try_get("name")?,
+            kind: ProposalTemplateKind::from_str(&row.try_get::<String, _>("kind")?)?,
+            parameters: serde_json::from_str(&row.try_get::<String, _>("parameters")?)?,
+            amount: row.try_get("amount")?,
+            description: row.try_get("description")?,
+            recurrence_days,
+            next_due_at: recurrence_days.map(|days| {
+                last_used_at.unwrap_or(created_at) + Duration::days(days as i64)
+            }),
+            last_used_at,
+            created_at,
+        })
+    }
+
+    pub async fn get_template(&self, template_id: &str) -> Result<Option<ProposalTemplate>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, wallet_id, name, kind, parameters, amount, description, recurrence_days, last_used_at, created_at
+            FROM multisig_templates
+            WHERE id = ?1
+            "#,
+        )
+        .bind(template_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| Self::template_from_row(&row)).transpose()
+    }
+
+    pub async fn list_templates(&self, wallet_id: &str) -> Result<Vec<ProposalTemplate>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, wallet_id, name, kind, parameters, amount, description, recurrence_days, last_used_at, created_at
+            FROM multisig_templates
+            WHERE wallet_id = ?1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(wallet_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(Self::template_from_row).collect()
+    }
+
+    pub async fn delete_template(&self, template_id: &str) -> Result<()> {
+        sqlx::query("DELETE FROM multisig_templates WHERE id = ?1")
+            .bind(template_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn mark_template_used(&self, template_id: &str) -> Result<()> {
+        sqlx::query("UPDATE multisig_templates SET last_used_at = ?1 WHERE id = ?2")
+            .bind(Utc::now().to_rfc3339())
+            .bind(template_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_reminder_settings(&self, settings: &ReminderSettings) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO multisig_reminder_settings (wallet_id, enabled, interval_hours, updated_at)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+        )
+        .bind(&settings.wallet_id)
+        .bind(settings.enabled)
+        .bind(settings.interval_hours as i64)
+        .bind(settings.updated_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_reminder_settings(&self, wallet_id: &str) -> Result<Option<ReminderSettings>> {
+        let row = sqlx::query(
+            r#"
+            SELECT wallet_id, enabled, interval_hours, updated_at
+            FROM multisig_reminder_settings
+            WHERE wallet_id = ?1
+            "#,
+        )
+        .bind(wallet_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(row) = row {
+            Ok(Some(ReminderSettings {
+                wallet_id: row.try_get("wallet_id")?,
+                enabled: row.try_get("enabled")?,
+                interval_hours: row.try_get::<i64, _>("interval_hours")? as u32,
+                updated_at: DateTime::parse_from_rfc3339(&row.try_get::<String, _>("updated_at")?)
+                    .map(|dt| dt.with_timezone(&Utc))?,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub async fn get_reminder_state(
+        &self,
+        proposal_id: &str,
+    ) -> Result<Option<ProposalReminderState>> {
+        let row = sqlx::query(
+            r#"
+            SELECT proposal_id, last_reminded_at, reminder_count
+            FROM multisig_proposal_reminders
+            WHERE proposal_id = ?1
+            "#,
+        )
+        .bind(proposal_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(row) = row {
+            Ok(Some(ProposalReminderState {
+                proposal_id: row.try_get("proposal_id")?,
+                last_reminded_at: DateTime::parse_from_rfc3339(
+                    &row.try_get::<String, _>("last_reminded_at")?,
+                )
+                .map(|dt| dt.with_timezone(&Utc))?,
+                reminder_count: row.try_get::<i64, _>("reminder_count")? as u32,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub async fn record_reminder(&self, proposal_id: &str, reminded_at: DateTime<Utc>) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO multisig_proposal_reminders (proposal_id, last_reminded_at, reminder_count)
+            VALUES (?1, ?2, 1)
+            ON CONFLICT(proposal_id) DO UPDATE SET
+                last_reminded_at = excluded.last_reminded_at,
+                reminder_count = reminder_count + 1
+            "#,
+        )
+        .bind(proposal_id)
+        .bind(reminded_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     fn derive_multisig_address(&self, members: &[String], threshold: u32) -> Result<String> {
         use sha2::{Digest, Sha256};
 
@@ -635,10 +1413,12 @@ pub async fn get_multisig_wallet(
 #[tauri::command]
 pub async fn create_proposal(
     request: CreateProposalRequest,
+    app_handle: AppHandle,
     db: State<'_, SharedMultisigDatabase>,
+    router: State<'_, SharedNotificationRouter>,
 ) -> Result<MultisigProposal, String> {
     let db_guard = db.read().await;
-    
+
     // Verify wallet exists
     let wallet = db_guard
         .get_wallet(&request.wallet_id)
@@ -651,10 +1431,16 @@ pub async fn create_proposal(
         return Err("Only wallet members can create proposals".to_string());
     }
 
-    db_guard
+    let proposal = db_guard
         .create_proposal(request)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    drop(db_guard);
+
+    let notification = build_notification(MultisigEventKind::ProposalCreated, &wallet, &proposal);
+    dispatch_notification(&app_handle, router.inner(), &notification).await;
+
+    Ok(proposal)
 }
 
 #[tauri::command]
@@ -716,16 +1502,16 @@ pub async fn sign_proposal(
         .map_err(|e| e.to_string())
 }
 
-#[tauri::command]
-pub async fn execute_proposal(
-    proposal_id: String,
-    db: State<'_, SharedMultisigDatabase>,
-) -> Result<String, String> {
-    let db_guard = db.read().await;
-
+/// Validates and executes an approved proposal, returning its wallet and executed state.
+///
+/// Shared by the `execute_proposal` command and the transaction scheduler.
+pub async fn execute_approved_proposal(
+    db: &MultisigDatabase,
+    proposal_id: &str,
+) -> Result<(MultisigWallet, MultisigProposal), String> {
     // Verify proposal exists and is approved
-    let proposal = db_guard
-        .get_proposal(&proposal_id)
+    let proposal = db
+        .get_proposal(proposal_id)
         .await
         .map_err(|e| e.to_string())?
         .ok_or_else(|| "Proposal not found".to_string())?;
@@ -735,37 +1521,61 @@ pub async fn execute_proposal(
     }
 
     // Verify threshold is met
-    let wallet = db_guard
+    let wallet = db
         .get_wallet(&proposal.wallet_id)
         .await
         .map_err(|e| e.to_string())?
         .ok_or_else(|| "Wallet not found".to_string())?;
 
-    if proposal.signatures.len() < wallet.threshold as usize {
-        return Err(format!(
-            "Insufficient signatures: {} of {} required",
-            proposal.signatures.len(),
-            wallet.threshold
-        ));
-    }
+    check_execution_policy(
+        proposal.policy.as_ref(),
+        wallet.threshold,
+        proposal.signatures.len(),
+        Utc::now(),
+    )?;
 
     // Simulate transaction execution
     // In a real implementation, this would execute the transaction on Solana
     let tx_signature = format!("tx_{}", Uuid::new_v4());
 
-    db_guard
-        .execute_proposal(&proposal_id, tx_signature.clone())
+    db.execute_proposal(proposal_id, tx_signature.clone())
         .await
         .map_err(|e| e.to_string())?;
 
-    Ok(tx_signature)
+    let executed = MultisigProposal {
+        status: ProposalStatus::Executed,
+        executed_at: Some(Utc::now()),
+        tx_signature: Some(tx_signature),
+        ..proposal
+    };
+
+    Ok((wallet, executed))
+}
+
+#[tauri::command]
+pub async fn execute_proposal(
+    proposal_id: String,
+    app_handle: AppHandle,
+    db: State<'_, SharedMultisigDatabase>,
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<String, String> {
+    let db_guard = db.read().await;
+    let (wallet, executed) = execute_approved_proposal(&db_guard, &proposal_id).await?;
+    drop(db_guard);
+
+    let notification = build_notification(MultisigEventKind::ProposalExecuted, &wallet, &executed);
+    dispatch_notification(&app_handle, router.inner(), &notification).await;
+
+    Ok(executed.tx_signature.unwrap_or_default())
 }
 
 #[tauri::command]
 pub async fn cancel_proposal(
     proposal_id: String,
     user_address: String,
+    app_handle: AppHandle,
     db: State<'_, SharedMultisigDatabase>,
+    router: State<'_, SharedNotificationRouter>,
 ) -> Result<(), String> {
     let db_guard = db.read().await;
 
@@ -786,8 +1596,338 @@ pub async fn cancel_proposal(
         return Err("Can only cancel pending proposals".to_string());
     }
 
+    let wallet = db_guard
+        .get_wallet(&proposal.wallet_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Wallet not found".to_string())?;
+
     db_guard
         .cancel_proposal(&proposal_id)
         .await
+        .map_err(|e| e.to_string())?;
+    drop(db_guard);
+
+    let cancelled = MultisigProposal {
+        status: ProposalStatus::Cancelled,
+        ..proposal
+    };
+    let notification = build_notification(MultisigEventKind::ProposalCancelled, &wallet, &cancelled);
+    dispatch_notification(&app_handle, router.inner(), &notification).await;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn create_proposal_template(
+    request: CreateTemplateRequest,
+    db: State<'_, SharedMultisigDatabase>,
+) -> Result<ProposalTemplate, String> {
+    if request.name.trim().is_empty() {
+        return Err("Template name is required".to_string());
+    }
+
+    if request.recurrence_days == Some(0) {
+        return Err("Recurrence must be at least one day".to_string());
+    }
+
+    let db_guard = db.read().await;
+    db_guard
+        .get_wallet(&request.wallet_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Wallet not found".to_string())?;
+
+    db_guard
+        .create_template(request)
+        .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+pub async fn list_proposal_templates(
+    wallet_id: String,
+    due_only: Option<bool>,
+    db: State<'_, SharedMultisigDatabase>,
+) -> Result<Vec<ProposalTemplate>, String> {
+    let db_guard = db.read().await;
+    let templates = db_guard
+        .list_templates(&wallet_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if due_only.unwrap_or(false) {
+        let now = Utc::now();
+        return Ok(templates
+            .into_iter()
+            .filter(|t| t.next_due_at.map(|due| due <= now).unwrap_or(false))
+            .collect());
+    }
+
+    Ok(templates)
+}
+
+#[tauri::command]
+pub async fn delete_proposal_template(
+    template_id: String,
+    db: State<'_, SharedMultisigDatabase>,
+) -> Result<(), String> {
+    let db_guard = db.read().await;
+    db_guard
+        .delete_template(&template_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn create_proposal_from_template(
+    template_id: String,
+    created_by: String,
+    description: Option<String>,
+    app_handle: AppHandle,
+    db: State<'_, SharedMultisigDatabase>,
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<MultisigProposal, String> {
+    let db_guard = db.read().await;
+
+    let template = db_guard
+        .get_template(&template_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Template not found".to_string())?;
+
+    let wallet = db_guard
+        .get_wallet(&template.wallet_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Wallet not found".to_string())?;
+
+    if !wallet.members.contains(&created_by) {
+        return Err("Only wallet members can create proposals".to_string());
+    }
+
+    let transaction_data = serde_json::json!({
+        "template": template.kind,
+        "parameters": template.parameters,
+    })
+    .to_string();
+
+    let proposal = db_guard
+        .create_proposal(CreateProposalRequest {
+            wallet_id: template.wallet_id,
+            transaction_data,
+            description: description.or(template.description).or(Some(template.name)),
+            created_by,
+            template_id: Some(template.id.clone()),
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+
+    db_guard
+        .mark_template_used(&template.id)
+        .await
+        .map_err(|e| e.to_string())?;
+    drop(db_guard);
+
+    let notification = build_notification(MultisigEventKind::ProposalCreated, &wallet, &proposal);
+    dispatch_notification(&app_handle, router.inner(), &notification).await;
+
+    Ok(proposal)
+}
+
+#[tauri::command]
+pub async fn set_spending_policy(
+    wallet_id: String,
+    max_amount_per_proposal: Option<f64>,
+    extra_approvals: Option<u32>,
+    time_lock_secs: Option<i64>,
+    over_limit_time_lock_secs: Option<i64>,
+    db: State<'_, SharedMultisigDatabase>,
+) -> Result<SpendingPolicy, String> {
+    if max_amount_per_proposal.map(|m| m < 0.0).unwrap_or(false) {
+        return Err("Spending limit cannot be negative".to_string());
+    }
+
+    if time_lock_secs.unwrap_or(0) < 0 || over_limit_time_lock_secs.unwrap_or(0) < 0 {
+        return Err("Time locks cannot be negative".to_string());
+    }
+
+    let db_guard = db.read().await;
+    let wallet = db_guard
+        .get_wallet(&wallet_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Wallet not found".to_string())?;
+
+    let extra_approvals = extra_approvals.unwrap_or(0);
+    if wallet.threshold + extra_approvals > wallet.members.len() as u32 {
+        return Err(format!(
+            "Threshold plus extra approvals cannot exceed {} members",
+            wallet.members.len()
+        ));
+    }
+
+    let policy = SpendingPolicy {
+        wallet_id,
+        max_amount_per_proposal,
+        extra_approvals,
+        time_lock_secs: time_lock_secs.unwrap_or(0),
+        over_limit_time_lock_secs: over_limit_time_lock_secs.unwrap_or(0),
+        updated_at: Utc::now(),
+    };
+
+    db_guard
+        .set_spending_policy(&policy)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(policy)
+}
+
+#[tauri::command]
+pub async fn get_spending_policy(
+    wallet_id: String,
+    db: State<'_, SharedMultisigDatabase>,
+) -> Result<Option<SpendingPolicy>, String> {
+    let db_guard = db.read().await;
+    db_guard
+        .get_spending_policy(&wallet_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn remove_spending_policy(
+    wallet_id: String,
+    db: State<'_, SharedMultisigDatabase>,
+) -> Result<(), String> {
+    let db_guard = db.read().await;
+    db_guard
+        .remove_spending_policy(&wallet_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn policy() -> SpendingPolicy {
+        SpendingPolicy {
+            wallet_id: "wallet".to_string(),
+            max_amount_per_proposal: Some(1_000.0),
+            extra_approvals: 1,
+            time_lock_secs: 3_600,
+            over_limit_time_lock_secs: 86_400,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn policy_within_limit_keeps_threshold() {
+        let now = Utc::now();
+        let metadata = evaluate_spending_policy("p", Some(&policy()), 2, Some(500.0), None, now);
+
+        assert_eq!(metadata.required_approvals, 2);
+        assert!(!metadata.exceeds_limit);
+        assert_eq!(metadata.executable_after, Some(now + Duration::seconds(3_600)));
+    }
+
+    #[test]
+    fn policy_over_limit_adds_approvals_and_lock() {
+        let now = Utc::now();
+        let metadata = evaluate_spending_policy("p", Some(&policy()), 2, Some(5_000.0), None, now);
+
+        assert_eq!(metadata.required_approvals, 3);
+        assert!(metadata.exceeds_limit);
+        assert_eq!(metadata.executable_after, Some(now + Duration::seconds(86_400)));
+
+        assert!(check_execution_policy(Some(&metadata), 2, 2, now + Duration::days(2)).is_err());
+        assert!(check_execution_policy(Some(&metadata), 2, 3, now).is_err());
+        assert!(check_execution_policy(Some(&metadata), 2, 3, now + Duration::days(2)).is_ok());
+    }
+
+    fn test_price(mint: &str) -> Option<f64> {
+        match mint {
+            SOL_MINT => Some(150.0),
+            "usdc" => Some(1.0),
+            _ => None,
+        }
+    }
+
+    fn encoded(instructions: &[Instruction]) -> String {
+        use base64::Engine;
+        use solana_sdk::message::{Message, VersionedMessage};
+        use solana_sdk::transaction::VersionedTransaction;
+
+        let payer = Pubkey::new_unique();
+        let transaction = VersionedTransaction {
+            signatures: vec![Signature::default()],
+            message: VersionedMessage::Legacy(Message::new(instructions, Some(&payer))),
+        };
+        base64::engine::general_purpose::STANDARD.encode(bincode::serialize(&transaction).unwrap())
+    }
+
+    #[test]
+    fn proposals_are_valued_in_usd_from_their_contents() {
+        let payer = Pubkey::new_unique();
+        let transfer = solana_sdk::system_instruction::transfer(
+            &payer,
+            &Pubkey::new_unique(),
+            2_000_000_000,
+        );
+        assert_eq!(
+            proposal_amount(&encoded(&[transfer]), test_price).unwrap(),
+            Some(300.0)
+        );
+
+        // Anything that can't be decoded or priced is unvalued, never trusted
+        let unknown = Instruction::new_with_bytes(Pubkey::new_unique(), &[1, 2, 3], vec![]);
+        assert_eq!(proposal_amount(&encoded(&[unknown]), test_price).unwrap(), None);
+        assert_eq!(proposal_amount("opaque-tx", test_price).unwrap(), None);
+
+        // Template proposals are valued from their parameters
+        let swap = json!({
+            "template": "treasury_swap",
+            "parameters": { "inputMint": SOL_MINT, "outputMint": "usdc", "amount": 10.0 }
+        })
+        .to_string();
+        assert_eq!(proposal_amount(&swap, test_price).unwrap(), Some(1_500.0));
+
+        let metadata = evaluate_spending_policy("p", Some(&policy()), 2, None, None, Utc::now());
+        assert!(metadata.exceeds_limit);
+        assert_eq!(metadata.required_approvals, 3);
+    }
+
+    #[test]
+    fn no_policy_falls_back_to_threshold() {
+        let metadata = evaluate_spending_policy("p", None, 2, Some(5_000.0), None, Utc::now());
+
+        assert_eq!(metadata.required_approvals, 2);
+        assert_eq!(metadata.executable_after, None);
+        assert!(check_execution_policy(None, 2, 2, Utc::now()).is_ok());
+    }
+
+    #[test]
+    fn template_movements_validate_parameters() {
+        let payroll = json!({
+            "mint": "usdc",
+            "recipients": [
+                { "address": "alice", "amount": 1_500.0 },
+                { "address": "bob", "amount": 2_500.0 }
+            ]
+        });
+        assert_eq!(
+            template_movements(ProposalTemplateKind::RecurringPayroll, &payroll).unwrap(),
+            Some(vec![("usdc".to_string(), 4_000.0)])
+        );
+        let unpaid = json!({ "recipients": [{ "address": "alice", "amount": 1.0 }] });
+        assert!(template_movements(ProposalTemplateKind::RecurringPayroll, &unpaid).is_err());
+        assert!(template_movements(
+            ProposalTemplateKind::TreasurySwap,
+            &json!({ "inputMint": "USDC", "amount": 10.0 })
+        )
+        .is_err());
+    }
+}
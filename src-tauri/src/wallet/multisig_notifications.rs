@@ -0,0 +1,431 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration as StdDuration;
+use tauri::{AppHandle, Manager, State};
+
+use crate::notifications::router::SharedNotificationRouter;
+use crate::wallet::multisig::{
+    MultisigProposal, MultisigWallet, ProposalStatus, ReminderSettings, SharedMultisigDatabase,
+};
+
+pub const MULTISIG_NOTIFICATION_EVENT: &str = "multisig_notification";
+const DEEP_LINK_SCHEME: &str = "eclipse://multisig";
+const DEFAULT_REMINDER_INTERVAL_HOURS: u32 = 24;
+const REMINDER_CHECK_INTERVAL: StdDuration = StdDuration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MultisigEventKind {
+    ProposalCreated,
+    ApprovalReminder,
+    ProposalExecuted,
+    ProposalCancelled,
+}
+
+impl MultisigEventKind {
+    fn title(&self) -> &'static str {
+        match self {
+            MultisigEventKind::ProposalCreated => "New multisig proposal",
+            MultisigEventKind::ApprovalReminder => "Multisig approval reminder",
+            MultisigEventKind::ProposalExecuted => "Multisig proposal executed",
+            MultisigEventKind::ProposalCancelled => "Multisig proposal cancelled",
+        }
+    }
+}
+
+/// Where the frontend should navigate when a member opens the notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalDeepLink {
+    pub url: String,
+    pub view: String,
+    pub wallet_id: String,
+    pub proposal_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultisigNotification {
+    pub kind: MultisigEventKind,
+    pub wallet_id: String,
+    pub wallet_name: String,
+    pub proposal_id: String,
+    pub proposal_description: Option<String>,
+    pub status: ProposalStatus,
+    /// Members the notification is addressed to.
+    pub recipients: Vec<String>,
+    pub signatures: usize,
+    pub required_signatures: u32,
+    pub message: String,
+    pub deep_link: ProposalDeepLink,
+    pub created_at: DateTime<Utc>,
+}
+
+pub fn proposal_deep_link(wallet_id: &str, proposal_id: &str) -> ProposalDeepLink {
+    ProposalDeepLink {
+        url: format!("{}/{}/proposals/{}", DEEP_LINK_SCHEME, wallet_id, proposal_id),
+        view: "multisig".to_string(),
+        wallet_id: wallet_id.to_string(),
+        proposal_id: proposal_id.to_string(),
+    }
+}
+
+pub fn unsigned_members(wallet: &MultisigWallet, proposal: &MultisigProposal) -> Vec<String> {
+    wallet
+        .members
+        .iter()
+        .filter(|member| !proposal.signatures.iter().any(|sig| &sig.signer == *member))
+        .cloned()
+        .collect()
+}
+
+/// A reminder is due once `interval_hours` have passed since creation or the previous reminder.
+pub fn reminder_due(
+    created_at: DateTime<Utc>,
+    last_reminded_at: Option<DateTime<Utc>>,
+    interval_hours: u32,
+    now: DateTime<Utc>,
+) -> bool {
+    if interval_hours == 0 {
+        return false;
+    }
+    let since = last_reminded_at.unwrap_or(created_at);
+    now - since >= Duration::hours(interval_hours as i64)
+}
+
+pub fn build_notification(
+    kind: MultisigEventKind,
+    wallet: &MultisigWallet,
+    proposal: &MultisigProposal,
+) -> MultisigNotification {
+    let required_signatures = proposal
+        .policy
+        .as_ref()
+        .map(|p| p.required_approvals)
+        .unwrap_or(wallet.threshold);
+    let recipients = match kind {
+        MultisigEventKind::ApprovalReminder => unsigned_members(wallet, proposal),
+        _ => wallet.members.clone(),
+    };
+    let deep_link = proposal_deep_link(&wallet.id, &proposal.id);
+    let subject = proposal
+        .description
+        .clone()
+        .unwrap_or_else(|| proposal.id.clone());
+
+    let summary = match kind {
+        MultisigEventKind::ProposalCreated => format!(
+            "{} proposed \"{}\" on {}. {} of {} signatures required.",
+            proposal.created_by, subject, wallet.name, required_signatures, wallet.members.len()
+        ),
+        MultisigEventKind::ApprovalReminder => format!(
+            "\"{}\" on {} is waiting on {} ({} of {} signatures collected).",
+            subject,
+            wallet.name,
+            recipients.join(", "),
+            proposal.signatures.len(),
+            required_signatures
+        ),
+        MultisigEventKind::ProposalExecuted => format!(
+            "\"{}\" on {} was executed{}.",
+            subject,
+            wallet.name,
+            proposal
+                .tx_signature
+                .as_ref()
+                .map(|sig| format!(" in transaction {}", sig))
+                .unwrap_or_default()
+        ),
+        MultisigEventKind::ProposalCancelled => format!(
+            "\"{}\" on {} was cancelled by {}.",
+            subject, wallet.name, proposal.created_by
+        ),
+    };
+
+    MultisigNotification {
+        kind,
+        wallet_id: wallet.id.clone(),
+        wallet_name: wallet.name.clone(),
+        proposal_id: proposal.id.clone(),
+        proposal_description: proposal.description.clone(),
+        status: proposal.status.clone(),
+        recipients,
+        signatures: proposal.signatures.len(),
+        required_signatures,
+        message: format!("{}\nOpen: {}", summary, deep_link.url),
+        deep_link,
+        created_at: Utc::now(),
+    }
+}
+
+/// Emits the notification to the frontend and fans it out to configured chat channels.
+pub async fn dispatch_notification(
+    app_handle: &AppHandle,
+    router: &SharedNotificationRouter,
+    notification: &MultisigNotification,
+) {
+    if let Err(err) = app_handle.emit_all(MULTISIG_NOTIFICATION_EVENT, notification) {
+        tracing::warn!(error = %err, "failed to emit multisig notification");
+    }
+
+    let router = router.read().await;
+    if let Err(err) = router
+        .send_broadcast_notification(
+            &notification.proposal_id,
+            notification.kind.title(),
+            &notification.message,
+        )
+        .await
+    {
+        tracing::warn!(error = %err, "failed to deliver multisig notification");
+    }
+}
+
+/// Looks up the proposal and its wallet and dispatches a notification of the given kind.
+pub async fn notify_proposal_event(
+    app_handle: &AppHandle,
+    db: &SharedMultisigDatabase,
+    router: &SharedNotificationRouter,
+    kind: MultisigEventKind,
+    proposal_id: &str,
+) {
+    let notification = {
+        let db = db.read().await;
+        let proposal = match db.get_proposal(proposal_id).await {
+            Ok(Some(proposal)) => proposal,
+            Ok(None) => return,
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to load proposal for notification");
+                return;
+            }
+        };
+        let wallet = match db.get_wallet(&proposal.wallet_id).await {
+            Ok(Some(wallet)) => wallet,
+            Ok(None) => return,
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to load wallet for notification");
+                return;
+            }
+        };
+        build_notification(kind, &wallet, &proposal)
+    };
+
+    dispatch_notification(app_handle, router, &notification).await;
+}
+
+/// Sends reminders for pending proposals whose unsigned members are due one.
+pub async fn process_approval_reminders(
+    app_handle: &AppHandle,
+    db: &SharedMultisigDatabase,
+    router: &SharedNotificationRouter,
+) -> Result<usize, String> {
+    let now = Utc::now();
+    let mut due = Vec::new();
+
+    {
+        let db = db.read().await;
+        let wallets = db.list_wallets().await.map_err(|e| e.to_string())?;
+
+        for wallet in wallets {
+            let settings = db
+                .get_reminder_settings(&wallet.id)
+                .await
+                .map_err(|e| e.to_string())?;
+            let interval_hours = match settings {
+                Some(settings) if !settings.enabled => continue,
+                Some(settings) => settings.interval_hours,
+                None => DEFAULT_REMINDER_INTERVAL_HOURS,
+            };
+
+            let proposals = db
+                .list_proposals(&wallet.id, Some(ProposalStatus::Pending.to_string()))
+                .await
+                .map_err(|e| e.to_string())?;
+
+            for proposal in proposals {
+                let last_reminded_at = db
+                    .get_reminder_state(&proposal.id)
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .map(|state| state.last_reminded_at);
+
+                if !reminder_due(proposal.created_at, last_reminded_at, interval_hours, now)
+                    || unsigned_members(&wallet, &proposal).is_empty()
+                {
+                    continue;
+                }
+
+                db.record_reminder(&proposal.id, now)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                due.push(build_notification(
+                    MultisigEventKind::ApprovalReminder,
+                    &wallet,
+                    &proposal,
+                ));
+            }
+        }
+    }
+
+    for notification in &due {
+        dispatch_notification(app_handle, router, notification).await;
+    }
+
+    Ok(due.len())
+}
+
+pub fn start_reminder_loop(
+    app_handle: AppHandle,
+    db: SharedMultisigDatabase,
+    router: SharedNotificationRouter,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(REMINDER_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = process_approval_reminders(&app_handle, &db, &router).await {
+                tracing::warn!(error = %err, "multisig reminder sweep failed");
+            }
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn set_multisig_reminder_settings(
+    wallet_id: String,
+    enabled: bool,
+    interval_hours: Option<u32>,
+    db: State<'_, SharedMultisigDatabase>,
+) -> Result<ReminderSettings, String> {
+    let interval_hours = interval_hours.unwrap_or(DEFAULT_REMINDER_INTERVAL_HOURS);
+    if enabled && interval_hours == 0 {
+        return Err("Reminder interval must be at least one hour".to_string());
+    }
+
+    let db_guard = db.read().await;
+    db_guard
+        .get_wallet(&wallet_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Wallet not found".to_string())?;
+
+    let settings = ReminderSettings {
+        wallet_id,
+        enabled,
+        interval_hours,
+        updated_at: Utc::now(),
+    };
+    db_guard
+        .set_reminder_settings(&settings)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn get_multisig_reminder_settings(
+    wallet_id: String,
+    db: State<'_, SharedMultisigDatabase>,
+) -> Result<ReminderSettings, String> {
+    let db_guard = db.read().await;
+    let settings = db_guard
+        .get_reminder_settings(&wallet_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(settings.unwrap_or(ReminderSettings {
+        wallet_id,
+        enabled: true,
+        interval_hours: DEFAULT_REMINDER_INTERVAL_HOURS,
+        updated_at: Utc::now(),
+    }))
+}
+
+#[tauri::command]
+pub async fn send_multisig_reminders_now(
+    app_handle: AppHandle,
+    db: State<'_, SharedMultisigDatabase>,
+    router: State<'_, SharedNotificationRouter>,
+) -> Result<usize, String> {
+    process_approval_reminders(&app_handle, db.inner(), router.inner()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::multisig::ProposalSignature;
+
+    fn wallet() -> MultisigWallet {
+        MultisigWallet {
+            id: "multisig_1".to_string(),
+            name: "Treasury".to_string(),
+            address: "addr".to_string(),
+            threshold: 2,
+            members: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+            created_at: Utc::now(),
+            balance: 0.0,
+        }
+    }
+
+    fn proposal(signers: &[&str]) -> MultisigProposal {
+        MultisigProposal {
+            id: "proposal_1".to_string(),
+            wallet_id: "multisig_1".to_string(),
+            transaction_data: "{}".to_string(),
+            status: ProposalStatus::Pending,
+            created_by: "alice".to_string(),
+            created_at: Utc::now(),
+            description: Some("Pay contributors".to_string()),
+            signatures: signers
+                .iter()
+                .map(|signer| ProposalSignature {
+                    id: format!("sig_{}", signer),
+                    proposal_id: "proposal_1".to_string(),
+                    signer: signer.to_string(),
+                    signature: "sig".to_string(),
+                    signed_at: Utc::now(),
+                })
+                .collect(),
+            executed_at: None,
+            tx_signature: None,
+            policy: None,
+        }
+    }
+
+    #[test]
+    fn reminder_targets_unsigned_members_with_deep_link() {
+        let notification = build_notification(
+            MultisigEventKind::ApprovalReminder,
+            &wallet(),
+            &proposal(&["alice"]),
+        );
+
+        assert_eq!(notification.recipients, vec!["bob".to_string(), "carol".to_string()]);
+        assert_eq!(notification.required_signatures, 2);
+        assert_eq!(
+            notification.deep_link.url,
+            "eclipse://multisig/multisig_1/proposals/proposal_1"
+        );
+        assert!(notification.message.contains(&notification.deep_link.url));
+    }
+
+    #[test]
+    fn created_notification_goes_to_all_members() {
+        let notification =
+            build_notification(MultisigEventKind::ProposalCreated, &wallet(), &proposal(&[]));
+        assert_eq!(notification.recipients.len(), 3);
+    }
+
+    #[test]
+    fn reminder_due_respects_interval() {
+        let now = Utc::now();
+        let created = now - Duration::hours(30);
+
+        assert!(reminder_due(created, None, 24, now));
+        assert!(!reminder_due(created, Some(now - Duration::hours(2)), 24, now));
+        assert!(reminder_due(created, Some(now - Duration::hours(25)), 24, now));
+        assert!(!reminder_due(created, None, 0, now));
+    }
+}
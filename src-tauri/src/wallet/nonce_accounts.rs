@@ -0,0 +1,858 @@
+use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
+use base64::Engine;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use solana_sdk::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::{Message, MessageHeader, VersionedMessage},
+    nonce::state::{State as NonceState, Versions as NonceVersions},
+    pubkey::Pubkey,
+    signature::Signature,
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+use sqlx::{FromRow, Pool, Sqlite, SqlitePool};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+
+use crate::wallet::offline_signing::{decode_transaction, inspect_unsigned_transaction};
+
+const NONCE_DB_FILE: &str = "nonce_accounts.db";
+const DEFAULT_RPC_ENDPOINT: &str = "https://api.mainnet-beta.solana.com";
+const NONCE_SEED_PREFIX: &str = "eclipse-nonce-";
+/// A recent blockhash stays valid for roughly this long (150 slots).
+pub const BLOCKHASH_LIFETIME_SECS: i64 = 60;
+
+/// Where a transaction goes after it is built. Paths that wait on people or
+/// timers outlive a recent blockhash and get a durable nonce instead.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionPath {
+    Immediate,
+    MultisigApproval,
+    Scheduled,
+    OfflineSigning,
+}
+
+pub fn prefers_durable_nonce(path: TransactionPath) -> bool {
+    !matches!(path, TransactionPath::Immediate)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NonceAccountStatus {
+    /// Create transaction built but the account is not on chain yet.
+    Pending,
+    Active,
+    Closed,
+}
+
+impl NonceAccountStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            NonceAccountStatus::Pending => "pending",
+            NonceAccountStatus::Active => "active",
+            NonceAccountStatus::Closed => "closed",
+        }
+    }
+}
+
+impl FromStr for NonceAccountStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(NonceAccountStatus::Pending),
+            "active" => Ok(NonceAccountStatus::Active),
+            "closed" => Ok(NonceAccountStatus::Closed),
+            _ => Err(format!("Invalid nonce account status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct NonceAccountRecord {
+    pub address: String,
+    /// Funding wallet and nonce authority.
+    pub wallet_address: String,
+    pub seed: String,
+    pub status: String,
+    pub nonce: Option<String>,
+    pub lamports: i64,
+    /// Set while a built transaction depends on the current nonce value.
+    pub reserved_for: Option<String>,
+    pub reserved_nonce: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl NonceAccountRecord {
+    pub fn status(&self) -> NonceAccountStatus {
+        NonceAccountStatus::from_str(&self.status).unwrap_or(NonceAccountStatus::Pending)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NonceAccountAction {
+    Create,
+    Advance,
+    Close,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NonceAccountTransaction {
+    pub nonce_address: String,
+    pub action: NonceAccountAction,
+    /// Base64 unsigned transaction for the wallet to sign.
+    pub transaction: String,
+    pub lamports: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DurableTransaction {
+    pub transaction: String,
+    /// None when the path does not need a nonce or the transaction already had one.
+    pub nonce_address: Option<String>,
+    pub nonce: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OnChainNonce {
+    pub authority: Pubkey,
+    pub nonce: Hash,
+}
+
+pub fn nonce_seed(index: i64) -> String {
+    format!("{}{}", NONCE_SEED_PREFIX, index)
+}
+
+/// Nonce accounts are derived from the wallet with a seed, so the wallet is the
+/// only signer needed to create them.
+pub fn derive_nonce_address(wallet: &Pubkey, seed: &str) -> Result<Pubkey, String> {
+    Pubkey::create_with_seed(wallet, seed, &system_program::id())
+        .map_err(|e| format!("Failed to derive nonce address: {e}"))
+}
+
+pub fn parse_nonce_account(data: &[u8]) -> Result<Option<OnChainNonce>, String> {
+    let versions: NonceVersions =
+        bincode::deserialize(data).map_err(|e| format!("Not a nonce account: {e}"))?;
+    Ok(match versions.state() {
+        NonceState::Initialized(data) => Some(OnChainNonce {
+            authority: data.authority,
+            nonce: data.blockhash(),
+        }),
+        NonceState::Uninitialized => None,
+    })
+}
+
+fn header_writable(header: &MessageHeader, index: usize, key_count: usize) -> bool {
+    let signers = header.num_required_signatures as usize;
+    if index < signers {
+        index < signers - header.num_readonly_signed_accounts as usize
+    } else {
+        index < key_count - header.num_readonly_unsigned_accounts as usize
+    }
+}
+
+/// Expands a legacy message back into instructions so it can be recompiled
+/// with extra instructions.
+pub fn decompile_legacy_message(message: &Message) -> Result<Vec<Instruction>, String> {
+    let keys = &message.account_keys;
+    message
+        .instructions
+        .iter()
+        .map(|compiled| {
+            let program_id = *keys
+                .get(compiled.program_id_index as usize)
+                .ok_or_else(|| "Instruction references a missing program".to_string())?;
+            let accounts = compiled
+                .accounts
+                .iter()
+                .map(|&index| {
+                    let index = index as usize;
+                    let pubkey = *keys
+                        .get(index)
+                        .ok_or_else(|| "Instruction references a missing account".to_string())?;
+                    Ok(AccountMeta {
+                        pubkey,
+                        is_signer: message.is_signer(index),
+                        is_writable: header_writable(&message.header, index, keys.len()),
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            Ok(Instruction::new_with_bytes(
+                program_id,
+                &compiled.data,
+                accounts,
+            ))
+        })
+        .collect()
+}
+
+/// Rebuilds an unsigned legacy transaction so it advances `nonce_address` first
+/// and uses the stored nonce in place of a recent blockhash.
+pub fn rewrite_with_durable_nonce(
+    encoded: &str,
+    nonce_address: &Pubkey,
+    authority: &Pubkey,
+    nonce: &Hash,
+) -> Result<String, String> {
+    let transaction = decode_transaction(encoded)?;
+    if transaction
+        .signatures
+        .iter()
+        .any(|s| *s != Signature::default())
+    {
+        return Err("Transaction is already signed; add the nonce before signing".to_string());
+    }
+    let message = match transaction.message {
+        VersionedMessage::Legacy(message) => message,
+        VersionedMessage::V0(_) => {
+            return Err("Durable nonces are only supported for legacy transactions".to_string())
+        }
+    };
+
+    let instructions = decompile_legacy_message(&message)?;
+    let payer = message
+        .account_keys
+        .first()
+        .ok_or_else(|| "Transaction has no fee payer".to_string())?;
+    let mut message = Message::new_with_nonce(instructions, Some(payer), nonce_address, authority);
+    message.recent_blockhash = *nonce;
+    let bytes = bincode::serialize(&Transaction::new_unsigned(message))
+        .map_err(|e| format!("Failed to serialize transaction: {e}"))?;
+    Ok(BASE64_ENGINE.encode(bytes))
+}
+
+pub struct NonceAccountManager {
+    pool: Pool<Sqlite>,
+    client: reqwest::Client,
+}
+
+pub type SharedNonceAccountManager = Arc<RwLock<NonceAccountManager>>;
+
+impl NonceAccountManager {
+    pub async fn open(db_path: &Path) -> Result<Self, String> {
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url)
+            .await
+            .map_err(|e| format!("Failed to open nonce account database: {e}"))?;
+
+        let manager = Self {
+            pool,
+            client: reqwest::Client::new(),
+        };
+        manager
+            .initialize()
+            .await
+            .map_err(|e| format!("Failed to initialize nonce account database: {e}"))?;
+        Ok(manager)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS nonce_accounts (
+                address TEXT PRIMARY KEY,
+                wallet_address TEXT NOT NULL,
+                seed TEXT NOT NULL,
+                status TEXT NOT NULL,
+                nonce TEXT,
+                lamports INTEGER NOT NULL,
+                reserved_for TEXT,
+                reserved_nonce TEXT,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_nonce_accounts_wallet
+                ON nonce_accounts(wallet_address)
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn rpc(&self, rpc_url: &str, method: &str, params: Value) -> Result<Value, String> {
+        let payload = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let data: Value = self
+            .client
+            .post(rpc_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("RPC request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Some(error) = data.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+        Ok(data["result"].clone())
+    }
+
+    async fn latest_blockhash(&self, rpc_url: &str) -> Result<Hash, String> {
+        self.rpc(rpc_url, "getLatestBlockhash", json!([]))
+            .await?
+            .get("value")
+            .and_then(|v| v.get("blockhash"))
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| "Invalid blockhash response".to_string())
+            .and_then(|s| Hash::from_str(s).map_err(|e| format!("Invalid blockhash: {e}")))
+    }
+
+    /// Returns the account's lamports and nonce, or None when it does not exist.
+    async fn fetch_nonce(
+        &self,
+        address: &str,
+        rpc_url: &str,
+    ) -> Result<Option<(u64, Option<OnChainNonce>)>, String> {
+        let result = self
+            .rpc(
+                rpc_url,
+                "getAccountInfo",
+                json!([address, { "encoding": "base64" }]),
+            )
+            .await?;
+        let value = match result.get("value") {
+            Some(value) if !value.is_null() => value,
+            _ => return Ok(None),
+        };
+        let lamports = value["lamports"].as_u64().unwrap_or(0);
+        let data = value["data"][0]
+            .as_str()
+            .ok_or_else(|| "Invalid account data".to_string())?;
+        let bytes = BASE64_ENGINE
+            .decode(data)
+            .map_err(|e| format!("Invalid account data: {e}"))?;
+        Ok(Some((lamports, parse_nonce_account(&bytes)?)))
+    }
+
+    async fn save(&self, record: &NonceAccountRecord) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO nonce_accounts (
+                address, wallet_address, seed, status, nonce, lamports,
+                reserved_for, reserved_nonce, created_at, updated_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            "#,
+        )
+        .bind(&record.address)
+        .bind(&record.wallet_address)
+        .bind(&record.seed)
+        .bind(&record.status)
+        .bind(&record.nonce)
+        .bind(record.lamports)
+        .bind(&record.reserved_for)
+        .bind(&record.reserved_nonce)
+        .bind(record.created_at)
+        .bind(record.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save nonce account: {e}"))?;
+        Ok(())
+    }
+
+    pub async fn get(&self, address: &str) -> Result<Option<NonceAccountRecord>, String> {
+        sqlx::query_as::<_, NonceAccountRecord>("SELECT * FROM nonce_accounts WHERE address = ?1")
+            .bind(address)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn list(&self, wallet: Option<&str>) -> Result<Vec<NonceAccountRecord>, String> {
+        sqlx::query_as::<_, NonceAccountRecord>(
+            r#"
+            SELECT * FROM nonce_accounts
+            WHERE ?1 IS NULL OR wallet_address = ?1
+            ORDER BY created_at
+            "#,
+        )
+        .bind(wallet)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    /// Syncs a record with chain state. A reservation is dropped once the nonce
+    /// has moved, since the transaction that held it was used or invalidated.
+    pub async fn refresh(
+        &self,
+        mut record: NonceAccountRecord,
+        rpc_url: &str,
+    ) -> Result<NonceAccountRecord, String> {
+        match self.fetch_nonce(&record.address, rpc_url).await? {
+            Some((lamports, Some(on_chain))) => {
+                record.status = NonceAccountStatus::Active.as_str().to_string();
+                record.nonce = Some(on_chain.nonce.to_string());
+                record.lamports = lamports as i64;
+            }
+            Some((lamports, None)) => record.lamports = lamports as i64,
+            None if record.status() == NonceAccountStatus::Active => {
+                record.status = NonceAccountStatus::Closed.as_str().to_string();
+                record.nonce = None;
+                record.lamports = 0;
+            }
+            None => {}
+        }
+        if record.reserved_nonce.is_some() && record.reserved_nonce != record.nonce {
+            record.reserved_for = None;
+            record.reserved_nonce = None;
+        }
+        record.updated_at = Utc::now().timestamp();
+        self.save(&record).await?;
+        Ok(record)
+    }
+
+    pub async fn build_create(
+        &self,
+        wallet: &str,
+        rpc_url: &str,
+    ) -> Result<NonceAccountTransaction, String> {
+        let owner = Pubkey::from_str(wallet).map_err(|e| format!("Invalid wallet address: {e}"))?;
+        let existing = self.list(Some(wallet)).await?;
+        let seed = nonce_seed(existing.len() as i64);
+        let nonce_address = derive_nonce_address(&owner, &seed)?;
+
+        let lamports = self
+            .rpc(
+                rpc_url,
+                "getMinimumBalanceForRentExemption",
+                json!([NonceState::size()]),
+            )
+            .await?
+            .as_u64()
+            .ok_or_else(|| "Invalid rent exemption response".to_string())?;
+        let blockhash = self.latest_blockhash(rpc_url).await?;
+
+        let instructions = system_instruction::create_nonce_account_with_seed(
+            &owner,
+            &nonce_address,
+            &owner,
+            &seed,
+            &owner,
+            lamports,
+        );
+        let message = Message::new_with_blockhash(&instructions, Some(&owner), &blockhash);
+        let transaction = encode_unsigned(message)?;
+
+        let now = Utc::now().timestamp();
+        self.save(&NonceAccountRecord {
+            address: nonce_address.to_string(),
+            wallet_address: wallet.to_string(),
+            seed,
+            status: NonceAccountStatus::Pending.as_str().to_string(),
+            nonce: None,
+            lamports: lamports as i64,
+            reserved_for: None,
+            reserved_nonce: None,
+            created_at: now,
+            updated_at: now,
+        })
+        .await?;
+
+        Ok(NonceAccountTransaction {
+            nonce_address: nonce_address.to_string(),
+            action: NonceAccountAction::Create,
+            transaction,
+            lamports,
+        })
+    }
+
+    /// Builds an advance or close transaction for an account the app manages.
+    pub async fn build_maintenance(
+        &self,
+        address: &str,
+        action: NonceAccountAction,
+        rpc_url: &str,
+    ) -> Result<NonceAccountTransaction, String> {
+        let record = self
+            .get(address)
+            .await?
+            .ok_or_else(|| "Nonce account not found".to_string())?;
+        let record = self.refresh(record, rpc_url).await?;
+        if record.status() != NonceAccountStatus::Active {
+            return Err("Nonce account is not active on chain".to_string());
+        }
+        if let Some(holder) = &record.reserved_for {
+            return Err(format!(
+                "Nonce account is reserved for {}; release it first",
+                holder
+            ));
+        }
+
+        let nonce_address = Pubkey::from_str(address).map_err(|e| e.to_string())?;
+        let owner = Pubkey::from_str(&record.wallet_address).map_err(|e| e.to_string())?;
+        let lamports = record.lamports.max(0) as u64;
+        let instruction = match action {
+            NonceAccountAction::Advance => {
+                system_instruction::advance_nonce_account(&nonce_address, &owner)
+            }
+            NonceAccountAction::Close => {
+                system_instruction::withdraw_nonce_account(&nonce_address, &owner, &owner, lamports)
+            }
+            NonceAccountAction::Create => {
+                return Err("Use build_create_nonce_account to create accounts".to_string())
+            }
+        };
+        let blockhash = self.latest_blockhash(rpc_url).await?;
+        let message = Message::new_with_blockhash(&[instruction], Some(&owner), &blockhash);
+
+        Ok(NonceAccountTransaction {
+            nonce_address: address.to_string(),
+            action,
+            transaction: encode_unsigned(message)?,
+            lamports,
+        })
+    }
+
+    /// Reserves an idle nonce account of the wallet and rewrites the transaction
+    /// to use it. Returns None when the wallet has no idle account.
+    pub async fn reserve_for_transaction(
+        &self,
+        wallet: &str,
+        transaction: &str,
+        reserved_for: &str,
+        rpc_url: &str,
+    ) -> Result<Option<DurableTransaction>, String> {
+        let authority =
+            Pubkey::from_str(wallet).map_err(|e| format!("Invalid wallet address: {e}"))?;
+
+        for record in self.list(Some(wallet)).await? {
+            if record.status() == NonceAccountStatus::Closed {
+                continue;
+            }
+            let mut record = match self.refresh(record, rpc_url).await {
+                Ok(record) => record,
+                Err(err) => {
+                    tracing::warn!(error = %err, "failed to refresh nonce account");
+                    continue;
+                }
+            };
+            if record.status() != NonceAccountStatus::Active || record.reserved_for.is_some() {
+                continue;
+            }
+            let nonce = match record.nonce.as_deref().map(Hash::from_str) {
+                Some(Ok(nonce)) => nonce,
+                _ => continue,
+            };
+
+            let nonce_address = Pubkey::from_str(&record.address).map_err(|e| e.to_string())?;
+            let rewritten =
+                rewrite_with_durable_nonce(transaction, &nonce_address, &authority, &nonce)?;
+            record.reserved_for = Some(reserved_for.to_string());
+            record.reserved_nonce = record.nonce.clone();
+            record.updated_at = Utc::now().timestamp();
+            self.save(&record).await?;
+
+            return Ok(Some(DurableTransaction {
+                transaction: rewritten,
+                nonce_address: Some(record.address),
+                nonce: record.nonce,
+            }));
+        }
+        Ok(None)
+    }
+
+    pub async fn release(&self, reserved_for: &str) -> Result<u64, String> {
+        let result = sqlx::query(
+            r#"
+            UPDATE nonce_accounts
+            SET reserved_for = NULL, reserved_nonce = NULL, updated_at = ?2
+            WHERE reserved_for = ?1
+            "#,
+        )
+        .bind(reserved_for)
+        .bind(Utc::now().timestamp())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn send_signed(&self, transaction: &str, rpc_url: &str) -> Result<String, String> {
+        self.rpc(
+            rpc_url,
+            "sendTransaction",
+            json!([transaction, { "encoding": "base64" }]),
+        )
+        .await?
+        .as_str()
+        .map(|s| s.to_string())
+        .ok_or_else(|| "Invalid sendTransaction response".to_string())
+    }
+}
+
+fn encode_unsigned(message: Message) -> Result<String, String> {
+    let bytes = bincode::serialize(&Transaction::new_unsigned(message))
+        .map_err(|e| format!("Failed to serialize transaction: {e}"))?;
+    Ok(BASE64_ENGINE.encode(bytes))
+}
+
+/// Uses a durable nonce when the path calls for one and the wallet has an idle
+/// account; otherwise the transaction is returned unchanged.
+pub async fn prepare_for_path(
+    manager: &NonceAccountManager,
+    wallet: &str,
+    transaction: &str,
+    path: TransactionPath,
+    reserved_for: &str,
+    rpc_url: &str,
+) -> Result<DurableTransaction, String> {
+    let unchanged = DurableTransaction {
+        transaction: transaction.to_string(),
+        nonce_address: None,
+        nonce: None,
+    };
+    if !prefers_durable_nonce(path) || inspect_unsigned_transaction(transaction)?.durable_nonce {
+        return Ok(unchanged);
+    }
+    Ok(manager
+        .reserve_for_transaction(wallet, transaction, reserved_for, rpc_url)
+        .await?
+        .unwrap_or(unchanged))
+}
+
+pub fn register_nonce_accounts(app: &tauri::App) {
+    let dir = match app.path_resolver().app_data_dir() {
+        Some(dir) => dir,
+        None => {
+            tracing::warn!("nonce accounts disabled: no app data directory");
+            return;
+        }
+    };
+    if let Err(err) = std::fs::create_dir_all(&dir) {
+        tracing::warn!(error = %err, "failed to create app data directory");
+    }
+
+    match tauri::async_runtime::block_on(NonceAccountManager::open(&dir.join(NONCE_DB_FILE))) {
+        Ok(manager) => {
+            app.manage::<SharedNonceAccountManager>(Arc::new(RwLock::new(manager)));
+        }
+        Err(err) => tracing::warn!(error = %err, "failed to open nonce account store"),
+    }
+}
+
+/// Releases the nonce held for `reserved_for`, for callers that may run
+/// without the nonce store.
+pub async fn release_nonce_reservation(app: &AppHandle, reserved_for: &str) {
+    if let Some(manager) = app.try_state::<SharedNonceAccountManager>() {
+        if let Err(err) = manager.read().await.release(reserved_for).await {
+            tracing::warn!(error = %err, "failed to release nonce reservation");
+        }
+    }
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn list_nonce_accounts(
+    wallet: Option<String>,
+    refresh: Option<bool>,
+    rpc_url: Option<String>,
+    manager: State<'_, SharedNonceAccountManager>,
+) -> Result<Vec<NonceAccountRecord>, String> {
+    let manager = manager.read().await;
+    let records = manager.list(wallet.as_deref()).await?;
+    if !refresh.unwrap_or(false) {
+        return Ok(records);
+    }
+
+    let rpc_url = rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT);
+    let mut refreshed = Vec::with_capacity(records.len());
+    for record in records {
+        if record.status() == NonceAccountStatus::Closed {
+            refreshed.push(record);
+        } else {
+            refreshed.push(manager.refresh(record, rpc_url).await?);
+        }
+    }
+    Ok(refreshed)
+}
+
+#[tauri::command]
+pub async fn build_create_nonce_account(
+    wallet: String,
+    rpc_url: Option<String>,
+    manager: State<'_, SharedNonceAccountManager>,
+) -> Result<NonceAccountTransaction, String> {
+    let manager = manager.read().await;
+    manager
+        .build_create(&wallet, rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT))
+        .await
+}
+
+#[tauri::command]
+pub async fn build_advance_nonce_account(
+    address: String,
+    rpc_url: Option<String>,
+    manager: State<'_, SharedNonceAccountManager>,
+) -> Result<NonceAccountTransaction, String> {
+    let manager = manager.read().await;
+    manager
+        .build_maintenance(
+            &address,
+            NonceAccountAction::Advance,
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await
+}
+
+#[tauri::command]
+pub async fn build_close_nonce_account(
+    address: String,
+    rpc_url: Option<String>,
+    manager: State<'_, SharedNonceAccountManager>,
+) -> Result<NonceAccountTransaction, String> {
+    let manager = manager.read().await;
+    manager
+        .build_maintenance(
+            &address,
+            NonceAccountAction::Close,
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await
+}
+
+/// Sends a signed create, advance or close transaction.
+#[tauri::command]
+pub async fn submit_nonce_account_transaction(
+    signed_transaction: String,
+    rpc_url: Option<String>,
+    manager: State<'_, SharedNonceAccountManager>,
+) -> Result<String, String> {
+    let manager = manager.read().await;
+    manager
+        .send_signed(
+            &signed_transaction,
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await
+}
+
+#[tauri::command]
+pub async fn prepare_durable_transaction(
+    wallet: String,
+    transaction: String,
+    path: TransactionPath,
+    reserved_for: String,
+    rpc_url: Option<String>,
+    manager: State<'_, SharedNonceAccountManager>,
+) -> Result<DurableTransaction, String> {
+    let manager = manager.read().await;
+    prepare_for_path(
+        &manager,
+        &wallet,
+        &transaction,
+        path,
+        &reserved_for,
+        rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn release_nonce_account(
+    reserved_for: String,
+    manager: State<'_, SharedNonceAccountManager>,
+) -> Result<u64, String> {
+    let manager = manager.read().await;
+    manager.release(&reserved_for).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::nonce::state::{Data, DurableNonce};
+    use solana_sdk::transaction::VersionedTransaction;
+
+    fn unsigned_transfer(payer: &Pubkey, to: &Pubkey) -> String {
+        let instruction = system_instruction::transfer(payer, to, 5_000);
+        let message = Message::new_with_blockhash(&[instruction], Some(payer), &Hash::new_unique());
+        encode_unsigned(message).unwrap()
+    }
+
+    #[test]
+    fn only_slow_paths_prefer_durable_nonces() {
+        assert!(!prefers_durable_nonce(TransactionPath::Immediate));
+        assert!(prefers_durable_nonce(TransactionPath::MultisigApproval));
+        assert!(prefers_durable_nonce(TransactionPath::Scheduled));
+        assert!(prefers_durable_nonce(TransactionPath::OfflineSigning));
+
+        let wallet = Pubkey::new_unique();
+        let first = derive_nonce_address(&wallet, &nonce_seed(0)).unwrap();
+        assert_eq!(
+            first,
+            derive_nonce_address(&wallet, &nonce_seed(0)).unwrap()
+        );
+        assert_ne!(
+            first,
+            derive_nonce_address(&wallet, &nonce_seed(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_initialized_nonce_accounts() {
+        let authority = Pubkey::new_unique();
+        let durable = DurableNonce::from_blockhash(&Hash::new_unique());
+        let state = NonceState::Initialized(Data::new(authority, durable, 5_000));
+        let bytes = bincode::serialize(&NonceVersions::new(state)).unwrap();
+
+        let parsed = parse_nonce_account(&bytes).unwrap().unwrap();
+        assert_eq!(parsed.authority, authority);
+        assert_eq!(parsed.nonce, *durable.as_hash());
+
+        let empty = bincode::serialize(&NonceVersions::new(NonceState::Uninitialized)).unwrap();
+        assert_eq!(parse_nonce_account(&empty).unwrap(), None);
+        assert!(parse_nonce_account(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn rewrites_transactions_to_advance_the_nonce_first() {
+        let wallet = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let nonce_address = derive_nonce_address(&wallet, &nonce_seed(0)).unwrap();
+        let nonce = Hash::new_unique();
+
+        let original = unsigned_transfer(&wallet, &recipient);
+        assert!(
+            !inspect_unsigned_transaction(&original)
+                .unwrap()
+                .durable_nonce
+        );
+
+        let rewritten =
+            rewrite_with_durable_nonce(&original, &nonce_address, &wallet, &nonce).unwrap();
+        let info = inspect_unsigned_transaction(&rewritten).unwrap();
+        assert!(info.durable_nonce);
+        assert_eq!(info.recent_blockhash, nonce.to_string());
+        assert_eq!(info.required_signers, vec![wallet.to_string()]);
+
+        let bytes = BASE64_ENGINE.decode(rewritten).unwrap();
+        let transaction: VersionedTransaction = bincode::deserialize(&bytes).unwrap();
+        let instructions = transaction.message.instructions();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(
+            instructions[1].data,
+            system_instruction::transfer(&wallet, &recipient, 5_000).data
+        );
+    }
+}
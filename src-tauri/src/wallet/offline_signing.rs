@@ -0,0 +1,999 @@
+use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use qrcodegen::{QrCode, QrCodeEcc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use solana_sdk::{
+    pubkey::Pubkey, signature::Signature, system_program, transaction::VersionedTransaction,
+};
+use sqlx::{Pool, Row, Sqlite, SqlitePool};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::wallet::nonce_accounts::{
+    prepare_for_path, release_nonce_reservation, SharedNonceAccountManager, TransactionPath,
+};
+use crate::wallet::tx_scheduler::SharedTransactionScheduler;
+
+const OFFLINE_SIGNING_DB_FILE: &str = "offline_signing.db";
+const OFFLINE_SIGNING_EVENT: &str = "offline_signing_update";
+const DEFAULT_RPC_ENDPOINT: &str = "https://api.mainnet-beta.solana.com";
+const WATCH_INTERVAL: StdDuration = StdDuration::from_secs(15);
+const BUNDLE_VERSION: u32 = 1;
+/// Prefix of the QR payload for an unsigned bundle, followed by `<id>:<base64 tx>`.
+const QR_PREFIX: &str = "eclipse-offline:v1:";
+/// `SystemInstruction::AdvanceNonceAccount` as encoded on the wire.
+const ADVANCE_NONCE_TAG: [u8; 4] = [4, 0, 0, 0];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OfflineSigningStatus {
+    AwaitingSignature,
+    Signed,
+    Submitted,
+    /// The blockhash lapsed before submission; the bundle must be re-exported.
+    Expired,
+    Failed,
+    Cancelled,
+}
+
+impl OfflineSigningStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            OfflineSigningStatus::AwaitingSignature => "awaiting_signature",
+            OfflineSigningStatus::Signed => "signed",
+            OfflineSigningStatus::Submitted => "submitted",
+            OfflineSigningStatus::Expired => "expired",
+            OfflineSigningStatus::Failed => "failed",
+            OfflineSigningStatus::Cancelled => "cancelled",
+        }
+    }
+
+    fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            OfflineSigningStatus::AwaitingSignature | OfflineSigningStatus::Signed
+        )
+    }
+}
+
+impl FromStr for OfflineSigningStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "awaiting_signature" => Ok(OfflineSigningStatus::AwaitingSignature),
+            "signed" => Ok(OfflineSigningStatus::Signed),
+            "submitted" => Ok(OfflineSigningStatus::Submitted),
+            "expired" => Ok(OfflineSigningStatus::Expired),
+            "failed" => Ok(OfflineSigningStatus::Failed),
+            "cancelled" => Ok(OfflineSigningStatus::Cancelled),
+            _ => Err(format!("Invalid offline signing status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineSigningRequest {
+    pub id: String,
+    pub wallet_address: String,
+    pub description: Option<String>,
+    /// Base64 transaction as exported, without signatures.
+    #[serde(skip_serializing)]
+    pub unsigned_transaction: String,
+    /// SHA-256 of the serialized message; a signed return must match it.
+    pub message_hash: String,
+    pub required_signers: Vec<String>,
+    pub recent_blockhash: String,
+    /// Durable-nonce transactions do not expire with the blockhash.
+    pub durable_nonce: bool,
+    #[serde(skip_serializing)]
+    pub signed_transaction: Option<String>,
+    pub rpc_url: Option<String>,
+    pub status: OfflineSigningStatus,
+    pub tx_signature: Option<String>,
+    pub error: Option<String>,
+    pub bundle_path: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub signed_at: Option<DateTime<Utc>>,
+    pub submitted_at: Option<DateTime<Utc>>,
+}
+
+/// File written to the outbox for the airgapped signer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineSigningBundle {
+    pub version: u32,
+    pub id: String,
+    pub wallet_address: String,
+    pub description: Option<String>,
+    pub message_hash: String,
+    pub required_signers: Vec<String>,
+    pub recent_blockhash: String,
+    pub durable_nonce: bool,
+    pub transaction: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Signed result brought back from the airgapped signer. The id is optional;
+/// without it the request is matched by message hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedOfflineBundle {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub transaction: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportOfflineSigningRequest {
+    pub wallet_address: String,
+    /// Base64 serialized transaction, signatures left empty.
+    pub transaction: String,
+    pub description: Option<String>,
+    pub rpc_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineSigningExport {
+    pub request: OfflineSigningRequest,
+    pub bundle: OfflineSigningBundle,
+    /// SVG QR code of the bundle, when the transaction fits in one code.
+    pub qr_svg: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineSigningConfig {
+    /// Folder holding `outbox/` (unsigned bundles) and `inbox/` (signed returns).
+    /// Defaults to `offline_signing` in the app data directory.
+    pub watch_folder: Option<String>,
+}
+
+impl Default for OfflineSigningConfig {
+    fn default() -> Self {
+        Self { watch_folder: None }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineSigningUpdate {
+    pub id: String,
+    pub status: OfflineSigningStatus,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsignedTransactionInfo {
+    pub message_hash: String,
+    pub required_signers: Vec<String>,
+    pub recent_blockhash: String,
+    pub durable_nonce: bool,
+}
+
+pub fn decode_transaction(encoded: &str) -> Result<VersionedTransaction, String> {
+    let bytes = BASE64_ENGINE
+        .decode(encoded.trim().as_bytes())
+        .map_err(|e| format!("Invalid transaction encoding: {e}"))?;
+    bincode::deserialize(&bytes).map_err(|e| format!("Failed to decode transaction: {e}"))
+}
+
+fn message_hash(transaction: &VersionedTransaction) -> String {
+    hex::encode(Sha256::digest(transaction.message.serialize()))
+}
+
+pub fn uses_durable_nonce(transaction: &VersionedTransaction) -> bool {
+    let keys = transaction.message.static_account_keys();
+    transaction
+        .message
+        .instructions()
+        .first()
+        .is_some_and(|instruction| {
+            keys.get(instruction.program_id_index as usize) == Some(&system_program::id())
+                && instruction.data.starts_with(&ADVANCE_NONCE_TAG)
+        })
+}
+
+/// Reads what the signer will be asked to sign from an unsigned transaction.
+pub fn inspect_unsigned_transaction(encoded: &str) -> Result<UnsignedTransactionInfo, String> {
+    let transaction = decode_transaction(encoded)?;
+    let required = transaction.message.header().num_required_signatures as usize;
+    let keys = transaction.message.static_account_keys();
+    if required == 0 || keys.len() < required {
+        return Err("Transaction names no signers".to_string());
+    }
+
+    Ok(UnsignedTransactionInfo {
+        message_hash: message_hash(&transaction),
+        required_signers: keys[..required].iter().map(Pubkey::to_string).collect(),
+        recent_blockhash: transaction.message.recent_blockhash().to_string(),
+        durable_nonce: uses_durable_nonce(&transaction),
+    })
+}
+
+/// Checks a signed return against the exported message: same message, and a
+/// valid signature from every required signer. Returns the first signature.
+pub fn verify_signed_transaction(expected_hash: &str, encoded: &str) -> Result<String, String> {
+    let transaction = decode_transaction(encoded)?;
+    if message_hash(&transaction) != expected_hash {
+        return Err("Signed transaction does not match the exported message".to_string());
+    }
+
+    let message = transaction.message.serialize();
+    let required = transaction.message.header().num_required_signatures as usize;
+    let keys = transaction.message.static_account_keys();
+    if transaction.signatures.len() < required || keys.len() < required {
+        return Err("Signed transaction is missing signatures".to_string());
+    }
+    for (signature, signer) in transaction.signatures.iter().zip(keys).take(required) {
+        if *signature == Signature::default() {
+            return Err(format!("{} has not signed", signer));
+        }
+        if !signature.verify(signer.as_ref(), &message) {
+            return Err(format!("Invalid signature from {}", signer));
+        }
+    }
+
+    Ok(transaction.signatures[0].to_string())
+}
+
+fn qr_svg(text: &str) -> Option<String> {
+    let qr = QrCode::encode_text(text, QrCodeEcc::Low).ok()?;
+    let size = qr.size() as usize;
+    let border = 4;
+    let total_size = size + border * 2;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" viewBox=\"0 0 {} {}\" stroke=\"none\">",
+        total_size, total_size
+    );
+    svg.push_str("<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>");
+    svg.push_str("<path d=\"");
+    for y in 0..size {
+        for x in 0..size {
+            if qr.get_module(x as i32, y as i32) {
+                svg.push_str(&format!("M{},{}h1v1h-1z", x + border, y + border));
+            }
+        }
+    }
+    svg.push_str("\" fill=\"#000000\"/></svg>");
+    Some(svg)
+}
+
+/// Accepts a signed bundle file, a scanned QR payload or a bare base64 transaction.
+pub fn parse_signed_payload(payload: &str) -> Result<SignedOfflineBundle, String> {
+    let payload = payload.trim();
+    if payload.starts_with('{') {
+        return serde_json::from_str(payload).map_err(|e| format!("Invalid signed bundle: {e}"));
+    }
+    if let Some(rest) = payload.strip_prefix(QR_PREFIX) {
+        let (id, transaction) = rest
+            .split_once(':')
+            .ok_or_else(|| "Malformed offline signing QR payload".to_string())?;
+        return Ok(SignedOfflineBundle {
+            id: Some(id.to_string()),
+            transaction: transaction.to_string(),
+        });
+    }
+    Ok(SignedOfflineBundle {
+        id: None,
+        transaction: payload.to_string(),
+    })
+}
+
+pub struct OfflineSigningStore {
+    pool: Pool<Sqlite>,
+    client: reqwest::Client,
+}
+
+pub type SharedOfflineSigningStore = Arc<RwLock<OfflineSigningStore>>;
+
+impl OfflineSigningStore {
+    pub async fn open(db_path: &Path) -> Result<Self, String> {
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url)
+            .await
+            .map_err(|e| format!("Failed to open offline signing database: {e}"))?;
+
+        let store = Self {
+            pool,
+            client: reqwest::Client::new(),
+        };
+        store
+            .initialize()
+            .await
+            .map_err(|e| format!("Failed to initialize offline signing database: {e}"))?;
+        Ok(store)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS offline_signing_requests (
+                id TEXT PRIMARY KEY,
+                wallet_address TEXT NOT NULL,
+                description TEXT,
+                unsigned_transaction TEXT NOT NULL,
+                message_hash TEXT NOT NULL,
+                required_signers TEXT NOT NULL,
+                recent_blockhash TEXT NOT NULL,
+                durable_nonce INTEGER NOT NULL,
+                signed_transaction TEXT,
+                rpc_url TEXT,
+                status TEXT NOT NULL,
+                tx_signature TEXT,
+                error TEXT,
+                bundle_path TEXT,
+                created_at TEXT NOT NULL,
+                signed_at TEXT,
+                submitted_at TEXT
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_offline_signing_status
+                ON offline_signing_requests(status, created_at)
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn save(&self, item: &OfflineSigningRequest) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO offline_signing_requests (
+                id, wallet_address, description, unsigned_transaction, message_hash,
+                required_signers, recent_blockhash, durable_nonce, signed_transaction, rpc_url,
+                status, tx_signature, error, bundle_path, created_at, signed_at, submitted_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+            "#,
+        )
+        .bind(&item.id)
+        .bind(&item.wallet_address)
+        .bind(&item.description)
+        .bind(&item.unsigned_transaction)
+        .bind(&item.message_hash)
+        .bind(item.required_signers.join(","))
+        .bind(&item.recent_blockhash)
+        .bind(item.durable_nonce)
+        .bind(&item.signed_transaction)
+        .bind(&item.rpc_url)
+        .bind(item.status.as_str())
+        .bind(&item.tx_signature)
+        .bind(&item.error)
+        .bind(&item.bundle_path)
+        .bind(item.created_at.to_rfc3339())
+        .bind(item.signed_at.map(|dt| dt.to_rfc3339()))
+        .bind(item.submitted_at.map(|dt| dt.to_rfc3339()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save offline signing request: {e}"))?;
+        Ok(())
+    }
+
+    fn from_row(row: &sqlx::sqlite::SqliteRow) -> Result<OfflineSigningRequest, String> {
+        let parse_time = |value: String| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| e.to_string())
+        };
+        let get = |e: sqlx::Error| e.to_string();
+        let optional_time = |column: &str| -> Result<Option<DateTime<Utc>>, String> {
+            row.try_get::<Option<String>, _>(column)
+                .map_err(get)?
+                .map(parse_time)
+                .transpose()
+        };
+
+        Ok(OfflineSigningRequest {
+            id: row.try_get("id").map_err(get)?,
+            wallet_address: row.try_get("wallet_address").map_err(get)?,
+            description: row.try_get("description").map_err(get)?,
+            unsigned_transaction: row.try_get("unsigned_transaction").map_err(get)?,
+            message_hash: row.try_get("message_hash").map_err(get)?,
+            required_signers: row
+                .try_get::<String, _>("required_signers")
+                .map_err(get)?
+                .split(',')
+                .filter(|signer| !signer.is_empty())
+                .map(str::to_string)
+                .collect(),
+            recent_blockhash: row.try_get("recent_blockhash").map_err(get)?,
+            durable_nonce: row.try_get("durable_nonce").map_err(get)?,
+            signed_transaction: row.try_get("signed_transaction").map_err(get)?,
+            rpc_url: row.try_get("rpc_url").map_err(get)?,
+            status: OfflineSigningStatus::from_str(
+                &row.try_get::<String, _>("status").map_err(get)?,
+            )?,
+            tx_signature: row.try_get("tx_signature").map_err(get)?,
+            error: row.try_get("error").map_err(get)?,
+            bundle_path: row.try_get("bundle_path").map_err(get)?,
+            created_at: parse_time(row.try_get("created_at").map_err(get)?)?,
+            signed_at: optional_time("signed_at")?,
+            submitted_at: optional_time("submitted_at")?,
+        })
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Option<OfflineSigningRequest>, String> {
+        let row = sqlx::query("SELECT * FROM offline_signing_requests WHERE id = ?1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        row.map(|row| Self::from_row(&row)).transpose()
+    }
+
+    pub async fn list(
+        &self,
+        status: Option<OfflineSigningStatus>,
+    ) -> Result<Vec<OfflineSigningRequest>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM offline_signing_requests
+            WHERE ?1 IS NULL OR status = ?1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(status.map(|status| status.as_str()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        rows.iter().map(Self::from_row).collect()
+    }
+
+    pub async fn find_awaiting_by_hash(
+        &self,
+        message_hash: &str,
+    ) -> Result<Option<OfflineSigningRequest>, String> {
+        let row = sqlx::query(
+            "SELECT * FROM offline_signing_requests WHERE message_hash = ?1 AND status = ?2",
+        )
+        .bind(message_hash)
+        .bind(OfflineSigningStatus::AwaitingSignature.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        row.map(|row| Self::from_row(&row)).transpose()
+    }
+
+    async fn rpc(&self, rpc_url: &str, method: &str, params: Value) -> Result<Value, String> {
+        let payload = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let data: Value = self
+            .client
+            .post(rpc_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("RPC request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Some(error) = data.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+        Ok(data["result"].clone())
+    }
+
+    pub async fn blockhash_is_valid(&self, blockhash: &str, rpc_url: &str) -> Result<bool, String> {
+        let result = self
+            .rpc(
+                rpc_url,
+                "isBlockhashValid",
+                json!([blockhash, { "commitment": "processed" }]),
+            )
+            .await?;
+        result["value"]
+            .as_bool()
+            .ok_or_else(|| "Invalid isBlockhashValid response".to_string())
+    }
+}
+
+fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Failed to resolve app data directory".to_string())
+}
+
+fn config_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(app_data_dir(app)?
+        .join("settings")
+        .join("offline_signing.json"))
+}
+
+pub fn load_offline_signing_config(app: &AppHandle) -> OfflineSigningConfig {
+    config_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn watch_folder(app: &AppHandle) -> Result<PathBuf, String> {
+    match load_offline_signing_config(app).watch_folder {
+        Some(folder) if !folder.trim().is_empty() => Ok(PathBuf::from(folder)),
+        _ => Ok(app_data_dir(app)?.join("offline_signing")),
+    }
+}
+
+fn publish_update(app: &AppHandle, item: &OfflineSigningRequest, message: String) {
+    let update = OfflineSigningUpdate {
+        id: item.id.clone(),
+        status: item.status,
+        message,
+    };
+    if let Err(err) = app.emit_all(OFFLINE_SIGNING_EVENT, &update) {
+        tracing::warn!(error = %err, "failed to emit offline signing update");
+    }
+}
+
+/// Verifies a signed return and moves its request to `Signed`.
+pub async fn import_signed_payload(
+    app: &AppHandle,
+    store: &OfflineSigningStore,
+    payload: &str,
+) -> Result<OfflineSigningRequest, String> {
+    let signed = parse_signed_payload(payload)?;
+    let mut item = match &signed.id {
+        Some(id) => store
+            .get(id)
+            .await?
+            .ok_or_else(|| format!("Offline signing request {} not found", id))?,
+        None => {
+            let hash = message_hash(&decode_transaction(&signed.transaction)?);
+            store
+                .find_awaiting_by_hash(&hash)
+                .await?
+                .ok_or_else(|| "No pending request matches this transaction".to_string())?
+        }
+    };
+    if item.status != OfflineSigningStatus::AwaitingSignature {
+        return Err(format!(
+            "Request {} is {} and cannot take a signature",
+            item.id,
+            item.status.as_str()
+        ));
+    }
+
+    let signature = verify_signed_transaction(&item.message_hash, &signed.transaction)?;
+    item.signed_transaction = Some(signed.transaction.trim().to_string());
+    item.tx_signature = Some(signature);
+    item.status = OfflineSigningStatus::Signed;
+    item.signed_at = Some(Utc::now());
+    item.error = None;
+    store.save(&item).await?;
+
+    publish_update(
+        app,
+        &item,
+        "Signed transaction verified and ready to submit".to_string(),
+    );
+    Ok(item)
+}
+
+/// Picks up signed files dropped in the inbox, then expires pending requests
+/// whose blockhash has lapsed.
+pub async fn run_offline_signing_tick(
+    app: &AppHandle,
+    store: &SharedOfflineSigningStore,
+) -> Result<(), String> {
+    let inbox = watch_folder(app)?.join("inbox");
+    if let Ok(entries) = std::fs::read_dir(&inbox) {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if !path.is_file() {
+                continue;
+            }
+            let content = match std::fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(err) => {
+                    tracing::warn!(error = %err, path = %path.display(), "unreadable bundle");
+                    continue;
+                }
+            };
+            let result = import_signed_payload(app, &*store.read().await, &content).await;
+            let target = match &result {
+                Ok(_) => inbox.join("processed"),
+                Err(err) => {
+                    tracing::warn!(error = %err, path = %path.display(), "rejected signed bundle");
+                    inbox.join("rejected")
+                }
+            };
+            if let (Some(name), Ok(())) = (path.file_name(), std::fs::create_dir_all(&target)) {
+                if let Err(err) = std::fs::rename(&path, target.join(name)) {
+                    tracing::warn!(error = %err, "failed to move signed bundle out of the inbox");
+                }
+            }
+        }
+    }
+
+    let store = store.read().await;
+    for mut item in store.list(None).await? {
+        if !item.status.is_pending() || item.durable_nonce {
+            continue;
+        }
+        let rpc_url = item.rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT);
+        if let Ok(false) = store
+            .blockhash_is_valid(&item.recent_blockhash, rpc_url)
+            .await
+        {
+            item.status = OfflineSigningStatus::Expired;
+            item.error = Some("Blockhash expired before submission".to_string());
+            store.save(&item).await?;
+            publish_update(
+                app,
+                &item,
+                "Blockhash expired; rebuild and re-export the transaction".to_string(),
+            );
+        }
+    }
+    Ok(())
+}
+
+pub fn register_offline_signing(app: &tauri::App) {
+    let handle = app.handle();
+    let dir = match app_data_dir(&handle) {
+        Ok(dir) => dir,
+        Err(err) => {
+            tracing::warn!(error = %err, "offline signing disabled");
+            return;
+        }
+    };
+    if let Err(err) = std::fs::create_dir_all(&dir) {
+        tracing::warn!(error = %err, "failed to create app data directory");
+    }
+
+    let store = match tauri::async_runtime::block_on(OfflineSigningStore::open(
+        &dir.join(OFFLINE_SIGNING_DB_FILE),
+    )) {
+        Ok(store) => Arc::new(RwLock::new(store)),
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to open offline signing store");
+            return;
+        }
+    };
+    app.manage::<SharedOfflineSigningStore>(store.clone());
+
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(WATCH_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = run_offline_signing_tick(&handle, &store).await {
+                tracing::warn!(error = %err, "offline signing tick failed");
+            }
+        }
+    });
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn export_offline_signing_bundle(
+    request: ExportOfflineSigningRequest,
+    app_handle: AppHandle,
+    store: State<'_, SharedOfflineSigningStore>,
+) -> Result<OfflineSigningExport, String> {
+    let info = inspect_unsigned_transaction(&request.transaction)?;
+    if !info.required_signers.contains(&request.wallet_address) {
+        return Err("The wallet is not a signer of this transaction".to_string());
+    }
+
+    // Signing on an airgapped device can outlast the blockhash, so move the
+    // transaction onto a durable nonce when the wallet has one available
+    let id = format!("offline_{}", Uuid::new_v4());
+    let mut transaction = request.transaction.trim().to_string();
+    if let Some(nonces) = app_handle.try_state::<SharedNonceAccountManager>() {
+        let rpc_url = request.rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT);
+        match prepare_for_path(
+            &*nonces.read().await,
+            &request.wallet_address,
+            &transaction,
+            TransactionPath::OfflineSigning,
+            &id,
+            rpc_url,
+        )
+        .await
+        {
+            Ok(prepared) => transaction = prepared.transaction,
+            Err(err) => tracing::warn!(error = %err, "exporting without a durable nonce"),
+        }
+    }
+    let info = inspect_unsigned_transaction(&transaction)?;
+
+    let now = Utc::now();
+    let mut item = OfflineSigningRequest {
+        id,
+        wallet_address: request.wallet_address,
+        description: request.description,
+        unsigned_transaction: transaction,
+        message_hash: info.message_hash,
+        required_signers: info.required_signers,
+        recent_blockhash: info.recent_blockhash,
+        durable_nonce: info.durable_nonce,
+        signed_transaction: None,
+        rpc_url: request.rpc_url,
+        status: OfflineSigningStatus::AwaitingSignature,
+        tx_signature: None,
+        error: None,
+        bundle_path: None,
+        created_at: now,
+        signed_at: None,
+        submitted_at: None,
+    };
+    let bundle = OfflineSigningBundle {
+        version: BUNDLE_VERSION,
+        id: item.id.clone(),
+        wallet_address: item.wallet_address.clone(),
+        description: item.description.clone(),
+        message_hash: item.message_hash.clone(),
+        required_signers: item.required_signers.clone(),
+        recent_blockhash: item.recent_blockhash.clone(),
+        durable_nonce: item.durable_nonce,
+        transaction: item.unsigned_transaction.clone(),
+        created_at: now,
+    };
+
+    let outbox = watch_folder(&app_handle)?.join("outbox");
+    std::fs::create_dir_all(&outbox)
+        .map_err(|e| format!("Failed to create offline signing outbox: {e}"))?;
+    std::fs::create_dir_all(outbox.with_file_name("inbox"))
+        .map_err(|e| format!("Failed to create offline signing inbox: {e}"))?;
+    let path = outbox.join(format!("{}.unsigned.json", item.id));
+    let content = serde_json::to_string_pretty(&bundle)
+        .map_err(|e| format!("Failed to serialize bundle: {e}"))?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write bundle: {e}"))?;
+    item.bundle_path = Some(path.display().to_string());
+
+    store.read().await.save(&item).await?;
+    let qr_svg = qr_svg(&format!("{}{}:{}", QR_PREFIX, item.id, bundle.transaction));
+    Ok(OfflineSigningExport {
+        request: item,
+        bundle,
+        qr_svg,
+    })
+}
+
+#[tauri::command]
+pub async fn import_offline_signed_transaction(
+    payload: String,
+    app_handle: AppHandle,
+    store: State<'_, SharedOfflineSigningStore>,
+) -> Result<OfflineSigningRequest, String> {
+    let store = store.read().await;
+    import_signed_payload(&app_handle, &store, &payload).await
+}
+
+/// Submits a verified transaction, re-checking the blockhash first so a stale
+/// bundle is marked expired instead of failing at the RPC.
+#[tauri::command]
+pub async fn submit_offline_signed_transaction(
+    id: String,
+    app_handle: AppHandle,
+    store: State<'_, SharedOfflineSigningStore>,
+    scheduler: State<'_, SharedTransactionScheduler>,
+) -> Result<OfflineSigningRequest, String> {
+    let store = store.read().await;
+    let mut item = store
+        .get(&id)
+        .await?
+        .ok_or_else(|| "Offline signing request not found".to_string())?;
+    if item.status != OfflineSigningStatus::Signed {
+        return Err("Only verified signed transactions can be submitted".to_string());
+    }
+    let transaction = item
+        .signed_transaction
+        .clone()
+        .ok_or_else(|| "Signed transaction payload is missing".to_string())?;
+    let rpc_url = item
+        .rpc_url
+        .clone()
+        .unwrap_or_else(|| DEFAULT_RPC_ENDPOINT.to_string());
+
+    if !item.durable_nonce
+        && !store
+            .blockhash_is_valid(&item.recent_blockhash, &rpc_url)
+            .await?
+    {
+        item.status = OfflineSigningStatus::Expired;
+        item.error = Some("Blockhash expired before submission".to_string());
+        store.save(&item).await?;
+        publish_update(&app_handle, &item, "Blockhash expired".to_string());
+        return Err("Blockhash expired; rebuild and re-export the transaction".to_string());
+    }
+
+    let result = scheduler
+        .read()
+        .await
+        .send_prepared_transaction(&transaction, &rpc_url)
+        .await;
+    match &result {
+        Ok(signature) => {
+            item.status = OfflineSigningStatus::Submitted;
+            item.tx_signature = Some(signature.clone());
+            item.submitted_at = Some(Utc::now());
+            item.error = None;
+        }
+        Err(err) => {
+            item.status = OfflineSigningStatus::Failed;
+            item.error = Some(err.clone());
+        }
+    }
+    store.save(&item).await?;
+    publish_update(
+        &app_handle,
+        &item,
+        match &result {
+            Ok(signature) => format!("Submitted ({})", signature),
+            Err(err) => format!("Submission failed: {}", err),
+        },
+    );
+    result.map(|_| item)
+}
+
+#[tauri::command]
+pub async fn list_offline_signing_requests(
+    status: Option<OfflineSigningStatus>,
+    store: State<'_, SharedOfflineSigningStore>,
+) -> Result<Vec<OfflineSigningRequest>, String> {
+    let store = store.read().await;
+    store.list(status).await
+}
+
+#[tauri::command]
+pub async fn cancel_offline_signing_request(
+    id: String,
+    app_handle: AppHandle,
+    store: State<'_, SharedOfflineSigningStore>,
+) -> Result<OfflineSigningRequest, String> {
+    let store = store.read().await;
+    let mut item = store
+        .get(&id)
+        .await?
+        .ok_or_else(|| "Offline signing request not found".to_string())?;
+    if !item.status.is_pending() {
+        return Err("Only pending requests can be cancelled".to_string());
+    }
+    item.status = OfflineSigningStatus::Cancelled;
+    store.save(&item).await?;
+    release_nonce_reservation(&app_handle, &item.id).await;
+    publish_update(&app_handle, &item, "Cancelled".to_string());
+    Ok(item)
+}
+
+#[tauri::command]
+pub async fn get_offline_signing_config(
+    app_handle: AppHandle,
+) -> Result<OfflineSigningConfig, String> {
+    Ok(load_offline_signing_config(&app_handle))
+}
+
+#[tauri::command]
+pub async fn update_offline_signing_config(
+    config: OfflineSigningConfig,
+    app_handle: AppHandle,
+) -> Result<OfflineSigningConfig, String> {
+    if let Some(folder) = config
+        .watch_folder
+        .as_deref()
+        .filter(|f| !f.trim().is_empty())
+    {
+        let folder = PathBuf::from(folder);
+        for sub in ["outbox", "inbox"] {
+            std::fs::create_dir_all(folder.join(sub))
+                .map_err(|e| format!("Watch folder is not writable: {e}"))?;
+        }
+    }
+    let path = config_path(&app_handle)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create settings directory: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(&config)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write settings: {}", e))?;
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+        message::{Message, VersionedMessage},
+        signature::{Keypair, Signer},
+    };
+
+    fn unsigned(payer: &Pubkey, first_data: Vec<u8>) -> VersionedTransaction {
+        let instruction = Instruction::new_with_bytes(
+            system_program::id(),
+            &first_data,
+            vec![AccountMeta::new(*payer, true)],
+        );
+        let message = Message::new_with_blockhash(&[instruction], Some(payer), &Hash::new_unique());
+        VersionedTransaction {
+            signatures: vec![Signature::default()],
+            message: VersionedMessage::Legacy(message),
+        }
+    }
+
+    fn encode(transaction: &VersionedTransaction) -> String {
+        BASE64_ENGINE.encode(bincode::serialize(transaction).unwrap())
+    }
+
+    #[test]
+    fn inspects_signers_and_durable_nonce() {
+        let payer = Keypair::new();
+        let plain = unsigned(&payer.pubkey(), vec![2, 0, 0, 0]);
+        let info = inspect_unsigned_transaction(&encode(&plain)).unwrap();
+        assert_eq!(info.required_signers, vec![payer.pubkey().to_string()]);
+        assert_eq!(info.message_hash, message_hash(&plain));
+        assert!(!info.durable_nonce);
+
+        let nonce = unsigned(&payer.pubkey(), ADVANCE_NONCE_TAG.to_vec());
+        assert!(
+            inspect_unsigned_transaction(&encode(&nonce))
+                .unwrap()
+                .durable_nonce
+        );
+        assert!(inspect_unsigned_transaction("not base64!").is_err());
+    }
+
+    #[test]
+    fn verifies_signatures_against_the_exported_message() {
+        let payer = Keypair::new();
+        let mut transaction = unsigned(&payer.pubkey(), vec![2, 0, 0, 0]);
+        let hash = message_hash(&transaction);
+        assert!(verify_signed_transaction(&hash, &encode(&transaction))
+            .unwrap_err()
+            .contains("has not signed"));
+
+        let signature = payer.sign_message(&transaction.message.serialize());
+        transaction.signatures[0] = signature;
+        assert_eq!(
+            verify_signed_transaction(&hash, &encode(&transaction)).unwrap(),
+            signature.to_string()
+        );
+
+        // A signature from another key, or over another message, is rejected
+        transaction.signatures[0] = Keypair::new().sign_message(&transaction.message.serialize());
+        assert!(verify_signed_transaction(&hash, &encode(&transaction)).is_err());
+        let other = unsigned(&payer.pubkey(), vec![2, 0, 0, 0]);
+        assert!(verify_signed_transaction(&message_hash(&other), &encode(&transaction)).is_err());
+    }
+
+    #[test]
+    fn parses_signed_payload_formats() {
+        let bundle = parse_signed_payload(r#"{"id":"offline_1","transaction":"AAA="}"#).unwrap();
+        assert_eq!(bundle.id.as_deref(), Some("offline_1"));
+
+        let qr = parse_signed_payload("eclipse-offline:v1:offline_2:BBB=").unwrap();
+        assert_eq!(
+            (qr.id.as_deref(), qr.transaction.as_str()),
+            (Some("offline_2"), "BBB=")
+        );
+
+        let raw = parse_signed_payload("  CCC=\n").unwrap();
+        assert_eq!((raw.id, raw.transaction.as_str()), (None, "CCC="));
+        assert!(parse_signed_payload("eclipse-offline:v1:missing").is_err());
+    }
+}
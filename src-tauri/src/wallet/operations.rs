@@ -1,15 +1,25 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::sync::Mutex;
 use tauri::State;
 use uuid::Uuid;
 
+use crate::config::token_registry::apply_token_overrides;
+use crate::idempotency::run_idempotent;
 use crate::security::keystore::{Keystore, KeystoreError};
+use crate::wallet::sns::SnsResolver;
 
 const KEYSTORE_TOKEN_CACHE_KEY: &str = "wallet.token_cache";
 const KEYSTORE_ADDRESS_BOOK_KEY: &str = "wallet.address_book";
 const KEYSTORE_SWAP_HISTORY_KEY: &str = "wallet.swap_history";
+const DEFAULT_RPC_ENDPOINT: &str = "https://api.mainnet-beta.solana.com";
+const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+const TOKEN_PROGRAM_IDS: [&str; 2] = [
+    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PfnBqCXEpPxuEb",
+];
 
 // Token Balance Types
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +41,10 @@ pub struct TokenBalancesCache {
     pub balances: HashMap<String, Vec<TokenBalance>>,
     pub last_updated: DateTime<Utc>,
     pub ttl_seconds: u64,
+    /// Whether the balances are demo fixtures. `None` marks caches written before
+    /// balances were read from chain, which only ever held placeholder values.
+    #[serde(default)]
+    pub demo: Option<bool>,
 }
 
 impl Default for TokenBalancesCache {
@@ -39,10 +53,21 @@ impl Default for TokenBalancesCache {
             balances: HashMap::new(),
             last_updated: Utc::now(),
             ttl_seconds: 60,
+            demo: None,
         }
     }
 }
 
+impl TokenBalancesCache {
+    /// Balances cached for the current mode; demo and real data never mix.
+    fn current(&self, address: &str) -> Option<&Vec<TokenBalance>> {
+        if self.demo != Some(crate::demo::is_demo_mode()) {
+            return None;
+        }
+        self.balances.get(address)
+    }
+}
+
 // Transaction Types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -76,6 +101,9 @@ pub struct AddressBookContact {
     pub last_used: Option<DateTime<Utc>>,
     pub transaction_count: u64,
     pub tags: Vec<String>,
+    /// The `.sol` domain the contact was added with, if any.
+    #[serde(default)]
+    pub domain: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -231,6 +259,24 @@ impl WalletOperationsManager {
         })
     }
 
+    pub fn cached_token_balances(&self, address: &str) -> Vec<TokenBalance> {
+        self.token_cache
+            .lock()
+            .map(|cache| cache.current(address).cloned().unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Balances for `address` and when they were fetched, or `None` when none
+    /// have been loaded in the current mode.
+    pub fn token_balances_with_age(
+        &self,
+        address: &str,
+    ) -> Option<(Vec<TokenBalance>, DateTime<Utc>)> {
+        let cache = self.token_cache.lock().ok()?;
+        let balances = cache.current(address)?.clone();
+        Some((balances, cache.last_updated))
+    }
+
     pub fn persist_token_cache(&self, keystore: &Keystore) -> Result<(), KeystoreError> {
         let guard = self.token_cache.lock().map_err(|_| KeystoreError::LockError)?;
         let data = serde_json::to_vec(&*guard).map_err(|_| KeystoreError::SerializationError)?;
@@ -250,58 +296,180 @@ impl WalletOperationsManager {
     }
 }
 
+/// The demo wallet's holdings, shown for any address while demo mode is on.
+fn demo_token_balances(now: DateTime<Utc>) -> Vec<TokenBalance> {
+    crate::demo::demo_portfolio()
+        .into_iter()
+        .filter_map(|position| {
+            let token = crate::demo::find_demo_token(&position.symbol)?;
+            Some(TokenBalance {
+                mint: token.address.to_string(),
+                symbol: token.symbol.to_string(),
+                name: token.name.to_string(),
+                balance: position.amount,
+                decimals: token.decimals,
+                usd_value: position.value,
+                change_24h: crate::demo::demo_change_24h(token),
+                logo_uri: None,
+                last_updated: now,
+            })
+        })
+        .collect()
+}
+
+async fn rpc_call(
+    client: &reqwest::Client,
+    rpc_url: &str,
+    method: &str,
+    params: Value,
+) -> Result<Value, String> {
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    });
+
+    let data: Value = client
+        .post(rpc_url)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| format!("RPC request failed: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+    if let Some(error) = data.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+
+    Ok(data["result"].clone())
+}
+
+fn add_balance(balances: &mut Vec<TokenBalance>, mint: &str, amount: f64, decimals: u8) {
+    if let Some(existing) = balances.iter_mut().find(|b| b.mint == mint) {
+        existing.balance += amount;
+        return;
+    }
+
+    let mut symbol = if mint == SOL_MINT {
+        "SOL".to_string()
+    } else {
+        mint.chars().take(4).collect()
+    };
+    let mut name = if mint == SOL_MINT { "Solana".to_string() } else { mint.to_string() };
+    let mut decimals = decimals;
+    apply_token_overrides(mint, &mut symbol, Some(&mut name), Some(&mut decimals), None);
+    balances.push(TokenBalance {
+        mint: mint.to_string(),
+        symbol,
+        name,
+        balance: amount,
+        decimals,
+        usd_value: 0.0,
+        change_24h: 0.0,
+        logo_uri: None,
+        last_updated: Utc::now(),
+    });
+}
+
+/// Reads SOL and token account balances from chain. USD values come from the
+/// price engine and stay at zero for tokens it has no price for.
+async fn fetch_chain_balances(address: &str, rpc_url: &str) -> Result<Vec<TokenBalance>, String> {
+    let client = reqwest::Client::new();
+    let mut balances = Vec::new();
+
+    let lamports = rpc_call(&client, rpc_url, "getBalance", json!([address])).await?["value"]
+        .as_u64()
+        .ok_or("Invalid balance response")?;
+    add_balance(&mut balances, SOL_MINT, lamports as f64 / 1_000_000_000.0, 9);
+
+    for program_id in TOKEN_PROGRAM_IDS {
+        let accounts = rpc_call(
+            &client,
+            rpc_url,
+            "getTokenAccountsByOwner",
+            json!([address, { "programId": program_id }, { "encoding": "jsonParsed" }]),
+        )
+        .await?;
+        for entry in accounts["value"].as_array().into_iter().flatten() {
+            let info = &entry["account"]["data"]["parsed"]["info"];
+            let Some(mint) = info["mint"].as_str() else {
+                continue;
+            };
+            let amount = info["tokenAmount"]["uiAmountString"]
+                .as_str()
+                .and_then(|value| value.parse::<f64>().ok())
+                .unwrap_or(0.0);
+            if amount > 0.0 {
+                let decimals = info["tokenAmount"]["decimals"].as_u64().unwrap_or(0) as u8;
+                add_balance(&mut balances, mint, amount, decimals);
+            }
+        }
+    }
+
+    let engine = crate::core::price_engine::get_price_engine();
+    for balance in &mut balances {
+        if let Some(price) = engine.get_cached_price(&balance.symbol) {
+            balance.usd_value = balance.balance * price.price;
+            balance.change_24h = price.change_24h;
+        }
+    }
+    Ok(balances)
+}
+
 // Tauri Commands
 #[tauri::command]
 pub async fn wallet_get_token_balances(
     address: String,
     force_refresh: bool,
+    rpc_url: Option<String>,
     operations: State<'_, WalletOperationsManager>,
     keystore: State<'_, Keystore>,
 ) -> Result<Vec<TokenBalance>, String> {
-    let mut cache = operations.token_cache.lock().map_err(|e| e.to_string())?;
-    
     let now = Utc::now();
-    let should_refresh = force_refresh 
-        || !cache.balances.contains_key(&address)
-        || (now.timestamp() - cache.last_updated.timestamp()) > cache.ttl_seconds as i64;
-
-    if should_refresh {
-        // In a real implementation, this would fetch from blockchain
-        // For now, we'll return mock data
-        let mock_balances = vec![
-            TokenBalance {
-                mint: "So11111111111111111111111111111111111111112".to_string(),
-                symbol: "SOL".to_string(),
-                name: "Solana".to_string(),
-                balance: 1.5,
-                decimals: 9,
-                usd_value: 150.0,
-                change_24h: 2.5,
-                logo_uri: Some("https://raw.githubusercontent.com/solana-labs/token-list/main/assets/mainnet/So11111111111111111111111111111111111111112/logo.png".to_string()),
-                last_updated: now,
-            },
-            TokenBalance {
-                mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
-                symbol: "USDC".to_string(),
-                name: "USD Coin".to_string(),
-                balance: 100.0,
-                decimals: 6,
-                usd_value: 100.0,
-                change_24h: 0.0,
-                logo_uri: Some("https://raw.githubusercontent.com/solana-labs/token-list/main/assets/mainnet/EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v/logo.png".to_string()),
-                last_updated: now,
-            },
-        ];
+    {
+        let cache = operations.token_cache.lock().map_err(|e| e.to_string())?;
+        let fresh = (now.timestamp() - cache.last_updated.timestamp()) <= cache.ttl_seconds as i64;
+        if let Some(balances) = cache.current(&address).filter(|_| fresh && !force_refresh) {
+            return Ok(with_registry_metadata(balances.clone()));
+        }
+    }
 
-        cache.balances.insert(address.clone(), mock_balances.clone());
-        cache.last_updated = now;
-        
-        operations.persist_token_cache(&keystore).map_err(|e| e.to_string())?;
-        
-        Ok(mock_balances)
+    let demo = crate::demo::is_demo_mode();
+    let balances = if demo {
+        demo_token_balances(now)
     } else {
-        Ok(cache.balances.get(&address).cloned().unwrap_or_default())
+        fetch_chain_balances(&address, rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT)).await?
+    };
+
+    {
+        let mut cache = operations.token_cache.lock().map_err(|e| e.to_string())?;
+        if cache.demo != Some(demo) {
+            cache.balances.clear();
+            cache.demo = Some(demo);
+        }
+        cache.balances.insert(address, balances.clone());
+        cache.last_updated = now;
+    }
+    operations.persist_token_cache(&keystore).map_err(|e| e.to_string())?;
+
+    Ok(with_registry_metadata(balances))
+}
+
+// Applied on read so registry edits show up without a balance refresh
+fn with_registry_metadata(mut balances: Vec<TokenBalance>) -> Vec<TokenBalance> {
+    for balance in &mut balances {
+        apply_token_overrides(
+            &balance.mint,
+            &mut balance.symbol,
+            Some(&mut balance.name),
+            Some(&mut balance.decimals),
+            Some(&mut balance.logo_uri),
+        );
     }
+    balances
 }
 
 #[tauri::command]
@@ -326,10 +494,22 @@ pub async fn wallet_estimate_fee(
 pub async fn wallet_send_transaction(
     input: SendTransactionInput,
     wallet_address: String,
+    resolver: State<'_, SnsResolver>,
+    idempotency_key: Option<String>,
 ) -> Result<String, String> {
-    // Mock implementation - in production, this would sign and send transaction
-    // Returns transaction signature
-    Ok(format!("mock_tx_signature_{}", Uuid::new_v4()))
+    let args = (&input, &wallet_address);
+    run_idempotent("wallet_send_transaction", idempotency_key.as_deref(), &args, || async {
+        let mut input = input.clone();
+        input.recipient = resolver
+            .resolve_input(&input.recipient)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Mock implementation - in production, this would sign and send transaction
+        // Returns transaction signature
+        Ok(format!("mock_tx_signature_{}", Uuid::new_v4()))
+    })
+    .await
 }
 
 #[tauri::command]
@@ -351,7 +531,9 @@ pub async fn wallet_generate_solana_pay_qr(
     label: Option<String>,
     message: Option<String>,
     memo: Option<String>,
+    resolver: State<'_, SnsResolver>,
 ) -> Result<SolanaPayQR, String> {
+    let recipient = resolver.resolve_input(&recipient).await.map_err(|e| e.to_string())?;
     let mut url = format!("solana:{}", recipient);
     let mut params = vec![];
     
@@ -398,11 +580,15 @@ pub async fn address_book_add_contact(
     request: AddContactRequest,
     operations: State<'_, WalletOperationsManager>,
     keystore: State<'_, Keystore>,
+    resolver: State<'_, SnsResolver>,
 ) -> Result<AddressBookContact, String> {
+    let address = resolver.resolve_input(&request.address).await.map_err(|e| e.to_string())?;
+    let domain = (address != request.address.trim()).then(|| request.address.trim().to_lowercase());
+
     let mut book = operations.address_book.lock().map_err(|e| e.to_string())?;
     
     // Check if address already exists
-    if book.contacts.values().any(|c| c.address == request.address) {
+    if book.contacts.values().any(|c| c.address == address) {
         return Err("Contact with this address already exists".to_string());
     }
     
@@ -411,7 +597,7 @@ pub async fn address_book_add_contact(
     
     let contact = AddressBookContact {
         id: contact_id.clone(),
-        address: request.address,
+        address,
         label: request.label,
         nickname: request.nickname,
         notes: request.notes,
@@ -420,6 +606,7 @@ pub async fn address_book_add_contact(
         last_used: None,
         transaction_count: 0,
         tags: request.tags,
+        domain,
     };
     
     book.contacts.insert(contact_id, contact.clone());
@@ -623,6 +623,26 @@ impl PerformanceDatabase {
         Ok(())
     }
 
+    pub async fn get_trades_between(
+        &self,
+        wallet_address: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Trade>, sqlx::Error> {
+        sqlx::query_as::<_, Trade>(
+            r#"
+            SELECT * FROM trades
+            WHERE wallet_address = ?1 AND timestamp >= ?2 AND timestamp <= ?3
+            ORDER BY timestamp ASC
+            "#,
+        )
+        .bind(wallet_address)
+        .bind(start.to_rfc3339())
+        .bind(end.to_rfc3339())
+        .fetch_all(&self.pool)
+        .await
+    }
+
     pub async fn get_score_history(&self, wallet_address: &str, limit: i64) -> Result<Vec<PerformanceScore>, sqlx::Error> {
         sqlx::query_as::<_, PerformanceScore>(
             r#"
@@ -0,0 +1,604 @@
+use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
+use base64::Engine;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use solana_sdk::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+    transaction::Transaction,
+};
+use sqlx::{FromRow, Pool, Sqlite, SqlitePool};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::wallet::multi_wallet::MultiWalletManager;
+
+const RENT_RECLAIM_DB_FILE: &str = "rent_reclaim.db";
+const DEFAULT_RPC_ENDPOINT: &str = "https://api.mainnet-beta.solana.com";
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PfnBqCXEpPxuEb";
+/// `CloseAccount` in both token programs' instruction enums.
+const CLOSE_ACCOUNT_INSTRUCTION: u8 = 9;
+/// Keeps each transaction comfortably under the packet size limit.
+const MAX_CLOSES_PER_TRANSACTION: usize = 20;
+/// Accounts that moved tokens within this window may be about to receive more.
+const RECENT_ACTIVITY_WINDOW_SECS: i64 = 7 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmptyTokenAccount {
+    pub address: String,
+    pub wallet_address: String,
+    pub mint: String,
+    pub program_id: String,
+    pub rent_lamports: u64,
+    /// Block time of the most recent transaction touching the account.
+    pub last_activity: Option<i64>,
+    pub recently_active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmptyAccountScan {
+    pub wallets: Vec<String>,
+    pub accounts: Vec<EmptyTokenAccount>,
+    /// Rent held by accounts that are safe to close now.
+    pub reclaimable_lamports: u64,
+    pub reclaimable_sol: f64,
+    pub recently_active_count: usize,
+}
+
+impl EmptyAccountScan {
+    pub fn from_accounts(wallets: Vec<String>, accounts: Vec<EmptyTokenAccount>) -> Self {
+        let reclaimable_lamports = accounts
+            .iter()
+            .filter(|account| !account.recently_active)
+            .map(|account| account.rent_lamports)
+            .sum();
+        Self {
+            wallets,
+            recently_active_count: accounts.iter().filter(|a| a.recently_active).count(),
+            accounts,
+            reclaimable_lamports,
+            reclaimable_sol: reclaimable_lamports as f64 / LAMPORTS_PER_SOL,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseAccountBatch {
+    pub wallet_address: String,
+    pub accounts: Vec<String>,
+    pub rent_lamports: u64,
+    /// Unsigned, base64-encoded transaction for the wallet to sign.
+    pub transaction: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct RentReclaimRecord {
+    pub id: String,
+    pub wallet_address: String,
+    pub signature: String,
+    pub account_count: i64,
+    pub rent_lamports: i64,
+    pub reclaimed_at: i64,
+}
+
+/// A token account can be closed when it holds nothing, is not frozen and
+/// the owner is allowed to close it.
+fn parse_empty_account(wallet: &str, entry: &Value, program_id: &str) -> Option<EmptyTokenAccount> {
+    let address = entry.get("pubkey")?.as_str()?;
+    let account = entry.get("account")?;
+    let info = account.get("data")?.get("parsed")?.get("info")?;
+
+    let amount = info.get("tokenAmount")?.get("amount")?.as_str()?;
+    if amount != "0" || info.get("state").and_then(|s| s.as_str()) == Some("frozen") {
+        return None;
+    }
+    if let Some(authority) = info.get("closeAuthority").and_then(|a| a.as_str()) {
+        if authority != wallet {
+            return None;
+        }
+    }
+
+    Some(EmptyTokenAccount {
+        address: address.to_string(),
+        wallet_address: wallet.to_string(),
+        mint: info.get("mint")?.as_str()?.to_string(),
+        program_id: program_id.to_string(),
+        rent_lamports: account
+            .get("lamports")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0),
+        last_activity: None,
+        recently_active: false,
+    })
+}
+
+fn close_account_instruction(
+    account: &EmptyTokenAccount,
+    owner: &Pubkey,
+) -> Result<Instruction, String> {
+    let program_id =
+        Pubkey::from_str(&account.program_id).map_err(|e| format!("Invalid token program: {e}"))?;
+    let address =
+        Pubkey::from_str(&account.address).map_err(|e| format!("Invalid token account: {e}"))?;
+
+    Ok(Instruction::new_with_bytes(
+        program_id,
+        &[CLOSE_ACCOUNT_INSTRUCTION],
+        vec![
+            AccountMeta::new(address, false),
+            AccountMeta::new(*owner, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+    ))
+}
+
+/// Groups closable accounts into transactions that each fit the size limit.
+pub fn plan_close_batches(
+    accounts: &[EmptyTokenAccount],
+    allow_recent: bool,
+) -> Vec<Vec<&EmptyTokenAccount>> {
+    let closable: Vec<&EmptyTokenAccount> = accounts
+        .iter()
+        .filter(|account| allow_recent || !account.recently_active)
+        .collect();
+    closable
+        .chunks(MAX_CLOSES_PER_TRANSACTION)
+        .map(|chunk| chunk.to_vec())
+        .collect()
+}
+
+/// Newest transaction touching an account, as reported by the RPC.
+enum LastActivity {
+    Never,
+    At(i64),
+    /// A transaction exists but has no block time yet.
+    Unconfirmed,
+}
+
+pub struct RentReclaimer {
+    pool: Pool<Sqlite>,
+    client: reqwest::Client,
+}
+
+pub type SharedRentReclaimer = Arc<RwLock<RentReclaimer>>;
+
+impl RentReclaimer {
+    pub async fn new(app: &AppHandle) -> Result<Self, String> {
+        let mut db_path: PathBuf = app
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+
+        std::fs::create_dir_all(&db_path)
+            .map_err(|e| format!("Failed to create data directory: {e}"))?;
+        db_path.push(RENT_RECLAIM_DB_FILE);
+
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url)
+            .await
+            .map_err(|e| format!("Failed to open rent reclaim database: {e}"))?;
+
+        let reclaimer = Self {
+            pool,
+            client: reqwest::Client::new(),
+        };
+        reclaimer
+            .initialize()
+            .await
+            .map_err(|e| format!("Failed to initialize rent reclaim database: {e}"))?;
+        Ok(reclaimer)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS rent_reclaims (
+                id TEXT PRIMARY KEY,
+                wallet_address TEXT NOT NULL,
+                signature TEXT NOT NULL UNIQUE,
+                account_count INTEGER NOT NULL,
+                rent_lamports INTEGER NOT NULL,
+                reclaimed_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn rpc(&self, rpc_url: &str, method: &str, params: Value) -> Result<Value, String> {
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let data: Value = self
+            .client
+            .post(rpc_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("RPC request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Some(error) = data.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+
+        Ok(data["result"].clone())
+    }
+
+    async fn last_activity(&self, address: &str, rpc_url: &str) -> Result<LastActivity, String> {
+        let signatures = self
+            .rpc(
+                rpc_url,
+                "getSignaturesForAddress",
+                json!([address, { "limit": 1 }]),
+            )
+            .await?;
+        let Some(newest) = signatures.as_array().and_then(|entries| entries.first()) else {
+            return Ok(LastActivity::Never);
+        };
+        Ok(match newest.get("blockTime").and_then(|time| time.as_i64()) {
+            Some(time) => LastActivity::At(time),
+            None => LastActivity::Unconfirmed,
+        })
+    }
+
+    /// Lists the wallet's empty token accounts across both token programs.
+    pub async fn scan_wallet(
+        &self,
+        wallet: &str,
+        rpc_url: &str,
+    ) -> Result<Vec<EmptyTokenAccount>, String> {
+        let now = Utc::now().timestamp();
+        let mut accounts = Vec::new();
+
+        for program_id in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+            let result = self
+                .rpc(
+                    rpc_url,
+                    "getTokenAccountsByOwner",
+                    json!([wallet, { "programId": program_id }, { "encoding": "jsonParsed" }]),
+                )
+                .await?;
+            accounts.extend(
+                result
+                    .get("value")
+                    .and_then(|v| v.as_array())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|entry| parse_empty_account(wallet, entry, program_id)),
+            );
+        }
+
+        for account in accounts.iter_mut() {
+            // An account with no transactions has nothing in flight and counts as idle.
+            // A failed lookup or a transaction without a block time counts as recent,
+            // so the account is never closed by default.
+            match self.last_activity(&account.address, rpc_url).await {
+                Ok(LastActivity::Never) => account.recently_active = false,
+                Ok(LastActivity::At(time)) => {
+                    account.last_activity = Some(time);
+                    account.recently_active = now - time < RECENT_ACTIVITY_WINDOW_SECS;
+                }
+                Ok(LastActivity::Unconfirmed) => account.recently_active = true,
+                Err(err) => {
+                    tracing::warn!(
+                        account = %account.address,
+                        error = %err,
+                        "failed to check token account activity"
+                    );
+                    account.recently_active = true;
+                }
+            }
+        }
+
+        Ok(accounts)
+    }
+
+    /// Builds unsigned close-account transactions returning rent to the wallet.
+    /// Emptiness is re-checked on chain rather than trusted from an earlier scan.
+    pub async fn build_close_transactions(
+        &self,
+        wallet: &str,
+        only_accounts: Option<&[String]>,
+        allow_recent: bool,
+        rpc_url: &str,
+    ) -> Result<Vec<CloseAccountBatch>, String> {
+        let owner = Pubkey::from_str(wallet).map_err(|e| format!("Invalid wallet address: {e}"))?;
+        let mut accounts = self.scan_wallet(wallet, rpc_url).await?;
+        if let Some(only) = only_accounts {
+            if let Some(missing) = only
+                .iter()
+                .find(|address| !accounts.iter().any(|a| &a.address == *address))
+            {
+                return Err(format!(
+                    "{missing} is not an empty token account of this wallet"
+                ));
+            }
+            accounts.retain(|account| only.contains(&account.address));
+        }
+
+        let batches = plan_close_batches(&accounts, allow_recent);
+        if batches.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let blockhash = self
+            .rpc(rpc_url, "getLatestBlockhash", json!([]))
+            .await?
+            .get("value")
+            .and_then(|v| v.get("blockhash"))
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| "Invalid blockhash response".to_string())
+            .and_then(|s| Hash::from_str(s).map_err(|e| format!("Invalid blockhash: {e}")))?;
+
+        batches
+            .into_iter()
+            .map(|batch| {
+                let instructions = batch
+                    .iter()
+                    .map(|account| close_account_instruction(account, &owner))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let message = Message::new_with_blockhash(&instructions, Some(&owner), &blockhash);
+                let bytes = bincode::serialize(&Transaction::new_unsigned(message))
+                    .map_err(|e| format!("Failed to serialize transaction: {e}"))?;
+
+                Ok(CloseAccountBatch {
+                    wallet_address: wallet.to_string(),
+                    accounts: batch.iter().map(|a| a.address.clone()).collect(),
+                    rent_lamports: batch.iter().map(|a| a.rent_lamports).sum(),
+                    transaction: BASE64_ENGINE.encode(bytes),
+                })
+            })
+            .collect()
+    }
+
+    /// Sends a signed close batch and records the rent it returned.
+    pub async fn submit_close_transaction(
+        &self,
+        batch: &CloseAccountBatch,
+        signed_transaction: &str,
+        rpc_url: &str,
+    ) -> Result<RentReclaimRecord, String> {
+        let signature = self
+            .rpc(
+                rpc_url,
+                "sendTransaction",
+                json!([signed_transaction, { "encoding": "base64" }]),
+            )
+            .await?
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| "Invalid sendTransaction response".to_string())?;
+
+        let record = RentReclaimRecord {
+            id: Uuid::new_v4().to_string(),
+            wallet_address: batch.wallet_address.clone(),
+            signature,
+            account_count: batch.accounts.len() as i64,
+            rent_lamports: batch.rent_lamports as i64,
+            reclaimed_at: Utc::now().timestamp(),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO rent_reclaims (
+                id, wallet_address, signature, account_count, rent_lamports, reclaimed_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+        )
+        .bind(&record.id)
+        .bind(&record.wallet_address)
+        .bind(&record.signature)
+        .bind(record.account_count)
+        .bind(record.rent_lamports)
+        .bind(record.reclaimed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(record)
+    }
+
+    pub async fn get_history(
+        &self,
+        wallet_address: Option<&str>,
+    ) -> Result<Vec<RentReclaimRecord>, sqlx::Error> {
+        match wallet_address {
+            Some(wallet) => sqlx::query_as::<_, RentReclaimRecord>(
+                "SELECT * FROM rent_reclaims WHERE wallet_address = ?1 ORDER BY reclaimed_at DESC",
+            )
+            .bind(wallet)
+            .fetch_all(&self.pool)
+            .await,
+            None => {
+                sqlx::query_as::<_, RentReclaimRecord>(
+                    "SELECT * FROM rent_reclaims ORDER BY reclaimed_at DESC",
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn scan_empty_token_accounts(
+    reclaimer: State<'_, SharedRentReclaimer>,
+    wallets: State<'_, MultiWalletManager>,
+    wallet: Option<String>,
+    rpc_url: Option<String>,
+) -> Result<EmptyAccountScan, String> {
+    let addresses: Vec<String> = match wallet {
+        Some(address) => vec![address],
+        None => wallets
+            .list_wallets()
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|wallet| wallet.public_key)
+            .collect(),
+    };
+
+    let reclaimer = reclaimer.read().await;
+    let rpc_url = rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT);
+    let mut accounts = Vec::new();
+    for address in &addresses {
+        accounts.extend(reclaimer.scan_wallet(address, rpc_url).await?);
+    }
+
+    Ok(EmptyAccountScan::from_accounts(addresses, accounts))
+}
+
+#[tauri::command]
+pub async fn build_close_empty_accounts(
+    reclaimer: State<'_, SharedRentReclaimer>,
+    wallet: String,
+    accounts: Option<Vec<String>>,
+    allow_recent: Option<bool>,
+    rpc_url: Option<String>,
+) -> Result<Vec<CloseAccountBatch>, String> {
+    let reclaimer = reclaimer.read().await;
+    reclaimer
+        .build_close_transactions(
+            &wallet,
+            accounts.as_deref(),
+            allow_recent.unwrap_or(false),
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await
+}
+
+#[tauri::command]
+pub async fn submit_close_empty_accounts(
+    reclaimer: State<'_, SharedRentReclaimer>,
+    batch: CloseAccountBatch,
+    signed_transaction: String,
+    rpc_url: Option<String>,
+) -> Result<RentReclaimRecord, String> {
+    let reclaimer = reclaimer.read().await;
+    reclaimer
+        .submit_close_transaction(
+            &batch,
+            &signed_transaction,
+            rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+        )
+        .await
+}
+
+#[tauri::command]
+pub async fn get_rent_reclaim_history(
+    reclaimer: State<'_, SharedRentReclaimer>,
+    wallet: Option<String>,
+) -> Result<Vec<RentReclaimRecord>, String> {
+    let reclaimer = reclaimer.read().await;
+    reclaimer
+        .get_history(wallet.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALLET: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+    fn token_account(amount: &str, state: &str, close_authority: Option<&str>) -> Value {
+        json!({
+            "pubkey": "Acct1111111111111111111111111111111111111111",
+            "account": {
+                "lamports": 2_039_280,
+                "data": { "parsed": { "info": {
+                    "mint": "So11111111111111111111111111111111111111112",
+                    "state": state,
+                    "closeAuthority": close_authority,
+                    "tokenAmount": { "amount": amount }
+                } } }
+            }
+        })
+    }
+
+    fn empty(address: &str, recently_active: bool) -> EmptyTokenAccount {
+        EmptyTokenAccount {
+            address: address.to_string(),
+            wallet_address: WALLET.to_string(),
+            mint: "mint".to_string(),
+            program_id: TOKEN_PROGRAM_ID.to_string(),
+            rent_lamports: 2_039_280,
+            last_activity: None,
+            recently_active,
+        }
+    }
+
+    #[test]
+    fn only_empty_closable_accounts_are_listed() {
+        let parsed = parse_empty_account(
+            WALLET,
+            &token_account("0", "initialized", None),
+            TOKEN_PROGRAM_ID,
+        )
+        .unwrap();
+        assert_eq!(parsed.rent_lamports, 2_039_280);
+
+        let skipped = [
+            token_account("5", "initialized", None),
+            token_account("0", "frozen", None),
+            token_account(
+                "0",
+                "initialized",
+                Some("SomeoneElse111111111111111111111111111111111"),
+            ),
+        ];
+        for entry in &skipped {
+            assert!(parse_empty_account(WALLET, entry, TOKEN_PROGRAM_ID).is_none());
+        }
+    }
+
+    #[test]
+    fn recently_active_accounts_are_held_back() {
+        let accounts: Vec<_> = (0..45)
+            .map(|i| empty(&format!("acct{i}"), i % 9 == 0))
+            .collect();
+        let batches = plan_close_batches(&accounts, false);
+        assert_eq!(batches.iter().map(|b| b.len()).sum::<usize>(), 40);
+        assert!(batches
+            .iter()
+            .all(|b| b.len() <= MAX_CLOSES_PER_TRANSACTION));
+        assert_eq!(plan_close_batches(&accounts, true).len(), 3);
+
+        let scan = EmptyAccountScan::from_accounts(vec![WALLET.to_string()], accounts);
+        assert_eq!(scan.recently_active_count, 5);
+        assert_eq!(scan.reclaimable_lamports, 40 * 2_039_280);
+    }
+
+    #[test]
+    fn close_instruction_returns_rent_to_owner() {
+        let owner = Pubkey::from_str(WALLET).unwrap();
+        let account = empty("So11111111111111111111111111111111111111112", false);
+        let instruction = close_account_instruction(&account, &owner).unwrap();
+        assert_eq!(instruction.data, vec![CLOSE_ACCOUNT_INSTRUCTION]);
+        assert_eq!(instruction.accounts[1].pubkey, owner);
+        assert!(instruction.accounts[2].is_signer);
+    }
+}
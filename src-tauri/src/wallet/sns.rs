@@ -0,0 +1,286 @@
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, State};
+
+const SNS_PROXY_URL: &str = "https://sns-sdk-proxy.bonfida.workers.dev";
+const RESOLVE_TTL_MINUTES: i64 = 60;
+const REVERSE_TTL_MINUTES: i64 = 360;
+const MISS_TTL_MINUTES: i64 = 10;
+const REQUEST_TIMEOUT_SECS: u64 = 5;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SnsError {
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("invalid domain: {0}")]
+    InvalidDomain(String),
+    #[error("{0} does not resolve to an address")]
+    NotFound(String),
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    value: Option<String>,
+    expires_at: DateTime<Utc>,
+}
+
+/// Resolves `.sol` domains to owner addresses and addresses back to their primary domain.
+/// Both directions are cached, including misses, so feeds can look up freely.
+pub struct SnsResolver {
+    http: reqwest::Client,
+    forward: Mutex<HashMap<String, CacheEntry>>,
+    reverse: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl Default for SnsResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lowercased domain without the `.sol` suffix, e.g. `Bonfida.SOL` -> `bonfida`.
+/// Subdomains (`dex.bonfida.sol`) are kept as-is.
+pub fn normalize_domain(input: &str) -> Option<String> {
+    let trimmed = input.trim().to_lowercase();
+    let name = trimmed.strip_suffix(".sol")?;
+    let valid = !name.is_empty()
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && label
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        });
+    valid.then(|| name.to_string())
+}
+
+pub fn is_sns_domain(input: &str) -> bool {
+    normalize_domain(input).is_some()
+}
+
+/// Reads the proxy's `{ "s": "ok", "result": ... }` envelope.
+fn proxy_result(body: &Value) -> Option<&Value> {
+    match body.get("s").and_then(|s| s.as_str()) {
+        Some("ok") => body.get("result").filter(|r| !r.is_null()),
+        _ => None,
+    }
+}
+
+pub fn parse_resolve_response(body: &Value) -> Option<String> {
+    proxy_result(body)
+        .and_then(|r| r.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
+
+pub fn parse_favorite_domain_response(body: &Value) -> Option<String> {
+    let result = proxy_result(body)?;
+    if result.get("stale").and_then(|s| s.as_bool()).unwrap_or(false) {
+        return None;
+    }
+    let name = result
+        .get("reverse")
+        .and_then(|r| r.as_str())
+        .or_else(|| result.as_str())?;
+    (!name.is_empty()).then(|| format!("{}.sol", name.trim_end_matches(".sol")))
+}
+
+fn cached(cache: &Mutex<HashMap<String, CacheEntry>>, key: &str) -> Option<Option<String>> {
+    let guard = cache.lock().ok()?;
+    guard
+        .get(key)
+        .filter(|entry| entry.expires_at > Utc::now())
+        .map(|entry| entry.value.clone())
+}
+
+fn store(cache: &Mutex<HashMap<String, CacheEntry>>, key: String, value: Option<String>, ttl_minutes: i64) {
+    let ttl = if value.is_some() { ttl_minutes } else { MISS_TTL_MINUTES };
+    if let Ok(mut guard) = cache.lock() {
+        guard.insert(
+            key,
+            CacheEntry {
+                value,
+                expires_at: Utc::now() + Duration::minutes(ttl),
+            },
+        );
+    }
+}
+
+impl SnsResolver {
+    pub fn new() -> Self {
+        Self {
+            http: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(REQUEST_TIMEOUT_SECS))
+                .build()
+                .unwrap_or_default(),
+            forward: Mutex::new(HashMap::new()),
+            reverse: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn resolve(&self, domain: &str) -> Result<String, SnsError> {
+        let name = normalize_domain(domain).ok_or_else(|| SnsError::InvalidDomain(domain.to_string()))?;
+
+        let owner = match cached(&self.forward, &name) {
+            Some(hit) => hit,
+            None => {
+                let body: Value = self
+                    .http
+                    .get(format!("{SNS_PROXY_URL}/resolve/{name}"))
+                    .send()
+                    .await?
+                    .json()
+                    .await?;
+                let owner = parse_resolve_response(&body);
+                store(&self.forward, name.clone(), owner.clone(), RESOLVE_TTL_MINUTES);
+                owner
+            }
+        };
+
+        owner.ok_or_else(|| SnsError::NotFound(format!("{name}.sol")))
+    }
+
+    /// Accepts either a raw address or a `.sol` domain and returns the address.
+    pub async fn resolve_input(&self, input: &str) -> Result<String, SnsError> {
+        if is_sns_domain(input) {
+            self.resolve(input).await
+        } else {
+            Ok(input.trim().to_string())
+        }
+    }
+
+    /// Primary domain for an address. Lookup failures are treated as "no domain".
+    pub async fn reverse_lookup(&self, address: &str) -> Option<String> {
+        if let Some(hit) = cached(&self.reverse, address) {
+            return hit;
+        }
+
+        let response = self
+            .http
+            .get(format!("{SNS_PROXY_URL}/favorite-domain/{address}"))
+            .send()
+            .await;
+        let domain = match response {
+            Ok(response) => match response.json::<Value>().await {
+                Ok(body) => parse_favorite_domain_response(&body),
+                Err(err) => {
+                    tracing::debug!(error = %err, "invalid SNS reverse lookup response");
+                    None
+                }
+            },
+            Err(err) => {
+                // Don't cache transport failures; the next lookup retries
+                tracing::debug!(error = %err, "SNS reverse lookup failed");
+                return None;
+            }
+        };
+
+        store(&self.reverse, address.to_string(), domain.clone(), REVERSE_TTL_MINUTES);
+        domain
+    }
+
+    pub async fn reverse_lookup_many(&self, addresses: &[String]) -> HashMap<String, Option<String>> {
+        let mut domains = HashMap::new();
+        for address in addresses {
+            if !domains.contains_key(address) {
+                domains.insert(address.clone(), self.reverse_lookup(address).await);
+            }
+        }
+        domains
+    }
+
+    pub fn clear_cache(&self) {
+        if let Ok(mut guard) = self.forward.lock() {
+            guard.clear();
+        }
+        if let Ok(mut guard) = self.reverse.lock() {
+            guard.clear();
+        }
+    }
+}
+
+/// Resolves a user-entered address for modules that only hold an `AppHandle`.
+pub async fn resolve_address_input(app: &AppHandle, input: &str) -> Result<String, String> {
+    if !is_sns_domain(input) {
+        return Ok(input.trim().to_string());
+    }
+    let resolver = app
+        .try_state::<SnsResolver>()
+        .ok_or_else(|| "Domain resolution unavailable".to_string())?;
+    resolver.resolve(input).await.map_err(|e| e.to_string())
+}
+
+/// Primary `.sol` domain for display, if the address has one.
+pub async fn display_domain(app: &AppHandle, address: &str) -> Option<String> {
+    let resolver = app.try_state::<SnsResolver>()?;
+    resolver.reverse_lookup(address).await
+}
+
+#[tauri::command]
+pub async fn sns_resolve(domain: String, resolver: State<'_, SnsResolver>) -> Result<String, String> {
+    resolver.resolve(&domain).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn sns_reverse_lookup(
+    addresses: Vec<String>,
+    resolver: State<'_, SnsResolver>,
+) -> Result<HashMap<String, Option<String>>, String> {
+    Ok(resolver.reverse_lookup_many(&addresses).await)
+}
+
+#[tauri::command]
+pub async fn sns_clear_cache(resolver: State<'_, SnsResolver>) -> Result<(), String> {
+    resolver.clear_cache();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn normalizes_domains_and_rejects_addresses() {
+        assert_eq!(normalize_domain(" Bonfida.SOL ").as_deref(), Some("bonfida"));
+        assert_eq!(normalize_domain("dex.bonfida.sol").as_deref(), Some("dex.bonfida"));
+        assert!(normalize_domain(".sol").is_none());
+        assert!(normalize_domain("bad..name.sol").is_none());
+        assert!(!is_sns_domain("HKKp49qGWXd639QsuH7JiLijfVW5UtCVY4s1n2HANwEA"));
+    }
+
+    #[test]
+    fn parses_proxy_envelopes() {
+        assert_eq!(
+            parse_resolve_response(&json!({ "s": "ok", "result": "HKKp49qGWXd639QsuH7JiLijfVW5UtCVY4s1n2HANwEA" }))
+                .as_deref(),
+            Some("HKKp49qGWXd639QsuH7JiLijfVW5UtCVY4s1n2HANwEA")
+        );
+        assert!(parse_resolve_response(&json!({ "s": "error", "result": "Domain not found" })).is_none());
+
+        assert_eq!(
+            parse_favorite_domain_response(&json!({
+                "s": "ok",
+                "result": { "domain": "Crf8hzfthWGbGbLTVCiqRqV5MVnbpHB1L9KQMd6gsinb", "reverse": "bonfida", "stale": false }
+            }))
+            .as_deref(),
+            Some("bonfida.sol")
+        );
+        assert!(parse_favorite_domain_response(&json!({
+            "s": "ok",
+            "result": { "reverse": "old", "stale": true }
+        }))
+        .is_none());
+    }
+
+    #[test]
+    fn cache_expires_entries() {
+        let cache = Mutex::new(HashMap::new());
+        store(&cache, "a".to_string(), Some("a.sol".to_string()), 10);
+        assert_eq!(cached(&cache, "a"), Some(Some("a.sol".to_string())));
+
+        cache.lock().unwrap().get_mut("a").unwrap().expires_at = Utc::now() - Duration::seconds(1);
+        assert_eq!(cached(&cache, "a"), None);
+    }
+}
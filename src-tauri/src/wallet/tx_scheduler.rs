@@ -0,0 +1,829 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::{Pool, Row, Sqlite, SqlitePool};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::notifications::router::SharedNotificationRouter;
+use crate::wallet::multisig::{
+    execute_approved_proposal, MultisigDatabase, ProposalStatus, SharedMultisigDatabase,
+};
+use crate::wallet::multisig_notifications::{
+    build_notification, dispatch_notification, proposal_deep_link, MultisigEventKind,
+};
+use crate::wallet::nonce_accounts::BLOCKHASH_LIFETIME_SECS;
+use crate::wallet::offline_signing::inspect_unsigned_transaction;
+
+const SCHEDULER_DB_FILE: &str = "scheduled_transactions.db";
+const DEFAULT_RPC_ENDPOINT: &str = "https://api.mainnet-beta.solana.com";
+const SCHEDULED_TX_EVENT: &str = "scheduled_transaction_update";
+const EXECUTOR_INTERVAL: StdDuration = StdDuration::from_secs(30);
+const DEFAULT_NOTIFY_BEFORE_SECS: i64 = 15 * 60;
+/// Schedules must leave at least this long to cancel before execution.
+pub const MIN_CANCELLATION_WINDOW_SECS: i64 = 60;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledTxKind {
+    MultisigProposal,
+    PreparedTransaction,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduledTxStatus {
+    Scheduled,
+    Executing,
+    Executed,
+    Cancelled,
+    Failed,
+}
+
+impl ScheduledTxKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ScheduledTxKind::MultisigProposal => "multisig_proposal",
+            ScheduledTxKind::PreparedTransaction => "prepared_transaction",
+        }
+    }
+}
+
+impl FromStr for ScheduledTxKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "multisig_proposal" => Ok(ScheduledTxKind::MultisigProposal),
+            "prepared_transaction" => Ok(ScheduledTxKind::PreparedTransaction),
+            _ => Err(format!("Invalid scheduled transaction kind: {}", s)),
+        }
+    }
+}
+
+impl ScheduledTxStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ScheduledTxStatus::Scheduled => "scheduled",
+            ScheduledTxStatus::Executing => "executing",
+            ScheduledTxStatus::Executed => "executed",
+            ScheduledTxStatus::Cancelled => "cancelled",
+            ScheduledTxStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for ScheduledTxStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scheduled" => Ok(ScheduledTxStatus::Scheduled),
+            "executing" => Ok(ScheduledTxStatus::Executing),
+            "executed" => Ok(ScheduledTxStatus::Executed),
+            "cancelled" => Ok(ScheduledTxStatus::Cancelled),
+            "failed" => Ok(ScheduledTxStatus::Failed),
+            _ => Err(format!("Invalid scheduled transaction status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledTransaction {
+    pub id: String,
+    pub kind: ScheduledTxKind,
+    pub proposal_id: Option<String>,
+    /// Signing wallet for prepared transactions, multisig wallet id for proposals.
+    pub wallet_address: Option<String>,
+    /// Base64 signed transaction for prepared single-wallet transactions.
+    #[serde(skip_serializing)]
+    pub transaction: Option<String>,
+    pub rpc_url: Option<String>,
+    pub description: Option<String>,
+    pub execute_at: DateTime<Utc>,
+    pub notify_before_secs: i64,
+    pub pre_notified: bool,
+    pub status: ScheduledTxStatus,
+    pub tx_signature: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub executed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleTransactionRequest {
+    pub kind: ScheduledTxKind,
+    pub proposal_id: Option<String>,
+    pub wallet_address: Option<String>,
+    /// Signed transaction; use a durable nonce when the delay exceeds the blockhash lifetime.
+    pub transaction: Option<String>,
+    pub rpc_url: Option<String>,
+    pub description: Option<String>,
+    pub execute_at: Option<DateTime<Utc>>,
+    pub delay_secs: Option<i64>,
+    pub notify_before_secs: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledTxUpdate {
+    pub id: String,
+    pub status: ScheduledTxStatus,
+    pub execute_at: DateTime<Utc>,
+    pub message: String,
+    pub deep_link: Option<String>,
+}
+
+/// Resolves the execution time from an explicit timestamp or a delay, enforcing the
+/// cancellation window and any earlier unlock time (e.g. a multisig policy time lock).
+pub fn resolve_execute_at(
+    execute_at: Option<DateTime<Utc>>,
+    delay_secs: Option<i64>,
+    not_before: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, String> {
+    let target = match (execute_at, delay_secs) {
+        (Some(at), _) => at,
+        (None, Some(delay)) if delay >= 0 => now + Duration::seconds(delay),
+        (None, Some(_)) => return Err("Delay cannot be negative".to_string()),
+        (None, None) => return Err("Either executeAt or delaySecs is required".to_string()),
+    };
+
+    if target < now + Duration::seconds(MIN_CANCELLATION_WINDOW_SECS) {
+        return Err(format!(
+            "Execution must be at least {} seconds in the future",
+            MIN_CANCELLATION_WINDOW_SECS
+        ));
+    }
+
+    if let Some(unlocks_at) = not_before {
+        if target < unlocks_at {
+            return Err(format!(
+                "Execution cannot precede the time lock ending {}",
+                unlocks_at.to_rfc3339()
+            ));
+        }
+    }
+
+    Ok(target)
+}
+
+pub fn needs_pre_notification(item: &ScheduledTransaction, now: DateTime<Utc>) -> bool {
+    item.status == ScheduledTxStatus::Scheduled
+        && !item.pre_notified
+        && item.notify_before_secs > 0
+        && now >= item.execute_at - Duration::seconds(item.notify_before_secs)
+        && now < item.execute_at
+}
+
+pub fn is_due(item: &ScheduledTransaction, now: DateTime<Utc>) -> bool {
+    item.status == ScheduledTxStatus::Scheduled && now >= item.execute_at
+}
+
+pub struct TransactionScheduler {
+    pool: Pool<Sqlite>,
+    client: reqwest::Client,
+}
+
+pub type SharedTransactionScheduler = Arc<RwLock<TransactionScheduler>>;
+
+impl TransactionScheduler {
+    pub async fn new(app: &AppHandle) -> Result<Self, String> {
+        let mut db_path: PathBuf = app
+            .path_resolver()
+            .app_data_dir()
+            .ok_or_else(|| "Unable to resolve app data directory".to_string())?;
+
+        std::fs::create_dir_all(&db_path)
+            .map_err(|e| format!("Failed to create data directory: {e}"))?;
+        db_path.push(SCHEDULER_DB_FILE);
+
+        let db_url = format!("sqlite:{}?mode=rwc", db_path.display());
+        let pool = SqlitePool::connect(&db_url)
+            .await
+            .map_err(|e| format!("Failed to open scheduler database: {e}"))?;
+
+        let scheduler = Self {
+            pool,
+            client: reqwest::Client::new(),
+        };
+        scheduler
+            .initialize()
+            .await
+            .map_err(|e| format!("Failed to initialize scheduler database: {e}"))?;
+        Ok(scheduler)
+    }
+
+    async fn initialize(&self) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS scheduled_transactions (
+                id TEXT PRIMARY KEY,
+                kind TEXT NOT NULL,
+                proposal_id TEXT,
+                wallet_address TEXT,
+                transaction_data TEXT,
+                rpc_url TEXT,
+                description TEXT,
+                execute_at TEXT NOT NULL,
+                notify_before_secs INTEGER NOT NULL,
+                pre_notified INTEGER NOT NULL DEFAULT 0,
+                status TEXT NOT NULL,
+                tx_signature TEXT,
+                error TEXT,
+                created_at TEXT NOT NULL,
+                executed_at TEXT
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_scheduled_transactions_status ON scheduled_transactions(status, execute_at)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn insert(&self, item: &ScheduledTransaction) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO scheduled_transactions (
+                id, kind, proposal_id, wallet_address, transaction_data, rpc_url, description,
+                execute_at, notify_before_secs, pre_notified, status, tx_signature, error,
+                created_at, executed_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            "#,
+        )
+        .bind(&item.id)
+        .bind(item.kind.as_str())
+        .bind(&item.proposal_id)
+        .bind(&item.wallet_address)
+        .bind(&item.transaction)
+        .bind(&item.rpc_url)
+        .bind(&item.description)
+        .bind(item.execute_at.to_rfc3339())
+        .bind(item.notify_before_secs)
+        .bind(item.pre_notified)
+        .bind(item.status.as_str())
+        .bind(&item.tx_signature)
+        .bind(&item.error)
+        .bind(item.created_at.to_rfc3339())
+        .bind(item.executed_at.map(|dt| dt.to_rfc3339()))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    fn from_row(row: &sqlx::sqlite::SqliteRow) -> Result<ScheduledTransaction, String> {
+        let parse_time = |value: String| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| e.to_string())
+        };
+        let get = |e: sqlx::Error| e.to_string();
+
+        Ok(ScheduledTransaction {
+            id: row.try_get("id").map_err(get)?,
+            kind: ScheduledTxKind::from_str(&row.try_get::<String, _>("kind").map_err(get)?)?,
+            proposal_id: row.try_get("proposal_id").map_err(get)?,
+            wallet_address: row.try_get("wallet_address").map_err(get)?,
+            transaction: row.try_get("transaction_data").map_err(get)?,
+            rpc_url: row.try_get("rpc_url").map_err(get)?,
+            description: row.try_get("description").map_err(get)?,
+            execute_at: parse_time(row.try_get("execute_at").map_err(get)?)?,
+            notify_before_secs: row.try_get("notify_before_secs").map_err(get)?,
+            pre_notified: row.try_get("pre_notified").map_err(get)?,
+            status: ScheduledTxStatus::from_str(&row.try_get::<String, _>("status").map_err(get)?)?,
+            tx_signature: row.try_get("tx_signature").map_err(get)?,
+            error: row.try_get("error").map_err(get)?,
+            created_at: parse_time(row.try_get("created_at").map_err(get)?)?,
+            executed_at: row
+                .try_get::<Option<String>, _>("executed_at")
+                .map_err(get)?
+                .map(parse_time)
+                .transpose()?,
+        })
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Option<ScheduledTransaction>, String> {
+        let row = sqlx::query("SELECT * FROM scheduled_transactions WHERE id = ?1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        row.map(|row| Self::from_row(&row)).transpose()
+    }
+
+    pub async fn list(
+        &self,
+        status: Option<ScheduledTxStatus>,
+    ) -> Result<Vec<ScheduledTransaction>, String> {
+        let rows = match status {
+            Some(status) => {
+                sqlx::query(
+                    "SELECT * FROM scheduled_transactions WHERE status = ?1 ORDER BY execute_at ASC",
+                )
+                .bind(status.as_str())
+                .fetch_all(&self.pool)
+                .await
+            }
+            None => {
+                sqlx::query("SELECT * FROM scheduled_transactions ORDER BY execute_at ASC")
+                    .fetch_all(&self.pool)
+                    .await
+            }
+        }
+        .map_err(|e| e.to_string())?;
+
+        rows.iter().map(Self::from_row).collect()
+    }
+
+    pub async fn find_active_for_proposal(
+        &self,
+        proposal_id: &str,
+    ) -> Result<Option<ScheduledTransaction>, String> {
+        let row = sqlx::query(
+            "SELECT * FROM scheduled_transactions WHERE proposal_id = ?1 AND status = ?2",
+        )
+        .bind(proposal_id)
+        .bind(ScheduledTxStatus::Scheduled.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        row.map(|row| Self::from_row(&row)).transpose()
+    }
+
+    /// Moves a schedule from `from` to `to`; returns false if it was no longer in `from`.
+    pub async fn transition(
+        &self,
+        id: &str,
+        from: ScheduledTxStatus,
+        to: ScheduledTxStatus,
+    ) -> Result<bool, String> {
+        let result = sqlx::query(
+            "UPDATE scheduled_transactions SET status = ?1 WHERE id = ?2 AND status = ?3",
+        )
+        .bind(to.as_str())
+        .bind(id)
+        .bind(from.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn mark_pre_notified(&self, id: &str) -> Result<(), String> {
+        sqlx::query("UPDATE scheduled_transactions SET pre_notified = 1 WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    pub async fn record_result(
+        &self,
+        id: &str,
+        result: &Result<String, String>,
+    ) -> Result<(), String> {
+        let (status, signature, error) = match result {
+            Ok(signature) => (ScheduledTxStatus::Executed, Some(signature.clone()), None),
+            Err(error) => (ScheduledTxStatus::Failed, None, Some(error.clone())),
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE scheduled_transactions
+            SET status = ?1, tx_signature = ?2, error = ?3, executed_at = ?4
+            WHERE id = ?5
+            "#,
+        )
+        .bind(status.as_str())
+        .bind(signature)
+        .bind(error)
+        .bind(Utc::now().to_rfc3339())
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    pub async fn send_prepared_transaction(
+        &self,
+        transaction: &str,
+        rpc_url: &str,
+    ) -> Result<String, String> {
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sendTransaction",
+            "params": [transaction, { "encoding": "base64" }],
+        });
+
+        let data: Value = self
+            .client
+            .post(rpc_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("RPC request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Some(error) = data.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+
+        data["result"]
+            .as_str()
+            .map(|s| s.to_string())
+            .ok_or_else(|| "Invalid sendTransaction response".to_string())
+    }
+}
+
+fn describe(item: &ScheduledTransaction) -> String {
+    item.description.clone().unwrap_or_else(|| match item.kind {
+        ScheduledTxKind::MultisigProposal => format!(
+            "Multisig proposal {}",
+            item.proposal_id.as_deref().unwrap_or("unknown")
+        ),
+        ScheduledTxKind::PreparedTransaction => format!(
+            "Transaction from {}",
+            item.wallet_address.as_deref().unwrap_or("wallet")
+        ),
+    })
+}
+
+async fn publish_update(
+    app_handle: &AppHandle,
+    router: &SharedNotificationRouter,
+    item: &ScheduledTransaction,
+    status: ScheduledTxStatus,
+    title: &str,
+    message: String,
+    deep_link: Option<String>,
+) {
+    let update = ScheduledTxUpdate {
+        id: item.id.clone(),
+        status,
+        execute_at: item.execute_at,
+        message: message.clone(),
+        deep_link: deep_link.clone(),
+    };
+    if let Err(err) = app_handle.emit_all(SCHEDULED_TX_EVENT, &update) {
+        tracing::warn!(error = %err, "failed to emit scheduled transaction update");
+    }
+
+    let body = match deep_link {
+        Some(link) => format!("{}\nOpen: {}", message, link),
+        None => message,
+    };
+    let router = router.read().await;
+    if let Err(err) = router.send_broadcast_notification(&item.id, title, &body).await {
+        tracing::warn!(error = %err, "failed to deliver scheduled transaction notification");
+    }
+}
+
+async fn execute_item(
+    app_handle: &AppHandle,
+    scheduler: &SharedTransactionScheduler,
+    multisig: &SharedMultisigDatabase,
+    router: &SharedNotificationRouter,
+    item: &ScheduledTransaction,
+) -> Result<String, String> {
+    match item.kind {
+        ScheduledTxKind::MultisigProposal => {
+            let proposal_id = item
+                .proposal_id
+                .as_deref()
+                .ok_or_else(|| "Scheduled proposal is missing its id".to_string())?;
+            let (wallet, executed) = {
+                let db = multisig.read().await;
+                execute_approved_proposal(&db, proposal_id).await?
+            };
+
+            let notification =
+                build_notification(MultisigEventKind::ProposalExecuted, &wallet, &executed);
+            dispatch_notification(app_handle, router, &notification).await;
+
+            Ok(executed.tx_signature.unwrap_or_default())
+        }
+        ScheduledTxKind::PreparedTransaction => {
+            let transaction = item
+                .transaction
+                .as_deref()
+                .ok_or_else(|| "Scheduled transaction has no payload".to_string())?;
+            let scheduler = scheduler.read().await;
+            scheduler
+                .send_prepared_transaction(
+                    transaction,
+                    item.rpc_url.as_deref().unwrap_or(DEFAULT_RPC_ENDPOINT),
+                )
+                .await
+        }
+    }
+}
+
+/// Sends upcoming-execution notices and executes schedules that have come due.
+pub async fn run_scheduler_tick(
+    app_handle: &AppHandle,
+    scheduler: &SharedTransactionScheduler,
+    multisig: &SharedMultisigDatabase,
+    router: &SharedNotificationRouter,
+) -> Result<(), String> {
+    let now = Utc::now();
+    let pending = {
+        let scheduler = scheduler.read().await;
+        scheduler.list(Some(ScheduledTxStatus::Scheduled)).await?
+    };
+
+    for item in pending {
+        let deep_link = item
+            .proposal_id
+            .as_ref()
+            .zip(item.wallet_address.as_ref())
+            .map(|(proposal_id, wallet_id)| proposal_deep_link(wallet_id, proposal_id).url);
+
+        if needs_pre_notification(&item, now) {
+            scheduler.read().await.mark_pre_notified(&item.id).await?;
+            let minutes = (item.execute_at - now).num_minutes().max(1);
+            publish_update(
+                app_handle,
+                router,
+                &item,
+                ScheduledTxStatus::Scheduled,
+                "Scheduled transaction executing soon",
+                format!(
+                    "{} executes in {} minute(s). Cancel before {} to stop it.",
+                    describe(&item),
+                    minutes,
+                    item.execute_at.to_rfc3339()
+                ),
+                deep_link.clone(),
+            )
+            .await;
+        }
+
+        if !is_due(&item, now) {
+            continue;
+        }
+
+        // Claim the schedule so a concurrent cancel or tick cannot run it twice
+        let claimed = scheduler
+            .read()
+            .await
+            .transition(&item.id, ScheduledTxStatus::Scheduled, ScheduledTxStatus::Executing)
+            .await?;
+        if !claimed {
+            continue;
+        }
+
+        let result = execute_item(app_handle, scheduler, multisig, router, &item).await;
+        scheduler.read().await.record_result(&item.id, &result).await?;
+
+        let (status, title, message) = match &result {
+            Ok(signature) => (
+                ScheduledTxStatus::Executed,
+                "Scheduled transaction executed",
+                format!("{} executed ({})", describe(&item), signature),
+            ),
+            Err(error) => (
+                ScheduledTxStatus::Failed,
+                "Scheduled transaction failed",
+                format!("{} failed: {}", describe(&item), error),
+            ),
+        };
+        publish_update(app_handle, router, &item, status, title, message, deep_link).await;
+    }
+
+    Ok(())
+}
+
+pub fn start_scheduler_executor(
+    app_handle: AppHandle,
+    scheduler: SharedTransactionScheduler,
+    multisig: SharedMultisigDatabase,
+    router: SharedNotificationRouter,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(EXECUTOR_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = run_scheduler_tick(&app_handle, &scheduler, &multisig, &router).await {
+                tracing::warn!(error = %err, "scheduled transaction tick failed");
+            }
+        }
+    });
+}
+
+/// Validates and stores a schedule; shared by the command and other features that
+/// activate proposals on a timer.
+pub async fn create_schedule(
+    scheduler: &TransactionScheduler,
+    multisig: &MultisigDatabase,
+    request: ScheduleTransactionRequest,
+) -> Result<ScheduledTransaction, String> {
+    let now = Utc::now();
+
+    let (wallet_address, not_before) = match request.kind {
+        ScheduledTxKind::MultisigProposal => {
+            let proposal_id = request
+                .proposal_id
+                .as_deref()
+                .ok_or_else(|| "proposalId is required for multisig schedules".to_string())?;
+            let proposal = multisig
+                .get_proposal(proposal_id)
+                .await
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| "Proposal not found".to_string())?;
+
+            if proposal.status != ProposalStatus::Approved {
+                return Err("Only approved proposals can be scheduled".to_string());
+            }
+
+            if scheduler.find_active_for_proposal(proposal_id).await?.is_some() {
+                return Err("Proposal already has a pending schedule".to_string());
+            }
+
+            (
+                Some(proposal.wallet_id),
+                proposal.policy.and_then(|p| p.executable_after),
+            )
+        }
+        ScheduledTxKind::PreparedTransaction => {
+            if request.transaction.as_deref().map(str::is_empty).unwrap_or(true) {
+                return Err("A signed transaction is required".to_string());
+            }
+            (request.wallet_address.clone(), None)
+        }
+    };
+
+    let execute_at = resolve_execute_at(request.execute_at, request.delay_secs, not_before, now)?;
+    if request.kind == ScheduledTxKind::PreparedTransaction
+        && (execute_at - now).num_seconds() > BLOCKHASH_LIFETIME_SECS
+    {
+        let transaction = request.transaction.as_deref().unwrap_or_default();
+        if !inspect_unsigned_transaction(transaction)?.durable_nonce {
+            return Err("Blockhash expires before execution; use a durable nonce".to_string());
+        }
+    }
+    let notify_before_secs = request
+        .notify_before_secs
+        .unwrap_or(DEFAULT_NOTIFY_BEFORE_SECS)
+        .max(0);
+
+    let item = ScheduledTransaction {
+        id: format!("schedule_{}", Uuid::new_v4()),
+        kind: request.kind,
+        proposal_id: request.proposal_id,
+        wallet_address,
+        transaction: request.transaction,
+        rpc_url: request.rpc_url,
+        description: request.description,
+        execute_at,
+        notify_before_secs,
+        pre_notified: false,
+        status: ScheduledTxStatus::Scheduled,
+        tx_signature: None,
+        error: None,
+        created_at: now,
+        executed_at: None,
+    };
+
+    scheduler.insert(&item).await.map_err(|e| e.to_string())?;
+
+    Ok(item)
+}
+
+// Tauri commands
+#[tauri::command]
+pub async fn schedule_transaction(
+    request: ScheduleTransactionRequest,
+    scheduler: State<'_, SharedTransactionScheduler>,
+    multisig: State<'_, SharedMultisigDatabase>,
+) -> Result<ScheduledTransaction, String> {
+    let multisig = multisig.read().await;
+    let scheduler = scheduler.read().await;
+    create_schedule(&scheduler, &multisig, request).await
+}
+
+#[tauri::command]
+pub async fn list_scheduled_transactions(
+    status: Option<ScheduledTxStatus>,
+    scheduler: State<'_, SharedTransactionScheduler>,
+) -> Result<Vec<ScheduledTransaction>, String> {
+    let scheduler = scheduler.read().await;
+    scheduler.list(status).await
+}
+
+#[tauri::command]
+pub async fn cancel_scheduled_transaction(
+    id: String,
+    scheduler: State<'_, SharedTransactionScheduler>,
+) -> Result<ScheduledTransaction, String> {
+    let scheduler = scheduler.read().await;
+    let item = scheduler
+        .get(&id)
+        .await?
+        .ok_or_else(|| "Scheduled transaction not found".to_string())?;
+
+    if item.status != ScheduledTxStatus::Scheduled {
+        return Err("Only scheduled transactions can be cancelled".to_string());
+    }
+
+    if !scheduler
+        .transition(&id, ScheduledTxStatus::Scheduled, ScheduledTxStatus::Cancelled)
+        .await?
+    {
+        return Err("Cancellation window has closed".to_string());
+    }
+
+    Ok(ScheduledTransaction {
+        status: ScheduledTxStatus::Cancelled,
+        ..item
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(execute_at: DateTime<Utc>, notify_before_secs: i64) -> ScheduledTransaction {
+        ScheduledTransaction {
+            id: "schedule_1".to_string(),
+            kind: ScheduledTxKind::PreparedTransaction,
+            proposal_id: None,
+            wallet_address: Some("wallet".to_string()),
+            transaction: Some("tx".to_string()),
+            rpc_url: None,
+            description: None,
+            execute_at,
+            notify_before_secs,
+            pre_notified: false,
+            status: ScheduledTxStatus::Scheduled,
+            tx_signature: None,
+            error: None,
+            created_at: Utc::now(),
+            executed_at: None,
+        }
+    }
+
+    #[test]
+    fn resolves_delay_and_enforces_windows() {
+        let now = Utc::now();
+
+        assert_eq!(
+            resolve_execute_at(None, Some(3_600), None, now).unwrap(),
+            now + Duration::hours(1)
+        );
+        assert!(resolve_execute_at(None, Some(10), None, now).is_err());
+        assert!(resolve_execute_at(None, None, None, now).is_err());
+        assert!(resolve_execute_at(
+            Some(now + Duration::hours(1)),
+            None,
+            Some(now + Duration::hours(2)),
+            now
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn pre_notification_and_due_checks() {
+        let now = Utc::now();
+        let upcoming = item(now + Duration::minutes(10), 15 * 60);
+
+        assert!(needs_pre_notification(&upcoming, now));
+        assert!(!is_due(&upcoming, now));
+        assert!(is_due(&upcoming, now + Duration::minutes(11)));
+
+        let notified = ScheduledTransaction {
+            pre_notified: true,
+            ..upcoming.clone()
+        };
+        assert!(!needs_pre_notification(&notified, now));
+
+        let cancelled = ScheduledTransaction {
+            status: ScheduledTxStatus::Cancelled,
+            ..upcoming
+        };
+        assert!(!is_due(&cancelled, now + Duration::hours(1)));
+    }
+}
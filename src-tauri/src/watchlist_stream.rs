@@ -0,0 +1,300 @@
+use crate::core::price_engine::{get_price_engine, CachedPrice};
+use crate::core::WebSocketManager;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::RwLock;
+use tokio::time::Duration;
+
+const DEFAULT_BATCH_INTERVAL_MS: u64 = 1000;
+const MIN_BATCH_INTERVAL_MS: u64 = 250;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchlistPriceUpdate {
+    pub symbol: String,
+    pub price: f64,
+    pub volume: f64,
+    pub change_24h: f64,
+    pub timestamp: u64,
+}
+
+/// Emitted on `watchlist_price_batch` with every update since the last batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchlistPriceBatch {
+    pub watchlist_id: String,
+    pub updates: Vec<WatchlistPriceUpdate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchlistStreamStatus {
+    pub interval_ms: u64,
+    pub watchlists: HashMap<String, Vec<String>>,
+    pub subscribed_symbols: Vec<String>,
+}
+
+struct WatchlistStreams {
+    /// Visible symbols per watchlist.
+    visible: HashMap<String, BTreeSet<String>>,
+    /// Symbols this module currently holds on the price stream.
+    subscribed: BTreeSet<String>,
+    /// Timestamp of the last price sent per watchlist and symbol.
+    last_sent: HashMap<String, HashMap<String, u64>>,
+    interval_ms: u64,
+    task_running: bool,
+}
+
+impl Default for WatchlistStreams {
+    fn default() -> Self {
+        Self {
+            visible: HashMap::new(),
+            subscribed: BTreeSet::new(),
+            last_sent: HashMap::new(),
+            interval_ms: DEFAULT_BATCH_INTERVAL_MS,
+            task_running: false,
+        }
+    }
+}
+
+impl WatchlistStreams {
+    fn desired_symbols(&self) -> BTreeSet<String> {
+        self.visible.values().flatten().cloned().collect()
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref WATCHLIST_STREAMS: Arc<RwLock<WatchlistStreams>> =
+        Arc::new(RwLock::new(WatchlistStreams::default()));
+}
+
+/// Symbols to add to and drop from the consolidated subscription.
+pub fn diff_subscriptions(
+    current: &BTreeSet<String>,
+    desired: &BTreeSet<String>,
+) -> (Vec<String>, Vec<String>) {
+    (
+        desired.difference(current).cloned().collect(),
+        current.difference(desired).cloned().collect(),
+    )
+}
+
+/// Coalesces the latest price of each symbol into one batch, skipping symbols
+/// whose price has not changed since the last batch.
+pub fn collect_batch(
+    symbols: &BTreeSet<String>,
+    last_sent: &mut HashMap<String, u64>,
+    lookup: impl Fn(&str) -> Option<CachedPrice>,
+) -> Vec<WatchlistPriceUpdate> {
+    last_sent.retain(|symbol, _| symbols.contains(symbol));
+    symbols
+        .iter()
+        .filter_map(|symbol| {
+            let cached = lookup(symbol)?;
+            let previous = last_sent.insert(symbol.clone(), cached.timestamp);
+            if previous.is_some_and(|sent| sent >= cached.timestamp) {
+                return None;
+            }
+            Some(WatchlistPriceUpdate {
+                symbol: symbol.clone(),
+                price: cached.price,
+                volume: cached.volume,
+                change_24h: cached.change_24h,
+                timestamp: cached.timestamp,
+            })
+        })
+        .collect()
+}
+
+/// Applies the new visible set to the price stream, subscribing only added
+/// symbols and releasing only those no watchlist shows any more.
+async fn sync_subscriptions(ws_manager: &WebSocketManager) -> Result<(), String> {
+    let (added, removed) = {
+        let mut streams = WATCHLIST_STREAMS.write().await;
+        let desired = streams.desired_symbols();
+        let diff = diff_subscriptions(&streams.subscribed, &desired);
+        streams.subscribed = desired;
+        diff
+    };
+
+    if !added.is_empty() {
+        ws_manager
+            .subscribe_prices(added)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    if !removed.is_empty() {
+        ws_manager
+            .unsubscribe_prices(removed)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn spawn_batch_task(app_handle: AppHandle) {
+    tokio::spawn(async move {
+        loop {
+            let interval_ms = WATCHLIST_STREAMS.read().await.interval_ms;
+            tokio::time::sleep(Duration::from_millis(interval_ms)).await;
+
+            let batches = {
+                let mut streams = WATCHLIST_STREAMS.write().await;
+                if streams.visible.is_empty() {
+                    streams.task_running = false;
+                    break;
+                }
+
+                let engine = get_price_engine();
+                let WatchlistStreams {
+                    visible, last_sent, ..
+                } = &mut *streams;
+                visible
+                    .iter()
+                    .filter_map(|(watchlist_id, symbols)| {
+                        let sent = last_sent.entry(watchlist_id.clone()).or_default();
+                        let updates =
+                            collect_batch(symbols, sent, |symbol| engine.get_cached_price(symbol));
+                        (!updates.is_empty()).then(|| WatchlistPriceBatch {
+                            watchlist_id: watchlist_id.clone(),
+                            updates,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            for batch in batches {
+                let _ = app_handle.emit_all("watchlist_price_batch", &batch);
+            }
+        }
+    });
+}
+
+/// Sets the tokens currently visible in a watchlist. All watchlists share one
+/// price subscription; updates arrive batched on `watchlist_price_batch`.
+#[tauri::command]
+pub async fn set_watchlist_stream(
+    app_handle: AppHandle,
+    ws_manager: State<'_, WebSocketManager>,
+    watchlist_id: String,
+    symbols: Vec<String>,
+    interval_ms: Option<u64>,
+) -> Result<WatchlistStreamStatus, String> {
+    let start_task = {
+        let mut streams = WATCHLIST_STREAMS.write().await;
+        if let Some(interval_ms) = interval_ms {
+            streams.interval_ms = interval_ms.max(MIN_BATCH_INTERVAL_MS);
+        }
+        if symbols.is_empty() {
+            streams.visible.remove(&watchlist_id);
+            streams.last_sent.remove(&watchlist_id);
+        } else {
+            streams
+                .visible
+                .insert(watchlist_id, symbols.into_iter().collect());
+        }
+        let start = !streams.task_running && !streams.visible.is_empty();
+        if start {
+            streams.task_running = true;
+        }
+        start
+    };
+
+    sync_subscriptions(&ws_manager).await?;
+    if start_task {
+        spawn_batch_task(app_handle);
+    }
+    get_watchlist_stream_status().await
+}
+
+#[tauri::command]
+pub async fn clear_watchlist_stream(
+    ws_manager: State<'_, WebSocketManager>,
+    watchlist_id: Option<String>,
+) -> Result<(), String> {
+    {
+        let mut streams = WATCHLIST_STREAMS.write().await;
+        match watchlist_id {
+            Some(id) => {
+                streams.visible.remove(&id);
+                streams.last_sent.remove(&id);
+            }
+            None => {
+                streams.visible.clear();
+                streams.last_sent.clear();
+            }
+        }
+    }
+    sync_subscriptions(&ws_manager).await
+}
+
+#[tauri::command]
+pub async fn get_watchlist_stream_status() -> Result<WatchlistStreamStatus, String> {
+    let streams = WATCHLIST_STREAMS.read().await;
+    Ok(WatchlistStreamStatus {
+        interval_ms: streams.interval_ms,
+        watchlists: streams
+            .visible
+            .iter()
+            .map(|(id, symbols)| (id.clone(), symbols.iter().cloned().collect()))
+            .collect(),
+        subscribed_symbols: streams.subscribed.iter().cloned().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(symbols: &[&str]) -> BTreeSet<String> {
+        symbols.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn price(price: f64, timestamp: u64) -> CachedPrice {
+        CachedPrice {
+            price,
+            volume: 0.0,
+            change_24h: 0.0,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn diff_only_touches_changed_symbols() {
+        let (added, removed) = diff_subscriptions(&set(&["SOL", "BONK"]), &set(&["SOL", "WIF"]));
+        assert_eq!(added, vec!["WIF".to_string()]);
+        assert_eq!(removed, vec!["BONK".to_string()]);
+    }
+
+    #[test]
+    fn symbols_shared_by_watchlists_stay_subscribed() {
+        let mut streams = WatchlistStreams::default();
+        streams.visible.insert("a".into(), set(&["SOL", "BONK"]));
+        streams.visible.insert("b".into(), set(&["SOL"]));
+        streams.subscribed = streams.desired_symbols();
+
+        streams.visible.remove("a");
+        let (added, removed) = diff_subscriptions(&streams.subscribed, &streams.desired_symbols());
+        assert!(added.is_empty());
+        assert_eq!(removed, vec!["BONK".to_string()]);
+    }
+
+    #[test]
+    fn batches_skip_unchanged_prices() {
+        let symbols = set(&["SOL", "BONK"]);
+        let mut sent = HashMap::new();
+        let lookup = |symbol: &str| match symbol {
+            "SOL" => Some(price(150.0, 10)),
+            _ => None,
+        };
+
+        let first = collect_batch(&symbols, &mut sent, lookup);
+        assert_eq!(first.len(), 1);
+        assert!(collect_batch(&symbols, &mut sent, lookup).is_empty());
+
+        let updated = collect_batch(&symbols, &mut sent, |_| Some(price(151.0, 11)));
+        assert_eq!(updated.len(), 2);
+    }
+}
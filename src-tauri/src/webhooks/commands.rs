@@ -1,9 +1,18 @@
+use super::events::{WebhookEvent, WebhookEventMatch};
 use super::manager::WebhookManager;
-use super::types::{WebhookConfig, WebhookDeliveryLog, WebhookError, WebhookTestResult};
+use super::types::{
+    WebhookConfig, WebhookDeadLetter, WebhookDeliveryLog, WebhookDeliveryStats, WebhookError,
+    WebhookTestResult,
+};
+use crate::config::entity_versions::VersionedEditError;
+use crate::config::recycle_bin::{
+    discard_recycled, recycle_deleted, undo_unavailable, RecycledEntityKind,
+};
+use chrono::{DateTime, Utc};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tauri::State;
+use tauri::{AppHandle, State};
 use tokio::sync::RwLock;
 
 pub type SharedWebhookManager = Arc<RwLock<WebhookManager>>;
@@ -39,20 +48,28 @@ pub async fn update_webhook(
     manager: State<'_, SharedWebhookManager>,
     id: String,
     config: WebhookConfig,
-) -> Result<(), String> {
+) -> Result<WebhookConfig, VersionedEditError> {
     let mgr = manager.read().await;
-    mgr.update_webhook(&id, config)
-        .await
-        .map_err(|e| e.to_string())
+    Ok(mgr.update_webhook(&id, config).await?)
 }
 
 #[tauri::command]
 pub async fn delete_webhook(
+    app_handle: AppHandle,
     manager: State<'_, SharedWebhookManager>,
     id: String,
 ) -> Result<(), String> {
     let mgr = manager.read().await;
-    mgr.delete_webhook(&id).await.map_err(|e| e.to_string())
+    let config = mgr.get_webhook(&id).await.map_err(|e| e.to_string())?;
+    let recycled =
+        recycle_deleted(&app_handle, RecycledEntityKind::Webhook, &id, &config.name, &config)
+            .await
+            .map_err(undo_unavailable)?;
+    if let Err(err) = mgr.delete_webhook(&id).await {
+        discard_recycled(&app_handle, &recycled.id).await;
+        return Err(err.to_string());
+    }
+    Ok(())
 }
 
 #[tauri::command]
@@ -90,3 +107,67 @@ pub async fn list_webhook_delivery_logs(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+pub async fn list_webhook_dead_letters(
+    manager: State<'_, SharedWebhookManager>,
+    webhook_id: Option<String>,
+    include_closed: Option<bool>,
+) -> Result<Vec<WebhookDeadLetter>, String> {
+    let mgr = manager.read().await;
+    mgr.list_dead_letters(webhook_id.as_deref(), include_closed.unwrap_or(false))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn replay_failed_delivery(
+    manager: State<'_, SharedWebhookManager>,
+    id: String,
+) -> Result<WebhookDeliveryLog, String> {
+    let mgr = manager.read().await;
+    mgr.replay_failed_delivery(&id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn discard_webhook_dead_letter(
+    manager: State<'_, SharedWebhookManager>,
+    id: String,
+) -> Result<(), String> {
+    let mgr = manager.read().await;
+    mgr.discard_dead_letter(&id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_webhook_delivery_stats(
+    manager: State<'_, SharedWebhookManager>,
+    webhook_id: Option<String>,
+    since: Option<DateTime<Utc>>,
+) -> Result<Vec<WebhookDeliveryStats>, String> {
+    let mgr = manager.read().await;
+    mgr.delivery_stats(webhook_id.as_deref(), since)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn dispatch_webhook_event(
+    manager: State<'_, SharedWebhookManager>,
+    event: WebhookEvent,
+) -> Result<Vec<WebhookDeliveryLog>, String> {
+    let mgr = manager.read().await;
+    mgr.dispatch_event(&event).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn preview_webhook_event(
+    manager: State<'_, SharedWebhookManager>,
+    event: WebhookEvent,
+) -> Result<Vec<WebhookEventMatch>, String> {
+    let mgr = manager.read().await;
+    mgr.preview_event(&event).await.map_err(|e| e.to_string())
+}
@@ -0,0 +1,287 @@
+use super::commands::SharedWebhookManager;
+use crate::core::message_queue::{enqueue_message, QueueTopic};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use tauri::{AppHandle, Manager};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventType {
+    PriceAlert,
+    Trade,
+    WalletActivity,
+    SmartMoney,
+    TokenUnlock,
+    CoinDetected,
+    System,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum EventSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEvent {
+    pub event_type: WebhookEventType,
+    pub severity: EventSeverity,
+    pub title: String,
+    pub message: String,
+    #[serde(default)]
+    pub token_address: Option<String>,
+    /// USD value of the trade, only meaningful for trade-like events.
+    #[serde(default)]
+    pub notional_usd: Option<f64>,
+    #[serde(default)]
+    pub data: Value,
+    #[serde(default = "Utc::now")]
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl WebhookEvent {
+    pub fn new(
+        event_type: WebhookEventType,
+        severity: EventSeverity,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            event_type,
+            severity,
+            title: title.into(),
+            message: message.into(),
+            token_address: None,
+            notional_usd: None,
+            data: Value::Null,
+            occurred_at: Utc::now(),
+        }
+    }
+
+    pub fn with_token(mut self, token_address: impl Into<String>) -> Self {
+        self.token_address = Some(token_address.into());
+        self
+    }
+
+    pub fn with_notional(mut self, notional_usd: f64) -> Self {
+        self.notional_usd = Some(notional_usd);
+        self
+    }
+
+    pub fn with_data(mut self, data: Value) -> Self {
+        self.data = data;
+        self
+    }
+
+    fn is_trade(&self) -> bool {
+        matches!(
+            self.event_type,
+            WebhookEventType::Trade | WebhookEventType::WalletActivity
+        )
+    }
+
+    /// Template variables for the webhook body: the event fields plus `data`.
+    pub fn variables(&self) -> HashMap<String, Value> {
+        let mut variables = HashMap::new();
+        if let Value::Object(data) = &self.data {
+            variables.extend(data.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        if let Ok(Value::Object(fields)) = serde_json::to_value(self) {
+            variables.extend(fields.into_iter().filter(|(k, _)| k != "data"));
+        }
+        variables
+    }
+}
+
+/// Which events a webhook receives. With no event types selected the webhook is
+/// only ever triggered manually, so existing endpoints are not flooded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookSubscription {
+    #[serde(default)]
+    pub event_types: Vec<WebhookEventType>,
+    /// Empty means any token.
+    #[serde(default)]
+    pub token_allowlist: Vec<String>,
+    #[serde(default)]
+    pub min_severity: Option<EventSeverity>,
+    /// Applies to trade and wallet activity events; those without a known
+    /// notional are dropped when set.
+    #[serde(default)]
+    pub min_trade_notional_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterRejection {
+    EventType,
+    Token,
+    Severity,
+    Notional,
+}
+
+impl WebhookSubscription {
+    pub fn rejection(&self, event: &WebhookEvent) -> Option<FilterRejection> {
+        if !self.event_types.contains(&event.event_type) {
+            return Some(FilterRejection::EventType);
+        }
+
+        if !self.token_allowlist.is_empty() {
+            let allowed = event.token_address.as_ref().is_some_and(|token| {
+                self.token_allowlist
+                    .iter()
+                    .any(|allowed| allowed.trim() == token)
+            });
+            if !allowed {
+                return Some(FilterRejection::Token);
+            }
+        }
+
+        if self.min_severity.is_some_and(|min| event.severity < min) {
+            return Some(FilterRejection::Severity);
+        }
+
+        if let Some(min) = self.min_trade_notional_usd {
+            if event.is_trade() && event.notional_usd.map_or(true, |notional| notional < min) {
+                return Some(FilterRejection::Notional);
+            }
+        }
+
+        None
+    }
+
+    pub fn matches(&self, event: &WebhookEvent) -> bool {
+        self.rejection(event).is_none()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEventMatch {
+    pub webhook_id: String,
+    pub webhook_name: String,
+    pub enabled: bool,
+    pub rejected_by: Option<FilterRejection>,
+}
+
+/// Hands an event to the delivery queue in the background; callers never wait on
+/// third-party endpoints. Retries and dead-lettering happen in the webhook manager.
+pub fn publish_event(app: &AppHandle, event: WebhookEvent) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        queue_event(&app, event, None).await;
+    });
+}
+
+/// Persists an event for at-least-once delivery. Producers that may fire twice
+/// for the same occurrence pass a dedupe key so subscribers see it once.
+pub async fn queue_event(app: &AppHandle, event: WebhookEvent, dedupe_key: Option<&str>) {
+    let payload = match serde_json::to_value(&event) {
+        Ok(payload) => payload,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to serialize webhook event");
+            return;
+        }
+    };
+    if enqueue_message(QueueTopic::Webhook, dedupe_key, payload)
+        .await
+        .is_ok()
+    {
+        return;
+    }
+
+    // Queue unavailable: fall back to a direct dispatch
+    let Some(manager) = app.try_state::<SharedWebhookManager>() else {
+        return;
+    };
+    let manager = manager.inner().clone();
+
+    tauri::async_runtime::spawn(async move {
+        let manager = manager.read().await;
+        if let Err(err) = manager.dispatch_event(&event).await {
+            tracing::warn!(error = %err, event_type = ?event.event_type, "failed to dispatch webhook event");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(token: &str, notional: Option<f64>) -> WebhookEvent {
+        let event = WebhookEvent::new(
+            WebhookEventType::Trade,
+            EventSeverity::Info,
+            "Order filled",
+            "",
+        )
+        .with_token(token);
+        match notional {
+            Some(notional) => event.with_notional(notional),
+            None => event,
+        }
+    }
+
+    #[test]
+    fn unsubscribed_webhooks_receive_nothing() {
+        let subscription = WebhookSubscription::default();
+        assert_eq!(
+            subscription.rejection(&trade("SOL", Some(1_000.0))),
+            Some(FilterRejection::EventType)
+        );
+    }
+
+    #[test]
+    fn filters_apply_token_severity_and_notional() {
+        let subscription = WebhookSubscription {
+            event_types: vec![WebhookEventType::Trade, WebhookEventType::PriceAlert],
+            token_allowlist: vec!["SOL".into()],
+            min_severity: None,
+            min_trade_notional_usd: Some(500.0),
+        };
+
+        assert!(subscription.matches(&trade("SOL", Some(750.0))));
+        assert_eq!(
+            subscription.rejection(&trade("BONK", Some(750.0))),
+            Some(FilterRejection::Token)
+        );
+        assert_eq!(
+            subscription.rejection(&trade("SOL", Some(100.0))),
+            Some(FilterRejection::Notional)
+        );
+        assert_eq!(
+            subscription.rejection(&trade("SOL", None)),
+            Some(FilterRejection::Notional)
+        );
+
+        let alert = WebhookEvent::new(
+            WebhookEventType::PriceAlert,
+            EventSeverity::Info,
+            "Alert",
+            "",
+        )
+        .with_token("SOL");
+        assert!(subscription.matches(&alert), "notional only gates trades");
+
+        let strict = WebhookSubscription {
+            min_severity: Some(EventSeverity::Warning),
+            ..subscription
+        };
+        assert_eq!(strict.rejection(&alert), Some(FilterRejection::Severity));
+    }
+
+    #[test]
+    fn variables_expose_event_fields_and_data() {
+        let event = trade("SOL", Some(10.0)).with_data(serde_json::json!({ "orderId": "o1" }));
+        let variables = event.variables();
+        assert_eq!(variables["orderId"], "o1");
+        assert_eq!(variables["eventType"], "trade");
+        assert_eq!(variables["tokenAddress"], "SOL");
+        assert!(!variables.contains_key("data"));
+    }
+}
@@ -1,15 +1,20 @@
+use super::events::{WebhookEvent, WebhookEventMatch, WebhookSubscription};
 use super::retry::RetryExecutor;
 use super::template::TemplateEngine;
 use super::types::{
-    DeliveryStatus, RetryPolicy, WebhookConfig, WebhookDeliveryLog, WebhookError, WebhookMethod,
-    WebhookTestResult,
+    DeadLetterStatus, DeliveryStatus, WebhookConfig, WebhookDeadLetter, WebhookDeliveryLog,
+    WebhookDeliveryStats, WebhookError, WebhookMethod, WebhookTestResult,
+};
+use crate::config::entity_versions::{
+    bump_entity_version, entity_version, initialize_entity_versions, VersionBump,
+    VersionConflict,
 };
 use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde_json::Value;
 use sqlx::{Pool, Row, Sqlite, SqlitePool};
 use std::collections::HashMap;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Manager};
 use tokio::sync::Mutex;
 use uuid::Uuid;
@@ -94,15 +99,57 @@ impl WebhookManager {
         .execute(&self.pool)
         .await?;
 
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS webhook_dead_letters (
+                id TEXT PRIMARY KEY,
+                webhook_id TEXT NOT NULL,
+                webhook_name TEXT NOT NULL,
+                delivery_log_id TEXT NOT NULL,
+                variables_json TEXT NOT NULL,
+                attempts INTEGER NOT NULL,
+                last_error TEXT,
+                last_response_code INTEGER,
+                status TEXT NOT NULL,
+                replay_count INTEGER NOT NULL DEFAULT 0,
+                failed_at TEXT NOT NULL,
+                last_replayed_at TEXT,
+                resolved_log_id TEXT
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_webhook_dead_letters_status ON webhook_dead_letters(status, failed_at)",
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS webhook_subscriptions (
+                webhook_id TEXT PRIMARY KEY,
+                filter_json TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        initialize_entity_versions(&self.pool).await?;
+
         Ok(())
     }
 
     pub async fn list_webhooks(&self) -> Result<Vec<WebhookConfig>, WebhookError> {
         let rows = sqlx::query(
             r#"
-            SELECT *
-            FROM webhooks
-            ORDER BY created_at DESC
+            SELECT w.*, s.filter_json
+            FROM webhooks w
+            LEFT JOIN webhook_subscriptions s ON s.webhook_id = w.id
+            ORDER BY w.created_at DESC
             "#,
         )
         .fetch_all(&self.pool)
@@ -110,7 +157,9 @@ impl WebhookManager {
 
         let mut configs = Vec::new();
         for row in rows {
-            configs.push(self.row_to_config(row)?);
+            let mut config = self.row_to_config(row)?;
+            config.version = entity_version(&self.pool, &config.id).await?;
+            configs.push(config);
         }
 
         Ok(configs)
@@ -119,7 +168,10 @@ impl WebhookManager {
     pub async fn get_webhook(&self, id: &str) -> Result<WebhookConfig, WebhookError> {
         let row = sqlx::query(
             r#"
-            SELECT * FROM webhooks WHERE id = ?1
+            SELECT w.*, s.filter_json
+            FROM webhooks w
+            LEFT JOIN webhook_subscriptions s ON s.webhook_id = w.id
+            WHERE w.id = ?1
             "#,
         )
         .bind(id)
@@ -127,24 +179,53 @@ impl WebhookManager {
         .await?
         .ok_or_else(|| WebhookError::NotFound(id.to_string()))?;
 
-        self.row_to_config(row)
+        let mut config = self.row_to_config(row)?;
+        config.version = entity_version(&self.pool, id).await?;
+        Ok(config)
     }
 
     pub async fn create_webhook(&self, mut config: WebhookConfig) -> Result<WebhookConfig, WebhookError> {
+        config.retry_policy.validate()?;
         let now = Utc::now();
         config.id = Uuid::new_v4().to_string();
         config.created_at = now;
         config.updated_at = now;
+        config.version = 1;
 
         self.insert_or_update(&config).await?;
         Ok(config)
     }
 
-    pub async fn update_webhook(&self, id: &str, config: WebhookConfig) -> Result<(), WebhookError> {
-        let mut updated = config.clone();
+    /// Saves an edited webhook. `config.version` is the version the edit was based on;
+    /// if the webhook changed since, the edit is rejected with the stored copy attached.
+    pub async fn update_webhook(
+        &self,
+        id: &str,
+        config: WebhookConfig,
+    ) -> Result<WebhookConfig, WebhookError> {
+        config.retry_policy.validate()?;
+        let current = self.get_webhook(id).await?;
+        let expected = Some(config.version);
+        let version = match bump_entity_version(&self.pool, id, expected).await? {
+            VersionBump::Bumped(version) => version,
+            VersionBump::Stale { latest } => {
+                return Err(VersionConflict::new(
+                    "webhook",
+                    id,
+                    config.version,
+                    latest,
+                    Some(&current),
+                )
+                .into());
+            }
+        };
+
+        let mut updated = config;
         updated.id = id.to_string();
         updated.updated_at = Utc::now();
-        self.insert_or_update(&updated).await
+        updated.version = version;
+        self.insert_or_update(&updated).await?;
+        Ok(updated)
     }
 
     pub async fn delete_webhook(&self, id: &str) -> Result<(), WebhookError> {
@@ -153,9 +234,24 @@ impl WebhookManager {
             .execute(&self.pool)
             .await?;
 
+        sqlx::query("DELETE FROM webhook_subscriptions WHERE webhook_id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
         Ok(())
     }
 
+    /// Writes a deleted webhook back with its original id and subscription.
+    pub async fn restore_webhook(&self, config: WebhookConfig) -> Result<WebhookConfig, WebhookError> {
+        config.retry_policy.validate()?;
+        let mut restored = config;
+        restored.updated_at = Utc::now();
+        self.insert_or_update(&restored).await?;
+        bump_entity_version(&self.pool, &restored.id, None).await?;
+        self.get_webhook(&restored.id).await
+    }
+
     async fn insert_or_update(&self, config: &WebhookConfig) -> Result<(), WebhookError> {
         sqlx::query(
             r#"
@@ -194,6 +290,17 @@ impl WebhookManager {
         .execute(&self.pool)
         .await?;
 
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_subscriptions (webhook_id, filter_json) VALUES (?1, ?2)
+            ON CONFLICT(webhook_id) DO UPDATE SET filter_json = excluded.filter_json
+            "#,
+        )
+        .bind(&config.id)
+        .bind(serde_json::to_string(&config.subscription).map_err(WebhookError::Serialization)?)
+        .execute(&self.pool)
+        .await?;
+
         Ok(())
     }
 
@@ -202,6 +309,10 @@ impl WebhookManager {
         let headers_json: String = row.try_get("headers_json")?;
         let variables_json: String = row.try_get("variables_json")?;
         let retry_policy_json: String = row.try_get("retry_policy_json")?;
+        let subscription = match row.try_get::<Option<String>, _>("filter_json")? {
+            Some(json) => serde_json::from_str(&json).map_err(WebhookError::Serialization)?,
+            None => WebhookSubscription::default(),
+        };
 
         Ok(WebhookConfig {
             id: row.try_get("id")?,
@@ -219,12 +330,14 @@ impl WebhookManager {
             enabled: row.try_get::<i64, _>("enabled")? == 1,
             retry_policy: serde_json::from_str(&retry_policy_json)
                 .map_err(WebhookError::Serialization)?,
+            subscription,
             created_at: DateTime::parse_from_rfc3339(&row.try_get::<String, _>("created_at")?)
                 .map_err(|e| WebhookError::Internal(format!("Invalid timestamp: {}", e)))?
                 .with_timezone(&Utc),
             updated_at: DateTime::parse_from_rfc3339(&row.try_get::<String, _>("updated_at")?)
                 .map_err(|e| WebhookError::Internal(format!("Invalid timestamp: {}", e)))?
                 .with_timezone(&Utc),
+            version: 1,
         })
     }
 
@@ -278,10 +391,14 @@ impl WebhookManager {
                 _ => DeliveryStatus::Failed,
             },
             attempt: row.try_get("attempt")?,
-            response_code: row.try_get("response_code"),
-            response_time_ms: row.try_get("response_time_ms"),
-            error: row.try_get("error"),
-            payload_preview: row.try_get("payload_preview"),
+            response_code: row
+                .try_get::<Option<i64>, _>("response_code")?
+                .map(|code| code as u16),
+            response_time_ms: row
+                .try_get::<Option<i64>, _>("response_time_ms")?
+                .map(|ms| ms as u128),
+            error: row.try_get("error")?,
+            payload_preview: row.try_get("payload_preview")?,
             triggered_at: DateTime::parse_from_rfc3339(&row.try_get::<String, _>("triggered_at")?)
                 .map_err(|e| WebhookError::Internal(format!("Invalid timestamp: {}", e)))?
                 .with_timezone(&Utc),
@@ -304,7 +421,64 @@ impl WebhookManager {
             return Err(WebhookError::Disabled);
         }
 
-        self.send_with_retries(&config, variables).await
+        self.deliver(&config, &variables).await
+    }
+
+    /// Sends an event to every enabled webhook whose subscription accepts it.
+    /// A failing endpoint is dead-lettered and does not stop the others.
+    pub async fn dispatch_event(
+        &self,
+        event: &WebhookEvent,
+    ) -> Result<Vec<WebhookDeliveryLog>, WebhookError> {
+        let variables = event.variables();
+        let mut logs = Vec::new();
+
+        for config in self.list_webhooks().await? {
+            if !config.enabled || !config.subscription.matches(event) {
+                continue;
+            }
+
+            match self.deliver(&config, &variables).await {
+                Ok(log) => logs.push(log),
+                Err(err) => tracing::warn!(
+                    error = %err,
+                    webhook_id = %config.id,
+                    "webhook event delivery failed"
+                ),
+            }
+        }
+
+        Ok(logs)
+    }
+
+    /// Reports which webhooks would receive an event, and why the others would not.
+    pub async fn preview_event(
+        &self,
+        event: &WebhookEvent,
+    ) -> Result<Vec<WebhookEventMatch>, WebhookError> {
+        Ok(self
+            .list_webhooks()
+            .await?
+            .into_iter()
+            .map(|config| WebhookEventMatch {
+                rejected_by: config.subscription.rejection(event),
+                webhook_id: config.id,
+                webhook_name: config.name,
+                enabled: config.enabled,
+            })
+            .collect())
+    }
+
+    async fn deliver(
+        &self,
+        config: &WebhookConfig,
+        variables: &HashMap<String, Value>,
+    ) -> Result<WebhookDeliveryLog, WebhookError> {
+        let log = self.send_with_retries(config, variables).await?;
+        if log.status == DeliveryStatus::Failed {
+            self.dead_letter(config, &log, variables).await?;
+        }
+        Ok(log)
     }
 
     pub async fn test_webhook(
@@ -313,85 +487,62 @@ impl WebhookManager {
         variables: HashMap<String, Value>,
     ) -> Result<WebhookTestResult, WebhookError> {
         let config = self.get_webhook(id).await?;
-        let result = self
-            .send_once(&config, variables, true, 1)
-            .await?;
-
-        Ok(result)
+        self.send_once(&config, &variables).await
     }
 
+    /// Delivers once per attempt allowed by the webhook's policy, keeping a single
+    /// log row that tracks the current attempt and ends as sent or failed.
     async fn send_with_retries(
         &self,
         config: &WebhookConfig,
-        variables: HashMap<String, Value>,
+        variables: &HashMap<String, Value>,
     ) -> Result<WebhookDeliveryLog, WebhookError> {
         let executor = RetryExecutor::new(config.retry_policy.clone());
         let log_id = Uuid::new_v4().to_string();
         let triggered_at = Utc::now();
-
-        let mut last_log = None;
-        let payload_preview = self.preview_payload(config, &variables)?;
+        let payload_preview = self.preview_payload(config, variables)?;
 
         let _guard = self.sending_lock.lock().await;
 
+        let mut last_attempt = 0;
         let result = executor
-            .execute(|| {
-                let config = config.clone();
-                let variables = variables.clone();
-                let payload_preview = payload_preview.clone();
+            .execute_with_attempt(|attempt| {
+                last_attempt = attempt;
                 let log_id = log_id.clone();
+                let payload_preview = payload_preview.clone();
                 async move {
                     self.log_status(
                         &log_id,
-                        &config,
+                        config,
                         DeliveryStatus::Retrying,
-                        0,
+                        attempt,
+                        None,
                         None,
                         None,
-                        Some("Scheduling retry"),
                         Some(&payload_preview),
                         triggered_at,
                         None,
                     )
                     .await?;
 
-                    let attempt = 1; // actual attempt tracked inside send_once
-                    match self
-                        .send_once(&config, variables.clone(), false, attempt)
-                        .await
-                    {
-                        Ok(test_result) => {
-                            self.log_status(
-                                &log_id,
-                                &config,
-                                DeliveryStatus::Sent,
-                                attempt,
-                                test_result.response_code,
-                                test_result.latency_ms,
-                                None,
-                                Some(&payload_preview),
-                                triggered_at,
-                                Some(Utc::now()),
-                            )
-                            .await?;
-
-                            Ok(self
-                                .get_delivery_log(&log_id)
-                                .await
-                                .expect("log should exist"))
-                        }
+                    match self.send_once(config, variables).await {
+                        Ok(result) => Ok((attempt, result)),
                         Err(err) => {
+                            let response_code = match &err {
+                                WebhookError::HttpStatus(code) => Some(*code),
+                                _ => None,
+                            };
                             self.log_status(
                                 &log_id,
-                                &config,
-                                DeliveryStatus::Failed,
+                                config,
+                                DeliveryStatus::Retrying,
                                 attempt,
-                                None,
+                                response_code,
                                 None,
                                 Some(&err.to_string()),
                                 Some(&payload_preview),
                                 triggered_at,
-                                Some(Utc::now()),
+                                None,
                             )
                             .await?;
                             Err(err)
@@ -402,45 +553,68 @@ impl WebhookManager {
             .await;
 
         match result {
-            Ok(log) => Ok(log),
+            Ok((attempt, result)) => {
+                self.log_status(
+                    &log_id,
+                    config,
+                    DeliveryStatus::Sent,
+                    attempt,
+                    result.response_code,
+                    result.latency_ms,
+                    None,
+                    Some(&payload_preview),
+                    triggered_at,
+                    Some(Utc::now()),
+                )
+                .await?;
+            }
             Err(err) => {
-                last_log = self.get_delivery_log(&log_id).await.ok();
-                if let Some(mut log) = last_log {
-                    log.status = DeliveryStatus::Failed;
-                    log.error = Some(err.to_string());
-                    Ok(log)
-                } else {
-                    Err(err)
-                }
+                let response_code = match &err {
+                    WebhookError::HttpStatus(code) => Some(*code),
+                    _ => None,
+                };
+                self.log_status(
+                    &log_id,
+                    config,
+                    DeliveryStatus::Failed,
+                    last_attempt,
+                    response_code,
+                    None,
+                    Some(&err.to_string()),
+                    Some(&payload_preview),
+                    triggered_at,
+                    Some(Utc::now()),
+                )
+                .await?;
             }
         }
+
+        self.get_delivery_log(&log_id).await
     }
 
     async fn send_once(
         &self,
         config: &WebhookConfig,
-        variables: HashMap<String, Value>,
-        test_only: bool,
-        attempt: u32,
+        variables: &HashMap<String, Value>,
     ) -> Result<WebhookTestResult, WebhookError> {
         let mut request_builder = match config.method {
             WebhookMethod::Get => self.client.get(&config.url),
             WebhookMethod::Post => self.client.post(&config.url),
         };
+        request_builder =
+            request_builder.timeout(Duration::from_secs(config.retry_policy.timeout_secs));
 
         for (key, value) in &config.headers {
             request_builder = request_builder.header(key, value);
         }
 
-        let payload_preview = self.preview_payload(config, &variables)?;
-
         if let Some(body_template) = &config.body_template {
-            let rendered = self.template_engine.render(body_template, &variables)?;
+            let rendered = self.template_engine.render(body_template, variables)?;
             let json: Value = serde_json::from_str(&rendered)
                 .map_err(|e| WebhookError::InvalidTemplate(e.to_string()))?;
             request_builder = request_builder.json(&json);
         } else if config.method == WebhookMethod::Post {
-            request_builder = request_builder.json(&variables);
+            request_builder = request_builder.json(variables);
         }
 
         let start = Instant::now();
@@ -450,36 +624,254 @@ impl WebhookManager {
         let text = response.text().await.ok();
 
         if status.is_success() {
-            if !test_only {
-                self.log_status(
-                    Uuid::new_v4().to_string().as_str(),
-                    config,
-                    DeliveryStatus::Sent,
-                    attempt,
-                    Some(status.as_u16()),
-                    Some(latency),
-                    None,
-                    Some(&payload_preview),
-                    Utc::now(),
-                    Some(Utc::now()),
-                )
-                .await?;
-            }
-
             Ok(WebhookTestResult {
                 success: true,
                 message: "Webhook delivered successfully".to_string(),
                 response_code: Some(status.as_u16()),
-                response_body: text.clone(),
+                response_body: text,
                 latency_ms: Some(latency),
             })
         } else {
-            let error_message = format!("Webhook failed with status {}", status);
-            Err(WebhookError::Internal(error_message))
+            Err(WebhookError::HttpStatus(status.as_u16()))
         }
     }
 
-    fn preview_payload(
+    async fn dead_letter(
+        &self,
+        config: &WebhookConfig,
+        log: &WebhookDeliveryLog,
+        variables: &HashMap<String, Value>,
+    ) -> Result<(), WebhookError> {
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_dead_letters (
+                id, webhook_id, webhook_name, delivery_log_id, variables_json, attempts,
+                last_error, last_response_code, status, replay_count, failed_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 'pending', 0, ?9)
+            "#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(&config.id)
+        .bind(&config.name)
+        .bind(&log.id)
+        .bind(serde_json::to_string(variables).map_err(WebhookError::Serialization)?)
+        .bind(log.attempt as i64)
+        .bind(&log.error)
+        .bind(log.response_code.map(|code| code as i64))
+        .bind(log.completed_at.unwrap_or_else(Utc::now).to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_dead_letters(
+        &self,
+        webhook_id: Option<&str>,
+        include_closed: bool,
+    ) -> Result<Vec<WebhookDeadLetter>, WebhookError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM webhook_dead_letters
+            WHERE (?1 IS NULL OR webhook_id = ?1)
+              AND (?2 = 1 OR status = 'pending')
+            ORDER BY failed_at DESC
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(if include_closed { 1 } else { 0 })
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_dead_letter(row))
+            .collect()
+    }
+
+    async fn get_dead_letter(&self, id: &str) -> Result<WebhookDeadLetter, WebhookError> {
+        let row = sqlx::query("SELECT * FROM webhook_dead_letters WHERE id = ?1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| WebhookError::NotFound(id.to_string()))?;
+
+        self.row_to_dead_letter(row)
+    }
+
+    /// Re-sends a dead-lettered delivery with the webhook's current config and
+    /// retry policy. The entry is resolved on success and stays pending otherwise.
+    pub async fn replay_failed_delivery(
+        &self,
+        id: &str,
+    ) -> Result<WebhookDeliveryLog, WebhookError> {
+        let letter = self.get_dead_letter(id).await?;
+        match letter.status {
+            DeadLetterStatus::Pending => {}
+            DeadLetterStatus::Resolved => {
+                return Err(WebhookError::DeadLetterClosed("resolved".to_string()))
+            }
+            DeadLetterStatus::Discarded => {
+                return Err(WebhookError::DeadLetterClosed("discarded".to_string()))
+            }
+        }
+
+        let config = self.get_webhook(&letter.webhook_id).await?;
+        if !config.enabled {
+            return Err(WebhookError::Disabled);
+        }
+
+        let log = self.send_with_retries(&config, &letter.variables).await?;
+        let resolved = log.status == DeliveryStatus::Sent;
+
+        sqlx::query(
+            r#"
+            UPDATE webhook_dead_letters SET
+                status = ?2,
+                replay_count = replay_count + 1,
+                attempts = attempts + ?3,
+                last_error = COALESCE(?4, last_error),
+                last_response_code = COALESCE(?5, last_response_code),
+                last_replayed_at = ?6,
+                resolved_log_id = ?7
+            WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .bind(if resolved { "resolved" } else { "pending" })
+        .bind(log.attempt as i64)
+        .bind(&log.error)
+        .bind(log.response_code.map(|code| code as i64))
+        .bind(Utc::now().to_rfc3339())
+        .bind(resolved.then(|| log.id.clone()))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(log)
+    }
+
+    pub async fn discard_dead_letter(&self, id: &str) -> Result<(), WebhookError> {
+        let result = sqlx::query(
+            "UPDATE webhook_dead_letters SET status = 'discarded' WHERE id = ?1 AND status = 'pending'",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            self.get_dead_letter(id).await?;
+            return Err(WebhookError::DeadLetterClosed("closed".to_string()));
+        }
+        Ok(())
+    }
+
+    fn row_to_dead_letter(
+        &self,
+        row: sqlx::sqlite::SqliteRow,
+    ) -> Result<WebhookDeadLetter, WebhookError> {
+        let parse_ts = |value: String| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| WebhookError::Internal(format!("Invalid timestamp: {}", e)))
+        };
+        let variables_json: String = row.try_get("variables_json")?;
+
+        Ok(WebhookDeadLetter {
+            id: row.try_get("id")?,
+            webhook_id: row.try_get("webhook_id")?,
+            webhook_name: row.try_get("webhook_name")?,
+            delivery_log_id: row.try_get("delivery_log_id")?,
+            variables: serde_json::from_str(&variables_json)
+                .map_err(WebhookError::Serialization)?,
+            attempts: row.try_get::<i64, _>("attempts")? as u32,
+            last_error: row.try_get("last_error")?,
+            last_response_code: row
+                .try_get::<Option<i64>, _>("last_response_code")?
+                .map(|code| code as u16),
+            status: match row.try_get::<String, _>("status")?.as_str() {
+                "resolved" => DeadLetterStatus::Resolved,
+                "discarded" => DeadLetterStatus::Discarded,
+                _ => DeadLetterStatus::Pending,
+            },
+            replay_count: row.try_get::<i64, _>("replay_count")? as u32,
+            failed_at: parse_ts(row.try_get("failed_at")?)?,
+            last_replayed_at: row
+                .try_get::<Option<String>, _>("last_replayed_at")?
+                .map(parse_ts)
+                .transpose()?,
+            resolved_log_id: row.try_get("resolved_log_id")?,
+        })
+    }
+
+    /// Success rate and latency per webhook over finished deliveries, optionally
+    /// limited to those triggered since `since`.
+    pub async fn delivery_stats(
+        &self,
+        webhook_id: Option<&str>,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<WebhookDeliveryStats>, WebhookError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                w.id AS webhook_id,
+                w.name AS webhook_name,
+                w.url AS url,
+                COUNT(l.id) AS total,
+                COALESCE(SUM(CASE WHEN l.status = 'sent' THEN 1 ELSE 0 END), 0) AS sent,
+                COALESCE(SUM(CASE WHEN l.status = 'failed' THEN 1 ELSE 0 END), 0) AS failed,
+                AVG(l.attempt) AS avg_attempts,
+                AVG(CASE WHEN l.status = 'sent' THEN l.response_time_ms END) AS avg_response_ms,
+                MAX(l.triggered_at) AS last_delivery_at,
+                (SELECT COUNT(*) FROM webhook_dead_letters d
+                    WHERE d.webhook_id = w.id AND d.status = 'pending') AS pending_dead_letters
+            FROM webhooks w
+            LEFT JOIN webhook_delivery_logs l
+                ON l.webhook_id = w.id
+                AND l.status IN ('sent', 'failed')
+                AND (?2 IS NULL OR l.triggered_at >= ?2)
+            WHERE (?1 IS NULL OR w.id = ?1)
+            GROUP BY w.id
+            ORDER BY w.name
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(since.map(|dt| dt.to_rfc3339()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let total = row.try_get::<i64, _>("total")? as u64;
+                let sent = row.try_get::<i64, _>("sent")? as u64;
+                Ok(WebhookDeliveryStats {
+                    webhook_id: row.try_get("webhook_id")?,
+                    webhook_name: row.try_get("webhook_name")?,
+                    url: row.try_get("url")?,
+                    total_deliveries: total,
+                    successful_deliveries: sent,
+                    failed_deliveries: row.try_get::<i64, _>("failed")? as u64,
+                    success_rate: success_rate(sent, total),
+                    average_attempts: row
+                        .try_get::<Option<f64>, _>("avg_attempts")?
+                        .unwrap_or(0.0),
+                    average_response_time_ms: row.try_get("avg_response_ms")?,
+                    pending_dead_letters: row.try_get::<i64, _>("pending_dead_letters")? as u64,
+                    last_delivery_at: row
+                        .try_get::<Option<String>, _>("last_delivery_at")?
+                        .map(|ts| {
+                            DateTime::parse_from_rfc3339(&ts)
+                                .map(|dt| dt.with_timezone(&Utc))
+                                .map_err(|e| {
+                                    WebhookError::Internal(format!("Invalid timestamp: {}", e))
+                                })
+                        })
+                        .transpose()?,
+                })
+            })
+            .collect()
+    }
+
+    /// The request body a webhook would send for these variables.
+    pub fn preview_payload(
         &self,
         config: &WebhookConfig,
         variables: &HashMap<String, Value>,
@@ -553,3 +945,12 @@ impl WebhookManager {
         self.row_to_log(row)
     }
 }
+
+/// Percentage of finished deliveries that succeeded; 100% when nothing was sent.
+pub fn success_rate(successful: u64, total: u64) -> f64 {
+    if total == 0 {
+        100.0
+    } else {
+        successful as f64 / total as f64 * 100.0
+    }
+}
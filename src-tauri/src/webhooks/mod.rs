@@ -3,7 +3,9 @@ pub mod manager;
 pub mod template;
 pub mod retry;
 pub mod commands;
+pub mod events;
 
 pub use manager::WebhookManager;
 pub use types::*;
 pub use commands::*;
+pub use events::*;
@@ -1,4 +1,4 @@
-use super::types::{RetryPolicy, WebhookError};
+use super::types::{BackoffCurve, RetryPolicy, WebhookError};
 use rand::Rng;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -16,11 +16,20 @@ impl RetryExecutor {
     where
         F: FnMut() -> Fut,
         Fut: std::future::Future<Output = Result<T, WebhookError>>,
+    {
+        self.execute_with_attempt(|_| operation()).await
+    }
+
+    /// Like `execute`, but hands the 1-based attempt number to the operation.
+    pub async fn execute_with_attempt<F, Fut, T>(&self, mut operation: F) -> Result<T, WebhookError>
+    where
+        F: FnMut(u32) -> Fut,
+        Fut: std::future::Future<Output = Result<T, WebhookError>>,
     {
         let mut last_error = None;
 
         for attempt in 1..=self.policy.max_attempts {
-            match operation().await {
+            match operation(attempt).await {
                 Ok(result) => return Ok(result),
                 Err(err) => {
                     last_error = Some(err);
@@ -39,9 +48,7 @@ impl RetryExecutor {
     }
 
     fn calculate_delay(&self, attempt: u32) -> Duration {
-        let base_delay = Duration::from_secs(self.policy.base_delay_secs);
-        let multiplier = 2u64.pow(attempt - 1);
-        let delay_secs = (base_delay.as_secs() * multiplier).min(self.policy.max_delay_secs);
+        let delay_secs = backoff_delay_secs(&self.policy, attempt);
 
         let delay = if self.policy.jitter {
             let mut rng = rand::thread_rng();
@@ -56,6 +63,20 @@ impl RetryExecutor {
     }
 }
 
+/// Delay before the retry that follows `attempt`, capped at the policy maximum
+/// and before jitter is applied.
+pub fn backoff_delay_secs(policy: &RetryPolicy, attempt: u32) -> u64 {
+    let base = policy.base_delay_secs;
+    let delay = match policy.backoff {
+        BackoffCurve::Exponential => {
+            base.saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)))
+        }
+        BackoffCurve::Linear => base.saturating_mul(attempt as u64),
+        BackoffCurve::Fixed => base,
+    };
+    delay.min(policy.max_delay_secs)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,6 +90,7 @@ mod tests {
             base_delay_secs: 1,
             max_delay_secs: 10,
             jitter: false,
+            ..RetryPolicy::default()
         };
         let executor = RetryExecutor::new(policy);
 
@@ -87,6 +109,7 @@ mod tests {
             base_delay_secs: 1,
             max_delay_secs: 10,
             jitter: false,
+            ..RetryPolicy::default()
         };
         let executor = RetryExecutor::new(policy);
         let counter = Arc::new(AtomicU32::new(0));
@@ -117,6 +140,7 @@ mod tests {
             base_delay_secs: 1,
             max_delay_secs: 10,
             jitter: false,
+            ..RetryPolicy::default()
         };
         let executor = RetryExecutor::new(policy);
         let counter = Arc::new(AtomicU32::new(0));
@@ -142,6 +166,7 @@ mod tests {
             base_delay_secs: 2,
             max_delay_secs: 60,
             jitter: false,
+            ..RetryPolicy::default()
         };
         let executor = RetryExecutor::new(policy);
 
@@ -161,10 +186,30 @@ mod tests {
             base_delay_secs: 2,
             max_delay_secs: 10,
             jitter: false,
+            ..RetryPolicy::default()
         };
         let executor = RetryExecutor::new(policy);
 
         let delay = executor.calculate_delay(10);
         assert!(delay.as_secs() <= 10);
     }
+
+    #[test]
+    fn test_backoff_curves() {
+        let mut policy = RetryPolicy {
+            base_delay_secs: 3,
+            max_delay_secs: 20,
+            jitter: false,
+            backoff: BackoffCurve::Linear,
+            ..RetryPolicy::default()
+        };
+        let delays: Vec<u64> = (1..=4).map(|a| backoff_delay_secs(&policy, a)).collect();
+        assert_eq!(delays, vec![3, 6, 9, 12]);
+
+        policy.backoff = BackoffCurve::Fixed;
+        assert_eq!(backoff_delay_secs(&policy, 4), 3);
+
+        policy.backoff = BackoffCurve::Exponential;
+        assert_eq!(backoff_delay_secs(&policy, 40), 20);
+    }
 }
@@ -1,10 +1,12 @@
+use super::events::WebhookSubscription;
+use crate::config::entity_versions::{VersionConflict, VersionedEditError};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub type WebhookHeaders = HashMap<String, String>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum WebhookMethod {
     Get,
@@ -33,6 +35,20 @@ pub struct WebhookConfig {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub retry_policy: RetryPolicy,
+    #[serde(default)]
+    pub subscription: WebhookSubscription,
+    /// Incremented on every edit; updates carrying an older version are rejected.
+    #[serde(default)]
+    pub version: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BackoffCurve {
+    #[default]
+    Exponential,
+    Linear,
+    Fixed,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +58,15 @@ pub struct RetryPolicy {
     pub base_delay_secs: u64,
     pub max_delay_secs: u64,
     pub jitter: bool,
+    #[serde(default)]
+    pub backoff: BackoffCurve,
+    /// Per-attempt request timeout.
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_timeout_secs() -> u64 {
+    10
 }
 
 impl Default for RetryPolicy {
@@ -51,10 +76,38 @@ impl Default for RetryPolicy {
             base_delay_secs: 2,
             max_delay_secs: 60,
             jitter: true,
+            backoff: BackoffCurve::Exponential,
+            timeout_secs: default_timeout_secs(),
         }
     }
 }
 
+impl RetryPolicy {
+    pub const MAX_ATTEMPTS: u32 = 20;
+    pub const MAX_TIMEOUT_SECS: u64 = 120;
+
+    pub fn validate(&self) -> Result<(), WebhookError> {
+        if self.max_attempts == 0 || self.max_attempts > Self::MAX_ATTEMPTS {
+            return Err(WebhookError::InvalidPolicy(format!(
+                "max attempts must be between 1 and {}",
+                Self::MAX_ATTEMPTS
+            )));
+        }
+        if self.timeout_secs == 0 || self.timeout_secs > Self::MAX_TIMEOUT_SECS {
+            return Err(WebhookError::InvalidPolicy(format!(
+                "timeout must be between 1 and {} seconds",
+                Self::MAX_TIMEOUT_SECS
+            )));
+        }
+        if self.base_delay_secs > self.max_delay_secs {
+            return Err(WebhookError::InvalidPolicy(
+                "base delay cannot exceed max delay".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WebhookDeliveryLog {
@@ -80,6 +133,50 @@ pub enum DeliveryStatus {
     Retrying,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeadLetterStatus {
+    Pending,
+    Resolved,
+    Discarded,
+}
+
+/// A delivery that exhausted its retry policy, kept with its variables so it
+/// can be replayed by hand once the endpoint is fixed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDeadLetter {
+    pub id: String,
+    pub webhook_id: String,
+    pub webhook_name: String,
+    pub delivery_log_id: String,
+    pub variables: HashMap<String, serde_json::Value>,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub last_response_code: Option<u16>,
+    pub status: DeadLetterStatus,
+    pub replay_count: u32,
+    pub failed_at: DateTime<Utc>,
+    pub last_replayed_at: Option<DateTime<Utc>>,
+    pub resolved_log_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDeliveryStats {
+    pub webhook_id: String,
+    pub webhook_name: String,
+    pub url: String,
+    pub total_deliveries: u64,
+    pub successful_deliveries: u64,
+    pub failed_deliveries: u64,
+    pub success_rate: f64,
+    pub average_attempts: f64,
+    pub average_response_time_ms: Option<f64>,
+    pub pending_dead_letters: u64,
+    pub last_delivery_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WebhookTestResult {
@@ -106,6 +203,23 @@ pub enum WebhookError {
     NotFound(String),
     #[error("webhook disabled")]
     Disabled,
+    #[error("webhook failed with status {0}")]
+    HttpStatus(u16),
+    #[error("invalid retry policy: {0}")]
+    InvalidPolicy(String),
+    #[error("dead letter already {0}")]
+    DeadLetterClosed(String),
+    #[error(transparent)]
+    Conflict(#[from] VersionConflict),
     #[error("internal error: {0}")]
     Internal(String),
 }
+
+impl From<WebhookError> for VersionedEditError {
+    fn from(error: WebhookError) -> Self {
+        match error {
+            WebhookError::Conflict(conflict) => VersionedEditError::Conflict(conflict),
+            other => VersionedEditError::Message(other.to_string()),
+        }
+    }
+}
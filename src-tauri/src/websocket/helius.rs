@@ -146,6 +146,11 @@ impl HeliusStream {
                         self.process_message(value).await;
                     }
                 }
+                Ok(Message::Pong(_)) => {
+                    // Answers to our keepalive pings keep quiet feeds from
+                    // looking stalled to the watchdog
+                    self.update_last_message().await;
+                }
                 Ok(Message::Ping(_)) => {
                     let mut writer = write.lock().await;
                     if let Err(e) = writer.send(Message::Pong(vec![])).await {
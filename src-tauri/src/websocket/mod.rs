@@ -2,8 +2,10 @@ pub mod birdeye;
 pub mod helius;
 pub mod reconnect;
 pub mod types;
+pub mod watchdog;
 
 pub use birdeye::*;
 pub use helius::*;
 pub use reconnect::*;
 pub use types::*;
+pub use watchdog::*;
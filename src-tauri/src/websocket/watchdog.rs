@@ -0,0 +1,200 @@
+use crate::websocket::types::StreamProvider;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+pub const STREAM_STALL_EVENT: &str = "stream_stall_detected";
+const RECENT_INCIDENT_CAPACITY: usize = 20;
+
+/// Longest silence tolerated before a stream counts as stalled. Both sockets
+/// are pinged every 30s and pongs count as traffic, so a healthy connection is
+/// never quiet for two ping intervals even when no topic has updates.
+pub fn heartbeat_timeout(provider: &StreamProvider) -> Duration {
+    match provider {
+        StreamProvider::Birdeye => Duration::from_secs(60),
+        StreamProvider::Helius => Duration::from_secs(90),
+    }
+}
+
+/// Chart streams read prices the socket feeds into the price engine, so a
+/// chart whose price stops moving for this long has lost its topic.
+pub const CHART_STALL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Returns how long the stream has been silent when that exceeds `timeout`.
+/// Silence is measured from the later of the last message and the moment the
+/// stream (re)connected, so a fresh connection gets a full window.
+pub fn detect_stall(
+    last_activity: Option<Instant>,
+    connected_at: Instant,
+    now: Instant,
+    timeout: Duration,
+) -> Option<Duration> {
+    let since = last_activity.map_or(connected_at, |last| last.max(connected_at));
+    let silent_for = now.saturating_duration_since(since);
+    (silent_for > timeout).then_some(silent_for)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StalledStream {
+    /// The provider socket went quiet; it is reconnected and every topic
+    /// resubscribed.
+    Socket,
+    /// A chart's price stopped updating while the socket stayed up; only the
+    /// chart's symbol is resubscribed.
+    Chart,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamIncident {
+    pub provider: StreamProvider,
+    pub stream: StalledStream,
+    pub detected_at: DateTime<Utc>,
+    pub silent_for_ms: u64,
+    pub resubscribed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderIncidentStats {
+    pub provider: StreamProvider,
+    pub socket_stalls: u64,
+    pub chart_stalls: u64,
+    pub resubscribed_topics: u64,
+    pub last_incident_at: Option<DateTime<Utc>>,
+    /// Newest first.
+    pub recent: Vec<StreamIncident>,
+}
+
+#[derive(Debug, Default)]
+struct ProviderIncidents {
+    socket_stalls: u64,
+    chart_stalls: u64,
+    resubscribed_topics: u64,
+    recent: VecDeque<StreamIncident>,
+}
+
+/// Counts the stalls the supervisor recovered from, per provider, so a flaky
+/// feed shows up in the stream status instead of as a frozen chart.
+#[derive(Debug, Default)]
+pub struct StreamWatchdog {
+    incidents: Mutex<HashMap<StreamProvider, ProviderIncidents>>,
+}
+
+impl StreamWatchdog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, incident: StreamIncident) {
+        let mut incidents = self.incidents.lock();
+        let entry = incidents.entry(incident.provider.clone()).or_default();
+        match incident.stream {
+            StalledStream::Socket => entry.socket_stalls += 1,
+            StalledStream::Chart => entry.chart_stalls += 1,
+        }
+        entry.resubscribed_topics += incident.resubscribed.len() as u64;
+        if entry.recent.len() == RECENT_INCIDENT_CAPACITY {
+            entry.recent.pop_back();
+        }
+        entry.recent.push_front(incident);
+    }
+
+    pub fn stats(&self) -> Vec<ProviderIncidentStats> {
+        let incidents = self.incidents.lock();
+        [StreamProvider::Birdeye, StreamProvider::Helius]
+            .into_iter()
+            .map(|provider| {
+                let entry = incidents.get(&provider);
+                ProviderIncidentStats {
+                    socket_stalls: entry.map_or(0, |e| e.socket_stalls),
+                    chart_stalls: entry.map_or(0, |e| e.chart_stalls),
+                    resubscribed_topics: entry.map_or(0, |e| e.resubscribed_topics),
+                    last_incident_at: entry
+                        .and_then(|e| e.recent.front())
+                        .map(|incident| incident.detected_at),
+                    recent: entry.map_or_else(Vec::new, |e| e.recent.iter().cloned().collect()),
+                    provider,
+                }
+            })
+            .collect()
+    }
+
+    pub fn reset(&self) {
+        self.incidents.lock().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn incident(stream: StalledStream, topics: &[&str]) -> StreamIncident {
+        StreamIncident {
+            provider: StreamProvider::Birdeye,
+            stream,
+            detected_at: Utc::now(),
+            silent_for_ms: 61_000,
+            resubscribed: topics.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn stall_is_measured_from_reconnect_or_last_message() {
+        let start = Instant::now();
+        let timeout = Duration::from_secs(60);
+
+        let silent = detect_stall(None, start, start + Duration::from_secs(61), timeout);
+        assert_eq!(silent, Some(Duration::from_secs(61)));
+        assert!(detect_stall(None, start, start + Duration::from_secs(30), timeout).is_none());
+
+        // A message before the reconnect doesn't shorten the new window
+        let reconnected = start + Duration::from_secs(100);
+        let old_message = Some(start + Duration::from_secs(10));
+        let now = reconnected + Duration::from_secs(30);
+        assert!(detect_stall(old_message, reconnected, now, timeout).is_none());
+
+        let recent_message = Some(reconnected + Duration::from_secs(20));
+        let now = reconnected + Duration::from_secs(70);
+        assert!(detect_stall(recent_message, reconnected, now, timeout).is_none());
+    }
+
+    #[test]
+    fn incidents_are_counted_per_provider_and_kind() {
+        let watchdog = StreamWatchdog::new();
+        watchdog.record(incident(StalledStream::Socket, &["SOL", "BONK"]));
+        watchdog.record(incident(StalledStream::Chart, &["SOL"]));
+
+        let stats = watchdog.stats();
+        let birdeye = stats
+            .iter()
+            .find(|s| s.provider == StreamProvider::Birdeye)
+            .unwrap();
+        assert_eq!(birdeye.socket_stalls, 1);
+        assert_eq!(birdeye.chart_stalls, 1);
+        assert_eq!(birdeye.resubscribed_topics, 3);
+        assert_eq!(birdeye.recent[0].stream, StalledStream::Chart);
+
+        let helius = stats
+            .iter()
+            .find(|s| s.provider == StreamProvider::Helius)
+            .unwrap();
+        assert_eq!(helius.socket_stalls, 0);
+        assert!(helius.last_incident_at.is_none());
+    }
+
+    #[test]
+    fn recent_incidents_are_bounded() {
+        let watchdog = StreamWatchdog::new();
+        for _ in 0..RECENT_INCIDENT_CAPACITY + 5 {
+            watchdog.record(incident(StalledStream::Socket, &[]));
+        }
+
+        let birdeye = &watchdog.stats()[0];
+        assert_eq!(birdeye.socket_stalls, RECENT_INCIDENT_CAPACITY as u64 + 5);
+        assert_eq!(birdeye.recent.len(), RECENT_INCIDENT_CAPACITY);
+    }
+}
@@ -2,7 +2,8 @@
 mod activity_log_tests {
     use chrono::{Duration, Utc};
     use eclipse_market_pro::security::activity_log::{
-        ActivityLogFilter, ActivityLogger, DEFAULT_RETENTION_DAYS,
+        ActivityChainHead, ActivityLogFilter, ActivityLogger, IntegrityIssueKind,
+        DEFAULT_RETENTION_DAYS,
     };
     use serde_json::json;
     use sqlx::SqlitePool;
@@ -357,4 +358,118 @@ mod activity_log_tests {
 
         assert_ne!(page1[0].id, page2[0].id);
     }
+
+    #[tokio::test]
+    async fn test_integrity_chain_intact() {
+        let (logger, _temp_dir, _db_url) = create_test_logger().await;
+
+        for i in 0..5 {
+            logger
+                .log_send("wallet", json!({"index": i}), true, None)
+                .await
+                .unwrap();
+        }
+
+        let report = logger.verify_integrity(None).await.unwrap();
+        assert!(report.valid);
+        assert_eq!(report.entries_checked, 5);
+        assert_eq!(report.head, logger.chain_head().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_integrity_detects_modification_and_deletion() {
+        let (logger, _temp_dir, db_url) = create_test_logger().await;
+
+        for i in 0..4 {
+            logger
+                .log_send("wallet", json!({"index": i}), true, None)
+                .await
+                .unwrap();
+        }
+
+        let pool = SqlitePool::connect(&db_url).await.unwrap();
+        sqlx::query("UPDATE activity_logs SET result = 'failure' WHERE id = 2")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM activity_logs WHERE id = 3")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let report = logger.verify_integrity(None).await.unwrap();
+        assert!(!report.valid);
+        assert!(report
+            .issues
+            .iter()
+            .any(|i| i.log_id == 2 && i.kind == IntegrityIssueKind::Modified));
+        assert!(report
+            .issues
+            .iter()
+            .any(|i| i.log_id == 3 && i.kind == IntegrityIssueKind::DeletedEntry));
+        assert!(report
+            .issues
+            .iter()
+            .any(|i| i.log_id == 4 && i.kind == IntegrityIssueKind::BrokenChain));
+    }
+
+    #[tokio::test]
+    async fn test_integrity_survives_retention_cleanup() {
+        let (logger, _temp_dir, db_url) = create_test_logger().await;
+
+        for i in 0..3 {
+            logger
+                .log_connect("wallet", json!({"index": i}), true, None)
+                .await
+                .unwrap();
+        }
+
+        // Age the first entry, then let the logger prune it
+        let pool = SqlitePool::connect(&db_url).await.unwrap();
+        sqlx::query("UPDATE activity_logs SET timestamp = ? WHERE id = 1")
+            .bind((Utc::now() - Duration::days(10)).to_rfc3339())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let deleted = logger.cleanup_old_logs(Some(5)).await.unwrap();
+        assert_eq!(deleted, 1);
+
+        let report = logger.verify_integrity(None).await.unwrap();
+        assert!(report.valid);
+        assert_eq!(report.entries_checked, 2);
+    }
+
+    #[tokio::test]
+    async fn test_integrity_cross_checks_backup_head() {
+        let (logger, _temp_dir, _db_url) = create_test_logger().await;
+
+        logger
+            .log_connect("wallet", json!({}), true, None)
+            .await
+            .unwrap();
+        let backup_head = logger.chain_head().unwrap();
+
+        logger
+            .log_sign("wallet", json!({}), true, None)
+            .await
+            .unwrap();
+
+        let report = logger
+            .verify_integrity(Some(backup_head.clone()))
+            .await
+            .unwrap();
+        assert!(report.valid);
+
+        // A backup head that isn't in the current chain means history was rewritten
+        let forged = ActivityChainHead {
+            log_id: backup_head.log_id,
+            hash: "f".repeat(64),
+        };
+        let report = logger.verify_integrity(Some(forged)).await.unwrap();
+        assert!(report
+            .issues
+            .iter()
+            .any(|i| i.kind == IntegrityIssueKind::BackupHeadMismatch));
+    }
 }
@@ -87,6 +87,7 @@ import { useWorkspaceStore } from './store/workspaceStore';
 import { useAlertNotifications } from './hooks/useAlertNotifications';
 import { useMonitorConfig } from './hooks/useMonitorConfig';
 import { useDevConsoleCommands } from './hooks/useDevConsoleCommands';
+import { useFeatureFlags } from './hooks/useFeatureFlags';
 import { createPanelDefinition } from './utils/workspace';
 import { PanelType } from './types/workspace';
 import { useThemeStore } from './store/themeStore';
@@ -173,6 +174,7 @@ function App() {
 
   useAlertNotifications();
   useMonitorConfig();
+  const { isPageEnabled } = useFeatureFlags();
   useDevConsoleCommands();
 
   useKeyboardShortcuts({
@@ -624,8 +626,8 @@ function App() {
       });
     }
 
-    return basePages;
-  }, [isPaperMode]);
+    return basePages.filter(page => isPageEnabled(page.id));
+  }, [isPaperMode, isPageEnabled]);
 
   const CurrentPageComponent = pages.find(p => p.id === currentPage)?.component || Dashboard;
 
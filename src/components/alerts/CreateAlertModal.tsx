@@ -30,6 +30,7 @@ const channelOptions: NotificationChannel[] = [
   'telegram',
   'slack',
   'discord',
+  'push',
 ];
 
 const CreateAlertModal = ({
@@ -0,0 +1,20 @@
+import React from 'react';
+import { useTokenImage } from '../../hooks/useTokenImage';
+
+interface TokenLogoProps {
+  uri: string | null | undefined;
+  alt: string;
+  className?: string;
+  /** Shown while the thumbnail is cached, or when the logo can't be used. */
+  fallback?: React.ReactNode;
+}
+
+/** Token logo served from the local thumbnail cache instead of the source host. */
+export function TokenLogo({ uri, alt, className, fallback = null }: TokenLogoProps) {
+  const src = useTokenImage(uri);
+
+  if (!src) {
+    return <>{fallback}</>;
+  }
+  return <img src={src} alt={alt} className={className} />;
+}
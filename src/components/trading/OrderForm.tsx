@@ -3,6 +3,7 @@ import { invoke } from '@tauri-apps/api/tauri';
 import { AlertCircle, Info, Lightbulb } from 'lucide-react';
 import { useTradingSettingsStore } from '../../store/tradingSettingsStore';
 import { useOrderFormSuggestionStore } from '../../store/orderFormSuggestionStore';
+import { newIdempotencyKey } from '../../utils/idempotency';
 
 interface OrderFormProps {
   fromToken: {
@@ -90,7 +91,7 @@ export function OrderForm({ fromToken, toToken, walletAddress, onOrderCreated }:
         walletAddress,
       };
 
-      await invoke('create_order', { request });
+      await invoke('create_order', { request, idempotencyKey: newIdempotencyKey() });
 
       setSuccess(true);
       setAmount('');
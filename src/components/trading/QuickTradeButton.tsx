@@ -1,6 +1,7 @@
 import { useState } from 'react';
 import { invoke } from '@tauri-apps/api/tauri';
 import { Zap, Loader2, CheckCircle, AlertCircle } from 'lucide-react';
+import { newIdempotencyKey } from '../../utils/idempotency';
 
 interface QuickTradeButtonProps {
   fromToken: {
@@ -87,6 +88,7 @@ export function QuickTradeButton({
           priorityFeeMicroLamports: 5000,
           walletAddress: walletAddress!,
         },
+        idempotencyKey: newIdempotencyKey(),
       });
 
       setStatus('success');
@@ -4,6 +4,7 @@ import { Send, AlertCircle, Check, QrCode, Shield } from 'lucide-react';
 import type { TransactionFeeEstimate, SendTransactionInput, AddressBookContact } from '../../types/wallet';
 import { useWalletStore } from '../../store/walletStore';
 import { useAddressBook } from '../../hooks/useAddressBook';
+import { newIdempotencyKey } from '../../utils/idempotency';
 
 interface SendFlowProps {
   onSuccess?: () => void;
@@ -83,6 +84,7 @@ export function SendFlow({ onSuccess }: SendFlowProps) {
       const signature = await invoke<string>('wallet_send_transaction', {
         input,
         walletAddress: publicKey,
+        idempotencyKey: newIdempotencyKey(),
       });
 
       setTxSignature(signature);
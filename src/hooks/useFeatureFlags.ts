@@ -0,0 +1,49 @@
+import { useCallback, useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/tauri';
+import { listen } from '@tauri-apps/api/event';
+import type { FeatureFlagStatus, FeatureModule } from '../types/featureFlags';
+
+/** Pages that belong to a switchable module. */
+export const PAGE_FEATURE_MODULES: Record<string, FeatureModule> = {
+  'p2p-marketplace': 'p2p',
+  launchpad: 'launchpad',
+  'social-intelligence': 'social',
+};
+
+export function useFeatureFlags() {
+  const [flags, setFlags] = useState<FeatureFlagStatus[]>([]);
+
+  useEffect(() => {
+    invoke<FeatureFlagStatus[]>('get_feature_flags')
+      .then(setFlags)
+      .catch(err => console.error('Failed to load feature flags:', err));
+
+    const unlisten = listen<FeatureFlagStatus[]>('feature_flags_changed', event => {
+      setFlags(event.payload);
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, []);
+
+  // Modules count as enabled until flags load so pages don't flicker away
+  const isModuleEnabled = useCallback(
+    (module: FeatureModule) => flags.find(flag => flag.module === module)?.enabled ?? true,
+    [flags]
+  );
+
+  const isPageEnabled = useCallback(
+    (pageId: string) => {
+      const module = PAGE_FEATURE_MODULES[pageId];
+      return !module || isModuleEnabled(module);
+    },
+    [isModuleEnabled]
+  );
+
+  const setFeatureFlag = useCallback(async (module: FeatureModule, enabled: boolean) => {
+    const updated = await invoke<FeatureFlagStatus[]>('set_feature_flag', { module, enabled });
+    setFlags(updated);
+  }, []);
+
+  return { flags, isModuleEnabled, isPageEnabled, setFeatureFlag };
+}
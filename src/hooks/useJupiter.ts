@@ -1,5 +1,6 @@
 import { invoke } from '@tauri-apps/api';
 import { useState, useCallback } from 'react';
+import { newIdempotencyKey } from '../utils/idempotency';
 
 export type SwapMode = 'exact_in' | 'exact_out';
 
@@ -114,7 +115,10 @@ export function useJupiter() {
     setLoadingSwap(true);
     setSwapError(null);
     try {
-      const result = await invoke<SwapResult>('jupiter_swap', { input });
+      const result = await invoke<SwapResult>('jupiter_swap', {
+        input,
+        idempotencyKey: newIdempotencyKey(),
+      });
       return result;
     } catch (error) {
       const errorMessage = error instanceof Error ? error.message : String(error);
@@ -0,0 +1,41 @@
+import { useEffect, useState } from 'react';
+import { convertFileSrc, invoke } from '@tauri-apps/api/tauri';
+
+export const TOKEN_IMAGE_PROTOCOL = 'tokenimg';
+
+/** Protocol URL of a logo's cached thumbnail; the backend never hotlinks the source. */
+export function tokenImageSrc(uri: string): string {
+  return convertFileSrc(uri, TOKEN_IMAGE_PROTOCOL);
+}
+
+/**
+ * Caches a token logo through the backend and returns its local thumbnail URL,
+ * or `null` while it downloads or when the source is rejected.
+ */
+export function useTokenImage(uri: string | null | undefined): string | null {
+  const [src, setSrc] = useState<string | null>(null);
+
+  useEffect(() => {
+    setSrc(null);
+    if (!uri) {
+      return;
+    }
+
+    let cancelled = false;
+    invoke('cache_token_image', { url: uri })
+      .then(() => {
+        if (!cancelled) {
+          setSrc(tokenImageSrc(uri));
+        }
+      })
+      .catch(() => {
+        // Invalid or unreachable logos simply aren't shown
+      });
+
+    return () => {
+      cancelled = true;
+    };
+  }, [uri]);
+
+  return src;
+}
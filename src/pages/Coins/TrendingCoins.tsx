@@ -3,6 +3,7 @@ import { invoke } from '@tauri-apps/api/tauri';
 import { motion } from 'framer-motion';
 import { TrendingUp, TrendingDown, Activity, Eye, RefreshCw } from 'lucide-react';
 import { QuickTradeButton } from '../trading/QuickTradeButton';
+import { TokenLogo } from '../../components/common/TokenLogo';
 
 interface TrendingCoin {
   address: string;
@@ -626,13 +627,16 @@ export function TrendingCoinsExplorer({ searchQuery, onSelectCoin }: TrendingCoi
             >
               <div className="flex items-start justify-between mb-4">
                 <div className="flex items-center gap-3">
-                  {coin.logo_uri ? (
-                    <img src={coin.logo_uri} alt={coin.symbol} className="w-12 h-12 rounded-full" />
-                  ) : (
-                    <div className="w-12 h-12 rounded-full bg-gradient-to-br from-purple-500 to-pink-500 flex items-center justify-center font-bold shadow-lg">
-                      {coin.symbol.substring(0, 2)}
-                    </div>
-                  )}
+                  <TokenLogo
+                    uri={coin.logo_uri}
+                    alt={coin.symbol}
+                    className="w-12 h-12 rounded-full"
+                    fallback={
+                      <div className="w-12 h-12 rounded-full bg-gradient-to-br from-purple-500 to-pink-500 flex items-center justify-center font-bold shadow-lg">
+                        {coin.symbol.substring(0, 2)}
+                      </div>
+                    }
+                  />
                   <div>
                     <div className="text-xl font-bold">{coin.symbol}</div>
                     <div className="text-sm text-gray-400">{coin.name}</div>
@@ -1,5 +1,6 @@
 import React, { useState, useEffect, useCallback } from 'react';
 import { invoke } from '@tauri-apps/api';
+import type { ResourceUsage } from '../types/resourceBudget';
 import { motion, AnimatePresence } from 'framer-motion';
 import {
   Terminal,
@@ -40,6 +41,7 @@ interface PerformanceMetrics {
   usedMemoryMb: number;
   processCpuUsage: number;
   processMemoryMb: number;
+  resourceUsage?: ResourceUsage[];
 }
 
 interface ErrorStats {
@@ -537,6 +539,20 @@ const PerformanceTab: React.FC<PerformanceTabProps> = ({ metrics }) => (
             </div>
           </div>
         </div>
+
+        {metrics.resourceUsage && metrics.resourceUsage.length > 0 && (
+          <div className="grid grid-cols-3 gap-4">
+            {metrics.resourceUsage.map(usage => (
+              <MetricCard
+                key={usage.store}
+                label={`${usage.label} (${usage.entries} entries, ${usage.shrinks} shrinks)`}
+                value={`${(usage.usedBytes / 1024 / 1024).toFixed(1)} / ${(usage.budgetBytes / 1024 / 1024).toFixed(0)} MB`}
+                max={usage.budgetBytes}
+                current={usage.usedBytes}
+              />
+            ))}
+          </div>
+        )}
       </div>
     ) : (
       <div className="text-gray-500 text-center py-8">Loading performance metrics...</div>
@@ -1,6 +1,7 @@
 import { useState, useEffect } from 'react';
 import { motion } from 'framer-motion';
 import { invoke } from '@tauri-apps/api/tauri';
+import type { SignedAuditExport } from '../../types/auditExport';
 import {
   Download,
   Filter,
@@ -35,6 +36,7 @@ interface EventFilter {
   to_time?: string;
   limit?: number;
   offset?: number;
+  includeArchived?: boolean;
 }
 
 const PAGE_SIZE = 50;
@@ -62,12 +64,13 @@ export function EventAuditLog() {
   const [eventTypeFilter, setEventTypeFilter] = useState('');
   const [startDate, setStartDate] = useState('');
   const [endDate, setEndDate] = useState('');
+  const [includeArchived, setIncludeArchived] = useState(false);
   const [currentPage, setCurrentPage] = useState(1);
   const [expandedRow, setExpandedRow] = useState<string | null>(null);
 
   useEffect(() => {
     loadData();
-  }, [aggregateIdFilter, eventTypeFilter, startDate, endDate, currentPage]);
+  }, [aggregateIdFilter, eventTypeFilter, startDate, endDate, includeArchived, currentPage]);
 
   const loadData = async () => {
     setLoading(true);
@@ -82,6 +85,7 @@ export function EventAuditLog() {
       if (eventTypeFilter) filter.event_type = eventTypeFilter;
       if (startDate) filter.from_time = new Date(startDate).toISOString();
       if (endDate) filter.to_time = new Date(endDate).toISOString();
+      if (includeArchived) filter.includeArchived = true;
 
       const [eventsData, statsData] = await Promise.all([
         invoke<EventRecord[]>('get_events_command', filter),
@@ -98,7 +102,19 @@ export function EventAuditLog() {
     }
   };
 
-  const handleExport = async (format: 'json' | 'csv') => {
+  const download = (content: string, type: string, filename: string) => {
+    const blob = new Blob([content], { type });
+    const url = URL.createObjectURL(blob);
+    const a = document.createElement('a');
+    a.href = url;
+    a.download = filename;
+    document.body.appendChild(a);
+    a.click();
+    document.body.removeChild(a);
+    URL.revokeObjectURL(url);
+  };
+
+  const handleExport = async (format: 'json' | 'csv' | 'jsonl') => {
     try {
       const filter: Partial<EventFilter> = {};
       if (aggregateIdFilter) filter.aggregate_id = aggregateIdFilter;
@@ -106,7 +122,7 @@ export function EventAuditLog() {
       if (startDate) filter.from_time = new Date(startDate).toISOString();
       if (endDate) filter.to_time = new Date(endDate).toISOString();
 
-      const content = await invoke<string>('export_audit_trail_command', {
+      const content = await invoke<string | SignedAuditExport>('export_audit_trail_command', {
         aggregateId: filter.aggregate_id || null,
         eventType: filter.event_type || null,
         fromTime: filter.from_time || null,
@@ -114,17 +130,15 @@ export function EventAuditLog() {
         format,
       });
 
-      const blob = new Blob([content], {
-        type: format === 'json' ? 'application/json' : 'text/csv',
-      });
-      const url = URL.createObjectURL(blob);
-      const a = document.createElement('a');
-      a.href = url;
-      a.download = `audit-trail-${new Date().toISOString()}.${format}`;
-      document.body.appendChild(a);
-      a.click();
-      document.body.removeChild(a);
-      URL.revokeObjectURL(url);
+      const base = `audit-trail-${new Date().toISOString()}`;
+      if (typeof content === 'string') {
+        download(content, format === 'json' ? 'application/json' : 'text/csv', `${base}.${format}`);
+      } else {
+        // Records, manifest and detached signature are verified together
+        download(content.records, 'application/x-ndjson', `${base}.jsonl`);
+        download(content.manifest, 'application/json', `${base}.manifest.json`);
+        download(content.signature, 'text/plain', `${base}.manifest.sig`);
+      }
     } catch (err) {
       console.error('Failed to export audit trail:', err);
       setError(String(err));
@@ -166,6 +180,7 @@ export function EventAuditLog() {
     setEventTypeFilter('');
     setStartDate('');
     setEndDate('');
+    setIncludeArchived(false);
     setCurrentPage(1);
   };
 
@@ -217,6 +232,13 @@ export function EventAuditLog() {
             <Download className="w-4 h-4" />
             Export JSON
           </button>
+          <button
+            onClick={() => handleExport('jsonl')}
+            className="flex items-center gap-2 px-4 py-2 bg-emerald-500/20 hover:bg-emerald-500/30 border border-emerald-500/30 rounded-xl font-medium transition-colors"
+          >
+            <Download className="w-4 h-4" />
+            Export Signed JSONL
+          </button>
         </div>
       </div>
 
@@ -301,6 +323,16 @@ export function EventAuditLog() {
           </div>
         </div>
 
+        <label className="flex items-center gap-2 text-sm text-white/80">
+          <input
+            type="checkbox"
+            checked={includeArchived}
+            onChange={e => setIncludeArchived(e.target.checked)}
+            className="accent-purple-500"
+          />
+          Include archived months (slower)
+        </label>
+
         <button
           onClick={resetFilters}
           className="px-4 py-2 bg-slate-700/50 hover:bg-slate-700/70 border border-slate-600/50 rounded-xl font-medium transition-colors"
@@ -369,6 +369,7 @@ const WebhookModal = ({ webhook, onClose, onSave }: WebhookModalProps) => {
       jitter: true,
     },
     variables: webhook?.variables || [],
+    version: webhook?.version,
   });
   const [isSaving, setIsSaving] = useState(false);
   const [error, setError] = useState<string | null>(null);
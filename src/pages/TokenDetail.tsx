@@ -22,6 +22,7 @@ import { MetadataViewer } from '../components/holders/MetadataViewer';
 import { VerificationBadges } from '../components/holders/VerificationBadges';
 import { RiskAnalysisPanel } from '../components/risk/RiskAnalysisPanel';
 import { TokenSecurityPanel } from '../components/security/TokenSecurityPanel';
+import { TokenLogo } from '../components/common/TokenLogo';
 import {
   HolderDistribution,
   HolderTrend,
@@ -158,13 +159,11 @@ export default function TokenDetail({ tokenAddress, onBack }: TokenDetailProps)
           </button>
           <div>
             <div className="flex items-center gap-3">
-              {metadata.logoUri && (
-                <img
-                  src={metadata.logoUri}
-                  alt={metadata.name}
-                  className="w-12 h-12 rounded-full"
-                />
-              )}
+              <TokenLogo
+                uri={metadata.logoUri}
+                alt={metadata.name}
+                className="w-12 h-12 rounded-full"
+              />
               <div>
                 <h1 className="text-3xl font-bold">{metadata.name}</h1>
                 <p className="text-gray-400">{metadata.symbol}</p>
@@ -16,6 +16,7 @@ import { ReceiveView } from '../components/wallet/ReceiveView';
 import { SwapView } from '../components/wallet/SwapView';
 import { BridgeView } from '../components/wallet/BridgeView';
 import { AddressBookView } from '../components/wallet/AddressBookView';
+import { TokenLogo } from '../components/common/TokenLogo';
 
 type TabType = 'balances' | 'send' | 'receive' | 'swap' | 'bridge' | 'addressBook';
 
@@ -113,16 +114,11 @@ export default function Wallet() {
                     >
                       <div className="flex items-center justify-between">
                         <div className="flex items-center gap-4">
-                          {token.logoUri && (
-                            <img
-                              src={token.logoUri}
-                              alt={token.symbol}
-                              className="w-10 h-10 rounded-full"
-                              onError={e => {
-                                (e.target as HTMLImageElement).style.display = 'none';
-                              }}
-                            />
-                          )}
+                          <TokenLogo
+                            uri={token.logoUri}
+                            alt={token.symbol}
+                            className="w-10 h-10 rounded-full"
+                          />
                           <div>
                             <p className="font-semibold">{token.symbol}</p>
                             <p className="text-sm text-gray-400">{token.name}</p>
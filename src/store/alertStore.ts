@@ -12,7 +12,8 @@ export type NotificationChannel =
   | 'webhook'
   | 'telegram'
   | 'slack'
-  | 'discord';
+  | 'discord'
+  | 'push';
 
 export interface AlertCondition {
   conditionType: AlertConditionType;
@@ -25,6 +26,17 @@ export interface CompoundCondition {
   operator: LogicalOperator;
 }
 
+export type AlertTriggerMode = 'one_shot' | 'recurring';
+
+/** When a recurring alert may fire again after its cooldown. */
+export interface AlertRearmPolicy {
+  mode: AlertTriggerMode;
+  /** Percent the price must move back past the threshold before re-arming; 0 disables. */
+  hysteresisPercent: number;
+  /** Conditions must be seen false after the cooldown before re-arming. */
+  requireReset: boolean;
+}
+
 export interface PriceAlert {
   id: string;
   name: string;
@@ -39,6 +51,8 @@ export interface PriceAlert {
   cooldownUntil?: string | null;
   createdAt: string;
   updatedAt: string;
+  version?: number;
+  rearmPolicy?: AlertRearmPolicy;
 }
 
 export interface AlertTestResult {
@@ -49,6 +63,126 @@ export interface AlertTestResult {
   message: string;
 }
 
+export interface AlertTriggerSample {
+  alertId: string;
+  alertName: string;
+  symbol: string;
+  currentPrice: number;
+  conditionsMet: string;
+  triggeredAt: string;
+}
+
+export interface PreviewTarget {
+  targetId: string;
+  targetName: string;
+  /** Whether a real trigger would reach this target. */
+  receivesLive: boolean;
+  sandbox: boolean;
+  payload: unknown;
+  error?: string | null;
+}
+
+export interface NotificationPreview {
+  alertId: string;
+  channel: NotificationChannel;
+  sample: AlertTriggerSample;
+  targets: PreviewTarget[];
+}
+
+export interface PreviewDeliveryResult {
+  success: boolean;
+  message: string;
+  latencyMs?: number | null;
+  error?: string | null;
+}
+
+export type MuteWeekday = 'Mon' | 'Tue' | 'Wed' | 'Thu' | 'Fri' | 'Sat' | 'Sun';
+
+export interface WeeklyMuteWindow {
+  days: MuteWeekday[];
+  startTime: string;
+  endTime: string;
+}
+
+export interface OneOffMuteWindow {
+  label?: string | null;
+  startsAt: string;
+  endsAt: string;
+}
+
+export interface AlertMuteSchedule {
+  alertId?: string | null;
+  enabled: boolean;
+  weekly: WeeklyMuteWindow[];
+  oneOff: OneOffMuteWindow[];
+  utcOffsetMinutes: number;
+  summarizeOnEnd: boolean;
+}
+
+export interface MutedTrigger {
+  id: number;
+  scope: string;
+  alertId: string;
+  alertName: string;
+  symbol: string;
+  currentPrice: number;
+  conditionsMet: string;
+  triggeredAt: string;
+}
+
+export interface MuteSummary {
+  alertId?: string | null;
+  triggers: MutedTrigger[];
+  windowEndedAt: string;
+}
+
+export type AlertOutcomeType = 'profit' | 'loss' | 'neutral' | 'pending' | 'unknown';
+export type AlertTradeBias = 'long' | 'short';
+
+export interface AlertHistoryEntry {
+  id: string;
+  alertId: string;
+  alertName: string;
+  symbol: string;
+  mint: string;
+  compoundCondition: CompoundCondition;
+  triggeredPrice: number;
+  conditionsMet: string;
+  notificationChannels: NotificationChannel[];
+  triggeredAt: string;
+  bookmarked: boolean;
+  outcomeNotes?: string | null;
+  priceAfter1h?: number | null;
+  priceAfter4h?: number | null;
+  priceAfter24h?: number | null;
+  outcomeType?: AlertOutcomeType | null;
+  createdAt: string;
+  price24hAgo?: number | null;
+  volume24h?: number | null;
+  change24h?: number | null;
+  muted: boolean;
+}
+
+export interface AlertOutcomeSummary {
+  triggerCount: number;
+  mutedCount: number;
+  evaluatedCount: number;
+  profitCount: number;
+  lossCount: number;
+  neutralCount: number;
+  hitRate?: number | null;
+  avgReturn1h?: number | null;
+  avgReturn24h?: number | null;
+  pruneSuggested: boolean;
+}
+
+export interface AlertTriggerHistory {
+  alertId: string;
+  bias: AlertTradeBias;
+  entries: AlertHistoryEntry[];
+  summary: AlertOutcomeSummary;
+}
+
 interface AlertStateStore {
   alerts: PriceAlert[];
   isLoading: boolean;
@@ -78,6 +212,15 @@ interface AlertStateStore {
     price24hAgo?: number | null,
     volume24h?: number | null
   ) => Promise<AlertTestResult>;
+  previewNotification: (
+    alertId: string,
+    channel: NotificationChannel
+  ) => Promise<NotificationPreview>;
+  sendNotificationPreview: (
+    alertId: string,
+    channel: NotificationChannel,
+    targetId: string
+  ) => Promise<PreviewDeliveryResult>;
   setLastTriggerEvent: (event: AlertStateStore['lastTriggerEvent']) => void;
   addEnhancedNotification: (notification: EnhancedAlertNotification) => void;
   dismissNotification: (alertId: string) => void;
@@ -113,6 +256,7 @@ export const useAlertStore = create<AlertStateStore>((set, get) => ({
           compoundCondition: payload.compoundCondition,
           notificationChannels: payload.notificationChannels,
           cooldownMinutes: payload.cooldownMinutes,
+          rearmPolicy: payload.rearmPolicy,
         },
       });
       set(state => ({ alerts: [alert, ...state.alerts] }));
@@ -134,6 +278,7 @@ export const useAlertStore = create<AlertStateStore>((set, get) => ({
           notificationChannels: payload.notificationChannels,
           cooldownMinutes: payload.cooldownMinutes,
           state: payload.state,
+          rearmPolicy: payload.rearmPolicy,
         },
       });
       set(state => ({
@@ -173,6 +318,30 @@ export const useAlertStore = create<AlertStateStore>((set, get) => ({
     }
   },
 
+  previewNotification: async (alertId, channel) => {
+    set({ error: null });
+    try {
+      return await invoke<NotificationPreview>('preview_notification', { alertId, channel });
+    } catch (error) {
+      set({ error: String(error) });
+      throw error;
+    }
+  },
+
+  sendNotificationPreview: async (alertId, channel, targetId) => {
+    set({ error: null });
+    try {
+      return await invoke<PreviewDeliveryResult>('send_notification_preview', {
+        alertId,
+        channel,
+        targetId,
+      });
+    } catch (error) {
+      set({ error: String(error) });
+      throw error;
+    }
+  },
+
   setLastTriggerEvent: event => set({ lastTriggerEvent: event }),
 
   addEnhancedNotification: notification => {
@@ -14,6 +14,26 @@ export interface Watchlist {
   items: WatchlistItem[];
   createdAt: string;
   updatedAt: string;
+  version?: number;
+}
+
+export interface WatchlistPriceUpdate {
+  symbol: string;
+  price: number;
+  volume: number;
+  change24h: number;
+  timestamp: number;
+}
+
+export interface WatchlistPriceBatch {
+  watchlistId: string;
+  updates: WatchlistPriceUpdate[];
+}
+
+export interface WatchlistStreamStatus {
+  intervalMs: number;
+  watchlists: Record<string, string[]>;
+  subscribedSymbols: string[];
 }
 
 interface WatchlistState {
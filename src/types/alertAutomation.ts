@@ -0,0 +1,65 @@
+export type AlertAutomationMetric = 'composite_score' | 'price';
+export type CrossDirection = 'above' | 'below';
+export type AlertAutomationMode = 'paper' | 'live';
+export type AlertAutomationStatus = 'filled' | 'placed' | 'blocked' | 'failed';
+
+/** Paper fills and trial length required before a rule may go live. */
+export const PAPER_TRIAL_MIN_FILLS = 3;
+export const PAPER_TRIAL_MIN_HOURS = 24;
+
+export interface AlertAutomationTrigger {
+  metric: AlertAutomationMetric;
+  direction: CrossDirection;
+  /** Composite scores run 0-100; price thresholds are in USD. */
+  threshold: number;
+}
+
+export interface AlertAutomationRule {
+  id: string;
+  name: string;
+  wallet_address: string;
+  token_mint: string;
+  token_symbol: string;
+  trigger: AlertAutomationTrigger;
+  amount_sol: number;
+  max_spend_per_day_sol: number;
+  max_spend_total_sol: number;
+  slippage_bps: number;
+  cooldown_minutes: number;
+  mode: AlertAutomationMode;
+  enabled: boolean;
+  paper_fills: number;
+  trial_started_at: string;
+  last_value: number | null;
+  last_triggered_at: string | null;
+  created_at: string;
+  updated_at: string;
+  promoted_at: string | null;
+}
+
+export interface CreateAlertAutomationRequest {
+  name: string;
+  wallet_address: string;
+  token_mint: string;
+  token_symbol: string;
+  trigger: AlertAutomationTrigger;
+  amount_sol: number;
+  max_spend_per_day_sol: number;
+  max_spend_total_sol: number;
+  slippage_bps?: number;
+  cooldown_minutes?: number;
+}
+
+export interface AlertAutomationExecution {
+  id: string;
+  rule_id: string;
+  mode: AlertAutomationMode;
+  status: AlertAutomationStatus;
+  trigger_value: number;
+  threshold: number;
+  amount_sol: number;
+  token_price: number | null;
+  order_id: string | null;
+  message: string;
+  created_at: string;
+}
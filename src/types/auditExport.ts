@@ -0,0 +1,27 @@
+export interface AuditExportFilter {
+  aggregateId: string | null;
+  eventType: string | null;
+  fromTime: string | null;
+  toTime: string | null;
+}
+
+export interface AuditExportManifest {
+  format: string;
+  generatedAt: string;
+  recordCount: number;
+  eventTypeCounts: Record<string, number>;
+  firstTimestamp: string | null;
+  lastTimestamp: string | null;
+  filter: AuditExportFilter;
+  /** Hex SHA-256 of the JSON Lines file exactly as exported. */
+  recordsSha256: string;
+  /** Base58 Ed25519 key, the same encoding as Solana addresses. */
+  publicKey: string;
+}
+
+/** `signature` is the base58 Ed25519 signature over the bytes of `manifest`. */
+export interface SignedAuditExport {
+  records: string;
+  manifest: string;
+  signature: string;
+}
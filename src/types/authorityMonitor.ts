@@ -0,0 +1,40 @@
+export type AuthorityChangeKind =
+  | 'mint_authority_enabled'
+  | 'mint_authority_changed'
+  | 'mint_authority_revoked'
+  | 'freeze_authority_enabled'
+  | 'freeze_authority_changed'
+  | 'freeze_authority_revoked'
+  | 'update_authority_changed'
+  | 'metadata_updated';
+
+export type AuthorityChangeSeverity = 'info' | 'low' | 'medium' | 'high' | 'critical';
+
+/** Last observed authorities of a held or watchlisted mint. */
+export interface TokenAuthoritySnapshot {
+  mint: string;
+  symbol: string;
+  mintAuthority?: string | null;
+  freezeAuthority?: string | null;
+  updateAuthority?: string | null;
+  metadataHash?: string | null;
+  checkedAt: string;
+}
+
+/** Emitted as `token_authority_changed` when a monitored mint changes. */
+export interface TokenAuthorityChange {
+  id: string;
+  mint: string;
+  symbol: string;
+  kind: AuthorityChangeKind;
+  severity: AuthorityChangeSeverity;
+  previous?: string | null;
+  current?: string | null;
+  detectedAt: string;
+}
+
+export interface AuthorityCheckReport {
+  checked: number;
+  failed: number;
+  changes: TokenAuthorityChange[];
+}
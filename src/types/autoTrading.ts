@@ -23,6 +23,8 @@ export interface PositionSizingConfig {
   targetVolatility?: number; // for volatility-based
 }
 
+export type TradingMode = 'paper' | 'live';
+
 export interface TradingStrategy {
   id: string;
   name: string;
@@ -34,6 +36,7 @@ export interface TradingStrategy {
   positionSizing: PositionSizingConfig;
   riskControls: RiskControls;
   allowedSymbols: string[]; // empty = all
+  mode: TradingMode;
   createdAt: number;
   updatedAt: number;
 }
@@ -50,10 +53,49 @@ export interface StrategyExecution {
   totalPnLPercent: number;
   winRate: number;
   currentDrawdown: number;
+  maxDrawdown: number;
+  winningTrades: number;
   dailyPnL: number;
   lastError?: string;
 }
 
+export interface PromotionGates {
+  min_duration_hours: number;
+  min_trades: number;
+  max_drawdown_percent: number;
+}
+
+export type PromotionGate = 'min_duration' | 'min_trades' | 'max_drawdown';
+
+export interface GateResult {
+  gate: PromotionGate;
+  required: number;
+  actual: number;
+  passed: boolean;
+}
+
+export interface PromotionTrackRecord {
+  started_at: string;
+  trades: number;
+  max_drawdown_percent: number;
+  total_pnl: number;
+}
+
+export type PromotionStatus = 'pending' | 'blocked' | 'approved' | 'rejected';
+
+export interface PromotionRequest {
+  id: string;
+  strategy_id: string;
+  strategy_name: string;
+  status: PromotionStatus;
+  gates: PromotionGates;
+  track_record: PromotionTrackRecord;
+  results: GateResult[];
+  requested_at: string;
+  decided_at?: string | null;
+  note?: string | null;
+}
+
 export interface BacktestConfig {
   strategyId: string;
   symbol: string;
@@ -0,0 +1,33 @@
+export type AwayCategory =
+  | 'alert_fired'
+  | 'order_filled'
+  | 'bot_trade'
+  | 'watchlist_move'
+  | 'risk_change';
+
+export interface AwayTimelineEntry {
+  timestamp: string;
+  category: AwayCategory;
+  title: string;
+  detail: string;
+  reference: string;
+}
+
+/** Emitted as `away_summary` on start after an absence and returned by `get_away_summary`. */
+export interface AwaySummary {
+  since: string;
+  until: string;
+  alertsFired: number;
+  ordersFilled: number;
+  botTrades: number;
+  watchlistMoves: number;
+  riskChanges: number;
+  timeline: AwayTimelineEntry[];
+  truncated: boolean;
+}
+
+export interface AwaySummarySettings {
+  digestEnabled: boolean;
+  minAwayMinutes: number;
+  watchlistMovePercent: number;
+}
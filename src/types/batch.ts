@@ -0,0 +1,36 @@
+export type BatchCommandName =
+  | 'watchlist_list'
+  | 'watchlist_get'
+  | 'watchlist_create'
+  | 'watchlist_update'
+  | 'watchlist_add_item'
+  | 'watchlist_remove_item'
+  | 'watchlist_reorder_items'
+  | 'alert_list'
+  | 'alert_get'
+  | 'alert_create'
+  | 'alert_update'
+  | 'alert_delete';
+
+export interface BatchCommand {
+  command: BatchCommandName;
+  /** Strings like `$0.id` are replaced by fields of earlier results. */
+  args?: Record<string, unknown>;
+}
+
+export interface BatchItemResult {
+  index: number;
+  command: BatchCommandName;
+  ok: boolean;
+  result?: unknown;
+  error?: string;
+  rolledBack: boolean;
+}
+
+export interface BatchResponse {
+  success: boolean;
+  atomic: boolean;
+  rolledBack: boolean;
+  results: BatchItemResult[];
+  rollbackErrors: string[];
+}
@@ -0,0 +1,30 @@
+/** Community blocklist endpoint. Payloads must be signed by `publicKey` (base58). */
+export interface BlocklistSource {
+  id: string;
+  name: string;
+  url: string;
+  publicKey: string;
+  enabled: boolean;
+  lastSyncedAt: string | null;
+  lastError: string | null;
+  entryCount: number;
+  createdAt: string;
+}
+
+export interface BlocklistSyncReport {
+  sourceId: string;
+  added: number;
+  removed: number;
+  unchanged: number;
+  rejected: number;
+  error: string | null;
+}
+
+export interface BlocklistProvenance {
+  address: string;
+  entryType: 'token' | 'wallet';
+  sourceId: string;
+  reason: string;
+  firstSeen: string;
+  lastSeen: string;
+}
@@ -0,0 +1,51 @@
+export type AnnotationKind = 'line' | 'note' | 'zone';
+
+/** `trade` and `alert` annotations are added automatically on fills and triggers. */
+export type AnnotationSource = 'user' | 'trade' | 'alert';
+
+export interface ChartAnnotation {
+  id: string;
+  token: string;
+  /** `null` shows the annotation on every timeframe. */
+  timeframe: string | null;
+  kind: AnnotationKind;
+  source: AnnotationSource;
+  price: number | null;
+  /** Upper bound of a zone, or the end of a sloped line. */
+  priceTo: number | null;
+  /** Unix time in seconds. */
+  time: number | null;
+  timeTo: number | null;
+  text: string | null;
+  color: string | null;
+  /** Order id or alert trigger for system annotations. */
+  referenceId: string | null;
+  createdAt: string;
+  updatedAt: string;
+}
+
+export interface ChartAnnotationInput {
+  token: string;
+  timeframe?: string | null;
+  kind: AnnotationKind;
+  price?: number | null;
+  priceTo?: number | null;
+  time?: number | null;
+  timeTo?: number | null;
+  text?: string | null;
+  color?: string | null;
+}
+
+export interface ChartAnnotationExport {
+  format: string;
+  exportedAt: string;
+  annotations: ChartAnnotation[];
+}
+
+export interface ChartAnnotationImportSummary {
+  imported: number;
+  skipped: number;
+}
+
+/** Emitted with the token address when system annotations are added. */
+export const CHART_ANNOTATIONS_CHANGED_EVENT = 'chart_annotations_changed';
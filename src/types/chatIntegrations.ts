@@ -1,4 +1,4 @@
-export type ChatServiceType = 'telegram' | 'slack' | 'discord';
+export type ChatServiceType = 'telegram' | 'slack' | 'discord' | 'push';
 export type AlertPriority = 'low' | 'medium' | 'high' | 'critical';
 export type DeliveryStatus = 'pending' | 'sent' | 'failed' | 'rate_limited';
 
@@ -8,16 +8,37 @@ export interface TelegramConfig {
   botToken: string;
   chatId: string;
   enabled: boolean;
+  /** Receives notification previews only, never live alerts. */
+  sandbox?: boolean;
   alertTypes?: string[];
   alertPriorities?: AlertPriority[];
 }
 
+/** `trade` commands (e.g. /pause_bots) also require a 2FA code. */
+export type BotPermission = 'view' | 'trade';
+
+export interface BotChat {
+  chatId: string;
+  label?: string;
+  permissions: BotPermission[];
+}
+
+export interface TelegramBotConfig {
+  enabled: boolean;
+  /** Telegram integration whose bot token receives commands. */
+  telegramConfigId?: string;
+  allowedChats: BotChat[];
+  pollTimeoutSecs: number;
+}
+
 export interface SlackConfig {
   id: string;
   name: string;
   webhookUrl: string;
   channel?: string;
   enabled: boolean;
+  /** Receives notification previews only, never live alerts. */
+  sandbox?: boolean;
   alertTypes?: string[];
   alertPriorities?: AlertPriority[];
 }
@@ -28,11 +49,42 @@ export interface DiscordConfig {
   webhookUrl: string;
   username?: string;
   enabled: boolean;
+  /** Receives notification previews only, never live alerts. */
+  sandbox?: boolean;
   roleMentions?: string[];
   alertTypes?: string[];
   alertPriorities?: AlertPriority[];
 }
 
+export type SlashCommand = 'price' | 'portfolio' | 'screener_run';
+
+export interface DiscordGuildRule {
+  guildId: string;
+  commands: SlashCommand[];
+  /** Empty lets every member of the guild use the commands. */
+  allowedRoleIds?: string[];
+}
+
+/** Grants commands to a user anywhere, including direct messages. */
+export interface DiscordUserRule {
+  userId: string;
+  commands: SlashCommand[];
+}
+
+export interface DiscordBotConfig {
+  enabled: boolean;
+  applicationId: string;
+  guilds: DiscordGuildRule[];
+  users: DiscordUserRule[];
+  userRequestsPerMinute: number;
+}
+
+export interface DiscordBotStatus {
+  config: DiscordBotConfig;
+  /** The bot token itself lives in the keystore and is never returned. */
+  tokenConfigured: boolean;
+}
+
 export interface ChatIntegrationSettings {
   telegram: TelegramConfig[];
   slack: SlackConfig[];
@@ -73,3 +125,61 @@ export interface RateLimitStatus {
   maxPerMinute: number;
   resetAt: string;
 }
+
+export type PushProvider = 'unified_push' | 'fcm_relay';
+
+export interface PushSubscription {
+  id: string;
+  name: string;
+  provider: PushProvider;
+  endpoint: string;
+  enabled: boolean;
+  createdAt: string;
+}
+
+export interface PushSubscriptionInput {
+  name: string;
+  provider: PushProvider;
+  endpoint: string;
+}
+
+export interface PushRegistration {
+  subscription: PushSubscription;
+  contentKey: string;
+}
+
+export interface PushRelayConfig {
+  url: string;
+  apiKey?: string;
+}
+
+export interface PushRelayStatus {
+  url?: string;
+  apiKeyConfigured: boolean;
+}
+
+export type IntegrationKind = 'email' | 'twitter' | 'telegram' | 'slack' | 'discord';
+
+export interface IntegrationHealth {
+  kind: IntegrationKind;
+  configId: string;
+  name: string;
+  healthy: boolean;
+  error?: string | null;
+  latencyMs?: number | null;
+  checkedAt: string;
+  lastHealthyAt?: string | null;
+  consecutiveFailures: number;
+  canNotify: boolean;
+}
+
+export interface IntegrationHealthReport {
+  checkedAt?: string | null;
+  integrations: IntegrationHealth[];
+  failingCount: number;
+}
+
+export interface IntegrationHealthAlert {
+  broken: IntegrationHealth[];
+  notifiedVia?: IntegrationKind | null;
+}
@@ -0,0 +1,28 @@
+export type ComputeTxKind = 'swap' | 'transfer' | 'multisig_exec' | 'other';
+export type ComputeBudgetSource = 'history' | 'simulation' | 'default';
+
+export const DEFAULT_SAFETY_MARGIN_PCT = 15;
+
+export interface ComputeBudgetRecommendation {
+  kind: ComputeTxKind;
+  units: number;
+  source: ComputeBudgetSource;
+  samples: number;
+  marginPct: number;
+}
+
+export interface ComputeKindStats {
+  kind: ComputeTxKind;
+  samples: number;
+  exceededFailures: number;
+  medianUnits?: number | null;
+  p95Units?: number | null;
+  /** Average limit requested by past transactions, to compare with the tuned one. */
+  avgRequestedUnits?: number | null;
+  recommendedUnits?: number | null;
+}
+
+export interface TunedTransaction {
+  transaction: string;
+  recommendation: ComputeBudgetRecommendation;
+}
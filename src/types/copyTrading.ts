@@ -0,0 +1,20 @@
+export interface CopyDeltaSummary {
+  source_wallet: string;
+  samples: number;
+  priced_samples: number;
+  avg_delay_ms: number;
+  avg_price_delta_bps: number | null;
+  worst_price_delta_bps: number | null;
+  copyable: boolean;
+}
+
+export interface CopyTradePerformance {
+  total_trades: number;
+  successful_trades: number;
+  failed_trades: number;
+  total_volume: number;
+  total_pnl: number;
+  win_rate: number;
+  avg_trade_size: number;
+  copy_delta: CopyDeltaSummary | null;
+}
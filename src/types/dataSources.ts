@@ -0,0 +1,15 @@
+export type DataModule = 'market' | 'social' | 'scanner' | 'price_engine';
+
+export type DataSourceMode = 'live' | 'mock' | 'recorded';
+
+export interface DataSourceConfig {
+  modes: Partial<Record<DataModule, DataSourceMode>>;
+  captureRecordings: boolean;
+}
+
+export interface DataSourceStatus {
+  config: DataSourceConfig;
+  effective: Record<DataModule, DataSourceMode>;
+  /** Release builds always use live providers regardless of the matrix. */
+  lockedToLive: boolean;
+}
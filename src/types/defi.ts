@@ -4,7 +4,8 @@ export type Protocol =
   | 'kamino'
   | 'jupiter'
   | 'raydium'
-  | 'orca';
+  | 'orca'
+  | 'meteora';
 
 export type PositionType = 'lending' | 'borrowing' | 'liquidityPool' | 'staking' | 'farming';
 
@@ -0,0 +1,28 @@
+export type QueueTopic = 'notification' | 'webhook';
+export type QueuedMessageStatus = 'pending' | 'processing' | 'delivered' | 'dead';
+
+/** Timestamps are unix seconds. */
+export interface QueuedMessage {
+  id: string;
+  topic: QueueTopic;
+  /** Producers set this so a replayed detection or alert is delivered once. */
+  dedupeKey: string | null;
+  payload: string;
+  status: QueuedMessageStatus;
+  attempts: number;
+  maxAttempts: number;
+  nextAttemptAt: number;
+  lockedUntil: number | null;
+  lastError: string | null;
+  createdAt: number;
+  deliveredAt: number | null;
+}
+
+export interface DeliveryQueueStats {
+  pending: number;
+  processing: number;
+  delivered: number;
+  dead: number;
+  /** Age in seconds of the oldest message still waiting. */
+  oldestPendingSecs: number | null;
+}
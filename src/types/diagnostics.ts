@@ -108,3 +108,51 @@ export interface DiagnosticsSettings {
   history_retention_days: number;
   dry_run: boolean;
 }
+
+export interface StartupCheckConfig {
+  enabled: boolean;
+  checkDatabases: boolean;
+  checkKeystore: boolean;
+  pingApis: boolean;
+  apiTimeoutSecs: number;
+  skipDatabases: string[];
+}
+
+export type StartupCheckCategory = 'database' | 'schema' | 'keystore' | 'api';
+export type StartupCheckStatus = 'passed' | 'warning' | 'failed';
+export type SelfRepairKind = 'rebuild_cache_database' | 'rerun_migrations';
+
+export interface SelfRepairOption {
+  kind: SelfRepairKind;
+  target: string;
+  description: string;
+}
+
+export interface StartupCheckResult {
+  id: string;
+  category: StartupCheckCategory;
+  name: string;
+  status: StartupCheckStatus;
+  detail: string;
+  durationMs: number;
+  repair?: SelfRepairOption | null;
+}
+
+/** Emitted as `startup_health` after the startup pass. */
+export interface StartupHealthReport {
+  startedAt: string;
+  durationMs: number;
+  overall: HealthLevel;
+  passed: number;
+  warnings: number;
+  failed: number;
+  checks: StartupCheckResult[];
+}
+
+export interface SelfRepairResult {
+  checkId: string;
+  kind: SelfRepairKind;
+  message: string;
+  backupLocation?: string | null;
+  restartRequired: boolean;
+}
@@ -0,0 +1,70 @@
+export type DomainEventKind =
+  | 'orderFilled'
+  | 'coinDetected'
+  | 'riskScoreChanged'
+  | 'whaleActivity'
+  | 'alertTriggered';
+
+export type DomainEvent =
+  | {
+      type: 'orderFilled';
+      orderId: string;
+      walletAddress: string;
+      side: string;
+      inputMint: string;
+      outputMint: string;
+      amount: number;
+      fillPrice: number;
+      txSignature?: string | null;
+    }
+  | {
+      type: 'coinDetected';
+      mint: string;
+      symbol: string;
+      liquidity: number;
+      safetyScore: number;
+    }
+  | {
+      type: 'riskScoreChanged';
+      tokenAddress: string;
+      previousScore?: number | null;
+      score: number;
+      previousLevel?: string | null;
+      riskLevel: string;
+    }
+  | {
+      type: 'whaleActivity';
+      walletAddress: string;
+      walletLabel?: string | null;
+      action: string;
+      token?: string | null;
+      amountUsd?: number | null;
+      txSignature: string;
+    }
+  | {
+      type: 'alertTriggered';
+      alertId: string;
+      alertName: string;
+      mint: string;
+      symbol: string;
+      price: number;
+      triggeredAt: string;
+    };
+
+export interface DomainEventEnvelope {
+  sequence: number;
+  source: string;
+  publishedAt: string;
+  event: DomainEvent;
+}
+
+export interface DomainEventBusStats {
+  publishedTotal: number;
+  subscriberCount: number;
+  publishedByKind: Partial<Record<DomainEventKind, number>>;
+}
+
+export interface DomainEventInspection {
+  stats: DomainEventBusStats;
+  events: DomainEventEnvelope[];
+}
@@ -0,0 +1,22 @@
+export type EventPartitionState = 'active' | 'compressed';
+
+export interface EventPartitionInfo {
+  partition: string;
+  state: EventPartitionState;
+  size_bytes: number;
+  starts_at: string;
+  ends_at: string;
+}
+
+/** Age-based archival for monthly event partitions. The current month is never touched. */
+export interface EventPartitionPolicy {
+  compress_after_months: number;
+  delete_after_months: number | null;
+  pinned_partitions: string[];
+}
+
+export interface EventPartitionPolicyReport {
+  compressed: string[];
+  deleted: string[];
+  space_saved_bytes: number;
+}
@@ -0,0 +1,33 @@
+export type GasPriority = 'slow' | 'medium' | 'fast' | { custom: number };
+
+/** Per-token execution settings. Unset fields fall through to the order or global settings. */
+export interface TokenExecutionPreset {
+  mint: string;
+  label?: string | null;
+  maxSlippageBps?: number | null;
+  priorityFee?: GasPriority | null;
+  mevProtection?: boolean | null;
+  updatedAt?: string | null;
+}
+
+export interface ExecutionParams {
+  slippageBps: number;
+  priorityFeeMicroLamports: number;
+  mevProtection: boolean;
+}
+
+export interface ResolvedExecution {
+  params: ExecutionParams;
+  presetMint: string | null;
+}
+
+export interface ExecutionPresetAudit {
+  id: number;
+  orderRef: string;
+  source: 'order' | 'dca' | 'copy_trade';
+  presetMint: string | null;
+  slippageBps: number;
+  priorityFeeMicroLamports: number;
+  mevProtection: boolean;
+  recordedAt: string;
+}
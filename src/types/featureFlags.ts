@@ -0,0 +1,15 @@
+/** Subsystems that can be switched off; disabled ones open no database and start no jobs. */
+export type FeatureModule = 'p2p' | 'launchpad' | 'social';
+
+export interface FeatureFlagStatus {
+  module: FeatureModule;
+  label: string;
+  description: string;
+  enabled: boolean;
+  /** Differs from the built-in default. */
+  overridden: boolean;
+  /** Started in this session. */
+  running: boolean;
+  /** Switched off while running; it stops at next launch. */
+  restartRequired: boolean;
+}
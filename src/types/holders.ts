@@ -1,9 +1,12 @@
+import type { BehaviorTag } from './insiders';
+
 export interface HolderInfo {
   address: string;
   balance: number;
   percentage: number;
   isKnownWallet: boolean;
   walletLabel?: string;
+  behavior?: BehaviorTag;
   rank: number;
 }
 
@@ -36,6 +39,34 @@ export interface LargeTransfer {
   transactionSignature: string;
 }
 
+export interface HolderBalanceChange {
+  address: string;
+  balanceBefore: number;
+  balanceAfter: number;
+  change: number;
+  isSmartMoney: boolean;
+  behavior?: BehaviorTag;
+}
+
+export interface HolderSnapshotDiff {
+  tokenAddress: string;
+  fromSnapshot: string;
+  toSnapshot: string;
+  newEntrants: HolderBalanceChange[];
+  exits: HolderBalanceChange[];
+  topAccumulators: HolderBalanceChange[];
+  topDistributors: HolderBalanceChange[];
+  totalAccumulated: number;
+  totalDistributed: number;
+  netFlow: 'accumulation' | 'distribution' | 'neutral';
+}
+
+export interface SmartMoneyEntryAlert {
+  tokenAddress: string;
+  wallets: HolderBalanceChange[];
+  detectedAt: string;
+}
+
 export interface TokenMetadata {
   address: string;
   name: string;
@@ -79,3 +110,34 @@ export interface VerificationStatus {
   communityVotes: CommunityVotes;
   riskScore: number;
 }
+
+export interface HolderRefreshConfig {
+  enabled: boolean;
+  /** Portfolio and watchlist tokens older than this are refreshed on the next tick. */
+  stalenessMinutes: number;
+  maxTokensPerRun: number;
+  checkIntervalMinutes: number;
+}
+
+export interface HolderRefreshFailure {
+  tokenAddress: string;
+  error: string;
+}
+
+export interface HolderRefreshReport {
+  refreshed: string[];
+  failed: HolderRefreshFailure[];
+  deferred: number;
+  durationMs: number;
+}
+
+export interface HolderRefreshRun {
+  id: number;
+  trigger: 'manual' | 'scheduled';
+  startedAt: string;
+  durationMs: number;
+  requested: number;
+  refreshed: number;
+  failed: number;
+  deferred: number;
+}
@@ -0,0 +1,39 @@
+export type HttpRecorderMode = 'off' | 'record' | 'replay';
+
+export interface HttpRecorderConfig {
+  mode: HttpRecorderMode;
+  retentionDays: number;
+}
+
+export interface HttpRecorderStatus {
+  config: HttpRecorderConfig;
+  effectiveMode: HttpRecorderMode;
+  /** Release builds can record but never replay. */
+  replayLocked: boolean;
+  storeReady: boolean;
+}
+
+export interface HttpRecordingSummary {
+  id: number;
+  service: string;
+  method: string;
+  url: string;
+  status: number;
+  latencyMs: number;
+  recordedAt: string;
+}
+
+export interface HttpRecording extends HttpRecordingSummary {
+  fingerprint: string;
+  requestHeaders: Record<string, string>;
+  requestBody?: string | null;
+  responseHeaders: Record<string, string>;
+  responseBody: string;
+}
+
+export interface HttpRecordingFilter {
+  service?: string;
+  since?: string;
+  until?: string;
+  limit?: number;
+}
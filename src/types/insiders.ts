@@ -26,6 +26,18 @@ export interface UpdateMonitoredWalletRequest {
 
 export type ActivityAction = 'buy' | 'sell' | 'transfer' | 'swap' | 'unknown';
 
+export type WalletBehavior =
+  | 'sniper'
+  | 'market_maker'
+  | 'exchange'
+  | 'long_term_holder'
+  | 'active_trader';
+
+export interface BehaviorTag {
+  label: WalletBehavior;
+  confidence: number;
+}
+
 export interface WalletActivity {
   id: string;
   wallet_address: string;
@@ -40,6 +52,7 @@ export interface WalletActivity {
   amount_usd?: number;
   price?: number;
   is_whale: boolean;
+  behavior?: BehaviorTag;
   timestamp: string;
 }
 
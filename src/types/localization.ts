@@ -0,0 +1,24 @@
+/** Locales reports and alert notifications can be written in. Amounts stay in USD. */
+export type SupportedLocaleCode = 'en-US' | 'en-GB' | 'de-DE' | 'fr-FR' | 'es-ES' | 'pt-BR';
+
+export interface LocalizationSettings {
+  locale: SupportedLocaleCode;
+  /** Offset for timestamps in alert notifications. */
+  utcOffsetMinutes: number;
+  /** Replaces built-in message templates by key, e.g. `report.title`. */
+  templateOverrides: Record<string, string>;
+}
+
+export interface SupportedLocale {
+  code: SupportedLocaleCode;
+  name: string;
+}
+
+export interface LocalizationPreview {
+  number: string;
+  currency: string;
+  percent: string;
+  date: string;
+  dateTime: string;
+  message: string;
+}
@@ -0,0 +1,35 @@
+export type TransactionPath = 'immediate' | 'multisig_approval' | 'scheduled' | 'offline_signing';
+export type NonceAccountStatus = 'pending' | 'active' | 'closed';
+export type NonceAccountAction = 'create' | 'advance' | 'close';
+
+/** A recent blockhash stays valid for roughly this many seconds. */
+export const BLOCKHASH_LIFETIME_SECS = 60;
+
+export interface NonceAccountRecord {
+  address: string;
+  /** Funding wallet and nonce authority. */
+  walletAddress: string;
+  seed: string;
+  status: NonceAccountStatus;
+  nonce?: string | null;
+  lamports: number;
+  /** Set while a built transaction depends on the current nonce value. */
+  reservedFor?: string | null;
+  reservedNonce?: string | null;
+  createdAt: number;
+  updatedAt: number;
+}
+
+export interface NonceAccountTransaction {
+  nonceAddress: string;
+  action: NonceAccountAction;
+  /** Base64 unsigned transaction for the wallet to sign. */
+  transaction: string;
+  lamports: number;
+}
+
+export interface DurableTransaction {
+  transaction: string;
+  nonceAddress?: string | null;
+  nonce?: string | null;
+}
@@ -0,0 +1,65 @@
+export type OfflineSigningStatus =
+  | 'awaiting_signature'
+  | 'signed'
+  | 'submitted'
+  | 'expired'
+  | 'failed'
+  | 'cancelled';
+
+export interface OfflineSigningRequest {
+  id: string;
+  walletAddress: string;
+  description?: string | null;
+  messageHash: string;
+  requiredSigners: string[];
+  recentBlockhash: string;
+  /** Durable-nonce transactions never expire with the blockhash. */
+  durableNonce: boolean;
+  rpcUrl?: string | null;
+  status: OfflineSigningStatus;
+  txSignature?: string | null;
+  error?: string | null;
+  bundlePath?: string | null;
+  createdAt: string;
+  signedAt?: string | null;
+  submittedAt?: string | null;
+}
+
+export interface OfflineSigningBundle {
+  version: number;
+  id: string;
+  walletAddress: string;
+  description?: string | null;
+  messageHash: string;
+  requiredSigners: string[];
+  recentBlockhash: string;
+  durableNonce: boolean;
+  transaction: string;
+  createdAt: string;
+}
+
+export interface ExportOfflineSigningRequest {
+  walletAddress: string;
+  /** Base64 serialized transaction with empty signatures. */
+  transaction: string;
+  description?: string;
+  rpcUrl?: string;
+}
+
+export interface OfflineSigningExport {
+  request: OfflineSigningRequest;
+  bundle: OfflineSigningBundle;
+  /** Absent when the transaction is too large for a single QR code. */
+  qrSvg?: string | null;
+}
+
+export interface OfflineSigningConfig {
+  /** Holds `outbox/` and `inbox/`; defaults to the app data directory. */
+  watchFolder?: string | null;
+}
+
+export interface OfflineSigningUpdate {
+  id: string;
+  status: OfflineSigningStatus;
+  message: string;
+}
@@ -0,0 +1,48 @@
+export interface PnlCalendarDay {
+  /** Local calendar date, `YYYY-MM-DD`. */
+  date: string;
+  realizedPnl: number;
+  trades: number;
+  wins: number;
+  losses: number;
+}
+
+export interface PnlCalendarWeek {
+  /** Monday of the week. */
+  weekStart: string;
+  realizedPnl: number;
+  trades: number;
+  tradingDays: number;
+  greenDays: number;
+}
+
+export interface PnlTimeBucket {
+  label: string;
+  realizedPnl: number;
+  trades: number;
+  wins: number;
+  averagePnl: number;
+}
+
+export interface PnlStreaks {
+  /** Positive for a run of green days, negative for red days. */
+  current: number;
+  longestWin: number;
+  longestLoss: number;
+}
+
+/** Returned by `get_pnl_calendar` and emitted as `weekly_pnl_recap`. */
+export interface PnlCalendar {
+  from: string;
+  to: string;
+  utcOffsetMinutes: number;
+  totalRealizedPnl: number;
+  trades: number;
+  days: PnlCalendarDay[];
+  weeks: PnlCalendarWeek[];
+  bestDay?: PnlCalendarDay | null;
+  worstDay?: PnlCalendarDay | null;
+  streaks: PnlStreaks;
+  weekdays: PnlTimeBucket[];
+  hours: PnlTimeBucket[];
+}
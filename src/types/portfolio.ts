@@ -23,17 +23,39 @@ export interface PortfolioMetrics {
   realizedPnl: number;
   unrealizedPnl: number;
   lastUpdated: string;
+  lendingCollateral?: number;
+  lendingDebt?: number;
+  netLendingExposure?: number;
+  bridgeInTransit?: number;
 }
 
 export interface AllocationTarget {
   symbol: string;
   targetPercent: number;
+  minPercent?: number | null;
+  maxPercent?: number | null;
+  doNotSell?: boolean;
+}
+
+export type AssetClass =
+  | 'sol'
+  | 'stablecoin'
+  | 'liquidStaking'
+  | 'memecoin'
+  | 'nft'
+  | 'other';
+
+export interface AssetClassTarget {
+  assetClass: AssetClass;
+  minPercent?: number | null;
+  maxPercent?: number | null;
 }
 
 export interface RebalanceProfile {
   id: string;
   name: string;
   targets: AllocationTarget[];
+  classTargets?: AssetClassTarget[];
   deviationTriggerPercent: number;
   timeIntervalHours?: number;
   enabled: boolean;
@@ -284,3 +306,79 @@ export interface PricePoint {
   close: number;
   volume: number;
 }
+
+export type LiquidationMethod = 'stable' | 'quoted' | 'mark' | 'unroutable';
+
+export interface TokenLiquidation {
+  mint: string;
+  symbol: string;
+  balance: number;
+  markValueUsd: number;
+  liquidationValueUsd: number;
+  priceImpactPercent: number;
+  method: LiquidationMethod;
+  error?: string | null;
+}
+
+export interface LiquidationEstimate {
+  markValueUsd: number;
+  liquidationValueUsd: number;
+  haircutUsd: number;
+  haircutPercent: number;
+  tokens: TokenLiquidation[];
+  generatedAt: string;
+}
+
+export interface LiquidationSnapshot {
+  timestamp: string;
+  markValueUsd: number;
+  liquidationValueUsd: number;
+}
+
+export type DustTarget = 'sol' | 'usdc';
+
+export interface DustSettings {
+  thresholdUsd: number;
+  target: DustTarget;
+  maxFeePercent: number;
+  maxSlippagePercent: number;
+}
+
+export type DustSkipReason =
+  | 'is_target'
+  | 'native_sol'
+  | 'unpriced'
+  | 'no_route'
+  | 'slippage_too_high'
+  | 'fee_exceeds_value'
+  | 'build_failed'
+  | 'not_signed';
+
+export type DustItemStatus = 'ready' | 'skipped' | 'submitted' | 'failed';
+
+export interface DustItem {
+  id: string;
+  walletAddress: string;
+  mint: string;
+  symbol: string;
+  balance: number;
+  valueUsd: number;
+  expectedOutputUsd: number;
+  networkFeeUsd: number;
+  slippagePercent: number;
+  status: DustItemStatus;
+  skipReason?: DustSkipReason | null;
+  detail?: string | null;
+  transaction?: string | null;
+  signature?: string | null;
+}
+
+export interface DustPlan {
+  id: string;
+  settings: DustSettings;
+  items: DustItem[];
+  dustValueUsd: number;
+  expectedOutputUsd: number;
+  networkFeesUsd: number;
+  createdAt: string;
+}
@@ -0,0 +1,18 @@
+export type RecycledEntityKind = 'watchlist' | 'alert' | 'wallet' | 'webhook';
+
+export interface DeletedItem {
+  id: string;
+  kind: RecycledEntityKind;
+  entityId: string;
+  label: string;
+  snapshot: unknown;
+  deletedAt: string;
+  expiresAt: string;
+}
+
+export interface RestoredItem {
+  kind: RecycledEntityKind;
+  entityId: string;
+  label: string;
+  restored: unknown;
+}
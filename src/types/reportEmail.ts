@@ -0,0 +1,38 @@
+export type Weekday = 'Mon' | 'Tue' | 'Wed' | 'Thu' | 'Fri' | 'Sat' | 'Sun';
+
+/** Sections can be switched off individually, but not all at once. */
+export interface ReportSections {
+  portfolioChange: boolean;
+  alertsFired: boolean;
+  botTrades: boolean;
+  upcomingUnlocks: boolean;
+}
+
+export interface ReportEmailSettings {
+  enabled: boolean;
+  recipients: string[];
+  sections: ReportSections;
+  sendDay: Weekday;
+  /** Local time, `HH:MM`. */
+  sendTime: string;
+  /** Offset of the user's local time used for the send slot. */
+  utcOffsetMinutes: number;
+}
+
+export interface ReportEmailPreview {
+  subject: string;
+  html: string;
+  nextSendAt: string | null;
+}
+
+export interface ReportEmailRecord {
+  id: string;
+  /** The scheduled slot, or the request time for manual sends. */
+  slot: string;
+  sentAt: string;
+  recipients: string;
+  subject: string;
+  status: 'sent' | 'failed';
+  error: string | null;
+  manual: boolean;
+}
@@ -0,0 +1,20 @@
+/** In-memory stores kept within a memory budget. */
+export type ResourceStore = 'response_cache' | 'social_sentiment' | 'token_flow_graphs';
+
+export interface ResourceBudgets {
+  responseCacheMb: number;
+  socialSentimentMb: number;
+  tokenFlowMb: number;
+}
+
+export interface ResourceUsage {
+  store: ResourceStore;
+  label: string;
+  /** Estimated from serialized size. */
+  usedBytes: number;
+  budgetBytes: number;
+  entries: number;
+  shrinks: number;
+  evictedEntries: number;
+  lastShrunkAt: string | null;
+}
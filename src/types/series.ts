@@ -0,0 +1,18 @@
+export type SeriesMetric = 'price' | 'volume' | 'holder_count' | 'sentiment' | 'risk';
+
+export interface SeriesPoint {
+  /** Unix seconds. */
+  timestamp: number;
+  value: number;
+}
+
+/** Chart-ready series returned by `get_series`, downsampled with LTTB. */
+export interface ChartSeries {
+  address: string;
+  metric: SeriesMetric;
+  from: number;
+  to: number;
+  sourcePoints: number;
+  points: SeriesPoint[];
+  cached: boolean;
+}
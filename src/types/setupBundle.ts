@@ -0,0 +1,30 @@
+export type SetupSection = 'alerts' | 'dca_bots' | 'copy_trading';
+
+export type SetupChangeKind = 'create' | 'update' | 'unchanged' | 'conflict';
+
+export interface SetupValidationIssue {
+  path: string;
+  message: string;
+}
+
+/** One entry of a dry-run import; `key` is `<section>/<name>`. */
+export interface SetupChange {
+  key: string;
+  section: SetupSection;
+  name: string;
+  kind: SetupChangeKind;
+  changed_fields: string[];
+  reason: string | null;
+}
+
+export interface SetupImportPreview {
+  issues: SetupValidationIssue[];
+  changes: SetupChange[];
+}
+
+export interface SetupImportReport {
+  created: string[];
+  updated: string[];
+  skipped: SetupChange[];
+  failed: SetupValidationIssue[];
+}
@@ -67,7 +67,12 @@ export interface SentimentComparison {
   timestamp: string;
 }
 
-export type AlertType = 'whale_transaction' | 'smart_money_buy' | 'smart_money_sell' | 'smart_money_consensus';
+export type AlertType =
+  | 'whale_transaction'
+  | 'smart_money_buy'
+  | 'smart_money_sell'
+  | 'smart_money_consensus'
+  | 'portfolio_token_trade';
 
 export interface AlertConfig {
   id: string;
@@ -79,3 +84,24 @@ export interface AlertConfig {
   telegram_enabled: boolean;
   telegram_config_id?: string;
 }
+
+export interface TokenInteractionSettings {
+  include_portfolio: boolean;
+  watchlist_ids: string[];
+}
+
+export interface TokenInteractionAlert {
+  id: string;
+  wallet_address: string;
+  wallet_label?: string;
+  activity_id: string;
+  tx_signature: string;
+  direction: 'buy' | 'sell';
+  token_mint: string;
+  token_symbol: string;
+  source: string;
+  amount?: number;
+  amount_usd: number;
+  price?: number;
+  timestamp: string;
+}
@@ -0,0 +1,26 @@
+export type StreamProvider = 'Birdeye' | 'Helius';
+
+/** `socket` stalls reconnect the provider; `chart` stalls resubscribe one symbol. */
+export type StalledStream = 'socket' | 'chart';
+
+export interface StreamIncident {
+  provider: StreamProvider;
+  stream: StalledStream;
+  detectedAt: string;
+  silentForMs: number;
+  /** Topics subscribed again during recovery: symbols or wallet addresses. */
+  resubscribed: string[];
+}
+
+export interface ProviderIncidentStats {
+  provider: StreamProvider;
+  socketStalls: number;
+  chartStalls: number;
+  resubscribedTopics: number;
+  lastIncidentAt: string | null;
+  /** Newest first. */
+  recent: StreamIncident[];
+}
+
+/** Emitted with a `StreamIncident` whenever the watchdog recovers a stall. */
+export const STREAM_STALL_EVENT = 'stream_stall_detected';
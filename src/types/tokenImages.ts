@@ -0,0 +1,5 @@
+export interface TokenImageCacheStats {
+  entries: number;
+  totalBytes: number;
+  maxBytes: number;
+}
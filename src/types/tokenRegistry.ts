@@ -0,0 +1,30 @@
+/** Custom tokens are unknown to every provider; overrides correct provider metadata field by field. */
+export type TokenEntryKind = 'custom' | 'override';
+
+export interface TokenRegistryEntry {
+  mint: string;
+  kind: TokenEntryKind;
+  symbol: string | null;
+  name: string | null;
+  decimals: number | null;
+  /** Local image file or URL. */
+  logoPath: string | null;
+  updatedAt: string;
+}
+
+export interface RegisterTokenRequest {
+  mint: string;
+  symbol: string;
+  name: string;
+  decimals: number;
+  logoPath?: string;
+}
+
+/** Fields left empty keep the provider's value. */
+export interface TokenOverrideRequest {
+  mint: string;
+  symbol?: string;
+  name?: string;
+  decimals?: number;
+  logoPath?: string;
+}
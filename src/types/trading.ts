@@ -36,3 +36,207 @@ export interface Order {
   tx_signature?: string | null;
   error_message?: string | null;
 }
+
+export type TradeIdeaStatus = 'draft' | 'pending' | 'open' | 'closed' | 'cancelled';
+
+export interface TradeIdeaChecklistItem {
+  id: string;
+  label: string;
+  checked: boolean;
+  automatic: boolean;
+}
+
+export interface TradeIdeaRisk {
+  risk_amount: number;
+  reward_amount: number;
+  reward_risk_ratio: number;
+}
+
+export interface TradeIdeaOutcome {
+  exit_reason: 'stop_loss' | 'take_profit';
+  entry_price: number;
+  exit_price: number;
+  pnl: number;
+  pnl_percent: number;
+  r_multiple: number;
+  closed_at: string;
+}
+
+export interface TradeIdea {
+  id: string;
+  wallet_address: string;
+  token_mint: string;
+  token_symbol: string;
+  quote_mint: string;
+  quote_symbol: string;
+  thesis: string;
+  entry_price: number;
+  stop_loss: number;
+  take_profit: number;
+  amount: number;
+  max_risk: number;
+  min_reward_risk: number;
+  risk: TradeIdeaRisk;
+  checklist: TradeIdeaChecklistItem[];
+  status: TradeIdeaStatus;
+  entry_order_id?: string | null;
+  stop_order_id?: string | null;
+  take_profit_order_id?: string | null;
+  journal_entry_ids: string[];
+  outcome?: TradeIdeaOutcome | null;
+  created_at: string;
+  updated_at: string;
+  executed_at?: string | null;
+}
+
+export interface SnipeFilterProfile {
+  minSafetyScore: number;
+  minLiquidityUsd: number;
+  minHolders: number;
+  maxTopHolderPercent: number;
+  requireMintAuthorityRevoked: boolean;
+}
+
+export interface SnipeGuardRails {
+  maxSpendPerSnipeUsd: number;
+  dailyCapUsd: number;
+  maxSlippageBps: number;
+  maxPriceImpactPct: number;
+  maxRoundTripLossPct: number;
+}
+
+export interface ComputeBudgetSettings {
+  unitPriceMicroLamports: number;
+  maxPriorityFeeLamports: number;
+}
+
+export interface SnipeConfig {
+  enabled: boolean;
+  walletAddress?: string | null;
+  spendUsd: number;
+  profile: SnipeFilterProfile;
+  guardRails: SnipeGuardRails;
+  computeBudget: ComputeBudgetSettings;
+}
+
+export type SnipeStatus = 'prepared' | 'submitted' | 'expired' | 'failed';
+
+export interface SnipeTicket {
+  id: string;
+  walletAddress: string;
+  tokenAddress: string;
+  symbol: string;
+  spendUsd: number;
+  expectedOutput: string;
+  priceImpactPct: number;
+  roundTripLossPct: number;
+  priorityFeeLamports?: number | null;
+  transaction: string;
+  lastValidBlockHeight: number;
+  status: SnipeStatus;
+  signature?: string | null;
+  error?: string | null;
+  createdAt: string;
+}
+
+export interface CachedBlockhash {
+  blockhash: string;
+  lastValidBlockHeight: number;
+  fetchedAt: string;
+}
+
+export interface SnipeStatusReport {
+  config: SnipeConfig;
+  spentTodayUsd: number;
+  remainingTodayUsd: number;
+  blockhash?: CachedBlockhash | null;
+}
+
+export interface LadderRung {
+  multiple: number;
+  sell_percent: number;
+}
+
+export interface ExitLadderSettings {
+  wallet_address: string;
+  enabled: boolean;
+  apply_to_manual: boolean;
+  apply_to_snipes: boolean;
+  rungs: LadderRung[];
+  stop_loss_percent?: number | null;
+}
+
+export type PositionSource = 'manual' | 'snipe';
+export type LadderLegStatus = 'working' | 'filled' | 'cancelled';
+export type ExitLadderStatus = 'active' | 'closed' | 'cancelled';
+
+export interface LadderLeg {
+  rung_index?: number | null;
+  order_id: string;
+  price: number;
+  amount: number;
+  status: LadderLegStatus;
+}
+
+export interface ExitLadder {
+  id: string;
+  wallet_address: string;
+  token_mint: string;
+  token_symbol: string;
+  quote_mint: string;
+  quote_symbol: string;
+  source: PositionSource;
+  quantity: number;
+  cost: number;
+  sold_quantity: number;
+  rungs: LadderRung[];
+  stop_loss_percent?: number | null;
+  legs: LadderLeg[];
+  entries: string[];
+  status: ExitLadderStatus;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface RiskPerTradePolicy {
+  default_risk_percent: number;
+  max_risk_percent: number;
+  enforce_on_orders: boolean;
+}
+
+export interface PositionSizeSuggestion {
+  wallet_address: string;
+  equity_usd: number;
+  risk_percent: number;
+  risk_amount_usd: number;
+  stop_distance_percent: number;
+  quantity: number;
+  notional_usd: number;
+  capped_by_policy: boolean;
+  limited_by_equity: boolean;
+}
+
+export type PaperFillLiquiditySource = 'order_book' | 'pool' | 'size_tier';
+
+export interface PaperFillReport {
+  trade_id: string;
+  symbol: string;
+  side: string;
+  quantity: number;
+  market_price: number;
+  naive_price: number;
+  modeled_price: number;
+  price_impact: number;
+  spread: number;
+  latency_ms: number;
+  latency_drift: number;
+  liquidity_source: PaperFillLiquiditySource;
+  pool_liquidity_usd: number | null;
+}
+
+export interface PaperFillComparison {
+  trades: PaperFillReport[];
+  avg_price_impact_bps: number;
+  avg_naive_gap_bps: number;
+  modeled_cost_vs_naive: number;
+}
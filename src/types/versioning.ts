@@ -0,0 +1,19 @@
+export type VersionedEntityKind = 'watchlist' | 'alert' | 'webhook' | 'dca_bot';
+
+/** Error returned when an edit was based on an outdated copy of the entity. */
+export interface VersionConflict {
+  entityKind: VersionedEntityKind;
+  entityId: string;
+  expectedVersion: number;
+  latestVersion: number;
+  latest?: unknown;
+}
+
+export function isVersionConflict(error: unknown): error is VersionConflict {
+  return (
+    typeof error === 'object' &&
+    error !== null &&
+    'latestVersion' in error &&
+    'entityId' in error
+  );
+}
@@ -35,6 +35,7 @@ export interface AddressBookContact {
   lastUsed?: string;
   transactionCount: number;
   tags: string[];
+  domain?: string;
 }
 
 export interface BridgeProvider {
@@ -123,3 +124,82 @@ export interface HardwareSigningResponse {
   publicKey: string;
   deviceId: string;
 }
+
+export type FeeDisclosurePeriod = '7d' | '30d' | '90d' | 'ytd' | 'all';
+
+export interface SwapFeeRecord {
+  id: string;
+  walletAddress: string;
+  inputMint: string;
+  outputMint: string;
+  inAmount: string;
+  outAmount: string;
+  priceImpactPct: number;
+  slippageBps: number;
+  routeFeeBps: number;
+  platformFeeBps: number;
+  platformFeeAmount: string;
+  platformFeeMint: string;
+  feeAccount?: string | null;
+  createdAt: number;
+}
+
+export interface FeeRevenue {
+  mint: string;
+  amount: string;
+  swapCount: number;
+}
+
+export interface FeeDisclosure {
+  period: FeeDisclosurePeriod;
+  swapCount: number;
+  swapsWithFees: number;
+  maxPlatformFeeBps: number;
+  revenue: FeeRevenue[];
+  feeAccounts: string[];
+  records: SwapFeeRecord[];
+}
+
+export interface ExecutionQualityReport {
+  period: FeeDisclosurePeriod;
+  swapCount: number;
+  avgPriceImpactPct: number;
+  avgSlippageBps: number;
+  avgRouteFeeBps: number;
+  avgPlatformFeeBps: number;
+  feeDisclosure: FeeDisclosure;
+}
+
+export interface EmptyTokenAccount {
+  address: string;
+  walletAddress: string;
+  mint: string;
+  programId: string;
+  rentLamports: number;
+  lastActivity?: number | null;
+  recentlyActive: boolean;
+}
+
+export interface EmptyAccountScan {
+  wallets: string[];
+  accounts: EmptyTokenAccount[];
+  reclaimableLamports: number;
+  reclaimableSol: number;
+  recentlyActiveCount: number;
+}
+
+export interface CloseAccountBatch {
+  walletAddress: string;
+  accounts: string[];
+  rentLamports: number;
+  transaction: string;
+}
+
+export interface RentReclaimRecord {
+  id: string;
+  walletAddress: string;
+  signature: string;
+  accountCount: number;
+  rentLamports: number;
+  reclaimedAt: number;
+}
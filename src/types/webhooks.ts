@@ -5,11 +5,57 @@ export interface WebhookTemplateVariable {
   description?: string;
 }
 
+export type BackoffCurve = 'exponential' | 'linear' | 'fixed';
+
 export interface RetryPolicy {
   maxAttempts: number;
   baseDelaySecs: number;
   maxDelaySecs: number;
   jitter: boolean;
+  backoff?: BackoffCurve;
+  /** Per-attempt request timeout, defaults to 10 seconds. */
+  timeoutSecs?: number;
+}
+
+export type WebhookEventType =
+  | 'price_alert'
+  | 'trade'
+  | 'wallet_activity'
+  | 'smart_money'
+  | 'token_unlock'
+  | 'coin_detected'
+  | 'system';
+
+export type EventSeverity = 'info' | 'warning' | 'critical';
+
+export interface WebhookSubscription {
+  /** Webhooks with no event types are only triggered manually. */
+  eventTypes: WebhookEventType[];
+  /** Empty means any token. */
+  tokenAllowlist: string[];
+  minSeverity?: EventSeverity;
+  /** Applies to trade and wallet activity events only. */
+  minTradeNotionalUsd?: number;
+}
+
+export interface WebhookEvent {
+  eventType: WebhookEventType;
+  severity: EventSeverity;
+  title: string;
+  message: string;
+  tokenAddress?: string;
+  notionalUsd?: number;
+  data?: Record<string, unknown>;
+  occurredAt?: string;
+}
+
+export type FilterRejection = 'event_type' | 'token' | 'severity' | 'notional';
+
+export interface WebhookEventMatch {
+  webhookId: string;
+  webhookName: string;
+  enabled: boolean;
+  rejectedBy?: FilterRejection;
 }
 
 export interface WebhookConfig {
@@ -25,6 +71,8 @@ export interface WebhookConfig {
   createdAt: string;
   updatedAt: string;
   retryPolicy: RetryPolicy;
+  subscription?: WebhookSubscription;
+  version?: number;
 }
 
 export type DeliveryStatus = 'pending' | 'sent' | 'failed' | 'retrying';
@@ -43,6 +91,38 @@ export interface WebhookDeliveryLog {
   completedAt?: string;
 }
 
+export type DeadLetterStatus = 'pending' | 'resolved' | 'discarded';
+
+export interface WebhookDeadLetter {
+  id: string;
+  webhookId: string;
+  webhookName: string;
+  deliveryLogId: string;
+  variables: Record<string, unknown>;
+  attempts: number;
+  lastError?: string;
+  lastResponseCode?: number;
+  status: DeadLetterStatus;
+  replayCount: number;
+  failedAt: string;
+  lastReplayedAt?: string;
+  resolvedLogId?: string;
+}
+
+export interface WebhookDeliveryStats {
+  webhookId: string;
+  webhookName: string;
+  url: string;
+  totalDeliveries: number;
+  successfulDeliveries: number;
+  failedDeliveries: number;
+  successRate: number;
+  averageAttempts: number;
+  averageResponseTimeMs?: number;
+  pendingDeadLetters: number;
+  lastDeliveryAt?: string;
+}
+
 export interface WebhookTestResult {
   success: boolean;
   message: string;
@@ -0,0 +1,8 @@
+/**
+ * Key for one user submission of a mutating command. Generate it once per
+ * submit so a resent invoke is answered with the original result instead of
+ * executing twice.
+ */
+export function newIdempotencyKey(): string {
+  return crypto.randomUUID();
+}